@@ -15,9 +15,15 @@
 //! Configuration for a Habitat Builder-API service
 
 use std::net;
+use std::time::Duration;
 
-use hab_net::config::{GitHubOAuth, RouteAddrs};
-use hab_core::config::{ConfigFile, ParseInto};
+use hab_net::config::{GitHubOAuth, Keepalive, MessageAuth, Reconnect, RouteAddrs, RouterAuth,
+                      Sockets};
+use hab_net::capture::CaptureConfig;
+use hab_net::routing::KeepaliveConfig;
+use hab_net::sched::SchedConfig;
+use hab_net::zap::ZapCredentials;
+use hab_core::config::{parse_duration_or_legacy, ConfigFile, ParseInto};
 use depot;
 use toml;
 
@@ -37,6 +43,10 @@ const DEV_GITHUB_CLIENT_SECRET: &'static str = "e5ff94188e3cf01d42f3e2bcbbe4faab
 pub struct Config {
     /// Public listening net address for HTTP requests
     pub http_addr: net::SocketAddrV4,
+    /// Public listening net address for WebSocket clients subscribing to events
+    pub ws_addr: net::SocketAddrV4,
+    /// List of net addresses of job servers publishing job status events
+    pub job_events_addrs: Vec<net::SocketAddrV4>,
     /// Depot's configuration
     pub depot: depot::Config,
     /// List of net addresses for routing servers to connect to
@@ -49,9 +59,44 @@ pub struct Config {
     pub github_client_secret: String,
     /// Path to UI files to host over HTTP. If not set the UI will be disabled.
     pub ui_root: Option<String>,
+    /// Shared secrets for authenticating routed messages, newest first.
+    pub hmac_keys: Vec<String>,
+    /// Credentials presented to routers which authenticate their clients.
+    pub router_auth: ZapCredentials,
+    /// How idle connections to routers are kept open and verified.
+    pub keepalive: KeepaliveConfig,
+    /// Number of ZeroMQ IO threads
+    pub io_threads: usize,
+    /// Most ZeroMQ sockets open at once
+    pub max_sockets: usize,
+    /// Time the router broker waits before reconnecting to a router
+    pub reconnect_ivl: Duration,
+    /// Most time the router broker waits between reconnect attempts
+    pub reconnect_ivl_max: Duration,
+    /// Most random time added to the router broker's first reconnect wait
+    pub reconnect_jitter: Duration,
+    /// CPU to pin the router broker's thread to and its priority, read from `broker_cpus` and
+    /// `broker_nice`
+    pub broker_sched: SchedConfig,
+    /// Capture of the traffic the router broker forwards, for replaying with `net-replay`. Read
+    /// from `broker_capture`, the capture file's path, and `broker_capture_max_size`,
+    /// `broker_capture_keep` and `broker_capture_bodies`
+    pub broker_capture: Option<CaptureConfig>,
+    /// Time a read routed by the gateway may go unanswered before a duplicate is sent, or zero
+    /// to never send one
+    pub hedge_delay: Duration,
 }
 
 impl Config {
+    /// The delay after which gateway reads are hedged, if they are.
+    pub fn hedge_delay(&self) -> Option<Duration> {
+        if self.hedge_delay == Duration::from_millis(0) {
+            None
+        } else {
+            Some(self.hedge_delay)
+        }
+    }
+
     /// Set the port of the http listener
     pub fn set_port(&mut self, port: u16) -> &mut Self {
         self.http_addr = net::SocketAddrV4::new(*self.http_addr.ip(), port);
@@ -63,12 +108,25 @@ impl Default for Config {
     fn default() -> Self {
         Config {
             http_addr: net::SocketAddrV4::new(net::Ipv4Addr::new(0, 0, 0, 0), 9636),
+            ws_addr: net::SocketAddrV4::new(net::Ipv4Addr::new(0, 0, 0, 0), 9637),
+            job_events_addrs: vec![net::SocketAddrV4::new(net::Ipv4Addr::new(127, 0, 0, 1), 5568)],
             routers: vec![net::SocketAddrV4::new(net::Ipv4Addr::new(127, 0, 0, 1), 5562)],
             depot: depot::Config::default(),
             github_url: GITHUB_URL.to_string(),
             github_client_id: DEV_GITHUB_CLIENT_ID.to_string(),
             github_client_secret: DEV_GITHUB_CLIENT_SECRET.to_string(),
             ui_root: None,
+            hmac_keys: vec![],
+            router_auth: ZapCredentials::default(),
+            keepalive: KeepaliveConfig::default(),
+            io_threads: 1,
+            max_sockets: 1024,
+            reconnect_ivl: Duration::from_millis(100),
+            reconnect_ivl_max: Duration::from_secs(10),
+            reconnect_jitter: Duration::from_secs(1),
+            broker_sched: SchedConfig::default(),
+            broker_capture: None,
+            hedge_delay: Duration::from_millis(0),
         }
     }
 }
@@ -83,9 +141,16 @@ impl ConfigFile for Config {
             cfg.ui_root = Some(pkg_path);
         }
         try!(toml.parse_into("cfg.http_addr", &mut cfg.http_addr));
+        try!(toml.parse_into("cfg.ws_addr", &mut cfg.ws_addr));
         try!(toml.parse_into("cfg.router_addrs", &mut cfg.routers));
+        try!(toml.parse_into("cfg.job_events_addrs", &mut cfg.job_events_addrs));
         try!(toml.parse_into("pkg.svc_data_path", &mut cfg.depot.path));
         try!(toml.parse_into("cfg.depot.datastore_addr", &mut cfg.depot.datastore_addr));
+        let mut rules: Vec<String> = vec![];
+        try!(toml.parse_into("cfg.depot.promotion_rules", &mut rules));
+        cfg.depot.promotion_rules = try!(depot::config::parse_rules(&rules,
+                                                                    "cfg.depot.promotion_rules"));
+        cfg.depot.job_events_addrs = cfg.job_events_addrs.clone();
         try!(toml.parse_into("cfg.github.url", &mut cfg.github_url));
         try!(toml.parse_into("cfg.github.url", &mut cfg.depot.github_url));
         if !try!(toml.parse_into("cfg.github.client_id", &mut cfg.github_client_id)) {
@@ -97,16 +162,88 @@ impl ConfigFile for Config {
         }
         try!(toml.parse_into("cfg.github.client_secret",
                              &mut cfg.depot.github_client_secret));
+        try!(toml.parse_into("cfg.hmac_keys", &mut cfg.hmac_keys));
+        try!(toml.parse_into("cfg.router_auth", &mut cfg.router_auth));
+        try!(toml.parse_into("cfg", &mut cfg.keepalive));
+        try!(toml.parse_into("cfg.io_threads", &mut cfg.io_threads));
+        try!(toml.parse_into("cfg.max_sockets", &mut cfg.max_sockets));
+        try!(parse_duration_or_legacy(&toml,
+                                      "cfg.reconnect_ivl",
+                                      "cfg.reconnect_ivl_ms",
+                                      &mut cfg.reconnect_ivl));
+        try!(parse_duration_or_legacy(&toml,
+                                      "cfg.reconnect_ivl_max",
+                                      "cfg.reconnect_ivl_max_ms",
+                                      &mut cfg.reconnect_ivl_max));
+        try!(parse_duration_or_legacy(&toml,
+                                      "cfg.reconnect_jitter",
+                                      "cfg.reconnect_jitter_ms",
+                                      &mut cfg.reconnect_jitter));
+        cfg.broker_sched = try!(SchedConfig::from_toml(&toml,
+                                                       "cfg.broker_cpus",
+                                                       "cfg.broker_nice"));
+        let mut capture_path: Option<String> = None;
+        try!(toml.parse_into("cfg.broker_capture", &mut capture_path));
+        if let Some(path) = capture_path {
+            let mut capture = CaptureConfig::new(path);
+            try!(toml.parse_into("cfg.broker_capture_max_size", &mut capture.max_size));
+            try!(toml.parse_into("cfg.broker_capture_keep", &mut capture.keep));
+            try!(toml.parse_into("cfg.broker_capture_bodies", &mut capture.bodies));
+            cfg.broker_capture = Some(capture);
+        }
+        try!(toml.parse_into("cfg.hedge_delay", &mut cfg.hedge_delay));
         Ok(cfg)
     }
 }
 
+impl Keepalive for Config {
+    fn keepalive(&self) -> KeepaliveConfig {
+        self.keepalive.clone()
+    }
+}
+
+impl MessageAuth for Config {
+    fn hmac_keys(&self) -> &[String] {
+        &self.hmac_keys
+    }
+}
+
+impl RouterAuth for Config {
+    fn router_auth(&self) -> ZapCredentials {
+        self.router_auth.clone()
+    }
+}
+
+impl Reconnect for Config {
+    fn reconnect_ivl(&self) -> Duration {
+        self.reconnect_ivl
+    }
+
+    fn reconnect_ivl_max(&self) -> Duration {
+        self.reconnect_ivl_max
+    }
+
+    fn reconnect_jitter(&self) -> Duration {
+        self.reconnect_jitter
+    }
+}
+
 impl RouteAddrs for Config {
     fn route_addrs(&self) -> &Vec<net::SocketAddrV4> {
         &self.routers
     }
 }
 
+impl Sockets for Config {
+    fn io_threads(&self) -> usize {
+        self.io_threads
+    }
+
+    fn max_sockets(&self) -> usize {
+        self.max_sockets
+    }
+}
+
 impl GitHubOAuth for Config {
     fn github_url(&self) -> &str {
         &self.github_url
@@ -18,6 +18,7 @@ use std::io;
 use std::result;
 
 use hab_core;
+use hab_net;
 use depot;
 use hyper;
 use protobuf;
@@ -29,13 +30,13 @@ pub enum Error {
     BadPort(String),
     Depot(depot::Error),
     HabitatCore(hab_core::Error),
+    HabitatNet(hab_net::Error),
     HyperError(hyper::error::Error),
     HTTP(hyper::status::StatusCode),
     IO(io::Error),
     JsonDecode(json::DecoderError),
     Protobuf(protobuf::ProtobufError),
     RequiredConfigField(&'static str),
-    Zmq(zmq::Error),
 }
 
 pub type Result<T> = result::Result<T, Error>;
@@ -46,6 +47,7 @@ impl fmt::Display for Error {
             Error::BadPort(ref e) => format!("{} is an invalid port. Valid range 1-65535.", e),
             Error::Depot(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
+            Error::HabitatNet(ref e) => format!("{}", e),
             Error::HyperError(ref e) => format!("{}", e),
             Error::HTTP(ref e) => format!("{}", e),
             Error::IO(ref e) => format!("{}", e),
@@ -54,7 +56,6 @@ impl fmt::Display for Error {
             Error::RequiredConfigField(ref e) => {
                 format!("Missing required field in configuration, {}", e)
             }
-            Error::Zmq(ref e) => format!("{}", e),
         };
         write!(f, "{}", msg)
     }
@@ -66,13 +67,13 @@ impl error::Error for Error {
             Error::BadPort(_) => "Received an invalid port or a number outside of the valid range.",
             Error::Depot(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
+            Error::HabitatNet(ref err) => err.description(),
             Error::HyperError(ref err) => err.description(),
             Error::HTTP(_) => "Non-200 HTTP response.",
             Error::IO(ref err) => err.description(),
             Error::JsonDecode(ref err) => err.description(),
             Error::Protobuf(ref err) => err.description(),
             Error::RequiredConfigField(_) => "Missing required field in configuration.",
-            Error::Zmq(ref err) => err.description(),
         }
     }
 }
@@ -89,6 +90,12 @@ impl From<depot::Error> for Error {
     }
 }
 
+impl From<hab_net::Error> for Error {
+    fn from(err: hab_net::Error) -> Self {
+        Error::HabitatNet(err)
+    }
+}
+
 impl From<hyper::error::Error> for Error {
     fn from(err: hyper::error::Error) -> Self {
         Error::HyperError(err)
@@ -115,6 +122,6 @@ impl From<protobuf::ProtobufError> for Error {
 
 impl From<zmq::Error> for Error {
     fn from(err: zmq::Error) -> Error {
-        Error::Zmq(err)
+        Error::HabitatNet(hab_net::Error::from(err))
     }
 }
@@ -0,0 +1,311 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Fan-out of job and package events to the gateway's WebSocket clients.
+//!
+//! The `EventHub` bridges two event sources to its subscribers: job status changes published by
+//! each job server, and packages uploaded to or promoted in the depot. Each subscriber registers
+//! a filter and receives every matching event as a JSON document over a bounded channel.
+//! Subscribers which fall `SUBSCRIBER_QUEUE` events behind are dropped rather than letting their
+//! backlog grow without limit.
+
+use std::collections::BTreeMap;
+use std::sync::{mpsc, Arc, Mutex, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use depot_client::PackageEvent;
+use hab_net::runtime::{self, Shutdown};
+use hab_net::server::ToAddrString;
+use protobuf::parse_from_bytes;
+use protocol::jobsrv::{self, Job};
+use rustc_serialize::json::{self, Json, ToJson};
+use zmq;
+
+use config::Config;
+use error::{Error, Result};
+
+/// Events queued for a subscriber before it is considered too slow and dropped.
+pub const SUBSCRIBER_QUEUE: usize = 256;
+// Time to wait before receiving again after a job event could not be received.
+const RECV_BACKOFF_MS: u64 = 1_000;
+
+/// An event delivered to subscribers.
+pub enum Event {
+    Job(Job),
+    Package(PackageEvent),
+}
+
+impl ToJson for Event {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        match *self {
+            Event::Job(ref job) => {
+                m.insert("type".to_string(), "job".to_json());
+                m.insert("job".to_string(), job.to_json());
+            }
+            Event::Package(ref event) => {
+                m.insert("type".to_string(), "package".to_json());
+                m.insert("package".to_string(), package_json(event));
+            }
+        }
+        Json::Object(m)
+    }
+}
+
+/// Which events a subscriber receives.
+#[derive(Debug, Default)]
+pub struct Filter {
+    /// Only receive events of jobs owned by this account. Sessions with the admin scope leave
+    /// this unset to receive events of every job.
+    pub owner_id: Option<u64>,
+    /// Only receive events of this job.
+    pub job_id: Option<u64>,
+    /// Only receive events of packages in this origin.
+    pub origin: Option<String>,
+}
+
+impl Filter {
+    pub fn matches(&self, event: &Event) -> bool {
+        match *event {
+            Event::Job(ref job) => {
+                self.owner_id.map_or(true, |id| id == job.get_owner_id()) &&
+                self.job_id.map_or(true, |id| id == job.get_id())
+            }
+            Event::Package(ref event) => {
+                self.job_id.is_none() && self.origin.as_ref().map_or(true, |o| *o == event.origin)
+            }
+        }
+    }
+}
+
+struct Subscriber {
+    filter: Filter,
+    tx: mpsc::SyncSender<Arc<String>>,
+}
+
+#[derive(Default)]
+pub struct EventHub {
+    subscribers: Mutex<Vec<Subscriber>>,
+}
+
+impl EventHub {
+    pub fn new() -> Self {
+        EventHub::default()
+    }
+
+    /// Receive every event matching the filter from now on, encoded as JSON. Unsubscribe by
+    /// dropping the receiver. The sending end is dropped, ending the subscription, if the
+    /// receiver falls `SUBSCRIBER_QUEUE` events behind.
+    pub fn subscribe(&self, filter: Filter) -> mpsc::Receiver<Arc<String>> {
+        let (tx, rx) = mpsc::sync_channel(SUBSCRIBER_QUEUE);
+        self.subscribers.lock().unwrap().push(Subscriber {
+            filter: filter,
+            tx: tx,
+        });
+        rx
+    }
+
+    /// Hand an event to each subscriber whose filter matches it.
+    pub fn publish(&self, event: &Event) {
+        let encoded = Arc::new(json::encode(&event.to_json()).unwrap());
+        // Subscribers whose receiving end has hung up or which can't keep up are dropped.
+        self.subscribers.lock().unwrap().retain(|sub| {
+            if !sub.filter.matches(event) {
+                return true;
+            }
+            match sub.tx.try_send(encoded.clone()) {
+                Ok(()) => true,
+                Err(mpsc::TrySendError::Full(_)) => {
+                    warn!("dropping event subscriber which fell {} events behind",
+                          SUBSCRIBER_QUEUE);
+                    false
+                }
+                Err(mpsc::TrySendError::Disconnected(_)) => false,
+            }
+        });
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.subscribers.lock().unwrap().len()
+    }
+}
+
+/// Subscribe to the job events of every configured job server and publish them to the hub until
+/// shutdown is requested.
+///
+/// # Errors
+///
+/// * The subscriber socket could not be created, connected or polled
+pub fn bridge_jobs(hub: Arc<EventHub>,
+                   ctx: Arc<RwLock<zmq::Context>>,
+                   config: &Config,
+                   shutdown: Shutdown)
+                   -> Result<()> {
+    let sock = try!(ctx.write().unwrap().socket(zmq::SUB));
+    try!(sock.set_subscribe(jobsrv::JOB_EVENT_TOPIC.as_bytes()));
+    for addr in config.job_events_addrs.iter() {
+        try!(sock.connect(&addr.to_addr_string()));
+    }
+    let mut msg = try!(zmq::Message::new());
+    while !shutdown.is_requested() {
+        match zmq::poll(&mut [sock.as_poll_item(zmq::POLLIN)], runtime::SHUTDOWN_POLL_MS) {
+            Ok(0) | Err(zmq::Error::EINTR) => continue,
+            Ok(_) => (),
+            Err(e) => return Err(Error::from(e)),
+        }
+        match recv_job(&sock, &mut msg) {
+            Ok(Some(job)) => hub.publish(&Event::Job(job)),
+            Ok(None) => (),
+            Err(zmq::Error::ETERM) => break,
+            Err(e) => {
+                error!("job event receive failed, err={}", e);
+                thread::sleep(Duration::from_millis(RECV_BACKOFF_MS));
+            }
+        }
+    }
+    Ok(())
+}
+
+// Receive the topic and body of a job event. Returns `None` if the event was malformed, once
+// every frame of it has been read.
+fn recv_job(sock: &zmq::Socket, msg: &mut zmq::Message) -> zmq::Result<Option<Job>> {
+    try!(sock.recv(msg, 0));
+    if !try!(sock.get_rcvmore()) {
+        warn!("dropping job event without a body");
+        return Ok(None);
+    }
+    try!(sock.recv(msg, 0));
+    let job = parse_from_bytes::<Job>(&msg[..]);
+    let mut extra = false;
+    while try!(sock.get_rcvmore()) {
+        extra = true;
+        try!(sock.recv(msg, 0));
+    }
+    if extra {
+        warn!("dropping job event with unexpected frames");
+        return Ok(None);
+    }
+    match job {
+        Ok(job) => Ok(Some(job)),
+        Err(e) => {
+            warn!("dropping malformed job event, err={}", e);
+            Ok(None)
+        }
+    }
+}
+
+/// Publish the package events received from a depot's notifier to the hub from a separate
+/// thread. The thread exits once the notifier is dropped.
+pub fn bridge_packages(hub: Arc<EventHub>, events: mpsc::Receiver<PackageEvent>) -> JoinHandle<()> {
+    thread::Builder::new()
+        .name("package-events".to_string())
+        .spawn(move || for event in events.iter() {
+            hub.publish(&Event::Package(event));
+        })
+        .unwrap()
+}
+
+fn package_json(event: &PackageEvent) -> Json {
+    let mut m = BTreeMap::new();
+    m.insert("seq".to_string(), event.seq.to_json());
+    m.insert("kind".to_string(), event.kind.to_json());
+    m.insert("view".to_string(), event.view.to_json());
+    m.insert("origin".to_string(), event.origin.to_json());
+    m.insert("name".to_string(), event.name.to_json());
+    m.insert("ident".to_string(), event.ident.to_json());
+    Json::Object(m)
+}
+
+#[cfg(test)]
+mod tests {
+    use depot_client::PackageEvent;
+    use protocol::jobsrv::Job;
+    use rustc_serialize::json::Json;
+
+    use super::{Event, EventHub, Filter, SUBSCRIBER_QUEUE};
+
+    fn job(id: u64, owner_id: u64) -> Event {
+        let mut job = Job::new();
+        job.set_id(id);
+        job.set_owner_id(owner_id);
+        Event::Job(job)
+    }
+
+    fn package(origin: &str) -> Event {
+        Event::Package(PackageEvent {
+            seq: 1,
+            kind: "uploaded".to_string(),
+            view: None,
+            origin: origin.to_string(),
+            name: "redis".to_string(),
+            ident: format!("{}/redis/3.2.1/20160824202939", origin),
+        })
+    }
+
+    #[test]
+    fn filter_matches_jobs_by_owner_and_id() {
+        let filter = Filter { owner_id: Some(1), ..Filter::default() };
+        assert!(filter.matches(&job(10, 1)));
+        assert!(!filter.matches(&job(10, 2)));
+        let filter = Filter { job_id: Some(10), ..Filter::default() };
+        assert!(filter.matches(&job(10, 2)));
+        assert!(!filter.matches(&job(11, 2)));
+        assert!(Filter::default().matches(&job(11, 2)));
+    }
+
+    #[test]
+    fn filter_matches_packages_by_origin() {
+        let filter = Filter { origin: Some("core".to_string()), ..Filter::default() };
+        assert!(filter.matches(&package("core")));
+        assert!(!filter.matches(&package("chef")));
+        assert!(Filter::default().matches(&package("chef")));
+        // Subscribers following a single job aren't interested in packages.
+        let filter = Filter { job_id: Some(10), ..Filter::default() };
+        assert!(!filter.matches(&package("core")));
+    }
+
+    #[test]
+    fn publish_delivers_matching_events() {
+        let hub = EventHub::new();
+        let mine = hub.subscribe(Filter { owner_id: Some(1), ..Filter::default() });
+        let theirs = hub.subscribe(Filter { owner_id: Some(2), ..Filter::default() });
+        hub.publish(&job(10, 1));
+        let event = Json::from_str(&mine.try_recv().unwrap()).unwrap();
+        assert_eq!(event.find("type").and_then(|t| t.as_string()), Some("job"));
+        assert!(theirs.try_recv().is_err());
+        assert_eq!(hub.subscriber_count(), 2);
+    }
+
+    #[test]
+    fn publish_drops_hung_up_subscribers() {
+        let hub = EventHub::new();
+        drop(hub.subscribe(Filter::default()));
+        hub.publish(&job(10, 1));
+        assert_eq!(hub.subscriber_count(), 0);
+    }
+
+    #[test]
+    fn publish_drops_slow_subscribers() {
+        let hub = EventHub::new();
+        let slow = hub.subscribe(Filter::default());
+        for _ in 0..SUBSCRIBER_QUEUE {
+            hub.publish(&job(10, 1));
+        }
+        assert_eq!(hub.subscriber_count(), 1);
+        hub.publish(&job(10, 1));
+        assert_eq!(hub.subscriber_count(), 0);
+        // The queued events are still delivered before the subscription ends.
+        assert_eq!(slow.iter().count(), SUBSCRIBER_QUEUE);
+    }
+}
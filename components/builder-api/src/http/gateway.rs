@@ -0,0 +1,434 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! A declarative table of the REST endpoints which are answered by routing a single protocol
+//! message through the `RouteSrv` mesh.
+//!
+//! Each endpoint is an `Rpc`: it authorizes the request's session for the endpoint's scope and
+//! features, translates the request into a protocol message, routes the message with a
+//! `BrokerConn`, and renders the reply as JSON. JSON request bodies are read into messages with
+//! `required` and `optional`, which answer a missing or mistyped field with `422 Unprocessable
+//! Entity`. Error replies are mapped to HTTP statuses the same way for every endpoint. Endpoints
+//! which need more than one routed call are still written as handlers.
+
+use std::marker::PhantomData;
+use std::result;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bodyparser;
+use hab_net::clients;
+use hab_net::routing::{Broker, BrokerContext};
+use iron::prelude::*;
+use iron::method::Method;
+use iron::status;
+use iron::Handler;
+use protobuf::MessageStatic;
+use protocol::Routable;
+use protobuf::RepeatedField;
+use protocol::jobsrv::{Job, JobCreate, JobGet, JobGroup, JobGroupGet, JobGroupSpec, JobLog,
+                       JobLogGet, JobSchedule, JobScheduleCreate, JobScheduleListRequest,
+                       JobScheduleListResponse};
+use protocol::sessionsrv::{ApiTokenListRequest, ApiTokenListResponse, FeatureFlags, Scope,
+                           Session, BUILDER};
+use protocol::vault::{AccountInvitationListRequest, AccountInvitationListResponse,
+                      AccountOriginListRequest, AccountOriginListResponse};
+use router::Router;
+use rustc_serialize::json::{self, Json, ToJson};
+
+use super::handlers::{authorize_features, extract_query_value, render_client_error};
+
+/// Translates a request, made by the owner of the given session, into the message to route.
+/// Requests which can't be translated are answered with the returned response instead.
+pub type Translate<M> = fn(&mut Request, &Session) -> result::Result<M, Response>;
+
+/// An endpoint answered by routing the message `M` and replying with the JSON of the reply `R`.
+pub struct Rpc<M, R> {
+    ctx: Arc<BrokerContext>,
+    scope: Scope,
+    features: FeatureFlags,
+    translate: Translate<M>,
+    status: status::Status,
+    hedge: Option<Duration>,
+    reply: PhantomData<fn() -> R>,
+}
+
+impl<M, R> Rpc<M, R>
+    where M: Routable,
+          R: MessageStatic + ToJson
+{
+    pub fn new(ctx: &Arc<BrokerContext>, scope: Scope, translate: Translate<M>) -> Self {
+        Rpc {
+            ctx: ctx.clone(),
+            scope: scope,
+            features: FeatureFlags::empty(),
+            translate: translate,
+            status: status::Ok,
+            hedge: None,
+            reply: PhantomData,
+        }
+    }
+
+    /// Answer successful calls with the given status instead of `200 OK`.
+    pub fn with_status(mut self, status: status::Status) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Only answer accounts enrolled in the given features, as though the endpoint didn't exist
+    /// for anyone else.
+    pub fn with_features(mut self, features: FeatureFlags) -> Self {
+        self.features = features;
+        self
+    }
+
+    /// Send a duplicate of calls which haven't been answered within the given delay, if any. Only
+    /// for calls which are safe to process twice. See `Broker::connect_hedged`.
+    pub fn with_hedge(mut self, delay: Option<Duration>) -> Self {
+        self.hedge = delay;
+        self
+    }
+}
+
+impl<M, R> Handler for Rpc<M, R>
+    where M: Routable + 'static,
+          R: MessageStatic + ToJson + 'static
+{
+    fn handle(&self, req: &mut Request) -> IronResult<Response> {
+        let session = match authorize_features(req, &self.ctx, &self.scope, self.features) {
+            Ok(session) => session,
+            Err(response) => return Ok(response),
+        };
+        let msg = match (self.translate)(req, &session) {
+            Ok(msg) => msg,
+            Err(response) => return Ok(response),
+        };
+        let mut conn = match self.hedge {
+            Some(delay) => Broker::connect_hedged(&self.ctx, delay).unwrap(),
+            None => Broker::connect(&self.ctx).unwrap(),
+        };
+        match clients::call::<_, M, R>(&mut conn, &msg) {
+            Ok(reply) => {
+                let encoded = json::encode(&reply.to_json()).unwrap();
+                Ok(Response::with((self.status, encoded)))
+            }
+            Err(e) => Ok(render_client_error(e)),
+        }
+    }
+}
+
+/// The endpoints answered by a single routed call, as `(method, path, endpoint)`. Reads are
+/// hedged after `hedge`, if given.
+pub fn routes(ctx: &Arc<BrokerContext>,
+              hedge: Option<Duration>)
+              -> Vec<(Method, &'static str, Box<Handler>)> {
+    vec![
+        (Method::Post, "/jobs",
+         Box::new(Rpc::<JobCreate, Job>::new(ctx, Scope::Admin, job_create)
+             .with_status(status::Created)
+             .with_features(BUILDER))),
+        (Method::Get, "/jobs/:id",
+         Box::new(Rpc::<JobGet, Job>::new(ctx, Scope::Read, job)
+             .with_features(BUILDER)
+             .with_hedge(hedge))),
+        (Method::Get, "/jobs/:id/log",
+         Box::new(Rpc::<JobLogGet, JobLog>::new(ctx, Scope::Read, job_log).with_hedge(hedge))),
+        (Method::Post, "/jobs/groups",
+         Box::new(Rpc::<JobGroupSpec, JobGroup>::new(
+             ctx, Scope::Admin, job_group_create).with_status(status::Created))),
+        (Method::Get, "/jobs/groups/:id",
+         Box::new(Rpc::<JobGroupGet, JobGroup>::new(ctx, Scope::Read, job_group)
+             .with_hedge(hedge))),
+        (Method::Get, "/user/invitations",
+         Box::new(Rpc::<AccountInvitationListRequest, AccountInvitationListResponse>::new(
+             ctx, Scope::Read, account_invitations).with_hedge(hedge))),
+        (Method::Get, "/user/origins",
+         Box::new(Rpc::<AccountOriginListRequest, AccountOriginListResponse>::new(
+             ctx, Scope::Read, account_origins).with_hedge(hedge))),
+        (Method::Get, "/user/tokens",
+         Box::new(Rpc::<ApiTokenListRequest, ApiTokenListResponse>::new(
+             ctx, Scope::Admin, api_tokens).with_hedge(hedge))),
+        (Method::Get, "/user/schedules",
+         Box::new(Rpc::<JobScheduleListRequest, JobScheduleListResponse>::new(
+             ctx, Scope::Read, job_schedules).with_hedge(hedge))),
+        (Method::Post, "/user/schedules",
+         Box::new(Rpc::<JobScheduleCreate, JobSchedule>::new(
+             ctx, Scope::Admin, job_schedule_create).with_status(status::Created))),
+    ]
+}
+
+/// Read a field of a JSON request body into a message.
+pub trait JsonField: Sized {
+    fn from_json(json: &Json) -> Option<Self>;
+}
+
+impl JsonField for String {
+    fn from_json(json: &Json) -> Option<Self> {
+        json.as_string().map(|s| s.to_string())
+    }
+}
+
+impl JsonField for bool {
+    fn from_json(json: &Json) -> Option<Self> {
+        json.as_boolean()
+    }
+}
+
+impl JsonField for u64 {
+    fn from_json(json: &Json) -> Option<Self> {
+        json.as_u64()
+    }
+}
+
+impl JsonField for u32 {
+    fn from_json(json: &Json) -> Option<Self> {
+        json.as_u64().and_then(|n| if n <= u32::max_value() as u64 {
+            Some(n as u32)
+        } else {
+            None
+        })
+    }
+}
+
+impl<T: JsonField> JsonField for Vec<T> {
+    fn from_json(json: &Json) -> Option<Self> {
+        json.as_array().and_then(|items| items.iter().map(T::from_json).collect())
+    }
+}
+
+/// The named field of a JSON body, which must be present and of the right type.
+pub fn required<T: JsonField>(body: &Json, name: &str) -> result::Result<T, Response> {
+    match try!(optional(body, name)) {
+        Some(value) => Ok(value),
+        None => Err(Response::with(status::UnprocessableEntity)),
+    }
+}
+
+/// The named field of a JSON body if it's present, which must be of the right type if it is.
+pub fn optional<T: JsonField>(body: &Json, name: &str) -> result::Result<Option<T>, Response> {
+    match body.find(name) {
+        Some(value) => {
+            match T::from_json(value) {
+                Some(value) => Ok(Some(value)),
+                None => Err(Response::with(status::UnprocessableEntity)),
+            }
+        }
+        None => Ok(None),
+    }
+}
+
+// The JSON body of a request.
+fn json_body(req: &mut Request) -> result::Result<Json, Response> {
+    match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => Ok(body),
+        _ => Err(Response::with(status::BadRequest)),
+    }
+}
+
+/// Add the endpoints of the route table to a router.
+pub fn register(router: &mut Router, ctx: &Arc<BrokerContext>, hedge: Option<Duration>) {
+    for (method, path, endpoint) in routes(ctx, hedge) {
+        router.route(method, path, endpoint);
+    }
+}
+
+fn account_invitations(_req: &mut Request,
+                       session: &Session)
+                       -> result::Result<AccountInvitationListRequest, Response> {
+    let mut msg = AccountInvitationListRequest::new();
+    msg.set_account_id(session.get_id());
+    Ok(msg)
+}
+
+fn account_origins(_req: &mut Request,
+                   session: &Session)
+                   -> result::Result<AccountOriginListRequest, Response> {
+    let mut msg = AccountOriginListRequest::new();
+    msg.set_account_id(session.get_id());
+    Ok(msg)
+}
+
+fn api_tokens(_req: &mut Request,
+              session: &Session)
+              -> result::Result<ApiTokenListRequest, Response> {
+    let mut msg = ApiTokenListRequest::new();
+    msg.set_owner_id(session.get_id());
+    Ok(msg)
+}
+
+fn job_schedules(_req: &mut Request,
+                 session: &Session)
+                 -> result::Result<JobScheduleListRequest, Response> {
+    let mut msg = JobScheduleListRequest::new();
+    msg.set_owner_id(session.get_id());
+    Ok(msg)
+}
+
+/// Schedule recurring builds of the project named in the body, replacing any schedule the
+/// account already has for it. The body gives a `cron` expression and optionally a `priority`
+/// and the capability tags the jobs `requires`.
+fn job_schedule_create(req: &mut Request,
+                       session: &Session)
+                       -> result::Result<JobScheduleCreate, Response> {
+    schedule_from_json(&try!(json_body(req)), session)
+}
+
+fn schedule_from_json(body: &Json,
+                      session: &Session)
+                      -> result::Result<JobScheduleCreate, Response> {
+    let mut msg = JobScheduleCreate::new();
+    msg.set_owner_id(session.get_id());
+    msg.set_project(try!(required(body, "project")));
+    msg.set_cron(try!(required(body, "cron")));
+    if let Some(priority) = try!(optional(body, "priority")) {
+        msg.set_priority(priority);
+    }
+    if let Some(requires) = try!(optional(body, "requires")) {
+        msg.set_requires(RepeatedField::from_vec(requires));
+    }
+    Ok(msg)
+}
+
+/// Rebuild the project named in the body and everything depending on it. With
+/// `dependents_only` set the project itself isn't rebuilt.
+fn job_group_create(req: &mut Request,
+                    session: &Session)
+                    -> result::Result<JobGroupSpec, Response> {
+    group_spec_from_json(&try!(json_body(req)), session)
+}
+
+fn group_spec_from_json(body: &Json, session: &Session) -> result::Result<JobGroupSpec, Response> {
+    let mut msg = JobGroupSpec::new();
+    msg.set_owner_id(session.get_id());
+    msg.set_project(try!(required(body, "project")));
+    if let Some(dependents_only) = try!(optional(body, "dependents_only")) {
+        msg.set_dependents_only(dependents_only);
+    }
+    Ok(msg)
+}
+
+fn job_create(_req: &mut Request, session: &Session) -> result::Result<JobCreate, Response> {
+    let mut msg = JobCreate::new();
+    msg.set_owner_id(session.get_id());
+    Ok(msg)
+}
+
+fn job(req: &mut Request, _session: &Session) -> result::Result<JobGet, Response> {
+    let mut msg = JobGet::new();
+    msg.set_id(try!(param(req, "id")));
+    Ok(msg)
+}
+
+fn job_group(req: &mut Request, _session: &Session) -> result::Result<JobGroupGet, Response> {
+    let mut msg = JobGroupGet::new();
+    msg.set_id(try!(param(req, "id")));
+    Ok(msg)
+}
+
+/// Tail a job's log from the chunk given by the `start` query parameter. Clients poll again from
+/// the returned `stop` until `is_complete` is set, passing the returned `attempt` as the
+/// `attempt` query parameter so a retried job doesn't switch logs under them.
+fn job_log(req: &mut Request, _session: &Session) -> result::Result<JobLogGet, Response> {
+    let start = match extract_query_value("start", req) {
+        Some(start) => try!(start.parse().map_err(|_| Response::with(status::BadRequest))),
+        None => 0,
+    };
+    let attempt = match extract_query_value("attempt", req) {
+        Some(attempt) => {
+            Some(try!(attempt.parse().map_err(|_| Response::with(status::BadRequest))))
+        }
+        None => None,
+    };
+    let mut msg = JobLogGet::new();
+    msg.set_job_id(try!(param(req, "id")));
+    msg.set_start(start);
+    if let Some(attempt) = attempt {
+        msg.set_attempt(attempt);
+    }
+    Ok(msg)
+}
+
+// Parse a parameter of the request's path.
+fn param<T: FromStr>(req: &Request, name: &str) -> result::Result<T, Response> {
+    match req.extensions.get::<Router>().unwrap().find(name).map(|v| v.parse()) {
+        Some(Ok(value)) => Ok(value),
+        _ => Err(Response::with(status::BadRequest)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iron::status;
+    use protocol::sessionsrv::Session;
+    use rustc_serialize::json::Json;
+
+    use super::{group_spec_from_json, optional, required, schedule_from_json};
+
+    fn session() -> Session {
+        let mut session = Session::new();
+        session.set_id(7);
+        session
+    }
+
+    fn parse(json: &str) -> Json {
+        Json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn fields_are_read_by_type() {
+        let body = parse(r#"{"name": "core", "count": 3, "tags": ["a", "b"], "big": 8589934592}"#);
+        assert_eq!(required::<String>(&body, "name").unwrap(), "core");
+        assert_eq!(required::<u32>(&body, "count").unwrap(), 3);
+        assert_eq!(required::<Vec<String>>(&body, "tags").unwrap(),
+                   vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(optional::<bool>(&body, "missing").unwrap(), None);
+        assert_eq!(required::<u64>(&body, "big").unwrap(), 8589934592);
+    }
+
+    #[test]
+    fn bad_fields_are_unprocessable() {
+        let body = parse(r#"{"name": 3, "big": 8589934592, "tags": ["a", 1]}"#);
+        for err in vec![required::<String>(&body, "name").unwrap_err(),
+                        required::<String>(&body, "missing").unwrap_err(),
+                        optional::<u32>(&body, "big").unwrap_err(),
+                        optional::<Vec<String>>(&body, "tags").unwrap_err()] {
+            assert_eq!(err.status, Some(status::UnprocessableEntity));
+        }
+    }
+
+    #[test]
+    fn schedule_is_translated_from_json() {
+        let body = parse(r#"{"project": "core/redis", "cron": "0 * * * *", "priority": 2,
+                            "requires": ["linux"]}"#);
+        let msg = schedule_from_json(&body, &session()).unwrap();
+        assert_eq!(msg.get_owner_id(), 7);
+        assert_eq!(msg.get_project(), "core/redis");
+        assert_eq!(msg.get_cron(), "0 * * * *");
+        assert_eq!(msg.get_priority(), 2);
+        assert_eq!(msg.get_requires(), &["linux".to_string()]);
+
+        let missing_cron = parse(r#"{"project": "core/redis"}"#);
+        assert!(schedule_from_json(&missing_cron, &session()).is_err());
+    }
+
+    #[test]
+    fn group_spec_is_translated_from_json() {
+        let body = parse(r#"{"project": "core/redis", "dependents_only": true}"#);
+        let msg = group_spec_from_json(&body, &session()).unwrap();
+        assert_eq!(msg.get_owner_id(), 7);
+        assert_eq!(msg.get_project(), "core/redis");
+        assert!(msg.get_dependents_only());
+        assert!(group_spec_from_json(&parse(r#"{"project": 1}"#), &session()).is_err());
+    }
+}
@@ -17,19 +17,24 @@
 use std::result;
 use std::sync::Arc;
 
+use bodyparser;
 use hab_net;
-use hab_net::routing::{Broker, BrokerContext};
+use hab_net::clients::{SessionClient, VaultClient};
+use hab_net::routing::{Broker, BrokerContext, Join, RouteClient, RECV_TIMEOUT_MS};
 use hab_net::oauth::github::GitHubClient;
+use hab_net::readiness;
 use iron::prelude::*;
 use iron::status;
 use iron::headers::{Authorization, Bearer};
 use protobuf;
-use protocol::jobsrv::{Job, JobCreate, JobGet};
-use protocol::sessionsrv::{OAuthProvider, Session, SessionCreate, SessionGet};
+use protocol::sessionsrv::{ApiToken, ApiTokenCreate, ApiTokenRevoke, FeatureFlags, OAuthProvider,
+                           Scope, Session, SessionCreate, SessionGet};
 use protocol::vault::*;
 use protocol::net::{self, NetError, ErrCode};
+use protobuf::RepeatedField;
 use router::Router;
 use rustc_serialize::json::{self, ToJson};
+use urlencoded::UrlEncodedQuery;
 
 pub fn authenticate(req: &mut Request,
                     ctx: &Arc<BrokerContext>)
@@ -37,34 +42,80 @@ pub fn authenticate(req: &mut Request,
     match req.headers.get::<Authorization<Bearer>>() {
         Some(&Authorization(Bearer { ref token })) => {
             let mut conn = Broker::connect(&ctx).unwrap();
-            let mut request = SessionGet::new();
-            request.set_token(token.to_string());
-            conn.route(&request).unwrap();
-            match conn.recv() {
-                Ok(rep) => {
-                    match rep.get_message_id() {
-                        "Session" => {
-                            let session = protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                            Ok(session)
-                        }
-                        "NetError" => {
-                            let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                            Err(render_net_error(&err))
-                        }
-
-                        _ => unreachable!("unexpected msg: {:?}", rep),
-                    }
-                }
-                Err(e) => {
-                    error!("session get, err={:?}", e);
-                    Err(Response::with(status::InternalServerError))
-                }
-            }
+            session_get(&mut conn, token)
         }
         _ => Err(Response::with(status::Unauthorized)),
     }
 }
 
+/// Authenticate the request and ensure its session was granted the given scope.
+pub fn authorize(req: &mut Request,
+                 ctx: &Arc<BrokerContext>,
+                 scope: &Scope)
+                 -> result::Result<Session, Response> {
+    let session = try!(authenticate(req, ctx));
+    if session.permits(scope) {
+        Ok(session)
+    } else {
+        debug!("session {} lacks scope {}", session.get_id(), scope);
+        Err(Response::with(status::Forbidden))
+    }
+}
+
+/// Authenticate the request and ensure its session was granted the given scope and its account
+/// is enrolled in the given features. Requests from accounts which aren't enrolled are answered
+/// as though the route didn't exist.
+pub fn authorize_features(req: &mut Request,
+                          ctx: &Arc<BrokerContext>,
+                          scope: &Scope,
+                          features: FeatureFlags)
+                          -> result::Result<Session, Response> {
+    let session = try!(authorize(req, ctx, scope));
+    if session.has_features(features) {
+        Ok(session)
+    } else {
+        debug!("session {} lacks features {:?}", session.get_id(), features);
+        Err(Response::with(status::NotFound))
+    }
+}
+
+/// Authenticate the request and retrieve the origin it is for, looking both up at the same time,
+/// then ensure the session was granted the given scope.
+fn authorize_origin(req: &mut Request,
+                    ctx: &Arc<BrokerContext>,
+                    scope: &Scope,
+                    origin_name: &str)
+                    -> result::Result<(Session, Origin), Response> {
+    let mut session_req = SessionGet::new();
+    match req.headers.get::<Authorization<Bearer>>() {
+        Some(&Authorization(Bearer { ref token })) => session_req.set_token(token.to_string()),
+        _ => return Err(Response::with(status::Unauthorized)),
+    }
+    let mut origin_req = OriginGet::new();
+    origin_req.set_name(origin_name.to_string());
+    let mut join = Join::new(ctx);
+    let session_at = try!(join.route(&session_req).map_err(render_client_error));
+    let origin_at = try!(join.route(&origin_req).map_err(render_client_error));
+    let mut replies = join.wait(RECV_TIMEOUT_MS as i64);
+    let session: Session = try!(replies.parse(session_at).map_err(render_client_error));
+    if !session.permits(scope) {
+        debug!("session {} lacks scope {}", session.get_id(), scope);
+        return Err(Response::with(status::Forbidden));
+    }
+    let origin = try!(replies.parse(origin_at).map_err(render_client_error));
+    Ok((session, origin))
+}
+
+/// Retrieve the session for the given token over any `RouteClient`. Failing to reach the session
+/// server, or an unexpected reply from it, is rendered the same way as for any other client call.
+pub fn session_get<C: RouteClient>(conn: &mut C,
+                                   token: &str)
+                                   -> result::Result<Session, Response> {
+    let mut request = SessionGet::new();
+    request.set_token(token.to_string());
+    SessionClient::get(conn, &request).map_err(render_client_error)
+}
+
 pub fn session_create(req: &mut Request,
                       github: &GitHubClient,
                       ctx: &Arc<BrokerContext>)
@@ -141,78 +192,133 @@ pub fn session_create(req: &mut Request,
     }
 }
 
-pub fn job_create(req: &mut Request, ctx: &Arc<BrokerContext>) -> IronResult<Response> {
-    let session = match authenticate(req, ctx) {
-        Ok(session) => session,
+pub fn list_origin_secrets(req: &mut Request, ctx: &Arc<BrokerContext>) -> IronResult<Response> {
+    let origin_name = match req.extensions.get::<Router>().unwrap().find("origin") {
+        Some(origin) => origin.to_string(),
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let (session, origin) = match authorize_origin(req, ctx, &Scope::Read, &origin_name) {
+        Ok(found) => found,
         Err(response) => return Ok(response),
     };
     let mut conn = Broker::connect(&ctx).unwrap();
-    let mut request = JobCreate::new();
-    request.set_owner_id(session.get_id());
-    conn.route(&request).unwrap();
-    match conn.recv() {
-        Ok(rep) => {
-            match rep.get_message_id() {
-                "Job" => {
-                    let job: Job = protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                    let encoded = json::encode(&job.to_json()).unwrap();
-                    Ok(Response::with((status::Created, encoded)))
-                }
-                "NetError" => {
-                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                    Ok(render_net_error(&err))
-                }
-                _ => unreachable!("unexpected msg: {:?}", rep),
-            }
-        }
-        Err(e) => {
-            error!("{:?}", e);
-            Ok(Response::with(status::ServiceUnavailable))
+    conn.set_session_token(Some(session.get_token().to_string()));
+    let mut request = OriginSecretListRequest::new();
+    request.set_origin_id(origin.get_id());
+    request.set_origin_name(origin_name);
+    request.set_requester_id(session.get_id());
+    match VaultClient::list_secrets(&mut conn, &request) {
+        Ok(secrets) => {
+            let encoded = json::encode(&secrets.to_json()).unwrap();
+            Ok(Response::with((status::Ok, encoded)))
         }
+        Err(e) => Ok(render_client_error(e)),
     }
 }
 
-pub fn job_show(req: &mut Request, ctx: &Arc<BrokerContext>) -> IronResult<Response> {
-    let params = req.extensions.get::<Router>().unwrap();
-    let id = match params.find("id") {
-        Some(id) => {
-            match id.parse() {
-                Ok(id) => id,
-                Err(_) => return Ok(Response::with(status::BadRequest)),
+/// Store a secret for an origin from a JSON body holding its `value`. The secret is encrypted
+/// at rest by the vault, and replaces any secret of the same name.
+pub fn origin_secret_put(req: &mut Request, ctx: &Arc<BrokerContext>) -> IronResult<Response> {
+    let (origin_name, name) = match secret_params(req) {
+        Some(params) => params,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let scope = Scope::Upload(origin_name.clone());
+    let (session, origin) = match authorize_origin(req, ctx, &scope, &origin_name) {
+        Ok(found) => found,
+        Err(response) => return Ok(response),
+    };
+    let value = match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            match body.find("value").and_then(|v| v.as_string()) {
+                Some(value) => value.to_string(),
+                None => return Ok(Response::with(status::UnprocessableEntity)),
             }
         }
         _ => return Ok(Response::with(status::BadRequest)),
     };
     let mut conn = Broker::connect(&ctx).unwrap();
-    let mut request = JobGet::new();
-    request.set_id(id);
-    conn.route(&request).unwrap();
-    match conn.recv() {
-        Ok(rep) => {
-            match rep.get_message_id() {
-                "Job" => {
-                    let job: Job = protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                    let encoded = json::encode(&job.to_json()).unwrap();
-                    Ok(Response::with((status::Ok, encoded)))
-                }
-                "NetError" => {
-                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                    Ok(render_net_error(&err))
-                }
-                _ => unreachable!("unexpected msg: {:?}", rep),
-            }
+    conn.set_session_token(Some(session.get_token().to_string()));
+    let mut request = OriginSecretCreate::new();
+    request.set_origin_id(origin.get_id());
+    request.set_origin_name(origin_name);
+    request.set_name(name);
+    request.set_value(value.into_bytes());
+    request.set_requester_id(session.get_id());
+    match VaultClient::create_secret(&mut conn, &request) {
+        Ok(secrets) => {
+            let encoded = json::encode(&secrets.to_json()).unwrap();
+            Ok(Response::with((status::Ok, encoded)))
         }
-        Err(e) => {
-            error!("{:?}", e);
-            Ok(Response::with(status::ServiceUnavailable))
+        Err(e) => Ok(render_client_error(e)),
+    }
+}
+
+pub fn origin_secret_show(req: &mut Request, ctx: &Arc<BrokerContext>) -> IronResult<Response> {
+    let (origin_name, name) = match secret_params(req) {
+        Some(params) => params,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let scope = Scope::Upload(origin_name.clone());
+    let (session, origin) = match authorize_origin(req, ctx, &scope, &origin_name) {
+        Ok(found) => found,
+        Err(response) => return Ok(response),
+    };
+    let mut conn = Broker::connect(&ctx).unwrap();
+    conn.set_session_token(Some(session.get_token().to_string()));
+    let mut request = OriginSecretGet::new();
+    request.set_origin_id(origin.get_id());
+    request.set_origin_name(origin_name);
+    request.set_name(name);
+    request.set_requester_id(session.get_id());
+    match VaultClient::get_secret(&mut conn, &request) {
+        Ok(secret) => {
+            let encoded = json::encode(&secret.to_json()).unwrap();
+            Ok(Response::with((status::Ok, encoded)))
         }
+        Err(e) => Ok(render_client_error(e)),
     }
 }
 
+pub fn origin_secret_delete(req: &mut Request, ctx: &Arc<BrokerContext>) -> IronResult<Response> {
+    let (origin_name, name) = match secret_params(req) {
+        Some(params) => params,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let scope = Scope::Upload(origin_name.clone());
+    let (session, origin) = match authorize_origin(req, ctx, &scope, &origin_name) {
+        Ok(found) => found,
+        Err(response) => return Ok(response),
+    };
+    let mut conn = Broker::connect(&ctx).unwrap();
+    conn.set_session_token(Some(session.get_token().to_string()));
+    let mut request = OriginSecretDelete::new();
+    request.set_origin_id(origin.get_id());
+    request.set_origin_name(origin_name);
+    request.set_name(name);
+    request.set_requester_id(session.get_id());
+    match VaultClient::delete_secret(&mut conn, &request) {
+        Ok(_) => Ok(Response::with(status::NoContent)),
+        Err(e) => Ok(render_client_error(e)),
+    }
+}
+
+/// Retrieve an origin by name over any `RouteClient`.
+pub fn origin_get<C: RouteClient>(conn: &mut C, name: &str) -> result::Result<Origin, Response> {
+    let mut request = OriginGet::new();
+    request.set_name(name.to_string());
+    VaultClient::get_origin(conn, &request).map_err(render_client_error)
+}
+
 /// Endpoint for determining availability of builder-api components.
 ///
-/// Returns a status 200 on success. Any non-200 responses are an outage or a partial outage.
+/// Returns a status 200 on success. Any non-200 responses are an outage or a partial outage, and
+/// a 503 is returned until every component, such as the router broker, has reported ready.
 pub fn status(_req: &mut Request) -> IronResult<Response> {
+    if !readiness::is_ready() {
+        return Ok(Response::with((status::ServiceUnavailable,
+                                  format!("waiting for {}", readiness::pending().join(", ")))));
+    }
     Ok(Response::with(status::Ok))
 }
 
@@ -229,39 +335,63 @@ pub fn status(_req: &mut Request) -> IronResult<Response> {
 /// * The NetError could not be encoded to JSON
 fn render_net_error(err: &NetError) -> Response {
     let encoded = json::encode(&err.to_json()).unwrap();
-    let status = match err.get_code() {
+    Response::with((net_error_status(err.get_code()), encoded))
+}
+
+// The HTTP status a `NetError` with the given code is answered with.
+fn net_error_status(code: ErrCode) -> status::Status {
+    match code {
         ErrCode::ENTITY_NOT_FOUND => status::NotFound,
+        ErrCode::ACCESS_DENIED => status::Forbidden,
         ErrCode::ENTITY_CONFLICT => status::Conflict,
         ErrCode::NO_SHARD => status::ServiceUnavailable,
+        ErrCode::BUSY => status::ServiceUnavailable,
+        ErrCode::THROTTLED => status::TooManyRequests,
         ErrCode::TIMEOUT => status::RequestTimeout,
         ErrCode::BAD_REMOTE_REPLY => status::BadGateway,
         ErrCode::SESSION_EXPIRED => status::Unauthorized,
         _ => status::InternalServerError,
-    };
-    Response::with((status, encoded))
+    }
 }
 
-pub fn list_account_invitations(req: &mut Request,
-                                ctx: &Arc<BrokerContext>)
-                                -> IronResult<Response> {
-    debug!("list_account_invitations");
-    let session = match authenticate(req, ctx) {
+pub fn accept_invitation(req: &mut Request, ctx: &Arc<BrokerContext>) -> IronResult<Response> {
+    debug!("accept_invitation");
+    let session = match authorize(req, ctx, &Scope::Admin) {
         Ok(session) => session,
         Err(response) => return Ok(response),
     };
+    let params = &req.extensions.get::<Router>().unwrap();
+
+    let invitation_id = match params.find("invitation_id") {
+        Some(ref invitation_id) => {
+            match invitation_id.parse::<u64>() {
+                Ok(v) => v,
+                Err(_) => return Ok(Response::with(status::BadRequest)),
+            }
+        }
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    // TODO: read the body to determine "ignore"
+    let ignore_val = false;
 
     let mut conn = Broker::connect(&ctx).unwrap();
-    let mut request = AccountInvitationListRequest::new();
-    request.set_account_id(session.get_id());
+    let mut request = OriginInvitationAcceptRequest::new();
+
+    // make sure we're not trying to accept someone else's request
+    request.set_account_accepting_request(session.get_id());
+    request.set_invite_id(invitation_id);
+    request.set_ignore(ignore_val);
+
     conn.route(&request).unwrap();
     match conn.recv() {
         Ok(rep) => {
             match rep.get_message_id() {
-                "AccountInvitationListResponse" => {
-                    let invites: AccountInvitationListResponse =
+                "OriginInvitationAcceptResponse" => {
+                    let _invites: OriginInvitationAcceptResponse =
                         protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                    let encoded = json::encode(&invites.to_json()).unwrap();
-                    Ok(Response::with((status::Ok, encoded)))
+                    // empty response
+                    Ok(Response::with(status::Ok))
                 }
                 "NetError" => {
                     let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
@@ -277,26 +407,64 @@ pub fn list_account_invitations(req: &mut Request,
     }
 }
 
-pub fn list_user_origins(req: &mut Request, ctx: &Arc<BrokerContext>) -> IronResult<Response> {
-    debug!("list_user_origins");
-    let session = match authenticate(req, ctx) {
+/// Authorize the request for managing the account's personal access tokens. Only sessions from
+/// an interactive sign in may, so that a leaked token can't be used to issue itself successors.
+fn authorize_token_admin(req: &mut Request,
+                         ctx: &Arc<BrokerContext>)
+                         -> result::Result<Session, Response> {
+    let session = try!(authorize(req, ctx, &Scope::Admin));
+    if session.has_api_token_id() {
+        debug!("session {} authenticated with api token {}",
+               session.get_id(),
+               session.get_api_token_id());
+        return Err(Response::with(status::Forbidden));
+    }
+    Ok(session)
+}
+
+pub fn api_token_create(req: &mut Request, ctx: &Arc<BrokerContext>) -> IronResult<Response> {
+    let session = match authorize_token_admin(req, ctx) {
         Ok(session) => session,
         Err(response) => return Ok(response),
     };
-
+    let mut request = ApiTokenCreate::new();
+    request.set_owner_id(session.get_id());
+    match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            let scopes = match body.find("scopes").and_then(|s| s.as_array()) {
+                Some(scopes) => scopes,
+                None => return Ok(Response::with(status::BadRequest)),
+            };
+            let mut parsed = vec![];
+            for scope in scopes {
+                match scope.as_string().map(|s| s.parse::<Scope>()) {
+                    Some(Ok(scope)) => parsed.push(scope.to_string()),
+                    _ => return Ok(Response::with(status::UnprocessableEntity)),
+                }
+            }
+            if parsed.is_empty() {
+                return Ok(Response::with(status::UnprocessableEntity));
+            }
+            request.set_scopes(RepeatedField::from_vec(parsed));
+            match body.find("name").and_then(|n| n.as_string()) {
+                Some(name) if !name.is_empty() => request.set_name(name.to_string()),
+                _ => return Ok(Response::with(status::UnprocessableEntity)),
+            }
+            if let Some(description) = body.find("description").and_then(|d| d.as_string()) {
+                request.set_description(description.to_string());
+            }
+        }
+        _ => return Ok(Response::with(status::BadRequest)),
+    }
     let mut conn = Broker::connect(&ctx).unwrap();
-
-    let mut request = AccountOriginListRequest::new();
-    request.set_account_id(session.get_id());
     conn.route(&request).unwrap();
     match conn.recv() {
         Ok(rep) => {
             match rep.get_message_id() {
-                "AccountOriginListResponse" => {
-                    let invites: AccountOriginListResponse =
-                        protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                    let encoded = json::encode(&invites.to_json()).unwrap();
-                    Ok(Response::with((status::Ok, encoded)))
+                "ApiToken" => {
+                    let token: ApiToken = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    let encoded = json::encode(&token.to_json()).unwrap();
+                    Ok(Response::with((status::Created, encoded)))
                 }
                 "NetError" => {
                     let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
@@ -312,45 +480,29 @@ pub fn list_user_origins(req: &mut Request, ctx: &Arc<BrokerContext>) -> IronRes
     }
 }
 
-pub fn accept_invitation(req: &mut Request, ctx: &Arc<BrokerContext>) -> IronResult<Response> {
-    debug!("accept_invitation");
-    let session = match authenticate(req, ctx) {
+pub fn revoke_api_token(req: &mut Request, ctx: &Arc<BrokerContext>) -> IronResult<Response> {
+    let session = match authorize_token_admin(req, ctx) {
         Ok(session) => session,
         Err(response) => return Ok(response),
     };
-    let params = &req.extensions.get::<Router>().unwrap();
-
-    let invitation_id = match params.find("invitation_id") {
-        Some(ref invitation_id) => {
-            match invitation_id.parse::<u64>() {
-                Ok(v) => v,
+    let id = match req.extensions.get::<Router>().unwrap().find("id") {
+        Some(id) => {
+            match id.parse::<u64>() {
+                Ok(id) => id,
                 Err(_) => return Ok(Response::with(status::BadRequest)),
             }
         }
         None => return Ok(Response::with(status::BadRequest)),
     };
-
-    // TODO: read the body to determine "ignore"
-    let ignore_val = false;
-
     let mut conn = Broker::connect(&ctx).unwrap();
-    let mut request = OriginInvitationAcceptRequest::new();
-
-    // make sure we're not trying to accept someone else's request
-    request.set_account_accepting_request(session.get_id());
-    request.set_invite_id(invitation_id);
-    request.set_ignore(ignore_val);
-
+    let mut request = ApiTokenRevoke::new();
+    request.set_owner_id(session.get_id());
+    request.set_id(id);
     conn.route(&request).unwrap();
     match conn.recv() {
         Ok(rep) => {
             match rep.get_message_id() {
-                "OriginInvitationAcceptResponse" => {
-                    let _invites: OriginInvitationAcceptResponse =
-                        protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                    // empty response
-                    Ok(Response::with(status::Ok))
-                }
+                "ApiToken" => Ok(Response::with(status::NoContent)),
                 "NetError" => {
                     let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
                     Ok(render_net_error(&err))
@@ -364,3 +516,106 @@ pub fn accept_invitation(req: &mut Request, ctx: &Arc<BrokerContext>) -> IronRes
         }
     }
 }
+
+/// Return a Response for an error returned by one of the service clients.
+pub fn render_client_error(err: hab_net::Error) -> Response {
+    match err {
+        hab_net::Error::Remote(ref err) => render_net_error(err),
+        hab_net::Error::Timeout => Response::with(status::GatewayTimeout),
+        err => {
+            error!("{:?}", err);
+            Response::with(status::ServiceUnavailable)
+        }
+    }
+}
+
+// The origin and name of the secret a request is for.
+fn secret_params(req: &mut Request) -> Option<(String, String)> {
+    let params = req.extensions.get::<Router>().unwrap();
+    match (params.find("origin"), params.find("name")) {
+        (Some(origin), Some(name)) => Some((origin.to_string(), name.to_string())),
+        _ => None,
+    }
+}
+
+pub fn extract_query_value(key: &str, req: &mut Request) -> Option<String> {
+    match req.get_ref::<UrlEncodedQuery>() {
+        Ok(map) => {
+            for (k, v) in map.iter() {
+                if key == *k {
+                    if v.len() < 1 {
+                        return None;
+                    }
+                    return Some(v[0].clone());
+                }
+            }
+            None
+        }
+        Err(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use hab_net;
+    use hab_net::testing::MockRouteClient;
+    use iron::status;
+    use protocol::net::{self, ErrCode};
+    use protocol::sessionsrv::Session;
+
+    use super::session_get;
+
+    #[test]
+    fn session_get_returns_the_session() {
+        let mut conn = MockRouteClient::new();
+        let mut session = Session::new();
+        session.set_id(1);
+        session.set_token("token".to_string());
+        conn.respond(&session);
+        assert_eq!(session_get(&mut conn, "token").unwrap().get_id(), 1);
+        assert_eq!(conn.routed()[0].get_message_id(), "SessionGet");
+    }
+
+    #[test]
+    fn session_get_renders_net_errors() {
+        let mut conn = MockRouteClient::new();
+        conn.respond(&net::err(ErrCode::SESSION_EXPIRED, "ss:session-get:1"));
+        let response = session_get(&mut conn, "token").unwrap_err();
+        assert_eq!(response.status, Some(status::Unauthorized));
+    }
+
+    #[test]
+    fn session_get_renders_unexpected_replies() {
+        let mut conn = MockRouteClient::new();
+        conn.respond(&net::Ping::new());
+        let response = session_get(&mut conn, "token").unwrap_err();
+        assert_eq!(response.status, Some(status::ServiceUnavailable));
+    }
+
+    #[test]
+    fn session_get_renders_timeouts() {
+        let mut conn = MockRouteClient::new();
+        conn.fail(hab_net::Error::Timeout);
+        let response = session_get(&mut conn, "token").unwrap_err();
+        assert_eq!(response.status, Some(status::GatewayTimeout));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iron::status;
+    use protocol::net::{self, ErrCode};
+
+    use super::{net_error_status, render_net_error};
+
+    #[test]
+    fn net_errors_map_to_statuses() {
+        assert_eq!(net_error_status(ErrCode::ENTITY_NOT_FOUND), status::NotFound);
+        assert_eq!(net_error_status(ErrCode::ACCESS_DENIED), status::Forbidden);
+        assert_eq!(net_error_status(ErrCode::SESSION_EXPIRED), status::Unauthorized);
+        assert_eq!(net_error_status(ErrCode::BUSY), status::ServiceUnavailable);
+        assert_eq!(net_error_status(ErrCode::INTERNAL), status::InternalServerError);
+        let response = render_net_error(&net::err(ErrCode::ENTITY_CONFLICT, "test:0"));
+        assert_eq!(response.status, Some(status::Conflict));
+    }
+}
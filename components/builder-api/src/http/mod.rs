@@ -14,14 +14,17 @@
 
 //! A module containing the HTTP server and handlers for servicing client requests
 
+pub mod gateway;
 pub mod handlers;
 
-use std::sync::{mpsc, Arc};
-use std::thread::{self, JoinHandle};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 
 use depot;
 use hab_net::oauth::github::GitHubClient;
 use hab_net::routing::BrokerContext;
+use hab_net::runtime::{self, Shutdown};
 use iron::prelude::*;
 use iron::AfterMiddleware;
 use iron::headers;
@@ -33,12 +36,9 @@ use unicase::UniCase;
 
 use config::Config;
 use error::Result;
+use events::{self, EventHub};
 use self::handlers::*;
 
-// Iron defaults to a threadpool of size `8 * num_cpus`.
-// See: http://172.16.2.131:9633/iron/prelude/struct.Iron.html#method.http
-const HTTP_THREAD_COUNT: usize = 128;
-
 /// Create a new `iron::Chain` containing a Router and it's required middleware
 pub fn router(config: Arc<Config>, context: Arc<BrokerContext>) -> Result<Chain> {
     let github = GitHubClient::new(&*config);
@@ -48,26 +48,34 @@ pub fn router(config: Arc<Config>, context: Arc<BrokerContext>) -> Result<Chain>
     let ctx4 = context.clone();
     let ctx5 = context.clone();
     let ctx6 = context.clone();
+    let ctx7 = context.clone();
+    let ctx8 = context.clone();
 
-    let router = router!(
+    let mut router = router!(
         get "/status" => move |r: &mut Request| status(r),
         get "/authenticate/:code" => move |r: &mut Request| session_create(r, &github, &ctx1),
 
-        post "/jobs" => move |r: &mut Request| job_create(r, &ctx2),
-        get "/jobs/:id" => move |r: &mut Request| job_show(r, &ctx3),
+        get "/origins/:origin/secrets" => move |r: &mut Request| list_origin_secrets(r, &ctx2),
+        get "/origins/:origin/secrets/:name" => {
+            move |r: &mut Request| origin_secret_show(r, &ctx3)
+        },
+        put "/origins/:origin/secrets/:name" => move |r: &mut Request| origin_secret_put(r, &ctx4),
+        delete "/origins/:origin/secrets/:name" => {
+            move |r: &mut Request| origin_secret_delete(r, &ctx5)
+        },
 
-        get "/user/invitations" => move |r: &mut Request| list_account_invitations(r, &ctx4),
-        put "/user/invitations/:invitation_id" => move |r: &mut Request| accept_invitation(r, &ctx5),
-        get "/user/origins" => move |r: &mut Request| list_user_origins(r, &ctx6),
+        put "/user/invitations/:invitation_id" => move |r: &mut Request| accept_invitation(r, &ctx6),
+        post "/user/tokens" => move |r: &mut Request| api_token_create(r, &ctx7),
+        delete "/user/tokens/:id" => move |r: &mut Request| revoke_api_token(r, &ctx8),
 
     );
+    gateway::register(&mut router, &context, config.hedge_delay());
     let mut chain = Chain::new(router);
     chain.link_after(Cors);
     Ok(chain)
 }
 
-/// Create a new HTTP listener and run it in a separate thread. This function will block the calling
-/// thread until the new listener has successfully started.
+/// Run the HTTP listener until shutdown is requested.
 ///
 /// # Errors
 ///
@@ -77,12 +85,16 @@ pub fn router(config: Arc<Config>, context: Arc<BrokerContext>) -> Result<Chain>
 /// # Panics
 ///
 /// * Listener crashed during startup
-pub fn run(config: Arc<Config>, context: Arc<BrokerContext>) -> Result<JoinHandle<()>> {
-    let (tx, rx) = mpsc::sync_channel(1);
-
+pub fn run(config: Arc<Config>,
+           context: Arc<BrokerContext>,
+           hub: Arc<EventHub>,
+           shutdown: Shutdown)
+           -> Result<()> {
     let addr = config.http_addr.clone();
     let ctx = context.clone();
     let depot = try!(depot::Depot::new(config.depot.clone(), ctx));
+    events::bridge_packages(hub, depot.notifier.listen());
+    try!(depot::promote::run(depot.clone()));
     let depot_chain = try!(depot::server::router(depot));
 
     let mut mount = Mount::new();
@@ -93,19 +105,16 @@ pub fn run(config: Arc<Config>, context: Arc<BrokerContext>) -> Result<JoinHandl
     let chain = try!(router(config, context));
     mount.mount("/v1", chain).mount("/v1/depot", depot_chain);
 
-    let handle = thread::Builder::new()
-        .name("http-srv".to_string())
-        .spawn(move || {
-            let _server = Iron::new(mount)
-                .listen_with(addr, HTTP_THREAD_COUNT, Protocol::Http, None)
-                .unwrap();
-            tx.send(()).unwrap();
-        })
+    let mut server = Iron::new(mount)
+        .listen_with(addr, depot::HTTP_THREAD_COUNT, Protocol::Http, None)
         .unwrap();
-    match rx.recv() {
-        Ok(()) => Ok(handle),
-        Err(e) => panic!("http-srv thread startup error, err={}", e),
+    while !shutdown.is_requested() {
+        thread::sleep(Duration::from_millis(runtime::SHUTDOWN_POLL_MS as u64));
+    }
+    if let Err(e) = server.close() {
+        warn!("http-srv did not close cleanly, err={}", e);
     }
+    Ok(())
 }
 
 struct Cors;
@@ -116,7 +125,7 @@ impl AfterMiddleware for Cors {
         res.headers
             .set(headers::AccessControlAllowHeaders(vec![UniCase("authorization".to_owned())]));
         res.headers
-            .set(headers::AccessControlAllowMethods(vec![Method::Put]));
+            .set(headers::AccessControlAllowMethods(vec![Method::Put, Method::Delete]));
         Ok(res)
     }
 }
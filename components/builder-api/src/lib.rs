@@ -16,6 +16,7 @@ extern crate bodyparser;
 extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hab_core;
 extern crate habitat_depot as depot;
+extern crate habitat_depot_client as depot_client;
 extern crate habitat_net as hab_net;
 extern crate hyper;
 extern crate iron;
@@ -27,6 +28,7 @@ extern crate redis;
 #[macro_use]
 extern crate router;
 extern crate rustc_serialize;
+extern crate sha1;
 extern crate staticfile;
 extern crate toml;
 extern crate unicase;
@@ -35,8 +37,10 @@ extern crate zmq;
 
 pub mod config;
 pub mod error;
+pub mod events;
 pub mod http;
 pub mod server;
+pub mod ws;
 
 pub use self::config::Config;
 pub use self::error::{Error, Result};
@@ -14,47 +14,86 @@
 
 //! Contains core functionality for the Application's main server.
 
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
-use hab_net::config::RouteAddrs;
-use hab_net::routing::{Broker, BrokerContext};
-use hab_net::server::NetIdent;
+use hab_net::config::{RouteAddrs, RouterAuth};
+use hab_net::hmac;
+use hab_net::runtime::Runtime;
+use hab_net::routing::{Broker, BrokerContext, BrokerOpts, KeepaliveConfig, ReconnectConfig};
+use hab_net::server::{NetIdent, ServerContext};
+
+use zmq;
 
 use config::Config;
 use error::Result;
+use events::{self, EventHub};
 use http;
+use ws;
 
 /// The main server for the Builder-API application. This should be run on the main thread.
 pub struct Server {
     pub config: Arc<Config>,
     ctx: Arc<BrokerContext>,
+    zmq: Arc<RwLock<zmq::Context>>,
 }
 
 impl Server {
     /// Create a new `Server`
-    pub fn new(config: Config) -> Self {
-        Server {
+    ///
+    /// # Errors
+    ///
+    /// * The ZeroMQ context could not be configured
+    pub fn new(config: Config) -> Result<Self> {
+        let ctx = try!(ServerContext::with_config(&config));
+        Ok(Server {
             config: Arc::new(config),
-            ctx: Arc::new(BrokerContext::new()),
-        }
+            ctx: ctx.broker(),
+            zmq: ctx.zmq(),
+        })
     }
 
     /// Runs the main server and starts and manages all supporting threads. This function will
-    /// block the calling thread.
+    /// block the calling thread until shutdown is requested by `SIGTERM` or `SIGINT`, or one of
+    /// the HTTP server, WebSocket server or job event bridge stops.
     ///
     /// # Errors
     ///
     /// * HTTP server could not start
+    /// * WebSocket server could not start
+    /// * Job event bridge could not start
     pub fn run(&mut self) -> Result<()> {
         let cfg1 = self.config.clone();
+        let cfg2 = self.config.clone();
+        let cfg3 = self.config.clone();
         let ctx1 = self.ctx.clone();
         let ctx2 = self.ctx.clone();
-        let broker = Broker::run(Self::net_ident(), ctx1, self.config.route_addrs());
-        let http = try!(http::run(cfg1, ctx2));
-
+        let ctx3 = self.ctx.clone();
+        let zmq = self.zmq.clone();
+        let hub = Arc::new(EventHub::new());
+        let (hub1, hub2, hub3) = (hub.clone(), hub.clone(), hub);
+        hmac::configure(&*self.config);
+        let opts = BrokerOpts {
+            auth: self.config.router_auth(),
+            reconnect: ReconnectConfig::from_config(&*self.config),
+            keepalive: KeepaliveConfig::from_config(&*self.config),
+            sched: self.config.broker_sched.clone(),
+            capture: self.config.broker_capture.clone(),
+            ..BrokerOpts::default()
+        };
+        // The broker has no way to be stopped, so it is left running when the runtime shuts down.
+        let _broker = Broker::run_with_opts(Self::net_ident(),
+                                            ctx1,
+                                            self.config.route_addrs(),
+                                            opts);
         println!("Builder API listening on {}", &self.config.http_addr);
-        http.join().unwrap();
-        broker.join().unwrap();
+        println!("Builder API events listening on {}", &self.config.ws_addr);
+        let rt = try!(Runtime::new()
+            .trap_signals()
+            .spawn("job-events",
+                   move |shutdown| events::bridge_jobs(hub1, zmq, &cfg1, shutdown)));
+        let rt = try!(rt.spawn("http-srv", move |shutdown| http::run(cfg2, ctx2, hub2, shutdown)));
+        let rt = try!(rt.spawn("ws-srv", move |shutdown| ws::run(cfg3, ctx3, hub3, shutdown)));
+        try!(rt.run());
         Ok(())
     }
 }
@@ -64,5 +103,5 @@ impl NetIdent for Server {}
 /// Helper function for creating a new Server and running it. This function will block the calling
 /// thread.
 pub fn run(config: Config) -> Result<()> {
-    Server::new(config).run()
+    try!(Server::new(config)).run()
 }
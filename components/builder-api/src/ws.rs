@@ -0,0 +1,559 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! WebSocket endpoint pushing job and package events to browsers.
+//!
+//! Clients open `ws://<ws_addr>/events?token=<token>` and may narrow what they receive with the
+//! `job=<id>` and `origin=<name>` query parameters. Browsers can't set an `Authorization` header
+//! on a WebSocket handshake so the session or API token travels in the query string, though a
+//! bearer header is honored as well. Sessions see the events of their own jobs, or of every job
+//! with the admin scope, and of packages in any origin.
+//!
+//! Each event is sent as a text frame holding a JSON document. Frames from the client are only
+//! read to answer pings and close requests.
+//!
+//! A client has `HANDSHAKE_TIMEOUT_MS` to send its handshake and be authenticated, and at most
+//! `MAX_PENDING_HANDSHAKES` connections may be waiting on either at once. Further connections are
+//! refused until one of them completes.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use hab_net::routing::{Broker, BrokerContext};
+use hab_net::runtime;
+use hyper::Url;
+use iron::status::Status;
+use protocol::sessionsrv::{Scope, Session};
+use rustc_serialize::base64::{STANDARD, ToBase64};
+use sha1::Sha1;
+
+use config::Config;
+use error::Result;
+use events::{EventHub, Filter};
+use http::handlers::session_get;
+
+/// Path of the event stream endpoint.
+pub const EVENTS_PATH: &'static str = "/events";
+/// Time a client has to complete its handshake, authentication included.
+pub const HANDSHAKE_TIMEOUT_MS: u64 = 10_000;
+/// Connections which may be waiting to complete their handshake at once.
+pub const MAX_PENDING_HANDSHAKES: usize = 64;
+
+// Appended to the client's key to compute the handshake's accept key. See RFC 6455, 1.3.
+const ACCEPT_GUID: &'static str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+// Largest handshake request read before the connection is refused.
+const MAX_HANDSHAKE_BYTES: usize = 8 * 1024;
+// Largest frame accepted from a client. Clients have no reason to send anything but control
+// frames, whose payloads are at most 125 bytes.
+const MAX_CLIENT_PAYLOAD: u64 = 4 * 1024;
+// Time a write to a client may block before the connection is dropped.
+const WRITE_TIMEOUT_MS: u64 = 30_000;
+
+const OP_TEXT: u8 = 0x1;
+const OP_CLOSE: u8 = 0x8;
+const OP_PING: u8 = 0x9;
+const OP_PONG: u8 = 0xA;
+
+/// Serve the WebSocket endpoint until shutdown is requested, each connection on a separate
+/// thread. New connections are accepted within `runtime::SHUTDOWN_POLL_MS` of arriving, and
+/// refused without a thread of their own while `MAX_PENDING_HANDSHAKES` others are pending.
+///
+/// # Errors
+///
+/// * The listen address could not be bound
+pub fn run(config: Arc<Config>,
+           ctx: Arc<BrokerContext>,
+           hub: Arc<EventHub>,
+           shutdown: runtime::Shutdown)
+           -> Result<()> {
+    let listener = try!(TcpListener::bind(config.ws_addr));
+    try!(listener.set_nonblocking(true));
+    let pending = Arc::new(AtomicUsize::new(0));
+    while !shutdown.is_requested() {
+        match listener.accept() {
+            Ok((mut stream, _)) => {
+                if let Err(e) = prepare(&stream) {
+                    warn!("websocket accept failed, err={}", e);
+                    continue;
+                }
+                let guard = match Pending::acquire(&pending) {
+                    Some(guard) => guard,
+                    None => {
+                        debug!("refusing websocket connection, too many pending handshakes");
+                        let _ = refuse(&mut stream, Status::ServiceUnavailable);
+                        continue;
+                    }
+                };
+                let ctx = ctx.clone();
+                let hub = hub.clone();
+                thread::Builder::new()
+                    .name("ws-conn".to_string())
+                    .spawn(move || if let Err(e) = serve(stream, &ctx, &hub, guard) {
+                        debug!("websocket connection closed, err={}", e);
+                    })
+                    .unwrap();
+            }
+            Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {
+                thread::sleep(Duration::from_millis(runtime::SHUTDOWN_POLL_MS as u64));
+            }
+            Err(e) => warn!("websocket accept failed, err={}", e),
+        }
+    }
+    Ok(())
+}
+
+fn prepare(stream: &TcpStream) -> io::Result<()> {
+    try!(stream.set_nonblocking(false));
+    stream.set_write_timeout(Some(Duration::from_millis(WRITE_TIMEOUT_MS)))
+}
+
+// Counts a connection as pending its handshake until dropped.
+struct Pending(Arc<AtomicUsize>);
+
+impl Pending {
+    // Count another pending connection, or return `None` if there are already
+    // `MAX_PENDING_HANDSHAKES` of them.
+    fn acquire(count: &Arc<AtomicUsize>) -> Option<Pending> {
+        if count.fetch_add(1, Ordering::SeqCst) >= MAX_PENDING_HANDSHAKES {
+            count.fetch_sub(1, Ordering::SeqCst);
+            return None;
+        }
+        Some(Pending(count.clone()))
+    }
+}
+
+impl Drop for Pending {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+// Reads from a connection, failing with `TimedOut` once the deadline, if any, has passed. A
+// read timeout alone would let a client keep the handshake open by trickling bytes.
+struct DeadlineReader {
+    stream: TcpStream,
+    deadline: Option<Instant>,
+}
+
+impl DeadlineReader {
+    fn new(stream: TcpStream, timeout: Duration) -> Self {
+        DeadlineReader {
+            stream: stream,
+            deadline: Some(Instant::now() + timeout),
+        }
+    }
+
+    // Let reads block for as long as it takes from now on.
+    fn clear_deadline(&mut self) -> io::Result<()> {
+        self.deadline = None;
+        self.stream.set_read_timeout(None)
+    }
+}
+
+impl Read for DeadlineReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if let Some(deadline) = self.deadline {
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(io::Error::new(io::ErrorKind::TimedOut, "handshake timed out"));
+            }
+            try!(self.stream.set_read_timeout(Some(deadline - now)));
+        }
+        self.stream.read(buf)
+    }
+}
+
+// The parts of a handshake request the endpoint cares about.
+struct Handshake {
+    path: String,
+    params: Vec<(String, String)>,
+    key: Option<String>,
+    upgrade: bool,
+    bearer: Option<String>,
+}
+
+impl Handshake {
+    // Read the request line and headers of a handshake. Returns `None` if the request is
+    // malformed, too large, or the client hung up part way through.
+    fn read<R: BufRead>(reader: &mut R) -> io::Result<Option<Handshake>> {
+        let mut lines = vec![];
+        let mut consumed = 0;
+        loop {
+            let mut line = String::new();
+            let limit = (MAX_HANDSHAKE_BYTES - consumed) as u64;
+            let n = try!(reader.by_ref().take(limit).read_line(&mut line));
+            if n == 0 || !line.ends_with('\n') {
+                return Ok(None);
+            }
+            consumed += n;
+            let line = line.trim_right().to_string();
+            if line.is_empty() {
+                break;
+            }
+            lines.push(line);
+        }
+        if lines.is_empty() {
+            return Ok(None);
+        }
+        let target = {
+            let parts: Vec<&str> = lines[0].split_whitespace().collect();
+            if parts.len() != 3 || parts[0] != "GET" {
+                return Ok(None);
+            }
+            parts[1].to_string()
+        };
+        let url = match Url::parse(&format!("ws://localhost{}", target)) {
+            Ok(url) => url,
+            Err(_) => return Ok(None),
+        };
+        let mut handshake = Handshake {
+            path: url.path().to_string(),
+            params: url.query_pairs().map(|(k, v)| (k.into_owned(), v.into_owned())).collect(),
+            key: None,
+            upgrade: false,
+            bearer: None,
+        };
+        for line in lines[1..].iter() {
+            let (name, value) = match line.find(':') {
+                Some(i) => (line[..i].trim().to_lowercase(), line[i + 1..].trim()),
+                None => return Ok(None),
+            };
+            match name.as_str() {
+                "upgrade" => handshake.upgrade = value.to_lowercase() == "websocket",
+                "sec-websocket-key" => handshake.key = Some(value.to_string()),
+                "authorization" if value.starts_with("Bearer ") => {
+                    handshake.bearer = Some(value["Bearer ".len()..].trim().to_string())
+                }
+                _ => (),
+            }
+        }
+        Ok(Some(handshake))
+    }
+
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params.iter().find(|&&(ref k, _)| k == name).map(|&(_, ref v)| v.as_str())
+    }
+
+    fn token(&self) -> Option<&str> {
+        self.param("token").or(self.bearer.as_ref().map(|t| t.as_str()))
+    }
+
+    // The events the session asked for and may see, or `None` if the parameters are invalid.
+    fn filter(&self, session: &Session) -> Option<Filter> {
+        let job_id = match self.param("job") {
+            Some(id) => {
+                match id.parse() {
+                    Ok(id) => Some(id),
+                    Err(_) => return None,
+                }
+            }
+            None => None,
+        };
+        let owner_id = if session.permits(&Scope::Admin) {
+            None
+        } else {
+            Some(session.get_id())
+        };
+        Some(Filter {
+            owner_id: owner_id,
+            job_id: job_id,
+            origin: self.param("origin").map(|o| o.to_string()),
+        })
+    }
+}
+
+fn serve(stream: TcpStream,
+         ctx: &Arc<BrokerContext>,
+         hub: &EventHub,
+         pending: Pending)
+         -> io::Result<()> {
+    let timeout = Duration::from_millis(HANDSHAKE_TIMEOUT_MS);
+    let mut reader = BufReader::new(DeadlineReader::new(try!(stream.try_clone()), timeout));
+    let mut writer = stream;
+    let request = match try!(Handshake::read(&mut reader)) {
+        Some(request) => request,
+        None => return refuse(&mut writer, Status::BadRequest),
+    };
+    if request.path != EVENTS_PATH {
+        return refuse(&mut writer, Status::NotFound);
+    }
+    let key = match request.key {
+        Some(ref key) if request.upgrade => key.clone(),
+        _ => return refuse(&mut writer, Status::BadRequest),
+    };
+    let session = match request.token() {
+        Some(token) => {
+            let mut conn = Broker::connect(ctx).unwrap();
+            match session_get(&mut conn, token) {
+                Ok(session) => session,
+                Err(response) => {
+                    let status = response.status.unwrap_or(Status::InternalServerError);
+                    return refuse(&mut writer, status);
+                }
+            }
+        }
+        None => return refuse(&mut writer, Status::Unauthorized),
+    };
+    if !session.permits(&Scope::Read) {
+        return refuse(&mut writer, Status::Forbidden);
+    }
+    let filter = match request.filter(&session) {
+        Some(filter) => filter,
+        None => return refuse(&mut writer, Status::BadRequest),
+    };
+    try!(reader.get_mut().clear_deadline());
+    drop(pending);
+    let events = hub.subscribe(filter);
+    try!(write!(writer,
+                "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: \
+                 Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+                accept_key(&key)));
+    debug!("websocket subscribed, session={}, subscribers={}",
+           session.get_id(),
+           hub.subscriber_count());
+    let writer = Arc::new(Mutex::new(writer));
+    let control = writer.clone();
+    thread::Builder::new()
+        .name("ws-read".to_string())
+        .spawn(move || {
+            if let Err(e) = read_frames(&mut reader, &control) {
+                debug!("websocket read failed, err={}", e);
+            }
+            // Shutting the socket down fails the next event write, which unsubscribes the
+            // connection.
+            let _ = control.lock().unwrap().shutdown(Shutdown::Both);
+        })
+        .unwrap();
+    for event in events.iter() {
+        try!(write_frame(&mut *writer.lock().unwrap(), OP_TEXT, event.as_bytes()));
+    }
+    Ok(())
+}
+
+fn refuse<W: Write>(writer: &mut W, status: Status) -> io::Result<()> {
+    write!(writer,
+           "HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+           status)
+}
+
+fn accept_key(key: &str) -> String {
+    let mut sha = Sha1::new();
+    sha.update(key.as_bytes());
+    sha.update(ACCEPT_GUID.as_bytes());
+    let mut digest = [0u8; 20];
+    sha.output(&mut digest);
+    digest.to_base64(STANDARD)
+}
+
+// Read frames from the client until it asks to close the connection, answering pings along the
+// way.
+fn read_frames<R: Read, W: Write>(reader: &mut R, writer: &Mutex<W>) -> io::Result<()> {
+    loop {
+        let mut head = [0u8; 2];
+        try!(reader.read_exact(&mut head));
+        let opcode = head[0] & 0x0F;
+        let len = match head[1] & 0x7F {
+            126 => {
+                let mut ext = [0u8; 2];
+                try!(reader.read_exact(&mut ext));
+                ext.iter().fold(0, |n, b| (n << 8) | *b as u64)
+            }
+            127 => {
+                let mut ext = [0u8; 8];
+                try!(reader.read_exact(&mut ext));
+                ext.iter().fold(0, |n, b| (n << 8) | *b as u64)
+            }
+            n => n as u64,
+        };
+        if len > MAX_CLIENT_PAYLOAD {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "client frame too large"));
+        }
+        let mut mask = [0u8; 4];
+        if head[1] & 0x80 != 0 {
+            try!(reader.read_exact(&mut mask));
+        }
+        let mut payload = vec![0u8; len as usize];
+        try!(reader.read_exact(&mut payload));
+        for (i, b) in payload.iter_mut().enumerate() {
+            *b ^= mask[i % 4];
+        }
+        match opcode {
+            OP_PING => try!(write_frame(&mut *writer.lock().unwrap(), OP_PONG, &payload)),
+            OP_CLOSE => {
+                // Echo the close code, if any, to complete the closing handshake.
+                let code = &payload[..if payload.len() >= 2 { 2 } else { 0 }];
+                return write_frame(&mut *writer.lock().unwrap(), OP_CLOSE, code);
+            }
+            _ => (),
+        }
+    }
+}
+
+fn write_frame<W: Write>(writer: &mut W, opcode: u8, payload: &[u8]) -> io::Result<()> {
+    let len = payload.len();
+    let mut frame = Vec::with_capacity(len + 10);
+    frame.push(0x80 | opcode);
+    if len < 126 {
+        frame.push(len as u8);
+    } else if len <= 0xFFFF {
+        frame.push(126);
+        frame.push((len >> 8) as u8);
+        frame.push(len as u8);
+    } else {
+        frame.push(127);
+        for shift in (0..8).rev() {
+            frame.push(((len as u64) >> (shift * 8)) as u8);
+        }
+    }
+    frame.extend_from_slice(payload);
+    writer.write_all(&frame)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::iter;
+    use std::sync::{Arc, Mutex};
+    use std::sync::atomic::AtomicUsize;
+
+    use protobuf::RepeatedField;
+    use protocol::sessionsrv::Session;
+
+    use super::{accept_key, read_frames, write_frame, Handshake, Pending,
+                MAX_PENDING_HANDSHAKES, OP_CLOSE, OP_PING, OP_PONG, OP_TEXT};
+
+    fn handshake(request: &str) -> Option<Handshake> {
+        Handshake::read(&mut Cursor::new(request.as_bytes())).unwrap()
+    }
+
+    fn session(id: u64, scope: &str) -> Session {
+        let mut session = Session::new();
+        session.set_id(id);
+        session.set_scopes(RepeatedField::from_vec(vec![scope.to_string()]));
+        session
+    }
+
+    // A frame as a client sends it, masked.
+    fn client_frame(opcode: u8, payload: &[u8]) -> Vec<u8> {
+        let mask = [0x37, 0xfa, 0x21, 0x3d];
+        let mut frame = vec![0x80 | opcode, 0x80 | payload.len() as u8];
+        frame.extend_from_slice(&mask);
+        frame.extend(payload.iter().enumerate().map(|(i, b)| b ^ mask[i % 4]));
+        frame
+    }
+
+    #[test]
+    fn accept_key_matches_rfc_sample() {
+        // RFC 6455, 1.3
+        assert_eq!(accept_key("dGhlIHNhbXBsZSBub25jZQ=="),
+                   "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+    }
+
+    #[test]
+    fn handshake_read_parses_request() {
+        let request = handshake("GET /events?token=abc&job=12 HTTP/1.1\r\n\
+                                 Host: localhost\r\n\
+                                 Upgrade: WebSocket\r\n\
+                                 Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\r\n")
+            .unwrap();
+        assert_eq!(request.path, "/events");
+        assert!(request.upgrade);
+        assert_eq!(request.key, Some("dGhlIHNhbXBsZSBub25jZQ==".to_string()));
+        assert_eq!(request.token(), Some("abc"));
+        assert_eq!(request.param("job"), Some("12"));
+    }
+
+    #[test]
+    fn handshake_read_honors_bearer_token() {
+        let request = handshake("GET /events HTTP/1.1\r\nAuthorization: Bearer xyz\r\n\r\n")
+            .unwrap();
+        assert_eq!(request.token(), Some("xyz"));
+        assert!(!request.upgrade);
+    }
+
+    #[test]
+    fn handshake_read_rejects_malformed_requests() {
+        assert!(handshake("").is_none());
+        assert!(handshake("POST /events HTTP/1.1\r\n\r\n").is_none());
+        assert!(handshake("GET /events HTTP/1.1\r\nno-colon\r\n\r\n").is_none());
+        // Hung up before the end of the headers
+        assert!(handshake("GET /events HTTP/1.1\r\nHost: localhost\r\n").is_none());
+        let pad: String = iter::repeat('a').take(super::MAX_HANDSHAKE_BYTES).collect();
+        let large = format!("GET /events HTTP/1.1\r\nX-Pad: {}\r\n\r\n", pad);
+        assert!(handshake(&large).is_none());
+    }
+
+    #[test]
+    fn handshake_filter_limits_sessions_to_their_jobs() {
+        let request = handshake("GET /events?job=12&origin=core HTTP/1.1\r\n\r\n").unwrap();
+        let filter = request.filter(&session(7, "read")).unwrap();
+        assert_eq!(filter.owner_id, Some(7));
+        assert_eq!(filter.job_id, Some(12));
+        assert_eq!(filter.origin, Some("core".to_string()));
+        let filter = request.filter(&session(7, "admin")).unwrap();
+        assert_eq!(filter.owner_id, None);
+        let request = handshake("GET /events?job=twelve HTTP/1.1\r\n\r\n").unwrap();
+        assert!(request.filter(&session(7, "read")).is_none());
+    }
+
+    #[test]
+    fn write_frame_encodes_lengths() {
+        let mut short = vec![];
+        write_frame(&mut short, OP_TEXT, b"hi").unwrap();
+        assert_eq!(short, vec![0x81, 2, b'h', b'i']);
+        let mut medium = vec![];
+        write_frame(&mut medium, OP_TEXT, &[0; 300]).unwrap();
+        assert_eq!(&medium[..4], &[0x81, 126, 1, 44]);
+        assert_eq!(medium.len(), 304);
+        let mut long = vec![];
+        write_frame(&mut long, OP_TEXT, &[0; 70_000]).unwrap();
+        assert_eq!(&long[..10], &[0x81, 127, 0, 0, 0, 0, 0, 1, 0x11, 0x70]);
+        assert_eq!(long.len(), 70_010);
+    }
+
+    #[test]
+    fn read_frames_answers_pings_and_close() {
+        let mut input = client_frame(OP_PING, b"ping");
+        input.extend(client_frame(OP_TEXT, b"ignored"));
+        input.extend(client_frame(OP_CLOSE, &[0x03, 0xe8, b'b', b'y', b'e']));
+        let output = Mutex::new(vec![]);
+        read_frames(&mut Cursor::new(input), &output).unwrap();
+        let mut expected = vec![];
+        write_frame(&mut expected, OP_PONG, b"ping").unwrap();
+        write_frame(&mut expected, OP_CLOSE, &[0x03, 0xe8]).unwrap();
+        assert_eq!(*output.lock().unwrap(), expected);
+    }
+
+    #[test]
+    fn read_frames_rejects_large_frames() {
+        let input = vec![0x81, 0xFF, 0, 0, 0, 0, 0, 1, 0, 0];
+        let output = Mutex::new(vec![]);
+        assert!(read_frames(&mut Cursor::new(input), &output).is_err());
+        assert!(output.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn pending_handshakes_are_limited() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let guards: Vec<Pending> = (0..MAX_PENDING_HANDSHAKES)
+            .map(|_| Pending::acquire(&count).unwrap())
+            .collect();
+        assert!(Pending::acquire(&count).is_none());
+        drop(guards);
+        assert!(Pending::acquire(&count).is_some());
+    }
+}
@@ -23,6 +23,7 @@ use std::ops::Deref;
 
 use protobuf::{Message, parse_from_bytes};
 use protocol::{InstaId, Persistable};
+use protocol::net::{HealthCheckResult, HealthStatus};
 use r2d2;
 use r2d2_redis;
 use redis::{self, Commands, PipelineCommands};
@@ -31,6 +32,35 @@ use error::{Error, Result};
 
 pub type ConnectionPool = r2d2::Pool<r2d2_redis::RedisConnectionManager>;
 
+/// Check that a connection can be taken from the pool and that the datastore answers a `PING`.
+pub fn ping(pool: &ConnectionPool) -> Result<()> {
+    let conn = try!(pool.get());
+    let _: String = try!(redis::cmd("PING").query(conn.deref()));
+    Ok(())
+}
+
+/// Name of the health check reported for a service's datastore.
+pub const HEALTH_CHECK: &'static str = "datastore";
+
+/// The health check result of a service's datastore: `DOWN` if the service has not connected to
+/// it yet or it does not answer a `PING`.
+pub fn health_check(pool: Option<&ConnectionPool>) -> HealthCheckResult {
+    let mut result = HealthCheckResult::new();
+    result.set_name(HEALTH_CHECK.to_string());
+    match pool.map(ping) {
+        Some(Ok(())) => result.set_status(HealthStatus::OK),
+        Some(Err(e)) => {
+            result.set_status(HealthStatus::DOWN);
+            result.set_msg(e.to_string());
+        }
+        None => {
+            result.set_status(HealthStatus::DOWN);
+            result.set_msg("not connected".to_string());
+        }
+    }
+    result
+}
+
 /// Base trait for storing peristable objects into a data store. A bucket prefixes the key of all
 /// entities with a developer-defined prefix.
 pub trait Bucket {
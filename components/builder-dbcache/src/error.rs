@@ -24,6 +24,7 @@ pub enum Error {
     ConnectionTimeout(r2d2::GetTimeout),
     DataStore(redis::RedisError),
     EntityNotFound,
+    EntityConflict,
 }
 
 pub type Result<T> = result::Result<T, Error>;
@@ -34,6 +35,7 @@ impl fmt::Display for Error {
             Error::ConnectionTimeout(ref e) => format!("Connection timeout, {}", e),
             Error::DataStore(ref e) => format!("DataStore error, {}", e),
             Error::EntityNotFound => format!("No value for key found"),
+            Error::EntityConflict => format!("Value for key already exists"),
         };
         write!(f, "{}", msg)
     }
@@ -45,6 +47,7 @@ impl error::Error for Error {
             Error::ConnectionTimeout(ref e) => e.description(),
             Error::DataStore(_) => "Error querying DataStore",
             Error::EntityNotFound => "Entity not found in DataStore",
+            Error::EntityConflict => "Entity already exists in DataStore",
         }
     }
 }
@@ -25,5 +25,6 @@ extern crate time;
 pub mod data_store;
 pub mod error;
 
-pub use self::data_store::{ConnectionPool, Bucket, BasicSet, ExpiringSet, InstaSet, IndexSet};
+pub use self::data_store::{ConnectionPool, Bucket, BasicSet, ExpiringSet, InstaSet, IndexSet,
+                           health_check, ping};
 pub use self::error::{Error, Result};
@@ -7,7 +7,7 @@ pkg_source=nosuchfile.tar.gz
 pkg_bin_dirs=(bin)
 pkg_deps=(core/glibc core/openssl core/gcc-libs core/zeromq core/libsodium core/libarchive)
 pkg_build_deps=(core/protobuf core/protobuf-rust core/coreutils core/cacerts core/rust core/gcc core/pkg-config)
-pkg_expose=(5566 5567)
+pkg_expose=(5566 5567 5568)
 srv_bin="bldr-job-srv"
 pkg_svc_run="bin/$srv_bin start -c ${pkg_svc_path}/config.toml"
 
@@ -14,10 +14,16 @@
 
 //! Configuration for a Habitat JobSrv service
 
+use std::collections::BTreeMap;
 use std::net;
+use std::time::Duration;
 
-use hab_core::config::{ConfigFile, ParseInto};
-use hab_net::config::{RouteAddrs, Shards};
+use hab_core::config::{parse_duration_or_legacy, ConfigFile, ParseInto};
+use hab_net::config::{Keepalive, MessageAuth, Metrics, RouteAddrs, RouterAuth, Shards,
+                       Sockets, Weight};
+use hab_net::routing::KeepaliveConfig;
+use hab_net::sched::SchedConfig;
+use hab_net::zap::ZapCredentials;
 use num_cpus;
 use protocol::sharding::{ShardId, SHARD_COUNT};
 use redis;
@@ -32,14 +38,46 @@ pub struct Config {
     pub worker_command_addr: net::SocketAddrV4,
     /// Listening net address for heartbeat traffic from Workers.
     pub worker_heartbeat_addr: net::SocketAddrV4,
+    /// Listening net address for publishing job status events to subscribers.
+    pub job_events_addr: net::SocketAddrV4,
     /// Net dddress to the persistent datastore.
     pub datastore_addr: net::SocketAddrV4,
     /// List of shard identifiers serviced by the running service.
     pub shards: Vec<ShardId>,
+    /// Share of its shards' requests the service takes relative to other servers serving them.
+    pub weight: Weight,
     /// Router's hearbeat port to connect to.
     pub heartbeat_port: u16,
     /// Number of threads to process queued messages.
     pub worker_threads: usize,
+    /// CPUs to pin worker threads to and their priority, read from `worker_cpus` and
+    /// `worker_nice`.
+    pub worker_sched: SchedConfig,
+    /// Address of a statsd server to push metrics to.
+    pub statsd_addr: Option<net::SocketAddrV4>,
+    /// Listening net address for a Prometheus metrics scrape endpoint.
+    pub metrics_addr: Option<net::SocketAddrV4>,
+    /// Shared secrets for authenticating routed messages, newest first.
+    pub hmac_keys: Vec<String>,
+    /// Credentials presented to routers which authenticate their clients.
+    pub router_auth: ZapCredentials,
+    /// How idle connections to routers are kept open and verified.
+    pub keepalive: KeepaliveConfig,
+    /// Most workers which may handle each kind of message at once, keyed by message id.
+    pub concurrency_limits: BTreeMap<String, usize>,
+    /// Time a message over its concurrency limit waits for a free worker before being rejected
+    /// as busy, at most `hab_net::concurrency::MAX_WAIT_MS`.
+    pub concurrency_wait: Duration,
+    /// Time a worker's lease on a job lasts without being renewed by a heartbeat before the job
+    /// is put back on the queue.
+    pub job_lease: Duration,
+    /// Most times a job is handed to a worker before it is failed instead of requeued.
+    pub job_max_attempts: usize,
+    /// Time the output of a job attempt is kept after its last chunk is received.
+    pub job_log_ttl: Duration,
+    /// Whether a job building a project rebuilds everything depending on the project, as a group
+    /// build, once it completes.
+    pub auto_group_builds: bool,
 }
 
 impl Default for Config {
@@ -48,10 +86,24 @@ impl Default for Config {
             routers: vec![net::SocketAddrV4::new(net::Ipv4Addr::new(127, 0, 0, 1), 5562)],
             worker_command_addr: net::SocketAddrV4::new(net::Ipv4Addr::new(0, 0, 0, 0), 5566),
             worker_heartbeat_addr: net::SocketAddrV4::new(net::Ipv4Addr::new(0, 0, 0, 0), 5567),
+            job_events_addr: net::SocketAddrV4::new(net::Ipv4Addr::new(0, 0, 0, 0), 5568),
             datastore_addr: net::SocketAddrV4::new(net::Ipv4Addr::new(127, 0, 0, 1), 6379),
             shards: (0..SHARD_COUNT).collect(),
+            weight: Weight::default(),
             heartbeat_port: 5563,
             worker_threads: num_cpus::get(),
+            worker_sched: SchedConfig::default(),
+            statsd_addr: None,
+            metrics_addr: None,
+            hmac_keys: vec![],
+            router_auth: ZapCredentials::default(),
+            keepalive: KeepaliveConfig::default(),
+            concurrency_limits: BTreeMap::new(),
+            concurrency_wait: Duration::from_millis(0),
+            job_lease: Duration::from_secs(65),
+            job_max_attempts: 3,
+            job_log_ttl: Duration::from_secs(30 * 86400),
+            auto_group_builds: true,
         }
     }
 }
@@ -64,13 +116,63 @@ impl ConfigFile for Config {
         try!(toml.parse_into("cfg.routers", &mut cfg.routers));
         try!(toml.parse_into("cfg.worker_command_addr", &mut cfg.worker_command_addr));
         try!(toml.parse_into("cfg.worker_heartbeat_addr", &mut cfg.worker_heartbeat_addr));
+        try!(toml.parse_into("cfg.job_events_addr", &mut cfg.job_events_addr));
         try!(toml.parse_into("cfg.datastore_addr", &mut cfg.datastore_addr));
         try!(toml.parse_into("cfg.shards", &mut cfg.shards));
+        try!(toml.parse_into("cfg", &mut cfg.weight));
         try!(toml.parse_into("cfg.heartbeat_port", &mut cfg.heartbeat_port));
+        cfg.worker_sched = try!(SchedConfig::from_toml(&toml,
+                                                       "cfg.worker_cpus",
+                                                       "cfg.worker_nice"));
+        try!(toml.parse_into("cfg.statsd_addr", &mut cfg.statsd_addr));
+        try!(toml.parse_into("cfg.metrics_addr", &mut cfg.metrics_addr));
+        try!(toml.parse_into("cfg.hmac_keys", &mut cfg.hmac_keys));
+        try!(toml.parse_into("cfg.router_auth", &mut cfg.router_auth));
+        try!(toml.parse_into("cfg", &mut cfg.keepalive));
+        try!(toml.parse_into("cfg.concurrency_limits", &mut cfg.concurrency_limits));
+        try!(parse_duration_or_legacy(&toml,
+                                      "cfg.concurrency_wait",
+                                      "cfg.concurrency_wait_ms",
+                                      &mut cfg.concurrency_wait));
+        try!(parse_duration_or_legacy(&toml,
+                                      "cfg.job_lease",
+                                      "cfg.job_lease_ms",
+                                      &mut cfg.job_lease));
+        try!(toml.parse_into("cfg.job_max_attempts", &mut cfg.job_max_attempts));
+        try!(toml.parse_into("cfg.job_log_ttl", &mut cfg.job_log_ttl));
+        try!(toml.parse_into("cfg.auto_group_builds", &mut cfg.auto_group_builds));
         Ok(cfg)
     }
 }
 
+impl Keepalive for Config {
+    fn keepalive(&self) -> KeepaliveConfig {
+        self.keepalive.clone()
+    }
+}
+
+impl MessageAuth for Config {
+    fn hmac_keys(&self) -> &[String] {
+        &self.hmac_keys
+    }
+}
+
+impl RouterAuth for Config {
+    fn router_auth(&self) -> ZapCredentials {
+        self.router_auth.clone()
+    }
+}
+
+impl Metrics for Config {
+    fn statsd_addr(&self) -> Option<net::SocketAddrV4> {
+        self.statsd_addr
+    }
+
+    fn metrics_addr(&self) -> Option<net::SocketAddrV4> {
+        self.metrics_addr
+    }
+}
+
 impl RouteAddrs for Config {
     fn route_addrs(&self) -> &Vec<net::SocketAddrV4> {
         &self.routers
@@ -85,8 +187,14 @@ impl Shards for Config {
     fn shards(&self) -> &Vec<u32> {
         &self.shards
     }
+
+    fn weight(&self) -> Weight {
+        self.weight
+    }
 }
 
+impl Sockets for Config {}
+
 impl<'a> redis::IntoConnectionInfo for &'a Config {
     fn into_connection_info(self) -> redis::RedisResult<redis::ConnectionInfo> {
         format!("redis://{}:{}",
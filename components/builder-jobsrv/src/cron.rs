@@ -0,0 +1,277 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cron expressions naming the times a schedule's jobs are enqueued.
+//!
+//! An expression has the five fields `minute hour day-of-month month day-of-week`, evaluated in
+//! UTC. Each field is `*`, a value, a range `a-b`, or a comma separated list of them, and any of
+//! `*` or a range may be followed by a step `/n`. Days of the week run from 0 for Sunday to 6,
+//! with 7 also meaning Sunday. As in cron, when both the day of the month and the day of the
+//! week are restricted a day matching either of them matches.
+
+use std::result;
+use std::str::FromStr;
+
+use error::{Error, Result};
+
+const MS_PER_MINUTE: i64 = 60_000;
+const MINUTES_PER_DAY: i64 = 24 * 60;
+// Furthest ahead the next matching time is searched for. Every satisfiable expression matches
+// within eight years, the longest gap between leap days falling on a given day of the week.
+const SEARCH_DAYS: i64 = 8 * 366;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cron {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+impl Cron {
+    /// The first time strictly after the given one the expression matches, both in milliseconds
+    /// since the Unix epoch. Returns `None` for expressions which never match, such as one
+    /// naming the 31st of February.
+    pub fn next_after(&self, after_ms: i64) -> Option<i64> {
+        let start = div_floor(after_ms, MS_PER_MINUTE) + 1;
+        let first_day = div_floor(start, MINUTES_PER_DAY);
+        for day in first_day..first_day + SEARCH_DAYS {
+            if !self.matches_day(day) {
+                continue;
+            }
+            let from = if day == first_day {
+                start - day * MINUTES_PER_DAY
+            } else {
+                0
+            };
+            for minute_of_day in from..MINUTES_PER_DAY {
+                if bit(self.hours, minute_of_day / 60) && bit(self.minutes, minute_of_day % 60) {
+                    return Some((day * MINUTES_PER_DAY + minute_of_day) * MS_PER_MINUTE);
+                }
+            }
+        }
+        None
+    }
+
+    fn matches_day(&self, day: i64) -> bool {
+        let (_, month, day_of_month) = civil_from_days(day);
+        if !bit(self.months, month) {
+            return false;
+        }
+        // The Unix epoch fell on a Thursday.
+        let weekday = ((day + 4) % 7 + 7) % 7;
+        let by_day = bit(self.days, day_of_month);
+        let by_weekday = bit(self.weekdays, weekday);
+        match (self.days_restricted, self.weekdays_restricted) {
+            (true, true) => by_day || by_weekday,
+            (true, false) => by_day,
+            (false, true) => by_weekday,
+            (false, false) => true,
+        }
+    }
+}
+
+impl FromStr for Cron {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let fields: Vec<&str> = value.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(Error::BadCron(value.to_string()));
+        }
+        let bad = |_| Error::BadCron(value.to_string());
+        let mut weekdays = try!(parse_field(fields[4], 0, 7).map_err(&bad));
+        // Sunday may be written as either 0 or 7.
+        if bit(weekdays, 7) {
+            weekdays = (weekdays | 1) & !(1 << 7);
+        }
+        Ok(Cron {
+            minutes: try!(parse_field(fields[0], 0, 59).map_err(&bad)),
+            hours: try!(parse_field(fields[1], 0, 23).map_err(&bad)),
+            days: try!(parse_field(fields[2], 1, 31).map_err(&bad)),
+            months: try!(parse_field(fields[3], 1, 12).map_err(&bad)),
+            weekdays: weekdays,
+            days_restricted: !fields[2].starts_with('*'),
+            weekdays_restricted: !fields[4].starts_with('*'),
+        })
+    }
+}
+
+// The set of values a field matches, as a bitmask.
+fn parse_field(field: &str, min: i64, max: i64) -> result::Result<u64, ()> {
+    let mut set = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.find('/') {
+            Some(i) => (&part[..i], try!(part[i + 1..].parse::<i64>().map_err(|_| ()))),
+            None => (part, 1),
+        };
+        let (first, last) = if range == "*" {
+            (min, max)
+        } else {
+            match range.find('-') {
+                Some(i) => {
+                    (try!(range[..i].parse::<i64>().map_err(|_| ())),
+                     try!(range[i + 1..].parse::<i64>().map_err(|_| ())))
+                }
+                None => {
+                    let value = try!(range.parse::<i64>().map_err(|_| ()));
+                    // A single value with a step runs to the end of the field's range.
+                    if part.contains('/') {
+                        (value, max)
+                    } else {
+                        (value, value)
+                    }
+                }
+            }
+        };
+        if step < 1 || first < min || last > max || first > last {
+            return Err(());
+        }
+        let mut value = first;
+        while value <= last {
+            set |= 1 << value;
+            value += step;
+        }
+    }
+    Ok(set)
+}
+
+fn bit(set: u64, value: i64) -> bool {
+    set & (1 << value) != 0
+}
+
+fn div_floor(n: i64, d: i64) -> i64 {
+    let q = n / d;
+    if n % d < 0 { q - 1 } else { q }
+}
+
+// The year, month and day of the month of a day counted from the Unix epoch, in the proleptic
+// Gregorian calendar.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = div_floor(z, 146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{civil_from_days, parse_field, Cron};
+
+    const MS_PER_DAY: i64 = 86_400_000;
+
+    fn next(expr: &str, after_ms: i64) -> Option<i64> {
+        expr.parse::<Cron>().unwrap().next_after(after_ms)
+    }
+
+    #[test]
+    fn parse_field_values_ranges_and_lists() {
+        assert_eq!(parse_field("*", 0, 59), Ok((1 << 60) - 1));
+        assert_eq!(parse_field("5", 0, 59), Ok(1 << 5));
+        assert_eq!(parse_field("1-3", 0, 59), Ok(0b1110));
+        assert_eq!(parse_field("1,3", 0, 59), Ok(0b1010));
+        assert_eq!(parse_field("1-2,5", 0, 59), Ok(0b100110));
+    }
+
+    #[test]
+    fn parse_field_steps() {
+        assert_eq!(parse_field("*/20", 0, 59), Ok(1 | 1 << 20 | 1 << 40));
+        assert_eq!(parse_field("10-20/5", 0, 59), Ok(1 << 10 | 1 << 15 | 1 << 20));
+        // A single value with a step runs to the end of the field.
+        assert_eq!(parse_field("5/20", 0, 59), Ok(1 << 5 | 1 << 25 | 1 << 45));
+        assert_eq!(parse_field("*/5", 1, 12), Ok(1 << 1 | 1 << 6 | 1 << 11));
+    }
+
+    #[test]
+    fn parse_field_rejects_bad_fields() {
+        for field in &["", "a", "60", "3-1", "-1", "1-", "*/0", "*/a", "1,,2"] {
+            assert_eq!(parse_field(field, 0, 59), Err(()), "field {:?}", field);
+        }
+        assert_eq!(parse_field("0", 1, 31), Err(()));
+        assert_eq!(parse_field("32", 1, 31), Err(()));
+    }
+
+    #[test]
+    fn cron_needs_five_fields() {
+        assert!("* * * *".parse::<Cron>().is_err());
+        assert!("* * * * * *".parse::<Cron>().is_err());
+        assert!("* * * * 8".parse::<Cron>().is_err());
+    }
+
+    #[test]
+    fn sunday_is_zero_or_seven() {
+        assert_eq!("0 0 * * 7".parse::<Cron>().unwrap(),
+                   "0 0 * * 0".parse::<Cron>().unwrap());
+        // The fourth of January 1970 was a Sunday.
+        assert_eq!(next("0 0 * * 7", 0), Some(3 * MS_PER_DAY));
+    }
+
+    #[test]
+    fn civil_from_days_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(59), (1970, 3, 1));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        assert_eq!(civil_from_days(11_017), (2000, 3, 1));
+        assert_eq!(civil_from_days(16_801), (2016, 1, 1));
+    }
+
+    #[test]
+    fn next_after_is_strictly_after() {
+        assert_eq!(next("*/15 * * * *", 0), Some(15 * 60_000));
+        assert_eq!(next("*/15 * * * *", 15 * 60_000), Some(30 * 60_000));
+        assert_eq!(next("*/15 * * * *", 15 * 60_000 - 1), Some(15 * 60_000));
+        assert_eq!(next("* * * * *", -1), Some(0));
+    }
+
+    #[test]
+    fn next_after_rolls_over_to_the_next_day() {
+        assert_eq!(next("30 2 * * *", 0), Some(150 * 60_000));
+        assert_eq!(next("30 2 * * *", 150 * 60_000), Some(MS_PER_DAY + 150 * 60_000));
+    }
+
+    #[test]
+    fn next_after_finds_a_leap_day() {
+        // The first leap day after the epoch was the 29th of February 1972.
+        assert_eq!(next("0 0 29 2 *", 0), Some(789 * MS_PER_DAY));
+    }
+
+    #[test]
+    fn next_after_never_matching() {
+        assert_eq!(next("0 0 31 2 *", 0), None);
+        assert_eq!(next("0 0 30 2 *", 0), None);
+    }
+
+    #[test]
+    fn day_of_month_or_day_of_week() {
+        // The 1st of January 1970 was a Thursday, so Friday the 2nd is the first match.
+        assert_eq!(next("0 0 13 * *", 0), Some(12 * MS_PER_DAY));
+        assert_eq!(next("0 0 * * 5", 0), Some(MS_PER_DAY));
+        // Restricting both matches days which are either the 13th or a Friday.
+        assert_eq!(next("0 0 13 * 5", 0), Some(MS_PER_DAY));
+        assert_eq!(next("0 0 13 * 5", MS_PER_DAY), Some(8 * MS_PER_DAY));
+        assert_eq!(next("0 0 13 * 5", 8 * MS_PER_DAY), Some(12 * MS_PER_DAY));
+        // A day of the week starting with `*` leaves the day of the month alone.
+        assert_eq!(next("0 0 13 * */2", 0), Some(12 * MS_PER_DAY));
+    }
+}
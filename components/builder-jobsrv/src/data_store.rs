@@ -12,20 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
+use std::ops::Deref;
 use std::sync::Arc;
 
-use dbcache::{ConnectionPool, Bucket, InstaSet};
-use protocol::InstaId;
-use protocol::jobsrv::Job;
+use dbcache::{self, ConnectionPool, Bucket, InstaSet};
+use protobuf::{parse_from_bytes, Message, RepeatedField};
+use protocol::{InstaId, Persistable};
+use protocol::jobsrv::{Job, JobGroup, JobLog, JobLogChunk, JobSchedule};
 use r2d2_redis::RedisConnectionManager;
 use redis::{self, Commands, PipelineCommands};
 
-use error::Result;
+use error::{Error, Result};
 
 pub struct DataStore {
     pub pool: Arc<ConnectionPool>,
     pub jobs: Arc<JobTable>,
     pub job_queue: JobQueue,
+    pub job_leases: JobLeases,
+    pub job_logs: JobLogTable,
+    pub job_schedules: JobScheduleTable,
+    pub job_groups: JobGroupTable,
+    pub job_graph: JobGraphTable,
 }
 
 impl DataStore {
@@ -36,13 +44,28 @@ impl DataStore {
         let pool = Arc::new(ConnectionPool::new(pool_cfg, manager).unwrap());
         let pool1 = pool.clone();
         let pool2 = pool.clone();
+        let pool3 = pool.clone();
+        let pool4 = pool.clone();
+        let pool5 = pool.clone();
+        let pool6 = pool.clone();
+        let pool7 = pool.clone();
         let jobs = Arc::new(JobTable::new(pool1));
         let jobs1 = jobs.clone();
         let job_queue = JobQueue::new(pool2, jobs1);
+        let job_leases = JobLeases::new(pool3);
+        let job_logs = JobLogTable::new(pool4);
+        let job_schedules = JobScheduleTable::new(pool5);
+        let job_groups = JobGroupTable::new(pool6);
+        let job_graph = JobGraphTable::new(pool7);
         Ok(DataStore {
             pool: pool,
             jobs: jobs,
             job_queue: job_queue,
+            job_leases: job_leases,
+            job_logs: job_logs,
+            job_schedules: job_schedules,
+            job_groups: job_groups,
+            job_graph: job_graph,
         })
     }
 }
@@ -75,6 +98,13 @@ impl InstaSet for JobTable {
     }
 }
 
+/// Highest priority a job may be queued with. Jobs queued with a higher one are clamped to it.
+pub const MAX_PRIORITY: u32 = 9;
+// Number of jobs at the head of each priority's queue searched for one a worker can run.
+const SCAN_DEPTH: isize = 100;
+
+/// Jobs waiting for a worker, one list per priority. Within a priority jobs are handed out in the
+/// order they were queued.
 pub struct JobQueue {
     jobs: Arc<JobTable>,
     pool: Arc<ConnectionPool>,
@@ -88,40 +118,631 @@ impl JobQueue {
         }
     }
 
-    // JW TODO: clean up this mess
+    /// Add a job to the back of its priority's queue.
     pub fn enqueue(&self, job: &Job) -> Result<()> {
-        let conn = self.pool.get().unwrap();
-        let _count: i32 = conn.rpush("job_queue", job.get_id()).unwrap();
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        try!(conn.rpush::<String, u64, ()>(Self::key(job.get_priority()), job.get_id())
+            .map_err(dbcache::Error::from));
+        Ok(())
+    }
+
+    /// Put a job back at the front of its priority's queue, ahead of jobs queued after it was.
+    pub fn requeue(&self, job: &Job) -> Result<()> {
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        try!(conn.lpush::<String, u64, ()>(Self::key(job.get_priority()), job.get_id())
+            .map_err(dbcache::Error::from));
         Ok(())
     }
 
-    // JW TODO: clean up this mess
-    pub fn dequeue(&self) -> Result<Option<InstaId>> {
-        let conn = self.pool.get().unwrap();
-        match conn.lpop("job_queue") {
-            Ok(id) => Ok(Some(id)),
-            Err(_) => Ok(None),
+    /// Take a job off the queue. Returns false if it had already been taken.
+    pub fn remove(&self, job: &Job) -> Result<bool> {
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        let removed: usize = try!(conn.lrem(Self::key(job.get_priority()), 1, job.get_id())
+            .map_err(dbcache::Error::from));
+        Ok(removed > 0)
+    }
+
+    /// Number of jobs waiting to be handed to a worker.
+    pub fn len(&self) -> Result<usize> {
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        let mut len = 0;
+        for priority in 0..MAX_PRIORITY + 1 {
+            let depth: usize = try!(conn.llen(Self::key(priority)).map_err(dbcache::Error::from));
+            len += depth;
         }
+        Ok(len)
     }
 
-    // JW TODO: clean up this mess
-    pub fn peek(&self) -> Result<Option<Job>> {
-        let conn = self.pool.get().unwrap();
-        match conn.lrange::<&'static str, Vec<u64>>("job_queue", 0, 0) {
-            Ok(id) => {
-                if id.is_empty() {
-                    return Ok(None);
-                }
-                match self.jobs.find(&id[0]) {
-                    Ok(job) => Ok(Some(job.into())),
-                    Err(_) => {
-                        // JW TODO: we should never hit this
-                        try!(self.dequeue());
-                        Ok(None)
+    /// The highest priority queued job which a worker with the given capabilities may run,
+    /// without taking it off the queue.
+    pub fn next_for(&self, capabilities: &[String]) -> Result<Option<Job>> {
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        for priority in (0..MAX_PRIORITY + 1).rev() {
+            let key = Self::key(priority);
+            let ids: Vec<u64> = try!(conn.lrange(key.clone(), 0, SCAN_DEPTH - 1)
+                .map_err(dbcache::Error::from));
+            for id in ids {
+                match self.jobs.find(&id) {
+                    Ok(job) => {
+                        if job.runnable_by(capabilities) {
+                            return Ok(Some(job));
+                        }
+                    }
+                    Err(dbcache::Error::EntityNotFound) => {
+                        warn!("dropping queued job which no longer exists, job={}", id);
+                        try!(conn.lrem::<String, u64, ()>(key.clone(), 1, id)
+                            .map_err(dbcache::Error::from));
                     }
+                    Err(e) => return Err(Error::from(e)),
                 }
             }
-            Err(_) => Ok(None),
         }
+        Ok(None)
+    }
+
+    // Jobs queued before priorities existed are in the priority 0 queue.
+    fn key(priority: u32) -> String {
+        match cmp::min(priority, MAX_PRIORITY) {
+            0 => "job_queue".to_string(),
+            priority => format!("job_queue:{}", priority),
+        }
+    }
+}
+
+/// Leases held by workers on the jobs they are running, renewed by each of their heartbeats. A
+/// job whose lease expires is put back on the queue.
+pub struct JobLeases {
+    pool: Arc<ConnectionPool>,
+}
+
+impl JobLeases {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        JobLeases { pool: pool }
+    }
+
+    /// Grant or extend the lease on a job until the given time, in milliseconds since the Unix
+    /// epoch.
+    pub fn renew(&self, job_id: u64, expires_at_ms: i64) -> Result<()> {
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        try!(conn.zadd::<&'static str, i64, u64, ()>(Self::key(), job_id, expires_at_ms)
+            .map_err(dbcache::Error::from));
+        Ok(())
+    }
+
+    pub fn release(&self, job_id: u64) -> Result<()> {
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        try!(conn.zrem::<&'static str, u64, ()>(Self::key(), job_id)
+            .map_err(dbcache::Error::from));
+        Ok(())
+    }
+
+    /// Jobs whose lease expired at or before the given time.
+    pub fn expired(&self, now_ms: i64) -> Result<Vec<u64>> {
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        let ids = try!(conn.zrangebyscore(Self::key(), 0, now_ms).map_err(dbcache::Error::from));
+        Ok(ids)
+    }
+
+    fn key() -> &'static str {
+        "job_leases"
+    }
+}
+
+/// Build output of each attempt at a job, stored as the list of chunks its worker sent. The
+/// output of an attempt expires a while after its last chunk is stored.
+pub struct JobLogTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl JobLogTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        JobLogTable { pool: pool }
+    }
+
+    /// Store a chunk of the output of the given attempt at a job, keeping the attempt's output
+    /// for `ttl_secs` from now. Chunks already stored, such as those a worker sent again after
+    /// reconnecting, are ignored.
+    pub fn append(&self, chunk: &JobLogChunk, attempt: u32, ttl_secs: usize) -> Result<()> {
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        let key = Self::key(chunk.get_job_id(), attempt);
+        let complete_key = Self::complete_key(chunk.get_job_id(), attempt);
+        let len: u64 = try!(conn.llen(key.clone()).map_err(dbcache::Error::from));
+        let mut pipe = redis::pipe();
+        if chunk.get_seq() < len {
+            debug!("ignoring duplicate log chunk, job={}, attempt={}, seq={}",
+                   chunk.get_job_id(),
+                   attempt,
+                   chunk.get_seq());
+        } else {
+            if chunk.get_seq() > len {
+                warn!("log chunks missing, job={}, attempt={}, expected={}, received={}",
+                      chunk.get_job_id(),
+                      attempt,
+                      len,
+                      chunk.get_seq());
+            }
+            pipe.rpush(key.clone(), chunk.get_content()).ignore();
+        }
+        if chunk.get_complete() {
+            pipe.set(complete_key.clone(), true).ignore();
+        }
+        pipe.expire(key, ttl_secs).ignore().expire(complete_key, ttl_secs).ignore();
+        try!(pipe.query::<()>(conn.deref()).map_err(dbcache::Error::from));
+        Ok(())
+    }
+
+    /// Read at most `limit` chunks of the output of an attempt at a job starting from the given
+    /// chunk.
+    pub fn read(&self, job_id: u64, attempt: u32, start: u64, limit: u64) -> Result<JobLog> {
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        let content: Vec<String> = try!(conn.lrange(Self::key(job_id, attempt),
+                    start as isize,
+                    (start + limit) as isize - 1)
+            .map_err(dbcache::Error::from));
+        let complete: Option<bool> = try!(conn.get(Self::complete_key(job_id, attempt))
+            .map_err(dbcache::Error::from));
+        let mut log = JobLog::new();
+        log.set_job_id(job_id);
+        log.set_attempt(attempt);
+        log.set_start(start);
+        log.set_stop(start + content.len() as u64);
+        log.set_content(RepeatedField::from_vec(content));
+        log.set_complete(complete.unwrap_or(false));
+        Ok(log)
+    }
+
+    fn key(job_id: u64, attempt: u32) -> String {
+        format!("job_log:{}:{}", job_id, attempt)
+    }
+
+    fn complete_key(job_id: u64, attempt: u32) -> String {
+        format!("job_log_complete:{}:{}", job_id, attempt)
+    }
+}
+
+/// Recurring build schedules, at most one per owner and project, and the time each next comes
+/// due.
+pub struct JobScheduleTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl JobScheduleTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        JobScheduleTable { pool: pool }
+    }
+
+    /// Look up an owner's schedule for a project.
+    pub fn find_by_project(&self, owner_id: u64, project: &str) -> dbcache::Result<JobSchedule> {
+        let conn = try!(self.pool().get());
+        let id: Option<u64> = try!(conn.hget(Self::owner_key(owner_id), project));
+        match id {
+            Some(id) => self.find(&id),
+            None => Err(dbcache::Error::EntityNotFound),
+        }
+    }
+
+    /// Return every schedule of the given owner.
+    pub fn get_by_owner(&self, owner_id: u64) -> Result<Vec<JobSchedule>> {
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        let ids: Vec<u64> = try!(conn.hvals(Self::owner_key(owner_id))
+            .map_err(dbcache::Error::from));
+        let schedules = ids.iter().fold(Vec::new(), |mut acc, id| {
+            match self.find(id) {
+                Ok(schedule) => acc.push(schedule),
+                Err(e) => debug!("Can't find job schedule for id {}:{}", id, e),
+            }
+            acc
+        });
+        Ok(schedules)
+    }
+
+    /// Store a change to an existing schedule, including when it next comes due.
+    pub fn replace(&self, record: &JobSchedule) -> Result<()> {
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .set(Self::key(&record.primary_key()), record.write_to_bytes().unwrap())
+            .ignore();
+        if record.has_next_run_ms() {
+            pipe.zadd(Self::due_key(), record.primary_key(), record.get_next_run_ms()).ignore();
+        } else {
+            pipe.zrem(Self::due_key(), record.primary_key()).ignore();
+        }
+        try!(pipe.query::<()>(conn.deref()).map_err(dbcache::Error::from));
+        Ok(())
+    }
+
+    /// Schedules which came due at or before the given time, with the time each came due.
+    pub fn due(&self, now_ms: i64) -> Result<Vec<(u64, i64)>> {
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        let due = try!(conn.zrangebyscore_withscores(Self::due_key(), 0, now_ms)
+            .map_err(dbcache::Error::from));
+        Ok(due)
+    }
+
+    /// Record the job queued for a schedule which came due at `due_ms`, claimed with `next_ms`.
+    /// The schedule is watched while it is read and stored, so a replacement of it stored
+    /// meanwhile is kept, along with its own next run.
+    pub fn ran(&self, id: u64, due_ms: i64, job_id: u64, next_ms: Option<i64>) -> Result<()> {
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        let key = Self::key(&id);
+        try!(redis::transaction(conn.deref(), &[key.as_str()], |txn| {
+            let bytes: Vec<u8> = try!(conn.get(key.as_str()));
+            if bytes.is_empty() {
+                return Ok(Some(()));
+            }
+            let mut schedule: JobSchedule = parse_from_bytes(&bytes).unwrap();
+            schedule.set_last_job_id(job_id);
+            if schedule.get_next_run_ms() == due_ms {
+                match next_ms {
+                    Some(next_ms) => schedule.set_next_run_ms(next_ms),
+                    None => schedule.clear_next_run_ms(),
+                }
+            }
+            txn.set(key.as_str(), schedule.write_to_bytes().unwrap()).ignore();
+            txn.query(conn.deref())
+        }).map_err(dbcache::Error::from));
+        Ok(())
+    }
+
+    /// Move a schedule which came due at `due_ms` on to its next run, or off the due times if it
+    /// has none. Returns false if it was already moved, by another JobSrv sharing the datastore
+    /// or by the schedule being replaced.
+    pub fn claim(&self, id: u64, due_ms: i64, next_ms: Option<i64>) -> Result<bool> {
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        let script = redis::Script::new(r"
+            if redis.call('zscore', KEYS[1], ARGV[1]) ~= ARGV[2] then
+                return 0
+            end
+            if ARGV[3] == '' then
+                redis.call('zrem', KEYS[1], ARGV[1])
+            else
+                redis.call('zadd', KEYS[1], ARGV[3], ARGV[1])
+            end
+            return 1
+        ");
+        let next = next_ms.map(|n| n.to_string()).unwrap_or(String::new());
+        let claimed: i32 = try!(script.key(Self::due_key())
+            .arg(id)
+            .arg(due_ms)
+            .arg(next)
+            .invoke(conn.deref())
+            .map_err(dbcache::Error::from));
+        Ok(claimed == 1)
+    }
+
+    fn owner_key(owner_id: u64) -> String {
+        format!("owner_to_job_schedules:{}", owner_id)
+    }
+
+    fn due_key() -> &'static str {
+        "job_schedules_due"
+    }
+}
+
+impl Bucket for JobScheduleTable {
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+
+    fn prefix() -> &'static str {
+        "job_schedule"
+    }
+}
+
+impl InstaSet for JobScheduleTable {
+    type Record = JobSchedule;
+
+    fn seq_id() -> &'static str {
+        "job_schedules_seq"
+    }
+
+    /// Store a new schedule, failing with `EntityConflict` if its owner already has one for the
+    /// project. The owner's schedules are watched, so of two created for a project at once only
+    /// one is stored.
+    fn write(&self, record: &mut Self::Record) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        let owner_key = Self::owner_key(record.get_owner_id());
+        let mut taken = false;
+        let watched = [Self::seq_id().to_string(), owner_key.clone()];
+        try!(redis::transaction(conn.deref(), &watched, |txn| {
+            taken = try!(conn.hexists(owner_key.clone(), record.get_project()));
+            if taken {
+                return Ok(Some(()));
+            }
+            let sequence_id: u64 = match conn.get::<&'static str, u64>(Self::seq_id()) {
+                Ok(value) => value + 1,
+                _ => 0,
+            };
+            let insta_id = InstaId::generate(sequence_id);
+            record.set_primary_key(*insta_id);
+            txn.set(Self::seq_id(), record.primary_key())
+                .ignore()
+                .set(Self::key(&record.primary_key()),
+                     record.write_to_bytes().unwrap())
+                .ignore()
+                .hset_nx(owner_key.clone(), record.get_project(), record.primary_key())
+                .ignore();
+            if record.has_next_run_ms() {
+                txn.zadd(Self::due_key(), record.primary_key(), record.get_next_run_ms())
+                    .ignore();
+            }
+            txn.query(conn.deref())
+        }));
+        if taken {
+            return Err(dbcache::Error::EntityConflict);
+        }
+        Ok(())
+    }
+}
+
+pub struct JobGroupTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl JobGroupTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        JobGroupTable { pool: pool }
+    }
+}
+
+impl Bucket for JobGroupTable {
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+
+    fn prefix() -> &'static str {
+        "job_group"
+    }
+}
+
+impl InstaSet for JobGroupTable {
+    type Record = JobGroup;
+
+    fn seq_id() -> &'static str {
+        "job_groups_seq"
+    }
+}
+
+/// The dependencies between projects, as of the latest package of each uploaded to the depot,
+/// kept in both directions so the dependents of a project can be found.
+pub struct JobGraphTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl JobGraphTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        JobGraphTable { pool: pool }
+    }
+
+    /// Replace the dependencies of a project.
+    pub fn add(&self, project: &str, deps: &[String]) -> Result<()> {
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        let old: Vec<String> = try!(conn.smembers(Self::deps_key(project))
+            .map_err(dbcache::Error::from));
+        let mut pipe = redis::pipe();
+        pipe.atomic().del(Self::deps_key(project)).ignore();
+        for dep in old.iter() {
+            pipe.srem(Self::rdeps_key(dep), project).ignore();
+        }
+        for dep in deps.iter() {
+            pipe.sadd(Self::deps_key(project), dep.as_str())
+                .ignore()
+                .sadd(Self::rdeps_key(dep), project)
+                .ignore();
+        }
+        try!(pipe.query::<()>(conn.deref()).map_err(dbcache::Error::from));
+        Ok(())
+    }
+
+    /// Projects the given project depends on directly.
+    pub fn deps(&self, project: &str) -> Result<Vec<String>> {
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        let deps = try!(conn.smembers(Self::deps_key(project)).map_err(dbcache::Error::from));
+        Ok(deps)
+    }
+
+    /// Projects depending directly on the given project.
+    pub fn rdeps(&self, project: &str) -> Result<Vec<String>> {
+        let conn = try!(self.pool.get().map_err(dbcache::Error::from));
+        let rdeps = try!(conn.smembers(Self::rdeps_key(project)).map_err(dbcache::Error::from));
+        Ok(rdeps)
+    }
+
+    fn deps_key(project: &str) -> String {
+        format!("graph:deps:{}", project)
+    }
+
+    fn rdeps_key(project: &str) -> String {
+        format!("graph:rdeps:{}", project)
+    }
+}
+
+#[cfg(all(test, feature = "functional"))]
+mod test {
+    use dbcache::{self, InstaSet};
+    use hab_net::clock::{Clock, SystemClock};
+    use protocol::jobsrv::{JobLogChunk, JobSchedule};
+    use redis::Commands;
+
+    use config::Config;
+    use super::{DataStore, JobLogTable};
+
+    fn chunk(job_id: u64, seq: u64, content: &str, complete: bool) -> JobLogChunk {
+        let mut chunk = JobLogChunk::new();
+        chunk.set_job_id(job_id);
+        chunk.set_seq(seq);
+        chunk.set_content(content.to_string());
+        chunk.set_complete(complete);
+        chunk
+    }
+
+    /// Needs a Redis server listening on the job server's default datastore address.
+    #[test]
+    fn logs_are_kept_per_attempt() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let job_id = SystemClock.now_ms() as u64;
+        datastore.job_logs.append(&chunk(job_id, 0, "first try\n", false), 1, 60).unwrap();
+        datastore.job_logs.append(&chunk(job_id, 0, "second try\n", false), 2, 60).unwrap();
+        datastore.job_logs.append(&chunk(job_id, 1, "done\n", true), 2, 60).unwrap();
+        // A chunk sent again after a reconnect is stored once.
+        datastore.job_logs.append(&chunk(job_id, 1, "done\n", true), 2, 60).unwrap();
+
+        let first = datastore.job_logs.read(job_id, 1, 0, 100).unwrap();
+        assert_eq!(first.get_content(), &["first try\n".to_string()]);
+        assert!(!first.get_complete());
+        let second = datastore.job_logs.read(job_id, 2, 0, 100).unwrap();
+        assert_eq!(second.get_attempt(), 2);
+        assert_eq!(second.get_content(),
+                   &["second try\n".to_string(), "done\n".to_string()]);
+        assert!(second.get_complete());
+        let tail = datastore.job_logs.read(job_id, 2, 1, 100).unwrap();
+        assert_eq!((tail.get_start(), tail.get_stop()), (1, 2));
+    }
+
+    /// Needs a Redis server listening on the job server's default datastore address.
+    #[test]
+    fn logs_expire() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let job_id = SystemClock.now_ms() as u64 + 1;
+        datastore.job_logs.append(&chunk(job_id, 0, "output\n", true), 1, 60).unwrap();
+        let conn = datastore.pool.get().unwrap();
+        let ttl: i64 = conn.ttl(JobLogTable::key(job_id, 1)).unwrap();
+        assert!(ttl > 0 && ttl <= 60, "unexpected ttl {}", ttl);
+        let ttl: i64 = conn.ttl(JobLogTable::complete_key(job_id, 1)).unwrap();
+        assert!(ttl > 0 && ttl <= 60, "unexpected ttl {}", ttl);
+    }
+
+    /// Needs a Redis server listening on the job server's default datastore address.
+    #[test]
+    fn leases_expire_unless_renewed_or_released() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let now = SystemClock.now_ms();
+        let (renewed, lapsed, released) = (now as u64, now as u64 + 1, now as u64 + 2);
+        datastore.job_leases.renew(renewed, now + 1_000).unwrap();
+        datastore.job_leases.renew(lapsed, now + 1_000).unwrap();
+        datastore.job_leases.renew(released, now + 1_000).unwrap();
+        assert!(!datastore.job_leases.expired(now).unwrap().contains(&lapsed));
+
+        datastore.job_leases.renew(renewed, now + 5_000).unwrap();
+        datastore.job_leases.release(released).unwrap();
+        let expired = datastore.job_leases.expired(now + 1_000).unwrap();
+        assert!(expired.contains(&lapsed));
+        assert!(!expired.contains(&renewed));
+        assert!(!expired.contains(&released));
+        for id in [renewed, lapsed].iter() {
+            datastore.job_leases.release(*id).unwrap();
+        }
+    }
+
+    /// Needs a Redis server listening on the job server's default datastore address.
+    #[test]
+    fn claim_moves_a_due_schedule_only_once() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let due_ms = SystemClock.now_ms() / 60_000 * 60_000;
+        let next_ms = due_ms + 60_000;
+        let mut schedule = JobSchedule::new();
+        schedule.set_owner_id(due_ms as u64);
+        schedule.set_project("core/redis".to_string());
+        schedule.set_cron("* * * * *".to_string());
+        schedule.set_next_run_ms(due_ms);
+        datastore.job_schedules.write(&mut schedule).unwrap();
+        let id = schedule.get_id();
+        let is_due = |now_ms| datastore.job_schedules.due(now_ms).unwrap().contains(&(id, due_ms));
+        assert!(is_due(due_ms));
+
+        assert!(datastore.job_schedules.claim(id, due_ms, Some(next_ms)).unwrap());
+        // A second JobSrv which saw the same due time loses the race.
+        assert!(!datastore.job_schedules.claim(id, due_ms, Some(next_ms)).unwrap());
+        assert!(!is_due(next_ms));
+        assert!(datastore.job_schedules.due(next_ms).unwrap().contains(&(id, next_ms)));
+
+        assert!(datastore.job_schedules.claim(id, next_ms, None).unwrap());
+        assert!(!datastore.job_schedules.due(next_ms).unwrap().iter().any(|&(d, _)| d == id));
+        assert!(!datastore.job_schedules.claim(id, next_ms, None).unwrap());
+    }
+
+    /// Needs a Redis server listening on the job server's default datastore address.
+    #[test]
+    fn claim_loses_to_a_replaced_schedule() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let due_ms = SystemClock.now_ms() / 60_000 * 60_000;
+        let mut schedule = JobSchedule::new();
+        schedule.set_owner_id(due_ms as u64 + 1);
+        schedule.set_project("core/redis".to_string());
+        schedule.set_cron("* * * * *".to_string());
+        schedule.set_next_run_ms(due_ms);
+        datastore.job_schedules.write(&mut schedule).unwrap();
+
+        schedule.set_next_run_ms(due_ms + 3_600_000);
+        datastore.job_schedules.replace(&schedule).unwrap();
+        assert!(!datastore.job_schedules.claim(schedule.get_id(), due_ms, None).unwrap());
+        assert!(datastore.job_schedules
+            .due(due_ms + 3_600_000)
+            .unwrap()
+            .contains(&(schedule.get_id(), due_ms + 3_600_000)));
+    }
+
+    /// Needs a Redis server listening on the job server's default datastore address.
+    #[test]
+    fn one_schedule_per_owner_and_project() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let due_ms = SystemClock.now_ms();
+        let mut schedule = JobSchedule::new();
+        schedule.set_owner_id(due_ms as u64 + 2);
+        schedule.set_project("core/redis".to_string());
+        schedule.set_cron("* * * * *".to_string());
+        schedule.set_next_run_ms(due_ms);
+        datastore.job_schedules.write(&mut schedule).unwrap();
+
+        let mut duplicate = schedule.clone();
+        duplicate.set_next_run_ms(due_ms + 60_000);
+        match datastore.job_schedules.write(&mut duplicate) {
+            Err(dbcache::Error::EntityConflict) => {}
+            other => panic!("second schedule for a project was stored, {:?}", other),
+        }
+        let found = datastore.job_schedules
+            .find_by_project(due_ms as u64 + 2, "core/redis")
+            .unwrap();
+        assert_eq!(found.get_id(), schedule.get_id());
+        assert_eq!(found.get_next_run_ms(), due_ms);
+        assert!(!datastore.job_schedules
+            .due(due_ms + 60_000)
+            .unwrap()
+            .contains(&(schedule.get_id(), due_ms + 60_000)));
+    }
+
+    /// Needs a Redis server listening on the job server's default datastore address.
+    #[test]
+    fn ran_keeps_a_replaced_schedule() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let due_ms = SystemClock.now_ms() / 60_000 * 60_000;
+        let mut schedule = JobSchedule::new();
+        schedule.set_owner_id(due_ms as u64 + 3);
+        schedule.set_project("core/redis".to_string());
+        schedule.set_cron("* * * * *".to_string());
+        schedule.set_next_run_ms(due_ms);
+        datastore.job_schedules.write(&mut schedule).unwrap();
+        let id = schedule.get_id();
+
+        assert!(datastore.job_schedules.claim(id, due_ms, Some(due_ms + 60_000)).unwrap());
+        datastore.job_schedules.ran(id, due_ms, 1, Some(due_ms + 60_000)).unwrap();
+        let ran = datastore.job_schedules.find(&id).unwrap();
+        assert_eq!(ran.get_last_job_id(), 1);
+        assert_eq!(ran.get_next_run_ms(), due_ms + 60_000);
+
+        // The schedule is replaced between being claimed and its job being recorded.
+        assert!(datastore.job_schedules
+            .claim(id, due_ms + 60_000, Some(due_ms + 120_000))
+            .unwrap());
+        let mut replaced = ran.clone();
+        replaced.set_cron("0 * * * *".to_string());
+        replaced.set_next_run_ms(due_ms + 3_600_000);
+        datastore.job_schedules.replace(&replaced).unwrap();
+        datastore.job_schedules.ran(id, due_ms + 60_000, 2, Some(due_ms + 120_000)).unwrap();
+        let ran = datastore.job_schedules.find(&id).unwrap();
+        assert_eq!(ran.get_last_job_id(), 2);
+        assert_eq!(ran.get_cron(), "0 * * * *");
+        assert_eq!(ran.get_next_run_ms(), due_ms + 3_600_000);
     }
 }
@@ -25,13 +25,13 @@ use zmq;
 
 #[derive(Debug)]
 pub enum Error {
+    BadCron(String),
     BadPort(String),
     DataStore(dbcache::Error),
     HabitatCore(hab_core::Error),
     IO(io::Error),
     NetError(hab_net::Error),
     Protobuf(protobuf::ProtobufError),
-    Zmq(zmq::Error),
 }
 
 pub type Result<T> = result::Result<T, Error>;
@@ -39,13 +39,13 @@ pub type Result<T> = result::Result<T, Error>;
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            Error::BadCron(ref e) => format!("{} is an invalid cron expression.", e),
             Error::BadPort(ref e) => format!("{} is an invalid port. Valid range 1-65535.", e),
             Error::DataStore(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::IO(ref e) => format!("{}", e),
             Error::NetError(ref e) => format!("{}", e),
             Error::Protobuf(ref e) => format!("{}", e),
-            Error::Zmq(ref e) => format!("{}", e),
         };
         write!(f, "{}", msg)
     }
@@ -54,13 +54,13 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
+            Error::BadCron(_) => "Received an invalid cron expression.",
             Error::BadPort(_) => "Received an invalid port or a number outside of the valid range.",
             Error::DataStore(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
             Error::NetError(ref err) => err.description(),
             Error::Protobuf(ref err) => err.description(),
-            Error::Zmq(ref err) => err.description(),
         }
     }
 }
@@ -97,6 +97,6 @@ impl From<protobuf::ProtobufError> for Error {
 
 impl From<zmq::Error> for Error {
     fn from(err: zmq::Error) -> Error {
-        Error::Zmq(err)
+        Error::NetError(hab_net::Error::from(err))
     }
 }
@@ -0,0 +1,159 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The order the projects of a group build are built in.
+//!
+//! A group build of a project rebuilds it and everything which depends on it, directly or
+//! through other packages. Projects are named `origin/name`, as packages are regardless of their
+//! version, and a project's dependencies are those of the latest of its packages uploaded to the
+//! depot.
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::result;
+
+/// The projects a group build of `root` builds, each with those of its dependencies which are
+/// also in the group, ordered so that every project comes after its dependencies. `rdeps` gives
+/// the projects depending directly on a project and `deps` those it depends on directly.
+///
+/// Projects which depend on each other in a cycle are ordered by name after all the others,
+/// each depending only on those in the group ordered before it.
+pub fn build_order<E, R, D>(root: &str,
+                            mut rdeps: R,
+                            mut deps: D)
+                            -> result::Result<Vec<(String, Vec<String>)>, E>
+    where R: FnMut(&str) -> result::Result<Vec<String>, E>,
+          D: FnMut(&str) -> result::Result<Vec<String>, E>
+{
+    let mut members = BTreeSet::new();
+    let mut queue = VecDeque::new();
+    members.insert(root.to_string());
+    queue.push_back(root.to_string());
+    while let Some(project) = queue.pop_front() {
+        for dependent in try!(rdeps(&project)) {
+            if members.insert(dependent.clone()) {
+                queue.push_back(dependent);
+            }
+        }
+    }
+    let mut remaining = BTreeMap::new();
+    for project in members.iter() {
+        let within: Vec<String> = try!(deps(project))
+            .into_iter()
+            .filter(|d| d != project && members.contains(d))
+            .collect();
+        remaining.insert(project.clone(), within);
+    }
+    let mut placed = BTreeSet::new();
+    let mut order = Vec::with_capacity(remaining.len());
+    loop {
+        let ready: Vec<String> = remaining.iter()
+            .filter(|&(_, deps)| deps.iter().all(|d| placed.contains(d)))
+            .map(|(project, _)| project.clone())
+            .collect();
+        if ready.is_empty() {
+            break;
+        }
+        for project in ready {
+            let deps = remaining.remove(&project).unwrap();
+            placed.insert(project.clone());
+            order.push((project, deps));
+        }
+    }
+    for (project, deps) in remaining {
+        let deps = deps.into_iter().filter(|d| placed.contains(d)).collect();
+        placed.insert(project.clone());
+        order.push((project, deps));
+    }
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::result;
+
+    use super::build_order;
+
+    // The build order of a group build of `root`, among projects each given with the projects
+    // it depends on.
+    fn order(root: &str, graph: &[(&str, &[&str])]) -> Vec<(String, Vec<String>)> {
+        let mut deps: HashMap<String, Vec<String>> = HashMap::new();
+        let mut rdeps: HashMap<String, Vec<String>> = HashMap::new();
+        for &(project, project_deps) in graph {
+            for dep in project_deps {
+                deps.entry(project.to_string()).or_insert(vec![]).push(dep.to_string());
+                rdeps.entry(dep.to_string()).or_insert(vec![]).push(project.to_string());
+            }
+        }
+        let lookup = |map: &HashMap<String, Vec<String>>, p: &str| {
+            Ok::<Vec<String>, ()>(map.get(p).cloned().unwrap_or(vec![]))
+        };
+        build_order(root, |p| lookup(&rdeps, p), |p| lookup(&deps, p)).unwrap()
+    }
+
+    fn names(order: &[(String, Vec<String>)]) -> Vec<&str> {
+        order.iter().map(|&(ref project, _)| project.as_str()).collect()
+    }
+
+    #[test]
+    fn root_alone() {
+        let order = order("core/a", &[("core/b", &["core/c"])]);
+        assert_eq!(order, vec![("core/a".to_string(), vec![])]);
+    }
+
+    #[test]
+    fn dependents_come_after_their_deps() {
+        let order = order("core/a",
+                          &[("core/c", &["core/b"]), ("core/b", &["core/a"]), ("core/x", &[])]);
+        assert_eq!(names(&order), vec!["core/a", "core/b", "core/c"]);
+        assert_eq!(order[2].1, vec!["core/b".to_string()]);
+    }
+
+    #[test]
+    fn diamond_builds_the_shared_dependent_once_last() {
+        let order = order("core/a",
+                          &[("core/b", &["core/a"]),
+                            ("core/c", &["core/a"]),
+                            ("core/d", &["core/b", "core/c", "core/z"])]);
+        assert_eq!(names(&order), vec!["core/a", "core/b", "core/c", "core/d"]);
+        // Dependencies outside the group are dropped.
+        assert_eq!(order[3].1, vec!["core/b".to_string(), "core/c".to_string()]);
+    }
+
+    #[test]
+    fn cycles_are_ordered_by_name_after_the_rest() {
+        let order = order("core/a",
+                          &[("core/b", &["core/a", "core/c"]),
+                            ("core/c", &["core/b"]),
+                            ("core/d", &["core/a"]),
+                            ("core/e", &["core/e", "core/a"])]);
+        assert_eq!(names(&order),
+                   vec!["core/a", "core/d", "core/e", "core/b", "core/c"]);
+        // A project's dependency on itself is ignored, and of a cycle each depends only on
+        // those before it.
+        assert_eq!(order[2].1, vec!["core/a".to_string()]);
+        assert_eq!(order[3].1, vec!["core/a".to_string()]);
+        assert_eq!(order[4].1, vec!["core/b".to_string()]);
+    }
+
+    #[test]
+    fn lookup_errors_are_returned() {
+        let failed: result::Result<_, &str> =
+            build_order("core/a", |_| Err("no rdeps"), |_| Ok(vec![]));
+        assert_eq!(failed, Err("no rdeps"));
+        let failed: result::Result<_, &str> =
+            build_order("core/a", |_| Ok(vec![]), |_| Err("no deps"));
+        assert_eq!(failed, Err("no deps"));
+    }
+}
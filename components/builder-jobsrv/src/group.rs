@@ -0,0 +1,260 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Coordination of group builds.
+//!
+//! A group build queues a job for each of its projects once every project in the group it
+//! depends on has been built, and skips the projects depending on one which failed to build.
+
+use dbcache::{self, InstaSet};
+use protobuf::RepeatedField;
+use protocol::jobsrv::{Job, JobGroup, JobGroupProject, JobGroupProjectState, JobGroupSpec,
+                       JobGroupState, JobState};
+
+use data_store::DataStore;
+use error::{Error, Result};
+use graph;
+
+/// Plan and store a group build, queueing jobs for the projects which can be built straight
+/// away.
+pub fn create(datastore: &DataStore, spec: &JobGroupSpec) -> Result<JobGroup> {
+    let root = spec.get_project();
+    let order = try!(graph::build_order(root,
+                                        |p| datastore.job_graph.rdeps(p),
+                                        |p| datastore.job_graph.deps(p)));
+    let mut group = JobGroup::new();
+    group.set_owner_id(spec.get_owner_id());
+    group.set_project(root.to_string());
+    group.set_state(JobGroupState::GroupQueued);
+    group.set_projects(RepeatedField::from_vec(plan(spec, order)));
+    try!(datastore.job_groups.write(&mut group));
+    try!(dispatch(datastore, &mut group));
+    Ok(group)
+}
+
+/// Record the outcome of a finished job of a group build and queue the projects it unblocked.
+/// Returns the group, if the job belongs to one.
+pub fn job_finished(datastore: &DataStore, job: &Job) -> Result<Option<JobGroup>> {
+    let mut group = match datastore.job_groups.find(&job.get_group_id()) {
+        Ok(group) => group,
+        Err(dbcache::Error::EntityNotFound) => return Ok(None),
+        Err(e) => return Err(Error::from(e)),
+    };
+    let state = match job.get_state() {
+        JobState::Complete => JobGroupProjectState::Success,
+        _ => JobGroupProjectState::Failure,
+    };
+    let found = match group.mut_projects()
+        .iter_mut()
+        .find(|p| p.get_name() == job.get_project() && p.get_job_id() == job.get_id()) {
+        Some(project) => {
+            project.set_state(state);
+            true
+        }
+        None => false,
+    };
+    if !found {
+        warn!("finished job not part of its group, job={}, group={}",
+              job.get_id(),
+              group.get_id());
+        return Ok(Some(group));
+    }
+    try!(dispatch(datastore, &mut group));
+    Ok(Some(group))
+}
+
+// The projects of a group build in the given build order, none of them started. The root is
+// left out, along with any dependency on it, if only its dependents are to be built.
+fn plan(spec: &JobGroupSpec, order: Vec<(String, Vec<String>)>) -> Vec<JobGroupProject> {
+    let root = spec.get_project();
+    let mut projects = Vec::with_capacity(order.len());
+    for (name, deps) in order {
+        if spec.get_dependents_only() && name == root {
+            continue;
+        }
+        let mut project = JobGroupProject::new();
+        project.set_name(name);
+        project.set_state(JobGroupProjectState::NotStarted);
+        let deps: Vec<String> = deps.into_iter()
+            .filter(|d| !spec.get_dependents_only() || d != root)
+            .collect();
+        project.set_deps(RepeatedField::from_vec(deps));
+        projects.push(project);
+    }
+    projects
+}
+
+// Queue jobs for the projects whose dependencies have all been built and store the group's
+// progress.
+fn dispatch(datastore: &DataStore, group: &mut JobGroup) -> Result<()> {
+    let mut jobs = vec![];
+    for i in ready(group) {
+        let mut job = Job::new();
+        job.set_state(JobState::default());
+        job.set_project(group.get_projects()[i].get_name().to_string());
+        job.set_group_id(group.get_id());
+        job.set_owner_id(group.get_owner_id());
+        try!(datastore.jobs.write(&mut job));
+        let project = &mut group.mut_projects()[i];
+        project.set_state(JobGroupProjectState::InProgress);
+        project.set_job_id(job.get_id());
+        jobs.push(job);
+    }
+    let state = group_state(group);
+    group.set_state(state);
+    try!(datastore.job_groups.update(group));
+    for job in jobs.iter() {
+        try!(datastore.job_queue.enqueue(job));
+    }
+    Ok(())
+}
+
+// Skip the projects which can no longer be built and return the positions of those not yet
+// started whose dependencies have all been built. Projects are in build order, so a project's
+// dependencies are always settled before it is looked at.
+fn ready(group: &mut JobGroup) -> Vec<usize> {
+    let mut ready = vec![];
+    for i in 0..group.get_projects().len() {
+        if group.get_projects()[i].get_state() != JobGroupProjectState::NotStarted {
+            continue;
+        }
+        let (blocked, waiting) = {
+            let projects = group.get_projects();
+            let states: Vec<JobGroupProjectState> = projects[i]
+                .get_deps()
+                .iter()
+                .filter_map(|d| projects.iter().find(|p| p.get_name() == d.as_str()))
+                .map(|p| p.get_state())
+                .collect();
+            (states.iter().any(|s| {
+                *s == JobGroupProjectState::Failure || *s == JobGroupProjectState::Skipped
+            }),
+             states.iter().any(|s| *s != JobGroupProjectState::Success))
+        };
+        if blocked {
+            group.mut_projects()[i].set_state(JobGroupProjectState::Skipped);
+        } else if !waiting {
+            ready.push(i);
+        }
+    }
+    ready
+}
+
+// The state of a group build given the states of its projects.
+fn group_state(group: &JobGroup) -> JobGroupState {
+    let succeeded = group.get_projects()
+        .iter()
+        .all(|p| p.get_state() == JobGroupProjectState::Success);
+    if !group.is_finished() {
+        JobGroupState::GroupDispatching
+    } else if succeeded {
+        JobGroupState::GroupComplete
+    } else {
+        JobGroupState::GroupFailed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use protobuf::RepeatedField;
+    use protocol::jobsrv::{JobGroup, JobGroupProjectState, JobGroupSpec, JobGroupState};
+
+    use super::{group_state, plan, ready};
+
+    fn order(projects: &[(&str, &[&str])]) -> Vec<(String, Vec<String>)> {
+        projects.iter()
+            .map(|&(name, deps)| (name.to_string(), deps.iter().map(|d| d.to_string()).collect()))
+            .collect()
+    }
+
+    // A group building core/a, core/b and core/c which depend on it, and core/d depending on
+    // both of those.
+    fn diamond(dependents_only: bool) -> JobGroup {
+        let mut spec = JobGroupSpec::new();
+        spec.set_project("core/a".to_string());
+        spec.set_dependents_only(dependents_only);
+        let order = order(&[("core/a", &[]),
+                            ("core/b", &["core/a"]),
+                            ("core/c", &["core/a"]),
+                            ("core/d", &["core/b", "core/c"])]);
+        let mut group = JobGroup::new();
+        group.set_projects(RepeatedField::from_vec(plan(&spec, order)));
+        group
+    }
+
+    fn finish(group: &mut JobGroup, i: usize, state: JobGroupProjectState) {
+        group.mut_projects()[i].set_state(state);
+    }
+
+    fn states(group: &JobGroup) -> Vec<JobGroupProjectState> {
+        group.get_projects().iter().map(|p| p.get_state()).collect()
+    }
+
+    #[test]
+    fn plan_leaves_out_the_root_for_dependents_only() {
+        let group = diamond(true);
+        let names: Vec<&str> = group.get_projects().iter().map(|p| p.get_name()).collect();
+        assert_eq!(names, vec!["core/b", "core/c", "core/d"]);
+        assert!(group.get_projects()[0].get_deps().is_empty());
+        assert_eq!(group.get_projects()[2].get_deps(),
+                   &["core/b".to_string(), "core/c".to_string()]);
+        assert!(states(&group).iter().all(|s| *s == JobGroupProjectState::NotStarted));
+    }
+
+    #[test]
+    fn projects_are_ready_once_their_deps_are_built() {
+        let mut group = diamond(false);
+        assert_eq!(ready(&mut group), vec![0]);
+        finish(&mut group, 0, JobGroupProjectState::Success);
+        assert_eq!(ready(&mut group), vec![1, 2]);
+        finish(&mut group, 1, JobGroupProjectState::Success);
+        finish(&mut group, 2, JobGroupProjectState::InProgress);
+        assert!(ready(&mut group).is_empty());
+        finish(&mut group, 2, JobGroupProjectState::Success);
+        assert_eq!(ready(&mut group), vec![3]);
+    }
+
+    #[test]
+    fn failed_deps_skip_their_dependents() {
+        let mut group = diamond(false);
+        finish(&mut group, 0, JobGroupProjectState::Success);
+        finish(&mut group, 1, JobGroupProjectState::Failure);
+        assert_eq!(ready(&mut group), vec![2]);
+        assert_eq!(states(&group)[3], JobGroupProjectState::Skipped);
+
+        let mut group = diamond(false);
+        finish(&mut group, 0, JobGroupProjectState::Failure);
+        assert!(ready(&mut group).is_empty());
+        assert_eq!(states(&group),
+                   vec![JobGroupProjectState::Failure,
+                        JobGroupProjectState::Skipped,
+                        JobGroupProjectState::Skipped,
+                        JobGroupProjectState::Skipped]);
+    }
+
+    #[test]
+    fn group_state_follows_its_projects() {
+        let mut group = diamond(true);
+        assert_eq!(group_state(&group), JobGroupState::GroupDispatching);
+        finish(&mut group, 0, JobGroupProjectState::Success);
+        finish(&mut group, 1, JobGroupProjectState::Success);
+        finish(&mut group, 2, JobGroupProjectState::InProgress);
+        assert_eq!(group_state(&group), JobGroupState::GroupDispatching);
+        finish(&mut group, 2, JobGroupProjectState::Success);
+        assert_eq!(group_state(&group), JobGroupState::GroupComplete);
+        finish(&mut group, 1, JobGroupProjectState::Failure);
+        finish(&mut group, 2, JobGroupProjectState::Skipped);
+        assert_eq!(group_state(&group), JobGroupState::GroupFailed);
+    }
+}
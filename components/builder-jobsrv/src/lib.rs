@@ -29,8 +29,11 @@ extern crate toml;
 extern crate zmq;
 
 pub mod config;
+pub mod cron;
 pub mod data_store;
 pub mod error;
+pub mod graph;
+pub mod group;
 pub mod server;
 
 pub use self::config::Config;
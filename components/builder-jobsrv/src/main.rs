@@ -14,9 +14,9 @@
 
 #[macro_use]
 extern crate clap;
-extern crate env_logger;
 extern crate habitat_builder_jobsrv as jobsrv;
 extern crate habitat_core as hab_core;
+extern crate habitat_net as hab_net;
 #[macro_use]
 extern crate log;
 
@@ -29,14 +29,14 @@ const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"))
 const CFG_DEFAULT_PATH: &'static str = "/hab/svc/hab-builder-jobsrv/config.toml";
 
 fn main() {
-    env_logger::init().unwrap();
+    hab_net::logging::init().unwrap();
     let matches = app().get_matches();
     debug!("CLI matches: {:?}", matches);
-    let config = match config_from_args(&matches) {
+    let (config, config_path) = match config_from_args(&matches) {
         Ok(result) => result,
         Err(e) => return exit_with(e, 1),
     };
-    match start(config) {
+    match start(config, &config_path) {
         Ok(_) => std::process::exit(0),
         Err(e) => exit_with(e, 1),
     }
@@ -56,12 +56,16 @@ fn app<'a, 'b>() -> clap::App<'a, 'b> {
     )
 }
 
-fn config_from_args(matches: &clap::ArgMatches) -> Result<Config> {
+// The configuration and the file it is reloaded from.
+fn config_from_args(matches: &clap::ArgMatches) -> Result<(Config, String)> {
     let cmd = matches.subcommand_name().unwrap();
     let args = matches.subcommand_matches(cmd).unwrap();
     let config = match args.value_of("config") {
-        Some(cfg_path) => try!(Config::from_file(cfg_path)),
-        None => Config::from_file(CFG_DEFAULT_PATH).unwrap_or(Config::default()),
+        Some(cfg_path) => (try!(Config::from_file(cfg_path)), cfg_path.to_string()),
+        None => {
+            (Config::from_file(CFG_DEFAULT_PATH).unwrap_or(Config::default()),
+             CFG_DEFAULT_PATH.to_string())
+        }
     };
     Ok(config)
 }
@@ -76,6 +80,6 @@ fn exit_with(err: Error, code: i32) {
 /// # Failures
 ///
 /// * Cannot bind to the port
-fn start(config: Config) -> Result<()> {
-    jobsrv::server::run(config)
+fn start(config: Config, config_path: &str) -> Result<()> {
+    jobsrv::server::run(config, config_path)
 }
@@ -12,27 +12,44 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
 use std::ops::Deref;
-use std::sync::{mpsc, Arc, RwLock};
+use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
-use std::thread::{self, JoinHandle};
+use std::thread;
 
 use dbcache::{self, InstaSet};
 use linked_hash_map::LinkedHashMap;
-use hab_net::server::{Application, Envelope, NetIdent, RouteConn, Service, Supervisor,
-                      Supervisable, ToAddrString};
-use protobuf::{parse_from_bytes, Message};
+use hab_net::clock::{self, Clock, SharedClock, SystemClock};
+use hab_net::concurrency::ConcurrencyLimits;
+use hab_net;
+use hab_net::debug;
+use hab_net::health::HealthCheck;
+use hab_net::runtime::{self, Runtime, Shutdown};
+use hab_net::server::{Application, Envelope, NetIdent, RouteConn, ServerContext, Service,
+                      Supervisor, Supervisable, ToAddrString};
+use protobuf::{parse_from_bytes, Message, RepeatedField};
 use protocol::net::{self, ErrCode};
 use protocol::jobsrv;
 use zmq;
 
 use config::Config;
-use data_store::DataStore;
+use cron::Cron;
+use data_store::{DataStore, MAX_PRIORITY};
 use error::{Error, Result};
+use group;
 
 const BE_LISTEN_ADDR: &'static str = "inproc://backend";
 const WORKER_MGR_ADDR: &'static str = "inproc://work-manager";
 const WORKER_TIMEOUT_MS: u64 = 33_000;
+// Most milliseconds between checks for expired job leases.
+const LEASE_CHECK_MS: i64 = 5_000;
+// Most milliseconds between checks for schedules which have come due.
+const SCHEDULE_CHECK_MS: i64 = 10_000;
+// Most chunks of a job's log returned in a single reply.
+const LOG_CHUNK_LIMIT: u64 = 1_000;
+// Milliseconds between samples of the job queue's depth published to the debug registry.
+const QUEUE_SAMPLE_MS: u64 = 5_000;
 
 pub struct Worker {
     config: Arc<RwLock<Config>>,
@@ -49,13 +66,27 @@ impl Worker {
     fn dispatch(&mut self, req: &mut Envelope) -> Result<()> {
         match req.message_id() {
             "JobCreate" => {
+                let msg: jobsrv::JobCreate = try!(req.parse_msg());
                 let mut job = jobsrv::Job::new();
+                job.set_owner_id(msg.get_owner_id());
                 job.set_state(jobsrv::JobState::default());
                 self.datastore().jobs.write(&mut job).unwrap();
                 self.datastore().job_queue.enqueue(&job).unwrap();
                 try!(self.notify_work_mgr());
                 try!(req.reply_complete(&mut self.sock, &job));
             }
+            "JobEnqueue" => {
+                let mut msg: jobsrv::JobEnqueue = try!(req.parse_msg());
+                let mut job = jobsrv::Job::new();
+                job.set_owner_id(msg.get_owner_id());
+                job.set_state(jobsrv::JobState::default());
+                job.set_priority(cmp::min(msg.get_priority(), MAX_PRIORITY));
+                job.set_requires(msg.take_requires());
+                try!(self.datastore().jobs.write(&mut job));
+                try!(self.datastore().job_queue.enqueue(&job));
+                try!(self.notify_work_mgr());
+                try!(req.reply_complete(&mut self.sock, &job));
+            }
             "JobGet" => {
                 let msg: jobsrv::JobGet = try!(req.parse_msg());
                 match self.datastore().jobs.find(&msg.get_id()) {
@@ -74,6 +105,116 @@ impl Worker {
                     }
                 }
             }
+            "JobLogGet" => {
+                let msg: jobsrv::JobLogGet = try!(req.parse_msg());
+                let attempt = if msg.has_attempt() {
+                    msg.get_attempt()
+                } else {
+                    match self.datastore().jobs.find(&msg.get_job_id()) {
+                        Ok(job) => job.get_attempts(),
+                        Err(dbcache::Error::EntityNotFound) => {
+                            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "jb:job-log-get:1");
+                            try!(req.reply_complete(&mut self.sock, &err));
+                            return Ok(());
+                        }
+                        Err(e) => {
+                            error!("datastore error, err={:?}", e);
+                            let err = net::err(ErrCode::INTERNAL, "jb:job-log-get:2");
+                            try!(req.reply_complete(&mut self.sock, &err));
+                            return Ok(());
+                        }
+                    }
+                };
+                let result = self.datastore()
+                    .job_logs
+                    .read(msg.get_job_id(), attempt, msg.get_start(), LOG_CHUNK_LIMIT);
+                match result {
+                    Ok(log) => try!(req.reply_complete(&mut self.sock, &log)),
+                    Err(e) => {
+                        error!("datastore error, err={:?}", e);
+                        let err = net::err(ErrCode::INTERNAL, "jb:job-log-get:0");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                    }
+                }
+            }
+            "JobScheduleCreate" => {
+                let msg: jobsrv::JobScheduleCreate = try!(req.parse_msg());
+                let next_run = match msg.get_cron().parse::<Cron>() {
+                    Ok(ref cron) if !msg.get_project().is_empty() => {
+                        cron.next_after(SystemClock.now_ms())
+                    }
+                    _ => None,
+                };
+                let next_run = match next_run {
+                    Some(next_run) => next_run,
+                    None => {
+                        let err = net::err(ErrCode::REMOTE_REJECTED, "jb:job-schedule-create:0");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                        return Ok(());
+                    }
+                };
+                match save_schedule(self.datastore(), &msg, next_run) {
+                    Ok(schedule) => try!(req.reply_complete(&mut self.sock, &schedule)),
+                    Err(e) => {
+                        error!("datastore error, err={:?}", e);
+                        let err = net::err(ErrCode::INTERNAL, "jb:job-schedule-create:1");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                    }
+                }
+            }
+            "JobScheduleListRequest" => {
+                let msg: jobsrv::JobScheduleListRequest = try!(req.parse_msg());
+                match self.datastore().job_schedules.get_by_owner(msg.get_owner_id()) {
+                    Ok(schedules) => {
+                        let mut reply = jobsrv::JobScheduleListResponse::new();
+                        reply.set_owner_id(msg.get_owner_id());
+                        reply.set_schedules(RepeatedField::from_vec(schedules));
+                        try!(req.reply_complete(&mut self.sock, &reply));
+                    }
+                    Err(e) => {
+                        error!("datastore error, err={:?}", e);
+                        let err = net::err(ErrCode::INTERNAL, "jb:job-schedule-list:0");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                    }
+                }
+            }
+            "JobGroupSpec" => {
+                let msg: jobsrv::JobGroupSpec = try!(req.parse_msg());
+                if msg.get_project().is_empty() {
+                    let err = net::err(ErrCode::REMOTE_REJECTED, "jb:job-group-create:0");
+                    try!(req.reply_complete(&mut self.sock, &err));
+                    return Ok(());
+                }
+                let group = try!(group::create(self.datastore(), &msg));
+                try!(self.notify_work_mgr());
+                try!(req.reply_complete(&mut self.sock, &group));
+            }
+            "JobGroupGet" => {
+                let msg: jobsrv::JobGroupGet = try!(req.parse_msg());
+                match self.datastore().job_groups.find(&msg.get_id()) {
+                    Ok(group) => try!(req.reply_complete(&mut self.sock, &group)),
+                    Err(dbcache::Error::EntityNotFound) => {
+                        let err = net::err(ErrCode::ENTITY_NOT_FOUND, "jb:job-group-get:1");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                    }
+                    Err(e) => {
+                        error!("datastore error, err={:?}", e);
+                        let err = net::err(ErrCode::INTERNAL, "jb:job-group-get:2");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                    }
+                }
+            }
+            "JobGraphPackage" => {
+                let msg: jobsrv::JobGraphPackage = try!(req.parse_msg());
+                match self.datastore().job_graph.add(msg.get_project(), msg.get_deps()) {
+                    Ok(()) => try!(req.reply_complete(&mut self.sock, &msg)),
+                    Err(e) => {
+                        error!("datastore error, err={:?}", e);
+                        let err = net::err(ErrCode::INTERNAL, "jb:job-graph-package:0");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                    }
+                }
+            }
             _ => panic!("unexpected message: {:?}", req.message_id()),
         }
         Ok(())
@@ -104,6 +245,12 @@ impl Worker {
     }
 }
 
+impl HealthCheck for Worker {
+    fn health_checks(&mut self) -> Vec<net::HealthCheckResult> {
+        vec![dbcache::health_check(self.datastore.as_ref().map(|d| &*d.pool))]
+    }
+}
+
 impl Supervisable for Worker {
     type Config = Config;
     type Error = Error;
@@ -149,31 +296,58 @@ pub struct Server {
     ctx: Arc<RwLock<zmq::Context>>,
     router: RouteConn,
     be_sock: zmq::Socket,
+    config_path: Option<String>,
+    clock: SharedClock,
 }
 
 impl Server {
     pub fn new(config: Config) -> Result<Self> {
-        let mut ctx = zmq::Context::new();
-        let router = try!(RouteConn::new(Self::net_ident(), &mut ctx));
+        let ctx = try!(ServerContext::with_config(&config));
+        let router = try!(RouteConn::new(Self::net_ident(), &mut ctx.zmq().write().unwrap()));
         let be = try!(ctx.socket(zmq::DEALER));
         Ok(Server {
             config: Arc::new(RwLock::new(config)),
-            ctx: Arc::new(RwLock::new(ctx)),
+            ctx: ctx.zmq(),
             router: router,
             be_sock: be,
+            config_path: None,
+            clock: Arc::new(SystemClock),
         })
     }
 
+    /// Use the given clock for job leases and schedules instead of the system's.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
+    /// Re-read the configuration from the given file, and roll the workers onto it, when the
+    /// process receives `SIGHUP`.
+    pub fn reload_from(&mut self, path: &str) {
+        self.config_path = Some(path.to_string());
+    }
+
     pub fn reconfigure(&self, config: Config) -> Result<()> {
-        {
-            let mut cfg = self.config.write().unwrap();
-            *cfg = config;
-        }
+        try!(hab_net::server::reconfigure(&self.config, &self.ctx, BE_LISTEN_ADDR, config));
         // * disconnect from removed routers
         // * notify remaining routers of any shard hosting changes
         // * connect to new shard servers
         Ok(())
     }
+
+    // A runtime which shuts the server down on `SIGTERM` or `SIGINT` and reloads its configuration
+    // file on `SIGHUP`.
+    fn runtime(&self) -> Runtime {
+        let rt = Runtime::new().trap_signals();
+        match self.config_path {
+            Some(ref path) => {
+                let path = path.clone();
+                let cfg = self.config.clone();
+                let ctx = self.ctx.clone();
+                rt.on_reload(move || hab_net::server::reload(&path, &cfg, &ctx, BE_LISTEN_ADDR))
+            }
+            None => rt,
+        }
+    }
 }
 
 impl Application for Server {
@@ -185,15 +359,26 @@ impl Application for Server {
         let cfg2 = self.config.clone();
         let ctx1 = self.ctx.clone();
         let ctx2 = self.ctx.clone();
-        let sup: Supervisor<Worker> = Supervisor::new(ctx1, cfg1);
-        let work_mgr = try!(WorkerManager::start(ctx2, cfg2));
-        {
+        let clock = self.clock.clone();
+        let (limits, sched) = {
             let cfg = self.config.read().unwrap();
-            try!(sup.start(BE_LISTEN_ADDR, cfg.worker_threads));
-        }
+            (ConcurrencyLimits::new(cfg.concurrency_limits.clone(), cfg.concurrency_wait),
+             cfg.worker_sched.clone())
+        };
+        let sup: Supervisor<Worker> =
+            Supervisor::new(ctx1, cfg1).with_limits(limits).with_sched(sched);
+        let sup = {
+            let cfg = self.config.read().unwrap();
+            try!(sup.spawn(BE_LISTEN_ADDR, cfg.worker_threads))
+        };
+        let rt = try!(self.runtime()
+            .with(sup)
+            .spawn("worker-manager",
+                   move |shutdown| WorkerManager::serve(ctx2, cfg2, clock, shutdown)));
         try!(self.connect());
-        try!(zmq::proxy(&mut self.router.socket, &mut self.be_sock));
-        work_mgr.join().unwrap();
+        let router = &mut self.router;
+        let be_sock = &mut self.be_sock;
+        try!(rt.run_with(|shutdown| runtime::serve(router, be_sock, shutdown)));
         Ok(())
     }
 }
@@ -222,31 +407,50 @@ impl Service for Server {
 
 impl NetIdent for Server {}
 
+// A worker waiting for a job.
+struct ReadyWorker {
+    expiry: Instant,
+    capabilities: Vec<String>,
+}
+
 struct WorkerManager {
     config: Arc<RwLock<Config>>,
     #[allow(dead_code)]
     ctx: Arc<RwLock<zmq::Context>>,
     datastore: DataStore,
+    ev_sock: zmq::Socket,
     hb_sock: zmq::Socket,
     rq_sock: zmq::Socket,
     work_mgr_sock: zmq::Socket,
     msg: zmq::Message,
-    workers: LinkedHashMap<String, Instant>,
+    workers: LinkedHashMap<String, ReadyWorker>,
+    queue_sampled: Option<Instant>,
+    clock: SharedClock,
+    // When job leases were last checked for expiry, in milliseconds since the Unix epoch.
+    leases_checked_ms: Option<i64>,
+    // When schedules were last checked for ones which came due, in milliseconds since the Unix
+    // epoch.
+    schedules_checked_ms: Option<i64>,
 }
 
 impl WorkerManager {
-    pub fn new(ctx: Arc<RwLock<zmq::Context>>, config: Arc<RwLock<Config>>) -> Result<Self> {
+    pub fn new(ctx: Arc<RwLock<zmq::Context>>,
+               config: Arc<RwLock<Config>>,
+               clock: SharedClock)
+               -> Result<Self> {
         let datastore = {
             let cfg = config.read().unwrap();
             try!(DataStore::open(cfg.deref()))
         };
-        let (hb_sock, rq_sock, work_mgr_sock) = {
+        let (ev_sock, hb_sock, rq_sock, work_mgr_sock) = {
             let mut ctx = ctx.write().unwrap();
+            let ev_sock = try!(ctx.socket(zmq::PUB));
             let hb_sock = try!(ctx.socket(zmq::SUB));
             let rq_sock = try!(ctx.socket(zmq::ROUTER));
             let work_mgr_sock = try!(ctx.socket(zmq::DEALER));
-            (hb_sock, rq_sock, work_mgr_sock)
+            (ev_sock, hb_sock, rq_sock, work_mgr_sock)
         };
+        try!(ev_sock.set_linger(0));
         try!(rq_sock.set_router_mandatory(true));
         try!(hb_sock.set_subscribe(&[]));
         try!(work_mgr_sock.set_rcvhwm(1));
@@ -257,32 +461,30 @@ impl WorkerManager {
             config: config,
             ctx: ctx,
             datastore: datastore,
+            ev_sock: ev_sock,
             hb_sock: hb_sock,
             rq_sock: rq_sock,
             work_mgr_sock: work_mgr_sock,
             msg: msg,
             workers: LinkedHashMap::new(),
+            queue_sampled: None,
+            clock: clock,
+            leases_checked_ms: None,
+            schedules_checked_ms: None,
         })
     }
 
-    pub fn start(ctx: Arc<RwLock<zmq::Context>>,
-                 config: Arc<RwLock<Config>>)
-                 -> Result<JoinHandle<()>> {
-        let (tx, rx) = mpsc::sync_channel(1);
-        let handle = thread::Builder::new()
-            .name("worker-manager".to_string())
-            .spawn(move || {
-                let mut manager = Self::new(ctx, config).unwrap();
-                manager.run(tx).unwrap();
-            })
-            .unwrap();
-        match rx.recv() {
-            Ok(()) => Ok(handle),
-            Err(e) => panic!("worker-manager thread startup error, err={}", e),
-        }
+    /// Run a manager until shutdown is requested. Shutdown is noticed within `LEASE_CHECK_MS`.
+    pub fn serve(ctx: Arc<RwLock<zmq::Context>>,
+                 config: Arc<RwLock<Config>>,
+                 clock: SharedClock,
+                 shutdown: Shutdown)
+                 -> Result<()> {
+        let mut manager = try!(Self::new(ctx, config, clock));
+        manager.run(&shutdown)
     }
 
-    fn run(&mut self, rz: mpsc::SyncSender<()>) -> Result<()> {
+    fn run(&mut self, shutdown: &Shutdown) -> Result<()> {
         try!(self.work_mgr_sock.bind(WORKER_MGR_ADDR));
         {
             let cfg = self.config.read().unwrap();
@@ -292,12 +494,18 @@ impl WorkerManager {
             println!("Listening for heartbeats on {}",
                      cfg.worker_heartbeat_addr.to_addr_string());
             try!(self.hb_sock.bind(&cfg.worker_heartbeat_addr.to_addr_string()));
+            println!("Publishing job events on {}",
+                     cfg.job_events_addr.to_addr_string());
+            try!(self.ev_sock.bind(&cfg.job_events_addr.to_addr_string()));
+            debug::set("jobsrv", "commands", cfg.worker_command_addr.to_addr_string());
+            debug::set("jobsrv", "heartbeats", cfg.worker_heartbeat_addr.to_addr_string());
+            debug::set("jobsrv", "job_events", cfg.job_events_addr.to_addr_string());
         }
         let mut hb_sock = false;
         let mut rq_sock = false;
         let mut work_mgr_sock = false;
-        rz.send(()).unwrap();
-        loop {
+        self.publish_state();
+        while !shutdown.is_requested() {
             {
                 let timeout = self.poll_timeout();
                 let mut items = [self.hb_sock.as_poll_item(1),
@@ -323,107 +531,546 @@ impl WorkerManager {
             }
             self.expire_workers();
             if rq_sock {
-                try!(self.process_job_status());
+                try!(self.process_worker_msg());
                 rq_sock = false;
             }
             if work_mgr_sock {
+                // Consume the to-do work notification; the queue itself is checked below.
+                try!(self.work_mgr_sock.recv(&mut self.msg, 0));
+                work_mgr_sock = false;
+            }
+            try!(self.expire_leases());
+            if let Err(e) = self.run_schedules() {
+                error!("unable to check job schedules, err={:?}", e);
+            }
+            if !self.workers.is_empty() {
                 try!(self.distribute_work());
             }
+            self.publish_state();
         }
         Ok(())
     }
 
+    // Publish the manager's state to the debug registry. The queue's depth takes a round trip to
+    // the datastore so it is only sampled every `QUEUE_SAMPLE_MS`.
+    fn publish_state(&mut self) {
+        debug::set("jobsrv", "workers.ready", self.workers.len());
+        let now = Instant::now();
+        if let Some(sampled) = self.queue_sampled {
+            if now.duration_since(sampled) < Duration::from_millis(QUEUE_SAMPLE_MS) {
+                return;
+            }
+        }
+        self.queue_sampled = Some(now);
+        match self.datastore.job_queue.len() {
+            Ok(depth) => debug::set("jobsrv", "job_queue.depth", depth),
+            Err(e) => debug::set("jobsrv", "job_queue.depth", format!("unknown, err={}", e)),
+        }
+    }
+
     fn poll_timeout(&self) -> i64 {
-        if let Some((_, expiry)) = self.workers.front() {
-            let timeout = *expiry - Instant::now();
-            (timeout.as_secs() as i64 * 1000) + (timeout.subsec_nanos() as i64 / 1000 / 1000)
+        if let Some((_, worker)) = self.workers.front() {
+            let timeout = worker.expiry - Instant::now();
+            let ms = (timeout.as_secs() as i64 * 1000) +
+                     (timeout.subsec_nanos() as i64 / 1000 / 1000);
+            cmp::min(ms, LEASE_CHECK_MS)
         } else {
-            -1
+            LEASE_CHECK_MS
         }
     }
 
+    // Hand each ready worker the highest priority queued job it is capable of running.
     fn distribute_work(&mut self) -> Result<()> {
-        loop {
-            let job = match self.datastore.job_queue.peek() {
-                Ok(Some(job)) => job,
-                Ok(None) => break,
-                Err(e) => return Err(e),
+        let ready: Vec<String> = self.workers.keys().cloned().collect();
+        for worker in ready {
+            let capabilities = match self.workers.get(&worker) {
+                Some(ready) => ready.capabilities.clone(),
+                None => continue,
             };
-            match self.workers.pop_front() {
-                Some((worker, _)) => {
-                    debug!("sending work, worker={:?}, job={:?}", worker, job);
-                    if self.rq_sock.send_str(&worker, zmq::SNDMORE).is_err() {
-                        debug!("failed to send, worker went away, worker={:?}", worker);
-                        continue;
-                    }
-                    if self.rq_sock.send(&[], zmq::SNDMORE).is_err() {
-                        debug!("failed to send, worker went away, worker={:?}", worker);
-                        continue;
-                    }
-                    if self.rq_sock.send(&job.write_to_bytes().unwrap(), 0).is_err() {
-                        debug!("failed to send, worker went away, worker={:?}", worker);
-                        continue;
-                    }
-                    // JW TODO: Wait for response back to ensure we can dequeue this. If state
-                    // returned is not processing then we move onto next worker and assume this
-                    // worker is no longer valid. Put work back on queue.
-                    try!(self.datastore.job_queue.dequeue());
-                    // Consume the to-do work notification if the queue is empty.
-                    if try!(self.datastore.job_queue.peek()).is_none() {
-                        try!(self.work_mgr_sock.recv(&mut self.msg, 0));
-                    }
-                    break;
-                }
-                None => break,
+            let mut job = match try!(self.datastore.job_queue.next_for(&capabilities)) {
+                Some(job) => job,
+                None => continue,
+            };
+            if !try!(self.datastore.job_queue.remove(&job)) {
+                continue;
+            }
+            self.workers.remove(&worker);
+            // The worker is sent the job as it will be saved, so it knows which attempt it runs.
+            let queued = job.clone();
+            let attempts = job.get_attempts() + 1;
+            job.set_worker(worker.clone());
+            job.set_attempts(attempts);
+            debug!("sending work, worker={:?}, job={:?}", worker, job);
+            if let Err(e) = self.send_job(&worker, &job) {
+                debug!("failed to send, worker went away, worker={:?}, err={}", worker, e);
+                try!(self.datastore.job_queue.requeue(&queued));
+                continue;
+            }
+            try!(self.save_job(&job));
+            try!(self.renew_lease(job.get_id()));
+        }
+        Ok(())
+    }
+
+    fn send_job(&mut self, worker: &str, job: &jobsrv::Job) -> Result<()> {
+        try!(self.rq_sock.send_str(worker, zmq::SNDMORE));
+        try!(self.rq_sock.send(&[], zmq::SNDMORE));
+        try!(self.rq_sock.send(&try!(job.write_to_bytes()), 0));
+        Ok(())
+    }
+
+    // Persist a change to a job and publish it to event subscribers as a `["job"][Job]`
+    // multipart message.
+    fn save_job(&mut self, job: &jobsrv::Job) -> Result<()> {
+        try!(self.datastore.jobs.update(job));
+        try!(self.ev_sock.send_str(jobsrv::JOB_EVENT_TOPIC, zmq::SNDMORE));
+        try!(self.ev_sock.send(&try!(job.write_to_bytes()), 0));
+        match job.get_state() {
+            jobsrv::JobState::Complete |
+            jobsrv::JobState::Failed => self.job_finished(job),
+            _ => Ok(()),
+        }
+    }
+
+    // Move along the group build a finished job is part of, or start one rebuilding whatever
+    // depends on the project a job outside of any group built.
+    fn job_finished(&mut self, job: &jobsrv::Job) -> Result<()> {
+        if job.has_group_id() {
+            if let Some(group) = try!(group::job_finished(&self.datastore, job)) {
+                debug!("group build progressed, group={}, state={:?}",
+                       group.get_id(),
+                       group.get_state());
             }
+            return Ok(());
+        }
+        if !self.config.read().unwrap().auto_group_builds || !job.has_project() ||
+           job.get_state() != jobsrv::JobState::Complete {
+            return Ok(());
+        }
+        if try!(self.datastore.job_graph.rdeps(job.get_project())).is_empty() {
+            return Ok(());
         }
+        let mut spec = jobsrv::JobGroupSpec::new();
+        spec.set_owner_id(job.get_owner_id());
+        spec.set_project(job.get_project().to_string());
+        spec.set_dependents_only(true);
+        let group = try!(group::create(&self.datastore, &spec));
+        info!("rebuilding dependents, job={}, project={}, group={}",
+              job.get_id(),
+              job.get_project(),
+              group.get_id());
         Ok(())
     }
 
+    fn renew_lease(&self, job_id: u64) -> Result<()> {
+        let lease_ms = clock::duration_ms(self.config.read().unwrap().job_lease);
+        self.datastore.job_leases.renew(job_id, self.clock.now_ms() + lease_ms)
+    }
+
+    // Put the jobs of workers which stopped renewing their leases back on the queue, or fail
+    // them if they have been attempted too many times. Leases are checked at most every
+    // `LEASE_CHECK_MS`, however busy the manager is.
+    fn expire_leases(&mut self) -> Result<()> {
+        let now = self.clock.now_ms();
+        if !interval_elapsed(self.leases_checked_ms, now, LEASE_CHECK_MS) {
+            return Ok(());
+        }
+        self.leases_checked_ms = Some(now);
+        let max_attempts = self.config.read().unwrap().job_max_attempts as u32;
+        for id in try!(self.datastore.job_leases.expired(now)) {
+            try!(self.datastore.job_leases.release(id));
+            let mut job = match self.datastore.jobs.find(&id) {
+                Ok(job) => job,
+                Err(dbcache::Error::EntityNotFound) => continue,
+                Err(e) => return Err(Error::from(e)),
+            };
+            match job.get_state() {
+                jobsrv::JobState::Pending |
+                jobsrv::JobState::Processing => (),
+                _ => continue,
+            }
+            let worker = job.get_worker().to_string();
+            if lease_expired(&mut job, max_attempts) {
+                info!("requeueing job, lease expired, job={}, worker={}", id, worker);
+                try!(self.save_job(&job));
+                try!(self.datastore.job_queue.requeue(&job));
+            } else {
+                warn!("failing job, lease expired after {} attempts, job={}, worker={}",
+                      job.get_attempts(),
+                      id,
+                      worker);
+                try!(self.save_job(&job));
+            }
+        }
+        Ok(())
+    }
+
+    // Queue a job for each schedule which has come due and move the schedule on to its next
+    // run. Runs missed while no JobSrv was running are made up for with a single job. Schedules
+    // are checked at most every `SCHEDULE_CHECK_MS`, and one which can't be run is logged and
+    // left for the next check rather than holding up the others.
+    fn run_schedules(&mut self) -> Result<()> {
+        let now = self.clock.now_ms();
+        if !interval_elapsed(self.schedules_checked_ms, now, SCHEDULE_CHECK_MS) {
+            return Ok(());
+        }
+        self.schedules_checked_ms = Some(now);
+        for (id, due_ms) in try!(self.datastore.job_schedules.due(now)) {
+            if let Err(e) = self.run_schedule(id, due_ms, now) {
+                error!("unable to run job schedule, schedule={}, err={:?}", id, e);
+            }
+        }
+        Ok(())
+    }
+
+    fn run_schedule(&mut self, id: u64, due_ms: i64, now: i64) -> Result<()> {
+        let schedule = match self.datastore.job_schedules.find(&id) {
+            Ok(schedule) => schedule,
+            Err(dbcache::Error::EntityNotFound) => return Ok(()),
+            Err(e) => return Err(Error::from(e)),
+        };
+        let next_run = schedule.get_cron()
+            .parse::<Cron>()
+            .ok()
+            .and_then(|cron| cron.next_after(now));
+        if !try!(self.datastore.job_schedules.claim(id, due_ms, next_run)) {
+            return Ok(());
+        }
+        let mut job = jobsrv::Job::new();
+        job.set_state(jobsrv::JobState::default());
+        job.set_priority(schedule.get_priority());
+        job.set_requires(RepeatedField::from_vec(schedule.get_requires().to_vec()));
+        job.set_schedule_id(id);
+        job.set_project(schedule.get_project().to_string());
+        job.set_owner_id(schedule.get_owner_id());
+        try!(self.datastore.jobs.write(&mut job));
+        try!(self.datastore.job_queue.enqueue(&job));
+        info!("queued scheduled job, job={}, schedule={}, project={}",
+              job.get_id(),
+              id,
+              schedule.get_project());
+        self.datastore.job_schedules.ran(id, due_ms, job.get_id(), next_run)
+    }
+
     fn expire_workers(&mut self) {
         let now = Instant::now();
         loop {
-            if let Some((_, expiry)) = self.workers.front() {
-                if expiry >= &now {
+            if let Some((_, worker)) = self.workers.front() {
+                if worker.expiry >= now {
                     break;
                 }
             } else {
                 break;
             }
-            let worker = self.workers.pop_front();
+            let worker = self.workers.pop_front().map(|(endpoint, _)| endpoint);
             debug!("expiring worker due to inactivity, worker={:?}", worker);
         }
     }
 
     fn process_heartbeat(&mut self) -> Result<()> {
         try!(self.hb_sock.recv(&mut self.msg, 0));
-        let heartbeat: jobsrv::Heartbeat = try!(parse_from_bytes(&self.msg));
+        let mut heartbeat: jobsrv::Heartbeat = try!(parse_from_bytes(&self.msg));
         debug!("heartbeat={:?}", heartbeat);
         match heartbeat.get_state() {
             jobsrv::WorkerState::Ready => {
                 let now = Instant::now();
-                let expiry = now + Duration::from_millis(WORKER_TIMEOUT_MS);
-                self.workers.insert(heartbeat.get_endpoint().to_string(), expiry);
+                let worker = ReadyWorker {
+                    expiry: now + Duration::from_millis(WORKER_TIMEOUT_MS),
+                    capabilities: heartbeat.take_capabilities().into_vec(),
+                };
+                self.workers.insert(heartbeat.get_endpoint().to_string(), worker);
             }
             jobsrv::WorkerState::Busy => {
                 self.workers.remove(heartbeat.get_endpoint());
+                if heartbeat.has_job_id() {
+                    try!(self.renew_held_lease(&heartbeat));
+                }
             }
         }
         Ok(())
     }
 
-    fn process_job_status(&mut self) -> Result<()> {
-        // Pop message delimiter
+    // Renew the lease on the job a busy worker is running, so long as the job is still the
+    // worker's. Its lease may have expired and the job been handed to another worker.
+    fn renew_held_lease(&mut self, heartbeat: &jobsrv::Heartbeat) -> Result<()> {
+        match self.datastore.jobs.find(&heartbeat.get_job_id()) {
+            Ok(ref job) if job.get_worker() == heartbeat.get_endpoint() &&
+                           job.get_state() == jobsrv::JobState::Processing => {
+                self.renew_lease(job.get_id())
+            }
+            Ok(_) |
+            Err(dbcache::Error::EntityNotFound) => Ok(()),
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
+    fn process_worker_msg(&mut self) -> Result<()> {
+        // Pop the identity of the worker which sent the message
+        try!(self.rq_sock.recv(&mut self.msg, 0));
+        let worker = self.msg.as_str().unwrap_or("").to_string();
+        // Pop message id
         try!(self.rq_sock.recv(&mut self.msg, 0));
+        let message_id = self.msg.as_str().unwrap_or("").to_string();
         // Pop message body
         try!(self.rq_sock.recv(&mut self.msg, 0));
-        let job: jobsrv::Job = try!(parse_from_bytes(&self.msg));
-        debug!("job_status={:?}", job);
-        try!(self.datastore.jobs.update(&job));
+        match message_id.as_str() {
+            "Job" => {
+                let status: jobsrv::Job = try!(parse_from_bytes(&self.msg));
+                self.process_job_status(&worker, status)
+            }
+            "JobLogChunk" => {
+                let chunk: jobsrv::JobLogChunk = try!(parse_from_bytes(&self.msg));
+                self.process_log_chunk(&worker, chunk)
+            }
+            _ => {
+                warn!("unexpected message from worker, message_id={:?}, worker={}",
+                      message_id,
+                      worker);
+                Ok(())
+            }
+        }
+    }
+
+    // Store a chunk of a job's output, so long as it's from the worker running the job's latest
+    // attempt. Chunks from workers which don't say which attempt they run are taken to be from
+    // the latest.
+    fn process_log_chunk(&mut self, worker: &str, chunk: jobsrv::JobLogChunk) -> Result<()> {
+        match self.datastore.jobs.find(&chunk.get_job_id()) {
+            Ok(ref job) if job.get_worker() == worker &&
+                           (!chunk.has_attempt() || chunk.get_attempt() == job.get_attempts()) => {
+                let ttl = self.config.read().unwrap().job_log_ttl.as_secs() as usize;
+                self.datastore.job_logs.append(&chunk, job.get_attempts(), ttl)
+            }
+            Ok(_) => {
+                debug!("ignoring log from worker no longer holding job, job={}, worker={}",
+                       chunk.get_job_id(),
+                       worker);
+                Ok(())
+            }
+            Err(dbcache::Error::EntityNotFound) => {
+                warn!("log for unknown job, job={}, worker={}", chunk.get_job_id(), worker);
+                Ok(())
+            }
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
+    fn process_job_status(&mut self, worker: &str, mut status: jobsrv::Job) -> Result<()> {
+        debug!("job_status={:?}, worker={}", status, worker);
+        let mut job = match self.datastore.jobs.find(&status.get_id()) {
+            Ok(job) => job,
+            Err(dbcache::Error::EntityNotFound) => {
+                warn!("status for unknown job, job={}, worker={}", status.get_id(), worker);
+                return Ok(());
+            }
+            Err(e) => return Err(Error::from(e)),
+        };
+        if job.get_worker() != worker {
+            debug!("ignoring status from worker no longer holding job, job={}, worker={}",
+                   job.get_id(),
+                   worker);
+            return Ok(());
+        }
+        match status.get_state() {
+            jobsrv::JobState::Rejected => {
+                job.set_state(jobsrv::JobState::Pending);
+                job.clear_worker();
+                try!(self.datastore.job_leases.release(job.get_id()));
+                try!(self.save_job(&job));
+                try!(self.datastore.job_queue.requeue(&job));
+            }
+            jobsrv::JobState::Processing => {
+                job.set_state(jobsrv::JobState::Processing);
+                try!(self.save_job(&job));
+                try!(self.renew_lease(job.get_id()));
+            }
+            state => {
+                job.set_state(state);
+                if state == jobsrv::JobState::Complete && status.has_package_ident() {
+                    job.set_package_ident(status.take_package_ident());
+                }
+                try!(self.datastore.job_leases.release(job.get_id()));
+                try!(self.save_job(&job));
+            }
+        }
         Ok(())
     }
 }
 
-pub fn run(config: Config) -> Result<()> {
-    try!(Server::new(config)).run()
+// Create the owner's schedule for a project, coming due next at `next_run`, or replace the one it
+// has. A schedule created for the project at the same time is replaced rather than duplicated.
+fn save_schedule(datastore: &DataStore,
+                 msg: &jobsrv::JobScheduleCreate,
+                 next_run: i64)
+                 -> Result<jobsrv::JobSchedule> {
+    loop {
+        let existing = match datastore.job_schedules
+            .find_by_project(msg.get_owner_id(), msg.get_project()) {
+            Ok(schedule) => Some(schedule),
+            Err(dbcache::Error::EntityNotFound) => None,
+            Err(e) => return Err(Error::from(e)),
+        };
+        let mut schedule = existing.clone().unwrap_or(jobsrv::JobSchedule::new());
+        schedule.set_owner_id(msg.get_owner_id());
+        schedule.set_project(msg.get_project().to_string());
+        schedule.set_cron(msg.get_cron().to_string());
+        schedule.set_priority(cmp::min(msg.get_priority(), MAX_PRIORITY));
+        schedule.set_requires(RepeatedField::from_vec(msg.get_requires().to_vec()));
+        schedule.set_next_run_ms(next_run);
+        if existing.is_some() {
+            try!(datastore.job_schedules.replace(&schedule));
+            return Ok(schedule);
+        }
+        match datastore.job_schedules.write(&mut schedule) {
+            Ok(()) => return Ok(schedule),
+            Err(dbcache::Error::EntityConflict) => continue,
+            Err(e) => return Err(Error::from(e)),
+        }
+    }
+}
+
+// Whether at least `interval_ms` has passed since something was last done, if it ever was.
+fn interval_elapsed(last_ms: Option<i64>, now_ms: i64, interval_ms: i64) -> bool {
+    match last_ms {
+        Some(last_ms) => now_ms - last_ms >= interval_ms,
+        None => true,
+    }
+}
+
+// Move a job whose lease expired back to pending, so it can be requeued, unless it has been
+// attempted `max_attempts` times already, in which case it fails. Returns whether it should be
+// requeued.
+fn lease_expired(job: &mut jobsrv::Job, max_attempts: u32) -> bool {
+    if job.get_attempts() >= max_attempts {
+        job.set_state(jobsrv::JobState::Failed);
+        false
+    } else {
+        job.set_state(jobsrv::JobState::Pending);
+        job.clear_worker();
+        true
+    }
+}
+
+pub fn run(config: Config, config_path: &str) -> Result<()> {
+    let mut server = try!(Server::new(config));
+    server.reload_from(config_path);
+    server.run()
+}
+
+#[cfg(test)]
+mod tests {
+    use protocol::jobsrv::{Job, JobState};
+
+    use super::{interval_elapsed, lease_expired};
+
+    fn processing(attempts: u32) -> Job {
+        let mut job = Job::new();
+        job.set_state(JobState::Processing);
+        job.set_worker("worker-1".to_string());
+        job.set_attempts(attempts);
+        job
+    }
+
+    #[test]
+    fn expired_lease_requeues_job() {
+        let mut job = processing(1);
+        assert!(lease_expired(&mut job, 3));
+        assert_eq!(job.get_state(), JobState::Pending);
+        assert!(!job.has_worker());
+    }
+
+    #[test]
+    fn expired_lease_fails_job_out_of_attempts() {
+        let mut job = processing(3);
+        assert!(!lease_expired(&mut job, 3));
+        assert_eq!(job.get_state(), JobState::Failed);
+        assert_eq!(job.get_worker(), "worker-1");
+    }
+
+    #[test]
+    fn interval_elapsed_rate_limits() {
+        assert!(interval_elapsed(None, 0, 5_000));
+        assert!(!interval_elapsed(Some(1_000), 5_999, 5_000));
+        assert!(interval_elapsed(Some(1_000), 6_000, 5_000));
+    }
+}
+
+#[cfg(all(test, feature = "functional"))]
+mod test {
+    use std::sync::{Arc, RwLock};
+
+    use dbcache::InstaSet;
+    use hab_net::clock::{duration_ms, Clock, ManualClock, SystemClock};
+    use protocol::jobsrv::{Job, JobSchedule, JobState};
+    use zmq;
+
+    use config::Config;
+    use super::{WorkerManager, SCHEDULE_CHECK_MS};
+
+    /// Needs a Redis server listening on the job server's default datastore address.
+    #[test]
+    fn lapsed_lease_requeues_job() {
+        let clock = Arc::new(ManualClock::new(SystemClock.now_ms()));
+        let ctx = Arc::new(RwLock::new(zmq::Context::new()));
+        let config = Arc::new(RwLock::new(Config::default()));
+        let mut manager = WorkerManager::new(ctx, config, clock.clone()).unwrap();
+        let mut job = Job::new();
+        job.set_state(JobState::Processing);
+        job.set_worker("worker-1".to_string());
+        job.set_attempts(1);
+        manager.datastore.jobs.write(&mut job).unwrap();
+        manager.renew_lease(job.get_id()).unwrap();
+
+        manager.expire_leases().unwrap();
+        assert_eq!(manager.datastore.jobs.find(&job.get_id()).unwrap().get_state(),
+                   JobState::Processing);
+        clock.advance(duration_ms(Config::default().job_lease));
+        manager.expire_leases().unwrap();
+        let requeued = manager.datastore.jobs.find(&job.get_id()).unwrap();
+        assert_eq!(requeued.get_state(), JobState::Pending);
+        assert!(!manager.datastore.job_leases
+            .expired(clock.now_ms())
+            .unwrap()
+            .contains(&job.get_id()));
+        assert!(manager.datastore.job_queue.remove(&requeued).unwrap());
+    }
+
+    /// Needs a Redis server listening on the job server's default datastore address.
+    #[test]
+    fn due_schedules_are_checked_at_intervals() {
+        let now = SystemClock.now_ms();
+        let clock = Arc::new(ManualClock::new(now));
+        let ctx = Arc::new(RwLock::new(zmq::Context::new()));
+        let config = Arc::new(RwLock::new(Config::default()));
+        let mut manager = WorkerManager::new(ctx, config, clock.clone()).unwrap();
+        let mut schedule = JobSchedule::new();
+        schedule.set_owner_id(now as u64);
+        schedule.set_project("core/redis".to_string());
+        schedule.set_cron("* * * * *".to_string());
+        schedule.set_next_run_ms(now);
+        manager.datastore.job_schedules.write(&mut schedule).unwrap();
+        let id = schedule.get_id();
+
+        manager.run_schedules().unwrap();
+        let ran = manager.datastore.job_schedules.find(&id).unwrap();
+        let first = manager.datastore.jobs.find(&ran.get_last_job_id()).unwrap();
+        assert_eq!(first.get_schedule_id(), id);
+        assert!(ran.get_next_run_ms() > now);
+
+        // Coming due again before the next check, the schedule waits for it.
+        let mut early = ran.clone();
+        early.set_next_run_ms(now + 1);
+        manager.datastore.job_schedules.replace(&early).unwrap();
+        clock.advance(1);
+        manager.run_schedules().unwrap();
+        assert_eq!(manager.datastore.job_schedules.find(&id).unwrap().get_last_job_id(),
+                   first.get_id());
+        clock.advance(SCHEDULE_CHECK_MS);
+        manager.run_schedules().unwrap();
+        let ran = manager.datastore.job_schedules.find(&id).unwrap();
+        assert!(ran.get_last_job_id() != first.get_id());
+        let second = manager.datastore.jobs.find(&ran.get_last_job_id()).unwrap();
+        assert!(manager.datastore.job_queue.remove(&first).unwrap());
+        assert!(manager.datastore.job_queue.remove(&second).unwrap());
+    }
 }
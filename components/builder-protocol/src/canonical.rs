@@ -0,0 +1,312 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Canonical serialization of messages which are signed or hashed.
+//!
+//! Protobuf doesn't promise one encoding per message. Another version of the library, or a peer
+//! built from newer protocol files, may order fields differently, pack repeated fields which
+//! this build doesn't, or carry fields this build doesn't know, and still decode to the same
+//! message. A signature or hash over `write_to_bytes()` would then fail to verify. Anything which
+//! signs or hashes a message should serialize it with `to_bytes()` instead, or rewrite the body
+//! of a routed message with `body()`, so that equal messages always produce equal bytes:
+//!
+//! * fields are written in ascending field number order, repeated fields in their own order
+//! * fields not in this crate's protocol files are dropped, recursively
+//! * a singular field which occurs more than once is written once, as protobuf would decode it
+//! * repeated scalars are packed exactly when their protocol file declares them `packed`
+//! * varints are written in their shortest form
+
+use protobuf::{self, CodedInputStream, ProtobufResult, UnknownFields};
+use protobuf::descriptor::{DescriptorProto, FieldDescriptorProto, FieldDescriptorProto_Label,
+                           FieldDescriptorProto_Type};
+use protobuf::wire_format::WireType;
+
+use message::{descriptor, FILES};
+use net;
+
+/// Serialize a message canonically. Messages not defined by this crate are serialized as they
+/// would be by `write_to_bytes()`.
+pub fn to_bytes<M: protobuf::Message>(msg: &M) -> ProtobufResult<Vec<u8>> {
+    let bytes = try!(msg.write_to_bytes());
+    canonicalize(&bytes, msg.descriptor().full_name())
+}
+
+/// Rewrite an encoded message of the named type, such as `net.RouteInfo`, in canonical form.
+pub fn canonicalize(bytes: &[u8], full_name: &str) -> ProtobufResult<Vec<u8>> {
+    match descriptor(full_name) {
+        Some(desc) => rewrite(bytes, desc),
+        None => Ok(bytes.to_vec()),
+    }
+}
+
+/// Rewrite the body of a routed message in canonical form, taking its type from the message id.
+/// Message names are unique across this crate's protocol files, so the id alone names the type.
+/// Bodies of messages not defined by this crate are returned as they are.
+pub fn body(msg: &net::Msg) -> ProtobufResult<Vec<u8>> {
+    for file in FILES.iter() {
+        let types = file().get_message_type();
+        if let Some(desc) = types.iter().find(|m| m.get_name() == msg.get_message_id()) {
+            return rewrite(msg.get_body(), desc);
+        }
+    }
+    Ok(msg.get_body().to_vec())
+}
+
+// A field value as encoded on the wire.
+enum Value {
+    Varint(u64),
+    Fixed64(u64),
+    Fixed32(u32),
+    Delimited(Vec<u8>),
+}
+
+fn rewrite(bytes: &[u8], desc: &DescriptorProto) -> ProtobufResult<Vec<u8>> {
+    let mut fields: Vec<&FieldDescriptorProto> = desc.get_field().iter().collect();
+    fields.sort_by_key(|f| f.get_number());
+    let mut values: Vec<Vec<Value>> = fields.iter().map(|_| vec![]).collect();
+    let mut unknown = UnknownFields::new();
+    {
+        let mut is = CodedInputStream::from_bytes(bytes);
+        while !try!(is.eof()) {
+            let (number, wire_type) = try!(is.read_tag_unpack());
+            match fields.iter().position(|f| f.get_number() as u32 == number) {
+                Some(i) => values[i].push(try!(read_value(&mut is, wire_type))),
+                None => {
+                    try!(protobuf::rt::read_unknown_or_skip_group(number, wire_type, &mut is,
+                                                                  &mut unknown))
+                }
+            }
+        }
+    }
+    let mut out = vec![];
+    for (field, values) in fields.iter().zip(values.into_iter()) {
+        try!(write_field(&mut out, field, values));
+    }
+    Ok(out)
+}
+
+fn read_value(is: &mut CodedInputStream, wire_type: WireType) -> ProtobufResult<Value> {
+    match wire_type {
+        WireType::WireTypeVarint => Ok(Value::Varint(try!(is.read_raw_varint64()))),
+        WireType::WireTypeFixed64 => Ok(Value::Fixed64(try!(is.read_raw_little_endian64()))),
+        WireType::WireTypeFixed32 => Ok(Value::Fixed32(try!(is.read_raw_little_endian32()))),
+        WireType::WireTypeLengthDelimited => {
+            let len = try!(is.read_raw_varint32());
+            Ok(Value::Delimited(try!(is.read_raw_bytes(len))))
+        }
+        _ => Err(protobuf::rt::unexpected_wire_type(wire_type)),
+    }
+}
+
+fn write_field(out: &mut Vec<u8>,
+               field: &FieldDescriptorProto,
+               values: Vec<Value>)
+               -> ProtobufResult<()> {
+    if values.is_empty() {
+        return Ok(());
+    }
+    let number = field.get_number() as u32;
+    let repeated = field.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED;
+    match field.get_field_type() {
+        FieldDescriptorProto_Type::TYPE_MESSAGE => {
+            let desc = descriptor(field.get_type_name());
+            let mut msgs = vec![];
+            for value in values {
+                match value {
+                    Value::Delimited(bytes) => msgs.push(bytes),
+                    other => return Err(protobuf::rt::unexpected_wire_type(wire_type(&other))),
+                }
+            }
+            // Occurrences of a singular message are merged, the same as concatenating them.
+            if !repeated {
+                msgs = vec![msgs.concat()];
+            }
+            for bytes in msgs {
+                let bytes = match desc {
+                    Some(desc) => try!(rewrite(&bytes, desc)),
+                    None => bytes,
+                };
+                put_delimited(out, number, &bytes);
+            }
+        }
+        FieldDescriptorProto_Type::TYPE_STRING |
+        FieldDescriptorProto_Type::TYPE_BYTES => {
+            let mut values = values;
+            if !repeated {
+                values = values.split_off(values.len() - 1);
+            }
+            for value in values {
+                match value {
+                    Value::Delimited(bytes) => put_delimited(out, number, &bytes),
+                    other => return Err(protobuf::rt::unexpected_wire_type(wire_type(&other))),
+                }
+            }
+        }
+        FieldDescriptorProto_Type::TYPE_GROUP => (),
+        field_type => {
+            let scalar_type = scalar_wire_type(field_type);
+            let mut scalars = vec![];
+            for value in values {
+                match value {
+                    Value::Delimited(bytes) => try!(unpack(&bytes, scalar_type, &mut scalars)),
+                    value => scalars.push(value),
+                }
+            }
+            if !repeated {
+                scalars = scalars.split_off(scalars.len() - 1);
+            }
+            if repeated && field.get_options().get_packed() {
+                let mut packed = vec![];
+                for value in &scalars {
+                    put_scalar(&mut packed, value);
+                }
+                put_delimited(out, number, &packed);
+            } else {
+                for value in &scalars {
+                    put_tag(out, number, wire_type(value));
+                    put_scalar(out, value);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// Split a packed repeated field into its values.
+fn unpack(bytes: &[u8], wire_type: WireType, values: &mut Vec<Value>) -> ProtobufResult<()> {
+    let mut is = CodedInputStream::from_bytes(bytes);
+    while !try!(is.eof()) {
+        values.push(try!(read_value(&mut is, wire_type)));
+    }
+    Ok(())
+}
+
+fn scalar_wire_type(field_type: FieldDescriptorProto_Type) -> WireType {
+    match field_type {
+        FieldDescriptorProto_Type::TYPE_DOUBLE |
+        FieldDescriptorProto_Type::TYPE_FIXED64 |
+        FieldDescriptorProto_Type::TYPE_SFIXED64 => WireType::WireTypeFixed64,
+        FieldDescriptorProto_Type::TYPE_FLOAT |
+        FieldDescriptorProto_Type::TYPE_FIXED32 |
+        FieldDescriptorProto_Type::TYPE_SFIXED32 => WireType::WireTypeFixed32,
+        _ => WireType::WireTypeVarint,
+    }
+}
+
+fn wire_type(value: &Value) -> WireType {
+    match *value {
+        Value::Varint(_) => WireType::WireTypeVarint,
+        Value::Fixed64(_) => WireType::WireTypeFixed64,
+        Value::Fixed32(_) => WireType::WireTypeFixed32,
+        Value::Delimited(_) => WireType::WireTypeLengthDelimited,
+    }
+}
+
+fn put_scalar(out: &mut Vec<u8>, value: &Value) {
+    match *value {
+        Value::Varint(v) => put_varint(out, v),
+        Value::Fixed64(v) => {
+            for i in 0..8 {
+                out.push((v >> (8 * i)) as u8);
+            }
+        }
+        Value::Fixed32(v) => {
+            for i in 0..4 {
+                out.push((v >> (8 * i)) as u8);
+            }
+        }
+        Value::Delimited(ref bytes) => {
+            put_varint(out, bytes.len() as u64);
+            out.extend_from_slice(bytes);
+        }
+    }
+}
+
+fn put_delimited(out: &mut Vec<u8>, number: u32, bytes: &[u8]) {
+    put_tag(out, number, WireType::WireTypeLengthDelimited);
+    put_varint(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+fn put_tag(out: &mut Vec<u8>, number: u32, wire_type: WireType) {
+    put_varint(out, ((number << 3) | wire_type as u32) as u64);
+}
+
+fn put_varint(out: &mut Vec<u8>, mut v: u64) {
+    while v >= 0x80 {
+        out.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+#[cfg(test)]
+mod tests {
+    use protobuf::{parse_from_bytes, Message};
+
+    use net::{Msg, Ping, Protocol};
+    use routesrv::Registration;
+    use super::*;
+
+    #[test]
+    fn matches_serialization_of_known_fields() {
+        let mut reg = Registration::new();
+        reg.set_protocol(Protocol::JobSrv);
+        reg.set_endpoint("srv".to_string());
+        reg.set_shards(vec![3, 1, 200]);
+        reg.set_capabilities(4);
+        assert_eq!(to_bytes(&reg).unwrap(), reg.write_to_bytes().unwrap());
+    }
+
+    #[test]
+    fn drops_unknown_fields() {
+        let mut ping = Ping::new();
+        ping.set_endpoint("srv".to_string());
+        let mut bytes = ping.write_to_bytes().unwrap();
+        // Field 15, a varint of 1, from a newer Ping
+        bytes.extend_from_slice(&[0x78, 0x01]);
+        let newer: Ping = parse_from_bytes(&bytes).unwrap();
+        assert_eq!(newer.write_to_bytes().unwrap(), bytes);
+        assert_eq!(to_bytes(&newer).unwrap(), ping.write_to_bytes().unwrap());
+    }
+
+    #[test]
+    fn orders_fields_and_repacks_scalars() {
+        let mut reg = Registration::new();
+        reg.set_protocol(Protocol::JobSrv);
+        reg.set_endpoint("srv".to_string());
+        reg.set_shards(vec![1, 2]);
+        // The shards unpacked and interleaved with the endpoint, which is written twice, followed
+        // by the protocol
+        let bytes = [0x18, 0x01, 0x12, 0x01, 0x78, 0x18, 0x02, 0x12, 0x03, 0x73, 0x72, 0x76, 0x08,
+                     0x04];
+        assert_eq!(canonicalize(&bytes, "routesrv.Registration").unwrap(),
+                   reg.write_to_bytes().unwrap());
+    }
+
+    #[test]
+    fn rewrites_bodies_by_message_id() {
+        let mut ping = Ping::new();
+        ping.set_endpoint("srv".to_string());
+        let mut bytes = ping.write_to_bytes().unwrap();
+        bytes.extend_from_slice(&[0x78, 0x01]);
+        let mut msg = Msg::new();
+        msg.set_message_id("Ping".to_string());
+        msg.set_body(bytes.clone());
+        assert_eq!(body(&msg).unwrap(), ping.write_to_bytes().unwrap());
+        // A message this build doesn't define is left alone.
+        msg.set_message_id("FromTheFuture".to_string());
+        assert_eq!(body(&msg).unwrap(), bytes);
+    }
+}
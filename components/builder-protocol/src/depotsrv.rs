@@ -21,6 +21,7 @@ use hab_core::package::{self, Identifiable, FromArchive, PackageArchive};
 use rustc_serialize::{Decoder, Decodable, Encoder, Encodable};
 use rustc_serialize::json::{Json, ToJson};
 use protobuf;
+use protobuf::ProtobufEnum;
 
 use message::Persistable;
 
@@ -53,11 +54,47 @@ impl Decodable for Package {
             if let Some(cfg) = try!(d.read_struct_field("config", 6, |d| Ok(Decodable::decode(d).ok()))) {
                 package.set_config(cfg);
             }
+            if let Some(provenance) = try!(d.read_struct_field("provenance", 7, |d| Ok(Decodable::decode(d).ok()))) {
+                package.set_provenance(provenance);
+            }
             Ok(package)
         })
     }
 }
 
+impl Decodable for PackageProvenance {
+    fn decode<D: Decoder>(d: &mut D) -> result::Result<Self, D::Error> {
+        d.read_struct("PackageProvenance", 4, |d| {
+            let mut provenance = PackageProvenance::new();
+            // job ids are rendered as strings, see `ToJson for PackageProvenance`
+            let job_id: Option<String> =
+                try!(d.read_struct_field("job_id", 0, |d| Decodable::decode(d)));
+            if let Some(job_id) = job_id {
+                match job_id.parse() {
+                    Ok(job_id) => provenance.set_job_id(job_id),
+                    Err(_) => return Err(d.error(&format!("invalid job_id: {}", job_id))),
+                }
+            }
+            let source_revision: Option<String> =
+                try!(d.read_struct_field("source_revision", 1, |d| Decodable::decode(d)));
+            if let Some(source_revision) = source_revision {
+                provenance.set_source_revision(source_revision);
+            }
+            let builder_host: Option<String> =
+                try!(d.read_struct_field("builder_host", 2, |d| Decodable::decode(d)));
+            if let Some(builder_host) = builder_host {
+                provenance.set_builder_host(builder_host);
+            }
+            let log_url: Option<String> =
+                try!(d.read_struct_field("log_url", 3, |d| Decodable::decode(d)));
+            if let Some(log_url) = log_url {
+                provenance.set_log_url(log_url);
+            }
+            Ok(provenance)
+        })
+    }
+}
+
 impl Decodable for PackageIdent {
     fn decode<D: Decoder>(d: &mut D) -> result::Result<Self, D::Error> {
         d.read_struct("PackageIdent", 4, |d| {
@@ -154,6 +191,18 @@ impl Persistable for View {
     }
 }
 
+impl Persistable for PromotionRequest {
+    type Key = u64;
+
+    fn primary_key(&self) -> Self::Key {
+        self.get_id()
+    }
+
+    fn set_primary_key(&mut self, value: Self::Key) {
+        self.set_id(value);
+    }
+}
+
 impl FromArchive for Package {
     type Error = hab_core::Error;
 
@@ -291,6 +340,46 @@ impl ToJson for Package {
         m.insert("tdeps".to_string(), self.get_tdeps().to_vec().to_json());
         m.insert("exposes".to_string(), self.get_exposes().to_json());
         m.insert("config".to_string(), self.get_config().to_json());
+        if self.has_provenance() {
+            m.insert("provenance".to_string(), self.get_provenance().to_json());
+        }
+        Json::Object(m)
+    }
+}
+
+impl ToJson for PackageProvenance {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        // NOTE: job ids are represented as strings, because they overflow JSON number
+        // representation in some tools
+        if self.has_job_id() {
+            m.insert("job_id".to_string(), self.get_job_id().to_string().to_json());
+        }
+        if self.has_source_revision() {
+            m.insert("source_revision".to_string(),
+                     self.get_source_revision().to_json());
+        }
+        if self.has_builder_host() {
+            m.insert("builder_host".to_string(), self.get_builder_host().to_json());
+        }
+        if self.has_log_url() {
+            m.insert("log_url".to_string(), self.get_log_url().to_json());
+        }
+        Json::Object(m)
+    }
+}
+
+impl ToJson for PromotionRequest {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("id".to_string(), self.get_id().to_json());
+        m.insert("view".to_string(), self.get_view().to_json());
+        m.insert("ident".to_string(), self.get_ident().to_json());
+        m.insert("requester".to_string(), self.get_requester().to_json());
+        m.insert("state".to_string(), self.get_state().value().to_json());
+        if self.has_approver() {
+            m.insert("approver".to_string(), self.get_approver().to_json());
+        }
         Json::Object(m)
     }
 }
@@ -25,6 +25,9 @@ use sharding::InstaId;
 
 pub use message::jobsrv::*;
 
+/// Topic of the job status events published by the JobSrv to its event subscribers.
+pub const JOB_EVENT_TOPIC: &'static str = "job";
+
 #[derive(Debug)]
 pub enum Error {
     BadJobState,
@@ -38,6 +41,14 @@ impl Routable for JobCreate {
     }
 }
 
+impl Routable for JobEnqueue {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_owner_id()))
+    }
+}
+
 impl Routable for JobGet {
     type H = InstaId;
 
@@ -46,11 +57,181 @@ impl Routable for JobGet {
     }
 }
 
+impl Routable for JobLogGet {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_job_id()))
+    }
+}
+
+impl Routable for JobScheduleCreate {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_owner_id()))
+    }
+}
+
+impl Routable for JobScheduleListRequest {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_owner_id()))
+    }
+}
+
+impl Persistable for JobSchedule {
+    type Key = u64;
+
+    fn primary_key(&self) -> Self::Key {
+        self.get_id()
+    }
+
+    fn set_primary_key(&mut self, value: Self::Key) {
+        self.set_id(value);
+    }
+}
+
+impl ToJson for JobSchedule {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("id".to_string(), self.get_id().to_json());
+        m.insert("project".to_string(), self.get_project().to_json());
+        m.insert("cron".to_string(), self.get_cron().to_json());
+        m.insert("priority".to_string(), self.get_priority().to_json());
+        m.insert("requires".to_string(), self.get_requires().to_vec().to_json());
+        if self.has_next_run_ms() {
+            m.insert("next_run_ms".to_string(), self.get_next_run_ms().to_json());
+        }
+        if self.has_last_job_id() {
+            m.insert("last_job_id".to_string(), self.get_last_job_id().to_json());
+        }
+        Json::Object(m)
+    }
+}
+
+impl ToJson for JobScheduleListResponse {
+    fn to_json(&self) -> Json {
+        let schedules: Vec<Json> = self.get_schedules().iter().map(|s| s.to_json()).collect();
+        let mut m = BTreeMap::new();
+        m.insert("schedules".to_string(), schedules.to_json());
+        Json::Object(m)
+    }
+}
+
+impl Routable for JobGroupSpec {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_owner_id()))
+    }
+}
+
+impl Routable for JobGroupGet {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_id()))
+    }
+}
+
+impl Routable for JobGraphPackage {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_project().to_string())
+    }
+}
+
+impl Persistable for JobGroup {
+    type Key = u64;
+
+    fn primary_key(&self) -> Self::Key {
+        self.get_id()
+    }
+
+    fn set_primary_key(&mut self, value: Self::Key) {
+        self.set_id(value);
+    }
+}
+
+impl JobGroup {
+    /// Whether every project of the group has finished, one way or another.
+    pub fn is_finished(&self) -> bool {
+        self.get_projects().iter().all(|p| match p.get_state() {
+            JobGroupProjectState::NotStarted |
+            JobGroupProjectState::InProgress => false,
+            _ => true,
+        })
+    }
+}
+
+impl ToJson for JobGroupProject {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("name".to_string(), self.get_name().to_json());
+        m.insert("state".to_string(), self.get_state().value().to_json());
+        if self.has_job_id() {
+            m.insert("job_id".to_string(), self.get_job_id().to_json());
+        }
+        m.insert("deps".to_string(), self.get_deps().to_vec().to_json());
+        Json::Object(m)
+    }
+}
+
+impl ToJson for JobGroup {
+    fn to_json(&self) -> Json {
+        let projects: Vec<Json> = self.get_projects().iter().map(|p| p.to_json()).collect();
+        let mut m = BTreeMap::new();
+        m.insert("id".to_string(), self.get_id().to_json());
+        m.insert("project".to_string(), self.get_project().to_json());
+        m.insert("state".to_string(), self.get_state().value().to_json());
+        m.insert("projects".to_string(), projects.to_json());
+        Json::Object(m)
+    }
+}
+
+impl ToJson for JobLog {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("job_id".to_string(), self.get_job_id().to_json());
+        m.insert("attempt".to_string(), self.get_attempt().to_json());
+        m.insert("start".to_string(), self.get_start().to_json());
+        m.insert("stop".to_string(), self.get_stop().to_json());
+        m.insert("content".to_string(), self.get_content().to_vec().to_json());
+        m.insert("is_complete".to_string(), self.get_complete().to_json());
+        Json::Object(m)
+    }
+}
+
+impl Job {
+    /// Whether a worker with the given capability tags may run this job.
+    pub fn runnable_by(&self, capabilities: &[String]) -> bool {
+        self.get_requires().iter().all(|tag| capabilities.contains(tag))
+    }
+}
+
 impl ToJson for Job {
     fn to_json(&self) -> Json {
         let mut m = BTreeMap::new();
         m.insert("id".to_string(), self.get_id().to_json());
+        m.insert("owner_id".to_string(), self.get_owner_id().to_json());
         m.insert("state".to_string(), self.get_state().value().to_json());
+        m.insert("priority".to_string(), self.get_priority().to_json());
+        m.insert("attempts".to_string(), self.get_attempts().to_json());
+        if self.has_schedule_id() {
+            m.insert("schedule_id".to_string(), self.get_schedule_id().to_json());
+        }
+        if self.has_project() {
+            m.insert("project".to_string(), self.get_project().to_json());
+        }
+        if self.has_group_id() {
+            m.insert("group_id".to_string(), self.get_group_id().to_json());
+        }
+        if self.has_package_ident() {
+            m.insert("package_ident".to_string(), self.get_package_ident().to_json());
+        }
         Json::Object(m)
     }
 }
@@ -104,3 +285,33 @@ impl Persistable for Job {
         self.set_id(value);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use protobuf::RepeatedField;
+
+    use super::{Job, JobGroup, JobGroupProject, JobGroupProjectState};
+
+    #[test]
+    fn job_runnable_by() {
+        let mut job = Job::new();
+        assert!(job.runnable_by(&[]));
+        job.set_requires(RepeatedField::from_vec(vec!["linux".to_string(), "docker".to_string()]));
+        assert!(!job.runnable_by(&["linux".to_string()]));
+        assert!(job.runnable_by(&["docker".to_string(), "gpu".to_string(), "linux".to_string()]));
+    }
+
+    #[test]
+    fn job_group_is_finished() {
+        let mut group = JobGroup::new();
+        assert!(group.is_finished());
+        for state in vec![JobGroupProjectState::Success, JobGroupProjectState::InProgress] {
+            let mut project = JobGroupProject::new();
+            project.set_state(state);
+            group.mut_projects().push(project);
+        }
+        assert!(!group.is_finished());
+        group.mut_projects()[1].set_state(JobGroupProjectState::Skipped);
+        assert!(group.is_finished());
+    }
+}
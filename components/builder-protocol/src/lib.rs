@@ -20,10 +20,12 @@ extern crate redis;
 extern crate rustc_serialize;
 extern crate time;
 
+pub mod canonical;
 pub mod depotsrv;
 pub mod jobsrv;
 pub mod net;
 pub mod routesrv;
+pub mod schema;
 pub mod sessionsrv;
 pub mod sharding;
 pub mod vault;
@@ -377,6 +377,7 @@ pub struct Package {
     tdeps: ::protobuf::RepeatedField<PackageIdent>,
     exposes: ::std::vec::Vec<u32>,
     config: ::protobuf::SingularField<::std::string::String>,
+    provenance: ::protobuf::SingularPtrField<PackageProvenance>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -405,6 +406,7 @@ impl Package {
                     tdeps: ::protobuf::RepeatedField::new(),
                     exposes: ::std::vec::Vec::new(),
                     config: ::protobuf::SingularField::none(),
+                    provenance: ::protobuf::SingularPtrField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -627,6 +629,39 @@ impl Package {
             None => "",
         }
     }
+
+    // optional .depotsrv.PackageProvenance provenance = 8;
+
+    pub fn clear_provenance(&mut self) {
+        self.provenance.clear();
+    }
+
+    pub fn has_provenance(&self) -> bool {
+        self.provenance.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_provenance(&mut self, v: PackageProvenance) {
+        self.provenance = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_provenance(&mut self) -> &mut PackageProvenance {
+        if self.provenance.is_none() {
+            self.provenance.set_default();
+        };
+        self.provenance.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_provenance(&mut self) -> PackageProvenance {
+        self.provenance.take().unwrap_or_else(|| PackageProvenance::new())
+    }
+
+    pub fn get_provenance(&self) -> &PackageProvenance {
+        self.provenance.as_ref().unwrap_or_else(|| PackageProvenance::default_instance())
+    }
 }
 
 impl ::protobuf::Message for Package {
@@ -668,6 +703,9 @@ impl ::protobuf::Message for Package {
                 7 => {
                     try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.config));
                 },
+                8 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.provenance));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -704,6 +742,10 @@ impl ::protobuf::Message for Package {
         for value in self.config.iter() {
             my_size += ::protobuf::rt::string_size(7, &value);
         };
+        for value in self.provenance.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -742,6 +784,11 @@ impl ::protobuf::Message for Package {
         if let Some(v) = self.config.as_ref() {
             try!(os.write_string(7, &v));
         };
+        if let Some(v) = self.provenance.as_ref() {
+            try!(os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -816,6 +863,11 @@ impl ::protobuf::MessageStatic for Package {
                     Package::has_config,
                     Package::get_config,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "provenance",
+                    Package::has_provenance,
+                    Package::get_provenance,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Package>(
                     "Package",
                     fields,
@@ -835,6 +887,7 @@ impl ::protobuf::Clear for Package {
         self.clear_tdeps();
         self.clear_exposes();
         self.clear_config();
+        self.clear_provenance();
         self.unknown_fields.clear();
     }
 }
@@ -848,6 +901,7 @@ impl ::std::cmp::PartialEq for Package {
         self.tdeps == other.tdeps &&
         self.exposes == other.exposes &&
         self.config == other.config &&
+        self.provenance == other.provenance &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -1344,6 +1398,914 @@ impl ::std::fmt::Debug for OriginKeyIdent {
     }
 }
 
+#[derive(Clone,Default)]
+pub struct PackageProvenance {
+    // message fields
+    job_id: ::std::option::Option<u64>,
+    source_revision: ::protobuf::SingularField<::std::string::String>,
+    builder_host: ::protobuf::SingularField<::std::string::String>,
+    log_url: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for PackageProvenance {}
+
+impl PackageProvenance {
+    pub fn new() -> PackageProvenance {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static PackageProvenance {
+        static mut instance: ::protobuf::lazy::Lazy<PackageProvenance> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PackageProvenance,
+        };
+        unsafe {
+            instance.get(|| {
+                PackageProvenance {
+                    job_id: ::std::option::Option::None,
+                    source_revision: ::protobuf::SingularField::none(),
+                    builder_host: ::protobuf::SingularField::none(),
+                    log_url: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // optional uint64 job_id = 1;
+
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
+    }
+
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
+    }
+
+    // optional string source_revision = 2;
+
+    pub fn clear_source_revision(&mut self) {
+        self.source_revision.clear();
+    }
+
+    pub fn has_source_revision(&self) -> bool {
+        self.source_revision.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_revision(&mut self, v: ::std::string::String) {
+        self.source_revision = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_revision(&mut self) -> &mut ::std::string::String {
+        if self.source_revision.is_none() {
+            self.source_revision.set_default();
+        };
+        self.source_revision.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source_revision(&mut self) -> ::std::string::String {
+        self.source_revision.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_source_revision(&self) -> &str {
+        match self.source_revision.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string builder_host = 3;
+
+    pub fn clear_builder_host(&mut self) {
+        self.builder_host.clear();
+    }
+
+    pub fn has_builder_host(&self) -> bool {
+        self.builder_host.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_builder_host(&mut self, v: ::std::string::String) {
+        self.builder_host = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_builder_host(&mut self) -> &mut ::std::string::String {
+        if self.builder_host.is_none() {
+            self.builder_host.set_default();
+        };
+        self.builder_host.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_builder_host(&mut self) -> ::std::string::String {
+        self.builder_host.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_builder_host(&self) -> &str {
+        match self.builder_host.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string log_url = 4;
+
+    pub fn clear_log_url(&mut self) {
+        self.log_url.clear();
+    }
+
+    pub fn has_log_url(&self) -> bool {
+        self.log_url.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_log_url(&mut self, v: ::std::string::String) {
+        self.log_url = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_log_url(&mut self) -> &mut ::std::string::String {
+        if self.log_url.is_none() {
+            self.log_url.set_default();
+        };
+        self.log_url.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_log_url(&mut self) -> ::std::string::String {
+        self.log_url.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_log_url(&self) -> &str {
+        match self.log_url.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for PackageProvenance {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.job_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.source_revision));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.builder_host));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.log_url));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.job_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.source_revision.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.builder_host.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.log_url.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.job_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.source_revision.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.builder_host.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.log_url.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<PackageProvenance>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for PackageProvenance {
+    fn new() -> PackageProvenance {
+        PackageProvenance::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<PackageProvenance>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "job_id",
+                    PackageProvenance::has_job_id,
+                    PackageProvenance::get_job_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "source_revision",
+                    PackageProvenance::has_source_revision,
+                    PackageProvenance::get_source_revision,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "builder_host",
+                    PackageProvenance::has_builder_host,
+                    PackageProvenance::get_builder_host,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "log_url",
+                    PackageProvenance::has_log_url,
+                    PackageProvenance::get_log_url,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PackageProvenance>(
+                    "PackageProvenance",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for PackageProvenance {
+    fn clear(&mut self) {
+        self.clear_job_id();
+        self.clear_source_revision();
+        self.clear_builder_host();
+        self.clear_log_url();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for PackageProvenance {
+    fn eq(&self, other: &PackageProvenance) -> bool {
+        self.job_id == other.job_id &&
+        self.source_revision == other.source_revision &&
+        self.builder_host == other.builder_host &&
+        self.log_url == other.log_url &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for PackageProvenance {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct PromotionRequest {
+    // message fields
+    id: ::std::option::Option<u64>,
+    view: ::protobuf::SingularField<::std::string::String>,
+    ident: ::protobuf::SingularPtrField<PackageIdent>,
+    requester_id: ::std::option::Option<u64>,
+    requester: ::protobuf::SingularField<::std::string::String>,
+    state: ::std::option::Option<PromotionState>,
+    approver_id: ::std::option::Option<u64>,
+    approver: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for PromotionRequest {}
+
+impl PromotionRequest {
+    pub fn new() -> PromotionRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static PromotionRequest {
+        static mut instance: ::protobuf::lazy::Lazy<PromotionRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PromotionRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                PromotionRequest {
+                    id: ::std::option::Option::None,
+                    view: ::protobuf::SingularField::none(),
+                    ident: ::protobuf::SingularPtrField::none(),
+                    requester_id: ::std::option::Option::None,
+                    requester: ::protobuf::SingularField::none(),
+                    state: ::std::option::Option::None,
+                    approver_id: ::std::option::Option::None,
+                    approver: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    // required string view = 2;
+
+    pub fn clear_view(&mut self) {
+        self.view.clear();
+    }
+
+    pub fn has_view(&self) -> bool {
+        self.view.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view(&mut self, v: ::std::string::String) {
+        self.view = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view(&mut self) -> &mut ::std::string::String {
+        if self.view.is_none() {
+            self.view.set_default();
+        };
+        self.view.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_view(&mut self) -> ::std::string::String {
+        self.view.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_view(&self) -> &str {
+        match self.view.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required .depotsrv.PackageIdent ident = 3;
+
+    pub fn clear_ident(&mut self) {
+        self.ident.clear();
+    }
+
+    pub fn has_ident(&self) -> bool {
+        self.ident.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ident(&mut self, v: PackageIdent) {
+        self.ident = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ident(&mut self) -> &mut PackageIdent {
+        if self.ident.is_none() {
+            self.ident.set_default();
+        };
+        self.ident.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ident(&mut self) -> PackageIdent {
+        self.ident.take().unwrap_or_else(|| PackageIdent::new())
+    }
+
+    pub fn get_ident(&self) -> &PackageIdent {
+        self.ident.as_ref().unwrap_or_else(|| PackageIdent::default_instance())
+    }
+
+    // required uint64 requester_id = 4;
+
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
+    }
+
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
+    }
+
+    // required string requester = 5;
+
+    pub fn clear_requester(&mut self) {
+        self.requester.clear();
+    }
+
+    pub fn has_requester(&self) -> bool {
+        self.requester.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requester(&mut self, v: ::std::string::String) {
+        self.requester = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_requester(&mut self) -> &mut ::std::string::String {
+        if self.requester.is_none() {
+            self.requester.set_default();
+        };
+        self.requester.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_requester(&mut self) -> ::std::string::String {
+        self.requester.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_requester(&self) -> &str {
+        match self.requester.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required .depotsrv.PromotionState state = 6;
+
+    pub fn clear_state(&mut self) {
+        self.state = ::std::option::Option::None;
+    }
+
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: PromotionState) {
+        self.state = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_state(&self) -> PromotionState {
+        self.state.unwrap_or(PromotionState::PENDING)
+    }
+
+    // optional uint64 approver_id = 7;
+
+    pub fn clear_approver_id(&mut self) {
+        self.approver_id = ::std::option::Option::None;
+    }
+
+    pub fn has_approver_id(&self) -> bool {
+        self.approver_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_approver_id(&mut self, v: u64) {
+        self.approver_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_approver_id(&self) -> u64 {
+        self.approver_id.unwrap_or(0)
+    }
+
+    // optional string approver = 8;
+
+    pub fn clear_approver(&mut self) {
+        self.approver.clear();
+    }
+
+    pub fn has_approver(&self) -> bool {
+        self.approver.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_approver(&mut self, v: ::std::string::String) {
+        self.approver = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_approver(&mut self) -> &mut ::std::string::String {
+        if self.approver.is_none() {
+            self.approver.set_default();
+        };
+        self.approver.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_approver(&mut self) -> ::std::string::String {
+        self.approver.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_approver(&self) -> &str {
+        match self.approver.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for PromotionRequest {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.view.is_none() {
+            return false;
+        };
+        if self.ident.is_none() {
+            return false;
+        };
+        if self.requester_id.is_none() {
+            return false;
+        };
+        if self.requester.is_none() {
+            return false;
+        };
+        if self.state.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.view));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ident));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.requester_id = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.requester));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.state = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.approver_id = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.approver));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.view.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.ident.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.requester_id.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.requester.iter() {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        for value in self.state.iter() {
+            my_size += ::protobuf::rt::enum_size(6, *value);
+        };
+        for value in self.approver_id.iter() {
+            my_size += ::protobuf::rt::value_size(7, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.approver.iter() {
+            my_size += ::protobuf::rt::string_size(8, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.view.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.ident.as_ref() {
+            try!(os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.requester_id {
+            try!(os.write_uint64(4, v));
+        };
+        if let Some(v) = self.requester.as_ref() {
+            try!(os.write_string(5, &v));
+        };
+        if let Some(v) = self.state {
+            try!(os.write_enum(6, v.value()));
+        };
+        if let Some(v) = self.approver_id {
+            try!(os.write_uint64(7, v));
+        };
+        if let Some(v) = self.approver.as_ref() {
+            try!(os.write_string(8, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<PromotionRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for PromotionRequest {
+    fn new() -> PromotionRequest {
+        PromotionRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<PromotionRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    PromotionRequest::has_id,
+                    PromotionRequest::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "view",
+                    PromotionRequest::has_view,
+                    PromotionRequest::get_view,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "ident",
+                    PromotionRequest::has_ident,
+                    PromotionRequest::get_ident,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "requester_id",
+                    PromotionRequest::has_requester_id,
+                    PromotionRequest::get_requester_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "requester",
+                    PromotionRequest::has_requester,
+                    PromotionRequest::get_requester,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "state",
+                    PromotionRequest::has_state,
+                    PromotionRequest::get_state,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "approver_id",
+                    PromotionRequest::has_approver_id,
+                    PromotionRequest::get_approver_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "approver",
+                    PromotionRequest::has_approver,
+                    PromotionRequest::get_approver,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PromotionRequest>(
+                    "PromotionRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for PromotionRequest {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_view();
+        self.clear_ident();
+        self.clear_requester_id();
+        self.clear_requester();
+        self.clear_state();
+        self.clear_approver_id();
+        self.clear_approver();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for PromotionRequest {
+    fn eq(&self, other: &PromotionRequest) -> bool {
+        self.id == other.id &&
+        self.view == other.view &&
+        self.ident == other.ident &&
+        self.requester_id == other.requester_id &&
+        self.requester == other.requester &&
+        self.state == other.state &&
+        self.approver_id == other.approver_id &&
+        self.approver == other.approver &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for PromotionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum PromotionState {
+    PENDING = 0,
+    APPROVED = 1,
+    REJECTED = 2,
+    EXPIRED = 3,
+}
+
+impl ::protobuf::ProtobufEnum for PromotionState {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<PromotionState> {
+        match value {
+            0 => ::std::option::Option::Some(PromotionState::PENDING),
+            1 => ::std::option::Option::Some(PromotionState::APPROVED),
+            2 => ::std::option::Option::Some(PromotionState::REJECTED),
+            3 => ::std::option::Option::Some(PromotionState::EXPIRED),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [PromotionState] = &[
+            PromotionState::PENDING,
+            PromotionState::APPROVED,
+            PromotionState::REJECTED,
+            PromotionState::EXPIRED,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<PromotionState>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("PromotionState", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for PromotionState {
+}
+
 static file_descriptor_proto_data: &'static [u8] = &[
     0x0a, 0x18, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x73, 0x2f, 0x64, 0x65, 0x70, 0x6f,
     0x74, 0x73, 0x72, 0x76, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x08, 0x64, 0x65, 0x70, 0x6f,
@@ -1352,7 +2314,7 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02,
     0x28, 0x09, 0x12, 0x0f, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20,
     0x01, 0x28, 0x09, 0x12, 0x0f, 0x0a, 0x07, 0x72, 0x65, 0x6c, 0x65, 0x61, 0x73, 0x65, 0x18, 0x04,
-    0x20, 0x01, 0x28, 0x09, 0x22, 0xc6, 0x01, 0x0a, 0x07, 0x50, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65,
+    0x20, 0x01, 0x28, 0x09, 0x22, 0xf7, 0x01, 0x0a, 0x07, 0x50, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65,
     0x12, 0x25, 0x0a, 0x05, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32,
     0x16, 0x2e, 0x64, 0x65, 0x70, 0x6f, 0x74, 0x73, 0x72, 0x76, 0x2e, 0x50, 0x61, 0x63, 0x6b, 0x61,
     0x67, 0x65, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x12, 0x10, 0x0a, 0x08, 0x63, 0x68, 0x65, 0x63, 0x6b,
@@ -1364,92 +2326,118 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x32, 0x16, 0x2e, 0x64, 0x65, 0x70, 0x6f, 0x74, 0x73, 0x72, 0x76, 0x2e, 0x50, 0x61, 0x63, 0x6b,
     0x61, 0x67, 0x65, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x12, 0x13, 0x0a, 0x07, 0x65, 0x78, 0x70, 0x6f,
     0x73, 0x65, 0x73, 0x18, 0x06, 0x20, 0x03, 0x28, 0x0d, 0x42, 0x02, 0x10, 0x01, 0x12, 0x0e, 0x0a,
-    0x06, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x22, 0x14, 0x0a,
-    0x04, 0x56, 0x69, 0x65, 0x77, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20,
-    0x02, 0x28, 0x09, 0x22, 0x44, 0x0a, 0x0e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4b, 0x65, 0x79,
-    0x49, 0x64, 0x65, 0x6e, 0x74, 0x12, 0x0e, 0x0a, 0x06, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x18,
-    0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x72, 0x65, 0x76, 0x69, 0x73, 0x69, 0x6f,
-    0x6e, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6c, 0x6f, 0x63, 0x61, 0x74,
-    0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x4a, 0xe7, 0x09, 0x0a, 0x06, 0x12, 0x04,
-    0x00, 0x00, 0x1b, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x10, 0x0a, 0x0a,
-    0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x02, 0x00, 0x07, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00,
-    0x01, 0x12, 0x03, 0x02, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03,
-    0x03, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x03, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x03, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x03, 0x12, 0x18, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x03, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x00, 0x02, 0x01, 0x12, 0x03, 0x04, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
-    0x04, 0x12, 0x03, 0x04, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12,
-    0x03, 0x04, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x04,
-    0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x04, 0x19, 0x1a,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x05, 0x02, 0x1e, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x05, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x05, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x02, 0x01, 0x12, 0x03, 0x05, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03,
-    0x12, 0x03, 0x05, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x06,
-    0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x04, 0x12, 0x03, 0x06, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x06, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x06, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x06, 0x1c, 0x1d, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01,
-    0x12, 0x04, 0x09, 0x00, 0x11, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x09,
-    0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0a, 0x02, 0x22, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x0a, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0a, 0x18, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x00, 0x03, 0x12, 0x03, 0x0a, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12,
-    0x03, 0x0b, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0b,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0b, 0x0b, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0b, 0x12, 0x1a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0b, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x0c, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x02, 0x04, 0x12, 0x03, 0x0c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05,
-    0x12, 0x03, 0x0c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x0c, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x0c, 0x1d,
-    0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x03, 0x0d, 0x02, 0x21, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x04, 0x12, 0x03, 0x0d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x03, 0x06, 0x12, 0x03, 0x0d, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x03, 0x01, 0x12, 0x03, 0x0d, 0x18, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03,
-    0x03, 0x12, 0x03, 0x0d, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x04, 0x12, 0x03,
-    0x0e, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x04, 0x12, 0x03, 0x0e, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x06, 0x12, 0x03, 0x0e, 0x0b, 0x17, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x0e, 0x18, 0x1d, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x04, 0x03, 0x12, 0x03, 0x0e, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x01, 0x02, 0x05, 0x12, 0x03, 0x0f, 0x02, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05,
-    0x04, 0x12, 0x03, 0x0f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x05, 0x12,
-    0x03, 0x0f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x01, 0x12, 0x03, 0x0f,
-    0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x03, 0x12, 0x03, 0x0f, 0x1c, 0x1d,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x08, 0x12, 0x03, 0x0f, 0x1e, 0x2b, 0x0a, 0x0f,
-    0x0a, 0x08, 0x04, 0x01, 0x02, 0x05, 0x08, 0xe7, 0x07, 0x00, 0x12, 0x03, 0x0f, 0x1f, 0x2a, 0x0a,
-    0x10, 0x0a, 0x09, 0x04, 0x01, 0x02, 0x05, 0x08, 0xe7, 0x07, 0x00, 0x02, 0x12, 0x03, 0x0f, 0x1f,
-    0x25, 0x0a, 0x11, 0x0a, 0x0a, 0x04, 0x01, 0x02, 0x05, 0x08, 0xe7, 0x07, 0x00, 0x02, 0x00, 0x12,
-    0x03, 0x0f, 0x1f, 0x25, 0x0a, 0x12, 0x0a, 0x0b, 0x04, 0x01, 0x02, 0x05, 0x08, 0xe7, 0x07, 0x00,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x0f, 0x1f, 0x25, 0x0a, 0x10, 0x0a, 0x09, 0x04, 0x01, 0x02, 0x05,
-    0x08, 0xe7, 0x07, 0x00, 0x03, 0x12, 0x03, 0x0f, 0x26, 0x2a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01,
-    0x02, 0x06, 0x12, 0x03, 0x10, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x04,
-    0x12, 0x03, 0x10, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x05, 0x12, 0x03,
-    0x10, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x01, 0x12, 0x03, 0x10, 0x12,
-    0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x03, 0x12, 0x03, 0x10, 0x1b, 0x1c, 0x0a,
-    0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x13, 0x00, 0x15, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
-    0x02, 0x01, 0x12, 0x03, 0x13, 0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12,
-    0x03, 0x14, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x14,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x14, 0x0b, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x14, 0x12, 0x16, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x14, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02,
-    0x04, 0x03, 0x12, 0x04, 0x17, 0x00, 0x1b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12,
-    0x03, 0x17, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x18, 0x02,
-    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x18, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x18, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x18, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x18, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02,
-    0x01, 0x12, 0x03, 0x19, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12,
-    0x03, 0x19, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x19,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x19, 0x12, 0x1a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x19, 0x1d, 0x1e, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x1a, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x03, 0x02, 0x02, 0x04, 0x12, 0x03, 0x1a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
-    0x02, 0x05, 0x12, 0x03, 0x1a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01,
-    0x12, 0x03, 0x1a, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03,
-    0x1a, 0x1d, 0x1e,
+    0x06, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x12, 0x2f, 0x0a,
+    0x0a, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x61, 0x6e, 0x63, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28,
+    0x0b, 0x32, 0x1b, 0x2e, 0x64, 0x65, 0x70, 0x6f, 0x74, 0x73, 0x72, 0x76, 0x2e, 0x50, 0x61, 0x63,
+    0x6b, 0x61, 0x67, 0x65, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x61, 0x6e, 0x63, 0x65, 0x22, 0x14,
+    0x0a, 0x04, 0x56, 0x69, 0x65, 0x77, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01,
+    0x20, 0x02, 0x28, 0x09, 0x22, 0x44, 0x0a, 0x0e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4b, 0x65,
+    0x79, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x12, 0x0e, 0x0a, 0x06, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x72, 0x65, 0x76, 0x69, 0x73, 0x69,
+    0x6f, 0x6e, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6c, 0x6f, 0x63, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x22, 0x63, 0x0a, 0x11, 0x50, 0x61,
+    0x63, 0x6b, 0x61, 0x67, 0x65, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x61, 0x6e, 0x63, 0x65, 0x12,
+    0x0e, 0x0a, 0x06, 0x6a, 0x6f, 0x62, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x12,
+    0x17, 0x0a, 0x0f, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x5f, 0x72, 0x65, 0x76, 0x69, 0x73, 0x69,
+    0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x12, 0x14, 0x0a, 0x0c, 0x62, 0x75, 0x69, 0x6c,
+    0x64, 0x65, 0x72, 0x5f, 0x68, 0x6f, 0x73, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0f,
+    0x0a, 0x07, 0x6c, 0x6f, 0x67, 0x5f, 0x75, 0x72, 0x6c, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x22,
+    0xcc, 0x01, 0x0a, 0x10, 0x50, 0x72, 0x6f, 0x6d, 0x6f, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04,
+    0x12, 0x0c, 0x0a, 0x04, 0x76, 0x69, 0x65, 0x77, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x25,
+    0x0a, 0x05, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x18, 0x03, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x16, 0x2e,
+    0x64, 0x65, 0x70, 0x6f, 0x74, 0x73, 0x72, 0x76, 0x2e, 0x50, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65,
+    0x49, 0x64, 0x65, 0x6e, 0x74, 0x12, 0x14, 0x0a, 0x0c, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x02, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x72,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x72, 0x18, 0x05, 0x20, 0x02, 0x28, 0x09, 0x12, 0x27,
+    0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x06, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x18, 0x2e,
+    0x64, 0x65, 0x70, 0x6f, 0x74, 0x73, 0x72, 0x76, 0x2e, 0x50, 0x72, 0x6f, 0x6d, 0x6f, 0x74, 0x69,
+    0x6f, 0x6e, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x13, 0x0a, 0x0b, 0x61, 0x70, 0x70, 0x72, 0x6f,
+    0x76, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x12, 0x10, 0x0a, 0x08,
+    0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x18, 0x08, 0x20, 0x01, 0x28, 0x09, 0x2a, 0x46,
+    0x0a, 0x0e, 0x50, 0x72, 0x6f, 0x6d, 0x6f, 0x74, 0x69, 0x6f, 0x6e, 0x53, 0x74, 0x61, 0x74, 0x65,
+    0x12, 0x0b, 0x0a, 0x07, 0x50, 0x45, 0x4e, 0x44, 0x49, 0x4e, 0x47, 0x10, 0x00, 0x12, 0x0c, 0x0a,
+    0x08, 0x41, 0x50, 0x50, 0x52, 0x4f, 0x56, 0x45, 0x44, 0x10, 0x01, 0x12, 0x0c, 0x0a, 0x08, 0x52,
+    0x45, 0x4a, 0x45, 0x43, 0x54, 0x45, 0x44, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x45, 0x58, 0x50,
+    0x49, 0x52, 0x45, 0x44, 0x10, 0x03, 0x4a, 0xe7, 0x09, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x1b,
+    0x01, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x10, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
+    0x00, 0x12, 0x04, 0x02, 0x00, 0x07, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03,
+    0x02, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x03, 0x02, 0x1d,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x03, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x03, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x03, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x03, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01,
+    0x12, 0x03, 0x04, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03,
+    0x04, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x04, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x04, 0x12, 0x16, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x04, 0x19, 0x1a, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x05, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x02, 0x04, 0x12, 0x03, 0x05, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02,
+    0x05, 0x12, 0x03, 0x05, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x05, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x05,
+    0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x06, 0x02, 0x1e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x04, 0x12, 0x03, 0x06, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x06, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x06, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x03, 0x03, 0x12, 0x03, 0x06, 0x1c, 0x1d, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x09,
+    0x00, 0x11, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x09, 0x08, 0x0f, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0a, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x00, 0x06, 0x12, 0x03, 0x0a, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x0a, 0x18, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x0a, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0b, 0x02,
+    0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0b, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0b, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0b, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x02, 0x12, 0x03, 0x0c, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x04, 0x12,
+    0x03, 0x0c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x0c,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0c, 0x12, 0x1a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x0c, 0x1d, 0x1e, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x03, 0x0d, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x03, 0x04, 0x12, 0x03, 0x0d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x03, 0x06, 0x12, 0x03, 0x0d, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01,
+    0x12, 0x03, 0x0d, 0x18, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x03,
+    0x0d, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x04, 0x12, 0x03, 0x0e, 0x02, 0x22,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x04, 0x12, 0x03, 0x0e, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x06, 0x12, 0x03, 0x0e, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x0e, 0x18, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x04, 0x03, 0x12, 0x03, 0x0e, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x05,
+    0x12, 0x03, 0x0f, 0x02, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x04, 0x12, 0x03,
+    0x0f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x05, 0x12, 0x03, 0x0f, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x01, 0x12, 0x03, 0x0f, 0x12, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x03, 0x12, 0x03, 0x0f, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x05, 0x08, 0x12, 0x03, 0x0f, 0x1e, 0x2b, 0x0a, 0x0f, 0x0a, 0x08, 0x04,
+    0x01, 0x02, 0x05, 0x08, 0xe7, 0x07, 0x00, 0x12, 0x03, 0x0f, 0x1f, 0x2a, 0x0a, 0x10, 0x0a, 0x09,
+    0x04, 0x01, 0x02, 0x05, 0x08, 0xe7, 0x07, 0x00, 0x02, 0x12, 0x03, 0x0f, 0x1f, 0x25, 0x0a, 0x11,
+    0x0a, 0x0a, 0x04, 0x01, 0x02, 0x05, 0x08, 0xe7, 0x07, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0f, 0x1f,
+    0x25, 0x0a, 0x12, 0x0a, 0x0b, 0x04, 0x01, 0x02, 0x05, 0x08, 0xe7, 0x07, 0x00, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x0f, 0x1f, 0x25, 0x0a, 0x10, 0x0a, 0x09, 0x04, 0x01, 0x02, 0x05, 0x08, 0xe7, 0x07,
+    0x00, 0x03, 0x12, 0x03, 0x0f, 0x26, 0x2a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x06, 0x12,
+    0x03, 0x10, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x04, 0x12, 0x03, 0x10,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x05, 0x12, 0x03, 0x10, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x01, 0x12, 0x03, 0x10, 0x12, 0x18, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x03, 0x12, 0x03, 0x10, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x02, 0x12, 0x04, 0x13, 0x00, 0x15, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12,
+    0x03, 0x13, 0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x14, 0x02,
+    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x14, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x14, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x14, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x14, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12,
+    0x04, 0x17, 0x00, 0x1b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x17, 0x08,
+    0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x18, 0x02, 0x1d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x18, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x18, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x18, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x18, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03,
+    0x19, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x19, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x19, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x19, 0x12, 0x1a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x19, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x03, 0x02, 0x02, 0x12, 0x03, 0x1a, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02,
+    0x04, 0x12, 0x03, 0x1a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x05, 0x12,
+    0x03, 0x1a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1a,
+    0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1a, 0x1d, 0x1e,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
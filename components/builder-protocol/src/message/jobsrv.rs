@@ -22,6 +22,8 @@ pub struct Heartbeat {
     endpoint: ::protobuf::SingularField<::std::string::String>,
     os: ::std::option::Option<Os>,
     state: ::std::option::Option<WorkerState>,
+    capabilities: ::protobuf::RepeatedField<::std::string::String>,
+    job_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -46,6 +48,8 @@ impl Heartbeat {
                     endpoint: ::protobuf::SingularField::none(),
                     os: ::std::option::Option::None,
                     state: ::std::option::Option::None,
+                    capabilities: ::protobuf::RepeatedField::new(),
+                    job_id: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -126,6 +130,50 @@ impl Heartbeat {
     pub fn get_state(&self) -> WorkerState {
         self.state.unwrap_or(WorkerState::Ready)
     }
+
+    // repeated string capabilities = 4;
+
+    pub fn clear_capabilities(&mut self) {
+        self.capabilities.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_capabilities(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.capabilities = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_capabilities(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.capabilities
+    }
+
+    // Take field
+    pub fn take_capabilities(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.capabilities, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_capabilities(&self) -> &[::std::string::String] {
+        &self.capabilities
+    }
+
+    // optional uint64 job_id = 5;
+
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
+    }
+
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
+    }
 }
 
 impl ::protobuf::Message for Heartbeat {
@@ -163,6 +211,16 @@ impl ::protobuf::Message for Heartbeat {
                     let tmp = try!(is.read_enum());
                     self.state = ::std::option::Option::Some(tmp);
                 },
+                4 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.capabilities));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.job_id = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -184,6 +242,12 @@ impl ::protobuf::Message for Heartbeat {
         for value in self.state.iter() {
             my_size += ::protobuf::rt::enum_size(3, *value);
         };
+        for value in self.capabilities.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.job_id.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -199,6 +263,12 @@ impl ::protobuf::Message for Heartbeat {
         if let Some(v) = self.state {
             try!(os.write_enum(3, v.value()));
         };
+        for v in self.capabilities.iter() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.job_id {
+            try!(os.write_uint64(5, v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -256,6 +326,15 @@ impl ::protobuf::MessageStatic for Heartbeat {
                     Heartbeat::has_state,
                     Heartbeat::get_state,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "capabilities",
+                    Heartbeat::get_capabilities,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "job_id",
+                    Heartbeat::has_job_id,
+                    Heartbeat::get_job_id,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Heartbeat>(
                     "Heartbeat",
                     fields,
@@ -271,6 +350,8 @@ impl ::protobuf::Clear for Heartbeat {
         self.clear_endpoint();
         self.clear_os();
         self.clear_state();
+        self.clear_capabilities();
+        self.clear_job_id();
         self.unknown_fields.clear();
     }
 }
@@ -280,6 +361,8 @@ impl ::std::cmp::PartialEq for Heartbeat {
         self.endpoint == other.endpoint &&
         self.os == other.os &&
         self.state == other.state &&
+        self.capabilities == other.capabilities &&
+        self.job_id == other.job_id &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -295,6 +378,15 @@ pub struct Job {
     // message fields
     id: ::std::option::Option<u64>,
     state: ::std::option::Option<JobState>,
+    priority: ::std::option::Option<u32>,
+    requires: ::protobuf::RepeatedField<::std::string::String>,
+    worker: ::protobuf::SingularField<::std::string::String>,
+    attempts: ::std::option::Option<u32>,
+    schedule_id: ::std::option::Option<u64>,
+    project: ::protobuf::SingularField<::std::string::String>,
+    group_id: ::std::option::Option<u64>,
+    owner_id: ::std::option::Option<u64>,
+    package_ident: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -318,6 +410,15 @@ impl Job {
                 Job {
                     id: ::std::option::Option::None,
                     state: ::std::option::Option::None,
+                    priority: ::std::option::Option::None,
+                    requires: ::protobuf::RepeatedField::new(),
+                    worker: ::protobuf::SingularField::none(),
+                    attempts: ::std::option::Option::None,
+                    schedule_id: ::std::option::Option::None,
+                    project: ::protobuf::SingularField::none(),
+                    group_id: ::std::option::Option::None,
+                    owner_id: ::std::option::Option::None,
+                    package_ident: ::protobuf::SingularField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -362,6 +463,234 @@ impl Job {
     pub fn get_state(&self) -> JobState {
         self.state.unwrap_or(JobState::Pending)
     }
+
+    // optional uint32 priority = 3;
+
+    pub fn clear_priority(&mut self) {
+        self.priority = ::std::option::Option::None;
+    }
+
+    pub fn has_priority(&self) -> bool {
+        self.priority.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priority(&mut self, v: u32) {
+        self.priority = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_priority(&self) -> u32 {
+        self.priority.unwrap_or(0)
+    }
+
+    // repeated string requires = 4;
+
+    pub fn clear_requires(&mut self) {
+        self.requires.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requires(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.requires = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_requires(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.requires
+    }
+
+    // Take field
+    pub fn take_requires(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.requires, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_requires(&self) -> &[::std::string::String] {
+        &self.requires
+    }
+
+    // optional string worker = 5;
+
+    pub fn clear_worker(&mut self) {
+        self.worker.clear();
+    }
+
+    pub fn has_worker(&self) -> bool {
+        self.worker.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_worker(&mut self, v: ::std::string::String) {
+        self.worker = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_worker(&mut self) -> &mut ::std::string::String {
+        if self.worker.is_none() {
+            self.worker.set_default();
+        };
+        self.worker.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_worker(&mut self) -> ::std::string::String {
+        self.worker.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_worker(&self) -> &str {
+        match self.worker.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional uint32 attempts = 6;
+
+    pub fn clear_attempts(&mut self) {
+        self.attempts = ::std::option::Option::None;
+    }
+
+    pub fn has_attempts(&self) -> bool {
+        self.attempts.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attempts(&mut self, v: u32) {
+        self.attempts = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_attempts(&self) -> u32 {
+        self.attempts.unwrap_or(0)
+    }
+
+    // optional uint64 schedule_id = 7;
+
+    pub fn clear_schedule_id(&mut self) {
+        self.schedule_id = ::std::option::Option::None;
+    }
+
+    pub fn has_schedule_id(&self) -> bool {
+        self.schedule_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_schedule_id(&mut self, v: u64) {
+        self.schedule_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_schedule_id(&self) -> u64 {
+        self.schedule_id.unwrap_or(0)
+    }
+
+    // optional string project = 8;
+
+    pub fn clear_project(&mut self) {
+        self.project.clear();
+    }
+
+    pub fn has_project(&self) -> bool {
+        self.project.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_project(&mut self, v: ::std::string::String) {
+        self.project = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_project(&mut self) -> &mut ::std::string::String {
+        if self.project.is_none() {
+            self.project.set_default();
+        };
+        self.project.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_project(&mut self) -> ::std::string::String {
+        self.project.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_project(&self) -> &str {
+        match self.project.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional uint64 group_id = 9;
+
+    pub fn clear_group_id(&mut self) {
+        self.group_id = ::std::option::Option::None;
+    }
+
+    pub fn has_group_id(&self) -> bool {
+        self.group_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_group_id(&mut self, v: u64) {
+        self.group_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_group_id(&self) -> u64 {
+        self.group_id.unwrap_or(0)
+    }
+
+    // optional uint64 owner_id = 10;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // optional string package_ident = 11;
+
+    pub fn clear_package_ident(&mut self) {
+        self.package_ident.clear();
+    }
+
+    pub fn has_package_ident(&self) -> bool {
+        self.package_ident.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_package_ident(&mut self, v: ::std::string::String) {
+        self.package_ident = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_package_ident(&mut self) -> &mut ::std::string::String {
+        if self.package_ident.is_none() {
+            self.package_ident.set_default();
+        };
+        self.package_ident.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_package_ident(&mut self) -> ::std::string::String {
+        self.package_ident.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_package_ident(&self) -> &str {
+        match self.package_ident.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
 }
 
 impl ::protobuf::Message for Job {
@@ -393,6 +722,53 @@ impl ::protobuf::Message for Job {
                     let tmp = try!(is.read_enum());
                     self.state = ::std::option::Option::Some(tmp);
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.priority = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.requires));
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.worker));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.attempts = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.schedule_id = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.project));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.group_id = ::std::option::Option::Some(tmp);
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                11 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.package_ident));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -411,6 +787,33 @@ impl ::protobuf::Message for Job {
         for value in self.state.iter() {
             my_size += ::protobuf::rt::enum_size(2, *value);
         };
+        for value in self.priority.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.requires.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.worker.iter() {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        for value in self.attempts.iter() {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.schedule_id.iter() {
+            my_size += ::protobuf::rt::value_size(7, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.project.iter() {
+            my_size += ::protobuf::rt::string_size(8, &value);
+        };
+        for value in self.group_id.iter() {
+            my_size += ::protobuf::rt::value_size(9, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(10, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.package_ident.iter() {
+            my_size += ::protobuf::rt::string_size(11, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -423,6 +826,33 @@ impl ::protobuf::Message for Job {
         if let Some(v) = self.state {
             try!(os.write_enum(2, v.value()));
         };
+        if let Some(v) = self.priority {
+            try!(os.write_uint32(3, v));
+        };
+        for v in self.requires.iter() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.worker.as_ref() {
+            try!(os.write_string(5, &v));
+        };
+        if let Some(v) = self.attempts {
+            try!(os.write_uint32(6, v));
+        };
+        if let Some(v) = self.schedule_id {
+            try!(os.write_uint64(7, v));
+        };
+        if let Some(v) = self.project.as_ref() {
+            try!(os.write_string(8, &v));
+        };
+        if let Some(v) = self.group_id {
+            try!(os.write_uint64(9, v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(10, v));
+        };
+        if let Some(v) = self.package_ident.as_ref() {
+            try!(os.write_string(11, &v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -475,6 +905,50 @@ impl ::protobuf::MessageStatic for Job {
                     Job::has_state,
                     Job::get_state,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "priority",
+                    Job::has_priority,
+                    Job::get_priority,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "requires",
+                    Job::get_requires,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "worker",
+                    Job::has_worker,
+                    Job::get_worker,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "attempts",
+                    Job::has_attempts,
+                    Job::get_attempts,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "schedule_id",
+                    Job::has_schedule_id,
+                    Job::get_schedule_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "project",
+                    Job::has_project,
+                    Job::get_project,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "group_id",
+                    Job::has_group_id,
+                    Job::get_group_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    Job::has_owner_id,
+                    Job::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "package_ident",
+                    Job::has_package_ident,
+                    Job::get_package_ident,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Job>(
                     "Job",
                     fields,
@@ -489,6 +963,15 @@ impl ::protobuf::Clear for Job {
     fn clear(&mut self) {
         self.clear_id();
         self.clear_state();
+        self.clear_priority();
+        self.clear_requires();
+        self.clear_worker();
+        self.clear_attempts();
+        self.clear_schedule_id();
+        self.clear_project();
+        self.clear_group_id();
+        self.clear_owner_id();
+        self.clear_package_ident();
         self.unknown_fields.clear();
     }
 }
@@ -497,6 +980,15 @@ impl ::std::cmp::PartialEq for Job {
     fn eq(&self, other: &Job) -> bool {
         self.id == other.id &&
         self.state == other.state &&
+        self.priority == other.priority &&
+        self.requires == other.requires &&
+        self.worker == other.worker &&
+        self.attempts == other.attempts &&
+        self.schedule_id == other.schedule_id &&
+        self.project == other.project &&
+        self.group_id == other.group_id &&
+        self.owner_id == other.owner_id &&
+        self.package_ident == other.package_ident &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -853,17 +1345,3877 @@ impl ::std::fmt::Debug for JobCreate {
     }
 }
 
-#[derive(Clone,PartialEq,Eq,Debug,Hash)]
-pub enum Os {
-    Linux = 1,
-    Darwin = 2,
-    Windows = 3,
+#[derive(Clone,Default)]
+pub struct JobEnqueue {
+    // message fields
+    owner_id: ::std::option::Option<u64>,
+    priority: ::std::option::Option<u32>,
+    requires: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
 }
 
-impl ::protobuf::ProtobufEnum for Os {
-    fn value(&self) -> i32 {
-        *self as i32
-    }
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobEnqueue {}
+
+impl JobEnqueue {
+    pub fn new() -> JobEnqueue {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobEnqueue {
+        static mut instance: ::protobuf::lazy::Lazy<JobEnqueue> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobEnqueue,
+        };
+        unsafe {
+            instance.get(|| {
+                JobEnqueue {
+                    owner_id: ::std::option::Option::None,
+                    priority: ::std::option::Option::None,
+                    requires: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 owner_id = 1;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // optional uint32 priority = 2;
+
+    pub fn clear_priority(&mut self) {
+        self.priority = ::std::option::Option::None;
+    }
+
+    pub fn has_priority(&self) -> bool {
+        self.priority.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priority(&mut self, v: u32) {
+        self.priority = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_priority(&self) -> u32 {
+        self.priority.unwrap_or(0)
+    }
+
+    // repeated string requires = 3;
+
+    pub fn clear_requires(&mut self) {
+        self.requires.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requires(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.requires = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_requires(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.requires
+    }
+
+    // Take field
+    pub fn take_requires(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.requires, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_requires(&self) -> &[::std::string::String] {
+        &self.requires
+    }
+}
+
+impl ::protobuf::Message for JobEnqueue {
+    fn is_initialized(&self) -> bool {
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.priority = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.requires));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.priority.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.requires.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.priority {
+            try!(os.write_uint32(2, v));
+        };
+        for v in self.requires.iter() {
+            try!(os.write_string(3, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobEnqueue>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobEnqueue {
+    fn new() -> JobEnqueue {
+        JobEnqueue::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobEnqueue>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    JobEnqueue::has_owner_id,
+                    JobEnqueue::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "priority",
+                    JobEnqueue::has_priority,
+                    JobEnqueue::get_priority,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "requires",
+                    JobEnqueue::get_requires,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobEnqueue>(
+                    "JobEnqueue",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobEnqueue {
+    fn clear(&mut self) {
+        self.clear_owner_id();
+        self.clear_priority();
+        self.clear_requires();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobEnqueue {
+    fn eq(&self, other: &JobEnqueue) -> bool {
+        self.owner_id == other.owner_id &&
+        self.priority == other.priority &&
+        self.requires == other.requires &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobEnqueue {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobLogChunk {
+    // message fields
+    job_id: ::std::option::Option<u64>,
+    seq: ::std::option::Option<u64>,
+    content: ::protobuf::SingularField<::std::string::String>,
+    complete: ::std::option::Option<bool>,
+    attempt: ::std::option::Option<u32>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobLogChunk {}
+
+impl JobLogChunk {
+    pub fn new() -> JobLogChunk {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobLogChunk {
+        static mut instance: ::protobuf::lazy::Lazy<JobLogChunk> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobLogChunk,
+        };
+        unsafe {
+            instance.get(|| {
+                JobLogChunk {
+                    job_id: ::std::option::Option::None,
+                    seq: ::std::option::Option::None,
+                    content: ::protobuf::SingularField::none(),
+                    complete: ::std::option::Option::None,
+                    attempt: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 job_id = 1;
+
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
+    }
+
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
+    }
+
+    // required uint64 seq = 2;
+
+    pub fn clear_seq(&mut self) {
+        self.seq = ::std::option::Option::None;
+    }
+
+    pub fn has_seq(&self) -> bool {
+        self.seq.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seq(&mut self, v: u64) {
+        self.seq = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_seq(&self) -> u64 {
+        self.seq.unwrap_or(0)
+    }
+
+    // required string content = 3;
+
+    pub fn clear_content(&mut self) {
+        self.content.clear();
+    }
+
+    pub fn has_content(&self) -> bool {
+        self.content.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content(&mut self, v: ::std::string::String) {
+        self.content = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content(&mut self) -> &mut ::std::string::String {
+        if self.content.is_none() {
+            self.content.set_default();
+        };
+        self.content.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_content(&mut self) -> ::std::string::String {
+        self.content.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_content(&self) -> &str {
+        match self.content.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional bool complete = 4;
+
+    pub fn clear_complete(&mut self) {
+        self.complete = ::std::option::Option::None;
+    }
+
+    pub fn has_complete(&self) -> bool {
+        self.complete.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_complete(&mut self, v: bool) {
+        self.complete = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_complete(&self) -> bool {
+        self.complete.unwrap_or(false)
+    }
+
+    // optional uint32 attempt = 5;
+
+    pub fn clear_attempt(&mut self) {
+        self.attempt = ::std::option::Option::None;
+    }
+
+    pub fn has_attempt(&self) -> bool {
+        self.attempt.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attempt(&mut self, v: u32) {
+        self.attempt = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_attempt(&self) -> u32 {
+        self.attempt.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for JobLogChunk {
+    fn is_initialized(&self) -> bool {
+        if self.job_id.is_none() {
+            return false;
+        };
+        if self.seq.is_none() {
+            return false;
+        };
+        if self.content.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.job_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.seq = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.content));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.complete = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.attempt = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.job_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.seq.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.content.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        if self.complete.is_some() {
+            my_size += 2;
+        };
+        for value in self.attempt.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.job_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.seq {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.content.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.complete {
+            try!(os.write_bool(4, v));
+        };
+        if let Some(v) = self.attempt {
+            try!(os.write_uint32(5, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobLogChunk>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobLogChunk {
+    fn new() -> JobLogChunk {
+        JobLogChunk::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobLogChunk>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "job_id",
+                    JobLogChunk::has_job_id,
+                    JobLogChunk::get_job_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "seq",
+                    JobLogChunk::has_seq,
+                    JobLogChunk::get_seq,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "content",
+                    JobLogChunk::has_content,
+                    JobLogChunk::get_content,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "complete",
+                    JobLogChunk::has_complete,
+                    JobLogChunk::get_complete,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "attempt",
+                    JobLogChunk::has_attempt,
+                    JobLogChunk::get_attempt,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobLogChunk>(
+                    "JobLogChunk",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobLogChunk {
+    fn clear(&mut self) {
+        self.clear_job_id();
+        self.clear_seq();
+        self.clear_content();
+        self.clear_complete();
+        self.clear_attempt();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobLogChunk {
+    fn eq(&self, other: &JobLogChunk) -> bool {
+        self.job_id == other.job_id &&
+        self.seq == other.seq &&
+        self.content == other.content &&
+        self.complete == other.complete &&
+        self.attempt == other.attempt &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobLogChunk {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobLogGet {
+    // message fields
+    job_id: ::std::option::Option<u64>,
+    start: ::std::option::Option<u64>,
+    attempt: ::std::option::Option<u32>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobLogGet {}
+
+impl JobLogGet {
+    pub fn new() -> JobLogGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobLogGet {
+        static mut instance: ::protobuf::lazy::Lazy<JobLogGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobLogGet,
+        };
+        unsafe {
+            instance.get(|| {
+                JobLogGet {
+                    job_id: ::std::option::Option::None,
+                    start: ::std::option::Option::None,
+                    attempt: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 job_id = 1;
+
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
+    }
+
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
+    }
+
+    // optional uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    // optional uint32 attempt = 3;
+
+    pub fn clear_attempt(&mut self) {
+        self.attempt = ::std::option::Option::None;
+    }
+
+    pub fn has_attempt(&self) -> bool {
+        self.attempt.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attempt(&mut self, v: u32) {
+        self.attempt = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_attempt(&self) -> u32 {
+        self.attempt.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for JobLogGet {
+    fn is_initialized(&self) -> bool {
+        if self.job_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.job_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.attempt = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.job_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.start.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.attempt.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.job_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.start {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.attempt {
+            try!(os.write_uint32(3, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobLogGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobLogGet {
+    fn new() -> JobLogGet {
+        JobLogGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobLogGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "job_id",
+                    JobLogGet::has_job_id,
+                    JobLogGet::get_job_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "start",
+                    JobLogGet::has_start,
+                    JobLogGet::get_start,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "attempt",
+                    JobLogGet::has_attempt,
+                    JobLogGet::get_attempt,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobLogGet>(
+                    "JobLogGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobLogGet {
+    fn clear(&mut self) {
+        self.clear_job_id();
+        self.clear_start();
+        self.clear_attempt();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobLogGet {
+    fn eq(&self, other: &JobLogGet) -> bool {
+        self.job_id == other.job_id &&
+        self.start == other.start &&
+        self.attempt == other.attempt &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobLogGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobLog {
+    // message fields
+    job_id: ::std::option::Option<u64>,
+    start: ::std::option::Option<u64>,
+    stop: ::std::option::Option<u64>,
+    content: ::protobuf::RepeatedField<::std::string::String>,
+    complete: ::std::option::Option<bool>,
+    attempt: ::std::option::Option<u32>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobLog {}
+
+impl JobLog {
+    pub fn new() -> JobLog {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobLog {
+        static mut instance: ::protobuf::lazy::Lazy<JobLog> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobLog,
+        };
+        unsafe {
+            instance.get(|| {
+                JobLog {
+                    job_id: ::std::option::Option::None,
+                    start: ::std::option::Option::None,
+                    stop: ::std::option::Option::None,
+                    content: ::protobuf::RepeatedField::new(),
+                    complete: ::std::option::Option::None,
+                    attempt: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 job_id = 1;
+
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
+    }
+
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
+    }
+
+    // required uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    // required uint64 stop = 3;
+
+    pub fn clear_stop(&mut self) {
+        self.stop = ::std::option::Option::None;
+    }
+
+    pub fn has_stop(&self) -> bool {
+        self.stop.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stop(&mut self, v: u64) {
+        self.stop = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_stop(&self) -> u64 {
+        self.stop.unwrap_or(0)
+    }
+
+    // repeated string content = 4;
+
+    pub fn clear_content(&mut self) {
+        self.content.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.content = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_content(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.content
+    }
+
+    // Take field
+    pub fn take_content(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.content, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_content(&self) -> &[::std::string::String] {
+        &self.content
+    }
+
+    // required bool complete = 5;
+
+    pub fn clear_complete(&mut self) {
+        self.complete = ::std::option::Option::None;
+    }
+
+    pub fn has_complete(&self) -> bool {
+        self.complete.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_complete(&mut self, v: bool) {
+        self.complete = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_complete(&self) -> bool {
+        self.complete.unwrap_or(false)
+    }
+
+    // optional uint32 attempt = 6;
+
+    pub fn clear_attempt(&mut self) {
+        self.attempt = ::std::option::Option::None;
+    }
+
+    pub fn has_attempt(&self) -> bool {
+        self.attempt.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attempt(&mut self, v: u32) {
+        self.attempt = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_attempt(&self) -> u32 {
+        self.attempt.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for JobLog {
+    fn is_initialized(&self) -> bool {
+        if self.job_id.is_none() {
+            return false;
+        };
+        if self.start.is_none() {
+            return false;
+        };
+        if self.stop.is_none() {
+            return false;
+        };
+        if self.complete.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.job_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.stop = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.content));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.complete = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.attempt = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.job_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.start.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.stop.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.content.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        if self.complete.is_some() {
+            my_size += 2;
+        };
+        for value in self.attempt.iter() {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.job_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.start {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.stop {
+            try!(os.write_uint64(3, v));
+        };
+        for v in self.content.iter() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.complete {
+            try!(os.write_bool(5, v));
+        };
+        if let Some(v) = self.attempt {
+            try!(os.write_uint32(6, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobLog>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobLog {
+    fn new() -> JobLog {
+        JobLog::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobLog>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "job_id",
+                    JobLog::has_job_id,
+                    JobLog::get_job_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "start",
+                    JobLog::has_start,
+                    JobLog::get_start,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "stop",
+                    JobLog::has_stop,
+                    JobLog::get_stop,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "content",
+                    JobLog::get_content,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "complete",
+                    JobLog::has_complete,
+                    JobLog::get_complete,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "attempt",
+                    JobLog::has_attempt,
+                    JobLog::get_attempt,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobLog>(
+                    "JobLog",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobLog {
+    fn clear(&mut self) {
+        self.clear_job_id();
+        self.clear_start();
+        self.clear_stop();
+        self.clear_content();
+        self.clear_complete();
+        self.clear_attempt();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobLog {
+    fn eq(&self, other: &JobLog) -> bool {
+        self.job_id == other.job_id &&
+        self.start == other.start &&
+        self.stop == other.stop &&
+        self.content == other.content &&
+        self.complete == other.complete &&
+        self.attempt == other.attempt &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobLog {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobSchedule {
+    // message fields
+    id: ::std::option::Option<u64>,
+    owner_id: ::std::option::Option<u64>,
+    project: ::protobuf::SingularField<::std::string::String>,
+    cron: ::protobuf::SingularField<::std::string::String>,
+    priority: ::std::option::Option<u32>,
+    requires: ::protobuf::RepeatedField<::std::string::String>,
+    next_run_ms: ::std::option::Option<i64>,
+    last_job_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobSchedule {}
+
+impl JobSchedule {
+    pub fn new() -> JobSchedule {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobSchedule {
+        static mut instance: ::protobuf::lazy::Lazy<JobSchedule> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobSchedule,
+        };
+        unsafe {
+            instance.get(|| {
+                JobSchedule {
+                    id: ::std::option::Option::None,
+                    owner_id: ::std::option::Option::None,
+                    project: ::protobuf::SingularField::none(),
+                    cron: ::protobuf::SingularField::none(),
+                    priority: ::std::option::Option::None,
+                    requires: ::protobuf::RepeatedField::new(),
+                    next_run_ms: ::std::option::Option::None,
+                    last_job_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    // required uint64 owner_id = 2;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // required string project = 3;
+
+    pub fn clear_project(&mut self) {
+        self.project.clear();
+    }
+
+    pub fn has_project(&self) -> bool {
+        self.project.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_project(&mut self, v: ::std::string::String) {
+        self.project = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_project(&mut self) -> &mut ::std::string::String {
+        if self.project.is_none() {
+            self.project.set_default();
+        };
+        self.project.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_project(&mut self) -> ::std::string::String {
+        self.project.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_project(&self) -> &str {
+        match self.project.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string cron = 4;
+
+    pub fn clear_cron(&mut self) {
+        self.cron.clear();
+    }
+
+    pub fn has_cron(&self) -> bool {
+        self.cron.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cron(&mut self, v: ::std::string::String) {
+        self.cron = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cron(&mut self) -> &mut ::std::string::String {
+        if self.cron.is_none() {
+            self.cron.set_default();
+        };
+        self.cron.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_cron(&mut self) -> ::std::string::String {
+        self.cron.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_cron(&self) -> &str {
+        match self.cron.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional uint32 priority = 5;
+
+    pub fn clear_priority(&mut self) {
+        self.priority = ::std::option::Option::None;
+    }
+
+    pub fn has_priority(&self) -> bool {
+        self.priority.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priority(&mut self, v: u32) {
+        self.priority = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_priority(&self) -> u32 {
+        self.priority.unwrap_or(0)
+    }
+
+    // repeated string requires = 6;
+
+    pub fn clear_requires(&mut self) {
+        self.requires.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requires(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.requires = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_requires(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.requires
+    }
+
+    // Take field
+    pub fn take_requires(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.requires, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_requires(&self) -> &[::std::string::String] {
+        &self.requires
+    }
+
+    // optional int64 next_run_ms = 7;
+
+    pub fn clear_next_run_ms(&mut self) {
+        self.next_run_ms = ::std::option::Option::None;
+    }
+
+    pub fn has_next_run_ms(&self) -> bool {
+        self.next_run_ms.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_run_ms(&mut self, v: i64) {
+        self.next_run_ms = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_next_run_ms(&self) -> i64 {
+        self.next_run_ms.unwrap_or(0)
+    }
+
+    // optional uint64 last_job_id = 8;
+
+    pub fn clear_last_job_id(&mut self) {
+        self.last_job_id = ::std::option::Option::None;
+    }
+
+    pub fn has_last_job_id(&self) -> bool {
+        self.last_job_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_job_id(&mut self, v: u64) {
+        self.last_job_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_last_job_id(&self) -> u64 {
+        self.last_job_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for JobSchedule {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        if self.project.is_none() {
+            return false;
+        };
+        if self.cron.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.project));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.cron));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.priority = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.requires));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_int64());
+                    self.next_run_ms = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.last_job_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.project.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.cron.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.priority.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.requires.iter() {
+            my_size += ::protobuf::rt::string_size(6, &value);
+        };
+        for value in self.next_run_ms.iter() {
+            my_size += ::protobuf::rt::value_size(7, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.last_job_id.iter() {
+            my_size += ::protobuf::rt::value_size(8, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.project.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.cron.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.priority {
+            try!(os.write_uint32(5, v));
+        };
+        for v in self.requires.iter() {
+            try!(os.write_string(6, &v));
+        };
+        if let Some(v) = self.next_run_ms {
+            try!(os.write_int64(7, v));
+        };
+        if let Some(v) = self.last_job_id {
+            try!(os.write_uint64(8, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobSchedule>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobSchedule {
+    fn new() -> JobSchedule {
+        JobSchedule::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobSchedule>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    JobSchedule::has_id,
+                    JobSchedule::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    JobSchedule::has_owner_id,
+                    JobSchedule::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "project",
+                    JobSchedule::has_project,
+                    JobSchedule::get_project,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "cron",
+                    JobSchedule::has_cron,
+                    JobSchedule::get_cron,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "priority",
+                    JobSchedule::has_priority,
+                    JobSchedule::get_priority,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "requires",
+                    JobSchedule::get_requires,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor(
+                    "next_run_ms",
+                    JobSchedule::has_next_run_ms,
+                    JobSchedule::get_next_run_ms,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "last_job_id",
+                    JobSchedule::has_last_job_id,
+                    JobSchedule::get_last_job_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobSchedule>(
+                    "JobSchedule",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobSchedule {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_owner_id();
+        self.clear_project();
+        self.clear_cron();
+        self.clear_priority();
+        self.clear_requires();
+        self.clear_next_run_ms();
+        self.clear_last_job_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobSchedule {
+    fn eq(&self, other: &JobSchedule) -> bool {
+        self.id == other.id &&
+        self.owner_id == other.owner_id &&
+        self.project == other.project &&
+        self.cron == other.cron &&
+        self.priority == other.priority &&
+        self.requires == other.requires &&
+        self.next_run_ms == other.next_run_ms &&
+        self.last_job_id == other.last_job_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobSchedule {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobScheduleCreate {
+    // message fields
+    owner_id: ::std::option::Option<u64>,
+    project: ::protobuf::SingularField<::std::string::String>,
+    cron: ::protobuf::SingularField<::std::string::String>,
+    priority: ::std::option::Option<u32>,
+    requires: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobScheduleCreate {}
+
+impl JobScheduleCreate {
+    pub fn new() -> JobScheduleCreate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobScheduleCreate {
+        static mut instance: ::protobuf::lazy::Lazy<JobScheduleCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobScheduleCreate,
+        };
+        unsafe {
+            instance.get(|| {
+                JobScheduleCreate {
+                    owner_id: ::std::option::Option::None,
+                    project: ::protobuf::SingularField::none(),
+                    cron: ::protobuf::SingularField::none(),
+                    priority: ::std::option::Option::None,
+                    requires: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 owner_id = 1;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // required string project = 2;
+
+    pub fn clear_project(&mut self) {
+        self.project.clear();
+    }
+
+    pub fn has_project(&self) -> bool {
+        self.project.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_project(&mut self, v: ::std::string::String) {
+        self.project = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_project(&mut self) -> &mut ::std::string::String {
+        if self.project.is_none() {
+            self.project.set_default();
+        };
+        self.project.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_project(&mut self) -> ::std::string::String {
+        self.project.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_project(&self) -> &str {
+        match self.project.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string cron = 3;
+
+    pub fn clear_cron(&mut self) {
+        self.cron.clear();
+    }
+
+    pub fn has_cron(&self) -> bool {
+        self.cron.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cron(&mut self, v: ::std::string::String) {
+        self.cron = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cron(&mut self) -> &mut ::std::string::String {
+        if self.cron.is_none() {
+            self.cron.set_default();
+        };
+        self.cron.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_cron(&mut self) -> ::std::string::String {
+        self.cron.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_cron(&self) -> &str {
+        match self.cron.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional uint32 priority = 4;
+
+    pub fn clear_priority(&mut self) {
+        self.priority = ::std::option::Option::None;
+    }
+
+    pub fn has_priority(&self) -> bool {
+        self.priority.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priority(&mut self, v: u32) {
+        self.priority = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_priority(&self) -> u32 {
+        self.priority.unwrap_or(0)
+    }
+
+    // repeated string requires = 5;
+
+    pub fn clear_requires(&mut self) {
+        self.requires.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requires(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.requires = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_requires(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.requires
+    }
+
+    // Take field
+    pub fn take_requires(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.requires, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_requires(&self) -> &[::std::string::String] {
+        &self.requires
+    }
+}
+
+impl ::protobuf::Message for JobScheduleCreate {
+    fn is_initialized(&self) -> bool {
+        if self.owner_id.is_none() {
+            return false;
+        };
+        if self.project.is_none() {
+            return false;
+        };
+        if self.cron.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.project));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.cron));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.priority = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.requires));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.project.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.cron.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.priority.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.requires.iter() {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.project.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.cron.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.priority {
+            try!(os.write_uint32(4, v));
+        };
+        for v in self.requires.iter() {
+            try!(os.write_string(5, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobScheduleCreate>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobScheduleCreate {
+    fn new() -> JobScheduleCreate {
+        JobScheduleCreate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobScheduleCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    JobScheduleCreate::has_owner_id,
+                    JobScheduleCreate::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "project",
+                    JobScheduleCreate::has_project,
+                    JobScheduleCreate::get_project,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "cron",
+                    JobScheduleCreate::has_cron,
+                    JobScheduleCreate::get_cron,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "priority",
+                    JobScheduleCreate::has_priority,
+                    JobScheduleCreate::get_priority,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "requires",
+                    JobScheduleCreate::get_requires,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobScheduleCreate>(
+                    "JobScheduleCreate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobScheduleCreate {
+    fn clear(&mut self) {
+        self.clear_owner_id();
+        self.clear_project();
+        self.clear_cron();
+        self.clear_priority();
+        self.clear_requires();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobScheduleCreate {
+    fn eq(&self, other: &JobScheduleCreate) -> bool {
+        self.owner_id == other.owner_id &&
+        self.project == other.project &&
+        self.cron == other.cron &&
+        self.priority == other.priority &&
+        self.requires == other.requires &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobScheduleCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobScheduleListRequest {
+    // message fields
+    owner_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobScheduleListRequest {}
+
+impl JobScheduleListRequest {
+    pub fn new() -> JobScheduleListRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobScheduleListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<JobScheduleListRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobScheduleListRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                JobScheduleListRequest {
+                    owner_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 owner_id = 1;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for JobScheduleListRequest {
+    fn is_initialized(&self) -> bool {
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(1, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobScheduleListRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobScheduleListRequest {
+    fn new() -> JobScheduleListRequest {
+        JobScheduleListRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobScheduleListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    JobScheduleListRequest::has_owner_id,
+                    JobScheduleListRequest::get_owner_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobScheduleListRequest>(
+                    "JobScheduleListRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobScheduleListRequest {
+    fn clear(&mut self) {
+        self.clear_owner_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobScheduleListRequest {
+    fn eq(&self, other: &JobScheduleListRequest) -> bool {
+        self.owner_id == other.owner_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobScheduleListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobScheduleListResponse {
+    // message fields
+    owner_id: ::std::option::Option<u64>,
+    schedules: ::protobuf::RepeatedField<JobSchedule>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobScheduleListResponse {}
+
+impl JobScheduleListResponse {
+    pub fn new() -> JobScheduleListResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobScheduleListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<JobScheduleListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobScheduleListResponse,
+        };
+        unsafe {
+            instance.get(|| {
+                JobScheduleListResponse {
+                    owner_id: ::std::option::Option::None,
+                    schedules: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 owner_id = 1;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // repeated .jobsrv.JobSchedule schedules = 2;
+
+    pub fn clear_schedules(&mut self) {
+        self.schedules.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_schedules(&mut self, v: ::protobuf::RepeatedField<JobSchedule>) {
+        self.schedules = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_schedules(&mut self) -> &mut ::protobuf::RepeatedField<JobSchedule> {
+        &mut self.schedules
+    }
+
+    // Take field
+    pub fn take_schedules(&mut self) -> ::protobuf::RepeatedField<JobSchedule> {
+        ::std::mem::replace(&mut self.schedules, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_schedules(&self) -> &[JobSchedule] {
+        &self.schedules
+    }
+}
+
+impl ::protobuf::Message for JobScheduleListResponse {
+    fn is_initialized(&self) -> bool {
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.schedules));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.schedules.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(1, v));
+        };
+        for v in self.schedules.iter() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobScheduleListResponse>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobScheduleListResponse {
+    fn new() -> JobScheduleListResponse {
+        JobScheduleListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobScheduleListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    JobScheduleListResponse::has_owner_id,
+                    JobScheduleListResponse::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "schedules",
+                    JobScheduleListResponse::get_schedules,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobScheduleListResponse>(
+                    "JobScheduleListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobScheduleListResponse {
+    fn clear(&mut self) {
+        self.clear_owner_id();
+        self.clear_schedules();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobScheduleListResponse {
+    fn eq(&self, other: &JobScheduleListResponse) -> bool {
+        self.owner_id == other.owner_id &&
+        self.schedules == other.schedules &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobScheduleListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobGroupProject {
+    // message fields
+    name: ::protobuf::SingularField<::std::string::String>,
+    state: ::std::option::Option<JobGroupProjectState>,
+    job_id: ::std::option::Option<u64>,
+    deps: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGroupProject {}
+
+impl JobGroupProject {
+    pub fn new() -> JobGroupProject {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGroupProject {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroupProject> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGroupProject,
+        };
+        unsafe {
+            instance.get(|| {
+                JobGroupProject {
+                    name: ::protobuf::SingularField::none(),
+                    state: ::std::option::Option::None,
+                    job_id: ::std::option::Option::None,
+                    deps: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required .jobsrv.JobGroupProjectState state = 2;
+
+    pub fn clear_state(&mut self) {
+        self.state = ::std::option::Option::None;
+    }
+
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: JobGroupProjectState) {
+        self.state = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_state(&self) -> JobGroupProjectState {
+        self.state.unwrap_or(JobGroupProjectState::NotStarted)
+    }
+
+    // optional uint64 job_id = 3;
+
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
+    }
+
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
+    }
+
+    // repeated string deps = 4;
+
+    pub fn clear_deps(&mut self) {
+        self.deps.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deps(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.deps = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_deps(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.deps
+    }
+
+    // Take field
+    pub fn take_deps(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.deps, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_deps(&self) -> &[::std::string::String] {
+        &self.deps
+    }
+}
+
+impl ::protobuf::Message for JobGroupProject {
+    fn is_initialized(&self) -> bool {
+        if self.name.is_none() {
+            return false;
+        };
+        if self.state.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.state = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.job_id = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.deps));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.state.iter() {
+            my_size += ::protobuf::rt::enum_size(2, *value);
+        };
+        for value in self.job_id.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.deps.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.state {
+            try!(os.write_enum(2, v.value()));
+        };
+        if let Some(v) = self.job_id {
+            try!(os.write_uint64(3, v));
+        };
+        for v in self.deps.iter() {
+            try!(os.write_string(4, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobGroupProject>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGroupProject {
+    fn new() -> JobGroupProject {
+        JobGroupProject::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGroupProject>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    JobGroupProject::has_name,
+                    JobGroupProject::get_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "state",
+                    JobGroupProject::has_state,
+                    JobGroupProject::get_state,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "job_id",
+                    JobGroupProject::has_job_id,
+                    JobGroupProject::get_job_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "deps",
+                    JobGroupProject::get_deps,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroupProject>(
+                    "JobGroupProject",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGroupProject {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_state();
+        self.clear_job_id();
+        self.clear_deps();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobGroupProject {
+    fn eq(&self, other: &JobGroupProject) -> bool {
+        self.name == other.name &&
+        self.state == other.state &&
+        self.job_id == other.job_id &&
+        self.deps == other.deps &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobGroupProject {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobGroup {
+    // message fields
+    id: ::std::option::Option<u64>,
+    owner_id: ::std::option::Option<u64>,
+    project: ::protobuf::SingularField<::std::string::String>,
+    state: ::std::option::Option<JobGroupState>,
+    projects: ::protobuf::RepeatedField<JobGroupProject>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGroup {}
+
+impl JobGroup {
+    pub fn new() -> JobGroup {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGroup {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroup> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGroup,
+        };
+        unsafe {
+            instance.get(|| {
+                JobGroup {
+                    id: ::std::option::Option::None,
+                    owner_id: ::std::option::Option::None,
+                    project: ::protobuf::SingularField::none(),
+                    state: ::std::option::Option::None,
+                    projects: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    // required uint64 owner_id = 2;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // required string project = 3;
+
+    pub fn clear_project(&mut self) {
+        self.project.clear();
+    }
+
+    pub fn has_project(&self) -> bool {
+        self.project.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_project(&mut self, v: ::std::string::String) {
+        self.project = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_project(&mut self) -> &mut ::std::string::String {
+        if self.project.is_none() {
+            self.project.set_default();
+        };
+        self.project.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_project(&mut self) -> ::std::string::String {
+        self.project.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_project(&self) -> &str {
+        match self.project.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required .jobsrv.JobGroupState state = 4;
+
+    pub fn clear_state(&mut self) {
+        self.state = ::std::option::Option::None;
+    }
+
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: JobGroupState) {
+        self.state = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_state(&self) -> JobGroupState {
+        self.state.unwrap_or(JobGroupState::GroupQueued)
+    }
+
+    // repeated .jobsrv.JobGroupProject projects = 5;
+
+    pub fn clear_projects(&mut self) {
+        self.projects.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_projects(&mut self, v: ::protobuf::RepeatedField<JobGroupProject>) {
+        self.projects = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_projects(&mut self) -> &mut ::protobuf::RepeatedField<JobGroupProject> {
+        &mut self.projects
+    }
+
+    // Take field
+    pub fn take_projects(&mut self) -> ::protobuf::RepeatedField<JobGroupProject> {
+        ::std::mem::replace(&mut self.projects, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_projects(&self) -> &[JobGroupProject] {
+        &self.projects
+    }
+}
+
+impl ::protobuf::Message for JobGroup {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        if self.project.is_none() {
+            return false;
+        };
+        if self.state.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.project));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.state = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.projects));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.project.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.state.iter() {
+            my_size += ::protobuf::rt::enum_size(4, *value);
+        };
+        for value in self.projects.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.project.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.state {
+            try!(os.write_enum(4, v.value()));
+        };
+        for v in self.projects.iter() {
+            try!(os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobGroup>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGroup {
+    fn new() -> JobGroup {
+        JobGroup::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGroup>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    JobGroup::has_id,
+                    JobGroup::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    JobGroup::has_owner_id,
+                    JobGroup::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "project",
+                    JobGroup::has_project,
+                    JobGroup::get_project,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "state",
+                    JobGroup::has_state,
+                    JobGroup::get_state,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "projects",
+                    JobGroup::get_projects,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroup>(
+                    "JobGroup",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGroup {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_owner_id();
+        self.clear_project();
+        self.clear_state();
+        self.clear_projects();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobGroup {
+    fn eq(&self, other: &JobGroup) -> bool {
+        self.id == other.id &&
+        self.owner_id == other.owner_id &&
+        self.project == other.project &&
+        self.state == other.state &&
+        self.projects == other.projects &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobGroup {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobGroupSpec {
+    // message fields
+    owner_id: ::std::option::Option<u64>,
+    project: ::protobuf::SingularField<::std::string::String>,
+    dependents_only: ::std::option::Option<bool>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGroupSpec {}
+
+impl JobGroupSpec {
+    pub fn new() -> JobGroupSpec {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGroupSpec {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroupSpec> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGroupSpec,
+        };
+        unsafe {
+            instance.get(|| {
+                JobGroupSpec {
+                    owner_id: ::std::option::Option::None,
+                    project: ::protobuf::SingularField::none(),
+                    dependents_only: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 owner_id = 1;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // required string project = 2;
+
+    pub fn clear_project(&mut self) {
+        self.project.clear();
+    }
+
+    pub fn has_project(&self) -> bool {
+        self.project.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_project(&mut self, v: ::std::string::String) {
+        self.project = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_project(&mut self) -> &mut ::std::string::String {
+        if self.project.is_none() {
+            self.project.set_default();
+        };
+        self.project.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_project(&mut self) -> ::std::string::String {
+        self.project.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_project(&self) -> &str {
+        match self.project.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional bool dependents_only = 3;
+
+    pub fn clear_dependents_only(&mut self) {
+        self.dependents_only = ::std::option::Option::None;
+    }
+
+    pub fn has_dependents_only(&self) -> bool {
+        self.dependents_only.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dependents_only(&mut self, v: bool) {
+        self.dependents_only = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_dependents_only(&self) -> bool {
+        self.dependents_only.unwrap_or(false)
+    }
+}
+
+impl ::protobuf::Message for JobGroupSpec {
+    fn is_initialized(&self) -> bool {
+        if self.owner_id.is_none() {
+            return false;
+        };
+        if self.project.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.project));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.dependents_only = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.project.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        if self.dependents_only.is_some() {
+            my_size += 2;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.project.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.dependents_only {
+            try!(os.write_bool(3, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobGroupSpec>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGroupSpec {
+    fn new() -> JobGroupSpec {
+        JobGroupSpec::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGroupSpec>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    JobGroupSpec::has_owner_id,
+                    JobGroupSpec::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "project",
+                    JobGroupSpec::has_project,
+                    JobGroupSpec::get_project,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "dependents_only",
+                    JobGroupSpec::has_dependents_only,
+                    JobGroupSpec::get_dependents_only,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroupSpec>(
+                    "JobGroupSpec",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGroupSpec {
+    fn clear(&mut self) {
+        self.clear_owner_id();
+        self.clear_project();
+        self.clear_dependents_only();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobGroupSpec {
+    fn eq(&self, other: &JobGroupSpec) -> bool {
+        self.owner_id == other.owner_id &&
+        self.project == other.project &&
+        self.dependents_only == other.dependents_only &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobGroupSpec {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobGroupGet {
+    // message fields
+    id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGroupGet {}
+
+impl JobGroupGet {
+    pub fn new() -> JobGroupGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGroupGet {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroupGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGroupGet,
+        };
+        unsafe {
+            instance.get(|| {
+                JobGroupGet {
+                    id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for JobGroupGet {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            try!(os.write_uint64(1, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobGroupGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGroupGet {
+    fn new() -> JobGroupGet {
+        JobGroupGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGroupGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    JobGroupGet::has_id,
+                    JobGroupGet::get_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroupGet>(
+                    "JobGroupGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGroupGet {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobGroupGet {
+    fn eq(&self, other: &JobGroupGet) -> bool {
+        self.id == other.id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobGroupGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobGraphPackage {
+    // message fields
+    project: ::protobuf::SingularField<::std::string::String>,
+    deps: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGraphPackage {}
+
+impl JobGraphPackage {
+    pub fn new() -> JobGraphPackage {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGraphPackage {
+        static mut instance: ::protobuf::lazy::Lazy<JobGraphPackage> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGraphPackage,
+        };
+        unsafe {
+            instance.get(|| {
+                JobGraphPackage {
+                    project: ::protobuf::SingularField::none(),
+                    deps: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string project = 1;
+
+    pub fn clear_project(&mut self) {
+        self.project.clear();
+    }
+
+    pub fn has_project(&self) -> bool {
+        self.project.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_project(&mut self, v: ::std::string::String) {
+        self.project = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_project(&mut self) -> &mut ::std::string::String {
+        if self.project.is_none() {
+            self.project.set_default();
+        };
+        self.project.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_project(&mut self) -> ::std::string::String {
+        self.project.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_project(&self) -> &str {
+        match self.project.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // repeated string deps = 2;
+
+    pub fn clear_deps(&mut self) {
+        self.deps.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deps(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.deps = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_deps(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.deps
+    }
+
+    // Take field
+    pub fn take_deps(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.deps, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_deps(&self) -> &[::std::string::String] {
+        &self.deps
+    }
+}
+
+impl ::protobuf::Message for JobGraphPackage {
+    fn is_initialized(&self) -> bool {
+        if self.project.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.project));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.deps));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.project.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.deps.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.project.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        for v in self.deps.iter() {
+            try!(os.write_string(2, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobGraphPackage>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGraphPackage {
+    fn new() -> JobGraphPackage {
+        JobGraphPackage::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGraphPackage>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "project",
+                    JobGraphPackage::has_project,
+                    JobGraphPackage::get_project,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "deps",
+                    JobGraphPackage::get_deps,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGraphPackage>(
+                    "JobGraphPackage",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGraphPackage {
+    fn clear(&mut self) {
+        self.clear_project();
+        self.clear_deps();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobGraphPackage {
+    fn eq(&self, other: &JobGraphPackage) -> bool {
+        self.project == other.project &&
+        self.deps == other.deps &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobGraphPackage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Os {
+    Linux = 1,
+    Darwin = 2,
+    Windows = 3,
+}
+
+impl ::protobuf::ProtobufEnum for Os {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
 
     fn from_i32(value: i32) -> ::std::option::Option<Os> {
         match value {
@@ -994,99 +5346,295 @@ impl ::protobuf::ProtobufEnum for JobState {
 impl ::std::marker::Copy for JobState {
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum JobGroupState {
+    GroupQueued = 0,
+    GroupDispatching = 1,
+    GroupComplete = 2,
+    GroupFailed = 3,
+}
+
+impl ::protobuf::ProtobufEnum for JobGroupState {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<JobGroupState> {
+        match value {
+            0 => ::std::option::Option::Some(JobGroupState::GroupQueued),
+            1 => ::std::option::Option::Some(JobGroupState::GroupDispatching),
+            2 => ::std::option::Option::Some(JobGroupState::GroupComplete),
+            3 => ::std::option::Option::Some(JobGroupState::GroupFailed),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [JobGroupState] = &[
+            JobGroupState::GroupQueued,
+            JobGroupState::GroupDispatching,
+            JobGroupState::GroupComplete,
+            JobGroupState::GroupFailed,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<JobGroupState>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("JobGroupState", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for JobGroupState {
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum JobGroupProjectState {
+    NotStarted = 0,
+    InProgress = 1,
+    Success = 2,
+    Failure = 3,
+    Skipped = 4,
+}
+
+impl ::protobuf::ProtobufEnum for JobGroupProjectState {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<JobGroupProjectState> {
+        match value {
+            0 => ::std::option::Option::Some(JobGroupProjectState::NotStarted),
+            1 => ::std::option::Option::Some(JobGroupProjectState::InProgress),
+            2 => ::std::option::Option::Some(JobGroupProjectState::Success),
+            3 => ::std::option::Option::Some(JobGroupProjectState::Failure),
+            4 => ::std::option::Option::Some(JobGroupProjectState::Skipped),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [JobGroupProjectState] = &[
+            JobGroupProjectState::NotStarted,
+            JobGroupProjectState::InProgress,
+            JobGroupProjectState::Success,
+            JobGroupProjectState::Failure,
+            JobGroupProjectState::Skipped,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<JobGroupProjectState>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("JobGroupProjectState", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for JobGroupProjectState {
+}
+
 static file_descriptor_proto_data: &'static [u8] = &[
     0x0a, 0x16, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x73, 0x2f, 0x6a, 0x6f, 0x62, 0x73,
     0x72, 0x76, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x06, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76,
-    0x22, 0x59, 0x0a, 0x09, 0x48, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74, 0x12, 0x10, 0x0a,
+    0x22, 0x7f, 0x0a, 0x09, 0x48, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74, 0x12, 0x10, 0x0a,
     0x08, 0x65, 0x6e, 0x64, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12,
     0x16, 0x0a, 0x02, 0x6f, 0x73, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0a, 0x2e, 0x6a, 0x6f,
     0x62, 0x73, 0x72, 0x76, 0x2e, 0x4f, 0x73, 0x12, 0x22, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65,
     0x18, 0x03, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x13, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e,
-    0x57, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x65, 0x22, 0x32, 0x0a, 0x03, 0x4a,
-    0x6f, 0x62, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x1f,
-    0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x10, 0x2e,
-    0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x53, 0x74, 0x61, 0x74, 0x65, 0x22,
-    0x14, 0x0a, 0x06, 0x4a, 0x6f, 0x62, 0x47, 0x65, 0x74, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18,
-    0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x1d, 0x0a, 0x09, 0x4a, 0x6f, 0x62, 0x43, 0x72, 0x65, 0x61,
-    0x74, 0x65, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01,
-    0x20, 0x02, 0x28, 0x04, 0x2a, 0x28, 0x0a, 0x02, 0x4f, 0x73, 0x12, 0x09, 0x0a, 0x05, 0x4c, 0x69,
-    0x6e, 0x75, 0x78, 0x10, 0x01, 0x12, 0x0a, 0x0a, 0x06, 0x44, 0x61, 0x72, 0x77, 0x69, 0x6e, 0x10,
-    0x02, 0x12, 0x0b, 0x0a, 0x07, 0x57, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x73, 0x10, 0x03, 0x2a, 0x22,
-    0x0a, 0x0b, 0x57, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x09, 0x0a,
-    0x05, 0x52, 0x65, 0x61, 0x64, 0x79, 0x10, 0x00, 0x12, 0x08, 0x0a, 0x04, 0x42, 0x75, 0x73, 0x79,
-    0x10, 0x01, 0x2a, 0x4f, 0x0a, 0x08, 0x4a, 0x6f, 0x62, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x0b,
-    0x0a, 0x07, 0x50, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x10, 0x00, 0x12, 0x0e, 0x0a, 0x0a, 0x50,
-    0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x10, 0x01, 0x12, 0x0c, 0x0a, 0x08, 0x43,
-    0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x52, 0x65, 0x6a,
-    0x65, 0x63, 0x74, 0x65, 0x64, 0x10, 0x03, 0x12, 0x0a, 0x0a, 0x06, 0x46, 0x61, 0x69, 0x6c, 0x65,
-    0x64, 0x10, 0x04, 0x4a, 0xb7, 0x08, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x26, 0x01, 0x0a, 0x08,
-    0x0a, 0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x0e, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04,
-    0x02, 0x00, 0x06, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x02, 0x05, 0x07,
-    0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x03, 0x02, 0x0c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x03, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x03, 0x0a, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02,
-    0x01, 0x12, 0x03, 0x04, 0x02, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12,
-    0x03, 0x04, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x04,
-    0x0b, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x05, 0x02, 0x0e, 0x0a,
-    0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x05, 0x02, 0x09, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x05, 0x0c, 0x0d, 0x0a, 0x0a, 0x0a, 0x02, 0x05,
-    0x01, 0x12, 0x04, 0x08, 0x00, 0x0b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x01, 0x01, 0x12, 0x03,
-    0x08, 0x05, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x00, 0x12, 0x03, 0x09, 0x02, 0x0c,
-    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x09, 0x02, 0x07, 0x0a, 0x0c,
-    0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x02, 0x12, 0x03, 0x09, 0x0a, 0x0b, 0x0a, 0x0b, 0x0a, 0x04,
-    0x05, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0a, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x0a, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02,
-    0x12, 0x03, 0x0a, 0x09, 0x0a, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x02, 0x12, 0x04, 0x0d, 0x00, 0x13,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x05, 0x0d, 0x0a, 0x0b, 0x0a,
-    0x04, 0x05, 0x02, 0x02, 0x00, 0x12, 0x03, 0x0e, 0x02, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x0e, 0x02, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00,
-    0x02, 0x12, 0x03, 0x0e, 0x0c, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x0f, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0f, 0x02,
-    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x01, 0x02, 0x12, 0x03, 0x0f, 0x0f, 0x10, 0x0a,
-    0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x02, 0x12, 0x03, 0x10, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
-    0x05, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x10, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02,
-    0x02, 0x02, 0x02, 0x12, 0x03, 0x10, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x03,
-    0x12, 0x03, 0x11, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03,
-    0x11, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x03, 0x02, 0x12, 0x03, 0x11, 0x0d,
-    0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x04, 0x12, 0x03, 0x12, 0x02, 0x0d, 0x0a, 0x0c,
-    0x0a, 0x05, 0x05, 0x02, 0x02, 0x04, 0x01, 0x12, 0x03, 0x12, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
-    0x05, 0x02, 0x02, 0x04, 0x02, 0x12, 0x03, 0x12, 0x0b, 0x0c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00,
-    0x12, 0x04, 0x15, 0x00, 0x19, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x15,
-    0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x16, 0x02, 0x1f, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x16, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x16, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x16, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x00, 0x03, 0x12, 0x03, 0x16, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12,
-    0x03, 0x17, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x17,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x06, 0x12, 0x03, 0x17, 0x0b, 0x0d,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x17, 0x0e, 0x10, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x17, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x18, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x02, 0x04, 0x12, 0x03, 0x18, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x06,
-    0x12, 0x03, 0x18, 0x0b, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x18, 0x17, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x18, 0x1f,
-    0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x1b, 0x00, 0x1e, 0x01, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x1b, 0x08, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
-    0x00, 0x12, 0x03, 0x1c, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12,
-    0x03, 0x1c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x1c,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1c, 0x12, 0x14,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1c, 0x17, 0x18, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x1d, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x1d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x01, 0x06, 0x12, 0x03, 0x1d, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01,
-    0x12, 0x03, 0x1d, 0x14, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03,
-    0x1d, 0x1c, 0x1d, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x20, 0x00, 0x22, 0x01, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x20, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x02, 0x02, 0x00, 0x12, 0x03, 0x21, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
-    0x04, 0x12, 0x03, 0x21, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x21, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x21,
-    0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x21, 0x17, 0x18,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x24, 0x00, 0x26, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x03, 0x01, 0x12, 0x03, 0x24, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00,
-    0x12, 0x03, 0x25, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03,
-    0x25, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x25, 0x0b,
-    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x25, 0x12, 0x1a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x25, 0x1d, 0x1e,
+    0x57, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x14, 0x0a, 0x0c, 0x63,
+    0x61, 0x70, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x69, 0x65, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28,
+    0x09, 0x12, 0x0e, 0x0a, 0x06, 0x6a, 0x6f, 0x62, 0x5f, 0x69, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28,
+    0x04, 0x22, 0xd9, 0x01, 0x0a, 0x03, 0x4a, 0x6f, 0x62, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x1f, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x02,
+    0x20, 0x02, 0x28, 0x0e, 0x32, 0x10, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f,
+    0x62, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x10, 0x0a, 0x08, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69,
+    0x74, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x12, 0x10, 0x0a, 0x08, 0x72, 0x65, 0x71, 0x75,
+    0x69, 0x72, 0x65, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x09, 0x12, 0x0e, 0x0a, 0x06, 0x77, 0x6f,
+    0x72, 0x6b, 0x65, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x61, 0x74,
+    0x74, 0x65, 0x6d, 0x70, 0x74, 0x73, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0d, 0x12, 0x13, 0x0a, 0x0b,
+    0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x07, 0x20, 0x01, 0x28,
+    0x04, 0x12, 0x0f, 0x0a, 0x07, 0x70, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x18, 0x08, 0x20, 0x01,
+    0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x5f, 0x69, 0x64, 0x18, 0x09,
+    0x20, 0x01, 0x28, 0x04, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64,
+    0x18, 0x0a, 0x20, 0x01, 0x28, 0x04, 0x12, 0x15, 0x0a, 0x0d, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67,
+    0x65, 0x5f, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x09, 0x22, 0x14, 0x0a,
+    0x06, 0x4a, 0x6f, 0x62, 0x47, 0x65, 0x74, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x04, 0x22, 0x1d, 0x0a, 0x09, 0x4a, 0x6f, 0x62, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65,
+    0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02,
+    0x28, 0x04, 0x22, 0x42, 0x0a, 0x0a, 0x4a, 0x6f, 0x62, 0x45, 0x6e, 0x71, 0x75, 0x65, 0x75, 0x65,
+    0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02,
+    0x28, 0x04, 0x12, 0x10, 0x0a, 0x08, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x0d, 0x12, 0x10, 0x0a, 0x08, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x73,
+    0x18, 0x03, 0x20, 0x03, 0x28, 0x09, 0x22, 0x5e, 0x0a, 0x0b, 0x4a, 0x6f, 0x62, 0x4c, 0x6f, 0x67,
+    0x43, 0x68, 0x75, 0x6e, 0x6b, 0x12, 0x0e, 0x0a, 0x06, 0x6a, 0x6f, 0x62, 0x5f, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0b, 0x0a, 0x03, 0x73, 0x65, 0x71, 0x18, 0x02, 0x20, 0x02,
+    0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x03, 0x20,
+    0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x18,
+    0x04, 0x20, 0x01, 0x28, 0x08, 0x12, 0x0f, 0x0a, 0x07, 0x61, 0x74, 0x74, 0x65, 0x6d, 0x70, 0x74,
+    0x18, 0x05, 0x20, 0x01, 0x28, 0x0d, 0x22, 0x3b, 0x0a, 0x09, 0x4a, 0x6f, 0x62, 0x4c, 0x6f, 0x67,
+    0x47, 0x65, 0x74, 0x12, 0x0e, 0x0a, 0x06, 0x6a, 0x6f, 0x62, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x72, 0x74, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x61, 0x74, 0x74, 0x65, 0x6d, 0x70, 0x74, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x0d, 0x22, 0x69, 0x0a, 0x06, 0x4a, 0x6f, 0x62, 0x4c, 0x6f, 0x67, 0x12, 0x0e, 0x0a,
+    0x06, 0x6a, 0x6f, 0x62, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a,
+    0x05, 0x73, 0x74, 0x61, 0x72, 0x74, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04,
+    0x73, 0x74, 0x6f, 0x70, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x63, 0x6f,
+    0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x04, 0x20, 0x03, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x63,
+    0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x18, 0x05, 0x20, 0x02, 0x28, 0x08, 0x12, 0x0f, 0x0a,
+    0x07, 0x61, 0x74, 0x74, 0x65, 0x6d, 0x70, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0d, 0x22, 0x98,
+    0x01, 0x0a, 0x0b, 0x4a, 0x6f, 0x62, 0x53, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x12, 0x0a,
+    0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77,
+    0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07,
+    0x70, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a,
+    0x04, 0x63, 0x72, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x70,
+    0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0d, 0x12, 0x10, 0x0a,
+    0x08, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x73, 0x18, 0x06, 0x20, 0x03, 0x28, 0x09, 0x12,
+    0x13, 0x0a, 0x0b, 0x6e, 0x65, 0x78, 0x74, 0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x6d, 0x73, 0x18, 0x07,
+    0x20, 0x01, 0x28, 0x03, 0x12, 0x13, 0x0a, 0x0b, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x6a, 0x6f, 0x62,
+    0x5f, 0x69, 0x64, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x22, 0x68, 0x0a, 0x11, 0x4a, 0x6f, 0x62,
+    0x53, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x10,
+    0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04,
+    0x12, 0x0f, 0x0a, 0x07, 0x70, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x18, 0x02, 0x20, 0x02, 0x28,
+    0x09, 0x12, 0x0c, 0x0a, 0x04, 0x63, 0x72, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12,
+    0x10, 0x0a, 0x08, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x18, 0x04, 0x20, 0x01, 0x28,
+    0x0d, 0x12, 0x10, 0x0a, 0x08, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x73, 0x18, 0x05, 0x20,
+    0x03, 0x28, 0x09, 0x22, 0x2a, 0x0a, 0x16, 0x4a, 0x6f, 0x62, 0x53, 0x63, 0x68, 0x65, 0x64, 0x75,
+    0x6c, 0x65, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a,
+    0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22,
+    0x53, 0x0a, 0x17, 0x4a, 0x6f, 0x62, 0x53, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x4c, 0x69,
+    0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77,
+    0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x26, 0x0a, 0x09,
+    0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32,
+    0x13, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x53, 0x63, 0x68, 0x65,
+    0x64, 0x75, 0x6c, 0x65, 0x22, 0x6a, 0x0a, 0x0f, 0x4a, 0x6f, 0x62, 0x47, 0x72, 0x6f, 0x75, 0x70,
+    0x50, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18,
+    0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x2b, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x02,
+    0x20, 0x02, 0x28, 0x0e, 0x32, 0x1c, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f,
+    0x62, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x50, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x53, 0x74, 0x61,
+    0x74, 0x65, 0x12, 0x0e, 0x0a, 0x06, 0x6a, 0x6f, 0x62, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01,
+    0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x64, 0x65, 0x70, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x09,
+    0x22, 0x8a, 0x01, 0x0a, 0x08, 0x4a, 0x6f, 0x62, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x0a, 0x0a,
+    0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e,
+    0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x70,
+    0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x24, 0x0a, 0x05,
+    0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x04, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x15, 0x2e, 0x6a, 0x6f,
+    0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x53, 0x74, 0x61,
+    0x74, 0x65, 0x12, 0x29, 0x0a, 0x08, 0x70, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x73, 0x18, 0x05,
+    0x20, 0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f,
+    0x62, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x50, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x22, 0x4a, 0x0a,
+    0x0c, 0x4a, 0x6f, 0x62, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x53, 0x70, 0x65, 0x63, 0x12, 0x10, 0x0a,
+    0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12,
+    0x0f, 0x0a, 0x07, 0x70, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09,
+    0x12, 0x17, 0x0a, 0x0f, 0x64, 0x65, 0x70, 0x65, 0x6e, 0x64, 0x65, 0x6e, 0x74, 0x73, 0x5f, 0x6f,
+    0x6e, 0x6c, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x22, 0x19, 0x0a, 0x0b, 0x4a, 0x6f, 0x62,
+    0x47, 0x72, 0x6f, 0x75, 0x70, 0x47, 0x65, 0x74, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01,
+    0x20, 0x02, 0x28, 0x04, 0x22, 0x30, 0x0a, 0x0f, 0x4a, 0x6f, 0x62, 0x47, 0x72, 0x61, 0x70, 0x68,
+    0x50, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x12, 0x0f, 0x0a, 0x07, 0x70, 0x72, 0x6f, 0x6a, 0x65,
+    0x63, 0x74, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x64, 0x65, 0x70, 0x73,
+    0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x2a, 0x28, 0x0a, 0x02, 0x4f, 0x73, 0x12, 0x09, 0x0a, 0x05,
+    0x4c, 0x69, 0x6e, 0x75, 0x78, 0x10, 0x01, 0x12, 0x0a, 0x0a, 0x06, 0x44, 0x61, 0x72, 0x77, 0x69,
+    0x6e, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x57, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x73, 0x10, 0x03,
+    0x2a, 0x22, 0x0a, 0x0b, 0x57, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12,
+    0x09, 0x0a, 0x05, 0x52, 0x65, 0x61, 0x64, 0x79, 0x10, 0x00, 0x12, 0x08, 0x0a, 0x04, 0x42, 0x75,
+    0x73, 0x79, 0x10, 0x01, 0x2a, 0x4f, 0x0a, 0x08, 0x4a, 0x6f, 0x62, 0x53, 0x74, 0x61, 0x74, 0x65,
+    0x12, 0x0b, 0x0a, 0x07, 0x50, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x10, 0x00, 0x12, 0x0e, 0x0a,
+    0x0a, 0x50, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x10, 0x01, 0x12, 0x0c, 0x0a,
+    0x08, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x52,
+    0x65, 0x6a, 0x65, 0x63, 0x74, 0x65, 0x64, 0x10, 0x03, 0x12, 0x0a, 0x0a, 0x06, 0x46, 0x61, 0x69,
+    0x6c, 0x65, 0x64, 0x10, 0x04, 0x2a, 0x5a, 0x0a, 0x0d, 0x4a, 0x6f, 0x62, 0x47, 0x72, 0x6f, 0x75,
+    0x70, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x0f, 0x0a, 0x0b, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x51,
+    0x75, 0x65, 0x75, 0x65, 0x64, 0x10, 0x00, 0x12, 0x14, 0x0a, 0x10, 0x47, 0x72, 0x6f, 0x75, 0x70,
+    0x44, 0x69, 0x73, 0x70, 0x61, 0x74, 0x63, 0x68, 0x69, 0x6e, 0x67, 0x10, 0x01, 0x12, 0x11, 0x0a,
+    0x0d, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x10, 0x02,
+    0x12, 0x0f, 0x0a, 0x0b, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x46, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x10,
+    0x03, 0x2a, 0x5d, 0x0a, 0x14, 0x4a, 0x6f, 0x62, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x50, 0x72, 0x6f,
+    0x6a, 0x65, 0x63, 0x74, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x0e, 0x0a, 0x0a, 0x4e, 0x6f, 0x74,
+    0x53, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x10, 0x00, 0x12, 0x0e, 0x0a, 0x0a, 0x49, 0x6e, 0x50,
+    0x72, 0x6f, 0x67, 0x72, 0x65, 0x73, 0x73, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x53, 0x75, 0x63,
+    0x63, 0x65, 0x73, 0x73, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x46, 0x61, 0x69, 0x6c, 0x75, 0x72,
+    0x65, 0x10, 0x03, 0x12, 0x0b, 0x0a, 0x07, 0x53, 0x6b, 0x69, 0x70, 0x70, 0x65, 0x64, 0x10, 0x04,
+    0x4a, 0xb7, 0x08, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x26, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x02,
+    0x12, 0x03, 0x00, 0x08, 0x0e, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x02, 0x00, 0x06,
+    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x02, 0x05, 0x07, 0x0a, 0x0b, 0x0a,
+    0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x03, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x03, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00,
+    0x02, 0x12, 0x03, 0x03, 0x0a, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x03,
+    0x04, 0x02, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x04, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x04, 0x0b, 0x0c, 0x0a,
+    0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x05, 0x02, 0x0e, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x05, 0x02, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
+    0x02, 0x02, 0x02, 0x12, 0x03, 0x05, 0x0c, 0x0d, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x04,
+    0x08, 0x00, 0x0b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x01, 0x01, 0x12, 0x03, 0x08, 0x05, 0x10,
+    0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x00, 0x12, 0x03, 0x09, 0x02, 0x0c, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x09, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x01, 0x02, 0x00, 0x02, 0x12, 0x03, 0x09, 0x0a, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02,
+    0x01, 0x12, 0x03, 0x0a, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x0a, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02, 0x12, 0x03, 0x0a,
+    0x09, 0x0a, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x02, 0x12, 0x04, 0x0d, 0x00, 0x13, 0x01, 0x0a, 0x0a,
+    0x0a, 0x03, 0x05, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x05, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02,
+    0x02, 0x00, 0x12, 0x03, 0x0e, 0x02, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x0e, 0x02, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00, 0x02, 0x12, 0x03,
+    0x0e, 0x0c, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0f, 0x02, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0f, 0x02, 0x0c, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x02, 0x02, 0x01, 0x02, 0x12, 0x03, 0x0f, 0x0f, 0x10, 0x0a, 0x0b, 0x0a, 0x04,
+    0x05, 0x02, 0x02, 0x02, 0x12, 0x03, 0x10, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x10, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x02, 0x02,
+    0x12, 0x03, 0x10, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x11,
+    0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x11, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x03, 0x02, 0x12, 0x03, 0x11, 0x0d, 0x0e, 0x0a, 0x0b,
+    0x0a, 0x04, 0x05, 0x02, 0x02, 0x04, 0x12, 0x03, 0x12, 0x02, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x02, 0x02, 0x04, 0x01, 0x12, 0x03, 0x12, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02,
+    0x04, 0x02, 0x12, 0x03, 0x12, 0x0b, 0x0c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x15,
+    0x00, 0x19, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x15, 0x08, 0x11, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x16, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x16, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x00, 0x05, 0x12, 0x03, 0x16, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x16, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x16, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x17, 0x02,
+    0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x17, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x06, 0x12, 0x03, 0x17, 0x0b, 0x0d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x17, 0x0e, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x17, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02,
+    0x02, 0x12, 0x03, 0x18, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12,
+    0x03, 0x18, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x06, 0x12, 0x03, 0x18,
+    0x0b, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x18, 0x17, 0x1c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x18, 0x1f, 0x20, 0x0a, 0x0a,
+    0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x1b, 0x00, 0x1e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01,
+    0x01, 0x12, 0x03, 0x1b, 0x08, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03,
+    0x1c, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x1c, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x1c, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1c, 0x12, 0x14, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1c, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x01, 0x02, 0x01, 0x12, 0x03, 0x1d, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01,
+    0x04, 0x12, 0x03, 0x1d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12,
+    0x03, 0x1d, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1d,
+    0x14, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1d, 0x1c, 0x1d,
+    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x20, 0x00, 0x22, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x02, 0x01, 0x12, 0x03, 0x20, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00,
+    0x12, 0x03, 0x21, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03,
+    0x21, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x21, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x21, 0x12, 0x14, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x21, 0x17, 0x18, 0x0a, 0x0a, 0x0a,
+    0x02, 0x04, 0x03, 0x12, 0x04, 0x24, 0x00, 0x26, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01,
+    0x12, 0x03, 0x24, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x25,
+    0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x25, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x25, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x25, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x25, 0x1d, 0x1e,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
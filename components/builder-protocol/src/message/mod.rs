@@ -16,6 +16,7 @@ use std::fmt;
 use std::hash::Hasher;
 
 use protobuf;
+use protobuf::descriptor::{DescriptorProto, FileDescriptorProto};
 
 use sharding::InstaId;
 
@@ -26,6 +27,34 @@ pub mod routesrv;
 pub mod sessionsrv;
 pub mod vault;
 
+/// Protocol files of this crate, as compiled into it.
+pub const FILES: [fn() -> &'static FileDescriptorProto; 6] = [depotsrv::file_descriptor_proto,
+                                                              jobsrv::file_descriptor_proto,
+                                                              net::file_descriptor_proto,
+                                                              routesrv::file_descriptor_proto,
+                                                              sessionsrv::file_descriptor_proto,
+                                                              vault::file_descriptor_proto];
+
+/// Descriptor of the message with the given full name, such as `net.RouteInfo`, if the crate
+/// defines it. A leading `.`, as in the type names of fields, is ignored.
+pub fn descriptor(full_name: &str) -> Option<&'static DescriptorProto> {
+    let full_name = full_name.trim_left_matches('.');
+    let (package, name) = match full_name.rfind('.') {
+        Some(i) => (&full_name[..i], &full_name[i + 1..]),
+        None => ("", full_name),
+    };
+    for file in FILES.iter() {
+        let file = file();
+        if file.get_package() != package {
+            continue;
+        }
+        if let Some(desc) = file.get_message_type().iter().find(|m| m.get_name() == name) {
+            return Some(desc);
+        }
+    }
+    None
+}
+
 #[derive(Debug)]
 pub struct Message<'a, T: 'a + protobuf::Message>(&'a T);
 
@@ -53,6 +82,7 @@ impl<'a, T: 'a + protobuf::Message> Message<'a, T> {
 pub struct MessageBuilder<'a, T: 'a + protobuf::Message> {
     pub route_info: Option<net::RouteInfo>,
     msg: Message<'a, T>,
+    sticky: bool,
 }
 
 impl<'a, T: 'a + protobuf::Message> MessageBuilder<'a, T> {
@@ -60,6 +90,7 @@ impl<'a, T: 'a + protobuf::Message> MessageBuilder<'a, T> {
         MessageBuilder {
             msg: msg,
             route_info: None,
+            sticky: false,
         }
     }
 
@@ -73,11 +104,22 @@ impl<'a, T: 'a + protobuf::Message> MessageBuilder<'a, T> {
         self
     }
 
+    /// Ask `RouteSrv` to keep routing messages like this one to the same server. Only has an
+    /// effect on routed messages.
+    pub fn sticky(mut self, sticky: bool) -> Self {
+        self.sticky = sticky;
+        self
+    }
+
     pub fn build(self) -> ::net::Msg {
         let mut msg = net::Msg::new();
         msg.set_body(self.msg.0.write_to_bytes().unwrap());
         msg.set_message_id(self.msg.0.descriptor().name().to_string());
-        if let Some(route_info) = self.route_info {
+        msg.set_capabilities(::net::SUPPORTED_CAPABILITIES.bits());
+        if let Some(mut route_info) = self.route_info {
+            if self.sticky {
+                route_info.set_sticky(true);
+            }
             msg.set_route_info(route_info);
         }
         msg
@@ -108,6 +150,13 @@ pub trait Routable: protobuf::Message {
     ///
     /// If `None`, the message will be randomly routed to an available node.
     fn route_key(&self) -> Option<Self::H>;
+
+    /// True if the message must always reach the same server as earlier messages with the same
+    /// route key, or from the same client if it has no route key, even when several servers share
+    /// the key's shard. Protocols which keep per-entity state in memory should return true.
+    fn sticky(&self) -> bool {
+        false
+    }
 }
 
 /// Provides an interface for hashing the implementing type for `Routable` messages.
@@ -21,6 +21,9 @@ pub struct RouteInfo {
     // message fields
     protocol: ::std::option::Option<Protocol>,
     hash: ::std::option::Option<u64>,
+    trace_id: ::std::option::Option<u64>,
+    span_id: ::std::option::Option<u64>,
+    sticky: ::std::option::Option<bool>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -44,6 +47,9 @@ impl RouteInfo {
                 RouteInfo {
                     protocol: ::std::option::Option::None,
                     hash: ::std::option::Option::None,
+                    trace_id: ::std::option::Option::None,
+                    span_id: ::std::option::Option::None,
+                    sticky: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -88,6 +94,63 @@ impl RouteInfo {
     pub fn get_hash(&self) -> u64 {
         self.hash.unwrap_or(0)
     }
+
+    // optional uint64 trace_id = 3;
+
+    pub fn clear_trace_id(&mut self) {
+        self.trace_id = ::std::option::Option::None;
+    }
+
+    pub fn has_trace_id(&self) -> bool {
+        self.trace_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_trace_id(&mut self, v: u64) {
+        self.trace_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_trace_id(&self) -> u64 {
+        self.trace_id.unwrap_or(0)
+    }
+
+    // optional uint64 span_id = 4;
+
+    pub fn clear_span_id(&mut self) {
+        self.span_id = ::std::option::Option::None;
+    }
+
+    pub fn has_span_id(&self) -> bool {
+        self.span_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_span_id(&mut self, v: u64) {
+        self.span_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_span_id(&self) -> u64 {
+        self.span_id.unwrap_or(0)
+    }
+
+    // optional bool sticky = 5;
+
+    pub fn clear_sticky(&mut self) {
+        self.sticky = ::std::option::Option::None;
+    }
+
+    pub fn has_sticky(&self) -> bool {
+        self.sticky.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sticky(&mut self, v: bool) {
+        self.sticky = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_sticky(&self) -> bool {
+        self.sticky.unwrap_or(false)
+    }
 }
 
 impl ::protobuf::Message for RouteInfo {
@@ -116,6 +179,27 @@ impl ::protobuf::Message for RouteInfo {
                     let tmp = try!(is.read_uint64());
                     self.hash = ::std::option::Option::Some(tmp);
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.trace_id = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.span_id = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.sticky = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -134,6 +218,15 @@ impl ::protobuf::Message for RouteInfo {
         for value in self.hash.iter() {
             my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
         };
+        for value in self.trace_id.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.span_id.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if self.sticky.is_some() {
+            my_size += 2;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -146,6 +239,15 @@ impl ::protobuf::Message for RouteInfo {
         if let Some(v) = self.hash {
             try!(os.write_uint64(2, v));
         };
+        if let Some(v) = self.trace_id {
+            try!(os.write_uint64(3, v));
+        };
+        if let Some(v) = self.span_id {
+            try!(os.write_uint64(4, v));
+        };
+        if let Some(v) = self.sticky {
+            try!(os.write_bool(5, v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -198,6 +300,21 @@ impl ::protobuf::MessageStatic for RouteInfo {
                     RouteInfo::has_hash,
                     RouteInfo::get_hash,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "trace_id",
+                    RouteInfo::has_trace_id,
+                    RouteInfo::get_trace_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "span_id",
+                    RouteInfo::has_span_id,
+                    RouteInfo::get_span_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "sticky",
+                    RouteInfo::has_sticky,
+                    RouteInfo::get_sticky,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<RouteInfo>(
                     "RouteInfo",
                     fields,
@@ -212,6 +329,9 @@ impl ::protobuf::Clear for RouteInfo {
     fn clear(&mut self) {
         self.clear_protocol();
         self.clear_hash();
+        self.clear_trace_id();
+        self.clear_span_id();
+        self.clear_sticky();
         self.unknown_fields.clear();
     }
 }
@@ -220,6 +340,9 @@ impl ::std::cmp::PartialEq for RouteInfo {
     fn eq(&self, other: &RouteInfo) -> bool {
         self.protocol == other.protocol &&
         self.hash == other.hash &&
+        self.trace_id == other.trace_id &&
+        self.span_id == other.span_id &&
+        self.sticky == other.sticky &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -236,6 +359,11 @@ pub struct Msg {
     message_id: ::protobuf::SingularField<::std::string::String>,
     body: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     route_info: ::protobuf::SingularPtrField<RouteInfo>,
+    hmac: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    sent_at_ms: ::std::option::Option<i64>,
+    correlation_id: ::std::option::Option<u64>,
+    capabilities: ::std::option::Option<u32>,
+    session_token: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -260,6 +388,11 @@ impl Msg {
                     message_id: ::protobuf::SingularField::none(),
                     body: ::protobuf::SingularField::none(),
                     route_info: ::protobuf::SingularPtrField::none(),
+                    hmac: ::protobuf::SingularField::none(),
+                    sent_at_ms: ::std::option::Option::None,
+                    correlation_id: ::std::option::Option::None,
+                    capabilities: ::std::option::Option::None,
+                    session_token: ::protobuf::SingularField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -371,6 +504,135 @@ impl Msg {
     pub fn get_route_info(&self) -> &RouteInfo {
         self.route_info.as_ref().unwrap_or_else(|| RouteInfo::default_instance())
     }
+
+    // optional bytes hmac = 4;
+
+    pub fn clear_hmac(&mut self) {
+        self.hmac.clear();
+    }
+
+    pub fn has_hmac(&self) -> bool {
+        self.hmac.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hmac(&mut self, v: ::std::vec::Vec<u8>) {
+        self.hmac = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hmac(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.hmac.is_none() {
+            self.hmac.set_default();
+        };
+        self.hmac.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_hmac(&mut self) -> ::std::vec::Vec<u8> {
+        self.hmac.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_hmac(&self) -> &[u8] {
+        match self.hmac.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    // optional int64 sent_at_ms = 5;
+
+    pub fn clear_sent_at_ms(&mut self) {
+        self.sent_at_ms = ::std::option::Option::None;
+    }
+
+    pub fn has_sent_at_ms(&self) -> bool {
+        self.sent_at_ms.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sent_at_ms(&mut self, v: i64) {
+        self.sent_at_ms = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_sent_at_ms(&self) -> i64 {
+        self.sent_at_ms.unwrap_or(0)
+    }
+
+    // optional uint64 correlation_id = 6;
+
+    pub fn clear_correlation_id(&mut self) {
+        self.correlation_id = ::std::option::Option::None;
+    }
+
+    pub fn has_correlation_id(&self) -> bool {
+        self.correlation_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_correlation_id(&mut self, v: u64) {
+        self.correlation_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_correlation_id(&self) -> u64 {
+        self.correlation_id.unwrap_or(0)
+    }
+
+    // optional uint32 capabilities = 7;
+
+    pub fn clear_capabilities(&mut self) {
+        self.capabilities = ::std::option::Option::None;
+    }
+
+    pub fn has_capabilities(&self) -> bool {
+        self.capabilities.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_capabilities(&mut self, v: u32) {
+        self.capabilities = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_capabilities(&self) -> u32 {
+        self.capabilities.unwrap_or(0)
+    }
+
+    // optional string session_token = 8;
+
+    pub fn clear_session_token(&mut self) {
+        self.session_token.clear();
+    }
+
+    pub fn has_session_token(&self) -> bool {
+        self.session_token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_session_token(&mut self, v: ::std::string::String) {
+        self.session_token = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_session_token(&mut self) -> &mut ::std::string::String {
+        if self.session_token.is_none() {
+            self.session_token.set_default();
+        };
+        self.session_token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_session_token(&mut self) -> ::std::string::String {
+        self.session_token.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_session_token(&self) -> &str {
+        match self.session_token.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
 }
 
 impl ::protobuf::Message for Msg {
@@ -397,6 +659,33 @@ impl ::protobuf::Message for Msg {
                 3 => {
                     try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.route_info));
                 },
+                4 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.hmac));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_int64());
+                    self.sent_at_ms = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.correlation_id = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.capabilities = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.session_token));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -419,6 +708,21 @@ impl ::protobuf::Message for Msg {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        for value in self.hmac.iter() {
+            my_size += ::protobuf::rt::bytes_size(4, &value);
+        };
+        for value in self.sent_at_ms.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.correlation_id.iter() {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.capabilities.iter() {
+            my_size += ::protobuf::rt::value_size(7, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.session_token.iter() {
+            my_size += ::protobuf::rt::string_size(8, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -436,6 +740,21 @@ impl ::protobuf::Message for Msg {
             try!(os.write_raw_varint32(v.get_cached_size()));
             try!(v.write_to_with_cached_sizes(os));
         };
+        if let Some(v) = self.hmac.as_ref() {
+            try!(os.write_bytes(4, &v));
+        };
+        if let Some(v) = self.sent_at_ms {
+            try!(os.write_int64(5, v));
+        };
+        if let Some(v) = self.correlation_id {
+            try!(os.write_uint64(6, v));
+        };
+        if let Some(v) = self.capabilities {
+            try!(os.write_uint32(7, v));
+        };
+        if let Some(v) = self.session_token.as_ref() {
+            try!(os.write_string(8, &v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -493,6 +812,31 @@ impl ::protobuf::MessageStatic for Msg {
                     Msg::has_route_info,
                     Msg::get_route_info,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "hmac",
+                    Msg::has_hmac,
+                    Msg::get_hmac,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor(
+                    "sent_at_ms",
+                    Msg::has_sent_at_ms,
+                    Msg::get_sent_at_ms,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "correlation_id",
+                    Msg::has_correlation_id,
+                    Msg::get_correlation_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "capabilities",
+                    Msg::has_capabilities,
+                    Msg::get_capabilities,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "session_token",
+                    Msg::has_session_token,
+                    Msg::get_session_token,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Msg>(
                     "Msg",
                     fields,
@@ -508,6 +852,11 @@ impl ::protobuf::Clear for Msg {
         self.clear_message_id();
         self.clear_body();
         self.clear_route_info();
+        self.clear_hmac();
+        self.clear_sent_at_ms();
+        self.clear_correlation_id();
+        self.clear_capabilities();
+        self.clear_session_token();
         self.unknown_fields.clear();
     }
 }
@@ -517,6 +866,11 @@ impl ::std::cmp::PartialEq for Msg {
         self.message_id == other.message_id &&
         self.body == other.body &&
         self.route_info == other.route_info &&
+        self.hmac == other.hmac &&
+        self.sent_at_ms == other.sent_at_ms &&
+        self.correlation_id == other.correlation_id &&
+        self.capabilities == other.capabilities &&
+        self.session_token == other.session_token &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -759,6 +1113,9 @@ impl ::std::fmt::Debug for NetError {
 
 #[derive(Clone,Default)]
 pub struct Ping {
+    // message fields
+    endpoint: ::protobuf::SingularField<::std::string::String>,
+    load: ::protobuf::SingularPtrField<Stats>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -780,12 +1137,83 @@ impl Ping {
         unsafe {
             instance.get(|| {
                 Ping {
+                    endpoint: ::protobuf::SingularField::none(),
+                    load: ::protobuf::SingularPtrField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
             })
         }
     }
+
+    // optional string endpoint = 1;
+
+    pub fn clear_endpoint(&mut self) {
+        self.endpoint.clear();
+    }
+
+    pub fn has_endpoint(&self) -> bool {
+        self.endpoint.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_endpoint(&mut self, v: ::std::string::String) {
+        self.endpoint = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_endpoint(&mut self) -> &mut ::std::string::String {
+        if self.endpoint.is_none() {
+            self.endpoint.set_default();
+        };
+        self.endpoint.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_endpoint(&mut self) -> ::std::string::String {
+        self.endpoint.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_endpoint(&self) -> &str {
+        match self.endpoint.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional .net.Stats load = 2;
+
+    pub fn clear_load(&mut self) {
+        self.load.clear();
+    }
+
+    pub fn has_load(&self) -> bool {
+        self.load.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_load(&mut self, v: Stats) {
+        self.load = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_load(&mut self) -> &mut Stats {
+        if self.load.is_none() {
+            self.load.set_default();
+        };
+        self.load.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_load(&mut self) -> Stats {
+        self.load.take().unwrap_or_else(|| Stats::new())
+    }
+
+    pub fn get_load(&self) -> &Stats {
+        self.load.as_ref().unwrap_or_else(|| Stats::default_instance())
+    }
 }
 
 impl ::protobuf::Message for Ping {
@@ -797,6 +1225,12 @@ impl ::protobuf::Message for Ping {
         while !try!(is.eof()) {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.endpoint));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.load));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -809,12 +1243,27 @@ impl ::protobuf::Message for Ping {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
+        for value in self.endpoint.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.load.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.endpoint.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.load.as_ref() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -856,7 +1305,17 @@ impl ::protobuf::MessageStatic for Ping {
         };
         unsafe {
             descriptor.get(|| {
-                let fields = ::std::vec::Vec::new();
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "endpoint",
+                    Ping::has_endpoint,
+                    Ping::get_endpoint,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "load",
+                    Ping::has_load,
+                    Ping::get_load,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Ping>(
                     "Ping",
                     fields,
@@ -869,12 +1328,16 @@ impl ::protobuf::MessageStatic for Ping {
 
 impl ::protobuf::Clear for Ping {
     fn clear(&mut self) {
+        self.clear_endpoint();
+        self.clear_load();
         self.unknown_fields.clear();
     }
 }
 
 impl ::std::cmp::PartialEq for Ping {
     fn eq(&self, other: &Ping) -> bool {
+        self.endpoint == other.endpoint &&
+        self.load == other.load &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -1013,38 +1476,2863 @@ impl ::std::fmt::Debug for Pong {
     }
 }
 
-#[derive(Clone,PartialEq,Eq,Debug,Hash)]
-pub enum Protocol {
-    Net = 0,
-    RouteSrv = 1,
-    SessionSrv = 2,
-    VaultSrv = 3,
-    JobSrv = 4,
+#[derive(Clone,Default)]
+pub struct HealthCheckResult {
+    // message fields
+    name: ::protobuf::SingularField<::std::string::String>,
+    status: ::std::option::Option<HealthStatus>,
+    msg: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
 }
 
-impl ::protobuf::ProtobufEnum for Protocol {
-    fn value(&self) -> i32 {
-        *self as i32
-    }
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for HealthCheckResult {}
 
-    fn from_i32(value: i32) -> ::std::option::Option<Protocol> {
-        match value {
-            0 => ::std::option::Option::Some(Protocol::Net),
-            1 => ::std::option::Option::Some(Protocol::RouteSrv),
-            2 => ::std::option::Option::Some(Protocol::SessionSrv),
-            3 => ::std::option::Option::Some(Protocol::VaultSrv),
-            4 => ::std::option::Option::Some(Protocol::JobSrv),
-            _ => ::std::option::Option::None
-        }
+impl HealthCheckResult {
+    pub fn new() -> HealthCheckResult {
+        ::std::default::Default::default()
     }
 
-    fn values() -> &'static [Self] {
-        static values: &'static [Protocol] = &[
-            Protocol::Net,
-            Protocol::RouteSrv,
-            Protocol::SessionSrv,
-            Protocol::VaultSrv,
-            Protocol::JobSrv,
+    pub fn default_instance() -> &'static HealthCheckResult {
+        static mut instance: ::protobuf::lazy::Lazy<HealthCheckResult> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const HealthCheckResult,
+        };
+        unsafe {
+            instance.get(|| {
+                HealthCheckResult {
+                    name: ::protobuf::SingularField::none(),
+                    status: ::std::option::Option::None,
+                    msg: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required .net.HealthStatus status = 2;
+
+    pub fn clear_status(&mut self) {
+        self.status = ::std::option::Option::None;
+    }
+
+    pub fn has_status(&self) -> bool {
+        self.status.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: HealthStatus) {
+        self.status = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_status(&self) -> HealthStatus {
+        self.status.unwrap_or(HealthStatus::OK)
+    }
+
+    // optional string msg = 3;
+
+    pub fn clear_msg(&mut self) {
+        self.msg.clear();
+    }
+
+    pub fn has_msg(&self) -> bool {
+        self.msg.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_msg(&mut self, v: ::std::string::String) {
+        self.msg = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_msg(&mut self) -> &mut ::std::string::String {
+        if self.msg.is_none() {
+            self.msg.set_default();
+        };
+        self.msg.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_msg(&mut self) -> ::std::string::String {
+        self.msg.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_msg(&self) -> &str {
+        match self.msg.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for HealthCheckResult {
+    fn is_initialized(&self) -> bool {
+        if self.name.is_none() {
+            return false;
+        };
+        if self.status.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.status = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.msg));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.status.iter() {
+            my_size += ::protobuf::rt::enum_size(2, *value);
+        };
+        for value in self.msg.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.status {
+            try!(os.write_enum(2, v.value()));
+        };
+        if let Some(v) = self.msg.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<HealthCheckResult>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for HealthCheckResult {
+    fn new() -> HealthCheckResult {
+        HealthCheckResult::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<HealthCheckResult>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    HealthCheckResult::has_name,
+                    HealthCheckResult::get_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "status",
+                    HealthCheckResult::has_status,
+                    HealthCheckResult::get_status,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "msg",
+                    HealthCheckResult::has_msg,
+                    HealthCheckResult::get_msg,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<HealthCheckResult>(
+                    "HealthCheckResult",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for HealthCheckResult {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_status();
+        self.clear_msg();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for HealthCheckResult {
+    fn eq(&self, other: &HealthCheckResult) -> bool {
+        self.name == other.name &&
+        self.status == other.status &&
+        self.msg == other.msg &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for HealthCheckResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct HealthGet {
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for HealthGet {}
+
+impl HealthGet {
+    pub fn new() -> HealthGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static HealthGet {
+        static mut instance: ::protobuf::lazy::Lazy<HealthGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const HealthGet,
+        };
+        unsafe {
+            instance.get(|| {
+                HealthGet {
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+}
+
+impl ::protobuf::Message for HealthGet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<HealthGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for HealthGet {
+    fn new() -> HealthGet {
+        HealthGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<HealthGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<HealthGet>(
+                    "HealthGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for HealthGet {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for HealthGet {
+    fn eq(&self, other: &HealthGet) -> bool {
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for HealthGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct Health {
+    // message fields
+    status: ::std::option::Option<HealthStatus>,
+    checks: ::protobuf::RepeatedField<HealthCheckResult>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Health {}
+
+impl Health {
+    pub fn new() -> Health {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Health {
+        static mut instance: ::protobuf::lazy::Lazy<Health> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Health,
+        };
+        unsafe {
+            instance.get(|| {
+                Health {
+                    status: ::std::option::Option::None,
+                    checks: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required .net.HealthStatus status = 1;
+
+    pub fn clear_status(&mut self) {
+        self.status = ::std::option::Option::None;
+    }
+
+    pub fn has_status(&self) -> bool {
+        self.status.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: HealthStatus) {
+        self.status = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_status(&self) -> HealthStatus {
+        self.status.unwrap_or(HealthStatus::OK)
+    }
+
+    // repeated .net.HealthCheckResult checks = 2;
+
+    pub fn clear_checks(&mut self) {
+        self.checks.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checks(&mut self, v: ::protobuf::RepeatedField<HealthCheckResult>) {
+        self.checks = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_checks(&mut self) -> &mut ::protobuf::RepeatedField<HealthCheckResult> {
+        &mut self.checks
+    }
+
+    // Take field
+    pub fn take_checks(&mut self) -> ::protobuf::RepeatedField<HealthCheckResult> {
+        ::std::mem::replace(&mut self.checks, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_checks(&self) -> &[HealthCheckResult] {
+        &self.checks
+    }
+}
+
+impl ::protobuf::Message for Health {
+    fn is_initialized(&self) -> bool {
+        if self.status.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.status = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.checks));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.status.iter() {
+            my_size += ::protobuf::rt::enum_size(1, *value);
+        };
+        for value in self.checks.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.status {
+            try!(os.write_enum(1, v.value()));
+        };
+        for v in self.checks.iter() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Health>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Health {
+    fn new() -> Health {
+        Health::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Health>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "status",
+                    Health::has_status,
+                    Health::get_status,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "checks",
+                    Health::get_checks,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Health>(
+                    "Health",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Health {
+    fn clear(&mut self) {
+        self.clear_status();
+        self.clear_checks();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Health {
+    fn eq(&self, other: &Health) -> bool {
+        self.status == other.status &&
+        self.checks == other.checks &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Health {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct DebugGet {
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for DebugGet {}
+
+impl DebugGet {
+    pub fn new() -> DebugGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static DebugGet {
+        static mut instance: ::protobuf::lazy::Lazy<DebugGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DebugGet,
+        };
+        unsafe {
+            instance.get(|| {
+                DebugGet {
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+}
+
+impl ::protobuf::Message for DebugGet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<DebugGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for DebugGet {
+    fn new() -> DebugGet {
+        DebugGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<DebugGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<DebugGet>(
+                    "DebugGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for DebugGet {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for DebugGet {
+    fn eq(&self, other: &DebugGet) -> bool {
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for DebugGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct DebugEntry {
+    // message fields
+    section: ::protobuf::SingularField<::std::string::String>,
+    key: ::protobuf::SingularField<::std::string::String>,
+    value: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for DebugEntry {}
+
+impl DebugEntry {
+    pub fn new() -> DebugEntry {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static DebugEntry {
+        static mut instance: ::protobuf::lazy::Lazy<DebugEntry> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DebugEntry,
+        };
+        unsafe {
+            instance.get(|| {
+                DebugEntry {
+                    section: ::protobuf::SingularField::none(),
+                    key: ::protobuf::SingularField::none(),
+                    value: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string section = 1;
+
+    pub fn clear_section(&mut self) {
+        self.section.clear();
+    }
+
+    pub fn has_section(&self) -> bool {
+        self.section.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_section(&mut self, v: ::std::string::String) {
+        self.section = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_section(&mut self) -> &mut ::std::string::String {
+        if self.section.is_none() {
+            self.section.set_default();
+        };
+        self.section.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_section(&mut self) -> ::std::string::String {
+        self.section.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_section(&self) -> &str {
+        match self.section.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string key = 2;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    pub fn has_key(&self) -> bool {
+        self.key.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        if self.key.is_none() {
+            self.key.set_default();
+        };
+        self.key.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        self.key.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_key(&self) -> &str {
+        match self.key.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string value = 3;
+
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        if self.value.is_none() {
+            self.value.set_default();
+        };
+        self.value.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        self.value.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_value(&self) -> &str {
+        match self.value.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for DebugEntry {
+    fn is_initialized(&self) -> bool {
+        if self.section.is_none() {
+            return false;
+        };
+        if self.key.is_none() {
+            return false;
+        };
+        if self.value.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.section));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.key));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.value));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.section.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.key.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.value.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.section.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.key.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.value.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<DebugEntry>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for DebugEntry {
+    fn new() -> DebugEntry {
+        DebugEntry::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<DebugEntry>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "section",
+                    DebugEntry::has_section,
+                    DebugEntry::get_section,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "key",
+                    DebugEntry::has_key,
+                    DebugEntry::get_key,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "value",
+                    DebugEntry::has_value,
+                    DebugEntry::get_value,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<DebugEntry>(
+                    "DebugEntry",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for DebugEntry {
+    fn clear(&mut self) {
+        self.clear_section();
+        self.clear_key();
+        self.clear_value();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for DebugEntry {
+    fn eq(&self, other: &DebugEntry) -> bool {
+        self.section == other.section &&
+        self.key == other.key &&
+        self.value == other.value &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for DebugEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct DebugDump {
+    // message fields
+    entries: ::protobuf::RepeatedField<DebugEntry>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for DebugDump {}
+
+impl DebugDump {
+    pub fn new() -> DebugDump {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static DebugDump {
+        static mut instance: ::protobuf::lazy::Lazy<DebugDump> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DebugDump,
+        };
+        unsafe {
+            instance.get(|| {
+                DebugDump {
+                    entries: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // repeated .net.DebugEntry entries = 1;
+
+    pub fn clear_entries(&mut self) {
+        self.entries.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_entries(&mut self, v: ::protobuf::RepeatedField<DebugEntry>) {
+        self.entries = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_entries(&mut self) -> &mut ::protobuf::RepeatedField<DebugEntry> {
+        &mut self.entries
+    }
+
+    // Take field
+    pub fn take_entries(&mut self) -> ::protobuf::RepeatedField<DebugEntry> {
+        ::std::mem::replace(&mut self.entries, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_entries(&self) -> &[DebugEntry] {
+        &self.entries
+    }
+}
+
+impl ::protobuf::Message for DebugDump {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.entries));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.entries.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in self.entries.iter() {
+            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<DebugDump>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for DebugDump {
+    fn new() -> DebugDump {
+        DebugDump::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<DebugDump>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "entries",
+                    DebugDump::get_entries,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<DebugDump>(
+                    "DebugDump",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for DebugDump {
+    fn clear(&mut self) {
+        self.clear_entries();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for DebugDump {
+    fn eq(&self, other: &DebugDump) -> bool {
+        self.entries == other.entries &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for DebugDump {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct SupervisorCommand {
+    // message fields
+    op: ::std::option::Option<SupervisorOp>,
+    worker: ::std::option::Option<u32>,
+    log_level: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for SupervisorCommand {}
+
+impl SupervisorCommand {
+    pub fn new() -> SupervisorCommand {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static SupervisorCommand {
+        static mut instance: ::protobuf::lazy::Lazy<SupervisorCommand> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SupervisorCommand,
+        };
+        unsafe {
+            instance.get(|| {
+                SupervisorCommand {
+                    op: ::std::option::Option::None,
+                    worker: ::std::option::Option::None,
+                    log_level: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required .net.SupervisorOp op = 1;
+
+    pub fn clear_op(&mut self) {
+        self.op = ::std::option::Option::None;
+    }
+
+    pub fn has_op(&self) -> bool {
+        self.op.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_op(&mut self, v: SupervisorOp) {
+        self.op = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_op(&self) -> SupervisorOp {
+        self.op.unwrap_or(SupervisorOp::STATS)
+    }
+
+    // optional uint32 worker = 2;
+
+    pub fn clear_worker(&mut self) {
+        self.worker = ::std::option::Option::None;
+    }
+
+    pub fn has_worker(&self) -> bool {
+        self.worker.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_worker(&mut self, v: u32) {
+        self.worker = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_worker(&self) -> u32 {
+        self.worker.unwrap_or(0)
+    }
+
+    // optional string log_level = 3;
+
+    pub fn clear_log_level(&mut self) {
+        self.log_level.clear();
+    }
+
+    pub fn has_log_level(&self) -> bool {
+        self.log_level.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_log_level(&mut self, v: ::std::string::String) {
+        self.log_level = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_log_level(&mut self) -> &mut ::std::string::String {
+        if self.log_level.is_none() {
+            self.log_level.set_default();
+        };
+        self.log_level.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_log_level(&mut self) -> ::std::string::String {
+        self.log_level.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_log_level(&self) -> &str {
+        match self.log_level.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for SupervisorCommand {
+    fn is_initialized(&self) -> bool {
+        if self.op.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.op = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.worker = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.log_level));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.op.iter() {
+            my_size += ::protobuf::rt::enum_size(1, *value);
+        };
+        for value in self.worker.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.log_level.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.op {
+            try!(os.write_enum(1, v.value()));
+        };
+        if let Some(v) = self.worker {
+            try!(os.write_uint32(2, v));
+        };
+        if let Some(v) = self.log_level.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<SupervisorCommand>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for SupervisorCommand {
+    fn new() -> SupervisorCommand {
+        SupervisorCommand::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<SupervisorCommand>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "op",
+                    SupervisorCommand::has_op,
+                    SupervisorCommand::get_op,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "worker",
+                    SupervisorCommand::has_worker,
+                    SupervisorCommand::get_worker,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "log_level",
+                    SupervisorCommand::has_log_level,
+                    SupervisorCommand::get_log_level,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SupervisorCommand>(
+                    "SupervisorCommand",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for SupervisorCommand {
+    fn clear(&mut self) {
+        self.clear_op();
+        self.clear_worker();
+        self.clear_log_level();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for SupervisorCommand {
+    fn eq(&self, other: &SupervisorCommand) -> bool {
+        self.op == other.op &&
+        self.worker == other.worker &&
+        self.log_level == other.log_level &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for SupervisorCommand {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct WorkerStatus {
+    // message fields
+    index: ::std::option::Option<u32>,
+    state: ::std::option::Option<WorkerState>,
+    handled: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for WorkerStatus {}
+
+impl WorkerStatus {
+    pub fn new() -> WorkerStatus {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static WorkerStatus {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerStatus> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerStatus,
+        };
+        unsafe {
+            instance.get(|| {
+                WorkerStatus {
+                    index: ::std::option::Option::None,
+                    state: ::std::option::Option::None,
+                    handled: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint32 index = 1;
+
+    pub fn clear_index(&mut self) {
+        self.index = ::std::option::Option::None;
+    }
+
+    pub fn has_index(&self) -> bool {
+        self.index.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: u32) {
+        self.index = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_index(&self) -> u32 {
+        self.index.unwrap_or(0)
+    }
+
+    // required .net.WorkerState state = 2;
+
+    pub fn clear_state(&mut self) {
+        self.state = ::std::option::Option::None;
+    }
+
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: WorkerState) {
+        self.state = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_state(&self) -> WorkerState {
+        self.state.unwrap_or(WorkerState::RUNNING)
+    }
+
+    // required uint64 handled = 3;
+
+    pub fn clear_handled(&mut self) {
+        self.handled = ::std::option::Option::None;
+    }
+
+    pub fn has_handled(&self) -> bool {
+        self.handled.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_handled(&mut self, v: u64) {
+        self.handled = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_handled(&self) -> u64 {
+        self.handled.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for WorkerStatus {
+    fn is_initialized(&self) -> bool {
+        if self.index.is_none() {
+            return false;
+        };
+        if self.state.is_none() {
+            return false;
+        };
+        if self.handled.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.index = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.state = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.handled = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.index.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.state.iter() {
+            my_size += ::protobuf::rt::enum_size(2, *value);
+        };
+        for value in self.handled.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.index {
+            try!(os.write_uint32(1, v));
+        };
+        if let Some(v) = self.state {
+            try!(os.write_enum(2, v.value()));
+        };
+        if let Some(v) = self.handled {
+            try!(os.write_uint64(3, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<WorkerStatus>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for WorkerStatus {
+    fn new() -> WorkerStatus {
+        WorkerStatus::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<WorkerStatus>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "index",
+                    WorkerStatus::has_index,
+                    WorkerStatus::get_index,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "state",
+                    WorkerStatus::has_state,
+                    WorkerStatus::get_state,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "handled",
+                    WorkerStatus::has_handled,
+                    WorkerStatus::get_handled,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerStatus>(
+                    "WorkerStatus",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerStatus {
+    fn clear(&mut self) {
+        self.clear_index();
+        self.clear_state();
+        self.clear_handled();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for WorkerStatus {
+    fn eq(&self, other: &WorkerStatus) -> bool {
+        self.index == other.index &&
+        self.state == other.state &&
+        self.handled == other.handled &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for WorkerStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct SupervisorStatus {
+    // message fields
+    workers: ::protobuf::RepeatedField<WorkerStatus>,
+    log_level: ::protobuf::SingularField<::std::string::String>,
+    in_flight: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for SupervisorStatus {}
+
+impl SupervisorStatus {
+    pub fn new() -> SupervisorStatus {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static SupervisorStatus {
+        static mut instance: ::protobuf::lazy::Lazy<SupervisorStatus> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SupervisorStatus,
+        };
+        unsafe {
+            instance.get(|| {
+                SupervisorStatus {
+                    workers: ::protobuf::RepeatedField::new(),
+                    log_level: ::protobuf::SingularField::none(),
+                    in_flight: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // repeated .net.WorkerStatus workers = 1;
+
+    pub fn clear_workers(&mut self) {
+        self.workers.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workers(&mut self, v: ::protobuf::RepeatedField<WorkerStatus>) {
+        self.workers = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_workers(&mut self) -> &mut ::protobuf::RepeatedField<WorkerStatus> {
+        &mut self.workers
+    }
+
+    // Take field
+    pub fn take_workers(&mut self) -> ::protobuf::RepeatedField<WorkerStatus> {
+        ::std::mem::replace(&mut self.workers, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_workers(&self) -> &[WorkerStatus] {
+        &self.workers
+    }
+
+    // optional string log_level = 2;
+
+    pub fn clear_log_level(&mut self) {
+        self.log_level.clear();
+    }
+
+    pub fn has_log_level(&self) -> bool {
+        self.log_level.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_log_level(&mut self, v: ::std::string::String) {
+        self.log_level = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_log_level(&mut self) -> &mut ::std::string::String {
+        if self.log_level.is_none() {
+            self.log_level.set_default();
+        };
+        self.log_level.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_log_level(&mut self) -> ::std::string::String {
+        self.log_level.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_log_level(&self) -> &str {
+        match self.log_level.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 in_flight = 3;
+
+    pub fn clear_in_flight(&mut self) {
+        self.in_flight = ::std::option::Option::None;
+    }
+
+    pub fn has_in_flight(&self) -> bool {
+        self.in_flight.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_in_flight(&mut self, v: u64) {
+        self.in_flight = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_in_flight(&self) -> u64 {
+        self.in_flight.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for SupervisorStatus {
+    fn is_initialized(&self) -> bool {
+        if self.in_flight.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.workers));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.log_level));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.in_flight = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.workers.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.log_level.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.in_flight.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in self.workers.iter() {
+            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.log_level.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.in_flight {
+            try!(os.write_uint64(3, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<SupervisorStatus>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for SupervisorStatus {
+    fn new() -> SupervisorStatus {
+        SupervisorStatus::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<SupervisorStatus>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "workers",
+                    SupervisorStatus::get_workers,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "log_level",
+                    SupervisorStatus::has_log_level,
+                    SupervisorStatus::get_log_level,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "in_flight",
+                    SupervisorStatus::has_in_flight,
+                    SupervisorStatus::get_in_flight,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SupervisorStatus>(
+                    "SupervisorStatus",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for SupervisorStatus {
+    fn clear(&mut self) {
+        self.clear_workers();
+        self.clear_log_level();
+        self.clear_in_flight();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for SupervisorStatus {
+    fn eq(&self, other: &SupervisorStatus) -> bool {
+        self.workers == other.workers &&
+        self.log_level == other.log_level &&
+        self.in_flight == other.in_flight &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for SupervisorStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct StatsGet {
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for StatsGet {}
+
+impl StatsGet {
+    pub fn new() -> StatsGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static StatsGet {
+        static mut instance: ::protobuf::lazy::Lazy<StatsGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StatsGet,
+        };
+        unsafe {
+            instance.get(|| {
+                StatsGet {
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+}
+
+impl ::protobuf::Message for StatsGet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<StatsGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for StatsGet {
+    fn new() -> StatsGet {
+        StatsGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<StatsGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<StatsGet>(
+                    "StatsGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for StatsGet {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for StatsGet {
+    fn eq(&self, other: &StatsGet) -> bool {
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for StatsGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct Stats {
+    // message fields
+    workers: ::std::option::Option<u32>,
+    busy_workers: ::std::option::Option<u32>,
+    queue_depth: ::std::option::Option<u64>,
+    queue_latency_ms: ::std::option::Option<u64>,
+    received: ::std::option::Option<u64>,
+    dispatched: ::std::option::Option<u64>,
+    errors: ::std::option::Option<u64>,
+    dropped: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Stats {}
+
+impl Stats {
+    pub fn new() -> Stats {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Stats {
+        static mut instance: ::protobuf::lazy::Lazy<Stats> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Stats,
+        };
+        unsafe {
+            instance.get(|| {
+                Stats {
+                    workers: ::std::option::Option::None,
+                    busy_workers: ::std::option::Option::None,
+                    queue_depth: ::std::option::Option::None,
+                    queue_latency_ms: ::std::option::Option::None,
+                    received: ::std::option::Option::None,
+                    dispatched: ::std::option::Option::None,
+                    errors: ::std::option::Option::None,
+                    dropped: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint32 workers = 1;
+
+    pub fn clear_workers(&mut self) {
+        self.workers = ::std::option::Option::None;
+    }
+
+    pub fn has_workers(&self) -> bool {
+        self.workers.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workers(&mut self, v: u32) {
+        self.workers = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_workers(&self) -> u32 {
+        self.workers.unwrap_or(0)
+    }
+
+    // required uint32 busy_workers = 2;
+
+    pub fn clear_busy_workers(&mut self) {
+        self.busy_workers = ::std::option::Option::None;
+    }
+
+    pub fn has_busy_workers(&self) -> bool {
+        self.busy_workers.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_busy_workers(&mut self, v: u32) {
+        self.busy_workers = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_busy_workers(&self) -> u32 {
+        self.busy_workers.unwrap_or(0)
+    }
+
+    // required uint64 queue_depth = 3;
+
+    pub fn clear_queue_depth(&mut self) {
+        self.queue_depth = ::std::option::Option::None;
+    }
+
+    pub fn has_queue_depth(&self) -> bool {
+        self.queue_depth.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queue_depth(&mut self, v: u64) {
+        self.queue_depth = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_queue_depth(&self) -> u64 {
+        self.queue_depth.unwrap_or(0)
+    }
+
+    // required uint64 queue_latency_ms = 4;
+
+    pub fn clear_queue_latency_ms(&mut self) {
+        self.queue_latency_ms = ::std::option::Option::None;
+    }
+
+    pub fn has_queue_latency_ms(&self) -> bool {
+        self.queue_latency_ms.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queue_latency_ms(&mut self, v: u64) {
+        self.queue_latency_ms = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_queue_latency_ms(&self) -> u64 {
+        self.queue_latency_ms.unwrap_or(0)
+    }
+
+    // required uint64 received = 5;
+
+    pub fn clear_received(&mut self) {
+        self.received = ::std::option::Option::None;
+    }
+
+    pub fn has_received(&self) -> bool {
+        self.received.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_received(&mut self, v: u64) {
+        self.received = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_received(&self) -> u64 {
+        self.received.unwrap_or(0)
+    }
+
+    // required uint64 dispatched = 6;
+
+    pub fn clear_dispatched(&mut self) {
+        self.dispatched = ::std::option::Option::None;
+    }
+
+    pub fn has_dispatched(&self) -> bool {
+        self.dispatched.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dispatched(&mut self, v: u64) {
+        self.dispatched = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_dispatched(&self) -> u64 {
+        self.dispatched.unwrap_or(0)
+    }
+
+    // required uint64 errors = 7;
+
+    pub fn clear_errors(&mut self) {
+        self.errors = ::std::option::Option::None;
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.errors.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_errors(&mut self, v: u64) {
+        self.errors = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_errors(&self) -> u64 {
+        self.errors.unwrap_or(0)
+    }
+
+    // required uint64 dropped = 8;
+
+    pub fn clear_dropped(&mut self) {
+        self.dropped = ::std::option::Option::None;
+    }
+
+    pub fn has_dropped(&self) -> bool {
+        self.dropped.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dropped(&mut self, v: u64) {
+        self.dropped = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_dropped(&self) -> u64 {
+        self.dropped.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for Stats {
+    fn is_initialized(&self) -> bool {
+        if self.workers.is_none() {
+            return false;
+        };
+        if self.busy_workers.is_none() {
+            return false;
+        };
+        if self.queue_depth.is_none() {
+            return false;
+        };
+        if self.queue_latency_ms.is_none() {
+            return false;
+        };
+        if self.received.is_none() {
+            return false;
+        };
+        if self.dispatched.is_none() {
+            return false;
+        };
+        if self.errors.is_none() {
+            return false;
+        };
+        if self.dropped.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.workers = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.busy_workers = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.queue_depth = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.queue_latency_ms = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.received = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.dispatched = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.errors = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.dropped = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.workers.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.busy_workers.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.queue_depth.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.queue_latency_ms.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.received.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.dispatched.iter() {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.errors.iter() {
+            my_size += ::protobuf::rt::value_size(7, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.dropped.iter() {
+            my_size += ::protobuf::rt::value_size(8, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.workers {
+            try!(os.write_uint32(1, v));
+        };
+        if let Some(v) = self.busy_workers {
+            try!(os.write_uint32(2, v));
+        };
+        if let Some(v) = self.queue_depth {
+            try!(os.write_uint64(3, v));
+        };
+        if let Some(v) = self.queue_latency_ms {
+            try!(os.write_uint64(4, v));
+        };
+        if let Some(v) = self.received {
+            try!(os.write_uint64(5, v));
+        };
+        if let Some(v) = self.dispatched {
+            try!(os.write_uint64(6, v));
+        };
+        if let Some(v) = self.errors {
+            try!(os.write_uint64(7, v));
+        };
+        if let Some(v) = self.dropped {
+            try!(os.write_uint64(8, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Stats>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Stats {
+    fn new() -> Stats {
+        Stats::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Stats>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "workers",
+                    Stats::has_workers,
+                    Stats::get_workers,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "busy_workers",
+                    Stats::has_busy_workers,
+                    Stats::get_busy_workers,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "queue_depth",
+                    Stats::has_queue_depth,
+                    Stats::get_queue_depth,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "queue_latency_ms",
+                    Stats::has_queue_latency_ms,
+                    Stats::get_queue_latency_ms,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "received",
+                    Stats::has_received,
+                    Stats::get_received,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "dispatched",
+                    Stats::has_dispatched,
+                    Stats::get_dispatched,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "errors",
+                    Stats::has_errors,
+                    Stats::get_errors,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "dropped",
+                    Stats::has_dropped,
+                    Stats::get_dropped,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Stats>(
+                    "Stats",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Stats {
+    fn clear(&mut self) {
+        self.clear_workers();
+        self.clear_busy_workers();
+        self.clear_queue_depth();
+        self.clear_queue_latency_ms();
+        self.clear_received();
+        self.clear_dispatched();
+        self.clear_errors();
+        self.clear_dropped();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Stats {
+    fn eq(&self, other: &Stats) -> bool {
+        self.workers == other.workers &&
+        self.busy_workers == other.busy_workers &&
+        self.queue_depth == other.queue_depth &&
+        self.queue_latency_ms == other.queue_latency_ms &&
+        self.received == other.received &&
+        self.dispatched == other.dispatched &&
+        self.errors == other.errors &&
+        self.dropped == other.dropped &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Stats {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct Echo {
+    // message fields
+    payload: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Echo {}
+
+impl Echo {
+    pub fn new() -> Echo {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Echo {
+        static mut instance: ::protobuf::lazy::Lazy<Echo> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Echo,
+        };
+        unsafe {
+            instance.get(|| {
+                Echo {
+                    payload: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // optional bytes payload = 1;
+
+    pub fn clear_payload(&mut self) {
+        self.payload.clear();
+    }
+
+    pub fn has_payload(&self) -> bool {
+        self.payload.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_payload(&mut self, v: ::std::vec::Vec<u8>) {
+        self.payload = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_payload(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.payload.is_none() {
+            self.payload.set_default();
+        };
+        self.payload.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_payload(&mut self) -> ::std::vec::Vec<u8> {
+        self.payload.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_payload(&self) -> &[u8] {
+        match self.payload.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+}
+
+impl ::protobuf::Message for Echo {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.payload));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.payload.iter() {
+            my_size += ::protobuf::rt::bytes_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.payload.as_ref() {
+            try!(os.write_bytes(1, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Echo>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Echo {
+    fn new() -> Echo {
+        Echo::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Echo>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "payload",
+                    Echo::has_payload,
+                    Echo::get_payload,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Echo>(
+                    "Echo",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Echo {
+    fn clear(&mut self) {
+        self.clear_payload();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Echo {
+    fn eq(&self, other: &Echo) -> bool {
+        self.payload == other.payload &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Echo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Protocol {
+    Net = 0,
+    RouteSrv = 1,
+    SessionSrv = 2,
+    VaultSrv = 3,
+    JobSrv = 4,
+}
+
+impl ::protobuf::ProtobufEnum for Protocol {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Protocol> {
+        match value {
+            0 => ::std::option::Option::Some(Protocol::Net),
+            1 => ::std::option::Option::Some(Protocol::RouteSrv),
+            2 => ::std::option::Option::Some(Protocol::SessionSrv),
+            3 => ::std::option::Option::Some(Protocol::VaultSrv),
+            4 => ::std::option::Option::Some(Protocol::JobSrv),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Protocol] = &[
+            Protocol::Net,
+            Protocol::RouteSrv,
+            Protocol::SessionSrv,
+            Protocol::VaultSrv,
+            Protocol::JobSrv,
         ];
         values
     }
@@ -1077,6 +4365,8 @@ pub enum ErrCode {
     ACCESS_DENIED = 7,
     SESSION_EXPIRED = 8,
     ENTITY_CONFLICT = 9,
+    BUSY = 10,
+    THROTTLED = 11,
 }
 
 impl ::protobuf::ProtobufEnum for ErrCode {
@@ -1096,6 +4386,8 @@ impl ::protobuf::ProtobufEnum for ErrCode {
             7 => ::std::option::Option::Some(ErrCode::ACCESS_DENIED),
             8 => ::std::option::Option::Some(ErrCode::SESSION_EXPIRED),
             9 => ::std::option::Option::Some(ErrCode::ENTITY_CONFLICT),
+            10 => ::std::option::Option::Some(ErrCode::BUSY),
+            11 => ::std::option::Option::Some(ErrCode::THROTTLED),
             _ => ::std::option::Option::None
         }
     }
@@ -1112,6 +4404,8 @@ impl ::protobuf::ProtobufEnum for ErrCode {
             ErrCode::ACCESS_DENIED,
             ErrCode::SESSION_EXPIRED,
             ErrCode::ENTITY_CONFLICT,
+            ErrCode::BUSY,
+            ErrCode::THROTTLED,
         ];
         values
     }
@@ -1132,119 +4426,344 @@ impl ::protobuf::ProtobufEnum for ErrCode {
 impl ::std::marker::Copy for ErrCode {
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum HealthStatus {
+    OK = 0,
+    DEGRADED = 1,
+    DOWN = 2,
+}
+
+impl ::protobuf::ProtobufEnum for HealthStatus {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<HealthStatus> {
+        match value {
+            0 => ::std::option::Option::Some(HealthStatus::OK),
+            1 => ::std::option::Option::Some(HealthStatus::DEGRADED),
+            2 => ::std::option::Option::Some(HealthStatus::DOWN),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [HealthStatus] = &[
+            HealthStatus::OK,
+            HealthStatus::DEGRADED,
+            HealthStatus::DOWN,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<HealthStatus>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("HealthStatus", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for HealthStatus {
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum SupervisorOp {
+    STATS = 0,
+    PAUSE = 1,
+    RESUME = 2,
+    DRAIN = 3,
+    SET_LOG_LEVEL = 4,
+    RESTART = 5,
+}
+
+impl ::protobuf::ProtobufEnum for SupervisorOp {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<SupervisorOp> {
+        match value {
+            0 => ::std::option::Option::Some(SupervisorOp::STATS),
+            1 => ::std::option::Option::Some(SupervisorOp::PAUSE),
+            2 => ::std::option::Option::Some(SupervisorOp::RESUME),
+            3 => ::std::option::Option::Some(SupervisorOp::DRAIN),
+            4 => ::std::option::Option::Some(SupervisorOp::SET_LOG_LEVEL),
+            5 => ::std::option::Option::Some(SupervisorOp::RESTART),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [SupervisorOp] = &[
+            SupervisorOp::STATS,
+            SupervisorOp::PAUSE,
+            SupervisorOp::RESUME,
+            SupervisorOp::DRAIN,
+            SupervisorOp::SET_LOG_LEVEL,
+            SupervisorOp::RESTART,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<SupervisorOp>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("SupervisorOp", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for SupervisorOp {
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum WorkerState {
+    RUNNING = 0,
+    PAUSED = 1,
+    DRAINING = 2,
+    DRAINED = 3,
+}
+
+impl ::protobuf::ProtobufEnum for WorkerState {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<WorkerState> {
+        match value {
+            0 => ::std::option::Option::Some(WorkerState::RUNNING),
+            1 => ::std::option::Option::Some(WorkerState::PAUSED),
+            2 => ::std::option::Option::Some(WorkerState::DRAINING),
+            3 => ::std::option::Option::Some(WorkerState::DRAINED),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [WorkerState] = &[
+            WorkerState::RUNNING,
+            WorkerState::PAUSED,
+            WorkerState::DRAINING,
+            WorkerState::DRAINED,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<WorkerState>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("WorkerState", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for WorkerState {
+}
+
 static file_descriptor_proto_data: &'static [u8] = &[
     0x0a, 0x13, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x73, 0x2f, 0x6e, 0x65, 0x74, 0x2e,
-    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x03, 0x6e, 0x65, 0x74, 0x22, 0x3a, 0x0a, 0x09, 0x52, 0x6f,
+    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x03, 0x6e, 0x65, 0x74, 0x22, 0x6d, 0x0a, 0x09, 0x52, 0x6f,
     0x75, 0x74, 0x65, 0x49, 0x6e, 0x66, 0x6f, 0x12, 0x1f, 0x0a, 0x08, 0x70, 0x72, 0x6f, 0x74, 0x6f,
     0x63, 0x6f, 0x6c, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0d, 0x2e, 0x6e, 0x65, 0x74, 0x2e,
     0x50, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x12, 0x0c, 0x0a, 0x04, 0x68, 0x61, 0x73, 0x68,
-    0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x22, 0x4b, 0x0a, 0x03, 0x4d, 0x73, 0x67, 0x12, 0x12, 0x0a,
-    0x0a, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28,
-    0x09, 0x12, 0x0c, 0x0a, 0x04, 0x62, 0x6f, 0x64, 0x79, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0c, 0x12,
-    0x22, 0x0a, 0x0a, 0x72, 0x6f, 0x75, 0x74, 0x65, 0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x18, 0x03, 0x20,
-    0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x6e, 0x65, 0x74, 0x2e, 0x52, 0x6f, 0x75, 0x74, 0x65, 0x49,
-    0x6e, 0x66, 0x6f, 0x22, 0x33, 0x0a, 0x08, 0x4e, 0x65, 0x74, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x12,
-    0x1a, 0x0a, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0c, 0x2e,
-    0x6e, 0x65, 0x74, 0x2e, 0x45, 0x72, 0x72, 0x43, 0x6f, 0x64, 0x65, 0x12, 0x0b, 0x0a, 0x03, 0x6d,
-    0x73, 0x67, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x22, 0x06, 0x0a, 0x04, 0x50, 0x69, 0x6e, 0x67,
-    0x22, 0x06, 0x0a, 0x04, 0x50, 0x6f, 0x6e, 0x67, 0x2a, 0x4b, 0x0a, 0x08, 0x50, 0x72, 0x6f, 0x74,
-    0x6f, 0x63, 0x6f, 0x6c, 0x12, 0x07, 0x0a, 0x03, 0x4e, 0x65, 0x74, 0x10, 0x00, 0x12, 0x0c, 0x0a,
-    0x08, 0x52, 0x6f, 0x75, 0x74, 0x65, 0x53, 0x72, 0x76, 0x10, 0x01, 0x12, 0x0e, 0x0a, 0x0a, 0x53,
-    0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x53, 0x72, 0x76, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x56,
-    0x61, 0x75, 0x6c, 0x74, 0x53, 0x72, 0x76, 0x10, 0x03, 0x12, 0x0a, 0x0a, 0x06, 0x4a, 0x6f, 0x62,
-    0x53, 0x72, 0x76, 0x10, 0x04, 0x2a, 0xb9, 0x01, 0x0a, 0x07, 0x45, 0x72, 0x72, 0x43, 0x6f, 0x64,
-    0x65, 0x12, 0x07, 0x0a, 0x03, 0x42, 0x55, 0x47, 0x10, 0x00, 0x12, 0x0b, 0x0a, 0x07, 0x54, 0x49,
-    0x4d, 0x45, 0x4f, 0x55, 0x54, 0x10, 0x01, 0x12, 0x13, 0x0a, 0x0f, 0x52, 0x45, 0x4d, 0x4f, 0x54,
-    0x45, 0x5f, 0x52, 0x45, 0x4a, 0x45, 0x43, 0x54, 0x45, 0x44, 0x10, 0x02, 0x12, 0x14, 0x0a, 0x10,
-    0x42, 0x41, 0x44, 0x5f, 0x52, 0x45, 0x4d, 0x4f, 0x54, 0x45, 0x5f, 0x52, 0x45, 0x50, 0x4c, 0x59,
-    0x10, 0x03, 0x12, 0x14, 0x0a, 0x10, 0x45, 0x4e, 0x54, 0x49, 0x54, 0x59, 0x5f, 0x4e, 0x4f, 0x54,
-    0x5f, 0x46, 0x4f, 0x55, 0x4e, 0x44, 0x10, 0x04, 0x12, 0x0c, 0x0a, 0x08, 0x49, 0x4e, 0x54, 0x45,
-    0x52, 0x4e, 0x41, 0x4c, 0x10, 0x05, 0x12, 0x0c, 0x0a, 0x08, 0x4e, 0x4f, 0x5f, 0x53, 0x48, 0x41,
-    0x52, 0x44, 0x10, 0x06, 0x12, 0x11, 0x0a, 0x0d, 0x41, 0x43, 0x43, 0x45, 0x53, 0x53, 0x5f, 0x44,
-    0x45, 0x4e, 0x49, 0x45, 0x44, 0x10, 0x07, 0x12, 0x13, 0x0a, 0x0f, 0x53, 0x45, 0x53, 0x53, 0x49,
-    0x4f, 0x4e, 0x5f, 0x45, 0x58, 0x50, 0x49, 0x52, 0x45, 0x44, 0x10, 0x08, 0x12, 0x13, 0x0a, 0x0f,
-    0x45, 0x4e, 0x54, 0x49, 0x54, 0x59, 0x5f, 0x43, 0x4f, 0x4e, 0x46, 0x4c, 0x49, 0x43, 0x54, 0x10,
-    0x09, 0x4a, 0x82, 0x0a, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x28, 0x0f, 0x0a, 0x08, 0x0a, 0x01,
-    0x02, 0x12, 0x03, 0x00, 0x08, 0x0b, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x02, 0x00,
-    0x08, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x02, 0x05, 0x0d, 0x0a, 0x0b,
-    0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x03, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x03, 0x02, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02,
-    0x00, 0x02, 0x12, 0x03, 0x03, 0x08, 0x09, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12,
-    0x03, 0x04, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x04,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x04, 0x0d, 0x0e,
-    0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x05, 0x02, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x05, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x05, 0x0f, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02,
-    0x03, 0x12, 0x03, 0x06, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x01, 0x12,
-    0x03, 0x06, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x06,
-    0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0d, 0x0a,
-    0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x07, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x07, 0x0b, 0x0c, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
-    0x00, 0x12, 0x04, 0x0a, 0x00, 0x0d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03,
-    0x0a, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0b, 0x02, 0x21,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0b, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x0b, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0b, 0x14, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x0b, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01,
-    0x12, 0x03, 0x0c, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03,
-    0x0c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0c, 0x0b,
-    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0c, 0x12, 0x16, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0c, 0x19, 0x1a, 0x0a, 0x0a, 0x0a,
-    0x02, 0x04, 0x01, 0x12, 0x04, 0x0f, 0x00, 0x13, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01,
-    0x12, 0x03, 0x0f, 0x08, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x10,
-    0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x10, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x10, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x10, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x10, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01,
-    0x02, 0x01, 0x12, 0x03, 0x11, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04,
-    0x12, 0x03, 0x11, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03,
-    0x11, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x11, 0x11,
-    0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x11, 0x18, 0x19, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x12, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x02, 0x04, 0x12, 0x03, 0x12, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x02, 0x06, 0x12, 0x03, 0x12, 0x0b, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02,
-    0x01, 0x12, 0x03, 0x12, 0x15, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12,
-    0x03, 0x12, 0x22, 0x23, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x04, 0x15, 0x00, 0x20, 0x01,
-    0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x01, 0x01, 0x12, 0x03, 0x15, 0x05, 0x0c, 0x0a, 0x0b, 0x0a, 0x04,
-    0x05, 0x01, 0x02, 0x00, 0x12, 0x03, 0x16, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x16, 0x02, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x02,
-    0x12, 0x03, 0x16, 0x08, 0x09, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x01, 0x12, 0x03, 0x17,
-    0x02, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x17, 0x02, 0x09,
-    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02, 0x12, 0x03, 0x17, 0x0c, 0x0d, 0x0a, 0x0b,
-    0x0a, 0x04, 0x05, 0x01, 0x02, 0x02, 0x12, 0x03, 0x18, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x18, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02,
-    0x02, 0x02, 0x12, 0x03, 0x18, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x03, 0x12,
-    0x03, 0x19, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x03, 0x01, 0x12, 0x03, 0x19,
-    0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x03, 0x02, 0x12, 0x03, 0x19, 0x15, 0x16,
-    0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x04, 0x12, 0x03, 0x1a, 0x02, 0x17, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x1a, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x01, 0x02, 0x04, 0x02, 0x12, 0x03, 0x1a, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02,
-    0x05, 0x12, 0x03, 0x1b, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x05, 0x01, 0x12,
-    0x03, 0x1b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x05, 0x02, 0x12, 0x03, 0x1b,
-    0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x06, 0x12, 0x03, 0x1c, 0x02, 0x0f, 0x0a,
-    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x06, 0x01, 0x12, 0x03, 0x1c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x01, 0x02, 0x06, 0x02, 0x12, 0x03, 0x1c, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05,
-    0x01, 0x02, 0x07, 0x12, 0x03, 0x1d, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x07,
-    0x01, 0x12, 0x03, 0x1d, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x07, 0x02, 0x12,
-    0x03, 0x1d, 0x12, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x08, 0x12, 0x03, 0x1e, 0x02,
-    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x08, 0x01, 0x12, 0x03, 0x1e, 0x02, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x08, 0x02, 0x12, 0x03, 0x1e, 0x14, 0x15, 0x0a, 0x0b, 0x0a,
-    0x04, 0x05, 0x01, 0x02, 0x09, 0x12, 0x03, 0x1f, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01,
-    0x02, 0x09, 0x01, 0x12, 0x03, 0x1f, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x09,
-    0x02, 0x12, 0x03, 0x1f, 0x14, 0x15, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x22, 0x00,
-    0x25, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x22, 0x08, 0x10, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x23, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x23, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
-    0x00, 0x06, 0x12, 0x03, 0x23, 0x0b, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x23, 0x13, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x23, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x24, 0x02, 0x1a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x04, 0x12, 0x03, 0x24, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x24, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x24, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x01, 0x03, 0x12, 0x03, 0x24, 0x18, 0x19, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x03,
-    0x27, 0x00, 0x0f, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x27, 0x08, 0x0c, 0x0a,
-    0x09, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x03, 0x28, 0x00, 0x0f, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04,
-    0x01, 0x12, 0x03, 0x28, 0x08, 0x0c,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x12, 0x10, 0x0a, 0x08, 0x74, 0x72, 0x61, 0x63, 0x65, 0x5f,
+    0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x73, 0x70, 0x61, 0x6e,
+    0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x12, 0x0e, 0x0a, 0x06, 0x73, 0x74, 0x69,
+    0x63, 0x6b, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x22, 0xb2, 0x01, 0x0a, 0x03, 0x4d, 0x73,
+    0x67, 0x12, 0x12, 0x0a, 0x0a, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x62, 0x6f, 0x64, 0x79, 0x18, 0x02, 0x20,
+    0x02, 0x28, 0x0c, 0x12, 0x22, 0x0a, 0x0a, 0x72, 0x6f, 0x75, 0x74, 0x65, 0x5f, 0x69, 0x6e, 0x66,
+    0x6f, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x6e, 0x65, 0x74, 0x2e, 0x52, 0x6f,
+    0x75, 0x74, 0x65, 0x49, 0x6e, 0x66, 0x6f, 0x12, 0x0c, 0x0a, 0x04, 0x68, 0x6d, 0x61, 0x63, 0x18,
+    0x04, 0x20, 0x01, 0x28, 0x0c, 0x12, 0x12, 0x0a, 0x0a, 0x73, 0x65, 0x6e, 0x74, 0x5f, 0x61, 0x74,
+    0x5f, 0x6d, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x03, 0x12, 0x16, 0x0a, 0x0e, 0x63, 0x6f, 0x72,
+    0x72, 0x65, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x01, 0x28,
+    0x04, 0x12, 0x14, 0x0a, 0x0c, 0x63, 0x61, 0x70, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x69, 0x65,
+    0x73, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0d, 0x12, 0x15, 0x0a, 0x0d, 0x73, 0x65, 0x73, 0x73, 0x69,
+    0x6f, 0x6e, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x08, 0x20, 0x01, 0x28, 0x09, 0x22, 0x33,
+    0x0a, 0x08, 0x4e, 0x65, 0x74, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x12, 0x1a, 0x0a, 0x04, 0x63, 0x6f,
+    0x64, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0c, 0x2e, 0x6e, 0x65, 0x74, 0x2e, 0x45,
+    0x72, 0x72, 0x43, 0x6f, 0x64, 0x65, 0x12, 0x0b, 0x0a, 0x03, 0x6d, 0x73, 0x67, 0x18, 0x02, 0x20,
+    0x02, 0x28, 0x09, 0x22, 0x32, 0x0a, 0x04, 0x50, 0x69, 0x6e, 0x67, 0x12, 0x10, 0x0a, 0x08, 0x65,
+    0x6e, 0x64, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x12, 0x18, 0x0a,
+    0x04, 0x6c, 0x6f, 0x61, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x6e, 0x65,
+    0x74, 0x2e, 0x53, 0x74, 0x61, 0x74, 0x73, 0x22, 0x06, 0x0a, 0x04, 0x50, 0x6f, 0x6e, 0x67, 0x22,
+    0x51, 0x0a, 0x11, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x52, 0x65,
+    0x73, 0x75, 0x6c, 0x74, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02,
+    0x28, 0x09, 0x12, 0x21, 0x0a, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18, 0x02, 0x20, 0x02,
+    0x28, 0x0e, 0x32, 0x11, 0x2e, 0x6e, 0x65, 0x74, 0x2e, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x53,
+    0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x0b, 0x0a, 0x03, 0x6d, 0x73, 0x67, 0x18, 0x03, 0x20, 0x01,
+    0x28, 0x09, 0x22, 0x0b, 0x0a, 0x09, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x47, 0x65, 0x74, 0x22,
+    0x53, 0x0a, 0x06, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x12, 0x21, 0x0a, 0x06, 0x73, 0x74, 0x61,
+    0x74, 0x75, 0x73, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x11, 0x2e, 0x6e, 0x65, 0x74, 0x2e,
+    0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x26, 0x0a, 0x06,
+    0x63, 0x68, 0x65, 0x63, 0x6b, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x16, 0x2e, 0x6e,
+    0x65, 0x74, 0x2e, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x52, 0x65,
+    0x73, 0x75, 0x6c, 0x74, 0x22, 0x0a, 0x0a, 0x08, 0x44, 0x65, 0x62, 0x75, 0x67, 0x47, 0x65, 0x74,
+    0x22, 0x39, 0x0a, 0x0a, 0x44, 0x65, 0x62, 0x75, 0x67, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x0f,
+    0x0a, 0x07, 0x73, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12,
+    0x0b, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0d, 0x0a, 0x05,
+    0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x22, 0x2d, 0x0a, 0x09, 0x44,
+    0x65, 0x62, 0x75, 0x67, 0x44, 0x75, 0x6d, 0x70, 0x12, 0x20, 0x0a, 0x07, 0x65, 0x6e, 0x74, 0x72,
+    0x69, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x6e, 0x65, 0x74, 0x2e,
+    0x44, 0x65, 0x62, 0x75, 0x67, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x22, 0x55, 0x0a, 0x11, 0x53, 0x75,
+    0x70, 0x65, 0x72, 0x76, 0x69, 0x73, 0x6f, 0x72, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x12,
+    0x1d, 0x0a, 0x02, 0x6f, 0x70, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x11, 0x2e, 0x6e, 0x65,
+    0x74, 0x2e, 0x53, 0x75, 0x70, 0x65, 0x72, 0x76, 0x69, 0x73, 0x6f, 0x72, 0x4f, 0x70, 0x12, 0x0e,
+    0x0a, 0x06, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x12, 0x11,
+    0x0a, 0x09, 0x6c, 0x6f, 0x67, 0x5f, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28,
+    0x09, 0x22, 0x4f, 0x0a, 0x0c, 0x57, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x75,
+    0x73, 0x12, 0x0d, 0x0a, 0x05, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0d,
+    0x12, 0x1f, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0e, 0x32,
+    0x10, 0x2e, 0x6e, 0x65, 0x74, 0x2e, 0x57, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74,
+    0x65, 0x12, 0x0f, 0x0a, 0x07, 0x68, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x64, 0x18, 0x03, 0x20, 0x02,
+    0x28, 0x04, 0x22, 0x5c, 0x0a, 0x10, 0x53, 0x75, 0x70, 0x65, 0x72, 0x76, 0x69, 0x73, 0x6f, 0x72,
+    0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x22, 0x0a, 0x07, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72,
+    0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x6e, 0x65, 0x74, 0x2e, 0x57, 0x6f,
+    0x72, 0x6b, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x11, 0x0a, 0x09, 0x6c, 0x6f,
+    0x67, 0x5f, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x12, 0x11, 0x0a,
+    0x09, 0x69, 0x6e, 0x5f, 0x66, 0x6c, 0x69, 0x67, 0x68, 0x74, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04,
+    0x22, 0x0a, 0x0a, 0x08, 0x53, 0x74, 0x61, 0x74, 0x73, 0x47, 0x65, 0x74, 0x22, 0xa4, 0x01, 0x0a,
+    0x05, 0x53, 0x74, 0x61, 0x74, 0x73, 0x12, 0x0f, 0x0a, 0x07, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72,
+    0x73, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0d, 0x12, 0x14, 0x0a, 0x0c, 0x62, 0x75, 0x73, 0x79, 0x5f,
+    0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x73, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0d, 0x12, 0x13, 0x0a,
+    0x0b, 0x71, 0x75, 0x65, 0x75, 0x65, 0x5f, 0x64, 0x65, 0x70, 0x74, 0x68, 0x18, 0x03, 0x20, 0x02,
+    0x28, 0x04, 0x12, 0x18, 0x0a, 0x10, 0x71, 0x75, 0x65, 0x75, 0x65, 0x5f, 0x6c, 0x61, 0x74, 0x65,
+    0x6e, 0x63, 0x79, 0x5f, 0x6d, 0x73, 0x18, 0x04, 0x20, 0x02, 0x28, 0x04, 0x12, 0x10, 0x0a, 0x08,
+    0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x12, 0x12,
+    0x0a, 0x0a, 0x64, 0x69, 0x73, 0x70, 0x61, 0x74, 0x63, 0x68, 0x65, 0x64, 0x18, 0x06, 0x20, 0x02,
+    0x28, 0x04, 0x12, 0x0e, 0x0a, 0x06, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x73, 0x18, 0x07, 0x20, 0x02,
+    0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x64, 0x72, 0x6f, 0x70, 0x70, 0x65, 0x64, 0x18, 0x08, 0x20,
+    0x02, 0x28, 0x04, 0x22, 0x17, 0x0a, 0x04, 0x45, 0x63, 0x68, 0x6f, 0x12, 0x0f, 0x0a, 0x07, 0x70,
+    0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x2a, 0x4b, 0x0a, 0x08,
+    0x50, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x12, 0x07, 0x0a, 0x03, 0x4e, 0x65, 0x74, 0x10,
+    0x00, 0x12, 0x0c, 0x0a, 0x08, 0x52, 0x6f, 0x75, 0x74, 0x65, 0x53, 0x72, 0x76, 0x10, 0x01, 0x12,
+    0x0e, 0x0a, 0x0a, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x53, 0x72, 0x76, 0x10, 0x02, 0x12,
+    0x0c, 0x0a, 0x08, 0x56, 0x61, 0x75, 0x6c, 0x74, 0x53, 0x72, 0x76, 0x10, 0x03, 0x12, 0x0a, 0x0a,
+    0x06, 0x4a, 0x6f, 0x62, 0x53, 0x72, 0x76, 0x10, 0x04, 0x2a, 0xd2, 0x01, 0x0a, 0x07, 0x45, 0x72,
+    0x72, 0x43, 0x6f, 0x64, 0x65, 0x12, 0x07, 0x0a, 0x03, 0x42, 0x55, 0x47, 0x10, 0x00, 0x12, 0x0b,
+    0x0a, 0x07, 0x54, 0x49, 0x4d, 0x45, 0x4f, 0x55, 0x54, 0x10, 0x01, 0x12, 0x13, 0x0a, 0x0f, 0x52,
+    0x45, 0x4d, 0x4f, 0x54, 0x45, 0x5f, 0x52, 0x45, 0x4a, 0x45, 0x43, 0x54, 0x45, 0x44, 0x10, 0x02,
+    0x12, 0x14, 0x0a, 0x10, 0x42, 0x41, 0x44, 0x5f, 0x52, 0x45, 0x4d, 0x4f, 0x54, 0x45, 0x5f, 0x52,
+    0x45, 0x50, 0x4c, 0x59, 0x10, 0x03, 0x12, 0x14, 0x0a, 0x10, 0x45, 0x4e, 0x54, 0x49, 0x54, 0x59,
+    0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x46, 0x4f, 0x55, 0x4e, 0x44, 0x10, 0x04, 0x12, 0x0c, 0x0a, 0x08,
+    0x49, 0x4e, 0x54, 0x45, 0x52, 0x4e, 0x41, 0x4c, 0x10, 0x05, 0x12, 0x0c, 0x0a, 0x08, 0x4e, 0x4f,
+    0x5f, 0x53, 0x48, 0x41, 0x52, 0x44, 0x10, 0x06, 0x12, 0x11, 0x0a, 0x0d, 0x41, 0x43, 0x43, 0x45,
+    0x53, 0x53, 0x5f, 0x44, 0x45, 0x4e, 0x49, 0x45, 0x44, 0x10, 0x07, 0x12, 0x13, 0x0a, 0x0f, 0x53,
+    0x45, 0x53, 0x53, 0x49, 0x4f, 0x4e, 0x5f, 0x45, 0x58, 0x50, 0x49, 0x52, 0x45, 0x44, 0x10, 0x08,
+    0x12, 0x13, 0x0a, 0x0f, 0x45, 0x4e, 0x54, 0x49, 0x54, 0x59, 0x5f, 0x43, 0x4f, 0x4e, 0x46, 0x4c,
+    0x49, 0x43, 0x54, 0x10, 0x09, 0x12, 0x08, 0x0a, 0x04, 0x42, 0x55, 0x53, 0x59, 0x10, 0x0a, 0x12,
+    0x0d, 0x0a, 0x09, 0x54, 0x48, 0x52, 0x4f, 0x54, 0x54, 0x4c, 0x45, 0x44, 0x10, 0x0b, 0x2a, 0x2e,
+    0x0a, 0x0c, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x06,
+    0x0a, 0x02, 0x4f, 0x4b, 0x10, 0x00, 0x12, 0x0c, 0x0a, 0x08, 0x44, 0x45, 0x47, 0x52, 0x41, 0x44,
+    0x45, 0x44, 0x10, 0x01, 0x12, 0x08, 0x0a, 0x04, 0x44, 0x4f, 0x57, 0x4e, 0x10, 0x02, 0x2a, 0x5b,
+    0x0a, 0x0c, 0x53, 0x75, 0x70, 0x65, 0x72, 0x76, 0x69, 0x73, 0x6f, 0x72, 0x4f, 0x70, 0x12, 0x09,
+    0x0a, 0x05, 0x53, 0x54, 0x41, 0x54, 0x53, 0x10, 0x00, 0x12, 0x09, 0x0a, 0x05, 0x50, 0x41, 0x55,
+    0x53, 0x45, 0x10, 0x01, 0x12, 0x0a, 0x0a, 0x06, 0x52, 0x45, 0x53, 0x55, 0x4d, 0x45, 0x10, 0x02,
+    0x12, 0x09, 0x0a, 0x05, 0x44, 0x52, 0x41, 0x49, 0x4e, 0x10, 0x03, 0x12, 0x11, 0x0a, 0x0d, 0x53,
+    0x45, 0x54, 0x5f, 0x4c, 0x4f, 0x47, 0x5f, 0x4c, 0x45, 0x56, 0x45, 0x4c, 0x10, 0x04, 0x12, 0x0b,
+    0x0a, 0x07, 0x52, 0x45, 0x53, 0x54, 0x41, 0x52, 0x54, 0x10, 0x05, 0x2a, 0x41, 0x0a, 0x0b, 0x57,
+    0x6f, 0x72, 0x6b, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x0b, 0x0a, 0x07, 0x52, 0x55,
+    0x4e, 0x4e, 0x49, 0x4e, 0x47, 0x10, 0x00, 0x12, 0x0a, 0x0a, 0x06, 0x50, 0x41, 0x55, 0x53, 0x45,
+    0x44, 0x10, 0x01, 0x12, 0x0c, 0x0a, 0x08, 0x44, 0x52, 0x41, 0x49, 0x4e, 0x49, 0x4e, 0x47, 0x10,
+    0x02, 0x12, 0x0b, 0x0a, 0x07, 0x44, 0x52, 0x41, 0x49, 0x4e, 0x45, 0x44, 0x10, 0x03, 0x4a, 0x82,
+    0x0a, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x28, 0x0f, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03,
+    0x00, 0x08, 0x0b, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x02, 0x00, 0x08, 0x01, 0x0a,
+    0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x02, 0x05, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x05,
+    0x00, 0x02, 0x00, 0x12, 0x03, 0x03, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x03, 0x02, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12,
+    0x03, 0x03, 0x08, 0x09, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x03, 0x04, 0x02,
+    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x04, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x04, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a,
+    0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x05, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x05, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02,
+    0x02, 0x12, 0x03, 0x05, 0x0f, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x03, 0x12, 0x03,
+    0x06, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x06, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x06, 0x0d, 0x0e, 0x0a,
+    0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0d, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x07, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
+    0x02, 0x04, 0x02, 0x12, 0x03, 0x07, 0x0b, 0x0c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04,
+    0x0a, 0x00, 0x0d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x0a, 0x08, 0x11,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0b, 0x02, 0x21, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x0b, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x0b, 0x14, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x0b, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x0c,
+    0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0c, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0c, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0c, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0c, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01,
+    0x12, 0x04, 0x0f, 0x00, 0x13, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0f,
+    0x08, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x10, 0x02, 0x21, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x10, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x10, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x10, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x10, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12,
+    0x03, 0x11, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x11,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x11, 0x0b, 0x10,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x11, 0x11, 0x15, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x11, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x12, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x02, 0x04, 0x12, 0x03, 0x12, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x06,
+    0x12, 0x03, 0x12, 0x0b, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x12, 0x15, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x12, 0x22,
+    0x23, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x04, 0x15, 0x00, 0x20, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x05, 0x01, 0x01, 0x12, 0x03, 0x15, 0x05, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02,
+    0x00, 0x12, 0x03, 0x16, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x16, 0x02, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x02, 0x12, 0x03, 0x16,
+    0x08, 0x09, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x01, 0x12, 0x03, 0x17, 0x02, 0x0e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x17, 0x02, 0x09, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x01, 0x02, 0x01, 0x02, 0x12, 0x03, 0x17, 0x0c, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x05,
+    0x01, 0x02, 0x02, 0x12, 0x03, 0x18, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x18, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x02, 0x02, 0x12,
+    0x03, 0x18, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x03, 0x12, 0x03, 0x19, 0x02,
+    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x03, 0x01, 0x12, 0x03, 0x19, 0x02, 0x12, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x03, 0x02, 0x12, 0x03, 0x19, 0x15, 0x16, 0x0a, 0x0b, 0x0a,
+    0x04, 0x05, 0x01, 0x02, 0x04, 0x12, 0x03, 0x1a, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01,
+    0x02, 0x04, 0x01, 0x12, 0x03, 0x1a, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x04,
+    0x02, 0x12, 0x03, 0x1a, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x05, 0x12, 0x03,
+    0x1b, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x05, 0x01, 0x12, 0x03, 0x1b, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x05, 0x02, 0x12, 0x03, 0x1b, 0x0d, 0x0e, 0x0a,
+    0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x06, 0x12, 0x03, 0x1c, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x01, 0x02, 0x06, 0x01, 0x12, 0x03, 0x1c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01,
+    0x02, 0x06, 0x02, 0x12, 0x03, 0x1c, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x07,
+    0x12, 0x03, 0x1d, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x07, 0x01, 0x12, 0x03,
+    0x1d, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x07, 0x02, 0x12, 0x03, 0x1d, 0x12,
+    0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x08, 0x12, 0x03, 0x1e, 0x02, 0x16, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x01, 0x02, 0x08, 0x01, 0x12, 0x03, 0x1e, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x01, 0x02, 0x08, 0x02, 0x12, 0x03, 0x1e, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01,
+    0x02, 0x09, 0x12, 0x03, 0x1f, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x09, 0x01,
+    0x12, 0x03, 0x1f, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x09, 0x02, 0x12, 0x03,
+    0x1f, 0x14, 0x15, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x22, 0x00, 0x25, 0x01, 0x0a,
+    0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x22, 0x08, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x02, 0x02, 0x00, 0x12, 0x03, 0x23, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
+    0x04, 0x12, 0x03, 0x23, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12,
+    0x03, 0x23, 0x0b, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x23,
+    0x13, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x23, 0x1a, 0x1b,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x24, 0x02, 0x1a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x01, 0x04, 0x12, 0x03, 0x24, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x24, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x24, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x24, 0x18, 0x19, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x03, 0x27, 0x00, 0x0f,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x27, 0x08, 0x0c, 0x0a, 0x09, 0x0a, 0x02,
+    0x04, 0x04, 0x12, 0x03, 0x28, 0x00, 0x0f, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03,
+    0x28, 0x08, 0x0c,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
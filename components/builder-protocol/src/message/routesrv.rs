@@ -464,6 +464,9 @@ pub struct Registration {
     protocol: ::std::option::Option<super::net::Protocol>,
     endpoint: ::protobuf::SingularField<::std::string::String>,
     shards: ::std::vec::Vec<u32>,
+    capabilities: ::std::option::Option<u32>,
+    weight: ::std::option::Option<u32>,
+    schemas: ::protobuf::RepeatedField<SchemaFingerprint>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -488,6 +491,9 @@ impl Registration {
                     protocol: ::std::option::Option::None,
                     endpoint: ::protobuf::SingularField::none(),
                     shards: ::std::vec::Vec::new(),
+                    capabilities: ::std::option::Option::None,
+                    weight: ::std::option::Option::None,
+                    schemas: ::protobuf::RepeatedField::new(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -574,6 +580,69 @@ impl Registration {
     pub fn get_shards(&self) -> &[u32] {
         &self.shards
     }
+
+    // optional uint32 capabilities = 4;
+
+    pub fn clear_capabilities(&mut self) {
+        self.capabilities = ::std::option::Option::None;
+    }
+
+    pub fn has_capabilities(&self) -> bool {
+        self.capabilities.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_capabilities(&mut self, v: u32) {
+        self.capabilities = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_capabilities(&self) -> u32 {
+        self.capabilities.unwrap_or(0)
+    }
+
+    // optional uint32 weight = 5;
+
+    pub fn clear_weight(&mut self) {
+        self.weight = ::std::option::Option::None;
+    }
+
+    pub fn has_weight(&self) -> bool {
+        self.weight.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_weight(&mut self, v: u32) {
+        self.weight = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_weight(&self) -> u32 {
+        self.weight.unwrap_or(0)
+    }
+
+    // repeated .routesrv.SchemaFingerprint schemas = 6;
+
+    pub fn clear_schemas(&mut self) {
+        self.schemas.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_schemas(&mut self, v: ::protobuf::RepeatedField<SchemaFingerprint>) {
+        self.schemas = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_schemas(&mut self) -> &mut ::protobuf::RepeatedField<SchemaFingerprint> {
+        &mut self.schemas
+    }
+
+    // Take field
+    pub fn take_schemas(&mut self) -> ::protobuf::RepeatedField<SchemaFingerprint> {
+        ::std::mem::replace(&mut self.schemas, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_schemas(&self) -> &[SchemaFingerprint] {
+        &self.schemas
+    }
 }
 
 impl ::protobuf::Message for Registration {
@@ -604,6 +673,23 @@ impl ::protobuf::Message for Registration {
                 3 => {
                     try!(::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.shards));
                 },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.capabilities = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.weight = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.schemas));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -625,6 +711,16 @@ impl ::protobuf::Message for Registration {
         if !self.shards.is_empty() {
             my_size += ::protobuf::rt::vec_packed_varint_size(3, &self.shards);
         };
+        for value in self.capabilities.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.weight.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.schemas.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -645,6 +741,17 @@ impl ::protobuf::Message for Registration {
                 try!(os.write_uint32_no_tag(*v));
             };
         };
+        if let Some(v) = self.capabilities {
+            try!(os.write_uint32(4, v));
+        };
+        if let Some(v) = self.weight {
+            try!(os.write_uint32(5, v));
+        };
+        for v in self.schemas.iter() {
+            try!(os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -701,6 +808,20 @@ impl ::protobuf::MessageStatic for Registration {
                     "shards",
                     Registration::get_shards,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "capabilities",
+                    Registration::has_capabilities,
+                    Registration::get_capabilities,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "weight",
+                    Registration::has_weight,
+                    Registration::get_weight,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "schemas",
+                    Registration::get_schemas,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Registration>(
                     "Registration",
                     fields,
@@ -716,6 +837,9 @@ impl ::protobuf::Clear for Registration {
         self.clear_protocol();
         self.clear_endpoint();
         self.clear_shards();
+        self.clear_capabilities();
+        self.clear_weight();
+        self.clear_schemas();
         self.unknown_fields.clear();
     }
 }
@@ -725,6 +849,9 @@ impl ::std::cmp::PartialEq for Registration {
         self.protocol == other.protocol &&
         self.endpoint == other.endpoint &&
         self.shards == other.shards &&
+        self.capabilities == other.capabilities &&
+        self.weight == other.weight &&
+        self.schemas == other.schemas &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -735,6 +862,1874 @@ impl ::std::fmt::Debug for Registration {
     }
 }
 
+#[derive(Clone,Default)]
+pub struct ShardMap {
+    // message fields
+    registrations: ::protobuf::RepeatedField<Registration>,
+    taken_at_ms: ::std::option::Option<i64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ShardMap {}
+
+impl ShardMap {
+    pub fn new() -> ShardMap {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ShardMap {
+        static mut instance: ::protobuf::lazy::Lazy<ShardMap> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ShardMap,
+        };
+        unsafe {
+            instance.get(|| {
+                ShardMap {
+                    registrations: ::protobuf::RepeatedField::new(),
+                    taken_at_ms: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // repeated .routesrv.Registration registrations = 1;
+
+    pub fn clear_registrations(&mut self) {
+        self.registrations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_registrations(&mut self, v: ::protobuf::RepeatedField<Registration>) {
+        self.registrations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_registrations(&mut self) -> &mut ::protobuf::RepeatedField<Registration> {
+        &mut self.registrations
+    }
+
+    // Take field
+    pub fn take_registrations(&mut self) -> ::protobuf::RepeatedField<Registration> {
+        ::std::mem::replace(&mut self.registrations, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_registrations(&self) -> &[Registration] {
+        &self.registrations
+    }
+
+    // required int64 taken_at_ms = 2;
+
+    pub fn clear_taken_at_ms(&mut self) {
+        self.taken_at_ms = ::std::option::Option::None;
+    }
+
+    pub fn has_taken_at_ms(&self) -> bool {
+        self.taken_at_ms.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_taken_at_ms(&mut self, v: i64) {
+        self.taken_at_ms = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_taken_at_ms(&self) -> i64 {
+        self.taken_at_ms.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for ShardMap {
+    fn is_initialized(&self) -> bool {
+        if self.taken_at_ms.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.registrations));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_int64());
+                    self.taken_at_ms = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.registrations.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.taken_at_ms.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in self.registrations.iter() {
+            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.taken_at_ms {
+            try!(os.write_int64(2, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ShardMap>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ShardMap {
+    fn new() -> ShardMap {
+        ShardMap::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ShardMap>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "registrations",
+                    ShardMap::get_registrations,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor(
+                    "taken_at_ms",
+                    ShardMap::has_taken_at_ms,
+                    ShardMap::get_taken_at_ms,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ShardMap>(
+                    "ShardMap",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ShardMap {
+    fn clear(&mut self) {
+        self.clear_registrations();
+        self.clear_taken_at_ms();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ShardMap {
+    fn eq(&self, other: &ShardMap) -> bool {
+        self.registrations == other.registrations &&
+        self.taken_at_ms == other.taken_at_ms &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ShardMap {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct DenyListUpdate {
+    // message fields
+    deny: ::protobuf::RepeatedField<::std::string::String>,
+    allow: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for DenyListUpdate {}
+
+impl DenyListUpdate {
+    pub fn new() -> DenyListUpdate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static DenyListUpdate {
+        static mut instance: ::protobuf::lazy::Lazy<DenyListUpdate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DenyListUpdate,
+        };
+        unsafe {
+            instance.get(|| {
+                DenyListUpdate {
+                    deny: ::protobuf::RepeatedField::new(),
+                    allow: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // repeated string deny = 1;
+
+    pub fn clear_deny(&mut self) {
+        self.deny.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deny(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.deny = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_deny(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.deny
+    }
+
+    // Take field
+    pub fn take_deny(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.deny, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_deny(&self) -> &[::std::string::String] {
+        &self.deny
+    }
+
+    // repeated string allow = 2;
+
+    pub fn clear_allow(&mut self) {
+        self.allow.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_allow(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.allow = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_allow(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.allow
+    }
+
+    // Take field
+    pub fn take_allow(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.allow, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_allow(&self) -> &[::std::string::String] {
+        &self.allow
+    }
+}
+
+impl ::protobuf::Message for DenyListUpdate {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.deny));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.allow));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.deny.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.allow.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in self.deny.iter() {
+            try!(os.write_string(1, &v));
+        };
+        for v in self.allow.iter() {
+            try!(os.write_string(2, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<DenyListUpdate>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for DenyListUpdate {
+    fn new() -> DenyListUpdate {
+        DenyListUpdate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<DenyListUpdate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "deny",
+                    DenyListUpdate::get_deny,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "allow",
+                    DenyListUpdate::get_allow,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<DenyListUpdate>(
+                    "DenyListUpdate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for DenyListUpdate {
+    fn clear(&mut self) {
+        self.clear_deny();
+        self.clear_allow();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for DenyListUpdate {
+    fn eq(&self, other: &DenyListUpdate) -> bool {
+        self.deny == other.deny &&
+        self.allow == other.allow &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for DenyListUpdate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct DenyListGet {
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for DenyListGet {}
+
+impl DenyListGet {
+    pub fn new() -> DenyListGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static DenyListGet {
+        static mut instance: ::protobuf::lazy::Lazy<DenyListGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DenyListGet,
+        };
+        unsafe {
+            instance.get(|| {
+                DenyListGet {
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+}
+
+impl ::protobuf::Message for DenyListGet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<DenyListGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for DenyListGet {
+    fn new() -> DenyListGet {
+        DenyListGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<DenyListGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<DenyListGet>(
+                    "DenyListGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for DenyListGet {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for DenyListGet {
+    fn eq(&self, other: &DenyListGet) -> bool {
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for DenyListGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct DenyEntry {
+    // message fields
+    ident: ::protobuf::SingularField<::std::string::String>,
+    blocked: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for DenyEntry {}
+
+impl DenyEntry {
+    pub fn new() -> DenyEntry {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static DenyEntry {
+        static mut instance: ::protobuf::lazy::Lazy<DenyEntry> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DenyEntry,
+        };
+        unsafe {
+            instance.get(|| {
+                DenyEntry {
+                    ident: ::protobuf::SingularField::none(),
+                    blocked: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string ident = 1;
+
+    pub fn clear_ident(&mut self) {
+        self.ident.clear();
+    }
+
+    pub fn has_ident(&self) -> bool {
+        self.ident.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ident(&mut self, v: ::std::string::String) {
+        self.ident = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ident(&mut self) -> &mut ::std::string::String {
+        if self.ident.is_none() {
+            self.ident.set_default();
+        };
+        self.ident.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ident(&mut self) -> ::std::string::String {
+        self.ident.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_ident(&self) -> &str {
+        match self.ident.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 blocked = 2;
+
+    pub fn clear_blocked(&mut self) {
+        self.blocked = ::std::option::Option::None;
+    }
+
+    pub fn has_blocked(&self) -> bool {
+        self.blocked.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blocked(&mut self, v: u64) {
+        self.blocked = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_blocked(&self) -> u64 {
+        self.blocked.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for DenyEntry {
+    fn is_initialized(&self) -> bool {
+        if self.ident.is_none() {
+            return false;
+        };
+        if self.blocked.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.ident));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.blocked = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.ident.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.blocked.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.ident.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.blocked {
+            try!(os.write_uint64(2, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<DenyEntry>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for DenyEntry {
+    fn new() -> DenyEntry {
+        DenyEntry::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<DenyEntry>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "ident",
+                    DenyEntry::has_ident,
+                    DenyEntry::get_ident,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "blocked",
+                    DenyEntry::has_blocked,
+                    DenyEntry::get_blocked,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<DenyEntry>(
+                    "DenyEntry",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for DenyEntry {
+    fn clear(&mut self) {
+        self.clear_ident();
+        self.clear_blocked();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for DenyEntry {
+    fn eq(&self, other: &DenyEntry) -> bool {
+        self.ident == other.ident &&
+        self.blocked == other.blocked &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for DenyEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct DenyList {
+    // message fields
+    entries: ::protobuf::RepeatedField<DenyEntry>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for DenyList {}
+
+impl DenyList {
+    pub fn new() -> DenyList {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static DenyList {
+        static mut instance: ::protobuf::lazy::Lazy<DenyList> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DenyList,
+        };
+        unsafe {
+            instance.get(|| {
+                DenyList {
+                    entries: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // repeated .routesrv.DenyEntry entries = 1;
+
+    pub fn clear_entries(&mut self) {
+        self.entries.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_entries(&mut self, v: ::protobuf::RepeatedField<DenyEntry>) {
+        self.entries = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_entries(&mut self) -> &mut ::protobuf::RepeatedField<DenyEntry> {
+        &mut self.entries
+    }
+
+    // Take field
+    pub fn take_entries(&mut self) -> ::protobuf::RepeatedField<DenyEntry> {
+        ::std::mem::replace(&mut self.entries, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_entries(&self) -> &[DenyEntry] {
+        &self.entries
+    }
+}
+
+impl ::protobuf::Message for DenyList {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.entries));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.entries.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in self.entries.iter() {
+            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<DenyList>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for DenyList {
+    fn new() -> DenyList {
+        DenyList::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<DenyList>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "entries",
+                    DenyList::get_entries,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<DenyList>(
+                    "DenyList",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for DenyList {
+    fn clear(&mut self) {
+        self.clear_entries();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for DenyList {
+    fn eq(&self, other: &DenyList) -> bool {
+        self.entries == other.entries &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for DenyList {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ServerLoadGet {
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ServerLoadGet {}
+
+impl ServerLoadGet {
+    pub fn new() -> ServerLoadGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ServerLoadGet {
+        static mut instance: ::protobuf::lazy::Lazy<ServerLoadGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ServerLoadGet,
+        };
+        unsafe {
+            instance.get(|| {
+                ServerLoadGet {
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+}
+
+impl ::protobuf::Message for ServerLoadGet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ServerLoadGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServerLoadGet {
+    fn new() -> ServerLoadGet {
+        ServerLoadGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ServerLoadGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<ServerLoadGet>(
+                    "ServerLoadGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServerLoadGet {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ServerLoadGet {
+    fn eq(&self, other: &ServerLoadGet) -> bool {
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ServerLoadGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ServerLoad {
+    // message fields
+    endpoint: ::protobuf::SingularField<::std::string::String>,
+    protocol: ::std::option::Option<super::net::Protocol>,
+    load: ::protobuf::SingularPtrField<super::net::Stats>,
+    received_at_ms: ::std::option::Option<i64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ServerLoad {}
+
+impl ServerLoad {
+    pub fn new() -> ServerLoad {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ServerLoad {
+        static mut instance: ::protobuf::lazy::Lazy<ServerLoad> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ServerLoad,
+        };
+        unsafe {
+            instance.get(|| {
+                ServerLoad {
+                    endpoint: ::protobuf::SingularField::none(),
+                    protocol: ::std::option::Option::None,
+                    load: ::protobuf::SingularPtrField::none(),
+                    received_at_ms: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string endpoint = 1;
+
+    pub fn clear_endpoint(&mut self) {
+        self.endpoint.clear();
+    }
+
+    pub fn has_endpoint(&self) -> bool {
+        self.endpoint.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_endpoint(&mut self, v: ::std::string::String) {
+        self.endpoint = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_endpoint(&mut self) -> &mut ::std::string::String {
+        if self.endpoint.is_none() {
+            self.endpoint.set_default();
+        };
+        self.endpoint.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_endpoint(&mut self) -> ::std::string::String {
+        self.endpoint.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_endpoint(&self) -> &str {
+        match self.endpoint.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required .net.Protocol protocol = 2;
+
+    pub fn clear_protocol(&mut self) {
+        self.protocol = ::std::option::Option::None;
+    }
+
+    pub fn has_protocol(&self) -> bool {
+        self.protocol.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_protocol(&mut self, v: super::net::Protocol) {
+        self.protocol = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_protocol(&self) -> super::net::Protocol {
+        self.protocol.unwrap_or(super::net::Protocol::Net)
+    }
+
+    // required .net.Stats load = 3;
+
+    pub fn clear_load(&mut self) {
+        self.load.clear();
+    }
+
+    pub fn has_load(&self) -> bool {
+        self.load.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_load(&mut self, v: super::net::Stats) {
+        self.load = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_load(&mut self) -> &mut super::net::Stats {
+        if self.load.is_none() {
+            self.load.set_default();
+        };
+        self.load.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_load(&mut self) -> super::net::Stats {
+        self.load.take().unwrap_or_else(|| super::net::Stats::new())
+    }
+
+    pub fn get_load(&self) -> &super::net::Stats {
+        self.load.as_ref().unwrap_or_else(|| super::net::Stats::default_instance())
+    }
+
+    // required int64 received_at_ms = 4;
+
+    pub fn clear_received_at_ms(&mut self) {
+        self.received_at_ms = ::std::option::Option::None;
+    }
+
+    pub fn has_received_at_ms(&self) -> bool {
+        self.received_at_ms.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_received_at_ms(&mut self, v: i64) {
+        self.received_at_ms = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_received_at_ms(&self) -> i64 {
+        self.received_at_ms.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for ServerLoad {
+    fn is_initialized(&self) -> bool {
+        if self.endpoint.is_none() {
+            return false;
+        };
+        if self.protocol.is_none() {
+            return false;
+        };
+        if self.load.is_none() {
+            return false;
+        };
+        if self.received_at_ms.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.endpoint));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.protocol = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.load));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_int64());
+                    self.received_at_ms = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.endpoint.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.protocol.iter() {
+            my_size += ::protobuf::rt::enum_size(2, *value);
+        };
+        for value in self.load.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.received_at_ms.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.endpoint.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.protocol {
+            try!(os.write_enum(2, v.value()));
+        };
+        if let Some(v) = self.load.as_ref() {
+            try!(os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.received_at_ms {
+            try!(os.write_int64(4, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ServerLoad>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServerLoad {
+    fn new() -> ServerLoad {
+        ServerLoad::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ServerLoad>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "endpoint",
+                    ServerLoad::has_endpoint,
+                    ServerLoad::get_endpoint,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "protocol",
+                    ServerLoad::has_protocol,
+                    ServerLoad::get_protocol,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "load",
+                    ServerLoad::has_load,
+                    ServerLoad::get_load,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_i64_accessor(
+                    "received_at_ms",
+                    ServerLoad::has_received_at_ms,
+                    ServerLoad::get_received_at_ms,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ServerLoad>(
+                    "ServerLoad",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServerLoad {
+    fn clear(&mut self) {
+        self.clear_endpoint();
+        self.clear_protocol();
+        self.clear_load();
+        self.clear_received_at_ms();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ServerLoad {
+    fn eq(&self, other: &ServerLoad) -> bool {
+        self.endpoint == other.endpoint &&
+        self.protocol == other.protocol &&
+        self.load == other.load &&
+        self.received_at_ms == other.received_at_ms &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ServerLoad {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ServerLoads {
+    // message fields
+    servers: ::protobuf::RepeatedField<ServerLoad>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ServerLoads {}
+
+impl ServerLoads {
+    pub fn new() -> ServerLoads {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ServerLoads {
+        static mut instance: ::protobuf::lazy::Lazy<ServerLoads> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ServerLoads,
+        };
+        unsafe {
+            instance.get(|| {
+                ServerLoads {
+                    servers: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // repeated .routesrv.ServerLoad servers = 1;
+
+    pub fn clear_servers(&mut self) {
+        self.servers.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_servers(&mut self, v: ::protobuf::RepeatedField<ServerLoad>) {
+        self.servers = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_servers(&mut self) -> &mut ::protobuf::RepeatedField<ServerLoad> {
+        &mut self.servers
+    }
+
+    // Take field
+    pub fn take_servers(&mut self) -> ::protobuf::RepeatedField<ServerLoad> {
+        ::std::mem::replace(&mut self.servers, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_servers(&self) -> &[ServerLoad] {
+        &self.servers
+    }
+}
+
+impl ::protobuf::Message for ServerLoads {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.servers));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.servers.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in self.servers.iter() {
+            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ServerLoads>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServerLoads {
+    fn new() -> ServerLoads {
+        ServerLoads::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ServerLoads>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "servers",
+                    ServerLoads::get_servers,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ServerLoads>(
+                    "ServerLoads",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServerLoads {
+    fn clear(&mut self) {
+        self.clear_servers();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ServerLoads {
+    fn eq(&self, other: &ServerLoads) -> bool {
+        self.servers == other.servers &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ServerLoads {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct SchemaFingerprint {
+    // message fields
+    name: ::protobuf::SingularField<::std::string::String>,
+    fingerprint: ::std::option::Option<u64>,
+    fields: ::std::vec::Vec<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for SchemaFingerprint {}
+
+impl SchemaFingerprint {
+    pub fn new() -> SchemaFingerprint {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static SchemaFingerprint {
+        static mut instance: ::protobuf::lazy::Lazy<SchemaFingerprint> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SchemaFingerprint,
+        };
+        unsafe {
+            instance.get(|| {
+                SchemaFingerprint {
+                    name: ::protobuf::SingularField::none(),
+                    fingerprint: ::std::option::Option::None,
+                    fields: ::std::vec::Vec::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 fingerprint = 2;
+
+    pub fn clear_fingerprint(&mut self) {
+        self.fingerprint = ::std::option::Option::None;
+    }
+
+    pub fn has_fingerprint(&self) -> bool {
+        self.fingerprint.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fingerprint(&mut self, v: u64) {
+        self.fingerprint = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_fingerprint(&self) -> u64 {
+        self.fingerprint.unwrap_or(0)
+    }
+
+    // repeated uint64 fields = 3;
+
+    pub fn clear_fields(&mut self) {
+        self.fields.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fields(&mut self, v: ::std::vec::Vec<u64>) {
+        self.fields = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_fields(&mut self) -> &mut ::std::vec::Vec<u64> {
+        &mut self.fields
+    }
+
+    // Take field
+    pub fn take_fields(&mut self) -> ::std::vec::Vec<u64> {
+        ::std::mem::replace(&mut self.fields, ::std::vec::Vec::new())
+    }
+
+    pub fn get_fields(&self) -> &[u64] {
+        &self.fields
+    }
+}
+
+impl ::protobuf::Message for SchemaFingerprint {
+    fn is_initialized(&self) -> bool {
+        if self.name.is_none() {
+            return false;
+        };
+        if self.fingerprint.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.fingerprint = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_repeated_uint64_into(wire_type, is, &mut self.fields));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.fingerprint.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.fields.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.fingerprint {
+            try!(os.write_uint64(2, v));
+        };
+        for v in self.fields.iter() {
+            try!(os.write_uint64(3, *v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<SchemaFingerprint>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for SchemaFingerprint {
+    fn new() -> SchemaFingerprint {
+        SchemaFingerprint::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<SchemaFingerprint>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    SchemaFingerprint::has_name,
+                    SchemaFingerprint::get_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "fingerprint",
+                    SchemaFingerprint::has_fingerprint,
+                    SchemaFingerprint::get_fingerprint,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_u64_accessor(
+                    "fields",
+                    SchemaFingerprint::get_fields,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SchemaFingerprint>(
+                    "SchemaFingerprint",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for SchemaFingerprint {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_fingerprint();
+        self.clear_fields();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for SchemaFingerprint {
+    fn eq(&self, other: &SchemaFingerprint) -> bool {
+        self.name == other.name &&
+        self.fingerprint == other.fingerprint &&
+        self.fields == other.fields &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for SchemaFingerprint {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = &[
     0x0a, 0x18, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x73, 0x2f, 0x72, 0x6f, 0x75, 0x74,
     0x65, 0x73, 0x72, 0x76, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x08, 0x72, 0x6f, 0x75, 0x74,
@@ -744,44 +2739,81 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x74, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x16, 0x2e, 0x72, 0x6f, 0x75,
     0x74, 0x65, 0x73, 0x72, 0x76, 0x2e, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x61, 0x74, 0x69,
     0x6f, 0x6e, 0x22, 0x0b, 0x0a, 0x09, 0x43, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x4f, 0x6b, 0x22,
-    0x0c, 0x0a, 0x0a, 0x44, 0x69, 0x73, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x22, 0x55, 0x0a,
-    0x0c, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1f, 0x0a,
-    0x08, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32,
-    0x0d, 0x2e, 0x6e, 0x65, 0x74, 0x2e, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x12, 0x10,
-    0x0a, 0x08, 0x65, 0x6e, 0x64, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09,
-    0x12, 0x12, 0x0a, 0x06, 0x73, 0x68, 0x61, 0x72, 0x64, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0d,
-    0x42, 0x02, 0x10, 0x01, 0x4a, 0xf9, 0x03, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x0e, 0x01, 0x0a,
-    0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x00, 0x07, 0x1c, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12,
-    0x03, 0x01, 0x08, 0x10, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x03, 0x00, 0x05, 0x01,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x03, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x04, 0x02, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x00, 0x04, 0x12, 0x03, 0x04, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06,
-    0x12, 0x03, 0x04, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x04, 0x18, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x04, 0x27,
-    0x28, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x03, 0x07, 0x00, 0x14, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x01, 0x01, 0x12, 0x03, 0x07, 0x08, 0x11, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x03,
-    0x08, 0x00, 0x15, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x08, 0x08, 0x12, 0x0a,
-    0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x0a, 0x00, 0x0e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
-    0x03, 0x01, 0x12, 0x03, 0x0a, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12,
-    0x03, 0x0b, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0b,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x0b, 0x0b, 0x17,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0b, 0x18, 0x20, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0b, 0x23, 0x24, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x0c, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
-    0x01, 0x04, 0x12, 0x03, 0x0c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05,
-    0x12, 0x03, 0x0c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03,
-    0x0c, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0c, 0x1d,
-    0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x0d, 0x02, 0x2b, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x04, 0x12, 0x03, 0x0d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x03, 0x02, 0x02, 0x05, 0x12, 0x03, 0x0d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
-    0x02, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02,
-    0x03, 0x12, 0x03, 0x0d, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x08, 0x12,
-    0x03, 0x0d, 0x1d, 0x2a, 0x0a, 0x0f, 0x0a, 0x08, 0x04, 0x03, 0x02, 0x02, 0x08, 0xe7, 0x07, 0x00,
-    0x12, 0x03, 0x0d, 0x1e, 0x29, 0x0a, 0x10, 0x0a, 0x09, 0x04, 0x03, 0x02, 0x02, 0x08, 0xe7, 0x07,
-    0x00, 0x02, 0x12, 0x03, 0x0d, 0x1e, 0x24, 0x0a, 0x11, 0x0a, 0x0a, 0x04, 0x03, 0x02, 0x02, 0x08,
-    0xe7, 0x07, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0d, 0x1e, 0x24, 0x0a, 0x12, 0x0a, 0x0b, 0x04, 0x03,
-    0x02, 0x02, 0x08, 0xe7, 0x07, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0d, 0x1e, 0x24, 0x0a, 0x10,
-    0x0a, 0x09, 0x04, 0x03, 0x02, 0x02, 0x08, 0xe7, 0x07, 0x00, 0x03, 0x12, 0x03, 0x0d, 0x25, 0x29,
+    0x0c, 0x0a, 0x0a, 0x44, 0x69, 0x73, 0x63, 0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x22, 0xa9, 0x01,
+    0x0a, 0x0c, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1f,
+    0x0a, 0x08, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e,
+    0x32, 0x0d, 0x2e, 0x6e, 0x65, 0x74, 0x2e, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x12,
+    0x10, 0x0a, 0x08, 0x65, 0x6e, 0x64, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x02, 0x28,
+    0x09, 0x12, 0x12, 0x0a, 0x06, 0x73, 0x68, 0x61, 0x72, 0x64, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28,
+    0x0d, 0x42, 0x02, 0x10, 0x01, 0x12, 0x14, 0x0a, 0x0c, 0x63, 0x61, 0x70, 0x61, 0x62, 0x69, 0x6c,
+    0x69, 0x74, 0x69, 0x65, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0d, 0x12, 0x0e, 0x0a, 0x06, 0x77,
+    0x65, 0x69, 0x67, 0x68, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0d, 0x12, 0x2c, 0x0a, 0x07, 0x73,
+    0x63, 0x68, 0x65, 0x6d, 0x61, 0x73, 0x18, 0x06, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x72,
+    0x6f, 0x75, 0x74, 0x65, 0x73, 0x72, 0x76, 0x2e, 0x53, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x46, 0x69,
+    0x6e, 0x67, 0x65, 0x72, 0x70, 0x72, 0x69, 0x6e, 0x74, 0x22, 0x4e, 0x0a, 0x08, 0x53, 0x68, 0x61,
+    0x72, 0x64, 0x4d, 0x61, 0x70, 0x12, 0x2d, 0x0a, 0x0d, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x16, 0x2e, 0x72,
+    0x6f, 0x75, 0x74, 0x65, 0x73, 0x72, 0x76, 0x2e, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x12, 0x13, 0x0a, 0x0b, 0x74, 0x61, 0x6b, 0x65, 0x6e, 0x5f, 0x61, 0x74,
+    0x5f, 0x6d, 0x73, 0x18, 0x02, 0x20, 0x02, 0x28, 0x03, 0x22, 0x2d, 0x0a, 0x0e, 0x44, 0x65, 0x6e,
+    0x79, 0x4c, 0x69, 0x73, 0x74, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x12, 0x0c, 0x0a, 0x04, 0x64,
+    0x65, 0x6e, 0x79, 0x18, 0x01, 0x20, 0x03, 0x28, 0x09, 0x12, 0x0d, 0x0a, 0x05, 0x61, 0x6c, 0x6c,
+    0x6f, 0x77, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x22, 0x0d, 0x0a, 0x0b, 0x44, 0x65, 0x6e, 0x79,
+    0x4c, 0x69, 0x73, 0x74, 0x47, 0x65, 0x74, 0x22, 0x2b, 0x0a, 0x09, 0x44, 0x65, 0x6e, 0x79, 0x45,
+    0x6e, 0x74, 0x72, 0x79, 0x12, 0x0d, 0x0a, 0x05, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x09, 0x12, 0x0f, 0x0a, 0x07, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x65, 0x64, 0x18, 0x02,
+    0x20, 0x02, 0x28, 0x04, 0x22, 0x30, 0x0a, 0x08, 0x44, 0x65, 0x6e, 0x79, 0x4c, 0x69, 0x73, 0x74,
+    0x12, 0x24, 0x0a, 0x07, 0x65, 0x6e, 0x74, 0x72, 0x69, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28,
+    0x0b, 0x32, 0x13, 0x2e, 0x72, 0x6f, 0x75, 0x74, 0x65, 0x73, 0x72, 0x76, 0x2e, 0x44, 0x65, 0x6e,
+    0x79, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x22, 0x0f, 0x0a, 0x0d, 0x53, 0x65, 0x72, 0x76, 0x65, 0x72,
+    0x4c, 0x6f, 0x61, 0x64, 0x47, 0x65, 0x74, 0x22, 0x71, 0x0a, 0x0a, 0x53, 0x65, 0x72, 0x76, 0x65,
+    0x72, 0x4c, 0x6f, 0x61, 0x64, 0x12, 0x10, 0x0a, 0x08, 0x65, 0x6e, 0x64, 0x70, 0x6f, 0x69, 0x6e,
+    0x74, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x1f, 0x0a, 0x08, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x63, 0x6f, 0x6c, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0d, 0x2e, 0x6e, 0x65, 0x74, 0x2e,
+    0x50, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x12, 0x18, 0x0a, 0x04, 0x6c, 0x6f, 0x61, 0x64,
+    0x18, 0x03, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x6e, 0x65, 0x74, 0x2e, 0x53, 0x74, 0x61,
+    0x74, 0x73, 0x12, 0x16, 0x0a, 0x0e, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x5f, 0x61,
+    0x74, 0x5f, 0x6d, 0x73, 0x18, 0x04, 0x20, 0x02, 0x28, 0x03, 0x22, 0x34, 0x0a, 0x0b, 0x53, 0x65,
+    0x72, 0x76, 0x65, 0x72, 0x4c, 0x6f, 0x61, 0x64, 0x73, 0x12, 0x25, 0x0a, 0x07, 0x73, 0x65, 0x72,
+    0x76, 0x65, 0x72, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x72, 0x6f, 0x75,
+    0x74, 0x65, 0x73, 0x72, 0x76, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x65, 0x72, 0x4c, 0x6f, 0x61, 0x64,
+    0x22, 0x46, 0x0a, 0x11, 0x53, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x46, 0x69, 0x6e, 0x67, 0x65, 0x72,
+    0x70, 0x72, 0x69, 0x6e, 0x74, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b, 0x66, 0x69, 0x6e, 0x67, 0x65, 0x72, 0x70, 0x72, 0x69,
+    0x6e, 0x74, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0e, 0x0a, 0x06, 0x66, 0x69, 0x65, 0x6c,
+    0x64, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x04, 0x4a, 0xf9, 0x03, 0x0a, 0x06, 0x12, 0x04, 0x00,
+    0x00, 0x0e, 0x01, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x00, 0x07, 0x1c, 0x0a, 0x08,
+    0x0a, 0x01, 0x02, 0x12, 0x03, 0x01, 0x08, 0x10, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04,
+    0x03, 0x00, 0x05, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x03, 0x08, 0x0f,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x04, 0x02, 0x29, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x04, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x04, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x04, 0x18, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x04, 0x27, 0x28, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x03, 0x07, 0x00, 0x14,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x07, 0x08, 0x11, 0x0a, 0x09, 0x0a, 0x02,
+    0x04, 0x02, 0x12, 0x03, 0x08, 0x00, 0x15, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03,
+    0x08, 0x08, 0x12, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x0a, 0x00, 0x0e, 0x01, 0x0a,
+    0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x0a, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x03, 0x02, 0x00, 0x12, 0x03, 0x0b, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00,
+    0x04, 0x12, 0x03, 0x0b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12,
+    0x03, 0x0b, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0b,
+    0x18, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0b, 0x23, 0x24,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x0c, 0x02, 0x1f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x0c, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x0c, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x0d,
+    0x02, 0x2b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x04, 0x12, 0x03, 0x0d, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x05, 0x12, 0x03, 0x0d, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x0d, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x02, 0x08, 0x12, 0x03, 0x0d, 0x1d, 0x2a, 0x0a, 0x0f, 0x0a, 0x08, 0x04, 0x03, 0x02, 0x02,
+    0x08, 0xe7, 0x07, 0x00, 0x12, 0x03, 0x0d, 0x1e, 0x29, 0x0a, 0x10, 0x0a, 0x09, 0x04, 0x03, 0x02,
+    0x02, 0x08, 0xe7, 0x07, 0x00, 0x02, 0x12, 0x03, 0x0d, 0x1e, 0x24, 0x0a, 0x11, 0x0a, 0x0a, 0x04,
+    0x03, 0x02, 0x02, 0x08, 0xe7, 0x07, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0d, 0x1e, 0x24, 0x0a, 0x12,
+    0x0a, 0x0b, 0x04, 0x03, 0x02, 0x02, 0x08, 0xe7, 0x07, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0d,
+    0x1e, 0x24, 0x0a, 0x10, 0x0a, 0x09, 0x04, 0x03, 0x02, 0x02, 0x08, 0xe7, 0x07, 0x00, 0x03, 0x12,
+    0x03, 0x0d, 0x25, 0x29,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
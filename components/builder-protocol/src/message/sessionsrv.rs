@@ -22,6 +22,7 @@ pub struct Account {
     id: ::std::option::Option<u64>,
     email: ::protobuf::SingularField<::std::string::String>,
     name: ::protobuf::SingularField<::std::string::String>,
+    flags: ::std::option::Option<u32>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -46,6 +47,7 @@ impl Account {
                     id: ::std::option::Option::None,
                     email: ::protobuf::SingularField::none(),
                     name: ::protobuf::SingularField::none(),
+                    flags: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -143,6 +145,25 @@ impl Account {
             None => "",
         }
     }
+
+    // optional uint32 flags = 4;
+
+    pub fn clear_flags(&mut self) {
+        self.flags = ::std::option::Option::None;
+    }
+
+    pub fn has_flags(&self) -> bool {
+        self.flags.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_flags(&mut self, v: u32) {
+        self.flags = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_flags(&self) -> u32 {
+        self.flags.unwrap_or(0)
+    }
 }
 
 impl ::protobuf::Message for Account {
@@ -176,6 +197,13 @@ impl ::protobuf::Message for Account {
                 3 => {
                     try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
                 },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.flags = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -197,6 +225,9 @@ impl ::protobuf::Message for Account {
         for value in self.name.iter() {
             my_size += ::protobuf::rt::string_size(3, &value);
         };
+        for value in self.flags.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -212,6 +243,9 @@ impl ::protobuf::Message for Account {
         if let Some(v) = self.name.as_ref() {
             try!(os.write_string(3, &v));
         };
+        if let Some(v) = self.flags {
+            try!(os.write_uint32(4, v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -269,6 +303,11 @@ impl ::protobuf::MessageStatic for Account {
                     Account::has_name,
                     Account::get_name,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "flags",
+                    Account::has_flags,
+                    Account::get_flags,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Account>(
                     "Account",
                     fields,
@@ -284,6 +323,7 @@ impl ::protobuf::Clear for Account {
         self.clear_id();
         self.clear_email();
         self.clear_name();
+        self.clear_flags();
         self.unknown_fields.clear();
     }
 }
@@ -293,6 +333,7 @@ impl ::std::cmp::PartialEq for Account {
         self.id == other.id &&
         self.email == other.email &&
         self.name == other.name &&
+        self.flags == other.flags &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -496,6 +537,10 @@ pub struct Session {
     email: ::protobuf::SingularField<::std::string::String>,
     name: ::protobuf::SingularField<::std::string::String>,
     token: ::protobuf::SingularField<::std::string::String>,
+    scopes: ::protobuf::RepeatedField<::std::string::String>,
+    expires_at: ::std::option::Option<u64>,
+    flags: ::std::option::Option<u32>,
+    api_token_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -521,6 +566,10 @@ impl Session {
                     email: ::protobuf::SingularField::none(),
                     name: ::protobuf::SingularField::none(),
                     token: ::protobuf::SingularField::none(),
+                    scopes: ::protobuf::RepeatedField::new(),
+                    expires_at: ::std::option::Option::None,
+                    flags: ::std::option::Option::None,
+                    api_token_id: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -654,6 +703,88 @@ impl Session {
             None => "",
         }
     }
+
+    // repeated string scopes = 5;
+
+    pub fn clear_scopes(&mut self) {
+        self.scopes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scopes(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.scopes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scopes(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.scopes
+    }
+
+    // Take field
+    pub fn take_scopes(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.scopes, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_scopes(&self) -> &[::std::string::String] {
+        &self.scopes
+    }
+
+    // optional uint64 expires_at = 6;
+
+    pub fn clear_expires_at(&mut self) {
+        self.expires_at = ::std::option::Option::None;
+    }
+
+    pub fn has_expires_at(&self) -> bool {
+        self.expires_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expires_at(&mut self, v: u64) {
+        self.expires_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_expires_at(&self) -> u64 {
+        self.expires_at.unwrap_or(0)
+    }
+
+    // optional uint32 flags = 7;
+
+    pub fn clear_flags(&mut self) {
+        self.flags = ::std::option::Option::None;
+    }
+
+    pub fn has_flags(&self) -> bool {
+        self.flags.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_flags(&mut self, v: u32) {
+        self.flags = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_flags(&self) -> u32 {
+        self.flags.unwrap_or(0)
+    }
+
+    // optional uint64 api_token_id = 8;
+
+    pub fn clear_api_token_id(&mut self) {
+        self.api_token_id = ::std::option::Option::None;
+    }
+
+    pub fn has_api_token_id(&self) -> bool {
+        self.api_token_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_api_token_id(&mut self, v: u64) {
+        self.api_token_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_api_token_id(&self) -> u64 {
+        self.api_token_id.unwrap_or(0)
+    }
 }
 
 impl ::protobuf::Message for Session {
@@ -693,6 +824,30 @@ impl ::protobuf::Message for Session {
                 4 => {
                     try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token));
                 },
+                5 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.scopes));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.expires_at = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.flags = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.api_token_id = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -717,6 +872,18 @@ impl ::protobuf::Message for Session {
         for value in self.token.iter() {
             my_size += ::protobuf::rt::string_size(4, &value);
         };
+        for value in self.scopes.iter() {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        for value in self.expires_at.iter() {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.flags.iter() {
+            my_size += ::protobuf::rt::value_size(7, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.api_token_id.iter() {
+            my_size += ::protobuf::rt::value_size(8, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -735,6 +902,18 @@ impl ::protobuf::Message for Session {
         if let Some(v) = self.token.as_ref() {
             try!(os.write_string(4, &v));
         };
+        for v in self.scopes.iter() {
+            try!(os.write_string(5, &v));
+        };
+        if let Some(v) = self.expires_at {
+            try!(os.write_uint64(6, v));
+        };
+        if let Some(v) = self.flags {
+            try!(os.write_uint32(7, v));
+        };
+        if let Some(v) = self.api_token_id {
+            try!(os.write_uint64(8, v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -797,6 +976,25 @@ impl ::protobuf::MessageStatic for Session {
                     Session::has_token,
                     Session::get_token,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "scopes",
+                    Session::get_scopes,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "expires_at",
+                    Session::has_expires_at,
+                    Session::get_expires_at,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "flags",
+                    Session::has_flags,
+                    Session::get_flags,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "api_token_id",
+                    Session::has_api_token_id,
+                    Session::get_api_token_id,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Session>(
                     "Session",
                     fields,
@@ -813,6 +1011,10 @@ impl ::protobuf::Clear for Session {
         self.clear_email();
         self.clear_name();
         self.clear_token();
+        self.clear_scopes();
+        self.clear_expires_at();
+        self.clear_flags();
+        self.clear_api_token_id();
         self.unknown_fields.clear();
     }
 }
@@ -823,6 +1025,10 @@ impl ::std::cmp::PartialEq for Session {
         self.email == other.email &&
         self.name == other.name &&
         self.token == other.token &&
+        self.scopes == other.scopes &&
+        self.expires_at == other.expires_at &&
+        self.flags == other.flags &&
+        self.api_token_id == other.api_token_id &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -838,6 +1044,8 @@ pub struct SessionToken {
     // message fields
     token: ::protobuf::SingularField<::std::string::String>,
     owner_id: ::std::option::Option<u64>,
+    scopes: ::protobuf::RepeatedField<::std::string::String>,
+    expires_at: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -861,6 +1069,8 @@ impl SessionToken {
                 SessionToken {
                     token: ::protobuf::SingularField::none(),
                     owner_id: ::std::option::Option::None,
+                    scopes: ::protobuf::RepeatedField::new(),
+                    expires_at: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -922,6 +1132,50 @@ impl SessionToken {
     pub fn get_owner_id(&self) -> u64 {
         self.owner_id.unwrap_or(0)
     }
+
+    // repeated string scopes = 3;
+
+    pub fn clear_scopes(&mut self) {
+        self.scopes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scopes(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.scopes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scopes(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.scopes
+    }
+
+    // Take field
+    pub fn take_scopes(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.scopes, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_scopes(&self) -> &[::std::string::String] {
+        &self.scopes
+    }
+
+    // optional uint64 expires_at = 4;
+
+    pub fn clear_expires_at(&mut self) {
+        self.expires_at = ::std::option::Option::None;
+    }
+
+    pub fn has_expires_at(&self) -> bool {
+        self.expires_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expires_at(&mut self, v: u64) {
+        self.expires_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_expires_at(&self) -> u64 {
+        self.expires_at.unwrap_or(0)
+    }
 }
 
 impl ::protobuf::Message for SessionToken {
@@ -949,6 +1203,16 @@ impl ::protobuf::Message for SessionToken {
                     let tmp = try!(is.read_uint64());
                     self.owner_id = ::std::option::Option::Some(tmp);
                 },
+                3 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.scopes));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.expires_at = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -967,6 +1231,12 @@ impl ::protobuf::Message for SessionToken {
         for value in self.owner_id.iter() {
             my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
         };
+        for value in self.scopes.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.expires_at.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -979,6 +1249,12 @@ impl ::protobuf::Message for SessionToken {
         if let Some(v) = self.owner_id {
             try!(os.write_uint64(2, v));
         };
+        for v in self.scopes.iter() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.expires_at {
+            try!(os.write_uint64(4, v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -1031,6 +1307,15 @@ impl ::protobuf::MessageStatic for SessionToken {
                     SessionToken::has_owner_id,
                     SessionToken::get_owner_id,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "scopes",
+                    SessionToken::get_scopes,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "expires_at",
+                    SessionToken::has_expires_at,
+                    SessionToken::get_expires_at,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<SessionToken>(
                     "SessionToken",
                     fields,
@@ -1045,6 +1330,8 @@ impl ::protobuf::Clear for SessionToken {
     fn clear(&mut self) {
         self.clear_token();
         self.clear_owner_id();
+        self.clear_scopes();
+        self.clear_expires_at();
         self.unknown_fields.clear();
     }
 }
@@ -1053,6 +1340,8 @@ impl ::std::cmp::PartialEq for SessionToken {
     fn eq(&self, other: &SessionToken) -> bool {
         self.token == other.token &&
         self.owner_id == other.owner_id &&
+        self.scopes == other.scopes &&
+        self.expires_at == other.expires_at &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -1637,159 +1926,1950 @@ impl ::std::fmt::Debug for SessionGet {
     }
 }
 
-#[derive(Clone,PartialEq,Eq,Debug,Hash)]
-pub enum OAuthProvider {
-    GitHub = 0,
+#[derive(Clone,Default)]
+pub struct ApiToken {
+    // message fields
+    id: ::std::option::Option<u64>,
+    owner_id: ::std::option::Option<u64>,
+    token: ::protobuf::SingularField<::std::string::String>,
+    scopes: ::protobuf::RepeatedField<::std::string::String>,
+    description: ::protobuf::SingularField<::std::string::String>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
 }
 
-impl ::protobuf::ProtobufEnum for OAuthProvider {
-    fn value(&self) -> i32 {
-        *self as i32
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ApiToken {}
+
+impl ApiToken {
+    pub fn new() -> ApiToken {
+        ::std::default::Default::default()
     }
 
-    fn from_i32(value: i32) -> ::std::option::Option<OAuthProvider> {
-        match value {
-            0 => ::std::option::Option::Some(OAuthProvider::GitHub),
-            _ => ::std::option::Option::None
+    pub fn default_instance() -> &'static ApiToken {
+        static mut instance: ::protobuf::lazy::Lazy<ApiToken> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ApiToken,
+        };
+        unsafe {
+            instance.get(|| {
+                ApiToken {
+                    id: ::std::option::Option::None,
+                    owner_id: ::std::option::Option::None,
+                    token: ::protobuf::SingularField::none(),
+                    scopes: ::protobuf::RepeatedField::new(),
+                    description: ::protobuf::SingularField::none(),
+                    name: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
         }
     }
 
-    fn values() -> &'static [Self] {
-        static values: &'static [OAuthProvider] = &[
-            OAuthProvider::GitHub,
-        ];
-        values
+    // required uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
     }
 
-    fn enum_descriptor_static(_: Option<OAuthProvider>) -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    // required uint64 owner_id = 2;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // optional string token = 3;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        if self.token.is_none() {
+            self.token.set_default();
         };
-        unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("OAuthProvider", file_descriptor_proto())
-            })
+        self.token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_token(&self) -> &str {
+        match self.token.as_ref() {
+            Some(v) => &v,
+            None => "",
         }
     }
-}
 
-impl ::std::marker::Copy for OAuthProvider {
-}
+    // repeated string scopes = 4;
 
-static file_descriptor_proto_data: &'static [u8] = &[
-    0x0a, 0x1a, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x73, 0x2f, 0x73, 0x65, 0x73, 0x73,
-    0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x0a, 0x73, 0x65,
-    0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x22, 0x32, 0x0a, 0x07, 0x41, 0x63, 0x63, 0x6f,
-    0x75, 0x6e, 0x74, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12,
-    0x0d, 0x0a, 0x05, 0x65, 0x6d, 0x61, 0x69, 0x6c, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c,
-    0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x22, 0x1a, 0x0a, 0x0a,
-    0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x47, 0x65, 0x74, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61,
-    0x6d, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22, 0x41, 0x0a, 0x07, 0x53, 0x65, 0x73, 0x73,
+    pub fn clear_scopes(&mut self) {
+        self.scopes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scopes(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.scopes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scopes(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.scopes
+    }
+
+    // Take field
+    pub fn take_scopes(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.scopes, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_scopes(&self) -> &[::std::string::String] {
+        &self.scopes
+    }
+
+    // optional string description = 5;
+
+    pub fn clear_description(&mut self) {
+        self.description.clear();
+    }
+
+    pub fn has_description(&self) -> bool {
+        self.description.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.description = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        if self.description.is_none() {
+            self.description.set_default();
+        };
+        self.description.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        self.description.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_description(&self) -> &str {
+        match self.description.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string name = 6;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for ApiToken {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.scopes));
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.description));
+                },
+                6 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.token.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.scopes.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.description.iter() {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(6, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.token.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        for v in self.scopes.iter() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.description.as_ref() {
+            try!(os.write_string(5, &v));
+        };
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(6, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ApiToken>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ApiToken {
+    fn new() -> ApiToken {
+        ApiToken::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ApiToken>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    ApiToken::has_id,
+                    ApiToken::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    ApiToken::has_owner_id,
+                    ApiToken::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "token",
+                    ApiToken::has_token,
+                    ApiToken::get_token,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "scopes",
+                    ApiToken::get_scopes,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "description",
+                    ApiToken::has_description,
+                    ApiToken::get_description,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    ApiToken::has_name,
+                    ApiToken::get_name,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ApiToken>(
+                    "ApiToken",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ApiToken {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_owner_id();
+        self.clear_token();
+        self.clear_scopes();
+        self.clear_description();
+        self.clear_name();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ApiToken {
+    fn eq(&self, other: &ApiToken) -> bool {
+        self.id == other.id &&
+        self.owner_id == other.owner_id &&
+        self.token == other.token &&
+        self.scopes == other.scopes &&
+        self.description == other.description &&
+        self.name == other.name &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ApiToken {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ApiTokenCreate {
+    // message fields
+    owner_id: ::std::option::Option<u64>,
+    scopes: ::protobuf::RepeatedField<::std::string::String>,
+    description: ::protobuf::SingularField<::std::string::String>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ApiTokenCreate {}
+
+impl ApiTokenCreate {
+    pub fn new() -> ApiTokenCreate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ApiTokenCreate {
+        static mut instance: ::protobuf::lazy::Lazy<ApiTokenCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ApiTokenCreate,
+        };
+        unsafe {
+            instance.get(|| {
+                ApiTokenCreate {
+                    owner_id: ::std::option::Option::None,
+                    scopes: ::protobuf::RepeatedField::new(),
+                    description: ::protobuf::SingularField::none(),
+                    name: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 owner_id = 1;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // repeated string scopes = 2;
+
+    pub fn clear_scopes(&mut self) {
+        self.scopes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scopes(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.scopes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scopes(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.scopes
+    }
+
+    // Take field
+    pub fn take_scopes(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.scopes, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_scopes(&self) -> &[::std::string::String] {
+        &self.scopes
+    }
+
+    // optional string description = 3;
+
+    pub fn clear_description(&mut self) {
+        self.description.clear();
+    }
+
+    pub fn has_description(&self) -> bool {
+        self.description.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.description = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        if self.description.is_none() {
+            self.description.set_default();
+        };
+        self.description.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        self.description.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_description(&self) -> &str {
+        match self.description.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string name = 4;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for ApiTokenCreate {
+    fn is_initialized(&self) -> bool {
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.scopes));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.description));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.scopes.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.description.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(1, v));
+        };
+        for v in self.scopes.iter() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.description.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ApiTokenCreate>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ApiTokenCreate {
+    fn new() -> ApiTokenCreate {
+        ApiTokenCreate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ApiTokenCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    ApiTokenCreate::has_owner_id,
+                    ApiTokenCreate::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "scopes",
+                    ApiTokenCreate::get_scopes,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "description",
+                    ApiTokenCreate::has_description,
+                    ApiTokenCreate::get_description,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    ApiTokenCreate::has_name,
+                    ApiTokenCreate::get_name,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ApiTokenCreate>(
+                    "ApiTokenCreate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ApiTokenCreate {
+    fn clear(&mut self) {
+        self.clear_owner_id();
+        self.clear_scopes();
+        self.clear_description();
+        self.clear_name();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ApiTokenCreate {
+    fn eq(&self, other: &ApiTokenCreate) -> bool {
+        self.owner_id == other.owner_id &&
+        self.scopes == other.scopes &&
+        self.description == other.description &&
+        self.name == other.name &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ApiTokenCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ApiTokenListRequest {
+    // message fields
+    owner_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ApiTokenListRequest {}
+
+impl ApiTokenListRequest {
+    pub fn new() -> ApiTokenListRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ApiTokenListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ApiTokenListRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ApiTokenListRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                ApiTokenListRequest {
+                    owner_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 owner_id = 1;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for ApiTokenListRequest {
+    fn is_initialized(&self) -> bool {
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(1, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ApiTokenListRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ApiTokenListRequest {
+    fn new() -> ApiTokenListRequest {
+        ApiTokenListRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ApiTokenListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    ApiTokenListRequest::has_owner_id,
+                    ApiTokenListRequest::get_owner_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ApiTokenListRequest>(
+                    "ApiTokenListRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ApiTokenListRequest {
+    fn clear(&mut self) {
+        self.clear_owner_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ApiTokenListRequest {
+    fn eq(&self, other: &ApiTokenListRequest) -> bool {
+        self.owner_id == other.owner_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ApiTokenListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ApiTokenListResponse {
+    // message fields
+    owner_id: ::std::option::Option<u64>,
+    tokens: ::protobuf::RepeatedField<ApiToken>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ApiTokenListResponse {}
+
+impl ApiTokenListResponse {
+    pub fn new() -> ApiTokenListResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ApiTokenListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ApiTokenListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ApiTokenListResponse,
+        };
+        unsafe {
+            instance.get(|| {
+                ApiTokenListResponse {
+                    owner_id: ::std::option::Option::None,
+                    tokens: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 owner_id = 1;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // repeated .sessionsrv.ApiToken tokens = 2;
+
+    pub fn clear_tokens(&mut self) {
+        self.tokens.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tokens(&mut self, v: ::protobuf::RepeatedField<ApiToken>) {
+        self.tokens = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tokens(&mut self) -> &mut ::protobuf::RepeatedField<ApiToken> {
+        &mut self.tokens
+    }
+
+    // Take field
+    pub fn take_tokens(&mut self) -> ::protobuf::RepeatedField<ApiToken> {
+        ::std::mem::replace(&mut self.tokens, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_tokens(&self) -> &[ApiToken] {
+        &self.tokens
+    }
+}
+
+impl ::protobuf::Message for ApiTokenListResponse {
+    fn is_initialized(&self) -> bool {
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.tokens));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.tokens.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(1, v));
+        };
+        for v in self.tokens.iter() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ApiTokenListResponse>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ApiTokenListResponse {
+    fn new() -> ApiTokenListResponse {
+        ApiTokenListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ApiTokenListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    ApiTokenListResponse::has_owner_id,
+                    ApiTokenListResponse::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "tokens",
+                    ApiTokenListResponse::get_tokens,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ApiTokenListResponse>(
+                    "ApiTokenListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ApiTokenListResponse {
+    fn clear(&mut self) {
+        self.clear_owner_id();
+        self.clear_tokens();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ApiTokenListResponse {
+    fn eq(&self, other: &ApiTokenListResponse) -> bool {
+        self.owner_id == other.owner_id &&
+        self.tokens == other.tokens &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ApiTokenListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ApiTokenRevoke {
+    // message fields
+    owner_id: ::std::option::Option<u64>,
+    id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ApiTokenRevoke {}
+
+impl ApiTokenRevoke {
+    pub fn new() -> ApiTokenRevoke {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ApiTokenRevoke {
+        static mut instance: ::protobuf::lazy::Lazy<ApiTokenRevoke> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ApiTokenRevoke,
+        };
+        unsafe {
+            instance.get(|| {
+                ApiTokenRevoke {
+                    owner_id: ::std::option::Option::None,
+                    id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 owner_id = 1;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // required uint64 id = 2;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for ApiTokenRevoke {
+    fn is_initialized(&self) -> bool {
+        if self.owner_id.is_none() {
+            return false;
+        };
+        if self.id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.id {
+            try!(os.write_uint64(2, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ApiTokenRevoke>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ApiTokenRevoke {
+    fn new() -> ApiTokenRevoke {
+        ApiTokenRevoke::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ApiTokenRevoke>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    ApiTokenRevoke::has_owner_id,
+                    ApiTokenRevoke::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    ApiTokenRevoke::has_id,
+                    ApiTokenRevoke::get_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ApiTokenRevoke>(
+                    "ApiTokenRevoke",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ApiTokenRevoke {
+    fn clear(&mut self) {
+        self.clear_owner_id();
+        self.clear_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ApiTokenRevoke {
+    fn eq(&self, other: &ApiTokenRevoke) -> bool {
+        self.owner_id == other.owner_id &&
+        self.id == other.id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ApiTokenRevoke {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct SessionRenew {
+    // message fields
+    token: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for SessionRenew {}
+
+impl SessionRenew {
+    pub fn new() -> SessionRenew {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static SessionRenew {
+        static mut instance: ::protobuf::lazy::Lazy<SessionRenew> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SessionRenew,
+        };
+        unsafe {
+            instance.get(|| {
+                SessionRenew {
+                    token: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string token = 1;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        if self.token.is_none() {
+            self.token.set_default();
+        };
+        self.token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_token(&self) -> &str {
+        match self.token.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for SessionRenew {
+    fn is_initialized(&self) -> bool {
+        if self.token.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.token.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.token.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<SessionRenew>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for SessionRenew {
+    fn new() -> SessionRenew {
+        SessionRenew::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<SessionRenew>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "token",
+                    SessionRenew::has_token,
+                    SessionRenew::get_token,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SessionRenew>(
+                    "SessionRenew",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for SessionRenew {
+    fn clear(&mut self) {
+        self.clear_token();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for SessionRenew {
+    fn eq(&self, other: &SessionRenew) -> bool {
+        self.token == other.token &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for SessionRenew {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AccountFlagsSet {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    flags: ::std::option::Option<u32>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccountFlagsSet {}
+
+impl AccountFlagsSet {
+    pub fn new() -> AccountFlagsSet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccountFlagsSet {
+        static mut instance: ::protobuf::lazy::Lazy<AccountFlagsSet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccountFlagsSet,
+        };
+        unsafe {
+            instance.get(|| {
+                AccountFlagsSet {
+                    account_id: ::std::option::Option::None,
+                    flags: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // required uint32 flags = 2;
+
+    pub fn clear_flags(&mut self) {
+        self.flags = ::std::option::Option::None;
+    }
+
+    pub fn has_flags(&self) -> bool {
+        self.flags.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_flags(&mut self, v: u32) {
+        self.flags = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_flags(&self) -> u32 {
+        self.flags.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for AccountFlagsSet {
+    fn is_initialized(&self) -> bool {
+        if self.account_id.is_none() {
+            return false;
+        };
+        if self.flags.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.flags = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.flags.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.flags {
+            try!(os.write_uint32(2, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AccountFlagsSet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccountFlagsSet {
+    fn new() -> AccountFlagsSet {
+        AccountFlagsSet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccountFlagsSet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    AccountFlagsSet::has_account_id,
+                    AccountFlagsSet::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "flags",
+                    AccountFlagsSet::has_flags,
+                    AccountFlagsSet::get_flags,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountFlagsSet>(
+                    "AccountFlagsSet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccountFlagsSet {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_flags();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AccountFlagsSet {
+    fn eq(&self, other: &AccountFlagsSet) -> bool {
+        self.account_id == other.account_id &&
+        self.flags == other.flags &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AccountFlagsSet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum OAuthProvider {
+    GitHub = 0,
+}
+
+impl ::protobuf::ProtobufEnum for OAuthProvider {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<OAuthProvider> {
+        match value {
+            0 => ::std::option::Option::Some(OAuthProvider::GitHub),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [OAuthProvider] = &[
+            OAuthProvider::GitHub,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<OAuthProvider>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("OAuthProvider", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for OAuthProvider {
+}
+
+static file_descriptor_proto_data: &'static [u8] = &[
+    0x0a, 0x1a, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x73, 0x2f, 0x73, 0x65, 0x73, 0x73,
+    0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x0a, 0x73, 0x65,
+    0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x22, 0x41, 0x0a, 0x07, 0x41, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12,
+    0x0d, 0x0a, 0x05, 0x65, 0x6d, 0x61, 0x69, 0x6c, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c,
+    0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0d, 0x0a, 0x05,
+    0x66, 0x6c, 0x61, 0x67, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0d, 0x22, 0x1a, 0x0a, 0x0a, 0x41,
+    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x47, 0x65, 0x74, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d,
+    0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22, 0x8a, 0x01, 0x0a, 0x07, 0x53, 0x65, 0x73, 0x73,
     0x69, 0x6f, 0x6e, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12,
     0x0d, 0x0a, 0x05, 0x65, 0x6d, 0x61, 0x69, 0x6c, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c,
     0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0d, 0x0a, 0x05,
-    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x22, 0x2f, 0x0a, 0x0c, 0x53,
-    0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x0d, 0x0a, 0x05, 0x74,
-    0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77,
-    0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x22, 0x7b, 0x0a, 0x0d,
-    0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x0d, 0x0a,
-    0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x11, 0x0a, 0x09,
-    0x65, 0x78, 0x74, 0x65, 0x72, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12,
-    0x0d, 0x0a, 0x05, 0x65, 0x6d, 0x61, 0x69, 0x6c, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c,
-    0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x2b, 0x0a, 0x08,
-    0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x72, 0x18, 0x05, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x19,
-    0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x4f, 0x41, 0x75, 0x74,
-    0x68, 0x50, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x72, 0x22, 0x1b, 0x0a, 0x0a, 0x53, 0x65, 0x73,
-    0x73, 0x69, 0x6f, 0x6e, 0x47, 0x65, 0x74, 0x12, 0x0d, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
-    0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x2a, 0x1b, 0x0a, 0x0d, 0x4f, 0x41, 0x75, 0x74, 0x68, 0x50,
-    0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x72, 0x12, 0x0a, 0x0a, 0x06, 0x47, 0x69, 0x74, 0x48, 0x75,
-    0x62, 0x10, 0x00, 0x4a, 0xd4, 0x0a, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x27, 0x01, 0x0a, 0x08,
-    0x0a, 0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x12, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04,
-    0x02, 0x00, 0x04, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x02, 0x05, 0x12,
-    0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x03, 0x02, 0x0d, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x03, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x03, 0x0b, 0x0c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12,
-    0x04, 0x06, 0x00, 0x0a, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x06, 0x08,
-    0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x07, 0x02, 0x19, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x07, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x07, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
-    0x03, 0x12, 0x03, 0x07, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03,
-    0x08, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x08, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x08, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x08, 0x12, 0x17, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x08, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x00, 0x02, 0x02, 0x12, 0x03, 0x09, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02,
-    0x04, 0x12, 0x03, 0x09, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12,
-    0x03, 0x09, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x09,
-    0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x09, 0x19, 0x1a,
-    0x0a, 0x2b, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0d, 0x00, 0x0f, 0x01, 0x1a, 0x1f, 0x20, 0x67,
-    0x65, 0x74, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x62, 0x79,
-    0x20, 0x47, 0x48, 0x20, 0x75, 0x73, 0x65, 0x72, 0x6e, 0x61, 0x6d, 0x65, 0x0a, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0d, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
-    0x00, 0x12, 0x03, 0x0e, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12,
-    0x03, 0x0e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x0e,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0e, 0x12, 0x16,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0e, 0x19, 0x1a, 0x0a, 0x0a,
-    0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x11, 0x00, 0x16, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02,
-    0x01, 0x12, 0x03, 0x11, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03,
-    0x12, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x12, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x12, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x12, 0x12, 0x14, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x12, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x02, 0x02, 0x01, 0x12, 0x03, 0x13, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01,
-    0x04, 0x12, 0x03, 0x13, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12,
-    0x03, 0x13, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x13,
-    0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x13, 0x1a, 0x1b,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x02, 0x12, 0x03, 0x14, 0x02, 0x1b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x02, 0x04, 0x12, 0x03, 0x14, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x02, 0x02, 0x02, 0x05, 0x12, 0x03, 0x14, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
-    0x02, 0x01, 0x12, 0x03, 0x14, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x03,
-    0x12, 0x03, 0x14, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x15,
-    0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x04, 0x12, 0x03, 0x15, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x05, 0x12, 0x03, 0x15, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x15, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x03, 0x03, 0x12, 0x03, 0x15, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03,
-    0x12, 0x04, 0x18, 0x00, 0x1b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x18,
-    0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x19, 0x02, 0x1c, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x19, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x19, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x19, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
-    0x00, 0x03, 0x12, 0x03, 0x19, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12,
-    0x03, 0x1a, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x1a,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1a, 0x0b, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1a, 0x12, 0x1a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1a, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02,
-    0x04, 0x04, 0x12, 0x04, 0x1d, 0x00, 0x23, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12,
-    0x03, 0x1d, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x1e, 0x02,
-    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x1e, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x1e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1e, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1e, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02,
-    0x01, 0x12, 0x03, 0x1f, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12,
-    0x03, 0x1f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1f,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1f, 0x12, 0x1b,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1f, 0x1e, 0x1f, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x04, 0x02, 0x02, 0x12, 0x03, 0x20, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x02, 0x04, 0x12, 0x03, 0x20, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
-    0x02, 0x05, 0x12, 0x03, 0x20, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x01,
-    0x12, 0x03, 0x20, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03,
-    0x20, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x03, 0x12, 0x03, 0x21, 0x02, 0x1b,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x04, 0x12, 0x03, 0x21, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x05, 0x12, 0x03, 0x21, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x04, 0x02, 0x03, 0x01, 0x12, 0x03, 0x21, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
-    0x02, 0x03, 0x03, 0x12, 0x03, 0x21, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x04,
-    0x12, 0x03, 0x22, 0x02, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x04, 0x12, 0x03,
-    0x22, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x06, 0x12, 0x03, 0x22, 0x0b,
-    0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x01, 0x12, 0x03, 0x22, 0x19, 0x21, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x03, 0x12, 0x03, 0x22, 0x24, 0x25, 0x0a, 0x0a, 0x0a,
-    0x02, 0x04, 0x05, 0x12, 0x04, 0x25, 0x00, 0x27, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01,
-    0x12, 0x03, 0x25, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x26,
-    0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x26, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x26, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x26, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x26, 0x1a, 0x1b,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0e, 0x0a, 0x06, 0x73,
+    0x63, 0x6f, 0x70, 0x65, 0x73, 0x18, 0x05, 0x20, 0x03, 0x28, 0x09, 0x12, 0x12, 0x0a, 0x0a, 0x65,
+    0x78, 0x70, 0x69, 0x72, 0x65, 0x73, 0x5f, 0x61, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x12,
+    0x0d, 0x0a, 0x05, 0x66, 0x6c, 0x61, 0x67, 0x73, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0d, 0x12, 0x14,
+    0x0a, 0x0c, 0x61, 0x70, 0x69, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x08,
+    0x20, 0x01, 0x28, 0x04, 0x22, 0x53, 0x0a, 0x0c, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x54,
+    0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x0d, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18,
+    0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0e, 0x0a, 0x06, 0x73, 0x63, 0x6f, 0x70, 0x65, 0x73, 0x18,
+    0x03, 0x20, 0x03, 0x28, 0x09, 0x12, 0x12, 0x0a, 0x0a, 0x65, 0x78, 0x70, 0x69, 0x72, 0x65, 0x73,
+    0x5f, 0x61, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x22, 0x7b, 0x0a, 0x0d, 0x53, 0x65, 0x73,
+    0x73, 0x69, 0x6f, 0x6e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x0d, 0x0a, 0x05, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x11, 0x0a, 0x09, 0x65, 0x78, 0x74,
+    0x65, 0x72, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05,
+    0x65, 0x6d, 0x61, 0x69, 0x6c, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x6e,
+    0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x2b, 0x0a, 0x08, 0x70, 0x72, 0x6f,
+    0x76, 0x69, 0x64, 0x65, 0x72, 0x18, 0x05, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x19, 0x2e, 0x73, 0x65,
+    0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x4f, 0x41, 0x75, 0x74, 0x68, 0x50, 0x72,
+    0x6f, 0x76, 0x69, 0x64, 0x65, 0x72, 0x22, 0x1b, 0x0a, 0x0a, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f,
+    0x6e, 0x47, 0x65, 0x74, 0x12, 0x0d, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x09, 0x22, 0x6a, 0x0a, 0x08, 0x41, 0x70, 0x69, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12,
+    0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x10, 0x0a, 0x08, 0x6f,
+    0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a,
+    0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0e, 0x0a, 0x06,
+    0x73, 0x63, 0x6f, 0x70, 0x65, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b,
+    0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x05, 0x20, 0x01, 0x28,
+    0x09, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x22,
+    0x55, 0x0a, 0x0e, 0x41, 0x70, 0x69, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x43, 0x72, 0x65, 0x61, 0x74,
+    0x65, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x04, 0x12, 0x0e, 0x0a, 0x06, 0x73, 0x63, 0x6f, 0x70, 0x65, 0x73, 0x18, 0x02, 0x20,
+    0x03, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69,
+    0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65,
+    0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x22, 0x27, 0x0a, 0x13, 0x41, 0x70, 0x69, 0x54, 0x6f, 0x6b,
+    0x65, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a,
+    0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22,
+    0x4e, 0x0a, 0x14, 0x41, 0x70, 0x69, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72,
+    0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x24, 0x0a, 0x06, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x73, 0x65, 0x73, 0x73,
+    0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x41, 0x70, 0x69, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x22,
+    0x2e, 0x0a, 0x0e, 0x41, 0x70, 0x69, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x76, 0x6f, 0x6b,
+    0x65, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x04, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x22,
+    0x1d, 0x0a, 0x0c, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x6e, 0x65, 0x77, 0x12,
+    0x0d, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22, 0x34,
+    0x0a, 0x0f, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x53, 0x65,
+    0x74, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x66, 0x6c, 0x61, 0x67, 0x73, 0x18, 0x02,
+    0x20, 0x02, 0x28, 0x0d, 0x2a, 0x1b, 0x0a, 0x0d, 0x4f, 0x41, 0x75, 0x74, 0x68, 0x50, 0x72, 0x6f,
+    0x76, 0x69, 0x64, 0x65, 0x72, 0x12, 0x0a, 0x0a, 0x06, 0x47, 0x69, 0x74, 0x48, 0x75, 0x62, 0x10,
+    0x00, 0x4a, 0xd4, 0x0a, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x27, 0x01, 0x0a, 0x08, 0x0a, 0x01,
+    0x02, 0x12, 0x03, 0x00, 0x08, 0x12, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x02, 0x00,
+    0x04, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x02, 0x05, 0x12, 0x0a, 0x0b,
+    0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x03, 0x02, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x03, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02,
+    0x00, 0x02, 0x12, 0x03, 0x03, 0x0b, 0x0c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x06,
+    0x00, 0x0a, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x06, 0x08, 0x0f, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x07, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x00, 0x05, 0x12, 0x03, 0x07, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x07, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x07, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x08, 0x02,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x08, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x08, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x08, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x08, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02,
+    0x02, 0x12, 0x03, 0x09, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12,
+    0x03, 0x09, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x09,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x09, 0x12, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x09, 0x19, 0x1a, 0x0a, 0x2b,
+    0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0d, 0x00, 0x0f, 0x01, 0x1a, 0x1f, 0x20, 0x67, 0x65, 0x74,
+    0x20, 0x61, 0x6e, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x62, 0x79, 0x20, 0x47,
+    0x48, 0x20, 0x75, 0x73, 0x65, 0x72, 0x6e, 0x61, 0x6d, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x01, 0x01, 0x12, 0x03, 0x0d, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12,
+    0x03, 0x0e, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0e,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x0e, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0e, 0x12, 0x16, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0e, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x02, 0x12, 0x04, 0x11, 0x00, 0x16, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12,
+    0x03, 0x11, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x12, 0x02,
+    0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x12, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x12, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x12, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x12, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x13, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x04, 0x12,
+    0x03, 0x13, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x13,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x13, 0x12, 0x17,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x13, 0x1a, 0x1b, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x02, 0x02, 0x02, 0x12, 0x03, 0x14, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x02, 0x04, 0x12, 0x03, 0x14, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x02, 0x05, 0x12, 0x03, 0x14, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x01,
+    0x12, 0x03, 0x14, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x03, 0x12, 0x03,
+    0x14, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x15, 0x02, 0x1c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x04, 0x12, 0x03, 0x15, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x05, 0x12, 0x03, 0x15, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x15, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x03, 0x03, 0x12, 0x03, 0x15, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04,
+    0x18, 0x00, 0x1b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x18, 0x08, 0x14,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x19, 0x02, 0x1c, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x19, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x19, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x19, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x19, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x1a,
+    0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x1a, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1a, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1a, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1a, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04,
+    0x12, 0x04, 0x1d, 0x00, 0x23, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x1d,
+    0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x1e, 0x02, 0x1c, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x1e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x1e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1e, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x1e, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12,
+    0x03, 0x1f, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03, 0x1f,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1f, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1f, 0x12, 0x1b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1f, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x04, 0x02, 0x02, 0x12, 0x03, 0x20, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x02, 0x04, 0x12, 0x03, 0x20, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x05,
+    0x12, 0x03, 0x20, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x20, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x20, 0x1a,
+    0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x03, 0x12, 0x03, 0x21, 0x02, 0x1b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x04, 0x12, 0x03, 0x21, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x03, 0x05, 0x12, 0x03, 0x21, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x21, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03,
+    0x03, 0x12, 0x03, 0x21, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x04, 0x12, 0x03,
+    0x22, 0x02, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x04, 0x12, 0x03, 0x22, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x06, 0x12, 0x03, 0x22, 0x0b, 0x18, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x01, 0x12, 0x03, 0x22, 0x19, 0x21, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x04, 0x03, 0x12, 0x03, 0x22, 0x24, 0x25, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
+    0x05, 0x12, 0x04, 0x25, 0x00, 0x27, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03,
+    0x25, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x26, 0x02, 0x1c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x26, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x26, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x26, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x26, 0x1a, 0x1b,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
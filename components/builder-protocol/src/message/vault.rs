@@ -2331,6 +2331,7 @@ impl ::std::fmt::Debug for CheckOriginAccessRequest {
 pub struct CheckOriginAccessResponse {
     // message fields
     has_access: ::std::option::Option<bool>,
+    role: ::std::option::Option<OriginRole>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -2353,6 +2354,7 @@ impl CheckOriginAccessResponse {
             instance.get(|| {
                 CheckOriginAccessResponse {
                     has_access: ::std::option::Option::None,
+                    role: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -2378,6 +2380,25 @@ impl CheckOriginAccessResponse {
     pub fn get_has_access(&self) -> bool {
         self.has_access.unwrap_or(false)
     }
+
+    // optional .vault.OriginRole role = 2;
+
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
+    }
+
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: OriginRole) {
+        self.role = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_role(&self) -> OriginRole {
+        self.role.unwrap_or(OriginRole::MAINTAINER)
+    }
 }
 
 impl ::protobuf::Message for CheckOriginAccessResponse {
@@ -2399,6 +2420,13 @@ impl ::protobuf::Message for CheckOriginAccessResponse {
                     let tmp = try!(is.read_bool());
                     self.has_access = ::std::option::Option::Some(tmp);
                 },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -2414,6 +2442,9 @@ impl ::protobuf::Message for CheckOriginAccessResponse {
         if self.has_access.is_some() {
             my_size += 2;
         };
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(2, *value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2423,6 +2454,9 @@ impl ::protobuf::Message for CheckOriginAccessResponse {
         if let Some(v) = self.has_access {
             try!(os.write_bool(1, v));
         };
+        if let Some(v) = self.role {
+            try!(os.write_enum(2, v.value()));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -2470,6 +2504,11 @@ impl ::protobuf::MessageStatic for CheckOriginAccessResponse {
                     CheckOriginAccessResponse::has_has_access,
                     CheckOriginAccessResponse::get_has_access,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    CheckOriginAccessResponse::has_role,
+                    CheckOriginAccessResponse::get_role,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<CheckOriginAccessResponse>(
                     "CheckOriginAccessResponse",
                     fields,
@@ -2483,6 +2522,7 @@ impl ::protobuf::MessageStatic for CheckOriginAccessResponse {
 impl ::protobuf::Clear for CheckOriginAccessResponse {
     fn clear(&mut self) {
         self.clear_has_access();
+        self.clear_role();
         self.unknown_fields.clear();
     }
 }
@@ -2490,6 +2530,7 @@ impl ::protobuf::Clear for CheckOriginAccessResponse {
 impl ::std::cmp::PartialEq for CheckOriginAccessResponse {
     fn eq(&self, other: &CheckOriginAccessResponse) -> bool {
         self.has_access == other.has_access &&
+        self.role == other.role &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -3291,6 +3332,7 @@ pub struct OriginInvitation {
     origin_id: ::std::option::Option<u64>,
     origin_name: ::protobuf::SingularField<::std::string::String>,
     owner_id: ::std::option::Option<u64>,
+    role: ::std::option::Option<OriginRole>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -3318,6 +3360,7 @@ impl OriginInvitation {
                     origin_id: ::std::option::Option::None,
                     origin_name: ::protobuf::SingularField::none(),
                     owner_id: ::std::option::Option::None,
+                    role: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -3472,6 +3515,25 @@ impl OriginInvitation {
     pub fn get_owner_id(&self) -> u64 {
         self.owner_id.unwrap_or(0)
     }
+
+    // optional .vault.OriginRole role = 7;
+
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
+    }
+
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: OriginRole) {
+        self.role = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_role(&self) -> OriginRole {
+        self.role.unwrap_or(OriginRole::MAINTAINER)
+    }
 }
 
 impl ::protobuf::Message for OriginInvitation {
@@ -3535,6 +3597,13 @@ impl ::protobuf::Message for OriginInvitation {
                     let tmp = try!(is.read_uint64());
                     self.owner_id = ::std::option::Option::Some(tmp);
                 },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -3565,6 +3634,9 @@ impl ::protobuf::Message for OriginInvitation {
         for value in self.owner_id.iter() {
             my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
         };
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(7, *value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -3589,6 +3661,9 @@ impl ::protobuf::Message for OriginInvitation {
         if let Some(v) = self.owner_id {
             try!(os.write_uint64(6, v));
         };
+        if let Some(v) = self.role {
+            try!(os.write_enum(7, v.value()));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -3661,6 +3736,11 @@ impl ::protobuf::MessageStatic for OriginInvitation {
                     OriginInvitation::has_owner_id,
                     OriginInvitation::get_owner_id,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    OriginInvitation::has_role,
+                    OriginInvitation::get_role,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<OriginInvitation>(
                     "OriginInvitation",
                     fields,
@@ -3679,6 +3759,7 @@ impl ::protobuf::Clear for OriginInvitation {
         self.clear_origin_id();
         self.clear_origin_name();
         self.clear_owner_id();
+        self.clear_role();
         self.unknown_fields.clear();
     }
 }
@@ -3691,6 +3772,7 @@ impl ::std::cmp::PartialEq for OriginInvitation {
         self.origin_id == other.origin_id &&
         self.origin_name == other.origin_name &&
         self.owner_id == other.owner_id &&
+        self.role == other.role &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -3709,6 +3791,7 @@ pub struct OriginInvitationCreate {
     origin_id: ::std::option::Option<u64>,
     origin_name: ::protobuf::SingularField<::std::string::String>,
     owner_id: ::std::option::Option<u64>,
+    role: ::std::option::Option<OriginRole>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -3735,6 +3818,7 @@ impl OriginInvitationCreate {
                     origin_id: ::std::option::Option::None,
                     origin_name: ::protobuf::SingularField::none(),
                     owner_id: ::std::option::Option::None,
+                    role: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -3870,6 +3954,25 @@ impl OriginInvitationCreate {
     pub fn get_owner_id(&self) -> u64 {
         self.owner_id.unwrap_or(0)
     }
+
+    // optional .vault.OriginRole role = 6;
+
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
+    }
+
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: OriginRole) {
+        self.role = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_role(&self) -> OriginRole {
+        self.role.unwrap_or(OriginRole::MAINTAINER)
+    }
 }
 
 impl ::protobuf::Message for OriginInvitationCreate {
@@ -3923,6 +4026,13 @@ impl ::protobuf::Message for OriginInvitationCreate {
                     let tmp = try!(is.read_uint64());
                     self.owner_id = ::std::option::Option::Some(tmp);
                 },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -3950,6 +4060,9 @@ impl ::protobuf::Message for OriginInvitationCreate {
         for value in self.owner_id.iter() {
             my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
         };
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(6, *value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -3971,6 +4084,9 @@ impl ::protobuf::Message for OriginInvitationCreate {
         if let Some(v) = self.owner_id {
             try!(os.write_uint64(5, v));
         };
+        if let Some(v) = self.role {
+            try!(os.write_enum(6, v.value()));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -4038,6 +4154,11 @@ impl ::protobuf::MessageStatic for OriginInvitationCreate {
                     OriginInvitationCreate::has_owner_id,
                     OriginInvitationCreate::get_owner_id,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    OriginInvitationCreate::has_role,
+                    OriginInvitationCreate::get_role,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationCreate>(
                     "OriginInvitationCreate",
                     fields,
@@ -4055,6 +4176,7 @@ impl ::protobuf::Clear for OriginInvitationCreate {
         self.clear_origin_id();
         self.clear_origin_name();
         self.clear_owner_id();
+        self.clear_role();
         self.unknown_fields.clear();
     }
 }
@@ -4066,6 +4188,7 @@ impl ::std::cmp::PartialEq for OriginInvitationCreate {
         self.origin_id == other.origin_id &&
         self.origin_name == other.origin_name &&
         self.owner_id == other.owner_id &&
+        self.role == other.role &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -5285,6 +5408,3104 @@ impl ::std::fmt::Debug for OriginSecretKeyCreate {
     }
 }
 
+#[derive(Clone,Default)]
+pub struct OriginMemberRoleSet {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    origin_name: ::protobuf::SingularField<::std::string::String>,
+    account_id: ::std::option::Option<u64>,
+    account_name: ::protobuf::SingularField<::std::string::String>,
+    role: ::std::option::Option<OriginRole>,
+    requester_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginMemberRoleSet {}
+
+impl OriginMemberRoleSet {
+    pub fn new() -> OriginMemberRoleSet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginMemberRoleSet {
+        static mut instance: ::protobuf::lazy::Lazy<OriginMemberRoleSet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginMemberRoleSet,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginMemberRoleSet {
+                    origin_id: ::std::option::Option::None,
+                    origin_name: ::protobuf::SingularField::none(),
+                    account_id: ::std::option::Option::None,
+                    account_name: ::protobuf::SingularField::none(),
+                    role: ::std::option::Option::None,
+                    requester_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required string origin_name = 2;
+
+    pub fn clear_origin_name(&mut self) {
+        self.origin_name.clear();
+    }
+
+    pub fn has_origin_name(&self) -> bool {
+        self.origin_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if self.origin_name.is_none() {
+            self.origin_name.set_default();
+        };
+        self.origin_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 account_id = 3;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // required string account_name = 4;
+
+    pub fn clear_account_name(&mut self) {
+        self.account_name.clear();
+    }
+
+    pub fn has_account_name(&self) -> bool {
+        self.account_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_name(&mut self, v: ::std::string::String) {
+        self.account_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
+        if self.account_name.is_none() {
+            self.account_name.set_default();
+        };
+        self.account_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_account_name(&mut self) -> ::std::string::String {
+        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_account_name(&self) -> &str {
+        match self.account_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required .vault.OriginRole role = 5;
+
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
+    }
+
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: OriginRole) {
+        self.role = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_role(&self) -> OriginRole {
+        self.role.unwrap_or(OriginRole::READONLY)
+    }
+
+    // required uint64 requester_id = 6;
+
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
+    }
+
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for OriginMemberRoleSet {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.origin_name.is_none() {
+            return false;
+        };
+        if self.account_id.is_none() {
+            return false;
+        };
+        if self.account_name.is_none() {
+            return false;
+        };
+        if self.role.is_none() {
+            return false;
+        };
+        if self.requester_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.requester_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.origin_name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_name.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(5, *value);
+        };
+        for value in self.requester_id.iter() {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.origin_name.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(3, v));
+        };
+        if let Some(v) = self.account_name.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.role {
+            try!(os.write_enum(5, v.value()));
+        };
+        if let Some(v) = self.requester_id {
+            try!(os.write_uint64(6, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginMemberRoleSet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginMemberRoleSet {
+    fn new() -> OriginMemberRoleSet {
+        OriginMemberRoleSet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginMemberRoleSet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginMemberRoleSet::has_origin_id,
+                    OriginMemberRoleSet::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin_name",
+                    OriginMemberRoleSet::has_origin_name,
+                    OriginMemberRoleSet::get_origin_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    OriginMemberRoleSet::has_account_id,
+                    OriginMemberRoleSet::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "account_name",
+                    OriginMemberRoleSet::has_account_name,
+                    OriginMemberRoleSet::get_account_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    OriginMemberRoleSet::has_role,
+                    OriginMemberRoleSet::get_role,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "requester_id",
+                    OriginMemberRoleSet::has_requester_id,
+                    OriginMemberRoleSet::get_requester_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginMemberRoleSet>(
+                    "OriginMemberRoleSet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginMemberRoleSet {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_origin_name();
+        self.clear_account_id();
+        self.clear_account_name();
+        self.clear_role();
+        self.clear_requester_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginMemberRoleSet {
+    fn eq(&self, other: &OriginMemberRoleSet) -> bool {
+        self.origin_id == other.origin_id &&
+        self.origin_name == other.origin_name &&
+        self.account_id == other.account_id &&
+        self.account_name == other.account_name &&
+        self.role == other.role &&
+        self.requester_id == other.requester_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginMemberRoleSet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginMemberRole {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    account_id: ::std::option::Option<u64>,
+    account_name: ::protobuf::SingularField<::std::string::String>,
+    role: ::std::option::Option<OriginRole>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginMemberRole {}
+
+impl OriginMemberRole {
+    pub fn new() -> OriginMemberRole {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginMemberRole {
+        static mut instance: ::protobuf::lazy::Lazy<OriginMemberRole> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginMemberRole,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginMemberRole {
+                    origin_id: ::std::option::Option::None,
+                    account_id: ::std::option::Option::None,
+                    account_name: ::protobuf::SingularField::none(),
+                    role: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required uint64 account_id = 2;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // required string account_name = 3;
+
+    pub fn clear_account_name(&mut self) {
+        self.account_name.clear();
+    }
+
+    pub fn has_account_name(&self) -> bool {
+        self.account_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_name(&mut self, v: ::std::string::String) {
+        self.account_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
+        if self.account_name.is_none() {
+            self.account_name.set_default();
+        };
+        self.account_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_account_name(&mut self) -> ::std::string::String {
+        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_account_name(&self) -> &str {
+        match self.account_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required .vault.OriginRole role = 4;
+
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
+    }
+
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: OriginRole) {
+        self.role = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_role(&self) -> OriginRole {
+        self.role.unwrap_or(OriginRole::READONLY)
+    }
+}
+
+impl ::protobuf::Message for OriginMemberRole {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.account_id.is_none() {
+            return false;
+        };
+        if self.account_name.is_none() {
+            return false;
+        };
+        if self.role.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_name.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(4, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.account_name.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.role {
+            try!(os.write_enum(4, v.value()));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginMemberRole>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginMemberRole {
+    fn new() -> OriginMemberRole {
+        OriginMemberRole::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginMemberRole>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginMemberRole::has_origin_id,
+                    OriginMemberRole::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    OriginMemberRole::has_account_id,
+                    OriginMemberRole::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "account_name",
+                    OriginMemberRole::has_account_name,
+                    OriginMemberRole::get_account_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    OriginMemberRole::has_role,
+                    OriginMemberRole::get_role,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginMemberRole>(
+                    "OriginMemberRole",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginMemberRole {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_account_id();
+        self.clear_account_name();
+        self.clear_role();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginMemberRole {
+    fn eq(&self, other: &OriginMemberRole) -> bool {
+        self.origin_id == other.origin_id &&
+        self.account_id == other.account_id &&
+        self.account_name == other.account_name &&
+        self.role == other.role &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginMemberRole {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginSecret {
+    // message fields
+    id: ::std::option::Option<u64>,
+    origin_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    key_revision: ::protobuf::SingularField<::std::string::String>,
+    nonce: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    ciphertext: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    owner_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginSecret {}
+
+impl OriginSecret {
+    pub fn new() -> OriginSecret {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginSecret {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecret> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginSecret,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginSecret {
+                    id: ::std::option::Option::None,
+                    origin_id: ::std::option::Option::None,
+                    name: ::protobuf::SingularField::none(),
+                    key_revision: ::protobuf::SingularField::none(),
+                    nonce: ::protobuf::SingularField::none(),
+                    ciphertext: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    // required uint64 origin_id = 2;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required string name = 3;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string key_revision = 4;
+
+    pub fn clear_key_revision(&mut self) {
+        self.key_revision.clear();
+    }
+
+    pub fn has_key_revision(&self) -> bool {
+        self.key_revision.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_revision(&mut self, v: ::std::string::String) {
+        self.key_revision = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key_revision(&mut self) -> &mut ::std::string::String {
+        if self.key_revision.is_none() {
+            self.key_revision.set_default();
+        };
+        self.key_revision.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key_revision(&mut self) -> ::std::string::String {
+        self.key_revision.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_key_revision(&self) -> &str {
+        match self.key_revision.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required bytes nonce = 5;
+
+    pub fn clear_nonce(&mut self) {
+        self.nonce.clear();
+    }
+
+    pub fn has_nonce(&self) -> bool {
+        self.nonce.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonce(&mut self, v: ::std::vec::Vec<u8>) {
+        self.nonce = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nonce(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.nonce.is_none() {
+            self.nonce.set_default();
+        };
+        self.nonce.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_nonce(&mut self) -> ::std::vec::Vec<u8> {
+        self.nonce.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_nonce(&self) -> &[u8] {
+        match self.nonce.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    // required bytes ciphertext = 6;
+
+    pub fn clear_ciphertext(&mut self) {
+        self.ciphertext.clear();
+    }
+
+    pub fn has_ciphertext(&self) -> bool {
+        self.ciphertext.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ciphertext(&mut self, v: ::std::vec::Vec<u8>) {
+        self.ciphertext = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ciphertext(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.ciphertext.is_none() {
+            self.ciphertext.set_default();
+        };
+        self.ciphertext.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ciphertext(&mut self) -> ::std::vec::Vec<u8> {
+        self.ciphertext.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_ciphertext(&self) -> &[u8] {
+        match self.ciphertext.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    // required uint64 owner_id = 7;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for OriginSecret {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.name.is_none() {
+            return false;
+        };
+        if self.key_revision.is_none() {
+            return false;
+        };
+        if self.nonce.is_none() {
+            return false;
+        };
+        if self.ciphertext.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.key_revision));
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.nonce));
+                },
+                6 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.ciphertext));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.key_revision.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.nonce.iter() {
+            my_size += ::protobuf::rt::bytes_size(5, &value);
+        };
+        for value in self.ciphertext.iter() {
+            my_size += ::protobuf::rt::bytes_size(6, &value);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(7, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.key_revision.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.nonce.as_ref() {
+            try!(os.write_bytes(5, &v));
+        };
+        if let Some(v) = self.ciphertext.as_ref() {
+            try!(os.write_bytes(6, &v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(7, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginSecret>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginSecret {
+    fn new() -> OriginSecret {
+        OriginSecret::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginSecret>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    OriginSecret::has_id,
+                    OriginSecret::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginSecret::has_origin_id,
+                    OriginSecret::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginSecret::has_name,
+                    OriginSecret::get_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "key_revision",
+                    OriginSecret::has_key_revision,
+                    OriginSecret::get_key_revision,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "nonce",
+                    OriginSecret::has_nonce,
+                    OriginSecret::get_nonce,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "ciphertext",
+                    OriginSecret::has_ciphertext,
+                    OriginSecret::get_ciphertext,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    OriginSecret::has_owner_id,
+                    OriginSecret::get_owner_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecret>(
+                    "OriginSecret",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginSecret {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_origin_id();
+        self.clear_name();
+        self.clear_key_revision();
+        self.clear_nonce();
+        self.clear_ciphertext();
+        self.clear_owner_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginSecret {
+    fn eq(&self, other: &OriginSecret) -> bool {
+        self.id == other.id &&
+        self.origin_id == other.origin_id &&
+        self.name == other.name &&
+        self.key_revision == other.key_revision &&
+        self.nonce == other.nonce &&
+        self.ciphertext == other.ciphertext &&
+        self.owner_id == other.owner_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginSecret {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginSecretCreate {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    origin_name: ::protobuf::SingularField<::std::string::String>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    value: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    requester_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginSecretCreate {}
+
+impl OriginSecretCreate {
+    pub fn new() -> OriginSecretCreate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginSecretCreate {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginSecretCreate,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginSecretCreate {
+                    origin_id: ::std::option::Option::None,
+                    origin_name: ::protobuf::SingularField::none(),
+                    name: ::protobuf::SingularField::none(),
+                    value: ::protobuf::SingularField::none(),
+                    requester_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required string origin_name = 2;
+
+    pub fn clear_origin_name(&mut self) {
+        self.origin_name.clear();
+    }
+
+    pub fn has_origin_name(&self) -> bool {
+        self.origin_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if self.origin_name.is_none() {
+            self.origin_name.set_default();
+        };
+        self.origin_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string name = 3;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required bytes value = 4;
+
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.value.is_none() {
+            self.value.set_default();
+        };
+        self.value.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        self.value.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_value(&self) -> &[u8] {
+        match self.value.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    // required uint64 requester_id = 5;
+
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
+    }
+
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for OriginSecretCreate {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.origin_name.is_none() {
+            return false;
+        };
+        if self.name.is_none() {
+            return false;
+        };
+        if self.value.is_none() {
+            return false;
+        };
+        if self.requester_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.value));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.requester_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.origin_name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.value.iter() {
+            my_size += ::protobuf::rt::bytes_size(4, &value);
+        };
+        for value in self.requester_id.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.origin_name.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.value.as_ref() {
+            try!(os.write_bytes(4, &v));
+        };
+        if let Some(v) = self.requester_id {
+            try!(os.write_uint64(5, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginSecretCreate>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginSecretCreate {
+    fn new() -> OriginSecretCreate {
+        OriginSecretCreate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginSecretCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginSecretCreate::has_origin_id,
+                    OriginSecretCreate::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin_name",
+                    OriginSecretCreate::has_origin_name,
+                    OriginSecretCreate::get_origin_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginSecretCreate::has_name,
+                    OriginSecretCreate::get_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "value",
+                    OriginSecretCreate::has_value,
+                    OriginSecretCreate::get_value,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "requester_id",
+                    OriginSecretCreate::has_requester_id,
+                    OriginSecretCreate::get_requester_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretCreate>(
+                    "OriginSecretCreate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginSecretCreate {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_origin_name();
+        self.clear_name();
+        self.clear_value();
+        self.clear_requester_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginSecretCreate {
+    fn eq(&self, other: &OriginSecretCreate) -> bool {
+        self.origin_id == other.origin_id &&
+        self.origin_name == other.origin_name &&
+        self.name == other.name &&
+        self.value == other.value &&
+        self.requester_id == other.requester_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginSecretCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginSecretGet {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    origin_name: ::protobuf::SingularField<::std::string::String>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    requester_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginSecretGet {}
+
+impl OriginSecretGet {
+    pub fn new() -> OriginSecretGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginSecretGet {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginSecretGet,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginSecretGet {
+                    origin_id: ::std::option::Option::None,
+                    origin_name: ::protobuf::SingularField::none(),
+                    name: ::protobuf::SingularField::none(),
+                    requester_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required string origin_name = 2;
+
+    pub fn clear_origin_name(&mut self) {
+        self.origin_name.clear();
+    }
+
+    pub fn has_origin_name(&self) -> bool {
+        self.origin_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if self.origin_name.is_none() {
+            self.origin_name.set_default();
+        };
+        self.origin_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string name = 3;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 requester_id = 4;
+
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
+    }
+
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for OriginSecretGet {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.origin_name.is_none() {
+            return false;
+        };
+        if self.name.is_none() {
+            return false;
+        };
+        if self.requester_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.requester_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.origin_name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.requester_id.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.origin_name.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.requester_id {
+            try!(os.write_uint64(4, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginSecretGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginSecretGet {
+    fn new() -> OriginSecretGet {
+        OriginSecretGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginSecretGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginSecretGet::has_origin_id,
+                    OriginSecretGet::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin_name",
+                    OriginSecretGet::has_origin_name,
+                    OriginSecretGet::get_origin_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginSecretGet::has_name,
+                    OriginSecretGet::get_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "requester_id",
+                    OriginSecretGet::has_requester_id,
+                    OriginSecretGet::get_requester_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretGet>(
+                    "OriginSecretGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginSecretGet {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_origin_name();
+        self.clear_name();
+        self.clear_requester_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginSecretGet {
+    fn eq(&self, other: &OriginSecretGet) -> bool {
+        self.origin_id == other.origin_id &&
+        self.origin_name == other.origin_name &&
+        self.name == other.name &&
+        self.requester_id == other.requester_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginSecretGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginSecretValue {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    value: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginSecretValue {}
+
+impl OriginSecretValue {
+    pub fn new() -> OriginSecretValue {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginSecretValue {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretValue> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginSecretValue,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginSecretValue {
+                    origin_id: ::std::option::Option::None,
+                    name: ::protobuf::SingularField::none(),
+                    value: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required string name = 2;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required bytes value = 3;
+
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.value.is_none() {
+            self.value.set_default();
+        };
+        self.value.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        self.value.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_value(&self) -> &[u8] {
+        match self.value.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+}
+
+impl ::protobuf::Message for OriginSecretValue {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.name.is_none() {
+            return false;
+        };
+        if self.value.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.value));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.value.iter() {
+            my_size += ::protobuf::rt::bytes_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.value.as_ref() {
+            try!(os.write_bytes(3, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginSecretValue>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginSecretValue {
+    fn new() -> OriginSecretValue {
+        OriginSecretValue::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginSecretValue>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginSecretValue::has_origin_id,
+                    OriginSecretValue::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginSecretValue::has_name,
+                    OriginSecretValue::get_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "value",
+                    OriginSecretValue::has_value,
+                    OriginSecretValue::get_value,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretValue>(
+                    "OriginSecretValue",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginSecretValue {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_name();
+        self.clear_value();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginSecretValue {
+    fn eq(&self, other: &OriginSecretValue) -> bool {
+        self.origin_id == other.origin_id &&
+        self.name == other.name &&
+        self.value == other.value &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginSecretValue {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginSecretDelete {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    origin_name: ::protobuf::SingularField<::std::string::String>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    requester_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginSecretDelete {}
+
+impl OriginSecretDelete {
+    pub fn new() -> OriginSecretDelete {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginSecretDelete {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretDelete> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginSecretDelete,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginSecretDelete {
+                    origin_id: ::std::option::Option::None,
+                    origin_name: ::protobuf::SingularField::none(),
+                    name: ::protobuf::SingularField::none(),
+                    requester_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required string origin_name = 2;
+
+    pub fn clear_origin_name(&mut self) {
+        self.origin_name.clear();
+    }
+
+    pub fn has_origin_name(&self) -> bool {
+        self.origin_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if self.origin_name.is_none() {
+            self.origin_name.set_default();
+        };
+        self.origin_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string name = 3;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 requester_id = 4;
+
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
+    }
+
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for OriginSecretDelete {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.origin_name.is_none() {
+            return false;
+        };
+        if self.name.is_none() {
+            return false;
+        };
+        if self.requester_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.requester_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.origin_name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.requester_id.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.origin_name.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.requester_id {
+            try!(os.write_uint64(4, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginSecretDelete>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginSecretDelete {
+    fn new() -> OriginSecretDelete {
+        OriginSecretDelete::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginSecretDelete>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginSecretDelete::has_origin_id,
+                    OriginSecretDelete::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin_name",
+                    OriginSecretDelete::has_origin_name,
+                    OriginSecretDelete::get_origin_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginSecretDelete::has_name,
+                    OriginSecretDelete::get_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "requester_id",
+                    OriginSecretDelete::has_requester_id,
+                    OriginSecretDelete::get_requester_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretDelete>(
+                    "OriginSecretDelete",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginSecretDelete {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_origin_name();
+        self.clear_name();
+        self.clear_requester_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginSecretDelete {
+    fn eq(&self, other: &OriginSecretDelete) -> bool {
+        self.origin_id == other.origin_id &&
+        self.origin_name == other.origin_name &&
+        self.name == other.name &&
+        self.requester_id == other.requester_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginSecretDelete {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginSecretListRequest {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    origin_name: ::protobuf::SingularField<::std::string::String>,
+    requester_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginSecretListRequest {}
+
+impl OriginSecretListRequest {
+    pub fn new() -> OriginSecretListRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginSecretListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretListRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginSecretListRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginSecretListRequest {
+                    origin_id: ::std::option::Option::None,
+                    origin_name: ::protobuf::SingularField::none(),
+                    requester_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required string origin_name = 2;
+
+    pub fn clear_origin_name(&mut self) {
+        self.origin_name.clear();
+    }
+
+    pub fn has_origin_name(&self) -> bool {
+        self.origin_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if self.origin_name.is_none() {
+            self.origin_name.set_default();
+        };
+        self.origin_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 requester_id = 3;
+
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
+    }
+
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for OriginSecretListRequest {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.origin_name.is_none() {
+            return false;
+        };
+        if self.requester_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.requester_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.origin_name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.requester_id.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.origin_name.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.requester_id {
+            try!(os.write_uint64(3, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginSecretListRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginSecretListRequest {
+    fn new() -> OriginSecretListRequest {
+        OriginSecretListRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginSecretListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginSecretListRequest::has_origin_id,
+                    OriginSecretListRequest::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin_name",
+                    OriginSecretListRequest::has_origin_name,
+                    OriginSecretListRequest::get_origin_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "requester_id",
+                    OriginSecretListRequest::has_requester_id,
+                    OriginSecretListRequest::get_requester_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretListRequest>(
+                    "OriginSecretListRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginSecretListRequest {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_origin_name();
+        self.clear_requester_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginSecretListRequest {
+    fn eq(&self, other: &OriginSecretListRequest) -> bool {
+        self.origin_id == other.origin_id &&
+        self.origin_name == other.origin_name &&
+        self.requester_id == other.requester_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginSecretListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginSecretListResponse {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    names: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginSecretListResponse {}
+
+impl OriginSecretListResponse {
+    pub fn new() -> OriginSecretListResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginSecretListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginSecretListResponse,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginSecretListResponse {
+                    origin_id: ::std::option::Option::None,
+                    names: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // repeated string names = 2;
+
+    pub fn clear_names(&mut self) {
+        self.names.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_names(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.names = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_names(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.names
+    }
+
+    // Take field
+    pub fn take_names(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.names, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_names(&self) -> &[::std::string::String] {
+        &self.names
+    }
+}
+
+impl ::protobuf::Message for OriginSecretListResponse {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.names));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.names.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        for v in self.names.iter() {
+            try!(os.write_string(2, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginSecretListResponse>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginSecretListResponse {
+    fn new() -> OriginSecretListResponse {
+        OriginSecretListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginSecretListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginSecretListResponse::has_origin_id,
+                    OriginSecretListResponse::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "names",
+                    OriginSecretListResponse::get_names,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretListResponse>(
+                    "OriginSecretListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginSecretListResponse {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_names();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginSecretListResponse {
+    fn eq(&self, other: &OriginSecretListResponse) -> bool {
+        self.origin_id == other.origin_id &&
+        self.names == other.names &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginSecretListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum OriginRole {
+    READONLY = 0,
+    MAINTAINER = 1,
+    OWNER = 2,
+}
+
+impl ::protobuf::ProtobufEnum for OriginRole {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<OriginRole> {
+        match value {
+            0 => ::std::option::Option::Some(OriginRole::READONLY),
+            1 => ::std::option::Option::Some(OriginRole::MAINTAINER),
+            2 => ::std::option::Option::Some(OriginRole::OWNER),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [OriginRole] = &[
+            OriginRole::READONLY,
+            OriginRole::MAINTAINER,
+            OriginRole::OWNER,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<OriginRole>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("OriginRole", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for OriginRole {
+}
+
 static file_descriptor_proto_data: &'static [u8] = &[
     0x0a, 0x15, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x73, 0x2f, 0x76, 0x61, 0x75, 0x6c,
     0x74, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x05, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x22, 0x34,
@@ -5326,370 +8547,441 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09,
     0x48, 0x01, 0x42, 0x0e, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x6e,
     0x66, 0x6f, 0x42, 0x0d, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x6e, 0x66,
-    0x6f, 0x22, 0x2f, 0x0a, 0x19, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x6f, 0x22, 0x5c, 0x0a, 0x19, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e,
     0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12,
     0x0a, 0x0a, 0x68, 0x61, 0x73, 0x5f, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x02,
-    0x28, 0x08, 0x22, 0x32, 0x0a, 0x1c, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x49, 0x6e, 0x76,
-    0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65,
-    0x73, 0x74, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64,
-    0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x61, 0x0a, 0x1d, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e,
-    0x74, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52,
-    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75,
-    0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2c, 0x0a, 0x0b, 0x69,
+    0x28, 0x08, 0x12, 0x2b, 0x0a, 0x04, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e,
+    0x32, 0x11, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x52,
+    0x6f, 0x6c, 0x65, 0x3a, 0x0a, 0x4d, 0x41, 0x49, 0x4e, 0x54, 0x41, 0x49, 0x4e, 0x45, 0x52, 0x22,
+    0x32, 0x0a, 0x1c, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+    0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x04, 0x22, 0x61, 0x0a, 0x1d, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x49, 0x6e,
+    0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2c, 0x0a, 0x0b, 0x69, 0x6e, 0x76, 0x69,
+    0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e,
+    0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69,
+    0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x30, 0x0a, 0x1b, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x5f, 0x0a, 0x1c, 0x4f, 0x72, 0x69, 0x67,
+    0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73, 0x74,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67,
+    0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2c, 0x0a, 0x0b, 0x69,
     0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b,
     0x32, 0x17, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49,
-    0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x30, 0x0a, 0x1b, 0x4f, 0x72, 0x69,
-    0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73,
-    0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67,
-    0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x5f, 0x0a, 0x1c, 0x4f,
-    0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c,
-    0x69, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f,
-    0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2c,
-    0x0a, 0x0b, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x02, 0x20,
-    0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67,
-    0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x82, 0x01, 0x0a,
-    0x10, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f,
-    0x6e, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x12, 0x0a,
-    0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28,
-    0x04, 0x12, 0x14, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d,
-    0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69,
-    0x6e, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x6f, 0x72,
-    0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x02, 0x28, 0x09, 0x12,
-    0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x02, 0x28,
-    0x04, 0x22, 0x7c, 0x0a, 0x16, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74,
-    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x61,
-    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12,
-    0x14, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18,
-    0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f,
-    0x69, 0x64, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67,
-    0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a,
-    0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x22,
-    0x65, 0x0a, 0x1d, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74,
-    0x69, 0x6f, 0x6e, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-    0x12, 0x21, 0x0a, 0x19, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x61, 0x63, 0x63, 0x65,
-    0x70, 0x74, 0x69, 0x6e, 0x67, 0x5f, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x01, 0x20,
-    0x02, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x5f, 0x69, 0x64,
-    0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0e, 0x0a, 0x06, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65,
-    0x18, 0x03, 0x20, 0x02, 0x28, 0x08, 0x22, 0x20, 0x0a, 0x1e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e,
-    0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74,
-    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x70, 0x0a, 0x0f, 0x4f, 0x72, 0x69, 0x67,
-    0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x4b, 0x65, 0x79, 0x12, 0x0a, 0x0a, 0x02, 0x69,
-    0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69,
-    0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61,
-    0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x72, 0x65, 0x76, 0x69,
-    0x73, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x62, 0x6f,
-    0x64, 0x79, 0x18, 0x05, 0x20, 0x02, 0x28, 0x0c, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65,
-    0x72, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x02, 0x28, 0x04, 0x22, 0x6a, 0x0a, 0x15, 0x4f, 0x72,
-    0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x4b, 0x65, 0x79, 0x43, 0x72, 0x65,
-    0x61, 0x74, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64,
-    0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02,
-    0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x72, 0x65, 0x76, 0x69, 0x73, 0x69, 0x6f, 0x6e,
-    0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x62, 0x6f, 0x64, 0x79, 0x18, 0x04,
-    0x20, 0x02, 0x28, 0x0c, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64,
-    0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x4a, 0xf6, 0x25, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0x93,
-    0x01, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x0d, 0x0a, 0x1b, 0x0a, 0x02,
-    0x04, 0x00, 0x12, 0x04, 0x03, 0x00, 0x07, 0x01, 0x1a, 0x0f, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65,
-    0x64, 0x20, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01,
-    0x12, 0x03, 0x03, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x04,
-    0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x04, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x04, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x04, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x04, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00,
-    0x02, 0x01, 0x12, 0x03, 0x05, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04,
-    0x12, 0x03, 0x05, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03,
-    0x05, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x05, 0x12,
-    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x05, 0x19, 0x1a, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x06, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x06, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x02, 0x05, 0x12, 0x03, 0x06, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02,
-    0x01, 0x12, 0x03, 0x06, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12,
-    0x03, 0x06, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x09, 0x00, 0x0d, 0x01,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x09, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0a, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x00, 0x04, 0x12, 0x03, 0x0a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05,
-    0x12, 0x03, 0x0a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x0a, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0a, 0x19,
-    0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0b, 0x02, 0x1f, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x0b, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x0b, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03,
-    0x0c, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x04, 0x12, 0x03, 0x0c, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x0c, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0c, 0x12, 0x1c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x0c, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
-    0x02, 0x12, 0x04, 0x0f, 0x00, 0x11, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03,
-    0x0f, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x10, 0x02, 0x1b,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x10, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x10, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x10, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x10, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04,
-    0x13, 0x00, 0x15, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x13, 0x08, 0x11,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x14, 0x02, 0x1b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x14, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x14, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x14, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x14, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x17, 0x00, 0x1a,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x17, 0x08, 0x1a, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x18, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x18, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x18, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x18, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x18,
-    0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x19, 0x04, 0x20, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03, 0x19, 0x04, 0x0c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x03, 0x19, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x19, 0x14, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x19, 0x1e, 0x1f, 0x0a, 0x2b, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x1d,
-    0x00, 0x1f, 0x01, 0x1a, 0x1f, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x6d,
-    0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69,
-    0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x1d, 0x08, 0x1f,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x1e, 0x04, 0x22, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x1e, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x1e, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x1e, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x1e, 0x20, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x21, 0x00, 0x24,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x21, 0x08, 0x20, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x22, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x22, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x22, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x22, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x22,
-    0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x23, 0x04, 0x20, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x04, 0x12, 0x03, 0x23, 0x04, 0x0c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03, 0x23, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x23, 0x14, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x23, 0x1e, 0x1f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x27,
-    0x00, 0x29, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x27, 0x08, 0x20, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x28, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03, 0x28, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x28, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x28, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x28, 0x21, 0x22, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x2b, 0x00, 0x2e, 0x01,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x2b, 0x08, 0x21, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x2c, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
-    0x00, 0x04, 0x12, 0x03, 0x2c, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05,
-    0x12, 0x03, 0x2c, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x2c, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2c, 0x21,
-    0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x03, 0x2d, 0x04, 0x20, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x04, 0x12, 0x03, 0x2d, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03, 0x2d, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x2d, 0x14, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x2d, 0x1e, 0x1f, 0x0a, 0x6d, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x35, 0x00,
-    0x3e, 0x01, 0x1a, 0x61, 0x20, 0x21, 0x21, 0x21, 0x4e, 0x4f, 0x54, 0x45, 0x21, 0x21, 0x21, 0x0a,
-    0x20, 0x21, 0x21, 0x21, 0x4e, 0x4f, 0x54, 0x45, 0x21, 0x21, 0x21, 0x0a, 0x20, 0x6f, 0x6e, 0x6c,
-    0x79, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x20, 0x61, 0x6e, 0x64,
-    0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x61, 0x72, 0x65,
-    0x20, 0x69, 0x6d, 0x70, 0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x65, 0x64, 0x0a, 0x20, 0x21, 0x21,
+    0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0xaf, 0x01, 0x0a, 0x10, 0x4f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x0a,
+    0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x14,
+    0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03,
+    0x20, 0x02, 0x28, 0x09, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69,
+    0x64, 0x18, 0x04, 0x20, 0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08,
+    0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2b,
+    0x0a, 0x04, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x11, 0x2e, 0x76,
+    0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x52, 0x6f, 0x6c, 0x65, 0x3a,
+    0x0a, 0x4d, 0x41, 0x49, 0x4e, 0x54, 0x41, 0x49, 0x4e, 0x45, 0x52, 0x22, 0xa9, 0x01, 0x0a, 0x16,
+    0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+    0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x14, 0x0a, 0x0c, 0x61, 0x63,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09,
+    0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20,
+    0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61,
+    0x6d, 0x65, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65,
+    0x72, 0x5f, 0x69, 0x64, 0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2b, 0x0a, 0x04, 0x72, 0x6f,
+    0x6c, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x11, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74,
+    0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x52, 0x6f, 0x6c, 0x65, 0x3a, 0x0a, 0x4d, 0x41, 0x49,
+    0x4e, 0x54, 0x41, 0x49, 0x4e, 0x45, 0x52, 0x22, 0x65, 0x0a, 0x1d, 0x4f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x63, 0x63, 0x65, 0x70,
+    0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x19, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x5f, 0x61, 0x63, 0x63, 0x65, 0x70, 0x74, 0x69, 0x6e, 0x67, 0x5f, 0x72, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x69,
+    0x6e, 0x76, 0x69, 0x74, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0e,
+    0x0a, 0x06, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x08, 0x22, 0x20,
+    0x0a, 0x1e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x22, 0x70, 0x0a, 0x0f, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74,
+    0x4b, 0x65, 0x79, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12,
+    0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02,
+    0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09,
+    0x12, 0x10, 0x0a, 0x08, 0x72, 0x65, 0x76, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x02,
+    0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x62, 0x6f, 0x64, 0x79, 0x18, 0x05, 0x20, 0x02, 0x28, 0x0c,
+    0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x02,
+    0x28, 0x04, 0x22, 0x6a, 0x0a, 0x15, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72,
+    0x65, 0x74, 0x4b, 0x65, 0x79, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0c,
+    0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08,
+    0x72, 0x65, 0x76, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c,
+    0x0a, 0x04, 0x62, 0x6f, 0x64, 0x79, 0x18, 0x04, 0x20, 0x02, 0x28, 0x0c, 0x12, 0x10, 0x0a, 0x08,
+    0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x22, 0x9e,
+    0x01, 0x0a, 0x13, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52,
+    0x6f, 0x6c, 0x65, 0x53, 0x65, 0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x6f, 0x72, 0x69,
+    0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x12,
+    0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x02,
+    0x28, 0x04, 0x12, 0x14, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61,
+    0x6d, 0x65, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x1f, 0x0a, 0x04, 0x72, 0x6f, 0x6c, 0x65,
+    0x18, 0x05, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x11, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x52, 0x6f, 0x6c, 0x65, 0x12, 0x14, 0x0a, 0x0c, 0x72, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x02, 0x28, 0x04, 0x22,
+    0x70, 0x0a, 0x10, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52,
+    0x6f, 0x6c, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64,
+    0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+    0x74, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x14, 0x0a, 0x0c, 0x61, 0x63,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09,
+    0x12, 0x1f, 0x0a, 0x04, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x04, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x11,
+    0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x52, 0x6f, 0x6c,
+    0x65, 0x22, 0x86, 0x01, 0x0a, 0x0c, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72,
+    0x65, 0x74, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x11,
+    0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28,
+    0x04, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12,
+    0x14, 0x0a, 0x0c, 0x6b, 0x65, 0x79, 0x5f, 0x72, 0x65, 0x76, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x18,
+    0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0d, 0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x18, 0x05,
+    0x20, 0x02, 0x28, 0x0c, 0x12, 0x12, 0x0a, 0x0a, 0x63, 0x69, 0x70, 0x68, 0x65, 0x72, 0x74, 0x65,
+    0x78, 0x74, 0x18, 0x06, 0x20, 0x02, 0x28, 0x0c, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65,
+    0x72, 0x5f, 0x69, 0x64, 0x18, 0x07, 0x20, 0x02, 0x28, 0x04, 0x22, 0x6f, 0x0a, 0x12, 0x4f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65,
+    0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61,
+    0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65,
+    0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0d, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18,
+    0x04, 0x20, 0x02, 0x28, 0x0c, 0x12, 0x14, 0x0a, 0x0c, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x22, 0x5d, 0x0a, 0x0f, 0x4f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x47, 0x65, 0x74, 0x12, 0x11,
+    0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28,
+    0x04, 0x12, 0x13, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65,
+    0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03,
+    0x20, 0x02, 0x28, 0x09, 0x12, 0x14, 0x0a, 0x0c, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65,
+    0x72, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x02, 0x28, 0x04, 0x22, 0x43, 0x0a, 0x11, 0x4f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x12,
+    0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02,
+    0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09,
+    0x12, 0x0d, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x0c, 0x22,
+    0x60, 0x0a, 0x12, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x44,
+    0x65, 0x6c, 0x65, 0x74, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67,
+    0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a,
+    0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x14, 0x0a, 0x0c, 0x72,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x02, 0x28,
+    0x04, 0x22, 0x57, 0x0a, 0x17, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65,
+    0x74, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x11, 0x0a, 0x09,
+    0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12,
+    0x13, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02,
+    0x20, 0x02, 0x28, 0x09, 0x12, 0x14, 0x0a, 0x0c, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65,
+    0x72, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x22, 0x3c, 0x0a, 0x18, 0x4f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x6e, 0x61, 0x6d,
+    0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x2a, 0x35, 0x0a, 0x0a, 0x4f, 0x72, 0x69, 0x67,
+    0x69, 0x6e, 0x52, 0x6f, 0x6c, 0x65, 0x12, 0x0c, 0x0a, 0x08, 0x52, 0x45, 0x41, 0x44, 0x4f, 0x4e,
+    0x4c, 0x59, 0x10, 0x00, 0x12, 0x0e, 0x0a, 0x0a, 0x4d, 0x41, 0x49, 0x4e, 0x54, 0x41, 0x49, 0x4e,
+    0x45, 0x52, 0x10, 0x01, 0x12, 0x09, 0x0a, 0x05, 0x4f, 0x57, 0x4e, 0x45, 0x52, 0x10, 0x02, 0x4a,
+    0xf6, 0x25, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0x93, 0x01, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x02,
+    0x12, 0x03, 0x00, 0x08, 0x0d, 0x0a, 0x1b, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x03, 0x00, 0x07,
+    0x01, 0x1a, 0x0f, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x65, 0x6e, 0x74, 0x69, 0x74,
+    0x79, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x03, 0x08, 0x0e, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x04, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x04, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x00, 0x05, 0x12, 0x03, 0x04, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x04, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x04, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x05, 0x02, 0x1b,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x05, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x05, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x05, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x01, 0x03, 0x12, 0x03, 0x05, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02,
+    0x12, 0x03, 0x06, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03,
+    0x06, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x06, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x06, 0x12, 0x1a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x06, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a,
+    0x02, 0x04, 0x01, 0x12, 0x04, 0x09, 0x00, 0x0d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01,
+    0x12, 0x03, 0x09, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0a,
+    0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0a, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x0a, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0a, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0a, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x01, 0x12, 0x03, 0x0b, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04,
+    0x12, 0x03, 0x0b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x0b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0b, 0x12,
+    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0b, 0x1d, 0x1e, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x0c, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x02, 0x04, 0x12, 0x03, 0x0c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x02, 0x05, 0x12, 0x03, 0x0c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x0c, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x0c, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x0f, 0x00, 0x11, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x0f, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x10, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x00, 0x04, 0x12, 0x03, 0x10, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x10, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x10, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x10, 0x19,
+    0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x13, 0x00, 0x15, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x13, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02,
+    0x00, 0x12, 0x03, 0x14, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12,
+    0x03, 0x14, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x14,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x14, 0x12, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x14, 0x19, 0x1a, 0x0a, 0x0a,
+    0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x17, 0x00, 0x1a, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04,
+    0x01, 0x12, 0x03, 0x17, 0x08, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03,
+    0x18, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x18, 0x04,
+    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x18, 0x0d, 0x13, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x18, 0x14, 0x1d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x18, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x04, 0x02, 0x01, 0x12, 0x03, 0x19, 0x04, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01,
+    0x04, 0x12, 0x03, 0x19, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12,
+    0x03, 0x19, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x19,
+    0x14, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x19, 0x1e, 0x1f,
+    0x0a, 0x2b, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x1d, 0x00, 0x1f, 0x01, 0x1a, 0x1f, 0x20, 0x6c,
+    0x69, 0x73, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x20,
+    0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x1d, 0x08, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02,
+    0x00, 0x12, 0x03, 0x1e, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12,
+    0x03, 0x1e, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x1e,
+    0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1e, 0x14, 0x1d,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1e, 0x20, 0x21, 0x0a, 0x0a,
+    0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x21, 0x00, 0x24, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06,
+    0x01, 0x12, 0x03, 0x21, 0x08, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03,
+    0x22, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x04, 0x12, 0x03, 0x22, 0x04,
+    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x22, 0x0d, 0x13, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x22, 0x14, 0x1d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x22, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x06, 0x02, 0x01, 0x12, 0x03, 0x23, 0x04, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01,
+    0x04, 0x12, 0x03, 0x23, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12,
+    0x03, 0x23, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x23,
+    0x14, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x23, 0x1e, 0x1f,
+    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x27, 0x00, 0x29, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x07, 0x01, 0x12, 0x03, 0x27, 0x08, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00,
+    0x12, 0x03, 0x28, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03,
+    0x28, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x28, 0x0d,
+    0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x28, 0x14, 0x1e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x28, 0x21, 0x22, 0x0a, 0x0a, 0x0a,
+    0x02, 0x04, 0x08, 0x12, 0x04, 0x2b, 0x00, 0x2e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01,
+    0x12, 0x03, 0x2b, 0x08, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x2c,
+    0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x2c, 0x04, 0x0c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2c, 0x0d, 0x13, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2c, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2c, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08,
+    0x02, 0x01, 0x12, 0x03, 0x2d, 0x04, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x04,
+    0x12, 0x03, 0x2d, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x2d, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2d, 0x14,
+    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x2d, 0x1e, 0x1f, 0x0a,
+    0x6d, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x35, 0x00, 0x3e, 0x01, 0x1a, 0x61, 0x20, 0x21, 0x21,
     0x21, 0x4e, 0x4f, 0x54, 0x45, 0x21, 0x21, 0x21, 0x0a, 0x20, 0x21, 0x21, 0x21, 0x4e, 0x4f, 0x54,
-    0x45, 0x21, 0x21, 0x21, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x35, 0x08,
-    0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x09, 0x08, 0x00, 0x12, 0x04, 0x36, 0x04, 0x39, 0x05, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x08, 0x00, 0x01, 0x12, 0x03, 0x36, 0x0a, 0x16, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x37, 0x08, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x37, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x37, 0x0f, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x37, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x38, 0x08,
-    0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x05, 0x12, 0x03, 0x38, 0x08, 0x0e, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x38, 0x0f, 0x1b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x38, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x09, 0x08, 0x01, 0x12, 0x04, 0x3a, 0x04, 0x3d, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x08,
-    0x01, 0x01, 0x12, 0x03, 0x3a, 0x0a, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x02, 0x12,
-    0x03, 0x3b, 0x08, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x05, 0x12, 0x03, 0x3b,
-    0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3b, 0x0f, 0x18,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x03, 0x3b, 0x1b, 0x1c, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12, 0x03, 0x3c, 0x08, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x09, 0x02, 0x03, 0x05, 0x12, 0x03, 0x3c, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
-    0x03, 0x01, 0x12, 0x03, 0x3c, 0x0f, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x03,
-    0x12, 0x03, 0x3c, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x40, 0x00, 0x42,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x40, 0x08, 0x21, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x41, 0x04, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x41, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x41, 0x0d, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x41, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x41,
-    0x1f, 0x20, 0x0a, 0x3e, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x04, 0x45, 0x00, 0x47, 0x01, 0x1a, 0x32,
-    0x20, 0x6c, 0x69, 0x73, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e,
-    0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x66, 0x6f,
-    0x72, 0x20, 0x61, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e,
-    0x74, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x45, 0x08, 0x24, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x46, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0b, 0x02, 0x00, 0x04, 0x12, 0x03, 0x46, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
-    0x00, 0x05, 0x12, 0x03, 0x46, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x46, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x46, 0x21, 0x22, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x04, 0x49, 0x00, 0x4c, 0x01, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x0c, 0x01, 0x12, 0x03, 0x49, 0x08, 0x25, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x0c, 0x02, 0x00, 0x12, 0x03, 0x4a, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00,
-    0x04, 0x12, 0x03, 0x4a, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x4a, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4a,
-    0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4a, 0x21, 0x22,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x01, 0x12, 0x03, 0x4b, 0x04, 0x2e, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0c, 0x02, 0x01, 0x04, 0x12, 0x03, 0x4b, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0c, 0x02, 0x01, 0x06, 0x12, 0x03, 0x4b, 0x0d, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x4b, 0x1e, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03,
-    0x12, 0x03, 0x4b, 0x2c, 0x2d, 0x0a, 0x3d, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x04, 0x4f, 0x00, 0x51,
-    0x01, 0x1a, 0x31, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x70, 0x65, 0x6e,
-    0x64, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73,
-    0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x6f, 0x72, 0x69,
-    0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x03, 0x4f, 0x08, 0x23,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x00, 0x12, 0x03, 0x50, 0x04, 0x22, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0d, 0x02, 0x00, 0x04, 0x12, 0x03, 0x50, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0d, 0x02, 0x00, 0x05, 0x12, 0x03, 0x50, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x50, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x50, 0x20, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x04, 0x53, 0x00, 0x56,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x03, 0x53, 0x08, 0x24, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x0e, 0x02, 0x00, 0x12, 0x03, 0x54, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x54, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x54, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x54, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x03, 0x12, 0x03, 0x54,
-    0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x01, 0x12, 0x03, 0x55, 0x04, 0x2e, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x04, 0x12, 0x03, 0x55, 0x04, 0x0c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0e, 0x02, 0x01, 0x06, 0x12, 0x03, 0x55, 0x0d, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0e, 0x02, 0x01, 0x01, 0x12, 0x03, 0x55, 0x1e, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x55, 0x2c, 0x2d, 0x0a, 0x1b, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x04, 0x59,
-    0x00, 0x66, 0x01, 0x1a, 0x0f, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x65, 0x6e, 0x74,
-    0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x03, 0x59, 0x08, 0x18,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x03, 0x5a, 0x02, 0x19, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0f, 0x02, 0x00, 0x04, 0x12, 0x03, 0x5a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0f, 0x02, 0x00, 0x05, 0x12, 0x03, 0x5a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x5a, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x5a, 0x17, 0x18, 0x0a, 0x2d, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x01, 0x12, 0x03, 0x5d,
-    0x02, 0x21, 0x1a, 0x20, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x2f, 0x61, 0x63,
-    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69,
-    0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x04, 0x12, 0x03, 0x5d,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x05, 0x12, 0x03, 0x5d, 0x0b, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5d, 0x12, 0x1c, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x03, 0x12, 0x03, 0x5d, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x0f, 0x02, 0x02, 0x12, 0x03, 0x5e, 0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02,
-    0x02, 0x04, 0x12, 0x03, 0x5e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x05,
-    0x12, 0x03, 0x5e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x5e, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x03, 0x12, 0x03, 0x5e, 0x21,
-    0x22, 0x0a, 0x40, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x03, 0x12, 0x03, 0x61, 0x02, 0x20, 0x1a, 0x33,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20, 0x69, 0x64, 0x20, 0x74,
-    0x68, 0x61, 0x74, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x20, 0x69,
-    0x73, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x64, 0x20,
-    0x74, 0x6f, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x04, 0x12, 0x03, 0x61, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x05, 0x12, 0x03, 0x61, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x01, 0x12, 0x03, 0x61, 0x12, 0x1b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0f, 0x02, 0x03, 0x03, 0x12, 0x03, 0x61, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x0f, 0x02, 0x04, 0x12, 0x03, 0x62, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x04,
-    0x04, 0x12, 0x03, 0x62, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x04, 0x05, 0x12,
-    0x03, 0x62, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x04, 0x01, 0x12, 0x03, 0x62,
-    0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x04, 0x03, 0x12, 0x03, 0x62, 0x20, 0x21,
-    0x0a, 0x33, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x05, 0x12, 0x03, 0x65, 0x02, 0x1f, 0x1a, 0x26, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63, 0x72,
-    0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61,
-    0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x05, 0x04, 0x12, 0x03,
-    0x65, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x05, 0x05, 0x12, 0x03, 0x65, 0x0b,
-    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x05, 0x01, 0x12, 0x03, 0x65, 0x12, 0x1a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x05, 0x03, 0x12, 0x03, 0x65, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a,
-    0x02, 0x04, 0x10, 0x12, 0x04, 0x68, 0x00, 0x73, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x10, 0x01,
-    0x12, 0x03, 0x68, 0x08, 0x1e, 0x0a, 0x25, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x03, 0x6a,
-    0x02, 0x21, 0x1a, 0x18, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x62, 0x65,
-    0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x10, 0x02, 0x00, 0x04, 0x12, 0x03, 0x6a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x6a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x6a, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x6a, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x01, 0x12, 0x03, 0x6b, 0x02,
-    0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x04, 0x12, 0x03, 0x6b, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x05, 0x12, 0x03, 0x6b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x10, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6b, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x10, 0x02, 0x01, 0x03, 0x12, 0x03, 0x6b, 0x21, 0x22, 0x0a, 0x40, 0x0a, 0x04, 0x04, 0x10, 0x02,
-    0x02, 0x12, 0x03, 0x6e, 0x02, 0x20, 0x1a, 0x33, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69,
-    0x67, 0x69, 0x6e, 0x20, 0x69, 0x64, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x61, 0x63, 0x63, 0x6f,
-    0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x20, 0x69, 0x73, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20,
-    0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x10, 0x02, 0x02, 0x04, 0x12, 0x03, 0x6e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02,
-    0x02, 0x05, 0x12, 0x03, 0x6e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x01,
-    0x12, 0x03, 0x6e, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x03, 0x12, 0x03,
-    0x6e, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x03, 0x12, 0x03, 0x6f, 0x02, 0x22,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x04, 0x12, 0x03, 0x6f, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x05, 0x12, 0x03, 0x6f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x10, 0x02, 0x03, 0x01, 0x12, 0x03, 0x6f, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10,
-    0x02, 0x03, 0x03, 0x12, 0x03, 0x6f, 0x20, 0x21, 0x0a, 0x33, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x04,
-    0x12, 0x03, 0x72, 0x02, 0x1f, 0x1a, 0x26, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72,
-    0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x10, 0x02, 0x04, 0x04, 0x12, 0x03, 0x72, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x10, 0x02, 0x04, 0x05, 0x12, 0x03, 0x72, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02,
-    0x04, 0x01, 0x12, 0x03, 0x72, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x04, 0x03,
-    0x12, 0x03, 0x72, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x11, 0x12, 0x04, 0x75, 0x00, 0x7a,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x11, 0x01, 0x12, 0x03, 0x75, 0x08, 0x25, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x11, 0x02, 0x00, 0x12, 0x03, 0x76, 0x02, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x76, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x76, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x76, 0x12, 0x2b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x03, 0x12, 0x03, 0x76,
-    0x2e, 0x2f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x01, 0x12, 0x03, 0x77, 0x02, 0x20, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x04, 0x12, 0x03, 0x77, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x11, 0x02, 0x01, 0x05, 0x12, 0x03, 0x77, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x11, 0x02, 0x01, 0x01, 0x12, 0x03, 0x77, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x77, 0x1e, 0x1f, 0x0a, 0x45, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x02, 0x12,
-    0x03, 0x79, 0x02, 0x1b, 0x1a, 0x38, 0x20, 0x69, 0x66, 0x20, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65,
-    0x20, 0x3d, 0x3d, 0x20, 0x74, 0x72, 0x75, 0x65, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x6e, 0x20, 0x74,
-    0x68, 0x65, 0x79, 0x27, 0x72, 0x65, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x6a, 0x6f, 0x69, 0x6e, 0x69,
-    0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x04, 0x12, 0x03, 0x79, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x11, 0x02, 0x02, 0x05, 0x12, 0x03, 0x79, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11,
-    0x02, 0x02, 0x01, 0x12, 0x03, 0x79, 0x10, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02,
-    0x03, 0x12, 0x03, 0x79, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x12, 0x12, 0x04, 0x7c, 0x00,
-    0x7d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x12, 0x01, 0x12, 0x03, 0x7c, 0x08, 0x26, 0x0a, 0x1d,
-    0x0a, 0x02, 0x04, 0x13, 0x12, 0x06, 0x80, 0x01, 0x00, 0x8b, 0x01, 0x01, 0x1a, 0x0f, 0x20, 0x73,
-    0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0b, 0x0a,
-    0x03, 0x04, 0x13, 0x01, 0x12, 0x04, 0x80, 0x01, 0x08, 0x17, 0x0a, 0x25, 0x0a, 0x04, 0x04, 0x13,
-    0x02, 0x00, 0x12, 0x04, 0x82, 0x01, 0x04, 0x1b, 0x1a, 0x17, 0x20, 0x70, 0x6b, 0x20, 0x66, 0x6f,
-    0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x20, 0x6b, 0x65, 0x79,
-    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x04, 0x12, 0x04, 0x82, 0x01, 0x04, 0x0c,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x05, 0x12, 0x04, 0x82, 0x01, 0x0d, 0x13, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x01, 0x12, 0x04, 0x82, 0x01, 0x14, 0x16, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x03, 0x12, 0x04, 0x82, 0x01, 0x19, 0x1a, 0x0a, 0x21, 0x0a,
-    0x04, 0x04, 0x13, 0x02, 0x01, 0x12, 0x04, 0x84, 0x01, 0x04, 0x22, 0x1a, 0x13, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x70, 0x61, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x04, 0x12, 0x04, 0x84, 0x01, 0x04, 0x0c, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x05, 0x12, 0x04, 0x84, 0x01, 0x0d, 0x13, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x01, 0x12, 0x04, 0x84, 0x01, 0x14, 0x1d, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x13, 0x02, 0x01, 0x03, 0x12, 0x04, 0x84, 0x01, 0x20, 0x21, 0x0a, 0x18, 0x0a, 0x04,
-    0x04, 0x13, 0x02, 0x02, 0x12, 0x04, 0x86, 0x01, 0x04, 0x1d, 0x1a, 0x0a, 0x20, 0x6b, 0x65, 0x79,
-    0x20, 0x6e, 0x61, 0x6d, 0x65, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x04, 0x12,
-    0x04, 0x86, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x05, 0x12, 0x04,
-    0x86, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x01, 0x12, 0x04, 0x86,
-    0x01, 0x14, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x03, 0x12, 0x04, 0x86, 0x01,
-    0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x03, 0x12, 0x04, 0x87, 0x01, 0x04, 0x21,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x04, 0x12, 0x04, 0x87, 0x01, 0x04, 0x0c, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x05, 0x12, 0x04, 0x87, 0x01, 0x0d, 0x13, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x01, 0x12, 0x04, 0x87, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x13, 0x02, 0x03, 0x03, 0x12, 0x04, 0x87, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04,
-    0x04, 0x13, 0x02, 0x04, 0x12, 0x04, 0x88, 0x01, 0x04, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13,
-    0x02, 0x04, 0x04, 0x12, 0x04, 0x88, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02,
-    0x04, 0x05, 0x12, 0x04, 0x88, 0x01, 0x0d, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04,
-    0x01, 0x12, 0x04, 0x88, 0x01, 0x13, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x03,
-    0x12, 0x04, 0x88, 0x01, 0x1a, 0x1b, 0x0a, 0x2e, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x05, 0x12, 0x04,
-    0x8a, 0x01, 0x04, 0x21, 0x1a, 0x20, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69,
-    0x64, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x6b, 0x65, 0x79, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x04, 0x12,
-    0x04, 0x8a, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x05, 0x12, 0x04,
-    0x8a, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x01, 0x12, 0x04, 0x8a,
-    0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x03, 0x12, 0x04, 0x8a, 0x01,
-    0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x14, 0x12, 0x06, 0x8d, 0x01, 0x00, 0x93, 0x01, 0x01,
-    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x14, 0x01, 0x12, 0x04, 0x8d, 0x01, 0x08, 0x1d, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x14, 0x02, 0x00, 0x12, 0x04, 0x8e, 0x01, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x14, 0x02, 0x00, 0x04, 0x12, 0x04, 0x8e, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14,
-    0x02, 0x00, 0x05, 0x12, 0x04, 0x8e, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02,
-    0x00, 0x01, 0x12, 0x04, 0x8e, 0x01, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00,
-    0x03, 0x12, 0x04, 0x8e, 0x01, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x01, 0x12,
-    0x04, 0x8f, 0x01, 0x04, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x04, 0x12, 0x04,
-    0x8f, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x05, 0x12, 0x04, 0x8f,
-    0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8f, 0x01,
-    0x14, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8f, 0x01, 0x1b,
-    0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x02, 0x12, 0x04, 0x90, 0x01, 0x04, 0x21, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x04, 0x12, 0x04, 0x90, 0x01, 0x04, 0x0c, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x05, 0x12, 0x04, 0x90, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x14, 0x02, 0x02, 0x01, 0x12, 0x04, 0x90, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x14, 0x02, 0x02, 0x03, 0x12, 0x04, 0x90, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x14, 0x02, 0x03, 0x12, 0x04, 0x91, 0x01, 0x04, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02,
-    0x03, 0x04, 0x12, 0x04, 0x91, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03,
-    0x05, 0x12, 0x04, 0x91, 0x01, 0x0d, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x01,
-    0x12, 0x04, 0x91, 0x01, 0x13, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x03, 0x12,
-    0x04, 0x91, 0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x04, 0x12, 0x04, 0x92,
-    0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x04, 0x12, 0x04, 0x92, 0x01,
-    0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x05, 0x12, 0x04, 0x92, 0x01, 0x0d,
-    0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x01, 0x12, 0x04, 0x92, 0x01, 0x14, 0x1c,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x03, 0x12, 0x04, 0x92, 0x01, 0x1f, 0x20,
+    0x45, 0x21, 0x21, 0x21, 0x0a, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75,
+    0x6e, 0x74, 0x5f, 0x69, 0x64, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x61, 0x72, 0x65, 0x20, 0x69, 0x6d, 0x70, 0x6c, 0x65, 0x6d,
+    0x65, 0x6e, 0x74, 0x65, 0x64, 0x0a, 0x20, 0x21, 0x21, 0x21, 0x4e, 0x4f, 0x54, 0x45, 0x21, 0x21,
+    0x21, 0x0a, 0x20, 0x21, 0x21, 0x21, 0x4e, 0x4f, 0x54, 0x45, 0x21, 0x21, 0x21, 0x0a, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x35, 0x08, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x09,
+    0x08, 0x00, 0x12, 0x04, 0x36, 0x04, 0x39, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x08, 0x00,
+    0x01, 0x12, 0x03, 0x36, 0x0a, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03,
+    0x37, 0x08, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x05, 0x12, 0x03, 0x37, 0x08,
+    0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x37, 0x0f, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x37, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x38, 0x08, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x01, 0x05, 0x12, 0x03, 0x38, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x38, 0x0f, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x38, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x09, 0x08, 0x01, 0x12, 0x04, 0x3a, 0x04,
+    0x3d, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x08, 0x01, 0x01, 0x12, 0x03, 0x3a, 0x0a, 0x15,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x02, 0x12, 0x03, 0x3b, 0x08, 0x1d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x02, 0x05, 0x12, 0x03, 0x3b, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3b, 0x0f, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x02, 0x03, 0x12, 0x03, 0x3b, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12,
+    0x03, 0x3c, 0x08, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x05, 0x12, 0x03, 0x3c,
+    0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x01, 0x12, 0x03, 0x3c, 0x0f, 0x1a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x03, 0x12, 0x03, 0x3c, 0x1d, 0x1e, 0x0a, 0x0a,
+    0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x40, 0x00, 0x42, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a,
+    0x01, 0x12, 0x03, 0x40, 0x08, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03,
+    0x41, 0x04, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x04, 0x12, 0x03, 0x41, 0x04,
+    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x05, 0x12, 0x03, 0x41, 0x0d, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x41, 0x12, 0x1c, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x41, 0x1f, 0x20, 0x0a, 0x3e, 0x0a, 0x02, 0x04,
+    0x0b, 0x12, 0x04, 0x45, 0x00, 0x47, 0x01, 0x1a, 0x32, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x20, 0x61,
+    0x6c, 0x6c, 0x20, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x67, 0x69, 0x76,
+    0x65, 0x6e, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x0b, 0x01, 0x12, 0x03, 0x45, 0x08, 0x24, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12,
+    0x03, 0x46, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x04, 0x12, 0x03, 0x46,
+    0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x03, 0x46, 0x0d, 0x13,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x03, 0x46, 0x14, 0x1e, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x03, 0x46, 0x21, 0x22, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x0c, 0x12, 0x04, 0x49, 0x00, 0x4c, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0c, 0x01, 0x12,
+    0x03, 0x49, 0x08, 0x25, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x00, 0x12, 0x03, 0x4a, 0x04,
+    0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x04, 0x12, 0x03, 0x4a, 0x04, 0x0c, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12, 0x03, 0x4a, 0x0d, 0x13, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4a, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0c, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4a, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02,
+    0x01, 0x12, 0x03, 0x4b, 0x04, 0x2e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x04, 0x12,
+    0x03, 0x4b, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x06, 0x12, 0x03, 0x4b,
+    0x0d, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4b, 0x1e, 0x29,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03, 0x12, 0x03, 0x4b, 0x2c, 0x2d, 0x0a, 0x3d,
+    0x0a, 0x02, 0x04, 0x0d, 0x12, 0x04, 0x4f, 0x00, 0x51, 0x01, 0x1a, 0x31, 0x20, 0x6c, 0x69, 0x73,
+    0x74, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e,
+    0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20,
+    0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x0d, 0x01, 0x12, 0x03, 0x4f, 0x08, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02,
+    0x00, 0x12, 0x03, 0x50, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x04, 0x12,
+    0x03, 0x50, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x05, 0x12, 0x03, 0x50,
+    0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x01, 0x12, 0x03, 0x50, 0x14, 0x1d,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03, 0x12, 0x03, 0x50, 0x20, 0x21, 0x0a, 0x0a,
+    0x0a, 0x02, 0x04, 0x0e, 0x12, 0x04, 0x53, 0x00, 0x56, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0e,
+    0x01, 0x12, 0x03, 0x53, 0x08, 0x24, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x00, 0x12, 0x03,
+    0x54, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x04, 0x12, 0x03, 0x54, 0x04,
+    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x05, 0x12, 0x03, 0x54, 0x0d, 0x13, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x01, 0x12, 0x03, 0x54, 0x14, 0x1d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x00, 0x03, 0x12, 0x03, 0x54, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x0e, 0x02, 0x01, 0x12, 0x03, 0x55, 0x04, 0x2e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01,
+    0x04, 0x12, 0x03, 0x55, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x06, 0x12,
+    0x03, 0x55, 0x0d, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x01, 0x12, 0x03, 0x55,
+    0x1e, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x03, 0x12, 0x03, 0x55, 0x2c, 0x2d,
+    0x0a, 0x1b, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x04, 0x59, 0x00, 0x66, 0x01, 0x1a, 0x0f, 0x20, 0x73,
+    0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x0f, 0x01, 0x12, 0x03, 0x59, 0x08, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0f, 0x02,
+    0x00, 0x12, 0x03, 0x5a, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x04, 0x12,
+    0x03, 0x5a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x05, 0x12, 0x03, 0x5a,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x01, 0x12, 0x03, 0x5a, 0x12, 0x14,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x03, 0x12, 0x03, 0x5a, 0x17, 0x18, 0x0a, 0x2d,
+    0x0a, 0x04, 0x04, 0x0f, 0x02, 0x01, 0x12, 0x03, 0x5d, 0x02, 0x21, 0x1a, 0x20, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x2f, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x62,
+    0x65, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0f, 0x02, 0x01, 0x04, 0x12, 0x03, 0x5d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0f, 0x02, 0x01, 0x05, 0x12, 0x03, 0x5d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x5d, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x5d, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x02, 0x12, 0x03, 0x5e,
+    0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x04, 0x12, 0x03, 0x5e, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x05, 0x12, 0x03, 0x5e, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x01, 0x12, 0x03, 0x5e, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0f, 0x02, 0x02, 0x03, 0x12, 0x03, 0x5e, 0x21, 0x22, 0x0a, 0x40, 0x0a, 0x04, 0x04, 0x0f,
+    0x02, 0x03, 0x12, 0x03, 0x61, 0x02, 0x20, 0x1a, 0x33, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x20, 0x69, 0x64, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x61, 0x63, 0x63,
+    0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x20, 0x69, 0x73, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67,
+    0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0f, 0x02, 0x03, 0x04, 0x12, 0x03, 0x61, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f,
+    0x02, 0x03, 0x05, 0x12, 0x03, 0x61, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x61, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x03, 0x12,
+    0x03, 0x61, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x04, 0x12, 0x03, 0x62, 0x02,
+    0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x04, 0x04, 0x12, 0x03, 0x62, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x04, 0x05, 0x12, 0x03, 0x62, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0f, 0x02, 0x04, 0x01, 0x12, 0x03, 0x62, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0f, 0x02, 0x04, 0x03, 0x12, 0x03, 0x62, 0x20, 0x21, 0x0a, 0x33, 0x0a, 0x04, 0x04, 0x0f, 0x02,
+    0x05, 0x12, 0x03, 0x65, 0x02, 0x1f, 0x1a, 0x26, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65,
+    0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x05, 0x04, 0x12, 0x03, 0x65, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0f, 0x02, 0x05, 0x05, 0x12, 0x03, 0x65, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f,
+    0x02, 0x05, 0x01, 0x12, 0x03, 0x65, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x05,
+    0x03, 0x12, 0x03, 0x65, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x10, 0x12, 0x04, 0x68, 0x00,
+    0x73, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12, 0x03, 0x68, 0x08, 0x1e, 0x0a, 0x25,
+    0x0a, 0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x03, 0x6a, 0x02, 0x21, 0x1a, 0x18, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76,
+    0x69, 0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x04, 0x12, 0x03,
+    0x6a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x05, 0x12, 0x03, 0x6a, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6a, 0x12, 0x1c, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6a, 0x1f, 0x20, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x10, 0x02, 0x01, 0x12, 0x03, 0x6b, 0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10,
+    0x02, 0x01, 0x04, 0x12, 0x03, 0x6b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01,
+    0x05, 0x12, 0x03, 0x6b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x6b, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x03, 0x12, 0x03, 0x6b,
+    0x21, 0x22, 0x0a, 0x40, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x02, 0x12, 0x03, 0x6e, 0x02, 0x20, 0x1a,
+    0x33, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20, 0x69, 0x64, 0x20,
+    0x74, 0x68, 0x61, 0x74, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x20,
+    0x69, 0x73, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x64,
+    0x20, 0x74, 0x6f, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x04, 0x12, 0x03, 0x6e,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x05, 0x12, 0x03, 0x6e, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x01, 0x12, 0x03, 0x6e, 0x12, 0x1b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x03, 0x12, 0x03, 0x6e, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x10, 0x02, 0x03, 0x12, 0x03, 0x6f, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02,
+    0x03, 0x04, 0x12, 0x03, 0x6f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x05,
+    0x12, 0x03, 0x6f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x01, 0x12, 0x03,
+    0x6f, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x03, 0x12, 0x03, 0x6f, 0x20,
+    0x21, 0x0a, 0x33, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x04, 0x12, 0x03, 0x72, 0x02, 0x1f, 0x1a, 0x26,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63,
+    0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x04, 0x04, 0x12,
+    0x03, 0x72, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x04, 0x05, 0x12, 0x03, 0x72,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x04, 0x01, 0x12, 0x03, 0x72, 0x12, 0x1a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x04, 0x03, 0x12, 0x03, 0x72, 0x1d, 0x1e, 0x0a, 0x0a,
+    0x0a, 0x02, 0x04, 0x11, 0x12, 0x04, 0x75, 0x00, 0x7a, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x11,
+    0x01, 0x12, 0x03, 0x75, 0x08, 0x25, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x00, 0x12, 0x03,
+    0x76, 0x02, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x04, 0x12, 0x03, 0x76, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x05, 0x12, 0x03, 0x76, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x01, 0x12, 0x03, 0x76, 0x12, 0x2b, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x11, 0x02, 0x00, 0x03, 0x12, 0x03, 0x76, 0x2e, 0x2f, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x11, 0x02, 0x01, 0x12, 0x03, 0x77, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01,
+    0x04, 0x12, 0x03, 0x77, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x05, 0x12,
+    0x03, 0x77, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x01, 0x12, 0x03, 0x77,
+    0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x03, 0x12, 0x03, 0x77, 0x1e, 0x1f,
+    0x0a, 0x45, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x02, 0x12, 0x03, 0x79, 0x02, 0x1b, 0x1a, 0x38, 0x20,
+    0x69, 0x66, 0x20, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65, 0x20, 0x3d, 0x3d, 0x20, 0x74, 0x72, 0x75,
+    0x65, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x79, 0x27, 0x72, 0x65, 0x20,
+    0x6e, 0x6f, 0x74, 0x20, 0x6a, 0x6f, 0x69, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x04,
+    0x12, 0x03, 0x79, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x05, 0x12, 0x03,
+    0x79, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x01, 0x12, 0x03, 0x79, 0x10,
+    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x03, 0x12, 0x03, 0x79, 0x19, 0x1a, 0x0a,
+    0x0a, 0x0a, 0x02, 0x04, 0x12, 0x12, 0x04, 0x7c, 0x00, 0x7d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x12, 0x01, 0x12, 0x03, 0x7c, 0x08, 0x26, 0x0a, 0x1d, 0x0a, 0x02, 0x04, 0x13, 0x12, 0x06, 0x80,
+    0x01, 0x00, 0x8b, 0x01, 0x01, 0x1a, 0x0f, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x65,
+    0x6e, 0x74, 0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x13, 0x01, 0x12, 0x04, 0x80,
+    0x01, 0x08, 0x17, 0x0a, 0x25, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x00, 0x12, 0x04, 0x82, 0x01, 0x04,
+    0x1b, 0x1a, 0x17, 0x20, 0x70, 0x6b, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70,
+    0x75, 0x62, 0x6c, 0x69, 0x63, 0x20, 0x6b, 0x65, 0x79, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13,
+    0x02, 0x00, 0x04, 0x12, 0x04, 0x82, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02,
+    0x00, 0x05, 0x12, 0x04, 0x82, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0x82, 0x01, 0x14, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0x82, 0x01, 0x19, 0x1a, 0x0a, 0x21, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x01, 0x12, 0x04,
+    0x84, 0x01, 0x04, 0x22, 0x1a, 0x13, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x72, 0x65, 0x6e,
+    0x74, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02,
+    0x01, 0x04, 0x12, 0x04, 0x84, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01,
+    0x05, 0x12, 0x04, 0x84, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0x84, 0x01, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x03, 0x12,
+    0x04, 0x84, 0x01, 0x20, 0x21, 0x0a, 0x18, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x02, 0x12, 0x04, 0x86,
+    0x01, 0x04, 0x1d, 0x1a, 0x0a, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x04, 0x12, 0x04, 0x86, 0x01, 0x04, 0x0c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x05, 0x12, 0x04, 0x86, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x13, 0x02, 0x02, 0x01, 0x12, 0x04, 0x86, 0x01, 0x14, 0x18, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x13, 0x02, 0x02, 0x03, 0x12, 0x04, 0x86, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x13, 0x02, 0x03, 0x12, 0x04, 0x87, 0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02,
+    0x03, 0x04, 0x12, 0x04, 0x87, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03,
+    0x05, 0x12, 0x04, 0x87, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x01,
+    0x12, 0x04, 0x87, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x03, 0x12,
+    0x04, 0x87, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x04, 0x12, 0x04, 0x88,
+    0x01, 0x04, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x04, 0x12, 0x04, 0x88, 0x01,
+    0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x05, 0x12, 0x04, 0x88, 0x01, 0x0d,
+    0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x01, 0x12, 0x04, 0x88, 0x01, 0x13, 0x17,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x03, 0x12, 0x04, 0x88, 0x01, 0x1a, 0x1b, 0x0a,
+    0x2e, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x05, 0x12, 0x04, 0x8a, 0x01, 0x04, 0x21, 0x1a, 0x20, 0x20,
+    0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69, 0x64, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20,
+    0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6b, 0x65, 0x79, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x04, 0x12, 0x04, 0x8a, 0x01, 0x04, 0x0c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x05, 0x12, 0x04, 0x8a, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x13, 0x02, 0x05, 0x01, 0x12, 0x04, 0x8a, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x13, 0x02, 0x05, 0x03, 0x12, 0x04, 0x8a, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
+    0x14, 0x12, 0x06, 0x8d, 0x01, 0x00, 0x93, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x14, 0x01,
+    0x12, 0x04, 0x8d, 0x01, 0x08, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x00, 0x12, 0x04,
+    0x8e, 0x01, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x04, 0x12, 0x04, 0x8e,
+    0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x05, 0x12, 0x04, 0x8e, 0x01,
+    0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8e, 0x01, 0x14,
+    0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8e, 0x01, 0x20, 0x21,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x01, 0x12, 0x04, 0x8f, 0x01, 0x04, 0x1d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x04, 0x12, 0x04, 0x8f, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x14, 0x02, 0x01, 0x05, 0x12, 0x04, 0x8f, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x14, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8f, 0x01, 0x14, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x14, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8f, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14,
+    0x02, 0x02, 0x12, 0x04, 0x90, 0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02,
+    0x04, 0x12, 0x04, 0x90, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x05,
+    0x12, 0x04, 0x90, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x01, 0x12,
+    0x04, 0x90, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x03, 0x12, 0x04,
+    0x90, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x03, 0x12, 0x04, 0x91, 0x01,
+    0x04, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x04, 0x12, 0x04, 0x91, 0x01, 0x04,
+    0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x05, 0x12, 0x04, 0x91, 0x01, 0x0d, 0x12,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x01, 0x12, 0x04, 0x91, 0x01, 0x13, 0x17, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x03, 0x12, 0x04, 0x91, 0x01, 0x1a, 0x1b, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x14, 0x02, 0x04, 0x12, 0x04, 0x92, 0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x14, 0x02, 0x04, 0x04, 0x12, 0x04, 0x92, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x14, 0x02, 0x04, 0x05, 0x12, 0x04, 0x92, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14,
+    0x02, 0x04, 0x01, 0x12, 0x04, 0x92, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02,
+    0x04, 0x03, 0x12, 0x04, 0x92, 0x01, 0x1f, 0x20,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
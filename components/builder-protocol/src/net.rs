@@ -17,8 +17,32 @@ use std::collections::BTreeMap;
 use protobuf::core::ProtobufEnum;
 use rustc_serialize::json::{Json, ToJson};
 
+use message::Routable;
+
 pub use message::net::*;
 
+bitflags! {
+    /// Optional behaviours of the messaging layer. A peer advertises the ones it supports in the
+    /// `capabilities` of its `Registration` and of every `Msg` it sends, and a behaviour may only
+    /// be used with a peer once both sides are known to support it.
+    pub flags Capabilities: u32 {
+        /// Message bodies may be compressed
+        const COMPRESSION  = 0b00000001,
+        /// Large messages may be split across several frames
+        const SEGMENTATION = 0b00000010,
+        /// Routed messages may carry trace context in their `RouteInfo`
+        const TRACING      = 0b00000100,
+    }
+}
+
+/// Capabilities of this build, advertised to every peer.
+pub const SUPPORTED_CAPABILITIES: Capabilities = TRACING;
+
+/// The capabilities both this build and a peer advertising `theirs` support.
+pub fn negotiate(theirs: Capabilities) -> Capabilities {
+    SUPPORTED_CAPABILITIES & theirs
+}
+
 pub fn err<M: Into<String>>(code: ErrCode, msg: M) -> NetError {
     let mut err = NetError::new();
     err.set_code(code);
@@ -26,6 +50,60 @@ pub fn err<M: Into<String>>(code: ErrCode, msg: M) -> NetError {
     err
 }
 
+impl Msg {
+    /// Capabilities the sender advertised. Unrecognized bits, from peers newer than this build,
+    /// are ignored.
+    pub fn capabilities(&self) -> Capabilities {
+        Capabilities::from_bits_truncate(self.get_capabilities())
+    }
+
+    /// Whether both this build and the sender support every one of the given capabilities, so
+    /// they may be used in replying to the message.
+    pub fn supports(&self, capabilities: Capabilities) -> bool {
+        negotiate(self.capabilities()).contains(capabilities)
+    }
+}
+
+impl Routable for Ping {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl Routable for HealthGet {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl Routable for DebugGet {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl Routable for StatsGet {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl Routable for Echo {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
 impl ToJson for ErrCode {
     fn to_json(&self) -> Json {
         Json::U64(self.value() as u64)
@@ -41,6 +119,31 @@ impl ToJson for NetError {
     }
 }
 
+impl ToJson for HealthStatus {
+    fn to_json(&self) -> Json {
+        Json::U64(self.value() as u64)
+    }
+}
+
+impl ToJson for HealthCheckResult {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("name".to_string(), self.get_name().to_json());
+        m.insert("status".to_string(), self.get_status().to_json());
+        m.insert("msg".to_string(), self.get_msg().to_json());
+        Json::Object(m)
+    }
+}
+
+impl ToJson for Health {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("status".to_string(), self.get_status().to_json());
+        m.insert("checks".to_string(), self.get_checks().to_vec().to_json());
+        Json::Object(m)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use protobuf::Message;
@@ -51,4 +154,16 @@ mod tests {
         let msg = Ping::new();
         assert_eq!(msg.descriptor().name(), "Ping");
     }
+
+    #[test]
+    fn capabilities_are_negotiated() {
+        let mut msg = Msg::new();
+        assert!(msg.capabilities().is_empty());
+        assert!(!msg.supports(TRACING));
+        msg.set_capabilities((TRACING | COMPRESSION).bits() | 0x80000000);
+        assert_eq!(msg.capabilities(), TRACING | COMPRESSION);
+        assert!(msg.supports(TRACING));
+        assert!(!msg.supports(COMPRESSION));
+        assert!(!msg.supports(TRACING | COMPRESSION));
+    }
 }
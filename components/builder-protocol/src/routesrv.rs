@@ -12,4 +12,46 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
+
+use message::Routable;
+use net::Capabilities;
+
 pub use message::routesrv::*;
+
+impl Registration {
+    /// Capabilities the registering server advertised. Unrecognized bits are ignored.
+    pub fn capabilities(&self) -> Capabilities {
+        Capabilities::from_bits_truncate(self.get_capabilities())
+    }
+
+    /// Weight of the registering server when spreading requests among the servers of a shard.
+    /// Servers which predate weights, or registered a weight of zero, count as one.
+    pub fn weight(&self) -> u32 {
+        cmp::max(self.get_weight(), 1)
+    }
+}
+
+impl Routable for DenyListUpdate {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl Routable for DenyListGet {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl Routable for ServerLoadGet {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
@@ -0,0 +1,268 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fingerprints of message schemas, for catching servers whose protocol files have drifted apart.
+//!
+//! After a partial deploy, servers built from different protocol files can exchange messages one
+//! side decodes differently than the other meant them. Every message type has a fingerprint
+//! taken from the descriptor protoc compiled into this crate: a hash of the number, label and
+//! type of each of its fields, and of the values of the enums they use. Names don't contribute
+//! since they never go over the wire, so renaming a field keeps the fingerprint. Servers send the
+//! fingerprints of the messages they handle when they register, and a `RouteSrv` compares them
+//! with its own and with those of the other servers registered for the same protocol.
+//!
+//! Each field has a fingerprint of its own as well, so an optional or repeated field only one
+//! side has, such as one added by the newer build, isn't taken for a mismatch. Adding a required
+//! field, or changing a field both sides have, is.
+
+use protobuf::descriptor::{DescriptorProto, FieldDescriptorProto, FieldDescriptorProto_Label,
+                           FieldDescriptorProto_Type};
+
+use message::{self, FILES};
+use net::Protocol;
+use routesrv::SchemaFingerprint;
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+/// Bit of a field fingerprint set for required fields.
+const REQUIRED_FIELD: u64 = 1 << 31;
+
+/// Fingerprint of the message with the given full name, such as `jobsrv.Job`, if the crate
+/// defines it.
+pub fn fingerprint(full_name: &str) -> Option<u64> {
+    message::descriptor(full_name).map(hash_message)
+}
+
+/// Fingerprints of every message of the given packages, sorted by name.
+pub fn fingerprints(packages: &[&str]) -> Vec<SchemaFingerprint> {
+    let mut fingerprints = vec![];
+    for file in FILES.iter() {
+        let file = file();
+        if !packages.contains(&file.get_package()) {
+            continue;
+        }
+        for desc in file.get_message_type() {
+            let mut fingerprint = SchemaFingerprint::new();
+            fingerprint.set_name(format!("{}.{}", file.get_package(), desc.get_name()));
+            fingerprint.set_fingerprint(hash_message(desc));
+            fingerprint.set_fields(desc.get_field().iter().map(field_fingerprint).collect());
+            fingerprints.push(fingerprint);
+        }
+    }
+    fingerprints.sort_by(|a, b| a.get_name().cmp(b.get_name()));
+    fingerprints
+}
+
+/// The package of the messages a server of the given protocol handles.
+pub fn package(protocol: Protocol) -> &'static str {
+    match protocol {
+        Protocol::Net => "net",
+        Protocol::RouteSrv => "routesrv",
+        Protocol::SessionSrv => "sessionsrv",
+        Protocol::VaultSrv => "vault",
+        Protocol::JobSrv => "jobsrv",
+    }
+}
+
+/// Names of the messages whose fingerprints differ between two sets, sorted. Messages only one
+/// set has are ignored, as only those both sides handle can be misread.
+pub fn mismatches(ours: &[SchemaFingerprint], theirs: &[SchemaFingerprint]) -> Vec<String> {
+    let mut names: Vec<String> = ours.iter()
+        .filter(|o| theirs.iter().any(|t| t.get_name() == o.get_name() && !compatible(o, t)))
+        .map(|o| o.get_name().to_string())
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Fingerprint of a single field: its number in the high 32 bits, whether it is required, and a
+/// hash of its label and type in the rest.
+pub fn field_fingerprint(field: &FieldDescriptorProto) -> u64 {
+    let number = field.get_number() as u32 as u64;
+    let required = if field.get_label() == FieldDescriptorProto_Label::LABEL_REQUIRED {
+        REQUIRED_FIELD
+    } else {
+        0
+    };
+    let hash = fnv(FNV_OFFSET, &field_schema(field)) & (REQUIRED_FIELD - 1);
+    number << 32 | required | hash
+}
+
+// Whether two fingerprints of the same message can be read by either side. Fingerprints sent by
+// servers which predate field fingerprints can only be compared as a whole.
+fn compatible(ours: &SchemaFingerprint, theirs: &SchemaFingerprint) -> bool {
+    if ours.get_fingerprint() == theirs.get_fingerprint() {
+        return true;
+    }
+    if ours.get_fields().is_empty() || theirs.get_fields().is_empty() {
+        return false;
+    }
+    let unmatched_required = |a: &[u64], b: &[u64]| {
+        a.iter().any(|field| {
+            field & REQUIRED_FIELD != 0 && !b.iter().any(|other| other >> 32 == field >> 32)
+        })
+    };
+    let changed = ours.get_fields().iter().any(|field| {
+        theirs.get_fields().iter().any(|other| other >> 32 == field >> 32 && other != field)
+    });
+    !changed && !unmatched_required(ours.get_fields(), theirs.get_fields()) &&
+    !unmatched_required(theirs.get_fields(), ours.get_fields())
+}
+
+fn hash_message(desc: &DescriptorProto) -> u64 {
+    let mut fields: Vec<_> = desc.get_field().iter().collect();
+    fields.sort_by_key(|f| f.get_number());
+    fields.iter().fold(FNV_OFFSET, |hash, field| fnv(hash, &field_schema(field)))
+}
+
+// The number, label and type of a field, with the values of the enum it uses.
+fn field_schema(field: &FieldDescriptorProto) -> String {
+    let mut schema = format!("{}:{:?}:{:?}",
+                             field.get_number(),
+                             field.get_label(),
+                             field.get_field_type());
+    match field.get_field_type() {
+        FieldDescriptorProto_Type::TYPE_MESSAGE => {
+            schema.push_str(&format!(":{}", field.get_type_name()));
+        }
+        FieldDescriptorProto_Type::TYPE_ENUM => {
+            schema.push_str(&format!(":{}", field.get_type_name()));
+            for value in enum_values(field.get_type_name()) {
+                schema.push_str(&format!(",{}", value));
+            }
+        }
+        _ => (),
+    }
+    if field.get_options().get_packed() {
+        schema.push_str(":packed");
+    }
+    schema.push(';');
+    schema
+}
+
+fn fnv(hash: u64, data: &str) -> u64 {
+    data.bytes().fold(hash, |hash, byte| (hash ^ byte as u64).wrapping_mul(FNV_PRIME))
+}
+
+// Numbers of the values of the enum with the given type name, sorted.
+fn enum_values(type_name: &str) -> Vec<i32> {
+    let full_name = type_name.trim_left_matches('.');
+    for file in FILES.iter() {
+        let file = file();
+        for desc in file.get_enum_type() {
+            if format!("{}.{}", file.get_package(), desc.get_name()) == full_name {
+                let mut values: Vec<i32> =
+                    desc.get_value().iter().map(|v| v.get_number()).collect();
+                values.sort();
+                return values;
+            }
+        }
+    }
+    vec![]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fingerprints_every_message_of_a_package() {
+        let fingerprints = fingerprints(&["routesrv"]);
+        assert!(fingerprints.iter().all(|f| f.get_name().starts_with("routesrv.")));
+        assert!(fingerprints.iter().any(|f| f.get_name() == "routesrv.Registration"));
+        let reg = fingerprints.iter().find(|f| f.get_name() == "routesrv.Registration").unwrap();
+        assert_eq!(Some(reg.get_fingerprint()), fingerprint("routesrv.Registration"));
+        assert!(fingerprint("routesrv.Registration") != fingerprint("routesrv.ShardMap"));
+        assert_eq!(fingerprint("routesrv.Missing"), None);
+    }
+
+    #[test]
+    fn mismatches_of_shared_messages() {
+        let ours = fingerprints(&["net"]);
+        let mut theirs = fingerprints(&["net", "jobsrv"]);
+        assert!(mismatches(&ours, &theirs).is_empty());
+        for fingerprint in theirs.iter_mut().filter(|f| f.get_name() == "net.Ping") {
+            fingerprint.set_fingerprint(0);
+        }
+        assert_eq!(mismatches(&ours, &theirs), vec!["net.Ping".to_string()]);
+    }
+
+    fn registration() -> SchemaFingerprint {
+        fingerprints(&["routesrv"])
+            .into_iter()
+            .find(|f| f.get_name() == "routesrv.Registration")
+            .unwrap()
+    }
+
+    // A fingerprint of the registration as another build with the given fields would send it.
+    fn with_fields(fields: Vec<u64>) -> SchemaFingerprint {
+        let mut fingerprint = registration();
+        fingerprint.set_fingerprint(fields.iter().fold(0, |hash, field| hash ^ field));
+        fingerprint.set_fields(fields);
+        fingerprint
+    }
+
+    #[test]
+    fn fields_are_fingerprinted_by_number() {
+        let fields = registration().get_fields().to_vec();
+        assert!(!fields.is_empty());
+        let mut numbers: Vec<u64> = fields.iter().map(|f| f >> 32).collect();
+        numbers.sort();
+        assert_eq!(numbers, (1..(fields.len() as u64 + 1)).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn added_optional_fields_are_not_mismatches() {
+        let ours = vec![registration()];
+        let mut fields = registration().get_fields().to_vec();
+        // A build with a new optional field 99, and one without one of our optional fields.
+        fields.push(99 << 32 | 12345);
+        assert!(mismatches(&ours, &[with_fields(fields.clone())]).is_empty());
+        let optional = fields.iter()
+            .position(|f| f & REQUIRED_FIELD == 0 && f >> 32 != 99)
+            .unwrap();
+        fields.remove(optional);
+        assert!(mismatches(&ours, &[with_fields(fields)]).is_empty());
+    }
+
+    #[test]
+    fn added_required_fields_are_mismatches() {
+        let ours = vec![registration()];
+        let mut fields = registration().get_fields().to_vec();
+        fields.push(99 << 32 | REQUIRED_FIELD | 12345);
+        assert_eq!(mismatches(&ours, &[with_fields(fields)]),
+                   vec!["routesrv.Registration".to_string()]);
+    }
+
+    #[test]
+    fn changed_fields_are_mismatches() {
+        let ours = vec![registration()];
+        let mut fields = registration().get_fields().to_vec();
+        fields[0] ^= 1;
+        assert_eq!(mismatches(&ours, &[with_fields(fields)]),
+                   vec!["routesrv.Registration".to_string()]);
+    }
+
+    #[test]
+    fn fingerprints_without_fields_are_compared_whole() {
+        let ours = vec![registration()];
+        let mut theirs = registration();
+        theirs.clear_fields();
+        assert!(mismatches(&ours, &[theirs.clone()]).is_empty());
+        theirs.set_fingerprint(0);
+        assert_eq!(mismatches(&ours, &[theirs]),
+                   vec!["routesrv.Registration".to_string()]);
+    }
+}
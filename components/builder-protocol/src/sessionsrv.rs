@@ -13,13 +13,113 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::fmt;
+use std::result;
+use std::str::FromStr;
 
 use rustc_serialize::json::{Json, ToJson};
 
 use message::{Persistable, Routable};
+use sharding::InstaId;
 
 pub use message::sessionsrv::*;
 
+/// Scopes granted to sessions created by signing in. Tokens issued afterwards may be narrowed.
+pub const LOGIN_SCOPES: &'static [&'static str] = &["admin"];
+
+bitflags! {
+    /// Features an account may be enrolled in ahead of general availability, carried in the
+    /// `flags` of its `Account` and of its sessions.
+    pub flags FeatureFlags: u32 {
+        const EARLY_ACCESS = 0b00000001,
+        const BUILDER      = 0b00000010,
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    BadScope(String),
+}
+
+/// A permission granted to a session or API token.
+///
+/// * `read` - read access to anything the account can see
+/// * `upload:origin/<name>` - publish packages and keys to the named origin, or to every origin
+///   the account belongs to if the name is `*`. Implies `read`.
+/// * `admin` - everything, including managing origins, members, and tokens
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Scope {
+    Read,
+    Upload(String),
+    Admin,
+}
+
+impl Scope {
+    /// Whether holding this scope allows an action which requires the given scope.
+    pub fn permits(&self, required: &Scope) -> bool {
+        match (self, required) {
+            (&Scope::Admin, _) => true,
+            (&Scope::Upload(_), &Scope::Read) => true,
+            (&Scope::Upload(ref held), &Scope::Upload(ref origin)) => {
+                held == "*" || held == origin
+            }
+            (&Scope::Read, &Scope::Read) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Scope::Read => write!(f, "read"),
+            Scope::Upload(ref origin) => write!(f, "upload:origin/{}", origin),
+            Scope::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+impl FromStr for Scope {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value {
+            "read" => Ok(Scope::Read),
+            "admin" => Ok(Scope::Admin),
+            _ => {
+                let mut parts = value.splitn(2, ":origin/");
+                match (parts.next(), parts.next()) {
+                    (Some("upload"), Some(origin)) if !origin.is_empty() => {
+                        Ok(Scope::Upload(origin.to_string()))
+                    }
+                    _ => Err(Error::BadScope(value.to_string())),
+                }
+            }
+        }
+    }
+}
+
+impl Session {
+    /// Whether any of the session's scopes allows an action which requires the given scope.
+    /// Unrecognized scopes grant nothing.
+    pub fn permits(&self, required: &Scope) -> bool {
+        self.get_scopes()
+            .iter()
+            .filter_map(|s| s.parse::<Scope>().ok())
+            .any(|s| s.permits(required))
+    }
+
+    /// Features the session's account is enrolled in. Unrecognized flags are ignored.
+    pub fn feature_flags(&self) -> FeatureFlags {
+        FeatureFlags::from_bits_truncate(self.get_flags())
+    }
+
+    /// Whether the session's account is enrolled in every one of the given features.
+    pub fn has_features(&self, features: FeatureFlags) -> bool {
+        self.feature_flags().contains(features)
+    }
+}
+
 impl Routable for SessionCreate {
     type H = u64;
 
@@ -38,6 +138,14 @@ impl Routable for SessionGet {
     }
 }
 
+impl Routable for SessionRenew {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
 impl Persistable for Account {
     type Key = u64;
 
@@ -56,20 +164,41 @@ impl Into<Session> for Account {
         session.set_id(self.get_id());
         session.set_email(self.get_email().to_owned());
         session.set_name(self.get_name().to_owned());
+        session.set_flags(self.get_flags());
         session
     }
 }
 
+impl Into<Account> for Session {
+    fn into(self) -> Account {
+        let mut account = Account::new();
+        account.set_id(self.get_id());
+        account.set_email(self.get_email().to_owned());
+        account.set_name(self.get_name().to_owned());
+        account.set_flags(self.get_flags());
+        account
+    }
+}
+
 impl ToJson for Account {
     fn to_json(&self) -> Json {
         let mut m = BTreeMap::new();
         m.insert("id".to_string(), self.get_id().to_string().to_json());
         m.insert("name".to_string(), self.get_name().to_json());
         m.insert("email".to_string(), self.get_email().to_json());
+        m.insert("flags".to_string(), self.get_flags().to_json());
         Json::Object(m)
     }
 }
 
+impl Routable for AccountFlagsSet {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_account_id()))
+    }
+}
+
 impl Routable for AccountGet {
     type H = String;
 
@@ -90,6 +219,14 @@ impl Persistable for SessionToken {
     }
 }
 
+impl SessionToken {
+    /// Whether the session has expired at the given time, in seconds since the Unix epoch.
+    /// Sessions stored without an expiry never do.
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.has_expires_at() && self.get_expires_at() <= now
+    }
+}
+
 impl ToJson for Session {
     fn to_json(&self) -> Json {
         let mut m = BTreeMap::new();
@@ -97,6 +234,130 @@ impl ToJson for Session {
         m.insert("email".to_string(), self.get_email().to_json());
         m.insert("name".to_string(), self.get_name().to_json());
         m.insert("id".to_string(), self.get_id().to_json());
+        m.insert("scopes".to_string(), self.get_scopes().to_vec().to_json());
+        if self.has_expires_at() {
+            m.insert("expires_at".to_string(), self.get_expires_at().to_json());
+        }
+        m.insert("flags".to_string(), self.get_flags().to_json());
         Json::Object(m)
     }
 }
+
+impl Persistable for ApiToken {
+    type Key = u64;
+
+    fn primary_key(&self) -> Self::Key {
+        self.get_id()
+    }
+
+    fn set_primary_key(&mut self, value: Self::Key) {
+        self.set_id(value);
+    }
+}
+
+impl ToJson for ApiToken {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("id".to_string(), self.get_id().to_string().to_json());
+        if self.has_token() {
+            m.insert("token".to_string(), self.get_token().to_json());
+        }
+        m.insert("scopes".to_string(), self.get_scopes().to_vec().to_json());
+        m.insert("name".to_string(), self.get_name().to_json());
+        m.insert("description".to_string(), self.get_description().to_json());
+        Json::Object(m)
+    }
+}
+
+impl Routable for ApiTokenCreate {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_owner_id()))
+    }
+}
+
+impl Routable for ApiTokenListRequest {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_owner_id()))
+    }
+}
+
+impl ToJson for ApiTokenListResponse {
+    fn to_json(&self) -> Json {
+        let tokens: Vec<Json> = self.get_tokens().iter().map(|t| t.to_json()).collect();
+        let mut m = BTreeMap::new();
+        m.insert("tokens".to_string(), tokens.to_json());
+        Json::Object(m)
+    }
+}
+
+impl Routable for ApiTokenRevoke {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_owner_id()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Scope, Session, SessionToken, BUILDER, EARLY_ACCESS};
+    use protobuf::RepeatedField;
+
+    #[test]
+    fn parse_scopes() {
+        assert_eq!("read".parse::<Scope>().unwrap(), Scope::Read);
+        assert_eq!("admin".parse::<Scope>().unwrap(), Scope::Admin);
+        assert_eq!("upload:origin/core".parse::<Scope>().unwrap(),
+                   Scope::Upload("core".to_string()));
+        assert!("upload:origin/".parse::<Scope>().is_err());
+        assert!("write".parse::<Scope>().is_err());
+        assert_eq!(Scope::Upload("core".to_string()).to_string(),
+                   "upload:origin/core");
+    }
+
+    #[test]
+    fn scope_permits() {
+        let core = Scope::Upload("core".to_string());
+        let acme = Scope::Upload("acme".to_string());
+        assert!(Scope::Admin.permits(&core));
+        assert!(core.permits(&Scope::Read));
+        assert!(core.permits(&core));
+        assert!(!core.permits(&acme));
+        assert!(Scope::Upload("*".to_string()).permits(&acme));
+        assert!(!Scope::Read.permits(&core));
+        assert!(!core.permits(&Scope::Admin));
+    }
+
+    #[test]
+    fn session_permits() {
+        let mut session = Session::new();
+        assert!(!session.permits(&Scope::Read));
+        session.set_scopes(RepeatedField::from_vec(vec!["bogus".to_string(),
+                                                        "upload:origin/core".to_string()]));
+        assert!(session.permits(&Scope::Upload("core".to_string())));
+        assert!(!session.permits(&Scope::Admin));
+    }
+
+    #[test]
+    fn session_features() {
+        let mut session = Session::new();
+        assert!(!session.has_features(EARLY_ACCESS));
+        session.set_flags(EARLY_ACCESS.bits() | 0x80);
+        assert!(session.has_features(EARLY_ACCESS));
+        assert!(!session.has_features(EARLY_ACCESS | BUILDER));
+        assert_eq!(session.feature_flags(), EARLY_ACCESS);
+    }
+
+    #[test]
+    fn session_token_expiry() {
+        let mut token = SessionToken::new();
+        assert!(!token.is_expired(u64::max_value()));
+        token.set_expires_at(100);
+        assert!(!token.is_expired(99));
+        assert!(token.is_expired(100));
+    }
+}
@@ -5,6 +5,9 @@
 // is made available under an open source license such as the Apache 2.0 License.
 
 use std::collections::BTreeMap;
+use std::fmt;
+use std::result;
+use std::str::FromStr;
 
 use rustc_serialize::json::{Json, ToJson};
 
@@ -13,6 +16,48 @@ use sharding::InstaId;
 
 pub use message::vault::*;
 
+#[derive(Debug)]
+pub enum Error {
+    BadRole(String),
+}
+
+impl OriginRole {
+    /// Whether holding this role allows an action which requires the given role. Each role
+    /// includes everything the roles below it may do.
+    pub fn permits(&self, required: OriginRole) -> bool {
+        (*self as i32) >= (required as i32)
+    }
+}
+
+impl fmt::Display for OriginRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            OriginRole::READONLY => write!(f, "readonly"),
+            OriginRole::MAINTAINER => write!(f, "maintainer"),
+            OriginRole::OWNER => write!(f, "owner"),
+        }
+    }
+}
+
+impl FromStr for OriginRole {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value {
+            "readonly" => Ok(OriginRole::READONLY),
+            "maintainer" => Ok(OriginRole::MAINTAINER),
+            "owner" => Ok(OriginRole::OWNER),
+            _ => Err(Error::BadRole(value.to_string())),
+        }
+    }
+}
+
+impl ToJson for OriginRole {
+    fn to_json(&self) -> Json {
+        self.to_string().to_json()
+    }
+}
+
 impl Persistable for Origin {
     type Key = u64;
 
@@ -93,6 +138,72 @@ impl Routable for OriginSecretKeyCreate {
     }
 }
 
+impl Persistable for OriginSecret {
+    type Key = u64;
+
+    fn primary_key(&self) -> Self::Key {
+        self.get_id()
+    }
+
+    fn set_primary_key(&mut self, value: Self::Key) {
+        self.set_id(value);
+    }
+}
+
+impl Routable for OriginSecretCreate {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Routable for OriginSecretGet {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Routable for OriginSecretDelete {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Routable for OriginSecretListRequest {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl ToJson for OriginSecretValue {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("origin_id".to_string(),
+                 self.get_origin_id().to_string().to_json());
+        m.insert("name".to_string(), self.get_name().to_json());
+        m.insert("value".to_string(),
+                 String::from_utf8_lossy(self.get_value()).to_json());
+        Json::Object(m)
+    }
+}
+
+impl ToJson for OriginSecretListResponse {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("origin_id".to_string(),
+                 self.get_origin_id().to_string().to_json());
+        m.insert("names".to_string(), self.get_names().to_json());
+        Json::Object(m)
+    }
+}
+
 impl Routable for OriginInvitationCreate {
     type H = u64;
 
@@ -129,6 +240,7 @@ impl ToJson for OriginInvitation {
         m.insert("origin_name".to_string(), self.get_origin_name().to_json());
         m.insert("owner_id".to_string(),
                  self.get_owner_id().to_string().to_json());
+        m.insert("role".to_string(), self.get_role().to_json());
         Json::Object(m)
     }
 }
@@ -240,3 +352,68 @@ impl Routable for CheckOriginAccessRequest {
         Some(self.get_account_id())
     }
 }
+
+impl Routable for OriginMemberRoleSet {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl ToJson for OriginMemberRole {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("origin_id".to_string(),
+                 self.get_origin_id().to_string().to_json());
+        m.insert("account_id".to_string(),
+                 self.get_account_id().to_string().to_json());
+        m.insert("account_name".to_string(),
+                 self.get_account_name().to_json());
+        m.insert("role".to_string(), self.get_role().to_json());
+        Json::Object(m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CheckOriginAccessResponse, OriginRole};
+
+    #[test]
+    fn parse_roles() {
+        assert_eq!("owner".parse::<OriginRole>().unwrap(), OriginRole::OWNER);
+        assert_eq!("maintainer".parse::<OriginRole>().unwrap(),
+                   OriginRole::MAINTAINER);
+        assert_eq!("readonly".parse::<OriginRole>().unwrap(), OriginRole::READONLY);
+        assert!("member".parse::<OriginRole>().is_err());
+        assert_eq!(OriginRole::MAINTAINER.to_string(), "maintainer");
+    }
+
+    #[test]
+    fn role_permits() {
+        use self::OriginRole::*;
+        // held role, then whether it permits readonly, maintainer and owner actions
+        let matrix = [(READONLY, [true, false, false]),
+                      (MAINTAINER, [true, true, false]),
+                      (OWNER, [true, true, true])];
+        let required_roles = [READONLY, MAINTAINER, OWNER];
+        for &(held, expected) in matrix.iter() {
+            for (&required, &permitted) in required_roles.iter().zip(expected.iter()) {
+                assert_eq!(held.permits(required),
+                           permitted,
+                           "{} permits {}",
+                           held,
+                           required);
+            }
+        }
+    }
+
+    #[test]
+    fn unsent_access_role_is_maintainer() {
+        let mut resp = CheckOriginAccessResponse::new();
+        resp.set_has_access(true);
+        assert_eq!(resp.get_role(), OriginRole::MAINTAINER);
+        resp.set_role(OriginRole::READONLY);
+        assert_eq!(resp.get_role(), OriginRole::READONLY);
+    }
+}
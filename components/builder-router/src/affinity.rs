@@ -0,0 +1,139 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Affinity of sticky messages to the servers which first handled them.
+//!
+//! Several servers may register for the same shard. Messages are normally spread across them, but
+//! messages marked sticky are bound to the server which handled the first message with the same
+//! key and keep going there for as long as that server remains registered for the shard.
+
+use std::collections::HashMap;
+
+use hab_net;
+use protocol::net::Protocol;
+
+/// Most bindings kept at once. Once full, an arbitrary binding is forgotten to make room.
+pub const MAX_AFFINITIES: usize = 65_536;
+
+/// Which entity, or which client for messages without a route key, a binding is for.
+pub type AffinityKey = (Protocol, u64);
+
+pub struct AffinityTable {
+    bindings: HashMap<AffinityKey, String>,
+    capacity: usize,
+}
+
+impl AffinityTable {
+    pub fn new(capacity: usize) -> Self {
+        AffinityTable {
+            bindings: HashMap::new(),
+            capacity: capacity,
+        }
+    }
+
+    /// Index into `servers` of the server the key is bound to. A key which isn't bound, or whose
+    /// server is no longer among `servers`, is bound to `servers[fallback]` instead. `servers`
+    /// must not be empty.
+    pub fn select(&mut self,
+                  key: AffinityKey,
+                  servers: &[hab_net::ServerReg],
+                  fallback: usize)
+                  -> usize {
+        if let Some(endpoint) = self.bindings.get(&key) {
+            if let Some(i) = servers.iter().position(|s| &s.endpoint == endpoint) {
+                return i;
+            }
+        }
+        if !self.bindings.contains_key(&key) && self.bindings.len() >= self.capacity {
+            let evicted = self.bindings.keys().next().cloned();
+            if let Some(evicted) = evicted {
+                self.bindings.remove(&evicted);
+            }
+        }
+        self.bindings.insert(key, servers[fallback].endpoint.clone());
+        fallback
+    }
+
+    pub fn len(&self) -> usize {
+        self.bindings.len()
+    }
+}
+
+impl Default for AffinityTable {
+    fn default() -> Self {
+        AffinityTable::new(MAX_AFFINITIES)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use hab_net::ServerReg;
+    use protocol::net::Protocol;
+
+    use super::AffinityTable;
+
+    fn servers(endpoints: &[&str]) -> Vec<ServerReg> {
+        endpoints.iter().map(|e| ServerReg::new(e.to_string())).collect()
+    }
+
+    #[test]
+    fn select_binds_a_key_to_its_first_server() {
+        let mut table = AffinityTable::new(8);
+        let regs = servers(&["srv-1", "srv-2", "srv-3"]);
+        assert_eq!(table.select((Protocol::JobSrv, 7), &regs, 1), 1);
+        // Later messages with the same key stay on the bound server whatever the fallback
+        assert_eq!(table.select((Protocol::JobSrv, 7), &regs, 2), 1);
+        assert_eq!(table.select((Protocol::JobSrv, 7), &regs, 0), 1);
+        assert_eq!(table.len(), 1);
+    }
+
+    #[test]
+    fn select_follows_a_server_which_moved() {
+        let mut table = AffinityTable::new(8);
+        assert_eq!(table.select((Protocol::JobSrv, 7), &servers(&["srv-1", "srv-2"]), 1), 1);
+        // The bound server is found by endpoint, not by position
+        assert_eq!(table.select((Protocol::JobSrv, 7), &servers(&["srv-2", "srv-3"]), 1), 0);
+    }
+
+    #[test]
+    fn select_rebinds_a_key_whose_server_left() {
+        let mut table = AffinityTable::new(8);
+        assert_eq!(table.select((Protocol::JobSrv, 7), &servers(&["srv-1", "srv-2"]), 0), 0);
+        let remaining = servers(&["srv-2", "srv-3"]);
+        assert_eq!(table.select((Protocol::JobSrv, 7), &remaining, 1), 1);
+        assert_eq!(table.select((Protocol::JobSrv, 7), &remaining, 0), 1);
+        assert_eq!(table.len(), 1);
+    }
+
+    #[test]
+    fn keys_of_different_protocols_are_bound_apart() {
+        let mut table = AffinityTable::new(8);
+        let regs = servers(&["srv-1", "srv-2"]);
+        assert_eq!(table.select((Protocol::JobSrv, 7), &regs, 0), 0);
+        assert_eq!(table.select((Protocol::VaultSrv, 7), &regs, 1), 1);
+        assert_eq!(table.len(), 2);
+    }
+
+    #[test]
+    fn select_evicts_a_binding_once_full() {
+        let mut table = AffinityTable::new(2);
+        let regs = servers(&["srv-1", "srv-2"]);
+        table.select((Protocol::JobSrv, 1), &regs, 0);
+        table.select((Protocol::JobSrv, 2), &regs, 1);
+        table.select((Protocol::JobSrv, 3), &regs, 0);
+        assert_eq!(table.len(), 2);
+        // Selecting a key which is already bound never evicts
+        table.select((Protocol::JobSrv, 3), &regs, 1);
+        assert_eq!(table.len(), 2);
+    }
+}
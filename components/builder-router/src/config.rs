@@ -14,12 +14,18 @@
 
 //! Configuration for a Habitat RouteSrv service
 
+use std::collections::BTreeMap;
 use std::net;
+use std::time::Duration;
 
-use hab_core::config::{ConfigFile, ParseInto};
+use hab_core;
+use hab_core::config::{parse_duration_or_legacy, ConfigFile, ParseInto};
+use hab_net::zap::ZapConfig;
 use toml;
 
+use denylist::DenyAction;
 use error::{Error, Result};
+use schema::SchemaAction;
 
 #[derive(Debug)]
 pub struct Config {
@@ -27,6 +33,28 @@ pub struct Config {
     pub listen_addr: net::SocketAddrV4,
     /// Port for receiving service heartbeats
     pub heartbeat_port: u16,
+    /// Authentication of connections to the listening sockets
+    pub zap: ZapConfig,
+    /// File the shard map is periodically saved to and restored from at startup
+    pub snapshot_path: Option<String>,
+    /// Time between saves of the shard map
+    pub snapshot_interval: Duration,
+    /// Time a server restored from a snapshot stays routable without registering again
+    pub snapshot_grace: Duration,
+    /// Originating identities whose messages are not routed
+    pub deny: Vec<String>,
+    /// What is done with messages from a denied identity
+    pub deny_action: DenyAction,
+    /// Originating identities allowed to change the deny-list at runtime
+    pub deny_admins: Vec<String>,
+    /// Messages per second each registered server may route, or zero for no cap
+    pub rate_cap: usize,
+    /// Rate caps for the servers of particular protocols, keyed by protocol name
+    pub rate_caps: BTreeMap<String, usize>,
+    /// Messages a server may route at once after being idle, or zero for one second's worth
+    pub rate_burst: usize,
+    /// What is done with a registration whose message schemas disagree with those of its peers
+    pub schema_action: SchemaAction,
 }
 
 impl Config {
@@ -51,6 +79,17 @@ impl Default for Config {
         Config {
             listen_addr: net::SocketAddrV4::new(net::Ipv4Addr::new(0, 0, 0, 0), 5562),
             heartbeat_port: 5563,
+            zap: ZapConfig::default(),
+            snapshot_path: None,
+            snapshot_interval: Duration::from_secs(30),
+            snapshot_grace: Duration::from_secs(30),
+            deny: vec![],
+            deny_action: DenyAction::default(),
+            deny_admins: vec![],
+            rate_cap: 0,
+            rate_caps: BTreeMap::new(),
+            rate_burst: 0,
+            schema_action: SchemaAction::default(),
         }
     }
 }
@@ -62,6 +101,41 @@ impl ConfigFile for Config {
         let mut cfg = Config::default();
         try!(toml.parse_into("cfg.listen_addr", &mut cfg.listen_addr));
         try!(toml.parse_into("cfg.heartbeat_port", &mut cfg.heartbeat_port));
+        try!(toml.parse_into("cfg.snapshot_path", &mut cfg.snapshot_path));
+        try!(parse_duration_or_legacy(&toml,
+                                      "cfg.snapshot_interval",
+                                      "cfg.snapshot_interval_ms",
+                                      &mut cfg.snapshot_interval));
+        try!(parse_duration_or_legacy(&toml,
+                                      "cfg.snapshot_grace",
+                                      "cfg.snapshot_grace_ms",
+                                      &mut cfg.snapshot_grace));
+        try!(toml.parse_into("cfg.deny", &mut cfg.deny));
+        try!(toml.parse_into("cfg.deny_admins", &mut cfg.deny_admins));
+        try!(toml.parse_into("cfg.rate_cap", &mut cfg.rate_cap));
+        try!(toml.parse_into("cfg.rate_caps", &mut cfg.rate_caps));
+        try!(toml.parse_into("cfg.rate_burst", &mut cfg.rate_burst));
+        let mut deny_action = String::new();
+        if try!(toml.parse_into("cfg.deny_action", &mut deny_action)) {
+            match DenyAction::from_name(&deny_action) {
+                Some(action) => cfg.deny_action = action,
+                None => {
+                    let err = hab_core::Error::ConfigInvalidString("cfg.deny_action");
+                    return Err(Error::from(err));
+                }
+            }
+        }
+        let mut schema_action = String::new();
+        if try!(toml.parse_into("cfg.schema_action", &mut schema_action)) {
+            match SchemaAction::from_name(&schema_action) {
+                Some(action) => cfg.schema_action = action,
+                None => {
+                    let err = hab_core::Error::ConfigInvalidString("cfg.schema_action");
+                    return Err(Error::from(err));
+                }
+            }
+        }
+        try!(toml.parse_into("cfg.zap", &mut cfg.zap));
         Ok(cfg)
     }
 }
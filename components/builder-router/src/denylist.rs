@@ -0,0 +1,184 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Originating identities a RouteSrv refuses to route messages from.
+//!
+//! A client which floods the mesh, through a bug or because it was compromised, can be cut off at
+//! the router without touching the client or the services it floods. Its identity, the first hop
+//! of every message it sends, is put on the deny-list, through the router's configuration or at
+//! runtime with a `DenyListUpdate`. From then on its messages are dropped, or answered with an
+//! `ACCESS_DENIED` error, and counted.
+//!
+//! Only the identities listed in the router's `deny_admins` may send a `DenyListUpdate`. As any
+//! client can pick its own identity, a router taking runtime updates should also authenticate its
+//! clients with ZAP.
+
+use std::collections::HashMap;
+
+use protocol::routesrv;
+
+/// What a router does with a message from a denied identity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DenyAction {
+    /// Drop the message without a reply
+    Drop,
+    /// Answer the message with an `ACCESS_DENIED` error
+    Reject,
+}
+
+impl DenyAction {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "drop" => Some(DenyAction::Drop),
+            "reject" => Some(DenyAction::Reject),
+            _ => None,
+        }
+    }
+}
+
+impl Default for DenyAction {
+    fn default() -> Self {
+        DenyAction::Drop
+    }
+}
+
+/// Denied identities and the number of messages blocked from each since it was denied.
+#[derive(Debug, Default)]
+pub struct DenyList {
+    blocked: HashMap<String, u64>,
+    // Identities allowed to update the deny-list
+    admins: Vec<String>,
+}
+
+impl DenyList {
+    pub fn new(idents: &[String], admins: &[String]) -> Self {
+        let mut list = DenyList::default();
+        for ident in idents {
+            list.deny(ident);
+        }
+        list.admins = admins.to_vec();
+        list
+    }
+
+    /// Whether the identity may change the deny-list with a `DenyListUpdate`.
+    pub fn is_admin(&self, ident: &[u8]) -> bool {
+        self.admins.iter().any(|admin| admin.as_bytes() == ident)
+    }
+
+    /// Deny messages from the identity. Denying an identity twice keeps its count.
+    pub fn deny(&mut self, ident: &str) {
+        self.blocked.entry(ident.to_string()).or_insert(0);
+    }
+
+    /// Route messages from the identity again. Returns false if it wasn't denied.
+    pub fn allow(&mut self, ident: &str) -> bool {
+        self.blocked.remove(ident).is_some()
+    }
+
+    /// Whether messages from the identity are denied. A denied message is counted as blocked,
+    /// and the number of messages blocked from the identity so far is returned.
+    pub fn check(&mut self, ident: &[u8]) -> Option<u64> {
+        if self.blocked.is_empty() {
+            return None;
+        }
+        let ident = match ::std::str::from_utf8(ident) {
+            Ok(ident) => ident,
+            Err(_) => return None,
+        };
+        match self.blocked.get_mut(ident) {
+            Some(count) => {
+                *count += 1;
+                Some(*count)
+            }
+            None => None,
+        }
+    }
+
+    /// Apply a `DenyListUpdate`, denying its `deny` identities and then allowing its `allow` ones.
+    pub fn update(&mut self, req: &routesrv::DenyListUpdate) {
+        for ident in req.get_deny() {
+            info!("denying messages from identity, ident={}", ident);
+            self.deny(ident);
+        }
+        for ident in req.get_allow() {
+            if self.allow(ident) {
+                info!("allowing messages from identity, ident={}", ident);
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.blocked.len()
+    }
+
+    /// The deny-list as a protocol message, sorted by identity.
+    pub fn to_proto(&self) -> routesrv::DenyList {
+        let mut idents: Vec<&String> = self.blocked.keys().collect();
+        idents.sort();
+        let mut list = routesrv::DenyList::new();
+        for ident in idents {
+            let mut entry = routesrv::DenyEntry::new();
+            entry.set_ident(ident.clone());
+            entry.set_blocked(self.blocked[ident]);
+            list.mut_entries().push(entry);
+        }
+        list
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use protocol::routesrv;
+    use protobuf::RepeatedField;
+
+    use super::DenyList;
+
+    #[test]
+    fn check_counts_messages_from_denied_identities() {
+        let mut list = DenyList::new(&["flooder".to_string()], &[]);
+        assert_eq!(list.check(b"flooder"), Some(1));
+        assert_eq!(list.check(b"flooder"), Some(2));
+        assert_eq!(list.check(b"gentle"), None);
+        assert_eq!(list.check(&[0xff, 0xfe]), None);
+        assert_eq!(DenyList::default().check(b"flooder"), None);
+    }
+
+    #[test]
+    fn update_denies_then_allows() {
+        let mut list = DenyList::new(&["flooder".to_string()], &[]);
+        list.check(b"flooder");
+        let mut req = routesrv::DenyListUpdate::new();
+        req.set_deny(RepeatedField::from_vec(vec!["flooder".to_string(), "noisy".to_string()]));
+        list.update(&req);
+        // Denying an identity again keeps its count
+        assert_eq!(list.check(b"flooder"), Some(2));
+        assert_eq!(list.check(b"noisy"), Some(1));
+
+        let mut req = routesrv::DenyListUpdate::new();
+        req.set_deny(RepeatedField::from_vec(vec!["noisy".to_string()]));
+        req.set_allow(RepeatedField::from_vec(vec!["noisy".to_string(), "flooder".to_string()]));
+        list.update(&req);
+        assert_eq!(list.check(b"noisy"), None);
+        assert_eq!(list.check(b"flooder"), None);
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn only_admins_may_update() {
+        let list = DenyList::new(&[], &["operator".to_string()]);
+        assert!(list.is_admin(b"operator"));
+        assert!(!list.is_admin(b"flooder"));
+        assert!(!DenyList::default().is_admin(b"operator"));
+    }
+}
@@ -29,7 +29,6 @@ pub enum Error {
     IO(io::Error),
     NetError(hab_net::Error),
     Protobuf(protobuf::ProtobufError),
-    Zmq(zmq::Error),
 }
 
 pub type Result<T> = result::Result<T, Error>;
@@ -42,7 +41,6 @@ impl fmt::Display for Error {
             Error::IO(ref e) => format!("{}", e),
             Error::NetError(ref e) => format!("{}", e),
             Error::Protobuf(ref e) => format!("{}", e),
-            Error::Zmq(ref e) => format!("{}", e),
         };
         write!(f, "{}", msg)
     }
@@ -56,7 +54,6 @@ impl error::Error for Error {
             Error::IO(ref err) => err.description(),
             Error::NetError(ref err) => err.description(),
             Error::Protobuf(ref err) => err.description(),
-            Error::Zmq(ref err) => err.description(),
         }
     }
 }
@@ -87,6 +84,6 @@ impl From<protobuf::ProtobufError> for Error {
 
 impl From<zmq::Error> for Error {
     fn from(err: zmq::Error) -> Self {
-        Error::Zmq(err)
+        Error::NetError(hab_net::Error::from(err))
     }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+extern crate fnv;
 extern crate habitat_builder_dbcache as dbcache;
 extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hab_core;
@@ -20,13 +21,21 @@ extern crate habitat_net as hab_net;
 extern crate log;
 extern crate protobuf;
 extern crate rand;
+#[cfg(test)]
+extern crate tempdir;
 extern crate toml;
 #[macro_use]
 extern crate zmq;
 
+pub mod affinity;
 pub mod config;
+pub mod denylist;
 pub mod error;
+pub mod load;
+pub mod schema;
 pub mod server;
+pub mod snapshot;
+pub mod throttle;
 
 pub use self::config::Config;
 pub use self::error::{Error, Result};
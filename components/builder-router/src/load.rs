@@ -0,0 +1,180 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The load each server registered with a RouteSrv reports in its heartbeats.
+//!
+//! Every `PING_INTERVAL` a server sends each router it registered with a `Ping` carrying a snapshot
+//! of its worker utilization and queue depth. The router keeps the latest one of every registered
+//! server, forgetting it when the server is unregistered, and answers `ServerLoadGet` with them so
+//! operators can see which servers of a protocol are saturated.
+
+use std::collections::HashMap;
+
+use protocol::net::{Ping, Protocol};
+use protocol::routesrv;
+
+/// The latest load reported by each registered server, keyed by the identity of the heartbeat
+/// socket it registered over. Pings are attributed to the endpoint that socket registered rather
+/// than the one they name, so a server can't report load for another.
+#[derive(Debug, Default)]
+pub struct LoadTable {
+    endpoints: HashMap<Vec<u8>, String>,
+    loads: HashMap<Vec<u8>, routesrv::ServerLoad>,
+}
+
+impl LoadTable {
+    /// Attribute the pings of the heartbeat socket with the given identity to the endpoint it
+    /// registered, in place of any socket which registered the endpoint before.
+    pub fn bind(&mut self, ident: &[u8], endpoint: &str) {
+        self.forget(endpoint);
+        self.endpoints.insert(ident.to_vec(), endpoint.to_string());
+    }
+
+    /// The endpoint the heartbeat socket with the given identity registered, if any.
+    pub fn endpoint_of(&self, ident: &[u8]) -> Option<&str> {
+        self.endpoints.get(ident).map(|endpoint| endpoint.as_str())
+    }
+
+    /// Record the load reported in a heartbeat ping from the socket with the given identity, whose
+    /// server registered with the given protocol. Pings without a load, or from a socket which
+    /// registered no endpoint, are ignored.
+    pub fn record(&mut self, ident: &[u8], protocol: Protocol, ping: &Ping, now_ms: i64) {
+        if !ping.has_load() {
+            return;
+        }
+        let endpoint = match self.endpoints.get(ident) {
+            Some(endpoint) => endpoint.clone(),
+            None => return,
+        };
+        let mut load = routesrv::ServerLoad::new();
+        load.set_endpoint(endpoint);
+        load.set_protocol(protocol);
+        load.set_load(ping.get_load().clone());
+        load.set_received_at_ms(now_ms);
+        self.loads.insert(ident.to_vec(), load);
+    }
+
+    /// Forget the load of an unregistered server.
+    pub fn forget(&mut self, endpoint: &str) {
+        let idents: Vec<Vec<u8>> = self.endpoints
+            .iter()
+            .filter(|&(_, e)| e == endpoint)
+            .map(|(ident, _)| ident.clone())
+            .collect();
+        for ident in idents {
+            self.endpoints.remove(&ident);
+            self.loads.remove(&ident);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.loads.len()
+    }
+
+    /// The table as a protocol message, sorted by endpoint.
+    pub fn to_proto(&self) -> routesrv::ServerLoads {
+        let mut servers: Vec<&routesrv::ServerLoad> = self.loads.values().collect();
+        servers.sort_by(|a, b| a.get_endpoint().cmp(b.get_endpoint()));
+        let mut loads = routesrv::ServerLoads::new();
+        for server in servers {
+            loads.mut_servers().push(server.clone());
+        }
+        loads
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use protocol::net::{Ping, Protocol};
+
+    use super::*;
+
+    fn ping(endpoint: &str, busy: u32) -> Ping {
+        let mut ping = Ping::new();
+        ping.set_endpoint(endpoint.to_string());
+        ping.mut_load().set_workers(4);
+        ping.mut_load().set_busy_workers(busy);
+        ping
+    }
+
+    #[test]
+    fn records_the_latest_load_of_registered_sockets() {
+        let mut table = LoadTable::default();
+        table.bind(b"hb#jobsrv-1", "jobsrv-1");
+        table.record(b"hb#jobsrv-1", Protocol::JobSrv, &ping("jobsrv-1", 1), 10);
+        table.record(b"hb#jobsrv-1", Protocol::JobSrv, &ping("jobsrv-1", 3), 20);
+        let loads = table.to_proto();
+        assert_eq!(loads.get_servers().len(), 1);
+        let load = &loads.get_servers()[0];
+        assert_eq!(load.get_endpoint(), "jobsrv-1");
+        assert_eq!(load.get_protocol(), Protocol::JobSrv);
+        assert_eq!(load.get_load().get_busy_workers(), 3);
+        assert_eq!(load.get_received_at_ms(), 20);
+    }
+
+    #[test]
+    fn ignores_pings_without_a_load_or_registration() {
+        let mut table = LoadTable::default();
+        table.bind(b"hb#jobsrv-1", "jobsrv-1");
+        table.record(b"hb#jobsrv-1", Protocol::JobSrv, &Ping::new(), 10);
+        table.record(b"hb#jobsrv-2", Protocol::JobSrv, &ping("jobsrv-2", 1), 10);
+        assert_eq!(table.len(), 0);
+        assert_eq!(table.endpoint_of(b"hb#jobsrv-2"), None);
+    }
+
+    #[test]
+    fn attributes_pings_to_the_registered_endpoint() {
+        let mut table = LoadTable::default();
+        table.bind(b"hb#jobsrv-1", "jobsrv-1");
+        table.record(b"hb#jobsrv-1", Protocol::JobSrv, &ping("vault-1", 1), 10);
+        assert_eq!(table.to_proto().get_servers()[0].get_endpoint(), "jobsrv-1");
+    }
+
+    #[test]
+    fn forgets_unregistered_servers() {
+        let mut table = LoadTable::default();
+        table.bind(b"hb#jobsrv-1", "jobsrv-1");
+        table.bind(b"hb#vault-1", "vault-1");
+        table.record(b"hb#jobsrv-1", Protocol::JobSrv, &ping("jobsrv-1", 1), 10);
+        table.record(b"hb#vault-1", Protocol::VaultSrv, &ping("vault-1", 1), 10);
+        table.forget("jobsrv-1");
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.endpoint_of(b"hb#jobsrv-1"), None);
+        assert_eq!(table.to_proto().get_servers()[0].get_endpoint(), "vault-1");
+    }
+
+    #[test]
+    fn rebinding_an_endpoint_replaces_its_socket() {
+        let mut table = LoadTable::default();
+        table.bind(b"hb#old", "jobsrv-1");
+        table.record(b"hb#old", Protocol::JobSrv, &ping("jobsrv-1", 1), 10);
+        table.bind(b"hb#new", "jobsrv-1");
+        assert_eq!(table.len(), 0);
+        assert_eq!(table.endpoint_of(b"hb#old"), None);
+        assert_eq!(table.endpoint_of(b"hb#new"), Some("jobsrv-1"));
+    }
+
+    #[test]
+    fn lists_loads_by_endpoint() {
+        let mut table = LoadTable::default();
+        for endpoint in &["vault-1", "jobsrv-2", "jobsrv-1"] {
+            let ident = format!("hb#{}", endpoint);
+            table.bind(ident.as_bytes(), endpoint);
+            table.record(ident.as_bytes(), Protocol::JobSrv, &ping(endpoint, 1), 10);
+        }
+        let endpoints: Vec<&str> =
+            table.to_proto().get_servers().iter().map(|s| s.get_endpoint()).collect();
+        assert_eq!(endpoints, vec!["jobsrv-1", "jobsrv-2", "vault-1"]);
+    }
+}
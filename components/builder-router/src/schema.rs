@@ -0,0 +1,209 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checks of the message schemas of servers registering with a RouteSrv.
+//!
+//! Servers send the fingerprints of the messages they handle when they register, see
+//! `protocol::schema`. A registration is checked against the router's own fingerprints of the
+//! `net` and `routesrv` messages it handles itself, and against those of every other server
+//! registered for the same protocol. Mismatches are logged, and the registration is refused if
+//! the router is configured to. Servers which predate fingerprints send none and aren't checked.
+
+use std::collections::HashMap;
+
+use protocol::net::Protocol;
+use protocol::routesrv::{Registration, SchemaFingerprint};
+use protocol::schema;
+
+/// Packages of the messages a router handles itself.
+pub const ROUTER_PACKAGES: [&'static str; 2] = ["net", "routesrv"];
+
+/// What a router does with a registration whose schemas disagree with those of its peers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SchemaAction {
+    /// Log the mismatch and register the server anyway
+    Warn,
+    /// Refuse the registration
+    Reject,
+}
+
+impl SchemaAction {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "warn" => Some(SchemaAction::Warn),
+            "reject" => Some(SchemaAction::Reject),
+            _ => None,
+        }
+    }
+}
+
+impl Default for SchemaAction {
+    fn default() -> Self {
+        SchemaAction::Warn
+    }
+}
+
+/// The schema fingerprints of the router and of each registered server.
+#[derive(Debug)]
+pub struct SchemaTable {
+    ours: Vec<SchemaFingerprint>,
+    servers: HashMap<String, (Protocol, Vec<SchemaFingerprint>)>,
+}
+
+impl SchemaTable {
+    /// Messages the registration disagrees with the router or another server of its protocol on,
+    /// each followed by who it disagrees with. Empty if the schemas agree.
+    pub fn check(&self, registration: &Registration) -> Vec<String> {
+        let theirs = registration.get_schemas();
+        let mut mismatches: Vec<String> = schema::mismatches(theirs, &self.ours)
+            .into_iter()
+            .map(|name| format!("{} (router)", name))
+            .collect();
+        let mut peers: Vec<(&String, &Vec<SchemaFingerprint>)> = self.servers
+            .iter()
+            .filter(|&(endpoint, &(protocol, _))| {
+                protocol == registration.get_protocol() && endpoint != registration.get_endpoint()
+            })
+            .map(|(endpoint, &(_, ref schemas))| (endpoint, schemas))
+            .collect();
+        peers.sort_by(|a, b| a.0.cmp(b.0));
+        for (endpoint, schemas) in peers {
+            for name in schema::mismatches(theirs, schemas) {
+                mismatches.push(format!("{} ({})", name, endpoint));
+            }
+        }
+        mismatches
+    }
+
+    /// Remember the fingerprints of a registered server.
+    pub fn track(&mut self, registration: &Registration) {
+        if registration.get_schemas().is_empty() {
+            return;
+        }
+        self.servers.insert(registration.get_endpoint().to_string(),
+                            (registration.get_protocol(), registration.get_schemas().to_vec()));
+    }
+
+    /// Forget the fingerprints of an unregistered server.
+    pub fn forget(&mut self, endpoint: &str) {
+        self.servers.remove(endpoint);
+    }
+}
+
+impl Default for SchemaTable {
+    fn default() -> Self {
+        SchemaTable {
+            ours: schema::fingerprints(&ROUTER_PACKAGES),
+            servers: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use protocol::net::Protocol;
+    use protocol::routesrv::{Registration, SchemaFingerprint};
+    use protocol::schema;
+
+    use super::{SchemaAction, SchemaTable};
+
+    fn registration(endpoint: &str,
+                    protocol: Protocol,
+                    schemas: Vec<SchemaFingerprint>)
+                    -> Registration {
+        let mut registration = Registration::new();
+        registration.set_endpoint(endpoint.to_string());
+        registration.set_protocol(protocol);
+        registration.set_schemas(::protobuf::RepeatedField::from_vec(schemas));
+        registration
+    }
+
+    fn jobsrv_schemas() -> Vec<SchemaFingerprint> {
+        schema::fingerprints(&["net", "jobsrv"])
+    }
+
+    // The fingerprints with the one of the named message changed, as a build which disagrees on
+    // one of its existing fields would send them.
+    fn changed(mut schemas: Vec<SchemaFingerprint>, name: &str) -> Vec<SchemaFingerprint> {
+        for fingerprint in schemas.iter_mut().filter(|f| f.get_name() == name) {
+            let fingerprint_value = fingerprint.get_fingerprint();
+            fingerprint.set_fingerprint(fingerprint_value ^ 1);
+            fingerprint.mut_fields()[0] ^= 1;
+        }
+        schemas
+    }
+
+    #[test]
+    fn schema_action_from_name() {
+        assert_eq!(SchemaAction::from_name("warn"), Some(SchemaAction::Warn));
+        assert_eq!(SchemaAction::from_name("reject"), Some(SchemaAction::Reject));
+        assert_eq!(SchemaAction::from_name("ignore"), None);
+        assert_eq!(SchemaAction::default(), SchemaAction::Warn);
+    }
+
+    #[test]
+    fn registrations_are_checked_against_the_routers_own_messages() {
+        let table = SchemaTable::default();
+        let ok = registration("jobsrv-1", Protocol::JobSrv, jobsrv_schemas());
+        assert!(table.check(&ok).is_empty());
+        let bad = registration("jobsrv-1",
+                               Protocol::JobSrv,
+                               changed(jobsrv_schemas(), "net.Ping"));
+        assert_eq!(table.check(&bad), vec!["net.Ping (router)".to_string()]);
+    }
+
+    #[test]
+    fn registrations_are_checked_against_peers_of_their_protocol() {
+        let mut table = SchemaTable::default();
+        table.track(&registration("jobsrv-1", Protocol::JobSrv, jobsrv_schemas()));
+        table.track(&registration("vault-1",
+                                  Protocol::VaultSrv,
+                                  changed(jobsrv_schemas(), "jobsrv.Job")));
+        let bad = registration("jobsrv-2",
+                               Protocol::JobSrv,
+                               changed(jobsrv_schemas(), "jobsrv.Job"));
+        assert_eq!(table.check(&bad), vec!["jobsrv.Job (jobsrv-1)".to_string()]);
+        // A server registering again isn't compared with its own last registration.
+        let again = registration("jobsrv-1",
+                                 Protocol::JobSrv,
+                                 changed(jobsrv_schemas(), "jobsrv.Job"));
+        assert!(table.check(&again).is_empty());
+        table.forget("jobsrv-1");
+        assert!(table.check(&bad).is_empty());
+    }
+
+    #[test]
+    fn fields_added_by_a_newer_peer_are_not_mismatches() {
+        let mut table = SchemaTable::default();
+        table.track(&registration("jobsrv-1", Protocol::JobSrv, jobsrv_schemas()));
+        let mut newer = jobsrv_schemas();
+        for fingerprint in newer.iter_mut().filter(|f| f.get_name() == "jobsrv.Job") {
+            fingerprint.set_fingerprint(0);
+            fingerprint.mut_fields().push(99 << 32 | 12345);
+        }
+        assert!(table.check(&registration("jobsrv-2", Protocol::JobSrv, newer)).is_empty());
+    }
+
+    #[test]
+    fn servers_without_fingerprints_are_not_checked() {
+        let mut table = SchemaTable::default();
+        let old = registration("jobsrv-1", Protocol::JobSrv, vec![]);
+        assert!(table.check(&old).is_empty());
+        table.track(&old);
+        let other = registration("jobsrv-2",
+                                 Protocol::JobSrv,
+                                 changed(jobsrv_schemas(), "jobsrv.Job"));
+        assert!(table.check(&other).is_empty());
+    }
+}
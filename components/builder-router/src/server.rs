@@ -13,54 +13,101 @@
 // limitations under the License.
 
 use std::collections::{HashMap, HashSet};
+use std::hash::Hasher;
 use std::sync::{Arc, Mutex};
 
+use fnv::FnvHasher;
 use hab_net;
+use hab_net::clock::{self, Clock, SystemClock};
+use hab_net::codec::Header;
+use hab_net::debug;
+use hab_net::metrics::{self, Counter};
 use hab_net::server::{Application, Envelope};
+use hab_net::zap;
 use protobuf::{parse_from_bytes, Message};
 use protocol::{self, routesrv};
 use protocol::sharding::{ShardId, SHARD_COUNT};
-use protocol::net::{ErrCode, Protocol};
+use protocol::net::{ErrCode, Ping, Protocol, SUPPORTED_CAPABILITIES};
 use rand::{self, Rng};
 use zmq;
 
+use affinity::AffinityTable;
 use config::Config;
+use denylist::{DenyAction, DenyList};
 use error::{Error, Result};
+use load::LoadTable;
+use schema::{SchemaAction, SchemaTable};
+use snapshot;
+use throttle::Throttle;
 
-pub type ServerMap = HashMap<Protocol, HashMap<ShardId, hab_net::ServerReg>>;
+/// Servers registered for each shard of each protocol.
+pub type ServerMap = HashMap<Protocol, HashMap<ShardId, Vec<hab_net::ServerReg>>>;
 
 pub struct Server<'a> {
     config: Arc<Mutex<Config>>,
-    #[allow(dead_code)]
     ctx: Arc<Mutex<zmq::Context>>,
     fe_sock: zmq::Socket,
     hb_sock: zmq::Socket,
     servers: ServerMap,
+    affinity: AffinityTable,
+    denied: DenyList,
+    deny_action: DenyAction,
+    throttle: Throttle,
+    loads: LoadTable,
+    schemas: SchemaTable,
+    schema_action: SchemaAction,
+    // Whether the message being received came from a server over its rate cap
+    throttled: bool,
+    // Endpoints restored from a snapshot which haven't registered since, and when they expire
+    restored: HashMap<String, i64>,
+    snapshot_at: i64,
     active: HashSet<&'a hab_net::ServerReg>,
     state: SocketState,
     envelope: Envelope,
     req: zmq::Message,
     rng: rand::ThreadRng,
+    clock: Box<Clock + Send>,
 }
 
 impl<'a> Server<'a> {
     pub fn new(config: Config) -> Self {
+        Self::with_clock(config, Box::new(SystemClock))
+    }
+
+    /// Create a router whose rate caps, load reports and snapshot grace periods follow the
+    /// given clock.
+    pub fn with_clock(config: Config, clock: Box<Clock + Send>) -> Self {
         let mut ctx = zmq::Context::new();
         let fe_sock = ctx.socket(zmq::ROUTER).unwrap();
         let hb_sock = ctx.socket(zmq::ROUTER).unwrap();
         fe_sock.set_router_mandatory(true).unwrap();
         hb_sock.set_router_mandatory(true).unwrap();
+        let denied = DenyList::new(&config.deny, &config.deny_admins);
+        let deny_action = config.deny_action;
+        let throttle = Throttle::new(config.rate_cap, config.rate_caps.clone(), config.rate_burst);
+        let schema_action = config.schema_action;
         Server {
             config: Arc::new(Mutex::new(config)),
             ctx: Arc::new(Mutex::new(ctx)),
             fe_sock: fe_sock,
             hb_sock: hb_sock,
             servers: ServerMap::new(),
+            affinity: AffinityTable::default(),
+            denied: denied,
+            deny_action: deny_action,
+            throttle: throttle,
+            loads: LoadTable::default(),
+            schemas: SchemaTable::default(),
+            schema_action: schema_action,
+            throttled: false,
+            restored: HashMap::new(),
+            snapshot_at: 0,
             active: HashSet::new(),
             state: SocketState::default(),
             envelope: Envelope::default(),
             req: zmq::Message::new().unwrap(),
             rng: rand::thread_rng(),
+            clock: clock,
         }
     }
 
@@ -110,10 +157,21 @@ impl<'a> Server<'a> {
                 }
                 SocketState::Control => {
                     try!(self.fe_sock.recv(&mut self.req, 0));
-                    match self.req.as_str() {
-                        Some("RP") => self.state = SocketState::Forwarding,
-                        Some("RQ") => self.state = SocketState::Routing,
-                        _ => {
+                    match Header::from_bytes(&self.req) {
+                        Some(Header::Reply) => self.state = SocketState::Forwarding,
+                        Some(Header::Request) => {
+                            self.throttled = self.over_rate_cap();
+                            self.state = SocketState::Routing;
+                        }
+                        Some(Header::Forward) => {
+                            // The server handing the message on is the one its rate cap applies
+                            // to, so it is checked before the server is stripped from the hops.
+                            self.throttled = self.over_rate_cap();
+                            // A server handing a message on to another; replies go to the client.
+                            self.envelope.strip_sender();
+                            self.state = SocketState::Routing;
+                        }
+                        None => {
                             warn!("framing error");
                             self.state = SocketState::Cleaning;
                         }
@@ -137,8 +195,8 @@ impl<'a> Server<'a> {
                         continue;
                     }
                     debug!("received req, {:?}/{:?}", self.req.as_str(), self.req.len());
-                    match parse_from_bytes(&self.req) {
-                        Ok(msg) => self.envelope.msg = msg,
+                    match self.envelope.parse_frame(&self.req) {
+                        Ok(()) => (),
                         Err(e) => {
                             println!("failed to parse message, err={:?}", e);
                             self.state = SocketState::Cleaning;
@@ -151,6 +209,18 @@ impl<'a> Server<'a> {
                         self.state = SocketState::Cleaning;
                         continue;
                     }
+                    if try!(self.deny()) {
+                        self.state = SocketState::Cleaning;
+                        continue;
+                    }
+                    if self.throttled {
+                        try!(send_err(&mut self.fe_sock,
+                                      &self.envelope,
+                                      ErrCode::THROTTLED,
+                                      "rt:route:4"));
+                        self.state = SocketState::Cleaning;
+                        continue;
+                    }
                     match self.envelope.msg.get_route_info().get_protocol() {
                         Protocol::RouteSrv => try!(self.handle_message()),
                         _ => try!(self.route_message()),
@@ -167,60 +237,377 @@ impl<'a> Server<'a> {
         Ok(())
     }
 
+    // Handle one message from a server's heartbeat socket, which begins with the socket's
+    // identity: an empty probe sent when it connects, which is answered by asking it to register,
+    // `R` and its registration, or `P` and a ping. Every frame of the message is read, so a
+    // malformed one can't leave frames to be taken for the start of the next.
     fn process_heartbeat(&mut self) -> Result<()> {
         try!(self.hb_sock.recv(&mut self.req, 0));
-        match self.req.as_str() {
-            Some("") | None => return Ok(()),
-            Some("R") => (),
-            Some(ident) => {
-                try!(self.hb_sock.send_str(ident, zmq::SNDMORE));
-                try!(self.hb_sock.send(&[], zmq::SNDMORE));
-                try!(self.hb_sock.send_str("REG", 0));
-                return Ok(());
+        let ident = self.req.to_vec();
+        if !try!(self.hb_sock.get_rcvmore()) {
+            return Ok(());
+        }
+        try!(self.hb_sock.recv(&mut self.req, 0));
+        let more = try!(self.hb_sock.get_rcvmore());
+        let frame = self.req.as_str().map(String::from);
+        let result = match (frame.as_ref().map(|f| f.as_str()), more) {
+            (Some(""), false) => self.reply_heartbeat(&ident, "REG"),
+            (Some("R"), true) => self.process_registration(&ident),
+            (Some("P"), true) => self.process_ping(&ident),
+            (frame, _) => {
+                debug!("dropping unexpected heartbeat message, frame={:?}", frame);
+                Ok(())
             }
+        };
+        try!(self.drain_heartbeat());
+        result
+    }
+
+    // Replies go back to a server's heartbeat socket after its identity and an empty delimiter.
+    fn reply_heartbeat(&mut self, ident: &[u8], reply: &str) -> Result<()> {
+        try!(self.hb_sock.send(ident, zmq::SNDMORE));
+        try!(self.hb_sock.send(&[], zmq::SNDMORE));
+        try!(self.hb_sock.send_str(reply, 0));
+        Ok(())
+    }
+
+    // Discard what is left of the message being read from the heartbeat socket.
+    fn drain_heartbeat(&mut self) -> Result<()> {
+        while try!(self.hb_sock.get_rcvmore()) {
+            try!(self.hb_sock.recv(&mut self.req, 0));
         }
+        Ok(())
+    }
+
+    fn process_registration(&mut self, ident: &[u8]) -> Result<()> {
         try!(self.hb_sock.recv(&mut self.req, 0));
         // JW TODO: this datastructure doesn't support a case where a shard *no longer* supports
         // shards, it only allows for additions. We need to keep track of what any given server reg
         // supports and then use the servers map as an index on top of that.
-        let registration: routesrv::Registration = try!(parse_from_bytes(&self.req));
+        let registration: routesrv::Registration = match parse_from_bytes(&self.req) {
+            Ok(registration) => registration,
+            Err(e) => {
+                warn!("rejecting server reg, err={:?}", e);
+                return Ok(());
+            }
+        };
         debug!("received server reg, {:?}", registration);
+        let mismatches = self.schemas.check(&registration);
+        if !mismatches.is_empty() {
+            warn!("server's message schemas disagree with its peers', endpoint={}, messages={}",
+                  registration.get_endpoint(),
+                  mismatches.join(", "));
+            debug::set("router",
+                       &format!("schema.{}", registration.get_endpoint()),
+                       mismatches.join(", "));
+            if self.schema_action == SchemaAction::Reject {
+                let reply = format!("REGERR schema mismatch, {}", mismatches.join(", "));
+                return self.reply_heartbeat(ident, &reply);
+            }
+        }
+        if self.restored.remove(registration.get_endpoint()).is_some() {
+            // Replace the shards the snapshot listed for the server with those it serves now.
+            info!("restored server registered, endpoint={}", registration.get_endpoint());
+            self.unregister(registration.get_endpoint());
+        }
+        self.register(&registration);
+        self.loads.bind(ident, registration.get_endpoint());
+        // The router's capabilities follow `REGOK` in the same frame, so servers which predate
+        // them read it as the `REGOK` they expect.
+        self.reply_heartbeat(ident, &format!("REGOK {}", SUPPORTED_CAPABILITIES.bits()))
+    }
+
+    // Record the load a registered server reported in a heartbeat ping from the socket with the
+    // given identity. Pings from sockets which haven't registered a server are dropped, they'll
+    // report again once they have.
+    fn process_ping(&mut self, ident: &[u8]) -> Result<()> {
+        try!(self.hb_sock.recv(&mut self.req, 0));
+        let ping: Ping = match parse_from_bytes(&self.req) {
+            Ok(ping) => ping,
+            Err(e) => {
+                warn!("rejecting server ping, err={:?}", e);
+                return Ok(());
+            }
+        };
+        let endpoint = match self.loads.endpoint_of(ident) {
+            Some(endpoint) => endpoint.to_string(),
+            None => {
+                debug!("dropping ping from unregistered server, ident={}",
+                       String::from_utf8_lossy(ident));
+                return Ok(());
+            }
+        };
+        let protocol = match self.protocol_of(&endpoint) {
+            Some(protocol) => protocol,
+            None => {
+                debug!("dropping ping from unregistered server, endpoint={}", endpoint);
+                return Ok(());
+            }
+        };
+        self.loads.record(ident, protocol, &ping, self.clock.now_ms());
+        debug::set("router",
+                   &format!("load.{}", endpoint),
+                   format!("busy={}/{} queue_depth={}",
+                           ping.get_load().get_busy_workers(),
+                           ping.get_load().get_workers(),
+                           ping.get_load().get_queue_depth()));
+        Ok(())
+    }
+
+    // The protocol the server with the given endpoint registered for, if it is registered.
+    fn protocol_of(&self, endpoint: &str) -> Option<Protocol> {
+        self.servers
+            .iter()
+            .find(|&(_, shards)| {
+                shards.values().any(|servers| servers.iter().any(|s| s.endpoint == endpoint))
+            })
+            .map(|(protocol, _)| *protocol)
+    }
+
+    fn register(&mut self, registration: &routesrv::Registration) {
         if !self.servers.contains_key(&registration.get_protocol()) {
             self.servers.insert(registration.get_protocol(), HashMap::new());
         }
+        self.schemas.track(registration);
+        self.throttle.track(registration.get_endpoint(),
+                            registration.get_protocol(),
+                            self.clock.now_ms());
         let shards = self.servers.get_mut(&registration.get_protocol()).unwrap();
         for shard in registration.get_shards().iter() {
-            let server = hab_net::ServerReg::new(registration.get_endpoint().to_string());
-            shards.insert(*shard, server);
+            let endpoint = registration.get_endpoint().to_string();
+            let mut server = hab_net::ServerReg::with_clock(endpoint, &self.clock);
+            server.capabilities = registration.capabilities();
+            server.weight = registration.weight();
+            let servers = shards.entry(*shard).or_insert_with(Vec::new);
+            match servers.iter().position(|s| s.endpoint == server.endpoint) {
+                Some(i) => servers[i] = server,
+                None => servers.push(server),
+            }
+        }
+        debug::set("router",
+                   &format!("server.{}", registration.get_endpoint()),
+                   format!("protocol={:?} shards={} capabilities={:?} weight={}",
+                           registration.get_protocol(),
+                           registration.get_shards().len(),
+                           registration.capabilities(),
+                           registration.weight()));
+        debug::set("router",
+                   &format!("shards.{:?}", registration.get_protocol()),
+                   shards.len());
+    }
+
+    // Load the shard map saved by a previous router, if any. Its servers are routable until the
+    // grace period ends, by which time each must have registered again to stay in the map. A
+    // snapshot which can't be read is logged and the router starts with an empty map.
+    fn restore(&mut self) {
+        let (path, grace_ms) = {
+            let cfg = self.config.lock().unwrap();
+            match cfg.snapshot_path {
+                Some(ref path) => (path.clone(), clock::duration_ms(cfg.snapshot_grace)),
+                None => return,
+            }
+        };
+        let snapshot = match snapshot::load(&path) {
+            Ok(Some(snapshot)) => snapshot,
+            Ok(None) => return,
+            Err(e) => {
+                warn!("ignoring unreadable shard map snapshot, starting empty, path={}, err={}",
+                      path,
+                      e);
+                return;
+            }
+        };
+        let expires = self.clock.now_ms() + grace_ms;
+        for registration in snapshot.get_registrations() {
+            self.register(registration);
+            self.restored.insert(registration.get_endpoint().to_string(), expires);
+        }
+        info!("restored {} servers from shard map snapshot, path={}, age_ms={}",
+              self.restored.len(),
+              path,
+              self.clock.now_ms() - snapshot.get_taken_at_ms());
+        debug::set("router", "restored", self.restored.len());
+    }
+
+    // Save the shard map if a snapshot is due and drop restored servers whose grace period ended
+    // without them registering. Returns the milliseconds until this is next due, or -1 if
+    // snapshots are disabled.
+    fn maintain(&mut self) -> i64 {
+        let (path, interval_ms) = {
+            let cfg = self.config.lock().unwrap();
+            match cfg.snapshot_path {
+                Some(ref path) => (path.clone(), clock::duration_ms(cfg.snapshot_interval)),
+                None => return -1,
+            }
+        };
+        let now = self.clock.now_ms();
+        if now >= self.snapshot_at {
+            if let Err(e) = snapshot::save(&path, &snapshot::capture(&self.servers, now)) {
+                warn!("failed to save shard map snapshot, path={}, err={}", path, e);
+            }
+            self.snapshot_at = now + interval_ms;
+        }
+        let expired: Vec<String> = self.restored
+            .iter()
+            .filter(|&(_, expires)| *expires <= now)
+            .map(|(endpoint, _)| endpoint.clone())
+            .collect();
+        for endpoint in expired {
+            warn!("dropping restored server, no registration within grace period, endpoint={}",
+                  endpoint);
+            self.restored.remove(&endpoint);
+            self.unregister(&endpoint);
+            debug::remove("router", &format!("server.{}", endpoint));
+            debug::remove("router", &format!("load.{}", endpoint));
+        }
+        debug::set("router", "restored", self.restored.len());
+        self.snapshot_at - now
+    }
+
+    fn unregister(&mut self, endpoint: &str) {
+        self.throttle.forget(endpoint);
+        self.loads.forget(endpoint);
+        self.schemas.forget(endpoint);
+        for shards in self.servers.values_mut() {
+            for servers in shards.values_mut() {
+                servers.retain(|s| s.endpoint != endpoint);
+            }
         }
-        try!(self.hb_sock.send_str("REGOK", 0));
-        Ok(())
     }
 
     fn reset(&mut self) {
+        self.throttled = false;
         self.envelope.reset();
     }
 
+    // Take a token for the message being received from its sender's bucket. Returns true if the
+    // sender is a registered server over its rate cap.
+    fn over_rate_cap(&mut self) -> bool {
+        let throttled = match self.envelope.hops().first() {
+            Some(hop) => self.throttle.admit(hop, self.clock.now_ms()),
+            None => None,
+        };
+        match throttled {
+            Some(count) => {
+                let endpoint = String::from_utf8_lossy(&self.envelope.hops()[0]).into_owned();
+                debug!("throttling message, server over rate cap, endpoint={}", endpoint);
+                metrics::incr(Counter::Throttled);
+                debug::set("router", &format!("throttled.{}", endpoint), count);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Block the message if its originating identity is on the deny-list, and tell the sender if
+    // the router is configured to. Returns true if the message was blocked.
+    fn deny(&mut self) -> Result<bool> {
+        let blocked = match self.envelope.hops().first() {
+            Some(hop) => self.denied.check(hop),
+            None => None,
+        };
+        let count = match blocked {
+            Some(count) => count,
+            None => return Ok(false),
+        };
+        let ident = String::from_utf8_lossy(&self.envelope.hops()[0]).into_owned();
+        debug!("blocked message from denied identity, ident={}, msg={:?}",
+               ident,
+               self.envelope.msg);
+        debug::set("router", &format!("denied.{}", ident), count);
+        if self.deny_action == DenyAction::Reject {
+            try!(send_err(&mut self.fe_sock,
+                          &self.envelope,
+                          ErrCode::ACCESS_DENIED,
+                          "rt:route:3"));
+        }
+        Ok(true)
+    }
+
     fn handle_message(&mut self) -> Result<()> {
         let msg = &self.envelope.msg;
         debug!("handle-message, msg={:?}", &msg);
         match self.envelope.message_id() {
             "Connect" => {
-                let req: routesrv::Connect = parse_from_bytes(msg.get_body()).unwrap();
+                let req: routesrv::Connect = match parse_from_bytes(msg.get_body()) {
+                    Ok(req) => req,
+                    Err(e) => {
+                        warn!("failed to parse Connect, err={:?}", e);
+                        return Ok(());
+                    }
+                };
                 debug!("Connect={:?}", req);
+                // Brokers send a `Connect` while warming up, and count the router as up once
+                // this reply reaches them.
                 let rep = protocol::Message::new(&routesrv::ConnectOk::new()).build();
-                self.fe_sock.send(&rep.write_to_bytes().unwrap(), 0).unwrap();
+                try!(send_reply(&mut self.fe_sock, &self.envelope, &rep));
             }
             "Disconnect" => {
-                let req: routesrv::Disconnect = parse_from_bytes(msg.get_body()).unwrap();
+                let req: routesrv::Disconnect = match parse_from_bytes(msg.get_body()) {
+                    Ok(req) => req,
+                    Err(e) => {
+                        warn!("failed to parse Disconnect, err={:?}", e);
+                        return Ok(());
+                    }
+                };
                 debug!("Disconnect={:?}", req);
                 // JW TODO: handle service disconnection messages
             }
             "Registration" => {
-                let req: routesrv::Registration = parse_from_bytes(msg.get_body()).unwrap();
+                let req: routesrv::Registration = match parse_from_bytes(msg.get_body()) {
+                    Ok(req) => req,
+                    Err(e) => {
+                        warn!("failed to parse Registration, err={:?}", e);
+                        return Ok(());
+                    }
+                };
                 debug!("Registration={:?}", req);
                 // JW TODO: handle service server registration update messages
             }
+            "DebugGet" => {
+                let rep = protocol::Message::new(&debug::snapshot()).build();
+                try!(send_reply(&mut self.fe_sock, &self.envelope, &rep));
+            }
+            "DenyListUpdate" => {
+                let admin = match self.envelope.hops().first() {
+                    Some(hop) => self.denied.is_admin(hop),
+                    None => false,
+                };
+                if !admin {
+                    let ident = match self.envelope.hops().first() {
+                        Some(hop) => String::from_utf8_lossy(hop).into_owned(),
+                        None => String::new(),
+                    };
+                    warn!("refused DenyListUpdate from identity not in deny_admins, ident={}",
+                          ident);
+                    try!(send_err(&mut self.fe_sock,
+                                  &self.envelope,
+                                  ErrCode::ACCESS_DENIED,
+                                  "rt:deny:0"));
+                    return Ok(());
+                }
+                let req: routesrv::DenyListUpdate = match parse_from_bytes(msg.get_body()) {
+                    Ok(req) => req,
+                    Err(e) => {
+                        warn!("failed to parse DenyListUpdate, err={:?}", e);
+                        return Ok(());
+                    }
+                };
+                self.denied.update(&req);
+                let mut rep = protocol::Message::new(&self.denied.to_proto()).build();
+                correlate(&self.envelope, &mut rep);
+                try!(send_reply(&mut self.fe_sock, &self.envelope, &rep));
+            }
+            "DenyListGet" => {
+                let mut rep = protocol::Message::new(&self.denied.to_proto()).build();
+                correlate(&self.envelope, &mut rep);
+                try!(send_reply(&mut self.fe_sock, &self.envelope, &rep));
+            }
+            "ServerLoadGet" => {
+                let mut rep = protocol::Message::new(&self.loads.to_proto()).build();
+                correlate(&self.envelope, &mut rep);
+                try!(send_reply(&mut self.fe_sock, &self.envelope, &rep));
+            }
             id => warn!("Unknown message, msg={}", id),
         }
         Ok(())
@@ -231,7 +618,17 @@ impl<'a> Server<'a> {
         match self.servers.get(&self.envelope.protocol()) {
             Some(shards) => {
                 match shards.get(&shard) {
-                    Some(server) => {
+                    Some(servers) if !servers.is_empty() => {
+                        let pick = hab_net::server::select_weighted(servers, self.rng.gen());
+                        let i = if self.envelope.route_info().get_sticky() {
+                            let key = (self.envelope.protocol(), affinity_hash(&self.envelope));
+                            let i = self.affinity.select(key, servers, pick);
+                            debug::set("router", "affinities", self.affinity.len());
+                            i
+                        } else {
+                            pick
+                        };
+                        let server = &servers[i];
                         debug!("routing, srv={:?}, hops={:?}, msg={:?}",
                                server.endpoint,
                                self.envelope.hops().len(),
@@ -241,35 +638,24 @@ impl<'a> Server<'a> {
                             try!(self.fe_sock.send(&*hop, zmq::SNDMORE));
                         }
                         try!(self.fe_sock.send(&[], zmq::SNDMORE));
-                        try!(self.fe_sock.send(&self.envelope.msg.write_to_bytes().unwrap(), 0));
+                        // The message is routed unchanged, so the frame it arrived in is passed on
+                        // instead of being encoded again.
+                        try!(self.fe_sock.send(&*self.req, 0));
                     }
-                    None => {
+                    _ => {
                         warn!("failed to route message, no server servicing shard, msg={:?}",
                               self.envelope.msg);
-                        let err = protocol::Message::new(&protocol::net::err(ErrCode::NO_SHARD,
-                                                                             "rt:route:1"))
-                            .build();
-                        let bytes = try!(err.write_to_bytes());
-                        for hop in self.envelope.hops() {
-                            try!(self.fe_sock.send(&*hop, zmq::SNDMORE));
-                        }
-                        try!(self.fe_sock.send(&[], zmq::SNDMORE));
-                        try!(self.fe_sock.send(&bytes, 0));
+                        try!(send_err(&mut self.fe_sock,
+                                      &self.envelope,
+                                      ErrCode::NO_SHARD,
+                                      "rt:route:1"));
                     }
                 }
             }
             None => {
                 warn!("failed to route message, no servers registered for protocol, msg={:?}",
                       self.envelope.msg);
-                let err = protocol::Message::new(&protocol::net::err(ErrCode::NO_SHARD,
-                                                                     "rt:route:2"))
-                    .build();
-                let bytes = try!(err.write_to_bytes());
-                for hop in self.envelope.hops() {
-                    try!(self.fe_sock.send(&*hop, zmq::SNDMORE));
-                }
-                try!(self.fe_sock.send(&[], zmq::SNDMORE));
-                try!(self.fe_sock.send(&bytes, 0));
+                try!(send_err(&mut self.fe_sock, &self.envelope, ErrCode::NO_SHARD, "rt:route:2"));
             }
         }
         Ok(())
@@ -285,27 +671,80 @@ impl<'a> Server<'a> {
     }
 }
 
+// Send a reply back along the hops of the given message.
+fn send_reply(sock: &mut zmq::Socket,
+              envelope: &Envelope,
+              rep: &protocol::net::Msg)
+              -> Result<()> {
+    let bytes = try!(rep.write_to_bytes());
+    for hop in envelope.hops() {
+        try!(sock.send(&*hop, zmq::SNDMORE));
+    }
+    try!(sock.send(&[], zmq::SNDMORE));
+    try!(sock.send(&bytes, 0));
+    Ok(())
+}
+
+// Answer the given message with an error.
+fn send_err(sock: &mut zmq::Socket,
+            envelope: &Envelope,
+            code: ErrCode,
+            msg: &'static str)
+            -> Result<()> {
+    let mut err = protocol::Message::new(&protocol::net::err(code, msg)).build();
+    correlate(envelope, &mut err);
+    send_reply(sock, envelope, &err)
+}
+
+// Echo a request's correlation id in the error sent back in its place.
+fn correlate(envelope: &Envelope, err: &mut protocol::net::Msg) {
+    if envelope.msg.has_correlation_id() {
+        err.set_correlation_id(envelope.msg.get_correlation_id());
+    }
+}
+
+// Sticky messages with a route key stay with the entity it names. Those without one stay with the
+// client which sent them, identified by its first hop.
+fn affinity_hash(envelope: &Envelope) -> u64 {
+    let route_hash = envelope.route_info().get_hash();
+    if route_hash != 0 {
+        return route_hash;
+    }
+    let mut hasher = FnvHasher::default();
+    if let Some(hop) = envelope.hops().first() {
+        hasher.write(hop);
+    }
+    hasher.finish()
+}
+
 impl<'a> Application for Server<'a> {
     type Error = Error;
 
     fn run(&mut self) -> Result<()> {
         {
             let cfg = self.config.lock().unwrap();
+            try!(zap::start(&mut self.ctx.lock().unwrap(), cfg.zap.clone()));
+            try!(zap::apply(&self.hb_sock, &cfg.zap));
+            try!(zap::apply(&self.fe_sock, &cfg.zap));
             try!(self.hb_sock.bind(&cfg.hb_addr()));
             try!(self.fe_sock.bind(&cfg.fe_addr()));
             println!("Listening on ({})", cfg.fe_addr());
             println!("Heartbeat on ({})", cfg.hb_addr());
+            debug::set("router", "frontend", cfg.fe_addr());
+            debug::set("router", "heartbeat", cfg.hb_addr());
         }
+        self.restore();
         let mut hb_msg = false;
         let mut fe_msg = false;
         loop {
+            let timeout = self.maintain();
             {
                 let mut items = [self.hb_sock.as_poll_item(1), self.fe_sock.as_poll_item(1)];
                 // Poll until a message is received on either socket. Checking for the zmq::POLLIN
                 // flag on a poll item's revents will let you know if you have received a message
                 // or not on that socket.
                 // JW TODO: Implement service heartbeat and expiration
-                try!(zmq::poll(&mut items, -1));
+                try!(zmq::poll(&mut items, timeout));
                 if (items[0].get_revents() & zmq::POLLIN) > 0 {
                     hb_msg = true;
                 }
@@ -348,5 +787,110 @@ impl Default for SocketState {
 }
 
 pub fn run(config: Config) -> Result<()> {
+    debug::trap_signal();
     Server::new(config).run()
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::{mpsc, Arc};
+    use std::thread;
+    use std::time::Duration;
+
+    use hab_net::clock::ManualClock;
+    use hab_net::server::{recv_registration_event, send_registration, RegistrationEvent};
+    use protobuf::RepeatedField;
+    use protocol::{routesrv, schema};
+    use protocol::net::{Protocol, SUPPORTED_CAPABILITIES};
+    use zmq;
+
+    use config::Config;
+    use schema::SchemaAction;
+    use super::Server;
+
+    fn registration(schemas: Vec<routesrv::SchemaFingerprint>) -> routesrv::Registration {
+        let mut registration = routesrv::Registration::new();
+        registration.set_protocol(Protocol::JobSrv);
+        registration.set_endpoint("jobsrv-1".to_string());
+        registration.set_shards(vec![0, 1]);
+        registration.set_capabilities(SUPPORTED_CAPABILITIES.bits());
+        registration.set_schemas(RepeatedField::from_vec(schemas));
+        registration
+    }
+
+    // Register with a router listening for heartbeats on `addr` the way a service's heartbeat
+    // socket does, returning the router's reply and the protocol it then has the server down for.
+    fn register(addr: &'static str,
+                schema_action: SchemaAction,
+                registration: routesrv::Registration)
+                -> (RegistrationEvent, Option<Protocol>) {
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let mut config = Config::default();
+            config.schema_action = schema_action;
+            let mut server = Server::new(config);
+            server.hb_sock.bind(addr).unwrap();
+            // The probe the socket sends on connecting, then its registration.
+            server.process_heartbeat().unwrap();
+            server.process_heartbeat().unwrap();
+            tx.send(server.protocol_of("jobsrv-1")).unwrap();
+        });
+        let mut ctx = zmq::Context::new();
+        let sock = ctx.socket(zmq::DEALER).unwrap();
+        sock.set_identity(b"hb#jobsrv-1").unwrap();
+        sock.set_probe_router(true).unwrap();
+        sock.connect(addr).unwrap();
+        let mut msg = zmq::Message::new().unwrap();
+        assert_eq!(recv_registration_event(&sock, &mut msg).unwrap(),
+                   RegistrationEvent::Requested);
+        send_registration(&sock, &registration).unwrap();
+        let reply = recv_registration_event(&sock, &mut msg).unwrap();
+        (reply, rx.recv().unwrap())
+    }
+
+    #[test]
+    fn servers_register_over_the_heartbeat_socket() {
+        let schemas = schema::fingerprints(&["net", "jobsrv"]);
+        let (reply, protocol) = register("tcp://127.0.0.1:15581",
+                                         SchemaAction::Reject,
+                                         registration(schemas));
+        assert_eq!(reply, RegistrationEvent::Accepted(SUPPORTED_CAPABILITIES));
+        assert_eq!(protocol, Some(Protocol::JobSrv));
+    }
+
+    #[test]
+    fn registrations_whose_schemas_disagree_are_refused() {
+        let mut schemas = schema::fingerprints(&["net", "jobsrv"]);
+        for fingerprint in schemas.iter_mut().filter(|f| f.get_name() == "net.Ping") {
+            fingerprint.set_fingerprint(0);
+            fingerprint.clear_fields();
+        }
+        let (reply, protocol) = register("tcp://127.0.0.1:15582",
+                                         SchemaAction::Reject,
+                                         registration(schemas));
+        assert_eq!(reply,
+                   RegistrationEvent::Rejected("schema mismatch, net.Ping (router)".to_string()));
+        assert_eq!(protocol, None);
+    }
+
+    #[test]
+    fn restored_servers_are_dropped_once_their_grace_period_ends() {
+        let clock = Arc::new(ManualClock::new(0));
+        let mut config = Config::default();
+        config.snapshot_path = Some("/nonexistent/router.snapshot".to_string());
+        config.snapshot_grace = Duration::from_secs(30);
+        let mut server = Server::with_clock(config, Box::new(clock.clone()));
+        let mut registration = routesrv::Registration::new();
+        registration.set_protocol(Protocol::SessionSrv);
+        registration.set_endpoint("sessionsrv-1".to_string());
+        registration.set_shards(vec![0]);
+        server.register(&registration);
+        server.restored.insert("sessionsrv-1".to_string(), 30_000);
+        server.maintain();
+        assert_eq!(server.protocol_of("sessionsrv-1"), Some(Protocol::SessionSrv));
+        clock.advance(30_000);
+        server.maintain();
+        assert_eq!(server.protocol_of("sessionsrv-1"), None);
+        assert!(server.restored.is_empty());
+    }
+}
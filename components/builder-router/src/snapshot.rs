@@ -0,0 +1,157 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Snapshots of a RouteSrv's shard map for recovering from the loss of the router.
+//!
+//! A router configured with a snapshot path saves every registration it knows about to that file
+//! on an interval. A replacement router loads the file at startup and routes to the servers it
+//! names straight away instead of waiting for each of them to register again. Restored servers
+//! which don't register within the grace period are assumed gone and dropped from the map.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use protobuf::{parse_from_bytes, Message, RepeatedField};
+use protocol::net::Protocol;
+use protocol::routesrv::{Registration, ShardMap};
+
+use error::Result;
+use server::ServerMap;
+
+/// Capture the registrations in a server map.
+pub fn capture(servers: &ServerMap, taken_at_ms: i64) -> ShardMap {
+    let mut regs: HashMap<(String, Protocol), Registration> = HashMap::new();
+    for (protocol, shards) in servers.iter() {
+        for (shard, shard_servers) in shards.iter() {
+            for server in shard_servers.iter() {
+                let reg = regs.entry((server.endpoint.clone(), *protocol))
+                    .or_insert_with(|| {
+                        let mut reg = Registration::new();
+                        reg.set_protocol(*protocol);
+                        reg.set_endpoint(server.endpoint.clone());
+                        reg.set_capabilities(server.capabilities.bits());
+                        reg.set_weight(server.weight);
+                        reg
+                    });
+                reg.mut_shards().push(*shard);
+            }
+        }
+    }
+    let mut regs: Vec<Registration> = regs.into_iter().map(|(_, reg)| reg).collect();
+    for reg in regs.iter_mut() {
+        reg.mut_shards().sort();
+    }
+    regs.sort_by(|a, b| a.get_endpoint().cmp(b.get_endpoint()));
+    let mut snapshot = ShardMap::new();
+    snapshot.set_registrations(RepeatedField::from_vec(regs));
+    snapshot.set_taken_at_ms(taken_at_ms);
+    snapshot
+}
+
+/// Write a snapshot to the given path. The snapshot is written beside it first and moved into
+/// place so a crash never leaves a partial snapshot behind.
+pub fn save<P: AsRef<Path>>(path: P, snapshot: &ShardMap) -> Result<()> {
+    let path = path.as_ref();
+    let tmp = path.with_extension("tmp");
+    {
+        let mut file = try!(File::create(&tmp));
+        try!(file.write_all(&try!(snapshot.write_to_bytes())));
+        try!(file.sync_all());
+    }
+    try!(fs::rename(&tmp, path));
+    Ok(())
+}
+
+/// Read the snapshot at the given path, or `None` if none has been saved yet.
+pub fn load<P: AsRef<Path>>(path: P) -> Result<Option<ShardMap>> {
+    let mut file = match File::open(path.as_ref()) {
+        Ok(file) => file,
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let mut bytes = vec![];
+    try!(file.read_to_end(&mut bytes));
+    let snapshot = try!(parse_from_bytes(&bytes));
+    Ok(Some(snapshot))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::fs::File;
+    use std::io::Write;
+
+    use hab_net::ServerReg;
+    use protocol::net::{Protocol, TRACING};
+    use tempdir::TempDir;
+
+    use server::ServerMap;
+    use super::{capture, load, save};
+
+    fn srv_1() -> ServerReg {
+        let mut reg = ServerReg::new("srv-1".to_string());
+        reg.capabilities = TRACING;
+        reg.weight = 3;
+        reg
+    }
+
+    fn server_map() -> ServerMap {
+        let mut shards = HashMap::new();
+        shards.insert(4, vec![srv_1()]);
+        shards.insert(2, vec![srv_1(), ServerReg::new("srv-2".to_string())]);
+        let mut servers = HashMap::new();
+        servers.insert(Protocol::JobSrv, shards);
+        servers
+    }
+
+    #[test]
+    fn capture_groups_shards_by_server() {
+        let snapshot = capture(&server_map(), 42);
+        assert_eq!(snapshot.get_taken_at_ms(), 42);
+        let regs = snapshot.get_registrations();
+        assert_eq!(regs.len(), 2);
+        assert_eq!(regs[0].get_endpoint(), "srv-1");
+        assert_eq!(regs[0].get_protocol(), Protocol::JobSrv);
+        assert_eq!(regs[0].get_shards(), &[2, 4]);
+        assert_eq!(regs[0].get_capabilities(), TRACING.bits());
+        assert_eq!(regs[0].get_weight(), 3);
+        assert_eq!(regs[1].get_endpoint(), "srv-2");
+        assert_eq!(regs[1].get_shards(), &[2]);
+    }
+
+    #[test]
+    fn save_then_load_round_trips() {
+        let dir = TempDir::new("snapshot").unwrap();
+        let path = dir.path().join("shards.snapshot");
+        let snapshot = capture(&server_map(), 42);
+        save(&path, &snapshot).unwrap();
+        assert!(!path.with_extension("tmp").exists());
+        assert_eq!(load(&path).unwrap(), Some(snapshot));
+    }
+
+    #[test]
+    fn load_without_a_snapshot_is_none() {
+        let dir = TempDir::new("snapshot").unwrap();
+        assert_eq!(load(dir.path().join("shards.snapshot")).unwrap(), None);
+    }
+
+    #[test]
+    fn load_of_a_corrupt_snapshot_fails() {
+        let dir = TempDir::new("snapshot").unwrap();
+        let path = dir.path().join("shards.snapshot");
+        File::create(&path).unwrap().write_all(&[0xff, 0xff, 0xff]).unwrap();
+        assert!(load(&path).is_err());
+    }
+}
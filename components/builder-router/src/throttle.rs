@@ -0,0 +1,174 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Caps on the rate of messages a RouteSrv accepts from each registered server.
+//!
+//! Routers are shared by every service, so a server which sends more messages than the rest of
+//! the mesh can absorb, through a fault or a burst of work, can starve the others. Each registered
+//! server gets a token bucket which refills at its protocol's capped rate and holds up to the
+//! configured burst. A message routed by a server with an empty bucket is refused with a
+//! `THROTTLED` error instead.
+
+use std::collections::{BTreeMap, HashMap};
+use std::str;
+
+use protocol::net::Protocol;
+
+/// Rate caps and the token buckets of the servers they apply to.
+#[derive(Debug, Default)]
+pub struct Throttle {
+    // Messages per second each server may send, unless its protocol has a cap of its own. Zero
+    // leaves servers uncapped.
+    rate: usize,
+    // Caps keyed by protocol name, such as `JobSrv`
+    rates: BTreeMap<String, usize>,
+    // Messages a server may send at once after being idle, or zero for one second's worth
+    burst: usize,
+    buckets: HashMap<String, Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    rate: usize,
+    capacity: f64,
+    tokens: f64,
+    refilled_ms: i64,
+    throttled: u64,
+}
+
+impl Throttle {
+    pub fn new(rate: usize, rates: BTreeMap<String, usize>, burst: usize) -> Self {
+        Throttle {
+            rate: rate,
+            rates: rates,
+            burst: burst,
+            buckets: HashMap::new(),
+        }
+    }
+
+    /// Start capping the server registered with the given endpoint and protocol, if its protocol
+    /// has a cap. A server which registers again keeps its bucket.
+    pub fn track(&mut self, endpoint: &str, protocol: Protocol, now_ms: i64) {
+        let rate = self.rates.get(&format!("{:?}", protocol)).cloned().unwrap_or(self.rate);
+        if rate == 0 {
+            self.buckets.remove(endpoint);
+            return;
+        }
+        let capacity = if self.burst == 0 { rate as f64 } else { self.burst as f64 };
+        let bucket = self.buckets.entry(endpoint.to_string()).or_insert(Bucket {
+            rate: rate,
+            capacity: capacity,
+            tokens: capacity,
+            refilled_ms: now_ms,
+            throttled: 0,
+        });
+        bucket.rate = rate;
+        bucket.capacity = capacity;
+    }
+
+    /// Stop capping the server with the given endpoint.
+    pub fn forget(&mut self, endpoint: &str) {
+        self.buckets.remove(endpoint);
+    }
+
+    /// Take a token for a message from the given sender. Returns the number of messages refused
+    /// from the sender so far, counting this one, if it is over its cap. Senders which aren't
+    /// capped servers are always admitted.
+    pub fn admit(&mut self, sender: &[u8], now_ms: i64) -> Option<u64> {
+        if self.buckets.is_empty() {
+            return None;
+        }
+        let sender = match str::from_utf8(sender) {
+            Ok(sender) => sender,
+            Err(_) => return None,
+        };
+        let bucket = match self.buckets.get_mut(sender) {
+            Some(bucket) => bucket,
+            None => return None,
+        };
+        let elapsed_ms = now_ms - bucket.refilled_ms;
+        if elapsed_ms > 0 {
+            let refill = elapsed_ms as f64 * bucket.rate as f64 / 1000.0;
+            bucket.tokens = (bucket.tokens + refill).min(bucket.capacity);
+            bucket.refilled_ms = now_ms;
+        }
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            None
+        } else {
+            bucket.throttled += 1;
+            Some(bucket.throttled)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use protocol::net::Protocol;
+
+    use super::Throttle;
+
+    #[test]
+    fn admit_refuses_a_server_over_its_cap_until_its_bucket_refills() {
+        let mut throttle = Throttle::new(2, BTreeMap::new(), 0);
+        throttle.track("jobsrv-1", Protocol::JobSrv, 0);
+        assert_eq!(throttle.admit(b"jobsrv-1", 0), None);
+        assert_eq!(throttle.admit(b"jobsrv-1", 0), None);
+        assert_eq!(throttle.admit(b"jobsrv-1", 0), Some(1));
+        assert_eq!(throttle.admit(b"jobsrv-1", 100), Some(2));
+        // By half a second in, one token has been refilled at two a second
+        assert_eq!(throttle.admit(b"jobsrv-1", 500), None);
+        assert_eq!(throttle.admit(b"jobsrv-1", 500), Some(3));
+        // An idle server's bucket holds no more than its capacity
+        assert_eq!(throttle.admit(b"jobsrv-1", 60_000), None);
+        assert_eq!(throttle.admit(b"jobsrv-1", 60_000), None);
+        assert_eq!(throttle.admit(b"jobsrv-1", 60_000), Some(4));
+    }
+
+    #[test]
+    fn admit_uses_the_burst_and_protocol_caps() {
+        let mut rates = BTreeMap::new();
+        rates.insert("JobSrv".to_string(), 1);
+        let mut throttle = Throttle::new(10, rates, 3);
+        throttle.track("jobsrv-1", Protocol::JobSrv, 0);
+        throttle.track("vault-1", Protocol::VaultSrv, 0);
+        for _ in 0..3 {
+            assert_eq!(throttle.admit(b"jobsrv-1", 0), None);
+            assert_eq!(throttle.admit(b"vault-1", 0), None);
+        }
+        assert_eq!(throttle.admit(b"jobsrv-1", 0), Some(1));
+        assert_eq!(throttle.admit(b"vault-1", 0), Some(1));
+        // The vault falls under the default cap of ten a second, the job server its own of one
+        assert_eq!(throttle.admit(b"vault-1", 100), None);
+        assert_eq!(throttle.admit(b"jobsrv-1", 100), Some(2));
+    }
+
+    #[test]
+    fn admit_lets_uncapped_senders_through() {
+        let mut throttle = Throttle::new(0, BTreeMap::new(), 0);
+        throttle.track("jobsrv-1", Protocol::JobSrv, 0);
+        assert_eq!(throttle.admit(b"jobsrv-1", 0), None);
+
+        let mut throttle = Throttle::new(1, BTreeMap::new(), 0);
+        throttle.track("jobsrv-1", Protocol::JobSrv, 0);
+        assert_eq!(throttle.admit(b"jobsrv-1", 0), None);
+        assert_eq!(throttle.admit(b"client", 0), None);
+        assert_eq!(throttle.admit(b"client", 0), None);
+        throttle.forget("jobsrv-1");
+        assert_eq!(throttle.admit(b"jobsrv-1", 0), None);
+        assert_eq!(throttle.admit(b"jobsrv-1", 0), None);
+    }
+}
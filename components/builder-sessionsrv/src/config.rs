@@ -14,9 +14,14 @@
 //! Configuration for a Habitat SessionSrv service
 
 use std::net;
+use std::time::Duration;
 
-use hab_core::config::{ConfigFile, ParseInto};
-use hab_net::config::{RouteAddrs, Shards};
+use hab_core::config::{parse_duration_or_legacy, ConfigFile, ParseInto};
+use hab_net::config::{Keepalive, MessageAuth, Metrics, RouteAddrs, RouterAuth, Shards,
+                       Sockets, Weight};
+use hab_net::routing::KeepaliveConfig;
+use hab_net::sched::SchedConfig;
+use hab_net::zap::ZapCredentials;
 use num_cpus;
 use protocol::sharding::{ShardId, SHARD_COUNT};
 use redis;
@@ -33,8 +38,29 @@ pub struct Config {
     pub heartbeat_port: u16,
     /// List of shard identifiers serviced by the running service.
     pub shards: Vec<ShardId>,
+    /// Share of its shards' requests the service takes relative to other servers serving them.
+    pub weight: Weight,
     /// Number of threads to process queued messages.
     pub worker_threads: usize,
+    /// CPUs to pin worker threads to and their priority, read from `worker_cpus` and
+    /// `worker_nice`.
+    pub worker_sched: SchedConfig,
+    /// Address of a statsd server to push metrics to.
+    pub statsd_addr: Option<net::SocketAddrV4>,
+    /// Listening net address for a Prometheus metrics scrape endpoint.
+    pub metrics_addr: Option<net::SocketAddrV4>,
+    /// Shared secrets for authenticating routed messages, newest first.
+    pub hmac_keys: Vec<String>,
+    /// Credentials presented to routers which authenticate their clients.
+    pub router_auth: ZapCredentials,
+    /// How idle connections to routers are kept open and verified.
+    pub keepalive: KeepaliveConfig,
+    /// Time a session is valid for after it is created or renewed.
+    pub session_ttl: Duration,
+    /// Time between sweeps of the datastore for expired sessions.
+    pub session_prune_interval: Duration,
+    /// GitHub usernames of the accounts allowed to set the feature flags of other accounts.
+    pub admins: Vec<String>,
 }
 
 impl Default for Config {
@@ -44,7 +70,17 @@ impl Default for Config {
             datastore_addr: net::SocketAddrV4::new(net::Ipv4Addr::new(127, 0, 0, 1), 6379),
             heartbeat_port: 5563,
             shards: (0..SHARD_COUNT).collect(),
+            weight: Weight::default(),
             worker_threads: num_cpus::get(),
+            worker_sched: SchedConfig::default(),
+            statsd_addr: None,
+            metrics_addr: None,
+            hmac_keys: vec![],
+            router_auth: ZapCredentials::default(),
+            keepalive: KeepaliveConfig::default(),
+            session_ttl: Duration::from_secs(86400),
+            session_prune_interval: Duration::from_secs(3600),
+            admins: vec![],
         }
     }
 }
@@ -58,11 +94,57 @@ impl ConfigFile for Config {
         try!(toml.parse_into("cfg.datastore_addr", &mut cfg.datastore_addr));
         try!(toml.parse_into("cfg.heartbeat_port", &mut cfg.heartbeat_port));
         try!(toml.parse_into("cfg.shards", &mut cfg.shards));
+        try!(toml.parse_into("cfg", &mut cfg.weight));
         try!(toml.parse_into("cfg.worker_threads", &mut cfg.worker_threads));
+        cfg.worker_sched = try!(SchedConfig::from_toml(&toml,
+                                                       "cfg.worker_cpus",
+                                                       "cfg.worker_nice"));
+        try!(toml.parse_into("cfg.statsd_addr", &mut cfg.statsd_addr));
+        try!(toml.parse_into("cfg.metrics_addr", &mut cfg.metrics_addr));
+        try!(toml.parse_into("cfg.hmac_keys", &mut cfg.hmac_keys));
+        try!(toml.parse_into("cfg.router_auth", &mut cfg.router_auth));
+        try!(toml.parse_into("cfg", &mut cfg.keepalive));
+        try!(parse_duration_or_legacy(&toml,
+                                      "cfg.session_ttl",
+                                      "cfg.session_ttl_secs",
+                                      &mut cfg.session_ttl));
+        try!(parse_duration_or_legacy(&toml,
+                                      "cfg.session_prune_interval",
+                                      "cfg.session_prune_interval_secs",
+                                      &mut cfg.session_prune_interval));
+        try!(toml.parse_into("cfg.admins", &mut cfg.admins));
         Ok(cfg)
     }
 }
 
+impl Keepalive for Config {
+    fn keepalive(&self) -> KeepaliveConfig {
+        self.keepalive.clone()
+    }
+}
+
+impl MessageAuth for Config {
+    fn hmac_keys(&self) -> &[String] {
+        &self.hmac_keys
+    }
+}
+
+impl RouterAuth for Config {
+    fn router_auth(&self) -> ZapCredentials {
+        self.router_auth.clone()
+    }
+}
+
+impl Metrics for Config {
+    fn statsd_addr(&self) -> Option<net::SocketAddrV4> {
+        self.statsd_addr
+    }
+
+    fn metrics_addr(&self) -> Option<net::SocketAddrV4> {
+        self.metrics_addr
+    }
+}
+
 impl RouteAddrs for Config {
     fn route_addrs(&self) -> &Vec<net::SocketAddrV4> {
         &self.routers
@@ -77,8 +159,14 @@ impl Shards for Config {
     fn shards(&self) -> &Vec<u32> {
         &self.shards
     }
+
+    fn weight(&self) -> Weight {
+        self.weight
+    }
 }
 
+impl Sockets for Config {}
+
 impl<'a> redis::IntoConnectionInfo for &'a Config {
     fn into_connection_info(self) -> redis::RedisResult<redis::ConnectionInfo> {
         format!("redis://{}:{}",
@@ -12,12 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
+use std::ops::Deref;
 use std::sync::Arc;
 
 use dbcache::{self, Bucket, ConnectionPool, ExpiringSet, IndexSet, InstaSet};
-use protocol::sessionsrv;
+use protobuf::Message;
+use protocol::{sessionsrv, InstaId, Persistable};
 use r2d2_redis::RedisConnectionManager;
-use redis;
+use redis::{self, Commands, PipelineCommands};
+use rustc_serialize::hex::ToHex;
+use sodiumoxide::crypto::hash::sha256;
+use time;
 
 use error::Result;
 
@@ -25,6 +31,7 @@ pub struct DataStore {
     pub pool: Arc<ConnectionPool>,
     pub accounts: AccountTable,
     pub sessions: SessionTable,
+    pub api_tokens: ApiTokenTable,
 }
 
 impl DataStore {
@@ -35,14 +42,17 @@ impl DataStore {
         let pool = Arc::new(ConnectionPool::new(pool_cfg, manager).unwrap());
         let pool1 = pool.clone();
         let pool2 = pool.clone();
+        let pool3 = pool.clone();
 
         let accounts = AccountTable::new(pool1);
         let sessions = SessionTable::new(pool2);
+        let api_tokens = ApiTokenTable::new(pool3);
 
         Ok(DataStore {
             pool: pool,
             accounts: accounts,
             sessions: sessions,
+            api_tokens: api_tokens,
         })
     }
 }
@@ -119,6 +129,41 @@ impl SessionTable {
     pub fn new(pool: Arc<ConnectionPool>) -> Self {
         SessionTable { pool: pool }
     }
+
+    /// Remove a session so its token can no longer be used.
+    pub fn delete(&self, token: &str) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        try!(redis::pipe()
+            .atomic()
+            .del(Self::key(&token.to_string()))
+            .ignore()
+            .zrem(Self::expiry_index(), token)
+            .ignore()
+            .query(conn.deref()));
+        Ok(())
+    }
+
+    /// Remove every session which expired at or before the given time, in seconds since the
+    /// Unix epoch. Returns the number of sessions removed.
+    pub fn prune(&self, now: u64) -> dbcache::Result<usize> {
+        let conn = try!(self.pool().get());
+        let expired: Vec<String> = try!(conn.zrangebyscore(Self::expiry_index(), 0, now));
+        if expired.is_empty() {
+            return Ok(0);
+        }
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        for token in expired.iter() {
+            pipe.del(Self::key(token)).ignore().zrem(Self::expiry_index(), token).ignore();
+        }
+        try!(pipe.query(conn.deref()));
+        Ok(expired.len())
+    }
+
+    // Sorted set of session tokens scored by the time they expire.
+    fn expiry_index() -> &'static str {
+        "session_expiry"
+    }
 }
 
 impl Bucket for SessionTable {
@@ -137,6 +182,217 @@ impl ExpiringSet for SessionTable {
     fn expiry() -> usize {
         86400
     }
+
+    /// Write a session, keeping it in the datastore until it expires. Sessions without an expiry
+    /// are kept for the default `expiry`.
+    fn write(&self, record: &Self::Record) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        let ttl = if record.has_expires_at() {
+            session_ttl(record.get_expires_at(), time::get_time().sec as u64)
+        } else {
+            Self::expiry()
+        };
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .set_ex(Self::key(&record.primary_key()),
+                    record.write_to_bytes().unwrap(),
+                    ttl)
+            .ignore();
+        if record.has_expires_at() {
+            pipe.zadd(Self::expiry_index(),
+                      record.get_token(),
+                      record.get_expires_at())
+                .ignore();
+        }
+        try!(pipe.query(conn.deref()));
+        Ok(())
+    }
+}
+
+/// Seconds until a session expiring at `expires_at` lapses, never less than one so a session
+/// which has already expired is still written and lapses straight away.
+fn session_ttl(expires_at: u64, now: u64) -> usize {
+    cmp::max(expires_at.saturating_sub(now), 1) as usize
+}
+
+/// API tokens issued to accounts. Unlike sessions these don't expire; they last until revoked.
+///
+/// Only the SHA-256 digest of a token's secret is stored, in the record's `token` field and as
+/// the key of the index tokens are looked up by, so the secret is only ever known to the client
+/// it was issued to.
+pub struct ApiTokenTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl ApiTokenTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        ApiTokenTable { pool: pool }
+    }
+
+    /// Look up the API token with the given secret.
+    pub fn find_by_token(&self, token: &str) -> dbcache::Result<sessionsrv::ApiToken> {
+        let conn = try!(self.pool().get());
+        let id: Option<u64> = try!(conn.hget(Self::token_index(), digest(token)));
+        if let Some(id) = id {
+            return self.find(&id);
+        }
+        // Tokens issued before secrets were hashed are stored and indexed by the secret itself
+        // until they are next used.
+        let id: Option<u64> = try!(conn.hget(Self::token_index(), token));
+        match id {
+            Some(id) => {
+                let mut record = try!(self.find(&id));
+                try!(self.rehash(&mut record));
+                Ok(record)
+            }
+            None => Err(dbcache::Error::EntityNotFound),
+        }
+    }
+
+    /// Look up the API token of the given name issued to an account.
+    pub fn find_by_name(&self,
+                        account_id: u64,
+                        name: &str)
+                        -> dbcache::Result<sessionsrv::ApiToken> {
+        let conn = try!(self.pool().get());
+        let id: Option<u64> = try!(conn.hget(Self::names_key(account_id), name));
+        match id {
+            Some(id) => self.find(&id),
+            None => Err(dbcache::Error::EntityNotFound),
+        }
+    }
+
+    /// Return all API tokens issued to the given account.
+    pub fn get_by_account_id(&self,
+                             account_id: u64)
+                             -> dbcache::Result<Vec<sessionsrv::ApiToken>> {
+        let conn = try!(self.pool().get());
+        let ids = try!(conn.smembers::<String, Vec<u64>>(Self::account_key(account_id)));
+        let tokens = ids.iter().fold(Vec::new(), |mut acc, id| {
+            match self.find(id) {
+                Ok(token) => acc.push(token),
+                Err(e) => debug!("Can't find api token for id {}:{}", id, e),
+            }
+            acc
+        });
+        Ok(tokens)
+    }
+
+    /// Remove an API token so it can no longer be used to authenticate.
+    pub fn revoke(&self, token: &sessionsrv::ApiToken) -> dbcache::Result<()> {
+        // The stored record has the key the token is indexed by, whether or not the given one
+        // still carries the secret.
+        let stored = try!(self.find(&token.get_id()));
+        let conn = try!(self.pool().get());
+        try!(redis::pipe()
+            .atomic()
+            .del(Self::key(&token.get_id()))
+            .ignore()
+            .srem(Self::account_key(token.get_owner_id()), token.get_id())
+            .ignore()
+            .hdel(Self::token_index(), stored.get_token())
+            .ignore()
+            .hdel(Self::names_key(token.get_owner_id()), token.get_name())
+            .ignore()
+            .query(conn.deref()));
+        Ok(())
+    }
+
+    // Replace the secret a token issued before secrets were hashed is stored and indexed by
+    // with its digest.
+    fn rehash(&self, record: &mut sessionsrv::ApiToken) -> dbcache::Result<()> {
+        let secret = record.take_token();
+        record.set_token(digest(&secret));
+        let conn = try!(self.pool().get());
+        try!(redis::pipe()
+            .atomic()
+            .set(Self::key(&record.get_id()), record.write_to_bytes().unwrap())
+            .ignore()
+            .hdel(Self::token_index(), secret)
+            .ignore()
+            .hset(Self::token_index(), record.get_token(), record.get_id())
+            .ignore()
+            .query(conn.deref()));
+        Ok(())
+    }
+
+    fn account_key(account_id: u64) -> String {
+        format!("account_to_api_tokens:{}", account_id)
+    }
+
+    fn names_key(account_id: u64) -> String {
+        format!("account_to_api_token_names:{}", account_id)
+    }
+
+    fn token_index() -> &'static str {
+        "api_token2id"
+    }
+}
+
+impl Bucket for ApiTokenTable {
+    fn prefix() -> &'static str {
+        "api_token"
+    }
+
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+}
+
+impl InstaSet for ApiTokenTable {
+    type Record = sessionsrv::ApiToken;
+
+    fn seq_id() -> &'static str {
+        "api_token_seq"
+    }
+
+    /// Issue a token, failing with `EntityConflict` if it is named and its owner already has a
+    /// token of that name. The owner's token names are watched, so of two tokens given the same
+    /// name at once only one is written.
+    fn write(&self, record: &mut Self::Record) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        let names_key = Self::names_key(record.get_owner_id());
+        let mut taken = false;
+        let watched = [Self::seq_id().to_string(), names_key.clone()];
+        try!(redis::transaction(conn.deref(), &watched, |txn| {
+            if record.has_name() {
+                taken = try!(conn.hexists(names_key.clone(), record.get_name()));
+                if taken {
+                    return Ok(Some(()));
+                }
+            }
+            let sequence_id: u64 = match conn.get::<&'static str, u64>(Self::seq_id()) {
+                Ok(value) => value + 1,
+                _ => 0,
+            };
+            let insta_id = InstaId::generate(sequence_id);
+            record.set_primary_key(*insta_id);
+            let mut stored = record.clone();
+            stored.set_token(digest(record.get_token()));
+            txn.set(Self::seq_id(), record.primary_key())
+                .ignore()
+                .set(Self::key(&record.primary_key()), stored.write_to_bytes().unwrap())
+                .ignore()
+                .sadd(Self::account_key(record.get_owner_id()), record.primary_key())
+                .ignore()
+                .hset(Self::token_index(), stored.get_token(), record.primary_key())
+                .ignore();
+            if record.has_name() {
+                txn.hset_nx(names_key.clone(), record.get_name(), record.primary_key())
+                    .ignore();
+            }
+            txn.query(conn.deref())
+        }));
+        if taken {
+            return Err(dbcache::Error::EntityConflict);
+        }
+        Ok(())
+    }
+}
+
+/// The digest an API token's secret is stored as.
+pub fn digest(token: &str) -> String {
+    sha256::hash(token.as_bytes()).0.to_hex()
 }
 
 struct GitHub2AccountIdx {
@@ -190,3 +446,179 @@ impl IndexSet for GitHubUser2AccountIdx {
     type Key = String;
     type Value = u64;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{digest, session_ttl};
+
+    #[test]
+    fn digest_is_sha256_hex() {
+        assert_eq!(digest("abc"),
+                   "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    }
+
+    #[test]
+    fn session_ttl_counts_down_to_expiry() {
+        assert_eq!(session_ttl(1_000, 400), 600);
+    }
+
+    #[test]
+    fn expired_session_ttl_is_one_second() {
+        assert_eq!(session_ttl(1_000, 1_000), 1);
+        assert_eq!(session_ttl(1_000, 5_000), 1);
+    }
+}
+
+#[cfg(all(test, feature = "functional"))]
+mod test {
+    use dbcache::{self, Bucket, ExpiringSet, InstaSet};
+    use protobuf::Message;
+    use protocol::{sessionsrv, Persistable};
+    use redis::Commands;
+    use time;
+
+    use config::Config;
+    use super::{digest, ApiTokenTable, DataStore, SessionTable};
+
+    /// Needs a Redis server listening on the session server's default datastore address.
+    #[test]
+    fn session_write_sets_ttl_and_indexes_expiry() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let now = time::get_time().sec as u64;
+        let mut session = sessionsrv::SessionToken::new();
+        session.set_token(format!("ttl-session-{}", time::precise_time_ns()));
+        session.set_expires_at(now + 600);
+        datastore.sessions.write(&session).unwrap();
+
+        let conn = datastore.pool.get().unwrap();
+        let ttl: i64 = conn.ttl(SessionTable::key(&session.primary_key())).unwrap();
+        assert!(ttl > 590 && ttl <= 600, "unexpected ttl {}", ttl);
+        let score: Option<u64> = conn.zscore(SessionTable::expiry_index(), session.get_token())
+            .unwrap();
+        assert_eq!(score, Some(now + 600));
+        datastore.sessions.delete(session.get_token()).unwrap();
+    }
+
+    /// Needs a Redis server listening on the session server's default datastore address.
+    #[test]
+    fn prune_removes_only_expired_sessions() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let now = time::get_time().sec as u64;
+        let id = time::precise_time_ns();
+        let mut expired = sessionsrv::SessionToken::new();
+        expired.set_token(format!("expired-session-{}", id));
+        expired.set_expires_at(now - 10);
+        let mut live = sessionsrv::SessionToken::new();
+        live.set_token(format!("live-session-{}", id));
+        live.set_expires_at(now + 600);
+        datastore.sessions.write(&expired).unwrap();
+        datastore.sessions.write(&live).unwrap();
+
+        assert!(datastore.sessions.prune(now).unwrap() >= 1);
+        let conn = datastore.pool.get().unwrap();
+        let gone: bool = conn.exists(SessionTable::key(&expired.primary_key())).unwrap();
+        assert!(!gone);
+        let score: Option<u64> = conn.zscore(SessionTable::expiry_index(), expired.get_token())
+            .unwrap();
+        assert_eq!(score, None);
+        let kept: bool = conn.exists(SessionTable::key(&live.primary_key())).unwrap();
+        assert!(kept);
+        datastore.sessions.delete(live.get_token()).unwrap();
+    }
+
+    /// Needs a Redis server listening on the session server's default datastore address.
+    #[test]
+    fn api_token_write_find_and_revoke() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let owner_id = time::precise_time_ns();
+        let mut token = sessionsrv::ApiToken::new();
+        token.set_owner_id(owner_id);
+        token.set_token(format!("test-token-{}", owner_id));
+        token.set_name("ci".to_string());
+        datastore.api_tokens.write(&mut token).unwrap();
+
+        let found = datastore.api_tokens.find_by_token(token.get_token()).unwrap();
+        assert_eq!(found.get_id(), token.get_id());
+        assert_eq!(found.get_owner_id(), owner_id);
+        assert_eq!(found.get_token(), super::digest(token.get_token()));
+        let named = datastore.api_tokens.find_by_name(owner_id, "ci").unwrap();
+        assert_eq!(named.get_id(), token.get_id());
+        let issued = datastore.api_tokens.get_by_account_id(owner_id).unwrap();
+        assert_eq!(issued.len(), 1);
+
+        datastore.api_tokens.revoke(&token).unwrap();
+        match datastore.api_tokens.find_by_token(token.get_token()) {
+            Err(dbcache::Error::EntityNotFound) => {}
+            other => panic!("revoked token still found, {:?}", other),
+        }
+        match datastore.api_tokens.find(&token.get_id()) {
+            Err(dbcache::Error::EntityNotFound) => {}
+            other => panic!("revoked token still stored, {:?}", other),
+        }
+        assert!(datastore.api_tokens.get_by_account_id(owner_id).unwrap().is_empty());
+    }
+
+    /// Needs a Redis server listening on the session server's default datastore address.
+    #[test]
+    fn api_token_names_are_unique_per_owner() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let owner_id = time::precise_time_ns();
+        let mut first = sessionsrv::ApiToken::new();
+        first.set_owner_id(owner_id);
+        first.set_token(format!("first-token-{}", owner_id));
+        first.set_name("ci".to_string());
+        datastore.api_tokens.write(&mut first).unwrap();
+
+        let mut second = first.clone();
+        second.set_token(format!("second-token-{}", owner_id));
+        match datastore.api_tokens.write(&mut second) {
+            Err(dbcache::Error::EntityConflict) => {}
+            other => panic!("token with a taken name was written, {:?}", other),
+        }
+        match datastore.api_tokens.find_by_token(second.get_token()) {
+            Err(dbcache::Error::EntityNotFound) => {}
+            other => panic!("token with a taken name is stored, {:?}", other),
+        }
+        assert_eq!(datastore.api_tokens.get_by_account_id(owner_id).unwrap().len(), 1);
+        assert_eq!(datastore.api_tokens.find_by_name(owner_id, "ci").unwrap().get_id(),
+                   first.get_id());
+
+        // Another owner may use the name, and so may the first once the token is revoked.
+        let mut other = second.clone();
+        other.set_owner_id(owner_id + 1);
+        datastore.api_tokens.write(&mut other).unwrap();
+        datastore.api_tokens.revoke(&first).unwrap();
+        datastore.api_tokens.write(&mut second).unwrap();
+        assert_eq!(datastore.api_tokens.find_by_name(owner_id, "ci").unwrap().get_id(),
+                   second.get_id());
+        datastore.api_tokens.revoke(&second).unwrap();
+        datastore.api_tokens.revoke(&other).unwrap();
+    }
+
+    /// Needs a Redis server listening on the session server's default datastore address.
+    #[test]
+    fn legacy_token_is_rehashed_on_use() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let owner_id = time::precise_time_ns();
+        let secret = format!("legacy-token-{}", owner_id);
+        let mut token = sessionsrv::ApiToken::new();
+        token.set_id(owner_id);
+        token.set_owner_id(owner_id);
+        token.set_token(secret.clone());
+        {
+            let conn = datastore.pool.get().unwrap();
+            let _: () = conn.set(ApiTokenTable::key(&owner_id), token.write_to_bytes().unwrap())
+                .unwrap();
+            let _: () = conn.hset(ApiTokenTable::token_index(), &secret, owner_id).unwrap();
+        }
+
+        let found = datastore.api_tokens.find_by_token(&secret).unwrap();
+        assert_eq!(found.get_token(), digest(&secret));
+        let stored = datastore.api_tokens.find(&owner_id).unwrap();
+        assert_eq!(stored.get_token(), digest(&secret));
+        let conn = datastore.pool.get().unwrap();
+        let legacy: Option<u64> = conn.hget(ApiTokenTable::token_index(), &secret).unwrap();
+        assert_eq!(legacy, None);
+        datastore.api_tokens.revoke(&found).unwrap();
+    }
+}
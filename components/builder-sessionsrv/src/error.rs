@@ -37,7 +37,6 @@ pub enum Error {
     NetError(hab_net::Error),
     JsonDecode(json::DecoderError),
     Protobuf(protobuf::ProtobufError),
-    Zmq(zmq::Error),
 }
 
 pub type Result<T> = result::Result<T, Error>;
@@ -55,7 +54,6 @@ impl fmt::Display for Error {
             Error::NetError(ref e) => format!("{}", e),
             Error::JsonDecode(ref e) => format!("JSON decoding error, {}", e),
             Error::Protobuf(ref e) => format!("{}", e),
-            Error::Zmq(ref e) => format!("{}", e),
         };
         write!(f, "{}", msg)
     }
@@ -74,7 +72,6 @@ impl error::Error for Error {
             Error::NetError(ref err) => err.description(),
             Error::JsonDecode(ref err) => err.description(),
             Error::Protobuf(ref err) => err.description(),
-            Error::Zmq(ref err) => err.description(),
         }
     }
 }
@@ -123,6 +120,6 @@ impl From<protobuf::ProtobufError> for Error {
 
 impl From<zmq::Error> for Error {
     fn from(err: zmq::Error) -> Self {
-        Error::Zmq(err)
+        Error::NetError(hab_net::Error::from(err))
     }
 }
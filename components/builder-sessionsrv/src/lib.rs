@@ -25,6 +25,7 @@ extern crate r2d2;
 extern crate r2d2_redis;
 extern crate redis;
 extern crate rustc_serialize;
+extern crate sodiumoxide;
 extern crate time;
 extern crate toml;
 #[macro_use]
@@ -13,15 +13,28 @@
 // limitations under the License.
 
 use std::ops::Deref;
+use std::result;
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use std::thread;
 
 use dbcache::{self, ExpiringSet, InstaSet, IndexSet};
-use hab_net::server::{Application, Envelope, NetIdent, RouteConn, Service, Supervisor,
-                      Supervisable};
-use protocol::net::{self, ErrCode};
-use protocol::sessionsrv::{Account, AccountGet, Session, SessionGet, SessionCreate, SessionToken};
+use hab_net::auth::{Authenticate, Authenticator, SessionSource};
+use hab_net::clock::{self, Clock, SystemClock};
+use hab_net::health::HealthCheck;
+use hab_net;
+use hab_net::runtime::{self, Runtime, Shutdown};
+use hab_net::server::{Application, Envelope, NetIdent, RouteConn, ServerContext, Service,
+                      Supervisor, Supervisable};
+use protocol::net::{self, ErrCode, NetError};
+use protobuf::RepeatedField;
+use protocol::sessionsrv::{self, Account, AccountFlagsSet, AccountGet, ApiToken,
+                           ApiTokenCreate, ApiTokenListRequest, ApiTokenListResponse,
+                           ApiTokenRevoke, Scope, Session, SessionGet, SessionCreate,
+                           SessionRenew, SessionToken};
+use rustc_serialize::hex::ToHex;
+use sodiumoxide::randombytes::randombytes;
+use time;
 use zmq;
 
 use config::Config;
@@ -29,11 +42,14 @@ use data_store::DataStore;
 use error::{Error, Result};
 
 const BE_LISTEN_ADDR: &'static str = "inproc://backend";
+// Number of random bytes in a newly issued API token.
+const API_TOKEN_BYTES: usize = 32;
 
 pub struct Worker {
     config: Arc<RwLock<Config>>,
     sock: zmq::Socket,
-    datastore: Option<DataStore>,
+    datastore: Option<Arc<DataStore>>,
+    auth: Option<Authenticator<LocalSessions>>,
 }
 
 impl Worker {
@@ -60,6 +76,36 @@ impl Worker {
                     }
                 }
             }
+            "AccountFlagsSet" => {
+                let msg: AccountFlagsSet = try!(req.parse_msg());
+                if !self.sent_by_admin(req) {
+                    let err = net::err(ErrCode::ACCESS_DENIED, "ss:account-flags-set:3");
+                    try!(req.reply_complete(&mut self.sock, &err));
+                    return Ok(());
+                }
+                match self.datastore().accounts.find(&msg.get_account_id()) {
+                    Ok(mut account) => {
+                        account.set_flags(msg.get_flags());
+                        match self.datastore().accounts.update(&account) {
+                            Ok(()) => try!(req.reply_complete(&mut self.sock, &account)),
+                            Err(e) => {
+                                error!("datastore error, err={:?}", e);
+                                let err = net::err(ErrCode::INTERNAL, "ss:account-flags-set:1");
+                                try!(req.reply_complete(&mut self.sock, &err));
+                            }
+                        }
+                    }
+                    Err(dbcache::Error::EntityNotFound) => {
+                        let err = net::err(ErrCode::ENTITY_NOT_FOUND, "ss:account-flags-set:0");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                    }
+                    Err(e) => {
+                        error!("datastore error, err={:?}", e);
+                        let err = net::err(ErrCode::INTERNAL, "ss:account-flags-set:2");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                    }
+                }
+            }
             "SessionCreate" => {
                 let mut msg: SessionCreate = try!(req.parse_msg());
                 let mut account: Account = match self.datastore()
@@ -71,31 +117,130 @@ impl Worker {
                 let mut session_token = SessionToken::new();
                 session_token.set_owner_id(account.get_id());
                 session_token.set_token(msg.take_token());
+                session_token.set_scopes(login_scopes());
+                session_token.set_expires_at(self.expires_at());
                 try!(self.datastore().sessions.write(&mut session_token));
                 let mut session = Session::new();
                 session.set_token(session_token.take_token());
                 session.set_id(session_token.get_owner_id());
                 session.set_email(account.take_email());
                 session.set_name(account.take_name());
+                session.set_scopes(session_token.take_scopes());
+                session.set_expires_at(session_token.get_expires_at());
+                session.set_flags(account.get_flags());
                 try!(req.reply_complete(&mut self.sock, &session));
             }
             "SessionGet" => {
                 let msg: SessionGet = try!(req.parse_msg());
+                match find_session(self.datastore(), msg.get_token()) {
+                    Ok(session) => try!(req.reply_complete(&mut self.sock, &session)),
+                    Err(err) => try!(req.reply_complete(&mut self.sock, &err)),
+                }
+            }
+            "SessionRenew" => {
+                let msg: SessionRenew = try!(req.parse_msg());
                 match self.datastore().sessions.find(&msg.get_token().to_string()) {
+                    Ok(ref token) if token.is_expired(now()) => {
+                        try!(self.datastore().sessions.delete(token.get_token()));
+                        let err = net::err(ErrCode::SESSION_EXPIRED, "ss:session-renew:0");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                    }
                     Ok(mut token) => {
-                        let account: Account =
-                            self.datastore().accounts.find(&token.get_owner_id()).unwrap();
-                        let mut session: Session = account.into();
-                        session.set_token(token.take_token());
-                        try!(req.reply_complete(&mut self.sock, &session));
+                        token.set_expires_at(self.expires_at());
+                        try!(self.datastore().sessions.write(&token));
+                        match session_of(self.datastore(), token) {
+                            Ok(session) => try!(req.reply_complete(&mut self.sock, &session)),
+                            Err(dbcache::Error::EntityNotFound) => {
+                                let err = net::err(ErrCode::ACCESS_DENIED, "ss:session-renew:3");
+                                try!(req.reply_complete(&mut self.sock, &err));
+                            }
+                            Err(e) => {
+                                error!("datastore error, err={:?}", e);
+                                let err = net::err(ErrCode::INTERNAL, "ss:session-renew:4");
+                                try!(req.reply_complete(&mut self.sock, &err));
+                            }
+                        }
+                    }
+                    Err(dbcache::Error::EntityNotFound) => {
+                        let err = net::err(ErrCode::SESSION_EXPIRED, "ss:session-renew:1");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                    }
+                    Err(e) => {
+                        error!("datastore error, err={:?}", e);
+                        let err = net::err(ErrCode::INTERNAL, "ss:session-renew:2");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                    }
+                }
+            }
+            "ApiTokenCreate" => {
+                let mut msg: ApiTokenCreate = try!(req.parse_msg());
+                if msg.get_scopes().is_empty() ||
+                   msg.get_scopes().iter().any(|s| s.parse::<Scope>().is_err()) {
+                    let err = net::err(ErrCode::REMOTE_REJECTED, "ss:api-token-create:0");
+                    try!(req.reply_complete(&mut self.sock, &err));
+                    return Ok(());
+                }
+                let mut token = ApiToken::new();
+                token.set_owner_id(msg.get_owner_id());
+                token.set_token(randombytes(API_TOKEN_BYTES).to_hex());
+                token.set_scopes(msg.take_scopes());
+                token.set_description(msg.take_description());
+                if msg.has_name() {
+                    token.set_name(msg.take_name());
+                }
+                match self.datastore().api_tokens.write(&mut token) {
+                    Ok(()) => try!(req.reply_complete(&mut self.sock, &token)),
+                    Err(dbcache::Error::EntityConflict) => {
+                        let err = net::err(ErrCode::ENTITY_CONFLICT, "ss:api-token-create:2");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                    }
+                    Err(e) => {
+                        error!("datastore error, err={:?}", e);
+                        let err = net::err(ErrCode::INTERNAL, "ss:api-token-create:1");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                    }
+                }
+            }
+            "ApiTokenListRequest" => {
+                let msg: ApiTokenListRequest = try!(req.parse_msg());
+                match self.datastore().api_tokens.get_by_account_id(msg.get_owner_id()) {
+                    Ok(tokens) => {
+                        let mut reply = ApiTokenListResponse::new();
+                        reply.set_owner_id(msg.get_owner_id());
+                        // The secret is only ever handed out when the token is created.
+                        let tokens = tokens.into_iter()
+                            .map(|mut t| {
+                                t.clear_token();
+                                t
+                            })
+                            .collect();
+                        reply.set_tokens(RepeatedField::from_vec(tokens));
+                        try!(req.reply_complete(&mut self.sock, &reply));
                     }
+                    Err(e) => {
+                        error!("datastore error, err={:?}", e);
+                        let err = net::err(ErrCode::INTERNAL, "ss:api-token-list:0");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                    }
+                }
+            }
+            "ApiTokenRevoke" => {
+                let msg: ApiTokenRevoke = try!(req.parse_msg());
+                match self.datastore().api_tokens.find(&msg.get_id()) {
+                    Ok(ref token) if token.get_owner_id() == msg.get_owner_id() => {
+                        try!(self.datastore().api_tokens.revoke(token));
+                        let mut reply = token.clone();
+                        reply.clear_token();
+                        try!(req.reply_complete(&mut self.sock, &reply));
+                    }
+                    Ok(_) |
                     Err(dbcache::Error::EntityNotFound) => {
-                        let err = net::err(ErrCode::SESSION_EXPIRED, "ss:auth:4");
+                        let err = net::err(ErrCode::ENTITY_NOT_FOUND, "ss:api-token-revoke:0");
                         try!(req.reply_complete(&mut self.sock, &err));
                     }
                     Err(e) => {
                         error!("datastore error, err={:?}", e);
-                        let err = net::err(ErrCode::INTERNAL, "ss:auth:5");
+                        let err = net::err(ErrCode::INTERNAL, "ss:api-token-revoke:1");
                         try!(req.reply_complete(&mut self.sock, &err));
                     }
                 }
@@ -104,6 +249,157 @@ impl Worker {
         }
         Ok(())
     }
+
+    // Expiry for a session created or renewed now.
+    fn expires_at(&self) -> u64 {
+        now() + clock::duration_secs(self.config.read().unwrap().session_ttl)
+    }
+
+    // Whether the message was sent on behalf of an administrator. Its session was checked by the
+    // worker's authenticator before the message was dispatched.
+    fn sent_by_admin(&self, req: &Envelope) -> bool {
+        let cfg = self.config.read().unwrap();
+        req.session().map_or(false, |session| is_admin(&cfg.admins, session))
+    }
+}
+
+// Finds sessions in the session server's own datastore, as `SessionGet` does, for the messages it
+// authenticates.
+struct LocalSessions(Arc<DataStore>);
+
+impl SessionSource for LocalSessions {
+    fn find_session(&mut self, token: &str) -> result::Result<Session, NetError> {
+        find_session(&self.0, token)
+    }
+}
+
+// The session a token belongs to, which is either a signed in session or a personal access token.
+// Expired sessions are removed as they are found.
+fn find_session(datastore: &DataStore, token: &str) -> result::Result<Session, NetError> {
+    match datastore.sessions.find(&token.to_string()) {
+        Ok(ref stored) if stored.is_expired(now()) => {
+            if let Err(e) = datastore.sessions.delete(stored.get_token()) {
+                warn!("unable to remove expired session, err={:?}", e);
+            }
+            Err(net::err(ErrCode::SESSION_EXPIRED, "ss:auth:7"))
+        }
+        Ok(stored) => {
+            match session_of(datastore, stored) {
+                Ok(session) => Ok(session),
+                Err(dbcache::Error::EntityNotFound) => {
+                    Err(net::err(ErrCode::ACCESS_DENIED, "ss:auth:10"))
+                }
+                Err(e) => {
+                    error!("datastore error, err={:?}", e);
+                    Err(net::err(ErrCode::INTERNAL, "ss:auth:11"))
+                }
+            }
+        }
+        Err(dbcache::Error::EntityNotFound) => {
+            match datastore.api_tokens.find_by_token(token) {
+                Ok(mut api_token) => {
+                    match datastore.accounts.find(&api_token.get_owner_id()) {
+                        Ok(account) => {
+                            let mut session: Session = account.into();
+                            session.set_token(token.to_string());
+                            session.set_scopes(api_token.take_scopes());
+                            session.set_api_token_id(api_token.get_id());
+                            Ok(session)
+                        }
+                        Err(dbcache::Error::EntityNotFound) => {
+                            // The account the token was issued to is gone
+                            Err(net::err(ErrCode::ACCESS_DENIED, "ss:auth:8"))
+                        }
+                        Err(e) => {
+                            error!("datastore error, err={:?}", e);
+                            Err(net::err(ErrCode::INTERNAL, "ss:auth:9"))
+                        }
+                    }
+                }
+                Err(dbcache::Error::EntityNotFound) => {
+                    Err(net::err(ErrCode::SESSION_EXPIRED, "ss:auth:4"))
+                }
+                Err(e) => {
+                    error!("datastore error, err={:?}", e);
+                    Err(net::err(ErrCode::INTERNAL, "ss:auth:6"))
+                }
+            }
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            Err(net::err(ErrCode::INTERNAL, "ss:auth:5"))
+        }
+    }
+}
+
+// The session for a token, which fails if the token's account can't be found.
+fn session_of(datastore: &DataStore, mut token: SessionToken) -> dbcache::Result<Session> {
+    let account: Account = try!(datastore.accounts.find(&token.get_owner_id()));
+    let mut session: Session = account.into();
+    session.set_token(token.take_token());
+    // Sessions stored before scopes existed were granted everything.
+    if token.get_scopes().is_empty() {
+        session.set_scopes(login_scopes());
+    } else {
+        session.set_scopes(token.take_scopes());
+    }
+    if token.has_expires_at() {
+        session.set_expires_at(token.get_expires_at());
+    }
+    Ok(session)
+}
+
+fn now() -> u64 {
+    time::get_time().sec as u64
+}
+
+// Remove expired sessions from the datastore every prune interval until shutdown.
+fn prune_sessions(config: Arc<RwLock<Config>>, shutdown: Shutdown) -> hab_net::Result<()> {
+    let mut datastore = None;
+    let mut next_prune_ms = 0;
+    while !shutdown.is_requested() {
+        let now_ms = SystemClock.now_ms();
+        if now_ms >= next_prune_ms {
+            let interval = config.read().unwrap().session_prune_interval;
+            next_prune_ms = now_ms + clock::duration_ms(interval);
+            if datastore.is_none() {
+                let result = {
+                    let cfg = config.read().unwrap();
+                    DataStore::open(cfg.deref())
+                };
+                match result {
+                    Ok(ds) => datastore = Some(ds),
+                    Err(e) => error!("{}", e),
+                }
+            }
+            if let Some(ref ds) = datastore {
+                match ds.sessions.prune(now()) {
+                    Ok(0) => (),
+                    Ok(n) => info!("pruned {} expired sessions", n),
+                    Err(e) => error!("failed to prune expired sessions, err={:?}", e),
+                }
+            }
+        }
+        thread::sleep(Duration::from_millis(runtime::SHUTDOWN_POLL_MS as u64));
+    }
+    Ok(())
+}
+
+// Whether the session was granted the `admin` scope by an account listed as an administrator.
+// Only signed in sessions count, never personal access tokens.
+fn is_admin(admins: &[String], session: &Session) -> bool {
+    !session.has_api_token_id() && session.permits(&Scope::Admin) &&
+    admins.iter().any(|name| name == session.get_name())
+}
+
+fn login_scopes() -> RepeatedField<String> {
+    RepeatedField::from_vec(sessionsrv::LOGIN_SCOPES.iter().map(|s| s.to_string()).collect())
+}
+
+impl HealthCheck for Worker {
+    fn health_checks(&mut self) -> Vec<net::HealthCheckResult> {
+        vec![dbcache::health_check(self.datastore.as_ref().map(|d| &*d.pool))]
+    }
 }
 
 impl Supervisable for Worker {
@@ -116,6 +412,7 @@ impl Supervisable for Worker {
             config: config,
             sock: sock,
             datastore: None,
+            auth: None,
         }
     }
 
@@ -127,7 +424,14 @@ impl Supervisable for Worker {
             };
             match result {
                 Ok(datastore) => {
+                    let datastore = Arc::new(datastore);
+                    // Sessions are read from the datastore on every message, so revoking one
+                    // takes effect at once.
+                    let auth = Authenticator::with_source(LocalSessions(datastore.clone()))
+                        .with_ttl(Duration::from_secs(0))
+                        .require("AccountFlagsSet");
                     self.datastore = Some(datastore);
+                    self.auth = Some(auth);
                     break;
                 }
                 Err(e) => {
@@ -143,6 +447,10 @@ impl Supervisable for Worker {
         self.dispatch(req)
     }
 
+    fn authenticator(&mut self) -> Option<&mut Authenticate> {
+        self.auth.as_mut().map(|auth| auth as &mut Authenticate)
+    }
+
     fn socket(&mut self) -> &mut zmq::Socket {
         &mut self.sock
     }
@@ -160,31 +468,51 @@ pub struct Server {
     ctx: Arc<RwLock<zmq::Context>>,
     router: RouteConn,
     be_sock: zmq::Socket,
+    config_path: Option<String>,
 }
 
 impl Server {
     pub fn new(config: Config) -> Result<Self> {
-        let mut ctx = zmq::Context::new();
-        let router = try!(RouteConn::new(Self::net_ident(), &mut ctx));
+        let ctx = try!(ServerContext::with_config(&config));
+        let router = try!(RouteConn::new(Self::net_ident(), &mut ctx.zmq().write().unwrap()));
         let be = try!(ctx.socket(zmq::DEALER));
         Ok(Server {
             config: Arc::new(RwLock::new(config)),
-            ctx: Arc::new(RwLock::new(ctx)),
+            ctx: ctx.zmq(),
             router: router,
             be_sock: be,
+            config_path: None,
         })
     }
 
+    /// Re-read the configuration from the given file, and roll the workers onto it, when the
+    /// process receives `SIGHUP`.
+    pub fn reload_from(&mut self, path: &str) {
+        self.config_path = Some(path.to_string());
+    }
+
     pub fn reconfigure(&self, config: Config) -> Result<()> {
-        {
-            let mut cfg = self.config.write().unwrap();
-            *cfg = config;
-        }
+        try!(hab_net::server::reconfigure(&self.config, &self.ctx, BE_LISTEN_ADDR, config));
         // * disconnect from removed routers
         // * notify remaining routers of any shard hosting changes
         // * connect to new shard servers
         Ok(())
     }
+
+    // A runtime which shuts the server down on `SIGTERM` or `SIGINT` and reloads its configuration
+    // file on `SIGHUP`.
+    fn runtime(&self) -> Runtime {
+        let rt = Runtime::new().trap_signals();
+        match self.config_path {
+            Some(ref path) => {
+                let path = path.clone();
+                let cfg = self.config.clone();
+                let ctx = self.ctx.clone();
+                rt.on_reload(move || hab_net::server::reload(&path, &cfg, &ctx, BE_LISTEN_ADDR))
+            }
+            None => rt,
+        }
+    }
 }
 
 impl Application for Server {
@@ -194,13 +522,20 @@ impl Application for Server {
         try!(self.be_sock.bind(BE_LISTEN_ADDR));
         let ctx = self.ctx.clone();
         let cfg = self.config.clone();
-        let sup: Supervisor<Worker> = Supervisor::new(ctx, cfg);
-        {
+        let sched = self.config.read().unwrap().worker_sched.clone();
+        let sup: Supervisor<Worker> = Supervisor::new(ctx, cfg).with_sched(sched);
+        let sup = {
             let cfg = self.config.read().unwrap();
-            try!(sup.start(BE_LISTEN_ADDR, cfg.worker_threads));
-        }
+            try!(sup.spawn(BE_LISTEN_ADDR, cfg.worker_threads))
+        };
         try!(self.connect());
-        try!(zmq::proxy(&mut self.router.socket, &mut self.be_sock));
+        let cfg = self.config.clone();
+        let rt = try!(self.runtime()
+            .with(sup)
+            .spawn("session-pruner", move |shutdown| prune_sessions(cfg, shutdown)));
+        let router = &mut self.router;
+        let be_sock = &mut self.be_sock;
+        try!(rt.run_with(|shutdown| runtime::serve(router, be_sock, shutdown)));
         Ok(())
     }
 }
@@ -229,6 +564,35 @@ impl Service for Server {
 
 impl NetIdent for Server {}
 
-pub fn run(config: Config) -> Result<()> {
-    try!(Server::new(config)).run()
+pub fn run(config: Config, config_path: &str) -> Result<()> {
+    let mut server = try!(Server::new(config));
+    server.reload_from(config_path);
+    server.run()
+}
+
+#[cfg(test)]
+mod tests {
+    use protobuf::RepeatedField;
+    use protocol::sessionsrv::Session;
+
+    use super::is_admin;
+
+    fn session(name: &str, scopes: &[&str]) -> Session {
+        let mut session = Session::new();
+        session.set_name(name.to_string());
+        session.set_scopes(RepeatedField::from_vec(scopes.iter().map(|s| s.to_string()).collect()));
+        session
+    }
+
+    #[test]
+    fn admin_needs_listed_name_and_admin_scope() {
+        let admins = vec!["root".to_string()];
+        assert!(is_admin(&admins, &session("root", &["admin"])));
+        assert!(!is_admin(&admins, &session("root", &["read"])));
+        assert!(!is_admin(&admins, &session("someone", &["admin"])));
+        assert!(!is_admin(&[], &session("root", &["admin"])));
+        let mut token = session("root", &["admin"]);
+        token.set_api_token_id(1);
+        assert!(!is_admin(&admins, &token));
+    }
 }
@@ -16,7 +16,7 @@ use std::ops::Deref;
 use std::sync::Arc;
 
 use dbcache::{self, ConnectionPool, Bucket, IndexSet, InstaSet};
-use protobuf::Message;
+use protobuf::{Message, ProtobufEnum};
 use protocol::{vault, InstaId, Persistable};
 use r2d2_redis::RedisConnectionManager;
 use redis::{self, Commands, PipelineCommands};
@@ -49,6 +49,7 @@ pub struct OriginTable {
     pool: Arc<ConnectionPool>,
 
     pub origin_secret_keys: OriginSecretKeysTable,
+    pub secrets: OriginSecretsTable,
     pub invites: OriginInvitesTable,
     pub name_idx: OriginNameIdx,
 }
@@ -58,14 +59,17 @@ impl OriginTable {
         let pool1 = pool.clone();
         let pool2 = pool.clone();
         let pool3 = pool.clone();
+        let pool4 = pool.clone();
 
         let origin_secret_keys = OriginSecretKeysTable::new(pool1);
+        let secrets = OriginSecretsTable::new(pool4);
         let invites = OriginInvitesTable::new(pool2);
         let name_idx = OriginNameIdx::new(pool3);
 
         OriginTable {
             pool: pool,
             origin_secret_keys: origin_secret_keys,
+            secrets: secrets,
             invites: invites,
             name_idx: name_idx,
         }
@@ -86,6 +90,7 @@ impl OriginTable {
         //  will do for now.
         let account_origins_key = format!("account_origins:{}", &invite.get_account_id());
         let origin_members_key = format!("origin_members:{}", &invite.get_origin_id());
+        let origin_roles_key = self.origin_roles_key(&invite.get_origin_id());
         debug!("account_origins_key = {}", &account_origins_key);
         debug!("origin_members_key = {}", &origin_members_key);
 
@@ -95,10 +100,15 @@ impl OriginTable {
             // accept the invite: add the account to the origin and delete the
             // invite
             try!(redis::transaction(conn.deref(),
-                                    &[account_origins_key.clone(), origin_members_key.clone()],
+                                    &[account_origins_key.clone(),
+                                      origin_members_key.clone(),
+                                      origin_roles_key.clone()],
                                     |txn| {
                 txn.sadd(account_origins_key.clone(), invite.get_origin_name())
                     .sadd(origin_members_key.clone(), invite.get_account_name())
+                    .hset(origin_roles_key.clone(),
+                          invite.get_account_id(),
+                          invite.get_role().value())
                     .del(OriginInvitesTable::key(invite.get_id()))
                     .query(conn.deref())
             }));
@@ -118,12 +128,18 @@ impl OriginTable {
         format!("origin_members:{}", origin_id)
     }
 
+    /// origin_roles stores origin_id -> hash of account id -> role
+    pub fn origin_roles_key(&self, origin_id: &u64) -> String {
+        format!("origin_roles:{}", origin_id)
+    }
+
     /// this is used to add the owner of the account to the full list of members
     /// right after an origin is created
     pub fn add_origin_member(&self,
                              account_id: u64,
                              account_name: &str,
-                             origin_name: &str)
+                             origin_name: &str,
+                             role: proto::OriginRole)
                              -> dbcache::Result<()> {
 
         let conn = try!(self.pool().get());
@@ -131,16 +147,76 @@ impl OriginTable {
         let origin_id = try!(self.name_idx.find(&origin_name.to_string()));
         let account_origins_key = self.account_origins_key(&account_id);
         let origin_members_key = self.origin_members_key(&origin_id);
+        let origin_roles_key = self.origin_roles_key(&origin_id);
         try!(redis::transaction(conn.deref(),
-                                &[account_origins_key.clone(), origin_members_key.clone()],
+                                &[account_origins_key.clone(),
+                                  origin_members_key.clone(),
+                                  origin_roles_key.clone()],
                                 |txn| {
                                     txn.sadd(account_origins_key.clone(), origin_name)
                                         .sadd(origin_members_key.clone(), account_name)
+                                        .hset(origin_roles_key.clone(), account_id, role.value())
                                         .query(conn.deref())
                                 }));
         Ok(())
     }
 
+    /// Returns the role the account holds in the named origin, or `None` if it isn't a member.
+    /// The name is only used to find the origin's id, which roles are keyed by.
+    pub fn origin_member_role(&self,
+                              account_id: u64,
+                              origin_name: &str)
+                              -> dbcache::Result<Option<proto::OriginRole>> {
+        let origin_id = match self.name_idx.find(&origin_name.to_string()) {
+            Ok(origin_id) => origin_id,
+            Err(dbcache::Error::EntityNotFound) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        self.origin_member_role_by_id(account_id, origin_id)
+    }
+
+    /// Returns the role the account holds in the origin, or `None` if it isn't a member.
+    ///
+    /// Members who joined before roles were recorded are treated as maintainers, and the
+    /// origin's owner always holds the owner role.
+    pub fn origin_member_role_by_id(&self,
+                                    account_id: u64,
+                                    origin_id: u64)
+                                    -> dbcache::Result<Option<proto::OriginRole>> {
+        let origin = match self.find(&origin_id) {
+            Ok(origin) => origin,
+            Err(dbcache::Error::EntityNotFound) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        if origin.get_owner_id() == account_id {
+            return Ok(Some(proto::OriginRole::OWNER));
+        }
+        let conn = try!(self.pool().get());
+        let value = try!(conn.hget::<String, u64, Option<i32>>(self.origin_roles_key(&origin_id),
+                                                               account_id));
+        if let Some(role) = value.and_then(proto::OriginRole::from_i32) {
+            return Ok(Some(role));
+        }
+        if try!(self.is_origin_member(account_id, origin.get_name())) {
+            Ok(Some(proto::OriginRole::MAINTAINER))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Change the role of an existing member of an origin.
+    pub fn set_origin_member_role(&self,
+                                  origin_id: u64,
+                                  account_id: u64,
+                                  role: proto::OriginRole)
+                                  -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        try!(conn.hset::<String, u64, i32, ()>(self.origin_roles_key(&origin_id),
+                                               account_id,
+                                               role.value()));
+        Ok(())
+    }
+
     pub fn list_origin_members(&self, origin_id: u64) -> dbcache::Result<Vec<String>> {
         let origin_members_key = self.origin_members_key(&origin_id);
         let conn = try!(self.pool().get());
@@ -238,6 +314,68 @@ impl OriginSecretKeysTable {
     pub fn new(pool: Arc<ConnectionPool>) -> Self {
         OriginSecretKeysTable { pool: pool }
     }
+
+    /// The secret key most recently stored for an origin, if any. Keys stored before the
+    /// latest key and revisions of each origin were recorded are only found by this or by
+    /// `find_by_revision` once `backfill` has indexed them.
+    pub fn latest(&self, origin_id: u64) -> dbcache::Result<Option<vault::OriginSecretKey>> {
+        let conn = try!(self.pool().get());
+        match try!(conn.get::<String, Option<u64>>(Self::latest_key(origin_id))) {
+            Some(id) => self.find(&id).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// The secret key stored for an origin with the given revision, if any.
+    pub fn find_by_revision(&self,
+                            origin_id: u64,
+                            revision: &str)
+                            -> dbcache::Result<Option<vault::OriginSecretKey>> {
+        let conn = try!(self.pool().get());
+        match try!(conn.hget::<String, &str, Option<u64>>(Self::revisions_key(origin_id),
+                                                          revision)) {
+            Some(id) => self.find(&id).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Index the secret keys stored before the latest key and revisions of each origin were
+    /// recorded. Keys already indexed are left alone, and a key only becomes its origin's latest
+    /// if it is newer than the one recorded. Returns the number of keys indexed.
+    pub fn backfill(&self) -> dbcache::Result<usize> {
+        let conn = try!(self.pool().get());
+        let prefix = format!("{}:", Self::prefix());
+        let keys: Vec<String> = try!(conn.scan_match::<String, String>(format!("{}*", prefix)))
+            .collect();
+        let mut indexed = 0;
+        // The latest and revisions indexes share the prefix, but only records are keyed by id.
+        for id in keys.iter().filter_map(|k| k[prefix.len()..].parse::<u64>().ok()) {
+            let key = match self.find(&id) {
+                Ok(key) => key,
+                Err(dbcache::Error::EntityNotFound) => continue,
+                Err(e) => return Err(e),
+            };
+            let added = try!(conn.hset_nx::<String, &str, u64, bool>(
+                Self::revisions_key(key.get_origin_id()), key.get_revision(), id));
+            let latest_key = Self::latest_key(key.get_origin_id());
+            let latest = try!(conn.get::<String, Option<u64>>(latest_key.clone()));
+            if latest.map_or(true, |latest| latest < id) {
+                try!(conn.set::<String, u64, ()>(latest_key, id));
+            }
+            if added {
+                indexed += 1;
+            }
+        }
+        Ok(indexed)
+    }
+
+    fn latest_key(origin_id: u64) -> String {
+        format!("{}:latest:{}", Self::prefix(), origin_id)
+    }
+
+    fn revisions_key(origin_id: u64) -> String {
+        format!("{}:revisions:{}", Self::prefix(), origin_id)
+    }
 }
 
 impl Bucket for OriginSecretKeysTable {
@@ -271,6 +409,13 @@ impl InstaSet for OriginSecretKeysTable {
                 .ignore()
                 .set(Self::key(&record.primary_key()),
                      record.write_to_bytes().unwrap())
+                .ignore()
+                .set(Self::latest_key(record.get_origin_id()), record.primary_key())
+                .ignore()
+                .hset(Self::revisions_key(record.get_origin_id()),
+                      record.get_revision(),
+                      record.primary_key())
+                .ignore()
                 .query(conn.deref())
 
         }));
@@ -278,6 +423,114 @@ impl InstaSet for OriginSecretKeysTable {
     }
 }
 
+/// Secrets stored for origins, encrypted with a key derived from the origin's secret key. Each
+/// origin's secrets are indexed by name.
+pub struct OriginSecretsTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl OriginSecretsTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        OriginSecretsTable { pool: pool }
+    }
+
+    pub fn find_by_name(&self,
+                        origin_id: u64,
+                        name: &str)
+                        -> dbcache::Result<Option<vault::OriginSecret>> {
+        let conn = try!(self.pool().get());
+        match try!(conn.hget::<String, &str, Option<u64>>(Self::names_key(origin_id), name)) {
+            Some(id) => self.find(&id).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Delete an origin's secret. Returns false if the origin had no secret of that name.
+    pub fn delete(&self, origin_id: u64, name: &str) -> dbcache::Result<bool> {
+        let conn = try!(self.pool().get());
+        let names_key = Self::names_key(origin_id);
+        let mut found = false;
+        try!(redis::transaction(conn.deref(), &[names_key.clone()], |txn| {
+            match try!(conn.hget::<String, &str, Option<u64>>(names_key.clone(), name)) {
+                Some(id) => {
+                    found = true;
+                    txn.hdel(names_key.clone(), name)
+                        .ignore()
+                        .del(Self::key(&id))
+                        .ignore()
+                        .query(conn.deref())
+                }
+                None => {
+                    found = false;
+                    Ok(Some(()))
+                }
+            }
+        }));
+        Ok(found)
+    }
+
+    /// The names of an origin's secrets, in sorted order.
+    pub fn list(&self, origin_id: u64) -> dbcache::Result<Vec<String>> {
+        let conn = try!(self.pool().get());
+        let mut names = try!(conn.hkeys::<String, Vec<String>>(Self::names_key(origin_id)));
+        names.sort();
+        Ok(names)
+    }
+
+    fn names_key(origin_id: u64) -> String {
+        format!("origin_secrets:{}", origin_id)
+    }
+}
+
+impl Bucket for OriginSecretsTable {
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+
+    fn prefix() -> &'static str {
+        "origin_secret"
+    }
+}
+
+impl InstaSet for OriginSecretsTable {
+    type Record = vault::OriginSecret;
+
+    fn seq_id() -> &'static str {
+        "origin_secret_seq"
+    }
+
+    /// Store a secret, replacing any secret of the same name the origin already has.
+    fn write(&self, record: &mut Self::Record) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        let names_key = Self::names_key(record.get_origin_id());
+        let watched = [Self::seq_id().to_string(), names_key.clone()];
+        try!(redis::transaction(conn.deref(), &watched, |txn| {
+            // Read inside the transaction so a secret stored concurrently under the same name is
+            // replaced rather than left behind.
+            let previous = try!(conn.hget::<String, &str, Option<u64>>(names_key.clone(),
+                                                                       record.get_name()));
+            let sequence_id: u64 = match conn.get::<&'static str, u64>(Self::seq_id()) {
+                Ok(value) => value + 1,
+                _ => 0,
+            };
+            let insta_id = InstaId::generate(sequence_id);
+            record.set_primary_key(*insta_id);
+            txn.set(Self::seq_id(), record.primary_key())
+                .ignore()
+                .set(Self::key(&record.primary_key()),
+                     record.write_to_bytes().unwrap())
+                .ignore()
+                .hset(names_key.clone(), record.get_name(), record.primary_key())
+                .ignore();
+            if let Some(id) = previous {
+                txn.del(Self::key(&id)).ignore();
+            }
+            txn.query(conn.deref())
+        }));
+        Ok(())
+    }
+}
+
 pub struct OriginInvitesTable {
     pool: Arc<ConnectionPool>,
 }
@@ -383,3 +636,150 @@ impl InstaSet for OriginInvitesTable {
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "functional"))]
+mod test {
+    use std::ops::Deref;
+
+    use dbcache::{Bucket, InstaSet};
+    use protobuf::Message;
+    use protocol::Persistable;
+    use protocol::vault::{Origin, OriginRole, OriginSecret, OriginSecretKey};
+    use redis::Commands;
+
+    use config::Config;
+    use super::{DataStore, OriginSecretKeysTable};
+
+    // Create an origin owned by the given account with a name no other test run uses.
+    fn origin(datastore: &DataStore, owner_id: u64, owner_name: &str) -> Origin {
+        let mut origin = Origin::new();
+        origin.set_name(format!("roles-{}-{}", owner_id, owner_name));
+        origin.set_owner_id(owner_id);
+        datastore.origins.write(&mut origin).unwrap();
+        datastore.origins
+            .add_origin_member(owner_id, owner_name, origin.get_name(), OriginRole::OWNER)
+            .unwrap();
+        origin
+    }
+
+    /// Needs a Redis server listening on the vault's default datastore address.
+    #[test]
+    fn member_roles_are_found_by_origin_id_and_name() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let origin = origin(&datastore, 1, "owner");
+        let origins = &datastore.origins;
+        origins.add_origin_member(2, "reader", origin.get_name(), OriginRole::READONLY).unwrap();
+        origins.add_origin_member(3, "maintainer", origin.get_name(), OriginRole::MAINTAINER)
+            .unwrap();
+
+        for &(account_id, role) in [(1, Some(OriginRole::OWNER)),
+                                    (2, Some(OriginRole::READONLY)),
+                                    (3, Some(OriginRole::MAINTAINER)),
+                                    (4, None)]
+            .iter() {
+            assert_eq!(origins.origin_member_role_by_id(account_id, origin.get_id()).unwrap(),
+                       role);
+            assert_eq!(origins.origin_member_role(account_id, origin.get_name()).unwrap(),
+                       role);
+        }
+        assert_eq!(origins.origin_member_role(1, "no-such-origin").unwrap(), None);
+    }
+
+    /// Needs a Redis server listening on the vault's default datastore address.
+    #[test]
+    fn members_without_a_recorded_role_are_maintainers() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let origin = origin(&datastore, 21, "owner");
+        let conn = datastore.pool.get().unwrap();
+        conn.deref().sadd::<String, &str, ()>(datastore.origins.account_origins_key(&22),
+                                      origin.get_name())
+            .unwrap();
+        assert_eq!(datastore.origins.origin_member_role_by_id(22, origin.get_id()).unwrap(),
+                   Some(OriginRole::MAINTAINER));
+    }
+
+    /// Needs a Redis server listening on the vault's default datastore address.
+    #[test]
+    fn set_role_is_seen_by_name_lookups() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let origin = origin(&datastore, 11, "owner");
+        let origins = &datastore.origins;
+        origins.add_origin_member(12, "member", origin.get_name(), OriginRole::MAINTAINER)
+            .unwrap();
+        origins.set_origin_member_role(origin.get_id(), 12, OriginRole::READONLY).unwrap();
+        assert_eq!(origins.origin_member_role(12, origin.get_name()).unwrap(),
+                   Some(OriginRole::READONLY));
+    }
+
+    fn secret(origin_id: u64, name: &str, ciphertext: &[u8]) -> OriginSecret {
+        let mut secret = OriginSecret::new();
+        secret.set_origin_id(origin_id);
+        secret.set_name(name.to_string());
+        secret.set_key_revision("20160101000000".to_string());
+        secret.set_nonce(vec![0; 24]);
+        secret.set_ciphertext(ciphertext.to_vec());
+        secret.set_owner_id(1);
+        secret
+    }
+
+    /// Needs a Redis server listening on the vault's default datastore address.
+    #[test]
+    fn secrets_are_replaced_listed_and_deleted_by_name() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let origin = origin(&datastore, 31, "owner");
+        let secrets = &datastore.origins.secrets;
+        secrets.write(&mut secret(origin.get_id(), "b-token", b"one")).unwrap();
+        let mut first = secret(origin.get_id(), "a-token", b"two");
+        secrets.write(&mut first).unwrap();
+        let mut replaced = secret(origin.get_id(), "a-token", b"three");
+        secrets.write(&mut replaced).unwrap();
+
+        assert_eq!(secrets.list(origin.get_id()).unwrap(),
+                   vec!["a-token".to_string(), "b-token".to_string()]);
+        let found = secrets.find_by_name(origin.get_id(), "a-token").unwrap().unwrap();
+        assert_eq!(found.get_ciphertext(), b"three");
+        // The replaced record is removed along with its name.
+        assert!(secrets.find(&first.get_id()).is_err());
+
+        assert!(secrets.delete(origin.get_id(), "a-token").unwrap());
+        assert!(!secrets.delete(origin.get_id(), "a-token").unwrap());
+        assert_eq!(secrets.find_by_name(origin.get_id(), "a-token").unwrap(), None);
+        assert!(secrets.find(&replaced.get_id()).is_err());
+        assert_eq!(secrets.list(origin.get_id()).unwrap(), vec!["b-token".to_string()]);
+    }
+
+    /// Needs a Redis server listening on the vault's default datastore address.
+    #[test]
+    fn backfill_indexes_unindexed_secret_keys() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let origin = origin(&datastore, 41, "owner");
+        let keys = &datastore.origins.origin_secret_keys;
+        // Keys stored before the indexes existed have only their record.
+        let conn = datastore.pool.get().unwrap();
+        let mut stored = vec![];
+        for (id, revision) in [(origin.get_id() + 1, "20160101000000"),
+                               (origin.get_id() + 2, "20160202000000")]
+            .iter() {
+            let mut key = OriginSecretKey::new();
+            key.set_id(*id);
+            key.set_origin_id(origin.get_id());
+            key.set_name(origin.get_name().to_string());
+            key.set_revision(revision.to_string());
+            key.set_body(vec![1; 32]);
+            key.set_owner_id(41);
+            let _: () = conn.set(OriginSecretKeysTable::key(&key.primary_key()),
+                     key.write_to_bytes().unwrap())
+                .unwrap();
+            stored.push(key);
+        }
+        assert_eq!(keys.latest(origin.get_id()).unwrap(), None);
+
+        assert!(keys.backfill().unwrap() >= 2);
+        assert_eq!(keys.latest(origin.get_id()).unwrap(), Some(stored[1].clone()));
+        assert_eq!(keys.find_by_revision(origin.get_id(), "20160101000000").unwrap(),
+                   Some(stored[0].clone()));
+        // Backfilling again leaves the indexes as they are.
+        keys.backfill().unwrap();
+        assert_eq!(keys.latest(origin.get_id()).unwrap(), Some(stored[1].clone()));
+    }
+}
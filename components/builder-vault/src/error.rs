@@ -33,7 +33,6 @@ pub enum Error {
     JsonDecode(json::DecoderError),
     NetError(hab_net::Error),
     Protobuf(protobuf::ProtobufError),
-    Zmq(zmq::Error),
 }
 
 pub type Result<T> = result::Result<T, Error>;
@@ -48,7 +47,6 @@ impl fmt::Display for Error {
             Error::JsonDecode(ref e) => format!("JSON decoding error, {}", e),
             Error::NetError(ref e) => format!("{}", e),
             Error::Protobuf(ref e) => format!("{}", e),
-            Error::Zmq(ref e) => format!("{}", e),
         };
         write!(f, "{}", msg)
     }
@@ -64,7 +62,6 @@ impl error::Error for Error {
             Error::JsonDecode(ref err) => err.description(),
             Error::NetError(ref err) => err.description(),
             Error::Protobuf(ref err) => err.description(),
-            Error::Zmq(ref err) => err.description(),
         }
     }
 }
@@ -107,6 +104,6 @@ impl From<protobuf::ProtobufError> for Error {
 
 impl From<zmq::Error> for Error {
     fn from(err: zmq::Error) -> Self {
-        Error::Zmq(err)
+        Error::NetError(hab_net::Error::from(err))
     }
 }
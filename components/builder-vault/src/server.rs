@@ -21,8 +21,15 @@ use protobuf::RepeatedField;
 use zmq;
 
 use dbcache::{self, ExpiringSet, IndexSet, InstaSet};
-use hab_net::server::{Application, Envelope, NetIdent, RouteConn, Service, Supervisor,
-                      Supervisable};
+use hab_core::crypto::SymKey;
+use hab_net;
+use hab_net::auth::{Authenticate, Authenticator, BrokerSessions};
+use hab_net::config::{RouteAddrs, RouterAuth};
+use hab_net::health::HealthCheck;
+use hab_net::routing::{Broker, BrokerContext, BrokerOpts, KeepaliveConfig};
+use hab_net::runtime::{self, Runtime};
+use hab_net::server::{Application, Envelope, NetIdent, RouteConn, ServerContext, Service,
+                      Supervisor, Supervisable};
 use protocol::net::{self, ErrCode};
 use protocol::vault as proto;
 
@@ -36,6 +43,7 @@ pub struct Worker {
     config: Arc<RwLock<Config>>,
     sock: zmq::Socket,
     datastore: Option<DataStore>,
+    auth: Option<Authenticator<BrokerSessions>>,
 }
 
 impl Worker {
@@ -65,15 +73,24 @@ impl Worker {
             "CheckOriginAccessRequest" => {
                 // !!!NOTE!!!
                 // !!!NOTE!!!
-                // only account_id and origin_name are implemented
+                // only account_id is implemented, with either origin_id or origin_name
                 // !!!NOTE!!!
                 // !!!NOTE!!!
                 let msg: proto::CheckOriginAccessRequest = try!(req.parse_msg());
-                let is_member = try!(self.datastore()
-                    .origins
-                    .is_origin_member(msg.get_account_id(), msg.get_origin_name()));
+                let role = if msg.has_origin_id() {
+                    try!(self.datastore()
+                        .origins
+                        .origin_member_role_by_id(msg.get_account_id(), msg.get_origin_id()))
+                } else {
+                    try!(self.datastore()
+                        .origins
+                        .origin_member_role(msg.get_account_id(), msg.get_origin_name()))
+                };
                 let mut resp = proto::CheckOriginAccessResponse::new();
-                resp.set_has_access(is_member);
+                resp.set_has_access(role.is_some());
+                if let Some(role) = role {
+                    resp.set_role(role);
+                }
                 try!(req.reply_complete(&mut self.sock, &resp));
             }
             "OriginCreate" => {
@@ -97,7 +114,10 @@ impl Worker {
                 debug!("Adding owner as origin member: {}", &msg.get_owner_name());
                 try!(self.datastore()
                     .origins
-                    .add_origin_member(msg.get_owner_id(), msg.get_owner_name(), msg.get_name()));
+                    .add_origin_member(msg.get_owner_id(),
+                                       msg.get_owner_name(),
+                                       msg.get_name(),
+                                       proto::OriginRole::OWNER));
                 try!(req.reply_complete(&mut self.sock, &origin));
             }
             "OriginGet" => {
@@ -150,12 +170,14 @@ impl Worker {
             "OriginInvitationCreate" => {
                 let msg: proto::OriginInvitationCreate = try!(req.parse_msg());
                 let mut invitation = proto::OriginInvitation::new();
-                if !try!(self.datastore()
+                let inviter_role = try!(self.datastore()
                     .origins
-                    .is_origin_member(msg.get_account_id(), msg.get_origin_name())) {
-                    debug!("Can't invite to this org unless your already a member");
+                    .origin_member_role_by_id(msg.get_owner_id(), msg.get_origin_id()));
+                if !inviter_role.map_or(false, |r| r.permits(proto::OriginRole::OWNER)) {
+                    debug!("Can't invite to this org unless you're an owner");
                     let err = net::err(ErrCode::ACCESS_DENIED, "vt:origin-create:0");
                     try!(req.reply_complete(&mut self.sock, &err));
+                    return Ok(());
                 }
 
                 let existing_invites = try!(self.datastore()
@@ -180,6 +202,7 @@ impl Worker {
                 invitation.set_origin_name(msg.get_origin_name().to_string());
 
                 invitation.set_owner_id(msg.get_owner_id());
+                invitation.set_role(msg.get_role());
                 try!(self.datastore().origins.invites.write(&mut invitation));
                 try!(req.reply_complete(&mut self.sock, &invitation));
             }
@@ -237,6 +260,162 @@ impl Worker {
                 resp.set_origins(r_origins);
                 try!(req.reply_complete(&mut self.sock, &resp));
             }
+            "OriginMemberRoleSet" => {
+                let msg: proto::OriginMemberRoleSet = try!(req.parse_msg());
+                let requester_role = try!(self.datastore()
+                    .origins
+                    .origin_member_role_by_id(requester(req), msg.get_origin_id()));
+                if !requester_role.map_or(false, |r| r.permits(proto::OriginRole::OWNER)) {
+                    let err = net::err(ErrCode::ACCESS_DENIED, "vt:origin-member-role-set:0");
+                    try!(req.reply_complete(&mut self.sock, &err));
+                    return Ok(());
+                }
+                let origin = try!(self.datastore().origins.find(&msg.get_origin_id()));
+                if origin.get_owner_id() == msg.get_account_id() {
+                    // the account which created the origin always remains an owner
+                    let err = net::err(ErrCode::ACCESS_DENIED, "vt:origin-member-role-set:1");
+                    try!(req.reply_complete(&mut self.sock, &err));
+                    return Ok(());
+                }
+                if try!(self.datastore()
+                        .origins
+                        .origin_member_role_by_id(msg.get_account_id(), msg.get_origin_id()))
+                    .is_none() {
+                    let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-member-role-set:2");
+                    try!(req.reply_complete(&mut self.sock, &err));
+                    return Ok(());
+                }
+                try!(self.datastore().origins.set_origin_member_role(msg.get_origin_id(),
+                                                                     msg.get_account_id(),
+                                                                     msg.get_role()));
+                let mut resp = proto::OriginMemberRole::new();
+                resp.set_origin_id(msg.get_origin_id());
+                resp.set_account_id(msg.get_account_id());
+                resp.set_account_name(msg.get_account_name().to_string());
+                resp.set_role(msg.get_role());
+                try!(req.reply_complete(&mut self.sock, &resp));
+            }
+            "OriginSecretCreate" => {
+                let msg: proto::OriginSecretCreate = try!(req.parse_msg());
+                if !try!(self.permits(requester(req),
+                                      msg.get_origin_id(),
+                                      msg.get_origin_name(),
+                                      proto::OriginRole::MAINTAINER)) {
+                    let err = net::err(ErrCode::ACCESS_DENIED, "vt:origin-secret-create:0");
+                    try!(req.reply_complete(&mut self.sock, &err));
+                    return Ok(());
+                }
+                let key = match try!(self.datastore()
+                    .origins
+                    .origin_secret_keys
+                    .latest(msg.get_origin_id())) {
+                    Some(key) => key,
+                    None => {
+                        let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-secret-create:1");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                        return Ok(());
+                    }
+                };
+                let (nonce, ciphertext) = try!(secret_box(&key).encrypt(msg.get_value()));
+                let mut secret = proto::OriginSecret::new();
+                secret.set_origin_id(msg.get_origin_id());
+                secret.set_name(msg.get_name().to_string());
+                secret.set_key_revision(key.get_revision().to_string());
+                secret.set_nonce(nonce);
+                secret.set_ciphertext(ciphertext);
+                secret.set_owner_id(requester(req));
+                try!(self.datastore().origins.secrets.write(&mut secret));
+                let resp = try!(self.secret_list(msg.get_origin_id()));
+                try!(req.reply_complete(&mut self.sock, &resp));
+            }
+            "OriginSecretDelete" => {
+                let msg: proto::OriginSecretDelete = try!(req.parse_msg());
+                if !try!(self.permits(requester(req),
+                                      msg.get_origin_id(),
+                                      msg.get_origin_name(),
+                                      proto::OriginRole::MAINTAINER)) {
+                    let err = net::err(ErrCode::ACCESS_DENIED, "vt:origin-secret-delete:0");
+                    try!(req.reply_complete(&mut self.sock, &err));
+                    return Ok(());
+                }
+                if !try!(self.datastore()
+                    .origins
+                    .secrets
+                    .delete(msg.get_origin_id(), msg.get_name())) {
+                    let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-secret-delete:1");
+                    try!(req.reply_complete(&mut self.sock, &err));
+                    return Ok(());
+                }
+                let resp = try!(self.secret_list(msg.get_origin_id()));
+                try!(req.reply_complete(&mut self.sock, &resp));
+            }
+            "OriginSecretGet" => {
+                let msg: proto::OriginSecretGet = try!(req.parse_msg());
+                if !try!(self.permits(requester(req),
+                                      msg.get_origin_id(),
+                                      msg.get_origin_name(),
+                                      proto::OriginRole::MAINTAINER)) {
+                    let err = net::err(ErrCode::ACCESS_DENIED, "vt:origin-secret-get:0");
+                    try!(req.reply_complete(&mut self.sock, &err));
+                    return Ok(());
+                }
+                let secret = match try!(self.datastore()
+                    .origins
+                    .secrets
+                    .find_by_name(msg.get_origin_id(), msg.get_name())) {
+                    Some(secret) => secret,
+                    None => {
+                        let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-secret-get:1");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                        return Ok(());
+                    }
+                };
+                let key = match try!(self.datastore()
+                    .origins
+                    .origin_secret_keys
+                    .find_by_revision(msg.get_origin_id(), secret.get_key_revision())) {
+                    Some(key) => key,
+                    None => {
+                        error!("origin secret key missing, origin={}, revision={}",
+                               msg.get_origin_id(),
+                               secret.get_key_revision());
+                        let err = net::err(ErrCode::INTERNAL, "vt:origin-secret-get:2");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                        return Ok(());
+                    }
+                };
+                let value = match secret_box(&key)
+                    .decrypt(secret.get_nonce(), secret.get_ciphertext()) {
+                    Ok(value) => value,
+                    Err(e) => {
+                        error!("origin secret undecryptable, origin={}, name={}, err={}",
+                               msg.get_origin_id(),
+                               msg.get_name(),
+                               e);
+                        let err = net::err(ErrCode::INTERNAL, "vt:origin-secret-get:3");
+                        try!(req.reply_complete(&mut self.sock, &err));
+                        return Ok(());
+                    }
+                };
+                let mut resp = proto::OriginSecretValue::new();
+                resp.set_origin_id(msg.get_origin_id());
+                resp.set_name(msg.get_name().to_string());
+                resp.set_value(value);
+                try!(req.reply_complete(&mut self.sock, &resp));
+            }
+            "OriginSecretListRequest" => {
+                let msg: proto::OriginSecretListRequest = try!(req.parse_msg());
+                if !try!(self.permits(requester(req),
+                                      msg.get_origin_id(),
+                                      msg.get_origin_name(),
+                                      proto::OriginRole::READONLY)) {
+                    let err = net::err(ErrCode::ACCESS_DENIED, "vt:origin-secret-list:0");
+                    try!(req.reply_complete(&mut self.sock, &err));
+                    return Ok(());
+                }
+                let resp = try!(self.secret_list(msg.get_origin_id()));
+                try!(req.reply_complete(&mut self.sock, &resp));
+            }
             "OriginSecretKeyCreate" => {
                 let msg: proto::OriginSecretKeyCreate = try!(req.parse_msg());
                 let mut pk = proto::OriginSecretKey::new();
@@ -253,6 +432,59 @@ impl Worker {
         }
         Ok(())
     }
+
+    // Whether the account holds at least the given role in the origin. Secret messages name
+    // their origin both by id, which secrets are stored under, and by name, so the name must be
+    // of the same origin or nothing is permitted.
+    fn permits(&self,
+               account_id: u64,
+               origin_id: u64,
+               origin_name: &str,
+               required: proto::OriginRole)
+               -> Result<bool> {
+        match self.datastore().origins.name_idx.find(&origin_name.to_string()) {
+            Ok(id) if id == origin_id => (),
+            Ok(id) => {
+                warn!("origin name and id disagree, name={}, id={}, named={}",
+                      origin_name,
+                      origin_id,
+                      id);
+                return Ok(false);
+            }
+            Err(dbcache::Error::EntityNotFound) => return Ok(false),
+            Err(e) => return Err(Error::from(e)),
+        }
+        let role = try!(self.datastore()
+            .origins
+            .origin_member_role_by_id(account_id, origin_id));
+        Ok(role.map_or(false, |r| r.permits(required)))
+    }
+
+    fn secret_list(&self, origin_id: u64) -> Result<proto::OriginSecretListResponse> {
+        let names = try!(self.datastore().origins.secrets.list(origin_id));
+        let mut resp = proto::OriginSecretListResponse::new();
+        resp.set_origin_id(origin_id);
+        resp.set_names(RepeatedField::from_vec(names));
+        Ok(resp)
+    }
+}
+
+// The key an origin's secrets are encrypted with, derived from one of the origin's secret keys.
+// The account a message was sent on behalf of. Messages which act on an origin's members or
+// secrets are authenticated, so their requester is the account of their session rather than the
+// one they name.
+fn requester(req: &Envelope) -> u64 {
+    req.account().map_or(0, |account| account.get_id())
+}
+
+fn secret_box(key: &proto::OriginSecretKey) -> SymKey {
+    SymKey::derive_from(key.get_name(), key.get_revision(), key.get_body())
+}
+
+impl HealthCheck for Worker {
+    fn health_checks(&mut self) -> Vec<net::HealthCheckResult> {
+        vec![dbcache::health_check(self.datastore.as_ref().map(|d| &*d.pool))]
+    }
 }
 
 impl Supervisable for Worker {
@@ -265,6 +497,7 @@ impl Supervisable for Worker {
             config: config,
             sock: sock,
             datastore: None,
+            auth: None,
         }
     }
 
@@ -292,6 +525,20 @@ impl Supervisable for Worker {
         self.dispatch(req)
     }
 
+    fn authenticator(&mut self) -> Option<&mut Authenticate> {
+        self.auth.as_mut().map(|auth| auth as &mut Authenticate)
+    }
+
+    fn set_broker(&mut self, ctx: Arc<BrokerContext>) {
+        let auth = Authenticator::with_source(BrokerSessions(ctx))
+            .require("OriginMemberRoleSet")
+            .require("OriginSecretCreate")
+            .require("OriginSecretDelete")
+            .require("OriginSecretGet")
+            .require("OriginSecretListRequest");
+        self.auth = Some(auth);
+    }
+
     fn socket(&mut self) -> &mut zmq::Socket {
         &mut self.sock
     }
@@ -308,31 +555,71 @@ pub struct Server {
     ctx: Arc<RwLock<zmq::Context>>,
     router: RouteConn,
     be_sock: zmq::Socket,
+    config_path: Option<String>,
 }
 
 impl Server {
     pub fn new(config: Config) -> Result<Self> {
-        let mut ctx = zmq::Context::new();
-        let router = try!(RouteConn::new(Self::net_ident(), &mut ctx));
+        let ctx = try!(ServerContext::with_config(&config));
+        let router = try!(RouteConn::new(Self::net_ident(), &mut ctx.zmq().write().unwrap()));
         let be = try!(ctx.socket(zmq::DEALER));
         Ok(Server {
             config: Arc::new(RwLock::new(config)),
-            ctx: Arc::new(RwLock::new(ctx)),
+            ctx: ctx.zmq(),
             router: router,
             be_sock: be,
+            config_path: None,
         })
     }
 
+    /// Re-read the configuration from the given file, and roll the workers onto it, when the
+    /// process receives `SIGHUP`.
+    pub fn reload_from(&mut self, path: &str) {
+        self.config_path = Some(path.to_string());
+    }
+
     pub fn reconfigure(&self, config: Config) -> Result<()> {
-        {
-            let mut cfg = self.config.write().unwrap();
-            *cfg = config;
-        }
-        // obtain lock and replace our config
-        // notify datastore to refresh it's connection if it needs to
+        try!(hab_net::server::reconfigure(&self.config, &self.ctx, BE_LISTEN_ADDR, config));
         // notify sockets to reconnect if changes
         Ok(())
     }
+
+    // A runtime which shuts the server down on `SIGTERM` or `SIGINT` and reloads its configuration
+    // file on `SIGHUP`.
+    fn runtime(&self) -> Runtime {
+        let rt = Runtime::new().trap_signals();
+        match self.config_path {
+            Some(ref path) => {
+                let path = path.clone();
+                let cfg = self.config.clone();
+                let ctx = self.ctx.clone();
+                rt.on_reload(move || hab_net::server::reload(&path, &cfg, &ctx, BE_LISTEN_ADDR))
+            }
+            None => rt,
+        }
+    }
+
+    // Index the origin secret keys stored before their indexes existed, so the secrets of those
+    // origins can be stored and read. The vault serves without them if the datastore can't be
+    // reached, and the next start tries again.
+    fn backfill(&self) {
+        let result = {
+            let cfg = self.config.read().unwrap();
+            DataStore::open(cfg.deref())
+        };
+        let datastore = match result {
+            Ok(datastore) => datastore,
+            Err(e) => {
+                warn!("unable to index origin secret keys, err={}", e);
+                return;
+            }
+        };
+        match datastore.origins.origin_secret_keys.backfill() {
+            Ok(0) => (),
+            Ok(n) => info!("indexed {} origin secret keys", n),
+            Err(e) => warn!("unable to index origin secret keys, err={:?}", e),
+        }
+    }
 }
 
 impl Application for Server {
@@ -340,15 +627,35 @@ impl Application for Server {
 
     fn run(&mut self) -> Result<()> {
         try!(self.be_sock.bind(BE_LISTEN_ADDR));
+        // Workers look up the sessions of the messages they authenticate through a broker of
+        // their own, which must be told apart from the server's connection by the routers.
+        let (routers, opts) = {
+            let cfg = self.config.read().unwrap();
+            let opts = BrokerOpts {
+                auth: cfg.router_auth(),
+                keepalive: KeepaliveConfig::from_config(&*cfg),
+                ..BrokerOpts::default()
+            };
+            (cfg.route_addrs().clone(), opts)
+        };
+        let _broker = Broker::run_with_opts(format!("broker#{}", Self::net_ident()),
+                                            Arc::new(BrokerContext::shared(self.ctx.clone())),
+                                            &routers,
+                                            opts);
         let ctx = self.ctx.clone();
         let cfg = self.config.clone();
-        let sup: Supervisor<Worker> = Supervisor::new(ctx, cfg);
-        {
+        let sched = self.config.read().unwrap().worker_sched.clone();
+        let sup: Supervisor<Worker> = Supervisor::new(ctx, cfg).with_sched(sched);
+        let sup = {
             let cfg = self.config.read().unwrap();
-            try!(sup.start(BE_LISTEN_ADDR, cfg.worker_threads));
-        }
+            try!(sup.spawn(BE_LISTEN_ADDR, cfg.worker_threads))
+        };
+        self.backfill();
         try!(self.connect());
-        try!(zmq::proxy(&mut self.router.socket, &mut self.be_sock));
+        let rt = self.runtime().with(sup);
+        let router = &mut self.router;
+        let be_sock = &mut self.be_sock;
+        try!(rt.run_with(|shutdown| runtime::serve(router, be_sock, shutdown)));
         Ok(())
     }
 }
@@ -377,6 +684,8 @@ impl Service for Server {
 
 impl NetIdent for Server {}
 
-pub fn run(config: Config) -> Result<()> {
-    try!(Server::new(config)).run()
+pub fn run(config: Config, config_path: &str) -> Result<()> {
+    let mut server = try!(Server::new(config));
+    server.reload_from(config_path);
+    server.run()
 }
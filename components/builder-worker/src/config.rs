@@ -24,6 +24,12 @@ use error::{Error, Result};
 
 pub struct Config {
     pub job_servers: Vec<BTreeMap<String, String>>,
+    /// Tags naming what this worker can build. It is only sent jobs which require a subset of
+    /// them.
+    pub capabilities: Vec<String>,
+    /// Directory builds leave their artifacts in, along with a `last_build.env` report naming the
+    /// package they built.
+    pub results_path: String,
 }
 
 impl Config {
@@ -47,7 +53,11 @@ impl Default for Config {
         jobsrv.insert("ip".to_string(), "127.0.0.1".to_string());
         jobsrv.insert("port".to_string(), "5566".to_string());
         jobsrv.insert("heartbeat".to_string(), "5567".to_string());
-        Config { job_servers: vec![jobsrv] }
+        Config {
+            job_servers: vec![jobsrv],
+            capabilities: vec![],
+            results_path: "/src/results".to_string(),
+        }
     }
 }
 
@@ -58,6 +68,8 @@ impl ConfigFile for Config {
         let mut cfg = Config::default();
         // JW TODO: parse job_servers into the config
         // try!(toml.parse_into("cfg.job_servers", &mut cfg.job_servers));
+        try!(toml.parse_into("cfg.capabilities", &mut cfg.capabilities));
+        try!(toml.parse_into("cfg.results_path", &mut cfg.results_path));
         Ok(cfg)
     }
 }
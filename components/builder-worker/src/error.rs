@@ -18,6 +18,7 @@ use std::io;
 use std::result;
 
 use hab_core;
+use hab_net;
 use protobuf;
 use zmq;
 
@@ -25,8 +26,8 @@ use zmq;
 pub enum Error {
     HabitatCore(hab_core::Error),
     IO(io::Error),
+    NetError(hab_net::Error),
     Protobuf(protobuf::ProtobufError),
-    Zmq(zmq::Error),
 }
 
 pub type Result<T> = result::Result<T, Error>;
@@ -36,8 +37,8 @@ impl fmt::Display for Error {
         let msg = match *self {
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::IO(ref e) => format!("{}", e),
+            Error::NetError(ref e) => format!("{}", e),
             Error::Protobuf(ref e) => format!("{}", e),
-            Error::Zmq(ref e) => format!("{}", e),
         };
         write!(f, "{}", msg)
     }
@@ -48,8 +49,8 @@ impl error::Error for Error {
         match *self {
             Error::HabitatCore(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::NetError(ref err) => err.description(),
             Error::Protobuf(ref err) => err.description(),
-            Error::Zmq(ref err) => err.description(),
         }
     }
 }
@@ -66,6 +67,12 @@ impl From<io::Error> for Error {
     }
 }
 
+impl From<hab_net::Error> for Error {
+    fn from(err: hab_net::Error) -> Error {
+        Error::NetError(err)
+    }
+}
+
 impl From<protobuf::ProtobufError> for Error {
     fn from(err: protobuf::ProtobufError) -> Error {
         Error::Protobuf(err)
@@ -74,6 +81,6 @@ impl From<protobuf::ProtobufError> for Error {
 
 impl From<zmq::Error> for Error {
     fn from(err: zmq::Error) -> Error {
-        Error::Zmq(err)
+        Error::NetError(hab_net::Error::from(err))
     }
 }
@@ -12,17 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::Path;
 use std::sync::{mpsc, Arc, RwLock};
 use std::time::Duration;
 use std::thread::{self, JoinHandle};
 
 use hab_net::server::NetIdent;
-use protobuf::{parse_from_bytes, Message};
+use protobuf::{parse_from_bytes, Message, RepeatedField};
 use protocol;
 use zmq;
 
 use config::Config;
-use error::Result;
+use error::{Error, Result};
 
 const HEARTBEAT_MS: i64 = 30_000;
 const HB_INPROC_ADDR: &'static str = "inproc://heartbeat";
@@ -31,7 +34,9 @@ const HB_CMD_PAUSE: &'static str = "P";
 const RUNNER_INPROC_ADDR: &'static str = "inproc://runner";
 const WORK_ACK: &'static str = "A";
 const WORK_COMPLETE: &'static str = "C";
-
+const WORK_LOG: &'static str = "L";
+// The report a build leaves in the results directory.
+const BUILD_REPORT: &'static str = "last_build.env";
 
 #[cfg(target_os = "linux")]
 fn worker_os() -> protocol::jobsrv::Os {
@@ -121,13 +126,20 @@ impl Server {
             }
             if runner_sock {
                 try!(self.runner_sock.recv(&mut self.msg, 0));
-                if Some(WORK_COMPLETE) != self.msg.as_str() {
-                    unreachable!("run:1, received unexpected response from runner");
+                match self.msg.as_str() {
+                    Some(WORK_LOG) => {
+                        try!(self.runner_sock.recv(&mut self.msg, 0));
+                        try!(self.fe_sock.send_str("JobLogChunk", zmq::SNDMORE));
+                        try!(self.fe_sock.send(&*self.msg, 0));
+                    }
+                    Some(WORK_COMPLETE) => {
+                        try!(self.runner_sock.recv(&mut self.msg, 0));
+                        try!(self.fe_sock.send_str("Job", zmq::SNDMORE));
+                        try!(self.fe_sock.send(&*self.msg, 0));
+                        try!(self.set_ready());
+                    }
+                    _ => unreachable!("run:1, received unexpected response from runner"),
                 }
-
-                try!(self.runner_sock.recv(&mut self.msg, 0));
-                try!(self.fe_sock.send(&*self.msg, 0));
-                try!(self.set_ready());
                 runner_sock = false;
             }
             if fe_sock {
@@ -146,13 +158,15 @@ impl Server {
                         reply.set_id(job_id);
                         reply.set_state(protocol::jobsrv::JobState::Processing);
 
-                        try!(self.set_busy());
+                        try!(self.set_busy(job_id));
+                        try!(self.fe_sock.send_str("Job", zmq::SNDMORE));
                         try!(self.fe_sock.send(&try!(reply.write_to_bytes()), 0));
                     }
                     State::Busy => {
                         reply = parse_from_bytes(&self.msg).unwrap();
                         reply.set_state(protocol::jobsrv::JobState::Rejected);
                         let bytes = try!(reply.write_to_bytes());
+                        try!(self.fe_sock.send_str("Job", zmq::SNDMORE));
                         try!(self.fe_sock.send(&bytes, 0));
                     }
                 }
@@ -164,8 +178,9 @@ impl Server {
         Ok(())
     }
 
-    fn set_busy(&mut self) -> Result<()> {
-        try!(self.hb_conn.send_str(PulseState::Pause.as_ref(), 0));
+    fn set_busy(&mut self, job_id: u64) -> Result<()> {
+        try!(self.hb_conn.send_str(PulseState::Pause.as_ref(), zmq::SNDMORE));
+        try!(self.hb_conn.send_str(&job_id.to_string(), 0));
         try!(self.hb_conn.recv(&mut self.msg, 0));
         self.state = State::Busy;
         Ok(())
@@ -209,7 +224,6 @@ impl Default for PulseState {
 }
 
 struct Heartbeat {
-    state: PulseState,
     config: Arc<RwLock<Config>>,
     ctx: Arc<RwLock<zmq::Context>>,
     pub_sock: zmq::Socket,
@@ -232,8 +246,11 @@ impl Heartbeat {
         reg.set_endpoint(Server::net_ident());
         reg.set_os(worker_os());
         reg.set_state(protocol::jobsrv::WorkerState::Ready);
+        {
+            let cfg = config.read().unwrap();
+            reg.set_capabilities(RepeatedField::from_vec(cfg.capabilities.clone()));
+        }
         Ok(Heartbeat {
-            state: PulseState::default(),
             config: config,
             ctx: ctx,
             pub_sock: pub_sock,
@@ -274,9 +291,8 @@ impl Heartbeat {
         let mut be_sock = false;
         let mut msg = try!(zmq::Message::new());
         loop {
-            if self.state == PulseState::Pulse {
-                try!(self.pulse());
-            }
+            // A busy worker keeps pulsing to renew the lease on the job it is running.
+            try!(self.pulse());
             {
                 let mut items = [self.be_sock.as_poll_item(1)];
                 // Poll until timeout or message is received. Checking for the zmq::POLLIN flag on
@@ -291,7 +307,9 @@ impl Heartbeat {
                 try!(self.be_sock.recv(&mut msg, 0));
                 match msg.as_str() {
                     Some(HB_CMD_PAUSE) => {
-                        self.pause();
+                        try!(self.be_sock.recv(&mut msg, 0));
+                        let job_id = msg.as_str().and_then(|id| id.parse().ok()).unwrap_or(0);
+                        self.pause(job_id);
                         try!(self.be_sock.send(&[], 0));
                     }
                     Some(HB_CMD_PULSE) => {
@@ -306,16 +324,16 @@ impl Heartbeat {
         Ok(())
     }
 
-    fn pause(&mut self) {
-        debug!("heartbeat paused");
+    fn pause(&mut self, job_id: u64) {
+        debug!("heartbeat busy, job={}", job_id);
         self.reg.set_state(protocol::jobsrv::WorkerState::Busy);
-        self.state = PulseState::Pause;
+        self.reg.set_job_id(job_id);
     }
 
     fn resume(&mut self) {
-        debug!("heartbeat resumed");
+        debug!("heartbeat ready");
         self.reg.set_state(protocol::jobsrv::WorkerState::Ready);
-        self.state = PulseState::Pulse;
+        self.reg.clear_job_id();
     }
 
     fn pulse(&mut self) -> Result<()> {
@@ -326,11 +344,12 @@ impl Heartbeat {
 }
 
 pub struct Runner {
-    #[allow(dead_code)]
     config: Arc<RwLock<Config>>,
     #[allow(dead_code)]
     ctx: Arc<RwLock<zmq::Context>>,
     sock: zmq::Socket,
+    // Sequence number of the next chunk of the running job's log.
+    log_seq: u64,
 }
 
 impl Runner {
@@ -343,6 +362,7 @@ impl Runner {
             config: config,
             ctx: ctx,
             sock: sock,
+            log_seq: 0,
         })
     }
 
@@ -373,21 +393,108 @@ impl Runner {
             debug!("processing job={:?}", job);
             try!(self.sock.send_str(WORK_ACK, zmq::SNDMORE));
             try!(self.sock.send_str(&job.get_id().to_string(), 0));
-            self.execute_job(&mut job);
+            self.log_seq = 0;
+            try!(self.execute_job(&mut job));
             try!(self.sock.send_str(WORK_COMPLETE, zmq::SNDMORE));
             try!(self.sock.send(&job.write_to_bytes().unwrap(), 0));
         }
         Ok(())
     }
 
-    fn execute_job(&mut self, job: &mut protocol::jobsrv::Job) {
+    fn execute_job(&mut self, job: &mut protocol::jobsrv::Job) -> Result<()> {
+        let report = Path::new(&self.config.read().unwrap().results_path).join(BUILD_REPORT);
+        // A report left by an earlier build must not be mistaken for this one's.
+        match fs::remove_file(&report) {
+            Ok(()) => (),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => (),
+            Err(e) => return Err(Error::from(e)),
+        }
+        try!(self.log(job, &format!("Starting job {}\n", job.get_id()), false));
         thread::sleep(Duration::from_millis(5_000));
         // set Failed on failure
         debug!("job complete, {:?}", job);
         job.set_state(protocol::jobsrv::JobState::Complete);
+        match read_report(&report) {
+            Ok(Some(ident)) => job.set_package_ident(ident),
+            Ok(None) => warn!("build reported no package, job={}", job.get_id()),
+            Err(e) => {
+                warn!("unable to read build report, job={}, path={}, err={}",
+                      job.get_id(),
+                      report.display(),
+                      e)
+            }
+        }
+        try!(self.log(job, &format!("Job {} complete\n", job.get_id()), true));
+        Ok(())
+    }
+
+    // Send a chunk of the job's output to be forwarded to the job server. The last chunk of a
+    // job's output is marked complete.
+    fn log(&mut self, job: &protocol::jobsrv::Job, content: &str, complete: bool) -> Result<()> {
+        let mut chunk = protocol::jobsrv::JobLogChunk::new();
+        chunk.set_job_id(job.get_id());
+        chunk.set_attempt(job.get_attempts());
+        chunk.set_seq(self.log_seq);
+        chunk.set_content(content.to_string());
+        chunk.set_complete(complete);
+        self.log_seq += 1;
+        try!(self.sock.send_str(WORK_LOG, zmq::SNDMORE));
+        try!(self.sock.send(&try!(chunk.write_to_bytes()), 0));
+        Ok(())
     }
 }
 
+// The ident of the package a build reported building, if it left a report.
+fn read_report(path: &Path) -> io::Result<Option<String>> {
+    let mut report = String::new();
+    match File::open(path) {
+        Ok(mut file) => try!(file.read_to_string(&mut report)),
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    Ok(built_ident(&report))
+}
+
+// The `pkg_ident` of a build report, which holds one `key=value` pair per line.
+fn built_ident(report: &str) -> Option<String> {
+    report.lines()
+        .filter_map(|line| {
+            let mut pair = line.splitn(2, '=');
+            match (pair.next(), pair.next()) {
+                (Some("pkg_ident"), Some(ident)) if !ident.trim().is_empty() => {
+                    Some(ident.trim().to_string())
+                }
+                _ => None,
+            }
+        })
+        .next()
+}
+
 pub fn run(config: Config) -> Result<()> {
     try!(Server::new(config)).run()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::built_ident;
+
+    #[test]
+    fn built_ident_reads_the_build_report() {
+        let report = "pkg_origin=core\n\
+                      pkg_name=redis\n\
+                      pkg_version=3.0.7\n\
+                      pkg_release=20160614230104\n\
+                      pkg_ident=core/redis/3.0.7/20160614230104\n\
+                      pkg_artifact=core-redis-3.0.7-20160614230104-x86_64-linux.hart\n";
+        assert_eq!(built_ident(report),
+                   Some("core/redis/3.0.7/20160614230104".to_string()));
+    }
+
+    #[test]
+    fn built_ident_needs_a_pkg_ident() {
+        assert_eq!(built_ident(""), None);
+        assert_eq!(built_ident("pkg_origin=core\npkg_name=redis\n"), None);
+        assert_eq!(built_ident("pkg_ident=\n"), None);
+        assert_eq!(built_ident("pkg_identity=core/redis\n"), None);
+    }
+}
@@ -19,37 +19,63 @@ use std::io::{self, Write};
 use pbr;
 use depot_client::DisplayProgress;
 
+use ui;
+
 /// A moving progress bar to track progress of a sized event, similar to wget, curl, npm, etc.
 ///
 /// This is designed to satisfy a generic behavior which sets the size of the task (usually a
 /// number of bytes representing the total download/upload/transfer size) and will be a generic
 /// writer (i.e. implementing the `Write` trait) as a means to increase progress towards
 /// completion.
+///
+/// Nothing is drawn unless output is in the human format.
 pub struct ProgressBar {
-    bar: pbr::ProgressBar,
+    bar: Option<pbr::ProgressBar>,
 }
 
 impl Default for ProgressBar {
     fn default() -> Self {
-        ProgressBar { bar: pbr::ProgressBar::new(0) }
+        ProgressBar { bar: None }
     }
 }
 
 impl DisplayProgress for ProgressBar {
     fn size(&mut self, size: u64) {
-        self.bar = pbr::ProgressBar::new(size);
-        self.bar.set_units(pbr::Units::Bytes);
-        self.bar.show_tick = true;
-        self.bar.message("    ");
+        if !ui::is_human() {
+            return;
+        }
+        let mut bar = pbr::ProgressBar::new(size);
+        bar.set_units(pbr::Units::Bytes);
+        bar.show_tick = true;
+        bar.message("    ");
+        self.bar = Some(bar);
+    }
+
+    fn advance(&mut self, count: u64) {
+        if let Some(ref mut bar) = self.bar {
+            bar.add(count);
+        }
+    }
+
+    fn retry(&mut self, attempt: usize, _offset: u64) {
+        if let Some(ref mut bar) = self.bar {
+            bar.message(&format!("retry {} ", attempt));
+        }
     }
 }
 
 impl Write for ProgressBar {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        self.bar.write(buf)
+        match self.bar {
+            Some(ref mut bar) => bar.write(buf),
+            None => Ok(buf.len()),
+        }
     }
 
     fn flush(&mut self) -> io::Result<()> {
-        self.bar.flush()
+        match self.bar {
+            Some(ref mut bar) => bar.flush(),
+            None => Ok(()),
+        }
     }
 }
@@ -28,6 +28,18 @@
 //!
 //! This would install the `3.0.1` version of redis.
 //!
+//! Large artifacts are downloaded in ranges over several connections when the depot supports
+//! it. `HAB_DOWNLOAD_CONCURRENCY` sets how many ranges are fetched at once (`1` downloads over a
+//! single connection) and `HAB_DOWNLOAD_CHUNK_SIZE` the size of each, such as `16MB`. A download
+//! which fails part way through resumes from where it left off, up to `HAB_DOWNLOAD_RETRIES`
+//! times.
+//!
+//! Mirrors of the depot may be listed, comma separated, in `HAB_DEPOT_MIRRORS`. Packages and keys
+//! which can't be fetched from the depot, because it can't be reached or doesn't have them, are
+//! fetched from the first mirror which can provide them instead. Setting
+//! `HAB_DEPOT_MIRROR_ORDER=latency` tries the fastest to respond first. The URL of the depot or
+//! mirror each package was downloaded from is kept in the `DEPOT` file of its installed path.
+//!
 //! # Internals
 //!
 //! * Download the artifact
@@ -37,43 +49,163 @@
 
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 
 use ansi_term::Colour::{Blue, Green, Yellow};
-use depot_client::Client;
+use depot_client::{self, Client, DownloadOpts};
 use hcore::crypto::{artifact, SigKeyPair};
 use hcore::crypto::keys::parse_name_with_rev;
+use hcore::env as henv;
 use hcore::fs::cache_artifact_path;
 use hcore::package::{Identifiable, PackageArchive, PackageIdent, PackageInstall};
+use hcore::url::default_depot_mirrors;
 use protocol::depotsrv;
 
 use command::ProgressBar;
 use error::Result;
+use ui;
+
+/// Order in which mirrors are tried environment variable, either `listed` or `latency`
+pub const MIRROR_ORDER_ENVVAR: &'static str = "HAB_DEPOT_MIRROR_ORDER";
+/// How long a depot has to respond when mirrors are ordered by latency
+const PING_TIMEOUT_MS: u64 = 2_000;
+
+/// The depot packages are installed from followed by its mirrors.
+struct Depots {
+    depots: Vec<(String, Client)>,
+}
+
+impl Depots {
+    fn new(url: &str, fs_root_path: &Path) -> Result<Self> {
+        let by_latency = henv::var(MIRROR_ORDER_ENVVAR).map(|v| v == "latency").unwrap_or(false);
+        Self::from_urls(url, default_depot_mirrors(), fs_root_path, by_latency)
+    }
+
+    fn from_urls(url: &str,
+                 mirrors: Vec<String>,
+                 fs_root_path: &Path,
+                 by_latency: bool)
+                 -> Result<Self> {
+        let mut urls = vec![url.to_string()];
+        for mirror in mirrors {
+            if !urls.contains(&mirror) {
+                urls.push(mirror);
+            }
+        }
+        let mut depots = vec![];
+        for url in urls {
+            let mut client = try!(Client::new(&url[..], Some(fs_root_path)));
+            client.set_download_opts(DownloadOpts::from_env());
+            depots.push((url, client));
+        }
+        if by_latency && depots.len() > 1 {
+            let timed = depots.into_iter()
+                .map(|(url, client)| {
+                    let latency = match client.ping(Duration::from_millis(PING_TIMEOUT_MS)) {
+                        Ok(latency) => Some(latency),
+                        Err(e) => {
+                            debug!("Depot {} did not respond, err={:?}", url, e);
+                            None
+                        }
+                    };
+                    (latency, (url, client))
+                })
+                .collect();
+            depots = by_latency_order(timed);
+        }
+        Ok(Depots { depots: depots })
+    }
+
+    /// The URL of the depot which is tried first.
+    fn primary(&self) -> &str {
+        &self.depots[0].0
+    }
+
+    /// Run `f` against each depot in turn until one succeeds, returning its result along with
+    /// the URL of the depot which provided it. A depot which can't be reached, or doesn't have
+    /// what was asked for, is passed over for the next one.
+    fn first<T, F>(&self, mut f: F) -> Result<(T, &str)>
+        where F: FnMut(&Client) -> depot_client::Result<T>
+    {
+        let mut last_err = None;
+        for &(ref url, ref client) in self.depots.iter() {
+            match f(client) {
+                Ok(value) => return Ok((value, url)),
+                Err(e) => {
+                    if !can_fall_back(&e) {
+                        return Err(e.into());
+                    }
+                    if self.depots.len() > 1 {
+                        ui::warn(format!("{} {}: {}",
+                                         Yellow.paint("✗ Unavailable from"),
+                                         url,
+                                         e));
+                    }
+                    last_err = Some(e);
+                }
+            }
+        }
+        Err(last_err.unwrap().into())
+    }
+}
+
+/// Depots sorted by how long they took to respond, fastest first. Depots which didn't respond
+/// are tried last, in the order they were listed.
+fn by_latency_order<T>(timed: Vec<(Option<Duration>, T)>) -> Vec<T> {
+    let mut timed = timed;
+    timed.sort_by(|a, b| {
+        match (a.0, b.0) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (a, b) => b.is_some().cmp(&a.is_some()),
+        }
+    });
+    timed.into_iter().map(|(_, depot)| depot).collect()
+}
+
+/// Whether a failure to fetch something from one depot may be made up for by another. A
+/// download which doesn't match its checksum may be a bad copy on one mirror.
+fn can_fall_back(err: &depot_client::Error) -> bool {
+    match *err {
+        depot_client::Error::DownloadChecksumMismatch(_) |
+        depot_client::Error::DownloadInterrupted(_) |
+        depot_client::Error::HyperError(_) |
+        depot_client::Error::InvalidRange(_) |
+        depot_client::Error::RemoteOriginKeyNotFound(_) |
+        depot_client::Error::RemotePackageNotFound(_) => true,
+        depot_client::Error::HTTP(ref status) => {
+            status.to_u16() == 404 || status.is_server_error()
+        }
+        _ => false,
+    }
+}
 
+/// Install a package, given either its identifier or the path to its artifact, returning the
+/// identifier of the release installed.
 pub fn start<P1: ?Sized, P2: ?Sized, P3: ?Sized>(url: &str,
                                                  ident_or_archive: &str,
                                                  fs_root_path: &P1,
                                                  cache_artifact_path: &P2,
                                                  cache_key_path: &P3)
-                                                 -> Result<()>
+                                                 -> Result<PackageIdent>
     where P1: AsRef<Path>,
           P2: AsRef<Path>,
           P3: AsRef<Path>
 {
     if Path::new(ident_or_archive).is_file() {
-        try!(from_archive(url,
-                          &ident_or_archive,
-                          fs_root_path,
-                          cache_artifact_path,
-                          cache_key_path));
+        from_archive(url,
+                     &ident_or_archive,
+                     fs_root_path,
+                     cache_artifact_path,
+                     cache_key_path)
     } else {
         let ident = try!(PackageIdent::from_str(ident_or_archive));
-        try!(from_url(url,
-                      &ident,
-                      fs_root_path,
-                      cache_artifact_path,
-                      cache_key_path));
+        let package = try!(from_url(url,
+                                    &ident,
+                                    fs_root_path,
+                                    cache_artifact_path,
+                                    cache_key_path));
+        Ok(package.get_ident().clone().into())
     }
-    Ok(())
 }
 
 /// Given a package name and a base url, downloads the package
@@ -81,7 +213,7 @@ pub fn start<P1: ?Sized, P2: ?Sized, P3: ?Sized>(url: &str,
 ///
 /// # Failures
 ///
-/// * Fails if it cannot download the package from the upstream
+/// * Fails if it cannot download the package from the upstream or any of its mirrors
 pub fn from_url<P1: ?Sized, P2: ?Sized, P3: ?Sized>(url: &str,
                                                     ident: &PackageIdent,
                                                     fs_root_path: &P1,
@@ -92,29 +224,27 @@ pub fn from_url<P1: ?Sized, P2: ?Sized, P3: ?Sized>(url: &str,
           P2: AsRef<Path>,
           P3: AsRef<Path>
 {
-    println!("{}",
-             Yellow.bold().paint(format!("» Installing {}", ident)));
-    let depot_client = try!(Client::new(url, Some(fs_root_path.as_ref())));
-    let pkg_data = try!(depot_client.show_package(ident.clone()));
+    ui::status(Yellow.bold().paint(format!("» Installing {}", ident)));
+    let depots = try!(Depots::new(url, fs_root_path.as_ref()));
+    let (pkg_data, _) = try!(depots.first(|client| client.show_package(ident.clone())));
     for dep in pkg_data.get_tdeps().into_iter() {
         let d: PackageIdent = (*dep).clone().into();
-        try!(install_from_depot(url,
+        try!(install_from_depot(&depots,
                                 &d,
                                 &d,
                                 fs_root_path.as_ref(),
                                 cache_artifact_path.as_ref(),
                                 cache_key_path.as_ref()));
     }
-    try!(install_from_depot(url,
+    try!(install_from_depot(&depots,
                             &pkg_data.get_ident().clone().into(),
                             ident,
                             fs_root_path.as_ref(),
                             cache_artifact_path.as_ref(),
                             cache_key_path.as_ref()));
-    println!("{}",
-             Blue.paint(format!("★ Install of {} complete with {} packages installed.",
-                                ident,
-                                1 + &pkg_data.get_tdeps().len())));
+    ui::status(Blue.paint(format!("★ Install of {} complete with {} packages installed.",
+                                  ident,
+                                  1 + &pkg_data.get_tdeps().len())));
     Ok(pkg_data)
 }
 
@@ -123,38 +253,37 @@ pub fn from_archive<P1: ?Sized, P2: ?Sized, P3: ?Sized, P4: ?Sized>(url: &str,
                                                                     fs_root_path: &P2,
                                                                     cache_artifact_path: &P3,
                                                                     cache_key_path: &P4)
-                                                                    -> Result<()>
+                                                                    -> Result<PackageIdent>
     where P1: AsRef<Path>,
           P2: AsRef<Path>,
           P3: AsRef<Path>,
           P4: AsRef<Path>
 {
-    println!("{}",
-             Yellow.bold().paint(format!("» Installing {}", path.as_ref().display())));
+    ui::status(Yellow.bold().paint(format!("» Installing {}", path.as_ref().display())));
+    let depots = try!(Depots::new(url, fs_root_path.as_ref()));
     let mut archive = PackageArchive::new(PathBuf::from(path.as_ref()));
     let ident = try!(archive.ident());
     let tdeps = try!(archive.tdeps());
     for dep in &tdeps {
-        try!(install_from_depot(url,
+        try!(install_from_depot(&depots,
                                 &dep,
                                 dep.as_ref(),
                                 fs_root_path.as_ref(),
                                 cache_artifact_path.as_ref(),
                                 cache_key_path.as_ref()));
     }
-    try!(install_from_archive(url,
+    try!(install_from_archive(&depots,
                               archive,
                               &ident,
                               fs_root_path.as_ref(),
                               cache_key_path.as_ref()));
-    println!("{}",
-             Blue.paint(format!("★ Install of {} complete with {} packages installed.",
-                                &ident,
-                                1 + &tdeps.len())));
-    Ok(())
+    ui::status(Blue.paint(format!("★ Install of {} complete with {} packages installed.",
+                                  &ident,
+                                  1 + &tdeps.len())));
+    Ok(ident)
 }
 
-fn install_from_depot(url: &str,
+fn install_from_depot(depots: &Depots,
                       ident: &PackageIdent,
                       given_ident: &PackageIdent,
                       fs_root_path: &Path,
@@ -164,33 +293,42 @@ fn install_from_depot(url: &str,
     match PackageInstall::load(ident, Some(&fs_root_path)) {
         Ok(_) => {
             if given_ident.fully_qualified() {
-                println!("{} {}", Green.paint("→ Using"), ident);
+                ui::status(format!("{} {}", Green.paint("→ Using"), ident));
             } else {
-                println!("{} {} which satisfies {}",
-                         Green.paint("→ Using"),
-                         given_ident,
-                         ident.as_ref());
+                ui::status(format!("{} {} which satisfies {}",
+                                   Green.paint("→ Using"),
+                                   given_ident,
+                                   ident.as_ref()));
             }
         }
         Err(_) => {
-            println!("{} {}",
-                     Green.bold().paint("↓ Downloading"),
-                     ident.as_ref());
+            ui::status(format!("{} {}",
+                               Green.bold().paint("↓ Downloading"),
+                               ident.as_ref()));
             let mut progress = ProgressBar::default();
-            let depot_client = try!(Client::new(url, Some(fs_root_path)));
-            let mut archive = try!(depot_client.fetch_package((*ident).clone(),
-                                                               cache_artifact_path,
-                                                               Some(&mut progress)));
+            let (mut archive, url) = try!(depots.first(|client| {
+                client.fetch_package((*ident).clone(), cache_artifact_path, Some(&mut progress))
+            }));
             let ident = try!(archive.ident());
-            try!(verify(url, &archive, &ident, fs_root_path, cache_key_path));
+            try!(verify(depots, &archive, &ident, cache_key_path));
             try!(archive.unpack(Some(fs_root_path)));
-            println!("{} {}", Green.bold().paint("✓ Installed"), ident.as_ref());
+            try!(try!(PackageInstall::load(&ident, Some(fs_root_path))).set_depot(url));
+            if url == depots.primary() {
+                ui::status(format!("{} {}",
+                                   Green.bold().paint("✓ Installed"),
+                                   ident.as_ref()));
+            } else {
+                ui::status(format!("{} {} from mirror {}",
+                                   Green.bold().paint("✓ Installed"),
+                                   ident.as_ref(),
+                                   url));
+            }
         }
     }
     Ok(())
 }
 
-fn install_from_archive(url: &str,
+fn install_from_archive(depots: &Depots,
                         archive: PackageArchive,
                         ident: &PackageIdent,
                         fs_root_path: &Path,
@@ -198,43 +336,162 @@ fn install_from_archive(url: &str,
                         -> Result<()> {
     match PackageInstall::load(ident.as_ref(), Some(&fs_root_path)) {
         Ok(_) => {
-            println!("{} {}", Green.paint("→ Using"), ident);
+            ui::status(format!("{} {}", Green.paint("→ Using"), ident));
         }
         Err(_) => {
-            println!("{} {} from cache",
-                     Green.bold().paint("← Extracting"),
-                     ident);
-            try!(verify(url, &archive, &ident, fs_root_path, cache_key_path));
+            ui::status(format!("{} {} from cache",
+                               Green.bold().paint("← Extracting"),
+                               ident));
+            try!(verify(depots, &archive, &ident, cache_key_path));
             try!(archive.unpack(Some(fs_root_path)));
-            println!("{} {}", Green.bold().paint("✓ Installed"), ident);
+            ui::status(format!("{} {}", Green.bold().paint("✓ Installed"), ident));
         }
     }
     Ok(())
 }
 
 /// get the signer for the artifact and see if we have the key locally.
-/// If we don't, attempt to download it from the depot.
-fn verify(url: &str,
+/// If we don't, attempt to download it from the depot or one of its mirrors.
+fn verify(depots: &Depots,
           archive: &PackageArchive,
           ident: &PackageIdent,
-          fs_root_path: &Path,
           cache_key_path: &Path)
           -> Result<()> {
     let nwr = try!(artifact::artifact_signer(&archive.path));
     if let Err(_) = SigKeyPair::get_public_key_path(&nwr, cache_key_path) {
-        println!("{} {} public origin key",
-                 Green.bold().paint("↓ Downloading"),
-                 &nwr);
+        ui::status(format!("{} {} public origin key",
+                           Green.bold().paint("↓ Downloading"),
+                           &nwr));
         let (name, rev) = try!(parse_name_with_rev(&nwr));
         let mut progress = ProgressBar::default();
-        let depot_client = try!(Client::new(url, Some(fs_root_path)));
-        try!(depot_client.fetch_origin_key(&name, &rev, cache_key_path, Some(&mut progress)));
-        println!("{} {} public origin key",
-                 Green.bold().paint("☑ Cached"),
-                 &nwr);
+        let (_, url) = try!(depots.first(|client| {
+            client.fetch_origin_key(&name, &rev, cache_key_path, Some(&mut progress))
+        }));
+        ui::status(format!("{} {} public origin key",
+                           Green.bold().paint("☑ Cached"),
+                           &nwr));
+        info!("Fetched {} public origin key from {}", &nwr, url);
     }
 
     try!(archive.verify(&cache_key_path));
     info!("Verified {} signed by {}", &ident, &nwr);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::fs;
+    use std::path::Path;
+    use std::time::Duration;
+
+    use depot_client;
+    use hcore::package::PackageIdent;
+    use tempdir::TempDir;
+
+    use super::{by_latency_order, can_fall_back, Depots};
+
+    const ARCHIVE: &'static str = "happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart";
+
+    fn ident() -> PackageIdent {
+        "happyhumans/possums".parse().unwrap()
+    }
+
+    fn file_url(dir: &TempDir) -> String {
+        format!("file://{}", dir.path().display())
+    }
+
+    /// A depot in a local directory holding the fixture archive.
+    fn depot_with_archive() -> TempDir {
+        let dir = TempDir::new("install-depot").unwrap();
+        fs::copy(Path::new("../core/tests/fixtures").join(ARCHIVE),
+                 dir.path().join(ARCHIVE))
+            .unwrap();
+        dir
+    }
+
+    #[test]
+    fn mirrors_are_tried_in_order_without_the_depot_twice() {
+        let fs_root = TempDir::new("install-root").unwrap();
+        let primary = TempDir::new("install-depot").unwrap();
+        let mirror = TempDir::new("install-depot").unwrap();
+        let mirrors = vec![file_url(&primary), file_url(&mirror)];
+        let depots = Depots::from_urls(&file_url(&primary), mirrors, fs_root.path(), false)
+            .unwrap();
+        let urls: Vec<&str> = depots.depots.iter().map(|&(ref url, _)| &url[..]).collect();
+        assert_eq!(urls, vec![&file_url(&primary)[..], &file_url(&mirror)[..]]);
+        assert_eq!(depots.primary(), file_url(&primary));
+    }
+
+    #[test]
+    fn first_falls_back_to_a_mirror_which_has_the_package() {
+        let fs_root = TempDir::new("install-root").unwrap();
+        let primary = TempDir::new("install-depot").unwrap();
+        let mirror = depot_with_archive();
+        let depots = Depots::from_urls(&file_url(&primary),
+                                       vec![file_url(&mirror)],
+                                       fs_root.path(),
+                                       false)
+            .unwrap();
+        let (package, url) = depots.first(|client| client.show_package(ident())).unwrap();
+        assert_eq!(package.get_ident().get_release(), "20160427165340");
+        assert_eq!(url, file_url(&mirror));
+    }
+
+    #[test]
+    fn first_stops_at_an_error_another_depot_cant_make_up_for() {
+        let fs_root = TempDir::new("install-root").unwrap();
+        let primary = TempDir::new("install-depot").unwrap();
+        let mirror = depot_with_archive();
+        let depots = Depots::from_urls(&file_url(&primary),
+                                       vec![file_url(&mirror)],
+                                       fs_root.path(),
+                                       false)
+            .unwrap();
+        let calls = Cell::new(0);
+        let result = depots.first(|_| -> depot_client::Result<()> {
+            calls.set(calls.get() + 1);
+            Err(depot_client::Error::NoXFilename)
+        });
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn first_fails_when_no_depot_has_the_package() {
+        let fs_root = TempDir::new("install-root").unwrap();
+        let primary = TempDir::new("install-depot").unwrap();
+        let mirror = TempDir::new("install-depot").unwrap();
+        let depots = Depots::from_urls(&file_url(&primary),
+                                       vec![file_url(&mirror)],
+                                       fs_root.path(),
+                                       false)
+            .unwrap();
+        let calls = Cell::new(0);
+        let result = depots.first(|client| {
+            calls.set(calls.get() + 1);
+            client.show_package(ident())
+        });
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn by_latency_order_puts_the_fastest_first_and_the_unresponsive_last() {
+        let ms = Duration::from_millis;
+        let timed = vec![(None, "a"), (Some(ms(30)), "b"), (None, "c"), (Some(ms(10)), "d")];
+        assert_eq!(by_latency_order(timed), vec!["d", "b", "a", "c"]);
+    }
+
+    #[test]
+    fn can_fall_back_on_errors_another_depot_may_not_have() {
+        assert!(can_fall_back(&depot_client::Error::RemotePackageNotFound(ident())));
+        assert!(can_fall_back(&depot_client::Error::RemoteOriginKeyNotFound("core".to_string())));
+        assert!(can_fall_back(&depot_client::Error::DownloadInterrupted(10)));
+        assert!(can_fall_back(&depot_client::Error::DownloadChecksumMismatch("a.hart"
+            .to_string())));
+        assert!(can_fall_back(&depot_client::Error::InvalidRange(10)));
+        assert!(!can_fall_back(&depot_client::Error::NoXFilename));
+        assert!(!can_fall_back(&depot_client::Error::WriteSyncFailed));
+    }
+}
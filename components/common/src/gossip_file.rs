@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::fs::File;
 use std::io::prelude::*;
@@ -446,6 +446,18 @@ impl GossipFileList {
         true
     }
 
+    /// Returns the version number of every file gossiped to each service group, keyed by the
+    /// service group and then by the file name.
+    pub fn versions(&self) -> BTreeMap<String, BTreeMap<String, u64>> {
+        let mut versions = BTreeMap::new();
+        for (&(ref sg, ref file_name), ref gf) in self.gossip_files.iter() {
+            versions.entry(format!("{}.{}", sg.service, sg.group))
+                .or_insert(BTreeMap::new())
+                .insert(file_name.clone(), gf.version_number);
+        }
+        versions
+    }
+
     fn get_mut(&mut self,
                service_group: &ServiceGroup,
                file_name: &str)
@@ -17,6 +17,8 @@ extern crate habitat_core as hcore;
 extern crate habitat_depot_client as depot_client;
 extern crate ansi_term;
 #[macro_use]
+extern crate lazy_static;
+#[macro_use]
 extern crate log;
 extern crate openssl;
 extern crate pbr;
@@ -32,4 +34,5 @@ pub use self::error::{Error, Result};
 pub mod command;
 pub mod gossip_file;
 pub mod error;
+pub mod ui;
 pub mod wire_message;
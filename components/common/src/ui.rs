@@ -0,0 +1,148 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Output of the command line tools, in the format asked for on the command line.
+//!
+//! Commands print their progress with `status` and `warn` and their outcome with `report`,
+//! rather than printing directly. In the default human format everything is printed. With
+//! `--json` only the outcome is printed, as a single JSON document on stdout which scripts can
+//! parse, and warnings go to stderr. With `--quiet` nothing but errors is printed, which suits
+//! commands run from cron.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+
+use rustc_serialize::json::{Json, ToJson};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Human,
+    Json,
+    Quiet,
+}
+
+static FORMAT: AtomicUsize = ATOMIC_USIZE_INIT;
+
+/// Print everything from now on in the given format.
+pub fn set_format(format: Format) {
+    FORMAT.store(encode(format), Ordering::SeqCst);
+}
+
+pub fn format() -> Format {
+    decode(FORMAT.load(Ordering::SeqCst))
+}
+
+// The format as stored in `FORMAT`, which starts out holding the human format.
+fn encode(format: Format) -> usize {
+    match format {
+        Format::Human => 0,
+        Format::Json => 1,
+        Format::Quiet => 2,
+    }
+}
+
+fn decode(value: usize) -> Format {
+    match value {
+        1 => Format::Json,
+        2 => Format::Quiet,
+        _ => Format::Human,
+    }
+}
+
+/// Whether output is for a person at a terminal, so progress is worth showing.
+pub fn is_human() -> bool {
+    format() == Format::Human
+}
+
+/// Print a line of progress for a person watching the command run.
+pub fn status<T: fmt::Display>(line: T) {
+    if is_human() {
+        println!("{}", line);
+    }
+}
+
+/// Print something which went wrong without stopping the command. Kept off stdout in the JSON
+/// format, so as not to corrupt the document printed there.
+pub fn warn<T: fmt::Display>(line: T) {
+    match format() {
+        Format::Human => println!("{}", line),
+        Format::Json => {
+            let _ = writeln!(io::stderr(), "{}", line);
+        }
+        Format::Quiet => (),
+    }
+}
+
+/// Print the outcome of a command: `human` prints it for people, and in the JSON format `json`
+/// is printed instead.
+pub fn report<T: ToJson, F: FnOnce()>(json: &T, human: F) {
+    match format() {
+        Format::Human => human(),
+        Format::Json => println!("{}", json.to_json().pretty()),
+        Format::Quiet => (),
+    }
+}
+
+/// Print an error which stopped the command, in every format. In the JSON format it is printed
+/// on stdout as `{"error": <message>}`, in place of the outcome, or added to `outcome` for a
+/// command which failed with one, so that a single document is printed either way.
+pub fn fatal<T: fmt::Display, F: FnOnce()>(err: T, outcome: Option<Json>, human: F) {
+    match format() {
+        Format::Json => println!("{}", error_document(&err.to_string(), outcome).pretty()),
+        _ => human(),
+    }
+}
+
+fn error_document(err: &str, outcome: Option<Json>) -> Json {
+    let mut m = match outcome {
+        Some(Json::Object(m)) => m,
+        _ => BTreeMap::new(),
+    };
+    m.insert("error".to_string(), err.to_json());
+    Json::Object(m)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use rustc_serialize::json::{Json, ToJson};
+
+    use super::{decode, encode, error_document, Format};
+
+    #[test]
+    fn formats_are_stored_and_read_back() {
+        for format in &[Format::Human, Format::Json, Format::Quiet] {
+            assert_eq!(decode(encode(*format)), *format);
+        }
+        // Until a format is set the human one is used.
+        assert_eq!(decode(0), Format::Human);
+        assert_eq!(decode(7), Format::Human);
+    }
+
+    #[test]
+    fn error_document_stands_alone_or_joins_the_outcome() {
+        let alone = error_document("no such package", None);
+        assert_eq!(alone.find("error"), Some(&"no such package".to_json()));
+        assert_eq!(alone.as_object().unwrap().len(), 1);
+
+        let mut outcome = BTreeMap::new();
+        outcome.insert("failed".to_string(), vec!["core/redis".to_string()].to_json());
+        let joined = error_document("1 failed", Some(Json::Object(outcome)));
+        assert_eq!(joined.find("error"), Some(&"1 failed".to_json()));
+        assert_eq!(joined.find("failed"), Some(&vec!["core/redis".to_string()].to_json()));
+    }
+}
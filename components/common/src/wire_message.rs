@@ -20,15 +20,41 @@
 //! optionally be encrypted or decrypted with a symmetric encryption key. The message itself
 //! contains extra information if encryption is used, and blank if the message is plaintext.
 
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::str;
+use std::sync::RwLock;
 
 use hcore::crypto::SymKey;
+use hcore::crypto::keys::parse_name_with_rev;
 use rustc_serialize::{Decodable, Encodable, json};
 
 use error::{Error, Result};
 
 const WIRE_VERSION: &'static str = "WIRE-1";
 
+lazy_static! {
+    // Other revisions of ring keys which messages were encrypted with, by key cache and name
+    // with revision, so each is only read from its key cache once.
+    static ref ROTATED_KEYS: RwLock<HashMap<(PathBuf, String), SymKey>> =
+        RwLock::new(HashMap::new());
+}
+
+/// Returns the given revision of a ring key, reading it from the key cache the first time.
+fn load_rotated_key(name_with_rev: &str, cache_key_path: &Path) -> Option<SymKey> {
+    let id = (cache_key_path.to_path_buf(), name_with_rev.to_string());
+    if let Some(key) = ROTATED_KEYS.read().unwrap().get(&id) {
+        return Some(key.clone());
+    }
+    match SymKey::get_pair_for(name_with_rev, cache_key_path) {
+        Ok(key) => {
+            ROTATED_KEYS.write().unwrap().insert(id, key.clone());
+            Some(key)
+        }
+        Err(_) => None,
+    }
+}
+
 /// The types of valid messages: currently `Plain` or `Encrypted`.
 #[derive(Debug, PartialEq, RustcEncodable, RustcDecodable)]
 pub enum MessageFormat {
@@ -185,10 +211,14 @@ impl WireMessage {
     ///
     ///     let bonham = Person { given_name: "John".to_string(), surname: "Bonham".to_string() };
     ///     let encrypted = WireMessage::encrypted(&bonham, &sym_key).unwrap();
-    ///     let result: Person = encrypted.msg(Some(&sym_key)).unwrap();
+    ///     let result: Person = encrypted.msg(Some(&sym_key), cache.path()).unwrap();
     ///
     ///     // The result message matches the input message
     ///     assert_eq!(result, bonham);
+    ///
+    ///     // Plaintext messages are rejected when a key is loaded
+    ///     let plain = WireMessage::plain(&bonham).unwrap();
+    ///     assert!(plain.msg::<Person, _>(Some(&sym_key), cache.path()).is_err());
     /// }
     /// ```
     ///
@@ -197,9 +227,20 @@ impl WireMessage {
     /// * If the message can't be decoded from bytes
     /// * If the wire message is malformed, that is, missing fields required for encrypted messages
     /// * If a required key with revision is not present for decrypting
-    pub fn msg<T: Decodable>(&self, sym_key: Option<&SymKey>) -> Result<T> {
+    /// * If the message is plaintext but a key is loaded, as unencrypted traffic is not
+    ///   authenticated and must be rejected when a ring key is in use
+    pub fn msg<T: Decodable, P: AsRef<Path> + ?Sized>(&self,
+                                                     sym_key: Option<&SymKey>,
+                                                     cache_key_path: &P)
+                                                     -> Result<T> {
         match self.format {
             MessageFormat::Plain => {
+                if let Some(k) = sym_key.as_ref() {
+                    let msg = format!("Message is plaintext but key {} is loaded; only \
+                                      encrypted messages are accepted.",
+                                      k.name_with_rev());
+                    return Err(Error::WireDecode(msg));
+                }
                 let msg_str = try!(str::from_utf8(&self.msg_bytes));
                 let decoded: T = try!(json::decode(msg_str));
                 Ok(decoded)
@@ -228,12 +269,31 @@ impl WireMessage {
                         return Err(Error::WireDecode(msg));
                     }
                 };
-                if key_name_with_rev != &sym_key.name_with_rev() {
-                    let msg = format!("Loaded key {} does not match message encrypted with key {}",
-                                      &sym_key.name_with_rev(),
-                                      key_name_with_rev);
-                    return Err(Error::WireDecode(msg));
-                }
+                let rotated_key;
+                let sym_key = if key_name_with_rev == &sym_key.name_with_rev() {
+                    *sym_key
+                } else {
+                    // The message may be encrypted with another revision of our ring key, for
+                    // example while the key is being rotated. Accept it if that revision is in
+                    // the key cache.
+                    let loaded = match parse_name_with_rev(key_name_with_rev) {
+                        Ok((ref name, _)) if name == &sym_key.name => {
+                            load_rotated_key(key_name_with_rev, cache_key_path.as_ref())
+                        }
+                        _ => None,
+                    };
+                    rotated_key = match loaded {
+                        Some(k) => k,
+                        None => {
+                            let msg = format!("Loaded key {} does not match message encrypted \
+                                               with key {}",
+                                              &sym_key.name_with_rev(),
+                                              key_name_with_rev);
+                            return Err(Error::WireDecode(msg));
+                        }
+                    };
+                    &rotated_key
+                };
                 let msg = try!(sym_key.decrypt(&nonce[..], &self.msg_bytes[..]));
                 let msg_str = try!(str::from_utf8(&msg));
                 let decoded: T = try!(json::decode(msg_str));
@@ -242,3 +302,43 @@ impl WireMessage {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::thread;
+    use std::time::Duration;
+
+    use hcore::crypto::SymKey;
+    use tempdir::TempDir;
+
+    use super::WireMessage;
+
+    #[test]
+    fn msg_decrypts_with_another_revision_of_the_ring_key() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let old_key = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+        // Revisions are timestamps to the second
+        thread::sleep(Duration::from_millis(1100));
+        let new_key = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+        let encrypted = WireMessage::encrypted(&"crazy in love".to_string(), &old_key).unwrap();
+        let result: String = encrypted.msg(Some(&new_key), cache.path()).unwrap();
+        assert_eq!(result, "crazy in love");
+
+        // The old revision is only read from the key cache once
+        for entry in fs::read_dir(cache.path()).unwrap() {
+            fs::remove_file(entry.unwrap().path()).unwrap();
+        }
+        let result: String = encrypted.msg(Some(&new_key), cache.path()).unwrap();
+        assert_eq!(result, "crazy in love");
+    }
+
+    #[test]
+    fn msg_rejects_a_key_of_another_ring() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let other_key = SymKey::generate_pair_for_ring("jayz", cache.path()).unwrap();
+        let sym_key = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+        let encrypted = WireMessage::encrypted(&"crazy in love".to_string(), &other_key).unwrap();
+        assert!(encrypted.msg::<String, _>(Some(&sym_key), cache.path()).is_err());
+    }
+}
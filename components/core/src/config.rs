@@ -13,12 +13,15 @@
 // limitations under the License.
 
 use std;
+use std::collections::BTreeMap;
+use std::fmt;
 use std::fs::File;
 use std::io::Read;
 use std::net;
 use std::path::Path;
 use std::result;
 use std::str::FromStr;
+use std::time::Duration;
 
 use toml;
 
@@ -100,6 +103,30 @@ impl ParseInto<net::SocketAddrV4> for toml::Value {
     }
 }
 
+impl ParseInto<Option<net::SocketAddrV4>> for toml::Value {
+    fn parse_into(&self,
+                  field: &'static str,
+                  out: &mut Option<net::SocketAddrV4>)
+                  -> Result<bool> {
+        if let Some(val) = self.lookup(field) {
+            if let Some(v) = val.as_str() {
+                match net::SocketAddrV4::from_str(v) {
+                    Ok(addr) => {
+                        *out = Some(addr);
+                        Ok(true)
+                    }
+                    Err(_) => Err(Error::ConfigInvalidSocketAddrV4(field)),
+                }
+            } else {
+                Err(Error::ConfigInvalidSocketAddrV4(field))
+            }
+        } else {
+            *out = None;
+            Ok(true)
+        }
+    }
+}
+
 impl ParseInto<net::Ipv4Addr> for toml::Value {
     fn parse_into(&self, field: &'static str, out: &mut net::Ipv4Addr) -> Result<bool> {
         if let Some(val) = self.lookup(field) {
@@ -120,6 +147,32 @@ impl ParseInto<net::Ipv4Addr> for toml::Value {
     }
 }
 
+impl ParseInto<Vec<net::Ipv4Addr>> for toml::Value {
+    fn parse_into(&self, field: &'static str, out: &mut Vec<net::Ipv4Addr>) -> Result<bool> {
+        if let Some(val) = self.lookup(field) {
+            if let Some(slice) = val.as_slice() {
+                let mut buf = vec![];
+                for entry in slice.iter() {
+                    if let Some(v) = entry.as_str() {
+                        match net::Ipv4Addr::from_str(v) {
+                            Ok(addr) => buf.push(addr),
+                            Err(_) => return Err(Error::ConfigInvalidIpv4Addr(field)),
+                        }
+                    } else {
+                        return Err(Error::ConfigInvalidIpv4Addr(field));
+                    }
+                }
+                *out = buf;
+                Ok(true)
+            } else {
+                Err(Error::ConfigInvalidArray(field))
+            }
+        } else {
+            Ok(false)
+        }
+    }
+}
+
 impl ParseInto<String> for toml::Value {
     fn parse_into(&self, field: &'static str, out: &mut String) -> Result<bool> {
         if let Some(val) = self.lookup(field) {
@@ -151,6 +204,29 @@ impl ParseInto<Option<String>> for toml::Value {
     }
 }
 
+impl ParseInto<Vec<String>> for toml::Value {
+    fn parse_into(&self, field: &'static str, out: &mut Vec<String>) -> Result<bool> {
+        if let Some(val) = self.lookup(field) {
+            if let Some(v) = val.as_slice() {
+                let mut buf = vec![];
+                for entry in v.iter() {
+                    if let Some(s) = entry.as_str() {
+                        buf.push(s.to_string());
+                    } else {
+                        return Err(Error::ConfigInvalidArray(field));
+                    }
+                }
+                *out = buf;
+                Ok(true)
+            } else {
+                Err(Error::ConfigInvalidArray(field))
+            }
+        } else {
+            Ok(false)
+        }
+    }
+}
+
 impl ParseInto<usize> for toml::Value {
     fn parse_into(&self, field: &'static str, out: &mut usize) -> Result<bool> {
         if let Some(val) = self.lookup(field) {
@@ -166,6 +242,37 @@ impl ParseInto<usize> for toml::Value {
     }
 }
 
+impl ParseInto<bool> for toml::Value {
+    fn parse_into(&self, field: &'static str, out: &mut bool) -> Result<bool> {
+        if let Some(val) = self.lookup(field) {
+            if let Some(v) = val.as_bool() {
+                *out = v;
+                Ok(true)
+            } else {
+                Err(Error::ConfigInvalidString(field))
+            }
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+impl ParseInto<Option<i32>> for toml::Value {
+    fn parse_into(&self, field: &'static str, out: &mut Option<i32>) -> Result<bool> {
+        if let Some(val) = self.lookup(field) {
+            if let Some(v) = val.as_integer() {
+                *out = Some(v as i32);
+                Ok(true)
+            } else {
+                Err(Error::ConfigInvalidString(field))
+            }
+        } else {
+            *out = None;
+            Ok(true)
+        }
+    }
+}
+
 impl ParseInto<u16> for toml::Value {
     fn parse_into(&self, field: &'static str, out: &mut u16) -> Result<bool> {
         if let Some(val) = self.lookup(field) {
@@ -227,6 +334,177 @@ impl ParseInto<Vec<u32>> for toml::Value {
     }
 }
 
+impl ParseInto<BTreeMap<String, usize>> for toml::Value {
+    fn parse_into(&self,
+                  field: &'static str,
+                  out: &mut BTreeMap<String, usize>)
+                  -> Result<bool> {
+        if let Some(val) = self.lookup(field) {
+            if let Some(v) = val.as_table() {
+                let mut buf = BTreeMap::new();
+                for (key, int) in v.iter() {
+                    match int.as_integer() {
+                        Some(i) if i >= 0 => buf.insert(key.clone(), i as usize),
+                        _ => return Err(Error::ConfigInvalidTable(field)),
+                    };
+                }
+                *out = buf;
+                Ok(true)
+            } else {
+                Err(Error::ConfigInvalidTable(field))
+            }
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+impl ParseInto<Duration> for toml::Value {
+    fn parse_into(&self, field: &'static str, out: &mut Duration) -> Result<bool> {
+        if let Some(val) = self.lookup(field) {
+            let duration = match *val {
+                toml::Value::String(ref v) => parse_duration(v),
+                // Zero means the same thing in every unit.
+                toml::Value::Integer(0) => Some(Duration::from_millis(0)),
+                toml::Value::Integer(v) if v > 0 => {
+                    warn!("config field {} is a number without a unit, which is deprecated; it is \
+                           read as \"{}ms\"",
+                          field,
+                          v);
+                    Some(Duration::from_millis(v as u64))
+                }
+                _ => None,
+            };
+            match duration {
+                Some(duration) => {
+                    *out = duration;
+                    Ok(true)
+                }
+                None => Err(Error::ConfigInvalidDuration(field)),
+            }
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+impl ParseInto<ByteSize> for toml::Value {
+    fn parse_into(&self, field: &'static str, out: &mut ByteSize) -> Result<bool> {
+        if let Some(val) = self.lookup(field) {
+            let size = match *val {
+                toml::Value::String(ref v) => parse_byte_size(v),
+                toml::Value::Integer(v) if v >= 0 => Some(v as u64),
+                _ => None,
+            };
+            match size {
+                Some(size) => {
+                    *out = ByteSize(size);
+                    Ok(true)
+                }
+                None => Err(Error::ConfigInvalidByteSize(field)),
+            }
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+/// Parse a duration from `field`, falling back to the deprecated `legacy` field it replaced if
+/// `field` isn't set. Legacy fields hold a bare number in the unit named by their suffix, `_ms` or
+/// `_secs`, and reading one logs a deprecation warning.
+pub fn parse_duration_or_legacy(toml: &toml::Value,
+                                field: &'static str,
+                                legacy: &'static str,
+                                out: &mut Duration)
+                                -> Result<bool> {
+    if try!(toml.parse_into(field, out)) {
+        return Ok(true);
+    }
+    let (scale, unit) = if legacy.ends_with("_secs") {
+        (1_000, "s")
+    } else {
+        (1, "ms")
+    };
+    match toml.lookup(legacy).map(|val| val.as_integer()) {
+        Some(Some(n)) if n >= 0 => {
+            warn!("config field {} is deprecated, set {} to \"{}{}\" instead",
+                  legacy,
+                  field,
+                  n,
+                  unit);
+            *out = Duration::from_millis(n as u64 * scale);
+            Ok(true)
+        }
+        Some(_) => Err(Error::ConfigInvalidDuration(legacy)),
+        None => Ok(false),
+    }
+}
+
+/// A number of bytes read from a configuration file, where it may be written with a unit such as
+/// `"64MB"`. Units are powers of 1024.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ByteSize(pub u64);
+
+impl ByteSize {
+    pub fn as_bytes(&self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for &(unit, scale) in BYTE_UNITS.iter().rev() {
+            if self.0 != 0 && self.0 % scale == 0 {
+                return write!(f, "{}{}", self.0 / scale, unit);
+            }
+        }
+        write!(f, "{}B", self.0)
+    }
+}
+
+const DURATION_UNITS: &'static [(&'static str, u64)] = &[("ms", 1),
+                                                          ("s", 1_000),
+                                                          ("m", 60 * 1_000),
+                                                          ("h", 60 * 60 * 1_000),
+                                                          ("d", 24 * 60 * 60 * 1_000)];
+
+const BYTE_UNITS: &'static [(&'static str, u64)] = &[("B", 1),
+                                                      ("KB", 1 << 10),
+                                                      ("MB", 1 << 20),
+                                                      ("GB", 1 << 30),
+                                                      ("TB", 1 << 40)];
+
+/// Parse a duration written as a whole number followed by one of the units `ms`, `s`, `m`, `h`
+/// or `d`, such as `"250ms"` or `"5s"`.
+pub fn parse_duration(value: &str) -> Option<Duration> {
+    parse_quantity(value, DURATION_UNITS, false).map(Duration::from_millis)
+}
+
+/// Parse a number of bytes written as a whole number optionally followed by one of the units
+/// `B`, `KB`, `MB`, `GB` or `TB`, such as `"64MB"`.
+pub fn parse_byte_size(value: &str) -> Option<u64> {
+    parse_quantity(value, BYTE_UNITS, true)
+}
+
+fn parse_quantity(value: &str, units: &[(&str, u64)], unit_optional: bool) -> Option<u64> {
+    let value = value.trim();
+    let split = value.find(|c: char| !c.is_digit(10)).unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number = match u64::from_str(number) {
+        Ok(number) => number,
+        Err(_) => return None,
+    };
+    let scale = if unit.is_empty() && unit_optional {
+        1
+    } else {
+        match units.iter().find(|&&(name, _)| name == unit.trim()) {
+            Some(&(_, scale)) => scale,
+            None => return None,
+        }
+    };
+    number.checked_mul(scale)
+}
+
 fn format_errors(errors: &Vec<toml::ParserError>) -> String {
     let mut msg = String::new();
     for err in errors {
@@ -234,3 +512,147 @@ fn format_errors(errors: &Vec<toml::ParserError>) -> String {
     }
     msg
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::time::Duration;
+
+    use toml;
+
+    use super::*;
+
+    fn value(raw: &str) -> toml::Value {
+        raw.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_duration_with_units() {
+        assert_eq!(parse_duration("250ms"), Some(Duration::from_millis(250)));
+        assert_eq!(parse_duration("5s"), Some(Duration::from_secs(5)));
+        assert_eq!(parse_duration("2m"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1h"), Some(Duration::from_secs(3_600)));
+        assert_eq!(parse_duration("1d"), Some(Duration::from_secs(86_400)));
+    }
+
+    #[test]
+    fn parse_duration_requires_a_known_unit() {
+        assert_eq!(parse_duration("5"), None);
+        assert_eq!(parse_duration("5 minutes"), None);
+        assert_eq!(parse_duration("1.5s"), None);
+        assert_eq!(parse_duration("-5s"), None);
+        assert_eq!(parse_duration("s"), None);
+        assert_eq!(parse_duration(""), None);
+    }
+
+    #[test]
+    fn parse_byte_size_with_units() {
+        assert_eq!(parse_byte_size("512"), Some(512));
+        assert_eq!(parse_byte_size("512B"), Some(512));
+        assert_eq!(parse_byte_size("64KB"), Some(64 * 1024));
+        assert_eq!(parse_byte_size("64MB"), Some(64 * 1024 * 1024));
+        assert_eq!(parse_byte_size("2GB"), Some(2 * 1024 * 1024 * 1024));
+    }
+
+    #[test]
+    fn parse_byte_size_rejects_unknown_units_and_overflow() {
+        assert_eq!(parse_byte_size("64mb"), None);
+        assert_eq!(parse_byte_size("64XB"), None);
+        assert_eq!(parse_byte_size("99999999999TB"), None);
+    }
+
+    #[test]
+    fn byte_size_displays_largest_whole_unit() {
+        assert_eq!(ByteSize(10 * 1024 * 1024).to_string(), "10MB");
+        assert_eq!(ByteSize(1536).to_string(), "1536B");
+        assert_eq!(ByteSize(0).to_string(), "0B");
+    }
+
+    #[test]
+    fn parse_into_duration() {
+        let toml = value("[cfg]\nwait = \"30s\"\nnone = 0\nbare = 30\n");
+        let mut wait = Duration::from_millis(0);
+        assert!(toml.parse_into("cfg.wait", &mut wait).unwrap());
+        assert_eq!(wait, Duration::from_secs(30));
+        let mut none = Duration::from_secs(1);
+        assert!(toml.parse_into("cfg.none", &mut none).unwrap());
+        assert_eq!(none, Duration::from_millis(0));
+        // Bare numbers are deprecated and read as milliseconds.
+        let mut bare = Duration::from_secs(1);
+        assert!(toml.parse_into("cfg.bare", &mut bare).unwrap());
+        assert_eq!(bare, Duration::from_millis(30));
+        assert!(!toml.parse_into("cfg.missing", &mut bare).unwrap());
+    }
+
+    #[test]
+    fn parse_into_duration_rejects_malformed_values() {
+        let toml = value("[cfg]\nnegative = -5\nunitless = \"30\"\nflag = true\n");
+        let mut wait = Duration::from_secs(1);
+        assert!(toml.parse_into("cfg.negative", &mut wait).is_err());
+        assert!(toml.parse_into("cfg.unitless", &mut wait).is_err());
+        assert!(toml.parse_into("cfg.flag", &mut wait).is_err());
+        assert_eq!(wait, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn parse_duration_or_legacy_reads_deprecated_fields() {
+        let toml = value("[cfg]\nwait_ms = 250\nttl_secs = 30\nlease = \"5s\"\nlease_ms = 10\n");
+        let mut wait = Duration::from_secs(1);
+        assert!(parse_duration_or_legacy(&toml, "cfg.wait", "cfg.wait_ms", &mut wait).unwrap());
+        assert_eq!(wait, Duration::from_millis(250));
+        let mut ttl = Duration::from_secs(1);
+        assert!(parse_duration_or_legacy(&toml, "cfg.ttl", "cfg.ttl_secs", &mut ttl).unwrap());
+        assert_eq!(ttl, Duration::from_secs(30));
+        // The new field wins over the legacy one.
+        let mut lease = Duration::from_secs(1);
+        assert!(parse_duration_or_legacy(&toml, "cfg.lease", "cfg.lease_ms", &mut lease).unwrap());
+        assert_eq!(lease, Duration::from_secs(5));
+        let mut missing = Duration::from_secs(1);
+        assert!(!parse_duration_or_legacy(&toml, "cfg.x", "cfg.x_ms", &mut missing).unwrap());
+        assert_eq!(missing, Duration::from_secs(1));
+        let toml = value("[cfg]\nwait_ms = \"soon\"\n");
+        assert!(parse_duration_or_legacy(&toml, "cfg.wait", "cfg.wait_ms", &mut wait).is_err());
+    }
+
+    #[test]
+    fn parse_into_optional_integer() {
+        let toml = value("[cfg]\nnice = -5\nname = \"high\"\n");
+        let mut nice = None;
+        assert!(toml.parse_into("cfg.nice", &mut nice).unwrap());
+        assert_eq!(nice, Some(-5));
+        assert!(toml.parse_into("cfg.name", &mut nice).is_err());
+        assert!(toml.parse_into("cfg.missing", &mut nice).unwrap());
+        assert_eq!(nice, None);
+    }
+
+    #[test]
+    fn parse_into_bool() {
+        let toml = value("[cfg]\nenabled = false\nname = \"yes\"\n");
+        let mut enabled = true;
+        assert!(toml.parse_into("cfg.enabled", &mut enabled).unwrap());
+        assert!(!enabled);
+        assert!(toml.parse_into("cfg.name", &mut enabled).is_err());
+        assert!(!toml.parse_into("cfg.missing", &mut enabled).unwrap());
+        assert!(!enabled);
+    }
+
+    #[test]
+    fn parse_into_integer_table() {
+        let toml = value("[cfg.caps]\nJobCreate = 2\n[cfg.negative]\nJobCreate = -1\n");
+        let mut caps: BTreeMap<String, usize> = BTreeMap::new();
+        assert!(toml.parse_into("cfg.caps", &mut caps).unwrap());
+        assert_eq!(caps.get("JobCreate"), Some(&2));
+        assert!(toml.parse_into("cfg.negative", &mut caps).is_err());
+        assert_eq!(caps.get("JobCreate"), Some(&2));
+    }
+
+    #[test]
+    fn parse_into_byte_size() {
+        let toml = value("[cfg]\nmax = \"64MB\"\nraw = 4096\n");
+        let mut max = ByteSize(0);
+        assert!(toml.parse_into("cfg.max", &mut max).unwrap());
+        assert_eq!(max.as_bytes(), 64 * 1024 * 1024);
+        assert!(toml.parse_into("cfg.raw", &mut max).unwrap());
+        assert_eq!(max, ByteSize(4096));
+    }
+}
@@ -35,45 +35,75 @@ pub fn hash_file<P: AsRef<Path>>(filename: &P) -> Result<String> {
 }
 
 pub fn hash_string(data: &str) -> Result<String> {
-    let mut out = [0u8; libsodium_sys::crypto_generichash_BYTES];
-    let mut st = vec![0u8; (unsafe { libsodium_sys::crypto_generichash_statebytes() })];
-    let pst = unsafe {
-        mem::transmute::<*mut u8, *mut libsodium_sys::crypto_generichash_state>(st.as_mut_ptr())
-    };
-    unsafe {
-        libsodium_sys::crypto_generichash_init(pst, ptr::null_mut(), 0, out.len());
-        libsodium_sys::crypto_generichash_update(pst, data[..].as_ptr(), data.len() as u64);
-        libsodium_sys::crypto_generichash_final(pst, out.as_mut_ptr(), out.len());
-    }
-    Ok(out.to_hex())
+    let mut hasher = Hasher::new();
+    hasher.update(data.as_bytes());
+    Ok(hasher.finish())
 }
 
 pub fn hash_reader(reader: &mut BufReader<File>) -> Result<String> {
-    let mut out = [0u8; libsodium_sys::crypto_generichash_BYTES];
-    let mut st = vec![0u8; (unsafe { libsodium_sys::crypto_generichash_statebytes() })];
-    let pst = unsafe {
-        mem::transmute::<*mut u8, *mut libsodium_sys::crypto_generichash_state>(st.as_mut_ptr())
-    };
-
-    unsafe {
-        libsodium_sys::crypto_generichash_init(pst, ptr::null_mut(), 0, out.len());
-    }
-
+    let mut hasher = Hasher::new();
     let mut buf = [0u8; BUF_SIZE];
     loop {
         let bytes_read = try!(reader.read(&mut buf));
         if bytes_read == 0 {
             break;
         }
-        let chunk = &buf[0..bytes_read];
+        hasher.update(&buf[0..bytes_read]);
+    }
+    Ok(hasher.finish())
+}
+
+/// Incrementally calculate the same BLAKE2b hash as `hash_file`, for data which is seen a chunk
+/// at a time such as a file being streamed to disk.
+pub struct Hasher {
+    // Backing storage for a `crypto_generichash_state`, whose size is only known at runtime.
+    state: Vec<u8>,
+}
+
+impl Hasher {
+    pub fn new() -> Self {
+        let mut hasher = Hasher {
+            state: vec![0u8; (unsafe { libsodium_sys::crypto_generichash_statebytes() })],
+        };
         unsafe {
-            libsodium_sys::crypto_generichash_update(pst, chunk.as_ptr(), chunk.len() as u64);
+            libsodium_sys::crypto_generichash_init(hasher.state_ptr(),
+                                                   ptr::null_mut(),
+                                                   0,
+                                                   libsodium_sys::crypto_generichash_BYTES);
         }
+        hasher
     }
-    unsafe {
-        libsodium_sys::crypto_generichash_final(pst, out.as_mut_ptr(), out.len());
+
+    /// Add the next chunk of data to the hash.
+    pub fn update(&mut self, chunk: &[u8]) {
+        unsafe {
+            libsodium_sys::crypto_generichash_update(self.state_ptr(),
+                                                     chunk.as_ptr(),
+                                                     chunk.len() as u64);
+        }
+    }
+
+    /// The hash of all the data seen, as a hex string.
+    pub fn finish(mut self) -> String {
+        let mut out = [0u8; libsodium_sys::crypto_generichash_BYTES];
+        unsafe {
+            libsodium_sys::crypto_generichash_final(self.state_ptr(), out.as_mut_ptr(), out.len());
+        }
+        out.to_hex()
+    }
+
+    fn state_ptr(&mut self) -> *mut libsodium_sys::crypto_generichash_state {
+        unsafe {
+            mem::transmute::<*mut u8,
+                             *mut libsodium_sys::crypto_generichash_state>(self.state.as_mut_ptr())
+        }
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Self::new()
     }
-    Ok(out.to_hex())
 }
 
 #[cfg(test)]
@@ -82,7 +112,7 @@ mod test {
     #[allow(unused_imports)]
     use std::fs::{self, File};
     #[allow(unused_imports)]
-    use std::io;
+    use std::io::{self, Read};
     use std::path::PathBuf;
 
     #[cfg(feature = "functional")]
@@ -128,6 +158,17 @@ mod test {
         assert_eq!(computed, expected);
     }
 
+    #[test]
+    fn hasher_matches_hash_file_across_chunks() {
+        let mut content = vec![];
+        File::open(fixture("signme.dat")).unwrap().read_to_end(&mut content).unwrap();
+        let mut hasher = Hasher::new();
+        for chunk in content.chunks(7) {
+            hasher.update(chunk);
+        }
+        assert_eq!(hasher.finish(), hash_file(&fixture("signme.dat")).unwrap());
+    }
+
     #[test]
     #[cfg(feature = "functional")]
     fn hash_file_large_binary() {
@@ -17,6 +17,7 @@ use std::path::{Path, PathBuf};
 
 use rustc_serialize::base64::{STANDARD, ToBase64};
 use rustc_serialize::hex::ToHex;
+use sodiumoxide::crypto::hash::sha256;
 use sodiumoxide::crypto::secretbox;
 use sodiumoxide::crypto::secretbox::Key as SymSecretKey;
 use sodiumoxide::randombytes::randombytes;
@@ -90,6 +91,17 @@ impl SymKey {
         Ok(Self::new(name, rev, pk, sk))
     }
 
+    /// Derives a sym key from the material of another key, such as the contents of an origin's
+    /// secret key file, so data can be encrypted with it without storing a key of its own. The
+    /// same material always derives the same key. The derived key is never written to disk.
+    pub fn derive_from(name: &str, rev: &str, material: &[u8]) -> Self {
+        let digest = sha256::hash(material);
+        Self::new(name.to_string(),
+                  rev.to_string(),
+                  None,
+                  Some(SymSecretKey(digest.0)))
+    }
+
     pub fn get_latest_pair_for<P: AsRef<Path> + ?Sized>(name: &str,
                                                         cache_key_path: &P)
                                                         -> Result<Self> {
@@ -419,6 +431,19 @@ mod test {
         }
     }
 
+    #[test]
+    fn derive_from() {
+        let pair = SymKey::derive_from("origin", "20160504220722", b"key material");
+        let same = SymKey::derive_from("origin", "20160504220722", b"key material");
+        let other = SymKey::derive_from("origin", "20160504220722", b"other material");
+        let (nonce, ciphertext) = pair.encrypt("Guess who?".as_bytes()).unwrap();
+
+        assert_eq!(same.decrypt(&nonce, &ciphertext).unwrap(),
+                   "Guess who?".to_string().into_bytes());
+        assert!(other.decrypt(&nonce, &ciphertext).is_err());
+        assert_eq!(pair.public, None);
+    }
+
     #[test]
     fn generated_ring_pair() {
         let cache = TempDir::new("key_cache").unwrap();
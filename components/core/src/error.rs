@@ -41,12 +41,18 @@ pub enum Error {
     ConfigFileSyntax(String),
     /// Expected a valid array of values for configuration field value.
     ConfigInvalidArray(&'static str),
+    /// Expected a number of bytes, optionally with a unit, for configuration field value.
+    ConfigInvalidByteSize(&'static str),
+    /// Expected a duration with a unit for configuration field value.
+    ConfigInvalidDuration(&'static str),
     /// Expected a valid Ipv4 network address for configuration field value.
     ConfigInvalidIpv4Addr(&'static str),
     /// Expected a valid SocketAddrV4 address pair for configuration field value.
     ConfigInvalidSocketAddrV4(&'static str),
     /// Expected a string for configuration field value.
     ConfigInvalidString(&'static str),
+    /// Expected a table of values for configuration field value.
+    ConfigInvalidTable(&'static str),
     /// Crypto library error
     CryptoError(String),
     /// Occurs when a file that should exist does not or could not be read.
@@ -101,6 +107,13 @@ impl fmt::Display for Error {
             Error::ConfigInvalidArray(ref f) => {
                 format!("Invalid array of values in config, field={}", f)
             }
+            Error::ConfigInvalidByteSize(ref f) => {
+                format!("Invalid size in config, field={}. (example: 4096 or \"64MB\")", f)
+            }
+            Error::ConfigInvalidDuration(ref f) => {
+                format!("Invalid duration in config, field={}. (example: \"250ms\" or \"5s\")",
+                        f)
+            }
             Error::ConfigInvalidIpv4Addr(ref f) => {
                 format!("Invalid Ipv4 address in config, field={}. (example: \"127.0.0.0\")",
                         f)
@@ -113,6 +126,9 @@ impl fmt::Display for Error {
             Error::ConfigInvalidString(ref f) => {
                 format!("Invalid string value in config, field={}.", f)
             }
+            Error::ConfigInvalidTable(ref f) => {
+                format!("Invalid table of values in config, field={}", f)
+            }
             Error::CryptoError(ref e) => format!("Crypto error: {}", e),
             Error::FileNotFound(ref e) => format!("File not found at: {}", e),
             Error::InvalidPackageIdent(ref e) => {
@@ -162,6 +178,12 @@ impl error::Error for Error {
             Error::ConfigInvalidArray(_) => {
                 "Invalid array of values encountered while parsing a configuration file"
             }
+            Error::ConfigInvalidByteSize(_) => {
+                "Invalid size encountered while parsing a configuration file"
+            }
+            Error::ConfigInvalidDuration(_) => {
+                "Invalid duration encountered while parsing a configuration file"
+            }
             Error::ConfigInvalidIpv4Addr(_) => {
                 "Invalid Ipv4 network address encountered while parsing a configuration file"
             }
@@ -171,6 +193,9 @@ impl error::Error for Error {
             Error::ConfigInvalidString(_) => {
                 "Invalid string value encountered while parsing a configuration file"
             }
+            Error::ConfigInvalidTable(_) => {
+                "Invalid table of values encountered while parsing a configuration file"
+            }
             Error::CryptoError(_) => "Crypto error",
             Error::FileNotFound(_) => "File not found",
             Error::InvalidPackageIdent(_) => {
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::cmp::{Ordering, PartialOrd};
 use std::env;
 use std::fs::{DirEntry, File};
@@ -21,6 +21,7 @@ use std::io::prelude::*;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
+use crypto::HART_FORMAT_VERSION;
 use error::{Error, Result};
 use fs::{self, PKG_PATH};
 use package::{Identifiable, MetaFile, PackageIdent};
@@ -103,6 +104,19 @@ impl PackageInstall {
         }
     }
 
+    /// Returns the identifiers of every package installed within the package path.
+    ///
+    /// An optional `fs_root` path may be provided to list the packages on a filesystem not
+    /// currently rooted at `/`.
+    pub fn all(fs_root_path: Option<&Path>) -> Result<Vec<PackageIdent>> {
+        let fs_root_path = fs_root_path.unwrap_or(Path::new("/"));
+        let package_root_path = fs_root_path.join(PKG_PATH);
+        if !package_root_path.exists() {
+            return Ok(vec![]);
+        }
+        Self::package_list(&package_root_path)
+    }
+
     pub fn deps(&self) -> Result<Vec<PackageIdent>> {
         self.read_deps(MetaFile::Deps)
     }
@@ -111,6 +125,21 @@ impl PackageInstall {
         self.read_deps(MetaFile::TDeps)
     }
 
+    /// A vector of the bind names the service requires
+    pub fn binds(&self) -> Result<Vec<String>> {
+        match self.read_metafile(MetaFile::Binds) {
+            Ok(body) => {
+                let v: Vec<String> = body.lines()
+                    .map(|x| x.trim().to_string())
+                    .filter(|x| !x.is_empty())
+                    .collect();
+                Ok(v)
+            }
+            Err(Error::MetaFileNotFound(MetaFile::Binds)) => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
     /// A vector of ports we expose
     pub fn exposes(&self) -> Result<Vec<String>> {
         match self.read_metafile(MetaFile::Exposes) {
@@ -128,10 +157,73 @@ impl PackageInstall {
         }
     }
 
+    /// The contents of the package's default configuration, if it has one
+    pub fn default_cfg(&self) -> Result<Option<String>> {
+        match self.read_metafile(MetaFile::Config) {
+            Ok(body) => Ok(Some(body)),
+            Err(Error::MetaFileNotFound(MetaFile::Config)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The checksum of every file in the package, keyed by the file's path relative to the
+    /// package's installed path. Packages built without a FILES metafile have none.
+    ///
+    /// # Failures
+    ///
+    /// * The package contains a FILES metafile but it could not be read or it was malformed
+    pub fn files(&self) -> Result<BTreeMap<String, String>> {
+        let body = match self.read_metafile(MetaFile::Files) {
+            Ok(body) => body,
+            Err(Error::MetaFileNotFound(MetaFile::Files)) => return Ok(BTreeMap::new()),
+            Err(e) => return Err(e),
+        };
+        let mut lines = body.lines().peekable();
+        // The checksums are signed, skip the header of the signed file
+        if lines.peek().map_or(false, |l| l.trim() == HART_FORMAT_VERSION) {
+            for _ in 0..5 {
+                lines.next();
+            }
+        }
+        // Files are listed by their path when the package was built
+        let prefix = try!(Self::calc_installed_path(&self.ident, &Path::new("/").join(PKG_PATH)));
+        let mut files = BTreeMap::new();
+        for line in lines.filter(|l| !l.trim().is_empty()) {
+            let mut parts = line.splitn(2, char::is_whitespace);
+            match (parts.next(), parts.next()) {
+                (Some(checksum), Some(path)) => {
+                    let path = Path::new(path.trim());
+                    let relative = path.strip_prefix(&prefix).unwrap_or(path);
+                    files.insert(relative.to_string_lossy().into_owned(), checksum.to_string());
+                }
+                _ => return Err(Error::MetaFileMalformed(MetaFile::Files)),
+            }
+        }
+        Ok(files)
+    }
+
     pub fn ident(&self) -> &PackageIdent {
         &self.ident
     }
 
+    /// The URL of the Depot the package was downloaded from, if one was recorded when it was
+    /// installed
+    pub fn depot(&self) -> Result<Option<String>> {
+        match self.read_metafile(MetaFile::Depot) {
+            Ok(body) => Ok(Some(body)),
+            Err(Error::MetaFileNotFound(MetaFile::Depot)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Record the URL of the Depot the package was downloaded from
+    pub fn set_depot(&self, url: &str) -> Result<()> {
+        let filepath = self.installed_path.join(MetaFile::Depot.to_string());
+        let mut f = try!(File::create(&filepath).map_err(Error::MetaFileIO));
+        try!(writeln!(f, "{}", url).map_err(Error::MetaFileIO));
+        Ok(())
+    }
+
     /// Return the PATH string from the package metadata, if it exists
     ///
     /// # Failures
@@ -24,11 +24,14 @@ use std::fmt;
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub enum MetaFile {
+    Binds,
     CFlags,
     Config,
     Deps,
+    Depot,
     TDeps,
     Exposes,
+    Files,
     Ident,
     LdRunPath,
     LdFlags,
@@ -39,11 +42,14 @@ pub enum MetaFile {
 impl fmt::Display for MetaFile {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let id = match *self {
+            MetaFile::Binds => "BINDS",
             MetaFile::CFlags => "CFLAGS",
             MetaFile::Config => "default.toml",
             MetaFile::Deps => "DEPS",
+            MetaFile::Depot => "DEPOT",
             MetaFile::TDeps => "TDEPS",
             MetaFile::Exposes => "EXPOSES",
+            MetaFile::Files => "FILES",
             MetaFile::Ident => "IDENT",
             MetaFile::LdRunPath => "LD_RUN_PATH",
             MetaFile::LdFlags => "LDFLAGS",
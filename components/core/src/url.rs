@@ -26,3 +26,32 @@ pub fn default_depot_url() -> String {
         Err(_) => DEFAULT_DEPOT_URL.to_string(),
     }
 }
+
+/// Depot mirrors environment variable, a comma separated list of URLs to fall back to
+pub const DEPOT_MIRRORS_ENVVAR: &'static str = "HAB_DEPOT_MIRRORS";
+
+pub fn default_depot_mirrors() -> Vec<String> {
+    match henv::var(DEPOT_MIRRORS_ENVVAR) {
+        Ok(val) => {
+            val.split(',')
+                .map(|url| url.trim())
+                .filter(|url| !url.is_empty())
+                .map(|url| url.to_string())
+                .collect()
+        }
+        Err(_) => vec![],
+    }
+}
+
+/// Default Builder API URL
+pub const DEFAULT_BLDR_URL: &'static str = "https://willem.habitat.sh/v1";
+
+/// Default Builder API URL environment variable
+pub const BLDR_URL_ENVVAR: &'static str = "HAB_BLDR_URL";
+
+pub fn default_bldr_url() -> String {
+    match henv::var(BLDR_URL_ENVVAR) {
+        Ok(val) => val,
+        Err(_) => DEFAULT_BLDR_URL.to_string(),
+    }
+}
@@ -18,6 +18,7 @@ use std::fmt;
 use std::result;
 
 use hyper;
+use rustc_serialize::json;
 use url;
 
 use hab_core::{self, package};
@@ -26,13 +27,19 @@ use hab_http;
 
 #[derive(Debug)]
 pub enum Error {
+    DownloadChecksumMismatch(String),
+    DownloadInterrupted(u64),
     HabitatCore(hab_core::Error),
     HabitatHttpClient(hab_http::Error),
     HTTP(hyper::status::StatusCode),
     HyperError(hyper::error::Error),
     IO(io::Error),
+    InvalidLocalDepot(String),
+    InvalidRange(u64),
+    JsonDecode(json::DecoderError),
     NoFilePart,
     NoXFilename,
+    NotSupportedByLocalDepot(&'static str),
     RemoteOriginKeyNotFound(String),
     RemotePackageNotFound(package::PackageIdent),
     UrlParseError(url::ParseError),
@@ -44,11 +51,25 @@ pub type Result<T> = result::Result<T, Error>;
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            Error::DownloadChecksumMismatch(ref file) => {
+                format!("Downloaded {} does not match the checksum the Depot sent for it", file)
+            }
+            Error::DownloadInterrupted(offset) => {
+                format!("Download from a Depot was interrupted at byte {}", offset)
+            }
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::HabitatHttpClient(ref e) => format!("{}", e),
             Error::HTTP(ref e) => format!("{}", e),
             Error::HyperError(ref err) => format!("{}", err),
             Error::IO(ref e) => format!("{}", e),
+            Error::InvalidLocalDepot(ref path) => {
+                format!("Local Depot {} is not a directory", path)
+            }
+            Error::InvalidRange(offset) => {
+                format!("Invalid partial download from a Depot - bad range at byte {}",
+                        offset)
+            }
+            Error::JsonDecode(ref e) => format!("Malformed response from a Depot, {}", e),
             Error::NoFilePart => {
                 format!("An invalid path was passed - we needed a filename, and this path does \
                          not have one")
@@ -56,6 +77,9 @@ impl fmt::Display for Error {
             Error::NoXFilename => {
                 format!("Invalid download from a Depot - missing X-Filename header")
             }
+            Error::NotSupportedByLocalDepot(ref op) => {
+                format!("A Depot in a local directory does not support {}", op)
+            }
             Error::RemoteOriginKeyNotFound(ref e) => format!("{}", e),
             Error::RemotePackageNotFound(ref pkg) => {
                 if pkg.fully_qualified() {
@@ -76,15 +100,25 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
+            Error::DownloadChecksumMismatch(_) => {
+                "Downloaded file does not match the checksum the Depot sent for it"
+            }
+            Error::DownloadInterrupted(_) => "Download from a Depot was interrupted",
             Error::HabitatCore(ref err) => err.description(),
             Error::HabitatHttpClient(ref err) => err.description(),
             Error::HTTP(_) => "Received an HTTP error",
             Error::HyperError(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::InvalidLocalDepot(_) => "Local Depot is not a directory",
+            Error::InvalidRange(_) => "Invalid partial download from a Depot - bad range",
+            Error::JsonDecode(ref err) => err.description(),
             Error::NoFilePart => {
                 "An invalid path was passed - we needed a filename, and this path does not have one"
             }
             Error::NoXFilename => "Invalid download from a Depot - missing X-Filename header",
+            Error::NotSupportedByLocalDepot(_) => {
+                "Operation is not supported by a Depot in a local directory"
+            }
             Error::RemoteOriginKeyNotFound(_) => "Remote origin key not found",
             Error::RemotePackageNotFound(_) => "Cannot find a package in any sources",
             Error::UrlParseError(ref err) => err.description(),
@@ -119,6 +153,12 @@ impl From<io::Error> for Error {
     }
 }
 
+impl From<json::DecoderError> for Error {
+    fn from(err: json::DecoderError) -> Error {
+        Error::JsonDecode(err)
+    }
+}
+
 impl From<url::ParseError> for Error {
     fn from(err: url::ParseError) -> Error {
         Error::UrlParseError(err)
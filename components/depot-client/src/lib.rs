@@ -24,47 +24,208 @@ extern crate pbr;
 extern crate rustc_serialize;
 extern crate tee;
 extern crate url;
+extern crate walkdir;
+
+#[cfg(test)]
+extern crate tempdir;
 
 pub mod error;
+mod local;
 
 pub use error::{Error, Result};
 
-use std::fs::{self, File};
-use std::io::{self, Read, Write};
+use std::cmp;
+use std::collections::HashSet;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use broadcast::BroadcastWriter;
-use hab_core::package::{Identifiable, PackageArchive};
+use hab_core::config::parse_byte_size;
+use hab_core::crypto::hash;
+use hab_core::env as henv;
+use hab_core::package::{Identifiable, PackageArchive, PackageIdent};
 use hab_http::new_hyper_client;
 use hyper::client::{Body, IntoUrl, Response};
 use hyper::status::StatusCode;
 use hyper::header::{Headers, Authorization, Bearer};
 use hyper::Url;
+use local::LocalDepot;
 use protocol::depotsrv;
 use rustc_serialize::json;
 use tee::TeeReader;
 
 header! { (XFileName, "X-Filename") => [String] }
 header! { (ETag, "ETag") => [String] }
+header! { (ContentRange, "Content-Range") => [String] }
+
+/// Number of byte ranges of a download fetched at once environment variable
+pub const DOWNLOAD_CONCURRENCY_ENVVAR: &'static str = "HAB_DOWNLOAD_CONCURRENCY";
+/// Size of each byte range of a download environment variable
+pub const DOWNLOAD_CHUNK_SIZE_ENVVAR: &'static str = "HAB_DOWNLOAD_CHUNK_SIZE";
+/// Number of times a failed download is tried again environment variable
+pub const DOWNLOAD_RETRIES_ENVVAR: &'static str = "HAB_DOWNLOAD_RETRIES";
 
 pub trait DisplayProgress: Write {
     fn size(&mut self, size: u64);
+    /// Count bytes which were written to their destination without passing through the
+    /// progress display, such as ranges of a download fetched in parallel.
+    fn advance(&mut self, count: u64);
+    /// Show that a failed download is being tried again, resuming from the given byte.
+    fn retry(&mut self, attempt: usize, offset: u64);
+}
+
+/// How a large download is split into byte ranges fetched over parallel connections. Depots
+/// which don't support range requests are always downloaded from over a single connection.
+#[derive(Clone, Copy, Debug)]
+pub struct DownloadOpts {
+    /// Number of ranges fetched at once. A value of 1 disables range requests.
+    pub concurrency: usize,
+    /// Size of each range in bytes. Downloads no larger than this use a single connection.
+    pub chunk_size: u64,
+    /// Number of times a download, or a range of one, which fails part way through is tried
+    /// again before giving up.
+    pub retries: usize,
+}
+
+impl DownloadOpts {
+    /// Options read from `HAB_DOWNLOAD_CONCURRENCY`, `HAB_DOWNLOAD_CHUNK_SIZE` and
+    /// `HAB_DOWNLOAD_RETRIES`, where the chunk size may be given with a unit such as `"16MB"`.
+    /// Unset or invalid values are left at their defaults.
+    pub fn from_env() -> Self {
+        let mut opts = DownloadOpts::default();
+        if let Ok(val) = henv::var(DOWNLOAD_CONCURRENCY_ENVVAR) {
+            match val.parse::<usize>() {
+                Ok(concurrency) if concurrency > 0 => opts.concurrency = concurrency,
+                _ => warn!("Ignoring invalid {}={}", DOWNLOAD_CONCURRENCY_ENVVAR, val),
+            }
+        }
+        if let Ok(val) = henv::var(DOWNLOAD_CHUNK_SIZE_ENVVAR) {
+            match parse_byte_size(&val) {
+                Some(chunk_size) if chunk_size > 0 => opts.chunk_size = chunk_size,
+                _ => warn!("Ignoring invalid {}={}", DOWNLOAD_CHUNK_SIZE_ENVVAR, val),
+            }
+        }
+        if let Ok(val) = henv::var(DOWNLOAD_RETRIES_ENVVAR) {
+            match val.parse::<usize>() {
+                Ok(retries) => opts.retries = retries,
+                Err(_) => warn!("Ignoring invalid {}={}", DOWNLOAD_RETRIES_ENVVAR, val),
+            }
+        }
+        opts
+    }
+}
+
+impl Default for DownloadOpts {
+    fn default() -> Self {
+        DownloadOpts {
+            concurrency: 4,
+            chunk_size: 8 * 1024 * 1024,
+            retries: 3,
+        }
+    }
+}
+
+/// A package uploaded to a Depot or promoted into one of its views.
+#[derive(Clone, Debug, RustcEncodable, RustcDecodable)]
+pub struct PackageEvent {
+    /// Position of the event in the Depot's event stream
+    pub seq: u64,
+    /// One of `uploaded`, `promoted` or `promotion_requested`, the last for promotions into a
+    /// protected view which are waiting for approval
+    pub kind: String,
+    /// View the package was promoted into, for promotions and promotion requests
+    pub view: Option<String>,
+    pub origin: String,
+    pub name: String,
+    /// Fully qualified identifier of the package
+    pub ident: String,
+}
+
+/// Events returned by `Client::watch_origin` and the position to watch from next.
+#[derive(Clone, Debug, RustcEncodable, RustcDecodable)]
+pub struct PackageEvents {
+    pub events: Vec<PackageEvent>,
+    pub next: u64,
+    /// True if events after the requested position were discarded before they could be
+    /// delivered. Packages the watcher cares about should be checked directly.
+    pub missed: bool,
 }
 
+/// A client of a remote Depot, or of a Depot kept in a local directory when given a `file://`
+/// URL.
 pub struct Client {
     depot_url: Url,
-    client: hyper::Client,
+    client: Arc<hyper::Client>,
+    download_opts: DownloadOpts,
+    local: Option<LocalDepot>,
 }
 
 impl Client {
     pub fn new<U: IntoUrl>(hab_depot_url: U, fs_root_path: Option<&Path>) -> Result<Self> {
         let url = try!(hab_depot_url.into_url());
+        let local = if url.scheme() == "file" {
+            match url.to_file_path() {
+                Ok(path) => Some(try!(LocalDepot::new(path))),
+                Err(_) => return Err(Error::InvalidLocalDepot(url.to_string())),
+            }
+        } else {
+            None
+        };
         Ok(Client {
             depot_url: url.clone(),
-            client: try!(new_hyper_client(Some(&url), fs_root_path)),
+            client: Arc::new(try!(new_hyper_client(Some(&url), fs_root_path))),
+            download_opts: DownloadOpts::default(),
+            local: local,
         })
     }
 
+    /// Set how packages and keys are split into ranges fetched in parallel when downloaded.
+    pub fn set_download_opts(&mut self, opts: DownloadOpts) {
+        self.download_opts = opts;
+    }
+
+    /// Time a small request to the Depot, to compare how quickly mirrors respond. A Depot which
+    /// hasn't responded within `timeout` is given up on, though the request is left to finish
+    /// in the background.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * Remote Depot did not respond in time
+    pub fn ping(&self, timeout: Duration) -> Result<Duration> {
+        if self.local.is_some() {
+            return Ok(Duration::from_secs(0));
+        }
+        let url = try!(self.url_join("views"));
+        debug!("GET {} with {:?}", &url, &self.client);
+        let (tx, rx) = mpsc::channel();
+        let timer = tx.clone();
+        let client = self.client.clone();
+        thread::spawn(move || {
+            let start = Instant::now();
+            let result = match client.get(url).send() {
+                Ok(ref res) if res.status.is_server_error() => Err(Error::HTTP(res.status)),
+                Ok(_) => Ok(start.elapsed()),
+                Err(e) => Err(Error::from(e)),
+            };
+            let _ = tx.send(result);
+        });
+        thread::spawn(move || {
+            thread::sleep(timeout);
+            let _ = timer.send(Err(Error::IO(io::Error::new(io::ErrorKind::TimedOut,
+                                                            "Depot did not respond in time"))));
+        });
+        match rx.recv() {
+            Ok(result) => result,
+            Err(_) => Err(Error::IO(io::Error::new(io::ErrorKind::Other, "ping failed"))),
+        }
+    }
+
     /// Download a public key from a remote Depot to the given filepath.
     ///
     /// # Failures
@@ -78,11 +239,17 @@ impl Client {
                                                      dst_path: &P,
                                                      progress: Option<&mut DisplayProgress>)
                                                      -> Result<PathBuf> {
+        if let Some(ref local) = self.local {
+            return local.fetch_origin_key(origin, revision, dst_path.as_ref(), progress);
+        }
         let url = try!(self.url_join(&format!("origins/{}/keys/{}", origin, revision)));
         self.download(url, dst_path.as_ref(), progress)
     }
 
     pub fn show_origin_keys(&self, origin: &str) -> Result<Vec<depotsrv::OriginKeyIdent>> {
+        if let Some(ref local) = self.local {
+            return local.show_origin_keys(origin);
+        }
         let url = try!(self.url_join(&format!("origins/{}/keys", origin)));
         debug!("GET {} with {:?}", &url, &self.client);
         let request = self.client.get(url);
@@ -117,6 +284,9 @@ impl Client {
                           token: &str,
                           progress: Option<&mut DisplayProgress>)
                           -> Result<()> {
+        if let Some(ref local) = self.local {
+            return local.put_origin_key(origin, revision, src_path, progress);
+        }
         let mut headers = Headers::new();
         headers.set(Authorization(Bearer { token: token.to_string() }));
         let url = try!(self.url_join(&format!("origins/{}/keys/{}", &origin, &revision)));
@@ -162,6 +332,9 @@ impl Client {
                                  token: &str,
                                  progress: Option<&mut DisplayProgress>)
                                  -> Result<()> {
+        if self.local.is_some() {
+            return Err(Error::NotSupportedByLocalDepot("uploading secret origin keys"));
+        }
         let mut headers = Headers::new();
         headers.set(Authorization(Bearer { token: token.to_string() }));
         let url = try!(self.url_join(&format!("origins/{}/secret_keys/{}", &origin, &revision)));
@@ -206,6 +379,9 @@ impl Client {
                                                   dst_path: &P,
                                                   progress: Option<&mut DisplayProgress>)
                                                   -> Result<PackageArchive> {
+        if let Some(ref local) = self.local {
+            return local.fetch_package(&ident, dst_path.as_ref(), progress);
+        }
         let url = try!(self.url_join(&format!("pkgs/{}/download", ident)));
         match self.download(url, dst_path.as_ref(), progress) {
             Ok(file) => {
@@ -229,6 +405,9 @@ impl Client {
     /// * Package cannot be found
     /// * Remote Depot is not available
     pub fn show_package<I: Identifiable>(&self, ident: I) -> Result<depotsrv::Package> {
+        if let Some(ref local) = self.local {
+            return local.show_package(&ident);
+        }
         let url = try!(self.url_show_package(&ident));
         debug!("GET {} with {:?}", &url, &self.client);
         let request = self.client.get(url);
@@ -245,7 +424,112 @@ impl Client {
         Ok(package)
     }
 
-    /// Upload a package to a remote Depot.
+    /// List the releases of an origin's packages, or of one package if a name is given, fetching
+    /// every page of the Depot's listing.
+    ///
+    /// # Failures
+    ///
+    /// * Origin or package cannot be found
+    /// * Remote Depot is not available
+    pub fn list_packages(&self,
+                         origin: &str,
+                         name: Option<&str>)
+                         -> Result<Vec<depotsrv::PackageIdent>> {
+        if let Some(ref local) = self.local {
+            return Ok(local.list_packages(origin, name));
+        }
+        let path = match name {
+            Some(name) => format!("pkgs/{}/{}", origin, name),
+            None => format!("pkgs/{}", origin),
+        };
+        let url = try!(self.url_join(&path));
+        let mut packages = Vec::new();
+        // A Depot's pages overlap by a release, which is only kept the first time it is seen.
+        let mut seen = HashSet::new();
+        let mut range: Option<String> = None;
+        loop {
+            debug!("GET {} with {:?}, range={:?}", &url, &self.client, range);
+            let mut headers = Headers::new();
+            if let Some(ref range) = range {
+                headers.set_raw("Range", vec![range.clone().into_bytes()]);
+            }
+            let mut res = try!(self.client.get(url.clone()).headers(headers).send());
+            match res.status {
+                StatusCode::Ok | StatusCode::PartialContent => (),
+                StatusCode::NotFound => {
+                    let ident = PackageIdent::new(origin, name.unwrap_or(""), None, None);
+                    return Err(Error::RemotePackageNotFound(ident));
+                }
+                status => return Err(Error::HTTP(status)),
+            }
+            let mut encoded = String::new();
+            try!(res.read_to_string(&mut encoded));
+            debug!("Body: {:?}", encoded);
+            let page: Vec<depotsrv::PackageIdent> = try!(json::decode(&encoded));
+            let before = packages.len();
+            for ident in page {
+                if seen.insert(ident.to_string()) {
+                    packages.push(ident);
+                }
+            }
+            let added = packages.len() > before;
+            range = match res.headers.get_raw("Next-Range") {
+                Some(values) if res.status == StatusCode::PartialContent && added => {
+                    values.get(0).map(|v| String::from_utf8_lossy(v).into_owned())
+                }
+                _ => None,
+            };
+            if range.is_none() {
+                return Ok(packages);
+            }
+        }
+    }
+
+    /// Wait for packages of an origin to be uploaded or promoted. Only promotions into the given
+    /// view are returned if one is named.
+    ///
+    /// Events after position `after` are returned as soon as there are any, or an empty list once
+    /// the Depot's wait times out. With no position, no events are returned, only the position of
+    /// the latest one to watch from.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * Remote Depot does not support watching, or has too many watchers
+    pub fn watch_origin(&self,
+                        origin: &str,
+                        view: Option<&str>,
+                        after: Option<u64>,
+                        timeout_ms: u64)
+                        -> Result<PackageEvents> {
+        if self.local.is_some() {
+            return Err(Error::NotSupportedByLocalDepot("watching origins"));
+        }
+        let mut url = try!(self.url_join(&format!("origins/{}/events", origin)));
+        {
+            let mut query = url.query_pairs_mut();
+            if let Some(view) = view {
+                query.append_pair("view", view);
+            }
+            if let Some(after) = after {
+                query.append_pair("after", &after.to_string());
+            }
+            query.append_pair("timeout_ms", &timeout_ms.to_string());
+        }
+        debug!("GET {} with {:?}", &url, &self.client);
+        let mut res = try!(self.client.get(url).send());
+        if res.status != hyper::status::StatusCode::Ok {
+            return Err(Error::HTTP(res.status));
+        }
+        let mut encoded = String::new();
+        try!(res.read_to_string(&mut encoded));
+        debug!("Body: {:?}", encoded);
+        let events: PackageEvents = try!(json::decode(&encoded));
+        Ok(events)
+    }
+
+    /// Upload a package to a remote Depot, along with how it was built if known. The provenance
+    /// is returned with the package's metadata afterwards.
     ///
     /// # Failures
     ///
@@ -258,8 +542,12 @@ impl Client {
     pub fn put_package(&self,
                        pa: &mut PackageArchive,
                        token: &str,
+                       provenance: Option<&depotsrv::PackageProvenance>,
                        progress: Option<&mut DisplayProgress>)
                        -> Result<()> {
+        if let Some(ref local) = self.local {
+            return local.put_package(pa, progress);
+        }
         let mut headers = Headers::new();
         headers.set(Authorization(Bearer { token: token.to_string() }));
         let checksum = try!(pa.checksum());
@@ -267,7 +555,24 @@ impl Client {
         let mut file = try!(File::open(&pa.path));
         let file_size = try!(file.metadata()).len();
         let mut url = try!(self.url_join(&format!("pkgs/{}", ident)));
-        url.query_pairs_mut().append_pair("checksum", &checksum);
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("checksum", &checksum);
+            if let Some(provenance) = provenance {
+                if provenance.has_job_id() {
+                    query.append_pair("job_id", &provenance.get_job_id().to_string());
+                }
+                if provenance.has_source_revision() {
+                    query.append_pair("source_revision", provenance.get_source_revision());
+                }
+                if provenance.has_builder_host() {
+                    query.append_pair("builder_host", provenance.get_builder_host());
+                }
+                if provenance.has_log_url() {
+                    query.append_pair("log_url", provenance.get_log_url());
+                }
+            }
+        }
         debug!("Reading from {}", &pa.path.display());
         let result = if let Some(progress) = progress {
             progress.size(file_size);
@@ -299,17 +604,42 @@ impl Client {
         }
     }
 
+    /// Download a file into the given directory, named by the Depot. If the Depot answers the
+    /// request for the first range with a partial response, the rest of a large file is fetched
+    /// in ranges over parallel connections, written into place, and checked against the entity
+    /// tag of the file once complete. A Depot which sends no entity tag has the file fetched
+    /// whole over a single connection, as ranges of it couldn't be checked.
+    ///
+    /// A download which fails part way through is tried again, resuming from the last byte
+    /// written where the Depot supports it. The partly downloaded file is removed if the
+    /// download fails.
     fn download(&self,
                 url: Url,
                 dst_path: &Path,
                 progress: Option<&mut DisplayProgress>)
                 -> Result<PathBuf> {
-        debug!("GET {} with {:?}", &url, &self.client);
-        let mut res = try!(self.client.get(url).send());
-        debug!("Response: {:?}", res);
-
-        if res.status != hyper::status::StatusCode::Ok {
-            return Err(Error::HTTP(res.status));
+        let opts = self.download_opts;
+        let mut progress = progress;
+        let range = if opts.concurrency > 1 {
+            Some((0, Some(opts.chunk_size - 1)))
+        } else {
+            None
+        };
+        let mut attempt = 0;
+        let mut res = try!(get_retrying(&self.client,
+                                        &url,
+                                        range,
+                                        opts.retries,
+                                        &mut attempt,
+                                        &mut progress));
+        if res.status == StatusCode::PartialContent && res.headers.get::<ETag>().is_none() {
+            debug!("No entity tag for {}, downloading it over a single connection", url);
+            res = try!(get_retrying(&self.client,
+                                    &url,
+                                    None,
+                                    opts.retries,
+                                    &mut attempt,
+                                    &mut progress));
         }
         try!(fs::create_dir_all(&dst_path));
 
@@ -318,19 +648,36 @@ impl Client {
             None => return Err(Error::NoXFilename),
         };
         let tmp_file_path = dst_path.join(format!("{}.tmp", file_name));
-        let dst_file_path = dst_path.join(file_name);
+        let dst_file_path = dst_path.join(file_name.clone());
+        let etag = res.headers.get::<ETag>().map(|etag| etag.to_string());
         debug!("Writing to {}", &tmp_file_path.display());
-        let mut f = try!(File::create(&tmp_file_path));
-        match progress {
-            Some(progress) => {
-                let size: u64 =
-                    res.headers.get::<hyper::header::ContentLength>().map_or(0, |v| **v);
-                progress.size(size);
-                let mut writer = BroadcastWriter::new(&mut f, progress);
-                try!(io::copy(&mut res, &mut writer))
+        let f = try!(File::create(&tmp_file_path));
+        let result = match etag {
+            Some(ref etag) if res.status == StatusCode::PartialContent => {
+                self.fetch_ranges(&url,
+                                  res,
+                                  etag,
+                                  &file_name,
+                                  &tmp_file_path,
+                                  f,
+                                  attempt,
+                                  progress)
+            }
+            _ => {
+                self.fetch_whole(&url,
+                                 res,
+                                 etag.as_ref().map(|e| e.as_str()),
+                                 f,
+                                 attempt,
+                                 progress)
             }
-            None => try!(io::copy(&mut res, &mut f)),
         };
+        if let Err(e) = result {
+            if let Err(err) = fs::remove_file(&tmp_file_path) {
+                warn!("Unable to remove {}, {}", tmp_file_path.display(), err);
+            }
+            return Err(e);
+        }
         debug!("Moving {} to {}",
                &tmp_file_path.display(),
                &dst_file_path.display());
@@ -338,7 +685,951 @@ impl Client {
         Ok(dst_file_path)
     }
 
+    /// Write the first range of a download from its partial response `res` into `f`, then fetch
+    /// the rest of the file in ranges and check the whole of it against its entity tag.
+    fn fetch_ranges(&self,
+                    url: &Url,
+                    mut res: Response,
+                    etag: &str,
+                    file_name: &str,
+                    path: &Path,
+                    mut f: File,
+                    attempt: usize,
+                    progress: Option<&mut DisplayProgress>)
+                    -> Result<()> {
+        let mut progress = progress;
+        let (last, total) = match parse_content_range(&res.headers) {
+            Some((0, last, total)) if last < total => (last, total),
+            _ => return Err(Error::InvalidRange(0)),
+        };
+        try!(f.set_len(total));
+        let mut copied = 0;
+        let result = match progress {
+            Some(ref mut progress) => {
+                progress.size(total);
+                let mut writer = BroadcastWriter::new(&mut f, progress);
+                copy_body(&mut res, &mut writer, Some(last + 1), 0, &mut copied)
+            }
+            None => copy_body(&mut res, &mut f, Some(last + 1), 0, &mut copied),
+        };
+        drop(f);
+        // Whatever is left of the first range is fetched again along with the others.
+        let mut ranges = vec![];
+        if let Err(e) = result {
+            let attempt = try!(backoff(e, attempt, self.download_opts.retries));
+            if let Some(ref mut progress) = progress {
+                progress.retry(attempt, copied);
+            }
+            ranges.push((copied, last));
+        }
+        ranges.extend(split_ranges(last + 1, total, self.download_opts.chunk_size));
+        if !ranges.is_empty() {
+            try!(self.download_ranges(url, etag, path, ranges, progress));
+        }
+        let checksum = try!(hash::hash_file(&path));
+        if checksum != etag.trim_matches('"') {
+            return Err(Error::DownloadChecksumMismatch(file_name.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Write the body of the whole file from the response `res` into `f`, resuming after a
+    /// failure. Only a download whose length is known is tried again, so that a resumed body
+    /// can be checked to end where the file does. Without an entity tag to make the resumed
+    /// range conditional, the file is fetched again from the start.
+    fn fetch_whole(&self,
+                   url: &Url,
+                   mut res: Response,
+                   etag: Option<&str>,
+                   mut f: File,
+                   attempt: usize,
+                   progress: Option<&mut DisplayProgress>)
+                   -> Result<()> {
+        let mut progress = progress;
+        let mut attempt = attempt;
+        let mut total = res.headers.get::<hyper::header::ContentLength>().map(|v| **v);
+        if let Some(ref mut progress) = progress {
+            progress.size(total.unwrap_or(0));
+        }
+        let mut written = 0;
+        let mut resume = false;
+        loop {
+            if resume {
+                let range = etag.map(|_| (written, None));
+                match send_get(&self.client, url, range, etag) {
+                    Ok(response) => res = response,
+                    Err(e) => {
+                        attempt = try!(backoff(e, attempt, self.download_opts.retries));
+                        if let Some(ref mut progress) = progress {
+                            progress.retry(attempt, written);
+                        }
+                        continue;
+                    }
+                }
+                if res.status == StatusCode::PartialContent {
+                    match parse_content_range(&res.headers) {
+                        Some((first, _, len)) if first == written && Some(len) == total => (),
+                        _ => return Err(Error::InvalidRange(written)),
+                    }
+                } else {
+                    // The Depot doesn't support resuming, or the file has changed since,
+                    // so the whole file is sent again and may not be the same length.
+                    written = 0;
+                    total = res.headers.get::<hyper::header::ContentLength>().map(|v| **v);
+                    try!(f.set_len(0));
+                    try!(f.seek(SeekFrom::Start(0)));
+                    if let Some(ref mut progress) = progress {
+                        progress.size(total.unwrap_or(0));
+                    }
+                }
+                if total.is_none() {
+                    return Err(Error::DownloadInterrupted(written));
+                }
+            }
+            let remaining = total.map(|total| total - written);
+            let mut copied = 0;
+            let result = match progress {
+                Some(ref mut progress) => {
+                    let mut writer = BroadcastWriter::new(&mut f, progress);
+                    copy_body(&mut res, &mut writer, remaining, written, &mut copied)
+                }
+                None => copy_body(&mut res, &mut f, remaining, written, &mut copied),
+            };
+            written += copied;
+            match result {
+                Ok(()) => return Ok(()),
+                Err(e) if total.is_none() => return Err(e),
+                Err(e) => {
+                    attempt = try!(backoff(e, attempt, self.download_opts.retries));
+                    if let Some(ref mut progress) = progress {
+                        progress.retry(attempt, written);
+                    }
+                    resume = true;
+                }
+            }
+        }
+    }
+
+    /// Fetch the given byte ranges of a download, several at once, writing each into place in
+    /// the file at `path`. Passing the entity tag of the first response makes the Depot refuse
+    /// ranges of a file which has since changed.
+    ///
+    /// Once a range fails the other workers stop, and are waited on so nothing is written to
+    /// the file after this returns.
+    fn download_ranges(&self,
+                       url: &Url,
+                       etag: &str,
+                       path: &Path,
+                       ranges: Vec<(u64, u64)>,
+                       progress: Option<&mut DisplayProgress>)
+                       -> Result<()> {
+        let retries = self.download_opts.retries;
+        let workers = cmp::min(self.download_opts.concurrency, ranges.len());
+        let queue = Arc::new(Mutex::new(ranges.into_iter()));
+        let abort = Arc::new(AtomicBool::new(false));
+        let (tx, rx) = mpsc::channel();
+        let mut handles = Vec::with_capacity(workers);
+        for _ in 0..workers {
+            let client = self.client.clone();
+            let url = url.clone();
+            let etag = etag.to_string();
+            let path = path.to_path_buf();
+            let queue = queue.clone();
+            let abort = abort.clone();
+            let tx = tx.clone();
+            handles.push(thread::spawn(move || {
+                while !abort.load(Ordering::SeqCst) {
+                    let next = queue.lock().unwrap().next();
+                    let (first, last) = match next {
+                        Some(range) => range,
+                        None => break,
+                    };
+                    let result = download_range(&client,
+                                                &url,
+                                                &etag,
+                                                &path,
+                                                (first, last),
+                                                retries,
+                                                &abort,
+                                                &tx);
+                    if let Err(e) = result {
+                        let _ = tx.send(Err(e));
+                        break;
+                    }
+                }
+            }));
+        }
+        drop(tx);
+        let mut progress = progress;
+        let mut result = Ok(());
+        for event in rx.iter() {
+            match event {
+                Ok(RangeEvent::Fetched(count)) => {
+                    if let Some(ref mut progress) = progress {
+                        progress.advance(count);
+                    }
+                }
+                Ok(RangeEvent::Retry(attempt, offset)) => {
+                    if let Some(ref mut progress) = progress {
+                        progress.retry(attempt, offset);
+                    }
+                }
+                Err(e) => {
+                    result = Err(e);
+                    break;
+                }
+            }
+        }
+        abort.store(true, Ordering::SeqCst);
+        for handle in handles {
+            let _ = handle.join();
+        }
+        result
+    }
+
     fn url_join(&self, path: &str) -> Result<Url> {
         Ok(try!(self.depot_url.join(&format!("{}/{}", self.depot_url.path(), path))))
     }
 }
+
+/// Progress of a worker fetching ranges of a download.
+enum RangeEvent {
+    /// Bytes written to the file
+    Fetched(u64),
+    /// Number of the attempt about to be made and the offset it resumes from
+    Retry(usize, u64),
+}
+
+/// Fetch the bytes from `first` to `last` of a download and write them at the same offset in
+/// the file at `path`, resuming from the last byte written after a failure. Gives up without
+/// trying again once `abort` is set.
+fn download_range(client: &hyper::Client,
+                  url: &Url,
+                  etag: &str,
+                  path: &Path,
+                  (first, last): (u64, u64),
+                  retries: usize,
+                  abort: &AtomicBool,
+                  events: &mpsc::Sender<Result<RangeEvent>>)
+                  -> Result<()> {
+    let mut file = try!(OpenOptions::new().write(true).open(path));
+    let mut offset = first;
+    let mut attempt = 0;
+    loop {
+        let mut copied = 0;
+        let result = send_get(client, url, Some((offset, Some(last))), Some(etag))
+            .and_then(|mut res| {
+                match parse_content_range(&res.headers) {
+                    Some((f, l, _)) if res.status == StatusCode::PartialContent &&
+                                       f == offset && l == last => {}
+                    _ => return Err(Error::InvalidRange(offset)),
+                }
+                try!(file.seek(SeekFrom::Start(offset)));
+                copy_body(&mut res, &mut file, Some(last - offset + 1), offset, &mut copied)
+            });
+        offset += copied;
+        if copied > 0 {
+            let _ = events.send(Ok(RangeEvent::Fetched(copied)));
+        }
+        match result {
+            Ok(()) => return Ok(()),
+            Err(e) => {
+                if abort.load(Ordering::SeqCst) {
+                    return Err(e);
+                }
+                attempt = try!(backoff(e, attempt, retries));
+                let _ = events.send(Ok(RangeEvent::Retry(attempt, offset)));
+            }
+        }
+    }
+}
+
+/// Send a GET request for a download with `send_get`, trying again after failures which
+/// `backoff` allows. `attempt` counts the attempts made so far across the whole download.
+fn get_retrying(client: &hyper::Client,
+                url: &Url,
+                range: Option<(u64, Option<u64>)>,
+                retries: usize,
+                attempt: &mut usize,
+                progress: &mut Option<&mut DisplayProgress>)
+                -> Result<Response> {
+    loop {
+        match send_get(client, url, range, None) {
+            Ok(res) => return Ok(res),
+            Err(e) => {
+                *attempt = try!(backoff(e, *attempt, retries));
+                if let Some(ref mut progress) = *progress {
+                    progress.retry(*attempt, 0);
+                }
+            }
+        }
+    }
+}
+
+/// Send a GET request for a download, optionally for a range of bytes from the first offset
+/// to the last, or to the end if there is no last. An entity tag makes the range conditional
+/// on the file being unchanged.
+fn send_get(client: &hyper::Client,
+            url: &Url,
+            range: Option<(u64, Option<u64>)>,
+            etag: Option<&str>)
+            -> Result<Response> {
+    let mut headers = Headers::new();
+    match range {
+        Some((first, Some(last))) => {
+            headers.set_raw("Range", vec![format!("bytes={}-{}", first, last).into_bytes()])
+        }
+        Some((first, None)) => {
+            headers.set_raw("Range", vec![format!("bytes={}-", first).into_bytes()])
+        }
+        None => (),
+    }
+    if let Some(etag) = etag {
+        headers.set_raw("If-Range", vec![etag.as_bytes().to_vec()]);
+    }
+    debug!("GET {} with {:?}", url, headers);
+    let res = try!(client.get(url.clone()).headers(headers).send());
+    debug!("Response: {:?}", res);
+    match res.status {
+        StatusCode::Ok | StatusCode::PartialContent => Ok(res),
+        status => Err(Error::HTTP(status)),
+    }
+}
+
+/// Copy the body of a response to `writer`, expecting `len` bytes if the length is known, and
+/// counting the bytes written in `copied` so a failed copy can be resumed. `offset` is where the
+/// body starts in the file being downloaded. A body which fails or ends early is reported as
+/// interrupted; failing to write is not.
+fn copy_body<R: Read, W: Write>(res: &mut R,
+                                writer: &mut W,
+                                len: Option<u64>,
+                                offset: u64,
+                                copied: &mut u64)
+                                -> Result<()> {
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        let want = match len {
+            Some(len) if *copied >= len => return Ok(()),
+            Some(len) => cmp::min(buf.len() as u64, len - *copied) as usize,
+            None => buf.len(),
+        };
+        let count = match res.read(&mut buf[..want]) {
+            Ok(0) if len.is_none() => return Ok(()),
+            Ok(0) => return Err(Error::DownloadInterrupted(offset + *copied)),
+            Ok(count) => count,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => {
+                debug!("Download interrupted at byte {}, err={:?}", offset + *copied, e);
+                return Err(Error::DownloadInterrupted(offset + *copied));
+            }
+        };
+        try!(writer.write_all(&buf[..count]));
+        *copied += count as u64;
+    }
+}
+
+/// Waits before a failed download is tried again, a little longer after each attempt, and
+/// returns the number of the next attempt. The error is returned instead if the retries are used
+/// up or it isn't one which trying again could fix.
+fn backoff(err: Error, attempt: usize, retries: usize) -> Result<usize> {
+    let transient = match err {
+        Error::DownloadInterrupted(_) |
+        Error::HyperError(_) => true,
+        Error::HTTP(ref status) => status.is_server_error(),
+        _ => false,
+    };
+    if !transient || attempt >= retries {
+        return Err(err);
+    }
+    warn!("Download failed, retrying ({}/{}): {}", attempt + 1, retries, err);
+    thread::sleep(Duration::from_millis(500 << cmp::min(attempt, 5)));
+    Ok(attempt + 1)
+}
+
+/// Split the bytes of a download from `first` up to `total` into ranges of at most `chunk_size`
+/// bytes, each given by its first and last byte offsets.
+fn split_ranges(first: u64, total: u64, chunk_size: u64) -> Vec<(u64, u64)> {
+    let mut ranges = vec![];
+    let mut first = first;
+    while first < total {
+        let last = cmp::min(first + chunk_size, total) - 1;
+        ranges.push((first, last));
+        first = last + 1;
+    }
+    ranges
+}
+
+/// The first and last byte offsets and the total length from a `Content-Range` header of the
+/// form `bytes first-last/total`.
+fn parse_content_range(headers: &Headers) -> Option<(u64, u64, u64)> {
+    let raw = match headers.get::<ContentRange>() {
+        Some(raw) => raw.to_string(),
+        None => return None,
+    };
+    let raw = raw.trim();
+    if !raw.starts_with("bytes ") {
+        return None;
+    }
+    let spec = &raw["bytes ".len()..];
+    let (range, total) = match spec.find('/') {
+        Some(idx) => (&spec[..idx], &spec[idx + 1..]),
+        None => return None,
+    };
+    let (first, last) = match range.find('-') {
+        Some(idx) => (&range[..idx], &range[idx + 1..]),
+        None => return None,
+    };
+    match (first.parse::<u64>(), last.parse::<u64>(), total.parse::<u64>()) {
+        (Ok(first), Ok(last), Ok(total)) if first <= last => Some((first, last, total)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp;
+    use std::fs::File;
+    use std::io::{self, BufRead, BufReader, Cursor, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::path::{Path, PathBuf};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+    use std::u64;
+
+    use hab_core::crypto::hash::Hasher;
+    use hyper::header::Headers;
+    use hyper::status::StatusCode;
+    use hyper::Url;
+    use protocol::depotsrv;
+    use rustc_serialize::json;
+    use tempdir::TempDir;
+
+    use super::{backoff, copy_body, parse_content_range, split_ranges, Client, ContentRange,
+                DownloadOpts, Error};
+
+    const FILE_NAME: &'static str = "core-test-1.0.0-20160101000000-x86_64-linux.hart";
+
+    /// The byte range a request asked for, if any.
+    type Requested = Option<(u64, Option<u64>)>;
+
+    /// A Depot serving a single download, answering each request with the raw response `reply`
+    /// makes from the range it asked for. The ranges asked for are recorded in order.
+    struct TestDepot {
+        url: String,
+        requests: Arc<Mutex<Vec<Requested>>>,
+    }
+
+    impl TestDepot {
+        fn start<F>(reply: F) -> TestDepot
+            where F: Fn(Requested) -> Vec<u8> + Send + 'static
+        {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}/v1/depot", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(vec![]));
+            let recorded = requests.clone();
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = match stream {
+                        Ok(stream) => stream,
+                        Err(_) => break,
+                    };
+                    let range = read_request(&stream);
+                    recorded.lock().unwrap().push(range);
+                    let _ = stream.write_all(&reply(range));
+                }
+            });
+            TestDepot {
+                url: url,
+                requests: requests,
+            }
+        }
+
+        fn requests(&self) -> Vec<Requested> {
+            self.requests.lock().unwrap().clone()
+        }
+
+        fn client(&self, fs_root: &Path, concurrency: usize, retries: usize) -> Client {
+            let mut client = Client::new(&self.url[..], Some(fs_root)).unwrap();
+            client.set_download_opts(DownloadOpts {
+                concurrency: concurrency,
+                chunk_size: 100,
+                retries: retries,
+            });
+            client
+        }
+
+        fn download_url(&self) -> Url {
+            Url::parse(&format!("{}/pkgs/core/test/download", self.url)).unwrap()
+        }
+    }
+
+    fn read_request(stream: &TcpStream) -> Requested {
+        let reader = BufReader::new(stream.try_clone().unwrap());
+        let mut range = None;
+        for line in reader.lines() {
+            let line = line.unwrap();
+            if line.is_empty() {
+                break;
+            }
+            if line.to_lowercase().starts_with("range: bytes=") {
+                let mut spec = line["range: bytes=".len()..].splitn(2, '-');
+                let first = spec.next().unwrap().parse().unwrap();
+                let last = spec.next().and_then(|last| last.parse().ok());
+                range = Some((first, last));
+            }
+        }
+        range
+    }
+
+    /// A raw response of the given status carrying `body`, with a `Content-Length` of `len`
+    /// which may be more than the body to cut it short, or none at all.
+    fn response(status: &str, headers: &[String], len: Option<usize>, body: &[u8]) -> Vec<u8> {
+        let mut res = format!("HTTP/1.1 {}\r\nConnection: close\r\nX-Filename: {}\r\n",
+                              status,
+                              FILE_NAME);
+        if let Some(len) = len {
+            res.push_str(&format!("Content-Length: {}\r\n", len));
+        }
+        for header in headers {
+            res.push_str(header);
+            res.push_str("\r\n");
+        }
+        res.push_str("\r\n");
+        let mut res = res.into_bytes();
+        res.extend_from_slice(body);
+        res
+    }
+
+    /// The response of a Depot which supports ranges to a request for `range` of `body`.
+    fn serve_range(body: &[u8], etag: Option<&str>, range: Requested) -> Vec<u8> {
+        let mut headers = vec![];
+        if let Some(etag) = etag {
+            headers.push(format!("ETag: \"{}\"", etag));
+        }
+        match range {
+            Some((first, last)) => {
+                let last = cmp::min(last.unwrap_or(u64::MAX), body.len() as u64 - 1);
+                headers.push(format!("Content-Range: bytes {}-{}/{}", first, last, body.len()));
+                let part = &body[first as usize..last as usize + 1];
+                response("206 Partial Content", &headers, Some(part.len()), part)
+            }
+            None => response("200 OK", &headers, Some(body.len()), body),
+        }
+    }
+
+    fn body() -> Vec<u8> {
+        (0..1000).map(|i| (i % 251) as u8).collect()
+    }
+
+    fn checksum(body: &[u8]) -> String {
+        let mut hasher = Hasher::new();
+        hasher.update(body);
+        hasher.finish()
+    }
+
+    fn read(path: &Path) -> Vec<u8> {
+        let mut content = vec![];
+        File::open(path).unwrap().read_to_end(&mut content).unwrap();
+        content
+    }
+
+    fn tmp_file(dir: &TempDir) -> PathBuf {
+        dir.path().join(format!("{}.tmp", FILE_NAME))
+    }
+
+    /// The first response of a Depot which supports ranges, cut short after 400 bytes.
+    fn cut_short(body: &[u8], etag: Option<&str>) -> Vec<u8> {
+        let headers: Vec<String> =
+            etag.into_iter().map(|etag| format!("ETag: \"{}\"", etag)).collect();
+        response("200 OK", &headers, Some(body.len()), &body[..400])
+    }
+
+    /// A reader which fails after giving up the bytes it holds.
+    struct Failing(Cursor<Vec<u8>>);
+
+    impl Read for Failing {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.read(buf) {
+                Ok(0) => Err(io::Error::new(io::ErrorKind::ConnectionReset, "reset")),
+                result => result,
+            }
+        }
+    }
+
+    /// A writer which refuses everything.
+    struct Full;
+
+    impl Write for Full {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::Other, "disk full"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn copy_body_stops_at_the_expected_length() {
+        let mut out = vec![];
+        let mut copied = 0;
+        copy_body(&mut Cursor::new(body()), &mut out, Some(300), 0, &mut copied).unwrap();
+        assert_eq!(copied, 300);
+        assert_eq!(&out[..], &body()[..300]);
+    }
+
+    #[test]
+    fn copy_body_reports_a_body_which_ends_early_as_interrupted() {
+        let mut out = vec![];
+        let mut copied = 0;
+        match copy_body(&mut Cursor::new(body()), &mut out, Some(1200), 100, &mut copied) {
+            Err(Error::DownloadInterrupted(1100)) => (),
+            other => panic!("Expected an interrupted download, got {:?}", other),
+        }
+        assert_eq!(copied, 1000);
+    }
+
+    #[test]
+    fn copy_body_reads_a_body_of_unknown_length_to_the_end() {
+        let mut out = vec![];
+        let mut copied = 0;
+        copy_body(&mut Cursor::new(body()), &mut out, None, 0, &mut copied).unwrap();
+        assert_eq!(copied, 1000);
+        assert_eq!(out, body());
+    }
+
+    #[test]
+    fn copy_body_reports_a_failed_read_as_interrupted() {
+        let mut out = vec![];
+        let mut copied = 0;
+        let mut res = Failing(Cursor::new(body()));
+        match copy_body(&mut res, &mut out, None, 0, &mut copied) {
+            Err(Error::DownloadInterrupted(1000)) => (),
+            other => panic!("Expected an interrupted download, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn copy_body_reports_a_failed_write_as_such() {
+        let mut copied = 0;
+        match copy_body(&mut Cursor::new(body()), &mut Full, None, 0, &mut copied) {
+            Err(Error::IO(_)) => (),
+            other => panic!("Expected a write error, got {:?}", other),
+        }
+        assert_eq!(copied, 0);
+    }
+
+    #[test]
+    fn backoff_counts_attempts_of_transient_errors() {
+        assert_eq!(backoff(Error::DownloadInterrupted(10), 0, 2).unwrap(), 1);
+        assert_eq!(backoff(Error::HTTP(StatusCode::ServiceUnavailable), 1, 2).unwrap(),
+                   2);
+    }
+
+    #[test]
+    fn backoff_gives_up_once_the_retries_are_used() {
+        match backoff(Error::DownloadInterrupted(10), 2, 2) {
+            Err(Error::DownloadInterrupted(10)) => (),
+            other => panic!("Expected the error back, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn backoff_gives_up_on_errors_which_trying_again_cant_fix() {
+        match backoff(Error::HTTP(StatusCode::NotFound), 0, 2) {
+            Err(Error::HTTP(StatusCode::NotFound)) => (),
+            other => panic!("Expected the error back, got {:?}", other),
+        }
+        match backoff(Error::InvalidRange(10), 0, 2) {
+            Err(Error::InvalidRange(10)) => (),
+            other => panic!("Expected the error back, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn download_resumes_from_the_last_byte_written() {
+        let dir = TempDir::new("depot-client").unwrap();
+        let etag = checksum(&body());
+        let depot = TestDepot::start(move |range| {
+            match range {
+                None => cut_short(&body(), Some(&etag[..])),
+                range => serve_range(&body(), Some(&etag[..]), range),
+            }
+        });
+        let client = depot.client(dir.path(), 1, 1);
+
+        let path = client.download(depot.download_url(), dir.path(), None).unwrap();
+
+        assert_eq!(read(&path), body());
+        assert_eq!(depot.requests(), vec![None, Some((400, None))]);
+    }
+
+    #[test]
+    fn download_without_an_entity_tag_starts_again_instead_of_resuming() {
+        let dir = TempDir::new("depot-client").unwrap();
+        let depot = TestDepot::start(move |_| {
+            response("200 OK", &[], Some(1000), &body()[..400])
+        });
+        let client = depot.client(dir.path(), 1, 1);
+        // The second attempt is cut short too, and there are no retries left.
+        assert!(client.download(depot.download_url(), dir.path(), None).is_err());
+        assert_eq!(depot.requests(), vec![None, None]);
+        assert!(!tmp_file(&dir).exists());
+    }
+
+    #[test]
+    fn download_takes_the_length_of_a_restarted_file_from_its_response() {
+        let dir = TempDir::new("depot-client").unwrap();
+        let etag = checksum(&body());
+        let depot = TestDepot::start(move |range| {
+            match range {
+                None => cut_short(&body(), Some(&etag[..])),
+                // The file has changed, so the Depot sends the whole of the new one.
+                Some(_) => serve_range(&body()[..600], None, None),
+            }
+        });
+        let client = depot.client(dir.path(), 1, 1);
+
+        let path = client.download(depot.download_url(), dir.path(), None).unwrap();
+
+        assert_eq!(read(&path), &body()[..600]);
+    }
+
+    #[test]
+    fn download_fails_a_resumed_body_of_unknown_length() {
+        let dir = TempDir::new("depot-client").unwrap();
+        let etag = checksum(&body());
+        let depot = TestDepot::start(move |range| {
+            match range {
+                None => cut_short(&body(), Some(&etag[..])),
+                Some(_) => response("200 OK", &[], None, &body()[..600]),
+            }
+        });
+        let client = depot.client(dir.path(), 1, 1);
+
+        match client.download(depot.download_url(), dir.path(), None) {
+            Err(Error::DownloadInterrupted(0)) => (),
+            other => panic!("Expected an interrupted download, got {:?}", other),
+        }
+        assert!(!tmp_file(&dir).exists());
+    }
+
+    #[test]
+    fn download_of_unknown_length_reads_to_the_end() {
+        let dir = TempDir::new("depot-client").unwrap();
+        let depot = TestDepot::start(|_| response("200 OK", &[], None, &body()));
+        let client = depot.client(dir.path(), 1, 1);
+
+        let path = client.download(depot.download_url(), dir.path(), None).unwrap();
+
+        assert_eq!(read(&path), body());
+        assert_eq!(depot.requests(), vec![None]);
+    }
+
+    #[test]
+    fn ping_times_a_depot_which_responds() {
+        let dir = TempDir::new("depot-client").unwrap();
+        let depot = TestDepot::start(|_| response("200 OK", &[], Some(0), b""));
+        let client = depot.client(dir.path(), 1, 0);
+        assert!(client.ping(Duration::from_secs(10)).unwrap() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn ping_gives_up_on_a_depot_which_doesnt_respond() {
+        let dir = TempDir::new("depot-client").unwrap();
+        // Connections are accepted by the listener's backlog but never answered.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/v1/depot", listener.local_addr().unwrap());
+        let client = Client::new(&url[..], Some(dir.path())).unwrap();
+        match client.ping(Duration::from_millis(100)) {
+            Err(Error::IO(ref e)) if e.kind() == io::ErrorKind::TimedOut => (),
+            other => panic!("Expected the ping to time out, got {:?}", other),
+        }
+    }
+
+    /// A Depot listing `count` releases of core/test, paged the way a Depot pages them: a page
+    /// starts at the 1-based position in the `Range` header and ends at the position named by
+    /// `Next-Range`, which is also the first release of the next page.
+    fn listing_depot(count: usize, page_size: usize) -> (String, Arc<Mutex<Vec<Option<usize>>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/v1/depot", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = match stream {
+                    Ok(stream) => stream,
+                    Err(_) => break,
+                };
+                let reader = BufReader::new(stream.try_clone().unwrap());
+                let mut range = None;
+                for line in reader.lines() {
+                    let line = line.unwrap();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if line.to_lowercase().starts_with("range: ") {
+                        range = line["range: ".len()..].parse().ok();
+                    }
+                }
+                recorded.lock().unwrap().push(range);
+                let offset = range.map(|r: usize| r.saturating_sub(1)).unwrap_or(0);
+                let last = offset + page_size;
+                let page: Vec<depotsrv::PackageIdent> = (offset..cmp::min(last + 1, count))
+                    .map(|i| {
+                        let mut ident = depotsrv::PackageIdent::new();
+                        ident.set_origin("core".to_string());
+                        ident.set_name("test".to_string());
+                        ident.set_version(i.to_string());
+                        ident.set_release("20160101000000".to_string());
+                        ident
+                    })
+                    .collect();
+                let body = json::encode(&page).unwrap();
+                let reply = if count >= last + 1 {
+                    response("206 Partial Content",
+                             &[format!("Next-Range: {}", last + 1)],
+                             Some(body.len()),
+                             body.as_bytes())
+                } else {
+                    response("200 OK", &[], Some(body.len()), body.as_bytes())
+                };
+                let _ = stream.write_all(&reply);
+            }
+        });
+        (url, requests)
+    }
+
+    #[test]
+    fn list_packages_follows_pages_without_repeating_releases() {
+        let dir = TempDir::new("depot-client").unwrap();
+        let (url, requests) = listing_depot(10, 3);
+        let client = Client::new(&url[..], Some(dir.path())).unwrap();
+        let versions: Vec<String> = client.list_packages("core", Some("test"))
+            .unwrap()
+            .iter()
+            .map(|ident| ident.get_version().to_string())
+            .collect();
+        let expected: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+        assert_eq!(versions, expected);
+        assert_eq!(*requests.lock().unwrap(),
+                   vec![None, Some(4), Some(7), Some(10)]);
+    }
+
+    #[test]
+    fn list_packages_rejects_a_malformed_page() {
+        let dir = TempDir::new("depot-client").unwrap();
+        let depot = TestDepot::start(|_| response("200 OK", &[], Some(7), b"[{\"or\":"));
+        let client = depot.client(dir.path(), 1, 0);
+        match client.list_packages("core", None) {
+            Err(Error::JsonDecode(_)) => (),
+            other => panic!("Expected a malformed response, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn download_fetches_the_rest_of_a_large_file_in_ranges() {
+        let dir = TempDir::new("depot-client").unwrap();
+        let etag = checksum(&body());
+        let depot = TestDepot::start(move |range| serve_range(&body(), Some(&etag[..]), range));
+        let client = depot.client(dir.path(), 4, 0);
+
+        let path = client.download(depot.download_url(), dir.path(), None).unwrap();
+
+        assert_eq!(path, dir.path().join(FILE_NAME));
+        assert_eq!(read(&path), body());
+        assert!(!tmp_file(&dir).exists());
+        let mut requests = depot.requests();
+        requests.sort();
+        let expected: Vec<Requested> =
+            (0..10).map(|i| Some((i * 100, Some(i * 100 + 99)))).collect();
+        assert_eq!(requests, expected);
+    }
+
+    #[test]
+    fn download_rejects_ranges_which_dont_match_the_entity_tag() {
+        let dir = TempDir::new("depot-client").unwrap();
+        let etag = checksum(b"some other file");
+        let depot = TestDepot::start(move |range| serve_range(&body(), Some(&etag[..]), range));
+        let client = depot.client(dir.path(), 4, 0);
+
+        match client.download(depot.download_url(), dir.path(), None) {
+            Err(Error::DownloadChecksumMismatch(ref file)) if file == FILE_NAME => (),
+            other => panic!("Expected a checksum mismatch, got {:?}", other),
+        }
+        assert!(!tmp_file(&dir).exists());
+        assert!(!dir.path().join(FILE_NAME).exists());
+    }
+
+    #[test]
+    fn download_without_an_entity_tag_uses_a_single_connection() {
+        let dir = TempDir::new("depot-client").unwrap();
+        let depot = TestDepot::start(|range| serve_range(&body(), None, range));
+        let client = depot.client(dir.path(), 4, 0);
+
+        let path = client.download(depot.download_url(), dir.path(), None).unwrap();
+
+        assert_eq!(read(&path), body());
+        assert_eq!(depot.requests(), vec![Some((0, Some(99))), None]);
+    }
+
+    #[test]
+    fn download_stops_fetching_ranges_after_one_fails() {
+        let dir = TempDir::new("depot-client").unwrap();
+        let etag = checksum(&body());
+        let depot = TestDepot::start(move |range| {
+            match range {
+                Some((500, _)) => response("404 Not Found", &[], Some(0), b""),
+                range => serve_range(&body(), Some(&etag[..]), range),
+            }
+        });
+        let client = depot.client(dir.path(), 4, 0);
+
+        match client.download(depot.download_url(), dir.path(), None) {
+            Err(Error::HTTP(StatusCode::NotFound)) => (),
+            other => panic!("Expected the failed range's error, got {:?}", other),
+        }
+        assert!(!tmp_file(&dir).exists());
+        assert!(!dir.path().join(FILE_NAME).exists());
+        // Every worker has stopped by the time the download returns.
+        let made = depot.requests().len();
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(depot.requests().len(), made);
+        assert_eq!(depot.requests().iter().filter(|r| **r == Some((500, Some(599)))).count(),
+                   1);
+    }
+
+    fn content_range(value: &str) -> Option<(u64, u64, u64)> {
+        let mut headers = Headers::new();
+        headers.set(ContentRange(value.to_string()));
+        parse_content_range(&headers)
+    }
+
+    #[test]
+    fn parse_content_range_reads_the_range_and_total() {
+        assert_eq!(content_range("bytes 0-99/1000"), Some((0, 99, 1000)));
+        assert_eq!(content_range(" bytes 900-999/1000 "), Some((900, 999, 1000)));
+        assert_eq!(content_range("bytes 5-5/6"), Some((5, 5, 6)));
+    }
+
+    #[test]
+    fn parse_content_range_rejects_malformed_headers() {
+        assert_eq!(parse_content_range(&Headers::new()), None);
+        assert_eq!(content_range("0-99/1000"), None);
+        assert_eq!(content_range("bytes 0-99"), None);
+        assert_eq!(content_range("bytes 99/1000"), None);
+        assert_eq!(content_range("bytes 100-99/1000"), None);
+        assert_eq!(content_range("bytes 0-99/*"), None);
+        assert_eq!(content_range("bytes */1000"), None);
+    }
+
+    #[test]
+    fn split_ranges_covers_the_rest_of_the_file() {
+        assert_eq!(split_ranges(100, 350, 100),
+                   vec![(100, 199), (200, 299), (300, 349)]);
+        assert_eq!(split_ranges(100, 300, 100), vec![(100, 199), (200, 299)]);
+        assert_eq!(split_ranges(0, 1, 100), vec![(0, 0)]);
+        assert!(split_ranges(300, 300, 100).is_empty());
+    }
+}
@@ -0,0 +1,434 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A Depot kept in a directory on the local filesystem, such as one exported to removable media
+//! for an air-gapped environment. Packages and public origin keys are found anywhere beneath the
+//! directory, so both a copy of a Depot's data directory and a plain directory of artifacts and
+//! keys can be used. Uploads are written to `pkgs/` and `keys/` beneath it.
+//!
+//! The directory is searched once, when the Depot is opened, so files added to it by anything
+//! other than this Depot afterwards are not seen.
+
+use std::cmp::Ordering;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use broadcast::BroadcastWriter;
+use hab_core::crypto::PUBLIC_KEY_SUFFIX;
+use hab_core::package::{FromArchive, Identifiable, PackageArchive, PackageIdent};
+use hyper::status::StatusCode;
+use protocol::depotsrv;
+use walkdir::WalkDir;
+
+use error::{Error, Result};
+use DisplayProgress;
+
+const ARCHIVE_SUFFIX: &'static str = "hart";
+
+pub struct LocalDepot {
+    path: PathBuf,
+    /// Names and paths of the archives and public keys beneath the directory.
+    files: Mutex<Vec<(String, PathBuf)>>,
+}
+
+impl LocalDepot {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Result<Self> {
+        let path = path.into();
+        if !path.is_dir() {
+            return Err(Error::InvalidLocalDepot(path.display().to_string()));
+        }
+        let files = index(&path);
+        debug!("Found {} archives and keys in {}", files.len(), path.display());
+        Ok(LocalDepot {
+            path: path,
+            files: Mutex::new(files),
+        })
+    }
+
+    pub fn fetch_origin_key(&self,
+                            origin: &str,
+                            revision: &str,
+                            dst_path: &Path,
+                            progress: Option<&mut DisplayProgress>)
+                            -> Result<PathBuf> {
+        let file_name = format!("{}-{}.{}", origin, revision, PUBLIC_KEY_SUFFIX);
+        match self.find(PUBLIC_KEY_SUFFIX, |name| name == file_name).pop() {
+            Some(src) => copy_file(&src, dst_path, progress),
+            None => Err(Error::RemoteOriginKeyNotFound(format!("{}-{}", origin, revision))),
+        }
+    }
+
+    pub fn show_origin_keys(&self, origin: &str) -> Result<Vec<depotsrv::OriginKeyIdent>> {
+        let prefix = format!("{}-", origin);
+        let mut keys: Vec<depotsrv::OriginKeyIdent> = self.find(PUBLIC_KEY_SUFFIX, |name| {
+                name.starts_with(&prefix)
+            })
+            .into_iter()
+            .filter_map(|path| {
+                let stem = path.file_stem().unwrap().to_string_lossy().into_owned();
+                let revision = stem[prefix.len()..].to_string();
+                // Keys of other origins whose names start with this one's are skipped.
+                if revision.is_empty() || !revision.chars().all(|c| c.is_digit(10)) {
+                    return None;
+                }
+                let mut ident = depotsrv::OriginKeyIdent::new();
+                ident.set_origin(origin.to_string());
+                ident.set_revision(revision);
+                ident.set_location(path.display().to_string());
+                Some(ident)
+            })
+            .collect();
+        if keys.is_empty() {
+            return Err(Error::RemoteOriginKeyNotFound(origin.to_string()));
+        }
+        keys.sort_by(|a, b| b.get_revision().cmp(a.get_revision()));
+        Ok(keys)
+    }
+
+    pub fn put_origin_key(&self,
+                          origin: &str,
+                          revision: &str,
+                          src_path: &Path,
+                          progress: Option<&mut DisplayProgress>)
+                          -> Result<()> {
+        let file_name = format!("{}-{}.{}", origin, revision, PUBLIC_KEY_SUFFIX);
+        if !self.find(PUBLIC_KEY_SUFFIX, |name| name == file_name).is_empty() {
+            return Err(Error::HTTP(StatusCode::Conflict));
+        }
+        let path = try!(copy_file(src_path, &self.path.join("keys"), progress));
+        self.add(path);
+        Ok(())
+    }
+
+    pub fn fetch_package<I: Identifiable>(&self,
+                                          ident: &I,
+                                          dst_path: &Path,
+                                          progress: Option<&mut DisplayProgress>)
+                                          -> Result<PackageArchive> {
+        let archive = try!(self.archive(ident));
+        let path = try!(copy_file(&archive.path, dst_path, progress));
+        Ok(PackageArchive::new(path))
+    }
+
+    pub fn show_package<I: Identifiable>(&self, ident: &I) -> Result<depotsrv::Package> {
+        let mut archive = try!(self.archive(ident));
+        Ok(try!(depotsrv::Package::from_archive(&mut archive)))
+    }
+
+    pub fn put_package(&self,
+                       pa: &mut PackageArchive,
+                       progress: Option<&mut DisplayProgress>)
+                       -> Result<()> {
+        let ident = try!(pa.ident());
+        if self.archive(&ident).is_ok() {
+            return Err(Error::HTTP(StatusCode::Conflict));
+        }
+        let path = try!(copy_file(&pa.path, &self.path.join("pkgs"), progress));
+        self.add(path);
+        Ok(())
+    }
+
+    /// Identifiers of the archives of an origin, or of one of its packages, sorted by release.
+    pub fn list_packages(&self, origin: &str, name: Option<&str>) -> Vec<depotsrv::PackageIdent> {
+        let prefix = match name {
+            Some(name) => format!("{}-{}-", origin, name),
+            None => format!("{}-", origin),
+        };
+        let mut idents = Vec::new();
+        for path in self.find(ARCHIVE_SUFFIX, |file| file.starts_with(&prefix)) {
+            let mut archive = PackageArchive::new(path);
+            match archive.ident() {
+                Ok(ident) => {
+                    if ident.origin == origin && name.map_or(true, |name| ident.name == name) {
+                        idents.push(ident);
+                    }
+                }
+                Err(e) => {
+                    warn!("Skipping unreadable archive {}, err={}",
+                          archive.path.display(),
+                          e);
+                }
+            }
+        }
+        idents.sort_by(|a, b| match a.name.cmp(&b.name) {
+            Ordering::Equal => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+            ordering => ordering,
+        });
+        idents.into_iter().map(depotsrv::PackageIdent::from).collect()
+    }
+
+    /// The archive of the latest release of a package satisfying the given identifier.
+    fn archive<I: Identifiable>(&self, ident: &I) -> Result<PackageArchive> {
+        let prefix = format!("{}-{}-", ident.origin(), ident.name());
+        let mut latest: Option<(PackageIdent, PackageArchive)> = None;
+        for path in self.find(ARCHIVE_SUFFIX, |name| name.starts_with(&prefix)) {
+            let mut archive = PackageArchive::new(path);
+            let candidate = match archive.ident() {
+                Ok(candidate) => candidate,
+                Err(e) => {
+                    warn!("Skipping unreadable archive {}, err={}",
+                          archive.path.display(),
+                          e);
+                    continue;
+                }
+            };
+            if !candidate.satisfies(ident) {
+                continue;
+            }
+            let newer = match latest {
+                Some((ref current, _)) => candidate.partial_cmp(current) == Some(Ordering::Greater),
+                None => true,
+            };
+            if newer {
+                latest = Some((candidate, archive));
+            }
+        }
+        match latest {
+            Some((_, archive)) => Ok(archive),
+            None => Err(Error::RemotePackageNotFound(PackageIdent::new(ident.origin(),
+                                                                       ident.name(),
+                                                                       ident.version(),
+                                                                       ident.release()))),
+        }
+    }
+
+    /// Paths of the files beneath the Depot's directory with the given extension whose names
+    /// match.
+    fn find<F>(&self, extension: &str, matches: F) -> Vec<PathBuf>
+        where F: Fn(&str) -> bool
+    {
+        self.files
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|&&(ref name, ref path)| {
+                path.extension().map_or(false, |ext| ext == extension) && matches(&name[..])
+            })
+            .map(|&(_, ref path)| path.clone())
+            .collect()
+    }
+
+    /// Add a file written beneath the Depot's directory to its index.
+    fn add(&self, path: PathBuf) {
+        let mut files = self.files.lock().unwrap();
+        if files.iter().any(|&(_, ref p)| *p == path) {
+            return;
+        }
+        if let Some(name) = path.file_name().and_then(|name| name.to_str()).map(String::from) {
+            files.push((name, path));
+        }
+    }
+}
+
+/// Names and paths of the archives and public keys beneath a directory.
+fn index(dir: &Path) -> Vec<(String, PathBuf)> {
+    WalkDir::new(dir)
+        .follow_links(true)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.path().to_path_buf())
+        .filter(|path| {
+            path.extension()
+                .map_or(false, |ext| ext == ARCHIVE_SUFFIX || ext == PUBLIC_KEY_SUFFIX)
+        })
+        .filter_map(|path| {
+            let name = path.file_name().and_then(|name| name.to_str()).map(String::from);
+            name.map(|name| (name, path))
+        })
+        .collect()
+}
+
+/// Copy a file into a directory, through a temporary file so that a partial copy is never left
+/// under the file's name, and return the path of the copy.
+fn copy_file(src: &Path,
+             dst_dir: &Path,
+             progress: Option<&mut DisplayProgress>)
+             -> Result<PathBuf> {
+    let file_name = match src.file_name() {
+        Some(file_name) => file_name.to_os_string(),
+        None => return Err(Error::NoFilePart),
+    };
+    try!(fs::create_dir_all(dst_dir));
+    let dst_file_path = dst_dir.join(&file_name);
+    let mut tmp_name = file_name.clone();
+    tmp_name.push(".tmp");
+    let tmp_file_path = dst_dir.join(tmp_name);
+    debug!("Copying {} to {}", src.display(), tmp_file_path.display());
+    let mut src_file = try!(File::open(src));
+    let mut f = try!(File::create(&tmp_file_path));
+    match progress {
+        Some(progress) => {
+            progress.size(try!(src_file.metadata()).len());
+            let mut writer = BroadcastWriter::new(&mut f, progress);
+            try!(io::copy(&mut src_file, &mut writer));
+        }
+        None => {
+            try!(io::copy(&mut src_file, &mut f));
+        }
+    }
+    try!(f.flush());
+    try!(fs::rename(&tmp_file_path, &dst_file_path));
+    Ok(dst_file_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
+
+    use hab_core::package::{PackageArchive, PackageIdent};
+    use hyper::status::StatusCode;
+    use tempdir::TempDir;
+
+    use super::LocalDepot;
+    use error::Error;
+
+    const ARCHIVE: &'static str = "happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart";
+
+    fn fixture() -> PathBuf {
+        Path::new("../core/tests/fixtures").join(ARCHIVE)
+    }
+
+    fn ident(ident: &str) -> PackageIdent {
+        ident.parse().unwrap()
+    }
+
+    fn touch(path: &Path) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        File::create(path).unwrap().write_all(b"key").unwrap();
+    }
+
+    /// A local Depot with the fixture archive and some keys in nested directories.
+    fn depot() -> (TempDir, LocalDepot) {
+        let dir = TempDir::new("local-depot").unwrap();
+        fs::create_dir_all(dir.path().join("pkgs/happyhumans/possums")).unwrap();
+        fs::copy(fixture(),
+                 dir.path().join("pkgs/happyhumans/possums").join(ARCHIVE))
+            .unwrap();
+        touch(&dir.path().join("keys/core-20160101000000.pub"));
+        touch(&dir.path().join("more/core-20160202000000.pub"));
+        touch(&dir.path().join("keys/coreutils-20160303000000.pub"));
+        touch(&dir.path().join("keys/core-20160101000000.sig.key"));
+        let depot = LocalDepot::new(dir.path()).unwrap();
+        (dir, depot)
+    }
+
+    #[test]
+    fn new_rejects_a_path_which_is_not_a_directory() {
+        let dir = TempDir::new("local-depot").unwrap();
+        touch(&dir.path().join("file"));
+        match LocalDepot::new(dir.path().join("file")) {
+            Err(Error::InvalidLocalDepot(_)) => (),
+            _ => panic!("Expected an invalid local Depot"),
+        }
+        assert!(LocalDepot::new(dir.path().join("missing")).is_err());
+    }
+
+    #[test]
+    fn fetch_package_copies_the_latest_satisfying_archive() {
+        let (_dir, depot) = depot();
+        let dst = TempDir::new("local-depot-dst").unwrap();
+        let mut archive = depot.fetch_package(&ident("happyhumans/possums"), dst.path(), None)
+            .unwrap();
+        assert_eq!(archive.path, dst.path().join(ARCHIVE));
+        assert_eq!(archive.ident().unwrap(),
+                   ident("happyhumans/possums/8.1.4/20160427165340"));
+        assert!(!dst.path().join(format!("{}.tmp", ARCHIVE)).exists());
+    }
+
+    #[test]
+    fn fetch_package_of_a_missing_package_is_not_found() {
+        let (_dir, depot) = depot();
+        let dst = TempDir::new("local-depot-dst").unwrap();
+        match depot.fetch_package(&ident("happyhumans/possums/9.0.0"), dst.path(), None) {
+            Err(Error::RemotePackageNotFound(_)) => (),
+            _ => panic!("Expected the package not to be found"),
+        }
+        match depot.fetch_package(&ident("happyhumans/possum"), dst.path(), None) {
+            Err(Error::RemotePackageNotFound(_)) => (),
+            _ => panic!("Expected the package not to be found"),
+        }
+    }
+
+    #[test]
+    fn list_packages_lists_an_origins_archives() {
+        let (_dir, depot) = depot();
+        let idents = depot.list_packages("happyhumans", None);
+        assert_eq!(idents.len(), 1);
+        assert_eq!(idents[0].get_name(), "possums");
+        assert_eq!(idents[0].get_release(), "20160427165340");
+        assert_eq!(depot.list_packages("happyhumans", Some("possums")).len(), 1);
+        assert!(depot.list_packages("happyhumans", Some("possum")).is_empty());
+        assert!(depot.list_packages("sadhumans", None).is_empty());
+    }
+
+    #[test]
+    fn show_origin_keys_lists_only_the_origins_public_keys_newest_first() {
+        let (_dir, depot) = depot();
+        let keys = depot.show_origin_keys("core").unwrap();
+        let revisions: Vec<&str> = keys.iter().map(|key| key.get_revision()).collect();
+        assert_eq!(revisions, vec!["20160202000000", "20160101000000"]);
+        match depot.show_origin_keys("nobody") {
+            Err(Error::RemoteOriginKeyNotFound(_)) => (),
+            _ => panic!("Expected no keys to be found"),
+        }
+    }
+
+    #[test]
+    fn fetch_origin_key_copies_the_key() {
+        let (_dir, depot) = depot();
+        let dst = TempDir::new("local-depot-dst").unwrap();
+        let path = depot.fetch_origin_key("core", "20160202000000", dst.path(), None).unwrap();
+        assert_eq!(path, dst.path().join("core-20160202000000.pub"));
+        assert!(depot.fetch_origin_key("core", "20160303000000", dst.path(), None).is_err());
+    }
+
+    #[test]
+    fn put_origin_key_adds_the_key_and_rejects_it_again() {
+        let (dir, depot) = depot();
+        let src = TempDir::new("local-depot-src").unwrap();
+        touch(&src.path().join("core-20160404000000.pub"));
+        let key = src.path().join("core-20160404000000.pub");
+
+        depot.put_origin_key("core", "20160404000000", &key, None).unwrap();
+
+        assert!(dir.path().join("keys/core-20160404000000.pub").is_file());
+        assert_eq!(depot.show_origin_keys("core").unwrap()[0].get_revision(),
+                   "20160404000000");
+        match depot.put_origin_key("core", "20160404000000", &key, None) {
+            Err(Error::HTTP(StatusCode::Conflict)) => (),
+            _ => panic!("Expected a conflict"),
+        }
+    }
+
+    #[test]
+    fn put_package_adds_the_archive_and_rejects_it_again() {
+        let dir = TempDir::new("local-depot").unwrap();
+        let depot = LocalDepot::new(dir.path()).unwrap();
+        let mut archive = PackageArchive::new(fixture());
+
+        depot.put_package(&mut archive, None).unwrap();
+
+        assert!(dir.path().join("pkgs").join(ARCHIVE).is_file());
+        assert_eq!(depot.list_packages("happyhumans", None).len(), 1);
+        match depot.put_package(&mut archive, None) {
+            Err(Error::HTTP(StatusCode::Conflict)) => (),
+            _ => panic!("Expected a conflict"),
+        }
+    }
+}
@@ -0,0 +1,299 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Append-only log of authenticated, mutating actions taken against the depot.
+//!
+//! Each entry is written as a single line of JSON to `<path>/audit/audit.log`. Once the active
+//! log grows past the configured size it is rotated to `audit.log.1`, shifting older logs up one
+//! and discarding any past the configured number of logs to keep.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use rustc_serialize::json;
+use time;
+
+use error::Result;
+
+const LOG_NAME: &'static str = "audit.log";
+
+/// A single recorded action.
+#[derive(Clone, Debug, RustcEncodable, RustcDecodable)]
+pub struct Entry {
+    /// Seconds since the Unix epoch at which the action completed
+    pub timestamp: i64,
+    /// Name of the action, for example `package.upload`
+    pub action: String,
+    /// Name of the authenticated account, if there was one
+    pub actor: Option<String>,
+    /// Identifier of the authenticated account, if there was one
+    pub actor_id: Option<u64>,
+    pub origin: Option<String>,
+    pub ident: Option<String>,
+    pub source_ip: String,
+    /// HTTP status code returned to the client
+    pub status: u16,
+    /// Either `success` or `failure`
+    pub outcome: String,
+    /// View a package was promoted into, for promotions
+    pub view: Option<String>,
+    /// Job whose completion set off the action, for actions the depot took by itself
+    pub job_id: Option<u64>,
+}
+
+/// Criteria for selecting entries from the audit log.
+#[derive(Debug, Default)]
+pub struct Query {
+    pub action: Option<String>,
+    pub actor: Option<String>,
+    pub origin: Option<String>,
+    pub limit: usize,
+}
+
+impl Query {
+    fn matches(&self, entry: &Entry) -> bool {
+        if let Some(ref action) = self.action {
+            if *action != entry.action {
+                return false;
+            }
+        }
+        if let Some(ref actor) = self.actor {
+            if entry.actor.as_ref() != Some(actor) {
+                return false;
+            }
+        }
+        if let Some(ref origin) = self.origin {
+            if entry.origin.as_ref() != Some(origin) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+pub struct AuditLog {
+    dir: PathBuf,
+    max_bytes: usize,
+    keep: usize,
+    lock: Mutex<()>,
+}
+
+impl AuditLog {
+    /// Open the audit log stored in the given directory, creating the directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// * The directory could not be created
+    pub fn open<P: AsRef<Path>>(dir: P, max_bytes: usize, keep: usize) -> Result<Self> {
+        try!(fs::create_dir_all(dir.as_ref()));
+        Ok(AuditLog {
+            dir: dir.as_ref().to_path_buf(),
+            max_bytes: max_bytes,
+            keep: keep,
+            lock: Mutex::new(()),
+        })
+    }
+
+    /// Append an entry to the active log, rotating it first if it has grown too large.
+    ///
+    /// # Errors
+    ///
+    /// * The log could not be rotated, opened, or written to
+    pub fn record(&self, mut entry: Entry) -> Result<()> {
+        entry.timestamp = time::get_time().sec;
+        let line = json::encode(&entry).unwrap();
+        let _guard = self.lock.lock().unwrap();
+        try!(self.rotate_if_needed());
+        let mut file = try!(OpenOptions::new().create(true).append(true).open(self.log_path(0)));
+        try!(writeln!(file, "{}", line));
+        Ok(())
+    }
+
+    /// Return the entries matching the given query, newest first.
+    ///
+    /// # Errors
+    ///
+    /// * One of the logs could not be read
+    pub fn query(&self, query: &Query) -> Result<Vec<Entry>> {
+        let _guard = self.lock.lock().unwrap();
+        let mut found = vec![];
+        for n in 0..(self.keep + 1) {
+            let path = self.log_path(n);
+            if !path.is_file() {
+                continue;
+            }
+            let file = try!(File::open(&path));
+            let mut entries = vec![];
+            for line in BufReader::new(file).lines() {
+                let line = try!(line);
+                match json::decode::<Entry>(&line) {
+                    Ok(entry) => {
+                        if query.matches(&entry) {
+                            entries.push(entry);
+                        }
+                    }
+                    Err(e) => warn!("skipping unreadable audit entry in {:?}, err={}", path, e),
+                }
+            }
+            for entry in entries.into_iter().rev() {
+                found.push(entry);
+                if found.len() >= query.limit {
+                    return Ok(found);
+                }
+            }
+        }
+        Ok(found)
+    }
+
+    fn log_path(&self, n: usize) -> PathBuf {
+        if n == 0 {
+            self.dir.join(LOG_NAME)
+        } else {
+            self.dir.join(format!("{}.{}", LOG_NAME, n))
+        }
+    }
+
+    fn rotate_if_needed(&self) -> Result<()> {
+        let size = match fs::metadata(self.log_path(0)) {
+            Ok(meta) => meta.len() as usize,
+            Err(_) => return Ok(()),
+        };
+        if size < self.max_bytes {
+            return Ok(());
+        }
+        if self.keep == 0 {
+            try!(fs::remove_file(self.log_path(0)));
+            return Ok(());
+        }
+        let oldest = self.log_path(self.keep);
+        if oldest.is_file() {
+            try!(fs::remove_file(&oldest));
+        }
+        for n in (0..self.keep).rev() {
+            let path = self.log_path(n);
+            if path.is_file() {
+                try!(fs::rename(&path, self.log_path(n + 1)));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempdir::TempDir;
+
+    use super::*;
+
+    fn entry(action: &str, actor: &str, origin: &str) -> Entry {
+        Entry {
+            timestamp: 0,
+            action: action.to_string(),
+            actor: Some(actor.to_string()),
+            actor_id: None,
+            origin: Some(origin.to_string()),
+            ident: None,
+            source_ip: "127.0.0.1".to_string(),
+            status: 201,
+            outcome: "success".to_string(),
+            view: None,
+            job_id: None,
+        }
+    }
+
+    fn all() -> Query {
+        Query { limit: 100, ..Query::default() }
+    }
+
+    #[test]
+    fn query_returns_newest_first() {
+        let dir = TempDir::new("audit").unwrap();
+        let log = AuditLog::open(dir.path(), 1024 * 1024, 2).unwrap();
+        log.record(entry("package.upload", "bobo", "core")).unwrap();
+        log.record(entry("origin.create", "bobo", "core")).unwrap();
+        let found = log.query(&all()).unwrap();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].action, "origin.create");
+        assert_eq!(found[1].action, "package.upload");
+    }
+
+    #[test]
+    fn query_filters_and_limits() {
+        let dir = TempDir::new("audit").unwrap();
+        let log = AuditLog::open(dir.path(), 1024 * 1024, 2).unwrap();
+        log.record(entry("package.upload", "bobo", "core")).unwrap();
+        log.record(entry("package.upload", "wesker", "core")).unwrap();
+        log.record(entry("package.upload", "bobo", "other")).unwrap();
+        log.record(entry("origin.create", "bobo", "core")).unwrap();
+        let query = Query {
+            action: Some("package.upload".to_string()),
+            actor: Some("bobo".to_string()),
+            ..all()
+        };
+        let found = log.query(&query).unwrap();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].origin, Some("other".to_string()));
+        let query = Query {
+            origin: Some("core".to_string()),
+            limit: 1,
+            ..Query::default()
+        };
+        let found = log.query(&query).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].action, "origin.create");
+    }
+
+    #[test]
+    fn rotates_and_discards_old_logs() {
+        let dir = TempDir::new("audit").unwrap();
+        // Every entry is larger than the size limit, so each record rotates the previous one.
+        let log = AuditLog::open(dir.path(), 1, 2).unwrap();
+        for action in &["first", "second", "third", "fourth"] {
+            log.record(entry(action, "bobo", "core")).unwrap();
+        }
+        assert!(dir.path().join("audit.log").is_file());
+        assert!(dir.path().join("audit.log.1").is_file());
+        assert!(dir.path().join("audit.log.2").is_file());
+        assert!(!dir.path().join("audit.log.3").exists());
+        let actions: Vec<String> =
+            log.query(&all()).unwrap().into_iter().map(|e| e.action).collect();
+        assert_eq!(actions, vec!["fourth", "third", "second"]);
+    }
+
+    #[test]
+    fn rotation_without_keep_truncates() {
+        let dir = TempDir::new("audit").unwrap();
+        let log = AuditLog::open(dir.path(), 1, 0).unwrap();
+        log.record(entry("first", "bobo", "core")).unwrap();
+        log.record(entry("second", "bobo", "core")).unwrap();
+        let found = log.query(&all()).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].action, "second");
+    }
+
+    #[test]
+    fn query_skips_unreadable_entries() {
+        let dir = TempDir::new("audit").unwrap();
+        let log = AuditLog::open(dir.path(), 1024 * 1024, 2).unwrap();
+        log.record(entry("first", "bobo", "core")).unwrap();
+        {
+            let mut file = OpenOptions::new().append(true).open(dir.path().join(LOG_NAME)).unwrap();
+            writeln!(file, "not json").unwrap();
+        }
+        log.record(entry("second", "bobo", "core")).unwrap();
+        assert_eq!(log.query(&all()).unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,735 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Portable bundles of an origin, for moving it into a depot which can't reach the one it lives
+//! in, such as an air-gapped depot.
+//!
+//! A bundle is a tar archive. Its first entry, `MANIFEST.json`, names the origin. It lists every
+//! release of the origin's packages with the checksum of its archive, its build provenance and
+//! the views it is a member of. It also lists every revision of the origin's public key with its
+//! checksum. The archives follow under `pkgs/` and the keys under `keys/`. Secret keys are never
+//! bundled.
+//!
+//! On import every file is staged and checked against the checksum in the manifest before it is
+//! moved into the depot and indexed. Packages and keys the depot already has are skipped, so a
+//! bundle may be imported again after an interrupted import. Packages join the views they were
+//! members of like any other promotion, so joining a protected view waits for approval.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+use dbcache::{self, BasicSet};
+use hab_core::crypto::keys::PairType;
+use hab_core::crypto::{hash, SigKeyPair};
+use hab_core::package::{FromArchive, PackageArchive, PackageIdent};
+use protocol::depotsrv;
+use protocol::sessionsrv::Session;
+use rustc_serialize::json;
+use time;
+
+use super::{Depot, Promotion};
+use error::{Error, Result};
+
+/// Name of the entry holding a bundle's manifest.
+pub const MANIFEST: &'static str = "MANIFEST.json";
+const BLOCK: usize = 512;
+// Bytes copied between a bundle and the files it holds at a time.
+const COPY_BUF_SIZE: usize = 64 * 1024;
+
+/// Contents of a bundle.
+#[derive(Debug, RustcEncodable, RustcDecodable)]
+pub struct Manifest {
+    pub origin: String,
+    pub packages: Vec<PackageEntry>,
+    pub keys: Vec<KeyEntry>,
+}
+
+/// A package release held by a bundle.
+#[derive(Debug, RustcEncodable, RustcDecodable)]
+pub struct PackageEntry {
+    /// Fully qualified identifier of the release
+    pub ident: String,
+    /// Name of the bundle entry holding the release's archive
+    pub file: String,
+    /// Checksum of the archive
+    pub checksum: String,
+    /// Views the release is a member of
+    pub views: Vec<String>,
+    pub job_id: Option<u64>,
+    pub source_revision: Option<String>,
+    pub builder_host: Option<String>,
+    pub log_url: Option<String>,
+}
+
+/// A revision of an origin's public key held by a bundle.
+#[derive(Debug, RustcEncodable, RustcDecodable)]
+pub struct KeyEntry {
+    pub revision: String,
+    /// Name of the bundle entry holding the key
+    pub file: String,
+    /// Checksum of the key file
+    pub checksum: String,
+}
+
+/// An origin's packages and keys gathered for export.
+pub struct Bundle {
+    pub manifest: Manifest,
+    // Name of each entry after the manifest and the file it is read from.
+    files: Vec<(String, PathBuf)>,
+}
+
+impl Bundle {
+    /// Gather every package release and public key of an origin.
+    ///
+    /// # Failures
+    ///
+    /// * The datastore cannot be read
+    /// * A package's key file cannot be read to compute its checksum
+    pub fn collect(depot: &Depot, origin: &str) -> Result<Self> {
+        let mut manifest = Manifest {
+            origin: origin.to_string(),
+            packages: vec![],
+            keys: vec![],
+        };
+        let mut files = vec![];
+        let idents = match depot.datastore.packages.index.list(origin, 0, -1) {
+            Ok(idents) => idents,
+            Err(Error::DataStore(dbcache::Error::EntityNotFound)) => vec![],
+            Err(e) => return Err(e),
+        };
+        for ident in idents {
+            let package = try!(depot.datastore.packages.find(&ident));
+            let path = depot.archive_path(&ident);
+            if fs::metadata(&path).is_err() {
+                warn!("not exporting {}, archive missing, run `hab-depot repair`", ident);
+                continue;
+            }
+            let file = format!("pkgs/{}", path.file_name().unwrap().to_string_lossy());
+            let provenance = package.get_provenance();
+            let optional = |value: &str| if value.is_empty() {
+                None
+            } else {
+                Some(value.to_string())
+            };
+            manifest.packages.push(PackageEntry {
+                ident: ident.to_string(),
+                file: file.clone(),
+                checksum: package.get_checksum().to_string(),
+                views: try!(depot.datastore.views.pkg_view_idx.all(&ident)),
+                job_id: if provenance.has_job_id() {
+                    Some(provenance.get_job_id())
+                } else {
+                    None
+                },
+                source_revision: optional(provenance.get_source_revision()),
+                builder_host: optional(provenance.get_builder_host()),
+                log_url: optional(provenance.get_log_url()),
+            });
+            files.push((file, path));
+        }
+        for key in try!(depot.datastore.origin_keys.all(origin)) {
+            let path = depot.key_path(origin, key.get_revision());
+            if fs::metadata(&path).is_err() {
+                warn!("not exporting {}-{}, key file missing", origin, key.get_revision());
+                continue;
+            }
+            let file = format!("keys/{}-{}.pub", origin, key.get_revision());
+            manifest.keys.push(KeyEntry {
+                revision: key.get_revision().to_string(),
+                file: file.clone(),
+                checksum: try!(hash::hash_file(&path)),
+            });
+            files.push((file, path));
+        }
+        Ok(Bundle {
+            manifest: manifest,
+            files: files,
+        })
+    }
+
+    /// Write the bundle as a tar archive.
+    ///
+    /// # Failures
+    ///
+    /// * A file of the bundle cannot be read
+    /// * The archive cannot be written
+    pub fn write<W: Write>(&self, out: &mut W) -> Result<()> {
+        let manifest = json::encode(&self.manifest).unwrap().into_bytes();
+        try!(write_entry(out, MANIFEST, manifest.len() as u64, &mut &manifest[..]));
+        for &(ref name, ref path) in self.files.iter() {
+            let mut file = try!(File::open(path));
+            let len = try!(file.metadata()).len();
+            try!(write_entry(out, name, len, &mut file));
+        }
+        try!(out.write_all(&[0; BLOCK * 2]));
+        try!(out.flush());
+        Ok(())
+    }
+}
+
+/// Outcome of importing a bundle.
+#[derive(Debug, Default, RustcEncodable)]
+pub struct ImportReport {
+    pub origin: String,
+    /// Package releases and key revisions added to the depot
+    pub imported: Vec<String>,
+    /// Package releases and key revisions the depot already had
+    pub skipped: Vec<String>,
+    /// Package releases and key revisions which could not be imported and why
+    pub failed: Vec<(String, String)>,
+}
+
+impl ImportReport {
+    /// True if everything in the bundle is now in the depot.
+    pub fn success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Import a bundle read from `input` into the depot. If `origin` is given the bundle must be of
+/// that origin. Promotions into protected views are requested on behalf of `requester`, or of
+/// the depot's operator if the bundle is imported without a session.
+///
+/// # Failures
+///
+/// * The input is not a bundle, or is of another origin
+/// * The bundle cannot be read
+/// * The depot's staging directory cannot be created
+pub fn import<R: Read>(depot: &Depot,
+                       input: R,
+                       origin: Option<&str>,
+                       requester: Option<&Session>)
+                       -> Result<ImportReport> {
+    let mut reader = TarReader::new(input);
+    let manifest: Manifest = match try!(reader.next_entry()) {
+        Some((ref name, _)) if name == MANIFEST => {
+            let mut body = vec![];
+            try!(reader.copy_entry(&mut body));
+            let body = try!(String::from_utf8(body)
+                .map_err(|_| Error::BadBundle("manifest is not UTF-8".to_string())));
+            try!(json::decode(&body).map_err(|e| Error::BadBundle(format!("{}", e))))
+        }
+        _ => return Err(Error::BadBundle(format!("first entry is not {}", MANIFEST))),
+    };
+    if let Some(origin) = origin {
+        if manifest.origin != origin {
+            return Err(Error::BadBundle(format!("bundle is of origin {}", manifest.origin)));
+        }
+    }
+    let staging = Path::new(&depot.config.path)
+        .join("tmp")
+        .join(format!("import-{}", time::precise_time_ns()));
+    try!(fs::create_dir_all(&staging));
+    let result = Importer {
+            depot: depot,
+            manifest: &manifest,
+            staging: &staging,
+            requester: requester,
+        }
+        .run(&mut reader);
+    let _ = fs::remove_dir_all(&staging);
+    result
+}
+
+struct Importer<'a> {
+    depot: &'a Depot,
+    manifest: &'a Manifest,
+    staging: &'a Path,
+    requester: Option<&'a Session>,
+}
+
+impl<'a> Importer<'a> {
+    fn run<R: Read>(&self, reader: &mut TarReader<R>) -> Result<ImportReport> {
+        let mut report = ImportReport {
+            origin: self.manifest.origin.clone(),
+            ..ImportReport::default()
+        };
+        let mut packages: HashMap<&str, &PackageEntry> =
+            self.manifest.packages.iter().map(|p| (&p.file[..], p)).collect();
+        let mut keys: HashMap<&str, &KeyEntry> =
+            self.manifest.keys.iter().map(|k| (&k.file[..], k)).collect();
+        while let Some((name, _)) = try!(reader.next_entry()) {
+            if let Some(entry) = packages.remove(&name[..]) {
+                let result = self.stage(reader, &entry.checksum)
+                    .and_then(|staged| self.install_package(entry, &staged));
+                record(&mut report, entry.ident.clone(), result);
+            } else if let Some(entry) = keys.remove(&name[..]) {
+                let result = self.stage(reader, &entry.checksum)
+                    .and_then(|staged| self.install_key(entry, &staged));
+                let name = format!("{}-{}", self.manifest.origin, entry.revision);
+                record(&mut report, name, result);
+            } else {
+                warn!("ignoring bundle entry {}, not in the manifest", name);
+            }
+        }
+        for entry in packages.values() {
+            report.failed.push((entry.ident.clone(), "missing from bundle".to_string()));
+        }
+        for entry in keys.values() {
+            report.failed.push((format!("{}-{}", self.manifest.origin, entry.revision),
+                                "missing from bundle".to_string()));
+        }
+        Ok(report)
+    }
+
+    // Copy the current entry to the staging directory, returning its path if its checksum is
+    // the expected one.
+    fn stage<R: Read>(&self, reader: &mut TarReader<R>, expected: &str) -> Result<PathBuf> {
+        let path = self.staging.join(format!("entry-{}", time::precise_time_ns()));
+        let mut writer = HashingWriter {
+            file: try!(File::create(&path)),
+            hasher: hash::Hasher::new(),
+        };
+        try!(reader.copy_entry(&mut writer));
+        try!(writer.file.flush());
+        if writer.hasher.finish() != expected {
+            let _ = fs::remove_file(&path);
+            return Err(Error::BadBundle("checksum mismatch".to_string()));
+        }
+        Ok(path)
+    }
+
+    // Returns false if the depot already had the package.
+    fn install_package(&self, entry: &PackageEntry, staged: &Path) -> Result<bool> {
+        let ident: depotsrv::PackageIdent = match entry.ident.parse::<PackageIdent>() {
+            Ok(ident) if ident.fully_qualified() => ident.into(),
+            _ => return Err(Error::InvalidPackageIdent(entry.ident.clone())),
+        };
+        if ident.get_origin() != self.manifest.origin {
+            return Err(Error::BadBundle(format!("{} is not of origin {}",
+                                                entry.ident,
+                                                self.manifest.origin)));
+        }
+        let path = self.depot.archive_path(&ident);
+        let exists = match self.depot.datastore.packages.find(&ident) {
+            Ok(_) => fs::metadata(&path).is_ok(),
+            Err(dbcache::Error::EntityNotFound) => false,
+            Err(e) => return Err(Error::from(e)),
+        };
+        if exists {
+            return Ok(false);
+        }
+        let mut archive = PackageArchive::new(staged.to_path_buf());
+        let mut object = try!(depotsrv::Package::from_archive(&mut archive));
+        if object.get_ident().to_string() != entry.ident {
+            return Err(Error::InvalidPackageIdent(object.get_ident().to_string()));
+        }
+        try!(fs::create_dir_all(path.parent().unwrap()));
+        try!(fs::rename(staged, &path));
+        if entry.job_id.is_some() || entry.source_revision.is_some() ||
+           entry.builder_host.is_some() || entry.log_url.is_some() {
+            let mut provenance = depotsrv::PackageProvenance::new();
+            if let Some(job_id) = entry.job_id {
+                provenance.set_job_id(job_id);
+            }
+            if let Some(ref revision) = entry.source_revision {
+                provenance.set_source_revision(revision.clone());
+            }
+            if let Some(ref host) = entry.builder_host {
+                provenance.set_builder_host(host.clone());
+            }
+            if let Some(ref url) = entry.log_url {
+                provenance.set_log_url(url.clone());
+            }
+            object.set_provenance(provenance);
+        }
+        try!(self.depot.datastore.packages.write(&object));
+        let (requester_id, requester) = match self.requester {
+            Some(session) => (session.get_id(), session.get_name()),
+            None => (0, "import"),
+        };
+        for view in entry.views.iter() {
+            try!(self.depot.datastore.views.write(view));
+            let promotion = try!(self.depot.promote(view, &object, requester_id, requester));
+            if let Promotion::Requested(_) = promotion {
+                info!("promotion of imported package awaits approval, view={}, package={}",
+                      view,
+                      entry.ident);
+            }
+        }
+        self.depot.notifier.uploaded(object.get_ident());
+        Ok(true)
+    }
+
+    // Returns false if the depot already had the key.
+    fn install_key(&self, entry: &KeyEntry, staged: &Path) -> Result<bool> {
+        if !valid_revision(&entry.revision) {
+            return Err(Error::BadBundle(format!("invalid key revision {}", entry.revision)));
+        }
+        let path = self.depot.key_path(&self.manifest.origin, &entry.revision);
+        if fs::metadata(&path).is_ok() {
+            return Ok(false);
+        }
+        let mut content = String::new();
+        try!(File::open(staged).and_then(|mut file| file.read_to_string(&mut content)));
+        try!(verify_public_key(&self.manifest.origin, &entry.revision, &content));
+        try!(fs::create_dir_all(path.parent().unwrap()));
+        try!(fs::rename(staged, &path));
+        try!(self.depot.datastore.origin_keys.write(&self.manifest.origin, &entry.revision));
+        Ok(true)
+    }
+}
+
+// Key revisions are timestamps, `YYYYMMDDhhmmss`.
+fn valid_revision(revision: &str) -> bool {
+    revision.len() == 14 && revision.bytes().all(|b| b >= b'0' && b <= b'9')
+}
+
+// Check that a key file is the public key of the given origin and revision.
+fn verify_public_key(origin: &str, revision: &str, content: &str) -> Result<()> {
+    match SigKeyPair::parse_key_str(content) {
+        Ok((PairType::Public, ref name, _)) if *name == format!("{}-{}", origin, revision) => {
+            Ok(())
+        }
+        Ok((PairType::Public, name, _)) => {
+            Err(Error::BadBundle(format!("key is {}, not {}-{}", name, origin, revision)))
+        }
+        Ok(_) => Err(Error::BadBundle("key is not a public key".to_string())),
+        Err(e) => Err(Error::BadBundle(format!("invalid public key, {}", e))),
+    }
+}
+
+fn record(report: &mut ImportReport, name: String, result: Result<bool>) {
+    match result {
+        Ok(true) => report.imported.push(name),
+        Ok(false) => report.skipped.push(name),
+        Err(e) => {
+            warn!("unable to import {}, err={}", name, e);
+            report.failed.push((name, e.to_string()));
+        }
+    }
+}
+
+// Writes to a file while hashing what is written.
+struct HashingWriter {
+    file: File,
+    hasher: hash::Hasher,
+}
+
+impl Write for HashingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = try!(self.file.write(buf));
+        self.hasher.update(&buf[..len]);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+// Write a regular file entry in the ustar format, reading its contents from `content`.
+fn write_entry<W: Write, R: Read>(out: &mut W,
+                                  name: &str,
+                                  len: u64,
+                                  content: &mut R)
+                                  -> Result<()> {
+    try!(out.write_all(&try!(header(name, len))));
+    let copied = try!(io::copy(&mut content.take(len), out));
+    if copied != len {
+        return Err(Error::BadBundle(format!("{} changed while it was bundled", name)));
+    }
+    let pad = padding(len);
+    try!(out.write_all(&[0; BLOCK][..pad]));
+    Ok(())
+}
+
+fn header(name: &str, len: u64) -> Result<[u8; BLOCK]> {
+    // Names longer than the name field are split at a `/` into the prefix field.
+    let (prefix, base) = if name.len() <= 100 {
+        ("", name)
+    } else {
+        match name.rfind('/') {
+            Some(i) if i <= 155 && name.len() - i - 1 <= 100 => (&name[..i], &name[i + 1..]),
+            _ => return Err(Error::BadBundle(format!("entry name too long, {}", name))),
+        }
+    };
+    let mut block = [0; BLOCK];
+    block[..base.len()].copy_from_slice(base.as_bytes());
+    try!(octal(&mut block[100..108], 0o644));
+    try!(octal(&mut block[108..116], 0));
+    try!(octal(&mut block[116..124], 0));
+    try!(octal(&mut block[124..136], len));
+    try!(octal(&mut block[136..148], time::get_time().sec as u64));
+    block[156] = b'0';
+    block[257..263].copy_from_slice(b"ustar\0");
+    block[263..265].copy_from_slice(b"00");
+    block[345..345 + prefix.len()].copy_from_slice(prefix.as_bytes());
+    let sum = checksum(&block);
+    block[148..156].copy_from_slice(format!("{:06o}\0 ", sum).as_bytes());
+    Ok(block)
+}
+
+// Sum of a header's bytes, with its checksum field counted as spaces.
+fn checksum(block: &[u8; BLOCK]) -> u64 {
+    let mut sum = 0;
+    for (i, &b) in block.iter().enumerate() {
+        sum += if i >= 148 && i < 156 { b' ' as u64 } else { b as u64 };
+    }
+    sum
+}
+
+fn octal(field: &mut [u8], value: u64) -> Result<()> {
+    let digits = format!("{:01$o}", value, field.len() - 1);
+    if digits.len() >= field.len() {
+        return Err(Error::BadBundle(format!("{} too large for a tar header", value)));
+    }
+    field[..digits.len()].copy_from_slice(digits.as_bytes());
+    Ok(())
+}
+
+fn padding(len: u64) -> usize {
+    (BLOCK - (len % BLOCK as u64) as usize) % BLOCK
+}
+
+// Reads the regular file entries of a ustar archive in order.
+struct TarReader<R: Read> {
+    input: R,
+    // Bytes of the current entry's contents and padding not yet read.
+    remaining: u64,
+    pad: usize,
+}
+
+impl<R: Read> TarReader<R> {
+    fn new(input: R) -> Self {
+        TarReader {
+            input: input,
+            remaining: 0,
+            pad: 0,
+        }
+    }
+
+    // Move to the next regular file entry, skipping whatever is left of the current one, and
+    // return its name and size. Returns `None` at the end of the archive.
+    fn next_entry(&mut self) -> Result<Option<(String, u64)>> {
+        loop {
+            try!(self.skip_entry());
+            let mut block = [0; BLOCK];
+            try!(self.input.read_exact(&mut block));
+            if block.iter().all(|&b| b == 0) {
+                return Ok(None);
+            }
+            if parse_octal(&block[148..156]) != Some(checksum(&block)) {
+                return Err(Error::BadBundle("corrupt tar header".to_string()));
+            }
+            let len = match parse_octal(&block[124..136]) {
+                Some(len) => len,
+                None => return Err(Error::BadBundle("corrupt tar header".to_string())),
+            };
+            self.remaining = len;
+            self.pad = padding(len);
+            if block[156] != b'0' && block[156] != 0 {
+                continue;
+            }
+            let base = field_str(&block[..100]);
+            let prefix = field_str(&block[345..500]);
+            let name = if prefix.is_empty() {
+                base
+            } else {
+                format!("{}/{}", prefix, base)
+            };
+            return Ok(Some((name, len)));
+        }
+    }
+
+    // Copy what is left of the current entry's contents to `out`.
+    fn copy_entry<W: Write>(&mut self, out: &mut W) -> Result<()> {
+        let mut buf = vec![0; COPY_BUF_SIZE];
+        while self.remaining > 0 {
+            let want = if self.remaining < buf.len() as u64 {
+                self.remaining as usize
+            } else {
+                buf.len()
+            };
+            let len = try!(self.input.read(&mut buf[..want]));
+            if len == 0 {
+                return Err(Error::IO(io::Error::new(io::ErrorKind::UnexpectedEof,
+                                                    "bundle ended within an entry")));
+            }
+            try!(out.write_all(&buf[..len]));
+            self.remaining -= len as u64;
+        }
+        Ok(())
+    }
+
+    fn skip_entry(&mut self) -> Result<()> {
+        try!(self.copy_entry(&mut io::sink()));
+        let mut pad = [0; BLOCK];
+        try!(self.input.read_exact(&mut pad[..self.pad]));
+        self.pad = 0;
+        Ok(())
+    }
+}
+
+fn field_str(field: &[u8]) -> String {
+    let end = field.iter().position(|&b| b == 0).unwrap_or(field.len());
+    String::from_utf8_lossy(&field[..end]).into_owned()
+}
+
+fn parse_octal(field: &[u8]) -> Option<u64> {
+    let digits = field_str(field);
+    u64::from_str_radix(digits.trim(), 8).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use super::{header, verify_public_key, valid_revision, write_entry, TarReader, BLOCK};
+    use error::Error;
+
+    fn archive(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut out = vec![];
+        for &(name, content) in entries {
+            write_entry(&mut out, name, content.len() as u64, &mut &content[..]).unwrap();
+        }
+        out.extend_from_slice(&[0; BLOCK * 2]);
+        out
+    }
+
+    fn read_all(bytes: Vec<u8>) -> Result<Vec<(String, Vec<u8>)>> {
+        let mut reader = TarReader::new(Cursor::new(bytes));
+        let mut entries = vec![];
+        while let Some((name, len)) = try!(reader.next_entry()) {
+            let mut content = vec![];
+            try!(reader.copy_entry(&mut content));
+            assert_eq!(content.len() as u64, len);
+            entries.push((name, content));
+        }
+        Ok(entries)
+    }
+
+    fn name(len: usize) -> String {
+        ::std::iter::repeat("a").take(len).collect()
+    }
+
+    fn assert_bad_bundle<T: ::std::fmt::Debug>(result: Result<T>) {
+        match result {
+            Err(Error::BadBundle(_)) => (),
+            other => panic!("expected a bad bundle error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn entries_round_trip() {
+        let long = format!("pkgs/{}/core-redis-3.0.1-20160101000000-x86_64-linux.hart",
+                           name(80));
+        let big = vec![7; BLOCK * 3 + 1];
+        let exact = [1; BLOCK];
+        let entries = read_all(archive(&[(MANIFEST, &b"{}"[..]),
+                                         ("keys/empty", &b""[..]),
+                                         (&long[..], &big[..]),
+                                         ("keys/exact", &exact[..])]))
+            .unwrap();
+        assert_eq!(entries,
+                   vec![(MANIFEST.to_string(), b"{}".to_vec()),
+                        ("keys/empty".to_string(), vec![]),
+                        (long, big),
+                        ("keys/exact".to_string(), vec![1; BLOCK])]);
+    }
+
+    #[test]
+    fn unread_entries_are_skipped() {
+        let bytes = archive(&[("a", &b"first"[..]), ("b", &b"second"[..])]);
+        let mut reader = TarReader::new(Cursor::new(bytes));
+        assert_eq!(reader.next_entry().unwrap(), Some(("a".to_string(), 5)));
+        assert_eq!(reader.next_entry().unwrap(), Some(("b".to_string(), 6)));
+        assert_eq!(reader.next_entry().unwrap(), None);
+    }
+
+    #[test]
+    fn entries_other_than_regular_files_are_skipped() {
+        let mut bytes = archive(&[("dir", &b""[..]), ("file", &b"content"[..])]);
+        bytes[156] = b'5';
+        let sum = super::checksum(&{
+            let mut block = [0; BLOCK];
+            block.copy_from_slice(&bytes[..BLOCK]);
+            block
+        });
+        bytes[148..156].copy_from_slice(format!("{:06o}\0 ", sum).as_bytes());
+        assert_eq!(read_all(bytes).unwrap(),
+                   vec![("file".to_string(), b"content".to_vec())]);
+    }
+
+    #[test]
+    fn names_which_cannot_be_split_are_rejected() {
+        assert_bad_bundle(header(&name(101), 0));
+        assert_bad_bundle(header(&format!("pkgs/{}", name(101)), 0));
+        assert_bad_bundle(header(&format!("{}/a", name(156)), 0));
+    }
+
+    #[test]
+    fn corrupt_headers_are_rejected() {
+        let mut bytes = archive(&[("file", &b"content"[..])]);
+        bytes[0] = b'g';
+        assert_bad_bundle(read_all(bytes));
+    }
+
+    #[test]
+    fn unparsable_sizes_are_rejected() {
+        let mut bytes = archive(&[("file", &b"content"[..])]);
+        bytes[124..136].copy_from_slice(b"zzzzzzzzzzz\0");
+        let mut block = [0; BLOCK];
+        block.copy_from_slice(&bytes[..BLOCK]);
+        let sum = super::checksum(&block);
+        bytes[148..156].copy_from_slice(format!("{:06o}\0 ", sum).as_bytes());
+        assert_bad_bundle(read_all(bytes));
+    }
+
+    #[test]
+    fn truncated_entries_are_an_error() {
+        let mut bytes = archive(&[("file", &[1; 2048][..])]);
+        bytes.truncate(BLOCK + 1000);
+        match read_all(bytes) {
+            Err(Error::IO(_)) => (),
+            other => panic!("expected an IO error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn truncated_headers_are_an_error() {
+        let mut bytes = archive(&[("file", &b"content"[..])]);
+        bytes.truncate(BLOCK / 2);
+        assert!(read_all(bytes).is_err());
+    }
+
+    #[test]
+    fn revisions_are_timestamps() {
+        assert!(valid_revision("20160101000000"));
+        assert!(!valid_revision("2016010100000"));
+        assert!(!valid_revision("201601010000000"));
+        assert!(!valid_revision("../../../etc/x"));
+        assert!(!valid_revision("2016010100000a"));
+    }
+
+    #[test]
+    fn keys_must_be_public_keys_of_the_origin() {
+        let key = |version: &str, name: &str| format!("{}\n{}\n\nc2VjcmV0\n", version, name);
+        verify_public_key("core", "20160101000000", &key("SIG-PUB-1", "core-20160101000000"))
+            .unwrap();
+        assert_bad_bundle(verify_public_key("core",
+                                            "20160101000000",
+                                            &key("SIG-PUB-1", "acme-20160101000000")));
+        assert_bad_bundle(verify_public_key("core",
+                                            "20160101000000",
+                                            &key("SIG-PUB-1", "core-20170101000000")));
+        assert_bad_bundle(verify_public_key("core",
+                                            "20160101000000",
+                                            &key("SIG-SEC-1", "core-20160101000000")));
+        assert_bad_bundle(verify_public_key("core", "20160101000000", "not a key"));
+    }
+}
@@ -13,13 +13,17 @@
 // limitations under the License.
 
 use std::net;
+use std::time::Duration;
 
-use hab_core::config::{ConfigFile, ParseInto};
-use hab_net::config::{GitHubOAuth, RouteAddrs};
+use hab_core::config::{parse_duration_or_legacy, ByteSize, ConfigFile, ParseInto};
+use hab_net::config::{GitHubOAuth, MessageAuth, RouteAddrs, RouterAuth};
+use hab_net::zap::ZapCredentials;
+use hab_core;
 use redis;
 use toml;
 
 use error::{Error, Result};
+use promote::Rule;
 
 /// URL to GitHub API endpoint
 const GITHUB_URL: &'static str = "https://api.github.com";
@@ -46,6 +50,42 @@ pub struct Config {
     pub github_client_secret: String,
     /// allows you to upload packages and public keys without auth
     pub insecure: bool,
+    /// Shared secrets for authenticating routed messages, newest first.
+    pub hmac_keys: Vec<String>,
+    /// Credentials presented to routers which authenticate their clients.
+    pub router_auth: ZapCredentials,
+    /// Size at which the audit log is rotated
+    pub audit_max_size: ByteSize,
+    /// Number of rotated audit logs to keep
+    pub audit_keep: usize,
+    /// GitHub logins of accounts allowed to use administrative endpoints
+    pub admins: Vec<String>,
+    /// Time replies to origin lookups are cached by the depot's broker, or zero to disable.
+    /// Writes made through other services are only seen once a cached reply expires.
+    pub reply_cache_ttl: Duration,
+    /// Longest a client may wait on the package events endpoint
+    pub watch_max_wait: Duration,
+    /// Most clients which may wait on the package events endpoint at once. Each holds one of the
+    /// HTTP server's threads while it waits, so at most `MAX_WATCHERS` are allowed.
+    pub watch_max_watchers: usize,
+    /// Origins browser based clients may call the HTTP API from, or `*` for any, the default.
+    /// Cross-origin requests are not handled if empty.
+    pub cors_origins: Vec<String>,
+    /// Methods cross-origin requests may use
+    pub cors_methods: Vec<String>,
+    /// Request headers cross-origin requests may set
+    pub cors_headers: Vec<String>,
+    /// Time a browser may cache the result of a preflight request
+    pub cors_max_age: Duration,
+    /// Views a package may only be promoted into once a second member of its origin approves
+    pub protected_views: Vec<String>,
+    /// Time a request to promote into a protected view waits for approval before it expires
+    pub promotion_ttl: Duration,
+    /// Views the packages built by successful jobs are promoted into, by origin
+    pub promotion_rules: Vec<Rule>,
+    /// List of net addresses of job servers publishing the job status events promotion rules
+    /// are applied to
+    pub job_events_addrs: Vec<net::SocketAddrV4>,
 }
 
 impl ConfigFile for Config {
@@ -57,6 +97,38 @@ impl ConfigFile for Config {
         try!(toml.parse_into("cfg.bind_addr", &mut cfg.listen_addr));
         try!(toml.parse_into("cfg.datastore_addr", &mut cfg.datastore_addr));
         try!(toml.parse_into("cfg.router_addrs", &mut cfg.routers));
+        try!(toml.parse_into("cfg.hmac_keys", &mut cfg.hmac_keys));
+        try!(toml.parse_into("cfg.router_auth", &mut cfg.router_auth));
+        if !try!(toml.parse_into("cfg.audit_max_size", &mut cfg.audit_max_size)) &&
+           try!(toml.parse_into("cfg.audit_max_bytes", &mut cfg.audit_max_size)) {
+            warn!("config field cfg.audit_max_bytes is deprecated, set cfg.audit_max_size to \
+                   \"{}\" instead",
+                  cfg.audit_max_size);
+        }
+        try!(toml.parse_into("cfg.audit_keep", &mut cfg.audit_keep));
+        try!(toml.parse_into("cfg.admins", &mut cfg.admins));
+        try!(parse_duration_or_legacy(&toml,
+                                      "cfg.reply_cache_ttl",
+                                      "cfg.reply_cache_ttl_ms",
+                                      &mut cfg.reply_cache_ttl));
+        try!(parse_duration_or_legacy(&toml,
+                                      "cfg.watch_max_wait",
+                                      "cfg.watch_max_wait_ms",
+                                      &mut cfg.watch_max_wait));
+        try!(toml.parse_into("cfg.watch_max_watchers", &mut cfg.watch_max_watchers));
+        try!(toml.parse_into("cfg.cors_origins", &mut cfg.cors_origins));
+        try!(toml.parse_into("cfg.cors_methods", &mut cfg.cors_methods));
+        try!(toml.parse_into("cfg.cors_headers", &mut cfg.cors_headers));
+        try!(parse_duration_or_legacy(&toml,
+                                      "cfg.cors_max_age",
+                                      "cfg.cors_max_age_secs",
+                                      &mut cfg.cors_max_age));
+        try!(toml.parse_into("cfg.protected_views", &mut cfg.protected_views));
+        try!(toml.parse_into("cfg.promotion_ttl", &mut cfg.promotion_ttl));
+        let mut rules: Vec<String> = vec![];
+        try!(toml.parse_into("cfg.promotion_rules", &mut rules));
+        cfg.promotion_rules = try!(parse_rules(&rules, "cfg.promotion_rules"));
+        try!(toml.parse_into("cfg.job_events_addrs", &mut cfg.job_events_addrs));
         Ok(cfg)
     }
 }
@@ -72,8 +144,39 @@ impl Default for Config {
             github_client_id: DEV_GITHUB_CLIENT_ID.to_string(),
             github_client_secret: DEV_GITHUB_CLIENT_SECRET.to_string(),
             insecure: false,
+            hmac_keys: vec![],
+            router_auth: ZapCredentials::default(),
+            audit_max_size: ByteSize(10 * 1024 * 1024),
+            audit_keep: 5,
+            admins: vec![],
+            reply_cache_ttl: Duration::from_millis(0),
+            watch_max_wait: Duration::from_secs(30),
+            watch_max_watchers: 32,
+            cors_origins: vec!["*".to_string()],
+            cors_methods: vec!["GET".to_string(),
+                               "POST".to_string(),
+                               "PUT".to_string(),
+                               "DELETE".to_string()],
+            cors_headers: vec!["authorization".to_string(), "range".to_string()],
+            cors_max_age: Duration::from_secs(3600),
+            protected_views: vec![],
+            promotion_ttl: Duration::from_secs(7 * 24 * 60 * 60),
+            promotion_rules: vec![],
+            job_events_addrs: vec![],
+        }
+    }
+}
+
+/// Parse the `<origin>:<view>` entries of a list of promotion rules read from the given field.
+pub fn parse_rules(entries: &[String], field: &'static str) -> Result<Vec<Rule>> {
+    let mut rules = Vec::with_capacity(entries.len());
+    for entry in entries.iter() {
+        match entry.parse() {
+            Ok(rule) => rules.push(rule),
+            Err(()) => return Err(Error::from(hab_core::Error::ConfigInvalidString(field))),
         }
     }
+    Ok(rules)
 }
 
 impl<'a> redis::IntoConnectionInfo for &'a Config {
@@ -85,6 +188,18 @@ impl<'a> redis::IntoConnectionInfo for &'a Config {
     }
 }
 
+impl MessageAuth for Config {
+    fn hmac_keys(&self) -> &[String] {
+        &self.hmac_keys
+    }
+}
+
+impl RouterAuth for Config {
+    fn router_auth(&self) -> ZapCredentials {
+        self.router_auth.clone()
+    }
+}
+
 impl RouteAddrs for Config {
     fn route_addrs(&self) -> &Vec<net::SocketAddrV4> {
         &self.routers
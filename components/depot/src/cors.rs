@@ -0,0 +1,273 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cross-origin resource sharing for the Depot's HTTP API, so that browser based clients served
+//! from another origin may call it.
+//!
+//! Any origin is allowed by default, as the Depot always has. Handling is disabled if no allowed
+//! origins are configured. Requests from origins which aren't allowed are served as usual, but
+//! without the headers a browser needs to let the calling page read the response.
+
+use iron::headers;
+use iron::method::Method;
+use iron::prelude::*;
+use iron::status;
+use iron::{AroundMiddleware, Handler};
+use unicase::UniCase;
+
+use config::Config;
+
+/// Headers a browser may read from responses, in addition to the simple response headers.
+const EXPOSE_HEADERS: &'static [&'static str] = &["content-range", "next-range"];
+
+#[derive(Clone, Debug)]
+pub struct Cors {
+    origins: Vec<String>,
+    methods: Vec<Method>,
+    headers: Vec<String>,
+    max_age_secs: u32,
+}
+
+impl Cors {
+    pub fn new(config: &Config) -> Self {
+        Cors {
+            origins: config.cors_origins.clone(),
+            methods: config.cors_methods
+                .iter()
+                .filter_map(|m| match m.to_uppercase().parse() {
+                    Ok(method) => Some(method),
+                    Err(_) => {
+                        warn!("Ignoring unknown CORS method, {}", m);
+                        None
+                    }
+                })
+                .collect(),
+            headers: config.cors_headers.iter().map(|h| h.to_lowercase()).collect(),
+            max_age_secs: config.cors_max_age.as_secs() as u32,
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        !self.origins.is_empty()
+    }
+
+    /// The `Access-Control-Allow-Origin` value for a request with the given headers, if its
+    /// origin is allowed. Every request is allowed if any origin is, whether it names one or not.
+    fn allowed_origin(&self,
+                      headers: &headers::Headers)
+                      -> Option<headers::AccessControlAllowOrigin> {
+        if self.origins.iter().any(|o| o == "*") {
+            return Some(headers::AccessControlAllowOrigin::Any);
+        }
+        let origin = match headers.get_raw("Origin") {
+            Some(values) if values.len() == 1 => String::from_utf8_lossy(&values[0]).into_owned(),
+            _ => return None,
+        };
+        if self.origins.iter().any(|o| *o == origin) {
+            Some(headers::AccessControlAllowOrigin::Value(origin))
+        } else {
+            None
+        }
+    }
+
+    fn is_preflight(&self, method: &Method, headers: &headers::Headers) -> bool {
+        *method == Method::Options && headers.get_raw("Access-Control-Request-Method").is_some()
+    }
+
+    fn preflight(&self, origin: Option<headers::AccessControlAllowOrigin>) -> Response {
+        let mut res = Response::with(status::NoContent);
+        if let Some(origin) = origin {
+            self.allow(&mut res, origin);
+            res.headers.set(headers::AccessControlAllowMethods(self.methods.clone()));
+            res.headers.set(headers::AccessControlAllowHeaders(self.headers
+                .iter()
+                .map(|h| UniCase(h.clone()))
+                .collect()));
+            res.headers.set(headers::AccessControlMaxAge(self.max_age_secs));
+        }
+        res
+    }
+
+    fn allow(&self, res: &mut Response, origin: headers::AccessControlAllowOrigin) {
+        // responses naming the origin differ between origins, so shared caches must keep them
+        // apart
+        if let headers::AccessControlAllowOrigin::Value(_) = origin {
+            vary_on_origin(&mut res.headers);
+        }
+        res.headers.set(origin);
+        res.headers.set(headers::AccessControlExposeHeaders(EXPOSE_HEADERS.iter()
+            .map(|h| UniCase(h.to_string()))
+            .collect()));
+    }
+}
+
+// Add `Origin` to the headers a response varies by, keeping any the handler already named.
+fn vary_on_origin(headers: &mut headers::Headers) {
+    let mut items = match headers.get::<headers::Vary>() {
+        Some(&headers::Vary::Any) => return,
+        Some(&headers::Vary::Items(ref items)) => items.clone(),
+        None => vec![],
+    };
+    let origin = UniCase("origin".to_string());
+    if !items.contains(&origin) {
+        items.push(origin);
+    }
+    headers.set(headers::Vary::Items(items));
+}
+
+impl AroundMiddleware for Cors {
+    fn around(self, handler: Box<Handler>) -> Box<Handler> {
+        Box::new(CorsHandler {
+            cors: self,
+            handler: handler,
+        })
+    }
+}
+
+struct CorsHandler {
+    cors: Cors,
+    handler: Box<Handler>,
+}
+
+impl Handler for CorsHandler {
+    fn handle(&self, req: &mut Request) -> IronResult<Response> {
+        if !self.cors.enabled() {
+            return self.handler.handle(req);
+        }
+        let origin = self.cors.allowed_origin(&req.headers);
+        if self.cors.is_preflight(&req.method, &req.headers) {
+            return Ok(self.cors.preflight(origin));
+        }
+        match (self.handler.handle(req), origin) {
+            (Ok(mut res), Some(origin)) => {
+                self.cors.allow(&mut res, origin);
+                Ok(res)
+            }
+            (Err(mut err), Some(origin)) => {
+                self.cors.allow(&mut err.response, origin);
+                Err(err)
+            }
+            (result, None) => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use iron::headers::{self, AccessControlAllowOrigin, Headers};
+    use iron::method::Method;
+    use iron::prelude::*;
+    use iron::status;
+    use unicase::UniCase;
+
+    use config::Config;
+    use super::{vary_on_origin, Cors};
+
+    fn cors(origins: &[&str]) -> Cors {
+        let mut config = Config::default();
+        config.cors_origins = origins.iter().map(|o| o.to_string()).collect();
+        Cors::new(&config)
+    }
+
+    fn from(origin: &str) -> Headers {
+        let mut headers = Headers::new();
+        headers.set_raw("Origin", vec![origin.as_bytes().to_vec()]);
+        headers
+    }
+
+    fn vary(res: &Response) -> Option<headers::Vary> {
+        res.headers.get::<headers::Vary>().cloned()
+    }
+
+    #[test]
+    fn any_origin_is_allowed_by_default() {
+        let cors = Cors::new(&Config::default());
+        assert!(cors.enabled());
+        assert_eq!(cors.allowed_origin(&Headers::new()),
+                   Some(AccessControlAllowOrigin::Any));
+        assert_eq!(cors.allowed_origin(&from("https://app.example.com")),
+                   Some(AccessControlAllowOrigin::Any));
+
+        let mut res = Response::with(status::Ok);
+        cors.allow(&mut res, AccessControlAllowOrigin::Any);
+        assert_eq!(res.headers.get::<AccessControlAllowOrigin>(),
+                   Some(&AccessControlAllowOrigin::Any));
+        assert!(res.headers.get::<headers::AccessControlExposeHeaders>().is_some());
+        assert_eq!(vary(&res), None);
+    }
+
+    #[test]
+    fn only_listed_origins_are_allowed() {
+        let cors = cors(&["https://app.example.com"]);
+        assert_eq!(cors.allowed_origin(&from("https://app.example.com")),
+                   Some(AccessControlAllowOrigin::Value("https://app.example.com".to_string())));
+        assert_eq!(cors.allowed_origin(&from("https://evil.example.com")), None);
+        assert_eq!(cors.allowed_origin(&Headers::new()), None);
+    }
+
+    #[test]
+    fn no_origins_disables_handling() {
+        assert!(!cors(&[]).enabled());
+    }
+
+    #[test]
+    fn allowing_a_named_origin_varies_by_origin() {
+        let cors = cors(&["https://app.example.com"]);
+        let mut res = Response::with(status::Ok);
+        cors.allow(&mut res,
+                   AccessControlAllowOrigin::Value("https://app.example.com".to_string()));
+        assert_eq!(vary(&res),
+                   Some(headers::Vary::Items(vec![UniCase("origin".to_string())])));
+    }
+
+    #[test]
+    fn vary_on_origin_keeps_existing_items() {
+        let mut headers = Headers::new();
+        headers.set(headers::Vary::Items(vec![UniCase("accept-encoding".to_string())]));
+        vary_on_origin(&mut headers);
+        vary_on_origin(&mut headers);
+        assert_eq!(headers.get::<headers::Vary>(),
+                   Some(&headers::Vary::Items(vec![UniCase("accept-encoding".to_string()),
+                                                   UniCase("origin".to_string())])));
+
+        let mut headers = Headers::new();
+        headers.set(headers::Vary::Any);
+        vary_on_origin(&mut headers);
+        assert_eq!(headers.get::<headers::Vary>(), Some(&headers::Vary::Any));
+    }
+
+    #[test]
+    fn preflight_requests_are_recognized() {
+        let cors = cors(&["*"]);
+        let mut headers = from("https://app.example.com");
+        assert!(!cors.is_preflight(&Method::Options, &headers));
+        headers.set_raw("Access-Control-Request-Method", vec![b"PUT".to_vec()]);
+        assert!(cors.is_preflight(&Method::Options, &headers));
+        assert!(!cors.is_preflight(&Method::Get, &headers));
+    }
+
+    #[test]
+    fn preflight_answers_allowed_origins_only() {
+        let cors = cors(&["*"]);
+        let res = cors.preflight(Some(AccessControlAllowOrigin::Any));
+        assert_eq!(res.status, Some(status::NoContent));
+        assert!(res.headers.get::<headers::AccessControlAllowMethods>().is_some());
+        assert_eq!(res.headers.get::<headers::AccessControlMaxAge>(),
+                   Some(&headers::AccessControlMaxAge(3600)));
+
+        let res = cors.preflight(None);
+        assert_eq!(res.status, Some(status::NoContent));
+        assert!(res.headers.get::<AccessControlAllowOrigin>().is_none());
+    }
+}
@@ -17,10 +17,10 @@ use std::result;
 use std::str::FromStr;
 use std::sync::Arc;
 
-use dbcache::{self, ConnectionPool, Bucket, BasicSet, IndexSet};
+use dbcache::{self, ConnectionPool, Bucket, BasicSet, IndexSet, InstaSet};
 use hab_core::package::{self, Identifiable};
 use protobuf::Message;
-use protocol::depotsrv;
+use protocol::{depotsrv, InstaId, Persistable};
 use r2d2_redis::RedisConnectionManager;
 use redis::{self, Commands, Pipeline, PipelineCommands};
 
@@ -31,6 +31,7 @@ pub struct DataStore {
     pub packages: PackagesTable,
     pub views: ViewsTable,
     pub origin_keys: OriginKeysTable,
+    pub promotions: PromotionsTable,
 }
 
 impl DataStore {
@@ -42,14 +43,17 @@ impl DataStore {
         let pool1 = pool.clone();
         let pool2 = pool.clone();
         let pool3 = pool.clone();
+        let pool4 = pool.clone();
         let packages = PackagesTable::new(pool1);
         let views = ViewsTable::new(pool2);
         let origin_keys = OriginKeysTable::new(pool3);
+        let promotions = PromotionsTable::new(pool4);
         Ok(DataStore {
             pool: pool,
             packages: packages,
             views: views,
             origin_keys: origin_keys,
+            promotions: promotions,
         })
     }
 
@@ -133,6 +137,24 @@ impl PackagesIndex {
         Ok(val)
     }
 
+    /// Returns the identifiers of every package in the index, as they are stored. Any of them
+    /// may fail to parse if the index is damaged.
+    pub fn all(&self) -> Result<Vec<String>> {
+        let conn = self.pool().get().unwrap();
+        match conn.zrange::<&'static str, Vec<String>>(Self::prefix(), 0, -1) {
+            Ok(entries) => {
+                let mut ids: Vec<String> = entries.iter()
+                    .filter_map(|e| e.split(":").last())
+                    .map(|id| id.to_string())
+                    .collect();
+                ids.sort();
+                ids.dedup();
+                Ok(ids)
+            }
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
     pub fn list(&self,
                 id: &str,
                 offset: isize,
@@ -347,6 +369,15 @@ impl PkgViewIndex {
     pub fn new(pool: Arc<ConnectionPool>) -> Self {
         PkgViewIndex { pool: pool }
     }
+
+    /// Returns the names of every view the given package is a member of.
+    pub fn all<T: Identifiable>(&self, pkg: &T) -> Result<Vec<String>> {
+        let conn = self.pool().get().unwrap();
+        match conn.smembers::<String, Vec<String>>(Self::key(&pkg.to_string())) {
+            Ok(views) => Ok(views),
+            Err(e) => Err(Error::from(e)),
+        }
+    }
 }
 
 impl Bucket for PkgViewIndex {
@@ -496,3 +527,138 @@ impl IndexSet for OriginKeysTable {
     type Key = String;
     type Value = String;
 }
+
+/// Contains requests to promote packages into protected views, and an index of the ones in each
+/// view still waiting for approval.
+pub struct PromotionsTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl PromotionsTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        PromotionsTable { pool: pool }
+    }
+
+    /// Returns every request to promote into the given view which is waiting for approval.
+    pub fn pending(&self, view: &str) -> Result<Vec<depotsrv::PromotionRequest>> {
+        let conn = self.pool().get().unwrap();
+        match conn.smembers::<String, Vec<u64>>(Self::pending_key(view)) {
+            Ok(ids) => {
+                let requests = ids.iter().fold(Vec::new(), |mut acc, id| {
+                    match self.find(id) {
+                        Ok(request) => acc.push(request),
+                        Err(e) => debug!("Can't find promotion request {}: {}", id, e),
+                    }
+                    acc
+                });
+                Ok(requests)
+            }
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
+    /// Returns the request to promote the given package into the given view which is waiting
+    /// for approval, if there is one.
+    pub fn pending_for(&self,
+                       view: &str,
+                       ident: &depotsrv::PackageIdent)
+                       -> Result<Option<depotsrv::PromotionRequest>> {
+        let requests = try!(self.pending(view));
+        Ok(requests.into_iter().find(|r| r.get_ident() == ident))
+    }
+
+    /// Records a request which was approved, rejected or expired, removing it from its view's
+    /// pending requests.
+    pub fn close(&self, record: &depotsrv::PromotionRequest) -> Result<()> {
+        let conn = self.pool().get().unwrap();
+        try!(redis::pipe()
+            .atomic()
+            .set(Self::key(&record.primary_key()), record.write_to_bytes().unwrap())
+            .ignore()
+            .srem(Self::pending_key(record.get_view()), record.primary_key())
+            .ignore()
+            .query::<()>(conn.deref()));
+        Ok(())
+    }
+
+    fn pending_key(view: &str) -> String {
+        format!("{}:pending:{}", Self::prefix(), view)
+    }
+}
+
+impl Bucket for PromotionsTable {
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+
+    fn prefix() -> &'static str {
+        "promotion"
+    }
+}
+
+impl InstaSet for PromotionsTable {
+    type Record = depotsrv::PromotionRequest;
+
+    fn seq_id() -> &'static str {
+        "promotion_seq"
+    }
+
+    fn write(&self, record: &mut Self::Record) -> result::Result<(), dbcache::Error> {
+        let conn = try!(self.pool().get());
+        try!(redis::transaction(conn.deref(), &[Self::seq_id()], |txn| {
+            let sequence_id: u64 = match conn.get::<&'static str, u64>(Self::seq_id()) {
+                Ok(value) => value + 1,
+                _ => 0,
+            };
+            let insta_id = InstaId::generate(sequence_id);
+            record.set_primary_key(*insta_id);
+            txn.set(Self::seq_id(), record.primary_key())
+                .ignore()
+                .set(Self::key(&record.primary_key()),
+                     record.write_to_bytes().unwrap())
+                .ignore()
+                .sadd(Self::pending_key(record.get_view()), record.primary_key())
+                .ignore()
+                .query(conn.deref())
+        }));
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "functional"))]
+mod test {
+    use dbcache::InstaSet;
+    use protocol::depotsrv;
+    use time;
+
+    use config::Config;
+    use super::DataStore;
+
+    /// Needs a Redis server listening on the depot's default datastore address.
+    #[test]
+    fn pending_for_finds_only_a_waiting_request() {
+        let datastore = DataStore::open(&Config::default()).unwrap();
+        let view = format!("protected-{}", time::precise_time_ns());
+        let mut ident = depotsrv::PackageIdent::new();
+        ident.set_origin("core".to_string());
+        ident.set_name("redis".to_string());
+        ident.set_version("3.0.7".to_string());
+        ident.set_release("20160614230104".to_string());
+        let mut request = depotsrv::PromotionRequest::new();
+        request.set_view(view.clone());
+        request.set_ident(ident.clone());
+        request.set_requester_id(1);
+        request.set_state(depotsrv::PromotionState::PENDING);
+        datastore.promotions.write(&mut request).unwrap();
+
+        let pending = datastore.promotions.pending_for(&view, &ident).unwrap().unwrap();
+        assert_eq!(pending.get_id(), request.get_id());
+        let mut other = ident.clone();
+        other.set_release("20160615000000".to_string());
+        assert!(datastore.promotions.pending_for(&view, &other).unwrap().is_none());
+
+        request.set_state(depotsrv::PromotionState::APPROVED);
+        datastore.promotions.close(&request).unwrap();
+        assert!(datastore.promotions.pending_for(&view, &ident).unwrap().is_none());
+    }
+}
@@ -15,13 +15,15 @@
 use std::fs;
 use std::io;
 use std::path::PathBuf;
+use std::str::FromStr;
 
-use dbcache::BasicSet;
+use dbcache::{self, BasicSet};
+use depot_client;
 use hab_core;
-use hab_core::package::{FromArchive, PackageArchive};
+use hab_core::package::{FromArchive, PackageArchive, PackageIdent};
 use protocol::depotsrv;
 use time;
-use walkdir::WalkDir;
+use walkdir::{self, WalkDir};
 
 use super::Depot;
 use data_store::DataStore;
@@ -103,15 +105,24 @@ impl Default for ReportBuilder {
 
 #[derive(Debug)]
 pub enum OperationType {
+    /// Record of an archive being fetched from an upstream depot to replace a missing or corrupt
+    /// archive. Contains the package identifier of the archive.
+    ArchiveFetch(String),
     /// Record of an archive being re-inserted into the datastore. Contains the filepath to the
     /// final location of the archive.
     ArchiveInsert(String),
+    /// Record of an archive on disk being checked against its entry in the datastore. Contains the
+    /// filepath of the archive.
+    ArchiveVerify(String),
     /// Record of cleaning up after the doctor has run. Contains the filepath of the trash which
     /// was cleaned.
     CleanupTrash(String),
     /// Record of initializing the depot's datastore filesystem. Contains the filepath of the new
     /// filesystem.
     InitDepotFs(String),
+    /// Record of a datastore entry being checked against its archive on disk. Contains the
+    /// package identifier of the entry.
+    MetadataVerify(String),
     /// Record of preparing the datastore for re-build. Contains the amount of records dropped from
     /// the entire datastore.
     TruncateDataStore(usize),
@@ -122,9 +133,15 @@ pub enum Reason {
     BadArchive,
     BadMetadata(hab_core::Error),
     BadPermissions,
+    ChecksumMismatch,
+    DataStore(dbcache::Error),
+    FetchFailed(depot_client::Error),
     IO(io::Error),
     FileExists,
+    MissingArchive,
     NotEmpty,
+    NotIndexed,
+    Unreadable(walkdir::Error),
 }
 
 #[derive(Debug)]
@@ -182,25 +199,50 @@ impl<'a> Doctor<'a> {
     fn rebuild_metadata(&mut self) -> Result<()> {
         let mut directories = vec![];
         for entry in WalkDir::new(&self.packages_path).follow_links(false) {
-            let entry = entry.unwrap();
-            if entry.metadata().unwrap().is_dir() {
-                directories.push(entry);
-                continue;
+            let trash = self.packages_path.to_string_lossy().to_string();
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    self.report.failure(OperationType::ArchiveInsert(trash), Reason::Unreadable(e));
+                    continue;
+                }
+            };
+            match entry.metadata() {
+                Ok(ref meta) if meta.is_dir() => {
+                    directories.push(entry);
+                    continue;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    self.report.failure(OperationType::ArchiveInsert(trash), Reason::Unreadable(e));
+                    continue;
+                }
             }
             let mut archive = PackageArchive::new(PathBuf::from(entry.path()));
             match archive.ident() {
                 Ok(ident) => {
                     match depotsrv::Package::from_archive(&mut archive) {
                         Ok(object) => {
-                            try!(self.depot.datastore.packages.write(&object));
+                            if let Some(e) = self.depot.datastore.packages.write(&object).err() {
+                                self.report
+                                    .failure(OperationType::ArchiveInsert(entry.path()
+                                                 .to_string_lossy()
+                                                 .to_string()),
+                                             Reason::DataStore(e));
+                                continue;
+                            }
                             let path = self.depot.archive_path(&ident);
-                            if let Some(e) = fs::create_dir_all(path.parent().unwrap()).err() {
+                            let created = match path.parent() {
+                                Some(parent) => fs::create_dir_all(parent),
+                                None => Ok(()),
+                            };
+                            if let Some(e) = created.err() {
                                 self.report
                                     .failure(OperationType::ArchiveInsert(entry.path()
                                                  .to_string_lossy()
                                                  .to_string()),
                                              Reason::IO(e));
-                                break;
+                                continue;
                             }
                             if let Some(e) = fs::rename(entry.path(), &path).err() {
                                 self.report
@@ -208,7 +250,7 @@ impl<'a> Doctor<'a> {
                                                  .to_string_lossy()
                                                  .to_string()),
                                              Reason::IO(e));
-                                break;
+                                continue;
                             }
                             self.report
                                 .success(OperationType::ArchiveInsert(path.to_string_lossy()
@@ -255,6 +297,188 @@ impl<'a> Doctor<'a> {
     }
 }
 
+/// Checks the datastore and the archives on disk against each other without taking the Depot
+/// apart the way `Doctor` does.
+struct Verifier<'a> {
+    report: ReportBuilder,
+    depot: &'a Depot,
+    repair: bool,
+    upstream: Option<depot_client::Client>,
+}
+
+impl<'a> Verifier<'a> {
+    pub fn new(depot: &'a Depot, repair: bool, upstream: Option<&str>) -> Result<Self> {
+        let upstream = match upstream {
+            Some(url) => Some(try!(depot_client::Client::new(url, None))),
+            None => None,
+        };
+        Ok(Verifier {
+            report: ReportBuilder::new(),
+            depot: depot,
+            repair: repair,
+            upstream: upstream,
+        })
+    }
+
+    fn run(mut self) -> Result<Report> {
+        try!(self.verify_metadata());
+        try!(self.verify_archives());
+        Ok(self.report.generate())
+    }
+
+    /// Checks that every package in the datastore has an archive on disk whose checksum matches
+    /// the one recorded at upload time.
+    fn verify_metadata(&mut self) -> Result<()> {
+        for id in try!(self.depot.datastore.packages.index.all()) {
+            let ident = match PackageIdent::from_str(&id) {
+                Ok(ident) => depotsrv::PackageIdent::from(ident),
+                Err(e) => {
+                    self.report.failure(OperationType::MetadataVerify(id), Reason::BadMetadata(e));
+                    continue;
+                }
+            };
+            let package = match self.depot.datastore.packages.find(&ident) {
+                Ok(package) => package,
+                Err(e) => {
+                    self.report.failure(OperationType::MetadataVerify(id), Reason::DataStore(e));
+                    continue;
+                }
+            };
+            let reason = match self.depot.archive(&ident) {
+                Some(archive) => {
+                    match archive.checksum() {
+                        Ok(ref checksum) if checksum == package.get_checksum() => None,
+                        Ok(_) => Some(Reason::ChecksumMismatch),
+                        Err(e) => Some(Reason::BadMetadata(e)),
+                    }
+                }
+                None => Some(Reason::MissingArchive),
+            };
+            match reason {
+                None => {
+                    self.report.success(OperationType::MetadataVerify(id));
+                }
+                Some(reason) => {
+                    self.report.failure(OperationType::MetadataVerify(id), reason);
+                    if self.repair {
+                        self.fetch(&package);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that every archive on disk is readable and has an entry in the datastore.
+    fn verify_archives(&mut self) -> Result<()> {
+        for entry in WalkDir::new(&self.depot.packages_path()).follow_links(false) {
+            let packages = self.depot.packages_path().to_string_lossy().to_string();
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    self.report.failure(OperationType::ArchiveVerify(packages),
+                                        Reason::Unreadable(e));
+                    continue;
+                }
+            };
+            match entry.metadata() {
+                Ok(ref meta) if meta.is_dir() => continue,
+                Ok(_) => {}
+                Err(e) => {
+                    let path = entry.path().to_string_lossy().to_string();
+                    self.report.failure(OperationType::ArchiveVerify(path), Reason::Unreadable(e));
+                    continue;
+                }
+            }
+            let path = entry.path().to_string_lossy().to_string();
+            let mut archive = PackageArchive::new(PathBuf::from(entry.path()));
+            let ident = match archive.ident() {
+                Ok(ident) => depotsrv::PackageIdent::from(ident),
+                Err(e) => {
+                    debug!("Error reading, archive={:?} error={:?}", &archive, &e);
+                    self.report.failure(OperationType::ArchiveVerify(path), Reason::BadArchive);
+                    continue;
+                }
+            };
+            if self.depot.datastore.packages.find(&ident).is_ok() {
+                self.report.success(OperationType::ArchiveVerify(path));
+                continue;
+            }
+            self.report.failure(OperationType::ArchiveVerify(path.clone()), Reason::NotIndexed);
+            if self.repair {
+                match depotsrv::Package::from_archive(&mut archive) {
+                    Ok(object) => {
+                        match self.depot.datastore.packages.write(&object) {
+                            Ok(()) => self.report.success(OperationType::ArchiveInsert(path)),
+                            Err(e) => {
+                                self.report.failure(OperationType::ArchiveInsert(path),
+                                                    Reason::DataStore(e))
+                            }
+                        };
+                    }
+                    Err(e) => {
+                        self.report.failure(OperationType::ArchiveInsert(path),
+                                            Reason::BadMetadata(e));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces the archive of the given package with a copy from the upstream depot, if one was
+    /// given.
+    fn fetch(&mut self, package: &depotsrv::Package) {
+        let ident = package.get_ident();
+        let upstream = match self.upstream {
+            Some(ref upstream) => upstream,
+            None => return,
+        };
+        let path = self.depot.archive_path(ident);
+        let parent = path.parent().unwrap().to_path_buf();
+        if let Some(e) = fs::create_dir_all(&parent).err() {
+            self.report.failure(OperationType::ArchiveFetch(ident.to_string()), Reason::IO(e));
+            return;
+        }
+        let archive = match upstream.fetch_package(ident.clone(), &parent, None) {
+            Ok(archive) => archive,
+            Err(e) => {
+                self.report.failure(OperationType::ArchiveFetch(ident.to_string()),
+                                    Reason::FetchFailed(e));
+                return;
+            }
+        };
+        match archive.checksum() {
+            Ok(ref checksum) if checksum == package.get_checksum() => {}
+            _ => {
+                let _ = fs::remove_file(&archive.path);
+                self.report.failure(OperationType::ArchiveFetch(ident.to_string()),
+                                    Reason::ChecksumMismatch);
+                return;
+            }
+        }
+        if archive.path != path {
+            if let Some(e) = fs::rename(&archive.path, &path).err() {
+                self.report.failure(OperationType::ArchiveFetch(ident.to_string()), Reason::IO(e));
+                return;
+            }
+        }
+        self.report.success(OperationType::ArchiveFetch(ident.to_string()));
+    }
+}
+
+/// Runs the verify tool on the given Depot and returns a Report containing the results. The
+/// verify tool reports packages in the Depot's metadata store whose archive is missing or whose
+/// checksum doesn't match, and archives on disk which are unreadable or missing from the metadata
+/// store. A problem with one entry is recorded in the report and the tool moves on to the next,
+/// so every problem is reported.
+///
+/// In repair mode, archives missing from the metadata store are inserted into it, and missing or
+/// corrupt archives are re-fetched from the `upstream` depot if one is given.
+pub fn verify(depot: &Depot, repair: bool, upstream: Option<&str>) -> Result<Report> {
+    try!(Verifier::new(depot, repair, upstream)).run()
+}
+
 /// Runs the repair tool on the given Depot and returns a Report containing the results. A repair
 /// tool analyzes all packages found within the Depot's metadata store and re-inserts them into
 /// the file system and re-builds all indices.
@@ -19,25 +19,32 @@ use std::fmt;
 use std::result;
 
 use dbcache;
+use depot_client;
 use hab_core;
 use hab_core::package::{self, Identifiable};
 use hab_net;
 use hyper;
 use redis;
+use zmq;
 
 #[derive(Debug)]
 pub enum Error {
+    BadBundle(String),
     BadPort(String),
+    ChecksumMismatch(String),
     DataStore(dbcache::Error),
+    DepotClient(depot_client::Error),
     HabitatCore(hab_core::Error),
     HabitatNet(hab_net::Error),
     HTTP(hyper::status::StatusCode),
     InvalidPackageIdent(String),
     IO(io::Error),
+    NestedStorage(String),
     NoXFilename,
     NoFilePart,
     NulError(ffi::NulError),
     RemotePackageNotFound(package::PackageIdent),
+    UnsupportedStorage(String),
     WriteSyncFailed,
 }
 
@@ -46,8 +53,13 @@ pub type Result<T> = result::Result<T, Error>;
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            Error::BadBundle(ref e) => format!("Invalid origin bundle, {}", e),
             Error::BadPort(ref e) => format!("{} is an invalid port. Valid range 1-65535.", e),
+            Error::ChecksumMismatch(ref e) => {
+                format!("Copy of {} does not match the original's checksum", e)
+            }
             Error::DataStore(ref e) => format!("DataStore error, {}", e),
+            Error::DepotClient(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::HabitatNet(ref e) => format!("{}", e),
             Error::HTTP(ref e) => format!("{}", e),
@@ -57,6 +69,9 @@ impl fmt::Display for Error {
                         e)
             }
             Error::IO(ref e) => format!("{}", e),
+            Error::NestedStorage(ref e) => {
+                format!("Cannot migrate into {}, it is inside the source storage", e)
+            }
             Error::NoXFilename => {
                 format!("Invalid download from a Depot - missing X-Filename header")
             }
@@ -72,6 +87,10 @@ impl fmt::Display for Error {
                     format!("Cannot find a release of package in any sources: {}", pkg)
                 }
             }
+            Error::UnsupportedStorage(ref e) => {
+                format!("Unsupported storage location: {}. Only local directories are available",
+                        e)
+            }
             Error::WriteSyncFailed => {
                 format!("Could not write to destination; perhaps the disk is full?")
             }
@@ -83,8 +102,11 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
+            Error::BadBundle(_) => "Invalid origin bundle",
             Error::BadPort(_) => "Received an invalid port or a number outside of the valid range.",
+            Error::ChecksumMismatch(_) => "Copy does not match the original's checksum",
             Error::DataStore(ref err) => err.description(),
+            Error::DepotClient(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
             Error::HabitatNet(ref err) => err.description(),
             Error::HTTP(_) => "Received an HTTP error",
@@ -92,6 +114,7 @@ impl error::Error for Error {
                 "Package identifiers must be in origin/name format (example: acme/redis)"
             }
             Error::IO(ref err) => err.description(),
+            Error::NestedStorage(_) => "Cannot migrate into a location inside the source storage",
             Error::NulError(_) => {
                 "An attempt was made to build a CString with a null byte inside it"
             }
@@ -100,6 +123,7 @@ impl error::Error for Error {
             Error::NoFilePart => {
                 "An invalid path was passed - we needed a filename, and this path does not have one"
             }
+            Error::UnsupportedStorage(_) => "Unsupported storage location",
             Error::WriteSyncFailed => {
                 "Could not write to destination; bytes written was 0 on a non-0 buffer"
             }
@@ -113,6 +137,12 @@ impl From<dbcache::Error> for Error {
     }
 }
 
+impl From<depot_client::Error> for Error {
+    fn from(err: depot_client::Error) -> Error {
+        Error::DepotClient(err)
+    }
+}
+
 impl From<hab_core::Error> for Error {
     fn from(err: hab_core::Error) -> Error {
         Error::HabitatCore(err)
@@ -143,3 +173,9 @@ impl From<hab_net::Error> for Error {
         Error::HabitatNet(err)
     }
 }
+
+impl From<zmq::Error> for Error {
+    fn from(err: zmq::Error) -> Error {
+        Error::HabitatNet(hab_net::Error::from(err))
+    }
+}
@@ -15,6 +15,7 @@
 extern crate habitat_builder_dbcache as dbcache;
 extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hab_core;
+extern crate habitat_depot_client as depot_client;
 extern crate habitat_net as hab_net;
 #[macro_use]
 extern crate bitflags;
@@ -36,6 +37,8 @@ extern crate redis;
 #[macro_use]
 extern crate router;
 extern crate rustc_serialize;
+#[cfg(test)]
+extern crate tempdir;
 extern crate time;
 extern crate toml;
 extern crate unicase;
@@ -43,31 +46,63 @@ extern crate urlencoded;
 extern crate walkdir;
 extern crate zmq;
 
+pub mod audit;
+pub mod bundle;
 pub mod config;
+pub mod cors;
 pub mod error;
 pub mod data_store;
 pub mod doctor;
+pub mod migrate;
+pub mod notify;
+pub mod promote;
 pub mod server;
 
 pub use self::config::Config;
 pub use self::error::{Error, Result};
 
+use std::cmp;
 use std::sync::Arc;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
+use audit::AuditLog;
 use crypto::sha2::Sha256;
 use crypto::digest::Digest;
+use dbcache::InstaSet;
 use hab_core::package::{Identifiable, PackageArchive};
 use data_store::DataStore;
+use notify::Notifier;
+use protocol::depotsrv;
+use protocol::sharding::InstaId;
 
+use hab_net::clock;
 use hab_net::oauth::github::GitHubClient;
 use hab_net::server::NetIdent;
 use hab_net::routing::BrokerContext;
 
+/// Threads serving the depot's HTTP requests. Iron would otherwise size its pool at
+/// `8 * num_cpus`, leaving too few threads on small machines for the watch limit below.
+pub const HTTP_THREAD_COUNT: usize = 128;
+
+/// Most HTTP threads which may be held by clients waiting for package events, so half the pool
+/// is always free to serve other requests whatever the configured limit.
+pub const MAX_WATCHERS: usize = HTTP_THREAD_COUNT / 2;
+
+/// What came of promoting a package into a view.
+pub enum Promotion {
+    /// The package joined the view
+    Promoted,
+    /// The view is protected, so the promotion waits for approval in this request
+    Requested(depotsrv::PromotionRequest),
+}
+
 pub struct Depot {
     pub config: Config,
     pub datastore: DataStore,
+    pub audit: AuditLog,
+    pub notifier: Notifier,
     pub context: Arc<BrokerContext>,
     github: GitHubClient,
 }
@@ -76,14 +111,76 @@ impl Depot {
     pub fn new(config: Config, ctx: Arc<BrokerContext>) -> Result<Arc<Depot>> {
         let datastore = try!(DataStore::open(&config));
         let github = GitHubClient::new(&config);
+        let audit = try!(AuditLog::open(Path::new(&config.path).join("audit"),
+                                        config.audit_max_size.as_bytes() as usize,
+                                        config.audit_keep));
+        if config.watch_max_watchers > MAX_WATCHERS {
+            warn!("watch_max_watchers of {} would starve the HTTP server, capping at {}",
+                  config.watch_max_watchers,
+                  MAX_WATCHERS);
+        }
+        let notifier = Notifier::new(cmp::min(config.watch_max_watchers, MAX_WATCHERS));
         Ok(Arc::new(Depot {
             config: config,
             datastore: datastore,
+            audit: audit,
+            notifier: notifier,
             context: ctx,
             github: github,
         }))
     }
 
+    /// Promote a package into a view on behalf of the given requester. Promoting into a protected
+    /// view only records a request for another member of the package's origin to approve, or
+    /// returns the one already pending.
+    pub fn promote(&self,
+                   view: &str,
+                   package: &depotsrv::Package,
+                   requester_id: u64,
+                   requester: &str)
+                   -> Result<Promotion> {
+        if !self.config.protected_views.iter().any(|v| v == view) {
+            try!(self.datastore.views.associate(view, package));
+            self.notifier.promoted(view, package.get_ident());
+            return Ok(Promotion::Promoted);
+        }
+        let pending = try!(self.pending_promotions(view));
+        if let Some(request) = pending.into_iter().find(|r| r.get_ident() == package.get_ident()) {
+            return Ok(Promotion::Requested(request));
+        }
+        let mut request = depotsrv::PromotionRequest::new();
+        request.set_view(view.to_string());
+        request.set_ident(package.get_ident().clone());
+        request.set_requester_id(requester_id);
+        request.set_requester(requester.to_string());
+        request.set_state(depotsrv::PromotionState::PENDING);
+        try!(self.datastore.promotions.write(&mut request));
+        self.notifier.promotion_requested(view, package.get_ident());
+        Ok(Promotion::Requested(request))
+    }
+
+    /// Returns the requests to promote into the given view which are waiting for approval.
+    /// Requests older than the `promotion_ttl` are recorded as expired and left out.
+    pub fn pending_promotions(&self, view: &str) -> Result<Vec<depotsrv::PromotionRequest>> {
+        let mut pending = vec![];
+        for mut request in try!(self.datastore.promotions.pending(view)) {
+            if !self.promotion_expired(&request) {
+                pending.push(request);
+                continue;
+            }
+            request.set_state(depotsrv::PromotionState::EXPIRED);
+            if let Err(e) = self.datastore.promotions.close(&request) {
+                warn!("failed to expire promotion request, id={}, err={}", request.get_id(), e);
+            }
+        }
+        Ok(pending)
+    }
+
+    /// True if the promotion request has waited longer than the `promotion_ttl`.
+    pub fn promotion_expired(&self, request: &depotsrv::PromotionRequest) -> bool {
+        expired(request, self.config.promotion_ttl, InstaId::since_epoch())
+    }
+
     // Return a PackageArchive representing the given package. None is returned if the Depot
     // doesn't have an archive for the given package.
     fn archive<T: Identifiable>(&self, ident: &T) -> Option<PackageArchive> {
@@ -133,3 +230,32 @@ impl Depot {
 }
 
 impl NetIdent for Depot {}
+
+// True if a promotion request has waited `ttl` or longer at `now_ms`, in milliseconds since the
+// epoch of ids. Requests are timed by their ids, which start with the time they were issued.
+fn expired(request: &depotsrv::PromotionRequest, ttl: Duration, now_ms: u64) -> bool {
+    let age_ms = now_ms.saturating_sub(InstaId(request.get_id()).timestamp());
+    age_ms >= clock::duration_ms(ttl) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use protocol::depotsrv;
+
+    use super::expired;
+
+    #[test]
+    fn promotion_requests_expire_after_their_ttl() {
+        let mut request = depotsrv::PromotionRequest::new();
+        // Issued 5s after the epoch
+        request.set_id(5_000 << 23 | 7 << 13);
+        let ttl = Duration::from_secs(60);
+        assert!(!expired(&request, ttl, 5_000));
+        assert!(!expired(&request, ttl, 64_999));
+        assert!(expired(&request, ttl, 65_000));
+        // A clock behind the one which issued the id doesn't expire it.
+        assert!(!expired(&request, ttl, 0));
+    }
+}
@@ -23,6 +23,8 @@ extern crate env_logger;
 extern crate log;
 extern crate zmq;
 
+use std::fs::File;
+use std::io::BufWriter;
 use std::net;
 use std::process;
 use std::str::FromStr;
@@ -68,6 +70,29 @@ fn app<'a, 'b>() -> clap::App<'a, 'b> {
         (@subcommand repair =>
             (about: "Verify and repair data integrity of the package Depot")
         )
+        (@subcommand verify =>
+            (about: "Check the package Depot's metadata against the archives on disk")
+            (@arg repair: --repair
+                "Index unindexed archives and re-fetch missing or corrupt ones from --upstream")
+            (@arg upstream: --upstream +takes_value
+                "URL of a depot to re-fetch missing or corrupt archives from")
+        )
+        (@subcommand migrate =>
+            (about: "Copy the package Depot's archives and keys to another storage location")
+            (@arg from: --from +takes_value
+                "Storage to copy from, local for the Depot's path. [default: local]")
+            (@arg to: --to +takes_value +required
+                "Storage to copy to, a directory or file:// URL")
+        )
+        (@subcommand export =>
+            (about: "Write an origin's packages and public keys to a bundle")
+            (@arg origin: <origin> +required "Name of the origin to export")
+            (@arg file: "Bundle to write. [default: <origin>.bundle.tar]")
+        )
+        (@subcommand import =>
+            (about: "Import an origin bundle written by export")
+            (@arg file: <file> +required "Bundle to import")
+        )
         (@subcommand view =>
             (about: "Creates or lists views in the package Depot")
             (@subcommand create =>
@@ -112,6 +137,27 @@ fn dispatch(config: Config, matches: &clap::ArgMatches) -> Result<()> {
     match matches.subcommand_name() {
         Some("start") => start(config),
         Some("repair") => repair(config),
+        Some(cmd @ "verify") => {
+            let args = matches.subcommand_matches(cmd).unwrap();
+            verify(config, args.is_present("repair"), args.value_of("upstream"))
+        }
+        Some(cmd @ "migrate") => {
+            let args = matches.subcommand_matches(cmd).unwrap();
+            migrate(config, args.value_of("from").unwrap_or("local"), args.value_of("to").unwrap())
+        }
+        Some(cmd @ "export") => {
+            let args = matches.subcommand_matches(cmd).unwrap();
+            let origin = args.value_of("origin").unwrap();
+            let file = match args.value_of("file") {
+                Some(file) => file.to_string(),
+                None => format!("{}.bundle.tar", origin),
+            };
+            export(config, origin, &file)
+        }
+        Some(cmd @ "import") => {
+            let args = matches.subcommand_matches(cmd).unwrap();
+            import(config, args.value_of("file").unwrap())
+        }
         Some(cmd @ "view") => {
             let args = matches.subcommand_matches(cmd).unwrap();
             match args.subcommand_name() {
@@ -160,10 +206,117 @@ pub fn repair(config: Config) -> Result<()> {
     let ctx = Arc::new(BrokerContext::new());
     let depot = try!(depot::Depot::new(config, ctx));
     let report = try!(depot::doctor::repair(&depot));
+    for operation in report.operations.iter() {
+        if let depot::doctor::Operation::Failure(ref op, ref reason) = *operation {
+            println!("FAILED {:?}: {:?}", op, reason);
+        }
+    }
     println!("Report: {:?}", &report);
     Ok(())
 }
 
+/// Checks that every package in the depot's metadata has a matching archive on disk, and that
+/// every archive on disk is present in the metadata. Optionally repairs what it can.
+///
+/// # Failures
+///
+/// * The database cannot be read
+/// * A write transaction cannot be acquired when repairing
+/// * The upstream depot url is invalid
+pub fn verify(config: Config, repair: bool, upstream: Option<&str>) -> Result<()> {
+    let ctx = Arc::new(BrokerContext::new());
+    let depot = try!(depot::Depot::new(config, ctx));
+    let report = try!(depot::doctor::verify(&depot, repair, upstream));
+    for operation in report.operations.iter() {
+        if let depot::doctor::Operation::Failure(ref op, ref reason) = *operation {
+            println!("FAILED {:?}: {:?}", op, reason);
+        }
+    }
+    if report.success {
+        println!("Depot verified, no problems found");
+        Ok(())
+    } else {
+        println!("Report: {:?}", &report);
+        process::exit(1);
+    }
+}
+
+/// Copies the depot's archives and keys to another storage location, verifying each copy.
+/// Running it again after an interruption copies only what is missing. The depot keeps serving
+/// from its path until it is restarted with `--path` set to the new location.
+///
+/// # Failures
+///
+/// * A storage location is not a local directory
+/// * The source storage cannot be read
+/// * Any file could not be copied
+fn migrate(config: Config, from: &str, to: &str) -> Result<()> {
+    let from = try!(depot::migrate::Storage::parse(from, &config.path));
+    let to = try!(depot::migrate::Storage::parse(to, &config.path));
+    println!("Migrating {} to {}", from, to);
+    let report = try!(depot::migrate::migrate(&from, &to));
+    for &(ref file, ref reason) in report.failed.iter() {
+        println!("FAILED {}: {}", file, reason);
+    }
+    println!("Copied {} file(s), {} already present, {} failed",
+             report.copied.len(),
+             report.skipped,
+             report.failed.len());
+    if !report.success() {
+        println!("Run the migration again to retry the failed files");
+        process::exit(1);
+    }
+    println!("Migration complete, restart the Depot with --path set to the new location");
+    Ok(())
+}
+
+/// Writes every package release and public key of an origin, with their views and provenance, to
+/// a bundle which `import` can load into another depot.
+///
+/// # Failures
+///
+/// * The database cannot be read
+/// * The bundle file cannot be written
+fn export(config: Config, origin: &str, file: &str) -> Result<()> {
+    let ctx = Arc::new(BrokerContext::new());
+    let depot = try!(depot::Depot::new(config, ctx));
+    let bundle = try!(depot::bundle::Bundle::collect(&depot, origin));
+    let mut out = BufWriter::new(try!(File::create(file)));
+    try!(bundle.write(&mut out));
+    println!("Exported {} package(s) and {} key(s) of {} to {}",
+             bundle.manifest.packages.len(),
+             bundle.manifest.keys.len(),
+             origin,
+             file);
+    Ok(())
+}
+
+/// Loads an origin bundle into the depot. Packages and keys the depot already has are left as
+/// they are, so an interrupted import can be run again.
+///
+/// # Failures
+///
+/// * The file is not an origin bundle
+/// * The database cannot be written
+/// * Any package or key could not be imported
+fn import(config: Config, file: &str) -> Result<()> {
+    let ctx = Arc::new(BrokerContext::new());
+    let depot = try!(depot::Depot::new(config, ctx));
+    let report = try!(depot::bundle::import(&depot, try!(File::open(file)), None, None));
+    for &(ref entry, ref reason) in report.failed.iter() {
+        println!("FAILED {}: {}", entry, reason);
+    }
+    println!("Imported {} entries of {}, {} already present, {} failed",
+             report.imported.len(),
+             report.origin,
+             report.skipped.len(),
+             report.failed.len());
+    if !report.success() {
+        process::exit(1);
+    }
+    Ok(())
+}
+
 /// Create a view with the given name in the depot.
 ///
 /// # Failures
@@ -0,0 +1,342 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Moves the archives and keys of a depot from one storage location to another.
+//!
+//! Every file is copied next to its destination under a temporary name, checked against the
+//! SHA-256 digest of its source and only then renamed into place, so the destination never holds
+//! a partial or corrupt file under its real name. Files already at the destination with a
+//! matching digest are skipped, which lets an interrupted migration be resumed by running it
+//! again.
+//!
+//! Only local filesystem storage exists today, so both ends of a migration are directories.
+
+use std::env;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crypto::digest::Digest;
+use crypto::sha2::Sha256;
+use walkdir::WalkDir;
+
+use error::{Error, Result};
+
+// Directories of a depot's storage holding the files a migration copies.
+const STORAGE_DIRS: &'static [&'static str] = &["pkgs", "keys"];
+// Suffix of a file being copied, until it is verified.
+const PARTIAL_SUFFIX: &'static str = ".partial";
+
+/// A place a depot's archives and keys are stored.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Storage {
+    /// A directory on the local filesystem, laid out as a depot's data path
+    Local(PathBuf),
+}
+
+impl Storage {
+    /// Parse a storage location: `local` for the depot's own data path, or a directory given as
+    /// a path or a `file://` URL.
+    ///
+    /// # Errors
+    ///
+    /// * The location names a storage backend other than the local filesystem
+    pub fn parse(spec: &str, depot_path: &str) -> Result<Self> {
+        if spec == "local" {
+            return Ok(Storage::Local(PathBuf::from(depot_path)));
+        }
+        if spec.starts_with("file://") {
+            return Ok(Storage::Local(PathBuf::from(&spec["file://".len()..])));
+        }
+        if spec.contains("://") {
+            return Err(Error::UnsupportedStorage(spec.to_string()));
+        }
+        Ok(Storage::Local(PathBuf::from(spec)))
+    }
+
+    fn root(&self) -> &Path {
+        match *self {
+            Storage::Local(ref path) => path,
+        }
+    }
+}
+
+impl fmt::Display for Storage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Storage::Local(ref path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Outcome of a migration.
+#[derive(Debug, Default)]
+pub struct Report {
+    /// Files copied to the destination, relative to the storage root
+    pub copied: Vec<String>,
+    /// Number of files already at the destination with a matching digest
+    pub skipped: usize,
+    /// Files which could not be copied and why
+    pub failed: Vec<(String, String)>,
+}
+
+impl Report {
+    /// True if every file is at the destination.
+    pub fn success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Copy every archive and key from one storage location to another.
+///
+/// # Errors
+///
+/// * The destination is the source or lies inside it
+/// * A directory of the source can't be read
+pub fn migrate(from: &Storage, to: &Storage) -> Result<Report> {
+    if try!(absolute(to.root())).starts_with(try!(absolute(from.root()))) {
+        return Err(Error::NestedStorage(to.to_string()));
+    }
+    let mut report = Report::default();
+    for dir in STORAGE_DIRS {
+        let src_root = from.root().join(dir);
+        if fs::metadata(&src_root).is_err() {
+            debug!("nothing to migrate in {}", src_root.display());
+            continue;
+        }
+        for entry in WalkDir::new(&src_root).follow_links(false) {
+            let entry = try!(entry.map_err(|e| io::Error::new(io::ErrorKind::Other, e)));
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let src = entry.path();
+            if src.to_string_lossy().ends_with(PARTIAL_SUFFIX) {
+                continue;
+            }
+            let rel = src.strip_prefix(from.root()).unwrap().to_path_buf();
+            let dst = to.root().join(&rel);
+            let name = rel.to_string_lossy().into_owned();
+            match copy_verified(src, &dst) {
+                Ok(true) => report.copied.push(name),
+                Ok(false) => report.skipped += 1,
+                Err(e) => {
+                    warn!("unable to migrate {}, err={}", name, e);
+                    report.failed.push((name, e.to_string()));
+                }
+            }
+        }
+    }
+    Ok(report)
+}
+
+// Resolve a path which may not exist yet through its closest existing ancestor, so links and
+// relative components can't hide one storage location inside another.
+fn absolute(path: &Path) -> Result<PathBuf> {
+    let mut path = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        try!(env::current_dir()).join(path)
+    };
+    let mut missing = Vec::new();
+    loop {
+        match fs::canonicalize(&path) {
+            Ok(mut resolved) => {
+                for component in missing.iter().rev() {
+                    resolved.push(component);
+                }
+                return Ok(resolved);
+            }
+            Err(e) => {
+                match (path.file_name().map(|n| n.to_os_string()), path.parent()) {
+                    (Some(name), Some(parent)) => {
+                        missing.push(name);
+                        path = parent.to_path_buf();
+                        continue;
+                    }
+                    _ => return Err(Error::IO(e)),
+                }
+            }
+        }
+    }
+}
+
+// Copy a file to its destination unless it is already there, returning whether it was copied.
+fn copy_verified(src: &Path, dst: &Path) -> Result<bool> {
+    let digest = try!(file_digest(src));
+    if let Ok(existing) = file_digest(dst) {
+        if existing == digest {
+            return Ok(false);
+        }
+    }
+    if let Some(parent) = dst.parent() {
+        try!(fs::create_dir_all(parent));
+    }
+    let mut partial = dst.as_os_str().to_owned();
+    partial.push(PARTIAL_SUFFIX);
+    let partial = PathBuf::from(partial);
+    try!(fs::copy(src, &partial));
+    if try!(file_digest(&partial)) != digest {
+        let _ = fs::remove_file(&partial);
+        return Err(Error::ChecksumMismatch(src.to_string_lossy().into_owned()));
+    }
+    try!(fs::rename(&partial, dst));
+    Ok(true)
+}
+
+fn file_digest(path: &Path) -> Result<String> {
+    let mut file = try!(File::open(path));
+    let mut digest = Sha256::new();
+    let mut buf = [0; 8192];
+    loop {
+        let n = try!(file.read(&mut buf));
+        if n == 0 {
+            break;
+        }
+        digest.input(&buf[..n]);
+    }
+    Ok(digest.result_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+    use std::io::{Read, Write};
+    use std::path::{Path, PathBuf};
+
+    use tempdir::TempDir;
+
+    use super::*;
+    use error::Error;
+
+    fn write_file(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        File::create(path).unwrap().write_all(content.as_bytes()).unwrap();
+    }
+
+    fn read_file(path: &Path) -> String {
+        let mut content = String::new();
+        File::open(path).unwrap().read_to_string(&mut content).unwrap();
+        content
+    }
+
+    #[test]
+    fn parse_local_is_the_depot_path() {
+        assert_eq!(Storage::parse("local", "/hab/svc/depot/data").unwrap(),
+                   Storage::Local(PathBuf::from("/hab/svc/depot/data")));
+    }
+
+    #[test]
+    fn parse_file_urls_and_paths() {
+        assert_eq!(Storage::parse("file:///mnt/depot", "/data").unwrap(),
+                   Storage::Local(PathBuf::from("/mnt/depot")));
+        assert_eq!(Storage::parse("/mnt/depot", "/data").unwrap(),
+                   Storage::Local(PathBuf::from("/mnt/depot")));
+        assert_eq!(Storage::parse("depot", "/data").unwrap(),
+                   Storage::Local(PathBuf::from("depot")));
+    }
+
+    #[test]
+    fn parse_rejects_other_schemes() {
+        match Storage::parse("s3://bucket/depot", "/data") {
+            Err(Error::UnsupportedStorage(ref spec)) => assert_eq!(spec, "s3://bucket/depot"),
+            other => panic!("expected an unsupported storage error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn copy_verified_copies_a_missing_file() {
+        let dir = TempDir::new("migrate").unwrap();
+        let src = dir.path().join("src");
+        let dst = dir.path().join("dst/nested/file");
+        write_file(&src, "archive");
+        assert_eq!(copy_verified(&src, &dst).unwrap(), true);
+        assert_eq!(read_file(&dst), "archive");
+        assert!(!dir.path().join("dst/nested/file.partial").exists());
+    }
+
+    #[test]
+    fn copy_verified_skips_an_identical_file() {
+        let dir = TempDir::new("migrate").unwrap();
+        let src = dir.path().join("src");
+        let dst = dir.path().join("dst");
+        write_file(&src, "archive");
+        write_file(&dst, "archive");
+        assert_eq!(copy_verified(&src, &dst).unwrap(), false);
+    }
+
+    #[test]
+    fn copy_verified_replaces_a_differing_file() {
+        let dir = TempDir::new("migrate").unwrap();
+        let src = dir.path().join("src");
+        let dst = dir.path().join("dst");
+        write_file(&src, "archive");
+        write_file(&dst, "truncat");
+        assert_eq!(copy_verified(&src, &dst).unwrap(), true);
+        assert_eq!(read_file(&dst), "archive");
+    }
+
+    #[test]
+    fn migrate_copies_archives_and_keys() {
+        let dir = TempDir::new("migrate").unwrap();
+        let from = Storage::Local(dir.path().join("from"));
+        let to = Storage::Local(dir.path().join("to"));
+        write_file(&dir.path().join("from/pkgs/core/redis/3.0.1/20160101000000/a.hart"),
+                   "archive");
+        write_file(&dir.path().join("from/keys/core-20160101000000.pub"), "key");
+        write_file(&dir.path().join("from/keys/core-20160101000000.pub.partial"), "k");
+        write_file(&dir.path().join("from/other/file"), "ignored");
+
+        let report = migrate(&from, &to).unwrap();
+        assert!(report.success());
+        assert_eq!(report.copied.len(), 2);
+        assert_eq!(read_file(&dir.path()
+                       .join("to/pkgs/core/redis/3.0.1/20160101000000/a.hart")),
+                   "archive");
+        assert_eq!(read_file(&dir.path().join("to/keys/core-20160101000000.pub")), "key");
+        assert!(!dir.path().join("to/keys/core-20160101000000.pub.partial").exists());
+        assert!(!dir.path().join("to/other").exists());
+    }
+
+    #[test]
+    fn migrate_resumes_by_skipping_copied_files() {
+        let dir = TempDir::new("migrate").unwrap();
+        let from = Storage::Local(dir.path().join("from"));
+        let to = Storage::Local(dir.path().join("to"));
+        write_file(&dir.path().join("from/keys/a.pub"), "a");
+        write_file(&dir.path().join("from/keys/b.pub"), "b");
+        write_file(&dir.path().join("to/keys/a.pub"), "a");
+
+        let report = migrate(&from, &to).unwrap();
+        assert_eq!(report.copied, vec!["keys/b.pub".to_string()]);
+        assert_eq!(report.skipped, 1);
+        let report = migrate(&from, &to).unwrap();
+        assert!(report.copied.is_empty());
+        assert_eq!(report.skipped, 2);
+    }
+
+    #[test]
+    fn migrate_rejects_a_destination_inside_the_source() {
+        let dir = TempDir::new("migrate").unwrap();
+        write_file(&dir.path().join("from/keys/a.pub"), "a");
+        let from = Storage::Local(dir.path().join("from"));
+        for to in &["from", "from/pkgs/copy", "from/../from/backup"] {
+            match migrate(&from, &Storage::Local(dir.path().join(to))) {
+                Err(Error::NestedStorage(_)) => (),
+                other => panic!("expected migrating into {} to fail, got {:?}", to, other),
+            }
+        }
+        assert!(!dir.path().join("from/pkgs").exists());
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Notifications of packages uploaded to the depot, promoted into its views, or waiting for a
+//! promotion into a protected view to be approved or rejected.
+//!
+//! Clients watching an origin long-poll its events endpoint instead of repeatedly asking for the
+//! latest release of each package. A watch names the position of the last event it has seen and
+//! returns as soon as there are newer events, or empty handed once its timeout passes. Only the
+//! most recent events are kept; a watcher which falls further behind is told it missed some.
+//!
+//! Listeners in the same process, such as a gateway pushing events to its own clients, receive
+//! every event over a channel as it is published.
+
+use std::collections::VecDeque;
+use std::sync::{mpsc, Condvar, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use depot_client::{PackageEvent, PackageEvents};
+use hab_core::package::Identifiable;
+
+/// Number of events kept for watchers to catch up on.
+pub const EVENTS_RETAINED: usize = 1024;
+
+struct Events {
+    retained: VecDeque<PackageEvent>,
+    next_seq: u64,
+}
+
+pub struct Notifier {
+    events: Mutex<Events>,
+    published: Condvar,
+    listeners: Mutex<Vec<mpsc::Sender<PackageEvent>>>,
+    watchers: AtomicUsize,
+    max_watchers: usize,
+}
+
+impl Notifier {
+    /// Create a notifier allowing at most `max_watchers` watches to wait at once.
+    pub fn new(max_watchers: usize) -> Self {
+        Notifier {
+            events: Mutex::new(Events {
+                retained: VecDeque::with_capacity(EVENTS_RETAINED),
+                next_seq: 1,
+            }),
+            published: Condvar::new(),
+            listeners: Mutex::new(vec![]),
+            watchers: AtomicUsize::new(0),
+            max_watchers: max_watchers,
+        }
+    }
+
+    /// Record that a package was uploaded.
+    pub fn uploaded<T: Identifiable>(&self, ident: &T) {
+        self.publish("uploaded", None, ident);
+    }
+
+    /// Record that a package was promoted into a view.
+    pub fn promoted<T: Identifiable>(&self, view: &str, ident: &T) {
+        self.publish("promoted", Some(view.to_string()), ident);
+    }
+
+    /// Record that promoting a package into a protected view is waiting for approval.
+    pub fn promotion_requested<T: Identifiable>(&self, view: &str, ident: &T) {
+        self.publish("promotion_requested", Some(view.to_string()), ident);
+    }
+
+    /// Record that a request to promote a package into a protected view was rejected or withdrawn.
+    pub fn promotion_rejected<T: Identifiable>(&self, view: &str, ident: &T) {
+        self.publish("promotion_rejected", Some(view.to_string()), ident);
+    }
+
+    fn publish<T: Identifiable>(&self, kind: &str, view: Option<String>, ident: &T) {
+        let mut events = self.events.lock().unwrap();
+        let event = PackageEvent {
+            seq: events.next_seq,
+            kind: kind.to_string(),
+            view: view,
+            origin: ident.origin().to_string(),
+            name: ident.name().to_string(),
+            ident: ident.to_string(),
+        };
+        events.next_seq += 1;
+        // Listeners whose receiving end has hung up are dropped.
+        self.listeners.lock().unwrap().retain(|tx| tx.send(event.clone()).is_ok());
+        if events.retained.len() >= EVENTS_RETAINED {
+            events.retained.pop_front();
+        }
+        events.retained.push_back(event);
+        self.published.notify_all();
+    }
+
+    /// Receive every event published from now on. Stop listening by dropping the receiver.
+    pub fn listen(&self) -> mpsc::Receiver<PackageEvent> {
+        let (tx, rx) = mpsc::channel();
+        self.listeners.lock().unwrap().push(tx);
+        rx
+    }
+
+    /// Wait up to `timeout` for events of an origin after position `after`, only including
+    /// promotions into `view` if one is given. With no position, returns the latest position
+    /// straight away. Returns `None` if too many watches are already waiting.
+    pub fn watch(&self,
+                 origin: &str,
+                 view: Option<&str>,
+                 after: Option<u64>,
+                 timeout: Duration)
+                 -> Option<PackageEvents> {
+        let mut events = self.events.lock().unwrap();
+        let latest = events.next_seq - 1;
+        let after = match after {
+            // A position past the latest event was handed out before the depot restarted.
+            Some(after) if after <= latest => after,
+            Some(_) => {
+                return Some(PackageEvents {
+                    events: vec![],
+                    next: latest,
+                    missed: true,
+                })
+            }
+            None => {
+                return Some(PackageEvents {
+                    events: vec![],
+                    next: latest,
+                    missed: false,
+                })
+            }
+        };
+        if self.watchers.fetch_add(1, Ordering::SeqCst) >= self.max_watchers {
+            self.watchers.fetch_sub(1, Ordering::SeqCst);
+            return None;
+        }
+        let deadline = Instant::now() + timeout;
+        let batch;
+        loop {
+            let matching: Vec<PackageEvent> = events.retained
+                .iter()
+                .filter(|e| e.seq > after && e.origin == origin)
+                .filter(|e| view.map_or(true, |v| e.view.as_ref().map_or(false, |ev| ev == v)))
+                .cloned()
+                .collect();
+            let now = Instant::now();
+            if !matching.is_empty() || now >= deadline {
+                let oldest = events.retained.front().map_or(events.next_seq, |e| e.seq);
+                batch = PackageEvents {
+                    events: matching,
+                    next: events.next_seq - 1,
+                    missed: after + 1 < oldest,
+                };
+                break;
+            }
+            events = self.published.wait_timeout(events, deadline - now).unwrap().0;
+        }
+        self.watchers.fetch_sub(1, Ordering::SeqCst);
+        Some(batch)
+    }
+}
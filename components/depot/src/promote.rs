@@ -0,0 +1,374 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Automatic promotion of the packages built by successful jobs.
+//!
+//! The depot subscribes to the job status events published by each configured job server. When
+//! a job completes, the package it built is promoted into the view of every rule matching its
+//! origin, and each promotion is recorded in the audit log as a `package.promotion.auto` action.
+//! Packages are only promoted for jobs whose owner maintains the package's origin, as they would
+//! have to to promote it themselves. Promotions into protected views are requested rather than
+//! made, and wait for approval like any other.
+
+use std::str::FromStr;
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use dbcache::{self, BasicSet};
+use hab_core::package::{Identifiable, PackageIdent};
+use hab_net::clients::JobClient;
+use hab_net::routing::Broker;
+use hab_net::server::ToAddrString;
+use protobuf::parse_from_bytes;
+use protocol::depotsrv;
+use protocol::jobsrv::{self, Job, JobGet, JobState};
+use protocol::vault::OriginRole;
+use zmq;
+
+use super::{Depot, Promotion};
+use audit;
+use error::Result;
+use server;
+
+const ACTION: &'static str = "package.promotion.auto";
+// Milliseconds to wait before receiving again after the subscriber socket fails.
+const RECV_BACKOFF_MS: u64 = 1_000;
+
+/// Promote the packages built in an origin, or any origin, into a view. Written as
+/// `<origin>:<view>`, with an origin of `*` matching every origin.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub origin: Option<String>,
+    pub view: String,
+}
+
+impl Rule {
+    pub fn matches(&self, origin: &str) -> bool {
+        self.origin.as_ref().map_or(true, |o| o == origin)
+    }
+}
+
+impl FromStr for Rule {
+    type Err = ();
+
+    fn from_str(entry: &str) -> ::std::result::Result<Self, ()> {
+        match entry.find(':') {
+            Some(i) if i > 0 && i + 1 < entry.len() => {
+                let origin = match &entry[..i] {
+                    "*" => None,
+                    origin => Some(origin.to_string()),
+                };
+                Ok(Rule {
+                    origin: origin,
+                    view: entry[i + 1..].to_string(),
+                })
+            }
+            _ => Err(()),
+        }
+    }
+}
+
+/// Apply the depot's promotion rules to the jobs completing on its configured job servers, from
+/// a separate thread. No thread is started if there are no rules or no job servers.
+///
+/// # Errors
+///
+/// * The subscriber socket could not be created or connected
+pub fn run(depot: Arc<Depot>) -> Result<Option<JoinHandle<()>>> {
+    if depot.config.promotion_rules.is_empty() || depot.config.job_events_addrs.is_empty() {
+        return Ok(None);
+    }
+    let ctx = zmq::Context::new();
+    let sock = try!(ctx.socket(zmq::SUB));
+    try!(sock.set_subscribe(jobsrv::JOB_EVENT_TOPIC.as_bytes()));
+    for addr in depot.config.job_events_addrs.iter() {
+        try!(sock.connect(&addr.to_addr_string()));
+    }
+    let handle = thread::Builder::new()
+        .name("auto-promote".to_string())
+        .spawn(move || {
+            // The context has to outlive its socket.
+            let _ctx = ctx;
+            let mut msg = zmq::Message::new().unwrap();
+            loop {
+                match recv_event(&sock, &mut msg) {
+                    Ok(true) => (),
+                    Ok(false) => continue,
+                    Err(zmq::Error::ETERM) => break,
+                    Err(e) => {
+                        error!("job event receive failed, err={}", e);
+                        thread::sleep(Duration::from_millis(RECV_BACKOFF_MS));
+                        continue;
+                    }
+                }
+                match parse_from_bytes::<Job>(&msg) {
+                    Ok(ref job) if job.get_state() == JobState::Complete &&
+                                   job.has_package_ident() => job_completed(&depot, job),
+                    Ok(_) => (),
+                    Err(e) => warn!("dropping malformed job event, err={}", e),
+                }
+            }
+        })
+        .unwrap();
+    Ok(Some(handle))
+}
+
+// Receive the next job event into `msg`, popping its topic first. Returns false if the topic
+// came without a job.
+fn recv_event(sock: &zmq::Socket,
+              msg: &mut zmq::Message)
+              -> ::std::result::Result<bool, zmq::Error> {
+    try!(sock.recv(msg, 0));
+    if !try!(sock.get_rcvmore()) {
+        return Ok(false);
+    }
+    try!(sock.recv(msg, 0));
+    Ok(true)
+}
+
+// Job events arrive unauthenticated, so an event only says which job to look at. The job
+// itself, and the package it built, are read back from the job servers through the routing
+// layer.
+fn job_completed(depot: &Depot, event: &Job) {
+    let job = match fetch_job(depot, event.get_id()) {
+        Ok(job) => job,
+        Err(e) => {
+            error!("auto-promote:1, job={}, err={:?}", event.get_id(), e);
+            return;
+        }
+    };
+    if job.get_state() != JobState::Complete || !job.has_package_ident() {
+        return;
+    }
+    let ident = match PackageIdent::from_str(job.get_package_ident()) {
+        Ok(ident) => ident,
+        Err(_) => {
+            warn!("not promoting, job={}, package={}", job.get_id(), job.get_package_ident());
+            return;
+        }
+    };
+    if !ident.fully_qualified() {
+        warn!("not promoting partially qualified package, job={}, package={}",
+              job.get_id(),
+              ident);
+        return;
+    }
+    let rules: Vec<&Rule> = depot.config
+        .promotion_rules
+        .iter()
+        .filter(|r| r.matches(&ident.origin))
+        .collect();
+    if rules.is_empty() {
+        return;
+    }
+    let role = if job.has_owner_id() {
+        server::origin_role(depot, job.get_owner_id(), &ident.origin)
+    } else {
+        None
+    };
+    let built = depotsrv::PackageIdent::from(ident.clone());
+    let package = match depot.datastore.packages.find(&built) {
+        Ok(package) => package,
+        Err(dbcache::Error::EntityNotFound) => {
+            info!("not promoting, package not uploaded, job={}, package={}",
+                  job.get_id(),
+                  ident);
+            return;
+        }
+        Err(e) => {
+            error!("auto-promote:2, job={}, err={:?}", job.get_id(), e);
+            return;
+        }
+    };
+    for rule in rules {
+        let status = match promote(depot, &job, &rule.view, &package, role) {
+            Ok(status) => status,
+            Err(e) => {
+                error!("auto-promote:3, job={}, view={}, err={:?}",
+                       job.get_id(),
+                       rule.view,
+                       e);
+                500
+            }
+        };
+        let entry = audit::Entry {
+            timestamp: 0,
+            action: ACTION.to_string(),
+            actor: None,
+            actor_id: if job.has_owner_id() { Some(job.get_owner_id()) } else { None },
+            origin: Some(ident.origin.clone()),
+            ident: Some(package.get_ident().to_string()),
+            source_ip: String::new(),
+            status: status,
+            outcome: if status < 300 { "success" } else { "failure" }.to_string(),
+            job_id: Some(job.get_id()),
+            view: Some(rule.view.clone()),
+        };
+        if let Err(e) = depot.audit.record(entry) {
+            error!("audit log write failed, action={}, err={}", ACTION, e);
+        }
+    }
+}
+
+fn fetch_job(depot: &Depot, id: u64) -> Result<Job> {
+    let mut conn = try!(Broker::connect(&depot.context));
+    let mut request = JobGet::new();
+    request.set_id(id);
+    let job = try!(JobClient::get(&mut conn, &request));
+    Ok(job)
+}
+
+// Promote a package into a view for a job whose owner holds `role` in the package's origin,
+// answering with the HTTP status the owner promoting it would have been given.
+fn promote(depot: &Depot,
+           job: &Job,
+           view: &str,
+           package: &depotsrv::Package,
+           role: Option<OriginRole>)
+           -> Result<u16> {
+    if !try!(depot.datastore.views.is_member(view)) {
+        warn!("not promoting into unknown view, job={}, view={}", job.get_id(), view);
+        return Ok(404);
+    }
+    if !role.map_or(false, |r| r.permits(OriginRole::MAINTAINER)) {
+        warn!("not promoting, job owner does not maintain the origin, job={}, owner={}, \
+               package={}",
+              job.get_id(),
+              job.get_owner_id(),
+              package.get_ident());
+        return Ok(403);
+    }
+    let requester = format!("job:{}", job.get_id());
+    if let Promotion::Requested(_) = try!(depot.promote(view,
+                                                        package,
+                                                        job.get_owner_id(),
+                                                        &requester)) {
+        return Ok(202);
+    }
+    info!("promoted package, job={}, view={}, package={}",
+          job.get_id(),
+          view,
+          package.get_ident());
+    Ok(200)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::Rule;
+
+    #[test]
+    fn rule_from_str() {
+        assert_eq!(Rule::from_str("core:stable").unwrap(),
+                   Rule {
+                       origin: Some("core".to_string()),
+                       view: "stable".to_string(),
+                   });
+        assert_eq!(Rule::from_str("*:unstable").unwrap(),
+                   Rule {
+                       origin: None,
+                       view: "unstable".to_string(),
+                   });
+        // Only the first colon separates the origin from the view.
+        assert_eq!(Rule::from_str("core:stable:2").unwrap().view, "stable:2");
+    }
+
+    #[test]
+    fn rule_from_str_needs_an_origin_and_a_view() {
+        for entry in &["", "core", ":stable", "core:", ":"] {
+            assert!(Rule::from_str(entry).is_err(), "accepted {:?}", entry);
+        }
+    }
+
+    #[test]
+    fn rule_matches_its_origin() {
+        let core = Rule::from_str("core:stable").unwrap();
+        assert!(core.matches("core"));
+        assert!(!core.matches("corex"));
+        assert!(Rule::from_str("*:stable").unwrap().matches("anything"));
+    }
+}
+
+#[cfg(all(test, feature = "functional"))]
+mod test {
+    use std::sync::Arc;
+
+    use hab_net::routing::BrokerContext;
+    use protocol::depotsrv;
+    use protocol::jobsrv::Job;
+    use protocol::vault::OriginRole;
+    use tempdir::TempDir;
+    use time;
+
+    use config::Config;
+    use super::super::Depot;
+    use super::promote;
+
+    fn package() -> depotsrv::Package {
+        let mut ident = depotsrv::PackageIdent::new();
+        ident.set_origin("core".to_string());
+        ident.set_name("redis".to_string());
+        ident.set_version("3.0.7".to_string());
+        ident.set_release(time::precise_time_ns().to_string());
+        let mut package = depotsrv::Package::new();
+        package.set_ident(ident);
+        package
+    }
+
+    fn promoted(depot: &Depot, view: &str, package: &depotsrv::Package) -> bool {
+        let ident = package.get_ident().to_string();
+        depot.datastore
+            .views
+            .view_pkg_idx
+            .all(view, "core/redis")
+            .unwrap()
+            .iter()
+            .any(|p| p.to_string() == ident)
+    }
+
+    /// Needs a Redis server listening on the depot's default datastore address.
+    #[test]
+    fn promote_needs_a_maintainer_and_a_known_view() {
+        let dir = TempDir::new("depot-promote").unwrap();
+        let view = format!("promote-{}", time::precise_time_ns());
+        let protected = format!("{}-protected", view);
+        let mut config = Config::default();
+        config.path = dir.path().to_string_lossy().into_owned();
+        config.protected_views = vec![protected.clone()];
+        let depot = Depot::new(config, Arc::new(BrokerContext::new())).unwrap();
+        depot.datastore.views.write(&view).unwrap();
+        depot.datastore.views.write(&protected).unwrap();
+        let package = package();
+        let mut job = Job::new();
+        job.set_id(1);
+        job.set_owner_id(2);
+
+        let unknown = format!("{}-unknown", view);
+        let maintainer = Some(OriginRole::MAINTAINER);
+        assert_eq!(promote(&depot, &job, &unknown, &package, maintainer).unwrap(), 404);
+        assert_eq!(promote(&depot, &job, &view, &package, None).unwrap(), 403);
+        assert!(!promoted(&depot, &view, &package));
+        assert_eq!(promote(&depot, &job, &view, &package, maintainer).unwrap(), 200);
+        assert!(promoted(&depot, &view, &package));
+        assert_eq!(promote(&depot, &job, &protected, &package, Some(OriginRole::OWNER)).unwrap(),
+                   202);
+        assert!(!promoted(&depot, &protected, &package));
+        let pending = depot.pending_promotions(&protected).unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].get_requester(), "job:1");
+        assert_eq!(pending[0].get_requester_id(), 2);
+    }
+}
@@ -13,45 +13,78 @@
 // limitations under the License.
 
 use std::borrow::Cow;
+use std::cmp;
 use std::fs::{self, File};
-use std::io::{Read, Write, BufWriter};
-use std::path::PathBuf;
+use std::io::{self, Read, Seek, SeekFrom, Write, BufWriter};
+use std::path::{Path, PathBuf};
 use std::result;
 use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 
 use bodyparser;
-use dbcache::{self, BasicSet, IndexSet};
+use dbcache::{self, BasicSet, IndexSet, InstaSet};
 use hab_core::package::{Identifiable, FromArchive, PackageArchive};
 use hab_core::crypto::keys::{self, PairType};
-use hab_core::crypto::SigKeyPair;
+use hab_core::crypto::{hash, SigKeyPair};
 use hab_net;
-use hab_net::config::RouteAddrs;
+use hab_net::cache::CacheConfig;
+use hab_net::clock;
+use hab_net::config::{RouteAddrs, RouterAuth};
+use hab_net::hmac;
 use hab_net::routing::{Broker, BrokerContext};
 use hab_net::server::NetIdent;
 use hyper::mime::{Mime, TopLevel, SubLevel, Attr, Value};
 use iron::headers::ContentType;
 use iron::prelude::*;
-use iron::{status, headers, AfterMiddleware};
+use iron::{status, headers};
 use iron::headers::{Authorization, Bearer};
 use iron::request::Body;
+use iron::response::{BodyReader, ResponseBody, WriteBody};
+use iron::typemap;
+use iron::Protocol;
 use mount::Mount;
-use protobuf;
+use protobuf::{self, RepeatedField};
 use protocol::depotsrv;
+use protocol::jobsrv;
 use protocol::net::{self, NetError, ErrCode};
-use protocol::sessionsrv::{Account, AccountGet, OAuthProvider, Session, SessionCreate, SessionGet};
+use protocol::sessionsrv::{Account, AccountGet, OAuthProvider, Scope, Session, SessionCreate,
+                           SessionGet};
 use protocol::vault::*;
 use router::{Params, Router};
 use rustc_serialize::json::{self, ToJson};
-use unicase::UniCase;
+use time;
 use urlencoded::UrlEncodedQuery;
 
-use super::Depot;
+use super::{Depot, Promotion, HTTP_THREAD_COUNT};
+use audit;
+use bundle::{self, Bundle};
 use config::Config;
+use cors::Cors;
 use error::{Error, Result};
+use promote;
 
 const PAGINATION_RANGE_DEFAULT: isize = 0;
 const PAGINATION_RANGE_MAX: isize = 50;
 const ONE_YEAR_IN_SECS: usize = 31536000;
+const AUDIT_LIMIT_DEFAULT: usize = 50;
+const AUDIT_LIMIT_MAX: usize = 1000;
+// Bytes of an upload read from the request and written to disk at a time.
+const UPLOAD_BUF_SIZE: usize = 64 * 1024;
+
+// Request extension holding the session of the account which authenticated the request.
+struct Actor;
+
+impl typemap::Key for Actor {
+    type Value = Session;
+}
+
+// Request extension naming the origin a request acts on when it isn't part of the route.
+struct AuditOrigin;
+
+impl typemap::Key for AuditOrigin {
+    type Value = String;
+}
 
 /// Return an IronResult containing the body of a NetError and the appropriate HTTP response status
 /// for the corresponding NetError.
@@ -70,6 +103,8 @@ fn render_net_error(err: &NetError) -> Response {
         ErrCode::ENTITY_CONFLICT => status::Conflict,
         ErrCode::ENTITY_NOT_FOUND => status::NotFound,
         ErrCode::NO_SHARD => status::ServiceUnavailable,
+        ErrCode::BUSY => status::ServiceUnavailable,
+        ErrCode::THROTTLED => status::TooManyRequests,
         ErrCode::TIMEOUT => status::RequestTimeout,
         ErrCode::BAD_REMOTE_REPLY => status::BadGateway,
         ErrCode::SESSION_EXPIRED => status::Unauthorized,
@@ -139,13 +174,17 @@ pub fn authenticate(depot: &Depot, req: &mut Request) -> result::Result<Session,
                 Ok(rep) => {
                     match rep.get_message_id() {
                         "Session" => {
-                            let session = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                            let session: Session = protobuf::parse_from_bytes(rep.get_body())
+                                .unwrap();
+                            req.extensions.insert::<Actor>(session.clone());
                             Ok(session)
                         }
                         "NetError" => {
                             let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
                             if err.get_code() == ErrCode::SESSION_EXPIRED {
-                                session_create(depot, token)
+                                let session = try!(session_create(depot, token));
+                                req.extensions.insert::<Actor>(session.clone());
+                                Ok(session)
                             } else {
                                 Err(render_net_error(&err))
                             }
@@ -163,8 +202,22 @@ pub fn authenticate(depot: &Depot, req: &mut Request) -> result::Result<Session,
     }
 }
 
+/// Authenticate the request and ensure its session was granted the given scope.
+pub fn authorize(depot: &Depot,
+                 req: &mut Request,
+                 scope: &Scope)
+                 -> result::Result<Session, Response> {
+    let session = try!(authenticate(depot, req));
+    if session.permits(scope) {
+        Ok(session)
+    } else {
+        debug!("session {} lacks scope {}", session.get_id(), scope);
+        Err(Response::with(status::Forbidden))
+    }
+}
+
 pub fn origin_create(depot: &Depot, req: &mut Request) -> IronResult<Response> {
-    let session = match authenticate(&depot, req) {
+    let session = match authorize(&depot, req, &Scope::Admin) {
         Ok(session) => session,
         Err(response) => return Ok(response),
     };
@@ -181,6 +234,7 @@ pub fn origin_create(depot: &Depot, req: &mut Request) -> IronResult<Response> {
         _ => return Ok(Response::with(status::BadRequest)),
     };
 
+    req.extensions.insert::<AuditOrigin>(request.get_name().to_string());
     if !keys::is_valid_origin_name(request.get_name()) {
         return Ok(Response::with(status::UnprocessableEntity));
     }
@@ -275,6 +329,20 @@ pub fn get_origin(depot: &Depot, origin: &str) -> Result<Option<Origin>> {
 }
 
 pub fn check_origin_access(depot: &Depot, account_id: u64, origin_name: &str) -> bool {
+    origin_role(depot, account_id, origin_name).is_some()
+}
+
+/// Whether the account holds at least the given role in the origin.
+pub fn check_origin_role(depot: &Depot,
+                         account_id: u64,
+                         origin_name: &str,
+                         required: OriginRole)
+                         -> bool {
+    origin_role(depot, account_id, origin_name).map_or(false, |role| role.permits(required))
+}
+
+/// The role the account holds in the origin, or `None` if it is not a member.
+pub fn origin_role(depot: &Depot, account_id: u64, origin_name: &str) -> Option<OriginRole> {
     let mut conn = Broker::connect(&depot.context).unwrap();
 
     let mut request = CheckOriginAccessRequest::new();
@@ -292,25 +360,39 @@ pub fn check_origin_access(depot: &Depot, account_id: u64, origin_name: &str) ->
                 "CheckOriginAccessResponse" => {
                     let response: CheckOriginAccessResponse =
                         protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                    response.get_has_access()
+                    if response.get_has_access() {
+                        Some(response.get_role())
+                    } else {
+                        None
+                    }
                 }
-                "NetError" => false,
+                "NetError" => None,
                 _ => unreachable!("unexpected msg: {:?}", rep),
             }
         }
         Err(e) => {
             debug!("Error checking origin access: {}", e);
-            false
+            None
         }
     }
 }
 
 pub fn invite_to_origin(depot: &Depot, req: &mut Request) -> IronResult<Response> {
-    let session = match authenticate(depot, req) {
+    let session = match authorize(depot, req, &Scope::Admin) {
         Ok(session) => session,
         Err(response) => return Ok(response),
     };
 
+    let role = match extract_query_value("role", req) {
+        Some(role) => {
+            match role.parse::<OriginRole>() {
+                Ok(role) => role,
+                Err(_) => return Ok(Response::with(status::BadRequest)),
+            }
+        }
+        None => OriginRole::MAINTAINER,
+    };
+
     let params = req.extensions.get::<Router>().unwrap();
 
     let origin = match params.find("origin") {
@@ -322,11 +404,12 @@ pub fn invite_to_origin(depot: &Depot, req: &mut Request) -> IronResult<Response
         Some(username) => username,
         None => return Ok(Response::with(status::BadRequest)),
     };
-    debug!("Creating invitation for user {} origin {}",
+    debug!("Creating invitation for user {} origin {} as {}",
            &user_to_invite,
-           &origin);
+           &origin,
+           role);
 
-    if !check_origin_access(&depot, session.get_id(), &origin) {
+    if !check_origin_role(&depot, session.get_id(), &origin, OriginRole::OWNER) {
         return Ok(Response::with(status::Forbidden));
     }
 
@@ -371,6 +454,7 @@ pub fn invite_to_origin(depot: &Depot, req: &mut Request) -> IronResult<Response
     invite_request.set_origin_id(origin_obj.get_id());
     invite_request.set_origin_name(origin_obj.get_name().to_string());
     invite_request.set_owner_id(session.get_id());
+    invite_request.set_role(role);
 
     conn.route(&invite_request).unwrap();
     match conn.recv() {
@@ -402,7 +486,7 @@ pub fn invite_to_origin(depot: &Depot, req: &mut Request) -> IronResult<Response
 pub fn list_origin_invitations(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     debug!("list_origin_invitations");
 
-    let session = match authenticate(depot, req) {
+    let session = match authorize(depot, req, &Scope::Read) {
         Ok(session) => session,
         Err(response) => return Ok(response),
     };
@@ -455,7 +539,7 @@ pub fn list_origin_invitations(depot: &Depot, req: &mut Request) -> IronResult<R
 pub fn list_origin_members(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     debug!("list_origin_members");
 
-    let session = match authenticate(depot, req) {
+    let session = match authorize(depot, req, &Scope::Read) {
         Ok(session) => session,
         Err(response) => return Ok(response),
     };
@@ -505,6 +589,95 @@ pub fn list_origin_members(depot: &Depot, req: &mut Request) -> IronResult<Respo
     }
 }
 
+pub fn set_origin_member_role(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let session = match authorize(depot, req, &Scope::Admin) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+
+    let params = req.extensions.get::<Router>().unwrap();
+
+    let origin_name = match params.find("origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let username = match params.find("username") {
+        Some(username) => username,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let role = match params.find("role").map(|r| r.parse::<OriginRole>()) {
+        Some(Ok(role)) => role,
+        _ => return Ok(Response::with(status::BadRequest)),
+    };
+
+    if !check_origin_role(&depot, session.get_id(), &origin_name, OriginRole::OWNER) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let origin = match try!(get_origin(&depot, origin_name)) {
+        Some(o) => o,
+        None => return Ok(Response::with(status::NotFound)),
+    };
+
+    let mut conn = Broker::connect(&depot.context).unwrap();
+    let mut request = AccountGet::new();
+    request.set_name(username.to_string());
+    conn.route(&request).unwrap();
+
+    let account = match conn.recv() {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "Account" => {
+                    let account: Account = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    account
+                }
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    return Ok(render_net_error(&err));
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            return Ok(Response::with(status::ServiceUnavailable));
+        }
+    };
+
+    let mut request = OriginMemberRoleSet::new();
+    request.set_origin_id(origin.get_id());
+    request.set_origin_name(origin.get_name().to_string());
+    request.set_account_id(account.get_id());
+    request.set_account_name(account.get_name().to_string());
+    request.set_role(role);
+    request.set_requester_id(session.get_id());
+    conn.set_session_token(Some(session.get_token().to_string()));
+    conn.route(&request).unwrap();
+    match conn.recv() {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "OriginMemberRole" => {
+                    let member: OriginMemberRole = protobuf::parse_from_bytes(rep.get_body())
+                        .unwrap();
+                    let encoded = json::encode(&member.to_json()).unwrap();
+                    Ok(Response::with((status::Ok, encoded)))
+                }
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Ok(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Ok(Response::with(status::ServiceUnavailable))
+        }
+    }
+}
+
 
 fn write_string_to_file(filename: &PathBuf, body: String) -> Result<bool> {
     let path = filename.parent().unwrap();
@@ -518,34 +691,97 @@ fn write_string_to_file(filename: &PathBuf, body: String) -> Result<bool> {
     Ok(true)
 }
 
-fn write_file(filename: &PathBuf, body: &mut Body) -> Result<bool> {
+// Stream an uploaded archive to a temporary file beside its destination, hashing it as it is
+// written so it never has to be read back or held in memory. The archive is only moved into place
+// if its checksum matches `expected`; the checksum of what was received is returned either way.
+// Stream an uploaded archive to a temporary file next to `filename`, returning the temporary
+// file's path and the archive's checksum. Nothing is left behind if the upload can't be read.
+fn stage_archive(filename: &Path, body: &mut Body) -> Result<(PathBuf, String)> {
     let path = filename.parent().unwrap();
     try!(fs::create_dir_all(path));
-    let tempfile = format!("{}.tmp", filename.to_string_lossy());
-    let f = try!(File::create(&tempfile));
-    let mut writer = BufWriter::new(&f);
-    let mut written: i64 = 0;
-    let mut buf = [0u8; 100000]; // Our byte buffer
-    loop {
-        let len = try!(body.read(&mut buf)); // Raise IO errors
-        match len {
-            0 => {
-                // 0 == EOF, so stop writing and finish progress
-                break;
-            }
-            _ => {
-                // Write the buffer to the BufWriter on the Heap
-                let bytes_written = try!(writer.write(&buf[0..len]));
-                if bytes_written == 0 {
-                    return Err(Error::WriteSyncFailed);
+    let staged = PathBuf::from(format!("{}.tmp", filename.to_string_lossy()));
+    match stream_to_file(&staged, body) {
+        Ok(checksum) => Ok((staged, checksum)),
+        Err(e) => {
+            let _ = fs::remove_file(&staged);
+            Err(e)
+        }
+    }
+}
+
+// Check an archive staged by `stage_archive` against what the client said it uploaded, and move
+// it to `filename` if it passes. A rejected archive is removed, so a failed upload never leaves an
+// archive in place which would make a retry conflict.
+fn install_archive(staged: &Path,
+                   checksum: &str,
+                   expected_checksum: &str,
+                   ident: &depotsrv::PackageIdent,
+                   filename: &Path)
+                   -> result::Result<depotsrv::Package, status::Status> {
+    match validate_archive(staged, checksum, expected_checksum, ident) {
+        Ok(object) => {
+            match fs::rename(staged, filename) {
+                Ok(()) => {
+                    info!("File added to Depot at {}", filename.to_string_lossy());
+                    Ok(object)
+                }
+                Err(e) => {
+                    error!("upload_package:2, err={:?}", e);
+                    let _ = fs::remove_file(staged);
+                    Err(status::InternalServerError)
                 }
-                written = written + (bytes_written as i64);
             }
-        };
+        }
+        Err(status) => {
+            let _ = fs::remove_file(staged);
+            Err(status)
+        }
     }
-    info!("File added to Depot at {}", filename.to_string_lossy());
-    try!(fs::rename(&tempfile, &filename));
-    Ok(true)
+}
+
+fn validate_archive(staged: &Path,
+                    checksum: &str,
+                    expected_checksum: &str,
+                    ident: &depotsrv::PackageIdent)
+                    -> result::Result<depotsrv::Package, status::Status> {
+    if checksum != expected_checksum {
+        info!("Checksums did not match: from_param={:?}, from_artifact={:?}",
+              expected_checksum,
+              checksum);
+        return Err(status::UnprocessableEntity);
+    }
+    let mut archive = PackageArchive::new(staged);
+    debug!("Package Archive: {:#?}", archive);
+    let object = match depotsrv::Package::from_archive(&mut archive) {
+        Ok(object) => object,
+        Err(e) => {
+            info!("Error building package from archive: {:#?}", e);
+            return Err(status::UnprocessableEntity);
+        }
+    };
+    if !ident.satisfies(object.get_ident()) {
+        info!("Ident mismatch, expected={:?}, got={:?}",
+              ident,
+              object.get_ident());
+        return Err(status::UnprocessableEntity);
+    }
+    Ok(object)
+}
+
+fn stream_to_file<P: AsRef<Path>>(filename: P, body: &mut Body) -> Result<String> {
+    let mut writer = BufWriter::new(try!(File::create(filename)));
+    let mut hasher = hash::Hasher::new();
+    let mut buf = [0u8; UPLOAD_BUF_SIZE];
+    loop {
+        let len = try!(body.read(&mut buf));
+        if len == 0 {
+            break;
+        }
+        hasher.update(&buf[0..len]);
+        try!(writer.write_all(&buf[0..len]));
+    }
+    try!(writer.flush());
+    Ok(hasher.finish())
 }
 
 fn upload_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Response> {
@@ -574,7 +810,8 @@ fn upload_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Response> {
             Err(response) => return Ok(response),
         };
 
-        if !check_origin_access(&depot, session.get_id(), &origin) {
+        if !session.permits(&Scope::Upload(origin.clone())) ||
+           !check_origin_role(&depot, session.get_id(), &origin, OriginRole::MAINTAINER) {
             return Ok(Response::with(status::Forbidden));
         }
     }
@@ -640,7 +877,8 @@ fn upload_origin_secret_key(depot: &Depot, req: &mut Request) -> IronResult<Resp
         None => return Ok(Response::with(status::BadRequest)),
     };
 
-    if !check_origin_access(&depot, session.get_id(), &name) {
+    if !session.permits(&Scope::Upload(name.to_string())) ||
+       !check_origin_role(&depot, session.get_id(), &name, OriginRole::MAINTAINER) {
         return Ok(Response::with(status::Forbidden));
     }
 
@@ -692,6 +930,57 @@ fn upload_origin_secret_key(depot: &Depot, req: &mut Request) -> IronResult<Resp
     Ok(Response::with(status::Created))
 }
 
+// Tell the JobSrv what an uploaded package depends on, so group builds of its dependencies
+// rebuild it. The JobSrv is told from a thread of its own, so the upload doesn't wait on it, and
+// the upload stands if it can't be told.
+fn record_deps(depot: &Depot, package: &depotsrv::Package) {
+    let mut request = jobsrv::JobGraphPackage::new();
+    let project = format!("{}/{}",
+                          package.get_ident().get_origin(),
+                          package.get_ident().get_name());
+    request.set_project(project.clone());
+    let deps = package.get_deps()
+        .iter()
+        .map(|d| format!("{}/{}", d.get_origin(), d.get_name()))
+        .collect();
+    request.set_deps(RepeatedField::from_vec(deps));
+    let context = depot.context.clone();
+    let spawned = thread::Builder::new()
+        .name("record-deps".to_string())
+        .spawn(move || {
+            let reply = Broker::connect(&context).and_then(|mut conn| {
+                try!(conn.route(&request));
+                conn.recv()
+            });
+            match reply {
+                Ok(rep) => {
+                    if rep.get_message_id() == "NetError" {
+                        match protobuf::parse_from_bytes::<NetError>(rep.get_body()) {
+                            Ok(err) => {
+                                warn!("unable to record package deps, project={}, err={:?}",
+                                      request.get_project(),
+                                      err)
+                            }
+                            Err(e) => {
+                                warn!("unable to record package deps, project={}, err={}",
+                                      request.get_project(),
+                                      e)
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    warn!("unable to record package deps, project={}, err={:?}",
+                          request.get_project(),
+                          e)
+                }
+            }
+        });
+    if let Err(e) = spawned {
+        warn!("unable to record package deps, project={}, err={}", project, e);
+    }
+}
+
 fn upload_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     // this lets us get around ownership/mutability issues
     fn get_ident_and_checksum(req: &mut Request) -> Option<(String, depotsrv::PackageIdent)> {
@@ -709,6 +998,10 @@ fn upload_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
         Some((checksum_from_param, ident)) => (checksum_from_param, ident),
         None => return Ok(Response::with(status::BadRequest)),
     };
+    let provenance = match provenance_from_query(req) {
+        Ok(provenance) => provenance,
+        Err(()) => return Ok(Response::with(status::BadRequest)),
+    };
 
     if !depot.config.insecure {
         let session = match authenticate(depot, req) {
@@ -716,7 +1009,11 @@ fn upload_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
             Err(response) => return Ok(response),
         };
 
-        if !check_origin_access(&depot, session.get_id(), &ident.get_origin()) {
+        if !session.permits(&Scope::Upload(ident.get_origin().to_string())) ||
+           !check_origin_role(&depot,
+                              session.get_id(),
+                              &ident.get_origin(),
+                              OriginRole::MAINTAINER) {
             return Ok(Response::with(status::Forbidden));
         }
 
@@ -740,43 +1037,57 @@ fn upload_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     }
 
     let filename = depot.archive_path(&ident);
-    try!(write_file(&filename, &mut req.body));
-    let mut archive = PackageArchive::new(filename);
-    debug!("Package Archive: {:#?}", archive);
-    let checksum_from_artifact = match archive.checksum() {
-        Ok(cksum) => cksum,
-        Err(e) => {
-            info!("Could not compute a checksum for {:#?}: {:#?}", archive, e);
-            return Ok(Response::with(status::UnprocessableEntity));
-        }
+    let (staged, checksum_from_artifact) = try!(stage_archive(&filename, &mut req.body));
+    let mut object = match install_archive(&staged,
+                                           &checksum_from_artifact,
+                                           &checksum_from_param,
+                                           &ident,
+                                           &filename) {
+        Ok(object) => object,
+        Err(status) => return Ok(Response::with(status)),
     };
-    if checksum_from_param != checksum_from_artifact {
-        info!("Checksums did not match: from_param={:?}, from_artifact={:?}",
-              checksum_from_param,
-              checksum_from_artifact);
-        return Ok(Response::with(status::UnprocessableEntity));
+    if let Some(provenance) = provenance {
+        object.set_provenance(provenance);
     }
-    let object = match depotsrv::Package::from_archive(&mut archive) {
-        Ok(object) => object,
-        Err(e) => {
-            info!("Error building package from archive: {:#?}", e);
-            return Ok(Response::with(status::UnprocessableEntity));
-        }
-    };
-    if ident.satisfies(object.get_ident()) {
-        depot.datastore.packages.write(&object).unwrap();
-        let mut response = Response::with((status::Created,
-                                           format!("/pkgs/{}/download", object.get_ident())));
-        let mut base_url = req.url.clone();
-        base_url.path =
-            vec![String::from("pkgs"), object.get_ident().to_string(), String::from("download")];
-        response.headers.set(headers::Location(format!("{}", base_url)));
-        Ok(response)
+    depot.datastore.packages.write(&object).unwrap();
+    depot.notifier.uploaded(object.get_ident());
+    record_deps(depot, &object);
+    let mut response = Response::with((status::Created,
+                                       format!("/pkgs/{}/download", object.get_ident())));
+    let mut base_url = req.url.clone();
+    base_url.path =
+        vec![String::from("pkgs"), object.get_ident().to_string(), String::from("download")];
+    response.headers.set(headers::Location(format!("{}", base_url)));
+    Ok(response)
+}
+
+/// Read the build provenance reported with a package upload from the `job_id`,
+/// `source_revision`, `builder_host`, and `log_url` query parameters. Returns an error if the job
+/// id is not a number.
+fn provenance_from_query(req: &mut Request)
+                         -> result::Result<Option<depotsrv::PackageProvenance>, ()> {
+    let mut provenance = depotsrv::PackageProvenance::new();
+    let mut reported = false;
+    if let Some(job_id) = extract_query_value("job_id", req) {
+        provenance.set_job_id(try!(job_id.parse().map_err(|_| ())));
+        reported = true;
+    }
+    if let Some(source_revision) = extract_query_value("source_revision", req) {
+        provenance.set_source_revision(source_revision);
+        reported = true;
+    }
+    if let Some(builder_host) = extract_query_value("builder_host", req) {
+        provenance.set_builder_host(builder_host);
+        reported = true;
+    }
+    if let Some(log_url) = extract_query_value("log_url", req) {
+        provenance.set_log_url(log_url);
+        reported = true;
+    }
+    if reported {
+        Ok(Some(provenance))
     } else {
-        info!("Ident mismatch, expected={:?}, got={:?}",
-              ident,
-              object.get_ident());
-        Ok(Response::with(status::UnprocessableEntity))
+        Ok(None)
     }
 }
 
@@ -810,8 +1121,21 @@ fn download_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Response>
         }
     };
 
+    let etag = match hash::hash_file(&origin_keyfile) {
+        Ok(etag) => etag,
+        Err(e) => {
+            error!("download_origin_key:1, err={:?}", e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    };
+    if let Some(mut response) = not_modified(&req.headers, &etag) {
+        do_cache_response(&mut response);
+        return Ok(response);
+    }
+
     let xfilename = origin_keyfile.file_name().unwrap().to_string_lossy().into_owned();
     let mut response = Response::with((status::Ok, origin_keyfile));
+    set_etag(&mut response, &etag);
     // use set_raw because we're having problems with Iron's Hyper 0.8.x
     // and the newer Hyper 0.9.4. TODO: change back to set() once
     // Iron updates to Hyper 0.9.x.
@@ -849,8 +1173,21 @@ fn download_latest_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Re
         }
     };
 
+    let etag = match hash::hash_file(&origin_keyfile) {
+        Ok(etag) => etag,
+        Err(e) => {
+            error!("download_latest_origin_key:1, err={:?}", e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    };
+    if let Some(mut response) = not_modified(&req.headers, &etag) {
+        revalidate_response(&mut response);
+        return Ok(response);
+    }
+
     let xfilename = origin_keyfile.file_name().unwrap().to_string_lossy().into_owned();
     let mut response = Response::with((status::Ok, origin_keyfile));
+    set_etag(&mut response, &etag);
     // use set_raw because we're having problems with Iron's Hyper 0.8.x
     // and the newer Hyper 0.9.4. TODO: change back to set() once
     // Iron updates to Hyper 0.9.x.
@@ -858,7 +1195,7 @@ fn download_latest_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Re
     response.headers.set_raw("content-disposition",
                              vec![format!("attachment; filename=\"{}\"", xfilename.clone())
                                       .into_bytes()]);
-    dont_cache_response(&mut response);
+    revalidate_response(&mut response);
     Ok(response)
 }
 
@@ -871,8 +1208,50 @@ fn download_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
         Ok(ident) => {
             if let Some(archive) = depot.archive(&ident) {
                 match fs::metadata(&archive.path) {
-                    Ok(_) => {
-                        let mut response = Response::with((status::Ok, archive.path.clone()));
+                    Ok(meta) => {
+                        let cached = not_modified(&req.headers, ident.get_checksum());
+                        if let Some(mut response) = cached {
+                            do_cache_response(&mut response);
+                            return Ok(response);
+                        }
+                        let mut response = match byte_range(req, ident.get_checksum(), meta.len()) {
+                            Ok(Some((first, last))) => {
+                                let mut file = match File::open(&archive.path) {
+                                    Ok(file) => file,
+                                    Err(e) => {
+                                        error!("download_package:2, err={:?}", e);
+                                        return Ok(Response::with(status::InternalServerError));
+                                    }
+                                };
+                                if let Err(e) = file.seek(SeekFrom::Start(first)) {
+                                    error!("download_package:3, err={:?}", e);
+                                    return Ok(Response::with(status::InternalServerError));
+                                }
+                                let len = last - first + 1;
+                                let mut response =
+                                    Response::with((status::PartialContent,
+                                                    BodyReader(file.take(len))));
+                                response.headers.set_raw("Content-Range",
+                                                         vec![format!("bytes {}-{}/{}",
+                                                                      first,
+                                                                      last,
+                                                                      meta.len())
+                                                                  .into_bytes()]);
+                                response.headers
+                                    .set_raw("Content-Length", vec![len.to_string().into_bytes()]);
+                                response
+                            }
+                            Ok(None) => Response::with((status::Ok, archive.path.clone())),
+                            Err(()) => {
+                                let mut response = Response::with(status::RangeNotSatisfiable);
+                                response.headers.set_raw("Content-Range",
+                                                         vec![format!("bytes */{}", meta.len())
+                                                                  .into_bytes()]);
+                                return Ok(response);
+                            }
+                        };
+                        response.headers.set_raw("Accept-Ranges", vec![b"bytes".to_vec()]);
+                        set_etag(&mut response, ident.get_checksum());
                         // use set_raw because we're having problems with Iron's Hyper 0.8.x
                         // and the newer Hyper 0.9.4. TODO: change back to set() once
                         // Iron updates to Hyper 0.9.x.
@@ -904,6 +1283,94 @@ fn download_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     }
 }
 
+// Streams an origin bundle into the response as it is written rather than building it in memory.
+struct BundleBody(Bundle);
+
+impl WriteBody for BundleBody {
+    fn write_body(&mut self, res: &mut ResponseBody) -> io::Result<()> {
+        self.0.write(res).map_err(|e| io::Error::new(io::ErrorKind::Other, format!("{}", e)))
+    }
+}
+
+fn export_origin(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(depot, req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let origin = {
+        let params = req.extensions.get::<Router>().unwrap();
+        params.find("origin").unwrap().to_string()
+    };
+    if !check_origin_access(&depot, session.get_id(), &origin) {
+        return Ok(Response::with(status::Forbidden));
+    }
+    let bundle = match Bundle::collect(depot, &origin) {
+        Ok(bundle) => bundle,
+        Err(e) => {
+            error!("export_origin:1, err={:?}", e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    };
+    let body: Box<WriteBody + Send> = Box::new(BundleBody(bundle));
+    let mut response = Response::with((status::Ok, body));
+    response.headers.set(ContentType(Mime(TopLevel::Application,
+                                          SubLevel::Ext("x-tar".to_string()),
+                                          vec![])));
+    response.headers.set_raw("content-disposition",
+                             vec![format!("attachment; filename=\"{}.bundle.tar\"", origin)
+                                      .into_bytes()]);
+    dont_cache_response(&mut response);
+    Ok(response)
+}
+
+fn import_origin(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(depot, req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let origin = {
+        let params = req.extensions.get::<Router>().unwrap();
+        params.find("origin").unwrap().to_string()
+    };
+    if !check_origin_role(&depot, session.get_id(), &origin, OriginRole::OWNER) {
+        return Ok(Response::with(status::Forbidden));
+    }
+    let tmp = PathBuf::from(&depot.config.path).join("tmp");
+    if let Err(e) = fs::create_dir_all(&tmp) {
+        error!("import_origin:0, err={:?}", e);
+        return Ok(Response::with(status::InternalServerError));
+    }
+    let tempfile = tmp.join(format!("{}-{}.bundle.tar", origin, time::precise_time_ns()));
+    let tempfile = tempfile.to_string_lossy().into_owned();
+    if let Err(e) = stream_to_file(&tempfile, &mut req.body) {
+        let _ = fs::remove_file(&tempfile);
+        error!("import_origin:1, err={:?}", e);
+        return Ok(Response::with(status::BadRequest));
+    }
+    let result = File::open(&tempfile)
+        .map_err(Error::from)
+        .and_then(|file| bundle::import(depot, file, Some(&origin), Some(&session)));
+    let _ = fs::remove_file(&tempfile);
+    match result {
+        Ok(report) => {
+            let code = if report.success() {
+                status::Ok
+            } else {
+                status::UnprocessableEntity
+            };
+            Ok(Response::with((code, json::encode(&report).unwrap())))
+        }
+        Err(e @ Error::BadBundle(_)) => {
+            debug!("import_origin:2, err={}", e);
+            Ok(Response::with((status::BadRequest, format!("{}", e))))
+        }
+        Err(e) => {
+            error!("import_origin:3, err={:?}", e);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
 fn list_origin_keys(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     let params = req.extensions.get::<Router>().unwrap();
     let origin = match params.find("origin") {
@@ -914,8 +1381,22 @@ fn list_origin_keys(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     match depot.datastore.origin_keys.all(origin) {
         Ok(revisions) => {
             let body = json::encode(&revisions.to_json()).unwrap();
-            let mut response = Response::with((status::Ok, body));
-            dont_cache_response(&mut response);
+            let etag = match hash::hash_string(&body) {
+                Ok(etag) => etag,
+                Err(e) => {
+                    error!("list_origin_keys:2, err={:?}", e);
+                    return Ok(Response::with(status::InternalServerError));
+                }
+            };
+            let mut response = match not_modified(&req.headers, &etag) {
+                Some(response) => response,
+                None => {
+                    let mut response = Response::with((status::Ok, body));
+                    set_etag(&mut response, &etag);
+                    response
+                }
+            };
+            revalidate_response(&mut response);
             Ok(response)
         }
         Err(e) => {
@@ -1008,6 +1489,26 @@ fn list_views(depot: &Depot, _req: &mut Request) -> IronResult<Response> {
     Ok(response)
 }
 
+fn show_package_provenance(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let params = req.extensions.get::<Router>().unwrap();
+    let ident = ident_from_params(params);
+
+    match depot.datastore.packages.find(&ident) {
+        Ok(ref pkg) if pkg.has_provenance() => {
+            let body = json::encode(&pkg.get_provenance().to_json()).unwrap();
+            let mut response = Response::with((status::Ok, body));
+            do_cache_response(&mut response);
+            Ok(response)
+        }
+        Ok(_) |
+        Err(dbcache::Error::EntityNotFound) => Ok(Response::with(status::NotFound)),
+        Err(e) => {
+            error!("show_package_provenance:1, err={:?}", e);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
 fn show_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     let params = req.extensions.get::<Router>().unwrap();
     let mut ident = ident_from_params(params);
@@ -1017,7 +1518,7 @@ fn show_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
             match depot.datastore.views.view_pkg_idx.latest(view, &ident.to_string()) {
                 Ok(ident) => {
                     match depot.datastore.packages.find(&ident) {
-                        Ok(pkg) => render_package(&pkg, false),
+                        Ok(pkg) => render_package(req, &pkg, false),
                         Err(dbcache::Error::EntityNotFound) => Ok(Response::with(status::NotFound)),
                         Err(e) => {
                             error!("show_package:1, err={:?}", e);
@@ -1037,7 +1538,7 @@ fn show_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
             match depot.datastore.views.view_pkg_idx.is_member(view, &ident) {
                 Ok(true) => {
                     match depot.datastore.packages.find(&ident) {
-                        Ok(pkg) => render_package(&pkg, false),
+                        Ok(pkg) => render_package(req, &pkg, false),
                         Err(dbcache::Error::EntityNotFound) => Ok(Response::with(status::NotFound)),
                         Err(e) => {
                             error!("show_package:3, err={:?}", e);
@@ -1071,9 +1572,9 @@ fn show_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
                 // If the request was for a fully qualified ident, cache the response, otherwise do
                 // not cache
                 if ident.fully_qualified() {
-                    render_package(&pkg, true)
+                    render_package(req, &pkg, true)
                 } else {
-                    render_package(&pkg, false)
+                    render_package(req, &pkg, false)
                 }
             }
             Err(dbcache::Error::EntityNotFound) => Ok(Response::with(status::NotFound)),
@@ -1108,17 +1609,23 @@ fn search_packages(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     Ok(response)
 }
 
-fn render_package(pkg: &depotsrv::Package, should_cache: bool) -> IronResult<Response> {
-    let body = json::encode(&pkg.to_json()).unwrap();
-    let mut response = Response::with((status::Ok, body));
-    // use set_raw because we're having problems with Iron's Hyper 0.8.x
-    // and the newer Hyper 0.9.4.
-    // TODO: change back to set() once Iron updates to Hyper 0.9.x.
-    response.headers.set_raw("ETag", vec![pkg.get_checksum().to_string().into_bytes()]);
+fn render_package(req: &Request,
+                  pkg: &depotsrv::Package,
+                  should_cache: bool)
+                  -> IronResult<Response> {
+    let mut response = match not_modified(&req.headers, pkg.get_checksum()) {
+        Some(response) => response,
+        None => {
+            let body = json::encode(&pkg.to_json()).unwrap();
+            let mut response = Response::with((status::Ok, body));
+            set_etag(&mut response, pkg.get_checksum());
+            response
+        }
+    };
     if should_cache {
         do_cache_response(&mut response);
     } else {
-        dont_cache_response(&mut response);
+        revalidate_response(&mut response);
     }
     Ok(response)
 }
@@ -1135,13 +1642,26 @@ fn promote_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     match depot.datastore.views.is_member(view) {
         Ok(true) => {
             let ident = ident_from_params(params);
-            if !check_origin_access(&depot, session.get_id(), &ident.get_origin()) {
+            if !session.permits(&Scope::Upload(ident.get_origin().to_string())) ||
+               !check_origin_role(&depot,
+                              session.get_id(),
+                              &ident.get_origin(),
+                              OriginRole::MAINTAINER) {
                 return Ok(Response::with(status::Forbidden));
             }
             match depot.datastore.packages.find(&ident) {
                 Ok(package) => {
-                    depot.datastore.views.associate(view, &package).unwrap();
-                    Ok(Response::with(status::Ok))
+                    match depot.promote(view, &package, session.get_id(), session.get_name()) {
+                        Ok(Promotion::Promoted) => Ok(Response::with(status::Ok)),
+                        Ok(Promotion::Requested(request)) => {
+                            let body = json::encode(&request.to_json()).unwrap();
+                            Ok(Response::with((status::Accepted, body)))
+                        }
+                        Err(e) => {
+                            error!("promote:3, err={:?}", e);
+                            Ok(Response::with(status::InternalServerError))
+                        }
+                    }
                 }
                 Err(dbcache::Error::EntityNotFound) => Ok(Response::with(status::NotFound)),
                 Err(e) => {
@@ -1158,6 +1678,234 @@ fn promote_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn list_promotions(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(depot, req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let params = req.extensions.get::<Router>().unwrap();
+    let view = params.find("view").unwrap();
+    let requests: Vec<depotsrv::PromotionRequest> = try!(depot.pending_promotions(view))
+        .into_iter()
+        .filter(|r| check_origin_access(depot, session.get_id(), r.get_ident().get_origin()))
+        .collect();
+    let body = json::encode(&requests.to_json()).unwrap();
+    let mut response = Response::with((status::Ok, body));
+    dont_cache_response(&mut response);
+    Ok(response)
+}
+
+// The pending promotion request the route names, or the response to give if there is none. A
+// request which has waited too long is recorded as expired and answered with `410 Gone`.
+fn pending_promotion(depot: &Depot,
+                     req: &mut Request)
+                     -> result::Result<depotsrv::PromotionRequest, Response> {
+    let (view, id) = {
+        let params = req.extensions.get::<Router>().unwrap();
+        (params.find("view").unwrap().to_string(), params.find("id").unwrap().parse::<u64>())
+    };
+    let id = match id {
+        Ok(id) => id,
+        Err(_) => return Err(Response::with(status::BadRequest)),
+    };
+    let mut request = match depot.datastore.promotions.find(&id) {
+        Ok(request) => request,
+        Err(dbcache::Error::EntityNotFound) => return Err(Response::with(status::NotFound)),
+        Err(e) => {
+            error!("pending_promotion:1, err={:?}", e);
+            return Err(Response::with(status::InternalServerError));
+        }
+    };
+    if request.get_view() != view {
+        return Err(Response::with(status::NotFound));
+    }
+    req.extensions.insert::<AuditOrigin>(request.get_ident().get_origin().to_string());
+    if request.get_state() != depotsrv::PromotionState::PENDING {
+        return Err(Response::with(status::Conflict));
+    }
+    if depot.promotion_expired(&request) {
+        request.set_state(depotsrv::PromotionState::EXPIRED);
+        if let Err(e) = depot.datastore.promotions.close(&request) {
+            error!("pending_promotion:2, err={:?}", e);
+        }
+        return Err(Response::with(status::Gone));
+    }
+    Ok(request)
+}
+
+fn approve_promotion(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(depot, req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let mut request = match pending_promotion(depot, req) {
+        Ok(request) => request,
+        Err(response) => return Ok(response),
+    };
+    // The whole point of a protected view is that nobody can approve their own promotion.
+    if !may_approve(&session, &request) ||
+       !check_origin_role(&depot,
+                          session.get_id(),
+                          request.get_ident().get_origin(),
+                          OriginRole::OWNER) {
+        return Ok(Response::with(status::Forbidden));
+    }
+    let package = match depot.datastore.packages.find(request.get_ident()) {
+        Ok(package) => package,
+        Err(dbcache::Error::EntityNotFound) => return Ok(Response::with(status::NotFound)),
+        Err(e) => {
+            error!("approve_promotion:2, err={:?}", e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    };
+    try!(depot.datastore.views.associate(request.get_view(), &package));
+    depot.notifier.promoted(request.get_view(), package.get_ident());
+    request.set_state(depotsrv::PromotionState::APPROVED);
+    request.set_approver_id(session.get_id());
+    request.set_approver(session.get_name().to_string());
+    try!(depot.datastore.promotions.close(&request));
+    let body = json::encode(&request.to_json()).unwrap();
+    Ok(Response::with((status::Ok, body)))
+}
+
+/// Refuse a pending promotion request. Owners of the package's origin who could approve it may
+/// reject it, and the requester may withdraw it.
+fn reject_promotion(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(depot, req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let mut request = match pending_promotion(depot, req) {
+        Ok(request) => request,
+        Err(response) => return Ok(response),
+    };
+    if request.get_requester_id() != session.get_id() &&
+       (!may_approve(&session, &request) ||
+        !check_origin_role(&depot,
+                           session.get_id(),
+                           request.get_ident().get_origin(),
+                           OriginRole::OWNER)) {
+        return Ok(Response::with(status::Forbidden));
+    }
+    request.set_state(depotsrv::PromotionState::REJECTED);
+    request.set_approver_id(session.get_id());
+    request.set_approver(session.get_name().to_string());
+    try!(depot.datastore.promotions.close(&request));
+    depot.notifier.promotion_rejected(request.get_view(), request.get_ident());
+    let body = json::encode(&request.to_json()).unwrap();
+    Ok(Response::with((status::Ok, body)))
+}
+
+/// Whether the session may approve the given promotion request, as far as the session alone can
+/// tell. Nobody approves their own request, and approving needs the same upload scope on the
+/// package's origin as promoting it directly does.
+fn may_approve(session: &Session, request: &depotsrv::PromotionRequest) -> bool {
+    request.get_requester_id() != session.get_id() &&
+    session.permits(&Scope::Upload(request.get_ident().get_origin().to_string()))
+}
+
+fn watch_origin(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let view = extract_query_value("view", req);
+    let after = match extract_query_value("after", req).map(|a| a.parse::<u64>()) {
+        Some(Ok(after)) => Some(after),
+        Some(Err(_)) => return Ok(Response::with(status::BadRequest)),
+        None => None,
+    };
+    let max_wait_ms = clock::duration_ms(depot.config.watch_max_wait) as u64;
+    let wait_ms = match extract_query_value("timeout_ms", req).map(|t| t.parse::<u64>()) {
+        Some(Ok(wait_ms)) if wait_ms < max_wait_ms => wait_ms,
+        Some(Ok(_)) | None => max_wait_ms,
+        Some(Err(_)) => return Ok(Response::with(status::BadRequest)),
+    };
+    let params = req.extensions.get::<Router>().unwrap();
+    let origin = params.find("origin").unwrap();
+    match depot.notifier.watch(origin,
+                               view.as_ref().map(|v| v.as_str()),
+                               after,
+                               Duration::from_millis(wait_ms)) {
+        Some(events) => {
+            let mut response = Response::with((status::Ok, json::encode(&events).unwrap()));
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        None => Ok(Response::with(status::ServiceUnavailable)),
+    }
+}
+
+// Run a mutating handler and record its outcome, along with who asked for it, in the audit log.
+fn audited(depot: &Depot,
+           req: &mut Request,
+           action: &str,
+           handler: fn(&Depot, &mut Request) -> IronResult<Response>)
+           -> IronResult<Response> {
+    let (origin, ident, view) = {
+        let params = req.extensions.get::<Router>().unwrap();
+        let ident = match params.find("pkg") {
+            Some(_) => Some(ident_from_params(params).to_string()),
+            None => None,
+        };
+        (params.find("origin").map(|o| o.to_string()),
+         ident,
+         params.find("view").map(|v| v.to_string()))
+    };
+    let result = handler(depot, req);
+    let code = match result {
+        Ok(ref response) => response.status,
+        Err(ref err) => err.response.status,
+    };
+    let code = code.unwrap_or(status::InternalServerError);
+    let (actor, actor_id) = match req.extensions.get::<Actor>() {
+        Some(session) => (Some(session.get_name().to_string()), Some(session.get_id())),
+        None => (None, None),
+    };
+    let entry = audit::Entry {
+        timestamp: 0,
+        action: action.to_string(),
+        actor: actor,
+        actor_id: actor_id,
+        origin: origin.or_else(|| req.extensions.get::<AuditOrigin>().cloned()),
+        ident: ident,
+        source_ip: req.remote_addr.ip().to_string(),
+        status: code.to_u16(),
+        outcome: if code.is_success() { "success" } else { "failure" }.to_string(),
+        view: view,
+        job_id: None,
+    };
+    if let Err(e) = depot.audit.record(entry) {
+        error!("audit log write failed, action={}, err={}", action, e);
+    }
+    result
+}
+
+fn audit_log(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let session = match authorize(depot, req, &Scope::Admin) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    if !depot.config.admins.iter().any(|a| a == session.get_name()) {
+        return Ok(Response::with(status::Forbidden));
+    }
+    let limit = match extract_query_value("limit", req) {
+        Some(limit) => {
+            match limit.parse::<usize>() {
+                Ok(limit) if limit > 0 => cmp::min(limit, AUDIT_LIMIT_MAX),
+                _ => return Ok(Response::with(status::BadRequest)),
+            }
+        }
+        None => AUDIT_LIMIT_DEFAULT,
+    };
+    let query = audit::Query {
+        action: extract_query_value("action", req),
+        actor: extract_query_value("actor", req),
+        origin: extract_query_value("origin", req),
+        limit: limit,
+    };
+    let entries = try!(depot.audit.query(&query));
+    let mut response = Response::with((status::Ok, json::encode(&entries).unwrap()));
+    dont_cache_response(&mut response);
+    Ok(response)
+}
+
 fn ident_from_params(params: &Params) -> depotsrv::PackageIdent {
     let mut ident = depotsrv::PackageIdent::new();
     ident.set_origin(params.find("origin").unwrap().to_string());
@@ -1213,6 +1961,83 @@ fn extract_query_value(key: &str, req: &mut Request) -> Option<String> {
     }
 }
 
+/// The byte range of a representation of `len` bytes requested by a `Range` header, as the
+/// offsets of its first and last bytes. `None` means the whole representation should be sent,
+/// either because no range was requested, more than one was, or an `If-Range` header names an
+/// older representation. A range which starts past the end cannot be satisfied.
+fn byte_range(req: &Request, etag: &str, len: u64) -> result::Result<Option<(u64, u64)>, ()> {
+    let raw = match req.headers.get_raw("Range") {
+        Some(values) if values.len() == 1 => String::from_utf8_lossy(&values[0]).into_owned(),
+        _ => return Ok(None),
+    };
+    if let Some(values) = req.headers.get_raw("If-Range") {
+        if !values.iter().any(|value| String::from_utf8_lossy(value).trim_matches('"') == etag) {
+            return Ok(None);
+        }
+    }
+    let raw = raw.trim();
+    if !raw.starts_with("bytes=") {
+        return Ok(None);
+    }
+    let spec = &raw["bytes=".len()..];
+    if spec.contains(',') || len == 0 {
+        return Ok(None);
+    }
+    let (first, last) = match spec.find('-') {
+        Some(idx) => (spec[..idx].trim(), spec[idx + 1..].trim()),
+        None => return Ok(None),
+    };
+    let range = match (first.parse::<u64>(), last.parse::<u64>()) {
+        (Ok(first), Ok(last)) if first <= last => (first, cmp::min(last, len - 1)),
+        (Ok(first), Err(_)) if last.is_empty() => (first, len - 1),
+        (Err(_), Ok(suffix)) if first.is_empty() && suffix > 0 => {
+            (len.saturating_sub(suffix), len - 1)
+        }
+        _ => return Ok(None),
+    };
+    if range.0 >= len {
+        return Err(());
+    }
+    Ok(Some(range))
+}
+
+/// Whether a request's `If-None-Match` header matches the entity tag of the current
+/// representation, meaning the client already has it.
+fn etag_matches(headers: &headers::Headers, etag: &str) -> bool {
+    match headers.get_raw("If-None-Match") {
+        Some(values) => {
+            values.iter().any(|value| {
+                String::from_utf8_lossy(value).split(',').any(|tag| {
+                    let tag = tag.trim();
+                    let tag = if tag.starts_with("W/") { &tag[2..] } else { tag };
+                    tag == "*" || tag.trim_matches('"') == etag
+                })
+            })
+        }
+        None => false,
+    }
+}
+
+/// Tag the response with the entity tag of its content.
+fn set_etag(response: &mut Response, etag: &str) {
+    // use set_raw because we're having problems with Iron's Hyper 0.8.x
+    // and the newer Hyper 0.9.4.
+    // TODO: change back to set() once Iron updates to Hyper 0.9.x.
+    response.headers.set_raw("ETag", vec![format!("\"{}\"", etag).into_bytes()]);
+}
+
+/// A `304 Not Modified` response if the client sending the request headers already has the
+/// representation with the given entity tag.
+fn not_modified(headers: &headers::Headers, etag: &str) -> Option<Response> {
+    if etag_matches(headers, etag) {
+        let mut response = Response::with(status::NotModified);
+        set_etag(&mut response, etag);
+        Some(response)
+    } else {
+        None
+    }
+}
+
 fn do_cache_response(response: &mut Response) {
     response.headers.set_raw("Cache-Control",
                              vec![format!("public, max-age={}", ONE_YEAR_IN_SECS).into_bytes()]);
@@ -1223,19 +2048,9 @@ fn dont_cache_response(response: &mut Response) {
                              vec![format!("private, no-cache, no-store").into_bytes()]);
 }
 
-struct Cors;
-
-impl AfterMiddleware for Cors {
-    fn after(&self, _req: &mut Request, mut res: Response) -> IronResult<Response> {
-        res.headers.set(headers::AccessControlAllowOrigin::Any);
-        res.headers
-            .set(headers::AccessControlExposeHeaders(vec![UniCase("content-range".to_owned()),
-                                                          UniCase("next-range".to_owned())]));
-        res.headers
-            .set(headers::AccessControlAllowHeaders(vec![UniCase("authorization".to_owned()),
-                                                         UniCase("range".to_owned())]));
-        Ok(res)
-    }
+fn revalidate_response(response: &mut Response) {
+    response.headers.set_raw("Cache-Control",
+                             vec![format!("private, no-cache").into_bytes()]);
 }
 
 pub fn router(depot: Arc<Depot>) -> Result<Chain> {
@@ -1266,6 +2081,15 @@ pub fn router(depot: Arc<Depot>) -> Result<Chain> {
     let depot25 = depot.clone();
     let depot26 = depot.clone();
     let depot27 = depot.clone();
+    let depot28 = depot.clone();
+    let depot29 = depot.clone();
+    let depot30 = depot.clone();
+    let depot31 = depot.clone();
+    let depot32 = depot.clone();
+    let depot33 = depot.clone();
+    let depot34 = depot.clone();
+    let depot35 = depot.clone();
+    let depot36 = depot.clone();
 
     let router = router!(
         get "/views" => move |r: &mut Request| list_views(&depot1, r),
@@ -1284,7 +2108,18 @@ pub fn router(depot: Arc<Depot>) -> Result<Chain> {
             move |r: &mut Request| show_package(&depot7, r)
         },
         post "/views/:view/pkgs/:origin/:pkg/:version/:release/promote" => {
-            move |r: &mut Request| promote_package(&depot8, r)
+            move |r: &mut Request| audited(&depot8, r, "package.promote", promote_package)
+        },
+        get "/views/:view/promotions" => move |r: &mut Request| list_promotions(&depot34, r),
+        post "/views/:view/promotions/:id/approve" => {
+            move |r: &mut Request| {
+                audited(&depot35, r, "package.promotion.approve", approve_promotion)
+            }
+        },
+        post "/views/:view/promotions/:id/reject" => {
+            move |r: &mut Request| {
+                audited(&depot36, r, "package.promotion.reject", reject_promotion)
+            }
         },
 
         get "/pkgs/search/:query" => move |r: &mut Request| search_packages(&depot9, r),
@@ -1302,11 +2137,16 @@ pub fn router(depot: Arc<Depot>) -> Result<Chain> {
         get "/pkgs/:origin/:pkg/:version/:release/download" => {
             move |r: &mut Request| download_package(&depot16, r)
         },
+        get "/pkgs/:origin/:pkg/:version/:release/provenance" => {
+            move |r: &mut Request| show_package_provenance(&depot31, r)
+        },
         post "/pkgs/:origin/:pkg/:version/:release" => {
-            move |r: &mut Request| upload_package(&depot17, r)
+            move |r: &mut Request| audited(&depot17, r, "package.upload", upload_package)
         },
 
-        post "/origins" => move |r: &mut Request| origin_create(&depot18, r),
+        post "/origins" => {
+            move |r: &mut Request| audited(&depot18, r, "origin.create", origin_create)
+        },
         // TODO
         //delete "/origins/:origin" => move |r: &mut Request| origin_delete(&depot17, r),
 
@@ -1320,13 +2160,15 @@ pub fn router(depot: Arc<Depot>) -> Result<Chain> {
             move |r: &mut Request| download_origin_key(&depot22, r)
         },
         post "/origins/:origin/keys/:revision" => {
-            move |r: &mut Request| upload_origin_key(&depot23, r)
+            move |r: &mut Request| audited(&depot23, r, "origin.key.upload", upload_origin_key)
         },
         post "/origins/:origin/secret_keys/:revision" => {
-            move |r: &mut Request| upload_origin_secret_key(&depot24, r)
+            move |r: &mut Request| {
+                audited(&depot24, r, "origin.secret_key.upload", upload_origin_secret_key)
+            }
         },
         post "/origins/:origin/users/:username/invitations" => {
-            move |r: &mut Request| invite_to_origin(&depot25, r)
+            move |r: &mut Request| audited(&depot25, r, "origin.invite", invite_to_origin)
         },
         get "/origins/:origin/invitations" => {
             move |r: &mut Request| list_origin_invitations(&depot26, r)
@@ -1334,9 +2176,23 @@ pub fn router(depot: Arc<Depot>) -> Result<Chain> {
         get "/origins/:origin/users" => {
             move |r: &mut Request| list_origin_members(&depot27, r)
         },
+        put "/origins/:origin/users/:username/role/:role" => {
+            move |r: &mut Request| {
+                audited(&depot30, r, "origin.member.role", set_origin_member_role)
+            }
+        },
+        get "/origins/:origin/events" => move |r: &mut Request| watch_origin(&depot29, r),
+        get "/origins/:origin/export" => {
+            move |r: &mut Request| audited(&depot32, r, "origin.export", export_origin)
+        },
+        post "/origins/:origin/import" => {
+            move |r: &mut Request| audited(&depot33, r, "origin.import", import_origin)
+        },
+
+        get "/audit" => move |r: &mut Request| audit_log(&depot28, r),
     );
     let mut chain = Chain::new(router);
-    chain.link_after(Cors);
+    chain.link_around(Cors::new(&depot.config));
     Ok(chain)
 }
 
@@ -1346,13 +2202,26 @@ pub fn run(config: Config) -> Result<()> {
     let ctx = Arc::new(BrokerContext::new());
     let ctx1 = ctx.clone();
     let depot = try!(Depot::new(config.clone(), ctx));
+    let promoter = try!(promote::run(depot.clone()));
     let v1 = try!(router(depot.clone()));
-    let broker = Broker::run(Depot::net_ident(), ctx1, &config.route_addrs().clone());
+    hmac::configure(&config);
+    let cache = CacheConfig::new(config.reply_cache_ttl)
+        .cache("OriginGet")
+        .invalidate_on("OriginCreate", "OriginGet")
+        .invalidate_on("OriginDelete", "OriginGet");
+    let broker = Broker::run_with_cache(Depot::net_ident(),
+                                        ctx1,
+                                        &config.route_addrs().clone(),
+                                        config.router_auth(),
+                                        cache);
 
     let mut mount = Mount::new();
     mount.mount("/v1", v1);
-    Iron::new(mount).http(listen_addr).unwrap();
+    Iron::new(mount).listen_with(listen_addr, HTTP_THREAD_COUNT, Protocol::Http, None).unwrap();
     broker.join().unwrap();
+    if let Some(promoter) = promoter {
+        promoter.join().unwrap();
+    }
     Ok(())
 }
 
@@ -1364,3 +2233,157 @@ impl From<Error> for IronError {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use iron::headers::Headers;
+    use iron::status;
+    use protobuf::RepeatedField;
+    use protocol::depotsrv;
+    use protocol::sessionsrv::Session;
+
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::path::PathBuf;
+
+    use hab_core::crypto::hash;
+    use tempdir::TempDir;
+
+    use super::{etag_matches, install_archive, may_approve, not_modified};
+
+    const POSSUMS: &'static str = "happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart";
+
+    // Stage a copy of the fixture archive as an upload would, returning its path, checksum and
+    // the path it would be installed at.
+    fn stage(dir: &TempDir) -> (PathBuf, String, PathBuf) {
+        let fixture = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../core/tests/fixtures")
+            .join(POSSUMS);
+        let filename = dir.path().join(POSSUMS);
+        let staged = dir.path().join(format!("{}.tmp", POSSUMS));
+        fs::copy(&fixture, &staged).unwrap();
+        let checksum = hash::hash_file(&staged).unwrap();
+        (staged, checksum, filename)
+    }
+
+    fn possums(release: &str) -> depotsrv::PackageIdent {
+        let mut ident = depotsrv::PackageIdent::new();
+        ident.set_origin("happyhumans".to_string());
+        ident.set_name("possums".to_string());
+        ident.set_version("8.1.4".to_string());
+        ident.set_release(release.to_string());
+        ident
+    }
+
+    fn session(id: u64, scopes: &[&str]) -> Session {
+        let mut session = Session::new();
+        session.set_id(id);
+        session.set_scopes(RepeatedField::from_vec(scopes.iter().map(|s| s.to_string()).collect()));
+        session
+    }
+
+    fn request(requester_id: u64, origin: &str) -> depotsrv::PromotionRequest {
+        let mut ident = depotsrv::PackageIdent::new();
+        ident.set_origin(origin.to_string());
+        ident.set_name("redis".to_string());
+        let mut request = depotsrv::PromotionRequest::new();
+        request.set_ident(ident);
+        request.set_requester_id(requester_id);
+        request
+    }
+
+    #[test]
+    fn install_archive_moves_a_valid_upload_into_place() {
+        let dir = TempDir::new("depot-upload").unwrap();
+        let (staged, checksum, filename) = stage(&dir);
+        let ident = possums("20160427165340");
+        let object = install_archive(&staged, &checksum, &checksum, &ident, &filename).unwrap();
+        assert_eq!(object.get_ident().get_name(), "possums");
+        assert!(filename.is_file());
+        assert!(!staged.exists());
+    }
+
+    #[test]
+    fn install_archive_removes_an_upload_with_the_wrong_checksum() {
+        let dir = TempDir::new("depot-upload").unwrap();
+        let (staged, checksum, filename) = stage(&dir);
+        let ident = possums("20160427165340");
+        let result = install_archive(&staged, &checksum, "0000", &ident, &filename);
+        assert_eq!(result.err(), Some(status::UnprocessableEntity));
+        assert!(!filename.exists());
+        assert!(!staged.exists());
+    }
+
+    #[test]
+    fn install_archive_removes_an_upload_of_another_package() {
+        let dir = TempDir::new("depot-upload").unwrap();
+        let (staged, checksum, filename) = stage(&dir);
+        let ident = possums("20170101000000");
+        let result = install_archive(&staged, &checksum, &checksum, &ident, &filename);
+        assert_eq!(result.err(), Some(status::UnprocessableEntity));
+        assert!(!filename.exists());
+        assert!(!staged.exists());
+    }
+
+    #[test]
+    fn install_archive_removes_an_upload_which_is_not_an_archive() {
+        let dir = TempDir::new("depot-upload").unwrap();
+        let (staged, _, filename) = stage(&dir);
+        File::create(&staged).unwrap().write_all(b"not an archive").unwrap();
+        let checksum = hash::hash_file(&staged).unwrap();
+        let ident = possums("20160427165340");
+        let result = install_archive(&staged, &checksum, &checksum, &ident, &filename);
+        assert_eq!(result.err(), Some(status::UnprocessableEntity));
+        assert!(!filename.exists());
+        assert!(!staged.exists());
+    }
+
+    #[test]
+    fn may_approve_with_upload_scope_for_the_origin() {
+        assert!(may_approve(&session(2, &["upload:origin/core"]), &request(1, "core")));
+        assert!(may_approve(&session(2, &["upload:origin/*"]), &request(1, "core")));
+    }
+
+    #[test]
+    fn may_not_approve_without_upload_scope_for_the_origin() {
+        assert!(!may_approve(&session(2, &["read"]), &request(1, "core")));
+        assert!(!may_approve(&session(2, &["upload:origin/other"]), &request(1, "core")));
+        assert!(!may_approve(&session(2, &[]), &request(1, "core")));
+    }
+
+    #[test]
+    fn may_not_approve_own_request() {
+        assert!(!may_approve(&session(1, &["admin"]), &request(1, "core")));
+    }
+
+    fn if_none_match(value: &str) -> Headers {
+        let mut headers = Headers::new();
+        headers.set_raw("If-None-Match", vec![value.as_bytes().to_vec()]);
+        headers
+    }
+
+    #[test]
+    fn etag_matches_strong_weak_and_listed_tags() {
+        assert!(etag_matches(&if_none_match("\"abc\""), "abc"));
+        assert!(etag_matches(&if_none_match("W/\"abc\""), "abc"));
+        assert!(etag_matches(&if_none_match("\"xyz\", \"abc\""), "abc"));
+        assert!(etag_matches(&if_none_match("*"), "abc"));
+    }
+
+    #[test]
+    fn etag_does_not_match_other_tags() {
+        assert!(!etag_matches(&if_none_match("\"xyz\""), "abc"));
+        assert!(!etag_matches(&if_none_match("\"abcd\""), "abc"));
+        assert!(!etag_matches(&Headers::new(), "abc"));
+    }
+
+    #[test]
+    fn not_modified_answers_304_with_the_etag() {
+        let response = not_modified(&if_none_match("\"abc\""), "abc").unwrap();
+        assert_eq!(response.status, Some(status::NotModified));
+        assert_eq!(response.headers.get_raw("ETag"),
+                   Some(&[b"\"abc\"".to_vec()][..]));
+        assert!(not_modified(&if_none_match("\"xyz\""), "abc").is_none());
+        assert!(not_modified(&Headers::new(), "abc").is_none());
+    }
+}
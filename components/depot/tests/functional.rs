@@ -13,6 +13,7 @@
 // limitations under the License.
 
 extern crate regex;
+extern crate tempdir;
 extern crate time;
 extern crate hyper;
 extern crate url;
@@ -124,7 +125,7 @@ mod support;
 
 #[cfg(feature = "functional")]
 pub mod depot_tests {
-    use support::{command, docker, setup};
+    use support::{command, depot, setup};
 
     #[test]
     #[ignore]
@@ -132,13 +133,13 @@ pub mod depot_tests {
         setup::origin_setup();
         setup::key_install();
         setup::simple_service();
-        let d = docker::depot("test/simple_service");
-        let ipaddress = d.ipaddress();
+        let d = depot::start("test/simple_service");
+        let url = d.url();
 
         let mut upload = command::sup(&["upload",
                                         "test/simple_service",
                                         "-u",
-                                        &format!("http://{}:9632", ipaddress)])
+                                        &url])
             .unwrap();
         upload.wait_with_output();
         assert_cmd_exit_code!(upload, [0]);
@@ -148,9 +149,40 @@ pub mod depot_tests {
         let mut install = command::sup(&["install",
                                          "test/simple_service",
                                          "-u",
-                                         &format!("http://{}:9632", ipaddress)])
+                                         &url])
             .unwrap();
         install.wait_with_output();
         assert_cmd_exit_code!(install, [0]);
     }
 }
+
+#[cfg(feature = "functional")]
+pub mod doctor_tests {
+    use std::fs::{self, File};
+    use std::io::Write;
+
+    use tempdir::TempDir;
+
+    use support::{command, path};
+
+    /// Needs a Redis server listening on its default address, as a local depot does.
+    #[test]
+    fn verify_reports_every_bad_archive() {
+        let dir = poerr!(TempDir::new("hab-depot"));
+        let archives = dir.path().join("pkgs").join("00").join("01");
+        poerr!(fs::create_dir_all(&archives));
+        for name in &["garbage.hart", "rubbish.hart"] {
+            let mut garbage = poerr!(File::create(archives.join(name)));
+            poerr!(garbage.write_all(b"this is not an archive"));
+        }
+        let depot_path = dir.path().to_string_lossy().into_owned();
+
+        let mut verify = poerr!(command::run(&path::depot(), &["--path", &depot_path, "verify"]));
+        verify.wait_with_output();
+        assert_cmd_exit_code!(verify, [1]);
+        assert_regex!(verify.stdout(),
+                      r"FAILED ArchiveVerify\(.+garbage\.hart.*\): BadArchive");
+        assert_regex!(verify.stdout(),
+                      r"FAILED ArchiveVerify\(.+rubbish\.hart.*\): BadArchive");
+    }
+}
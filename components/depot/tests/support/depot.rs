@@ -0,0 +1,108 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Start a depot for a test, either in a docker container or, when `HAB_TEST_LOCAL` is set, as a
+//! local child process serving packages out of a temporary directory.
+//!
+//! A local depot still needs a Redis server listening on its default address.
+
+use std::net::{TcpListener, TcpStream};
+use std::process::Child;
+use std::thread;
+use std::time::Duration;
+
+use tempdir::TempDir;
+
+use super::{command, docker, path, setup};
+use super::docker::Docker;
+
+/// Seconds to wait for a local depot to begin accepting connections.
+const START_TIMEOUT_SECS: u64 = 30;
+
+pub enum Depot {
+    Docker(Docker),
+    Local(LocalDepot),
+}
+
+impl Depot {
+    /// URL of the running depot's HTTP API.
+    pub fn url(&self) -> String {
+        match *self {
+            Depot::Docker(ref d) => format!("http://{}:9632", d.ipaddress()),
+            Depot::Local(ref d) => format!("http://127.0.0.1:{}", d.port),
+        }
+    }
+}
+
+/// Start a depot. `image` is the docker image to run if the tests are not in local mode.
+pub fn start(image: &str) -> Depot {
+    if setup::local_mode() {
+        Depot::Local(LocalDepot::new())
+    } else {
+        Depot::Docker(docker::depot(image))
+    }
+}
+
+pub struct LocalDepot {
+    pub port: u16,
+    pub dir: TempDir,
+    child: Child,
+}
+
+impl LocalDepot {
+    pub fn new() -> LocalDepot {
+        let dir = TempDir::new("hab-depot").unwrap_or_else(|x| panic!("{:?}", x));
+        let port = free_port();
+        let depot_path = dir.path().to_string_lossy().into_owned();
+        let port_arg = port.to_string();
+        let cmd = command::command(&path::depot(),
+                                   &["--path", &depot_path, "start", "--port", &port_arg]);
+        let mut cmd = command::spawn(cmd).unwrap_or_else(|x| panic!("{:?}", x));
+        let child = cmd.child.take().unwrap();
+        println!("{}: Local depot {} serving {} on port {}",
+                 thread::current().name().unwrap_or("main"),
+                 child.id(),
+                 depot_path,
+                 port);
+        let depot = LocalDepot {
+            port: port,
+            dir: dir,
+            child: child,
+        };
+        depot.wait_until_listening();
+        depot
+    }
+
+    fn wait_until_listening(&self) {
+        for _ in 0..(START_TIMEOUT_SECS * 2) {
+            if TcpStream::connect(("127.0.0.1", self.port)).is_ok() {
+                return;
+            }
+            thread::sleep(Duration::from_millis(500));
+        }
+        panic!("Local depot not listening after {} seconds",
+               START_TIMEOUT_SECS);
+    }
+}
+
+impl Drop for LocalDepot {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+fn free_port() -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap_or_else(|x| panic!("{:?}", x));
+    listener.local_addr().unwrap().port()
+}
@@ -15,4 +15,5 @@
 pub mod setup;
 pub mod path;
 pub mod command;
+pub mod depot;
 pub mod docker;
@@ -39,3 +39,7 @@ pub fn fixture_as_string(name: &str) -> String {
 pub fn sup() -> String {
     root().parent().unwrap().join("target/debug/hab-sup").to_string_lossy().into_owned()
 }
+
+pub fn depot() -> String {
+    root().parent().unwrap().join("target/debug/hab-depot").to_string_lossy().into_owned()
+}
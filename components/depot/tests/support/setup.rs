@@ -15,6 +15,14 @@
 use std::sync::{Once, ONCE_INIT};
 use std::env;
 
+/// Setting this environment variable runs the depot as a local child process instead of in a
+/// docker container.
+pub const LOCAL_ENVVAR: &'static str = "HAB_TEST_LOCAL";
+
+pub fn local_mode() -> bool {
+    env::var_os(LOCAL_ENVVAR).is_some()
+}
+
 pub fn origin_setup() {
     env::set_var("HAB_CACHE_KEY_PATH", super::path::key_cache());
 }
@@ -31,7 +39,9 @@ pub fn simple_service() {
         if !simple_service.status.unwrap().success() {
             panic!("Failed to build simple service");
         }
-        dockerize("test/simple_service");
+        if !local_mode() {
+            dockerize("test/simple_service");
+        }
     });
 }
 
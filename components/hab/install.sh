@@ -52,4 +52,4 @@ tar xf "$archive" -C "$workdir"
 archive_dir="$(echo $archive | sed 's/.tar.gz$//')"
 # Install latest hab release using the extracted version and add/update symlink
 "$archive_dir/hab" install core/hab
-"$archive_dir/hab" pkg binlink core/hab hab
+"$archive_dir/hab" pkg binlink --force --dest /bin core/hab hab
@@ -26,6 +26,10 @@ pub fn get() -> App<'static, 'static> {
         .about("Alias for 'config apply'")
         .aliases(&["ap", "app", "appl"])
         .setting(AppSettings::Hidden);
+    let alias_export = sub_pkg_export()
+        .about("Alias for 'pkg export'")
+        .aliases(&["exp", "expo", "expor"])
+        .setting(AppSettings::Hidden);
     let alias_install = sub_pkg_install()
         .about("Alias for 'pkg install'")
         .aliases(&["i", "in", "ins", "inst", "insta", "instal"])
@@ -42,11 +46,28 @@ pub fn get() -> App<'static, 'static> {
         (author: "\nAuthors: The Habitat Maintainers <humans@habitat.sh>\n")
         (@setting VersionlessSubcommands)
         (@setting ArgRequiredElseHelp)
+        (@arg JSON: -j --json +global conflicts_with[QUIET]
+            "Print the outcome of the command as JSON, where it has one, and nothing else")
+        (@arg QUIET: -q --quiet +global "Print nothing but errors")
+        (@subcommand census =>
+            (about: "Displays the census of a service group from a local Supervisor")
+            (aliases: &["ce", "cen", "cens", "censu"])
+            (@arg SERVICE_GROUP: +required +takes_value
+                "Target service group (ex: redis.default)")
+            (@arg SIDECAR_URL: -u --url +takes_value
+                "Use a specific Supervisor HTTP API (default: http://127.0.0.1:9631)")
+        )
         (@subcommand cli =>
             (about: "Commands relating to Habitat runtime config")
             (aliases: &["cl"])
             (@setting ArgRequiredElseHelp)
             (subcommand: sub_cli_setup().aliases(&["s", "se", "set", "setu"]))
+            (@subcommand completions =>
+                (about: "Generates a shell completion script for hab and writes it to stdout")
+                (aliases: &["c", "co", "com", "comp"])
+                (@arg SHELL: +required +takes_value {valid_shell}
+                    "The shell to generate completions for (bash or zsh)")
+            )
         )
         (@subcommand config =>
             (about: "Commands relating to Habitat runtime config")
@@ -75,6 +96,51 @@ pub fn get() -> App<'static, 'static> {
                     "Ring key name, which will encrypt communication messages")
             )
         )
+        (@subcommand job =>
+            (about: "Commands relating to Habitat build jobs")
+            (aliases: &["j", "jo"])
+            (@setting ArgRequiredElseHelp)
+            (@subcommand log =>
+                (about: "Prints the build log of a job")
+                (aliases: &["l", "lo"])
+                (@arg JOB_ID: +required +takes_value "The job id")
+                (@arg BLDR_URL: -u --url +takes_value {valid_url} "Use a specific Builder API URL")
+                (@arg AUTH_TOKEN: -z --auth +takes_value
+                    "Authentication token for the Builder API")
+                (@arg FOLLOW: -f --follow
+                    "Keep printing the log as it is written until the job completes")
+            )
+            (@subcommand schedule =>
+                (about: "Commands relating to recurring builds")
+                (aliases: &["s", "sc", "sch", "sche", "sched"])
+                (@setting ArgRequiredElseHelp)
+                (@subcommand create =>
+                    (about: "Schedules recurring builds of a project, replacing any schedule \
+                        it already has")
+                    (aliases: &["c", "cr", "cre", "crea", "creat"])
+                    (@arg PROJECT: +required +takes_value "The project to build")
+                    (@arg CRON: +required +takes_value
+                        "When to build, as a five field cron expression evaluated in UTC \
+                        (ex: \"0 3 * * *\")")
+                    (@arg PRIORITY: --priority +takes_value
+                        "Priority of the scheduled jobs, from 0 to 9 (default: 0)")
+                    (@arg REQUIRES: --requires +takes_value +multiple
+                        "Capability tags a worker must have to run the scheduled jobs")
+                    (@arg BLDR_URL: -u --url +takes_value {valid_url}
+                        "Use a specific Builder API URL")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value
+                        "Authentication token for the Builder API")
+                )
+                (@subcommand list =>
+                    (about: "Lists your build schedules")
+                    (aliases: &["l", "li", "lis"])
+                    (@arg BLDR_URL: -u --url +takes_value {valid_url}
+                        "Use a specific Builder API URL")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value
+                        "Authentication token for the Builder API")
+                )
+            )
+        )
         (@subcommand origin =>
             (about: "Commands relating to Habitat origin keys")
             (aliases: &["o", "or", "ori", "orig", "origi"])
@@ -134,12 +200,22 @@ pub fn get() -> App<'static, 'static> {
                 (aliases: &["bi", "bin", "binl", "binli", "binlin"])
                 (@arg PKG_IDENT: +required +takes_value
                     "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
-                (@arg BINARY: +required +takes_value
-                    "The command to symlink (ex: bash)")
+                (@arg BINARY: +takes_value
+                    "The command to symlink (ex: bash, default: all binaries in the package)")
                 (@arg DEST_DIR: -d --dest +takes_value
-                    "Sets the destination directory (default: /bin)")
+                    "Sets the destination directory (default: /usr/local/bin)")
+                (@arg FORCE: -f --force "Overwrites existing files or symlinks at the destination")
             )
             (subcommand: sub_pkg_build())
+            (@subcommand diff =>
+                (about: "Compares the files, dependencies, and configuration of two installed \
+                    packages")
+                (aliases: &["d", "di", "dif"])
+                (@arg PKG_IDENT_A: +required +takes_value
+                    "A package identifier (ex: core/redis/3.0.7/20160614230104)")
+                (@arg PKG_IDENT_B: +required +takes_value
+                    "A package identifier to compare with (ex: core/redis/3.2.1/20160725012435)")
+            )
             (@subcommand exec =>
                 (about: "Executes a command using the 'PATH' context of an installed package")
                 (aliases: &["exe"])
@@ -150,13 +226,7 @@ pub fn get() -> App<'static, 'static> {
                 (@arg ARGS: +takes_value +multiple
                     "Arguments to the command (ex: -l /tmp)")
             )
-            (@subcommand export =>
-                (about: "Exports the package to the specified format")
-                (aliases: &["exp"])
-                (@arg FORMAT: +required +takes_value "The export format (ex: docker, aci)")
-                (@arg PKG_IDENT: +required +takes_value
-                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
-            )
+            (subcommand: sub_pkg_export().aliases(&["exp"]))
             (@subcommand hash =>
                 (about: "Generates a blake2b hashsum from a target at any given filepath")
                 (aliases: &["ha", "has"])
@@ -164,12 +234,28 @@ pub fn get() -> App<'static, 'static> {
             )
             (subcommand: sub_pkg_install().aliases(
                 &["i", "in", "ins", "inst", "insta", "instal"]))
+            (@subcommand list =>
+                (about: "Lists the releases of an origin's packages in a Depot")
+                (aliases: &["li", "lis"])
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                    "Use a specific Depot URL, or a file:// URL of a local Depot directory")
+                (@arg ORIGIN_OR_NAME: +required {valid_origin_or_name}
+                    "An origin, or an origin and package name (ex: core, core/redis)")
+            )
             (@subcommand path =>
                 (about: "Prints the path to a specific installed release of a package")
                 (aliases: &["p", "pa", "pat"])
                 (@arg PKG_IDENT: +required +takes_value
                     "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
             )
+            (@subcommand show =>
+                (about: "Shows the metadata of a package release in a Depot")
+                (aliases: &["sh", "sho"])
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                    "Use a specific Depot URL, or a file:// URL of a local Depot directory")
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+            )
             (@subcommand sign =>
                 (about: "Signs an archive with an origin key, generating a Habitat Artifact")
                 (aliases: &["s", "si", "sig"])
@@ -181,18 +267,37 @@ pub fn get() -> App<'static, 'static> {
                     "The destination path to the signed Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
             )
+            (@subcommand uninstall =>
+                (about: "Stops and removes an installed package along with its binlinks")
+                (aliases: &["un", "uni", "unin", "unins", "uninst", "uninsta", "uninstal"])
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg DEST_DIR: -d --dest +takes_value
+                    "Sets the binlink directory to clean up (default: /usr/local/bin)")
+                (@arg PRUNE: --prune
+                    "Also removes transitive dependencies no longer required by another package")
+            )
             (@subcommand upload =>
                 (about: "Uploads a local Habitat Artifact to a Depot")
                 (aliases: &["u", "up", "upl", "uplo", "uploa"])
-                (@arg DEPOT_URL: -u --url +takes_value {valid_url} "Use a specific Depot URL")
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                    "Use a specific Depot URL, or a file:// URL of a local Depot directory")
                 (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
-                (@arg HART_FILE: +required +multiple {file_exists}
-                    "One or more filepaths to a Habitat Artifact \
-                    (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
+                (@group upload =>
+                    (@attributes +required)
+                    (@arg HART_FILE: +multiple {file_exists}
+                        "One or more filepaths to a Habitat Artifact \
+                        (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
+                    (@arg ALL: --all +takes_value {dir_exists}
+                        "Upload every Habitat Artifact in a directory, dependencies first, \
+                        skipping those the Depot already has")
+                )
             )
             (@subcommand verify =>
                 (about: "Verifies a Habitat Artifact with an origin key")
                 (aliases: &["v", "ve", "ver", "veri", "verif"])
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                    "Download the origin key from a Depot if it isn't cached")
                 (@arg SOURCE: +required {file_exists}
                     "A path to a Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
@@ -263,11 +368,13 @@ pub fn get() -> App<'static, 'static> {
             )
         )
         (subcommand: alias_apply)
+        (subcommand: alias_export)
         (subcommand: alias_install)
         (subcommand: alias_setup)
         (subcommand: alias_start)
         (after_help: "\nALIASES:\
             \n    apply      Alias for: 'config apply'\
+            \n    export     Alias for: 'pkg export'\
             \n    install    Alias for: 'pkg install'\
             \n    setup      Alias for: 'cli setup'\
             \n    start      Alias for: 'sup start'\
@@ -333,10 +440,20 @@ fn sub_pkg_build() -> App<'static, 'static> {
     }
 }
 
+fn sub_pkg_export() -> App<'static, 'static> {
+    clap_app!(@subcommand export =>
+        (about: "Exports the package to the specified format")
+        (@arg FORMAT: +required +takes_value "The export format (ex: docker, aci, mesos)")
+        (@arg PKG_IDENT: +required +takes_value
+            "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+    )
+}
+
 fn sub_pkg_install() -> App<'static, 'static> {
     clap_app!(@subcommand install =>
         (about: "Installs a Habitat package from a Depot or locally from a Habitat Artifact")
-        (@arg DEPOT_URL: -u --url +takes_value {valid_url} "Use a specific Depot URL")
+        (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+            "Use a specific Depot URL, or a file:// URL of a local Depot directory")
         (@arg PKG_IDENT_OR_ARTIFACT: +required +multiple
             "One or more Habitat package identifiers (ex: acme/redis) and/or filepaths \
             to a Habitat Artifact (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
@@ -351,6 +468,14 @@ fn file_exists(val: String) -> result::Result<(), String> {
     }
 }
 
+fn dir_exists(val: String) -> result::Result<(), String> {
+    if Path::new(&val).is_dir() {
+        Ok(())
+    } else {
+        Err(format!("Directory: '{}' cannot be found", &val))
+    }
+}
+
 fn file_exists_or_stdin(val: String) -> result::Result<(), String> {
     if val == "-" {
         Ok(())
@@ -369,6 +494,13 @@ fn valid_pair_type(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_shell(val: String) -> result::Result<(), String> {
+    match val.as_str() {
+        "bash" | "zsh" => Ok(()),
+        _ => Err(format!("Shell '{}' is not supported, use bash or zsh", &val)),
+    }
+}
+
 fn valid_service_group(val: String) -> result::Result<(), String> {
     let regex = Regex::new(r"([A-Za-z_0-9]+)\.([A-Za-z_0-9]+)").unwrap();
     if regex.is_match(&val) {
@@ -378,6 +510,16 @@ fn valid_service_group(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_origin_or_name(val: String) -> result::Result<(), String> {
+    let parts: Vec<&str> = val.split('/').collect();
+    if parts.len() <= 2 && parts.iter().all(|p| !p.is_empty()) {
+        Ok(())
+    } else {
+        Err(format!("'{}' is not an origin or an origin and package name (ex: core/redis)",
+                    &val))
+    }
+}
+
 fn valid_url(val: String) -> result::Result<(), String> {
     match Url::parse(&val) {
         Ok(_) => Ok(()),
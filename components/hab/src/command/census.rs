@@ -0,0 +1,172 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reports a service group's census as seen by a local supervisor.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab census redis.default
+//! ```
+//!
+//! Will query the supervisor's HTTP sidecar and print the members of the `redis.default` service
+//! group along with their role, health, and the versions of any configuration gossiped to the
+//! group. `--json` prints the same report as JSON, for scripting, and `--quiet` prints nothing.
+
+use std::collections::BTreeMap;
+use std::io::Read;
+
+use ansi_term::Colour::{Green, Red, Yellow};
+use common::ui;
+use http_client;
+use rustc_serialize::json::{Json, ToJson};
+
+use error::{Error, Result};
+
+pub const DEFAULT_SIDECAR_URL: &'static str = "http://127.0.0.1:9631";
+
+pub fn start(url: &str, service_group: &str) -> Result<()> {
+    let census = try!(fetch(url));
+    let members = match census.find_path(&["census_list", "censuses", service_group, "population"])
+        .and_then(|p| p.as_object()) {
+        Some(population) => {
+            let mut members: Vec<Member> = population.values().map(Member::from_json).collect();
+            members.sort_by(|a, b| (&a.hostname, &a.id).cmp(&(&b.hostname, &b.id)));
+            members
+        }
+        None => return Err(Error::CensusNotFound(service_group.to_string())),
+    };
+    let config_versions: BTreeMap<String, u64> = census.find_path(&["config_versions",
+                                                                    service_group])
+        .and_then(|v| v.as_object())
+        .map(|v| {
+            v.iter()
+                .filter_map(|(file, version)| version.as_u64().map(|n| (file.clone(), n)))
+                .collect()
+        })
+        .unwrap_or(BTreeMap::new());
+
+    let mut report = BTreeMap::new();
+    report.insert("service_group".to_string(), service_group.to_json());
+    report.insert("members".to_string(),
+                  Json::Array(members.iter().map(|m| m.to_json()).collect()));
+    report.insert("leader".to_string(),
+                  members.iter().find(|m| m.leader).map(|m| m.id.clone()).to_json());
+    report.insert("config_versions".to_string(), config_versions.to_json());
+    ui::report(&Json::Object(report), || {
+        println!("{:<38} {:<24} {:<16} {:<8} {:<10} {:<10} {}",
+                 "MEMBER",
+                 "HOSTNAME",
+                 "IP",
+                 "PORT",
+                 "ROLE",
+                 "HEALTH",
+                 "PACKAGE");
+        for member in members.iter() {
+            let health = format!("{:<10}", member.health);
+            println!("{:<38} {:<24} {:<16} {:<8} {:<10} {} {}",
+                     member.id,
+                     member.hostname,
+                     member.ip,
+                     member.port,
+                     member.role(),
+                     match member.health {
+                         "alive" => Green.paint(health),
+                         "suspect" => Yellow.paint(health),
+                         _ => Red.paint(health),
+                     },
+                     member.package);
+        }
+        if !config_versions.is_empty() {
+            println!("");
+            println!("{:<38} {}", "CONFIG", "VERSION");
+            for (file, version) in config_versions.iter() {
+                println!("{:<38} {}", file, version);
+            }
+        }
+    });
+    Ok(())
+}
+
+fn fetch(url: &str) -> Result<Json> {
+    let client = try!(http_client::new_hyper_client(None, None));
+    let mut response = try!(client.get(&format!("{}/census", url.trim_right_matches('/'))).send());
+    if !response.status.is_success() {
+        return Err(Error::SidecarResponse(response.status));
+    }
+    let mut body = String::new();
+    try!(response.read_to_string(&mut body));
+    Ok(try!(Json::from_str(&body)))
+}
+
+struct Member {
+    id: String,
+    hostname: String,
+    ip: String,
+    port: String,
+    leader: bool,
+    follower: bool,
+    health: &'static str,
+    package: String,
+}
+
+impl Member {
+    fn from_json(entry: &Json) -> Member {
+        let string = |key: &str| entry.find(key).and_then(|v| v.as_string()).unwrap_or("");
+        let flag = |key: &str| entry.find(key).and_then(|v| v.as_boolean()).unwrap_or(false);
+        let health = if flag("detached") {
+            "detached"
+        } else if flag("confirmed") {
+            "confirmed"
+        } else if flag("suspect") {
+            "suspect"
+        } else {
+            "alive"
+        };
+        Member {
+            id: string("id").to_string(),
+            hostname: string("hostname").to_string(),
+            ip: string("ip").to_string(),
+            port: string("port").to_string(),
+            leader: flag("leader"),
+            follower: flag("follower"),
+            health: health,
+            package: string("package_ident").to_string(),
+        }
+    }
+
+    fn role(&self) -> &'static str {
+        if self.leader {
+            "leader"
+        } else if self.follower {
+            "follower"
+        } else {
+            "-"
+        }
+    }
+}
+
+impl ToJson for Member {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("id".to_string(), self.id.to_json());
+        m.insert("hostname".to_string(), self.hostname.to_json());
+        m.insert("ip".to_string(), self.ip.to_json());
+        m.insert("port".to_string(), self.port.to_json());
+        m.insert("role".to_string(), self.role().to_json());
+        m.insert("health".to_string(), self.health.to_json());
+        m.insert("package".to_string(), self.package.to_json());
+        Json::Object(m)
+    }
+}
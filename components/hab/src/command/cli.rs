@@ -12,6 +12,51 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod completions {
+    use std::io::{self, Write};
+    use std::str::FromStr;
+
+    use clap::Shell;
+
+    use cli;
+    use error::Result;
+
+    /// Bash functions appended to the generated completion script which complete the idents of
+    /// locally installed packages for the commands which take one.
+    const BASH_PKG_IDENTS: &'static str = r#"
+__hab_installed_idents() {
+  local root="${FS_ROOT:-}/hab/pkgs"
+  [ -d "$root" ] || return 0
+  find "$root" -mindepth 4 -maxdepth 4 -type d 2>/dev/null | sed "s|^$root/||"
+}
+
+_hab_with_idents() {
+  _hab "$@"
+  local cur="${COMP_WORDS[COMP_CWORD]}"
+  case "$cur" in
+    -*) return 0 ;;
+  esac
+  case "${COMP_WORDS[1]}:${COMP_WORDS[2]}" in
+    pkg:binlink|pkg:exec|pkg:export|pkg:path|pkg:uninstall|export:*|start:*|sup:start)
+      COMPREPLY=( $(compgen -W "$(__hab_installed_idents)" -- "$cur") )
+      ;;
+  esac
+}
+
+complete -F _hab_with_idents -o bashdefault -o default hab
+"#;
+
+    pub fn start(shell: &str) -> Result<()> {
+        let shell = Shell::from_str(shell).expect("Shell was validated by the cli");
+        let mut out = io::stdout();
+        cli::get().gen_completions_to("hab", shell, &mut out);
+        if let Shell::Bash = shell {
+            try!(out.write_all(BASH_PKG_IDENTS.as_bytes()));
+        }
+        Ok(())
+    }
+}
+
 pub mod setup {
     use std::io::{self, Write};
     use std::path::Path;
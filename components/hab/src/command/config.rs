@@ -20,6 +20,7 @@ pub mod apply {
     use hcore::crypto::SymKey;
     use hcore::service::ServiceGroup;
     use common::gossip_file::GossipFile;
+    use common::ui;
 
     use error::Result;
     use gossip::{self, hab_gossip};
@@ -30,8 +31,7 @@ pub mod apply {
                  number: u64,
                  file_path: Option<&Path>)
                  -> Result<()> {
-        println!("{}",
-                 Yellow.bold().paint(format!("» Applying configuration")));
+        ui::status(Yellow.bold().paint(format!("» Applying configuration")));
         let file = match file_path {
             Some(p) => try!(GossipFile::from_file(sg.clone(), p, number)),
             None => {
@@ -46,18 +46,18 @@ pub mod apply {
         list.add_rumor(rumor);
 
         if let Some(ring_key) = ring_key {
-            println!("{} communication to \"{}\" ring with {}",
-                     Green.bold().paint("☛ Encrypting"),
-                     &ring_key.name,
-                     &ring_key.name_with_rev());
+            ui::status(format!("{} communication to \"{}\" ring with {}",
+                               Green.bold().paint("☛ Encrypting"),
+                               &ring_key.name,
+                               &ring_key.name_with_rev()));
 
         }
-        println!("{} configuration for {} into ring via {:?}",
-                 Green.bold().paint("↑ Applying"),
-                 &sg,
-                 &peers);
+        ui::status(format!("{} configuration for {} into ring via {:?}",
+                           Green.bold().paint("↑ Applying"),
+                           &sg,
+                           &peers));
         try!(gossip::send_rumors_to_peers(&peers, ring_key, &list));
-        println!("{}", Blue.paint(format!("★ Applied configuration.")));
+        ui::report(peers, || println!("{}", Blue.paint(format!("★ Applied configuration."))));
         Ok(())
     }
 }
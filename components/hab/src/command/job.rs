@@ -0,0 +1,197 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+pub mod log {
+    //! Prints the build log of a job from the Builder API.
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab job log 8273 --follow
+    //! ```
+    //!
+    //! Will print the log of job 8273 and keep printing it as the job's worker writes it, until
+    //! the job completes.
+
+    use std::io::{self, Read, Write};
+    use std::thread;
+    use std::time::Duration;
+
+    use http_client;
+    use hyper::header::{Authorization, Bearer};
+    use rustc_serialize::json::Json;
+
+    use error::{Error, Result};
+
+    const POLL_INTERVAL_MS: u64 = 2_000;
+
+    pub fn start(url: &str, token: &str, job_id: u64, follow: bool) -> Result<()> {
+        let mut start = 0;
+        // Follow the attempt first read from, rather than restart from the latest if the job is
+        // retried.
+        let mut attempt = None;
+        loop {
+            let log = try!(fetch(url, token, job_id, start, attempt));
+            if attempt.is_none() {
+                attempt = log.find("attempt").and_then(|a| a.as_u64());
+            }
+            let stdout = io::stdout();
+            let mut stdout = stdout.lock();
+            if let Some(content) = log.find("content").and_then(|c| c.as_array()) {
+                for chunk in content.iter().filter_map(|c| c.as_string()) {
+                    try!(stdout.write_all(chunk.as_bytes()));
+                }
+            }
+            try!(stdout.flush());
+            let stop = log.find("stop").and_then(|s| s.as_u64()).unwrap_or(start);
+            let complete = log.find("is_complete").and_then(|c| c.as_boolean()).unwrap_or(false);
+            if complete || (stop == start && !follow) {
+                return Ok(());
+            }
+            // A reply holds a limited number of chunks, so fetch again right away while the log
+            // is still being caught up on.
+            if stop == start {
+                thread::sleep(Duration::from_millis(POLL_INTERVAL_MS));
+            }
+            start = stop;
+        }
+    }
+
+    fn fetch(url: &str,
+             token: &str,
+             job_id: u64,
+             start: u64,
+             attempt: Option<u64>)
+             -> Result<Json> {
+        let client = try!(http_client::new_hyper_client(None, None));
+        let mut path = format!("{}/jobs/{}/log?start={}",
+                               url.trim_right_matches('/'),
+                               job_id,
+                               start);
+        if let Some(attempt) = attempt {
+            path.push_str(&format!("&attempt={}", attempt));
+        }
+        let mut response = try!(client.get(&path)
+            .header(Authorization(Bearer { token: token.to_string() }))
+            .send());
+        if !response.status.is_success() {
+            return Err(Error::BuilderResponse(response.status));
+        }
+        let mut body = String::new();
+        try!(response.read_to_string(&mut body));
+        Ok(try!(Json::from_str(&body)))
+    }
+}
+
+pub mod schedule {
+    //! Schedules recurring builds of a project with the Builder API, and lists them.
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab job schedule create core/redis "0 3 * * *"
+    //! $ hab job schedule list
+    //! ```
+    //!
+    //! Will build core/redis every night at three o'clock UTC, then list every schedule of the
+    //! signed in account along with when each next runs.
+
+    use std::cmp;
+    use std::collections::BTreeMap;
+    use std::io::Read;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use common::ui;
+    use http_client;
+    use hyper::client::RequestBuilder;
+    use hyper::header::{Authorization, Bearer, ContentType};
+    use rustc_serialize::json::{Json, ToJson};
+
+    use error::{Error, Result};
+
+    pub fn create(url: &str,
+                  token: &str,
+                  project: &str,
+                  cron: &str,
+                  priority: Option<u32>,
+                  requires: &[&str])
+                  -> Result<()> {
+        let mut body = BTreeMap::new();
+        body.insert("project".to_string(), project.to_json());
+        body.insert("cron".to_string(), cron.to_json());
+        if let Some(priority) = priority {
+            body.insert("priority".to_string(), priority.to_json());
+        }
+        if !requires.is_empty() {
+            let requires: Vec<String> = requires.iter().map(|r| r.to_string()).collect();
+            body.insert("requires".to_string(), requires.to_json());
+        }
+        let body = Json::Object(body).to_string();
+        let client = try!(http_client::new_hyper_client(None, None));
+        let request = client.post(&schedules_url(url))
+            .header(ContentType::json())
+            .body(body.as_str());
+        let schedule = try!(send(request, token));
+        ui::report(&schedule, || println!("Scheduled {}", describe(&schedule)));
+        Ok(())
+    }
+
+    pub fn list(url: &str, token: &str) -> Result<()> {
+        let client = try!(http_client::new_hyper_client(None, None));
+        let reply = try!(send(client.get(&schedules_url(url)), token));
+        ui::report(&reply, || {
+            let schedules = reply.find("schedules").and_then(|s| s.as_array());
+            match schedules {
+                Some(schedules) if !schedules.is_empty() => {
+                    for schedule in schedules {
+                        println!("{}", describe(schedule));
+                    }
+                }
+                _ => println!("No build schedules"),
+            }
+        });
+        Ok(())
+    }
+
+    fn schedules_url(url: &str) -> String {
+        format!("{}/user/schedules", url.trim_right_matches('/'))
+    }
+
+    fn send(request: RequestBuilder, token: &str) -> Result<Json> {
+        let mut response = try!(request.header(Authorization(Bearer { token: token.to_string() }))
+            .send());
+        if !response.status.is_success() {
+            return Err(Error::BuilderResponse(response.status));
+        }
+        let mut body = String::new();
+        try!(response.read_to_string(&mut body));
+        Ok(try!(Json::from_str(&body)))
+    }
+
+    // One line naming a schedule's project and cron expression, and how soon it next runs.
+    fn describe(schedule: &Json) -> String {
+        let field = |name: &str| schedule.find(name).and_then(|v| v.as_string()).unwrap_or("");
+        let next_run = match schedule.find("next_run_ms").and_then(|n| n.as_i64()) {
+            Some(next_run_ms) => {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or(Duration::new(0, 0));
+                let now_ms = now.as_secs() as i64 * 1000 + now.subsec_nanos() as i64 / 1_000_000;
+                let minutes = cmp::max(next_run_ms - now_ms, 0) / 60_000;
+                format!("next run in {}h {}m", minutes / 60, minutes % 60)
+            }
+            None => "no further runs".to_string(),
+        };
+        format!("{} \"{}\", {}", field("project"), field("cron"), next_run)
+    }
+}
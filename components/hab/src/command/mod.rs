@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod census;
 pub mod cli;
 pub mod config;
 pub mod file;
+pub mod job;
 pub mod pkg;
 pub mod origin;
 pub mod ring;
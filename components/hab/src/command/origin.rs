@@ -59,6 +59,7 @@ pub mod key {
         use hcore::crypto::SigKeyPair;
 
         use common::command::ProgressBar;
+        use common::ui;
         use error::Result;
 
         pub fn start(depot: &str,
@@ -71,15 +72,19 @@ pub mod key {
                 Some(revision) => {
                     let nwr = format!("{}-{}", origin, revision);
                     let msg = format!("» Downloading public origin key {}", &nwr);
-                    println!("{}", Yellow.bold().paint(msg));
+                    ui::status(Yellow.bold().paint(msg));
                     try!(download_key(&depot_client, &nwr, origin, revision, cache));
-                    println!("{}",
-                             Blue.paint(format!("★ Download of {} public origin key completed.",
-                                                &nwr)));
+                    ui::report(&vec![nwr.clone()], || {
+                        println!("{}",
+                                 Blue.paint(format!("★ Download of {} public origin key \
+                                                     completed.",
+                                                    &nwr)));
+                    });
                 }
                 None => {
                     let msg = format!("» Downloading public origin keys for {}", origin);
-                    println!("{}", Yellow.bold().paint(msg));
+                    ui::status(Yellow.bold().paint(msg));
+                    let mut keys = vec![];
                     for key in try!(depot_client.show_origin_keys(origin)) {
                         let nwr = format!("{}-{}", key.get_origin(), key.get_revision());
                         try!(download_key(&depot_client,
@@ -87,10 +92,14 @@ pub mod key {
                                           key.get_origin(),
                                           key.get_revision(),
                                           cache));
+                        keys.push(nwr);
                     }
-                    println!("{}",
-                             Blue.paint(format!("★ Download of {} public origin keys completed.",
-                                                &origin)));
+                    ui::report(&keys, || {
+                        println!("{}",
+                                 Blue.paint(format!("★ Download of {} public origin keys \
+                                                     completed.",
+                                                    &origin)));
+                    });
                 }
             };
             Ok(())
@@ -104,13 +113,13 @@ pub mod key {
                         -> Result<()> {
             match SigKeyPair::get_public_key_path(&nwr, &cache) {
                 Ok(_) => {
-                    println!("{} {}", Green.paint("→ Using"), &nwr);
+                    ui::status(format!("{} {}", Green.paint("→ Using"), &nwr));
                 }
                 Err(_) => {
-                    println!("{} {}", Green.bold().paint("↓ Downloading"), &nwr);
+                    ui::status(format!("{} {}", Green.bold().paint("↓ Downloading"), &nwr));
                     let mut progress = ProgressBar::default();
                     try!(depot_client.fetch_origin_key(name, rev, cache, Some(&mut progress)));
-                    println!("{} {}", Green.bold().paint("☑ Cached"), &nwr);
+                    ui::status(format!("{} {}", Green.bold().paint("☑ Cached"), &nwr));
                 }
             }
             Ok(())
@@ -150,17 +159,29 @@ pub mod key {
         use std::path::Path;
 
         use ansi_term::Colour::{Blue, Yellow};
+        use common::ui;
         use hcore::crypto::SigKeyPair;
 
         use error::Result;
 
         pub fn start(origin: &str, cache: &Path) -> Result<()> {
-            println!("{}",
-                     Yellow.bold().paint(format!("» Generating origin key for {}", &origin)));
+            ui::status(Yellow.bold().paint(format!("» Generating origin key for {}", &origin)));
             let pair = try!(SigKeyPair::generate_pair_for_origin(origin, cache));
-            println!("{}",
-                     Blue.paint(format!("★ Generated origin key pair {}.",
-                                        &pair.name_with_rev())));
+            ui::report(&pair.name_with_rev(), || {
+                println!("{}",
+                         Blue.paint(format!("★ Generated origin key pair {}.",
+                                            &pair.name_with_rev())));
+            });
+            if try!(SigKeyPair::get_pairs_for(origin, cache)).len() > 1 {
+                ui::warn(format!("The origin key for {} has been rotated. Upload the new public \
+                                  key with `hab origin key upload` so that packages signed with \
+                                  it can be verified, and share the secret key with anyone \
+                                  building for this origin. Packages signed with earlier \
+                                  revisions remain valid as long as those public keys are \
+                                  available; rebuild or re-sign them with `hab pkg sign` to move \
+                                  them onto the new revision.",
+                                 &origin));
+            }
             Ok(())
         }
     }
@@ -169,29 +190,34 @@ pub mod key {
         use std::path::Path;
 
         use ansi_term::Colour::{Blue, Yellow};
+        use common::ui;
         use hcore::crypto::SigKeyPair;
 
         use error::Result;
 
         pub fn start(content: &str, cache: &Path) -> Result<()> {
-            println!("{}",
-                     Yellow.bold().paint(format!("» Importing origin key from standard input")));
+            ui::status(Yellow.bold().paint(format!("» Importing origin key from standard input")));
             let (pair, pair_type) = try!(SigKeyPair::write_file_from_str(content, cache));
-            println!("{}",
-                     Blue.paint(format!("★ Imported {} origin key {}.",
-                                        &pair_type,
-                                        &pair.name_with_rev())));
+            ui::report(&pair.name_with_rev(), || {
+                println!("{}",
+                         Blue.paint(format!("★ Imported {} origin key {}.",
+                                            &pair_type,
+                                            &pair.name_with_rev())));
+            });
             Ok(())
         }
     }
 
     pub mod upload {
+        use std::collections::BTreeMap;
         use std::path::Path;
 
         use ansi_term::Colour::{Blue, Green, Yellow};
         use hyper::status::StatusCode::{Forbidden, Unauthorized};
+        use rustc_serialize::json::{Json, ToJson};
 
         use common::command::ProgressBar;
+        use common::ui;
         use depot_client::{self, Client};
         use hcore::crypto::{PUBLIC_SIG_KEY_VERSION, SECRET_SIG_KEY_VERSION};
         use hcore::crypto::keys::parse_name_with_rev;
@@ -205,16 +231,14 @@ pub mod key {
                      secret_keyfile: Option<&Path>)
                      -> Result<()> {
             let depot_client = try!(Client::new(depot, None));
-            println!("{}",
-                     Yellow.bold()
-                         .paint(format!("» Uploading public origin key {}",
-                                        public_keyfile.display())));
+            ui::status(Yellow.bold()
+                .paint(format!("» Uploading public origin key {}", public_keyfile.display())));
 
             let name_with_rev = try!(get_name_with_rev(&public_keyfile, PUBLIC_SIG_KEY_VERSION));
             let (name, rev) = try!(parse_name_with_rev(&name_with_rev));
-            println!("{} {}",
-                     Green.bold().paint("↑ Uploading"),
-                     public_keyfile.display());
+            ui::status(format!("{} {}",
+                               Green.bold().paint("↑ Uploading"),
+                               public_keyfile.display()));
             let mut progress = ProgressBar::default();
 
             match depot_client.put_origin_key(&name,
@@ -223,7 +247,9 @@ pub mod key {
                                               token,
                                               Some(&mut progress)) {
                 Ok(()) => {
-                    println!("{} {}", Green.bold().paint("✓ Uploaded"), &name_with_rev);
+                    ui::status(format!("{} {}",
+                                       Green.bold().paint("✓ Uploaded"),
+                                       &name_with_rev));
                 }
                 Err(e @ depot_client::Error::HTTP(Forbidden)) |
                 Err(e @ depot_client::Error::HTTP(Unauthorized)) => {
@@ -232,26 +258,28 @@ pub mod key {
 
                 Err(e @ depot_client::Error::HTTP(_)) => {
                     debug!("Error uploading public key {}", e);
-                    println!("{} {}",
-                             Yellow.bold()
-                                 .paint("✓ Public key revision already exists in the depot"),
-                             &name_with_rev);
+                    ui::status(format!("{} {}",
+                                       Yellow.bold()
+                                           .paint("✓ Public key revision already exists in the \
+                                                   depot"),
+                                       &name_with_rev));
                 }
                 Err(e) => {
                     return Err(Error::DepotClient(e));
                 }
             };
 
-            println!("{}",
-                     Blue.paint(format!("★ Upload of public origin key {} complete.",
-                                        &name_with_rev)));
+            ui::status(Blue.paint(format!("★ Upload of public origin key {} complete.",
+                                          &name_with_rev)));
+            let mut report = BTreeMap::new();
+            report.insert("public".to_string(), name_with_rev.to_json());
             if let Some(secret_keyfile) = secret_keyfile {
                 let name_with_rev = try!(get_name_with_rev(&secret_keyfile,
                                                            SECRET_SIG_KEY_VERSION));
                 let (name, rev) = try!(parse_name_with_rev(&name_with_rev));
-                println!("{} {}",
-                         Green.bold().paint("↑ Uploading"),
-                         secret_keyfile.display());
+                ui::status(format!("{} {}",
+                                   Green.bold().paint("↑ Uploading"),
+                                   secret_keyfile.display()));
                 let mut progress = ProgressBar::default();
                 match depot_client.put_origin_secret_key(&name,
                                                          &rev,
@@ -260,17 +288,20 @@ pub mod key {
                                                          Some(&mut progress)) {
                     Ok(()) => {
 
-                        println!("{} {}", Green.bold().paint("✓ Uploaded"), &name_with_rev);
-                        println!("{}",
-                                 Blue.paint(format!("★ Upload of secret origin key {} complete.",
-                                                    &name_with_rev)));
+                        ui::status(format!("{} {}",
+                                           Green.bold().paint("✓ Uploaded"),
+                                           &name_with_rev));
+                        ui::status(Blue.paint(format!("★ Upload of secret origin key {} \
+                                                       complete.",
+                                                      &name_with_rev)));
+                        report.insert("secret".to_string(), name_with_rev.to_json());
                     }
                     Err(e) => {
                         return Err(Error::DepotClient(e));
                     }
                 };
             }
-
+            ui::report(&Json::Object(report), || ());
             Ok(())
         }
     }
@@ -282,10 +313,14 @@ pub mod key {
     pub mod upload_latest {
         use std::path::Path;
 
+        use std::collections::BTreeMap;
+
         use ansi_term::Colour::{Blue, Green, Yellow};
         use hyper::status::StatusCode::{Forbidden, Unauthorized};
+        use rustc_serialize::json::{Json, ToJson};
 
         use common::command::ProgressBar;
+        use common::ui;
         use depot_client::{self, Client};
         use error::{Error, Result};
         use hcore::crypto::keys::parse_name_with_rev;
@@ -308,16 +343,18 @@ pub mod key {
             let (name, rev) = try!(parse_name_with_rev(&name_with_rev));
 
 
-            println!("{} {}",
-                     Green.bold().paint("↑ Uploading public key"),
-                     public_keyfile.display());
+            ui::status(format!("{} {}",
+                               Green.bold().paint("↑ Uploading public key"),
+                               public_keyfile.display()));
             let mut progress = ProgressBar::default();
 
 
 
             match depot_client.put_origin_key(&name, &rev, &public_keyfile, token, Some(&mut progress)) {
                 Ok(()) => {
-                    println!("{} {}", Green.bold().paint("✓ Uploaded"), &name_with_rev);
+                    ui::status(format!("{} {}",
+                                       Green.bold().paint("✓ Uploaded"),
+                                       &name_with_rev));
                 }
                 Err(e @ depot_client::Error::HTTP(Forbidden)) |
                 Err(e @ depot_client::Error::HTTP(Unauthorized)) => {
@@ -325,19 +362,21 @@ pub mod key {
                 }
                 Err(e @ depot_client::Error::HTTP(_)) => {
                     debug!("Error uploading public key {}", e);
-                    println!("{} {}",
-                             Yellow.bold()
-                                 .paint("✓ Public key revision already exists in the depot"),
-                             &name_with_rev);
+                    ui::status(format!("{} {}",
+                                       Yellow.bold()
+                                           .paint("✓ Public key revision already exists in the \
+                                                   depot"),
+                                       &name_with_rev));
                 }
                 Err(e) => {
                     return Err(Error::DepotClient(e));
                 }
             };
 
-            println!("{}",
-                     Blue.paint(format!("★ Upload of public origin key {} complete.",
-                                        &name_with_rev)));
+            ui::status(Blue.paint(format!("★ Upload of public origin key {} complete.",
+                                          &name_with_rev)));
+            let mut report = BTreeMap::new();
+            report.insert("public".to_string(), name_with_rev.to_json());
 
             if with_secret {
                 let secret_keyfile = try!(SigKeyPair::get_secret_key_path(&latest.name_with_rev(),
@@ -347,9 +386,9 @@ pub mod key {
                 // check the SECRET_SIG_KEY_VERSION
                 let name_with_rev = try!(get_name_with_rev(&secret_keyfile,
                                                            SECRET_SIG_KEY_VERSION));
-                println!("{} {}",
-                         Green.bold().paint("↑ Uploading secret key"),
-                         secret_keyfile.display());
+                ui::status(format!("{} {}",
+                                   Green.bold().paint("↑ Uploading secret key"),
+                                   secret_keyfile.display()));
                 let mut progress = ProgressBar::default();
                 match depot_client.put_origin_secret_key(&name,
                                                          &rev,
@@ -358,16 +397,20 @@ pub mod key {
                                                          Some(&mut progress)) {
                     Ok(()) => {
 
-                        println!("{} {}", Green.bold().paint("✓ Uploaded"), &name_with_rev);
-                        println!("{}",
-                                 Blue.paint(format!("★ Upload of secret origin key {} complete.",
-                                                    &name_with_rev)));
+                        ui::status(format!("{} {}",
+                                           Green.bold().paint("✓ Uploaded"),
+                                           &name_with_rev));
+                        ui::status(Blue.paint(format!("★ Upload of secret origin key {} \
+                                                       complete.",
+                                                      &name_with_rev)));
+                        report.insert("secret".to_string(), name_with_rev.to_json());
                     }
                     Err(e) => {
                         return Err(Error::DepotClient(e));
                     }
                 }
             }
+            ui::report(&Json::Object(report), || ());
             Ok(())
         }
     }
@@ -16,55 +16,129 @@
 
 pub mod binlink {
     use std::fs;
-    use std::path::Path;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::{Path, PathBuf};
     use std::os::unix;
 
     use ansi_term::Colour::{Blue, Green, Yellow};
+    use common::ui;
     use hcore::package::{PackageIdent, PackageInstall};
 
     use error::{Error, Result};
     use exec::find_command_in_pkg;
 
+    /// Symlinks a binary from a package into a destination directory. If no binary is given then
+    /// every executable found in the package's `PATH` entries will be symlinked. With `--json`
+    /// the paths of the symlinks are printed.
+    ///
+    /// # Failures
+    ///
+    /// * The package is not installed
+    /// * The requested binary could not be found in the package
+    /// * A file or a symlink to another location already exists at a destination and `force` was
+    ///   not given
     pub fn start(ident: &PackageIdent,
-                 binary: &str,
+                 binary: Option<&str>,
                  dest_path: &Path,
-                 fs_root_path: &Path)
+                 fs_root_path: &Path,
+                 force: bool)
                  -> Result<()> {
         let dst_path = fs_root_path.join(try!(dest_path.strip_prefix("/")));
-        let dst = dst_path.join(&binary);
-        println!("{}",
-                 Yellow.bold().paint(format!("» Symlinking {} from {} into {}",
-                                             &binary,
-                                             &ident,
-                                             dst_path.display())));
         let pkg_install = try!(PackageInstall::load(&ident, Some(fs_root_path)));
-        let src = match try!(find_command_in_pkg(binary, &pkg_install, fs_root_path)) {
-            Some(c) => c,
+        let binaries = match binary {
+            Some(binary) => {
+                ui::status(Yellow.bold().paint(format!("» Symlinking {} from {} into {}",
+                                                       &binary,
+                                                       &ident,
+                                                       dst_path.display())));
+                match try!(find_command_in_pkg(binary, &pkg_install, fs_root_path)) {
+                    Some(c) => vec![(binary.to_string(), c)],
+                    None => {
+                        return Err(Error::CommandNotFoundInPkg((pkg_install.ident()
+                                                                   .to_string(),
+                                                               binary.to_string())))
+                    }
+                }
+            }
             None => {
-                return Err(Error::CommandNotFoundInPkg((pkg_install.ident().to_string(),
-                                                        binary.to_string())))
+                ui::status(Yellow.bold().paint(format!("» Symlinking all binaries from {} into {}",
+                                                       &ident,
+                                                       dst_path.display())));
+                try!(binaries_in_pkg(&pkg_install, fs_root_path))
             }
         };
         if !dst_path.is_dir() {
-            println!("{} parent directory {}",
-                     Green.paint("Ω Creating"),
-                     dst_path.display());
+            ui::status(format!("{} parent directory {}",
+                               Green.paint("Ω Creating"),
+                               dst_path.display()));
             try!(fs::create_dir_all(&dst_path))
         }
-        match fs::read_link(&dst) {
-            Ok(path) => {
-                if path != src {
-                    try!(fs::remove_file(&dst));
-                    try!(unix::fs::symlink(&src, &dst));
+        let mut linked = vec![];
+        for &(ref binary, ref src) in binaries.iter() {
+            let dst = dst_path.join(binary);
+            try!(link(src, &dst, force));
+            ui::status(Blue.paint(format!("★ Binary {} from {} symlinked to {}",
+                                          &binary,
+                                          &pkg_install.ident(),
+                                          &dst.display())));
+            linked.push(dst.to_string_lossy().into_owned());
+        }
+        ui::report(&linked, || ());
+        Ok(())
+    }
+
+    /// Returns the name and path of every executable file found in the package's `PATH` entries.
+    /// Entries which can't be followed, such as dangling symlinks, are skipped with a warning.
+    fn binaries_in_pkg(pkg_install: &PackageInstall,
+                       fs_root_path: &Path)
+                       -> Result<Vec<(String, PathBuf)>> {
+        let mut binaries = vec![];
+        for path in try!(pkg_install.paths()) {
+            let dir = fs_root_path.join(try!(path.strip_prefix("/")));
+            if !dir.is_dir() {
+                continue;
+            }
+            for entry in try!(fs::read_dir(&dir)) {
+                let entry = try!(entry);
+                let metadata = match fs::metadata(entry.path()) {
+                    Ok(metadata) => metadata,
+                    Err(e) => {
+                        ui::warn(format!("Skipping {}, {}", entry.path().display(), e));
+                        continue;
+                    }
+                };
+                if metadata.is_file() && metadata.permissions().mode() & 0o111 != 0 {
+                    let name = entry.file_name().to_string_lossy().into_owned();
+                    let src = path.join(&name);
+                    binaries.push((name, src));
+                }
+            }
+        }
+        Ok(binaries)
+    }
+
+    fn link(src: &Path, dst: &Path, force: bool) -> Result<()> {
+        match fs::symlink_metadata(dst) {
+            Ok(metadata) => {
+                if metadata.file_type().is_symlink() {
+                    let current = try!(fs::read_link(dst));
+                    if current == src {
+                        return Ok(());
+                    }
+                    if !force {
+                        return Err(Error::BinlinkCollision((dst.to_string_lossy().into_owned(),
+                                                            current.to_string_lossy()
+                                                                .into_owned())));
+                    }
+                } else if !force {
+                    return Err(Error::BinlinkCollision((dst.to_string_lossy().into_owned(),
+                                                        "a regular file".to_string())));
                 }
+                try!(fs::remove_file(dst));
+                try!(unix::fs::symlink(src, dst));
             }
-            Err(_) => try!(unix::fs::symlink(&src, &dst)),
+            Err(_) => try!(unix::fs::symlink(src, dst)),
         }
-        println!("{}",
-                 Blue.paint(format!("★ Binary {} from {} symlinked to {}",
-                                    &binary,
-                                    &pkg_install.ident(),
-                                    &dst.display())));
         Ok(())
     }
 }
@@ -103,6 +177,161 @@ pub mod build {
     }
 }
 
+pub mod diff {
+    //! Compares two installed releases of a package.
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab pkg diff core/redis/3.0.7/20160614230104 core/redis/3.2.1/20160725012435
+    //! ```
+    //!
+    //! Will list the files, dependencies, exposed ports, and default configuration settings which
+    //! were added (`+`), removed (`-`), or changed (`~`) between the two releases. `--json`
+    //! prints the same changes as JSON, for scripting.
+
+    use std::collections::BTreeMap;
+    use std::path::Path;
+
+    use ansi_term::Colour::{Blue, Green, Red, Yellow};
+    use common::ui;
+    use hcore::package::{PackageIdent, PackageInstall};
+    use rustc_serialize::json::{Json, ToJson};
+    use toml;
+
+    use error::Result;
+
+    pub fn start(a: &PackageIdent, b: &PackageIdent, fs_root_path: &Path) -> Result<()> {
+        let a = try!(PackageInstall::load(a, Some(fs_root_path)));
+        let b = try!(PackageInstall::load(b, Some(fs_root_path)));
+        ui::status(Yellow.bold().paint(format!("» Comparing {} with {}", a.ident(), b.ident())));
+
+        let mut report = BTreeMap::new();
+        let mut changed = 0;
+        changed += print_changes("Files", &try!(a.files()), &try!(b.files()), &mut report);
+        changed += print_changes("Dependencies",
+                                 &by_name(try!(a.deps())),
+                                 &by_name(try!(b.deps())),
+                                 &mut report);
+        changed += print_changes("Transitive dependencies",
+                                 &by_name(try!(a.tdeps())),
+                                 &by_name(try!(b.tdeps())),
+                                 &mut report);
+        changed += print_changes("Exposed ports",
+                                 &as_set(try!(a.exposes())),
+                                 &as_set(try!(b.exposes())),
+                                 &mut report);
+        changed += print_changes("Default configuration",
+                                 &settings(try!(a.default_cfg())),
+                                 &settings(try!(b.default_cfg())),
+                                 &mut report);
+        ui::report(&Json::Object(report), || {
+            println!("{}",
+                     Blue.paint(format!("★ {} difference{} found.",
+                                        changed,
+                                        if changed == 1 { "" } else { "s" })));
+        });
+        Ok(())
+    }
+
+    /// Print the entries added, removed, or changed between two releases, add them to the
+    /// report under the title, and return how many there were.
+    fn print_changes(title: &str,
+                     a: &BTreeMap<String, String>,
+                     b: &BTreeMap<String, String>,
+                     report: &mut BTreeMap<String, Json>)
+                     -> usize {
+        let mut lines = vec![];
+        let mut removed = vec![];
+        let mut changed = BTreeMap::new();
+        for (key, value) in a.iter() {
+            match b.get(key) {
+                None => {
+                    lines.push(format!("{} {}", Red.paint("-"), key));
+                    removed.push(key.clone());
+                }
+                Some(other) if other != value => {
+                    lines.push(format!("{} {}: {} -> {}", Yellow.paint("~"), key, value, other));
+                    changed.insert(key.clone(), vec![value.clone(), other.clone()].to_json());
+                }
+                Some(_) => (),
+            }
+        }
+        let mut added = vec![];
+        for key in b.keys().filter(|k| !a.contains_key(*k)) {
+            lines.push(format!("{} {}", Green.paint("+"), key));
+            added.push(key.clone());
+        }
+        if !lines.is_empty() {
+            ui::status(format!("{}:", title));
+            for line in lines.iter() {
+                ui::status(format!("  {}", line));
+            }
+        }
+        let mut section = BTreeMap::new();
+        section.insert("added".to_string(), added.to_json());
+        section.insert("removed".to_string(), removed.to_json());
+        section.insert("changed".to_string(), Json::Object(changed));
+        report.insert(title.to_string(), Json::Object(section));
+        lines.len()
+    }
+
+    /// Key dependencies by origin and name, so a dependency upgraded between the releases shows
+    /// as changed rather than as one removed and one added.
+    fn by_name(deps: Vec<PackageIdent>) -> BTreeMap<String, String> {
+        deps.into_iter()
+            .map(|d| {
+                (format!("{}/{}", d.origin, d.name),
+                 format!("{}/{}",
+                         d.version.unwrap_or(String::new()),
+                         d.release.unwrap_or(String::new())))
+            })
+            .collect()
+    }
+
+    fn as_set(values: Vec<String>) -> BTreeMap<String, String> {
+        values.into_iter()
+            .filter(|v| !v.is_empty())
+            .map(|v| (v, String::new()))
+            .collect()
+    }
+
+    /// Flatten a package's default configuration into its settings keyed by their dotted path.
+    /// Configuration which can't be parsed is compared line by line.
+    fn settings(cfg: Option<String>) -> BTreeMap<String, String> {
+        let mut settings = BTreeMap::new();
+        let cfg = match cfg {
+            Some(cfg) => cfg,
+            None => return settings,
+        };
+        match toml::Parser::new(&cfg).parse() {
+            Some(table) => flatten("", &table, &mut settings),
+            None => {
+                for line in cfg.lines().filter(|l| !l.trim().is_empty()) {
+                    settings.insert(line.to_string(), String::new());
+                }
+            }
+        }
+        settings
+    }
+
+    fn flatten(prefix: &str, table: &toml::Table, settings: &mut BTreeMap<String, String>) {
+        for (key, value) in table.iter() {
+            let path = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            match *value {
+                toml::Value::Table(ref inner) => flatten(&path, inner, settings),
+                ref value => {
+                    settings.insert(path, value.to_string());
+                }
+            }
+        }
+    }
+}
+
 pub mod exec {
     use std::env;
     use std::ffi::OsString;
@@ -165,6 +394,7 @@ pub mod export {
     mod inner {
         use command::pkg::exec;
         use common::command::package::install;
+        use common::ui;
         use error::{Error, Result};
         use hcore::crypto::default_cache_key_path;
         use hcore::fs::{cache_artifact_path, FS_ROOT_PATH};
@@ -207,10 +437,10 @@ pub mod export {
             match PackageInstall::load(format.pkg_ident(), None) {
                 Ok(_) => {}
                 _ => {
-                    println!("{} is not installed", &format_ident.to_string());
-                    println!("Searching for {} in remote {}",
-                             &format_ident.to_string(),
-                             &default_depot_url());
+                    ui::status(format!("{} is not installed", &format_ident.to_string()));
+                    ui::status(format!("Searching for {} in remote {}",
+                                       &format_ident.to_string(),
+                                       &default_depot_url()));
                     try!(install::from_url(&default_depot_url(),
                                            format_ident,
                                            Path::new(FS_ROOT_PATH),
@@ -226,6 +456,7 @@ pub mod export {
     #[cfg(not(target_os = "linux"))]
     mod inner {
         use ansi_term::Colour::Yellow;
+        use common::ui;
         use error::{Error, Result};
         use hcore::package::PackageIdent;
         use std::env;
@@ -236,7 +467,7 @@ pub mod export {
                                supported. Try running this command again on a 64-bit Linux \
                                operating system.\n",
                               value);
-            println!("{}", Yellow.bold().paint(msg));
+            ui::warn(Yellow.bold().paint(msg));
             let e = Error::UnsupportedExportFormat(value.to_string());
             Err(e)
         }
@@ -247,7 +478,7 @@ pub mod export {
             let msg = format!("∅ Exporting packages from this operating system is not yet \
                                supported. Try running this command again on a 64-bit Linux \
                                operating system.\n");
-            println!("{}", Yellow.bold().paint(msg));
+            ui::warn(Yellow.bold().paint(msg));
             Err(Error::SubcommandNotSupported(format!("{} {}", subcmd, subsubcmd)))
 
         }
@@ -255,13 +486,50 @@ pub mod export {
 }
 
 pub mod hash {
+    use common::ui;
     use hcore::crypto::hash;
 
     use error::Result;
 
     pub fn start(src: &str) -> Result<()> {
         let h = try!(hash::hash_file(&src));
-        println!("{}", h);
+        ui::report(&h, || println!("{}", h));
+        Ok(())
+    }
+}
+
+pub mod list {
+    //! Lists the releases of packages in a [Depot](../depot).
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab pkg list core/redis -u http://localhost:9632
+    //! ```
+    //!
+    //! Will print every release of `core/redis` in the Depot. Given only an origin, every release
+    //! of each of its packages is printed. `--json` prints the releases as JSON, for scripting.
+
+    use common::ui;
+    use depot_client::Client;
+    use rustc_serialize::json::{Json, ToJson};
+
+    use error::Result;
+
+    pub fn start(url: &str, origin: &str, name: Option<&str>) -> Result<()> {
+        let depot_client = try!(Client::new(url, None));
+        let idents = try!(depot_client.list_packages(origin, name));
+        let json = Json::Array(idents.iter().map(|i| i.to_json()).collect());
+        ui::report(&json, || {
+            println!("{:<24} {:<32} {:<16} {}", "ORIGIN", "NAME", "VERSION", "RELEASE");
+            for ident in idents.iter() {
+                println!("{:<24} {:<32} {:<16} {}",
+                         ident.get_origin(),
+                         ident.get_name(),
+                         ident.get_version(),
+                         ident.get_release());
+            }
+        });
         Ok(())
     }
 }
@@ -269,38 +537,373 @@ pub mod hash {
 pub mod path {
     use std::path::Path;
 
+    use common::ui;
     use hcore::package::{PackageIdent, PackageInstall};
 
     use error::Result;
 
     pub fn start(ident: &PackageIdent, fs_root_path: &Path) -> Result<()> {
         let pkg_install = try!(PackageInstall::load(ident, Some(fs_root_path)));
-        println!("{}", pkg_install.installed_path().display());
+        let path = pkg_install.installed_path().display().to_string();
+        ui::report(&path, || println!("{}", path));
         Ok(())
     }
 }
 
+pub mod show {
+    //! Shows the metadata of a package release in a [Depot](../depot).
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab pkg show core/redis -u http://localhost:9632
+    //! ```
+    //!
+    //! Will print the checksum, dependencies, exposed ports and provenance of the latest release
+    //! of `core/redis` in the Depot. `--json` prints the metadata as JSON, for scripting.
+
+    use common::ui;
+    use depot_client::Client;
+    use hcore::package::PackageIdent;
+
+    use error::Result;
+
+    pub fn start(url: &str, ident: &PackageIdent) -> Result<()> {
+        let depot_client = try!(Client::new(url, None));
+        let package = try!(depot_client.show_package(ident.clone()));
+        ui::report(&package, || {
+            println!("{:<16} {}", "Package:", package.get_ident());
+            println!("{:<16} {}", "Checksum:", package.get_checksum());
+            let deps: Vec<String> = package.get_deps().iter().map(|d| d.to_string()).collect();
+            list("Dependencies:", &deps);
+            let tdeps: Vec<String> = package.get_tdeps().iter().map(|d| d.to_string()).collect();
+            list("All deps:", &tdeps);
+            let exposes: Vec<String> =
+                package.get_exposes().iter().map(|p| p.to_string()).collect();
+            list("Exposes:", &exposes);
+            if package.has_provenance() {
+                let provenance = package.get_provenance();
+                if provenance.has_job_id() {
+                    println!("{:<16} {}", "Build job:", provenance.get_job_id());
+                }
+                if provenance.has_source_revision() {
+                    println!("{:<16} {}", "Source:", provenance.get_source_revision());
+                }
+                if provenance.has_builder_host() {
+                    println!("{:<16} {}", "Built on:", provenance.get_builder_host());
+                }
+                if provenance.has_log_url() {
+                    println!("{:<16} {}", "Build log:", provenance.get_log_url());
+                }
+            }
+        });
+        Ok(())
+    }
+
+    fn list(label: &str, values: &[String]) {
+        match values.split_first() {
+            Some((first, rest)) => {
+                println!("{:<16} {}", label, first);
+                for value in rest {
+                    println!("{:<16} {}", "", value);
+                }
+            }
+            None => println!("{:<16} none", label),
+        }
+    }
+}
+
 pub mod sign {
+    use std::collections::BTreeMap;
     use std::path::Path;
 
     use ansi_term::Colour::{Blue, Green, Yellow};
+    use common::ui;
     use hcore::crypto::{artifact, SigKeyPair};
+    use rustc_serialize::json::{Json, ToJson};
 
     use error::Result;
 
     pub fn start(origin: &SigKeyPair, src: &Path, dst: &Path) -> Result<()> {
-        println!("{}",
-                 Yellow.bold().paint(format!("» Signing {}", src.display())));
-        println!("{} {} with {} to create {}",
-                 Green.paint("☛ Signing"),
-                 src.display(),
-                 &origin.name_with_rev(),
-                 dst.display());
+        ui::status(Yellow.bold().paint(format!("» Signing {}", src.display())));
+        ui::status(format!("{} {} with {} to create {}",
+                           Green.paint("☛ Signing"),
+                           src.display(),
+                           &origin.name_with_rev(),
+                           dst.display()));
         try!(artifact::sign(src, dst, origin));
-        println!("{}",
-                 Blue.paint(format!("★ Signed artifact {}.", dst.display())));
+        let mut report = BTreeMap::new();
+        report.insert("artifact".to_string(), dst.display().to_string().to_json());
+        report.insert("signer".to_string(), origin.name_with_rev().to_json());
+        ui::report(&Json::Object(report), || {
+            println!("{}",
+                     Blue.paint(format!("★ Signed artifact {}.", dst.display())));
+        });
+        Ok(())
+    }
+}
+
+pub mod uninstall {
+    //! Removes an installed package from the local filesystem.
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab pkg uninstall core/redis --prune
+    //! ```
+    //!
+    //! Will stop a supervised `core/redis` service if one is running, remove the package and any
+    //! binlinks pointing into it, and then remove any of its transitive dependencies which are no
+    //! longer required by another installed package. Dependencies are removed in the same way,
+    //! each before those it depends on itself. `--json` prints the releases removed and those
+    //! kept.
+
+    use std::collections::{BTreeMap, HashMap};
+    use std::fs::{self, File};
+    use std::io::Read;
+    use std::path::{Path, PathBuf};
+    use std::thread;
+    use std::time::Duration;
+
+    use ansi_term::Colour::{Blue, Green, Yellow};
+    use common::ui;
+    use hcore::fs::svc_path;
+    use hcore::package::{PackageIdent, PackageInstall};
+    use libc;
+    use rustc_serialize::json::{Json, ToJson};
+
+    use error::{Error, Result};
+
+    const PIDFILE_NAME: &'static str = "PID";
+    /// Number of times to check whether a stopped service has exited before giving up.
+    const STOP_RETRIES: usize = 20;
+
+    pub fn start(ident: &PackageIdent,
+                 dest_path: &Path,
+                 fs_root_path: &Path,
+                 prune: bool)
+                 -> Result<()> {
+        let pkg_install = try!(PackageInstall::load(ident, Some(fs_root_path)));
+        ui::status(Yellow.bold().paint(format!("» Uninstalling {}", pkg_install.ident())));
+        let dependents = try!(dependents_of(pkg_install.ident(), fs_root_path));
+        if !dependents.is_empty() {
+            return Err(Error::PackageInUse((pkg_install.ident().to_string(),
+                                            dependents.iter()
+                                                .map(|d| d.to_string())
+                                                .collect())));
+        }
+        try!(stop_service(&pkg_install, fs_root_path));
+        let tdeps = try!(pkg_install.tdeps());
+        try!(remove(&pkg_install, dest_path, fs_root_path));
+        let mut removed = vec![pkg_install.ident().to_string()];
+        let mut kept_deps = vec![];
+        if prune {
+            let (pruned, kept) = prune_order(&tdeps, &try!(installed_tdeps(fs_root_path)));
+            for dep in kept.iter() {
+                ui::status(format!("{} {}, still required by another package",
+                                   Green.paint("→ Keeping"),
+                                   dep));
+                kept_deps.push(dep.to_string());
+            }
+            for dep in pruned.iter() {
+                let dep_install = try!(PackageInstall::load(dep, Some(fs_root_path)));
+                try!(stop_service(&dep_install, fs_root_path));
+                try!(remove(&dep_install, dest_path, fs_root_path));
+                removed.push(dep.to_string());
+            }
+        }
+        let mut report = BTreeMap::new();
+        report.insert("removed".to_string(), removed.to_json());
+        report.insert("kept".to_string(), kept_deps.to_json());
+        ui::report(&Json::Object(report), || {
+            println!("{}",
+                     Blue.paint(format!("★ Uninstall of {} complete.", pkg_install.ident())));
+        });
+        Ok(())
+    }
+
+    /// Returns every installed package which lists the given package as a transitive dependency.
+    fn dependents_of(ident: &PackageIdent, fs_root_path: &Path) -> Result<Vec<PackageIdent>> {
+        let mut dependents = vec![];
+        for installed in try!(PackageInstall::all(Some(fs_root_path))) {
+            if &installed == ident {
+                continue;
+            }
+            let pkg_install = try!(PackageInstall::load(&installed, Some(fs_root_path)));
+            if try!(pkg_install.tdeps()).iter().any(|dep| dep == ident) {
+                dependents.push(installed);
+            }
+        }
+        Ok(dependents)
+    }
+
+    /// Returns every installed package with its transitive dependencies.
+    fn installed_tdeps(fs_root_path: &Path) -> Result<HashMap<PackageIdent, Vec<PackageIdent>>> {
+        let mut installed = HashMap::new();
+        for ident in try!(PackageInstall::all(Some(fs_root_path))) {
+            let pkg_install = try!(PackageInstall::load(&ident, Some(fs_root_path)));
+            let tdeps = try!(pkg_install.tdeps());
+            installed.insert(ident, tdeps);
+        }
+        Ok(installed)
+    }
+
+    /// Splits the installed packages among `candidates` into those which can be pruned, ordered so
+    /// each comes before any of its own dependencies, and those still required by an installed
+    /// package which is not being pruned.
+    fn prune_order(candidates: &[PackageIdent],
+                   installed: &HashMap<PackageIdent, Vec<PackageIdent>>)
+                   -> (Vec<PackageIdent>, Vec<PackageIdent>) {
+        let mut kept: Vec<PackageIdent> =
+            candidates.iter().filter(|dep| installed.contains_key(*dep)).cloned().collect();
+        let mut pruned: Vec<PackageIdent> = vec![];
+        loop {
+            let next = kept.iter().position(|dep| {
+                installed.iter().all(|(ident, tdeps)| {
+                    ident == dep || pruned.contains(ident) || !tdeps.contains(dep)
+                })
+            });
+            match next {
+                Some(i) => pruned.push(kept.remove(i)),
+                None => break,
+            }
+        }
+        (pruned, kept)
+    }
+
+    /// Sends a `SIGTERM` to a running Supervisor-managed process for the package, if its PID file
+    /// is found, and waits for the process to exit.
+    fn stop_service(pkg_install: &PackageInstall, fs_root_path: &Path) -> Result<()> {
+        let svc_dir = svc_path(&pkg_install.ident().name);
+        let pid_file = fs_root_path.join(try!(svc_dir.strip_prefix("/"))).join(PIDFILE_NAME);
+        let pid = match read_pid(&pid_file) {
+            Some(pid) => pid,
+            None => return Ok(()),
+        };
+        ui::status(format!("{} service process {}", Green.paint("■ Stopping"), pid));
+        if unsafe { libc::kill(pid, libc::SIGTERM) } != 0 {
+            // The process is already gone, leaving a stale PID file behind.
+            return Ok(());
+        }
+        for _ in 0..STOP_RETRIES {
+            if unsafe { libc::kill(pid, 0) } != 0 {
+                return Ok(());
+            }
+            thread::sleep(Duration::from_millis(500));
+        }
+        Err(Error::ServiceNotStopped((pkg_install.ident().to_string(), pid)))
+    }
+
+    fn read_pid(pid_file: &Path) -> Option<libc::pid_t> {
+        let mut contents = String::new();
+        match File::open(pid_file) {
+            Ok(mut file) => {
+                if file.read_to_string(&mut contents).is_err() {
+                    return None;
+                }
+            }
+            Err(_) => return None,
+        }
+        contents.trim().parse().ok()
+    }
+
+    /// Removes any binlinks targeting the package followed by the package's installed directory.
+    /// Empty version and name directories left behind are cleaned up as well.
+    fn remove(pkg_install: &PackageInstall, dest_path: &Path, fs_root_path: &Path) -> Result<()> {
+        let dst_path = fs_root_path.join(try!(dest_path.strip_prefix("/")));
+        // Binlinks point at the package as seen from within the filesystem root
+        let pkg_path = Path::new("/").join(try!(pkg_install.installed_path()
+            .strip_prefix(fs_root_path)));
+        if dst_path.is_dir() {
+            for entry in try!(fs::read_dir(&dst_path)) {
+                let entry = try!(entry);
+                if let Ok(target) = fs::read_link(entry.path()) {
+                    if target.starts_with(&pkg_path) {
+                        try!(fs::remove_file(entry.path()));
+                        ui::status(format!("{} binlink {}",
+                                           Green.paint("✗ Removed"),
+                                           entry.path().display()));
+                    }
+                }
+            }
+        }
+        try!(fs::remove_dir_all(pkg_install.installed_path()));
+        let mut parent: Option<PathBuf> =
+            pkg_install.installed_path().parent().map(|p| p.to_path_buf());
+        // Walk up through the version and name directories, stopping at the first one which still
+        // contains other releases.
+        for _ in 0..2 {
+            match parent {
+                Some(dir) => {
+                    if fs::remove_dir(&dir).is_err() {
+                        break;
+                    }
+                    parent = dir.parent().map(|p| p.to_path_buf());
+                }
+                None => break,
+            }
+        }
+        ui::status(format!("{} {}", Green.bold().paint("✓ Uninstalled"), pkg_install.ident()));
         Ok(())
     }
+
+    #[cfg(test)]
+    mod tests {
+        use std::collections::HashMap;
+        use std::str::FromStr;
+
+        use hcore::package::PackageIdent;
+
+        use super::prune_order;
+
+        fn ident(s: &str) -> PackageIdent {
+            PackageIdent::from_str(s).unwrap()
+        }
+
+        fn installed(pkgs: &[(&str, Vec<&str>)]) -> HashMap<PackageIdent, Vec<PackageIdent>> {
+            pkgs.iter()
+                .map(|&(pkg, ref tdeps)| (ident(pkg), tdeps.iter().map(|d| ident(d)).collect()))
+                .collect()
+        }
+
+        #[test]
+        fn prune_order_removes_dependents_first() {
+            let installed = installed(&[("core/openssl/1.0.2/20160101000000",
+                                         vec!["core/glibc/2.22/20160101000000"]),
+                                        ("core/glibc/2.22/20160101000000", vec![])]);
+            let candidates = vec![ident("core/glibc/2.22/20160101000000"),
+                                  ident("core/openssl/1.0.2/20160101000000")];
+            let (pruned, kept) = prune_order(&candidates, &installed);
+            assert_eq!(pruned,
+                       vec![ident("core/openssl/1.0.2/20160101000000"),
+                            ident("core/glibc/2.22/20160101000000")]);
+            assert!(kept.is_empty());
+        }
+
+        #[test]
+        fn prune_order_keeps_dependencies_still_required() {
+            let installed = installed(&[("core/openssl/1.0.2/20160101000000",
+                                         vec!["core/glibc/2.22/20160101000000"]),
+                                        ("core/glibc/2.22/20160101000000", vec![]),
+                                        ("core/redis/3.0.7/20160101000000",
+                                         vec!["core/glibc/2.22/20160101000000"])]);
+            let candidates = vec![ident("core/glibc/2.22/20160101000000"),
+                                  ident("core/openssl/1.0.2/20160101000000")];
+            let (pruned, kept) = prune_order(&candidates, &installed);
+            assert_eq!(pruned, vec![ident("core/openssl/1.0.2/20160101000000")]);
+            assert_eq!(kept, vec![ident("core/glibc/2.22/20160101000000")]);
+        }
+
+        #[test]
+        fn prune_order_skips_packages_not_installed() {
+            let installed = installed(&[("core/glibc/2.22/20160101000000", vec![])]);
+            let candidates = vec![ident("core/zlib/1.2.8/20160101000000"),
+                                  ident("core/glibc/2.22/20160101000000")];
+            let (pruned, kept) = prune_order(&candidates, &installed);
+            assert_eq!(pruned, vec![ident("core/glibc/2.22/20160101000000")]);
+            assert!(kept.is_empty());
+        }
+    }
 }
 
 pub mod upload {
@@ -315,22 +918,39 @@ pub mod upload {
     //!
     //! Will upload a package to the Depot.
     //!
+    //! ```bash
+    //! $ hab pkg upload --all ./results -u http://localhost:9632
+    //! ```
+    //!
+    //! Will upload every package in `./results` the Depot doesn't already have, dependencies
+    //! first.
+    //!
+    //! With `--json` the releases uploaded, those the Depot already had and those which failed
+    //! are printed as JSON once the upload is over.
+    //!
     //! # Notes
     //!
     //! This should be extended to cover uploading specific packages, and finding them by ways more
     //! complex than just latest version.
     //!
 
+    use std::collections::{BTreeMap, HashMap, HashSet};
+    use std::fs;
     use std::path::{Path, PathBuf};
 
     use ansi_term::Colour::{Blue, Green, Red, Yellow};
     use common::command::ProgressBar;
+    use common::ui;
     use hcore::package::{PackageArchive, PackageIdent};
     use depot_client::{self, Client};
     use hyper::status::StatusCode;
+    use rustc_serialize::json::{Json, ToJson};
 
     use error::{Error, Result};
 
+    // Artifacts found by `start_all`, by package identifier, with their transitive dependencies.
+    type Artifacts = HashMap<String, (PackageIdent, PackageArchive, Vec<PackageIdent>)>;
+
     /// Upload a package from the cache to a Depot. The latest version/release of the package
     /// will be uploaded if not specified.
     ///
@@ -341,62 +961,197 @@ pub mod upload {
     /// * Fails if it cannot upload the file
     pub fn start<P: AsRef<Path>>(url: &str, token: &str, archive_path: &P) -> Result<()> {
         let mut archive = PackageArchive::new(PathBuf::from(archive_path.as_ref()));
-        println!("{}",
-                 Yellow.bold().paint(format!("» Uploading {}", archive_path.as_ref().display())));
+        ui::status(Yellow.bold()
+            .paint(format!("» Uploading {}", archive_path.as_ref().display())));
         let depot_client = try!(Client::new(url, None));
         let tdeps = try!(archive.tdeps());
+        let mut uploaded = vec![];
+        let mut present = vec![];
         for dep in tdeps.into_iter() {
             match depot_client.show_package(dep.clone()) {
-                Ok(_) => println!("{} {}", Green.paint("→ Exists"), &dep),
+                Ok(_) => {
+                    ui::status(format!("{} {}", Green.paint("→ Exists"), &dep));
+                    present.push(dep.to_string());
+                }
                 Err(depot_client::Error::RemotePackageNotFound(_)) => {
                     let candidate_path = match archive_path.as_ref().parent() {
                         Some(p) => PathBuf::from(p),
                         None => unreachable!(),
                     };
                     try!(attempt_upload_dep(&depot_client, token, &dep, &candidate_path));
+                    uploaded.push(dep.to_string());
                 }
                 Err(e) => return Err(Error::from(e)),
             }
         }
         let ident = try!(archive.ident());
         match depot_client.show_package(ident.clone()) {
-            Ok(_) => println!("{} {}", Green.paint("→ Exists"), &ident),
+            Ok(_) => {
+                ui::status(format!("{} {}", Green.paint("→ Exists"), &ident));
+                present.push(ident.to_string());
+            }
             Err(_) => {
                 try!(upload_into_depot(&depot_client, token, &ident, &mut archive));
+                uploaded.push(ident.to_string());
             }
         }
-        println!("{}",
-                 Blue.paint(format!("★ Upload of {} complete.", &ident)));
+        ui::report(&summary(&uploaded, &present, &[]), || {
+            println!("{}",
+                     Blue.paint(format!("★ Upload of {} complete.", &ident)));
+        });
         Ok(())
     }
 
+    /// Upload every Habitat Artifact in a directory, each after the artifacts in the directory it
+    /// depends on. Releases the Depot already has are skipped, and an artifact is not uploaded if
+    /// one it depends on could not be.
+    ///
+    /// # Failures
+    ///
+    /// * Fails if the directory cannot be read
+    /// * Fails if any artifact could not be read or uploaded
+    pub fn start_all<P: AsRef<Path>>(url: &str, token: &str, dir: &P) -> Result<()> {
+        let mut archives = Artifacts::new();
+        let mut failed = HashSet::new();
+        for entry in try!(fs::read_dir(dir.as_ref())) {
+            let path = try!(entry).path();
+            if path.extension().and_then(|e| e.to_str()) != Some("hart") {
+                continue;
+            }
+            let mut archive = PackageArchive::new(path);
+            match read_artifact(&mut archive) {
+                Ok((ident, tdeps)) => {
+                    archives.insert(ident.to_string(), (ident, archive, tdeps));
+                }
+                Err(e) => {
+                    ui::warn(format!("{} {}: {}",
+                                     Red.bold().paint("✗ Unreadable"),
+                                     archive.path.display(),
+                                     e));
+                    failed.insert(archive.path.display().to_string());
+                }
+            }
+        }
+        ui::status(Yellow.bold().paint(format!("» Uploading {} artifact(s) from {}",
+                                               archives.len(),
+                                               dir.as_ref().display())));
+
+        let mut names: Vec<String> = archives.keys().cloned().collect();
+        names.sort();
+        let mut order = Vec::with_capacity(names.len());
+        let mut visited = HashSet::new();
+        for name in names.iter() {
+            dependency_order(name, &archives, &mut visited, &mut order);
+        }
+
+        let depot_client = try!(Client::new(url, None));
+        let mut uploaded = vec![];
+        let mut present = vec![];
+        for name in order {
+            let &mut (ref ident, ref mut archive, ref tdeps) = archives.get_mut(&name).unwrap();
+            if let Some(dep) = tdeps.iter().find(|d| failed.contains(&d.to_string())) {
+                ui::warn(format!("{} {}, its dependency {} was not uploaded",
+                                 Red.bold().paint("✗ Skipping"),
+                                 ident,
+                                 dep));
+                failed.insert(name.clone());
+                continue;
+            }
+            let result = match depot_client.show_package(ident.clone()) {
+                Ok(_) => {
+                    ui::status(format!("{} {}", Green.paint("→ Exists"), ident));
+                    present.push(name.clone());
+                    continue;
+                }
+                Err(depot_client::Error::RemotePackageNotFound(_)) => {
+                    upload_into_depot(&depot_client, token, ident, archive)
+                }
+                Err(e) => Err(Error::from(e)),
+            };
+            match result {
+                Ok(()) => uploaded.push(name.clone()),
+                Err(e) => {
+                    ui::warn(format!("{} {}: {}", Red.bold().paint("✗ Failed"), ident, e));
+                    failed.insert(name.clone());
+                }
+            }
+        }
+        let mut failures: Vec<String> = failed.iter().cloned().collect();
+        failures.sort();
+        let summary = summary(&uploaded, &present, &failures);
+        let line = format!("★ Uploaded {} artifact(s), {} already present, {} failed",
+                           uploaded.len(),
+                           present.len(),
+                           failed.len());
+        if failed.is_empty() {
+            ui::report(&summary, || println!("{}", Blue.paint(line)));
+            Ok(())
+        } else {
+            // The summary is printed along with the error, so `--json` still prints one document.
+            ui::status(Blue.paint(line));
+            Err(Error::UploadFailed(failed.len(), summary))
+        }
+    }
+
+    fn read_artifact(archive: &mut PackageArchive) -> Result<(PackageIdent, Vec<PackageIdent>)> {
+        let ident = try!(archive.ident());
+        let tdeps = try!(archive.tdeps());
+        Ok((ident, tdeps))
+    }
+
+    // The outcome of an upload, as printed with `--json`.
+    fn summary(uploaded: &[String], present: &[String], failed: &[String]) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("uploaded".to_string(), uploaded.to_json());
+        m.insert("present".to_string(), present.to_json());
+        m.insert("failed".to_string(), failed.to_json());
+        Json::Object(m)
+    }
+
+    // Append `name` to `order` after every artifact in `archives` it transitively depends on.
+    fn dependency_order(name: &str,
+                        archives: &Artifacts,
+                        visited: &mut HashSet<String>,
+                        order: &mut Vec<String>) {
+        if !visited.insert(name.to_string()) {
+            return;
+        }
+        for dep in archives[name].2.iter() {
+            let dep = dep.to_string();
+            if archives.contains_key(&dep) {
+                dependency_order(&dep, archives, visited, order);
+            }
+        }
+        order.push(name.to_string());
+    }
+
     fn upload_into_depot(depot_client: &Client,
                          token: &str,
                          ident: &PackageIdent,
                          mut archive: &mut PackageArchive)
                          -> Result<()> {
-        println!("{} {}",
-                 Green.bold().paint("↑ Uploading"),
-                 archive.path.display());
+        ui::status(format!("{} {}",
+                           Green.bold().paint("↑ Uploading"),
+                           archive.path.display()));
         let mut progress = ProgressBar::default();
-        match depot_client.put_package(&mut archive, token, Some(&mut progress)) {
+        match depot_client.put_package(&mut archive, token, None, Some(&mut progress)) {
             Ok(()) => (),
             Err(depot_client::Error::HTTP(StatusCode::Conflict)) => {
-                println!("Package already exists on remote; skipping.");
+                ui::status("Package already exists on remote; skipping.");
             }
             Err(depot_client::Error::HTTP(StatusCode::UnprocessableEntity)) => {
                 return Err(Error::PackageArchiveMalformed(format!("{}", archive.path.display())));
             }
             Err(e @ depot_client::Error::HTTP(_)) => {
-                println!("Unexpected response from remote");
+                ui::warn("Unexpected response from remote");
                 return Err(Error::from(e));
             }
             Err(e) => {
-                println!("The package might exist on the remote - we fast abort, so.. :)");
+                ui::warn("The package might exist on the remote - we fast abort, so.. :)");
                 return Err(Error::from(e));
             }
         };
-        println!("{} {}", Green.bold().paint("✓ Uploaded"), ident);
+        ui::status(format!("{} {}", Green.bold().paint("✓ Uploaded"), ident));
         Ok(())
     }
 
@@ -418,39 +1173,179 @@ pub mod upload {
                     return Err(Error::PackageArchiveMalformed(e))
                 }
                 Err(e) => {
-                    println!("Unknown error encountered: {:?}", e);
+                    ui::warn(format!("Unknown error encountered: {:?}", e));
                     return Err(e);
                 }
             }
         } else {
-            println!("{} artifact for {} was not found in {}",
-                     Red.bold().paint("✗ Missing"),
-                     ident.archive_name().unwrap(),
-                     archives_dir.display());
+            ui::warn(format!("{} artifact for {} was not found in {}",
+                             Red.bold().paint("✗ Missing"),
+                             ident.archive_name().unwrap(),
+                             archives_dir.display()));
             return Err(Error::FileNotFound(archives_dir.to_string_lossy()
                 .into_owned()));
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use std::collections::HashSet;
+        use std::path::PathBuf;
+        use std::str::FromStr;
+
+        use hcore::package::{PackageArchive, PackageIdent};
+
+        use super::{dependency_order, Artifacts};
+
+        fn artifacts(pkgs: &[(&str, Vec<&str>)]) -> Artifacts {
+            pkgs.iter()
+                .map(|&(pkg, ref tdeps)| {
+                    let ident = PackageIdent::from_str(pkg).unwrap();
+                    let tdeps = tdeps.iter().map(|d| PackageIdent::from_str(d).unwrap()).collect();
+                    (pkg.to_string(), (ident, PackageArchive::new(PathBuf::from(pkg)), tdeps))
+                })
+                .collect()
+        }
+
+        fn order(archives: &Artifacts, names: &[&str]) -> Vec<String> {
+            let mut order = vec![];
+            let mut visited = HashSet::new();
+            for name in names {
+                dependency_order(name, archives, &mut visited, &mut order);
+            }
+            order
+        }
+
+        #[test]
+        fn dependencies_come_first() {
+            let archives = artifacts(&[("core/openssl/1.0.2/20160101000000",
+                                        vec!["core/glibc/2.22/20160101000000"]),
+                                       ("core/glibc/2.22/20160101000000", vec![])]);
+            assert_eq!(order(&archives, &["core/openssl/1.0.2/20160101000000"]),
+                       vec!["core/glibc/2.22/20160101000000",
+                            "core/openssl/1.0.2/20160101000000"]);
+        }
+
+        #[test]
+        fn shared_dependencies_are_ordered_once() {
+            let archives = artifacts(&[("core/app/1.0.0/20160101000000",
+                                        vec!["core/left/1.0.0/20160101000000",
+                                             "core/right/1.0.0/20160101000000",
+                                             "core/glibc/2.22/20160101000000"]),
+                                       ("core/left/1.0.0/20160101000000",
+                                        vec!["core/glibc/2.22/20160101000000"]),
+                                       ("core/right/1.0.0/20160101000000",
+                                        vec!["core/glibc/2.22/20160101000000"]),
+                                       ("core/glibc/2.22/20160101000000", vec![])]);
+            assert_eq!(order(&archives,
+                             &["core/app/1.0.0/20160101000000",
+                               "core/glibc/2.22/20160101000000",
+                               "core/left/1.0.0/20160101000000",
+                               "core/right/1.0.0/20160101000000"]),
+                       vec!["core/glibc/2.22/20160101000000",
+                            "core/left/1.0.0/20160101000000",
+                            "core/right/1.0.0/20160101000000",
+                            "core/app/1.0.0/20160101000000"]);
+        }
+
+        #[test]
+        fn dependencies_outside_the_directory_are_ignored() {
+            let archives = artifacts(&[("core/openssl/1.0.2/20160101000000",
+                                        vec!["core/glibc/2.22/20160101000000"])]);
+            assert_eq!(order(&archives, &["core/openssl/1.0.2/20160101000000"]),
+                       vec!["core/openssl/1.0.2/20160101000000"]);
+        }
+
+        #[test]
+        fn cycles_are_ordered_without_looping() {
+            let archives = artifacts(&[("core/a/1.0.0/20160101000000",
+                                        vec!["core/b/1.0.0/20160101000000"]),
+                                       ("core/b/1.0.0/20160101000000",
+                                        vec!["core/a/1.0.0/20160101000000"])]);
+            assert_eq!(order(&archives,
+                             &["core/a/1.0.0/20160101000000", "core/b/1.0.0/20160101000000"]),
+                       vec!["core/b/1.0.0/20160101000000", "core/a/1.0.0/20160101000000"]);
+        }
+    }
 }
 
 pub mod verify {
+    //! Verifies a Habitat Artifact's signature and checksum with the signing origin key.
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab pkg verify /path/to/acme-redis-2.0.7-2112010203120101-x86_64-linux.hart \
+    //!     -u http://localhost:9632
+    //! ```
+    //!
+    //! Will verify the artifact, downloading the public origin key it was signed with from the
+    //! Depot if it isn't already cached. An artifact which fails verification makes the command
+    //! exit with a failure, so it may be used to check artifacts before uploading them. If the
+    //! origin key can't be found the artifact can't be checked at all, which is reported apart
+    //! from tampering and exits with a status of its own. `--json` prints the signer and
+    //! checksum of a verified artifact.
+
+    use std::collections::BTreeMap;
     use std::path::Path;
 
-    use ansi_term::Colour::{Blue, Green, Yellow};
-    use hcore::crypto::artifact;
+    use ansi_term::Colour::{Blue, Green, Red, Yellow};
+    use common::command::ProgressBar;
+    use common::ui;
+    use depot_client::Client;
+    use hcore::crypto::{artifact, SigKeyPair};
+    use hcore::crypto::keys::parse_name_with_rev;
+    use rustc_serialize::json::{Json, ToJson};
 
-    use error::Result;
+    use error::{Error, Result};
 
-    pub fn start(src: &Path, cache: &Path) -> Result<()> {
-        println!("{}",
-                 Yellow.bold().paint(format!("» Verifying artifact {}", &src.display())));
-        let (name_with_rev, hash) = try!(artifact::verify(src, cache));
-        println!("{} checksum {} signed with {}",
-                 Green.bold().paint("✓ Verifed"),
-                 &hash,
-                 &name_with_rev);
-        println!("{}",
-                 Blue.paint(format!("★ Verified artifact {}.", &src.display())));
-        Ok(())
+    pub fn start(src: &Path, url: Option<&str>, fs_root_path: &Path, cache: &Path) -> Result<()> {
+        ui::status(Yellow.bold().paint(format!("» Verifying artifact {}", &src.display())));
+        let name_with_rev = try!(artifact::artifact_signer(src));
+        let (origin, revision) = try!(parse_name_with_rev(&name_with_rev));
+        ui::status(format!("  Signer:       {}", &origin));
+        ui::status(format!("  Key revision: {}", &revision));
+
+        if let Err(_) = SigKeyPair::get_public_key_path(&name_with_rev, cache) {
+            if let Some(url) = url {
+                ui::status(format!("{} {} public origin key",
+                                   Green.bold().paint("↓ Downloading"),
+                                   &name_with_rev));
+                let depot_client = try!(Client::new(url, Some(fs_root_path)));
+                let mut progress = ProgressBar::default();
+                try!(depot_client.fetch_origin_key(&origin, &revision, cache, Some(&mut progress)));
+                ui::status(format!("{} {} public origin key",
+                                   Green.bold().paint("☑ Cached"),
+                                   &name_with_rev));
+            }
+        }
+        if let Err(_) = SigKeyPair::get_public_key_path(&name_with_rev, cache) {
+            ui::warn(format!("  Tampered:     unknown, public origin key {} is not available",
+                             &name_with_rev));
+            return Err(Error::OriginKeyNotFound(name_with_rev));
+        }
+
+        match artifact::verify(src, cache) {
+            Ok((_, hash)) => {
+                ui::status(format!("  Checksum:     {}", &hash));
+                ui::status("  Tampered:     no");
+                let mut report = BTreeMap::new();
+                report.insert("artifact".to_string(), src.display().to_string().to_json());
+                report.insert("signer".to_string(), origin.to_json());
+                report.insert("revision".to_string(), revision.to_json());
+                report.insert("checksum".to_string(), hash.to_json());
+                ui::report(&Json::Object(report), || {
+                    println!("{}",
+                             Blue.paint(format!("★ Verified artifact {}.", &src.display())));
+                });
+                Ok(())
+            }
+            Err(e) => {
+                ui::warn("  Tampered:     yes");
+                ui::warn(Red.bold().paint(format!("✗ Failed to verify artifact {}",
+                                                  &src.display())));
+                Err(Error::from(e))
+            }
+        }
     }
 }
@@ -37,16 +37,28 @@ pub mod key {
         use std::path::Path;
 
         use ansi_term::Colour::{Blue, Yellow};
+        use common::ui;
         use hcore::crypto::SymKey;
 
         use error::Result;
 
         pub fn start(ring: &str, cache: &Path) -> Result<()> {
-            println!("{}",
-                     Yellow.bold().paint(format!("» Generating ring key for {}", &ring)));
+            ui::status(Yellow.bold().paint(format!("» Generating ring key for {}", &ring)));
             let pair = try!(SymKey::generate_pair_for_ring(ring, cache));
-            println!("{}",
-                     Blue.paint(format!("★ Generated ring key pair {}.", &pair.name_with_rev())));
+            ui::report(&pair.name_with_rev(), || {
+                println!("{}",
+                         Blue.paint(format!("★ Generated ring key pair {}.",
+                                            &pair.name_with_rev())));
+            });
+            if try!(SymKey::get_pairs_for(ring, cache)).len() > 1 {
+                ui::warn(format!("The ring key for {} has been rotated. Copy the new revision \
+                                  into the key cache of every supervisor in the ring (see `hab \
+                                  ring key export` and `hab ring key import`). Running \
+                                  supervisors accept messages encrypted with any revision of the \
+                                  ring key in their key cache, and begin encrypting with the new \
+                                  revision on their next start.",
+                                 &ring));
+            }
             Ok(())
         }
     }
@@ -55,18 +67,20 @@ pub mod key {
         use std::path::Path;
 
         use ansi_term::Colour::{Blue, Yellow};
+        use common::ui;
         use hcore::crypto::SymKey;
 
         use error::Result;
 
         pub fn start(content: &str, cache: &Path) -> Result<()> {
-            println!("{}",
-                     Yellow.bold().paint(format!("» Importing ring key from standard input")));
+            ui::status(Yellow.bold().paint(format!("» Importing ring key from standard input")));
             let (pair, pair_type) = try!(SymKey::write_file_from_str(content, cache));
-            println!("{}",
-                     Blue.paint(format!("★ Imported {} ring key {}.",
-                                        &pair_type,
-                                        &pair.name_with_rev())));
+            ui::report(&pair.name_with_rev(), || {
+                println!("{}",
+                         Blue.paint(format!("★ Imported {} ring key {}.",
+                                            &pair_type,
+                                            &pair.name_with_rev())));
+            });
             Ok(())
         }
     }
@@ -17,21 +17,23 @@ pub mod key {
         use std::path::Path;
 
         use ansi_term::Colour::{Blue, Yellow};
+        use common::ui;
         use hcore::crypto::BoxKeyPair;
         use hcore::service::ServiceGroup;
 
         use error::Result;
 
         pub fn start(org: &str, service_group: &ServiceGroup, cache: &Path) -> Result<()> {
-            println!("{}",
-                     Yellow.bold().paint(format!("» Generating service key for {} in {}",
-                                                 &service_group,
-                                                 org)));
+            ui::status(Yellow.bold().paint(format!("» Generating service key for {} in {}",
+                                                   &service_group,
+                                                   org)));
             let pair =
                 try!(BoxKeyPair::generate_pair_for_service(org, &service_group.to_string(), cache));
-            println!("{}",
-                     Blue.paint(format!("★ Generated service key pair {}.",
-                                        &pair.name_with_rev())));
+            ui::report(&pair.name_with_rev(), || {
+                println!("{}",
+                         Blue.paint(format!("★ Generated service key pair {}.",
+                                            &pair.name_with_rev())));
+            });
             Ok(())
         }
     }
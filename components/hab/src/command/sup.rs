@@ -73,6 +73,7 @@ mod inner {
     use std::ffi::OsString;
 
     use ansi_term::Colour::Yellow;
+    use common::ui;
 
     use error::{Error, Result};
 
@@ -81,7 +82,7 @@ mod inner {
         let msg = format!("∅ Launching a native Supervisor on this operating system is not yet \
                            supported. Try running this command again on a 64-bit Linux \
                            operating system.\n");
-        println!("{}", Yellow.bold().paint(msg));
+        ui::warn(Yellow.bold().paint(msg));
         Err(Error::SubcommandNotSupported(subcmd))
     }
 }
@@ -17,16 +17,19 @@ pub mod key {
         use std::path::Path;
 
         use ansi_term::Colour::{Blue, Yellow};
+        use common::ui;
         use hcore::crypto::BoxKeyPair;
 
         use error::Result;
 
         pub fn start(user: &str, cache: &Path) -> Result<()> {
-            println!("{}",
-                     Yellow.bold().paint(format!("» Generating user key for {}", &user)));
+            ui::status(Yellow.bold().paint(format!("» Generating user key for {}", &user)));
             let pair = try!(BoxKeyPair::generate_pair_for_user(user, cache));
-            println!("{}",
-                     Blue.paint(format!("★ Generated user key pair {}.", &pair.name_with_rev())));
+            ui::report(&pair.name_with_rev(), || {
+                println!("{}",
+                         Blue.paint(format!("★ Generated user key pair {}.",
+                                            &pair.name_with_rev())));
+            });
             Ok(())
         }
     }
@@ -22,6 +22,9 @@ use std::result;
 use depot_client;
 use common;
 use hcore;
+use http_client;
+use hyper;
+use rustc_serialize::json;
 
 pub type Result<T> = result::Result<T, Error>;
 
@@ -29,6 +32,9 @@ pub type Result<T> = result::Result<T, Error>;
 #[allow(dead_code)]
 pub enum Error {
     ArgumentError(&'static str),
+    BinlinkCollision((String, String)),
+    BuilderResponse(hyper::status::StatusCode),
+    CensusNotFound(String),
     CommandNotFoundInPkg((String, String)),
     CryptoCLI(String),
     DepotClient(depot_client::Error),
@@ -37,17 +43,37 @@ pub enum Error {
     FileNotFound(String),
     HabitatCommon(common::Error),
     HabitatCore(hcore::Error),
+    HttpClient(http_client::Error),
+    HyperError(hyper::error::Error),
     IO(io::Error),
+    JsonParser(json::ParserError),
+    NoJsonOutput(String),
+    OriginKeyNotFound(String),
     PackageArchiveMalformed(String),
+    PackageInUse((String, Vec<String>)),
     PathPrefixError(path::StripPrefixError),
+    ServiceNotStopped((String, i32)),
+    SidecarResponse(hyper::status::StatusCode),
     SubcommandNotSupported(String),
     UnsupportedExportFormat(String),
+    UploadFailed(usize, json::Json),
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
             Error::ArgumentError(ref e) => format!("{}", e),
+            Error::BinlinkCollision((ref d, ref c)) => {
+                format!("{} already exists and points to {}, use --force to replace it",
+                        d,
+                        c)
+            }
+            Error::BuilderResponse(ref status) => {
+                format!("Unexpected response from the Builder API: {}", status)
+            }
+            Error::CensusNotFound(ref sg) => {
+                format!("No census for service group {} is known to the supervisor", sg)
+            }
             Error::CommandNotFoundInPkg((ref p, ref c)) => {
                 format!("`{}' was not found under any 'PATH' directories in the {} package",
                         c,
@@ -62,25 +88,66 @@ impl fmt::Display for Error {
             Error::FileNotFound(ref e) => format!("File not found at: {}", e),
             Error::HabitatCommon(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
+            Error::HttpClient(ref err) => format!("{}", err),
+            Error::HyperError(ref err) => format!("{}", err),
             Error::IO(ref err) => format!("{}", err),
+            Error::JsonParser(ref err) => format!("{}", err),
             Error::PackageArchiveMalformed(ref e) => {
                 format!("Package archive was unreadable or contained unexpected contents: {:?}",
                         e)
             }
+            Error::NoJsonOutput(ref c) => format!("`hab {}' has no JSON output", c),
+            Error::OriginKeyNotFound(ref k) => {
+                format!("Public origin key {} not found, pass --url to download it", k)
+            }
+            Error::PackageInUse((ref p, ref d)) => {
+                format!("{} cannot be uninstalled, it is required by: {}",
+                        p,
+                        d.join(", "))
+            }
             Error::PathPrefixError(ref err) => format!("{}", err),
+            Error::ServiceNotStopped((ref p, ref pid)) => {
+                format!("The {} service process ({}) did not stop in time", p, pid)
+            }
+            Error::SidecarResponse(ref status) => {
+                format!("Unexpected response from the supervisor: {}", status)
+            }
             Error::SubcommandNotSupported(ref e) => {
                 format!("Subcommand `{}' not supported on this operating system", e)
             }
             Error::UnsupportedExportFormat(ref e) => format!("Unsupported export format: {}", e),
+            Error::UploadFailed(ref n, _) => format!("{} artifact(s) could not be uploaded", n),
         };
         write!(f, "{}", msg)
     }
 }
 
+impl Error {
+    /// Status the process exits with when a command fails with this error. Failures which mean
+    /// a check couldn't be made at all are told apart from those where it was made and failed.
+    /// What a command got done before failing, printed with the error in the JSON format.
+    pub fn outcome(&self) -> Option<json::Json> {
+        match *self {
+            Error::UploadFailed(_, ref summary) => Some(summary.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        match *self {
+            Error::OriginKeyNotFound(_) => 2,
+            _ => 1,
+        }
+    }
+}
+
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::ArgumentError(_) => "There was an error parsing an error or with it's value",
+            Error::BinlinkCollision(_) => "A file already exists at the binlink destination",
+            Error::BuilderResponse(_) => "Unexpected response from the Builder API",
+            Error::CensusNotFound(_) => "No census for the service group is known",
             Error::CommandNotFoundInPkg(_) => {
                 "Command was not found under any 'PATH' directories in the package"
             }
@@ -91,13 +158,22 @@ impl error::Error for Error {
             Error::FileNotFound(_) => "File not found",
             Error::HabitatCommon(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
+            Error::HttpClient(ref err) => err.description(),
+            Error::HyperError(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::JsonParser(ref err) => err.description(),
+            Error::NoJsonOutput(_) => "Command has no JSON output",
+            Error::OriginKeyNotFound(_) => "Public origin key not found",
             Error::PackageArchiveMalformed(_) => {
                 "Package archive was unreadable or had unexpected contents"
             }
+            Error::PackageInUse(_) => "Package is required by other installed packages",
             Error::PathPrefixError(ref err) => err.description(),
+            Error::ServiceNotStopped(_) => "Service process did not stop in time",
+            Error::SidecarResponse(_) => "Unexpected response from the supervisor",
             Error::SubcommandNotSupported(_) => "Subcommand not supported on this operating system",
             Error::UnsupportedExportFormat(_) => "Unsupported export format",
+            Error::UploadFailed(_, _) => "One or more artifacts could not be uploaded",
         }
     }
 }
@@ -126,12 +202,30 @@ impl From<hcore::Error> for Error {
     }
 }
 
+impl From<http_client::Error> for Error {
+    fn from(err: http_client::Error) -> Error {
+        Error::HttpClient(err)
+    }
+}
+
+impl From<hyper::error::Error> for Error {
+    fn from(err: hyper::error::Error) -> Error {
+        Error::HyperError(err)
+    }
+}
+
 impl From<io::Error> for Error {
     fn from(err: io::Error) -> Error {
         Error::IO(err)
     }
 }
 
+impl From<json::ParserError> for Error {
+    fn from(err: json::ParserError) -> Error {
+        Error::JsonParser(err)
+    }
+}
+
 impl From<path::StripPrefixError> for Error {
     fn from(err: path::StripPrefixError) -> Error {
         Error::PathPrefixError(err)
@@ -12,8 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-extern crate libc;
-
 use std;
 use std::ffi::{CString, OsString};
 use std::os::unix::ffi::OsStrExt;
@@ -22,10 +20,12 @@ use std::ptr;
 
 use ansi_term::Colour::Cyan;
 use common;
+use common::ui;
 use hcore;
 use hcore::fs::cache_artifact_path;
 use hcore::package::{PackageIdent, PackageInstall};
 use hcore::url::default_depot_url;
+use libc;
 
 use error::{Error, Result};
 
@@ -94,9 +94,8 @@ pub fn command_from_pkg(command: &str,
             }
         }
         Err(hcore::Error::PackageNotFound(_)) => {
-            println!("{}",
-                     Cyan.bold()
-                         .paint(format!("∵ Package for {} not found, installing", &ident)));
+            ui::status(Cyan.bold()
+                .paint(format!("∵ Package for {} not found, installing", &ident)));
             try!(common::command::package::install::from_url(&default_depot_url(),
                                                              ident,
                                                              fs_root_path,
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common::ui;
 use hcore::crypto::SymKey;
 
 use error::Result;
@@ -29,9 +30,9 @@ pub fn send_rumors_to_peers(peer_listeners: &Vec<String>,
                 return Ok(());
             } else {
                 count = count + 1;
-                println!("Could not connect to any initial peers; attempt {} of {}.",
-                         count,
-                         fail_after);
+                ui::warn(format!("Could not connect to any initial peers; attempt {} of {}.",
+                                 count,
+                                 fail_after));
             }
         }
     }
@@ -44,20 +45,20 @@ fn try_peers(peer_listeners: &Vec<String>,
              -> bool {
     let mut initialized = false;
     for to in peer_listeners {
-        println!("Joining peer: {}", to);
+        ui::status(format!("Joining peer: {}", to));
         let mut c = match hab_gossip::Client::new(&to[..], ring_key.clone()) {
             Ok(c) => c,
             Err(e) => {
                 debug!("Error creating gossip client - {:?}", e);
-                println!("Failed to create a gossip client for {}", to);
+                ui::warn(format!("Failed to create a gossip client for {}", to));
                 continue;
             }
         };
 
         match c.inject(rumor_list.clone()) {
-            Ok(_) => println!("Configuration applied to: {}", to),
+            Ok(_) => ui::status(format!("Configuration applied to: {}", to)),
             Err(e) => {
-                println!("Failed to ping {:?}: {:?}", to, e);
+                ui::warn(format!("Failed to ping {:?}: {:?}", to, e));
                 continue;
             }
         }
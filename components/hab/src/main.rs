@@ -22,6 +22,7 @@ extern crate ansi_term;
 extern crate clap;
 extern crate env_logger;
 extern crate hyper;
+extern crate libc;
 #[macro_use]
 extern crate log;
 extern crate pbr;
@@ -41,6 +42,7 @@ mod error;
 mod exec;
 mod gossip;
 
+use std::collections::BTreeMap;
 use std::env;
 use std::ffi::OsString;
 use std::io::{self, Read};
@@ -50,6 +52,8 @@ use std::thread;
 
 use ansi_term::Colour::Red;
 use clap::ArgMatches;
+use common::ui;
+use rustc_serialize::json::{Json, ToJson};
 
 use error::{Error, Result};
 use hcore::env as henv;
@@ -58,7 +62,7 @@ use hcore::crypto::keys::PairType;
 use hcore::fs::{cache_artifact_path, cache_analytics_path, FS_ROOT_PATH};
 use hcore::service::ServiceGroup;
 use hcore::package::PackageIdent;
-use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
+use hcore::url::{default_bldr_url, DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 
 use gossip::hab_gossip;
 
@@ -75,17 +79,32 @@ const HABITAT_USER_ENVVAR: &'static str = "HAB_USER";
 
 const FS_ROOT_ENVVAR: &'static str = "FS_ROOT";
 
-const DEFAULT_BINLINK_DIR: &'static str = "/bin";
+const DEFAULT_BINLINK_DIR: &'static str = "/usr/local/bin";
 
 const MAX_FILE_UPLOAD_SIZE_BYTES: u64 = 4096;
 
+/// Commands which are interactive, or whose output is something other than the outcome of the
+/// command, such as a key or a build log, and so refuse `--json`.
+const NO_JSON_OUTPUT: &'static [&'static str] = &["cli completions",
+                                                    "cli setup",
+                                                    "export",
+                                                    "job log",
+                                                    "origin key export",
+                                                    "pkg build",
+                                                    "pkg exec",
+                                                    "pkg export",
+                                                    "ring key export",
+                                                    "setup"];
+
 fn main() {
     env_logger::init().unwrap();
     thread::spawn(|| analytics::instrument_subcommand());
     if let Err(e) = start() {
-        println!("{}",
-                 Red.bold().paint(format!("✗✗✗\n✗✗✗ {}\n✗✗✗", e)));
-        std::process::exit(1)
+        ui::fatal(&e, e.outcome(), || {
+            println!("{}",
+                     Red.bold().paint(format!("✗✗✗\n✗✗✗ {}\n✗✗✗", e)));
+        });
+        std::process::exit(e.exit_code())
     }
 }
 
@@ -100,10 +119,19 @@ fn start() -> Result<()> {
             analytics::instrument_clap_error(&e);
             e.exit();
         });
+    ui::set_format(output_format(&app_matches));
+    if ui::format() == ui::Format::Json {
+        let command = subcommand_path(&app_matches);
+        if NO_JSON_OUTPUT.contains(&command.as_str()) {
+            return Err(Error::NoJsonOutput(command));
+        }
+    }
     match app_matches.subcommand() {
         ("apply", Some(m)) => try!(sub_config_apply(m)),
+        ("census", Some(m)) => try!(sub_census(m)),
         ("cli", Some(matches)) => {
             match matches.subcommand() {
+                ("completions", Some(m)) => try!(sub_cli_completions(m)),
                 ("setup", Some(_)) => try!(sub_cli_setup()),
                 _ => unreachable!(),
             }
@@ -120,7 +148,21 @@ fn start() -> Result<()> {
                 _ => unreachable!(),
             }
         }
+        ("export", Some(m)) => try!(sub_pkg_export(m)),
         ("install", Some(m)) => try!(sub_pkg_install(m)),
+        ("job", Some(matches)) => {
+            match matches.subcommand() {
+                ("log", Some(m)) => try!(sub_job_log(m)),
+                ("schedule", Some(m)) => {
+                    match m.subcommand() {
+                        ("create", Some(sc)) => try!(sub_job_schedule_create(sc)),
+                        ("list", Some(sc)) => try!(sub_job_schedule_list(sc)),
+                        _ => unreachable!(),
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
         ("origin", Some(matches)) => {
             match matches.subcommand() {
                 ("key", Some(m)) => {
@@ -140,12 +182,16 @@ fn start() -> Result<()> {
             match matches.subcommand() {
                 ("binlink", Some(m)) => try!(sub_pkg_binlink(m)),
                 ("build", Some(m)) => try!(sub_pkg_build(m)),
+                ("diff", Some(m)) => try!(sub_pkg_diff(m)),
                 ("exec", Some(m)) => try!(sub_pkg_exec(m, remaining_args)),
                 ("export", Some(m)) => try!(sub_pkg_export(m)),
                 ("hash", Some(m)) => try!(sub_pkg_hash(m)),
                 ("install", Some(m)) => try!(sub_pkg_install(m)),
+                ("list", Some(m)) => try!(sub_pkg_list(m)),
                 ("path", Some(m)) => try!(sub_pkg_path(m)),
+                ("show", Some(m)) => try!(sub_pkg_show(m)),
                 ("sign", Some(m)) => try!(sub_pkg_sign(m)),
+                ("uninstall", Some(m)) => try!(sub_pkg_uninstall(m)),
                 ("upload", Some(m)) => try!(sub_pkg_upload(m)),
                 ("verify", Some(m)) => try!(sub_pkg_verify(m)),
                 _ => unreachable!(),
@@ -192,6 +238,18 @@ fn start() -> Result<()> {
     Ok(())
 }
 
+fn sub_census(m: &ArgMatches) -> Result<()> {
+    let url = m.value_of("SIDECAR_URL").unwrap_or(command::census::DEFAULT_SIDECAR_URL);
+    let service_group = m.value_of("SERVICE_GROUP").unwrap();
+
+    command::census::start(url, service_group)
+}
+
+fn sub_cli_completions(m: &ArgMatches) -> Result<()> {
+    let shell = m.value_of("SHELL").unwrap();
+    command::cli::completions::start(shell)
+}
+
 fn sub_cli_setup() -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
@@ -288,6 +346,39 @@ fn sub_file_upload(m: &ArgMatches) -> Result<()> {
                                  file_path)
 }
 
+fn sub_job_log(m: &ArgMatches) -> Result<()> {
+    let env_or_default = default_bldr_url();
+    let url = m.value_of("BLDR_URL").unwrap_or(&env_or_default);
+    let job_id = value_t!(m, "JOB_ID", u64).unwrap_or_else(|e| e.exit());
+    let token = try!(auth_token_param_or_env(&m));
+
+    command::job::log::start(url, &token, job_id, m.is_present("FOLLOW"))
+}
+
+fn sub_job_schedule_create(m: &ArgMatches) -> Result<()> {
+    let env_or_default = default_bldr_url();
+    let url = m.value_of("BLDR_URL").unwrap_or(&env_or_default);
+    let project = m.value_of("PROJECT").unwrap();
+    let cron = m.value_of("CRON").unwrap();
+    let priority = if m.is_present("PRIORITY") {
+        Some(value_t!(m, "PRIORITY", u32).unwrap_or_else(|e| e.exit()))
+    } else {
+        None
+    };
+    let requires: Vec<&str> = m.values_of("REQUIRES").map(|r| r.collect()).unwrap_or(vec![]);
+    let token = try!(auth_token_param_or_env(&m));
+
+    command::job::schedule::create(url, &token, project, cron, priority, &requires)
+}
+
+fn sub_job_schedule_list(m: &ArgMatches) -> Result<()> {
+    let env_or_default = default_bldr_url();
+    let url = m.value_of("BLDR_URL").unwrap_or(&env_or_default);
+    let token = try!(auth_token_param_or_env(&m));
+
+    command::job::schedule::list(url, &token)
+}
+
 fn sub_origin_key_download(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
@@ -338,7 +429,7 @@ fn sub_origin_key_upload(m: &ArgMatches) -> Result<()> {
 
     let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
     let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
-    let token = try!(auth_token_param_or_env(&m));
+    let token = try!(depot_auth_token(url, &m));
 
     init();
 
@@ -362,10 +453,11 @@ fn sub_pkg_binlink(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Path::new(&fs_root);
     let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
-    let binary = m.value_of("BINARY").unwrap();
+    let binary = m.value_of("BINARY");
     let dest_dir = Path::new(m.value_of("DEST_DIR").unwrap_or(DEFAULT_BINLINK_DIR));
+    let force = m.is_present("FORCE");
 
-    command::pkg::binlink::start(&ident, &binary, &dest_dir, &fs_root_path)
+    command::pkg::binlink::start(&ident, binary, &dest_dir, &fs_root_path, force)
 }
 
 fn sub_pkg_build(m: &ArgMatches) -> Result<()> {
@@ -397,6 +489,15 @@ fn sub_pkg_build(m: &ArgMatches) -> Result<()> {
     command::pkg::build::start(plan_context, root, src, keys, reuse)
 }
 
+fn sub_pkg_diff(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Path::new(&fs_root);
+    let a = try!(PackageIdent::from_str(m.value_of("PKG_IDENT_A").unwrap()));
+    let b = try!(PackageIdent::from_str(m.value_of("PKG_IDENT_B").unwrap()));
+
+    command::pkg::diff::start(&a, &b, &fs_root_path)
+}
+
 fn sub_pkg_exec(m: &ArgMatches, cmd_args: Vec<OsString>) -> Result<()> {
     let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
     let cmd = m.value_of("CMD").unwrap();
@@ -426,16 +527,32 @@ fn sub_pkg_install(m: &ArgMatches) -> Result<()> {
     let ident_or_artifacts = m.values_of("PKG_IDENT_OR_ARTIFACT").unwrap();
     init();
 
+    let mut installed = vec![];
     for ident_or_artifact in ident_or_artifacts {
-        try!(common::command::package::install::start(url,
-                                                      ident_or_artifact,
-                                                      Path::new(&fs_root),
-                                                      &cache_artifact_path(fs_root_path),
-                                                      &default_cache_key_path(fs_root_path)));
+        let ident =
+            try!(common::command::package::install::start(url,
+                                                          ident_or_artifact,
+                                                          Path::new(&fs_root),
+                                                          &cache_artifact_path(fs_root_path),
+                                                          &default_cache_key_path(fs_root_path)));
+        installed.push(ident.to_string());
     }
+    let mut report = BTreeMap::new();
+    report.insert("installed".to_string(), installed.to_json());
+    ui::report(&Json::Object(report), || ());
     Ok(())
 }
 
+fn sub_pkg_list(m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let mut parts = m.value_of("ORIGIN_OR_NAME").unwrap().splitn(2, '/');
+    let origin = parts.next().unwrap();
+    let name = parts.next();
+
+    command::pkg::list::start(url, origin, name)
+}
+
 fn sub_pkg_path(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Path::new(&fs_root);
@@ -444,6 +561,14 @@ fn sub_pkg_path(m: &ArgMatches) -> Result<()> {
     command::pkg::path::start(&ident, &fs_root_path)
 }
 
+fn sub_pkg_show(m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
+
+    command::pkg::show::start(url, &ident)
+}
+
 fn sub_pkg_sign(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
@@ -456,10 +581,23 @@ fn sub_pkg_sign(m: &ArgMatches) -> Result<()> {
     command::pkg::sign::start(&pair, &src, &dst)
 }
 
+fn sub_pkg_uninstall(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Path::new(&fs_root);
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
+    let dest_dir = Path::new(m.value_of("DEST_DIR").unwrap_or(DEFAULT_BINLINK_DIR));
+    let prune = m.is_present("PRUNE");
+
+    command::pkg::uninstall::start(&ident, &dest_dir, &fs_root_path, prune)
+}
+
 fn sub_pkg_upload(m: &ArgMatches) -> Result<()> {
     let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
     let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
-    let token = try!(auth_token_param_or_env(&m));
+    let token = try!(depot_auth_token(url, &m));
+    if let Some(dir) = m.value_of("ALL") {
+        return command::pkg::upload::start_all(&url, &token, &dir);
+    }
     let artifact_paths = m.values_of("HART_FILE").unwrap();
     for artifact_path in artifact_paths {
         try!(command::pkg::upload::start(&url, &token, &artifact_path));
@@ -469,11 +607,15 @@ fn sub_pkg_upload(m: &ArgMatches) -> Result<()> {
 
 fn sub_pkg_verify(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
-    let fs_root_path = Some(Path::new(&fs_root));
+    let fs_root_path = Path::new(&fs_root);
     let src = Path::new(m.value_of("SOURCE").unwrap());
+    let url = m.value_of("DEPOT_URL");
     init();
 
-    command::pkg::verify::start(&src, &default_cache_key_path(fs_root_path))
+    command::pkg::verify::start(&src,
+                                url,
+                                fs_root_path,
+                                &default_cache_key_path(Some(fs_root_path)))
 }
 
 fn sub_ring_key_export(m: &ArgMatches) -> Result<()> {
@@ -537,6 +679,36 @@ fn exec_subcommand_if_called() -> Result<()> {
     }
 }
 
+/// The output format asked for with `--json` or `--quiet`, which may be given after any of the
+/// subcommands.
+fn output_format(m: &ArgMatches) -> ui::Format {
+    if m.is_present("JSON") {
+        return ui::Format::Json;
+    }
+    if m.is_present("QUIET") {
+        return ui::Format::Quiet;
+    }
+    match m.subcommand() {
+        (_, Some(sc)) => output_format(sc),
+        _ => ui::Format::Human,
+    }
+}
+
+/// The names of the subcommands given, separated by spaces, such as `origin key export`.
+fn subcommand_path(m: &ArgMatches) -> String {
+    match m.subcommand() {
+        (name, Some(sc)) => {
+            let rest = subcommand_path(sc);
+            if rest.is_empty() {
+                name.to_string()
+            } else {
+                format!("{} {}", name, rest)
+            }
+        }
+        _ => String::new(),
+    }
+}
+
 /// Parse the raw program arguments and split off any arguments that will skip clap's parsing.
 ///
 /// **Note** with the current version of clap there is no clean way to ignore arguments after a
@@ -555,6 +727,16 @@ fn raw_parse_args() -> (Vec<OsString>, Vec<OsString>) {
     }
 }
 
+/// The auth token for uploading to a Depot. A Depot in a local directory, given as a `file://`
+/// URL, doesn't need one.
+fn depot_auth_token(url: &str, m: &ArgMatches) -> Result<String> {
+    if url.starts_with("file://") {
+        Ok(String::new())
+    } else {
+        auth_token_param_or_env(m)
+    }
+}
+
 /// Check to see if the user has passed in an AUTH_TOKEN param. If not, check the
 /// HABITAT_AUTH_TOKEN env var. If not, check the CLI config to see if there is a default auth
 /// token set. If that's empty too, then error.
@@ -626,3 +808,45 @@ fn user_param_or_env(m: &ArgMatches) -> Result<String> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use clap::ErrorKind;
+
+    use cli;
+    use super::{subcommand_path, NO_JSON_OUTPUT};
+
+    fn path_of(args: &[&str]) -> String {
+        subcommand_path(&cli::get().get_matches_from_safe(args).unwrap())
+    }
+
+    #[test]
+    fn subcommand_path_names_every_subcommand() {
+        assert_eq!(path_of(&["hab", "census", "redis.default"]), "census");
+        assert_eq!(path_of(&["hab", "ring", "key", "export", "acme"]), "ring key export");
+        // Aliases and global flags don't change the path.
+        assert_eq!(path_of(&["hab", "r", "k", "exp", "acme"]), "ring key export");
+        assert_eq!(path_of(&["hab", "--json", "ring", "key", "generate", "acme"]),
+                   "ring key generate");
+    }
+
+    #[test]
+    fn no_json_output_names_only_commands() {
+        for command in NO_JSON_OUTPUT {
+            let mut args = vec!["hab"];
+            args.extend(command.split(' '));
+            args.push("--help");
+            match cli::get().get_matches_from_safe(args) {
+                Err(ref e) if e.kind == ErrorKind::HelpDisplayed => (),
+                other => panic!("`hab {}' is not a command, {:?}", command, other.map(|_| ())),
+            }
+        }
+    }
+
+    #[test]
+    fn no_json_output_leaves_out_commands_with_an_outcome() {
+        assert!(NO_JSON_OUTPUT.contains(&"origin key export"));
+        assert!(!NO_JSON_OUTPUT.contains(&"origin key generate"));
+        assert!(!NO_JSON_OUTPUT.contains(&"pkg upload"));
+    }
+}
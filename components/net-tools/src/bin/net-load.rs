@@ -0,0 +1,723 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Synthetic load generator for the `RouteSrv` mesh.
+//!
+//! `net-load` starts a `Broker` and a number of client threads which route a configurable mix of
+//! messages through one or more `RouteSrv`s, and reports the throughput and latency distribution
+//! of each kind of message once the run is over. Echo messages are answered by responders started
+//! in the same process, or by those of another `net-load` run with `--clients 0`, which register
+//! with the routers as `Net` services on every shard. Health and stats queries are answered by the
+//! same responders' dispatch loops.
+//!
+//! Without `--router` the tool runs its own `RouteSrv` in the process, listening on
+//! `EMBEDDED_ROUTER_PORT`, so a run needs nothing else to be running. Pass the addresses of
+//! running routers to drive traffic through them instead.
+//!
+//! When sending at a fixed `--rate` each request's latency is measured from the time it was
+//! scheduled to be sent, not the time it was sent, so a stall which delays the requests queued
+//! behind it shows up in their latencies rather than being hidden by the client falling behind.
+
+#[macro_use]
+extern crate clap;
+extern crate habitat_builder_protocol as protocol;
+extern crate habitat_builder_router as router;
+extern crate habitat_net as hab_net;
+#[macro_use]
+extern crate log;
+extern crate protobuf;
+extern crate time;
+extern crate zmq;
+
+use std::net;
+use std::process;
+use std::str::FromStr;
+use std::sync::{mpsc, Arc, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use hab_net::config::{Keepalive, MessageAuth, Metrics, RouteAddrs, RouterAuth, Shards};
+use hab_net::health::{self, HealthCheck};
+use hab_net::routing::{Broker, BrokerConn, BrokerContext};
+use hab_net::runtime::{self, Runtime};
+use hab_net::server::{Application, Envelope, NetIdent, RouteConn, Service, Supervisor,
+                      Supervisable};
+use hab_net::stats;
+use hab_net::{Error, Result};
+use protobuf::parse_from_bytes;
+use protocol::net::{self as proto, Protocol};
+use protocol::sharding::{ShardId, SHARD_COUNT};
+
+const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+const BE_LISTEN_ADDR: &'static str = "inproc://backend";
+// Ports of the router run in the process when no `--router` is given, away from those of a
+// router which may already be running on the host.
+const EMBEDDED_ROUTER_PORT: u16 = 15562;
+const EMBEDDED_HEARTBEAT_PORT: u16 = 15563;
+const PERCENTILES: &'static [(&'static str, f64)] = &[("p50", 0.5),
+                                                        ("p90", 0.9),
+                                                        ("p99", 0.99),
+                                                        ("p99.9", 0.999)];
+
+fn main() {
+    hab_net::logging::init().unwrap();
+    let matches = app().get_matches();
+    debug!("CLI matches: {:?}", matches);
+    let opts = match Opts::from_matches(&matches) {
+        Ok(opts) => opts,
+        Err(msg) => return exit_with(msg, 1),
+    };
+    if opts.embed_router {
+        start_router(&opts);
+    }
+    let responders = if opts.responders > 0 {
+        match start_responders(&opts) {
+            Ok(handle) => Some(handle),
+            Err(e) => return exit_with(format!("unable to start responders, {}", e), 1),
+        }
+    } else {
+        None
+    };
+    if opts.clients == 0 {
+        if let Some(handle) = responders {
+            println!("Responding until interrupted");
+            let _ = handle.join();
+        }
+        return;
+    }
+    let report = run_load(&opts);
+    let failed = report.errors() > 0;
+    report.print();
+    // Responders are left running on their own threads; exiting stops them.
+    process::exit(if failed { 2 } else { 0 })
+}
+
+fn app<'a, 'b>() -> clap::App<'a, 'b> {
+    clap_app!(NetLoad =>
+        (version: VERSION)
+        (about: "Drive synthetic traffic through a Habitat-Builder router mesh")
+        (@arg router: -r --router +takes_value +multiple
+            "Net address of a running router to connect to. A router is run in the process if \
+             none is given.")
+        (@arg heartbeat_port: --("heartbeat-port") +takes_value
+            "Running routers' heartbeat port for responders to register on. [default: 5563]")
+        (@arg responders: --responders +takes_value
+            "Echo responder threads to run in this process, 0 to rely on another. [default: 4]")
+        (@arg clients: -c --clients +takes_value
+            "Client threads sending requests, 0 to only run responders. [default: 4]")
+        (@arg rate: --rate +takes_value
+            "Total requests per second across all clients, 0 for as fast as replies arrive. \
+             [default: 0]")
+        (@arg duration: -d --duration +takes_value
+            "Length of the run in seconds. [default: 30]")
+        (@arg payload: --payload +takes_value
+            "Comma separated echo payload sizes in bytes, picked at random. [default: 64]")
+        (@arg mix: --mix +takes_value
+            "Comma separated kind=weight message mix of echo, health and stats. \
+             [default: echo=1]")
+        (@arg seed: --seed +takes_value
+            "Seed for the message mix and payload generator. [default: 1]")
+    )
+}
+
+fn exit_with<T: AsRef<str>>(msg: T, code: i32) {
+    println!("{}", msg.as_ref());
+    process::exit(code)
+}
+
+/// Kinds of message a client sends and the reply each expects.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kind {
+    Echo,
+    Health,
+    Stats,
+}
+
+const KINDS: &'static [Kind] = &[Kind::Echo, Kind::Health, Kind::Stats];
+
+impl Kind {
+    fn name(&self) -> &'static str {
+        match *self {
+            Kind::Echo => "echo",
+            Kind::Health => "health",
+            Kind::Stats => "stats",
+        }
+    }
+
+    fn reply_id(&self) -> &'static str {
+        match *self {
+            Kind::Echo => "Echo",
+            Kind::Health => "Health",
+            Kind::Stats => "Stats",
+        }
+    }
+
+    fn index(&self) -> usize {
+        KINDS.iter().position(|k| k == self).unwrap()
+    }
+}
+
+impl FromStr for Kind {
+    type Err = String;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        KINDS.iter()
+            .find(|k| k.name() == value)
+            .map(|k| *k)
+            .ok_or_else(|| {
+                format!("unknown message kind '{}', expected echo, health or stats",
+                        value)
+            })
+    }
+}
+
+struct Opts {
+    routers: Vec<net::SocketAddrV4>,
+    embed_router: bool,
+    heartbeat_port: u16,
+    responders: usize,
+    clients: usize,
+    rate: u64,
+    duration_secs: u64,
+    payloads: Vec<usize>,
+    mix: Vec<(Kind, u32)>,
+    seed: u64,
+}
+
+impl Opts {
+    fn from_matches(matches: &clap::ArgMatches) -> std::result::Result<Self, String> {
+        let (routers, heartbeat_port) = match matches.values_of("router") {
+            Some(values) => {
+                let routers: Vec<net::SocketAddrV4> =
+                    try!(values.map(|v| parse_arg("router", v)).collect());
+                let port = try!(parse_arg("heartbeat-port",
+                                          matches.value_of("heartbeat_port").unwrap_or("5563")));
+                (routers, port)
+            }
+            None => {
+                let addr = net::SocketAddrV4::new(net::Ipv4Addr::new(127, 0, 0, 1),
+                                                  EMBEDDED_ROUTER_PORT);
+                (vec![addr], EMBEDDED_HEARTBEAT_PORT)
+            }
+        };
+        let payloads: Vec<usize> = try!(matches.value_of("payload")
+            .unwrap_or("64")
+            .split(',')
+            .map(|v| parse_arg("payload", v.trim()))
+            .collect());
+        let mix = try!(parse_mix(matches.value_of("mix").unwrap_or("echo=1")));
+        Ok(Opts {
+            embed_router: matches.values_of("router").is_none(),
+            routers: routers,
+            heartbeat_port: heartbeat_port,
+            responders: try!(parse_arg("responders",
+                                       matches.value_of("responders").unwrap_or("4"))),
+            clients: try!(parse_arg("clients", matches.value_of("clients").unwrap_or("4"))),
+            rate: try!(parse_arg("rate", matches.value_of("rate").unwrap_or("0"))),
+            duration_secs: try!(parse_arg("duration",
+                                          matches.value_of("duration").unwrap_or("30"))),
+            payloads: payloads,
+            mix: mix,
+            seed: try!(parse_arg("seed", matches.value_of("seed").unwrap_or("1"))),
+        })
+    }
+}
+
+fn parse_arg<T: FromStr>(name: &str, value: &str) -> std::result::Result<T, String> {
+    T::from_str(value).map_err(|_| format!("invalid value for --{}: '{}'", name, value))
+}
+
+fn parse_mix(value: &str) -> std::result::Result<Vec<(Kind, u32)>, String> {
+    let mut mix = vec![];
+    for entry in value.split(',') {
+        let mut parts = entry.trim().splitn(2, '=');
+        let kind: Kind = try!(parts.next().unwrap().parse());
+        let weight = try!(parse_arg("mix", parts.next().unwrap_or("1")));
+        if weight > 0 {
+            mix.push((kind, weight));
+        }
+    }
+    if mix.is_empty() {
+        return Err(format!("invalid value for --mix: '{}', no kind has a weight", value));
+    }
+    Ok(mix)
+}
+
+// Xorshift generator so a run's sequence of messages is reproducible from its seed.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed.wrapping_mul(0x9e3779b97f4a7c15) | 1)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    fn pick_kind(&mut self, mix: &[(Kind, u32)]) -> Kind {
+        let total = mix.iter().fold(0, |acc, &(_, w)| acc + w as u64);
+        let mut roll = self.below(total);
+        for &(kind, weight) in mix {
+            if roll < weight as u64 {
+                return kind;
+            }
+            roll -= weight as u64;
+        }
+        unreachable!()
+    }
+
+    fn payload(&mut self, sizes: &[usize]) -> Vec<u8> {
+        let size = sizes[self.below(sizes.len() as u64) as usize];
+        (0..size).map(|_| self.next_u64() as u8).collect()
+    }
+}
+
+// Echo service registered with the routers as the `Net` protocol.
+struct Config {
+    routers: Vec<net::SocketAddrV4>,
+    heartbeat_port: u16,
+    shards: Vec<ShardId>,
+    worker_threads: usize,
+    hmac_keys: Vec<String>,
+}
+
+impl MessageAuth for Config {
+    fn hmac_keys(&self) -> &[String] {
+        &self.hmac_keys
+    }
+}
+
+impl Keepalive for Config {}
+
+impl RouterAuth for Config {}
+
+impl Metrics for Config {}
+
+impl RouteAddrs for Config {
+    fn route_addrs(&self) -> &Vec<net::SocketAddrV4> {
+        &self.routers
+    }
+
+    fn heartbeat_port(&self) -> u16 {
+        self.heartbeat_port
+    }
+}
+
+impl Shards for Config {
+    fn shards(&self) -> &Vec<u32> {
+        &self.shards
+    }
+}
+
+struct Worker {
+    sock: zmq::Socket,
+}
+
+impl Supervisable for Worker {
+    type Config = Config;
+    type Error = Error;
+
+    fn new(context: &mut zmq::Context, _config: Arc<RwLock<Config>>) -> Self {
+        let sock = context.socket(zmq::DEALER).unwrap();
+        Worker { sock: sock }
+    }
+
+    fn on_message(&mut self, req: &mut Envelope) -> Result<()> {
+        match req.message_id() {
+            "Echo" => {
+                let msg: proto::Echo = try!(req.parse_msg());
+                try!(req.reply_complete(&mut self.sock, &msg));
+            }
+            _ => {
+                warn!("unexpected message: {}", req.message_id());
+                let err = proto::err(proto::ErrCode::BUG, "nl:dispatch:0");
+                try!(req.reply_complete(&mut self.sock, &err));
+            }
+        }
+        Ok(())
+    }
+
+    fn socket(&mut self) -> &mut zmq::Socket {
+        &mut self.sock
+    }
+}
+
+impl HealthCheck for Worker {}
+
+struct Responder {
+    config: Arc<RwLock<Config>>,
+    ctx: Arc<RwLock<zmq::Context>>,
+    router: RouteConn,
+    be_sock: zmq::Socket,
+    ready: mpsc::Sender<()>,
+}
+
+impl Responder {
+    fn new(config: Config, ready: mpsc::Sender<()>) -> Result<Self> {
+        let mut ctx = zmq::Context::new();
+        let router = try!(RouteConn::new(Self::net_ident(), &mut ctx));
+        let be = try!(ctx.socket(zmq::DEALER));
+        Ok(Responder {
+            config: Arc::new(RwLock::new(config)),
+            ctx: Arc::new(RwLock::new(ctx)),
+            router: router,
+            be_sock: be,
+            ready: ready,
+        })
+    }
+}
+
+impl Application for Responder {
+    type Error = Error;
+
+    fn run(&mut self) -> Result<()> {
+        try!(self.be_sock.bind(BE_LISTEN_ADDR));
+        let ctx = self.ctx.clone();
+        let cfg = self.config.clone();
+        let sup: Supervisor<Worker> = Supervisor::new(ctx, cfg);
+        let sup = {
+            let cfg = self.config.read().unwrap();
+            try!(sup.spawn(BE_LISTEN_ADDR, cfg.worker_threads))
+        };
+        try!(self.connect());
+        let _ = self.ready.send(());
+        let router = &mut self.router;
+        let be_sock = &mut self.be_sock;
+        try!(Runtime::new()
+            .trap_signals()
+            .with(sup)
+            .run_with(|shutdown| runtime::serve(router, be_sock, shutdown)));
+        Ok(())
+    }
+}
+
+impl Service for Responder {
+    type Application = Self;
+    type Config = Config;
+    type Error = Error;
+
+    fn protocol() -> Protocol {
+        Protocol::Net
+    }
+
+    fn config(&self) -> &Arc<RwLock<Self::Config>> {
+        &self.config
+    }
+
+    fn conn(&self) -> &RouteConn {
+        &self.router
+    }
+
+    fn conn_mut(&mut self) -> &mut RouteConn {
+        &mut self.router
+    }
+}
+
+impl NetIdent for Responder {
+    fn component() -> Option<&'static str> {
+        Some("net-load-responder")
+    }
+}
+
+struct LoadClient;
+
+impl NetIdent for LoadClient {
+    fn component() -> Option<&'static str> {
+        Some("net-load")
+    }
+}
+
+// Run a router on its own thread, listening on the first of the routers the run connects to. The
+// thread runs until the process exits.
+fn start_router(opts: &Opts) {
+    let mut config = router::Config::default();
+    config.listen_addr = opts.routers[0];
+    config.heartbeat_port = opts.heartbeat_port;
+    thread::Builder::new()
+        .name("router".to_string())
+        .spawn(move || if let Err(e) = router::server::run(config) {
+            println!("Router stopped, {}", e);
+        })
+        .unwrap();
+}
+
+// Start the echo responders on their own thread, returning once they have registered with every
+// router.
+fn start_responders(opts: &Opts) -> Result<JoinHandle<()>> {
+    let config = Config {
+        routers: opts.routers.clone(),
+        heartbeat_port: opts.heartbeat_port,
+        shards: (0..SHARD_COUNT).collect(),
+        worker_threads: opts.responders,
+        hmac_keys: vec![],
+    };
+    let (tx, rx) = mpsc::channel();
+    let mut responder = try!(Responder::new(config, tx));
+    let handle = try!(thread::Builder::new().name("responder".to_string()).spawn(move || {
+        if let Err(e) = responder.run() {
+            println!("Responders stopped, {}", e);
+        }
+    }));
+    match rx.recv() {
+        Ok(()) => Ok(handle),
+        Err(_) => Err(Error::ComponentFailed("responder".to_string())),
+    }
+}
+
+// Latencies and failures recorded for one kind of message.
+#[derive(Default)]
+struct Sample {
+    latencies_ns: Vec<u64>,
+    errors: u64,
+}
+
+impl Sample {
+    fn merge(&mut self, other: Sample) {
+        self.latencies_ns.extend(other.latencies_ns);
+        self.errors += other.errors;
+    }
+}
+
+struct Report {
+    elapsed_secs: f64,
+    samples: Vec<Sample>,
+}
+
+impl Report {
+    fn errors(&self) -> u64 {
+        self.samples.iter().fold(0, |acc, s| acc + s.errors)
+    }
+
+    fn print(mut self) {
+        let mut header = format!("{:<8} {:>10} {:>8} {:>10}", "kind", "ok", "errors", "msg/s");
+        for &(name, _) in PERCENTILES {
+            header.push_str(&format!(" {:>9}", format!("{} ms", name)));
+        }
+        header.push_str(&format!(" {:>9}", "max ms"));
+        println!("{}", header);
+        let mut total = Sample::default();
+        for (i, mut sample) in self.samples.drain(..).enumerate() {
+            if sample.latencies_ns.is_empty() && sample.errors == 0 {
+                continue;
+            }
+            print_row(KINDS[i].name(), &mut sample, self.elapsed_secs);
+            total.merge(sample);
+        }
+        print_row("total", &mut total, self.elapsed_secs);
+    }
+}
+
+fn print_row(name: &str, sample: &mut Sample, elapsed_secs: f64) {
+    sample.latencies_ns.sort();
+    let latencies_ns = &sample.latencies_ns;
+    let mut row = format!("{:<8} {:>10} {:>8} {:>10.1}",
+                          name,
+                          latencies_ns.len(),
+                          sample.errors,
+                          latencies_ns.len() as f64 / elapsed_secs);
+    for &(_, p) in PERCENTILES {
+        row.push_str(&format!(" {:>9}", fmt_ms(percentile(latencies_ns, p))));
+    }
+    row.push_str(&format!(" {:>9}", fmt_ms(latencies_ns.last().cloned())));
+    println!("{}", row);
+}
+
+fn percentile(sorted: &[u64], p: f64) -> Option<u64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.saturating_sub(1).min(sorted.len() - 1)])
+}
+
+fn fmt_ms(ns: Option<u64>) -> String {
+    match ns {
+        Some(ns) => format!("{:.3}", ns as f64 / 1_000_000.0),
+        None => "-".to_string(),
+    }
+}
+
+fn run_load(opts: &Opts) -> Report {
+    let ctx = Arc::new(BrokerContext::new());
+    let _broker = Broker::run(LoadClient::net_ident(), ctx.clone(), &opts.routers);
+    println!("Sending {} for {}s from {} clients at {}",
+             opts.mix
+                 .iter()
+                 .map(|&(kind, weight)| format!("{}={}", kind.name(), weight))
+                 .collect::<Vec<String>>()
+                 .join(","),
+             opts.duration_secs,
+             opts.clients,
+             if opts.rate > 0 {
+                 format!("{} msg/s", opts.rate)
+             } else {
+                 "full speed".to_string()
+             });
+    let start_ns = time::precise_time_ns();
+    let deadline_ns = start_ns + opts.duration_secs * 1_000_000_000;
+    // Each client sends its share of the total rate.
+    let interval_ns = if opts.rate > 0 {
+        Some(opts.clients as u64 * 1_000_000_000 / opts.rate)
+    } else {
+        None
+    };
+    let handles: Vec<JoinHandle<Vec<Sample>>> = (0..opts.clients)
+        .map(|i| {
+            let ctx = ctx.clone();
+            let mix = opts.mix.clone();
+            let payloads = opts.payloads.clone();
+            let mut rng = Rng::new(opts.seed.wrapping_add(i as u64));
+            thread::Builder::new()
+                .name(format!("client-{}", i))
+                .spawn(move || {
+                    run_client(&ctx, &mut rng, &mix, &payloads, interval_ns, deadline_ns)
+                })
+                .unwrap()
+        })
+        .collect();
+    let mut samples: Vec<Sample> = KINDS.iter().map(|_| Sample::default()).collect();
+    for handle in handles {
+        match handle.join() {
+            Ok(client) => {
+                for (i, sample) in client.into_iter().enumerate() {
+                    samples[i].merge(sample);
+                }
+            }
+            Err(_) => error!("client thread panicked"),
+        }
+    }
+    Report {
+        elapsed_secs: (time::precise_time_ns() - start_ns) as f64 / 1_000_000_000.0,
+        samples: samples,
+    }
+}
+
+fn run_client(ctx: &BrokerContext,
+              rng: &mut Rng,
+              mix: &[(Kind, u32)],
+              payloads: &[usize],
+              interval_ns: Option<u64>,
+              deadline_ns: u64)
+              -> Vec<Sample> {
+    let mut samples: Vec<Sample> = KINDS.iter().map(|_| Sample::default()).collect();
+    let mut conn = Broker::connect(ctx).unwrap();
+    let mut next_ns = time::precise_time_ns();
+    loop {
+        let now_ns = time::precise_time_ns();
+        if now_ns >= deadline_ns {
+            break;
+        }
+        // Requests sent at a fixed rate are timed from when they were due, so the time they spent
+        // waiting behind a slow reply counts towards their latency.
+        let started_ns = match interval_ns {
+            Some(interval) => {
+                if next_ns > now_ns {
+                    let wait_ns = next_ns - now_ns;
+                    thread::sleep(Duration::new(wait_ns / 1_000_000_000,
+                                                (wait_ns % 1_000_000_000) as u32));
+                }
+                let due_ns = next_ns;
+                next_ns += interval;
+                due_ns
+            }
+            None => now_ns,
+        };
+        let kind = rng.pick_kind(mix);
+        let sample = &mut samples[kind.index()];
+        match request(&mut conn, kind, rng, payloads) {
+            Ok(()) => sample.latencies_ns.push(time::precise_time_ns() - started_ns),
+            Err(e) => {
+                debug!("{} request failed, {}", kind.name(), e);
+                sample.errors += 1;
+                // A request socket which missed its reply can't send again.
+                conn = Broker::connect(ctx).unwrap();
+            }
+        }
+    }
+    samples
+}
+
+// Send one request of the given kind and check its reply.
+fn request(conn: &mut BrokerConn, kind: Kind, rng: &mut Rng, payloads: &[usize]) -> Result<()> {
+    let mut echo = proto::Echo::new();
+    let msg = match kind {
+        Kind::Echo => {
+            echo.set_payload(rng.payload(payloads));
+            protocol::Message::new(&echo).routing(None).build()
+        }
+        Kind::Health => health::request(Protocol::Net, None),
+        Kind::Stats => stats::request(Protocol::Net, None),
+    };
+    try!(conn.route_msg(msg));
+    let reply = try!(conn.recv());
+    if reply.get_message_id() == "NetError" {
+        let err: proto::NetError = try!(parse_from_bytes(reply.get_body()));
+        return Err(Error::Remote(err));
+    }
+    if reply.get_message_id() != kind.reply_id() {
+        return Err(Error::UnexpectedReply(reply.get_message_id().to_string()));
+    }
+    if kind == Kind::Echo {
+        let body: proto::Echo = try!(parse_from_bytes(reply.get_body()));
+        if body.get_payload() != echo.get_payload() {
+            return Err(Error::MalformedReply);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_mix, percentile, Kind, Rng};
+
+    #[test]
+    fn percentile_picks_the_nearest_rank() {
+        let sorted: Vec<u64> = (1..101).collect();
+        assert_eq!(percentile(&sorted, 0.5), Some(50));
+        assert_eq!(percentile(&sorted, 0.99), Some(99));
+        assert_eq!(percentile(&sorted, 0.999), Some(100));
+        assert_eq!(percentile(&[7], 0.5), Some(7));
+        assert_eq!(percentile(&[], 0.5), None);
+    }
+
+    #[test]
+    fn parse_mix_reads_weights() {
+        assert_eq!(parse_mix("echo=3, health").unwrap(),
+                   vec![(Kind::Echo, 3), (Kind::Health, 1)]);
+        assert_eq!(parse_mix("echo=0,stats=2").unwrap(), vec![(Kind::Stats, 2)]);
+        assert!(parse_mix("echo=0").is_err());
+        assert!(parse_mix("gossip=1").is_err());
+        assert!(parse_mix("echo=heavy").is_err());
+    }
+
+    #[test]
+    fn rng_is_reproducible_from_its_seed() {
+        let mix = [(Kind::Echo, 1), (Kind::Stats, 1)];
+        let a: Vec<Kind> = {
+            let mut rng = Rng::new(7);
+            (0..32).map(|_| rng.pick_kind(&mix)).collect()
+        };
+        let mut rng = Rng::new(7);
+        assert!((0..32).all(|i| rng.pick_kind(&mix) == a[i]));
+        assert!(a.contains(&Kind::Echo) && a.contains(&Kind::Stats));
+        assert_eq!(Rng::new(7).payload(&[16]).len(), 16);
+    }
+}
@@ -0,0 +1,386 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Replays traffic captured by a `Broker` through a `RouteSrv` mesh.
+//!
+//! A broker started with a capture file in its `BrokerOpts` writes every message it forwards to
+//! that file. `net-replay` starts a `Broker` of its own and sends the captured requests through it
+//! again, keeping the gaps between them, or shortening them by `--speed`. Requests of each client
+//! in the capture are replayed in order over a connection of their own, by one of `--clients`
+//! client threads. While no more clients are captured than there are threads the interleaving of
+//! clients seen by the routers is the one the capture recorded; past that, clients wait for a
+//! thread and their requests are sent late. Replies are compared to the ones in the capture and
+//! the tool reports, for each kind of message, how many were answered differently or not at all.
+//!
+//! The routers and services to replay against must already be running. Captures don't keep
+//! session tokens, and keep message bodies only when the broker was configured to, so a capture
+//! without bodies replays the shape of the traffic rather than its requests. Messages are signed
+//! again with the keys given by `--hmac-key`, if the mesh verifies signatures.
+
+#[macro_use]
+extern crate clap;
+extern crate habitat_builder_protocol as protocol;
+extern crate habitat_net as hab_net;
+#[macro_use]
+extern crate log;
+extern crate protobuf;
+extern crate time;
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::net;
+use std::process;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use hab_net::capture::{CaptureReader, Direction};
+use hab_net::config::MessageAuth;
+use hab_net::hmac;
+use hab_net::routing::{Broker, BrokerContext};
+use hab_net::server::NetIdent;
+use hab_net::Result;
+use protobuf::parse_from_bytes;
+use protocol::net as proto;
+
+const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+
+fn main() {
+    hab_net::logging::init().unwrap();
+    let matches = app().get_matches();
+    debug!("CLI matches: {:?}", matches);
+    let opts = match Opts::from_matches(&matches) {
+        Ok(opts) => opts,
+        Err(msg) => return exit_with(msg, 1),
+    };
+    let lanes = match load(&opts.capture) {
+        Ok(lanes) => lanes,
+        Err(e) => return exit_with(format!("unable to read {}, {}", opts.capture, e), 1),
+    };
+    hmac::configure(&opts);
+    let report = replay(&opts, lanes);
+    let failed = report.failures() > 0;
+    report.print();
+    process::exit(if failed { 2 } else { 0 })
+}
+
+fn app<'a, 'b>() -> clap::App<'a, 'b> {
+    clap_app!(NetReplay =>
+        (version: VERSION)
+        (about: "Replay traffic captured by a router broker through a Habitat-Builder router mesh")
+        (@arg capture: +required "Capture file written by a router broker")
+        (@arg router: -r --router +takes_value +multiple
+            "Net address of a router to connect to. [default: 127.0.0.1:5562]")
+        (@arg speed: -s --speed +takes_value
+            "How many times faster than captured to replay, 0 for as fast as replies arrive. \
+             [default: 1]")
+        (@arg clients: -c --clients +takes_value
+            "Client threads replaying the captured clients' requests. [default: 16]")
+        (@arg hmac_key: --("hmac-key") +takes_value +multiple
+            "Secret to sign replayed messages with, if the mesh verifies signatures.")
+    )
+}
+
+fn exit_with<T: AsRef<str>>(msg: T, code: i32) {
+    println!("{}", msg.as_ref());
+    process::exit(code)
+}
+
+struct Opts {
+    capture: String,
+    routers: Vec<net::SocketAddrV4>,
+    speed: f64,
+    clients: usize,
+    hmac_keys: Vec<String>,
+}
+
+impl Opts {
+    fn from_matches(matches: &clap::ArgMatches) -> std::result::Result<Self, String> {
+        let routers: Vec<net::SocketAddrV4> = match matches.values_of("router") {
+            Some(values) => try!(values.map(|v| parse_arg("router", v)).collect()),
+            None => vec![net::SocketAddrV4::new(net::Ipv4Addr::new(127, 0, 0, 1), 5562)],
+        };
+        let speed: f64 = try!(parse_arg("speed", matches.value_of("speed").unwrap_or("1")));
+        if speed < 0.0 {
+            return Err(format!("invalid value for --speed: '{}'", speed));
+        }
+        let clients: usize =
+            try!(parse_arg("clients", matches.value_of("clients").unwrap_or("16")));
+        if clients == 0 {
+            return Err("invalid value for --clients: '0'".to_string());
+        }
+        Ok(Opts {
+            capture: matches.value_of("capture").unwrap().to_string(),
+            routers: routers,
+            speed: speed,
+            clients: clients,
+            hmac_keys: matches.values_of("hmac_key")
+                .map_or(vec![], |keys| keys.map(|k| k.to_string()).collect()),
+        })
+    }
+}
+
+impl MessageAuth for Opts {
+    fn hmac_keys(&self) -> &[String] {
+        &self.hmac_keys
+    }
+}
+
+fn parse_arg<T: FromStr>(name: &str, value: &str) -> std::result::Result<T, String> {
+    T::from_str(value).map_err(|_| format!("invalid value for --{}: '{}'", name, value))
+}
+
+struct ReplayClient;
+
+impl NetIdent for ReplayClient {
+    fn component() -> Option<&'static str> {
+        Some("net-replay")
+    }
+}
+
+// A captured request and the reply it was given in the capture, if the capture holds one.
+struct Request {
+    at_ms: i64,
+    msg: proto::Msg,
+    expected: Option<proto::Msg>,
+}
+
+// Read a capture into the requests of each client it holds, in the order each client's first
+// request was captured.
+fn load(path: &str) -> Result<Vec<Vec<Request>>> {
+    let mut lanes: Vec<Vec<Request>> = vec![];
+    let mut clients: HashMap<Vec<u8>, usize> = HashMap::new();
+    for record in try!(CaptureReader::open(path)) {
+        let record = try!(record);
+        let (client, msg) = match (record.client(), record.msg()) {
+            (Some(client), Some(msg)) => (client.to_vec(), msg),
+            _ => {
+                debug!("skipping captured frames without a message, at_ms={}", record.at_ms);
+                continue;
+            }
+        };
+        match record.direction {
+            Direction::Request => {
+                let next = lanes.len();
+                let lane = *clients.entry(client).or_insert(next);
+                if lane == next {
+                    lanes.push(vec![]);
+                }
+                lanes[lane].push(Request {
+                    at_ms: record.at_ms,
+                    msg: msg,
+                    expected: None,
+                });
+            }
+            Direction::Reply => {
+                // Clients hold REQ sockets, so a reply answers the client's latest request.
+                if let Some(&lane) = clients.get(&client) {
+                    if let Some(request) = lanes[lane].last_mut() {
+                        if request.expected.is_none() {
+                            request.expected = Some(msg);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(lanes)
+}
+
+// What a reply says, for comparing it to the reply in the capture: its message id, or the code of
+// the error it holds.
+fn outcome(reply: &proto::Msg) -> String {
+    if reply.get_message_id() == "NetError" {
+        if let Ok(err) = parse_from_bytes::<proto::NetError>(reply.get_body()) {
+            return format!("NetError({:?})", err.get_code());
+        }
+    }
+    reply.get_message_id().to_string()
+}
+
+// How the requests with one message id fared.
+#[derive(Default)]
+struct Tally {
+    sent: u64,
+    errors: u64,
+    differed: u64,
+    timeouts: u64,
+}
+
+impl Tally {
+    fn merge(&mut self, other: &Tally) {
+        self.sent += other.sent;
+        self.errors += other.errors;
+        self.differed += other.differed;
+        self.timeouts += other.timeouts;
+    }
+}
+
+#[derive(Default)]
+struct Report {
+    elapsed_secs: f64,
+    // Most time a request was sent after it was due, from replies slower than in the capture.
+    max_lag_ms: u64,
+    tallies: BTreeMap<String, Tally>,
+}
+
+impl Report {
+    fn failures(&self) -> u64 {
+        self.tallies.values().fold(0, |acc, t| acc + t.differed + t.timeouts)
+    }
+
+    fn print(&self) {
+        println!("{:<24} {:>8} {:>8} {:>8} {:>8}",
+                 "message",
+                 "sent",
+                 "errors",
+                 "differed",
+                 "timeouts");
+        let mut total = Tally::default();
+        for (message_id, tally) in self.tallies.iter() {
+            print_row(message_id, tally);
+            total.merge(tally);
+        }
+        print_row("total", &total);
+        println!("Replayed in {:.1}s, requests sent at most {}ms late",
+                 self.elapsed_secs,
+                 self.max_lag_ms);
+    }
+}
+
+fn print_row(name: &str, tally: &Tally) {
+    println!("{:<24} {:>8} {:>8} {:>8} {:>8}",
+             name,
+             tally.sent,
+             tally.errors,
+             tally.differed,
+             tally.timeouts);
+}
+
+fn replay(opts: &Opts, lanes: Vec<Vec<Request>>) -> Report {
+    let ctx = Arc::new(BrokerContext::new());
+    let _broker = Broker::run(ReplayClient::net_ident(), ctx.clone(), &opts.routers);
+    let requests = lanes.iter().fold(0, |acc, lane| acc + lane.len());
+    let first_at_ms =
+        lanes.iter().filter_map(|lane| lane.first()).map(|r| r.at_ms).min().unwrap_or(0);
+    println!("Replaying {} requests from {} clients at {}",
+             requests,
+             lanes.len(),
+             if opts.speed > 0.0 {
+                 format!("{}x captured speed", opts.speed)
+             } else {
+                 "full speed".to_string()
+             });
+    let start_ns = time::precise_time_ns();
+    let speed = opts.speed;
+    let threads = opts.clients.min(lanes.len());
+    let queue = Arc::new(Mutex::new(lanes.into_iter().collect::<VecDeque<_>>()));
+    let handles: Vec<JoinHandle<Report>> = (0..threads)
+        .map(|i| {
+            let ctx = ctx.clone();
+            let queue = queue.clone();
+            thread::Builder::new()
+                .name(format!("client-{}", i))
+                .spawn(move || run_lanes(&ctx, &queue, first_at_ms, start_ns, speed))
+                .unwrap()
+        })
+        .collect();
+    let mut report = Report::default();
+    for handle in handles {
+        match handle.join() {
+            Ok(lane) => {
+                for (message_id, tally) in lane.tallies.iter() {
+                    report.tallies
+                        .entry(message_id.clone())
+                        .or_insert_with(Tally::default)
+                        .merge(tally);
+                }
+                if lane.max_lag_ms > report.max_lag_ms {
+                    report.max_lag_ms = lane.max_lag_ms;
+                }
+            }
+            Err(_) => error!("client thread panicked"),
+        }
+    }
+    report.elapsed_secs = (time::precise_time_ns() - start_ns) as f64 / 1_000_000_000.0;
+    report
+}
+
+// Replay captured clients taken from the queue, one at a time, until it is empty.
+fn run_lanes(ctx: &BrokerContext,
+             queue: &Mutex<VecDeque<Vec<Request>>>,
+             first_at_ms: i64,
+             start_ns: u64,
+             speed: f64)
+             -> Report {
+    let mut report = Report::default();
+    loop {
+        let lane = match queue.lock().unwrap().pop_front() {
+            Some(lane) => lane,
+            None => return report,
+        };
+        run_lane(ctx, lane, first_at_ms, start_ns, speed, &mut report);
+    }
+}
+
+// Send the requests of one captured client in order, each once it is due relative to `start_ns`.
+fn run_lane(ctx: &BrokerContext,
+            lane: Vec<Request>,
+            first_at_ms: i64,
+            start_ns: u64,
+            speed: f64,
+            report: &mut Report) {
+    let mut conn = Broker::connect(ctx).unwrap();
+    for request in lane {
+        if speed > 0.0 {
+            let due_ns = start_ns + ((request.at_ms - first_at_ms) as f64 / speed * 1e6) as u64;
+            let now_ns = time::precise_time_ns();
+            if due_ns > now_ns {
+                let wait_ns = due_ns - now_ns;
+                thread::sleep(Duration::new(wait_ns / 1_000_000_000,
+                                            (wait_ns % 1_000_000_000) as u32));
+            } else {
+                let lag_ms = (now_ns - due_ns) / 1_000_000;
+                if lag_ms > report.max_lag_ms {
+                    report.max_lag_ms = lag_ms;
+                }
+            }
+        }
+        let tally = report.tallies
+            .entry(request.msg.get_message_id().to_string())
+            .or_insert_with(Tally::default);
+        tally.sent += 1;
+        let reply = match conn.route_msg(request.msg).and_then(|()| conn.recv()) {
+            Ok(reply) => reply,
+            Err(e) => {
+                debug!("replayed request failed, {}", e);
+                tally.timeouts += 1;
+                // A request socket which missed its reply can't send again.
+                conn = Broker::connect(ctx).unwrap();
+                continue;
+            }
+        };
+        if reply.get_message_id() == "NetError" {
+            tally.errors += 1;
+        }
+        if let Some(ref expected) = request.expected {
+            if outcome(&reply) != outcome(expected) {
+                debug!("replayed request answered with {} instead of {}",
+                       outcome(&reply),
+                       outcome(expected));
+                tally.differed += 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+// Generates a strongly-typed client for each `service` defined in the Builder protocols. See
+// `src/clients.rs`.
+
+use std::env;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+const PROTOCOLS_DIR: &'static str = "../builder-protocol/protocols";
+
+struct Rpc {
+    name: String,
+    request: String,
+    reply: String,
+    doc: Vec<String>,
+}
+
+struct Service {
+    package: String,
+    name: String,
+    doc: Vec<String>,
+    rpcs: Vec<Rpc>,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", PROTOCOLS_DIR);
+    let mut services = vec![];
+    let mut protocols: Vec<PathBuf> = fs::read_dir(PROTOCOLS_DIR)
+        .expect("unable to read protocols")
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().map_or(false, |ext| ext == "proto"))
+        .collect();
+    protocols.sort();
+    for path in protocols.iter() {
+        println!("cargo:rerun-if-changed={}", path.display());
+        services.extend(parse(path));
+    }
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("clients.rs");
+    let mut file = File::create(out).expect("unable to create clients.rs");
+    file.write_all(generate(&services).as_bytes()).expect("unable to write clients.rs");
+}
+
+fn parse(path: &Path) -> Vec<Service> {
+    let mut src = String::new();
+    File::open(path).and_then(|mut f| f.read_to_string(&mut src)).expect("unable to read protocol");
+    let mut package = String::new();
+    let mut services: Vec<Service> = vec![];
+    let mut doc = vec![];
+    let mut in_service = false;
+    for line in src.lines().map(|l| l.trim()) {
+        if line.starts_with("//") {
+            doc.push(line.trim_left_matches('/').trim().to_string());
+            continue;
+        }
+        let words: Vec<&str> = line.split(|c: char| c.is_whitespace() || "();{".contains(c))
+            .filter(|w| !w.is_empty())
+            .collect();
+        match words.first() {
+            Some(&"package") => package = words[1].to_string(),
+            Some(&"service") => {
+                in_service = true;
+                services.push(Service {
+                    package: package.clone(),
+                    name: words[1].to_string(),
+                    doc: doc.clone(),
+                    rpcs: vec![],
+                });
+            }
+            Some(&"rpc") if in_service => {
+                assert!(words.len() == 5 && words[3] == "returns",
+                        "malformed rpc in {}: {}",
+                        path.display(),
+                        line);
+                services.last_mut().unwrap().rpcs.push(Rpc {
+                    name: words[1].to_string(),
+                    request: words[2].to_string(),
+                    reply: words[4].to_string(),
+                    doc: doc.clone(),
+                });
+            }
+            Some(&"}") => in_service = false,
+            _ => (),
+        }
+        doc.clear();
+    }
+    services
+}
+
+fn generate(services: &[Service]) -> String {
+    let mut out = String::new();
+    for service in services.iter() {
+        let client = format!("{}Client", service.name.trim_right_matches("Srv"));
+        for line in service.doc.iter() {
+            out.push_str(&format!("/// {}\n", line));
+        }
+        out.push_str(&format!("pub struct {};\n\nimpl {} {{\n", client, client));
+        for (i, rpc) in service.rpcs.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            for line in rpc.doc.iter() {
+                out.push_str(&format!("    /// {}\n", line));
+            }
+            out.push_str(&format!("    pub fn {}<C: RouteClient>(conn: &mut C,\n        \
+                                   req: &protocol::{pkg}::{}) -> Result<protocol::{pkg}::{}> {{\n        \
+                                   call(conn, req)\n    }}\n",
+                                  snake_case(&rpc.name),
+                                  rpc.request,
+                                  rpc.reply,
+                                  pkg = service.package));
+        }
+        out.push_str("}\n\n");
+    }
+    out
+}
+
+fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
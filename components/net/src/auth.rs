@@ -0,0 +1,330 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Authentication of dispatched messages against the session server.
+//!
+//! Clients acting on behalf of a user set the user's session token on each message they send, see
+//! `BrokerConn::set_session_token`. A worker which returns an `Authenticator` from
+//! `Supervisable::authenticator()` has the token of every message it receives checked before the
+//! message reaches `on_message()`. The session is attached to the message's `Envelope` and
+//! messages without a valid session are answered with a `NetError` on the worker's behalf.
+//!
+//! An `Authenticator` finds sessions with a `SessionSource`. Services usually ask the session
+//! server through the application's `Broker` with `BrokerSessions`, and sessions are cached for a
+//! short time so a burst of messages from one user costs a single round trip. The session server
+//! itself reads them from its own datastore.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+
+use protocol::net::{self, ErrCode, NetError};
+use protocol::sessionsrv::{Session, SessionGet};
+
+use clients::SessionClient;
+use clock::{self, Clock, SystemClock};
+use error::Error;
+use routing::{Broker, BrokerContext, RouteClient};
+
+/// How long a validated session is trusted before it is checked again.
+pub const DEFAULT_SESSION_TTL: u64 = 30;
+/// Most sessions kept in an `Authenticator`'s cache.
+pub const MAX_CACHED_SESSIONS: usize = 4096;
+
+/// Finds the session a token belongs to.
+pub trait SessionSource {
+    /// # Errors
+    ///
+    /// * The token doesn't belong to a session, or its session expired (`ACCESS_DENIED` or
+    ///   `SESSION_EXPIRED`)
+    /// * The session could not be looked up (`TIMEOUT` or `INTERNAL`)
+    fn find_session(&mut self, token: &str) -> Result<Session, NetError>;
+}
+
+/// Asks the session server for sessions over a connection to the service cluster.
+pub struct SessionServer<C: RouteClient>(pub C);
+
+impl<C: RouteClient> SessionSource for SessionServer<C> {
+    fn find_session(&mut self, token: &str) -> Result<Session, NetError> {
+        session_get(&mut self.0, token)
+    }
+}
+
+/// Asks the session server for sessions through the application's `Broker`, over a connection
+/// made for each lookup like any other client request.
+pub struct BrokerSessions(pub Arc<BrokerContext>);
+
+impl SessionSource for BrokerSessions {
+    fn find_session(&mut self, token: &str) -> Result<Session, NetError> {
+        match Broker::connect(&self.0) {
+            Ok(mut conn) => session_get(&mut conn, token),
+            Err(e) => {
+                warn!("unable to connect to broker, err={}", e);
+                Err(net::err(ErrCode::TIMEOUT, "net:auth:4"))
+            }
+        }
+    }
+}
+
+fn session_get<C: RouteClient>(conn: &mut C, token: &str) -> Result<Session, NetError> {
+    let mut request = SessionGet::new();
+    request.set_token(token.to_string());
+    match SessionClient::get(conn, &request) {
+        Ok(session) => Ok(session),
+        Err(Error::Remote(err)) => {
+            if err.get_code() == ErrCode::SESSION_EXPIRED {
+                Err(net::err(ErrCode::SESSION_EXPIRED, "net:auth:1"))
+            } else {
+                Err(net::err(ErrCode::ACCESS_DENIED, "net:auth:2"))
+            }
+        }
+        Err(e) => {
+            warn!("unable to validate session, err={}", e);
+            Err(net::err(ErrCode::TIMEOUT, "net:auth:3"))
+        }
+    }
+}
+
+/// Checks the sessions of a worker's messages on its behalf, see `Supervisable::authenticator()`.
+pub trait Authenticate {
+    /// The session of the user a message was sent on behalf of, or `None` if the message
+    /// needn't be sent on behalf of anyone.
+    fn authenticate(&mut self, msg: &net::Msg) -> Result<Option<Session>, NetError>;
+}
+
+/// Validates the session tokens of messages, remembering the sessions it has seen.
+pub struct Authenticator<S: SessionSource> {
+    source: S,
+    ttl_ms: i64,
+    exempt: HashSet<String>,
+    required: HashSet<String>,
+    // Sessions by token, with the time they stop being trusted.
+    cache: HashMap<String, (Session, i64)>,
+}
+
+impl<C: RouteClient> Authenticator<SessionServer<C>> {
+    /// An authenticator which checks sessions over the given connection to the service cluster.
+    pub fn new(conn: C) -> Self {
+        Authenticator::with_source(SessionServer(conn))
+    }
+}
+
+impl<S: SessionSource> Authenticator<S> {
+    /// An authenticator which finds sessions with the given source.
+    pub fn with_source(source: S) -> Self {
+        Authenticator {
+            source: source,
+            ttl_ms: (DEFAULT_SESSION_TTL * 1000) as i64,
+            exempt: HashSet::new(),
+            required: HashSet::new(),
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Trust a validated session for the given time before checking it again. A session which
+    /// is revoked or expires may still be accepted for up to this long.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl_ms = clock::duration_ms(ttl);
+        self
+    }
+
+    /// Let messages with the given id through without a session, such as those which create one.
+    pub fn exempt(mut self, message_id: &str) -> Self {
+        self.exempt.insert(message_id.to_string());
+        self
+    }
+
+    /// Only check the sessions of messages with the given id, and of those named by earlier
+    /// calls, letting every other message through without a session. For servers most of whose
+    /// messages are sent by other services rather than on behalf of a user.
+    pub fn require(mut self, message_id: &str) -> Self {
+        self.required.insert(message_id.to_string());
+        self
+    }
+
+    /// The session of the user a message was sent on behalf of, or `None` if the message is
+    /// exempt from authentication.
+    ///
+    /// # Errors
+    ///
+    /// * The message carries no session token (`ACCESS_DENIED`)
+    /// * The session server doesn't know the token or the session expired (`ACCESS_DENIED` or
+    ///   `SESSION_EXPIRED`)
+    /// * The session server could not be asked (`TIMEOUT`)
+    pub fn authenticate(&mut self, msg: &net::Msg) -> Result<Option<Session>, NetError> {
+        self.authenticate_with_clock(msg, &SystemClock)
+    }
+
+    pub fn authenticate_with_clock<K: Clock>(&mut self,
+                                             msg: &net::Msg,
+                                             clock: &K)
+                                             -> Result<Option<Session>, NetError> {
+        if self.exempt.contains(msg.get_message_id()) ||
+           (!self.required.is_empty() && !self.required.contains(msg.get_message_id())) {
+            return Ok(None);
+        }
+        if msg.get_session_token().is_empty() {
+            return Err(net::err(ErrCode::ACCESS_DENIED, "net:auth:0"));
+        }
+        let now = clock.now_ms();
+        if let Some(&(ref session, expires)) = self.cache.get(msg.get_session_token()) {
+            if expires > now {
+                return Ok(Some(session.clone()));
+            }
+        }
+        match self.source.find_session(msg.get_session_token()) {
+            Ok(session) => {
+                self.remember(msg.get_session_token(), &session, now);
+                Ok(Some(session))
+            }
+            Err(err) => {
+                self.cache.remove(msg.get_session_token());
+                Err(err)
+            }
+        }
+    }
+
+    /// Forget every cached session, so the next message of each is checked again.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    fn remember(&mut self, token: &str, session: &Session, now: i64) {
+        if self.cache.len() >= MAX_CACHED_SESSIONS {
+            let expired: Vec<String> = self.cache
+                .iter()
+                .filter(|&(_, &(_, expires))| expires <= now)
+                .map(|(token, _)| token.clone())
+                .collect();
+            for token in expired.iter() {
+                self.cache.remove(token);
+            }
+            if self.cache.len() >= MAX_CACHED_SESSIONS {
+                self.cache.clear();
+            }
+        }
+        self.cache.insert(token.to_string(), (session.clone(), now + self.ttl_ms));
+    }
+}
+
+impl<S: SessionSource> Authenticate for Authenticator<S> {
+    fn authenticate(&mut self, msg: &net::Msg) -> Result<Option<Session>, NetError> {
+        Authenticator::authenticate(self, msg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use protocol::{self, net, sessionsrv};
+
+    use clock::ManualClock;
+    use error::Error;
+    use testing::MockRouteClient;
+    use super::*;
+
+    fn session() -> sessionsrv::Session {
+        let mut session = sessionsrv::Session::new();
+        session.set_id(1);
+        session.set_email("me@example.com".to_string());
+        session.set_name("me".to_string());
+        session.set_token("token".to_string());
+        session
+    }
+
+    fn msg(token: Option<&str>) -> net::Msg {
+        let mut msg = protocol::Message::new(&sessionsrv::AccountGet::new()).build();
+        if let Some(token) = token {
+            msg.set_session_token(token.to_string());
+        }
+        msg
+    }
+
+    #[test]
+    fn rejects_messages_without_a_session() {
+        let mut auth = Authenticator::new(MockRouteClient::new());
+        let err = auth.authenticate(&msg(None)).unwrap_err();
+        assert_eq!(err.get_code(), net::ErrCode::ACCESS_DENIED);
+        let mut auth = auth.exempt("AccountGet");
+        assert_eq!(auth.authenticate(&msg(None)).unwrap(), None);
+    }
+
+    #[test]
+    fn checks_only_required_messages() {
+        let mut auth = Authenticator::new(MockRouteClient::new()).require("AccountFlagsSet");
+        assert_eq!(auth.authenticate(&msg(None)).unwrap(), None);
+        let mut flags = protocol::Message::new(&sessionsrv::AccountFlagsSet::new()).build();
+        let err = auth.authenticate(&flags).unwrap_err();
+        assert_eq!(err.get_code(), net::ErrCode::ACCESS_DENIED);
+        flags.set_session_token("token".to_string());
+        auth.source.0.respond(&session());
+        assert_eq!(auth.authenticate(&flags).unwrap().unwrap().get_id(), 1);
+    }
+
+    #[test]
+    fn finds_sessions_with_any_source() {
+        struct Fixed(u64);
+
+        impl SessionSource for Fixed {
+            fn find_session(&mut self, token: &str) -> Result<Session, NetError> {
+                if token != "token" {
+                    return Err(net::err(net::ErrCode::ACCESS_DENIED, "test:0"));
+                }
+                self.0 += 1;
+                Ok(session())
+            }
+        }
+
+        let mut auth = Authenticator::with_source(Fixed(0)).with_ttl(Duration::from_secs(0));
+        assert!(auth.authenticate(&msg(Some("token"))).unwrap().is_some());
+        assert!(auth.authenticate(&msg(Some("token"))).unwrap().is_some());
+        assert_eq!(auth.source.0, 2);
+        let err = auth.authenticate(&msg(Some("other"))).unwrap_err();
+        assert_eq!(err.get_code(), net::ErrCode::ACCESS_DENIED);
+    }
+
+    #[test]
+    fn caches_sessions_until_their_ttl() {
+        let mut conn = MockRouteClient::new();
+        conn.respond(&session());
+        conn.respond(&session());
+        let clock = ManualClock::new(0);
+        let mut auth = Authenticator::new(conn).with_ttl(Duration::from_secs(10));
+        let found = auth.authenticate_with_clock(&msg(Some("token")), &clock).unwrap();
+        assert_eq!(found.unwrap().get_id(), 1);
+        clock.advance(5_000);
+        assert!(auth.authenticate_with_clock(&msg(Some("token")), &clock).unwrap().is_some());
+        assert_eq!(auth.source.0.routed().len(), 1);
+        clock.advance(5_000);
+        assert!(auth.authenticate_with_clock(&msg(Some("token")), &clock).unwrap().is_some());
+        assert_eq!(auth.source.0.routed().len(), 2);
+    }
+
+    #[test]
+    fn maps_session_server_errors() {
+        let mut conn = MockRouteClient::new();
+        conn.respond(&net::err(net::ErrCode::SESSION_EXPIRED, "ss:session-get:1"));
+        conn.respond(&net::err(net::ErrCode::ENTITY_NOT_FOUND, "ss:session-get:0"));
+        conn.fail(Error::Sys);
+        let mut auth = Authenticator::new(conn);
+        let codes: Vec<net::ErrCode> = (0..3)
+            .map(|_| auth.authenticate(&msg(Some("token"))).unwrap_err().get_code())
+            .collect();
+        assert_eq!(codes,
+                   vec![net::ErrCode::SESSION_EXPIRED,
+                        net::ErrCode::ACCESS_DENIED,
+                        net::ErrCode::TIMEOUT]);
+    }
+}
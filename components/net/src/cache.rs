@@ -0,0 +1,283 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A small time-limited cache of replies to idempotent read messages, kept by a `Broker`.
+//!
+//! Only messages whose id has been flagged cacheable are cached, keyed by message id, the full
+//! message body in canonical form and the session token, so a reply is never served to another
+//! user or for another request. Cached replies expire after a fixed time to live and are also
+//! dropped as soon as a message which changes what they describe passes through the same
+//! `Broker`.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use protocol::{self, canonical};
+
+use clock::{self, Clock};
+
+/// Which messages a `Broker` caches replies to and for how long.
+#[derive(Clone, Debug)]
+pub struct CacheConfig {
+    /// How long a cached reply is served for
+    pub ttl: Duration,
+    /// Most replies held at once
+    pub capacity: usize,
+    /// Ids of messages whose replies may be cached
+    pub cacheable: Vec<String>,
+    /// Ids of cacheable messages to drop from the cache, keyed by the id of a message which
+    /// invalidates them
+    pub invalidated_by: HashMap<String, Vec<String>>,
+}
+
+impl CacheConfig {
+    pub fn new(ttl: Duration) -> Self {
+        CacheConfig { ttl: ttl, ..CacheConfig::default() }
+    }
+
+    /// Cache replies to messages with the given id.
+    pub fn cache(mut self, message_id: &str) -> Self {
+        self.cacheable.push(message_id.to_string());
+        self
+    }
+
+    /// Drop cached replies to `cached` whenever a `write` message is routed.
+    pub fn invalidate_on(mut self, write: &str, cached: &str) -> Self {
+        self.invalidated_by
+            .entry(write.to_string())
+            .or_insert(vec![])
+            .push(cached.to_string());
+        self
+    }
+
+    /// True if any replies will be cached.
+    pub fn is_enabled(&self) -> bool {
+        self.ttl > Duration::from_millis(0) && self.capacity > 0 && !self.cacheable.is_empty()
+    }
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        CacheConfig {
+            ttl: Duration::from_millis(0),
+            capacity: 1024,
+            cacheable: vec![],
+            invalidated_by: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    message_id: String,
+    body: Vec<u8>,
+    session_token: String,
+}
+
+struct Entry {
+    expires: i64,
+    reply: Vec<u8>,
+}
+
+pub struct ReplyCache {
+    config: CacheConfig,
+    entries: HashMap<CacheKey, Entry>,
+}
+
+impl ReplyCache {
+    pub fn new(config: CacheConfig) -> Self {
+        ReplyCache {
+            config: config,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// The key a reply to the given message is cached under, or `None` if it isn't cacheable.
+    pub fn key(&self, msg: &protocol::net::Msg) -> Option<CacheKey> {
+        if !self.config.cacheable.iter().any(|id| id == msg.get_message_id()) {
+            return None;
+        }
+        // Requests encoded differently by different clients share a reply.
+        let body = canonical::body(msg).unwrap_or_else(|_| msg.get_body().to_vec());
+        Some(CacheKey {
+            message_id: msg.get_message_id().to_string(),
+            body: body,
+            session_token: msg.get_session_token().to_string(),
+        })
+    }
+
+    /// The cached reply for the given key, if it has not expired.
+    pub fn get<C: Clock>(&mut self, key: &CacheKey, clock: &C) -> Option<&[u8]> {
+        let expired = match self.entries.get(key) {
+            Some(entry) => entry.expires <= clock.now_ms(),
+            None => return None,
+        };
+        if expired {
+            self.entries.remove(key);
+            return None;
+        }
+        self.entries.get(key).map(|entry| &entry.reply[..])
+    }
+
+    /// Cache a reply. When the cache is full, expired replies are evicted first and then the reply
+    /// closest to expiring.
+    pub fn insert<C: Clock>(&mut self, key: CacheKey, reply: Vec<u8>, clock: &C) {
+        let now = clock.now_ms();
+        if self.entries.len() >= self.config.capacity && !self.entries.contains_key(&key) {
+            let expired: Vec<CacheKey> = self.entries
+                .iter()
+                .filter(|&(_, entry)| entry.expires <= now)
+                .map(|(key, _)| key.clone())
+                .collect();
+            for key in expired {
+                self.entries.remove(&key);
+            }
+            if self.entries.len() >= self.config.capacity {
+                let oldest = self.entries
+                    .iter()
+                    .min_by_key(|&(_, entry)| entry.expires)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    self.entries.remove(&oldest);
+                }
+            }
+        }
+        self.entries.insert(key,
+                            Entry {
+                                expires: now + clock::duration_ms(self.config.ttl),
+                                reply: reply,
+                            });
+    }
+
+    /// Drop every cached reply made stale by the given message.
+    pub fn invalidate(&mut self, msg: &protocol::net::Msg) {
+        let stale = match self.config.invalidated_by.get(msg.get_message_id()) {
+            Some(stale) => stale,
+            None => return,
+        };
+        let keys: Vec<CacheKey> = self.entries
+            .keys()
+            .filter(|key| stale.iter().any(|id| *id == key.message_id))
+            .cloned()
+            .collect();
+        for key in keys {
+            self.entries.remove(&key);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use protobuf::Message;
+    use protocol;
+
+    use super::*;
+    use clock::ManualClock;
+
+    fn msg(message_id: &str, body: &[u8]) -> protocol::net::Msg {
+        let mut msg = protocol::net::Msg::new();
+        msg.set_message_id(message_id.to_string());
+        msg.set_body(body.to_vec());
+        msg
+    }
+
+    fn cache(capacity: usize) -> ReplyCache {
+        let mut config = CacheConfig::new(Duration::from_secs(1))
+            .cache("OriginGet")
+            .invalidate_on("OriginCreate", "OriginGet");
+        config.capacity = capacity;
+        ReplyCache::new(config)
+    }
+
+    #[test]
+    fn only_cacheable_messages_have_keys() {
+        let cache = cache(8);
+        assert!(cache.key(&msg("OriginGet", b"core")).is_some());
+        assert!(cache.key(&msg("OriginCreate", b"core")).is_none());
+        assert!(cache.key(&msg("OriginGet", b"core")) != cache.key(&msg("OriginGet", b"other")));
+    }
+
+    #[test]
+    fn keys_match_however_the_body_was_encoded() {
+        let cache = cache(8);
+        let mut origin = protocol::vault::OriginGet::new();
+        origin.set_name("core".to_string());
+        let body = origin.write_to_bytes().unwrap();
+        // The same request from a newer client, with a field this build doesn't know
+        let mut newer = body.clone();
+        newer.extend_from_slice(&[0x78, 0x01]);
+        assert_eq!(cache.key(&msg("OriginGet", &body)),
+                   cache.key(&msg("OriginGet", &newer)));
+    }
+
+    #[test]
+    fn keys_differ_by_session_token() {
+        let cache = cache(8);
+        let mut other = msg("OriginGet", b"core");
+        other.set_session_token("other".to_string());
+        assert!(cache.key(&msg("OriginGet", b"core")) != cache.key(&other));
+    }
+
+    #[test]
+    fn replies_expire_after_ttl() {
+        let clock = ManualClock::new(0);
+        let mut cache = cache(8);
+        let key = cache.key(&msg("OriginGet", b"core")).unwrap();
+        cache.insert(key.clone(), b"reply".to_vec(), &clock);
+        clock.advance(999);
+        assert_eq!(cache.get(&key, &clock), Some(&b"reply"[..]));
+        clock.advance(1);
+        assert_eq!(cache.get(&key, &clock), None);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn writes_invalidate_related_replies() {
+        let clock = ManualClock::new(0);
+        let mut cache = cache(8);
+        let key = cache.key(&msg("OriginGet", b"core")).unwrap();
+        cache.insert(key.clone(), b"reply".to_vec(), &clock);
+        cache.invalidate(&msg("OriginDelete", b"core"));
+        assert_eq!(cache.len(), 1);
+        cache.invalidate(&msg("OriginCreate", b"new"));
+        assert_eq!(cache.get(&key, &clock), None);
+    }
+
+    #[test]
+    fn evicts_closest_to_expiring_when_full() {
+        let clock = ManualClock::new(0);
+        let mut cache = cache(2);
+        let first = cache.key(&msg("OriginGet", b"1")).unwrap();
+        let second = cache.key(&msg("OriginGet", b"2")).unwrap();
+        let third = cache.key(&msg("OriginGet", b"3")).unwrap();
+        cache.insert(first.clone(), vec![1], &clock);
+        clock.advance(10);
+        cache.insert(second.clone(), vec![2], &clock);
+        cache.insert(third.clone(), vec![3], &clock);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&first, &clock), None);
+        assert!(cache.get(&second, &clock).is_some());
+        assert!(cache.get(&third, &clock).is_some());
+    }
+}
@@ -0,0 +1,615 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Capture of the traffic a `Broker` routes, for replaying it later with `net-replay`.
+//!
+//! A capture file starts with a short magic number and is followed by one record per multipart
+//! message the broker forwarded, in the order it forwarded them. Each record holds the time it
+//! was seen, whether it was a request on its way to a router or a reply on its way back to a
+//! client, the route info of the message it carries, and every frame exactly as it was on the
+//! wire, so a capture also keeps the identity of the client which sent each request.
+//!
+//! All integers are little-endian:
+//!
+//! ```text
+//! at_ms: i64 | direction: u8 | flags: u8 | protocol: u32 | hash: u64 | frame_count: u32
+//! frame_count * (len: u32 | bytes)
+//! ```
+//!
+//! `flags` records which of `protocol` and `hash` were present in the message's route info.
+//!
+//! Session tokens and signatures are removed from every captured message, and so are message
+//! bodies unless the capture is configured to keep them. A capture file which grows past its size
+//! cap is rotated, and only the most recent few rotated files are kept.
+
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use hab_core::config::ByteSize;
+use protobuf::{parse_from_bytes, Message, ProtobufEnum};
+use protocol::net::{Msg, Protocol};
+
+use error::{Error, Result};
+
+const MAGIC: &'static [u8; 8] = b"HABCAP\x00\x01";
+const HAS_PROTOCOL: u8 = 0b01;
+const HAS_HASH: u8 = 0b10;
+// Largest frame a reader accepts, so a corrupt length can't exhaust memory.
+const MAX_FRAME_LEN: usize = 64 * 1024 * 1024;
+
+/// Most milliseconds a written record waits in a capture's buffer before it is flushed.
+pub const FLUSH_INTERVAL_MS: i64 = 1_000;
+/// Size a capture file grows to before it is rotated, unless configured otherwise.
+pub const DEFAULT_MAX_SIZE: u64 = 256 * 1024 * 1024;
+/// Rotated capture files kept, unless configured otherwise.
+pub const DEFAULT_KEEP: usize = 4;
+
+/// Where a `Broker` captures the traffic it forwards, and how much of it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CaptureConfig {
+    /// Path of the capture file. Rotated files are kept beside it as `<path>.1`, `<path>.2` and
+    /// so on, newest first
+    pub path: String,
+    /// Size the capture file grows to before it is rotated
+    pub max_size: ByteSize,
+    /// Rotated files kept, or zero to discard a capture file once it is full
+    pub keep: usize,
+    /// Keep the bodies of captured messages. Bodies hold the data of every request and reply, so
+    /// they are removed unless the traffic is needed whole, such as to replay it
+    pub bodies: bool,
+}
+
+impl CaptureConfig {
+    pub fn new<T: Into<String>>(path: T) -> Self {
+        CaptureConfig {
+            path: path.into(),
+            max_size: ByteSize(DEFAULT_MAX_SIZE),
+            keep: DEFAULT_KEEP,
+            bodies: false,
+        }
+    }
+}
+
+/// Which way a captured message was travelling through the `Broker`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    /// From a client to a router, as `[client][empty][header][msg]`
+    Request,
+    /// From a router back to a client, as `[client][empty][msg]`
+    Reply,
+}
+
+/// A message forwarded by a `Broker`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    /// Milliseconds since the Unix epoch when the broker received the message
+    pub at_ms: i64,
+    pub direction: Direction,
+    /// Protocol the message was routed to, if it had route info
+    pub protocol: Option<Protocol>,
+    /// Route hash of the message, if it had one
+    pub route_hash: Option<u64>,
+    pub frames: Vec<Vec<u8>>,
+}
+
+impl Record {
+    /// Create a record of the given frames, taking its route info from the message in the last
+    /// frame.
+    pub fn new(at_ms: i64, direction: Direction, frames: Vec<Vec<u8>>) -> Self {
+        let mut record = Record {
+            at_ms: at_ms,
+            direction: direction,
+            protocol: None,
+            route_hash: None,
+            frames: frames,
+        };
+        if let Some(msg) = record.msg() {
+            if msg.has_route_info() {
+                let route = msg.get_route_info();
+                record.protocol = Some(route.get_protocol());
+                if route.has_hash() {
+                    record.route_hash = Some(route.get_hash());
+                }
+            }
+        }
+        record
+    }
+
+    /// Identity of the client which sent the request, or is sent the reply.
+    pub fn client(&self) -> Option<&[u8]> {
+        self.frames.first().map(|frame| &frame[..])
+    }
+
+    /// The message carried by the record, if its last frame parses as one.
+    pub fn msg(&self) -> Option<Msg> {
+        self.frames.last().and_then(|frame| parse_from_bytes::<Msg>(frame).ok())
+    }
+
+    /// Remove the session token and signature of the message the record carries, and its body
+    /// unless `bodies` is set. Frames which don't carry a message are kept as they are.
+    pub fn redact(&mut self, bodies: bool) {
+        let mut msg = match self.msg() {
+            Some(msg) => msg,
+            None => return,
+        };
+        msg.clear_session_token();
+        msg.clear_hmac();
+        if !bodies {
+            msg.set_body(vec![]);
+        }
+        if let (Some(frame), Ok(bytes)) = (self.frames.last_mut(), msg.write_to_bytes()) {
+            *frame = bytes;
+        }
+    }
+}
+
+/// Writes records to a capture.
+pub struct CaptureWriter<W: Write> {
+    out: W,
+    // Bytes written so far, including the magic number.
+    written: u64,
+    // When the writer was last flushed, in the milliseconds of the records written to it.
+    flushed_at_ms: Option<i64>,
+}
+
+impl CaptureWriter<BufWriter<File>> {
+    /// Create a capture file at the given path, replacing any file already there.
+    ///
+    /// # Errors
+    ///
+    /// * The file cannot be created or written to
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let file = try!(File::create(path));
+        CaptureWriter::new(BufWriter::new(file))
+    }
+}
+
+impl<W: Write> CaptureWriter<W> {
+    /// Start a capture on the given writer.
+    ///
+    /// # Errors
+    ///
+    /// * The magic number cannot be written
+    pub fn new(mut out: W) -> Result<Self> {
+        try!(out.write_all(MAGIC));
+        try!(out.flush());
+        Ok(CaptureWriter {
+            out: out,
+            written: MAGIC.len() as u64,
+            flushed_at_ms: None,
+        })
+    }
+
+    /// Bytes written to the capture so far.
+    pub fn written(&self) -> u64 {
+        self.written
+    }
+
+    /// Append a record to the capture. Records are buffered and flushed at most
+    /// `FLUSH_INTERVAL_MS` after they were seen, so a capture of a broker which is killed loses
+    /// no more than the last moment of its traffic.
+    ///
+    /// # Errors
+    ///
+    /// * The record cannot be written
+    pub fn write(&mut self, record: &Record) -> Result<()> {
+        let mut flags = 0;
+        if record.protocol.is_some() {
+            flags |= HAS_PROTOCOL;
+        }
+        if record.route_hash.is_some() {
+            flags |= HAS_HASH;
+        }
+        let direction = match record.direction {
+            Direction::Request => 0,
+            Direction::Reply => 1,
+        };
+        let protocol = record.protocol.map_or(0, |p| p.value() as u32);
+        try!(self.out.write_all(&encode_u64(record.at_ms as u64)));
+        try!(self.out.write_all(&[direction, flags]));
+        try!(self.out.write_all(&encode_u32(protocol)));
+        try!(self.out.write_all(&encode_u64(record.route_hash.unwrap_or(0))));
+        try!(self.out.write_all(&encode_u32(record.frames.len() as u32)));
+        for frame in record.frames.iter() {
+            try!(self.out.write_all(&encode_u32(frame.len() as u32)));
+            try!(self.out.write_all(frame));
+        }
+        self.written += 26 + record.frames.iter().fold(0, |acc, f| acc + 4 + f.len() as u64);
+        if self.flushed_at_ms.is_none() {
+            self.flushed_at_ms = Some(record.at_ms);
+        }
+        self.flush_due(record.at_ms)
+    }
+
+    /// Flush the records written since the last flush if the oldest of them was seen at least
+    /// `FLUSH_INTERVAL_MS` before `now_ms`.
+    ///
+    /// # Errors
+    ///
+    /// * The records cannot be written
+    pub fn flush_due(&mut self, now_ms: i64) -> Result<()> {
+        match self.flushed_at_ms {
+            Some(at_ms) if now_ms - at_ms >= FLUSH_INTERVAL_MS => self.flush(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Flush every record written so far.
+    ///
+    /// # Errors
+    ///
+    /// * The records cannot be written
+    pub fn flush(&mut self) -> Result<()> {
+        try!(self.out.flush());
+        self.flushed_at_ms = None;
+        Ok(())
+    }
+}
+
+/// A capture file which removes what shouldn't be kept from each record it is given and rotates
+/// once it grows past its size cap.
+pub struct CaptureFile {
+    config: CaptureConfig,
+    writer: CaptureWriter<BufWriter<File>>,
+}
+
+impl CaptureFile {
+    /// Create the configured capture file, replacing any file already there.
+    ///
+    /// # Errors
+    ///
+    /// * The file cannot be created or written to
+    pub fn create(config: CaptureConfig) -> Result<Self> {
+        let writer = try!(CaptureWriter::create(&config.path));
+        Ok(CaptureFile {
+            config: config,
+            writer: writer,
+        })
+    }
+
+    /// Redact a record and append it to the capture, rotating the capture file first if it is
+    /// full.
+    ///
+    /// # Errors
+    ///
+    /// * The record cannot be written
+    /// * The capture file cannot be rotated
+    pub fn write(&mut self, mut record: Record) -> Result<()> {
+        record.redact(self.config.bodies);
+        if self.writer.written() >= self.config.max_size.as_bytes() {
+            try!(self.rotate());
+        }
+        self.writer.write(&record)
+    }
+
+    /// Flush the records written so far if they have waited long enough.
+    ///
+    /// # Errors
+    ///
+    /// * The records cannot be written
+    pub fn flush_due(&mut self, now_ms: i64) -> Result<()> {
+        self.writer.flush_due(now_ms)
+    }
+
+    // Move the capture file to `<path>.1`, shifting older rotated files along and dropping any
+    // past `keep`, and start a new capture file.
+    fn rotate(&mut self) -> Result<()> {
+        try!(self.writer.flush());
+        let path = &self.config.path;
+        if self.config.keep == 0 {
+            try!(fs::remove_file(path));
+        } else {
+            let oldest = format!("{}.{}", path, self.config.keep);
+            if Path::new(&oldest).exists() {
+                try!(fs::remove_file(&oldest));
+            }
+            for n in (1..self.config.keep).rev() {
+                let from = format!("{}.{}", path, n);
+                if Path::new(&from).exists() {
+                    try!(fs::rename(&from, format!("{}.{}", path, n + 1)));
+                }
+            }
+            try!(fs::rename(path, format!("{}.1", path)));
+        }
+        self.writer = try!(CaptureWriter::create(path));
+        Ok(())
+    }
+}
+
+/// Reads the records of a capture in the order they were written.
+pub struct CaptureReader<R: Read> {
+    input: R,
+}
+
+impl CaptureReader<BufReader<File>> {
+    /// Open the capture file at the given path.
+    ///
+    /// # Errors
+    ///
+    /// * The file cannot be opened or read
+    /// * The file is not a capture
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let file = try!(File::open(path));
+        CaptureReader::new(BufReader::new(file))
+    }
+}
+
+impl<R: Read> CaptureReader<R> {
+    /// Start reading a capture from the given reader.
+    ///
+    /// # Errors
+    ///
+    /// * The reader does not start with a capture's magic number
+    pub fn new(mut input: R) -> Result<Self> {
+        let mut magic = [0; 8];
+        match input.read_exact(&mut magic) {
+            Ok(()) if &magic == MAGIC => Ok(CaptureReader { input: input }),
+            Ok(()) => Err(Error::MalformedCapture("not a capture file".to_string())),
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                Err(Error::MalformedCapture("not a capture file".to_string()))
+            }
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
+    /// Read the next record, or `None` at the end of the capture. A record cut short, such as
+    /// the last one of a broker killed while writing it, also ends the capture.
+    ///
+    /// # Errors
+    ///
+    /// * The capture cannot be read
+    /// * A record is malformed
+    pub fn next_record(&mut self) -> Result<Option<Record>> {
+        match self.read_record() {
+            Ok(record) => Ok(Some(record)),
+            Err(Error::IO(ref e)) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn read_record(&mut self) -> Result<Record> {
+        let mut head = [0; 26];
+        try!(self.input.read_exact(&mut head));
+        let direction = match head[8] {
+            0 => Direction::Request,
+            1 => Direction::Reply,
+            d => return Err(Error::MalformedCapture(format!("unknown direction {}", d))),
+        };
+        let flags = head[9];
+        let protocol = if flags & HAS_PROTOCOL > 0 {
+            let value = decode_u32(&head[10..14]);
+            match Protocol::from_i32(value as i32) {
+                Some(protocol) => Some(protocol),
+                None => return Err(Error::MalformedCapture(format!("unknown protocol {}", value))),
+            }
+        } else {
+            None
+        };
+        let route_hash = if flags & HAS_HASH > 0 {
+            Some(decode_u64(&head[14..22]))
+        } else {
+            None
+        };
+        let count = decode_u32(&head[22..26]) as usize;
+        let mut frames = Vec::with_capacity(count);
+        let mut len = [0; 4];
+        for _ in 0..count {
+            try!(self.input.read_exact(&mut len));
+            let len = decode_u32(&len) as usize;
+            if len > MAX_FRAME_LEN {
+                return Err(Error::MalformedCapture(format!("frame of {} bytes", len)));
+            }
+            let mut frame = vec![0; len];
+            try!(self.input.read_exact(&mut frame));
+            frames.push(frame);
+        }
+        Ok(Record {
+            at_ms: decode_u64(&head[0..8]) as i64,
+            direction: direction,
+            protocol: protocol,
+            route_hash: route_hash,
+            frames: frames,
+        })
+    }
+}
+
+impl<R: Read> Iterator for CaptureReader<R> {
+    type Item = Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_record() {
+            Ok(Some(record)) => Some(Ok(record)),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+fn encode_u32(value: u32) -> [u8; 4] {
+    let mut bytes = [0; 4];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = (value >> (i * 8)) as u8;
+    }
+    bytes
+}
+
+fn encode_u64(value: u64) -> [u8; 8] {
+    let mut bytes = [0; 8];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = (value >> (i * 8)) as u8;
+    }
+    bytes
+}
+
+fn decode_u32(bytes: &[u8]) -> u32 {
+    bytes.iter().rev().fold(0, |acc, &b| acc << 8 | b as u32)
+}
+
+fn decode_u64(bytes: &[u8]) -> u64 {
+    bytes.iter().rev().fold(0, |acc, &b| acc << 8 | b as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io::{self, Cursor, Write};
+
+    use hab_core::config::ByteSize;
+    use protobuf::Message;
+    use protocol::net::{Msg, Protocol};
+    use tempdir::TempDir;
+
+    use super::*;
+
+    // Counts the times it is flushed.
+    #[derive(Default)]
+    struct Flushes {
+        bytes: Vec<u8>,
+        flushes: usize,
+    }
+
+    impl Write for Flushes {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.bytes.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.flushes += 1;
+            Ok(())
+        }
+    }
+
+    fn request(at_ms: i64, hash: Option<u64>) -> Record {
+        let mut msg = Msg::new();
+        msg.set_message_id("OriginGet".to_string());
+        msg.set_body(b"core".to_vec());
+        msg.set_session_token("secret".to_string());
+        msg.set_hmac(vec![1, 2, 3]);
+        msg.mut_route_info().set_protocol(Protocol::VaultSrv);
+        if let Some(hash) = hash {
+            msg.mut_route_info().set_hash(hash);
+        }
+        let frames = vec![b"client".to_vec(),
+                          vec![],
+                          b"RQ".to_vec(),
+                          msg.write_to_bytes().unwrap()];
+        Record::new(at_ms, Direction::Request, frames)
+    }
+
+    fn capture(records: &[Record]) -> Vec<u8> {
+        let mut writer = CaptureWriter::new(vec![]).unwrap();
+        for record in records {
+            writer.write(record).unwrap();
+        }
+        writer.out
+    }
+
+    #[test]
+    fn records_take_route_info_from_their_message() {
+        let record = request(10, Some(0xdeadbeef));
+        assert_eq!(record.protocol, Some(Protocol::VaultSrv));
+        assert_eq!(record.route_hash, Some(0xdeadbeef));
+        assert_eq!(record.client(), Some(&b"client"[..]));
+        let reply = Record::new(11, Direction::Reply, vec![b"client".to_vec(), vec![], vec![0xff]]);
+        assert_eq!(reply.protocol, None);
+        assert_eq!(reply.route_hash, None);
+    }
+
+    #[test]
+    fn records_are_read_back_as_written() {
+        let records = vec![request(1_476_000_000_000, Some(u64::max_value())),
+                           request(1_476_000_000_250, None)];
+        let reader = CaptureReader::new(Cursor::new(capture(&records))).unwrap();
+        let read: Vec<Record> = reader.map(|r| r.unwrap()).collect();
+        assert_eq!(read, records);
+    }
+
+    #[test]
+    fn capture_ends_at_the_last_whole_record() {
+        let mut bytes = capture(&[request(1, None), request(2, None)]);
+        let len = bytes.len();
+        bytes.truncate(len - 3);
+        let mut reader = CaptureReader::new(Cursor::new(bytes)).unwrap();
+        assert_eq!(reader.next_record().unwrap().map(|r| r.at_ms), Some(1));
+        assert!(reader.next_record().unwrap().is_none());
+    }
+
+    #[test]
+    fn other_files_are_rejected() {
+        assert!(CaptureReader::new(Cursor::new(b"not a capture".to_vec())).is_err());
+        assert!(CaptureReader::new(Cursor::new(vec![])).is_err());
+    }
+
+    #[test]
+    fn redacting_removes_tokens_signatures_and_bodies() {
+        let mut record = request(1, Some(7));
+        record.redact(false);
+        let msg = record.msg().unwrap();
+        assert!(!msg.has_session_token());
+        assert!(!msg.has_hmac());
+        assert!(msg.get_body().is_empty());
+        assert_eq!(msg.get_message_id(), "OriginGet");
+        assert_eq!(record.route_hash, Some(7));
+        assert_eq!(record.client(), Some(&b"client"[..]));
+    }
+
+    #[test]
+    fn redacting_can_keep_bodies() {
+        let mut record = request(1, None);
+        record.redact(true);
+        let msg = record.msg().unwrap();
+        assert!(!msg.has_session_token());
+        assert_eq!(msg.get_body(), b"core");
+    }
+
+    #[test]
+    fn records_are_flushed_once_they_have_waited() {
+        let mut writer = CaptureWriter::new(Flushes::default()).unwrap();
+        let flushed = writer.out.flushes;
+        writer.write(&request(1_000, None)).unwrap();
+        writer.write(&request(1_500, None)).unwrap();
+        assert_eq!(writer.out.flushes, flushed);
+        writer.flush_due(1_999).unwrap();
+        assert_eq!(writer.out.flushes, flushed);
+        writer.flush_due(2_000).unwrap();
+        assert_eq!(writer.out.flushes, flushed + 1);
+        writer.write(&request(2_100, None)).unwrap();
+        writer.write(&request(3_100, None)).unwrap();
+        assert_eq!(writer.out.flushes, flushed + 2);
+        assert_eq!(writer.written(), writer.out.bytes.len() as u64);
+    }
+
+    #[test]
+    fn full_capture_files_are_rotated() {
+        let dir = TempDir::new("capture").unwrap();
+        let path = dir.path().join("broker.cap").to_string_lossy().into_owned();
+        let mut config = CaptureConfig::new(path.clone());
+        // Every file holds only the record which filled it.
+        config.max_size = ByteSize(1);
+        config.keep = 2;
+        let mut capture = CaptureFile::create(config).unwrap();
+        for at_ms in 1..5 {
+            capture.write(request(at_ms, None)).unwrap();
+        }
+        drop(capture);
+        let first = |path: String| {
+            CaptureReader::open(path).unwrap().next_record().unwrap().map(|r| r.at_ms)
+        };
+        assert_eq!(first(path.clone()), Some(4));
+        assert_eq!(first(format!("{}.1", path)), Some(3));
+        assert_eq!(first(format!("{}.2", path)), Some(2));
+        assert!(fs::metadata(format!("{}.3", path)).is_err());
+    }
+}
@@ -0,0 +1,309 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Faults injected into the routing and dispatch paths for chaos testing.
+//!
+//! Faults are only injected by builds with the `chaos` feature, and by tests. In other builds
+//! `inject` never returns a fault and the rules below are never read, so the hooks cost nothing.
+//!
+//! Rules are read from the `HAB_NET_FAULTS` environment variable the first time a fault could be
+//! injected, unless `set_faults` installed some first. The variable holds a comma separated list
+//! of `point:message:fault:probability` rules, where
+//!
+//! * `point` is `route` for requests a `Broker` forwards to a router, `reply` for replies it
+//!   forwards back to a client, or `dispatch` for requests a worker is handed
+//! * `message` is the id of the messages the rule applies to, or `*` for every message
+//! * `fault` is `delay=<ms>` to hold the message back, `drop` to discard it, or `reconnect` to
+//!   discard it and re-establish the connection it arrived on
+//! * `probability` is the chance, from `0` to `1`, of the fault being injected into a message
+//!
+//! For example `route:OriginGet:delay=250:0.1,dispatch:*:drop:0.01`. Every rule matching a
+//! message is tried in order and the first whose roll succeeds is injected. A delay in a `Broker`
+//! holds back only the delayed message, which is passed on once its delay is over while the
+//! messages behind it go ahead. A delay in a worker holds up the worker, as a slow handler would.
+//! Health, debug and stats queries are never faulted by workers so services stay visible to their
+//! monitoring.
+
+use std::env;
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+#[cfg(any(test, feature = "chaos"))]
+use sodiumoxide::randombytes::randombytes;
+
+/// Environment variable the fault rules of a process are read from.
+pub const FAULTS_ENVVAR: &'static str = "HAB_NET_FAULTS";
+
+lazy_static! {
+    // Rules installed with `set_faults` or read from `FAULTS_ENVVAR`, once either has happened.
+    static ref FAULTS: RwLock<Option<Arc<Faults>>> = RwLock::new(None);
+}
+
+/// Where in a message's path a fault is injected.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Point {
+    /// A `Broker` forwarding a request to a router
+    Route,
+    /// A `Broker` forwarding a reply back to its client
+    Reply,
+    /// A worker about to handle a request
+    Dispatch,
+}
+
+impl FromStr for Point {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "route" => Ok(Point::Route),
+            "reply" => Ok(Point::Reply),
+            "dispatch" => Ok(Point::Dispatch),
+            _ => Err(format!("unknown point '{}', expected route, reply or dispatch", value)),
+        }
+    }
+}
+
+/// What is done to a message a fault is injected into.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Fault {
+    /// Hold the message back for the given time before passing it on
+    Delay(Duration),
+    /// Discard the message
+    Drop,
+    /// Discard the message and re-establish the connection it arrived on
+    Reconnect,
+}
+
+impl FromStr for Fault {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "drop" => return Ok(Fault::Drop),
+            "reconnect" => return Ok(Fault::Reconnect),
+            _ => (),
+        }
+        if value.starts_with("delay=") {
+            if let Ok(ms) = value["delay=".len()..].parse() {
+                return Ok(Fault::Delay(Duration::from_millis(ms)));
+            }
+        }
+        Err(format!("unknown fault '{}', expected delay=<ms>, drop or reconnect", value))
+    }
+}
+
+/// A fault and the messages it is injected into.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FaultRule {
+    pub point: Point,
+    /// Id of the messages the fault applies to, or `None` for every message
+    pub message_id: Option<String>,
+    pub fault: Fault,
+    /// Chance of the fault being injected into a matching message, from 0 to 1
+    pub probability: f64,
+}
+
+impl FaultRule {
+    fn matches(&self, point: Point, message_id: &str) -> bool {
+        self.point == point && self.message_id.as_ref().map_or(true, |id| id == message_id)
+    }
+}
+
+impl FromStr for FaultRule {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = value.trim().split(':').collect();
+        if parts.len() != 4 {
+            return Err(format!("invalid fault rule '{}', expected \
+                                point:message:fault:probability",
+                               value));
+        }
+        let probability: f64 = try!(parts[3]
+            .parse()
+            .map_err(|_| format!("invalid probability '{}'", parts[3])));
+        if probability < 0.0 || probability > 1.0 {
+            return Err(format!("invalid probability '{}', expected 0 to 1", parts[3]));
+        }
+        Ok(FaultRule {
+            point: try!(parts[0].parse()),
+            message_id: match parts[1] {
+                "*" => None,
+                id => Some(id.to_string()),
+            },
+            fault: try!(parts[2].parse()),
+            probability: probability,
+        })
+    }
+}
+
+/// The fault rules of a process.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Faults {
+    rules: Vec<FaultRule>,
+}
+
+impl Faults {
+    pub fn new(rules: Vec<FaultRule>) -> Self {
+        Faults { rules: rules }
+    }
+
+    /// Parse a comma separated list of rules, as held by `FAULTS_ENVVAR`.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let rules: Vec<FaultRule> = try!(spec.split(',')
+            .filter(|rule| !rule.trim().is_empty())
+            .map(|rule| rule.parse())
+            .collect());
+        Ok(Faults::new(rules))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// The fault to inject into a message at the given point, if any. `roll` is called once for
+    /// each matching rule and returns a number from 0 up to, but not including, 1.
+    pub fn pick<F>(&self, point: Point, message_id: &str, mut roll: F) -> Option<Fault>
+        where F: FnMut() -> f64
+    {
+        self.rules
+            .iter()
+            .filter(|rule| rule.matches(point, message_id))
+            .find(|rule| roll() < rule.probability)
+            .map(|rule| rule.fault)
+    }
+}
+
+/// Install the fault rules of this process in place of those in `FAULTS_ENVVAR`, or of those
+/// installed before.
+pub fn set_faults(faults: Faults) {
+    *FAULTS.write().unwrap() = Some(Arc::new(faults));
+}
+
+/// The fault rules of this process, read from `FAULTS_ENVVAR` if none were installed.
+pub fn faults() -> Arc<Faults> {
+    if let Some(ref faults) = *FAULTS.read().unwrap() {
+        return faults.clone();
+    }
+    let mut installed = FAULTS.write().unwrap();
+    if installed.is_none() {
+        let faults = match env::var(FAULTS_ENVVAR) {
+            Ok(spec) => {
+                match Faults::parse(&spec) {
+                    Ok(faults) => faults,
+                    Err(e) => {
+                        error!("ignoring {}, {}", FAULTS_ENVVAR, e);
+                        Faults::default()
+                    }
+                }
+            }
+            Err(_) => Faults::default(),
+        };
+        if !faults.is_empty() && cfg!(feature = "chaos") {
+            warn!("injecting faults, {:?}", faults.rules);
+        }
+        *installed = Some(Arc::new(faults));
+    }
+    installed.as_ref().unwrap().clone()
+}
+
+/// True if faults may be injected, so paths which would otherwise skip looking at messages must
+/// pass them to `inject`.
+pub fn is_enabled() -> bool {
+    cfg!(any(test, feature = "chaos")) && !faults().is_empty()
+}
+
+/// The fault to inject into the message with the given id at the given point, if any.
+#[cfg(any(test, feature = "chaos"))]
+pub fn inject(point: Point, message_id: &str) -> Option<Fault> {
+    let fault = faults().pick(point, message_id, roll);
+    if let Some(ref fault) = fault {
+        debug!("injecting {:?} into {} at {:?}", fault, message_id, point);
+    }
+    fault
+}
+
+/// The fault to inject into the message with the given id at the given point, if any.
+#[cfg(not(any(test, feature = "chaos")))]
+#[inline]
+pub fn inject(_point: Point, _message_id: &str) -> Option<Fault> {
+    None
+}
+
+#[cfg(any(test, feature = "chaos"))]
+fn roll() -> f64 {
+    let bytes = randombytes(4);
+    let n = bytes.iter().fold(0u32, |n, b| (n << 8) | *b as u32);
+    n as f64 / (u32::max_value() as f64 + 1.0)
+}
+
+/// Rules the tests of this crate inject faults with. A process has one set of rules, so every
+/// test which needs faults installs these, and each test sends messages only its own rules match.
+#[cfg(test)]
+pub const TEST_FAULTS: &'static str = "route:ChaosDelay:delay=300:1,reply:ChaosDrop:drop:1,\
+                                        dispatch:ChaosDrop:drop:1,dispatch:ChaosDelay:delay=300:1,\
+                                        dispatch:ChaosReconnect:reconnect:1";
+
+#[cfg(test)]
+pub fn install_test_faults() {
+    set_faults(Faults::parse(TEST_FAULTS).unwrap());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn rules_are_parsed() {
+        let faults = Faults::parse("route:OriginGet:delay=250:0.1, dispatch:*:drop:1").unwrap();
+        assert_eq!(faults,
+                   Faults::new(vec![FaultRule {
+                                        point: Point::Route,
+                                        message_id: Some("OriginGet".to_string()),
+                                        fault: Fault::Delay(Duration::from_millis(250)),
+                                        probability: 0.1,
+                                    },
+                                    FaultRule {
+                                        point: Point::Dispatch,
+                                        message_id: None,
+                                        fault: Fault::Drop,
+                                        probability: 1.0,
+                                    }]));
+        assert!(Faults::parse("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected() {
+        assert!(Faults::parse("route:*:drop").is_err());
+        assert!(Faults::parse("sideways:*:drop:0.5").is_err());
+        assert!(Faults::parse("route:*:explode:0.5").is_err());
+        assert!(Faults::parse("route:*:delay=soon:0.5").is_err());
+        assert!(Faults::parse("route:*:drop:1.5").is_err());
+    }
+
+    #[test]
+    fn first_matching_rule_whose_roll_succeeds_is_picked() {
+        let faults = Faults::parse("route:OriginGet:drop:0.5,route:*:reconnect:0.5,reply:*:drop:1")
+            .unwrap();
+        assert_eq!(faults.pick(Point::Route, "OriginGet", || 0.1), Some(Fault::Drop));
+        let mut rolls = vec![0.1, 0.9];
+        assert_eq!(faults.pick(Point::Route, "OriginGet", || rolls.pop().unwrap()),
+                   Some(Fault::Reconnect));
+        assert_eq!(faults.pick(Point::Route, "JobSpec", || 0.9), None);
+        assert_eq!(faults.pick(Point::Dispatch, "OriginGet", || 0.0), None);
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Strongly-typed clients for the services of the Builder cluster.
+//!
+//! A client is generated at build time for each `service` defined in the Builder protocols, with
+//! one function per `rpc` taking the request message and returning the reply it expects. Each
+//! works over any `RouteClient`, such as a `BrokerConn` or a `RouteConn`.
+//!
+//! ```ignore
+//! let mut req = protocol::sessionsrv::SessionGet::new();
+//! req.set_token(token);
+//! let session = try!(SessionClient::get(&mut conn, &req));
+//! ```
+//!
+//! A `NetError` reply is returned as `Error::Remote`.
+
+use protobuf::{parse_from_bytes, MessageStatic};
+use protocol::{self, Routable};
+
+use error::{Error, Result};
+use routing::RouteClient;
+
+include!(concat!(env!("OUT_DIR"), "/clients.rs"));
+
+/// Route a request and wait for its reply.
+///
+/// # Errors
+///
+/// * The request could not be routed or its reply was not received in time
+/// * The service replied with a `NetError`
+/// * The service replied with a message other than `R`
+pub fn call<C, M, R>(conn: &mut C, req: &M) -> Result<R>
+    where C: RouteClient,
+          M: Routable,
+          R: MessageStatic
+{
+    try!(conn.route(req));
+    let reply = try!(conn.recv());
+    if reply.get_message_id() == "NetError" {
+        let err: protocol::net::NetError = try!(parse_from_bytes(reply.get_body()));
+        return Err(Error::Remote(err));
+    }
+    let expected = R::descriptor_static(None).name();
+    if reply.get_message_id() != expected {
+        return Err(Error::UnexpectedReply(format!("expected {}, got {}",
+                                                  expected,
+                                                  reply.get_message_id())));
+    }
+    let msg = try!(parse_from_bytes(reply.get_body()));
+    Ok(msg)
+}
+
+#[cfg(test)]
+mod test {
+    use protocol::{net, sessionsrv};
+
+    use error::Error;
+    use testing::MockRouteClient;
+    use super::*;
+
+    fn session_get() -> sessionsrv::SessionGet {
+        let mut req = sessionsrv::SessionGet::new();
+        req.set_token("token".to_string());
+        req
+    }
+
+    #[test]
+    fn returns_reply() {
+        let mut conn = MockRouteClient::new();
+        let mut session = sessionsrv::Session::new();
+        session.set_id(1);
+        session.set_email("me@example.com".to_string());
+        session.set_name("me".to_string());
+        session.set_token("token".to_string());
+        conn.respond(&session);
+        let reply = SessionClient::get(&mut conn, &session_get()).unwrap();
+        assert_eq!(reply.get_id(), 1);
+        assert_eq!(conn.routed()[0].get_message_id(), "SessionGet");
+    }
+
+    #[test]
+    fn maps_net_errors() {
+        let mut conn = MockRouteClient::new();
+        conn.respond(&net::err(net::ErrCode::ENTITY_NOT_FOUND, "ss:session-get:0"));
+        match SessionClient::get(&mut conn, &session_get()) {
+            Err(Error::Remote(err)) => assert_eq!(err.get_code(), net::ErrCode::ENTITY_NOT_FOUND),
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+
+    #[test]
+    fn rejects_unexpected_replies() {
+        let mut conn = MockRouteClient::new();
+        let mut account = sessionsrv::Account::new();
+        account.set_id(1);
+        account.set_email("me@example.com".to_string());
+        account.set_name("me".to_string());
+        conn.respond(&account);
+        match SessionClient::get(&mut conn, &session_get()) {
+            Err(Error::UnexpectedReply(_)) => (),
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Sources of time for heartbeat and registration expiry logic.
+//!
+//! Code which needs the current time should take a `Clock` instead of reading the system time
+//! directly so tests can substitute a `ManualClock` and step through intervals deterministically.
+//!
+//! Senders stamp each message with the time it was put on the wire so receivers can tell how long
+//! it waited in queues. The stamp comes from the sender's clock, so a message which appears to
+//! arrive before it was sent reveals clock skew between the two hosts.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use protocol;
+use time;
+
+/// How far in the future a message may be stamped before the difference is treated as clock
+/// skew between peers rather than ordinary jitter.
+pub const MAX_CLOCK_SKEW_MS: i64 = 5_000;
+
+pub trait Clock {
+    /// Current time in milliseconds since the Unix epoch.
+    fn now_ms(&self) -> i64;
+}
+
+/// A clock shared by the sockets and workers of a process, such as one injected by a test.
+pub type SharedClock = Arc<Clock + Send + Sync>;
+
+/// A `Clock` reading the system's wall clock.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_ms(&self) -> i64 {
+        let timespec = time::get_time();
+        (timespec.sec as i64 * 1000) + (timespec.nsec as i64 / 1000 / 1000)
+    }
+}
+
+/// A `Clock` which only moves when told to.
+#[derive(Debug, Default)]
+pub struct ManualClock {
+    now_ms: Mutex<i64>,
+}
+
+impl ManualClock {
+    pub fn new(now_ms: i64) -> Self {
+        ManualClock { now_ms: Mutex::new(now_ms) }
+    }
+
+    /// Move the clock forward by the given number of milliseconds.
+    pub fn advance(&self, ms: i64) {
+        *self.now_ms.lock().unwrap() += ms;
+    }
+
+    /// Set the clock to the given time in milliseconds since the Unix epoch.
+    pub fn set(&self, now_ms: i64) {
+        *self.now_ms.lock().unwrap() = now_ms;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now_ms(&self) -> i64 {
+        *self.now_ms.lock().unwrap()
+    }
+}
+
+/// A boxed clock, such as one a server is given at construction.
+impl<C: Clock + ?Sized> Clock for Box<C> {
+    fn now_ms(&self) -> i64 {
+        (**self).now_ms()
+    }
+}
+
+/// A shared clock, such as a `ManualClock` a test keeps a handle to after giving it away.
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now_ms(&self) -> i64 {
+        (**self).now_ms()
+    }
+}
+
+/// The whole number of milliseconds in a duration, for comparing against `Clock` times and for
+/// ZeroMQ socket options.
+pub fn duration_ms(duration: Duration) -> i64 {
+    duration.as_secs() as i64 * 1000 + (duration.subsec_nanos() / 1000 / 1000) as i64
+}
+
+/// The number of seconds in a duration, rounding any fraction of a second up, for options and
+/// expiries which only have a granularity of whole seconds.
+pub fn duration_secs(duration: Duration) -> u64 {
+    (duration_ms(duration) as u64 + 999) / 1000
+}
+
+/// Stamp an outgoing message with the current time. Stamp before signing the message.
+pub fn stamp<C: Clock>(clock: &C, msg: &mut protocol::net::Msg) {
+    msg.set_sent_at_ms(clock.now_ms());
+}
+
+/// Milliseconds between when a message was sent and now, or `None` if the sender didn't stamp
+/// it. Negative if the sender's clock is ahead of ours.
+pub fn queue_latency_ms<C: Clock>(clock: &C, msg: &protocol::net::Msg) -> Option<i64> {
+    if msg.has_sent_at_ms() {
+        Some(clock.now_ms() - msg.get_sent_at_ms())
+    } else {
+        None
+    }
+}
+
+/// How far the sender's clock is ahead of ours, given a message's queue latency, if by more than
+/// `MAX_CLOCK_SKEW_MS`. A sender whose clock is behind looks the same as a long queue and can't
+/// be detected from a single message.
+pub fn skew_ms(latency_ms: i64) -> Option<i64> {
+    if latency_ms < -MAX_CLOCK_SKEW_MS {
+        Some(-latency_ms)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use protocol;
+
+    use super::*;
+
+    #[test]
+    fn queue_latency_of_stamped_message() {
+        let clock = ManualClock::new(10_000);
+        let mut msg = protocol::Message::new(&protocol::net::Ping::new()).build();
+        assert_eq!(queue_latency_ms(&clock, &msg), None);
+        stamp(&clock, &mut msg);
+        clock.advance(250);
+        assert_eq!(queue_latency_ms(&clock, &msg), Some(250));
+    }
+
+    #[test]
+    fn duration_conversions_keep_milliseconds() {
+        assert_eq!(duration_ms(Duration::new(2, 500_999_999)), 2_500);
+        assert_eq!(duration_secs(Duration::from_millis(0)), 0);
+        assert_eq!(duration_secs(Duration::from_millis(1)), 1);
+        assert_eq!(duration_secs(Duration::from_millis(1_500)), 2);
+        assert_eq!(duration_secs(Duration::from_secs(30)), 30);
+    }
+
+    #[test]
+    fn shared_clock_follows_its_source() {
+        let manual = Arc::new(ManualClock::new(0));
+        let boxed: Box<Clock + Send> = Box::new(manual.clone());
+        manual.advance(10);
+        assert_eq!(boxed.now_ms(), 10);
+    }
+
+    #[test]
+    fn skew_beyond_tolerance() {
+        assert_eq!(skew_ms(60_000), None);
+        assert_eq!(skew_ms(-10), None);
+        assert_eq!(skew_ms(-MAX_CLOCK_SKEW_MS), None);
+        assert_eq!(skew_ms(-MAX_CLOCK_SKEW_MS - 1), Some(MAX_CLOCK_SKEW_MS + 1));
+    }
+}
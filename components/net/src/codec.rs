@@ -0,0 +1,308 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Layout of a message on the wire, independent of the transport carrying it.
+//!
+//! A `Frame` is the list of hops a message travelled through, a header naming what the message
+//! is, and its body. Over ZeroMQ each of these is a part of a multipart message, with an empty
+//! part between the hops and the header:
+//!
+//! ```text
+//! [hop]...[empty][header][body]...
+//! ```
+//!
+//! A byte stream has no parts, so `encode` and `decode` write one length prefixed frame after
+//! another. All lengths are big endian:
+//!
+//! ```text
+//! [u8 hop count]([u32 length][hop])...[header][u32 body count]([u32 length][body])...
+//! ```
+
+use std::io::{self, Read, Write};
+
+use error::{Error, Result};
+
+/// Most hops a message may carry before it is dropped.
+pub const MAX_HOPS: usize = 8;
+/// Most bodies a single frame may hold.
+pub const MAX_BODIES: usize = 1024;
+/// Longest hop or body accepted when decoding a stream.
+pub const MAX_PART_LEN: usize = 64 * 1024 * 1024;
+
+/// What a frame carries, sent as a two byte header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Header {
+    /// A request from a client for a router to route to a server.
+    Request,
+    /// A reply from a server to the client at the end of its hops.
+    Reply,
+    /// A request re-routed by one server to another, which replies to the original client.
+    Forward,
+}
+
+impl Header {
+    pub fn as_bytes(&self) -> &'static [u8] {
+        match *self {
+            Header::Request => b"RQ",
+            Header::Reply => b"RP",
+            Header::Forward => b"FW",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Header::Request => "RQ",
+            Header::Reply => "RP",
+            Header::Forward => "FW",
+        }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Header> {
+        match bytes {
+            b"RQ" => Some(Header::Request),
+            b"RP" => Some(Header::Reply),
+            b"FW" => Some(Header::Forward),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub hops: Vec<Vec<u8>>,
+    pub header: Header,
+    /// Serialized messages. Replies streamed by a server hold one body per message.
+    pub bodies: Vec<Vec<u8>>,
+}
+
+impl Frame {
+    pub fn new(header: Header, body: Vec<u8>) -> Self {
+        Frame {
+            hops: vec![],
+            header: header,
+            bodies: vec![body],
+        }
+    }
+
+    /// Read a frame from the parts of a multipart message.
+    ///
+    /// # Errors
+    ///
+    /// * The parts carry more than `MAX_HOPS` hops
+    /// * There is no empty part ending the hops
+    /// * The header is unknown or there are no bodies
+    pub fn from_parts<P: AsRef<[u8]>>(parts: &[P]) -> Result<Frame> {
+        let delim = match parts.iter().position(|p| p.as_ref().is_empty()) {
+            Some(i) => i,
+            None => return Err(Error::MalformedFrame("missing hop delimiter".to_string())),
+        };
+        if delim > MAX_HOPS {
+            return Err(Error::MaxHops);
+        }
+        let header = match parts.get(delim + 1).and_then(|h| Header::from_bytes(h.as_ref())) {
+            Some(header) => header,
+            None => return Err(Error::MalformedFrame("unknown header".to_string())),
+        };
+        if parts.len() < delim + 3 {
+            return Err(Error::MalformedFrame("missing body".to_string()));
+        }
+        Ok(Frame {
+            hops: parts[..delim].iter().map(|p| p.as_ref().to_vec()).collect(),
+            header: header,
+            bodies: parts[delim + 2..].iter().map(|p| p.as_ref().to_vec()).collect(),
+        })
+    }
+
+    /// The parts of the multipart message carrying this frame.
+    pub fn to_parts(&self) -> Vec<&[u8]> {
+        let mut parts: Vec<&[u8]> = Vec::with_capacity(self.hops.len() + self.bodies.len() + 2);
+        for hop in self.hops.iter() {
+            parts.push(hop);
+        }
+        parts.push(&[]);
+        parts.push(self.header.as_bytes());
+        for body in self.bodies.iter() {
+            parts.push(body);
+        }
+        parts
+    }
+
+    /// Write this frame to a byte stream.
+    ///
+    /// # Errors
+    ///
+    /// * The frame carries more than `MAX_HOPS` hops or `MAX_BODIES` bodies
+    /// * The stream could not be written to
+    pub fn encode<W: Write>(&self, w: &mut W) -> Result<()> {
+        if self.hops.len() > MAX_HOPS {
+            return Err(Error::MaxHops);
+        }
+        if self.bodies.len() > MAX_BODIES {
+            return Err(Error::MalformedFrame(format!("{} bodies", self.bodies.len())));
+        }
+        try!(w.write_all(&[self.hops.len() as u8]));
+        for hop in self.hops.iter() {
+            try!(write_part(w, hop));
+        }
+        try!(w.write_all(self.header.as_bytes()));
+        try!(w.write_all(&u32_bytes(self.bodies.len() as u32)));
+        for body in self.bodies.iter() {
+            try!(write_part(w, body));
+        }
+        Ok(())
+    }
+
+    /// Read the next frame from a byte stream, or `None` if the stream ended before it.
+    ///
+    /// # Errors
+    ///
+    /// * The stream ended part way through a frame
+    /// * The frame is larger than the limits allow or its header is unknown
+    /// * The stream could not be read from
+    pub fn decode<R: Read>(r: &mut R) -> Result<Option<Frame>> {
+        let mut count = [0; 1];
+        loop {
+            match r.read(&mut count) {
+                Ok(0) => return Ok(None),
+                Ok(_) => break,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(Error::from(e)),
+            }
+        }
+        if count[0] as usize > MAX_HOPS {
+            return Err(Error::MaxHops);
+        }
+        let mut hops = Vec::with_capacity(count[0] as usize);
+        for _ in 0..count[0] {
+            hops.push(try!(read_part(r)));
+        }
+        let mut header = [0; 2];
+        try!(r.read_exact(&mut header));
+        let header = match Header::from_bytes(&header) {
+            Some(header) => header,
+            None => return Err(Error::MalformedFrame("unknown header".to_string())),
+        };
+        let count = try!(read_u32(r)) as usize;
+        if count == 0 || count > MAX_BODIES {
+            return Err(Error::MalformedFrame(format!("{} bodies", count)));
+        }
+        let mut bodies = Vec::with_capacity(count);
+        for _ in 0..count {
+            bodies.push(try!(read_part(r)));
+        }
+        Ok(Some(Frame {
+            hops: hops,
+            header: header,
+            bodies: bodies,
+        }))
+    }
+}
+
+fn u32_bytes(n: u32) -> [u8; 4] {
+    [(n >> 24) as u8, (n >> 16) as u8, (n >> 8) as u8, n as u8]
+}
+
+fn read_u32<R: Read>(r: &mut R) -> Result<u32> {
+    let mut buf = [0; 4];
+    try!(r.read_exact(&mut buf));
+    Ok(((buf[0] as u32) << 24) | ((buf[1] as u32) << 16) | ((buf[2] as u32) << 8) |
+       (buf[3] as u32))
+}
+
+fn write_part<W: Write>(w: &mut W, part: &[u8]) -> Result<()> {
+    if part.len() > MAX_PART_LEN {
+        return Err(Error::MalformedFrame(format!("part of {} bytes", part.len())));
+    }
+    try!(w.write_all(&u32_bytes(part.len() as u32)));
+    try!(w.write_all(part));
+    Ok(())
+}
+
+fn read_part<R: Read>(r: &mut R) -> Result<Vec<u8>> {
+    let len = try!(read_u32(r)) as usize;
+    if len > MAX_PART_LEN {
+        return Err(Error::MalformedFrame(format!("part of {} bytes", len)));
+    }
+    let mut part = vec![0; len];
+    try!(r.read_exact(&mut part));
+    Ok(part)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use error::Error;
+
+    fn reply() -> Frame {
+        Frame {
+            hops: vec![b"router".to_vec(), b"client".to_vec()],
+            header: Header::Reply,
+            bodies: vec![b"one".to_vec(), b"two".to_vec()],
+        }
+    }
+
+    #[test]
+    fn parts_round_trip() {
+        let frame = reply();
+        let parts = frame.to_parts();
+        assert_eq!(parts.len(), 6);
+        assert!(parts[2].is_empty());
+        assert_eq!(parts[3], b"RP");
+        assert_eq!(Frame::from_parts(&parts).unwrap(), frame);
+    }
+
+    #[test]
+    fn stream_round_trip() {
+        let mut buf = vec![];
+        reply().encode(&mut buf).unwrap();
+        Frame::new(Header::Request, b"req".to_vec()).encode(&mut buf).unwrap();
+        let mut r = Cursor::new(buf);
+        assert_eq!(Frame::decode(&mut r).unwrap(), Some(reply()));
+        assert_eq!(Frame::decode(&mut r).unwrap().unwrap().header, Header::Request);
+        assert_eq!(Frame::decode(&mut r).unwrap(), None);
+    }
+
+    #[test]
+    fn truncated_stream_is_an_error() {
+        let mut buf = vec![];
+        reply().encode(&mut buf).unwrap();
+        buf.pop();
+        assert!(Frame::decode(&mut Cursor::new(buf)).is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_parts() {
+        let no_delim: Vec<&[u8]> = vec![&b"hop"[..], &b"RP"[..], &b"body"[..]];
+        let bad_header: Vec<&[u8]> = vec![&b""[..], &b"XX"[..], &b"body"[..]];
+        let no_body: Vec<&[u8]> = vec![&b"hop"[..], &b""[..], &b"RQ"[..]];
+        for parts in vec![no_delim, bad_header, no_body] {
+            match Frame::from_parts(&parts) {
+                Err(Error::MalformedFrame(_)) => (),
+                r => panic!("expected MalformedFrame, got {:?}", r),
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_too_many_hops() {
+        let mut frame = reply();
+        frame.hops = vec![b"hop".to_vec(); MAX_HOPS + 1];
+        match frame.encode(&mut vec![]) {
+            Err(Error::MaxHops) => (),
+            r => panic!("expected MaxHops, got {:?}", r),
+        }
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Caps on how many of a Supervisor's workers may handle a given kind of message at once.
+//!
+//! Expensive requests can otherwise occupy every worker of a service, starving cheap ones. A
+//! worker takes a `Permit` for a message's id before dispatching it. When the cap for that id has
+//! been reached the worker waits up to the configured time, never more than `MAX_WAIT_MS`, for
+//! another worker to finish one, and if none does the request is rejected with a `BUSY` error.
+//! The wait is bounded because a waiting worker handles no other messages meanwhile.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+/// Most milliseconds a worker waits for a permit before rejecting a message.
+pub const MAX_WAIT_MS: u64 = 250;
+
+/// Concurrency caps keyed by message id, shared by all workers of a Supervisor.
+#[derive(Debug, Default)]
+pub struct ConcurrencyLimits {
+    caps: BTreeMap<String, usize>,
+    wait: Duration,
+    active: Mutex<HashMap<String, usize>>,
+    freed: Condvar,
+}
+
+impl ConcurrencyLimits {
+    /// Create limits from a map of message id to the most workers which may handle it at once.
+    /// Requests beyond a cap wait up to `wait`, capped at `MAX_WAIT_MS`, for a permit; a zero
+    /// wait rejects them outright.
+    pub fn new(caps: BTreeMap<String, usize>, wait: Duration) -> Self {
+        let max_wait = Duration::from_millis(MAX_WAIT_MS);
+        if wait > max_wait {
+            warn!("concurrency wait {:?} is too long, waiting at most {:?}", wait, max_wait);
+        }
+        ConcurrencyLimits {
+            caps: caps,
+            wait: ::std::cmp::min(wait, max_wait),
+            active: Mutex::new(HashMap::new()),
+            freed: Condvar::new(),
+        }
+    }
+
+    /// The cap for the given message id, if it has one.
+    pub fn cap(&self, message_id: &str) -> Option<usize> {
+        self.caps.get(message_id).cloned()
+    }
+
+    /// Take a permit to handle a message with the given id, waiting if its cap has been reached.
+    /// Returns `None` if no permit became free in time.
+    pub fn acquire(limits: &Arc<Self>, message_id: &str) -> Option<Permit> {
+        let cap = match limits.cap(message_id) {
+            Some(cap) => cap,
+            None => return Some(Permit(None)),
+        };
+        let deadline = Instant::now() + limits.wait;
+        let mut active = limits.active.lock().unwrap();
+        loop {
+            let count = active.get(message_id).cloned().unwrap_or(0);
+            if count < cap {
+                active.insert(message_id.to_string(), count + 1);
+                return Some(Permit(Some((limits.clone(), message_id.to_string()))));
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return None;
+            }
+            active = limits.freed.wait_timeout(active, deadline - now).unwrap().0;
+        }
+    }
+
+    /// Number of permits currently held for the given message id.
+    pub fn active(&self, message_id: &str) -> usize {
+        self.active.lock().unwrap().get(message_id).cloned().unwrap_or(0)
+    }
+
+    fn release(&self, message_id: &str) {
+        let mut active = self.active.lock().unwrap();
+        if let Some(count) = active.get_mut(message_id) {
+            *count -= 1;
+        }
+        self.freed.notify_all();
+    }
+}
+
+/// Permission to handle one message, given back when dropped. Messages without a cap get a
+/// permit which holds nothing.
+pub struct Permit(Option<(Arc<ConcurrencyLimits>, String)>);
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        if let Some((ref limits, ref message_id)) = self.0 {
+            limits.release(message_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use super::{ConcurrencyLimits, MAX_WAIT_MS};
+
+    fn limits(cap: usize, wait_ms: u64) -> Arc<ConcurrencyLimits> {
+        let mut caps = BTreeMap::new();
+        caps.insert("JobCreate".to_string(), cap);
+        Arc::new(ConcurrencyLimits::new(caps, Duration::from_millis(wait_ms)))
+    }
+
+    #[test]
+    fn uncapped_messages_always_get_a_permit() {
+        let limits = limits(0, 0);
+        let permits: Vec<_> = (0..10)
+            .map(|_| ConcurrencyLimits::acquire(&limits, "JobGet").unwrap())
+            .collect();
+        assert_eq!(permits.len(), 10);
+        assert_eq!(limits.active("JobGet"), 0);
+    }
+
+    #[test]
+    fn rejects_beyond_cap() {
+        let limits = limits(2, 0);
+        let first = ConcurrencyLimits::acquire(&limits, "JobCreate").unwrap();
+        let _second = ConcurrencyLimits::acquire(&limits, "JobCreate").unwrap();
+        assert!(ConcurrencyLimits::acquire(&limits, "JobCreate").is_none());
+        assert_eq!(limits.active("JobCreate"), 2);
+        drop(first);
+        assert_eq!(limits.active("JobCreate"), 1);
+        assert!(ConcurrencyLimits::acquire(&limits, "JobCreate").is_some());
+    }
+
+    #[test]
+    fn wait_is_bounded() {
+        let limits = limits(1, 60_000);
+        let _held = ConcurrencyLimits::acquire(&limits, "JobCreate").unwrap();
+        let start = Instant::now();
+        assert!(ConcurrencyLimits::acquire(&limits, "JobCreate").is_none());
+        assert!(start.elapsed() < Duration::from_millis(MAX_WAIT_MS * 4));
+    }
+
+    #[test]
+    fn waits_for_a_permit_to_be_released() {
+        let limits = limits(1, 5_000);
+        let held = ConcurrencyLimits::acquire(&limits, "JobCreate").unwrap();
+        let waiter = {
+            let limits = limits.clone();
+            thread::spawn(move || ConcurrencyLimits::acquire(&limits, "JobCreate").is_some())
+        };
+        thread::sleep(Duration::from_millis(50));
+        drop(held);
+        assert!(waiter.join().unwrap());
+    }
+}
@@ -12,7 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::i32;
 use std::net;
+use std::time::Duration;
+use std::u32;
+
+use hab_core;
+use hab_core::config::ParseInto;
+use toml;
+
+use clock;
+use error::{Error, Result};
+use routing::KeepaliveConfig;
+use zap::ZapCredentials;
 
 pub trait GitHubOAuth {
     fn github_url(&self) -> &str;
@@ -20,6 +32,79 @@ pub trait GitHubOAuth {
     fn github_client_secret(&self) -> &str;
 }
 
+pub trait MessageAuth {
+    /// Shared secrets for signing and verifying routed messages, newest first. Messages are not
+    /// signed if empty.
+    fn hmac_keys(&self) -> &[String] {
+        &[]
+    }
+}
+
+pub trait RouterAuth {
+    /// Credentials presented to routers which authenticate their clients with ZAP.
+    fn router_auth(&self) -> ZapCredentials {
+        ZapCredentials::default()
+    }
+}
+
+pub trait Keepalive {
+    /// How idle connections to routers are kept open and verified.
+    fn keepalive(&self) -> KeepaliveConfig {
+        KeepaliveConfig::default()
+    }
+}
+
+impl ParseInto<KeepaliveConfig> for toml::Value {
+    /// Read the `tcp_keepalive_idle`, `tcp_keepalive_ivl`, `tcp_keepalive_count`,
+    /// `heartbeat_ivl` and `heartbeat_timeout` entries of the table at `field`. Entries the table
+    /// lacks keep their current value.
+    fn parse_into(&self,
+                  field: &'static str,
+                  out: &mut KeepaliveConfig)
+                  -> hab_core::Result<bool> {
+        let table = match self.lookup(field) {
+            Some(table) => table,
+            None => return Ok(false),
+        };
+        try!(table.parse_into("tcp_keepalive_idle", &mut out.tcp_idle));
+        try!(table.parse_into("tcp_keepalive_ivl", &mut out.tcp_ivl));
+        try!(table.parse_into("tcp_keepalive_count", &mut out.tcp_count));
+        try!(table.parse_into("heartbeat_ivl", &mut out.heartbeat_ivl));
+        try!(table.parse_into("heartbeat_timeout", &mut out.heartbeat_timeout));
+        Ok(true)
+    }
+}
+
+pub trait Metrics {
+    /// Address of a statsd server to push metrics to, if any.
+    fn statsd_addr(&self) -> Option<net::SocketAddrV4> {
+        None
+    }
+
+    /// Address to serve metrics from in the Prometheus text format, if any.
+    fn metrics_addr(&self) -> Option<net::SocketAddrV4> {
+        None
+    }
+}
+
+pub trait Reconnect {
+    /// Time a `Broker` waits before reconnecting to a router.
+    fn reconnect_ivl(&self) -> Duration {
+        Duration::from_millis(100)
+    }
+
+    /// Most time a `Broker` waits between attempts to reconnect to a router.
+    fn reconnect_ivl_max(&self) -> Duration {
+        Duration::from_secs(10)
+    }
+
+    /// Most random time added to the first reconnect wait, spreading out brokers which lost a
+    /// router at the same time.
+    fn reconnect_jitter(&self) -> Duration {
+        Duration::from_secs(1)
+    }
+}
+
 pub trait RouteAddrs {
     fn route_addrs(&self) -> &Vec<net::SocketAddrV4>;
 
@@ -28,6 +113,168 @@ pub trait RouteAddrs {
     }
 }
 
+pub trait Sockets {
+    /// Number of IO threads of the process's ZeroMQ context. One is enough unless the service
+    /// moves more than about a gigabit of messages per second.
+    fn io_threads(&self) -> usize {
+        1
+    }
+
+    /// Most sockets the process's ZeroMQ context may have open at once.
+    fn max_sockets(&self) -> usize {
+        1024
+    }
+}
+
 pub trait Shards {
     fn shards(&self) -> &Vec<u32>;
+
+    /// Share of the requests for its shards the service takes, relative to the weights of the
+    /// other servers registered for them. Larger hosts can be given a larger weight so they take
+    /// more of the load.
+    fn weight(&self) -> Weight {
+        Weight::default()
+    }
+}
+
+/// A service's share of the requests for its shards, set with `cfg.weight`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Weight(pub usize);
+
+impl Weight {
+    /// The weight as a `Registration` carries it.
+    ///
+    /// # Errors
+    ///
+    /// * The weight is larger than a registration holds
+    pub fn registered(&self) -> Result<u32> {
+        if self.0 > u32::MAX as usize {
+            return Err(Error::OutOfRange("weight".to_string()));
+        }
+        Ok(self.0 as u32)
+    }
+}
+
+impl Default for Weight {
+    fn default() -> Self {
+        Weight(1)
+    }
+}
+
+impl ParseInto<Weight> for toml::Value {
+    /// Read the `weight` entry of the table at `field`, keeping the current weight if the table
+    /// lacks one.
+    fn parse_into(&self, field: &'static str, out: &mut Weight) -> hab_core::Result<bool> {
+        let table = match self.lookup(field) {
+            Some(table) => table,
+            None => return Ok(false),
+        };
+        table.parse_into("weight", &mut out.0)
+    }
+}
+
+/// Convert a configured count to the C `int` a ZeroMQ option takes.
+///
+/// # Errors
+///
+/// * The count is larger than an `int` holds
+pub fn option_int(name: &str, value: usize) -> Result<i32> {
+    if value > i32::MAX as usize {
+        return Err(Error::OutOfRange(name.to_string()));
+    }
+    Ok(value as i32)
+}
+
+/// Convert a configured duration to the milliseconds a ZeroMQ option takes.
+///
+/// # Errors
+///
+/// * The duration is more milliseconds than an `int` holds
+pub fn option_ms(name: &str, value: Duration) -> Result<i32> {
+    let ms = clock::duration_ms(value);
+    if ms > i32::MAX as i64 {
+        return Err(Error::OutOfRange(name.to_string()));
+    }
+    Ok(ms as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::i32;
+    use std::time::Duration;
+    use std::u32;
+    use std::usize;
+
+    use hab_core::config::ParseInto;
+    use toml;
+
+    use super::*;
+    use error::Error;
+    use routing::KeepaliveConfig;
+
+    #[test]
+    fn keepalive_is_parsed_from_the_table() {
+        let toml: toml::Value = "[cfg]\ntcp_keepalive_idle = \"30s\"\ntcp_keepalive_count = 5\n\
+                                 heartbeat_ivl = \"2s\"\n"
+            .parse()
+            .unwrap();
+        let mut keepalive = KeepaliveConfig::default();
+        assert!(toml.parse_into("cfg", &mut keepalive).unwrap());
+        assert_eq!(keepalive.tcp_idle, Duration::from_secs(30));
+        assert_eq!(keepalive.tcp_ivl, KeepaliveConfig::default().tcp_ivl);
+        assert_eq!(keepalive.tcp_count, 5);
+        assert_eq!(keepalive.heartbeat_ivl, Duration::from_secs(2));
+        assert_eq!(keepalive.heartbeat_timeout, Duration::from_secs(0));
+    }
+
+    #[test]
+    fn keepalive_is_left_alone_without_a_table() {
+        let toml: toml::Value = "[other]\ntcp_keepalive_count = 5\n".parse().unwrap();
+        let mut keepalive = KeepaliveConfig::default();
+        assert!(!toml.parse_into("cfg", &mut keepalive).unwrap());
+        assert_eq!(keepalive.tcp_count, 3);
+    }
+
+    #[test]
+    fn weight_is_parsed_from_the_table() {
+        let toml: toml::Value = "[cfg]\nweight = 3\n".parse().unwrap();
+        let mut weight = Weight::default();
+        assert!(toml.parse_into("cfg", &mut weight).unwrap());
+        assert_eq!(weight, Weight(3));
+        let toml: toml::Value = "[cfg]\nshards = [0]\n".parse().unwrap();
+        let mut weight = Weight::default();
+        assert!(!toml.parse_into("cfg", &mut weight).unwrap());
+        assert_eq!(weight, Weight(1));
+    }
+
+    #[test]
+    fn registered_weight_rejects_weights_past_u32() {
+        assert_eq!(Weight(3).registered().unwrap(), 3);
+        assert_eq!(Weight(u32::MAX as usize).registered().unwrap(), u32::MAX);
+        if (u32::MAX as u64) < (usize::MAX as u64) {
+            match Weight((u32::MAX as u64 + 1) as usize).registered() {
+                Err(Error::OutOfRange(ref name)) => assert_eq!(name, "weight"),
+                other => panic!("expected OutOfRange, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn option_int_rejects_counts_past_i32() {
+        assert_eq!(option_int("io_threads", 4).unwrap(), 4);
+        assert_eq!(option_int("io_threads", i32::MAX as usize).unwrap(), i32::MAX);
+        match option_int("max_sockets", i32::MAX as usize + 1) {
+            Err(Error::OutOfRange(ref name)) => assert_eq!(name, "max_sockets"),
+            other => panic!("expected an out of range error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn option_ms_rejects_durations_past_i32() {
+        assert_eq!(option_ms("reconnect_ivl", Duration::from_millis(1500)).unwrap(), 1500);
+        match option_ms("reconnect_ivl_max", Duration::from_secs(30 * 24 * 60 * 60)) {
+            Err(Error::OutOfRange(ref name)) => assert_eq!(name, "reconnect_ivl_max"),
+            other => panic!("expected an out of range error, got {:?}", other),
+        }
+    }
 }
@@ -0,0 +1,376 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! In-process command channel for a running `Supervisor`.
+//!
+//! Every `Supervisor` binds a REP socket at `control_addr()` of its backend address, in the
+//! context its workers share. An admin handler running in the same process connects with a
+//! `ControlClient` to pause, resume, or drain individual workers, change the log level, and read
+//! live worker stats without restarting the service.
+//!
+//! A paused worker stops reading messages, but messages already handed to it wait until it is
+//! resumed. Draining a worker disconnects it from the backend so no more messages are handed to
+//! it, lets it finish those already queued, and then leaves it idle until it is resumed.
+//!
+//! Restarting replaces every worker with a new one, one at a time, so that the workers pick up a
+//! changed configuration. Each old worker is drained once its replacement is ready, so no
+//! messages are dropped.
+
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use log::LogLevelFilter;
+use protobuf::{parse_from_bytes, Message};
+use protocol::{self, net};
+use zmq;
+
+use error::{Error, Result};
+use logging;
+use metrics;
+
+/// Milliseconds an idle worker waits between checks of its state.
+pub const CONTROL_POLL_MS: i64 = 100;
+
+/// Address a `Supervisor` with the given backend address accepts commands on.
+pub fn control_addr(be_addr: &str) -> String {
+    format!("{}.control", be_addr)
+}
+
+/// Run state of a single worker, shared between the worker and its `Supervisor`.
+pub struct WorkerControl {
+    state: AtomicUsize,
+    handled: AtomicUsize,
+    stopping: AtomicBool,
+}
+
+impl WorkerControl {
+    pub fn new() -> Self {
+        WorkerControl {
+            state: AtomicUsize::new(net::WorkerState::RUNNING as usize),
+            handled: AtomicUsize::new(0),
+            stopping: AtomicBool::new(false),
+        }
+    }
+
+    pub fn state(&self) -> net::WorkerState {
+        match self.state.load(Ordering::SeqCst) {
+            1 => net::WorkerState::PAUSED,
+            2 => net::WorkerState::DRAINING,
+            3 => net::WorkerState::DRAINED,
+            _ => net::WorkerState::RUNNING,
+        }
+    }
+
+    pub fn set_state(&self, state: net::WorkerState) {
+        self.state.store(state as usize, Ordering::SeqCst);
+    }
+
+    /// Ask the worker to return from its main loop once it finishes its current message.
+    pub fn stop(&self) {
+        self.stopping.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_stopping(&self) -> bool {
+        self.stopping.load(Ordering::SeqCst)
+    }
+
+    /// Number of messages the worker has handed to `on_message`.
+    pub fn handled(&self) -> u64 {
+        self.handled.load(Ordering::SeqCst) as u64
+    }
+
+    pub fn record_handled(&self) {
+        self.handled.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Block until a message is ready to be read from the worker's socket or the worker's state
+    /// is due another check. Connects the socket to or disconnects it from the backend as the
+    /// worker's state requires. Returns true if a message is ready.
+    pub fn await_work(&self,
+                      sock: &mut zmq::Socket,
+                      be_addr: &str,
+                      connected: &mut bool)
+                      -> ::std::result::Result<bool, zmq::Error> {
+        match self.state() {
+            net::WorkerState::RUNNING => {
+                if !*connected {
+                    try!(sock.connect(be_addr));
+                    *connected = true;
+                }
+                readable(sock, CONTROL_POLL_MS)
+            }
+            net::WorkerState::DRAINING => {
+                if *connected {
+                    try!(sock.disconnect(be_addr));
+                    *connected = false;
+                }
+                if try!(readable(sock, 0)) {
+                    Ok(true)
+                } else {
+                    self.set_state(net::WorkerState::DRAINED);
+                    Ok(false)
+                }
+            }
+            net::WorkerState::PAUSED |
+            net::WorkerState::DRAINED => {
+                thread::sleep(Duration::from_millis(CONTROL_POLL_MS as u64));
+                Ok(false)
+            }
+        }
+    }
+}
+
+fn readable(sock: &mut zmq::Socket, timeout_ms: i64) -> ::std::result::Result<bool, zmq::Error> {
+    let mut items = [sock.as_poll_item(zmq::POLLIN)];
+    try!(zmq::poll(&mut items, timeout_ms));
+    Ok(items[0].get_revents() & zmq::POLLIN > 0)
+}
+
+/// Serve commands for the given workers on a bound REP socket until `shutdown` is set. A restart
+/// command sets `restart` for the `Supervisor` to act on. The socket should have a receive
+/// timeout so the flag is checked regularly.
+pub fn serve(mut sock: zmq::Socket,
+             workers: Arc<RwLock<Vec<Arc<WorkerControl>>>>,
+             restart: Arc<AtomicBool>,
+             shutdown: Arc<AtomicBool>) {
+    loop {
+        if shutdown.load(Ordering::SeqCst) {
+            return;
+        }
+        let reply = match sock.recv_msg(0) {
+            Ok(bytes) => {
+                match parse_from_bytes::<net::SupervisorCommand>(&bytes) {
+                    Ok(cmd) => {
+                        let workers = workers.read().unwrap();
+                        match apply(&cmd, &workers, &restart) {
+                            Ok(()) => protocol::Message::new(&status(&workers)).build(),
+                            Err(err) => protocol::Message::new(&err).build(),
+                        }
+                    }
+                    Err(e) => {
+                        let err = net::err(net::ErrCode::BUG, format!("net:control:0, {}", e));
+                        protocol::Message::new(&err).build()
+                    }
+                }
+            }
+            Err(zmq::Error::EAGAIN) => continue,
+            Err(e) => {
+                warn!("supervisor control socket failed, err={}", e);
+                return;
+            }
+        };
+        if let Err(e) = sock.send(&reply.write_to_bytes().unwrap(), 0) {
+            warn!("unable to reply to supervisor command, err={}", e);
+        }
+    }
+}
+
+fn apply(cmd: &net::SupervisorCommand,
+         workers: &[Arc<WorkerControl>],
+         restart: &AtomicBool)
+         -> ::std::result::Result<(), net::NetError> {
+    let worker = || {
+        workers.get(cmd.get_worker() as usize)
+            .ok_or(net::err(net::ErrCode::ENTITY_NOT_FOUND, "net:control:1"))
+    };
+    match cmd.get_op() {
+        net::SupervisorOp::STATS => (),
+        net::SupervisorOp::PAUSE => try!(worker()).set_state(net::WorkerState::PAUSED),
+        net::SupervisorOp::RESUME => try!(worker()).set_state(net::WorkerState::RUNNING),
+        net::SupervisorOp::DRAIN => {
+            let worker = try!(worker());
+            if worker.state() != net::WorkerState::DRAINED {
+                worker.set_state(net::WorkerState::DRAINING);
+            }
+        }
+        net::SupervisorOp::SET_LOG_LEVEL => {
+            let level = match logging::parse_level(cmd.get_log_level()) {
+                Some(level) => level,
+                None => return Err(net::err(net::ErrCode::BUG, "net:control:2")),
+            };
+            if !logging::set_level(level) {
+                return Err(net::err(net::ErrCode::INTERNAL, "net:control:3"));
+            }
+            info!("log level set to {}", cmd.get_log_level());
+        }
+        net::SupervisorOp::RESTART => restart.store(true, Ordering::SeqCst),
+    }
+    Ok(())
+}
+
+fn status(workers: &[Arc<WorkerControl>]) -> net::SupervisorStatus {
+    let mut status = net::SupervisorStatus::new();
+    for (i, worker) in workers.iter().enumerate() {
+        status.mut_workers().push(worker_status(i, worker));
+    }
+    match logging::level_override() {
+        Some(level) => status.set_log_level(level.to_string().to_lowercase()),
+        None => status.set_log_level("default".to_string()),
+    }
+    status.set_in_flight(metrics::in_flight());
+    status
+}
+
+pub fn worker_status(index: usize, worker: &WorkerControl) -> net::WorkerStatus {
+    let mut status = net::WorkerStatus::new();
+    status.set_index(index as u32);
+    status.set_state(worker.state());
+    status.set_handled(worker.handled());
+    status
+}
+
+/// Client for sending commands to a `Supervisor` running in the same process.
+pub struct ControlClient {
+    sock: zmq::Socket,
+}
+
+impl ControlClient {
+    /// Connect to the `Supervisor` with the given backend address. The context must be the one
+    /// the `Supervisor` was created with.
+    pub fn connect(ctx: &mut zmq::Context, be_addr: &str) -> Result<Self> {
+        let sock = try!(ctx.socket(zmq::REQ));
+        try!(sock.set_rcvtimeo(5_000));
+        try!(sock.connect(&control_addr(be_addr)));
+        Ok(ControlClient { sock: sock })
+    }
+
+    /// Replace every worker of the `Supervisor` with the given backend address, one at a time.
+    /// Workers read their configuration when they start, so this applies a changed configuration
+    /// to a running service without dropping any messages.
+    pub fn roll(ctx: &mut zmq::Context, be_addr: &str) -> Result<()> {
+        let mut control = try!(ControlClient::connect(ctx, be_addr));
+        try!(control.restart());
+        Ok(())
+    }
+
+    pub fn stats(&mut self) -> Result<net::SupervisorStatus> {
+        self.send(net::SupervisorOp::STATS, None, None)
+    }
+
+    pub fn pause(&mut self, worker: u32) -> Result<net::SupervisorStatus> {
+        self.send(net::SupervisorOp::PAUSE, Some(worker), None)
+    }
+
+    pub fn resume(&mut self, worker: u32) -> Result<net::SupervisorStatus> {
+        self.send(net::SupervisorOp::RESUME, Some(worker), None)
+    }
+
+    pub fn drain(&mut self, worker: u32) -> Result<net::SupervisorStatus> {
+        self.send(net::SupervisorOp::DRAIN, Some(worker), None)
+    }
+
+    /// Replace every worker, one at a time. Returns once the restart has been requested, before
+    /// the workers have been replaced.
+    pub fn restart(&mut self) -> Result<net::SupervisorStatus> {
+        self.send(net::SupervisorOp::RESTART, None, None)
+    }
+
+    /// Set the log level of the whole process, or return to the levels configured by `RUST_LOG`
+    /// when given `None`.
+    pub fn set_log_level(&mut self,
+                         level: Option<LogLevelFilter>)
+                         -> Result<net::SupervisorStatus> {
+        let name = match level {
+            Some(level) => level.to_string().to_lowercase(),
+            None => "default".to_string(),
+        };
+        self.send(net::SupervisorOp::SET_LOG_LEVEL, None, Some(name))
+    }
+
+    fn send(&mut self,
+            op: net::SupervisorOp,
+            worker: Option<u32>,
+            log_level: Option<String>)
+            -> Result<net::SupervisorStatus> {
+        let mut cmd = net::SupervisorCommand::new();
+        cmd.set_op(op);
+        if let Some(worker) = worker {
+            cmd.set_worker(worker);
+        }
+        if let Some(log_level) = log_level {
+            cmd.set_log_level(log_level);
+        }
+        try!(self.sock.send(&try!(cmd.write_to_bytes()), 0));
+        let bytes = try!(self.sock.recv_msg(0));
+        let reply: net::Msg = try!(parse_from_bytes(&bytes));
+        if reply.get_message_id() == "NetError" {
+            let err: net::NetError = try!(parse_from_bytes(reply.get_body()));
+            return Err(Error::Remote(err));
+        }
+        let status = try!(parse_from_bytes(reply.get_body()));
+        Ok(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use protocol::net;
+
+    use super::*;
+
+    fn command(op: net::SupervisorOp, worker: u32) -> net::SupervisorCommand {
+        let mut cmd = net::SupervisorCommand::new();
+        cmd.set_op(op);
+        cmd.set_worker(worker);
+        cmd
+    }
+
+    #[test]
+    fn commands_change_worker_state() {
+        let workers = vec![Arc::new(WorkerControl::new()), Arc::new(WorkerControl::new())];
+        let restart = AtomicBool::new(false);
+        apply(&command(net::SupervisorOp::PAUSE, 1), &workers, &restart).unwrap();
+        assert_eq!(workers[0].state(), net::WorkerState::RUNNING);
+        assert_eq!(workers[1].state(), net::WorkerState::PAUSED);
+        apply(&command(net::SupervisorOp::DRAIN, 1), &workers, &restart).unwrap();
+        assert_eq!(workers[1].state(), net::WorkerState::DRAINING);
+        apply(&command(net::SupervisorOp::RESUME, 1), &workers, &restart).unwrap();
+        assert_eq!(workers[1].state(), net::WorkerState::RUNNING);
+    }
+
+    #[test]
+    fn commands_for_unknown_workers_fail() {
+        let workers = vec![Arc::new(WorkerControl::new())];
+        let restart = AtomicBool::new(false);
+        let err = apply(&command(net::SupervisorOp::PAUSE, 3), &workers, &restart).unwrap_err();
+        assert_eq!(err.get_code(), net::ErrCode::ENTITY_NOT_FOUND);
+    }
+
+    #[test]
+    fn restart_command_requests_a_restart() {
+        let workers = vec![Arc::new(WorkerControl::new())];
+        let restart = AtomicBool::new(false);
+        let mut cmd = net::SupervisorCommand::new();
+        cmd.set_op(net::SupervisorOp::RESTART);
+        apply(&cmd, &workers, &restart).unwrap();
+        assert!(restart.load(Ordering::SeqCst));
+        assert_eq!(workers[0].state(), net::WorkerState::RUNNING);
+    }
+
+    #[test]
+    fn status_reports_every_worker() {
+        let workers = vec![Arc::new(WorkerControl::new()), Arc::new(WorkerControl::new())];
+        workers[0].record_handled();
+        workers[1].set_state(net::WorkerState::DRAINED);
+        let status = status(&workers);
+        assert_eq!(status.get_workers().len(), 2);
+        assert_eq!(status.get_workers()[0].get_handled(), 1);
+        assert_eq!(status.get_workers()[1].get_state(), net::WorkerState::DRAINED);
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Process wide registry of connection and queue state for diagnosing a running service.
+//!
+//! Components publish what they know about themselves as `key = value` entries grouped into
+//! named sections: the endpoints a socket is bound or connected to, which routers a service has
+//! registered with, which shards a router knows about, the status of each supervised worker, or
+//! the depth of a queue. Entries are overwritten in place as the state changes.
+//!
+//! The registry can be read in two ways:
+//!
+//! * Sending a `DebugGet` message, built with `request()`, to any worker or router, which replies
+//!   with a `DebugDump`
+//! * Sending `SIGUSR1` to the process once `trap_signal()` has been called, which logs the dump
+//!   at the info level
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Mutex, Once, ONCE_INIT};
+use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
+use std::thread;
+use std::time::Duration;
+
+use libc;
+use protobuf::RepeatedField;
+use protocol;
+use protocol::net::{DebugDump, DebugEntry, DebugGet, Protocol};
+
+use logging;
+use metrics;
+
+/// Interval at which the signal watcher checks whether a dump was requested.
+const SIGNAL_POLL_MS: u64 = 250;
+
+lazy_static! {
+    static ref REGISTRY: Mutex<BTreeMap<String, BTreeMap<String, String>>> =
+        Mutex::new(BTreeMap::new());
+}
+
+static TRAP: Once = ONCE_INIT;
+static DUMP_REQUESTED: AtomicBool = ATOMIC_BOOL_INIT;
+
+/// Publish or overwrite an entry.
+pub fn set<V: ToString>(section: &str, key: &str, value: V) {
+    let mut sections = REGISTRY.lock().unwrap();
+    sections.entry(section.to_string())
+        .or_insert_with(BTreeMap::new)
+        .insert(key.to_string(), value.to_string());
+}
+
+/// Remove an entry which no longer applies.
+pub fn remove(section: &str, key: &str) {
+    let mut sections = REGISTRY.lock().unwrap();
+    let empty = match sections.get_mut(section) {
+        Some(entries) => {
+            entries.remove(key);
+            entries.is_empty()
+        }
+        None => false,
+    };
+    if empty {
+        sections.remove(section);
+    }
+}
+
+/// Every published entry, followed by the process's network identifier and metric counters.
+pub fn snapshot() -> DebugDump {
+    let mut entries = vec![entry("process", "net_ident", logging::net_ident())];
+    for counter in metrics::COUNTERS.iter() {
+        entries.push(entry("metrics", counter.name(), metrics::get(*counter).to_string()));
+    }
+    for (section, values) in REGISTRY.lock().unwrap().iter() {
+        for (key, value) in values.iter() {
+            entries.push(entry(section, key, value.clone()));
+        }
+    }
+    let mut dump = DebugDump::new();
+    dump.set_entries(RepeatedField::from_vec(entries));
+    dump
+}
+
+/// Render a `DebugDump` as one `[section]` block of `key = value` lines per section.
+pub fn render(dump: &DebugDump) -> String {
+    let mut out = String::new();
+    let mut section = "";
+    for entry in dump.get_entries() {
+        if entry.get_section() != section {
+            section = entry.get_section();
+            write!(out, "[{}]\n", section).unwrap();
+        }
+        write!(out, "{} = {}\n", entry.get_key(), entry.get_value()).unwrap();
+    }
+    out
+}
+
+/// Build a debug query for the given protocol. Use `Protocol::RouteSrv` to query the router
+/// itself instead of one of the servers it routes to. Send it with `BrokerConn::route_msg`.
+pub fn request(protocol: Protocol, route_hash: Option<u64>) -> protocol::net::Msg {
+    let req = DebugGet::new();
+    let mut msg = protocol::Message::new(&req).routing(route_hash).build();
+    msg.mut_route_info().set_protocol(protocol);
+    msg
+}
+
+/// Log a dump of the current state whenever the process receives `SIGUSR1`. Only the first call
+/// has an effect.
+pub fn trap_signal() {
+    TRAP.call_once(|| {
+        unsafe {
+            libc::signal(libc::SIGUSR1, handle_signal as libc::sighandler_t);
+        }
+        thread::Builder::new()
+            .name("debug-dump".to_string())
+            .spawn(|| {
+                loop {
+                    if DUMP_REQUESTED.swap(false, Ordering::SeqCst) {
+                        info!("debug dump requested\n{}", render(&snapshot()));
+                    }
+                    thread::sleep(Duration::from_millis(SIGNAL_POLL_MS));
+                }
+            })
+            .unwrap();
+    });
+}
+
+extern "C" fn handle_signal(_: libc::c_int) {
+    DUMP_REQUESTED.store(true, Ordering::SeqCst);
+}
+
+fn entry<S: ToString, K: ToString>(section: S, key: K, value: String) -> DebugEntry {
+    let mut entry = DebugEntry::new();
+    entry.set_section(section.to_string());
+    entry.set_key(key.to_string());
+    entry.set_value(value);
+    entry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(section: &str, key: &str) -> Option<String> {
+        snapshot()
+            .get_entries()
+            .iter()
+            .find(|e| e.get_section() == section && e.get_key() == key)
+            .map(|e| e.get_value().to_string())
+    }
+
+    #[test]
+    fn set_and_remove_entries() {
+        set("debug-test", "queue.depth", 3);
+        assert_eq!(find("debug-test", "queue.depth"), Some("3".to_string()));
+        set("debug-test", "queue.depth", 4);
+        assert_eq!(find("debug-test", "queue.depth"), Some("4".to_string()));
+        remove("debug-test", "queue.depth");
+        assert_eq!(find("debug-test", "queue.depth"), None);
+    }
+
+    #[test]
+    fn snapshot_includes_metrics() {
+        assert!(find("metrics", "dispatched").is_some());
+    }
+
+    #[test]
+    fn render_groups_sections() {
+        set("debug-render", "a", "1");
+        set("debug-render", "b", "2");
+        let out = render(&snapshot());
+        assert!(out.contains("[debug-render]\na = 1\nb = 2\n"));
+    }
+}
@@ -20,6 +20,7 @@ use std::result;
 
 use hyper;
 use protobuf;
+use protocol;
 use rustc_serialize::json;
 use zmq;
 
@@ -28,15 +29,37 @@ use oauth;
 #[derive(Debug)]
 pub enum Error {
     Auth(oauth::github::AuthErr),
+    /// A component of a `Runtime` stopped before shutdown was requested, or failed while stopping.
+    ComponentFailed(String),
     GitHubAPI(HashMap<String, String>),
     IO(io::Error),
     HyperError(hyper::error::Error),
     JsonDecode(json::DecoderError),
+    /// A traffic capture file could not be read, holding what was wrong with it.
+    MalformedCapture(String),
+    MalformedReply,
+    /// A message on the wire did not follow the layout of a `codec::Frame`.
+    MalformedFrame(String),
     MaxHops,
     HTTP(hyper::status::StatusCode),
     MissingScope(String),
+    /// Some components of the process did not report ready in time, holding their names.
+    NotReady(Vec<String>),
+    /// A configured value is too large for the ZeroMQ option it sets, holding the option's name.
+    OutOfRange(String),
     Protobuf(protobuf::ProtobufError),
+    /// A `RouteSrv` refused the registration of this server, holding the reason it gave.
+    RegistrationRejected(String),
+    /// A service replied with an error.
+    Remote(protocol::net::NetError),
+    /// The process's ZeroMQ context is being terminated, so no socket of it can be used again.
+    Shutdown,
     Sys,
+    /// A message could not be sent or received within the socket's timeout. Trying again later
+    /// may succeed.
+    Timeout,
+    /// A service replied with a message other than the one the request expects.
+    UnexpectedReply(String),
     Zmq(zmq::Error),
 }
 
@@ -46,15 +69,28 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
             Error::Auth(ref e) => format!("GitHub Authentication error, {}", e),
+            Error::ComponentFailed(ref e) => format!("Application component {} failed", e),
             Error::GitHubAPI(ref e) => format!("GitHub API error, {:?}", e),
             Error::IO(ref e) => format!("{}", e),
             Error::HyperError(ref e) => format!("{}", e),
             Error::JsonDecode(ref e) => format!("JSON decoding error, {}", e),
+            Error::MalformedCapture(ref e) => format!("Malformed traffic capture, {}", e),
+            Error::MalformedReply => {
+                format!("Received a reply which was not addressed to a router")
+            }
+            Error::MalformedFrame(ref e) => format!("Received a malformed frame, {}", e),
             Error::MaxHops => format!("Received a message containing too many network hops"),
             Error::HTTP(ref e) => format!("{}", e),
             Error::MissingScope(ref e) => format!("Missing GitHub permission: {}", e),
+            Error::NotReady(ref e) => format!("Components not ready in time: {}", e.join(", ")),
+            Error::OutOfRange(ref e) => format!("Configured {} is out of range", e),
             Error::Protobuf(ref e) => format!("{}", e),
+            Error::RegistrationRejected(ref e) => format!("Router rejected registration, {}", e),
+            Error::Remote(ref e) => format!("[{:?}] {}", e.get_code(), e.get_msg()),
+            Error::Shutdown => format!("Messaging is shutting down"),
             Error::Sys => format!("Internal system error"),
+            Error::Timeout => format!("Timed out sending or receiving a message"),
+            Error::UnexpectedReply(ref e) => format!("Received an unexpected reply, {}", e),
             Error::Zmq(ref e) => format!("{}", e),
         };
         write!(f, "{}", msg)
@@ -65,15 +101,26 @@ impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::Auth(_) => "GitHub authorization error.",
+            Error::ComponentFailed(_) => "Application component failed",
             Error::GitHubAPI(_) => "GitHub API error.",
             Error::IO(ref err) => err.description(),
             Error::HyperError(ref err) => err.description(),
             Error::HTTP(_) => "Non-200 HTTP response.",
             Error::JsonDecode(ref err) => err.description(),
+            Error::MalformedCapture(_) => "Malformed traffic capture",
+            Error::MalformedReply => "Received a reply which was not addressed to a router",
+            Error::MalformedFrame(_) => "Received a malformed frame",
             Error::MaxHops => "Received a message containing too many network hops",
             Error::MissingScope(_) => "Missing GitHub authorization scope.",
+            Error::NotReady(_) => "Components not ready in time",
+            Error::OutOfRange(_) => "Configured value is out of range",
             Error::Protobuf(ref err) => err.description(),
+            Error::RegistrationRejected(_) => "Router rejected registration",
+            Error::Remote(_) => "Service replied with an error",
+            Error::Shutdown => "Messaging is shutting down",
             Error::Sys => "Internal system error",
+            Error::Timeout => "Timed out sending or receiving a message",
+            Error::UnexpectedReply(_) => "Received an unexpected reply",
             Error::Zmq(ref err) => err.description(),
         }
     }
@@ -111,6 +158,33 @@ impl From<protobuf::ProtobufError> for Error {
 
 impl From<zmq::Error> for Error {
     fn from(err: zmq::Error) -> Error {
-        Error::Zmq(err)
+        match err {
+            zmq::Error::EAGAIN => Error::Timeout,
+            zmq::Error::ETERM => Error::Shutdown,
+            err => Error::Zmq(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zmq;
+
+    use super::*;
+
+    #[test]
+    fn zmq_errors_are_typed() {
+        match Error::from(zmq::Error::EAGAIN) {
+            Error::Timeout => (),
+            e => panic!("unexpected error: {:?}", e),
+        }
+        match Error::from(zmq::Error::ETERM) {
+            Error::Shutdown => (),
+            e => panic!("unexpected error: {:?}", e),
+        }
+        match Error::from(zmq::Error::EHOSTUNREACH) {
+            Error::Zmq(zmq::Error::EHOSTUNREACH) => (),
+            e => panic!("unexpected error: {:?}", e),
+        }
     }
 }
@@ -0,0 +1,154 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Uniform health reporting for net services.
+//!
+//! Every `Supervisable` worker answers `HealthGet` messages without any service specific
+//! dispatch code. Services describe what "healthy" means to them by implementing `HealthCheck`
+//! and returning one result per dependency they require, such as a datastore or a downstream
+//! router. The overall status of a `Health` reply is the worst status of its checks.
+
+use std::fmt;
+
+use protocol;
+use protocol::net::{Health, HealthCheckResult, HealthGet, HealthStatus, Protocol, WorkerState};
+
+use control::WorkerControl;
+
+/// Name of the check which is always reported by a worker answering a health query.
+pub const WORKER_CHECK: &'static str = "worker";
+
+pub trait HealthCheck {
+    /// Run every health check for this service and return their results.
+    fn health_checks(&mut self) -> Vec<HealthCheckResult> {
+        vec![]
+    }
+}
+
+/// Build a check result with the given name, status and optional message.
+pub fn check<N: Into<String>>(name: N,
+                              status: HealthStatus,
+                              msg: Option<String>)
+                              -> HealthCheckResult {
+    let mut result = HealthCheckResult::new();
+    result.set_name(name.into());
+    result.set_status(status);
+    if let Some(msg) = msg {
+        result.set_msg(msg);
+    }
+    result
+}
+
+/// Build a check result from the outcome of an operation. An error marks the check as `DOWN`
+/// and is recorded as the check's message.
+pub fn check_result<N, T, E>(name: N, result: ::std::result::Result<T, E>) -> HealthCheckResult
+    where N: Into<String>,
+          E: fmt::Display
+{
+    match result {
+        Ok(_) => check(name, HealthStatus::OK, None),
+        Err(e) => check(name, HealthStatus::DOWN, Some(e.to_string())),
+    }
+}
+
+/// The check result of a worker's run state. A paused or draining worker is `DEGRADED` and a
+/// drained or stopping one is `DOWN`.
+pub fn worker_check(control: &WorkerControl) -> HealthCheckResult {
+    if control.is_stopping() {
+        return check(WORKER_CHECK, HealthStatus::DOWN, Some("stopping".to_string()));
+    }
+    match control.state() {
+        WorkerState::RUNNING => check(WORKER_CHECK, HealthStatus::OK, None),
+        state @ WorkerState::PAUSED |
+        state @ WorkerState::DRAINING => {
+            check(WORKER_CHECK, HealthStatus::DEGRADED, Some(format!("{:?}", state)))
+        }
+        state @ WorkerState::DRAINED => {
+            check(WORKER_CHECK, HealthStatus::DOWN, Some(format!("{:?}", state)))
+        }
+    }
+}
+
+/// Combine check results into a `Health` reply.
+pub fn report(checks: Vec<HealthCheckResult>) -> Health {
+    let mut health = Health::new();
+    let status = checks.iter().fold(HealthStatus::OK, |acc, c| worst(acc, c.get_status()));
+    health.set_status(status);
+    health.set_checks(checks.into_iter().collect());
+    health
+}
+
+/// Build a health query for the given protocol. The router dispatches it like any other
+/// message so it is answered by a worker of a server implementing that protocol. Send it with
+/// `BrokerConn::route_msg`.
+pub fn request(protocol: Protocol, route_hash: Option<u64>) -> protocol::net::Msg {
+    let req = HealthGet::new();
+    let mut msg = protocol::Message::new(&req).routing(route_hash).build();
+    msg.mut_route_info().set_protocol(protocol);
+    msg
+}
+
+fn worst(a: HealthStatus, b: HealthStatus) -> HealthStatus {
+    if (b as i32) > (a as i32) { b } else { a }
+}
+
+#[cfg(test)]
+mod tests {
+    use protocol::net::{HealthStatus, WorkerState};
+
+    use super::*;
+    use control::WorkerControl;
+
+    #[test]
+    fn report_is_worst_status() {
+        let health = report(vec![check("a", HealthStatus::OK, None),
+                                 check("b", HealthStatus::DOWN, None),
+                                 check("c", HealthStatus::DEGRADED, None)]);
+        assert_eq!(health.get_status(), HealthStatus::DOWN);
+        assert_eq!(health.get_checks().len(), 3);
+    }
+
+    #[test]
+    fn empty_report_is_ok() {
+        assert_eq!(report(vec![]).get_status(), HealthStatus::OK);
+    }
+
+    #[test]
+    fn check_result_records_error() {
+        let result = check_result("db", Err::<(), _>("connection refused"));
+        assert_eq!(result.get_status(), HealthStatus::DOWN);
+        assert_eq!(result.get_msg(), "connection refused");
+    }
+
+    #[test]
+    fn worker_check_follows_state() {
+        let control = WorkerControl::new();
+        assert_eq!(worker_check(&control).get_status(), HealthStatus::OK);
+        control.set_state(WorkerState::PAUSED);
+        assert_eq!(worker_check(&control).get_status(), HealthStatus::DEGRADED);
+        control.set_state(WorkerState::DRAINING);
+        assert_eq!(worker_check(&control).get_status(), HealthStatus::DEGRADED);
+        control.set_state(WorkerState::DRAINED);
+        assert_eq!(worker_check(&control).get_status(), HealthStatus::DOWN);
+    }
+
+    #[test]
+    fn stopping_worker_is_down() {
+        let control = WorkerControl::new();
+        control.stop();
+        let result = worker_check(&control);
+        assert_eq!(result.get_status(), HealthStatus::DOWN);
+        assert_eq!(result.get_msg(), "stopping");
+    }
+}
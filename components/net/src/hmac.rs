@@ -0,0 +1,238 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! HMAC authentication of routed messages for deployments which can't use CURVE.
+//!
+//! Every service in a cluster is configured with the same shared secret. Messages routed through
+//! a `RouteSrv` carry an HMAC-SHA256 over their message id, body, and route info which the
+//! receiving worker verifies before dispatching the message. Messages which fail verification
+//! are answered with an `ACCESS_DENIED` error and never reach `on_message()`.
+//!
+//! Secrets are rotated by configuring the new secret first, followed by the secrets it replaces.
+//! Messages are always signed with the first secret and are accepted if they verify against any
+//! of them, so services can be reconfigured one at a time.
+
+use std::sync::RwLock;
+
+use protocol::{self, canonical};
+use sodiumoxide::crypto::auth::hmacsha256::State;
+use sodiumoxide::utils::memcmp;
+
+use config;
+
+/// Number of previous secrets accepted in addition to the current one.
+pub const MAX_PREVIOUS_KEYS: usize = 3;
+
+lazy_static! {
+    // Keyring installed with `set_keyring`, or `None` if messages are not signed.
+    static ref KEYRING: RwLock<Option<Keyring>> = RwLock::new(None);
+}
+
+/// The current secret used to sign messages and the previous secrets still accepted.
+#[derive(Clone, Debug)]
+pub struct Keyring {
+    keys: Vec<Vec<u8>>,
+}
+
+impl Keyring {
+    /// Create a keyring from secrets ordered newest first. Returns `None` if no secrets are
+    /// given. Secrets beyond the current one and `MAX_PREVIOUS_KEYS` previous ones are ignored.
+    pub fn new<K: AsRef<[u8]>>(keys: &[K]) -> Option<Self> {
+        if keys.is_empty() {
+            return None;
+        }
+        if keys.len() > MAX_PREVIOUS_KEYS + 1 {
+            warn!("ignoring {} expired hmac keys", keys.len() - MAX_PREVIOUS_KEYS - 1);
+        }
+        Some(Keyring {
+            keys: keys.iter()
+                .take(MAX_PREVIOUS_KEYS + 1)
+                .map(|k| k.as_ref().to_vec())
+                .collect(),
+        })
+    }
+
+    /// Sign a message with the current secret. Sign after the message's route info and
+    /// timestamp are final.
+    pub fn sign(&self, msg: &mut protocol::net::Msg) {
+        let tag = digest(&self.keys[0], msg);
+        msg.set_hmac(tag);
+    }
+
+    /// True if the message carries a signature made with the current or a previous secret.
+    pub fn verify(&self, msg: &protocol::net::Msg) -> bool {
+        if !msg.has_hmac() {
+            return false;
+        }
+        self.keys.iter().any(|key| memcmp(&digest(key, msg), msg.get_hmac()))
+    }
+}
+
+/// Install the keyring used to sign and verify messages for this process. Only the first call has
+/// an effect.
+pub fn set_keyring(keyring: Keyring) {
+    let mut installed = KEYRING.write().unwrap();
+    if installed.is_none() {
+        *installed = Some(keyring);
+    }
+}
+
+/// Install a keyring built from a service's configuration, if it names any secrets.
+pub fn configure<C: config::MessageAuth>(config: &C) {
+    if let Some(keyring) = Keyring::new(config.hmac_keys()) {
+        set_keyring(keyring);
+    }
+}
+
+/// The keyring installed for this process, if messages are signed.
+pub fn keyring() -> Option<Keyring> {
+    KEYRING.read().unwrap().clone()
+}
+
+/// Sign an outgoing message if a keyring is installed.
+pub fn sign(msg: &mut protocol::net::Msg) {
+    if let Some(ref keyring) = *KEYRING.read().unwrap() {
+        keyring.sign(msg);
+    }
+}
+
+/// True if no keyring is installed or the message verifies against it.
+pub fn verify(msg: &protocol::net::Msg) -> bool {
+    match *KEYRING.read().unwrap() {
+        Some(ref keyring) => keyring.verify(msg),
+        None => true,
+    }
+}
+
+fn digest(key: &[u8], msg: &protocol::net::Msg) -> Vec<u8> {
+    let mut state = State::init(key);
+    // Like the route info below, the body is hashed in canonical form. One which doesn't decode
+    // is hashed as it is, so it still fails to verify if it was altered.
+    let body = canonical::body(msg).unwrap_or_else(|_| msg.get_body().to_vec());
+    // Length prefix each field so fields can't be shifted into each other.
+    for field in &[msg.get_message_id().as_bytes(), &body[..]] {
+        state.update((field.len() as u64).to_string().as_bytes());
+        state.update(b":");
+        state.update(field);
+    }
+    if msg.has_route_info() {
+        // The route info was decoded by the receiver, so it's hashed in canonical form for both
+        // sides to agree on its bytes.
+        state.update(&canonical::to_bytes(msg.get_route_info()).unwrap());
+    }
+    if msg.has_sent_at_ms() {
+        state.update(b"sent_at_ms:");
+        state.update(msg.get_sent_at_ms().to_string().as_bytes());
+    }
+    if msg.has_session_token() {
+        state.update(b"session_token:");
+        state.update(msg.get_session_token().as_bytes());
+    }
+    state.finalize().0.to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use protobuf::{parse_from_bytes, Message};
+    use protocol;
+
+    use super::*;
+
+    fn message() -> protocol::net::Msg {
+        protocol::Message::new(&protocol::net::Ping::new()).routing(Some(42)).build()
+    }
+
+    #[test]
+    fn signed_message_verifies() {
+        let keyring = Keyring::new(&["current"]).unwrap();
+        let mut msg = message();
+        assert!(!keyring.verify(&msg));
+        keyring.sign(&mut msg);
+        assert!(keyring.verify(&msg));
+    }
+
+    #[test]
+    fn tampered_message_fails() {
+        let keyring = Keyring::new(&["current"]).unwrap();
+        let mut msg = message();
+        keyring.sign(&mut msg);
+        msg.mut_route_info().set_hash(43);
+        assert!(!keyring.verify(&msg));
+    }
+
+    #[test]
+    fn tampered_timestamp_fails() {
+        let keyring = Keyring::new(&["current"]).unwrap();
+        let mut msg = message();
+        msg.set_sent_at_ms(1000);
+        keyring.sign(&mut msg);
+        msg.set_sent_at_ms(2000);
+        assert!(!keyring.verify(&msg));
+    }
+
+    #[test]
+    fn previous_keys_accepted() {
+        let old = Keyring::new(&["old"]).unwrap();
+        let rotated = Keyring::new(&["new", "old"]).unwrap();
+        let mut msg = message();
+        old.sign(&mut msg);
+        assert!(rotated.verify(&msg));
+        rotated.sign(&mut msg);
+        assert!(!old.verify(&msg));
+    }
+
+    #[test]
+    fn expired_keys_rejected() {
+        let old = Keyring::new(&["k0"]).unwrap();
+        let rotated = Keyring::new(&["k4", "k3", "k2", "k1", "k0"]).unwrap();
+        let mut msg = message();
+        old.sign(&mut msg);
+        assert!(!rotated.verify(&msg));
+    }
+
+    #[test]
+    fn route_info_from_newer_peer_verifies() {
+        let keyring = Keyring::new(&["current"]).unwrap();
+        let mut msg = message();
+        keyring.sign(&mut msg);
+        // The route info re-encoded with a field this build doesn't know
+        let mut bytes = msg.get_route_info().write_to_bytes().unwrap();
+        bytes.extend_from_slice(&[0x78, 0x01]);
+        msg.set_route_info(parse_from_bytes(&bytes).unwrap());
+        assert!(keyring.verify(&msg));
+    }
+
+    #[test]
+    fn body_from_newer_peer_verifies() {
+        let keyring = Keyring::new(&["current"]).unwrap();
+        let mut ping = protocol::net::Ping::new();
+        ping.set_endpoint("srv".to_string());
+        let mut msg = protocol::Message::new(&ping).routing(Some(42)).build();
+        keyring.sign(&mut msg);
+        let mut body = msg.get_body().to_vec();
+        body.extend_from_slice(&[0x78, 0x01]);
+        msg.set_body(body);
+        assert!(keyring.verify(&msg));
+        // But a body whose known fields changed does not.
+        ping.set_endpoint("other".to_string());
+        msg.set_body(ping.write_to_bytes().unwrap());
+        assert!(!keyring.verify(&msg));
+    }
+
+    #[test]
+    fn empty_keyring() {
+        let keys: Vec<String> = vec![];
+        assert!(Keyring::new(&keys).is_none());
+    }
+}
@@ -12,22 +12,54 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+extern crate env_logger;
 extern crate fnv;
 extern crate habitat_builder_protocol as protocol;
+extern crate habitat_core as hab_core;
 extern crate hyper;
+#[macro_use]
+extern crate lazy_static;
 extern crate libc;
 #[macro_use]
 extern crate log;
 extern crate protobuf;
 extern crate rustc_serialize;
+extern crate sodiumoxide;
+#[cfg(test)]
+extern crate tempdir;
 extern crate time;
+extern crate toml;
 extern crate zmq;
 
+pub mod auth;
+pub mod cache;
+pub mod capture;
+pub mod chaos;
+pub mod clients;
+pub mod clock;
+pub mod codec;
+pub mod concurrency;
 pub mod config;
+pub mod control;
+pub mod debug;
 pub mod error;
+pub mod health;
+pub mod hmac;
+pub mod logging;
+pub mod metrics;
+pub mod mux;
 pub mod oauth;
+pub mod readiness;
 pub mod routing;
+pub mod runtime;
+pub mod sched;
 pub mod server;
+pub mod shard;
+pub mod stats;
+pub mod testing;
+pub mod trace;
+pub mod usage;
+pub mod zap;
 
 use std::process::Command;
 
@@ -0,0 +1,211 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Context attached to log records emitted while handling network messages.
+//!
+//! Log lines for a message should include its `LogContext`, which renders as space separated
+//! `key=value` pairs, so the records for one request can be grepped across every service it
+//! passed through:
+//!
+//! ```text
+//! net_ident=seshsrv#42@host txn=7 protocol=SessionSrv trace=5b6f0e1c msg_id=SessionGet ...
+//! ```
+//!
+//! Services which install their logger with `init` can have their log level changed while they
+//! run with `set_level`.
+
+use std::env;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+
+use env_logger;
+use log::{self, LogLevelFilter, LogMetadata, LogRecord, MaxLogLevelFilter, SetLoggerError};
+use protocol;
+
+lazy_static! {
+    // This process's network identifier, once set.
+    static ref NET_IDENT: RwLock<Option<String>> = RwLock::new(None);
+    // Handle for changing the maximum log level, once `init` has been called.
+    static ref MAX_LEVEL: RwLock<Option<MaxLogLevelFilter>> = RwLock::new(None);
+}
+
+static NEXT_TXN: AtomicUsize = ATOMIC_USIZE_INIT;
+// Level set with `set_level`, plus one, or 0 to use the levels configured by `RUST_LOG`.
+static LEVEL_OVERRIDE: AtomicUsize = ATOMIC_USIZE_INIT;
+// Maximum level configured by `RUST_LOG`.
+static CONFIGURED_LEVEL: AtomicUsize = ATOMIC_USIZE_INIT;
+
+// Logs as configured by `RUST_LOG` until a level is set with `set_level`, after which every
+// record at or below that level is written to stderr.
+struct Logger(env_logger::Logger);
+
+impl log::Log for Logger {
+    fn enabled(&self, metadata: &LogMetadata) -> bool {
+        match level_override() {
+            Some(level) => metadata.level() <= level,
+            None => self.0.enabled(metadata),
+        }
+    }
+
+    fn log(&self, record: &LogRecord) {
+        match level_override() {
+            Some(level) => {
+                if record.level() <= level {
+                    let _ = writeln!(io::stderr(),
+                                     "{}:{}: {}",
+                                     record.level(),
+                                     record.location().module_path(),
+                                     record.args());
+                }
+            }
+            None => self.0.log(record),
+        }
+    }
+}
+
+/// Install a logger configured by the `RUST_LOG` environment variable, like `env_logger::init`,
+/// whose level can later be changed with `set_level`.
+pub fn init() -> Result<(), SetLoggerError> {
+    let mut builder = env_logger::LogBuilder::new();
+    if let Ok(spec) = env::var("RUST_LOG") {
+        builder.parse(&spec);
+    }
+    let logger = builder.build();
+    log::set_logger(|max_level| {
+        max_level.set(logger.filter());
+        CONFIGURED_LEVEL.store(logger.filter() as usize, Ordering::SeqCst);
+        *MAX_LEVEL.write().unwrap() = Some(max_level);
+        Box::new(Logger(logger))
+    })
+}
+
+/// Log every record at or below the given level, or return to the levels configured by
+/// `RUST_LOG` when given `None`. Returns false if the logger wasn't installed with `init`.
+pub fn set_level(level: Option<LogLevelFilter>) -> bool {
+    let max_level = MAX_LEVEL.read().unwrap();
+    let max_level = match *max_level {
+        Some(ref max_level) => max_level,
+        None => return false,
+    };
+    match level {
+        Some(level) => {
+            LEVEL_OVERRIDE.store(level as usize + 1, Ordering::SeqCst);
+            max_level.set(level);
+        }
+        None => {
+            LEVEL_OVERRIDE.store(0, Ordering::SeqCst);
+            max_level.set(level_filter(CONFIGURED_LEVEL.load(Ordering::SeqCst)));
+        }
+    }
+    true
+}
+
+/// The level set with `set_level`, if any.
+pub fn level_override() -> Option<LogLevelFilter> {
+    match LEVEL_OVERRIDE.load(Ordering::SeqCst) {
+        0 => None,
+        level => Some(level_filter(level - 1)),
+    }
+}
+
+/// Parse a log level name such as `debug`, or `default` for the levels configured by `RUST_LOG`.
+pub fn parse_level(name: &str) -> Option<Option<LogLevelFilter>> {
+    if name == "default" {
+        return Some(None);
+    }
+    LogLevelFilter::from_str(name).ok().map(Some)
+}
+
+fn level_filter(value: usize) -> LogLevelFilter {
+    match value {
+        0 => LogLevelFilter::Off,
+        1 => LogLevelFilter::Error,
+        2 => LogLevelFilter::Warn,
+        3 => LogLevelFilter::Info,
+        4 => LogLevelFilter::Debug,
+        _ => LogLevelFilter::Trace,
+    }
+}
+
+/// Set the network identifier included in every `LogContext`. Only the first call has an effect.
+pub fn set_net_ident(ident: String) {
+    let mut net_ident = NET_IDENT.write().unwrap();
+    if net_ident.is_none() {
+        *net_ident = Some(ident);
+    }
+}
+
+/// The network identifier of this process, or `-` if it has not been set.
+pub fn net_ident() -> String {
+    match *NET_IDENT.read().unwrap() {
+        Some(ref ident) => ident.clone(),
+        None => "-".to_string(),
+    }
+}
+
+/// Allocate a transaction id for a newly received message. Ids are unique within a process.
+pub fn next_txn() -> u64 {
+    NEXT_TXN.fetch_add(1, Ordering::SeqCst) as u64 + 1
+}
+
+/// Structured fields describing the message a log record relates to.
+pub struct LogContext<'a> {
+    pub txn: Option<u64>,
+    pub msg: Option<&'a protocol::net::Msg>,
+}
+
+impl<'a> LogContext<'a> {
+    pub fn new(txn: Option<u64>, msg: Option<&'a protocol::net::Msg>) -> Self {
+        LogContext {
+            txn: txn,
+            msg: msg,
+        }
+    }
+}
+
+impl<'a> From<&'a protocol::net::Msg> for LogContext<'a> {
+    fn from(msg: &'a protocol::net::Msg) -> Self {
+        LogContext::new(None, Some(msg))
+    }
+}
+
+impl<'a> fmt::Display for LogContext<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "net_ident={}", net_ident()));
+        match self.txn {
+            Some(txn) => try!(write!(f, " txn={}", txn)),
+            None => try!(write!(f, " txn=-")),
+        }
+        match self.msg {
+            Some(msg) if msg.has_route_info() => {
+                try!(write!(f, " protocol={:?}", msg.get_route_info().get_protocol()))
+            }
+            _ => try!(write!(f, " protocol=-")),
+        }
+        match self.msg {
+            Some(msg) if msg.get_route_info().has_trace_id() => {
+                try!(write!(f, " trace={:x}", msg.get_route_info().get_trace_id()))
+            }
+            _ => try!(write!(f, " trace=-")),
+        }
+        match self.msg {
+            Some(msg) if !msg.get_message_id().is_empty() => {
+                write!(f, " msg_id={}", msg.get_message_id())
+            }
+            _ => write!(f, " msg_id=-"),
+        }
+    }
+}
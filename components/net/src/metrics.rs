@@ -0,0 +1,479 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Process wide counters for network activity and exporters which publish them.
+//!
+//! Counters are always collected. They are only exported when a service's configuration names a
+//! statsd server to push to or an address to serve a Prometheus scrape endpoint from.
+//!
+//! Messages a worker receives and answers are counted apart from requests a client sends through
+//! a `BrokerConn` or `Mux`, so a process which is both, such as a service calling another, reports
+//! each side separately.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::net::{self, UdpSocket};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering, ATOMIC_BOOL_INIT, ATOMIC_USIZE_INIT};
+use std::thread;
+use std::time::Duration;
+
+use hyper::server::{Request, Response, Server};
+
+use config;
+use error::Result;
+use logging;
+use usage::{self, Usage};
+
+/// Interval between pushes of counter deltas to statsd.
+pub const STATSD_FLUSH_MS: u64 = 10_000;
+/// Prefix of every exported metric name.
+pub const METRIC_PREFIX: &'static str = "hab_net";
+/// Upper bounds, in milliseconds, of the buckets the queue latencies of dispatched messages are
+/// counted in.
+pub static QUEUE_LATENCY_BUCKETS_MS: [u64; 12] = [1, 5, 10, 25, 50, 100, 250, 500, 1_000, 2_500,
+                                                  5_000, 10_000];
+
+lazy_static! {
+    static ref QUEUE_LATENCY: Histogram = Histogram::new(&QUEUE_LATENCY_BUCKETS_MS);
+}
+
+static CACHE_HITS: AtomicUsize = ATOMIC_USIZE_INIT;
+static CACHE_MISSES: AtomicUsize = ATOMIC_USIZE_INIT;
+static CLIENT_ERRORS: AtomicUsize = ATOMIC_USIZE_INIT;
+static CLOCK_SKEW: AtomicUsize = ATOMIC_USIZE_INIT;
+static DISPATCHED: AtomicUsize = ATOMIC_USIZE_INIT;
+static DROPPED: AtomicUsize = ATOMIC_USIZE_INIT;
+static ERRORS: AtomicUsize = ATOMIC_USIZE_INIT;
+static HEDGED: AtomicUsize = ATOMIC_USIZE_INIT;
+static RECEIVED: AtomicUsize = ATOMIC_USIZE_INIT;
+static RECONNECTS: AtomicUsize = ATOMIC_USIZE_INIT;
+static REPLIES: AtomicUsize = ATOMIC_USIZE_INIT;
+static REQUESTS: AtomicUsize = ATOMIC_USIZE_INIT;
+static SENT: AtomicUsize = ATOMIC_USIZE_INIT;
+static THROTTLED: AtomicUsize = ATOMIC_USIZE_INIT;
+// Set once the exporters have been started, so they're started only once per process.
+static STARTED: AtomicBool = ATOMIC_BOOL_INIT;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Counter {
+    /// Requests answered from a `Broker`'s reply cache
+    CacheHits,
+    /// Cacheable requests which had to be routed to a service
+    CacheMisses,
+    /// Client requests whose reply timed out or failed, or which a `Broker` dropped
+    ClientErrors,
+    /// Messages stamped further in the future than clock skew tolerates
+    ClockSkew,
+    /// Messages handed to a worker's `on_message`
+    Dispatched,
+    /// Malformed messages a worker discarded before dispatch
+    Dropped,
+    /// Messages a worker failed to parse or whose handler returned an error
+    Errors,
+    /// Duplicates of slow requests sent over a `BrokerConn`'s hedge connection
+    Hedged,
+    /// Messages a worker read from its socket
+    Received,
+    /// Connections re-established after a worker or socket failed
+    Reconnects,
+    /// Replies a client received
+    Replies,
+    /// Requests a client sent, not counting hedged duplicates
+    Requests,
+    /// Replies and forwarded messages a worker wrote to its socket
+    Sent,
+    /// Messages refused because their sender was over its rate cap
+    Throttled,
+}
+
+pub const COUNTERS: [Counter; 14] = [Counter::CacheHits,
+                                     Counter::CacheMisses,
+                                     Counter::ClientErrors,
+                                     Counter::ClockSkew,
+                                     Counter::Dispatched,
+                                     Counter::Dropped,
+                                     Counter::Errors,
+                                     Counter::Hedged,
+                                     Counter::Received,
+                                     Counter::Reconnects,
+                                     Counter::Replies,
+                                     Counter::Requests,
+                                     Counter::Sent,
+                                     Counter::Throttled];
+
+impl Counter {
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Counter::CacheHits => "cache_hits",
+            Counter::CacheMisses => "cache_misses",
+            Counter::ClientErrors => "client_errors",
+            Counter::ClockSkew => "clock_skew",
+            Counter::Dispatched => "dispatched",
+            Counter::Dropped => "dropped",
+            Counter::Errors => "errors",
+            Counter::Hedged => "hedged",
+            Counter::Received => "received",
+            Counter::Reconnects => "reconnects",
+            Counter::Replies => "replies",
+            Counter::Requests => "requests",
+            Counter::Sent => "sent",
+            Counter::Throttled => "throttled",
+        }
+    }
+
+    fn cell(&self) -> &'static AtomicUsize {
+        match *self {
+            Counter::CacheHits => &CACHE_HITS,
+            Counter::CacheMisses => &CACHE_MISSES,
+            Counter::ClientErrors => &CLIENT_ERRORS,
+            Counter::ClockSkew => &CLOCK_SKEW,
+            Counter::Dispatched => &DISPATCHED,
+            Counter::Dropped => &DROPPED,
+            Counter::Errors => &ERRORS,
+            Counter::Hedged => &HEDGED,
+            Counter::Received => &RECEIVED,
+            Counter::Reconnects => &RECONNECTS,
+            Counter::Replies => &REPLIES,
+            Counter::Requests => &REQUESTS,
+            Counter::Sent => &SENT,
+            Counter::Throttled => &THROTTLED,
+        }
+    }
+}
+
+pub fn incr(counter: Counter) {
+    counter.cell().fetch_add(1, Ordering::Relaxed);
+}
+
+pub fn get(counter: Counter) -> u64 {
+    counter.cell().load(Ordering::Relaxed) as u64
+}
+
+/// Counts of observed values by the bucket they fall in, exported as a Prometheus histogram.
+pub struct Histogram {
+    bounds: &'static [u64],
+    // Observations at or below each bound and above the one before it, then those past the last
+    // bound.
+    buckets: Vec<AtomicUsize>,
+    sum: AtomicUsize,
+}
+
+impl Histogram {
+    pub fn new(bounds: &'static [u64]) -> Self {
+        Histogram {
+            bounds: bounds,
+            buckets: (0..bounds.len() + 1).map(|_| AtomicUsize::new(0)).collect(),
+            sum: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn observe(&self, value: u64) {
+        let i = self.bounds.iter().position(|bound| value <= *bound).unwrap_or(self.bounds.len());
+        self.buckets[i].fetch_add(1, Ordering::Relaxed);
+        self.sum.fetch_add(value as usize, Ordering::Relaxed);
+    }
+
+    pub fn bounds(&self) -> &'static [u64] {
+        self.bounds
+    }
+
+    /// Observations in each bucket, then those past the last bound.
+    pub fn counts(&self) -> Vec<u64> {
+        self.buckets.iter().map(|n| n.load(Ordering::Relaxed) as u64).collect()
+    }
+
+    pub fn count(&self) -> u64 {
+        self.counts().iter().sum()
+    }
+
+    pub fn sum(&self) -> u64 {
+        self.sum.load(Ordering::Relaxed) as u64
+    }
+
+    /// Bound of the bucket holding the given quantile of the observations. See `quantile`.
+    pub fn quantile(&self, q: f64) -> u64 {
+        quantile(self.bounds, &self.counts(), q)
+    }
+}
+
+/// Bound of the bucket holding the given quantile, from 0 to 1, of observations counted by bucket
+/// as `Histogram::counts` returns them. Zero if there were none, and the last bound if the
+/// quantile is past it.
+pub fn quantile(bounds: &[u64], counts: &[u64], q: f64) -> u64 {
+    let total: u64 = counts.iter().sum();
+    if total == 0 {
+        return 0;
+    }
+    let rank = (q * total as f64).ceil().max(1.0) as u64;
+    let mut seen = 0;
+    for (bound, n) in bounds.iter().zip(counts.iter()) {
+        seen += *n;
+        if seen >= rank {
+            return *bound;
+        }
+    }
+    bounds.last().cloned().unwrap_or(0)
+}
+
+/// Record the queue latency of a message about to be dispatched. Negative latencies, from
+/// senders whose clocks are ahead, are recorded as zero.
+pub fn observe_queue_latency_ms(latency_ms: i64) {
+    QUEUE_LATENCY.observe(if latency_ms < 0 { 0 } else { latency_ms as u64 });
+}
+
+/// Queue latencies of the stamped messages dispatched by this process, in milliseconds.
+pub fn queue_latency() -> &'static Histogram {
+    &QUEUE_LATENCY
+}
+
+/// Messages received by a worker but not yet answered, a rough measure of how far behind a
+/// service is.
+pub fn in_flight() -> u64 {
+    let received = get(Counter::Received);
+    let finished = get(Counter::Sent) + get(Counter::Dropped);
+    received.saturating_sub(finished)
+}
+
+/// Requests sent by a client which haven't yet been answered or failed.
+pub fn requests_in_flight() -> u64 {
+    let sent = get(Counter::Requests);
+    let finished = get(Counter::Replies) + get(Counter::ClientErrors);
+    sent.saturating_sub(finished)
+}
+
+/// Render all metrics in the Prometheus text exposition format.
+pub fn render() -> String {
+    let mut out = String::new();
+    let ident = logging::net_ident();
+    for counter in COUNTERS.iter() {
+        let name = format!("{}_{}_total", METRIC_PREFIX, counter.name());
+        write!(out, "# TYPE {} counter\n", name).unwrap();
+        write!(out, "{}{{net_ident=\"{}\"}} {}\n", name, ident, get(*counter)).unwrap();
+    }
+    let name = format!("{}_in_flight", METRIC_PREFIX);
+    write!(out, "# TYPE {} gauge\n", name).unwrap();
+    write!(out, "{}{{net_ident=\"{}\"}} {}\n", name, ident, in_flight()).unwrap();
+    let name = format!("{}_requests_in_flight", METRIC_PREFIX);
+    write!(out, "# TYPE {} gauge\n", name).unwrap();
+    write!(out,
+           "{}{{net_ident=\"{}\"}} {}\n",
+           name,
+           ident,
+           requests_in_flight())
+        .unwrap();
+    render_histogram(&mut out, &ident, "queue_latency_ms", queue_latency());
+    render_usage(&mut out, &ident, "worker", &usage::workers());
+    render_usage(&mut out, &ident, "message", &usage::messages());
+    out
+}
+
+// Render a histogram's cumulative buckets, sum and count.
+fn render_histogram(out: &mut String, ident: &str, suffix: &str, histogram: &Histogram) {
+    let name = format!("{}_{}", METRIC_PREFIX, suffix);
+    write!(out, "# TYPE {} histogram\n", name).unwrap();
+    let counts = histogram.counts();
+    let mut cumulative = 0;
+    for (bound, n) in histogram.bounds().iter().zip(counts.iter()) {
+        cumulative += *n;
+        write!(out,
+               "{}_bucket{{net_ident=\"{}\",le=\"{}\"}} {}\n",
+               name,
+               ident,
+               bound,
+               cumulative)
+            .unwrap();
+    }
+    let count: u64 = counts.iter().sum();
+    write!(out, "{}_bucket{{net_ident=\"{}\",le=\"+Inf\"}} {}\n", name, ident, count).unwrap();
+    write!(out, "{}_sum{{net_ident=\"{}\"}} {}\n", name, ident, histogram.sum()).unwrap();
+    write!(out, "{}_count{{net_ident=\"{}\"}} {}\n", name, ident, count).unwrap();
+}
+
+// Render per worker or per message usage totals, labelled with the given label name.
+fn render_usage(out: &mut String, ident: &str, label: &str, totals: &BTreeMap<String, Usage>) {
+    if totals.is_empty() {
+        return;
+    }
+    let metrics = [("cpu_seconds_total", "counter"),
+                   ("peak_rss_bytes", "gauge"),
+                   ("max_rss_growth_bytes", "gauge")];
+    // The resident set is the process's, so it is only attributed to message ids.
+    let count = if label == "message" { metrics.len() } else { 1 };
+    for &(suffix, kind) in metrics[..count].iter() {
+        let name = format!("{}_{}_{}", METRIC_PREFIX, label, suffix);
+        write!(out, "# TYPE {} {}\n", name, kind).unwrap();
+        for (key, usage) in totals.iter() {
+            let value = match suffix {
+                "cpu_seconds_total" => format!("{}", usage.cpu_us as f64 / 1_000_000.0),
+                "peak_rss_bytes" => usage.peak_rss_bytes.to_string(),
+                _ => usage.max_rss_growth_bytes.to_string(),
+            };
+            write!(out,
+                   "{}{{net_ident=\"{}\",{}=\"{}\"}} {}\n",
+                   name,
+                   ident,
+                   label,
+                   key,
+                   value)
+                .unwrap();
+        }
+    }
+}
+
+/// Start exporting metrics with the given configuration. Does nothing if no exporter is
+/// configured, or if they were already started, such as by an earlier connection to the routers.
+///
+/// # Errors
+///
+/// * The statsd socket could not be bound
+/// * The scrape endpoint could not be bound
+pub fn start<T: config::Metrics>(cfg: &T) -> Result<()> {
+    if STARTED.swap(true, Ordering::SeqCst) {
+        return Ok(());
+    }
+    let started = start_exporters(cfg);
+    if started.is_err() {
+        STARTED.store(false, Ordering::SeqCst);
+    }
+    started
+}
+
+fn start_exporters<T: config::Metrics>(cfg: &T) -> Result<()> {
+    if let Some(addr) = cfg.statsd_addr() {
+        let socket = try!(UdpSocket::bind("0.0.0.0:0"));
+        thread::Builder::new()
+            .name("metrics-statsd".to_string())
+            .spawn(move || statsd(socket, addr))
+            .unwrap();
+    }
+    if let Some(addr) = cfg.metrics_addr() {
+        let server = try!(Server::http(addr));
+        thread::Builder::new()
+            .name("metrics-http".to_string())
+            .spawn(move || {
+                let handler = |_req: Request, res: Response| {
+                    if let Err(e) = res.send(render().as_bytes()) {
+                        warn!("{} failed to send metrics, err={}",
+                              logging::LogContext::new(None, None),
+                              e);
+                    }
+                };
+                if let Err(e) = server.handle(handler) {
+                    error!("metrics endpoint stopped, err={}", e);
+                }
+            })
+            .unwrap();
+    }
+    Ok(())
+}
+
+// Push counter deltas to a statsd server forever.
+fn statsd(socket: UdpSocket, addr: net::SocketAddrV4) {
+    // The value of each of `COUNTERS` and the queue latency counts as of the last push.
+    let mut last = vec![0u64; COUNTERS.len()];
+    let mut last_latency = queue_latency().counts();
+    loop {
+        thread::sleep(Duration::from_millis(STATSD_FLUSH_MS));
+        let mut packet = String::new();
+        for (i, counter) in COUNTERS.iter().enumerate() {
+            let value = get(*counter);
+            let delta = value - last[i];
+            last[i] = value;
+            if delta > 0 {
+                write!(packet, "{}.{}:{}|c\n", METRIC_PREFIX, counter.name(), delta).unwrap();
+            }
+        }
+        for (worker, usage) in usage::workers().iter() {
+            write!(packet,
+                   "{}.worker.{}.cpu_us:{}|g\n",
+                   METRIC_PREFIX,
+                   worker,
+                   usage.cpu_us)
+                .unwrap();
+        }
+        write!(packet, "{}.rss_bytes:{}|g\n", METRIC_PREFIX, usage::rss()).unwrap();
+        // Queue latency percentiles are of the messages dispatched since the last push.
+        let latency = queue_latency().counts();
+        let window: Vec<u64> = latency.iter()
+            .zip(last_latency.iter())
+            .map(|(n, l)| n - l)
+            .collect();
+        last_latency = latency;
+        for &(label, q) in [("p50", 0.5), ("p99", 0.99)].iter() {
+            write!(packet,
+                   "{}.queue_latency_ms.{}:{}|g\n",
+                   METRIC_PREFIX,
+                   label,
+                   quantile(&QUEUE_LATENCY_BUCKETS_MS, &window, q))
+                .unwrap();
+        }
+        write!(packet,
+               "{}.in_flight:{}|g\n{}.requests_in_flight:{}|g",
+               METRIC_PREFIX,
+               in_flight(),
+               METRIC_PREFIX,
+               requests_in_flight())
+            .unwrap();
+        if let Err(e) = socket.send_to(packet.as_bytes(), addr) {
+            debug!("failed to push metrics to statsd, err={}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static BOUNDS: [u64; 3] = [10, 100, 1000];
+
+    #[test]
+    fn histogram_counts_by_bucket() {
+        let histogram = Histogram::new(&BOUNDS);
+        for value in [0, 10, 11, 100, 5000].iter() {
+            histogram.observe(*value);
+        }
+        assert_eq!(histogram.counts(), vec![2, 2, 0, 1]);
+        assert_eq!(histogram.count(), 5);
+        assert_eq!(histogram.sum(), 5121);
+    }
+
+    #[test]
+    fn histogram_quantiles() {
+        let histogram = Histogram::new(&BOUNDS);
+        assert_eq!(histogram.quantile(0.5), 0);
+        for _ in 0..98 {
+            histogram.observe(5);
+        }
+        histogram.observe(50);
+        histogram.observe(5000);
+        assert_eq!(histogram.quantile(0.5), 10);
+        assert_eq!(histogram.quantile(0.99), 100);
+        assert_eq!(histogram.quantile(1.0), 1000);
+    }
+
+    #[test]
+    fn histogram_renders_cumulative_buckets() {
+        let histogram = Histogram::new(&BOUNDS);
+        histogram.observe(5);
+        histogram.observe(500);
+        let mut out = String::new();
+        render_histogram(&mut out, "test", "latency_ms", &histogram);
+        assert!(out.contains("# TYPE hab_net_latency_ms histogram\n"));
+        assert!(out.contains("hab_net_latency_ms_bucket{net_ident=\"test\",le=\"10\"} 1\n"));
+        assert!(out.contains("hab_net_latency_ms_bucket{net_ident=\"test\",le=\"100\"} 1\n"));
+        assert!(out.contains("hab_net_latency_ms_bucket{net_ident=\"test\",le=\"1000\"} 2\n"));
+        assert!(out.contains("hab_net_latency_ms_bucket{net_ident=\"test\",le=\"+Inf\"} 2\n"));
+        assert!(out.contains("hab_net_latency_ms_sum{net_ident=\"test\"} 505\n"));
+        assert!(out.contains("hab_net_latency_ms_count{net_ident=\"test\"} 2\n"));
+    }
+}
@@ -0,0 +1,347 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Many logical client connections sharing one DEALER socket per router.
+//!
+//! A service which talks to several protocols would otherwise open a socket to every router for
+//! each of them. A `Mux` connects one DEALER to each router and hands out `MuxHandle`s, each
+//! acting as its own `RouteClient`. Every request is tagged with a correlation id which the
+//! serving worker echoes in its reply, so a reply read by any handle is set aside for the handle
+//! which sent the request.
+//!
+//! A `Mux` and its handles belong to a single thread.
+//!
+//! ```ignore
+//! let mux = try!(Mux::new(&ctx, &routers));
+//! let mut sessions = mux.handle();
+//! let mut vault = mux.handle();
+//! let session = try!(SessionClient::get(&mut sessions, &req));
+//! let origin = try!(VaultClient::get_origin(&mut vault, &origin_req));
+//! ```
+
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::net;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use fnv::FnvHasher;
+use protobuf::{parse_from_bytes, Message};
+use protocol::{self, Routable, RouteKey};
+use zmq;
+
+use clock::{self, Clock, SharedClock, SystemClock};
+use codec::Header;
+use error::{Error, Result};
+use hmac;
+use logging::LogContext;
+use metrics::{self, Counter};
+use routing::{BrokerContext, RouteClient, RECV_TIMEOUT_MS, SEND_TIMEOUT_MS};
+use server::ToAddrString;
+use trace;
+
+// Client frame sent with every multiplexed request. Replies are matched to handles by correlation
+// id rather than by client.
+const CLIENT_FRAME: &'static [u8] = b"mux";
+
+/// Owner of the sockets shared by its handles.
+pub struct Mux {
+    inner: Rc<RefCell<Inner>>,
+}
+
+struct Inner {
+    socks: Vec<zmq::Socket>,
+    // Socket the next request is sent on; requests are spread round robin.
+    next_sock: usize,
+    next_handle: u64,
+    next_correlation_id: u64,
+    // Handle awaiting the reply to each request in flight, keyed by correlation id.
+    pending: HashMap<u64, u64>,
+    // Replies received for each handle which haven't been read yet.
+    ready: HashMap<u64, VecDeque<protocol::net::Msg>>,
+    hasher: FnvHasher,
+    clock: SharedClock,
+}
+
+impl Mux {
+    /// Connect a DEALER to each of the given routers.
+    ///
+    /// # Errors
+    ///
+    /// * A socket cannot be created or configured
+    /// * A socket cannot connect to its router
+    ///
+    /// # Panics
+    ///
+    /// * Could not read `zmq::Context` due to deadlock or poisoning
+    pub fn new(ctx: &BrokerContext, routers: &Vec<net::SocketAddrV4>) -> Result<Self> {
+        let mut ctx = ctx.0.write().unwrap();
+        let mut socks = Vec::with_capacity(routers.len());
+        for addr in routers.iter() {
+            let sock = try!(ctx.socket(zmq::DEALER));
+            try!(sock.set_sndtimeo(SEND_TIMEOUT_MS));
+            try!(sock.set_immediate(true));
+            try!(sock.connect(&addr.to_addr_string()));
+            socks.push(sock);
+        }
+        Ok(Mux {
+            inner: Rc::new(RefCell::new(Inner {
+                socks: socks,
+                next_sock: 0,
+                next_handle: 0,
+                next_correlation_id: 0,
+                pending: HashMap::new(),
+                ready: HashMap::new(),
+                hasher: FnvHasher::default(),
+                clock: Arc::new(SystemClock),
+            })),
+        })
+    }
+
+    /// A new logical connection over this multiplexer's sockets.
+    pub fn handle(&self) -> MuxHandle {
+        let mut inner = self.inner.borrow_mut();
+        inner.next_handle += 1;
+        let id = inner.next_handle;
+        inner.ready.insert(id, VecDeque::new());
+        MuxHandle {
+            id: id,
+            inner: self.inner.clone(),
+        }
+    }
+
+    /// Stamp the requests of every handle with the given clock.
+    pub fn set_clock(&self, clock: SharedClock) {
+        self.inner.borrow_mut().clock = clock;
+    }
+
+    /// Number of requests sent by any handle whose reply hasn't arrived yet.
+    pub fn in_flight(&self) -> usize {
+        self.inner.borrow().pending.len()
+    }
+}
+
+/// A logical client connection created by a `Mux`.
+pub struct MuxHandle {
+    id: u64,
+    inner: Rc<RefCell<Inner>>,
+}
+
+impl RouteClient for MuxHandle {
+    fn route<M: Routable>(&mut self, msg: &M) -> Result<()> {
+        let mut inner = self.inner.borrow_mut();
+        if inner.socks.is_empty() {
+            return Err(Error::Zmq(zmq::Error::EHOSTUNREACH));
+        }
+        let route_hash = msg.route_key().map(|key| key.hash(&mut inner.hasher));
+        let mut req = protocol::Message::new(msg)
+            .routing(route_hash)
+            .sticky(msg.sticky())
+            .build();
+        inner.next_correlation_id += 1;
+        let correlation_id = inner.next_correlation_id;
+        req.set_correlation_id(correlation_id);
+        // Requests entering the mesh from a client begin a new trace.
+        let span = trace::current().unwrap_or_else(trace::SpanContext::root);
+        span.inject(req.mut_route_info());
+        clock::stamp(&inner.clock, &mut req);
+        hmac::sign(&mut req);
+        let bytes = try!(req.write_to_bytes());
+        let i = inner.next_sock % inner.socks.len();
+        inner.next_sock = i + 1;
+        debug!("{} routing multiplexed message", LogContext::from(&req));
+        {
+            // Framed as a `Broker` forwards a client's request, `[client][empty]["RQ"][msg]`.
+            let sock = &mut inner.socks[i];
+            try!(sock.send(CLIENT_FRAME, zmq::SNDMORE));
+            try!(sock.send(&[], zmq::SNDMORE));
+            try!(sock.send(Header::Request.as_bytes(), zmq::SNDMORE));
+            try!(sock.send(&bytes, 0));
+        }
+        inner.pending.insert(correlation_id, self.id);
+        metrics::incr(Counter::Requests);
+        Ok(())
+    }
+
+    /// Receive the next reply to a request sent by this handle, setting aside any replies for
+    /// other handles read along the way.
+    ///
+    /// # Errors
+    ///
+    /// * No reply arrived within `RECV_TIMEOUT_MS`
+    fn recv(&mut self) -> Result<protocol::net::Msg> {
+        let deadline = SystemClock.now_ms() + RECV_TIMEOUT_MS as i64;
+        let mut inner = self.inner.borrow_mut();
+        loop {
+            if let Some(msg) = inner.ready.get_mut(&self.id).and_then(|q| q.pop_front()) {
+                return Ok(msg);
+            }
+            let remaining = deadline - SystemClock.now_ms();
+            if remaining <= 0 {
+                // Forget the requests given up on so their entries don't linger; late replies
+                // to them are dropped.
+                inner.abandon(self.id);
+                metrics::incr(Counter::ClientErrors);
+                return Err(Error::Timeout);
+            }
+            try!(inner.poll(remaining));
+        }
+    }
+}
+
+impl Drop for MuxHandle {
+    fn drop(&mut self) {
+        let mut inner = self.inner.borrow_mut();
+        inner.ready.remove(&self.id);
+        inner.abandon(self.id);
+    }
+}
+
+impl Inner {
+    // Stop waiting for the replies to every request the handle has in flight.
+    fn abandon(&mut self, handle: u64) {
+        let abandoned: Vec<u64> = self.pending
+            .iter()
+            .filter(|&(_, h)| *h == handle)
+            .map(|(correlation_id, _)| *correlation_id)
+            .collect();
+        for correlation_id in abandoned {
+            self.pending.remove(&correlation_id);
+        }
+    }
+
+    // Wait up to `timeout_ms` for replies and queue each for the handle which sent its request.
+    fn poll(&mut self, timeout_ms: i64) -> Result<()> {
+        let readable: Vec<usize> = {
+            let mut items: Vec<zmq::PollItem> =
+                self.socks.iter().map(|s| s.as_poll_item(zmq::POLLIN)).collect();
+            try!(zmq::poll(&mut items, timeout_ms));
+            items.iter()
+                .enumerate()
+                .filter(|&(_, item)| item.get_revents() & zmq::POLLIN > 0)
+                .map(|(i, _)| i)
+                .collect()
+        };
+        for i in readable {
+            // Replies arrive as `[client][empty][msg]`.
+            let mut frames = vec![try!(self.socks[i].recv_msg(0))];
+            while try!(self.socks[i].get_rcvmore()) {
+                frames.push(try!(self.socks[i].recv_msg(0)));
+            }
+            let msg: protocol::net::Msg = match frames.last().map(|f| parse_from_bytes(f)) {
+                Some(Ok(msg)) => msg,
+                _ => {
+                    warn!("dropping unparsable multiplexed reply");
+                    continue;
+                }
+            };
+            metrics::incr(Counter::Replies);
+            self.dispatch(msg);
+        }
+        Ok(())
+    }
+
+    fn dispatch(&mut self, msg: protocol::net::Msg) {
+        let correlation_id = msg.get_correlation_id();
+        // Each request is answered by a single multipart message, so its reply completes it.
+        let handle = match self.pending.remove(&correlation_id) {
+            Some(handle) => handle,
+            None => {
+                debug!("{} dropping reply to an abandoned request",
+                       LogContext::from(&msg));
+                return;
+            }
+        };
+        if let Some(queue) = self.ready.get_mut(&handle) {
+            queue.push_back(msg);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use protocol;
+
+    use super::*;
+    use routing::BrokerContext;
+
+    fn reply(correlation_id: u64) -> protocol::net::Msg {
+        let mut msg = protocol::net::Msg::new();
+        msg.set_message_id("SessionGet".to_string());
+        msg.set_correlation_id(correlation_id);
+        msg
+    }
+
+    #[test]
+    fn replies_are_queued_for_the_requesting_handle() {
+        let mux = Mux::new(&BrokerContext::new(), &vec![]).unwrap();
+        let a = mux.handle();
+        let b = mux.handle();
+        {
+            let mut inner = mux.inner.borrow_mut();
+            inner.pending.insert(1, a.id);
+            inner.pending.insert(2, b.id);
+            inner.dispatch(reply(2));
+            inner.dispatch(reply(1));
+            assert_eq!(inner.ready[&a.id][0].get_correlation_id(), 1);
+            assert_eq!(inner.ready[&b.id][0].get_correlation_id(), 2);
+        }
+        assert_eq!(mux.in_flight(), 0);
+    }
+
+    #[test]
+    fn duplicate_replies_are_discarded() {
+        let mux = Mux::new(&BrokerContext::new(), &vec![]).unwrap();
+        let a = mux.handle();
+        let mut inner = mux.inner.borrow_mut();
+        inner.pending.insert(1, a.id);
+        inner.dispatch(reply(1));
+        inner.dispatch(reply(1));
+        assert_eq!(inner.ready[&a.id].len(), 1);
+    }
+
+    #[test]
+    fn abandon_forgets_only_the_handles_requests() {
+        let mux = Mux::new(&BrokerContext::new(), &vec![]).unwrap();
+        let a = mux.handle();
+        let b = mux.handle();
+        let mut inner = mux.inner.borrow_mut();
+        inner.pending.insert(1, a.id);
+        inner.pending.insert(2, a.id);
+        inner.pending.insert(3, b.id);
+        inner.abandon(a.id);
+        assert_eq!(inner.pending.len(), 1);
+        assert_eq!(inner.pending[&3], b.id);
+    }
+
+    #[test]
+    fn replies_to_dropped_handles_are_discarded() {
+        let mux = Mux::new(&BrokerContext::new(), &vec![]).unwrap();
+        let a = mux.handle();
+        let id = a.id;
+        mux.inner.borrow_mut().pending.insert(1, id);
+        drop(a);
+        assert_eq!(mux.in_flight(), 0);
+        let mut inner = mux.inner.borrow_mut();
+        inner.dispatch(reply(1));
+        assert!(!inner.ready.contains_key(&id));
+    }
+
+    #[test]
+    fn route_without_routers_fails() {
+        let mux = Mux::new(&BrokerContext::new(), &vec![]).unwrap();
+        let mut handle = mux.handle();
+        assert!(handle.route(&protocol::sessionsrv::SessionGet::new()).is_err());
+    }
+}
@@ -0,0 +1,337 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Process wide barrier holding a service back until all of its components are ready.
+//!
+//! Each component which has to be up before a service can take traffic, such as a `Supervisor`
+//! and its workers or a `Broker`, is `expect`ed when it starts and reports `ready` once it is.
+//! A `Service` waits for every expected component before registering with its routers, and is
+//! only ready itself once registered. Until then the `readiness` check of its health replies is
+//! `DOWN`, naming the components still being waited on.
+//!
+//! A process may run several of each kind of component, such as a test starting more than one
+//! `Supervisor`, so components are expected under a name from `instance` which is unique to it.
+//! A component which can fail before it is ready is expected with `expecting`, so that it is
+//! forgotten again rather than waited on for good.
+
+use std::collections::BTreeMap;
+use std::sync::{Condvar, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use std::time::{Duration, Instant};
+
+use protocol::net::{HealthCheckResult, HealthStatus};
+
+use debug;
+use error::{Error, Result};
+use health;
+
+/// Kind of component reported ready by a `Broker` once its sockets are up.
+pub const BROKER: &'static str = "broker";
+/// Kind of component reported ready by a `Service` once it has registered with every router.
+pub const REGISTRATION: &'static str = "registration";
+/// Kind of component reported ready by a `Supervisor` once all of its workers have started.
+pub const SUPERVISOR: &'static str = "supervisor";
+
+/// Name of the health check reporting whether every component is ready.
+pub const READINESS_CHECK: &'static str = "readiness";
+
+/// How long a `Service` waits for its components before giving up on registering.
+pub const READY_TIMEOUT_MS: u64 = 30_000;
+
+/// The ready state of a set of components.
+#[derive(Debug, Default)]
+pub struct Readiness {
+    components: Mutex<BTreeMap<String, bool>>,
+    changed: Condvar,
+}
+
+impl Readiness {
+    pub fn new() -> Self {
+        Readiness::default()
+    }
+
+    /// Add a component which must report ready. A component expected again is no longer ready,
+    /// such as when it restarts.
+    pub fn expect(&self, component: &str) {
+        self.set(component, false);
+    }
+
+    /// Record that a component is ready.
+    pub fn ready(&self, component: &str) {
+        self.set(component, true);
+    }
+
+    /// Record that a component which was ready is no longer.
+    pub fn not_ready(&self, component: &str) {
+        self.expect(component);
+    }
+
+    /// Stop expecting a component, such as one which failed to start.
+    pub fn forget(&self, component: &str) {
+        self.components.lock().unwrap().remove(component);
+        self.changed.notify_all();
+    }
+
+    /// True once every expected component is ready.
+    pub fn is_ready(&self) -> bool {
+        self.components.lock().unwrap().values().all(|ready| *ready)
+    }
+
+    /// Names of the expected components which aren't ready.
+    pub fn pending(&self) -> Vec<String> {
+        pending(&self.components.lock().unwrap())
+    }
+
+    /// Block until every expected component is ready.
+    ///
+    /// # Errors
+    ///
+    /// * Some component was still not ready after `timeout`
+    pub fn wait(&self, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        let mut components = self.components.lock().unwrap();
+        loop {
+            let waiting = pending(&components);
+            if waiting.is_empty() {
+                return Ok(());
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(Error::NotReady(waiting));
+            }
+            components = self.changed.wait_timeout(components, deadline - now).unwrap().0;
+        }
+    }
+
+    /// A health check which is `DOWN` until every expected component is ready.
+    pub fn check(&self) -> HealthCheckResult {
+        let waiting = self.pending();
+        if waiting.is_empty() {
+            health::check(READINESS_CHECK, HealthStatus::OK, None)
+        } else {
+            health::check(READINESS_CHECK,
+                          HealthStatus::DOWN,
+                          Some(format!("waiting for {}", waiting.join(", "))))
+        }
+    }
+
+    fn set(&self, component: &str, ready: bool) {
+        self.components.lock().unwrap().insert(component.to_string(), ready);
+        self.changed.notify_all();
+    }
+}
+
+fn pending(components: &BTreeMap<String, bool>) -> Vec<String> {
+    components.iter().filter(|&(_, ready)| !*ready).map(|(name, _)| name.clone()).collect()
+}
+
+static INSTANCES: AtomicUsize = ATOMIC_USIZE_INIT;
+
+/// A name for one component of the given kind, such as `supervisor:inproc://backend#0`, which
+/// no other component of this process is expected under. `id` says which one it is in the
+/// pending list, such as the address a `Supervisor` binds.
+pub fn instance(kind: &str, id: &str) -> String {
+    format!("{}:{}#{}", kind, id, INSTANCES.fetch_add(1, Ordering::SeqCst))
+}
+
+lazy_static! {
+    // The components of this process, expected and reported through the functions below.
+    static ref REGISTRY: Readiness = Readiness::new();
+}
+
+fn registry() -> &'static Readiness {
+    &*REGISTRY
+}
+
+/// A component of this process expected with `expecting`. It is forgotten again if dropped
+/// before being reported ready, so a start which fails part way doesn't leave it pending.
+pub struct Expected {
+    component: String,
+    ready: bool,
+}
+
+impl Expected {
+    pub fn component(&self) -> &str {
+        &self.component
+    }
+
+    /// Record that the component is ready.
+    pub fn ready(mut self) {
+        ready(&self.component);
+        self.ready = true;
+    }
+}
+
+impl Drop for Expected {
+    fn drop(&mut self) {
+        if !self.ready {
+            forget(&self.component);
+        }
+    }
+}
+
+/// Add a component of this process which must report ready, until the returned `Expected` is
+/// dropped without having been.
+pub fn expecting(component: String) -> Expected {
+    expect(&component);
+    Expected {
+        component: component,
+        ready: false,
+    }
+}
+
+/// Add a component of this process which must report ready.
+pub fn expect(component: &str) {
+    debug::set("readiness", component, "waiting");
+    registry().expect(component);
+}
+
+/// Record that a component of this process is ready.
+pub fn ready(component: &str) {
+    debug::set("readiness", component, "ready");
+    registry().ready(component);
+}
+
+/// Record that a component of this process which was ready is no longer.
+pub fn not_ready(component: &str) {
+    debug::set("readiness", component, "waiting");
+    registry().not_ready(component);
+}
+
+/// Stop expecting a component of this process.
+pub fn forget(component: &str) {
+    debug::remove("readiness", component);
+    registry().forget(component);
+}
+
+/// True once every expected component of this process is ready.
+pub fn is_ready() -> bool {
+    registry().is_ready()
+}
+
+/// Names of the expected components of this process which aren't ready.
+pub fn pending() -> Vec<String> {
+    registry().pending()
+}
+
+/// Block until every expected component of this process is ready. See `Readiness::wait`.
+pub fn wait(timeout: Duration) -> Result<()> {
+    registry().wait(timeout)
+}
+
+/// A health check which is `DOWN` until every expected component of this process is ready.
+pub fn check() -> HealthCheckResult {
+    registry().check()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    use protocol::net::HealthStatus;
+
+    use error::Error;
+    use super::{expecting, instance, pending, Readiness, REGISTRATION, SUPERVISOR};
+
+    #[test]
+    fn ready_once_every_component_is() {
+        let readiness = Readiness::new();
+        assert!(readiness.is_ready());
+        readiness.expect("broker");
+        readiness.expect("supervisor");
+        readiness.ready("broker");
+        assert!(!readiness.is_ready());
+        assert_eq!(readiness.pending(), vec!["supervisor".to_string()]);
+        readiness.ready("supervisor");
+        assert!(readiness.is_ready());
+        readiness.not_ready("broker");
+        assert_eq!(readiness.pending(), vec!["broker".to_string()]);
+    }
+
+    #[test]
+    fn instances_of_a_kind_are_waited_on_separately() {
+        let readiness = Readiness::new();
+        let first = instance(SUPERVISOR, "inproc://backend");
+        let second = instance(SUPERVISOR, "inproc://backend");
+        assert!(first != second);
+        assert!(first.starts_with("supervisor:inproc://backend#"));
+        readiness.expect(&first);
+        readiness.expect(&second);
+        readiness.ready(&first);
+        assert_eq!(readiness.pending(), vec![second.clone()]);
+        readiness.ready(&second);
+        assert!(readiness.is_ready());
+    }
+
+    #[test]
+    fn forgotten_components_are_not_waited_on() {
+        let readiness = Readiness::new();
+        readiness.expect("broker");
+        readiness.expect("registration");
+        readiness.forget("registration");
+        assert_eq!(readiness.pending(), vec!["broker".to_string()]);
+        readiness.ready("broker");
+        assert!(readiness.wait(Duration::from_millis(10)).is_ok());
+    }
+
+    #[test]
+    fn expected_components_are_forgotten_unless_ready() {
+        let failed = expecting(instance(REGISTRATION, "failed"));
+        let name = failed.component().to_string();
+        assert!(pending().contains(&name));
+        drop(failed);
+        assert!(!pending().contains(&name));
+
+        let started = expecting(instance(REGISTRATION, "started"));
+        let name = started.component().to_string();
+        started.ready();
+        assert!(!pending().contains(&name));
+    }
+
+    #[test]
+    fn check_names_pending_components() {
+        let readiness = Readiness::new();
+        readiness.expect("broker");
+        let check = readiness.check();
+        assert_eq!(check.get_status(), HealthStatus::DOWN);
+        assert_eq!(check.get_msg(), "waiting for broker");
+        readiness.ready("broker");
+        assert_eq!(readiness.check().get_status(), HealthStatus::OK);
+    }
+
+    #[test]
+    fn wait_times_out_naming_pending_components() {
+        let readiness = Readiness::new();
+        readiness.expect("supervisor");
+        match readiness.wait(Duration::from_millis(10)) {
+            Err(Error::NotReady(pending)) => assert_eq!(pending, vec!["supervisor".to_string()]),
+            other => panic!("unexpected result, {:?}", other),
+        }
+    }
+
+    #[test]
+    fn wait_returns_once_ready() {
+        let readiness = Arc::new(Readiness::new());
+        readiness.expect("broker");
+        let waiter = {
+            let readiness = readiness.clone();
+            thread::spawn(move || readiness.wait(Duration::from_millis(5_000)).is_ok())
+        };
+        thread::sleep(Duration::from_millis(50));
+        readiness.ready("broker");
+        assert!(waiter.join().unwrap());
+    }
+}
@@ -16,42 +16,119 @@
 //! connected to one or more `RouteSrv`. All messages are routed through a `RouteSrv` and forwarded
 //! to the appropriate receiver of a message.
 
-use std::cell::UnsafeCell;
+use std::cmp;
+use std::collections::HashMap;
+use std::i32;
+use std::mem;
 use std::net;
-use std::sync::{mpsc, Arc};
+use std::sync::{mpsc, Arc, RwLock};
 use std::thread::{self, JoinHandle};
+use std::time::Duration;
 
 use fnv::FnvHasher;
-use protobuf::{parse_from_bytes, Message};
+use protobuf::{parse_from_bytes, Message, MessageStatic};
 use protocol::{self, Routable, RouteKey};
+use sodiumoxide::randombytes::randombytes;
 use zmq;
 
-use error::Result;
+use cache::{CacheConfig, CacheKey, ReplyCache};
+use capture::{self, CaptureConfig, CaptureFile, Direction, Record};
+use chaos::{self, Fault, Point};
+use clock::{self, Clock, SharedClock, SystemClock};
+use codec::{Header, MAX_HOPS};
+use config;
+use debug;
+use error::{Error, Result};
+use hmac;
+use logging::{self, LogContext};
+use metrics::{self, Counter};
+use readiness;
+use sched::SchedConfig;
+use trace;
 use server::ToAddrString;
+use zap::{self, ZapCredentials};
 
 /// Time to wait before timing out a message receive for a `BrokerConn`.
 pub const RECV_TIMEOUT_MS: i32 = 5_000;
 /// Time to wait before timing out a message send for a `Broker` to a router.
 pub const SEND_TIMEOUT_MS: i32 = 5_000;
+// Cacheable requests awaiting a reply a `Broker` tracks before forgetting ones which timed out.
+const MAX_PENDING_CACHE_FILLS: usize = 1024;
 // ZeroMQ address for the application's Broker's queue.
 const ROUTE_INPROC_ADDR: &'static str = "inproc://route-broker";
+// ZeroMQ address the application's Broker publishes connection events of its router socket on.
+const ROUTE_MONITOR_ADDR: &'static str = "inproc://route-broker-monitor";
+// Client identity of the `Connect` a warming up broker sends to find out a router answers.
+const WARM_UP_IDENT: &'static [u8] = b"broker-warm-up";
 
-pub struct BrokerContext(UnsafeCell<zmq::Context>);
+/// A connection which routes messages through a `RouteSrv` and receives their replies.
+///
+/// Code which sends messages to the service cluster should accept an implementor of this trait
+/// instead of a concrete connection so it can be exercised against an in-memory client such as
+/// `testing::MockRouteClient`.
+pub trait RouteClient {
+    /// Routes a message through a router to the appropriate service.
+    fn route<M: Routable>(&mut self, msg: &M) -> Result<()>;
+
+    /// Receives the reply to a previously routed message.
+    fn recv(&mut self) -> Result<protocol::net::Msg>;
+}
+
+/// The ZeroMQ context a `Broker` and its `BrokerConn`s are created in.
+pub struct BrokerContext(Arc<RwLock<zmq::Context>>);
 
 impl BrokerContext {
     pub fn new() -> Self {
-        BrokerContext(UnsafeCell::new(zmq::Context::new()))
+        BrokerContext(Arc::new(RwLock::new(zmq::Context::new())))
     }
-}
 
-unsafe impl Send for BrokerContext {}
-unsafe impl Sync for BrokerContext {}
+    /// A broker context which creates its sockets in an existing shared context. See
+    /// `server::ServerContext`.
+    pub fn shared(ctx: Arc<RwLock<zmq::Context>>) -> Self {
+        BrokerContext(ctx)
+    }
+}
 
 /// Client connection for sending and receiving messages to and from the service cluster through
 /// a running `Broker`.
+///
+/// A connection made with `Broker::connect_hedged` sends a duplicate of any request which hasn't
+/// been answered within its hedge delay over a second connection. The `Broker` hands the
+/// duplicate to the next router, and so most likely another service instance, and the first
+/// reply to arrive is returned. Only hedge idempotent requests, such as reads, since both copies
+/// may be processed.
 pub struct BrokerConn {
     sock: zmq::Socket,
     hasher: FnvHasher,
+    hedge: Option<Hedge>,
+    session_token: Option<String>,
+    clock: SharedClock,
+}
+
+// The second connection to the `Broker` a hedged request's duplicate is sent over.
+struct Hedge {
+    ctx: Arc<RwLock<zmq::Context>>,
+    delay: Duration,
+    // Closed after losing a race, since it can't send again until its reply arrives, and
+    // reconnected the next time a duplicate is sent.
+    sock: Option<zmq::Socket>,
+    // The request awaiting a reply, kept to be sent again.
+    request: Option<Vec<u8>>,
+}
+
+impl Hedge {
+    fn take_sock(&mut self) -> Result<zmq::Socket> {
+        match self.sock.take() {
+            Some(sock) => Ok(sock),
+            None => {
+                let sock = try!(self.ctx.write().unwrap().socket(zmq::REQ));
+                try!(sock.set_sndtimeo(SEND_TIMEOUT_MS));
+                try!(sock.set_immediate(true));
+                try!(sock.connect(ROUTE_INPROC_ADDR));
+                Ok(sock)
+            }
+        }
+    }
 }
 
 impl BrokerConn {
@@ -62,14 +139,16 @@ impl BrokerConn {
     /// * A socket cannot be created for within the given `zmq::Context`
     /// * The socket cannot be configured
     pub fn new(ctx: &BrokerContext) -> Result<Self> {
-        let ctx: &mut zmq::Context = unsafe { &mut *ctx.0.get() };
-        let socket = try!(ctx.socket(zmq::REQ));
+        let socket = try!(ctx.0.write().unwrap().socket(zmq::REQ));
         try!(socket.set_rcvtimeo(RECV_TIMEOUT_MS));
         try!(socket.set_sndtimeo(SEND_TIMEOUT_MS));
         try!(socket.set_immediate(true));
         Ok(BrokerConn {
             sock: socket,
             hasher: FnvHasher::default(),
+            hedge: None,
+            session_token: None,
+            clock: Arc::new(SystemClock),
         })
     }
 
@@ -83,6 +162,17 @@ impl BrokerConn {
         Ok(())
     }
 
+    /// Stamp every following message with the given clock.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
+    /// Send every following message on behalf of the user with the given session token, for
+    /// servers which authenticate their messages. See `auth`.
+    pub fn set_session_token(&mut self, token: Option<String>) {
+        self.session_token = token;
+    }
+
     /// Routes a message to the connected broker, through a router, and to appropriate service.
     ///
     /// # Errors
@@ -94,10 +184,41 @@ impl BrokerConn {
     /// * Could not serialize message
     pub fn route<M: Routable>(&mut self, msg: &M) -> Result<()> {
         let route_hash = msg.route_key().map(|key| key.hash(&mut self.hasher));
-        let req = protocol::Message::new(msg).routing(route_hash).build();
+        let req = protocol::Message::new(msg)
+            .routing(route_hash)
+            .sticky(msg.sticky())
+            .build();
+        self.route_msg(req)
+    }
+
+    /// Routes an already built message to the connected broker. Useful for messages whose route
+    /// info was set explicitly, such as a health query addressed to a specific protocol.
+    ///
+    /// # Errors
+    ///
+    /// * One or more message frames cannot be sent to the Broker's queue
+    ///
+    /// # Panics
+    ///
+    /// * Could not serialize message
+    pub fn route_msg(&mut self, mut req: protocol::net::Msg) -> Result<()> {
+        // Requests entering the mesh from a client begin a new trace.
+        let span = trace::current().unwrap_or_else(trace::SpanContext::root);
+        span.inject(req.mut_route_info());
+        if let Some(ref token) = self.session_token {
+            if !req.has_session_token() {
+                req.set_session_token(token.clone());
+            }
+        }
+        clock::stamp(&self.clock, &mut req);
+        hmac::sign(&mut req);
         let bytes = req.write_to_bytes().unwrap();
-        try!(self.sock.send_str("RQ", zmq::SNDMORE));
-        try!(self.sock.send(&bytes, 0));
+        debug!("{} routing message", LogContext::from(&req));
+        try!(send_request(&mut self.sock, &bytes));
+        if let Some(ref mut hedge) = self.hedge {
+            hedge.request = Some(bytes);
+        }
+        metrics::incr(Counter::Requests);
         Ok(())
     }
 
@@ -110,16 +231,418 @@ impl BrokerConn {
     /// * Message was not received within the timeout
     /// * Received an unparsable message
     pub fn recv(&mut self) -> Result<protocol::net::Msg> {
-        let envelope = try!(self.sock.recv_msg(0));
+        let hedged = self.hedge.as_ref().map_or(false, |hedge| hedge.request.is_some());
+        let received = if hedged {
+            self.recv_hedged()
+        } else {
+            self.sock.recv_msg(0).map_err(Error::from)
+        };
+        let envelope = match received {
+            Ok(envelope) => envelope,
+            Err(e) => {
+                metrics::incr(Counter::ClientErrors);
+                return Err(e);
+            }
+        };
+        metrics::incr(Counter::Replies);
         let msg: protocol::net::Msg = try!(parse_from_bytes(&envelope));
+        debug!("{} received reply", LogContext::from(&msg));
         Ok(msg)
     }
+
+    // Wait for the reply to a hedged request, sending its duplicate if the reply is slower than
+    // the hedge delay. Whichever connection answers first becomes the primary connection and the
+    // other is closed, so the `Broker` discards its late reply.
+    fn recv_hedged(&mut self) -> Result<zmq::Message> {
+        let deadline = self.clock.now_ms() + RECV_TIMEOUT_MS as i64;
+        let hedge = self.hedge.as_mut().unwrap();
+        let request = hedge.request.take().unwrap();
+        let answered = {
+            let mut items = [self.sock.as_poll_item(zmq::POLLIN)];
+            try!(zmq::poll(&mut items, clock::duration_ms(hedge.delay)));
+            items[0].get_revents() & zmq::POLLIN > 0
+        };
+        if answered {
+            return Ok(try!(self.sock.recv_msg(0)));
+        }
+        let mut dup = try!(hedge.take_sock());
+        match send_request(&mut dup, &request) {
+            Ok(()) => {
+                metrics::incr(Counter::Hedged);
+            }
+            Err(e) => {
+                // A REQ socket which failed part way through a send can't be relied on to send
+                // again, so the duplicate's connection is dropped and made afresh next time.
+                debug!("hedged request not sent, err={}", e);
+                return Ok(try!(self.sock.recv_msg(0)));
+            }
+        }
+        loop {
+            let wait_ms = deadline - self.clock.now_ms();
+            if wait_ms <= 0 {
+                return Err(Error::Timeout);
+            }
+            let (primary, duplicate) = {
+                let mut items = [self.sock.as_poll_item(zmq::POLLIN),
+                                 dup.as_poll_item(zmq::POLLIN)];
+                try!(zmq::poll(&mut items, wait_ms));
+                (items[0].get_revents() & zmq::POLLIN > 0,
+                 items[1].get_revents() & zmq::POLLIN > 0)
+            };
+            if primary {
+                return Ok(try!(self.sock.recv_msg(0)));
+            }
+            if duplicate {
+                let envelope = try!(dup.recv_msg(0));
+                mem::swap(&mut self.sock, &mut dup);
+                return Ok(envelope);
+            }
+        }
+    }
+}
+
+impl RouteClient for BrokerConn {
+    fn route<M: Routable>(&mut self, msg: &M) -> Result<()> {
+        BrokerConn::route(self, msg)
+    }
+
+    fn recv(&mut self) -> Result<protocol::net::Msg> {
+        BrokerConn::recv(self)
+    }
+}
+
+/// Routes a set of requests, which may be addressed to different services, at the same time and
+/// waits for all of their replies under a single deadline.
+///
+/// Each request in flight is sent over its own connection to the application's `Broker`.
+/// Connections are kept once their reply arrives and reused by later requests of the same `Join`,
+/// so a handler can issue several rounds of requests without reconnecting.
+pub struct Join<'a> {
+    ctx: &'a BrokerContext,
+    idle: Vec<BrokerConn>,
+    pending: Vec<BrokerConn>,
+}
+
+impl<'a> Join<'a> {
+    pub fn new(ctx: &'a BrokerContext) -> Self {
+        Join {
+            ctx: ctx,
+            idle: vec![],
+            pending: vec![],
+        }
+    }
+
+    /// Route a request and return its position in the `Replies` of the next call to `wait`.
+    ///
+    /// # Errors
+    ///
+    /// * Could not connect to `Broker`
+    /// * The request could not be sent
+    pub fn route<M: Routable>(&mut self, msg: &M) -> Result<usize> {
+        let mut conn = match self.idle.pop() {
+            Some(conn) => conn,
+            None => try!(Broker::connect(self.ctx)),
+        };
+        try!(conn.route(msg));
+        self.pending.push(conn);
+        Ok(self.pending.len() - 1)
+    }
+
+    /// Block until every routed request has been answered or `timeout_ms` has passed. Requests
+    /// which were not answered in time have a timeout error in place of their reply.
+    pub fn wait(&mut self, timeout_ms: i64) -> Replies {
+        let deadline = SystemClock.now_ms() + timeout_ms;
+        let mut replies: Vec<Option<Result<protocol::net::Msg>>> =
+            self.pending.iter().map(|_| None).collect();
+        let mut remaining = replies.len();
+        while remaining > 0 {
+            let wait_ms = deadline - SystemClock.now_ms();
+            if wait_ms <= 0 {
+                break;
+            }
+            let ready: Vec<usize> = {
+                let mut items: Vec<zmq::PollItem> = self.pending
+                    .iter()
+                    .map(|conn| conn.sock.as_poll_item(zmq::POLLIN))
+                    .collect();
+                if let Err(e) = zmq::poll(&mut items, wait_ms) {
+                    warn!("join poll failed, err={}", e);
+                    break;
+                }
+                items.iter()
+                    .enumerate()
+                    .filter(|&(i, item)| {
+                        replies[i].is_none() && item.get_revents() & zmq::POLLIN > 0
+                    })
+                    .map(|(i, _)| i)
+                    .collect()
+            };
+            for i in ready {
+                replies[i] = Some(self.pending[i].recv());
+                remaining -= 1;
+            }
+        }
+        // A connection still waiting on a reply can't send again, so only answered ones are kept.
+        for (conn, reply) in self.pending.drain(..).zip(replies.iter()) {
+            if reply.is_some() {
+                self.idle.push(conn);
+            }
+        }
+        Replies(replies.into_iter()
+            .map(|reply| reply.unwrap_or(Err(Error::Timeout)))
+            .collect())
+    }
+}
+
+/// Replies gathered by `Join::wait`, in the order their requests were routed.
+pub struct Replies(Vec<Result<protocol::net::Msg>>);
+
+impl Replies {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Take the reply to the request at the given position. Taking a reply a second time returns
+    /// a `MalformedReply` error.
+    ///
+    /// # Panics
+    ///
+    /// * No request was routed at the given position
+    pub fn take(&mut self, i: usize) -> Result<protocol::net::Msg> {
+        mem::replace(&mut self.0[i], Err(Error::MalformedReply))
+    }
+
+    /// Take the reply to the request at the given position and parse it as the expected message.
+    ///
+    /// # Errors
+    ///
+    /// * The request was not answered in time or its reply could not be received
+    /// * The service replied with a `NetError`
+    /// * The reply was not the expected message
+    pub fn parse<M: MessageStatic>(&mut self, i: usize) -> Result<M> {
+        let msg = try!(self.take(i));
+        if msg.get_message_id() == "NetError" {
+            let err: protocol::net::NetError = try!(parse_from_bytes(msg.get_body()));
+            return Err(Error::Remote(err));
+        }
+        let expected = M::descriptor_static(None).name();
+        if msg.get_message_id() != expected {
+            return Err(Error::UnexpectedReply(format!("expected {}, got {}",
+                                                      expected,
+                                                      msg.get_message_id())));
+        }
+        let reply = try!(parse_from_bytes(msg.get_body()));
+        Ok(reply)
+    }
+}
+
+/// How a `Broker` reconnects to a router it lost its connection to.
+///
+/// Each broker waits a random extra amount of up to `jitter` on top of `ivl` before its first
+/// reconnect attempt, so brokers which lost a router at the same moment don't all reconnect to it
+/// at the same moment. Later attempts back off exponentially up to `ivl_max`.
+#[derive(Clone, Debug)]
+pub struct ReconnectConfig {
+    /// Time to wait before reconnecting
+    pub ivl: Duration,
+    /// Most time to wait between reconnect attempts, or zero to always wait `ivl`
+    pub ivl_max: Duration,
+    /// Most random time added to `ivl`
+    pub jitter: Duration,
+}
+
+impl ReconnectConfig {
+    pub fn from_config<T: config::Reconnect>(cfg: &T) -> Self {
+        ReconnectConfig {
+            ivl: cfg.reconnect_ivl(),
+            ivl_max: cfg.reconnect_ivl_max(),
+            jitter: cfg.reconnect_jitter(),
+        }
+    }
+
+    /// The reconnect interval with a random amount of jitter added.
+    pub fn jittered_ivl(&self) -> Duration {
+        let bytes = randombytes(4);
+        self.ivl_with_jitter(bytes.iter().fold(0u32, |n, b| (n << 8) | *b as u32))
+    }
+
+    // The reconnect interval with the share of the jitter picked by the random draw `n`.
+    fn ivl_with_jitter(&self, n: u32) -> Duration {
+        let jitter_ms = clock::duration_ms(self.jitter);
+        if jitter_ms <= 0 {
+            return self.ivl;
+        }
+        self.ivl + Duration::from_millis(n as u64 % (jitter_ms as u64 + 1))
+    }
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        ReconnectConfig {
+            ivl: Duration::from_millis(100),
+            ivl_max: Duration::from_secs(10),
+            jitter: Duration::from_secs(1),
+        }
+    }
+}
+
+/// How idle connections to routers are kept open and verified.
+///
+/// Firewalls and NAT gateways silently drop connections which carry no traffic for a while, and
+/// the first message sent after a quiet spell is then lost. TCP keepalive probes keep those
+/// connections open and uncover dead ones; ZMTP heartbeats additionally check that the router at
+/// the other end still answers.
+#[derive(Clone, Debug)]
+pub struct KeepaliveConfig {
+    /// Idle time before TCP keepalive probes are sent, or zero to leave TCP keepalive off
+    pub tcp_idle: Duration,
+    /// Time between unanswered TCP keepalive probes
+    pub tcp_ivl: Duration,
+    /// Unanswered TCP keepalive probes after which the connection is dropped
+    pub tcp_count: usize,
+    /// Time between ZMTP heartbeats, or zero to send none
+    pub heartbeat_ivl: Duration,
+    /// Time without traffic after a ZMTP heartbeat before the connection is dropped, or zero for
+    /// `heartbeat_ivl`
+    pub heartbeat_timeout: Duration,
+}
+
+impl KeepaliveConfig {
+    pub fn from_config<T: config::Keepalive>(cfg: &T) -> Self {
+        cfg.keepalive()
+    }
+
+    /// Set the keepalive and heartbeat options of a socket. Must be called before the socket
+    /// connects for the options to take effect on that connection.
+    ///
+    /// # Errors
+    ///
+    /// * An option cannot be set on the socket
+    /// * The probe count or a heartbeat time is too large for a ZeroMQ option
+    pub fn apply(&self, sock: &zmq::Socket) -> Result<()> {
+        if self.tcp_idle > Duration::from_secs(0) {
+            let count = try!(config::option_int("tcp_keepalive_count", self.tcp_count));
+            try!(sock.set_tcp_keepalive(1));
+            // TCP keepalive options have a granularity of whole seconds.
+            try!(sock.set_tcp_keepalive_idle(keepalive_secs(self.tcp_idle)));
+            try!(sock.set_tcp_keepalive_intvl(keepalive_secs(self.tcp_ivl)));
+            try!(sock.set_tcp_keepalive_cnt(count));
+        } else {
+            try!(sock.set_tcp_keepalive(0));
+        }
+        let heartbeat_ms = try!(config::option_ms("heartbeat_ivl", self.heartbeat_ivl));
+        if heartbeat_ms > 0 {
+            let timeout_ms = try!(config::option_ms("heartbeat_timeout", self.heartbeat_timeout));
+            try!(apply_heartbeat(sock, heartbeat_ms, timeout_ms));
+        }
+        Ok(())
+    }
+}
+
+// ZMTP heartbeats need ZeroMQ 4.2 and a binding which exposes their options, which the pinned
+// zmq fork may not, so they are only set when built with the `zmtp-heartbeat` feature.
+#[cfg(feature = "zmtp-heartbeat")]
+fn apply_heartbeat(sock: &zmq::Socket, ivl_ms: i32, timeout_ms: i32) -> Result<()> {
+    try!(sock.set_heartbeat_ivl(ivl_ms));
+    if timeout_ms > 0 {
+        try!(sock.set_heartbeat_timeout(timeout_ms));
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "zmtp-heartbeat"))]
+fn apply_heartbeat(_sock: &zmq::Socket, _ivl_ms: i32, _timeout_ms: i32) -> Result<()> {
+    warn!("ignoring heartbeat_ivl, habitat_net was built without the zmtp-heartbeat feature");
+    Ok(())
+}
+
+impl Default for KeepaliveConfig {
+    fn default() -> Self {
+        KeepaliveConfig {
+            tcp_idle: Duration::from_secs(60),
+            tcp_ivl: Duration::from_secs(10),
+            tcp_count: 3,
+            heartbeat_ivl: Duration::from_secs(0),
+            heartbeat_timeout: Duration::from_secs(0),
+        }
+    }
+}
+
+// Whole seconds of a duration for a TCP keepalive option, rounding any fraction of a second up.
+fn keepalive_secs(duration: Duration) -> i32 {
+    cmp::min(cmp::max(clock::duration_secs(duration), 1), i32::MAX as u64) as i32
+}
+
+/// Options for a `Broker` started with `Broker::run_with_opts`.
+#[derive(Clone, Debug)]
+pub struct BrokerOpts {
+    /// Credentials presented to routers which authenticate their clients
+    pub auth: ZapCredentials,
+    /// Which replies are cached
+    pub cache: CacheConfig,
+    /// How connections to routers are re-established
+    pub reconnect: ReconnectConfig,
+    /// How idle connections to routers are kept open and verified
+    pub keepalive: KeepaliveConfig,
+    /// Most time to wait for a connection to at least one router before reporting the broker
+    /// ready, or zero to report it ready as soon as its sockets are set up
+    pub warmup: Duration,
+    /// CPU and priority of the broker's thread
+    pub sched: SchedConfig,
+    /// File to capture every message the broker forwards to, for replaying with `net-replay`.
+    /// Capturing replaces an existing file at the path and keeps the broker from forwarding with
+    /// a ZeroMQ proxy, so it is meant for reproducing problems rather than for normal operation.
+    pub capture: Option<CaptureConfig>,
+}
+
+impl Default for BrokerOpts {
+    fn default() -> Self {
+        BrokerOpts {
+            auth: ZapCredentials::default(),
+            cache: CacheConfig::default(),
+            reconnect: ReconnectConfig::default(),
+            keepalive: KeepaliveConfig::default(),
+            warmup: Duration::from_secs(5),
+            sched: SchedConfig::default(),
+            capture: None,
+        }
+    }
 }
 
 /// A messaging Broker for proxying messages from clients to one or more `RouteSrv` and vice versa.
 pub struct Broker {
     client_sock: zmq::Socket,
     router_sock: zmq::Socket,
+    // Receives the connection events of `router_sock` while the broker warms up.
+    monitor_sock: zmq::Socket,
+    // Frames of the message being forwarded.
+    frames: Vec<zmq::Message>,
+    capture: Option<CaptureFile>,
+    // Addresses of the routers `router_sock` connects to.
+    routers: Vec<String>,
+    // Messages held back by injected delays.
+    held: Vec<Held>,
+}
+
+// A message a `Broker` holds back because of an injected delay, and when to pass it on.
+struct Held {
+    due_ms: i64,
+    point: Point,
+    frames: Vec<zmq::Message>,
+    // The cache fill a held reply completes.
+    fill: Option<(CacheKey, i64)>,
+}
+
+// What becomes of a message a `Broker` forwards once any fault due has been injected into it.
+#[derive(Debug, PartialEq)]
+enum Verdict {
+    Pass,
+    Hold(Duration),
+    Discard,
 }
 
 impl Broker {
@@ -133,17 +656,38 @@ impl Broker {
     /// # Panics
     ///
     /// * Could not read `zmq::Context` due to deadlock or poisoning
-    fn new(net_ident: String, ctx: &BrokerContext) -> Result<Self> {
-        let ctx: &mut zmq::Context = unsafe { &mut *ctx.0.get() };
+    fn new(net_ident: String,
+           ctx: &BrokerContext,
+           auth: &ZapCredentials,
+           reconnect: &ReconnectConfig,
+           keepalive: &KeepaliveConfig)
+           -> Result<Self> {
+        let mut ctx = ctx.0.write().unwrap();
         let fe = try!(ctx.socket(zmq::ROUTER));
         let be = try!(ctx.socket(zmq::DEALER));
+        let monitor = try!(ctx.socket(zmq::PAIR));
+        try!(zap::apply_client(&be, auth));
         try!(fe.set_identity(net_ident.as_bytes()));
+        // Routers see the broker's identity as the first hop of the messages it sends, so it
+        // names the process they came from, such as in a router's deny-list.
+        try!(be.set_identity(net_ident.as_bytes()));
         try!(be.set_rcvtimeo(RECV_TIMEOUT_MS));
         try!(be.set_sndtimeo(SEND_TIMEOUT_MS));
         try!(be.set_immediate(true));
+        let ivl_ms = try!(config::option_ms("reconnect_ivl", reconnect.jittered_ivl()));
+        try!(be.set_reconnect_ivl(ivl_ms));
+        try!(be.set_reconnect_ivl_max(try!(config::option_ms("reconnect_ivl_max",
+                                                              reconnect.ivl_max))));
+        try!(keepalive.apply(&be));
+        debug::set("broker", "reconnect_ivl_ms", ivl_ms);
         Ok(Broker {
             client_sock: fe,
             router_sock: be,
+            monitor_sock: monitor,
+            frames: Vec::with_capacity(MAX_HOPS + 2),
+            capture: None,
+            routers: vec![],
+            held: vec![],
         })
     }
 
@@ -163,8 +707,27 @@ impl Broker {
         Ok(conn)
     }
 
+    /// Like `connect` but requests which aren't answered within `delay` are hedged by sending a
+    /// duplicate. See `BrokerConn`.
+    ///
+    /// # Errors
+    ///
+    /// * Could not connect to `Broker`
+    /// * Could not create socket within `zmq::Context`
+    pub fn connect_hedged(ctx: &BrokerContext, delay: Duration) -> Result<BrokerConn> {
+        let mut conn = try!(Self::connect(ctx));
+        conn.hedge = Some(Hedge {
+            ctx: ctx.0.clone(),
+            delay: delay,
+            sock: None,
+            request: None,
+        });
+        Ok(conn)
+    }
+
     /// Create a new `Broker` and run it in a separate thread. This function will block the calling
-    /// thread until the new broker has successfully started.
+    /// thread until the new broker has successfully started and connected to at least one router,
+    /// or has waited `BrokerOpts::default().warmup` for a router connection without one.
     ///
     /// # Panics
     ///
@@ -173,13 +736,71 @@ impl Broker {
                ctx: Arc<BrokerContext>,
                routers: &Vec<net::SocketAddrV4>)
                -> JoinHandle<()> {
+        Self::run_with_auth(net_ident, ctx, routers, ZapCredentials::default())
+    }
+
+    /// Like `run` but the broker presents the given credentials to routers which authenticate
+    /// their clients with ZAP.
+    ///
+    /// # Panics
+    ///
+    /// * Broker crashed during startup
+    pub fn run_with_auth(net_ident: String,
+                         ctx: Arc<BrokerContext>,
+                         routers: &Vec<net::SocketAddrV4>,
+                         auth: ZapCredentials)
+                         -> JoinHandle<()> {
+        Self::run_with_cache(net_ident, ctx, routers, auth, CacheConfig::default())
+    }
+
+    /// Like `run_with_auth` but replies to the messages flagged cacheable in the given
+    /// configuration are cached by the broker and served without reaching a service.
+    ///
+    /// # Panics
+    ///
+    /// * Broker crashed during startup
+    pub fn run_with_cache(net_ident: String,
+                          ctx: Arc<BrokerContext>,
+                          routers: &Vec<net::SocketAddrV4>,
+                          auth: ZapCredentials,
+                          cache: CacheConfig)
+                          -> JoinHandle<()> {
+        let opts = BrokerOpts {
+            auth: auth,
+            cache: cache,
+            ..BrokerOpts::default()
+        };
+        Self::run_with_opts(net_ident, ctx, routers, opts)
+    }
+
+    /// Like `run` but with the router credentials, reply cache, reconnect, keepalive, warm-up,
+    /// thread placement and traffic capture given by `opts`.
+    ///
+    /// # Panics
+    ///
+    /// * Broker crashed during startup
+    pub fn run_with_opts(net_ident: String,
+                         ctx: Arc<BrokerContext>,
+                         routers: &Vec<net::SocketAddrV4>,
+                         opts: BrokerOpts)
+                         -> JoinHandle<()> {
+        let BrokerOpts { auth, cache, reconnect, keepalive, warmup, sched, capture } = opts;
         let (tx, rx) = mpsc::sync_channel(1);
         let addrs = routers.iter().map(|a| a.to_addr_string()).collect();
+        let component = readiness::instance(readiness::BROKER, &net_ident);
+        readiness::expect(&component);
         let handle = thread::Builder::new()
             .name("router-broker".to_string())
             .spawn(move || {
-                let mut broker = Self::new(net_ident, &ctx).unwrap();
-                broker.start(tx, addrs).unwrap();
+                logging::set_net_ident(net_ident.clone());
+                debug::trap_signal();
+                sched.apply(0);
+                let mut broker =
+                    Self::new(net_ident, &ctx, &auth, &reconnect, &keepalive).unwrap();
+                match broker.start(tx, component, addrs, cache, warmup, capture) {
+                    Ok(()) | Err(Error::Shutdown) => debug!("router-broker stopped"),
+                    Err(e) => panic!("router-broker failed, err={}", e),
+                }
             })
             .unwrap();
         match rx.recv() {
@@ -191,14 +812,708 @@ impl Broker {
     // Main loop for `Broker`.
     //
     // Binds front-end socket to ZeroMQ inproc address and connects to all routers. Sends a message
-    // back to the caller over the given rendezvous channel to signal when ready.
-    fn start(&mut self, rz: mpsc::SyncSender<()>, routers: Vec<String>) -> Result<()> {
+    // back to the caller over the given rendezvous channel to signal when ready, which is once a
+    // router connection is up or `warmup` has passed, and reports `component` ready. Messages are
+    // forwarded by a ZeroMQ proxy unless some of them must be looked at on the way, to cache
+    // replies, capture traffic or inject faults.
+    fn start(&mut self,
+             rz: mpsc::SyncSender<()>,
+             component: String,
+             routers: Vec<String>,
+             cache: CacheConfig,
+             warmup: Duration,
+             capture: Option<CaptureConfig>)
+             -> Result<()> {
         try!(self.client_sock.bind(ROUTE_INPROC_ADDR));
-        for addr in routers {
-            try!(self.router_sock.connect(&addr));
+        debug::set("broker", "frontend", ROUTE_INPROC_ADDR);
+        if let Some(config) = capture {
+            let path = config.path.clone();
+            self.capture = Some(try!(CaptureFile::create(config)));
+            info!("router-broker capturing traffic to {}", path);
+            debug::set("broker", "capture", path);
+        }
+        // The monitor's address is bound by `monitor()`, so it must be set up before the pair
+        // socket connects to it, and before any router connection whose events it should see.
+        try!(self.router_sock.monitor(ROUTE_MONITOR_ADDR,
+                                      zmq::SocketEvent::CONNECTED.to_raw() as i32));
+        try!(self.monitor_sock.connect(ROUTE_MONITOR_ADDR));
+        for addr in routers.iter() {
+            try!(self.router_sock.connect(addr));
+            debug::set("broker", &format!("router.{}", addr), "connecting");
+        }
+        self.routers = routers;
+        if !self.routers.is_empty() {
+            match try!(self.warm_up(warmup)) {
+                Some(addr) => debug!("router-broker ready, connected to {}", addr),
+                None if warmup == Duration::from_secs(0) => (),
+                None => {
+                    warn!("router-broker reporting ready without a router answering, \
+                           waited={}ms",
+                          clock::duration_ms(warmup))
+                }
+            }
         }
+        try!(self.stop_monitor());
+        readiness::ready(&component);
         rz.send(()).unwrap();
-        try!(zmq::proxy(&mut self.client_sock, &mut self.router_sock));
+        if !cache.is_enabled() && self.capture.is_none() && !chaos::is_enabled() {
+            try!(zmq::proxy(&mut self.client_sock, &mut self.router_sock));
+            return Ok(());
+        }
+        debug::set("broker", "cache.ttl_ms", clock::duration_ms(cache.ttl));
+        debug::set("broker", "cache.messages", cache.cacheable.join(","));
+        let mut cache = ReplyCache::new(cache);
+        // Cacheable requests awaiting a reply, keyed by the identity of the client which sent
+        // them. Clients hold REQ sockets so each has at most one request outstanding.
+        let mut pending: HashMap<Vec<u8>, (CacheKey, i64)> = HashMap::new();
+        loop {
+            let (requests, replies) = {
+                let mut items = [self.client_sock.as_poll_item(zmq::POLLIN),
+                                 self.router_sock.as_poll_item(zmq::POLLIN)];
+                try!(zmq::poll(&mut items, self.poll_timeout(SystemClock.now_ms())));
+                (items[0].get_revents() & zmq::POLLIN > 0,
+                 items[1].get_revents() & zmq::POLLIN > 0)
+            };
+            self.flush_capture();
+            let now = SystemClock.now_ms();
+            try!(tolerate_timeout(self.release_held(&mut cache, &mut pending, now), "message"));
+            if requests {
+                try!(tolerate_timeout(self.forward_request(&mut cache, &mut pending), "request"));
+            }
+            if replies {
+                try!(tolerate_timeout(self.forward_reply(&mut cache, &mut pending), "reply"));
+            }
+        }
+    }
+
+    // Milliseconds to wait for traffic before the broker has something else to do: flush its
+    // capture, which is flushed while the broker is idle so records don't wait for more traffic,
+    // or pass on a held message. -1 to wait for traffic alone.
+    fn poll_timeout(&self, now_ms: i64) -> i64 {
+        let flush = self.capture.as_ref().map(|_| capture::FLUSH_INTERVAL_MS);
+        let held = self.held.iter().map(|held| cmp::max(held.due_ms - now_ms, 0)).min();
+        match (flush, held) {
+            (Some(flush), Some(held)) => cmp::min(flush, held),
+            (Some(ms), None) | (None, Some(ms)) => ms,
+            (None, None) => -1,
+        }
+    }
+
+    // Wait until a router has answered the broker, or until `timeout` has passed. Returns the
+    // address of the first router connected to, if a router answered. A connection is up as soon
+    // as a router's port accepts it, so a `Connect` is sent through it and the broker only counts
+    // as warmed up once a router has replied.
+    fn warm_up(&mut self, timeout: Duration) -> Result<Option<String>> {
+        let deadline = SystemClock.now_ms() + clock::duration_ms(timeout);
+        let addr = match try!(self.await_connection(deadline)) {
+            Some(addr) => addr,
+            None => return Ok(None),
+        };
+        let mut req = protocol::Message::new(&protocol::routesrv::Connect::new())
+            .routing(None)
+            .build();
+        clock::stamp(&SystemClock, &mut req);
+        hmac::sign(&mut req);
+        let bytes = try!(req.write_to_bytes());
+        try!(self.router_sock.send(WARM_UP_IDENT, zmq::SNDMORE));
+        try!(self.router_sock.send(&[], zmq::SNDMORE));
+        try!(self.router_sock.send(Header::Request.as_bytes(), zmq::SNDMORE));
+        match self.router_sock.send(&bytes, 0) {
+            Ok(()) => (),
+            Err(zmq::Error::EAGAIN) => return Ok(None),
+            Err(e) => return Err(Error::from(e)),
+        }
+        if try!(self.await_warm_up_reply(deadline)) {
+            debug::set("broker", &format!("router.{}", addr), "connected");
+            Ok(Some(addr))
+        } else {
+            Ok(None)
+        }
+    }
+
+    // Wait until the router socket has connected to a router, or until `deadline`. Returns the
+    // address of the connected router, if any.
+    fn await_connection(&mut self, deadline: i64) -> Result<Option<String>> {
+        let mut event = try!(zmq::Message::new());
+        let mut addr = try!(zmq::Message::new());
+        loop {
+            let wait_ms = deadline - SystemClock.now_ms();
+            if wait_ms <= 0 {
+                return Ok(None);
+            }
+            {
+                let mut items = [self.monitor_sock.as_poll_item(zmq::POLLIN)];
+                try!(zmq::poll(&mut items, wait_ms));
+                if items[0].get_revents() & zmq::POLLIN == 0 {
+                    continue;
+                }
+            }
+            // An event is a frame holding the event's id and value, followed by a frame holding
+            // the address of the connection it is about.
+            try!(self.monitor_sock.recv(&mut event, 0));
+            try!(self.monitor_sock.recv(&mut addr, 0));
+            if event.len() < 2 {
+                continue;
+            }
+            let id = event[0] as u16 | (event[1] as u16) << 8;
+            if id == zmq::SocketEvent::CONNECTED.to_raw() {
+                return Ok(Some(addr.as_str().unwrap_or("").to_string()));
+            }
+        }
+    }
+
+    // Wait until `deadline` for the reply to the warm-up `Connect`, which arrives as
+    // `[WARM_UP_IDENT][empty][msg]`. Returns whether it arrived.
+    fn await_warm_up_reply(&mut self, deadline: i64) -> Result<bool> {
+        loop {
+            let wait_ms = deadline - SystemClock.now_ms();
+            if wait_ms <= 0 {
+                return Ok(false);
+            }
+            {
+                let mut items = [self.router_sock.as_poll_item(zmq::POLLIN)];
+                try!(zmq::poll(&mut items, wait_ms));
+                if items[0].get_revents() & zmq::POLLIN == 0 {
+                    continue;
+                }
+            }
+            try!(recv_frames_into(&mut self.router_sock, &mut self.frames));
+            if self.frames.len() == 3 && &self.frames[0][..] == WARM_UP_IDENT {
+                return Ok(true);
+            }
+        }
+    }
+
+    // Stop the router socket's connection events once the broker has warmed up, so none queue
+    // up unread.
+    fn stop_monitor(&mut self) -> Result<()> {
+        if let Err(e) = self.router_sock.monitor(ROUTE_MONITOR_ADDR, 0) {
+            debug!("router-broker unable to stop monitoring router connections, err={}", e);
+        }
+        try!(self.monitor_sock.close());
         Ok(())
     }
+
+    // Answer a request from the cache or pass it on to a router. Requests arrive as
+    // `[client][empty]["RQ"][msg]`.
+    fn forward_request(&mut self,
+                       cache: &mut ReplyCache,
+                       pending: &mut HashMap<Vec<u8>, (CacheKey, i64)>)
+                       -> Result<()> {
+        try!(recv_frames_into(&mut self.client_sock, &mut self.frames));
+        self.record(Direction::Request);
+        match try!(self.inject_fault(Point::Route)) {
+            Verdict::Pass => self.route_request(cache, pending),
+            Verdict::Hold(delay) => {
+                self.hold(Point::Route, delay, None);
+                Ok(())
+            }
+            Verdict::Discard => Ok(()),
+        }
+    }
+
+    fn route_request(&mut self,
+                     cache: &mut ReplyCache,
+                     pending: &mut HashMap<Vec<u8>, (CacheKey, i64)>)
+                     -> Result<()> {
+        let frames = &self.frames;
+        if frames.len() == 4 {
+            if let Ok(msg) = parse_from_bytes::<protocol::net::Msg>(&frames[3]) {
+                cache.invalidate(&msg);
+                if let Some(key) = cache.key(&msg) {
+                    if let Some(reply) = cache.get(&key, &SystemClock) {
+                        debug!("{} cache hit", LogContext::from(&msg));
+                        metrics::incr(Counter::CacheHits);
+                        try!(self.client_sock.send(&frames[0], zmq::SNDMORE));
+                        try!(self.client_sock.send(&[], zmq::SNDMORE));
+                        try!(self.client_sock.send(reply, 0));
+                        return Ok(());
+                    }
+                    metrics::incr(Counter::CacheMisses);
+                    let now = SystemClock.now_ms();
+                    if pending.len() >= MAX_PENDING_CACHE_FILLS {
+                        // Clients which timed out never get a reply, so forget their requests.
+                        let stale: Vec<Vec<u8>> = pending.iter()
+                            .filter(|&(_, &(_, sent))| now - sent > RECV_TIMEOUT_MS as i64)
+                            .map(|(client, _)| client.clone())
+                            .collect();
+                        for client in stale {
+                            pending.remove(&client);
+                        }
+                    }
+                    pending.insert(frames[0].to_vec(), (key, now));
+                }
+            }
+        }
+        send_frames(&mut self.router_sock, &frames)
+    }
+
+    // Pass a reply from a router back to its client, caching it if it answers a cacheable
+    // request. Replies arrive as `[client][empty][msg]`.
+    fn forward_reply(&mut self,
+                     cache: &mut ReplyCache,
+                     pending: &mut HashMap<Vec<u8>, (CacheKey, i64)>)
+                     -> Result<()> {
+        try!(recv_frames_into(&mut self.router_sock, &mut self.frames));
+        self.record(Direction::Reply);
+        self.pass_reply(cache, pending)
+    }
+
+    // Pass on the reply being forwarded unless a fault is injected into it. The reply answers its
+    // client's request whatever becomes of it, so the client's cache fill is no longer pending.
+    fn pass_reply(&mut self,
+                  cache: &mut ReplyCache,
+                  pending: &mut HashMap<Vec<u8>, (CacheKey, i64)>)
+                  -> Result<()> {
+        let fill = self.frames.first().and_then(|client| pending.remove(&client[..]));
+        match try!(self.inject_fault(Point::Reply)) {
+            Verdict::Pass => self.deliver_reply(cache, fill),
+            Verdict::Hold(delay) => {
+                self.hold(Point::Reply, delay, fill);
+                Ok(())
+            }
+            Verdict::Discard => Ok(()),
+        }
+    }
+
+    fn deliver_reply(&mut self,
+                     cache: &mut ReplyCache,
+                     fill: Option<(CacheKey, i64)>)
+                     -> Result<()> {
+        if let Some((key, _)) = fill {
+            if self.frames.len() == 3 {
+                match parse_from_bytes::<protocol::net::Msg>(&self.frames[2]) {
+                    Ok(ref msg) if msg.get_message_id() != "NetError" => {
+                        cache.insert(key, self.frames[2].to_vec(), &SystemClock);
+                    }
+                    _ => (),
+                }
+            }
+        }
+        send_frames(&mut self.client_sock, &self.frames)
+    }
+
+    // Hold back the message being forwarded until its delay is over.
+    fn hold(&mut self, point: Point, delay: Duration, fill: Option<(CacheKey, i64)>) {
+        let frames = mem::replace(&mut self.frames, Vec::with_capacity(MAX_HOPS + 2));
+        self.held.push(Held {
+            due_ms: SystemClock.now_ms() + clock::duration_ms(delay),
+            point: point,
+            frames: frames,
+            fill: fill,
+        });
+    }
+
+    // Pass on every held message whose delay is over, in the order they were held.
+    fn release_held(&mut self,
+                    cache: &mut ReplyCache,
+                    pending: &mut HashMap<Vec<u8>, (CacheKey, i64)>,
+                    now_ms: i64)
+                    -> Result<()> {
+        if self.held.iter().all(|held| held.due_ms > now_ms) {
+            return Ok(());
+        }
+        let (due, held): (Vec<Held>, Vec<Held>) =
+            self.held.drain(..).partition(|held| held.due_ms <= now_ms);
+        self.held = held;
+        let mut result = Ok(());
+        for held in due {
+            self.frames = held.frames;
+            let sent = match held.point {
+                Point::Reply => self.deliver_reply(cache, held.fill),
+                _ => self.route_request(cache, pending),
+            };
+            if result.is_ok() {
+                result = sent;
+            }
+        }
+        result
+    }
+
+    // Inject a fault into the message being forwarded, if one is due.
+    fn inject_fault(&mut self, point: Point) -> Result<Verdict> {
+        if !chaos::is_enabled() {
+            return Ok(Verdict::Pass);
+        }
+        let fault = match self.frames
+            .last()
+            .and_then(|frame| parse_from_bytes::<protocol::net::Msg>(frame).ok()) {
+            Some(msg) => chaos::inject(point, msg.get_message_id()),
+            None => None,
+        };
+        match fault {
+            Some(Fault::Delay(delay)) => Ok(Verdict::Hold(delay)),
+            Some(Fault::Drop) => Ok(Verdict::Discard),
+            Some(Fault::Reconnect) => {
+                for addr in self.routers.iter() {
+                    try!(self.router_sock.disconnect(addr));
+                    try!(self.router_sock.connect(addr));
+                }
+                Ok(Verdict::Discard)
+            }
+            None => Ok(Verdict::Pass),
+        }
+    }
+
+    // Append the message being forwarded to the capture, if one is being taken. A capture which
+    // can no longer be written is stopped rather than stopping the broker with it.
+    fn record(&mut self, direction: Direction) {
+        let failed = match self.capture {
+            Some(ref mut capture) => {
+                let frames = self.frames.iter().map(|frame| frame.to_vec()).collect();
+                capture.write(Record::new(SystemClock.now_ms(), direction, frames)).err()
+            }
+            None => return,
+        };
+        if let Some(e) = failed {
+            self.stop_capture(e);
+        }
+    }
+
+    // Write out captured records which have waited long enough in the capture's buffer.
+    fn flush_capture(&mut self) {
+        let failed = match self.capture {
+            Some(ref mut capture) => capture.flush_due(SystemClock.now_ms()).err(),
+            None => return,
+        };
+        if let Some(e) = failed {
+            self.stop_capture(e);
+        }
+    }
+
+    fn stop_capture(&mut self, err: Error) {
+        warn!("router-broker stopped capturing traffic, err={}", err);
+        debug::set("broker", "capture", "failed");
+        self.capture = None;
+    }
+}
+
+// A message which can't be passed on in time is dropped, and its client times out waiting for the
+// reply, rather than stopping every other client's traffic.
+fn tolerate_timeout(result: Result<()>, what: &str) -> Result<()> {
+    match result {
+        Err(Error::Timeout) => {
+            warn!("router-broker dropped a {}, send timed out", what);
+            metrics::incr(Counter::ClientErrors);
+            Ok(())
+        }
+        result => result,
+    }
+}
+
+fn send_request(sock: &mut zmq::Socket, bytes: &[u8]) -> Result<()> {
+    try!(sock.send(Header::Request.as_bytes(), zmq::SNDMORE));
+    try!(sock.send(bytes, 0));
+    Ok(())
+}
+
+// Receive every frame of a multipart message into `frames`, replacing the frames it held.
+fn recv_frames_into(sock: &mut zmq::Socket, frames: &mut Vec<zmq::Message>) -> Result<()> {
+    frames.clear();
+    frames.push(try!(sock.recv_msg(0)));
+    while try!(sock.get_rcvmore()) {
+        frames.push(try!(sock.recv_msg(0)));
+    }
+    Ok(())
+}
+
+fn send_frames(sock: &mut zmq::Socket, frames: &[zmq::Message]) -> Result<()> {
+    for (i, frame) in frames.iter().enumerate() {
+        let flags = if i + 1 < frames.len() { zmq::SNDMORE } else { 0 };
+        try!(sock.send(frame, flags));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::net;
+    use std::sync::Arc;
+    use std::thread;
+    use std::i32;
+    use std::time::Duration;
+    use std::u32;
+
+    use protobuf::{parse_from_bytes, Message};
+    use protocol;
+    use zmq;
+
+    use cache::{CacheConfig, ReplyCache};
+    use chaos::{self, Point};
+    use super::*;
+
+    fn test_broker(ctx: &BrokerContext) -> Broker {
+        Broker::new("broker#test".to_string(),
+                    ctx,
+                    &ZapCredentials::default(),
+                    &ReconnectConfig::default(),
+                    &KeepaliveConfig::default())
+            .unwrap()
+    }
+
+    // A reply to `client` as a router sends it, `[client][empty][msg]`.
+    fn reply_frames(msg: &protocol::net::Msg) -> Vec<zmq::Message> {
+        vec![zmq::Message::from_slice(b"client").unwrap(),
+             zmq::Message::from_slice(&[]).unwrap(),
+             zmq::Message::from_slice(&msg.write_to_bytes().unwrap()).unwrap()]
+    }
+
+    // A request with the given message id, which the test fault rules may match.
+    fn request(message_id: &str) -> protocol::net::Msg {
+        let mut msg = protocol::Message::new(&protocol::sessionsrv::SessionGet::new()).build();
+        msg.set_message_id(message_id.to_string());
+        msg
+    }
+
+    // A broker connecting to a router at `addr`, watching its connections the way `start` does.
+    fn connecting_broker(ctx: &BrokerContext, addr: &str) -> Broker {
+        let mut broker = test_broker(ctx);
+        broker.router_sock
+            .monitor(ROUTE_MONITOR_ADDR,
+                     zmq::SocketEvent::CONNECTED.to_raw() as i32)
+            .unwrap();
+        broker.monitor_sock.connect(ROUTE_MONITOR_ADDR).unwrap();
+        broker.router_sock.connect(addr).unwrap();
+        broker
+    }
+
+    #[test]
+    fn broker_warms_up_once_a_router_answers() {
+        let ctx = BrokerContext::new();
+        let router = ctx.0.write().unwrap().socket(zmq::ROUTER).unwrap();
+        router.bind("tcp://127.0.0.1:15591").unwrap();
+        let fake = thread::spawn(move || {
+            let mut router = router;
+            let mut frames = vec![];
+            // `[broker][WARM_UP_IDENT][empty]["RQ"][Connect]`
+            recv_frames_into(&mut router, &mut frames).unwrap();
+            assert_eq!(&frames[1][..], WARM_UP_IDENT);
+            let req = parse_from_bytes::<protocol::net::Msg>(&frames[4]).unwrap();
+            assert_eq!(req.get_message_id(), "Connect");
+            let rep = protocol::Message::new(&protocol::routesrv::ConnectOk::new()).build();
+            router.send(&frames[0], zmq::SNDMORE).unwrap();
+            router.send(&frames[1], zmq::SNDMORE).unwrap();
+            router.send(&[], zmq::SNDMORE).unwrap();
+            router.send(&rep.write_to_bytes().unwrap(), 0).unwrap();
+            router
+        });
+        let mut broker = connecting_broker(&ctx, "tcp://127.0.0.1:15591");
+        assert_eq!(broker.warm_up(Duration::from_secs(5)).unwrap(),
+                   Some("tcp://127.0.0.1:15591".to_string()));
+        broker.stop_monitor().unwrap();
+        fake.join().unwrap();
+    }
+
+    #[test]
+    fn broker_does_not_warm_up_on_a_connection_alone() {
+        let ctx = BrokerContext::new();
+        // Accepts the connection but never answers.
+        let router = ctx.0.write().unwrap().socket(zmq::ROUTER).unwrap();
+        router.bind("tcp://127.0.0.1:15592").unwrap();
+        let mut broker = connecting_broker(&ctx, "tcp://127.0.0.1:15592");
+        assert_eq!(broker.warm_up(Duration::from_millis(200)).unwrap(), None);
+    }
+
+    #[test]
+    fn broker_does_not_warm_up_without_a_router() {
+        let ctx = BrokerContext::new();
+        let mut broker = connecting_broker(&ctx, "tcp://127.0.0.1:15593");
+        assert_eq!(broker.warm_up(Duration::from_millis(200)).unwrap(), None);
+        broker.stop_monitor().unwrap();
+    }
+
+    #[test]
+    fn delayed_requests_do_not_hold_up_others() {
+        chaos::install_test_faults();
+        let ctx = Arc::new(BrokerContext::new());
+        let router = ctx.0.write().unwrap().socket(zmq::ROUTER).unwrap();
+        router.bind("tcp://127.0.0.1:15594").unwrap();
+        // Echoes each request back, and returns the order the requests arrived in.
+        let fake = thread::spawn(move || {
+            let mut router = router;
+            let mut arrived = vec![];
+            for _ in 0..2 {
+                let mut frames = vec![];
+                // `[broker][client][empty]["RQ"][msg]`
+                recv_frames_into(&mut router, &mut frames).unwrap();
+                let req = parse_from_bytes::<protocol::net::Msg>(&frames[4]).unwrap();
+                arrived.push(req.get_message_id().to_string());
+                router.send(&frames[0], zmq::SNDMORE).unwrap();
+                router.send(&frames[1], zmq::SNDMORE).unwrap();
+                router.send(&[], zmq::SNDMORE).unwrap();
+                router.send(&frames[4], 0).unwrap();
+            }
+            arrived
+        });
+        let opts = BrokerOpts { warmup: Duration::from_secs(0), ..BrokerOpts::default() };
+        let routers = vec![net::SocketAddrV4::new(net::Ipv4Addr::new(127, 0, 0, 1), 15594)];
+        let _broker =
+            Broker::run_with_opts("broker#chaos".to_string(), ctx.clone(), &routers, opts);
+        let mut delayed = Broker::connect(&ctx).unwrap();
+        let mut prompt = Broker::connect(&ctx).unwrap();
+        delayed.route_msg(request("ChaosDelay")).unwrap();
+        prompt.route_msg(request("SessionGet")).unwrap();
+        assert_eq!(prompt.recv().unwrap().get_message_id(), "SessionGet");
+        assert_eq!(delayed.recv().unwrap().get_message_id(), "ChaosDelay");
+        assert_eq!(fake.join().unwrap(), vec!["SessionGet", "ChaosDelay"]);
+    }
+
+    #[test]
+    fn held_messages_are_passed_on_once_due() {
+        let ctx = BrokerContext::new();
+        let mut broker = test_broker(&ctx);
+        let mut cache = ReplyCache::new(CacheConfig::default());
+        let mut pending = HashMap::new();
+        assert_eq!(broker.poll_timeout(0), -1);
+        broker.frames = reply_frames(&request("SessionGet"));
+        broker.hold(Point::Reply, Duration::from_millis(60_000), None);
+        assert!(broker.frames.is_empty());
+        let due_ms = broker.held[0].due_ms;
+        assert_eq!(broker.poll_timeout(due_ms - 250), 250);
+        assert_eq!(broker.poll_timeout(due_ms + 250), 0);
+        broker.release_held(&mut cache, &mut pending, due_ms - 1).unwrap();
+        assert_eq!(broker.held.len(), 1);
+        broker.release_held(&mut cache, &mut pending, due_ms).unwrap();
+        assert!(broker.held.is_empty());
+        assert_eq!(broker.frames.len(), 3);
+    }
+
+    #[test]
+    fn dropped_replies_no_longer_await_a_cache_fill() {
+        chaos::install_test_faults();
+        let ctx = BrokerContext::new();
+        let mut broker = test_broker(&ctx);
+        let mut cache = ReplyCache::new(CacheConfig {
+            cacheable: vec!["ChaosDrop".to_string()],
+            ..CacheConfig::default()
+        });
+        let reply = request("ChaosDrop");
+        let mut pending = HashMap::new();
+        pending.insert(b"client".to_vec(), (cache.key(&reply).unwrap(), 0));
+        broker.frames = reply_frames(&reply);
+        broker.pass_reply(&mut cache, &mut pending).unwrap();
+        assert!(pending.is_empty());
+        assert!(broker.held.is_empty());
+    }
+
+    #[test]
+    fn hedged_request_returns_first_reply() {
+        let ctx = BrokerContext::new();
+        let broker = ctx.0.write().unwrap().socket(zmq::ROUTER).unwrap();
+        broker.bind(ROUTE_INPROC_ADDR).unwrap();
+        let fake = thread::spawn(move || {
+            let mut broker = broker;
+            let (mut first, mut dup) = (vec![], vec![]);
+            // Leave the request unanswered and answer its duplicate.
+            recv_frames_into(&mut broker, &mut first).unwrap();
+            recv_frames_into(&mut broker, &mut dup).unwrap();
+            assert!(first[0][..] != dup[0][..]);
+            assert_eq!(&first[3][..], &dup[3][..]);
+            broker.send(&dup[0], zmq::SNDMORE).unwrap();
+            broker.send(&[], zmq::SNDMORE).unwrap();
+            broker.send(&dup[3], 0).unwrap();
+        });
+        let mut conn = Broker::connect_hedged(&ctx, Duration::from_millis(10)).unwrap();
+        conn.route(&protocol::sessionsrv::SessionGet::new()).unwrap();
+        assert_eq!(conn.recv().unwrap().get_message_id(), "SessionGet");
+        fake.join().unwrap();
+    }
+
+    #[test]
+    fn keepalive_secs_rounds_up_and_saturates() {
+        assert_eq!(keepalive_secs(Duration::from_millis(0)), 1);
+        assert_eq!(keepalive_secs(Duration::from_millis(1_500)), 2);
+        assert_eq!(keepalive_secs(Duration::from_secs(60)), 60);
+        assert_eq!(keepalive_secs(Duration::from_secs(u32::MAX as u64 * 2)), i32::MAX);
+    }
+
+    #[test]
+    fn keepalive_applies_to_a_socket() {
+        let mut ctx = zmq::Context::new();
+        let sock = ctx.socket(zmq::DEALER).unwrap();
+        assert!(KeepaliveConfig::default().apply(&sock).is_ok());
+        let off = KeepaliveConfig {
+            tcp_idle: Duration::from_secs(0),
+            ..KeepaliveConfig::default()
+        };
+        assert!(off.apply(&sock).is_ok());
+    }
+
+    #[test]
+    fn keepalive_rejects_options_past_i32() {
+        let mut ctx = zmq::Context::new();
+        let sock = ctx.socket(zmq::DEALER).unwrap();
+        let count = KeepaliveConfig {
+            tcp_count: i32::MAX as usize + 1,
+            ..KeepaliveConfig::default()
+        };
+        match count.apply(&sock) {
+            Err(Error::OutOfRange(ref name)) => assert_eq!(name, "tcp_keepalive_count"),
+            other => panic!("expected an out of range error, got {:?}", other),
+        }
+        let heartbeat = KeepaliveConfig {
+            heartbeat_ivl: Duration::from_secs(30 * 24 * 60 * 60),
+            ..KeepaliveConfig::default()
+        };
+        match heartbeat.apply(&sock) {
+            Err(Error::OutOfRange(ref name)) => assert_eq!(name, "heartbeat_ivl"),
+            other => panic!("expected an out of range error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn reconnect_jitter_stays_within_bounds() {
+        let reconnect = ReconnectConfig {
+            ivl: Duration::from_millis(100),
+            ivl_max: Duration::from_secs(10),
+            jitter: Duration::from_millis(1000),
+        };
+        assert_eq!(reconnect.ivl_with_jitter(0), Duration::from_millis(100));
+        assert_eq!(reconnect.ivl_with_jitter(1000), Duration::from_millis(1100));
+        assert_eq!(reconnect.ivl_with_jitter(1001), Duration::from_millis(100));
+        assert!(reconnect.ivl_with_jitter(u32::MAX) <= Duration::from_millis(1100));
+        let ivls: Vec<Duration> = (0..100).map(|_| reconnect.jittered_ivl()).collect();
+        assert!(ivls.iter()
+            .all(|ivl| *ivl >= Duration::from_millis(100) && *ivl <= Duration::from_millis(1100)));
+        assert!(ivls.iter().any(|ivl| *ivl != ivls[0]),
+                "brokers should not all wait the same time");
+    }
+
+    #[test]
+    fn reconnect_without_jitter_waits_the_interval() {
+        let reconnect = ReconnectConfig {
+            jitter: Duration::from_millis(0),
+            ..ReconnectConfig::default()
+        };
+        assert_eq!(reconnect.ivl_with_jitter(12345), reconnect.ivl);
+        assert_eq!(reconnect.jittered_ivl(), reconnect.ivl);
+    }
+
+    #[test]
+    fn replies_parse_the_expected_message() {
+        let ping = protocol::Message::new(&protocol::net::Ping::new()).build();
+        let mut replies = Replies(vec![Ok(ping.clone()), Ok(ping)]);
+        assert!(replies.parse::<protocol::net::Ping>(0).is_ok());
+        match replies.parse::<protocol::sessionsrv::Session>(1) {
+            Err(Error::UnexpectedReply(_)) => (),
+            reply => panic!("expected an unexpected reply error, got {:?}", reply),
+        }
+        match replies.parse::<protocol::net::Ping>(0) {
+            Err(Error::MalformedReply) => (),
+            reply => panic!("expected the reply to be taken, got {:?}", reply),
+        }
+    }
+
+    #[test]
+    fn replies_parse_remote_errors() {
+        let err = protocol::net::err(protocol::net::ErrCode::ENTITY_NOT_FOUND, "rt:test:0");
+        let msg = protocol::Message::new(&err).build();
+        match Replies(vec![Ok(msg)]).parse::<protocol::net::Ping>(0) {
+            Err(Error::Remote(err)) => {
+                assert_eq!(err.get_code(), protocol::net::ErrCode::ENTITY_NOT_FOUND)
+            }
+            reply => panic!("expected a remote error, got {:?}", reply),
+        }
+    }
 }
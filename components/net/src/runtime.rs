@@ -0,0 +1,457 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runs the pieces of a service together and stops them together.
+//!
+//! A service's main loop proxies messages between its `RouteConn` and the backend socket its
+//! `Supervisor`'s workers connect to, and pings its routers with its load, alongside any `Broker`
+//! or background threads it started.
+//! A `Runtime` holds these components and a `Shutdown` signal they share. It runs until shutdown
+//! is requested, by a component, by `SIGTERM` or `SIGINT` when signals are trapped, or because a
+//! component stopped on its own. Either way every component is shut down and waited for before
+//! `run` returns. When signals are trapped `SIGHUP` runs the runtime's reload hook, which services
+//! use to re-read their configuration file and roll their workers onto it.
+//!
+//! ```ignore
+//! let sup = try!(Supervisor::<Worker>::new(ctx, cfg).spawn(BE_LISTEN_ADDR, workers));
+//! try!(self.connect());
+//! try!(Runtime::new().trap_signals().with(sup).run_with(|shutdown| {
+//!     runtime::serve(&mut self.router, &mut self.be_sock, shutdown)
+//! }));
+//! ```
+
+use std::fmt;
+use std::result;
+use std::sync::{Arc, Once, ONCE_INIT};
+use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use libc;
+use zmq;
+
+use error::{Error, Result};
+use server::{RouteConn, SupervisorHandle};
+
+/// Milliseconds between checks for shutdown.
+pub const SHUTDOWN_POLL_MS: i64 = 100;
+
+static TERMINATE_REQUESTED: AtomicBool = ATOMIC_BOOL_INIT;
+static RELOAD_REQUESTED: AtomicBool = ATOMIC_BOOL_INIT;
+static TRAP: Once = ONCE_INIT;
+
+/// Signal shared by every component of a `Runtime` that the application is shutting down.
+#[derive(Clone, Default)]
+pub struct Shutdown(Arc<AtomicBool>);
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Shutdown::default()
+    }
+
+    pub fn request(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_requested(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// A long running piece of an application managed by a `Runtime`.
+pub trait Component: Send {
+    fn name(&self) -> &str;
+
+    /// False once the component has stopped on its own.
+    fn is_alive(&self) -> bool;
+
+    /// Ask the component to stop. Must not block.
+    fn shutdown(&self);
+
+    /// Block until the component has stopped. Returns false if it failed.
+    fn join(self: Box<Self>) -> bool;
+}
+
+impl Component for SupervisorHandle {
+    fn name(&self) -> &str {
+        "supervisor"
+    }
+
+    fn is_alive(&self) -> bool {
+        self.is_running()
+    }
+
+    fn shutdown(&self) {
+        SupervisorHandle::shutdown(self)
+    }
+
+    fn join(self: Box<Self>) -> bool {
+        SupervisorHandle::join(*self).is_ok()
+    }
+}
+
+/// A component running on its own thread, started with `Runtime::spawn`.
+pub struct ThreadComponent {
+    name: String,
+    // The error a failed component returned, already formatted since each service has its own
+    // error type.
+    handle: JoinHandle<result::Result<(), String>>,
+    alive: Arc<AtomicBool>,
+    shutdown: Shutdown,
+}
+
+impl Component for ThreadComponent {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn is_alive(&self) -> bool {
+        self.alive.load(Ordering::SeqCst)
+    }
+
+    fn shutdown(&self) {
+        self.shutdown.request()
+    }
+
+    fn join(self: Box<Self>) -> bool {
+        match self.handle.join() {
+            Ok(Ok(())) => true,
+            Ok(Err(e)) => {
+                warn!("{} failed, err={}", self.name, e);
+                false
+            }
+            Err(_) => {
+                warn!("{} panicked", self.name);
+                false
+            }
+        }
+    }
+}
+
+// Marks a thread component as stopped however its thread exits.
+struct AliveGuard(Arc<AtomicBool>);
+
+impl Drop for AliveGuard {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
+pub struct Runtime {
+    shutdown: Shutdown,
+    components: Vec<Box<Component>>,
+    reload: Option<Box<FnMut() + Send>>,
+    trap_signals: bool,
+}
+
+impl Runtime {
+    pub fn new() -> Self {
+        Runtime {
+            shutdown: Shutdown::new(),
+            components: vec![],
+            reload: None,
+            trap_signals: false,
+        }
+    }
+
+    /// The shutdown signal shared by this runtime's components.
+    pub fn shutdown_signal(&self) -> Shutdown {
+        self.shutdown.clone()
+    }
+
+    /// Request shutdown when the process receives `SIGTERM` or `SIGINT`, and run the reload hook
+    /// when it receives `SIGHUP`.
+    pub fn trap_signals(mut self) -> Self {
+        self.trap_signals = true;
+        self
+    }
+
+    /// Run a function on the runtime's thread each time a reload is requested. The function
+    /// should not block for long since shutdown isn't checked while it runs.
+    pub fn on_reload<F>(mut self, f: F) -> Self
+        where F: FnMut() + Send + 'static
+    {
+        self.reload = Some(Box::new(f));
+        self
+    }
+
+    /// Manage an already started component.
+    pub fn with<C: Component + 'static>(mut self, component: C) -> Self {
+        self.components.push(Box::new(component));
+        self
+    }
+
+    /// Run a function on its own thread as a component. The function is given the runtime's
+    /// shutdown signal and should return once shutdown is requested. It may return any error
+    /// type, such as the error of the service it belongs to.
+    pub fn spawn<F, E>(mut self, name: &str, f: F) -> Result<Self>
+        where F: FnOnce(Shutdown) -> result::Result<(), E> + Send + 'static,
+              E: fmt::Display
+    {
+        let alive = Arc::new(AtomicBool::new(true));
+        let guard = AliveGuard(alive.clone());
+        let shutdown = self.shutdown.clone();
+        let handle = try!(thread::Builder::new().name(name.to_string()).spawn(move || {
+            let _guard = guard;
+            f(shutdown).map_err(|e| e.to_string())
+        }));
+        self.components.push(Box::new(ThreadComponent {
+            name: name.to_string(),
+            handle: handle,
+            alive: alive,
+            shutdown: self.shutdown.clone(),
+        }));
+        Ok(self)
+    }
+
+    /// Run until shutdown is requested or a component stops.
+    ///
+    /// # Errors
+    ///
+    /// * A component stopped before shutdown was requested or failed while stopping
+    pub fn run(self) -> Result<()> {
+        self.run_with(|shutdown| {
+            while !shutdown.is_requested() {
+                thread::sleep(Duration::from_millis(SHUTDOWN_POLL_MS as u64));
+            }
+            Ok(())
+        })
+    }
+
+    /// Like `run` but runs the given function on the calling thread alongside the components,
+    /// typically the service's proxy loop. Shutdown is requested when the function returns, and
+    /// the function should return once shutdown is requested.
+    ///
+    /// # Errors
+    ///
+    /// * The function returned an error
+    /// * A component stopped before shutdown was requested or failed while stopping
+    pub fn run_with<F>(self, main: F) -> Result<()>
+        where F: FnOnce(&Shutdown) -> Result<()>
+    {
+        if self.trap_signals {
+            trap_signals();
+        }
+        let shutdown = self.shutdown.clone();
+        let watcher = try!(thread::Builder::new()
+            .name("runtime".to_string())
+            .spawn(move || self.watch()));
+        let result = main(&shutdown);
+        shutdown.request();
+        let failed = watcher.join().unwrap_or(Some("runtime".to_string()));
+        try!(result);
+        match failed {
+            Some(name) => Err(Error::ComponentFailed(name)),
+            None => Ok(()),
+        }
+    }
+
+    // Wait for shutdown or for a component to stop, then stop every component. Returns the name
+    // of the first component which stopped on its own or failed.
+    fn watch(mut self) -> Option<String> {
+        let mut failed = None;
+        while !self.shutdown.is_requested() {
+            if TERMINATE_REQUESTED.load(Ordering::SeqCst) {
+                info!("termination requested, shutting down");
+                break;
+            }
+            if RELOAD_REQUESTED.swap(false, Ordering::SeqCst) {
+                match self.reload {
+                    Some(ref mut reload) => {
+                        info!("reload requested");
+                        (**reload)();
+                    }
+                    None => info!("reload requested, nothing to reload"),
+                }
+            }
+            if let Some(c) = self.components.iter().find(|c| !c.is_alive()) {
+                warn!("{} stopped, shutting down", c.name());
+                failed = Some(c.name().to_string());
+                break;
+            }
+            thread::sleep(Duration::from_millis(SHUTDOWN_POLL_MS as u64));
+        }
+        self.shutdown.request();
+        for component in self.components.iter() {
+            component.shutdown();
+        }
+        for component in self.components {
+            let name = component.name().to_string();
+            if !component.join() && failed.is_none() {
+                failed = Some(name);
+            }
+        }
+        failed
+    }
+}
+
+/// Forward messages between two sockets in both directions, like `zmq::proxy`, until shutdown is
+/// requested.
+pub fn proxy(frontend: &mut zmq::Socket,
+             backend: &mut zmq::Socket,
+             shutdown: &Shutdown)
+             -> Result<()> {
+    while !shutdown.is_requested() {
+        try!(proxy_once(frontend, backend));
+    }
+    Ok(())
+}
+
+/// Forward messages between a service's `RouteConn` and its backend socket like `proxy`, sending
+/// the routers it registered with a heartbeat ping whenever one is due, until shutdown is
+/// requested.
+pub fn serve(router: &mut RouteConn,
+             backend: &mut zmq::Socket,
+             shutdown: &Shutdown)
+             -> Result<()> {
+    while !shutdown.is_requested() {
+        try!(router.heartbeat());
+        try!(proxy_once(&mut router.socket, backend));
+    }
+    Ok(())
+}
+
+// Wait up to `SHUTDOWN_POLL_MS` for messages on either socket and forward what arrived. A poll
+// interrupted by a signal, such as the one a trapped `SIGTERM` raises, forwards nothing so the
+// caller can check for shutdown.
+fn proxy_once(frontend: &mut zmq::Socket, backend: &mut zmq::Socket) -> Result<()> {
+    let (from_front, from_back) = {
+        let mut items = [frontend.as_poll_item(zmq::POLLIN), backend.as_poll_item(zmq::POLLIN)];
+        match zmq::poll(&mut items, SHUTDOWN_POLL_MS) {
+            Ok(_) => (),
+            Err(zmq::Error::EINTR) => return Ok(()),
+            Err(e) => return Err(Error::from(e)),
+        }
+        (items[0].get_revents() & zmq::POLLIN > 0, items[1].get_revents() & zmq::POLLIN > 0)
+    };
+    if from_front {
+        try!(forward(frontend, backend));
+    }
+    if from_back {
+        try!(forward(backend, frontend));
+    }
+    Ok(())
+}
+
+// Forward every frame of one multipart message, retrying any receive or send interrupted by a
+// signal so a message is never split.
+fn forward(from: &mut zmq::Socket, to: &mut zmq::Socket) -> Result<()> {
+    loop {
+        let frame = try!(recv_frame(from));
+        let more = try!(from.get_rcvmore());
+        try!(send_frame(to, &frame, if more { zmq::SNDMORE } else { 0 }));
+        if !more {
+            return Ok(());
+        }
+    }
+}
+
+fn recv_frame(sock: &mut zmq::Socket) -> Result<zmq::Message> {
+    loop {
+        match sock.recv_msg(0) {
+            Err(zmq::Error::EINTR) => continue,
+            frame => return Ok(try!(frame)),
+        }
+    }
+}
+
+fn send_frame(sock: &mut zmq::Socket, frame: &[u8], flags: i32) -> Result<()> {
+    loop {
+        match sock.send(frame, flags) {
+            Err(zmq::Error::EINTR) => continue,
+            sent => return Ok(try!(sent)),
+        }
+    }
+}
+
+fn trap_signals() {
+    TRAP.call_once(|| unsafe {
+        libc::signal(libc::SIGTERM, handle_signal as libc::sighandler_t);
+        libc::signal(libc::SIGINT, handle_signal as libc::sighandler_t);
+        libc::signal(libc::SIGHUP, handle_hangup as libc::sighandler_t);
+    });
+}
+
+extern "C" fn handle_signal(_: libc::c_int) {
+    TERMINATE_REQUESTED.store(true, Ordering::SeqCst);
+}
+
+extern "C" fn handle_hangup(_: libc::c_int) {
+    RELOAD_REQUESTED.store(true, Ordering::SeqCst);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::Duration;
+
+    use libc;
+
+    use super::*;
+    use super::{handle_hangup, RELOAD_REQUESTED};
+    use error::Error;
+
+    fn wait(shutdown: Shutdown) -> Result<()> {
+        while !shutdown.is_requested() {
+            thread::sleep(Duration::from_millis(10));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn stops_components_when_one_fails() {
+        let result = Runtime::new()
+            .spawn("waits", wait)
+            .unwrap()
+            .spawn("fails", |_| Err(Error::Sys))
+            .unwrap()
+            .run();
+        match result {
+            Err(Error::ComponentFailed(ref name)) => assert_eq!(name, "fails"),
+            _ => panic!("expected the failed component to be reported"),
+        }
+    }
+
+    #[test]
+    fn runs_the_reload_hook_when_reload_is_requested() {
+        let reloads = Arc::new(AtomicUsize::new(0));
+        let counter = reloads.clone();
+        let runtime = Runtime::new().on_reload(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        let result = runtime.run_with(|_| {
+            handle_hangup(libc::SIGHUP);
+            for _ in 0..50 {
+                if reloads.load(Ordering::SeqCst) > 0 {
+                    break;
+                }
+                thread::sleep(Duration::from_millis(10));
+            }
+            Ok(())
+        });
+        assert!(result.is_ok());
+        assert_eq!(reloads.load(Ordering::SeqCst), 1);
+        assert!(!RELOAD_REQUESTED.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn shuts_down_when_main_returns() {
+        let runtime = Runtime::new()
+            .spawn("waits", wait)
+            .unwrap();
+        assert!(runtime.run_with(|_| Ok(())).is_ok());
+    }
+}
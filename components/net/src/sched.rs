@@ -0,0 +1,181 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! CPU pinning and scheduling priority of a service's message handling threads.
+//!
+//! Latency-critical services can keep their workers and router broker from being moved between
+//! CPUs, and from being preempted by less important processes on the host, by pinning them to
+//! dedicated CPUs and raising their priority. Both are supported on Linux only; elsewhere, and
+//! when the process lacks the privilege to change them, threads keep running where and how the
+//! operating system schedules them.
+
+use std::io;
+
+#[cfg(target_os = "linux")]
+use std::mem;
+
+use hab_core;
+use hab_core::config::ParseInto;
+#[cfg(target_os = "linux")]
+use libc;
+use toml;
+
+/// Number of CPUs threads can be pinned to, the size of the CPU set handed to the kernel. CPUs
+/// are numbered from zero.
+pub const MAX_CPUS: u32 = 1024;
+
+/// Where and at what priority a group of threads runs.
+#[derive(Clone, Debug, Default)]
+pub struct SchedConfig {
+    /// CPUs to pin the threads to, one each in turn, or empty to leave them unpinned
+    pub cpus: Vec<u32>,
+    /// Nice value to run the threads at, from -20 (most favourable) to 19, if not the process's
+    pub nice: Option<i32>,
+}
+
+impl SchedConfig {
+    pub fn new(cpus: Vec<u32>, nice: Option<i32>) -> Self {
+        SchedConfig {
+            cpus: cpus,
+            nice: nice,
+        }
+    }
+
+    /// Read the CPUs and nice value of a group of threads from the `cpus` and `nice` entries of
+    /// a configuration file, such as `cfg.worker_cpus` and `cfg.worker_nice`.
+    ///
+    /// # Errors
+    ///
+    /// * A CPU is not a number below `MAX_CPUS`
+    /// * The nice value is not a number from -20 to 19
+    pub fn from_toml(toml: &toml::Value,
+                     cpus: &'static str,
+                     nice: &'static str)
+                     -> hab_core::Result<Self> {
+        let mut cfg = SchedConfig::default();
+        try!(toml.parse_into(cpus, &mut cfg.cpus));
+        if cfg.cpus.iter().any(|cpu| *cpu >= MAX_CPUS) {
+            return Err(hab_core::Error::ConfigInvalidArray(cpus));
+        }
+        try!(toml.parse_into(nice, &mut cfg.nice));
+        if cfg.nice.map_or(false, |nice| nice < -20 || nice > 19) {
+            return Err(hab_core::Error::ConfigInvalidString(nice));
+        }
+        Ok(cfg)
+    }
+
+    /// Pin the calling thread, the `index`th of the group, to its CPU and set its priority.
+    /// Threads beyond the number of CPUs given wrap around and share them.
+    ///
+    /// Failures are logged and otherwise ignored so a service still starts on hosts where its
+    /// threads cannot be placed as configured.
+    pub fn apply(&self, index: usize) {
+        if !self.cpus.is_empty() {
+            let cpu = self.cpus[index % self.cpus.len()];
+            match pin_to_cpu(cpu) {
+                Ok(()) => debug!("pinned thread {} to cpu {}", index, cpu),
+                Err(e) => warn!("unable to pin thread {} to cpu {}, err={}", index, cpu, e),
+            }
+        }
+        if let Some(nice) = self.nice {
+            match set_nice(nice) {
+                Ok(()) => debug!("set priority of thread {} to nice {}", index, nice),
+                Err(e) => warn!("unable to set priority of thread {}, err={}", index, e),
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn pin_to_cpu(cpu: u32) -> io::Result<()> {
+    // `CPU_SET` panics on a CPU beyond the set.
+    if cpu >= MAX_CPUS {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                  format!("cpu {} is not below {}", cpu, MAX_CPUS)));
+    }
+    unsafe {
+        let mut set: libc::cpu_set_t = mem::zeroed();
+        libc::CPU_SET(cpu as usize, &mut set);
+        if libc::sched_setaffinity(0, mem::size_of::<libc::cpu_set_t>(), &set) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn pin_to_cpu(_cpu: u32) -> io::Result<()> {
+    Err(io::Error::new(io::ErrorKind::Other, "not supported on this platform"))
+}
+
+// Linux keeps a nice value per thread, so this only affects the calling thread. Other platforms
+// keep one per process, which would change the priority of every thread at once.
+#[cfg(target_os = "linux")]
+fn set_nice(nice: i32) -> io::Result<()> {
+    if unsafe { libc::setpriority(libc::PRIO_PROCESS, 0, nice) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_nice(_nice: i32) -> io::Result<()> {
+    Err(io::Error::new(io::ErrorKind::Other, "not supported on this platform"))
+}
+
+#[cfg(test)]
+mod tests {
+    use toml;
+
+    use super::*;
+
+    fn parse(config: &str) -> hab_core::Result<SchedConfig> {
+        let toml: toml::Value = config.parse().unwrap();
+        SchedConfig::from_toml(&toml, "cfg.worker_cpus", "cfg.worker_nice")
+    }
+
+    #[test]
+    fn sched_is_read_from_the_config() {
+        let cfg = parse("[cfg]\nworker_cpus = [2, 3]\nworker_nice = -5\n").unwrap();
+        assert_eq!(cfg.cpus, vec![2, 3]);
+        assert_eq!(cfg.nice, Some(-5));
+        let cfg = parse("[cfg]\n").unwrap();
+        assert!(cfg.cpus.is_empty());
+        assert_eq!(cfg.nice, None);
+    }
+
+    #[test]
+    fn sched_rejects_cpus_beyond_the_cpu_set() {
+        assert!(parse("[cfg]\nworker_cpus = [1023]\n").is_ok());
+        match parse("[cfg]\nworker_cpus = [0, 1024]\n") {
+            Err(hab_core::Error::ConfigInvalidArray(field)) => assert_eq!(field, "cfg.worker_cpus"),
+            other => panic!("expected an invalid array error, got {:?}", other),
+        }
+        assert!(parse("[cfg]\nworker_cpus = [-1]\n").is_err());
+    }
+
+    #[test]
+    fn sched_rejects_nice_values_out_of_range() {
+        assert!(parse("[cfg]\nworker_nice = 19\n").is_ok());
+        assert!(parse("[cfg]\nworker_nice = 20\n").is_err());
+        assert!(parse("[cfg]\nworker_nice = -21\n").is_err());
+    }
+
+    #[test]
+    fn threads_are_not_pinned_to_cpus_beyond_the_cpu_set() {
+        assert!(pin_to_cpu(MAX_CPUS).is_err());
+        // Logged rather than panicking.
+        SchedConfig::new(vec![MAX_CPUS], None).apply(0);
+    }
+}
@@ -14,26 +14,66 @@
 
 use std::error;
 use std::marker::PhantomData;
+use std::mem;
 use std::net;
 use std::result;
 use std::sync::{mpsc, Arc, RwLock};
-use std::thread;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::{self, JoinHandle};
 use std::time::Duration;
 
+use auth::Authenticate;
 use fnv::FnvHasher;
+use hab_core::config::ConfigFile;
 use libc;
-use protobuf::{self, parse_from_bytes};
+use protobuf::{self, parse_from_bytes, Clear, CodedInputStream};
 use protobuf::core::Message as ProtoBufMessage;
-use protocol::{self, Routable, RouteKey};
-use time;
+use protocol::{self, schema, Routable, RouteKey};
+use protocol::net::Capabilities;
+use protocol::sessionsrv::{Account, Session};
 use zmq;
 
+use chaos::{self, Fault, Point};
+use clock::{self, Clock, SharedClock, SystemClock};
+use codec::{Header, MAX_HOPS};
+use concurrency::ConcurrencyLimits;
 use config::{self, RouteAddrs, Shards};
+use control::{self, ControlClient, WorkerControl};
+use debug;
 use error::{Error, Result};
+use health::{self, HealthCheck};
+use hmac;
+use logging::{self, LogContext};
+use metrics::{self, Counter};
+use readiness;
+use trace;
+use routing::{BrokerContext, KeepaliveConfig, RouteClient};
+use sched::SchedConfig;
+use stats::{self, WorkerGauge};
+use usage;
+use zap;
 
-const PING_INTERVAL: i64 = 2000;
-const SERVER_TTL: i64 = 6000;
-const MAX_HOPS: usize = 8;
+pub const PING_INTERVAL: i64 = 2000;
+pub const SERVER_TTL: i64 = 6000;
+/// Milliseconds a `Supervisor` waits for a replaced worker to finish the messages it was handed
+/// before stopping it anyway.
+pub const DRAIN_TIMEOUT_MS: i64 = 30_000;
+
+lazy_static! {
+    // Capabilities the process's service negotiated with every RouteSrv it registered with.
+    static ref NEGOTIATED: RwLock<Capabilities> =
+        RwLock::new(protocol::net::SUPPORTED_CAPABILITIES);
+}
+
+/// Capabilities supported by this build and by every `RouteSrv` the process's service last
+/// registered with, which replies sent by its workers are stamped with.
+pub fn negotiated_capabilities() -> Capabilities {
+    *NEGOTIATED.read().unwrap()
+}
+
+fn set_negotiated_capabilities(capabilities: Capabilities) {
+    *NEGOTIATED.write().unwrap() = capabilities;
+}
 
 pub trait ToAddrString {
     fn to_addr_string(&self) -> String;
@@ -45,10 +85,20 @@ impl ToAddrString for net::SocketAddrV4 {
     }
 }
 
+/// A received message and the hops it travelled to reach this server.
+///
+/// An envelope is meant to be reused for every message a worker receives. Frames are parsed into
+/// the fields of the previous message and replies are encoded into a buffer kept from the last
+/// reply, so once a worker has seen messages of a given size it handles more without allocating
+/// for the message, its body, or the reply.
 pub struct Envelope {
     pub msg: protocol::net::Msg,
     hops: Vec<zmq::Message>,
     started: bool,
+    txn: Option<u64>,
+    buf: Vec<u8>,
+    session: Option<Session>,
+    clock: SharedClock,
 }
 
 impl Envelope {
@@ -71,10 +121,29 @@ impl Envelope {
         self.msg.get_body()
     }
 
+    /// Parse a received message frame into the envelope's message, reusing the storage of the
+    /// message it held before.
+    ///
+    /// # Errors
+    ///
+    /// * The frame was not a valid message
+    pub fn parse_frame(&mut self, frame: &[u8]) -> Result<()> {
+        self.msg.clear();
+        let mut is = CodedInputStream::from_bytes(frame);
+        try!(self.msg.merge_from(&mut is));
+        try!(self.msg.check_initialized());
+        Ok(())
+    }
+
     pub fn hops(&self) -> &Vec<zmq::Message> {
         &self.hops
     }
 
+    /// Structured context for log records about this message.
+    pub fn log_context(&self) -> LogContext {
+        LogContext::new(self.txn, Some(&self.msg))
+    }
+
     pub fn max_hops(&self) -> bool {
         self.hops.len() >= MAX_HOPS
     }
@@ -83,6 +152,15 @@ impl Envelope {
         self.msg.get_message_id()
     }
 
+    pub fn txn(&self) -> Option<u64> {
+        self.txn
+    }
+
+    /// Mark the start of a new transaction, assigning the envelope a fresh transaction id.
+    pub fn begin_txn(&mut self) {
+        self.txn = Some(logging::next_txn());
+    }
+
     pub fn route_info(&self) -> &protocol::net::RouteInfo {
         self.msg.get_route_info()
     }
@@ -91,10 +169,21 @@ impl Envelope {
         self.msg.get_route_info().get_protocol()
     }
 
+    /// Milliseconds the message waited between being sent and now, or `None` if the sender
+    /// didn't stamp it. Negative if the sender's clock is ahead of ours.
+    pub fn queue_latency_ms(&self) -> Option<i64> {
+        clock::queue_latency_ms(&self.clock, &self.msg)
+    }
+
+    /// Measure queue latency and stamp forwarded messages and replies with the given clock.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
     pub fn reply<M: ProtoBufMessage>(&mut self, sock: &mut zmq::Socket, msg: &M) -> Result<()> {
         try!(self.send_header(sock));
-        let rep = protocol::Message::new(msg).build();
-        try!(sock.send(&rep.write_to_bytes().unwrap(), zmq::SNDMORE));
+        try!(self.encode_reply(msg));
+        try!(sock.send(&self.buf, zmq::SNDMORE));
         Ok(())
     }
 
@@ -103,12 +192,78 @@ impl Envelope {
                                               msg: &M)
                                               -> Result<()> {
         try!(self.send_header(sock));
-        let rep = protocol::Message::new(msg).build();
-        let bytes = try!(rep.write_to_bytes());
-        try!(sock.send(&bytes, 0));
+        try!(self.encode_reply(msg));
+        try!(sock.send(&self.buf, 0));
+        metrics::incr(Counter::Sent);
         Ok(())
     }
 
+    /// Re-route the message, unchanged, to a server of another protocol. The forwarded message
+    /// keeps the envelope's hops, so whichever server handles it replies straight to the client
+    /// who sent it instead of back to this worker. The body is passed along as received; only
+    /// the route info is rewritten.
+    ///
+    /// Send it on the same socket replies would be sent on. The envelope must not be replied to
+    /// after it has been forwarded.
+    ///
+    /// # Errors
+    ///
+    /// * One or more message frames cannot be sent to the router
+    pub fn forward<K: RouteKey>(&mut self,
+                                sock: &mut zmq::Socket,
+                                protocol: protocol::net::Protocol,
+                                route_key: Option<K>)
+                                -> Result<()> {
+        // The envelope is done with once forwarded, so the message is rewritten in place.
+        {
+            let route_info = self.msg.mut_route_info();
+            route_info.set_protocol(protocol);
+            match route_key {
+                Some(key) => route_info.set_hash(key.hash(&mut FnvHasher::default())),
+                None => route_info.clear_hash(),
+            }
+        }
+        trace::inject_current(&mut self.msg);
+        clock::stamp(&self.clock, &mut self.msg);
+        hmac::sign(&mut self.msg);
+        self.buf.clear();
+        try!(self.msg.write_to_writer(&mut self.buf));
+        for hop in self.hops.iter() {
+            try!(sock.send(hop, zmq::SNDMORE));
+        }
+        try!(sock.send(&[], zmq::SNDMORE));
+        try!(sock.send(Header::Forward.as_bytes(), zmq::SNDMORE));
+        try!(sock.send(&self.buf, 0));
+        self.started = true;
+        metrics::incr(Counter::Sent);
+        Ok(())
+    }
+
+    /// Remove the first hop, the identity of the peer which handed this message to a router.
+    /// The router does this for forwarded messages so replies skip the server which forwarded
+    /// them.
+    pub fn strip_sender(&mut self) {
+        if !self.hops.is_empty() {
+            self.hops.remove(0);
+        }
+    }
+
+    /// Session of the user the message was sent on behalf of, if the worker authenticates its
+    /// messages. See `auth`.
+    pub fn session(&self) -> Option<&Session> {
+        self.session.as_ref()
+    }
+
+    pub fn set_session(&mut self, session: Option<Session>) {
+        self.session = session;
+    }
+
+    /// Account of the user the message was sent on behalf of, if the worker authenticates its
+    /// messages.
+    pub fn account(&self) -> Option<Account> {
+        self.session.clone().map(|session| session.into())
+    }
+
     pub fn parse_msg<M: protobuf::MessageStatic>(&self) -> Result<M> {
         let msg: M = try!(parse_from_bytes(&self.body()));
         Ok(msg)
@@ -116,17 +271,38 @@ impl Envelope {
 
     pub fn reset(&mut self) {
         self.started = false;
+        self.txn = None;
+        self.session = None;
         self.hops.clear();
-        self.msg = protocol::net::Msg::new();
+        self.msg.clear();
+    }
+
+    // Encode a reply to the envelope's message into the reply buffer.
+    fn encode_reply<M: ProtoBufMessage>(&mut self, msg: &M) -> Result<()> {
+        let mut rep = protocol::Message::new(msg).build();
+        rep.set_capabilities(negotiated_capabilities().bits());
+        self.correlate(&mut rep);
+        clock::stamp(&self.clock, &mut rep);
+        self.buf.clear();
+        try!(rep.write_to_writer(&mut self.buf));
+        Ok(())
+    }
+
+    // Echo the request's correlation id, if any, so a multiplexing client can match the reply
+    // to its request.
+    fn correlate(&self, rep: &mut protocol::net::Msg) {
+        if self.msg.has_correlation_id() {
+            rep.set_correlation_id(self.msg.get_correlation_id());
+        }
     }
 
     fn send_header(&mut self, sock: &mut zmq::Socket) -> Result<()> {
         if !self.started {
             for hop in self.hops.iter() {
-                sock.send(hop, zmq::SNDMORE).unwrap();
+                try!(sock.send(hop, zmq::SNDMORE));
             }
-            sock.send(&[], zmq::SNDMORE).unwrap();
-            sock.send_str("RP", zmq::SNDMORE).unwrap();
+            try!(sock.send(&[], zmq::SNDMORE));
+            try!(sock.send(Header::Reply.as_bytes(), zmq::SNDMORE));
             self.started = true;
         }
         Ok(())
@@ -139,6 +315,10 @@ impl Default for Envelope {
             msg: protocol::net::Msg::new(),
             hops: Vec::with_capacity(MAX_HOPS),
             started: false,
+            txn: None,
+            buf: vec![],
+            session: None,
+            clock: Arc::new(SystemClock),
         }
     }
 }
@@ -167,7 +347,8 @@ pub trait NetIdent {
 
 pub trait Service: NetIdent {
     type Application: Application;
-    type Config: config::RouteAddrs + config::Shards;
+    type Config: config::Keepalive + config::MessageAuth + config::Metrics + config::RouteAddrs +
+                 config::RouterAuth + config::Shards;
     type Error: error::Error + From<Error> + From<zmq::Error>;
 
     fn protocol() -> protocol::net::Protocol;
@@ -177,13 +358,38 @@ pub trait Service: NetIdent {
     fn conn(&self) -> &RouteConn;
     fn conn_mut(&mut self) -> &mut RouteConn;
 
+    /// Register with every configured router once each component of the process has reported
+    /// ready, so no traffic is routed to this server before it can be handled.
     fn connect(&mut self) -> result::Result<(), Self::Error> {
+        try!(readiness::wait(Duration::from_millis(readiness::READY_TIMEOUT_MS)));
+        // Forgotten again if registering fails, so it doesn't hold the process's readiness down.
+        let registration =
+            readiness::expecting(readiness::instance(readiness::REGISTRATION, &Self::net_ident()));
         let mut reg = protocol::routesrv::Registration::new();
         reg.set_protocol(Self::protocol());
         reg.set_endpoint(Self::net_ident());
+        reg.set_capabilities(protocol::net::SUPPORTED_CAPABILITIES.bits());
+        // Capabilities are negotiated afresh with the routers of each registration, so ones a
+        // router lacked on an earlier connect are used again once every router supports them.
+        self.conn_mut().router_capabilities = protocol::net::SUPPORTED_CAPABILITIES;
+        logging::set_net_ident(reg.get_endpoint().to_string());
+        debug::trap_signal();
+        debug::set("service", "protocol", format!("{:?}", Self::protocol()));
         let (hb_addrs, addrs) = {
             let cfg = self.config().read().unwrap();
+            try!(metrics::start(&*cfg));
+            hmac::configure(&*cfg);
             reg.set_shards(cfg.shards().clone());
+            reg.set_weight(try!(cfg.weight().registered()));
+            let packages = ["net", schema::package(Self::protocol())];
+            reg.set_schemas(protobuf::RepeatedField::from_vec(schema::fingerprints(&packages)));
+            debug::set("service", "shards", format!("{:?}", cfg.shards()));
+            let keepalive = KeepaliveConfig::from_config(&*cfg);
+            try!(keepalive.apply(&self.conn().socket));
+            try!(keepalive.apply(&self.conn().heartbeat));
+            let auth = cfg.router_auth();
+            try!(zap::apply_client(&self.conn().socket, &auth));
+            try!(zap::apply_client(&self.conn().heartbeat, &auth));
             let hb_addrs: Vec<String> = cfg.route_addrs()
                 .iter()
                 .map(|f| format!("tcp://{}:{}", f.ip(), cfg.heartbeat_port()))
@@ -194,30 +400,127 @@ pub trait Service: NetIdent {
                 .collect();
             (hb_addrs, addrs)
         };
+        self.conn_mut().registration = Some(reg.clone());
         for addr in &hb_addrs {
             println!("Connecting to {:?}...", addr);
             try!(self.conn_mut().register(&addr));
+            debug::set("service", &format!("heartbeat.{}", addr), "registering");
         }
         let mut ready = 0;
-        let mut rt = try!(zmq::Message::new());
-        let mut hb = try!(zmq::Message::new());
+        let mut msg = try!(zmq::Message::new());
         while ready < hb_addrs.len() {
-            try!(self.conn_mut().heartbeat.recv(&mut rt, 0));
-            try!(self.conn_mut().heartbeat.recv(&mut hb, 0));
-            debug!("received reg request, {:?}", hb.as_str());
-            try!(self.conn_mut().heartbeat.send_str("R", zmq::SNDMORE));
-            try!(self.conn_mut().heartbeat.send(&reg.write_to_bytes().unwrap(), 0));
-            try!(self.conn_mut().heartbeat.recv(&mut hb, 0));
-            ready += 1;
+            let event = try!(recv_registration_event(&self.conn().heartbeat, &mut msg));
+            match event {
+                RegistrationEvent::Requested => {
+                    debug!("{} received reg request", LogContext::new(None, None));
+                    try!(send_registration(&self.conn().heartbeat, &reg));
+                }
+                RegistrationEvent::Accepted(theirs) => {
+                    let capabilities = self.conn().router_capabilities &
+                                       protocol::net::negotiate(theirs);
+                    self.conn_mut().router_capabilities = capabilities;
+                    ready += 1;
+                    debug::set("service",
+                               "heartbeat.registered",
+                               format!("{}/{}", ready, hb_addrs.len()));
+                }
+                RegistrationEvent::Rejected(reason) => {
+                    return Err(Self::Error::from(Error::RegistrationRejected(reason)));
+                }
+                RegistrationEvent::Other(frame) => {
+                    debug!("{} ignoring {:?} while registering",
+                           LogContext::new(None, None),
+                           frame);
+                }
+            }
         }
         for addr in addrs {
             try!(self.conn_mut().connect(&addr));
+            debug::set("service", &format!("router.{}", addr), "connected");
         }
+        set_negotiated_capabilities(self.conn().router_capabilities());
+        debug::set("service",
+                   "capabilities",
+                   format!("{:?}", self.conn().router_capabilities()));
+        registration.ready();
         println!("Connected");
         Ok(())
     }
 }
 
+/// A message a `RouteSrv` sends on a server's heartbeat socket while the server registers.
+#[derive(Debug, PartialEq)]
+pub enum RegistrationEvent {
+    /// `REG`, asking for the server's registration
+    Requested,
+    /// `REGOK`, with the capabilities the router supports
+    Accepted(Capabilities),
+    /// `REGERR`, with the reason the router refused the registration
+    Rejected(String),
+    /// Anything else, such as a reply to a ping
+    Other(String),
+}
+
+/// Read the next message a `RouteSrv` sent on a heartbeat socket. Each is an empty delimiter
+/// frame followed by `REG`, `REGOK` or `REGERR`; frames of a message are read whole so none are
+/// left for the next read.
+pub fn recv_registration_event(sock: &zmq::Socket,
+                               msg: &mut zmq::Message)
+                               -> Result<RegistrationEvent> {
+    try!(sock.recv(msg, 0));
+    read_registration_event(sock, msg)
+}
+
+// Finish reading a message from a heartbeat socket whose first frame is in `msg`.
+fn read_registration_event(sock: &zmq::Socket,
+                           msg: &mut zmq::Message)
+                           -> Result<RegistrationEvent> {
+    while try!(sock.get_rcvmore()) {
+        try!(sock.recv(msg, 0));
+    }
+    let reply = msg.as_str().unwrap_or("").to_string();
+    if reply == "REG" {
+        return Ok(RegistrationEvent::Requested);
+    }
+    if let Some(reason) = registration_rejected(&reply) {
+        return Ok(RegistrationEvent::Rejected(reason));
+    }
+    if reply.split_whitespace().next() == Some("REGOK") {
+        return Ok(RegistrationEvent::Accepted(registered_capabilities(&reply)));
+    }
+    Ok(RegistrationEvent::Other(reply))
+}
+
+/// Answer a `RouteSrv`'s request for a server's registration on its heartbeat socket.
+pub fn send_registration(sock: &zmq::Socket,
+                         reg: &protocol::routesrv::Registration)
+                         -> Result<()> {
+    try!(sock.send_str("R", zmq::SNDMORE));
+    try!(sock.send(&try!(reg.write_to_bytes()), 0));
+    Ok(())
+}
+
+/// The capabilities a `RouteSrv` advertised in accepting a registration, which it replies to with
+/// `REGOK` followed by their bits. Routers which predate capabilities reply with `REGOK` alone and
+/// support none.
+pub fn registered_capabilities(reply: &str) -> Capabilities {
+    let mut parts = reply.split_whitespace();
+    match (parts.next(), parts.next().and_then(|bits| bits.parse::<u32>().ok())) {
+        (Some("REGOK"), Some(bits)) => Capabilities::from_bits_truncate(bits),
+        _ => Capabilities::empty(),
+    }
+}
+
+/// The reason a `RouteSrv` gave for refusing a registration, which it replies to with `REGERR`
+/// followed by the reason, or `None` if it accepted the registration.
+pub fn registration_rejected(reply: &str) -> Option<String> {
+    if reply.starts_with("REGERR") {
+        Some(reply["REGERR".len()..].trim().to_string())
+    } else {
+        None
+    }
+}
+
 #[derive(Eq, Hash)]
 pub struct ServerReg {
     /// Server identifier
@@ -228,37 +531,83 @@ pub struct ServerReg {
     pub ping_at: i64,
     /// Connection expires at this time
     pub expires: i64,
+    /// Optional behaviours the server advertised when it registered
+    pub capabilities: Capabilities,
+    /// Share of its shards' requests the server takes relative to the others serving them
+    pub weight: u32,
 }
 
 impl ServerReg {
     pub fn new(endpoint: String) -> Self {
-        let now_ms = Self::clock_time();
+        Self::with_clock(endpoint, &SystemClock)
+    }
+
+    /// Create a new registration whose ping and expiry times are relative to the given clock.
+    pub fn with_clock<C: Clock>(endpoint: String, clock: &C) -> Self {
+        let now_ms = clock.now_ms();
         ServerReg {
             endpoint: endpoint,
             alive: false,
             ping_at: now_ms + PING_INTERVAL,
             expires: now_ms + SERVER_TTL,
+            capabilities: Capabilities::empty(),
+            weight: 1,
         }
     }
 
     pub fn clock_time() -> i64 {
-        let timespec = time::get_time();
-        (timespec.sec as i64 * 1000) + (timespec.nsec as i64 / 1000 / 1000)
+        SystemClock.now_ms()
+    }
+
+    /// True if the server has not been heard from within its time to live.
+    pub fn is_expired<C: Clock>(&self, clock: &C) -> bool {
+        clock.now_ms() >= self.expires
+    }
+
+    /// True if it is time to send the server another ping.
+    pub fn ping_due<C: Clock>(&self, clock: &C) -> bool {
+        clock.now_ms() >= self.ping_at
+    }
+
+    /// Record that the server was heard from, marking it alive and extending its time to live.
+    pub fn refresh<C: Clock>(&mut self, clock: &C) {
+        self.alive = true;
+        self.expires = clock.now_ms() + SERVER_TTL;
     }
 
     pub fn ping(&mut self, socket: &mut zmq::Socket) -> Result<()> {
-        let now_ms = Self::clock_time();
-        if now_ms >= self.ping_at {
+        self.ping_with_clock(socket, &SystemClock)
+    }
+
+    pub fn ping_with_clock<C: Clock>(&mut self, socket: &mut zmq::Socket, clock: &C) -> Result<()> {
+        if self.ping_due(clock) {
             let ping = protocol::net::Ping::new();
             let req = protocol::Message::new(&ping).build();
             let bytes = try!(req.write_to_bytes());
             try!(socket.send(&bytes, 0));
-            self.ping_at = Self::clock_time() + PING_INTERVAL;
+            self.ping_at = clock.now_ms() + PING_INTERVAL;
         }
         Ok(())
     }
 }
 
+/// Pick one of the servers of a shard, each in proportion to its weight, given a uniformly
+/// distributed random number. `servers` must not be empty.
+pub fn select_weighted(servers: &[ServerReg], rand: u64) -> usize {
+    let total = servers.iter().fold(0, |total, s| total + s.weight as u64);
+    if total == 0 {
+        return (rand % servers.len() as u64) as usize;
+    }
+    let mut pick = rand % total;
+    for (i, server) in servers.iter().enumerate() {
+        if pick < server.weight as u64 {
+            return i;
+        }
+        pick -= server.weight as u64;
+    }
+    servers.len() - 1
+}
+
 impl PartialEq for ServerReg {
     fn eq(&self, other: &ServerReg) -> bool {
         if self.endpoint != other.endpoint {
@@ -273,6 +622,13 @@ pub struct RouteConn {
     pub socket: zmq::Socket,
     pub heartbeat: zmq::Socket,
     hasher: FnvHasher,
+    router_capabilities: Capabilities,
+    // RouteSrvs the heartbeat socket is connected to, and when they're next sent a ping
+    routers: usize,
+    ping_at: i64,
+    clock: SharedClock,
+    // Sent again to a RouteSrv which asks for it after registering, such as one which restarted
+    registration: Option<protocol::routesrv::Registration>,
 }
 
 impl RouteConn {
@@ -287,9 +643,25 @@ impl RouteConn {
             socket: socket,
             heartbeat: heartbeat,
             hasher: FnvHasher::default(),
+            router_capabilities: protocol::net::SUPPORTED_CAPABILITIES,
+            routers: 0,
+            ping_at: 0,
+            clock: Arc::new(SystemClock),
+            registration: None,
         })
     }
 
+    /// Schedule heartbeats and stamp routed messages with the given clock.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
+    /// Capabilities supported by this server and by every `RouteSrv` it registered with, so which
+    /// may be used in messages routed through any of them.
+    pub fn router_capabilities(&self) -> Capabilities {
+        self.router_capabilities
+    }
+
     pub fn close(&mut self) -> Result<()> {
         try!(self.socket.close());
         Ok(())
@@ -302,22 +674,98 @@ impl RouteConn {
 
     pub fn register(&mut self, addr: &str) -> Result<()> {
         try!(self.heartbeat.connect(addr));
+        self.routers += 1;
         Ok(())
     }
 
+    /// Send every `RouteSrv` this server registered with a heartbeat `Ping` reporting the load of
+    /// the process, if one is due. Call it regularly once connected, as `runtime::serve` does.
+    pub fn heartbeat(&mut self) -> Result<()> {
+        let clock = self.clock.clone();
+        self.heartbeat_with_clock(&clock)
+    }
+
+    pub fn heartbeat_with_clock<C: Clock>(&mut self, clock: &C) -> Result<()> {
+        try!(self.answer_routers());
+        if clock.now_ms() < self.ping_at {
+            return Ok(());
+        }
+        let mut ping = protocol::net::Ping::new();
+        ping.set_endpoint(self.ident.clone());
+        ping.set_load(stats::snapshot());
+        let bytes = try!(ping.write_to_bytes());
+        // The heartbeat socket deals messages to its routers in turn, so one ping per router
+        // reaches each of them. A router whose queue is full misses this round rather than
+        // blocking the server.
+        for _ in 0..self.routers {
+            match self.heartbeat.send_str("P", zmq::SNDMORE | zmq::DONTWAIT) {
+                Ok(()) => try!(self.heartbeat.send(&bytes, 0)),
+                Err(zmq::Error::EAGAIN) => debug!("skipping heartbeat ping, router queue full"),
+                Err(e) => return Err(Error::from(e)),
+            }
+        }
+        self.ping_at = clock.now_ms() + PING_INTERVAL;
+        Ok(())
+    }
+
+    // Read whatever the routers sent on the heartbeat socket since registering, so it doesn't
+    // pile up there. A router asking for the registration again is sent it; the socket deals it
+    // to its routers in turn, so with several it may first reach one which already has it, which
+    // just registers the server again.
+    fn answer_routers(&mut self) -> Result<()> {
+        let mut msg = try!(zmq::Message::new());
+        loop {
+            match self.heartbeat.recv(&mut msg, zmq::DONTWAIT) {
+                Ok(()) => (),
+                Err(zmq::Error::EAGAIN) => return Ok(()),
+                Err(e) => return Err(Error::from(e)),
+            }
+            match try!(read_registration_event(&self.heartbeat, &mut msg)) {
+                RegistrationEvent::Requested => {
+                    if let Some(ref reg) = self.registration {
+                        try!(send_registration(&self.heartbeat, reg));
+                    }
+                }
+                event => debug!("ignoring {:?} on heartbeat socket", event),
+            }
+        }
+    }
+
     pub fn recv(&mut self, flags: i32) -> Result<protocol::net::Msg> {
         let envelope = try!(self.socket.recv_msg(flags));
-        let msg: protocol::net::Msg = parse_from_bytes(&envelope).unwrap();
+        let msg: protocol::net::Msg = try!(parse_from_bytes(&envelope));
         Ok(msg)
     }
 
     pub fn route<M: Routable>(&mut self, msg: &M) -> Result<()> {
-        let route_hash = msg.route_key().map(|key| key.hash(&mut self.hasher));
-        let req = protocol::Message::new(msg).routing(route_hash).build();
-        let bytes = try!(req.write_to_bytes());
+        let bytes = try!(self.encode(msg));
         try!(self.socket.send(&bytes, 0));
         Ok(())
     }
+
+    fn encode<M: Routable>(&mut self, msg: &M) -> Result<Vec<u8>> {
+        let route_hash = msg.route_key().map(|key| key.hash(&mut self.hasher));
+        let mut req = protocol::Message::new(msg)
+            .routing(route_hash)
+            .sticky(msg.sticky())
+            .build();
+        req.set_capabilities(self.router_capabilities.bits());
+        trace::inject_current(&mut req);
+        clock::stamp(&self.clock, &mut req);
+        hmac::sign(&mut req);
+        let bytes = try!(req.write_to_bytes());
+        Ok(bytes)
+    }
+}
+
+impl RouteClient for RouteConn {
+    fn route<M: Routable>(&mut self, msg: &M) -> Result<()> {
+        RouteConn::route(self, msg)
+    }
+
+    fn recv(&mut self) -> Result<protocol::net::Msg> {
+        RouteConn::recv(self, 0)
+    }
 }
 
 impl Drop for RouteConn {
@@ -326,12 +774,100 @@ impl Drop for RouteConn {
     }
 }
 
+/// The ZeroMQ context of a process. Clones share one context, so a `Broker`, the `RouteConn` of a
+/// service, and its `Supervisor`'s workers can all create their sockets in it, and inproc
+/// addresses bound by one are reachable by the others.
+#[derive(Clone)]
+pub struct ServerContext(Arc<RwLock<zmq::Context>>);
+
+impl ServerContext {
+    pub fn new() -> Self {
+        ServerContext(Arc::new(RwLock::new(zmq::Context::new())))
+    }
+
+    /// Create a context with the IO threads and socket limit named by the given configuration.
+    /// This must happen before any socket is created in the context.
+    ///
+    /// # Errors
+    ///
+    /// * The context could not be configured
+    pub fn with_config<T: config::Sockets>(cfg: &T) -> Result<Self> {
+        let mut ctx = zmq::Context::new();
+        try!(ctx.set_io_threads(try!(config::option_int("io_threads", cfg.io_threads()))));
+        try!(ctx.set_max_sockets(try!(config::option_int("max_sockets", cfg.max_sockets()))));
+        debug::set("context", "io_threads", cfg.io_threads());
+        debug::set("context", "max_sockets", cfg.max_sockets());
+        Ok(ServerContext(Arc::new(RwLock::new(ctx))))
+    }
+
+    /// The shared context, for a `Supervisor` or `RouteConn`.
+    pub fn zmq(&self) -> Arc<RwLock<zmq::Context>> {
+        self.0.clone()
+    }
+
+    /// A `BrokerContext` sharing this context.
+    pub fn broker(&self) -> Arc<BrokerContext> {
+        Arc::new(BrokerContext::shared(self.0.clone()))
+    }
+
+    /// Create a socket in the shared context.
+    pub fn socket(&self, socket_type: zmq::SocketType) -> Result<zmq::Socket> {
+        let socket = try!(self.0.write().unwrap().socket(socket_type));
+        Ok(socket)
+    }
+}
+
+/// Replace a service's configuration and ask the `Supervisor` whose workers connect to `be_addr`
+/// to replace them, so the workers pick up the new configuration.
+///
+/// # Errors
+///
+/// * The `Supervisor` could not be asked to replace its workers
+pub fn reconfigure<C>(config: &RwLock<C>,
+                      ctx: &RwLock<zmq::Context>,
+                      be_addr: &str,
+                      new: C)
+                      -> Result<()> {
+    {
+        let mut cfg = config.write().unwrap();
+        *cfg = new;
+    }
+    ControlClient::roll(&mut ctx.write().unwrap(), be_addr)
+}
+
+/// Re-read a service's configuration file and `reconfigure` the service with it. A file which
+/// can't be read or parsed leaves the running configuration and workers as they are.
+pub fn reload<C: ConfigFile>(path: &str,
+                             config: &RwLock<C>,
+                             ctx: &RwLock<zmq::Context>,
+                             be_addr: &str) {
+    let new = match C::from_file(path) {
+        Ok(new) => new,
+        Err(e) => {
+            warn!("Keeping current configuration, cannot reload {}, err={}", path, e);
+            return;
+        }
+    };
+    match reconfigure(config, ctx, be_addr, new) {
+        Ok(()) => info!("Reloaded configuration from {}", path),
+        Err(e) => warn!("Reloaded configuration from {} but workers were not replaced, err={}",
+                        path,
+                        e),
+    }
+}
+
 pub struct Supervisor<T>
     where T: Supervisable
 {
     context: Arc<RwLock<zmq::Context>>,
     config: Arc<RwLock<T::Config>>,
     workers: Vec<mpsc::Receiver<()>>,
+    controls: Arc<RwLock<Vec<Arc<WorkerControl>>>>,
+    limits: Arc<ConcurrencyLimits>,
+    sched: SchedConfig,
+    drain_timeout_ms: i64,
+    restart: Arc<AtomicBool>,
+    shutdown: Arc<AtomicBool>,
     _marker: PhantomData<T>,
 }
 
@@ -343,19 +879,65 @@ impl<T> Supervisor<T>
             context: ctx,
             config: config,
             workers: vec![],
+            controls: Arc::new(RwLock::new(vec![])),
+            limits: Arc::new(ConcurrencyLimits::default()),
+            sched: SchedConfig::default(),
+            drain_timeout_ms: DRAIN_TIMEOUT_MS,
+            restart: Arc::new(AtomicBool::new(false)),
+            shutdown: Arc::new(AtomicBool::new(false)),
             _marker: PhantomData,
         }
     }
 
-    pub fn start(mut self, addr: &str, worker_count: usize) -> super::Result<()> {
+    /// Cap how many of this Supervisor's workers may handle each kind of message at once.
+    pub fn with_limits(mut self, limits: ConcurrencyLimits) -> Self {
+        self.limits = Arc::new(limits);
+        self
+    }
+
+    /// Pin this Supervisor's workers to CPUs and set their priority.
+    pub fn with_sched(mut self, sched: SchedConfig) -> Self {
+        self.sched = sched;
+        self
+    }
+
+    /// Wait at most the given milliseconds for a worker being replaced to drain. Defaults to
+    /// `DRAIN_TIMEOUT_MS`.
+    pub fn with_drain_timeout(mut self, timeout_ms: i64) -> Self {
+        self.drain_timeout_ms = timeout_ms;
+        self
+    }
+
+    pub fn start(self, addr: &str, worker_count: usize) -> super::Result<()> {
+        try!(self.spawn(addr, worker_count));
+        Ok(())
+    }
+
+    /// Like `start` but returns a handle for observing the workers and shutting them down.
+    pub fn spawn(mut self, addr: &str, worker_count: usize) -> super::Result<SupervisorHandle> {
         try!(self.init(addr, worker_count));
         debug!("Supervisor ready");
-        self.run(addr.to_string(), worker_count)
+        let controls = self.controls.clone();
+        let restart = self.restart.clone();
+        let shutdown = self.shutdown.clone();
+        let monitor = try!(self.run(addr.to_string()));
+        Ok(SupervisorHandle {
+            monitor: monitor,
+            controls: controls,
+            restart: restart,
+            shutdown: shutdown,
+        })
     }
 
     fn init(&mut self, addr: &str, worker_count: usize) -> super::Result<()> {
-        for _i in 0..worker_count {
-            let rx = try!(self.spawn_worker(addr.to_string()));
+        let component = readiness::instance(readiness::SUPERVISOR, addr);
+        readiness::expect(&component);
+        debug::set("supervisor", "backend", addr);
+        debug::set("supervisor", "workers", worker_count);
+        for i in 0..worker_count {
+            let control = Arc::new(WorkerControl::new());
+            self.controls.write().unwrap().push(control.clone());
+            let rx = try!(self.spawn_worker(i, addr.to_string(), control));
             self.workers.push(rx);
         }
         let mut success = 0;
@@ -363,29 +945,61 @@ impl<T> Supervisor<T>
             match self.workers[success].recv() {
                 Ok(()) => {
                     debug!("Worker {} ready", success);
+                    debug::set("supervisor", &worker_key(success), "ready");
                     success += 1;
                 }
                 Err(_) => debug!("Worker {} failed to start", success),
             }
         }
+        let sock = try!(self.context.write().unwrap().socket(zmq::REP));
+        try!(sock.bind(&control::control_addr(addr)));
+        debug::set("supervisor", "control", control::control_addr(addr));
+        try!(sock.set_rcvtimeo(control::CONTROL_POLL_MS as i32));
+        let controls = self.controls.clone();
+        let restart = self.restart.clone();
+        let shutdown = self.shutdown.clone();
+        try!(thread::Builder::new()
+            .name("supervisor-control".to_string())
+            .spawn(move || control::serve(sock, controls, restart, shutdown)));
+        readiness::ready(&component);
         Ok(())
     }
 
-    fn run(mut self, addr: String, worker_count: usize) -> super::Result<()> {
-        thread::spawn(move || {
+    fn run(mut self, addr: String) -> super::Result<JoinHandle<()>> {
+        let monitor = try!(thread::Builder::new().name("supervisor".to_string()).spawn(move || {
             loop {
-                for i in 0..worker_count {
+                if self.shutdown.load(Ordering::SeqCst) {
+                    self.stop();
+                    return;
+                }
+                if self.restart.swap(false, Ordering::SeqCst) {
+                    self.roll(&addr);
+                }
+                for i in 0..self.workers.len() {
                     match self.workers[i].try_recv() {
                         Err(mpsc::TryRecvError::Disconnected) => {
                             println!("Worker {} restarting...", i);
-                            let rx = self.spawn_worker(addr.clone()).unwrap();
+                            metrics::incr(Counter::Reconnects);
+                            debug::set("supervisor", &worker_key(i), "restarting");
+                            let control = self.controls.read().unwrap()[i].clone();
+                            // A worker which fails to restart keeps its slot, holding the
+                            // disconnected channel, and is restarted again on the next pass.
+                            let rx = match self.spawn_worker(i, addr.clone(), control) {
+                                Ok(rx) => rx,
+                                Err(e) => {
+                                    println!("Worker {} failed restart! {}", i, e);
+                                    debug::set("supervisor", &worker_key(i), "failed");
+                                    continue;
+                                }
+                            };
                             match rx.recv() {
-                                Ok(()) => self.workers[i] = rx,
+                                Ok(()) => debug::set("supervisor", &worker_key(i), "ready"),
                                 Err(_) => {
                                     println!("Worker {} failed restart!", i);
-                                    self.workers.remove(i);
+                                    debug::set("supervisor", &worker_key(i), "failed");
                                 }
                             }
+                            self.workers[i] = rx;
                         }
                         Ok(msg) => println!("Worker {} sent unexpected msg: {:?}", i, msg),
                         Err(mpsc::TryRecvError::Empty) => continue,
@@ -393,20 +1007,157 @@ impl<T> Supervisor<T>
                 }
                 thread::sleep(Duration::from_millis(500));
             }
-        });
-        Ok(())
+        }));
+        Ok(monitor)
     }
 
-    fn spawn_worker(&self, addr: String) -> super::Result<mpsc::Receiver<()>> {
+    // Replace every worker with a new one, one at a time, so the workers pick up a changed
+    // configuration without the service dropping any messages. A replacement is connected to the
+    // backend and ready before the worker it replaces is drained and stopped, so some worker is
+    // always reading messages.
+    fn roll(&mut self, addr: &str) {
+        info!("Restarting {} workers", self.workers.len());
+        for i in 0..self.workers.len() {
+            let old = self.controls.read().unwrap()[i].clone();
+            // Workers an operator paused or drained stay that way after they are replaced.
+            let control = Arc::new(WorkerControl::new());
+            match old.state() {
+                protocol::net::WorkerState::RUNNING => (),
+                protocol::net::WorkerState::PAUSED => {
+                    control.set_state(protocol::net::WorkerState::PAUSED)
+                }
+                protocol::net::WorkerState::DRAINING |
+                protocol::net::WorkerState::DRAINED => {
+                    control.set_state(protocol::net::WorkerState::DRAINED)
+                }
+            }
+            debug::set("supervisor", &worker_key(i), "restarting");
+            let rx = match self.spawn_worker(i, addr.to_string(), control.clone()) {
+                Ok(rx) => rx,
+                Err(e) => {
+                    warn!("Worker {} replacement failed to spawn, err={}", i, e);
+                    debug::set("supervisor", &worker_key(i), "ready");
+                    continue;
+                }
+            };
+            if rx.recv().is_err() {
+                warn!("Worker {} replacement failed to start, keeping current worker", i);
+                debug::set("supervisor", &worker_key(i), "ready");
+                continue;
+            }
+            self.controls.write().unwrap()[i] = control;
+            let old_rx = mem::replace(&mut self.workers[i], rx);
+            // Draining disconnects the old worker from the backend and lets it finish the messages
+            // already handed to it before it is stopped.
+            if old.state() != protocol::net::WorkerState::DRAINED {
+                old.set_state(protocol::net::WorkerState::DRAINING);
+            }
+            let deadline = SystemClock.now_ms() + self.drain_timeout_ms;
+            let mut drained = true;
+            while old.state() == protocol::net::WorkerState::DRAINING {
+                if let Err(mpsc::TryRecvError::Disconnected) = old_rx.try_recv() {
+                    break;
+                }
+                if SystemClock.now_ms() >= deadline {
+                    drained = false;
+                    break;
+                }
+                thread::sleep(Duration::from_millis(control::CONTROL_POLL_MS as u64));
+            }
+            old.stop();
+            if drained {
+                while old_rx.recv().is_ok() {}
+            } else {
+                // A worker stuck on a message would hold up every other worker's replacement, so
+                // it is left to stop on its own once the message is done.
+                warn!("Worker {} did not drain within {}ms, stopping it",
+                      i,
+                      self.drain_timeout_ms);
+            }
+            debug::set("supervisor", &worker_key(i), "ready");
+        }
+        info!("Restarted workers");
+    }
+
+    // Ask every worker to stop and wait for them to finish the message they are handling.
+    fn stop(&mut self) {
+        for control in self.controls.read().unwrap().iter() {
+            control.stop();
+        }
+        for (i, worker) in self.workers.iter().enumerate() {
+            while worker.recv().is_ok() {}
+            debug::set("supervisor", &worker_key(i), "stopped");
+        }
+        debug!("Supervisor stopped");
+    }
+
+    // Workers run on threads named after their index so their resource usage can be told apart.
+    fn spawn_worker(&self,
+                    i: usize,
+                    addr: String,
+                    control: Arc<WorkerControl>)
+                    -> super::Result<mpsc::Receiver<()>> {
         let cfg = self.config.clone();
+        let limits = self.limits.clone();
+        let sched = self.sched.clone();
         let (tx, rx) = mpsc::sync_channel(1);
-        let worker = T::new(&mut self.context.write().unwrap(), cfg);
-        thread::spawn(move || worker.start(addr, tx));
+        let mut worker = T::new(&mut self.context.write().unwrap(), cfg);
+        worker.set_broker(Arc::new(BrokerContext::shared(self.context.clone())));
+        try!(thread::Builder::new()
+            .name(format!("worker-{}", i))
+            .spawn(move || {
+                sched.apply(i);
+                worker.start(addr, tx, limits, control)
+            }));
         Ok(rx)
     }
 }
 
-pub trait Supervisable: Sized + Send {
+/// Handle to a running `Supervisor` returned by `Supervisor::spawn`.
+pub struct SupervisorHandle {
+    monitor: JoinHandle<()>,
+    controls: Arc<RwLock<Vec<Arc<WorkerControl>>>>,
+    restart: Arc<AtomicBool>,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl SupervisorHandle {
+    /// Current state of every worker.
+    pub fn workers(&self) -> Vec<protocol::net::WorkerStatus> {
+        self.controls
+            .read()
+            .unwrap()
+            .iter()
+            .enumerate()
+            .map(|(i, control)| control::worker_status(i, control))
+            .collect()
+    }
+
+    /// Ask the `Supervisor` to replace its workers one at a time, such as after a change to the
+    /// configuration they were created with. Returns immediately; the workers are replaced in the
+    /// background without dropping any messages.
+    pub fn restart_workers(&self) {
+        self.restart.store(true, Ordering::SeqCst);
+    }
+
+    /// True until the `Supervisor` has been asked to shut down.
+    pub fn is_running(&self) -> bool {
+        !self.shutdown.load(Ordering::SeqCst)
+    }
+
+    /// Ask the `Supervisor` to stop its workers. Returns immediately; use `join` to wait for the
+    /// workers to finish the messages they are handling.
+    pub fn shutdown(&self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+    }
+
+    /// Block until the `Supervisor` has shut down and all of its workers have stopped.
+    pub fn join(self) -> thread::Result<()> {
+        self.monitor.join()
+    }
+}
+
+pub trait Supervisable: HealthCheck + Sized + Send {
     type Config: Send + Sync;
     type Error: Send + From<zmq::Error>;
 
@@ -420,38 +1171,533 @@ pub trait Supervisable: Sized + Send {
 
     fn socket(&mut self) -> &mut zmq::Socket;
 
+    /// The authenticator sessions of this worker's messages are checked with before they are
+    /// handed to `on_message()`, if the worker requires them. Messages without a valid session
+    /// are answered with the `NetError` the authenticator returns and never reach the worker.
+    fn authenticator(&mut self) -> Option<&mut Authenticate> {
+        None
+    }
+
+    /// Called once after `new()` with a context for connecting to the application's `Broker`,
+    /// for workers which route requests of their own, such as an `auth::BrokerSessions`. The
+    /// application must be running a `Broker` for the connections to be answered.
+    fn set_broker(&mut self, _ctx: Arc<BrokerContext>) {}
+
+    /// The clock this worker measures queue latency and stamps its replies with.
+    fn clock(&self) -> SharedClock {
+        Arc::new(SystemClock)
+    }
+
+    /// Answer a `HealthGet` query with the results of this worker's health checks. Called by the
+    /// worker's main loop instead of `on_message()` for health queries.
+    fn reply_health(&mut self,
+                    envelope: &mut Envelope,
+                    control: &WorkerControl)
+                    -> result::Result<(), Self::Error> {
+        let mut checks = vec![health::worker_check(control), readiness::check()];
+        checks.extend(self.health_checks());
+        let report = health::report(checks);
+        debug!("{} health, status={:?}", envelope.log_context(), report.get_status());
+        if let Err(e) = envelope.reply_complete(self.socket(), &report) {
+            warn!("{} unable to reply to health query, err={}", envelope.log_context(), e);
+        }
+        Ok(())
+    }
+
     #[allow(unused_assignments)]
     fn start(mut self,
              be_addr: String,
-             rz: mpsc::SyncSender<()>)
+             rz: mpsc::SyncSender<()>,
+             limits: Arc<ConcurrencyLimits>,
+             control: Arc<WorkerControl>)
              -> result::Result<(), Self::Error> {
         try!(self.init());
-        try!(self.socket().connect(&be_addr));
+        // A restarted worker which had been drained stays disconnected until it is resumed.
+        let mut connected = false;
+        if control.state() != protocol::net::WorkerState::DRAINED {
+            try!(self.socket().connect(&be_addr));
+            connected = true;
+        }
+        let gauge = WorkerGauge::new();
         rz.send(()).unwrap();
         let mut raw = zmq::Message::new().unwrap();
         let mut envelope = Envelope::default();
+        envelope.set_clock(self.clock());
         'recv: loop {
+            if control.is_stopping() {
+                break;
+            }
+            // The worker stops, rather than failing, once the process's ZeroMQ context is being
+            // terminated.
+            match control.await_work(self.socket(), &be_addr, &mut connected) {
+                Ok(true) => (),
+                Ok(false) => continue,
+                Err(zmq::Error::ETERM) => break,
+                Err(e) => return Err(Self::Error::from(e)),
+            }
+            envelope.begin_txn();
             'hops: loop {
-                let hop = try!(self.socket().recv_msg(0));
+                let hop = match self.socket().recv_msg(0) {
+                    Ok(hop) => hop,
+                    Err(zmq::Error::ETERM) => break 'recv,
+                    Err(zmq::Error::EAGAIN) => {
+                        // Skip what remains of the message so its frames aren't read as the
+                        // start of the next one.
+                        if let Err(zmq::Error::ETERM) = drain(self.socket()) {
+                            break 'recv;
+                        }
+                        envelope.reset();
+                        continue 'recv;
+                    }
+                    Err(e) => return Err(Self::Error::from(e)),
+                };
+                if !try!(self.socket().get_rcvmore()) {
+                    warn!("{} drop message, missing message body", envelope.log_context());
+                    metrics::incr(Counter::Dropped);
+                    envelope.reset();
+                    continue 'recv;
+                }
                 if hop.len() == 0 {
                     break;
                 }
                 if envelope.add_hop(hop).is_err() {
-                    warn!("drop message, too many hops");
+                    warn!("{} drop message, too many hops", envelope.log_context());
+                    metrics::incr(Counter::Dropped);
+                    try!(drain(self.socket()));
                     envelope.reset();
-                    break 'recv;
+                    continue 'recv;
                 }
             }
-            try!(self.socket().recv(&mut raw, 0));
-            match parse_from_bytes(&raw) {
-                Ok(msg) => {
-                    envelope.msg = msg;
-                    try!(self.on_message(&mut envelope));
+            match self.socket().recv(&mut raw, 0) {
+                Ok(()) => (),
+                Err(zmq::Error::ETERM) => break,
+                Err(e) => return Err(Self::Error::from(e)),
+            }
+            metrics::incr(Counter::Received);
+            if try!(self.socket().get_rcvmore()) {
+                warn!("{} drop message, unexpected trailing frames", envelope.log_context());
+                metrics::incr(Counter::Dropped);
+                try!(drain(self.socket()));
+                envelope.reset();
+                continue;
+            }
+            match envelope.parse_frame(&raw) {
+                Ok(()) => {
+                    debug!("{} dispatching message", envelope.log_context());
+                    metrics::incr(Counter::Dispatched);
+                    let span = trace::Span::for_message(&envelope.msg);
+                    if !hmac::verify(&envelope.msg) {
+                        warn!("{} reject message, hmac verification failed",
+                              envelope.log_context());
+                        metrics::incr(Counter::Dropped);
+                        let err = protocol::net::err(protocol::net::ErrCode::ACCESS_DENIED,
+                                                     "net:hmac:0");
+                        if let Err(e) = envelope.reply_complete(self.socket(), &err) {
+                            warn!("{} unable to reply to rejected message, err={}",
+                                  envelope.log_context(),
+                                  e);
+                        }
+                        span.finish();
+                        envelope.reset();
+                        continue;
+                    }
+                    if let Some(latency) = envelope.queue_latency_ms() {
+                        metrics::observe_queue_latency_ms(latency);
+                        if let Some(skew) = clock::skew_ms(latency) {
+                            warn!("{} clock skew detected, sender is {}ms ahead",
+                                  envelope.log_context(),
+                                  skew);
+                            metrics::incr(Counter::ClockSkew);
+                        }
+                    }
+                    if envelope.message_id() == "HealthGet" {
+                        try!(self.reply_health(&mut envelope, &control));
+                        span.finish();
+                        envelope.reset();
+                        continue;
+                    }
+                    if envelope.message_id() == "DebugGet" {
+                        if let Err(e) = envelope.reply_complete(self.socket(), &debug::snapshot()) {
+                            warn!("{} unable to reply to debug query, err={}",
+                                  envelope.log_context(),
+                                  e);
+                        }
+                        span.finish();
+                        envelope.reset();
+                        continue;
+                    }
+                    if envelope.message_id() == "StatsGet" {
+                        if let Err(e) = envelope.reply_complete(self.socket(), &stats::snapshot()) {
+                            warn!("{} unable to reply to stats query, err={}",
+                                  envelope.log_context(),
+                                  e);
+                        }
+                        span.finish();
+                        envelope.reset();
+                        continue;
+                    }
+                    match chaos::inject(Point::Dispatch, envelope.message_id()) {
+                        Some(Fault::Delay(delay)) => thread::sleep(delay),
+                        Some(Fault::Drop) => {
+                            debug!("{} drop message, injected fault", envelope.log_context());
+                            span.finish();
+                            envelope.reset();
+                            continue;
+                        }
+                        Some(Fault::Reconnect) => {
+                            debug!("{} drop message and reconnect, injected fault",
+                                   envelope.log_context());
+                            try!(self.socket().disconnect(&be_addr));
+                            try!(self.socket().connect(&be_addr));
+                            span.finish();
+                            envelope.reset();
+                            continue;
+                        }
+                        None => (),
+                    }
+                    let denied = match self.authenticator() {
+                        Some(auth) => {
+                            match auth.authenticate(&envelope.msg) {
+                                Ok(session) => {
+                                    envelope.set_session(session);
+                                    None
+                                }
+                                Err(err) => Some(err),
+                            }
+                        }
+                        None => None,
+                    };
+                    if let Some(err) = denied {
+                        debug!("{} reject message, {}", envelope.log_context(), err.get_msg());
+                        metrics::incr(Counter::Dropped);
+                        if let Err(e) = envelope.reply_complete(self.socket(), &err) {
+                            warn!("{} unable to reply to rejected message, err={}",
+                                  envelope.log_context(),
+                                  e);
+                        }
+                        span.finish();
+                        envelope.reset();
+                        continue;
+                    }
+                    let permit = match ConcurrencyLimits::acquire(&limits, envelope.message_id()) {
+                        Some(permit) => permit,
+                        None => {
+                            warn!("{} reject message, concurrency limit reached",
+                                  envelope.log_context());
+                            metrics::incr(Counter::Dropped);
+                            let err = protocol::net::err(protocol::net::ErrCode::BUSY,
+                                                         "net:busy:0");
+                            if let Err(e) = envelope.reply_complete(self.socket(), &err) {
+                                warn!("{} unable to reply to rejected message, err={}",
+                                      envelope.log_context(),
+                                      e);
+                            }
+                            span.finish();
+                            envelope.reset();
+                            continue;
+                        }
+                    };
+                    let busy = gauge.busy();
+                    let sample = usage::Sample::now();
+                    let result = self.on_message(&mut envelope);
+                    usage::record(envelope.message_id(), &sample);
+                    control.record_handled();
+                    drop(permit);
+                    drop(busy);
+                    if let Err(e) = result {
+                        metrics::incr(Counter::Errors);
+                        return Err(e);
+                    }
+                    span.finish();
+                }
+                Err(e) => {
+                    warn!("{} erorr parsing message, err={:?}", envelope.log_context(), e);
+                    metrics::incr(Counter::Errors);
                 }
-                Err(e) => warn!("erorr parsing message, err={:?}", e),
             }
             envelope.reset();
         }
         Ok(())
     }
 }
+
+fn worker_key(i: usize) -> String {
+    format!("worker.{}", i)
+}
+
+/// Receive and discard the remaining frames of a multipart message.
+fn drain(sock: &mut zmq::Socket) -> result::Result<(), zmq::Error> {
+    while try!(sock.get_rcvmore()) {
+        try!(sock.recv_msg(0));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use protobuf::{parse_from_bytes, Message};
+    use protocol;
+    use zmq;
+
+    use super::*;
+    use clock::ManualClock;
+    use error::Error;
+
+    // Deterministic xorshift byte generator for fuzzing parsers.
+    fn garbage(seed: &mut u64, max: usize) -> Vec<u8> {
+        let mut next = || {
+            *seed ^= *seed << 13;
+            *seed ^= *seed >> 7;
+            *seed ^= *seed << 17;
+            *seed
+        };
+        let len = (next() as usize) % (max + 1);
+        (0..len).map(|_| next() as u8).collect()
+    }
+
+    #[test]
+    fn msg_parsing_never_panics() {
+        let mut seed = 0x9e3779b97f4a7c15;
+        for _ in 0..10_000 {
+            let bytes = garbage(&mut seed, 256);
+            let _ = parse_from_bytes::<protocol::net::Msg>(&bytes);
+        }
+    }
+
+    #[test]
+    fn envelope_parse_msg_returns_errors() {
+        let mut seed = 0xda942042e4dd58b5;
+        let mut envelope = Envelope::default();
+        for _ in 0..10_000 {
+            envelope.msg.set_body(garbage(&mut seed, 256));
+            let _ = envelope.parse_msg::<protocol::routesrv::Registration>();
+        }
+        // A truncated length-delimited field can never parse.
+        envelope.msg.set_body(vec![0x0a, 0x10, 0x01]);
+        assert!(envelope.parse_msg::<protocol::routesrv::Registration>().is_err());
+    }
+
+    #[test]
+    fn registered_capabilities_from_reply() {
+        assert!(registered_capabilities("REGOK").is_empty());
+        assert!(registered_capabilities("").is_empty());
+        assert!(registered_capabilities("REGOK junk").is_empty());
+        let bits = (protocol::net::TRACING | protocol::net::COMPRESSION).bits();
+        assert_eq!(registered_capabilities(&format!("REGOK {}", bits)),
+                   protocol::net::TRACING | protocol::net::COMPRESSION);
+    }
+
+    #[test]
+    fn registration_events_are_read_whole() {
+        let mut ctx = zmq::Context::new();
+        let router = ctx.socket(zmq::ROUTER).unwrap();
+        router.bind("inproc://registration-events").unwrap();
+        let dealer = ctx.socket(zmq::DEALER).unwrap();
+        dealer.set_identity(b"hb#test").unwrap();
+        dealer.connect("inproc://registration-events").unwrap();
+        // The router can only address the dealer once it has heard from it.
+        dealer.send(&[], 0).unwrap();
+        let mut msg = zmq::Message::new().unwrap();
+        router.recv(&mut msg, 0).unwrap();
+        router.recv(&mut msg, 0).unwrap();
+        for reply in &["REG", "REGOK 4", "REGERR schema mismatch", "pong"] {
+            router.send(b"hb#test", zmq::SNDMORE).unwrap();
+            router.send(&[], zmq::SNDMORE).unwrap();
+            router.send_str(reply, 0).unwrap();
+        }
+        let mut events = vec![];
+        for _ in 0..4 {
+            events.push(recv_registration_event(&dealer, &mut msg).unwrap());
+        }
+        let capabilities = protocol::net::Capabilities::from_bits_truncate(4);
+        assert_eq!(events,
+                   vec![RegistrationEvent::Requested,
+                        RegistrationEvent::Accepted(capabilities),
+                        RegistrationEvent::Rejected("schema mismatch".to_string()),
+                        RegistrationEvent::Other("pong".to_string())]);
+    }
+
+    #[test]
+    fn registration_rejected_from_reply() {
+        assert_eq!(registration_rejected("REGOK 4"), None);
+        assert_eq!(registration_rejected("REGERR schema mismatch, net.Ping"),
+                   Some("schema mismatch, net.Ping".to_string()));
+    }
+
+    #[test]
+    fn envelope_reuses_storage_between_frames() {
+        let mut envelope = Envelope::default();
+        let mut msg = protocol::net::Msg::new();
+        msg.set_message_id("Ping".to_string());
+        msg.set_body(vec![1; 64]);
+        envelope.parse_frame(&msg.write_to_bytes().unwrap()).unwrap();
+        let body = envelope.body().as_ptr();
+        envelope.reset();
+        msg.set_message_id("Pong".to_string());
+        msg.set_body(vec![2; 64]);
+        envelope.parse_frame(&msg.write_to_bytes().unwrap()).unwrap();
+        assert_eq!(envelope.message_id(), "Pong");
+        assert_eq!(envelope.body(), &[2; 64][..]);
+        assert_eq!(envelope.body().as_ptr(), body);
+    }
+
+    #[test]
+    fn envelope_parse_frame_clears_previous_message() {
+        let mut envelope = Envelope::default();
+        let mut msg = protocol::net::Msg::new();
+        msg.set_message_id("Ping".to_string());
+        msg.set_correlation_id(7);
+        envelope.parse_frame(&msg.write_to_bytes().unwrap()).unwrap();
+        msg.clear_correlation_id();
+        envelope.parse_frame(&msg.write_to_bytes().unwrap()).unwrap();
+        assert!(!envelope.msg.has_correlation_id());
+        assert!(envelope.parse_frame(&[0x0a, 0x10, 0x01]).is_err());
+    }
+
+    #[test]
+    fn envelope_rejects_too_many_hops() {
+        let mut envelope = Envelope::default();
+        for _ in 0..MAX_HOPS {
+            assert!(envelope.add_hop(zmq::Message::from_slice(b"hop").unwrap()).is_ok());
+        }
+        match envelope.add_hop(zmq::Message::from_slice(b"hop").unwrap()) {
+            Err(Error::MaxHops) => (),
+            _ => panic!("expected MaxHops error"),
+        }
+    }
+
+    #[test]
+    fn envelope_follows_its_clock() {
+        let clock = Arc::new(ManualClock::new(1000));
+        let mut envelope = Envelope::default();
+        envelope.set_clock(clock.clone());
+        clock::stamp(&clock, &mut envelope.msg);
+        clock.advance(250);
+        assert_eq!(envelope.queue_latency_ms(), Some(250));
+        envelope.encode_reply(&protocol::net::Ping::new()).unwrap();
+        let rep = parse_from_bytes::<protocol::net::Msg>(&envelope.buf).unwrap();
+        assert_eq!(rep.get_sent_at_ms(), 1250);
+    }
+
+    #[test]
+    fn routed_messages_carry_the_capabilities_of_every_router() {
+        let mut ctx = zmq::Context::new();
+        let mut conn = RouteConn::new("srv".to_string(), &mut ctx).unwrap();
+        conn.router_capabilities = protocol::net::Capabilities::empty();
+        let bytes = conn.encode(&protocol::sessionsrv::SessionGet::new()).unwrap();
+        let req = parse_from_bytes::<protocol::net::Msg>(&bytes).unwrap();
+        assert!(req.has_capabilities());
+        assert!(req.capabilities().is_empty());
+    }
+
+    #[test]
+    fn replies_carry_the_negotiated_capabilities() {
+        let mut envelope = Envelope::default();
+        envelope.encode_reply(&protocol::net::Ping::new()).unwrap();
+        let rep = parse_from_bytes::<protocol::net::Msg>(&envelope.buf).unwrap();
+        assert_eq!(rep.capabilities(), negotiated_capabilities());
+    }
+
+    #[test]
+    fn server_reg_expires_after_ttl() {
+        let clock = ManualClock::new(0);
+        let reg = ServerReg::with_clock("srv".to_string(), &clock);
+        clock.advance(SERVER_TTL - 1);
+        assert!(!reg.is_expired(&clock));
+        clock.advance(1);
+        assert!(reg.is_expired(&clock));
+    }
+
+    #[test]
+    fn server_reg_refresh_extends_ttl() {
+        let clock = ManualClock::new(0);
+        let mut reg = ServerReg::with_clock("srv".to_string(), &clock);
+        clock.advance(SERVER_TTL - 1);
+        reg.refresh(&clock);
+        assert!(reg.alive);
+        clock.advance(SERVER_TTL - 1);
+        assert!(!reg.is_expired(&clock));
+    }
+
+    #[test]
+    fn server_reg_ping_due_after_interval() {
+        let clock = ManualClock::new(1000);
+        let reg = ServerReg::with_clock("srv".to_string(), &clock);
+        assert!(!reg.ping_due(&clock));
+        clock.set(1000 + PING_INTERVAL);
+        assert!(reg.ping_due(&clock));
+    }
+
+    #[test]
+    fn select_weighted_in_proportion_to_weight() {
+        let mut light = ServerReg::new("light".to_string());
+        let mut heavy = ServerReg::new("heavy".to_string());
+        light.weight = 1;
+        heavy.weight = 3;
+        let servers = vec![light, heavy];
+        let picks: Vec<usize> = (0..8).map(|n| select_weighted(&servers, n)).collect();
+        assert_eq!(picks, vec![0, 1, 1, 1, 0, 1, 1, 1]);
+    }
+
+    #[test]
+    fn route_conn_heartbeat_reports_load() {
+        let clock = ManualClock::new(1000);
+        let mut ctx = zmq::Context::new();
+        let mut router = ctx.socket(zmq::ROUTER).unwrap();
+        router.bind("inproc://route-conn-heartbeat").unwrap();
+        let mut conn = RouteConn::new("loaded".to_string(), &mut ctx).unwrap();
+        conn.register("inproc://route-conn-heartbeat").unwrap();
+        conn.heartbeat_with_clock(&clock).unwrap();
+        assert_eq!(&router.recv_msg(0).unwrap()[..], b"hb#loaded");
+        // The probe sent on connecting
+        assert_eq!(&router.recv_msg(0).unwrap()[..], b"");
+        assert_eq!(&router.recv_msg(0).unwrap()[..], b"hb#loaded");
+        assert_eq!(&router.recv_msg(0).unwrap()[..], b"P");
+        let ping = parse_from_bytes::<protocol::net::Ping>(&router.recv_msg(0).unwrap()).unwrap();
+        assert_eq!(ping.get_endpoint(), "loaded");
+        assert!(ping.has_load());
+        clock.advance(PING_INTERVAL - 1);
+        conn.heartbeat_with_clock(&clock).unwrap();
+        assert!(router.recv_msg(zmq::DONTWAIT).is_err());
+        clock.advance(1);
+        conn.heartbeat_with_clock(&clock).unwrap();
+        assert_eq!(&router.recv_msg(0).unwrap()[..], b"hb#loaded");
+        assert_eq!(&router.recv_msg(0).unwrap()[..], b"P");
+    }
+
+    #[test]
+    fn route_conn_heartbeat_answers_routers() {
+        let clock = ManualClock::new(1000);
+        let mut ctx = zmq::Context::new();
+        let mut router = ctx.socket(zmq::ROUTER).unwrap();
+        router.bind("inproc://route-conn-reregister").unwrap();
+        let mut conn = RouteConn::new("srv".to_string(), &mut ctx).unwrap();
+        let mut reg = protocol::routesrv::Registration::new();
+        reg.set_endpoint("srv".to_string());
+        conn.registration = Some(reg);
+        conn.register("inproc://route-conn-reregister").unwrap();
+        // The probe sent on connecting
+        assert_eq!(&router.recv_msg(0).unwrap()[..], b"hb#srv");
+        assert_eq!(&router.recv_msg(0).unwrap()[..], b"");
+        for reply in &["REGOK 4", "REG"] {
+            router.send(b"hb#srv", zmq::SNDMORE).unwrap();
+            router.send(&[], zmq::SNDMORE).unwrap();
+            router.send_str(reply, 0).unwrap();
+        }
+        {
+            let mut items = [conn.heartbeat.as_poll_item(zmq::POLLIN)];
+            zmq::poll(&mut items, 1000).unwrap();
+        }
+        conn.heartbeat_with_clock(&clock).unwrap();
+        assert_eq!(&router.recv_msg(0).unwrap()[..], b"hb#srv");
+        assert_eq!(&router.recv_msg(0).unwrap()[..], b"R");
+        let frame = router.recv_msg(0).unwrap();
+        let reg = parse_from_bytes::<protocol::routesrv::Registration>(&frame).unwrap();
+        assert_eq!(reg.get_endpoint(), "srv");
+        // Followed by the ping due
+        assert_eq!(&router.recv_msg(0).unwrap()[..], b"hb#srv");
+        assert_eq!(&router.recv_msg(0).unwrap()[..], b"P");
+        router.recv_msg(0).unwrap();
+        assert!(conn.heartbeat.recv_msg(zmq::DONTWAIT).is_err());
+    }
+}
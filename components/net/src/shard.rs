@@ -0,0 +1,149 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! State kept separately for each shard a service owns.
+//!
+//! A `ShardMap` holds one value, such as a cache, per owned shard behind its own lock, so workers
+//! handling messages for different shards never wait on each other. A message's shard is the one
+//! the router picked for it from its route hash. When the shards a service owns change, state is
+//! created for each shard gained and handed back for each shard given up, so it can be flushed
+//! before it is dropped.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+
+use protocol::net::RouteInfo;
+use protocol::sharding::{ShardId, SHARD_COUNT};
+
+use config;
+use server::Envelope;
+
+/// The shard a message was routed to, or `None` for a message without a route hash, which the
+/// router sends to a shard picked at random.
+pub fn shard_id(route_info: &RouteInfo) -> Option<ShardId> {
+    match route_info.get_hash() {
+        0 => None,
+        hash => Some((hash % SHARD_COUNT as u64) as ShardId),
+    }
+}
+
+/// Per-shard state shared by all workers of a Supervisor.
+pub struct ShardMap<T> {
+    shards: RwLock<HashMap<ShardId, Arc<Mutex<T>>>>,
+    init: Box<Fn(ShardId) -> T + Send + Sync>,
+}
+
+impl<T> ShardMap<T> {
+    /// Create state with `init` for each of the given shards, and for each shard owned later.
+    pub fn new<F>(owned: &[ShardId], init: F) -> Self
+        where F: Fn(ShardId) -> T + Send + Sync + 'static
+    {
+        let shards = owned.iter().map(|s| (*s, Arc::new(Mutex::new(init(*s))))).collect();
+        ShardMap {
+            shards: RwLock::new(shards),
+            init: Box::new(init),
+        }
+    }
+
+    /// Create state for each of the shards in a service's configuration.
+    pub fn from_config<C, F>(cfg: &C, init: F) -> Self
+        where C: config::Shards,
+              F: Fn(ShardId) -> T + Send + Sync + 'static
+    {
+        Self::new(cfg.shards(), init)
+    }
+
+    /// The state of a shard, if it is owned.
+    pub fn get(&self, shard: ShardId) -> Option<Arc<Mutex<T>>> {
+        self.shards.read().unwrap().get(&shard).cloned()
+    }
+
+    /// The state of the shard a message was routed to, if it has one and it is owned.
+    pub fn for_envelope(&self, envelope: &Envelope) -> Option<Arc<Mutex<T>>> {
+        shard_id(envelope.route_info()).and_then(|s| self.get(s))
+    }
+
+    /// Run `f` with the state of a shard locked. Returns `None` if the shard isn't owned.
+    pub fn with<F, R>(&self, shard: ShardId, f: F) -> Option<R>
+        where F: FnOnce(&mut T) -> R
+    {
+        self.get(shard).map(|state| f(&mut *state.lock().unwrap()))
+    }
+
+    /// The owned shards, in order.
+    pub fn owned(&self) -> Vec<ShardId> {
+        let mut owned: Vec<ShardId> = self.shards.read().unwrap().keys().cloned().collect();
+        owned.sort();
+        owned
+    }
+
+    /// Change the owned shards to the given ones. State is created for each shard gained and the
+    /// state of each shard given up is returned, for the caller to flush or drop. A handler still
+    /// holding the state of a shard given up keeps it until it is done.
+    pub fn set_owned(&self, owned: &[ShardId]) -> Vec<(ShardId, Arc<Mutex<T>>)> {
+        let mut shards = self.shards.write().unwrap();
+        let released: Vec<ShardId> =
+            shards.keys().filter(|s| !owned.contains(*s)).cloned().collect();
+        for shard in owned.iter() {
+            if !shards.contains_key(shard) {
+                shards.insert(*shard, Arc::new(Mutex::new((self.init)(*shard))));
+            }
+        }
+        released.into_iter().map(|s| (s, shards.remove(&s).unwrap())).collect()
+    }
+
+    /// Like `set_owned` with the shards in a service's configuration, such as after it is
+    /// reloaded.
+    pub fn sync<C: config::Shards>(&self, cfg: &C) -> Vec<(ShardId, Arc<Mutex<T>>)> {
+        self.set_owned(cfg.shards())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use protocol::net::RouteInfo;
+    use protocol::sharding::SHARD_COUNT;
+
+    use super::*;
+
+    #[test]
+    fn shard_id_is_route_hash_modulo_shard_count() {
+        let mut route_info = RouteInfo::new();
+        assert_eq!(shard_id(&route_info), None);
+        route_info.set_hash(SHARD_COUNT as u64 + 5);
+        assert_eq!(shard_id(&route_info), Some(5));
+    }
+
+    #[test]
+    fn state_is_kept_per_owned_shard() {
+        let map = ShardMap::new(&[1, 2], |shard| vec![shard]);
+        assert_eq!(map.with(1, |s| s.clone()), Some(vec![1]));
+        map.with(2, |s| s.push(20));
+        assert_eq!(map.with(2, |s| s.clone()), Some(vec![2, 20]));
+        assert_eq!(map.with(3, |s| s.clone()), None);
+    }
+
+    #[test]
+    fn set_owned_creates_gained_and_returns_released() {
+        let map = ShardMap::new(&[1, 2], |shard| shard * 10);
+        map.with(1, |s| *s += 1);
+        let released = map.set_owned(&[1, 3]);
+        assert_eq!(map.owned(), vec![1, 3]);
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].0, 2);
+        assert_eq!(*released[0].1.lock().unwrap(), 20);
+        assert_eq!(map.with(1, |s| *s), Some(11));
+        assert_eq!(map.with(3, |s| *s), Some(30));
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Uniform load statistics answered by every net-based service.
+//!
+//! Every `Supervisable` worker answers `StatsGet` messages with a `Stats` snapshot of the whole
+//! process without any service specific code: how many workers it runs and how many are busy,
+//! an estimate of how many messages are queued behind them, and its message counters. External
+//! pollers can send one, built with `request()`, to any service to monitor it.
+
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+
+use protocol;
+use protocol::net::{Protocol, Stats, StatsGet};
+
+use metrics::{self, Counter};
+
+static WORKERS: AtomicUsize = ATOMIC_USIZE_INIT;
+static BUSY_WORKERS: AtomicUsize = ATOMIC_USIZE_INIT;
+
+/// Counts a worker as running for as long as it is held.
+pub struct WorkerGauge(());
+
+impl WorkerGauge {
+    pub fn new() -> Self {
+        WORKERS.fetch_add(1, Ordering::SeqCst);
+        WorkerGauge(())
+    }
+
+    /// Count the worker as busy for as long as the returned gauge is held.
+    pub fn busy(&self) -> BusyGauge {
+        BUSY_WORKERS.fetch_add(1, Ordering::SeqCst);
+        BusyGauge(())
+    }
+}
+
+impl Drop for WorkerGauge {
+    fn drop(&mut self) {
+        WORKERS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Counts a worker as busy until dropped.
+pub struct BusyGauge(());
+
+impl Drop for BusyGauge {
+    fn drop(&mut self) {
+        BUSY_WORKERS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Number of workers running in this process.
+pub fn workers() -> usize {
+    WORKERS.load(Ordering::SeqCst)
+}
+
+/// Number of workers in this process handling a message right now.
+pub fn busy_workers() -> usize {
+    BUSY_WORKERS.load(Ordering::SeqCst)
+}
+
+/// Current statistics of this process.
+pub fn snapshot() -> Stats {
+    let mut stats = Stats::new();
+    stats.set_workers(workers() as u32);
+    stats.set_busy_workers(busy_workers() as u32);
+    stats.set_queue_depth(metrics::in_flight());
+    stats.set_queue_latency_ms(metrics::queue_latency().quantile(0.99));
+    stats.set_received(metrics::get(Counter::Received));
+    stats.set_dispatched(metrics::get(Counter::Dispatched));
+    stats.set_errors(metrics::get(Counter::Errors));
+    stats.set_dropped(metrics::get(Counter::Dropped));
+    stats
+}
+
+/// Build a stats query for the given protocol. Send it with `BrokerConn::route_msg`.
+pub fn request(protocol: Protocol, route_hash: Option<u64>) -> protocol::net::Msg {
+    let req = StatsGet::new();
+    let mut msg = protocol::Message::new(&req).routing(route_hash).build();
+    msg.mut_route_info().set_protocol(protocol);
+    msg
+}
+
@@ -0,0 +1,740 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! In-memory stand-ins for a `RouteSrv` and its connections for unit testing.
+//!
+//! A `FakeRouter` binds a socket to a ZeroMQ inproc address and starts a `Supervisable` worker
+//! connected to it, exactly as a service's `Supervisor` would. Tests inject protocol messages
+//! into the worker and capture the replies it sends back without running a real router, a
+//! network, or docker.
+//!
+//! A `MockRouteClient` takes the place of a `BrokerConn` or `RouteConn` in code written against
+//! the `RouteClient` trait. It records every message routed through it and answers with
+//! responses scripted by the test.
+//!
+//! ```ignore
+//! let mut router = FakeRouter::new().unwrap();
+//! router.start::<Worker>(config).unwrap();
+//! router.route(&request).unwrap();
+//! let reply = router.recv().unwrap();
+//! assert_eq!(reply.message_id(), "Session");
+//! ```
+//!
+//! ```ignore
+//! let mut conn = MockRouteClient::new();
+//! conn.respond(&session);
+//! let reply = session_get(&mut conn, "token").unwrap();
+//! assert_eq!(conn.routed()[0].get_message_id(), "SessionGet");
+//! ```
+
+use std::collections::VecDeque;
+use std::sync::{mpsc, Arc, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use std::thread::{self, JoinHandle};
+
+use fnv::FnvHasher;
+use protobuf::{self, parse_from_bytes, Message};
+use protocol::{self, Routable, RouteKey};
+use zmq;
+
+use clock::{self, SharedClock, SystemClock};
+use codec::Header;
+use concurrency::ConcurrencyLimits;
+use control::WorkerControl;
+use error::{Error, Result};
+use hmac;
+use routing::{RouteClient, RECV_TIMEOUT_MS, SEND_TIMEOUT_MS};
+use server::Supervisable;
+use trace;
+
+/// Identity of the client hop attached to every message injected by a `FakeRouter`.
+pub const CLIENT_IDENT: &'static str = "fake-client";
+
+static NEXT_ROUTER: AtomicUsize = ATOMIC_USIZE_INIT;
+
+/// A reply captured from a worker by a `FakeRouter`.
+#[derive(Debug)]
+pub struct Reply {
+    /// Network hops the worker addressed the reply to
+    pub hops: Vec<Vec<u8>>,
+    /// Messages contained in the reply, in the order they were sent
+    pub msgs: Vec<protocol::net::Msg>,
+    /// True if the worker forwarded the message on to another service instead of replying
+    pub forwarded: bool,
+}
+
+impl Reply {
+    /// Message identifier of the first message in the reply.
+    pub fn message_id(&self) -> &str {
+        self.msgs[0].get_message_id()
+    }
+
+    /// Parse the body of the first message in the reply into the given protocol message.
+    ///
+    /// # Errors
+    ///
+    /// * The body could not be parsed into the given message
+    pub fn parse<M: protobuf::MessageStatic>(&self) -> Result<M> {
+        let msg: M = try!(parse_from_bytes(self.msgs[0].get_body()));
+        Ok(msg)
+    }
+}
+
+/// A fake `RouteSrv` which feeds messages to, and captures replies from, in-process workers.
+pub struct FakeRouter {
+    // Declared before the context so it is closed before the context is terminated.
+    sock: zmq::Socket,
+    ctx: zmq::Context,
+    addr: String,
+    hasher: FnvHasher,
+    clock: SharedClock,
+    workers: Vec<(Arc<WorkerControl>, JoinHandle<()>)>,
+}
+
+impl FakeRouter {
+    /// Create a new `FakeRouter` bound to a unique inproc address.
+    ///
+    /// # Errors
+    ///
+    /// * A socket cannot be created, configured, or bound
+    pub fn new() -> Result<Self> {
+        let mut ctx = zmq::Context::new();
+        let sock = try!(ctx.socket(zmq::DEALER));
+        try!(sock.set_rcvtimeo(RECV_TIMEOUT_MS));
+        try!(sock.set_sndtimeo(SEND_TIMEOUT_MS));
+        let addr = format!("inproc://fake-router-{}",
+                           NEXT_ROUTER.fetch_add(1, Ordering::SeqCst));
+        try!(sock.bind(&addr));
+        Ok(FakeRouter {
+            sock: sock,
+            ctx: ctx,
+            addr: addr,
+            hasher: FnvHasher::default(),
+            clock: Arc::new(SystemClock),
+            workers: vec![],
+        })
+    }
+
+    /// Stamp routed messages with the given clock.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
+    /// Inproc address workers of this router connect to.
+    pub fn addr(&self) -> &str {
+        &self.addr
+    }
+
+    /// Create a worker with the given configuration and run it in a separate thread. This
+    /// function will block the calling thread until the worker has finished initializing.
+    ///
+    /// Workers stop when the `FakeRouter` is dropped.
+    ///
+    /// # Panics
+    ///
+    /// * Worker crashed during startup
+    pub fn start<T>(&mut self, config: Arc<RwLock<T::Config>>) -> Result<()>
+        where T: Supervisable + 'static
+    {
+        self.start_with_limits::<T>(config, ConcurrencyLimits::default())
+    }
+
+    /// Like `start` but the worker enforces the given concurrency limits.
+    ///
+    /// # Panics
+    ///
+    /// * Worker crashed during startup
+    pub fn start_with_limits<T>(&mut self,
+                                config: Arc<RwLock<T::Config>>,
+                                limits: ConcurrencyLimits)
+                                -> Result<()>
+        where T: Supervisable + 'static
+    {
+        let (tx, rx) = mpsc::sync_channel(1);
+        let limits = Arc::new(limits);
+        let worker = T::new(&mut self.ctx, config);
+        let addr = self.addr.clone();
+        let control = Arc::new(WorkerControl::new());
+        let worker_control = control.clone();
+        let handle = thread::Builder::new()
+            .name(format!("fake-worker-{}", self.workers.len()))
+            .spawn(move || {
+                let _ = worker.start(addr, tx, limits, worker_control);
+            })
+            .unwrap();
+        match rx.recv() {
+            Ok(()) => {
+                self.workers.push((control, handle));
+                Ok(())
+            }
+            Err(e) => panic!("fake-worker thread startup error, err={}", e),
+        }
+    }
+
+    /// Route a protocol message to a connected worker as if it had been sent by a client through
+    /// a `RouteSrv`.
+    ///
+    /// # Errors
+    ///
+    /// * One or more message frames cannot be sent to the worker
+    pub fn route<M: Routable>(&mut self, msg: &M) -> Result<()> {
+        let route_hash = msg.route_key().map(|key| key.hash(&mut self.hasher));
+        let mut req = protocol::Message::new(msg)
+            .routing(route_hash)
+            .sticky(msg.sticky())
+            .build();
+        trace::inject_current(&mut req);
+        clock::stamp(&self.clock, &mut req);
+        hmac::sign(&mut req);
+        self.send(&req)
+    }
+
+    /// Send an already built network message to a connected worker.
+    ///
+    /// # Errors
+    ///
+    /// * One or more message frames cannot be sent to the worker
+    pub fn send(&mut self, msg: &protocol::net::Msg) -> Result<()> {
+        let bytes = try!(msg.write_to_bytes());
+        try!(self.sock.send_str(CLIENT_IDENT, zmq::SNDMORE));
+        try!(self.sock.send(&[], zmq::SNDMORE));
+        try!(self.sock.send(&bytes, 0));
+        Ok(())
+    }
+
+    /// Send raw frames to a connected worker as a single multipart message. Useful for checking
+    /// how a worker copes with malformed input.
+    ///
+    /// # Errors
+    ///
+    /// * One or more message frames cannot be sent to the worker
+    pub fn send_frames(&mut self, frames: &[&[u8]]) -> Result<()> {
+        for (i, frame) in frames.iter().enumerate() {
+            let flags = if i + 1 < frames.len() { zmq::SNDMORE } else { 0 };
+            try!(self.sock.send(frame, flags));
+        }
+        Ok(())
+    }
+
+    /// Receives the next reply sent, or message forwarded, by a worker. This function will block
+    /// the calling thread until a reply is received or a timeout occurs.
+    ///
+    /// # Errors
+    ///
+    /// * Reply was not received within the timeout
+    /// * Reply was not addressed back through the router
+    /// * Received an unparsable message
+    pub fn recv(&mut self) -> Result<Reply> {
+        let mut hops = vec![];
+        loop {
+            let hop = try!(self.sock.recv_msg(0));
+            if hop.len() == 0 {
+                break;
+            }
+            hops.push(hop.to_vec());
+        }
+        let header = try!(self.sock.recv_msg(0));
+        let forwarded = match Header::from_bytes(&header) {
+            Some(Header::Reply) => false,
+            Some(Header::Forward) => true,
+            _ => return Err(Error::MalformedReply),
+        };
+        let mut msgs = vec![];
+        loop {
+            let frame = try!(self.sock.recv_msg(0));
+            let msg: protocol::net::Msg = try!(parse_from_bytes(&frame));
+            msgs.push(msg);
+            if !try!(self.sock.get_rcvmore()) {
+                break;
+            }
+        }
+        Ok(Reply {
+            hops: hops,
+            msgs: msgs,
+            forwarded: forwarded,
+        })
+    }
+}
+
+impl Drop for FakeRouter {
+    // Workers hold sockets of the router's context, which can't be terminated until they are
+    // closed, so every worker is stopped and joined before the context is dropped.
+    fn drop(&mut self) {
+        for &(ref control, _) in self.workers.iter() {
+            control.stop();
+        }
+        for (_, handle) in self.workers.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// A `RouteClient` which records routed messages and replies with scripted responses.
+pub struct MockRouteClient {
+    hasher: FnvHasher,
+    routed: Vec<protocol::net::Msg>,
+    responses: VecDeque<Result<protocol::net::Msg>>,
+}
+
+impl MockRouteClient {
+    pub fn new() -> Self {
+        MockRouteClient {
+            hasher: FnvHasher::default(),
+            routed: vec![],
+            responses: VecDeque::new(),
+        }
+    }
+
+    /// Queue a protocol message to be returned by the next unanswered call to `recv()`.
+    pub fn respond<M: Message>(&mut self, msg: &M) {
+        let rep = protocol::Message::new(msg).build();
+        self.responses.push_back(Ok(rep));
+    }
+
+    /// Queue an error to be returned by the next unanswered call to `recv()`.
+    pub fn fail(&mut self, err: Error) {
+        self.responses.push_back(Err(err));
+    }
+
+    /// Messages routed through this client, in the order they were sent.
+    pub fn routed(&self) -> &Vec<protocol::net::Msg> {
+        &self.routed
+    }
+}
+
+impl RouteClient for MockRouteClient {
+    fn route<M: Routable>(&mut self, msg: &M) -> Result<()> {
+        let route_hash = msg.route_key().map(|key| key.hash(&mut self.hasher));
+        let mut req = protocol::Message::new(msg)
+            .routing(route_hash)
+            .sticky(msg.sticky())
+            .build();
+        trace::inject_current(&mut req);
+        self.routed.push(req);
+        Ok(())
+    }
+
+    /// Returns the next scripted response. A receive timeout is returned once all responses have
+    /// been consumed.
+    fn recv(&mut self) -> Result<protocol::net::Msg> {
+        match self.responses.pop_front() {
+            Some(response) => response,
+            None => Err(Error::Timeout),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::{Arc, RwLock};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use protobuf::Message;
+    use protocol;
+    use zmq;
+
+    use chaos;
+    use clock;
+    use concurrency::ConcurrencyLimits;
+    use control::{ControlClient, WorkerControl};
+    use error::{Error, Result};
+    use health::{self, HealthCheck};
+    use hmac;
+    use server::{self, Envelope, Supervisable, Supervisor};
+    use super::*;
+
+    // Replies to every `Ping`, forwards every `Pong`, and ignores everything else.
+    struct PingWorker {
+        sock: zmq::Socket,
+    }
+
+    impl Supervisable for PingWorker {
+        type Config = ();
+        type Error = Error;
+
+        fn new(context: &mut zmq::Context, _config: Arc<RwLock<()>>) -> Self {
+            PingWorker { sock: context.socket(zmq::DEALER).unwrap() }
+        }
+
+        fn on_message(&mut self, req: &mut Envelope) -> Result<()> {
+            match req.message_id() {
+                "Ping" => try!(req.reply_complete(&mut self.sock, &protocol::net::Ping::new())),
+                "Pong" => {
+                    try!(req.forward(&mut self.sock,
+                                     protocol::net::Protocol::SessionSrv,
+                                     Some("elsewhere".to_string())))
+                }
+                _ => (),
+            }
+            Ok(())
+        }
+
+        fn socket(&mut self) -> &mut zmq::Socket {
+            &mut self.sock
+        }
+    }
+
+    impl HealthCheck for PingWorker {
+        fn health_checks(&mut self) -> Vec<protocol::net::HealthCheckResult> {
+            vec![health::check("upstream", protocol::net::HealthStatus::DEGRADED, None)]
+        }
+    }
+
+    // Answers every message with an error naming the message it answers.
+    struct NamingWorker {
+        sock: zmq::Socket,
+    }
+
+    impl Supervisable for NamingWorker {
+        type Config = ();
+        type Error = Error;
+
+        fn new(context: &mut zmq::Context, _config: Arc<RwLock<()>>) -> Self {
+            NamingWorker { sock: context.socket(zmq::DEALER).unwrap() }
+        }
+
+        fn on_message(&mut self, req: &mut Envelope) -> Result<()> {
+            let answered = protocol::net::err(protocol::net::ErrCode::BUG, req.message_id());
+            try!(req.reply_complete(&mut self.sock, &answered));
+            Ok(())
+        }
+
+        fn socket(&mut self) -> &mut zmq::Socket {
+            &mut self.sock
+        }
+    }
+
+    impl HealthCheck for NamingWorker {}
+
+    // Route a message with the given id, which the test fault rules may match.
+    fn send_as(router: &mut FakeRouter, message_id: &str) {
+        let mut req = protocol::Message::new(&protocol::net::Ping::new()).build();
+        req.set_message_id(message_id.to_string());
+        clock::stamp(&router.clock, &mut req);
+        hmac::sign(&mut req);
+        router.send(&req).unwrap();
+    }
+
+    // The id of the message a `NamingWorker` answered.
+    fn answered(router: &mut FakeRouter) -> String {
+        let reply = router.recv().unwrap();
+        reply.parse::<protocol::net::NetError>().unwrap().get_msg().to_string()
+    }
+
+    // Shared by every worker created with it, so a test can see when workers are replaced.
+    struct RollConfig {
+        created: Arc<AtomicUsize>,
+        handling: Arc<AtomicBool>,
+        delay_ms: u64,
+    }
+
+    impl RollConfig {
+        fn new(delay_ms: u64) -> Self {
+            RollConfig {
+                created: Arc::new(AtomicUsize::new(0)),
+                handling: Arc::new(AtomicBool::new(false)),
+                delay_ms: delay_ms,
+            }
+        }
+    }
+
+    // Takes the configured delay to handle each message and never replies.
+    struct RollWorker {
+        sock: zmq::Socket,
+        handling: Arc<AtomicBool>,
+        delay_ms: u64,
+    }
+
+    impl Supervisable for RollWorker {
+        type Config = RollConfig;
+        type Error = Error;
+
+        fn new(context: &mut zmq::Context, config: Arc<RwLock<RollConfig>>) -> Self {
+            let cfg = config.read().unwrap();
+            cfg.created.fetch_add(1, Ordering::SeqCst);
+            RollWorker {
+                sock: context.socket(zmq::DEALER).unwrap(),
+                handling: cfg.handling.clone(),
+                delay_ms: cfg.delay_ms,
+            }
+        }
+
+        fn on_message(&mut self, _: &mut Envelope) -> Result<()> {
+            self.handling.store(true, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(self.delay_ms));
+            Ok(())
+        }
+
+        fn socket(&mut self) -> &mut zmq::Socket {
+            &mut self.sock
+        }
+    }
+
+    impl HealthCheck for RollWorker {}
+
+    // Wait up to two seconds for the condition to hold.
+    fn eventually<F: Fn() -> bool>(condition: F) -> bool {
+        for _ in 0..200 {
+            if condition() {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        false
+    }
+
+    // Small xorshift generator so fuzz runs are repeatable without extra dependencies.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn bytes(&mut self, max: usize) -> Vec<u8> {
+            let len = (self.next() as usize) % (max + 1);
+            (0..len).map(|_| self.next() as u8).collect()
+        }
+    }
+
+    fn ping(router: &mut FakeRouter) {
+        router.route(&protocol::net::Ping::new()).unwrap();
+        let reply = router.recv().unwrap();
+        assert_eq!(reply.message_id(), "Ping");
+        assert_eq!(reply.hops, vec![CLIENT_IDENT.as_bytes().to_vec()]);
+    }
+
+    #[test]
+    fn worker_replies_through_router() {
+        let mut router = FakeRouter::new().unwrap();
+        router.start::<PingWorker>(Arc::new(RwLock::new(()))).unwrap();
+        ping(&mut router);
+    }
+
+    #[test]
+    fn worker_survives_malformed_frames() {
+        let mut router = FakeRouter::new().unwrap();
+        router.start::<PingWorker>(Arc::new(RwLock::new(()))).unwrap();
+        let too_many_hops: Vec<&[u8]> = vec![b"hop"; 20];
+        router.send_frames(&too_many_hops).unwrap();
+        router.send_frames(&[b"hop"]).unwrap();
+        router.send_frames(&[b"hop", b""]).unwrap();
+        router.send_frames(&[b"hop", b"", b"body", b"trailing"]).unwrap();
+        ping(&mut router);
+    }
+
+    #[test]
+    fn worker_survives_random_frames() {
+        let mut router = FakeRouter::new().unwrap();
+        router.start::<PingWorker>(Arc::new(RwLock::new(()))).unwrap();
+        let mut rng = Rng(0x2545f4914f6cdd1d);
+        for _ in 0..500 {
+            let count = 1 + (rng.next() as usize) % 12;
+            let frames: Vec<Vec<u8>> = (0..count)
+                .map(|_| if rng.next() % 4 == 0 { vec![] } else { rng.bytes(64) })
+                .collect();
+            let refs: Vec<&[u8]> = frames.iter().map(|f| &f[..]).collect();
+            router.send_frames(&refs).unwrap();
+        }
+        ping(&mut router);
+    }
+
+    #[test]
+    fn worker_answers_health_queries() {
+        let mut router = FakeRouter::new().unwrap();
+        router.start::<PingWorker>(Arc::new(RwLock::new(()))).unwrap();
+        router.send(&health::request(protocol::net::Protocol::Net, None)).unwrap();
+        let reply = router.recv().unwrap();
+        assert_eq!(reply.message_id(), "Health");
+        let health: protocol::net::Health = reply.parse().unwrap();
+        assert_eq!(health.get_status(), protocol::net::HealthStatus::DEGRADED);
+        assert_eq!(health.get_checks()[0].get_name(), health::WORKER_CHECK);
+        assert_eq!(health.get_checks()[1].get_name(), "upstream");
+    }
+
+    #[test]
+    fn worker_answers_debug_queries() {
+        let mut router = FakeRouter::new().unwrap();
+        router.start::<PingWorker>(Arc::new(RwLock::new(()))).unwrap();
+        router.route(&protocol::net::DebugGet::new()).unwrap();
+        let reply = router.recv().unwrap();
+        assert_eq!(reply.message_id(), "DebugDump");
+        let dump: protocol::net::DebugDump = reply.parse().unwrap();
+        assert!(dump.get_entries().iter().any(|e| e.get_section() == "metrics"));
+    }
+
+    #[test]
+    fn worker_answers_stats_queries() {
+        let mut router = FakeRouter::new().unwrap();
+        router.start::<PingWorker>(Arc::new(RwLock::new(()))).unwrap();
+        router.route(&protocol::net::StatsGet::new()).unwrap();
+        let reply = router.recv().unwrap();
+        assert_eq!(reply.message_id(), "Stats");
+        let stats: protocol::net::Stats = reply.parse().unwrap();
+        assert!(stats.get_workers() >= 1);
+        assert!(stats.get_received() >= 1);
+    }
+
+    #[test]
+    fn worker_forwards_messages() {
+        let mut router = FakeRouter::new().unwrap();
+        router.start::<PingWorker>(Arc::new(RwLock::new(()))).unwrap();
+        let msg = protocol::Message::new(&protocol::net::Pong::new()).routing(Some(7)).build();
+        router.send(&msg).unwrap();
+        let reply = router.recv().unwrap();
+        assert!(reply.forwarded);
+        assert_eq!(reply.hops, vec![CLIENT_IDENT.as_bytes().to_vec()]);
+        assert_eq!(reply.message_id(), "Pong");
+        assert_eq!(reply.msgs[0].get_body(), msg.get_body());
+        let route_info = reply.msgs[0].get_route_info();
+        assert_eq!(route_info.get_protocol(), protocol::net::Protocol::SessionSrv);
+        assert!(route_info.get_hash() != 7);
+        ping(&mut router);
+    }
+
+    #[test]
+    fn worker_rejects_messages_over_concurrency_limit() {
+        let mut router = FakeRouter::new().unwrap();
+        let mut caps = BTreeMap::new();
+        caps.insert("Ping".to_string(), 0);
+        let limits = ConcurrencyLimits::new(caps, Duration::from_millis(0));
+        router.start_with_limits::<PingWorker>(Arc::new(RwLock::new(())), limits).unwrap();
+        router.route(&protocol::net::Ping::new()).unwrap();
+        let reply = router.recv().unwrap();
+        assert_eq!(reply.message_id(), "NetError");
+        let err: protocol::net::NetError = reply.parse().unwrap();
+        assert_eq!(err.get_code(), protocol::net::ErrCode::BUSY);
+        router.send(&health::request(protocol::net::Protocol::Net, None)).unwrap();
+        assert_eq!(router.recv().unwrap().message_id(), "Health");
+    }
+
+    #[test]
+    fn dropping_the_router_stops_its_workers() {
+        let mut router = FakeRouter::new().unwrap();
+        router.start::<PingWorker>(Arc::new(RwLock::new(()))).unwrap();
+        router.start::<PingWorker>(Arc::new(RwLock::new(()))).unwrap();
+        ping(&mut router);
+        let controls: Vec<Arc<WorkerControl>> =
+            router.workers.iter().map(|&(ref control, _)| control.clone()).collect();
+        drop(router);
+        assert!(controls.iter().all(|control| control.is_stopping()));
+    }
+
+    #[test]
+    fn supervisor_handle_shuts_down_workers() {
+        let ctx = Arc::new(RwLock::new(zmq::Context::new()));
+        let mut be = ctx.write().unwrap().socket(zmq::DEALER).unwrap();
+        be.bind("inproc://supervisor-handle").unwrap();
+        let sup: Supervisor<PingWorker> = Supervisor::new(ctx.clone(), Arc::new(RwLock::new(())));
+        let handle = sup.spawn("inproc://supervisor-handle", 2).unwrap();
+        let workers = handle.workers();
+        assert_eq!(workers.len(), 2);
+        assert!(workers.iter().all(|w| w.get_state() == protocol::net::WorkerState::RUNNING));
+        assert!(handle.is_running());
+        handle.shutdown();
+        assert!(!handle.is_running());
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn reconfigure_rolls_workers_onto_the_new_configuration() {
+        let addr = "inproc://supervisor-roll";
+        let ctx = Arc::new(RwLock::new(zmq::Context::new()));
+        let mut be = ctx.write().unwrap().socket(zmq::DEALER).unwrap();
+        be.bind(addr).unwrap();
+        let first = RollConfig::new(0);
+        let created = first.created.clone();
+        let cfg = Arc::new(RwLock::new(first));
+        let sup: Supervisor<RollWorker> = Supervisor::new(ctx.clone(), cfg.clone());
+        let handle = sup.spawn(addr, 2).unwrap();
+        assert_eq!(created.load(Ordering::SeqCst), 2);
+        ControlClient::connect(&mut ctx.write().unwrap(), addr).unwrap().pause(0).unwrap();
+
+        let second = RollConfig::new(0);
+        let recreated = second.created.clone();
+        server::reconfigure(&cfg, &ctx, addr, second).unwrap();
+        assert!(eventually(|| recreated.load(Ordering::SeqCst) == 2));
+        let states: Vec<_> = handle.workers().iter().map(|w| w.get_state()).collect();
+        assert_eq!(states,
+                   vec![protocol::net::WorkerState::PAUSED, protocol::net::WorkerState::RUNNING]);
+        handle.shutdown();
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn roll_stops_waiting_for_a_worker_which_does_not_drain() {
+        let addr = "inproc://supervisor-drain-timeout";
+        let ctx = Arc::new(RwLock::new(zmq::Context::new()));
+        let mut be = ctx.write().unwrap().socket(zmq::DEALER).unwrap();
+        be.bind(addr).unwrap();
+        let config = RollConfig::new(2_000);
+        let created = config.created.clone();
+        let handling = config.handling.clone();
+        let sup: Supervisor<RollWorker> =
+            Supervisor::new(ctx.clone(), Arc::new(RwLock::new(config))).with_drain_timeout(100);
+        let handle = sup.spawn(addr, 1).unwrap();
+        let mut req = protocol::Message::new(&protocol::net::Ping::new()).build();
+        hmac::sign(&mut req);
+        be.send_str(CLIENT_IDENT, zmq::SNDMORE).unwrap();
+        be.send(&[], zmq::SNDMORE).unwrap();
+        be.send(&req.write_to_bytes().unwrap(), 0).unwrap();
+        assert!(eventually(|| handling.load(Ordering::SeqCst)));
+
+        let started = Instant::now();
+        handle.restart_workers();
+        assert!(eventually(|| created.load(Ordering::SeqCst) == 2));
+        // The Supervisor only shuts down once the roll is over.
+        handle.shutdown();
+        handle.join().unwrap();
+        assert!(started.elapsed() < Duration::from_millis(1_500));
+    }
+
+    #[test]
+    fn worker_drops_faulted_messages() {
+        chaos::install_test_faults();
+        let mut router = FakeRouter::new().unwrap();
+        router.start::<NamingWorker>(Arc::new(RwLock::new(()))).unwrap();
+        send_as(&mut router, "ChaosDrop");
+        send_as(&mut router, "Ping");
+        assert_eq!(answered(&mut router), "Ping");
+    }
+
+    #[test]
+    fn worker_delays_faulted_messages() {
+        chaos::install_test_faults();
+        let mut router = FakeRouter::new().unwrap();
+        router.start::<NamingWorker>(Arc::new(RwLock::new(()))).unwrap();
+        let started = Instant::now();
+        send_as(&mut router, "ChaosDelay");
+        assert_eq!(answered(&mut router), "ChaosDelay");
+        assert!(started.elapsed() >= Duration::from_millis(300));
+    }
+
+    #[test]
+    fn worker_reconnects_on_faulted_messages() {
+        chaos::install_test_faults();
+        let mut router = FakeRouter::new().unwrap();
+        router.start::<NamingWorker>(Arc::new(RwLock::new(()))).unwrap();
+        send_as(&mut router, "ChaosReconnect");
+        // Messages sent while the worker reconnects could be lost with the old connection.
+        thread::sleep(Duration::from_millis(100));
+        send_as(&mut router, "Ping");
+        assert_eq!(answered(&mut router), "Ping");
+    }
+}
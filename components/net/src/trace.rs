@@ -0,0 +1,212 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Trace context propagated with messages routed through the `RouteSrv` mesh.
+//!
+//! Every routed message carries the trace id of the request it belongs to and the id of the span
+//! which sent it in its `RouteInfo`. A worker dispatching a message starts a child span of the
+//! sender's span, and any message routed while that span is current carries its ids onward, so a
+//! single API call can be followed across every service it touches.
+//!
+//! Finished spans are handed to the installed `Exporter`. By default they are logged at the debug
+//! level.
+
+use std::cell::RefCell;
+use std::hash::Hasher;
+use std::mem;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+
+use fnv::FnvHasher;
+use libc;
+use protocol;
+use time;
+
+use clock::{Clock, SystemClock};
+
+lazy_static! {
+    // Exporter installed with `set_exporter`, if any.
+    static ref EXPORTER: RwLock<Option<Box<Exporter>>> = RwLock::new(None);
+}
+
+static NEXT_ID: AtomicUsize = ATOMIC_USIZE_INIT;
+
+thread_local!(static CURRENT: RefCell<Option<SpanContext>> = RefCell::new(None));
+
+/// Identifies a span within a trace.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpanContext {
+    pub trace_id: u64,
+    pub span_id: u64,
+}
+
+impl SpanContext {
+    /// A context for the root of a new trace.
+    pub fn root() -> SpanContext {
+        SpanContext {
+            trace_id: new_id(),
+            span_id: new_id(),
+        }
+    }
+
+    /// Read the trace context a message was sent with, if any.
+    pub fn extract(route_info: &protocol::net::RouteInfo) -> Option<SpanContext> {
+        if route_info.has_trace_id() && route_info.has_span_id() {
+            Some(SpanContext {
+                trace_id: route_info.get_trace_id(),
+                span_id: route_info.get_span_id(),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Write this trace context into the route info of an outgoing message.
+    pub fn inject(&self, route_info: &mut protocol::net::RouteInfo) {
+        route_info.set_trace_id(self.trace_id);
+        route_info.set_span_id(self.span_id);
+    }
+}
+
+/// A span which has finished and is ready to be exported.
+#[derive(Clone, Debug)]
+pub struct FinishedSpan {
+    pub name: String,
+    pub context: SpanContext,
+    pub parent_id: Option<u64>,
+    /// Start time in milliseconds since the Unix epoch
+    pub start_ms: i64,
+    pub duration_ms: i64,
+}
+
+/// Receives spans as they finish.
+pub trait Exporter: Send + Sync {
+    fn export(&self, span: &FinishedSpan);
+}
+
+/// Install the exporter for finished spans. Only the first call has an effect.
+pub fn set_exporter(exporter: Box<Exporter>) {
+    let mut installed = EXPORTER.write().unwrap();
+    if installed.is_none() {
+        *installed = Some(exporter);
+    }
+}
+
+fn export(span: &FinishedSpan) {
+    match *EXPORTER.read().unwrap() {
+        Some(ref exporter) => exporter.export(span),
+        None => {
+            debug!("span finished, trace={:x} span={:x} parent={:?} name={} duration_ms={}",
+                   span.context.trace_id,
+                   span.context.span_id,
+                   span.parent_id.map(|id| format!("{:x}", id)),
+                   span.name,
+                   span.duration_ms)
+        }
+    }
+}
+
+/// Trace context of the span currently running on this thread, if any.
+pub fn current() -> Option<SpanContext> {
+    CURRENT.with(|c| *c.borrow())
+}
+
+/// Attach the current span's trace context, if any, to an outgoing message.
+pub fn inject_current(msg: &mut protocol::net::Msg) {
+    if let Some(ctx) = current() {
+        ctx.inject(msg.mut_route_info());
+    }
+}
+
+/// A unit of work within a trace. The span is current on its thread from when it is started
+/// until it is finished or dropped.
+pub struct Span {
+    name: String,
+    context: SpanContext,
+    parent_id: Option<u64>,
+    previous: Option<SpanContext>,
+    start_ms: i64,
+    finished: bool,
+}
+
+impl Span {
+    /// Start a span as a child of the current span, or as the root of a new trace if there is
+    /// no current span.
+    pub fn start<S: Into<String>>(name: S) -> Span {
+        Self::start_with_parent(name, current())
+    }
+
+    /// Start a span for handling a message, as a child of the span which sent it.
+    pub fn for_message(msg: &protocol::net::Msg) -> Span {
+        let parent = SpanContext::extract(msg.get_route_info()).or(current());
+        Self::start_with_parent(msg.get_message_id(), parent)
+    }
+
+    fn start_with_parent<S: Into<String>>(name: S, parent: Option<SpanContext>) -> Span {
+        let context = SpanContext {
+            trace_id: parent.map(|p| p.trace_id).unwrap_or_else(new_id),
+            span_id: new_id(),
+        };
+        let previous = CURRENT.with(|c| mem::replace(&mut *c.borrow_mut(), Some(context)));
+        Span {
+            name: name.into(),
+            context: context,
+            parent_id: parent.map(|p| p.span_id),
+            previous: previous,
+            start_ms: SystemClock.now_ms(),
+            finished: false,
+        }
+    }
+
+    pub fn context(&self) -> SpanContext {
+        self.context
+    }
+
+    /// Finish the span, export it, and restore the span which was current before it started.
+    pub fn finish(mut self) {
+        self.close();
+    }
+
+    fn close(&mut self) {
+        if self.finished {
+            return;
+        }
+        self.finished = true;
+        let previous = self.previous;
+        CURRENT.with(|c| *c.borrow_mut() = previous);
+        let now_ms = SystemClock.now_ms();
+        export(&FinishedSpan {
+            name: self.name.clone(),
+            context: self.context,
+            parent_id: self.parent_id,
+            start_ms: self.start_ms,
+            duration_ms: now_ms - self.start_ms,
+        });
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+fn new_id() -> u64 {
+    let timespec = time::get_time();
+    let mut hasher = FnvHasher::default();
+    hasher.write_i64(timespec.sec);
+    hasher.write_i32(timespec.nsec);
+    hasher.write_i32(unsafe { libc::getpid() });
+    hasher.write_usize(NEXT_ID.fetch_add(1, Ordering::SeqCst));
+    hasher.finish()
+}
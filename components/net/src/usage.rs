@@ -0,0 +1,219 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Resource usage of Supervisor workers.
+//!
+//! Every dispatch made by a worker is sampled: the CPU time its thread spent handling the message
+//! and the process's resident set once it was handled. CPU time is totalled both per worker
+//! thread and per message id so a runaway service can be traced to the kind of message which
+//! caused it, not just to the process. The resident set belongs to the whole process, so its
+//! growth is only attributed to the message ids whose dispatches observed it and not to worker
+//! threads. Totals are exported alongside the counters in `metrics`.
+//!
+//! CPU time is read with `getrusage(RUSAGE_THREAD)` and resident set size from
+//! `/proc/self/statm`. Both are only available on Linux; elsewhere they read as zero.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use std::thread;
+
+use libc;
+
+#[cfg(target_os = "linux")]
+const RUSAGE_THREAD: libc::c_int = 1;
+
+lazy_static! {
+    static ref TABLES: Mutex<Tables> = Mutex::new(Tables::default());
+}
+
+// Resident set size of the process observed after the most recently recorded dispatch.
+static LAST_RSS: AtomicUsize = ATOMIC_USIZE_INIT;
+
+/// Resource usage totalled over a set of dispatches.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// Number of messages dispatched
+    pub count: u64,
+    /// CPU time spent handling them, in microseconds
+    pub cpu_us: u64,
+    /// Largest resident set size of the process observed after one of them, in bytes. Only
+    /// recorded per message id.
+    pub peak_rss_bytes: u64,
+    /// Largest growth of the process's resident set observed after one of them, since the
+    /// previous dispatch on any worker, in bytes. Only recorded per message id.
+    pub max_rss_growth_bytes: u64,
+}
+
+impl Usage {
+    fn add_cpu(&mut self, start: &Sample, end: &Sample) {
+        self.count += 1;
+        self.cpu_us += end.cpu_us.saturating_sub(start.cpu_us);
+    }
+
+    fn add_rss(&mut self, rss_bytes: u64, growth: u64) {
+        if rss_bytes > self.peak_rss_bytes {
+            self.peak_rss_bytes = rss_bytes;
+        }
+        if growth > self.max_rss_growth_bytes {
+            self.max_rss_growth_bytes = growth;
+        }
+    }
+}
+
+#[derive(Default)]
+struct Tables {
+    workers: BTreeMap<String, Usage>,
+    messages: BTreeMap<String, Usage>,
+}
+
+/// CPU time of the calling thread at one instant.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Sample {
+    pub cpu_us: u64,
+}
+
+impl Sample {
+    pub fn now() -> Sample {
+        Sample { cpu_us: thread_cpu_us() }
+    }
+}
+
+/// Attribute the CPU time between `start` and now to the calling worker thread and the given
+/// message id, and the process's resident set as of now to the message id.
+pub fn record(message_id: &str, start: &Sample) {
+    let end = Sample::now();
+    let rss = rss_bytes();
+    let previous = LAST_RSS.swap(rss as usize, Ordering::SeqCst) as u64;
+    // Nothing was observed before the first dispatch to measure its growth against.
+    let growth = if previous == 0 {
+        0
+    } else {
+        rss.saturating_sub(previous)
+    };
+    record_between(&worker_name(), message_id, start, &end, rss, growth);
+}
+
+fn record_between(worker: &str,
+                  message_id: &str,
+                  start: &Sample,
+                  end: &Sample,
+                  rss_bytes: u64,
+                  rss_growth: u64) {
+    let mut tables = TABLES.lock().unwrap();
+    tables.workers.entry(worker.to_string()).or_insert_with(Usage::default).add_cpu(start, end);
+    let message = tables.messages.entry(message_id.to_string()).or_insert_with(Usage::default);
+    message.add_cpu(start, end);
+    message.add_rss(rss_bytes, rss_growth);
+}
+
+/// Usage totals for each worker thread, keyed by thread name.
+pub fn workers() -> BTreeMap<String, Usage> {
+    TABLES.lock().unwrap().workers.clone()
+}
+
+/// Usage totals for each message id.
+pub fn messages() -> BTreeMap<String, Usage> {
+    TABLES.lock().unwrap().messages.clone()
+}
+
+/// Resident set size of the process as of the most recently recorded dispatch, in bytes.
+pub fn rss() -> u64 {
+    LAST_RSS.load(Ordering::SeqCst) as u64
+}
+
+fn worker_name() -> String {
+    thread::current().name().unwrap_or("unnamed").to_string()
+}
+
+#[cfg(target_os = "linux")]
+fn thread_cpu_us() -> u64 {
+    let mut usage: libc::rusage = unsafe { ::std::mem::zeroed() };
+    if unsafe { libc::getrusage(RUSAGE_THREAD, &mut usage) } != 0 {
+        return 0;
+    }
+    let us = |tv: libc::timeval| tv.tv_sec as u64 * 1_000_000 + tv.tv_usec as u64;
+    us(usage.ru_utime) + us(usage.ru_stime)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn thread_cpu_us() -> u64 {
+    0
+}
+
+#[cfg(target_os = "linux")]
+fn rss_bytes() -> u64 {
+    use std::fs::File;
+    use std::io::Read;
+
+    let mut statm = String::new();
+    match File::open("/proc/self/statm").and_then(|mut f| f.read_to_string(&mut statm)) {
+        Ok(_) => {
+            let pages = statm.split_whitespace()
+                .nth(1)
+                .and_then(|p| p.parse::<u64>().ok())
+                .unwrap_or(0);
+            let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+            pages * page_size as u64
+        }
+        Err(_) => 0,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn rss_bytes() -> u64 {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{record_between, messages, rss_bytes, workers, Sample, Usage};
+
+    #[test]
+    fn usage_totals_by_worker_and_message() {
+        let start = Sample { cpu_us: 100 };
+        let end = Sample { cpu_us: 350 };
+        record_between("usage-test-0", "UsageTestA", &start, &end, 8192, 4096);
+        record_between("usage-test-0", "UsageTestB", &end, &end, 4096, 0);
+        let worker = workers()["usage-test-0"];
+        assert_eq!(worker,
+                   Usage {
+                       count: 2,
+                       cpu_us: 250,
+                       peak_rss_bytes: 0,
+                       max_rss_growth_bytes: 0,
+                   });
+        assert_eq!(messages()["UsageTestB"].cpu_us, 0);
+        assert_eq!(messages()["UsageTestA"],
+                   Usage {
+                       count: 1,
+                       cpu_us: 250,
+                       peak_rss_bytes: 8192,
+                       max_rss_growth_bytes: 4096,
+                   });
+    }
+
+    #[test]
+    fn usage_never_underflows() {
+        let mut usage = Usage::default();
+        usage.add_cpu(&Sample { cpu_us: 10 }, &Sample::default());
+        assert_eq!(usage.cpu_us, 0);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn reads_resident_set() {
+        assert!(rss_bytes() > 0);
+    }
+}
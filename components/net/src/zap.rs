@@ -0,0 +1,550 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! ZeroMQ authentication protocol (ZAP) handler for listening sockets.
+//!
+//! ZeroMQ asks a ZAP handler, bound to a well known inproc address within the same context, to
+//! approve every connection made to a socket which has a ZAP domain set. The handler started by
+//! `start()` approves connections based on a `ZapConfig`:
+//!
+//! * An IP allowlist, checked for every mechanism
+//! * PLAIN username and password pairs
+//! * CURVE client public key allowlist. The socket becomes a CURVE server using the configured
+//!   secret key and, if the allowlist is empty, any client completing the handshake is accepted
+//!
+//! CURVE takes precedence over PLAIN when both are configured. With neither, connections use the
+//! NULL mechanism and only the IP allowlist applies. Call `apply()` on every listening socket
+//! before binding it.
+//!
+//! Clients of an authenticated socket present the `ZapCredentials` for its mechanism. Call
+//! `apply_client()` on every socket connecting to it before connecting.
+
+use std::net;
+use std::result;
+use std::str::FromStr;
+use std::thread;
+
+use hab_core;
+use hab_core::config::ParseInto;
+use toml;
+use zmq;
+
+use error::Result;
+
+/// Address ZeroMQ sends authentication requests to.
+pub const ZAP_ENDPOINT: &'static str = "inproc://zeromq.zap.01";
+/// Authentication domain set on sockets protected by the handler.
+pub const ZAP_DOMAIN: &'static str = "habitat";
+const ZAP_VERSION: &'static str = "1.0";
+const Z85_CHARS: &'static [u8] = b"0123456789abcdefghijklmnopqrstuvwxyz\
+                                   ABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mechanism {
+    Null,
+    Plain,
+    Curve,
+}
+
+impl Mechanism {
+    fn name(&self) -> &'static str {
+        match *self {
+            Mechanism::Null => "NULL",
+            Mechanism::Plain => "PLAIN",
+            Mechanism::Curve => "CURVE",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ZapConfig {
+    /// Client addresses allowed to connect. Empty allows any address.
+    pub allow_ips: Vec<net::Ipv4Addr>,
+    /// Username and password pairs accepted by the PLAIN mechanism
+    pub plain_users: Vec<(String, String)>,
+    /// Z85 encoded public keys of clients accepted by the CURVE mechanism
+    pub curve_keys: Vec<String>,
+    /// Z85 encoded secret key for serving CURVE. Enables the CURVE mechanism when set.
+    pub curve_secret_key: Option<String>,
+}
+
+impl ZapConfig {
+    /// Add a PLAIN user from a `username:password` entry. Returns false if the entry is
+    /// malformed.
+    pub fn add_plain_user(&mut self, entry: &str) -> bool {
+        match entry.find(':') {
+            Some(i) if i > 0 => {
+                self.plain_users.push((entry[..i].to_string(), entry[i + 1..].to_string()));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// True if any form of authentication is configured.
+    pub fn is_enabled(&self) -> bool {
+        !self.allow_ips.is_empty() || !self.plain_users.is_empty() ||
+        self.curve_secret_key.is_some()
+    }
+
+    /// The security mechanism listening sockets are configured with.
+    pub fn mechanism(&self) -> Mechanism {
+        if self.curve_secret_key.is_some() {
+            Mechanism::Curve
+        } else if !self.plain_users.is_empty() {
+            Mechanism::Plain
+        } else {
+            Mechanism::Null
+        }
+    }
+
+    /// Approve or deny an authentication request. Returns the user id for the connection or the
+    /// reason it was denied.
+    pub fn authenticate(&self, req: &ZapRequest) -> result::Result<String, &'static str> {
+        if !self.allow_ips.is_empty() {
+            match net::Ipv4Addr::from_str(&req.address) {
+                Ok(ref ip) if self.allow_ips.contains(ip) => (),
+                _ => return Err("address not allowed"),
+            }
+        }
+        if req.mechanism != self.mechanism().name() {
+            return Err("unsupported mechanism");
+        }
+        match self.mechanism() {
+            Mechanism::Null => Ok(String::new()),
+            Mechanism::Plain => {
+                if req.credentials.len() != 2 {
+                    return Err("malformed credentials");
+                }
+                let user = String::from_utf8_lossy(&req.credentials[0]);
+                let denied = self.plain_users
+                    .iter()
+                    .all(|&(ref u, ref p)| {
+                        !(secure_eq(u.as_bytes(), user.as_bytes()) &&
+                          secure_eq(p.as_bytes(), &req.credentials[1]))
+                    });
+                if denied {
+                    Err("invalid username or password")
+                } else {
+                    Ok(user.into_owned())
+                }
+            }
+            Mechanism::Curve => {
+                if req.credentials.len() != 1 || req.credentials[0].len() != 32 {
+                    return Err("malformed credentials");
+                }
+                let key = z85_encode(&req.credentials[0]);
+                if self.curve_keys.is_empty() || self.curve_keys.contains(&key) {
+                    Ok(key)
+                } else {
+                    Err("public key not allowed")
+                }
+            }
+        }
+    }
+}
+
+impl ParseInto<ZapConfig> for toml::Value {
+    /// Read the `allow_ips`, `plain_users`, `curve_keys` and `curve_secret_key` entries of the
+    /// table at `field`.
+    fn parse_into(&self, field: &'static str, out: &mut ZapConfig) -> hab_core::Result<bool> {
+        let table = match self.lookup(field) {
+            Some(table) => table,
+            None => return Ok(false),
+        };
+        let mut cfg = ZapConfig::default();
+        try!(table.parse_into("allow_ips", &mut cfg.allow_ips));
+        try!(table.parse_into("curve_keys", &mut cfg.curve_keys));
+        try!(table.parse_into("curve_secret_key", &mut cfg.curve_secret_key));
+        let mut plain_users: Vec<String> = vec![];
+        try!(table.parse_into("plain_users", &mut plain_users));
+        for entry in plain_users.iter() {
+            if !cfg.add_plain_user(entry) {
+                return Err(hab_core::Error::ConfigInvalidString(field));
+            }
+        }
+        *out = cfg;
+        Ok(true)
+    }
+}
+
+/// Credentials a client presents to a socket authenticated by a ZAP handler.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ZapCredentials {
+    /// Username and password for the PLAIN mechanism
+    pub plain_user: Option<(String, String)>,
+    /// Z85 encoded public key of the server for the CURVE mechanism. Enables CURVE when set.
+    pub curve_server_key: Option<String>,
+    /// Z85 encoded public and secret key of the client for the CURVE mechanism
+    pub curve_keypair: Option<(String, String)>,
+}
+
+impl ZapCredentials {
+    /// The security mechanism client sockets are configured with.
+    pub fn mechanism(&self) -> Mechanism {
+        if self.curve_server_key.is_some() {
+            Mechanism::Curve
+        } else if self.plain_user.is_some() {
+            Mechanism::Plain
+        } else {
+            Mechanism::Null
+        }
+    }
+}
+
+impl ParseInto<ZapCredentials> for toml::Value {
+    /// Read the `plain_user`, as `username:password`, and the `curve_server_key`,
+    /// `curve_public_key` and `curve_secret_key` entries of the table at `field`. The CURVE keys
+    /// must be given together.
+    fn parse_into(&self,
+                  field: &'static str,
+                  out: &mut ZapCredentials)
+                  -> hab_core::Result<bool> {
+        let table = match self.lookup(field) {
+            Some(table) => table,
+            None => return Ok(false),
+        };
+        let mut creds = ZapCredentials::default();
+        let mut plain_user: Option<String> = None;
+        try!(table.parse_into("plain_user", &mut plain_user));
+        if let Some(entry) = plain_user {
+            let mut cfg = ZapConfig::default();
+            if !cfg.add_plain_user(&entry) {
+                return Err(hab_core::Error::ConfigInvalidString(field));
+            }
+            creds.plain_user = cfg.plain_users.pop();
+        }
+        let mut public_key: Option<String> = None;
+        let mut secret_key: Option<String> = None;
+        try!(table.parse_into("curve_server_key", &mut creds.curve_server_key));
+        try!(table.parse_into("curve_public_key", &mut public_key));
+        try!(table.parse_into("curve_secret_key", &mut secret_key));
+        match (creds.curve_server_key.is_some(), public_key, secret_key) {
+            (true, Some(public), Some(secret)) => creds.curve_keypair = Some((public, secret)),
+            (false, None, None) => (),
+            _ => return Err(hab_core::Error::ConfigInvalidString(field)),
+        }
+        *out = creds;
+        Ok(true)
+    }
+}
+
+/// A connection awaiting approval by the ZAP handler.
+#[derive(Debug, Default, PartialEq)]
+pub struct ZapRequest {
+    pub request_id: Vec<u8>,
+    pub domain: String,
+    pub address: String,
+    pub mechanism: String,
+    pub credentials: Vec<Vec<u8>>,
+}
+
+impl ZapRequest {
+    /// Read a request from the frames of a ZAP message: the version, request id, domain,
+    /// address, identity and mechanism followed by the mechanism's credentials. Returns `None`
+    /// if the message is malformed.
+    pub fn from_frames(frames: Vec<Vec<u8>>) -> Option<ZapRequest> {
+        if frames.len() < 6 || frames[0] != ZAP_VERSION.as_bytes() {
+            return None;
+        }
+        let mut frames = frames.into_iter().skip(1);
+        let request_id = frames.next().unwrap();
+        let domain = String::from_utf8_lossy(&frames.next().unwrap()).into_owned();
+        let address = String::from_utf8_lossy(&frames.next().unwrap()).into_owned();
+        frames.next();
+        let mechanism = String::from_utf8_lossy(&frames.next().unwrap()).into_owned();
+        Some(ZapRequest {
+            request_id: request_id,
+            domain: domain,
+            address: address,
+            mechanism: mechanism,
+            credentials: frames.collect(),
+        })
+    }
+}
+
+/// Configure a listening socket to be authenticated by the ZAP handler. Does nothing if no
+/// authentication is configured. Must be called before the socket is bound.
+pub fn apply(sock: &zmq::Socket, cfg: &ZapConfig) -> Result<()> {
+    if !cfg.is_enabled() {
+        return Ok(());
+    }
+    try!(sock.set_zap_domain(ZAP_DOMAIN));
+    match cfg.mechanism() {
+        Mechanism::Curve => {
+            try!(sock.set_curve_server(true));
+            try!(sock.set_curve_secretkey(cfg.curve_secret_key.as_ref().unwrap().as_bytes()));
+        }
+        Mechanism::Plain => try!(sock.set_plain_server(true)),
+        Mechanism::Null => (),
+    }
+    Ok(())
+}
+
+/// Configure a socket connecting to a socket authenticated by a ZAP handler to present the given
+/// credentials. Does nothing if no credentials are given. Must be called before the socket is
+/// connected.
+pub fn apply_client(sock: &zmq::Socket, creds: &ZapCredentials) -> Result<()> {
+    match creds.mechanism() {
+        Mechanism::Curve => {
+            let server_key = creds.curve_server_key.as_ref().unwrap();
+            let &(ref public, ref secret) = creds.curve_keypair.as_ref().unwrap();
+            try!(sock.set_curve_serverkey(server_key.as_bytes()));
+            try!(sock.set_curve_publickey(public.as_bytes()));
+            try!(sock.set_curve_secretkey(secret.as_bytes()));
+        }
+        Mechanism::Plain => {
+            let &(ref user, ref password) = creds.plain_user.as_ref().unwrap();
+            try!(sock.set_plain_username(user));
+            try!(sock.set_plain_password(password));
+        }
+        Mechanism::Null => (),
+    }
+    Ok(())
+}
+
+/// Bind a ZAP handler within the given context and serve authentication requests on a separate
+/// thread. Does nothing if no authentication is configured. Only one handler may be started per
+/// context and it must be started before any socket using it is bound.
+///
+/// # Errors
+///
+/// * A socket cannot be created within the given `zmq::Context`
+/// * A handler is already bound within the given `zmq::Context`
+pub fn start(ctx: &mut zmq::Context, cfg: ZapConfig) -> Result<()> {
+    if !cfg.is_enabled() {
+        return Ok(());
+    }
+    let mut sock = try!(ctx.socket(zmq::REP));
+    try!(sock.bind(ZAP_ENDPOINT));
+    thread::Builder::new()
+        .name("zap-handler".to_string())
+        .spawn(move || {
+            loop {
+                if let Err(e) = handle(&mut sock, &cfg) {
+                    error!("zap handler error, err={}", e);
+                }
+            }
+        })
+        .unwrap();
+    Ok(())
+}
+
+fn handle(sock: &mut zmq::Socket, cfg: &ZapConfig) -> Result<()> {
+    let mut frames = vec![try!(sock.recv_msg(0)).to_vec()];
+    while try!(sock.get_rcvmore()) {
+        frames.push(try!(sock.recv_msg(0)).to_vec());
+    }
+    let count = frames.len();
+    let request_id = frames.get(1).cloned().unwrap_or(vec![]);
+    let req = match ZapRequest::from_frames(frames) {
+        Some(req) => req,
+        None => {
+            warn!("malformed zap request, frames={}", count);
+            return reply(sock, &request_id, "400", "malformed request", "");
+        }
+    };
+    match cfg.authenticate(&req) {
+        Ok(user_id) => {
+            debug!("zap allowed, address={} mechanism={}", req.address, req.mechanism);
+            reply(sock, &req.request_id, "200", "OK", &user_id)
+        }
+        Err(reason) => {
+            warn!("zap denied, address={} mechanism={} reason={}",
+                  req.address,
+                  req.mechanism,
+                  reason);
+            reply(sock, &req.request_id, "400", reason, "")
+        }
+    }
+}
+
+fn reply(sock: &mut zmq::Socket,
+         request_id: &[u8],
+         status: &str,
+         text: &str,
+         user_id: &str)
+         -> Result<()> {
+    try!(sock.send_str(ZAP_VERSION, zmq::SNDMORE));
+    try!(sock.send(request_id, zmq::SNDMORE));
+    try!(sock.send_str(status, zmq::SNDMORE));
+    try!(sock.send_str(text, zmq::SNDMORE));
+    try!(sock.send_str(user_id, zmq::SNDMORE));
+    try!(sock.send(&[], 0));
+    Ok(())
+}
+
+/// Encode bytes, whose length is a multiple of 4, in the Z85 format used for CURVE keys.
+pub fn z85_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len() * 5 / 4);
+    for chunk in data.chunks(4) {
+        let mut value = chunk.iter().fold(0u32, |acc, b| (acc << 8) | *b as u32);
+        let mut buf = [0u8; 5];
+        for i in (0..5).rev() {
+            buf[i] = Z85_CHARS[(value % 85) as usize];
+            value /= 85;
+        }
+        out.push_str(&String::from_utf8_lossy(&buf));
+    }
+    out
+}
+
+// Compare two byte strings in time independent of where they first differ.
+fn secure_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net;
+
+    use hab_core::config::ParseInto;
+    use toml;
+
+    use super::*;
+
+    fn request(address: &str, mechanism: &str, credentials: Vec<&[u8]>) -> ZapRequest {
+        ZapRequest {
+            request_id: b"1".to_vec(),
+            domain: ZAP_DOMAIN.to_string(),
+            address: address.to_string(),
+            mechanism: mechanism.to_string(),
+            credentials: credentials.into_iter().map(|c| c.to_vec()).collect(),
+        }
+    }
+
+    #[test]
+    fn z85_encodes_reference_vector() {
+        let data = [0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B];
+        assert_eq!(z85_encode(&data), "HelloWorld");
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let cfg = ZapConfig::default();
+        assert!(!cfg.is_enabled());
+        assert_eq!(cfg.mechanism(), Mechanism::Null);
+    }
+
+    #[test]
+    fn add_plain_user_parses_entries() {
+        let mut cfg = ZapConfig::default();
+        assert!(cfg.add_plain_user("bobo:pass:word"));
+        assert!(!cfg.add_plain_user("bobo"));
+        assert!(!cfg.add_plain_user(":password"));
+        assert_eq!(cfg.plain_users,
+                   vec![("bobo".to_string(), "pass:word".to_string())]);
+    }
+
+    #[test]
+    fn ip_allowlist() {
+        let mut cfg = ZapConfig::default();
+        cfg.allow_ips.push(net::Ipv4Addr::new(10, 0, 0, 1));
+        assert!(cfg.authenticate(&request("10.0.0.1", "NULL", vec![])).is_ok());
+        assert!(cfg.authenticate(&request("10.0.0.2", "NULL", vec![])).is_err());
+    }
+
+    #[test]
+    fn plain_credentials() {
+        let mut cfg = ZapConfig::default();
+        cfg.plain_users.push(("bobo".to_string(), "s3cret".to_string()));
+        assert_eq!(cfg.mechanism(), Mechanism::Plain);
+        let valid = request("10.0.0.1", "PLAIN", vec![&b"bobo"[..], &b"s3cret"[..]]);
+        assert_eq!(cfg.authenticate(&valid), Ok("bobo".to_string()));
+        let wrong = request("10.0.0.1", "PLAIN", vec![&b"bobo"[..], &b"wrong"[..]]);
+        assert!(cfg.authenticate(&wrong).is_err());
+        assert!(cfg.authenticate(&request("10.0.0.1", "NULL", vec![])).is_err());
+    }
+
+    #[test]
+    fn curve_key_allowlist() {
+        let key = [7u8; 32];
+        let mut cfg = ZapConfig::default();
+        cfg.curve_secret_key = Some("secret".to_string());
+        assert!(cfg.authenticate(&request("10.0.0.1", "CURVE", vec![&key[..]])).is_ok());
+        cfg.curve_keys.push(z85_encode(&[8u8; 32]));
+        assert!(cfg.authenticate(&request("10.0.0.1", "CURVE", vec![&key[..]])).is_err());
+        cfg.curve_keys.push(z85_encode(&key));
+        assert!(cfg.authenticate(&request("10.0.0.1", "CURVE", vec![&key[..]])).is_ok());
+    }
+
+    #[test]
+    fn request_from_frames() {
+        let frames: Vec<Vec<u8>> = vec![b"1.0".to_vec(),
+                                        b"7".to_vec(),
+                                        ZAP_DOMAIN.as_bytes().to_vec(),
+                                        b"10.0.0.1".to_vec(),
+                                        vec![],
+                                        b"PLAIN".to_vec(),
+                                        b"bobo".to_vec(),
+                                        b"s3cret".to_vec()];
+        let req = ZapRequest::from_frames(frames).unwrap();
+        assert_eq!(req.request_id, b"7".to_vec());
+        assert_eq!(req.address, "10.0.0.1");
+        assert_eq!(req.mechanism, "PLAIN");
+        assert_eq!(req.credentials, vec![b"bobo".to_vec(), b"s3cret".to_vec()]);
+    }
+
+    #[test]
+    fn malformed_frames_are_rejected() {
+        assert_eq!(ZapRequest::from_frames(vec![b"1.0".to_vec(), b"7".to_vec()]), None);
+        let wrong_version = vec![b"2.0".to_vec(); 6];
+        assert_eq!(ZapRequest::from_frames(wrong_version), None);
+    }
+
+    #[test]
+    fn parse_config() {
+        let toml: toml::Value = r#"
+        [cfg.zap]
+        allow_ips = ["10.0.0.1"]
+        plain_users = ["bobo:s3cret"]
+        "#
+            .parse()
+            .unwrap();
+        let mut cfg = ZapConfig::default();
+        assert!(toml.parse_into("cfg.zap", &mut cfg).unwrap());
+        assert_eq!(cfg.allow_ips, vec![net::Ipv4Addr::new(10, 0, 0, 1)]);
+        assert_eq!(cfg.mechanism(), Mechanism::Plain);
+    }
+
+    #[test]
+    fn parse_credentials() {
+        let toml: toml::Value = r#"
+        [cfg.router_auth]
+        plain_user = "bobo:s3cret"
+        "#
+            .parse()
+            .unwrap();
+        let mut creds = ZapCredentials::default();
+        assert!(toml.parse_into("cfg.router_auth", &mut creds).unwrap());
+        assert_eq!(creds.plain_user,
+                   Some(("bobo".to_string(), "s3cret".to_string())));
+        assert_eq!(creds.mechanism(), Mechanism::Plain);
+    }
+
+    #[test]
+    fn parse_credentials_requires_curve_keypair() {
+        let toml: toml::Value = r#"
+        [cfg.router_auth]
+        curve_server_key = "server"
+        "#
+            .parse()
+            .unwrap();
+        let mut creds = ZapCredentials::default();
+        assert!(toml.parse_into("cfg.router_auth", &mut creds).is_err());
+    }
+}
@@ -113,6 +113,12 @@ package_version_tag() {
   cat $ident_file | awk 'BEGIN { FS = "/" }; { print $1 "/" $2 ":" $3 "-" $4 }'
 }
 
+package_ident() {
+  local pkg="$1"
+  local ident_file=$(find $DOCKER_CONTEXT/rootfs/$HAB_ROOT_PATH/pkgs/$pkg -name IDENT)
+  cat $ident_file
+}
+
 package_latest_tag() {
   local pkg="$1"
   local ident_file=$(find $DOCKER_CONTEXT/rootfs/$HAB_ROOT_PATH/pkgs/$pkg -name IDENT)
@@ -128,6 +134,7 @@ docker_image() {
   cat <<EOT > $DOCKER_CONTEXT/Dockerfile
 FROM scratch
 ENV $(cat $DOCKER_CONTEXT/rootfs/init.sh | grep PATH)
+LABEL sh.habitat.package.ident="$(package_ident $1)" sh.habitat.exporter="$program $version"
 WORKDIR /
 ADD rootfs /
 VOLUME $HAB_ROOT_PATH/svc/${pkg_name}/data $HAB_ROOT_PATH/svc/${pkg_name}/config
@@ -169,6 +169,13 @@
 # pkg_expose=(80 443)
 # ```
 #
+# ### pkg_binds
+# An array of the names of the binds this service requires. Each bind must be
+# satisfied with `--bind <name>:<service_group>` when the service is started.
+# ```
+# pkg_binds=(database)
+# ```
+#
 # ### pkg_origin
 # A string to use for the origin. The origin is used to denote a particular upstream of a
 # package; when we resolve dependencies, we consider a version of a package to be equal
@@ -348,6 +355,8 @@ pkg_include_dirs=()
 pkg_svc_run=''
 # An array of ports to expose.
 pkg_expose=()
+# An array of bind names this service requires.
+pkg_binds=()
 # The user to run the service as
 pkg_svc_user=hab
 # The group to run the service as
@@ -1753,6 +1762,7 @@ do_default_install() {
 
 # **Internal** Write out the package data to files:
 #
+# * `$pkg_prefix/BINDS` - Any binds the service requires
 # * `$pkg_prefix/BUILD_DEPS` - Any dependencies we need build the package
 # * `$pkg_prefix/CFLAGS` - Any CFLAGS for things that link against us
 # * `$pkg_prefix/DEPS` - Any dependencies we need to use the package at runtime
@@ -1820,6 +1830,10 @@ _build_metadata() {
     echo $port_part > $pkg_prefix/EXPOSES
   fi
 
+  if [[ ${#pkg_binds[@]} -gt 0 ]]; then
+    printf "%s\n" ${pkg_binds[@]} > $pkg_prefix/BINDS
+  fi
+
   if [[ ${#pkg_interpreters[@]} -gt 0 ]]; then
     local interpreters="$(printf "${pkg_prefix}/%s\n" ${pkg_interpreters[@]})"
     printf "%s\n" ${pkg_interpreters[@]} \
@@ -77,7 +77,7 @@ if ! hab pkg path core/hab-studio >/dev/null 2>&1; then
 fi
 
 info "Putting \`hab' in container PATH"
-hab pkg binlink core/hab hab
+hab pkg binlink --force --dest /bin core/hab hab
 info "Purging container hab cache"
 rm -rf $FS_ROOT/hab/cache
 
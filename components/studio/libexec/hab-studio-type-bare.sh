@@ -70,11 +70,11 @@ finish_setup() {
   $bb mkdir -p $v $HAB_STUDIO_ROOT$HAB_ROOT_PATH/bin
 
   # Put `hab` on the default `$PATH`
-  _hab pkg binlink --dest $HAB_ROOT_PATH/bin core/hab-static hab
+  _hab pkg binlink --force --dest $HAB_ROOT_PATH/bin core/hab-static hab
 
   # Create `/bin/{sh,bash}` for software that hardcodes these shells
-  _hab pkg binlink core/busybox-static bash
-  _hab pkg binlink core/busybox-static sh
+  _hab pkg binlink --force --dest /bin core/busybox-static bash
+  _hab pkg binlink --force --dest /bin core/busybox-static sh
 
   # Set the login shell for any relevant user to be `/bin/bash`
   $bb sed -e "s,/bin/sh,$busybox_path/bin/bash,g" -i $HAB_STUDIO_ROOT/etc/passwd
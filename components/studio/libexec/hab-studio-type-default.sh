@@ -38,11 +38,11 @@ finish_setup() {
   $bb mkdir -p $v $HAB_STUDIO_ROOT$HAB_ROOT_PATH/bin
 
   # Put `hab` on the default `$PATH`
-  _hab pkg binlink --dest $HAB_ROOT_PATH/bin core/hab hab
+  _hab pkg binlink --force --dest $HAB_ROOT_PATH/bin core/hab hab
 
   # Create `/bin/{sh,bash}` for software that hardcodes these shells
-  _hab pkg binlink core/bash bash
-  _hab pkg binlink core/bash sh
+  _hab pkg binlink --force --dest /bin core/bash bash
+  _hab pkg binlink --force --dest /bin core/bash sh
 
   # Create a wrapper to `build` so that any calls to it have a super-stripped
   # `$PATH` and not whatever augmented version is currently in use. This should
@@ -33,10 +33,12 @@
 use std::collections::{HashMap, BTreeMap};
 use std::fmt;
 use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
 use std::sync::{Arc, RwLock};
 use std::thread;
 use std::time::Duration;
 
+use hcore::package::PackageIdent;
 use toml;
 use uuid::Uuid;
 
@@ -75,6 +77,7 @@ pub struct CensusEntry {
     pub confirmed: bool,
     pub detached: bool,
     pub incarnation: LamportClock,
+    pub package_ident: Option<String>,
 }
 
 impl CensusEntry {
@@ -105,6 +108,7 @@ impl CensusEntry {
             service: service.into(),
             group: group.into(),
             incarnation: LamportClock::new(),
+            package_ident: None,
         }
     }
 
@@ -134,6 +138,13 @@ impl CensusEntry {
         self.needs_write = Some(true);
     }
 
+    /// Set the fully qualified identifier of the package we are running.
+    pub fn package_ident(&mut self, ident: Option<String>) {
+        self.package_ident = ident;
+        self.incarnation.increment();
+        self.needs_write = Some(true);
+    }
+
     /// Set our status at the leader.
     pub fn leader(&mut self, leader: bool) {
         if self.leader != leader {
@@ -626,6 +637,30 @@ impl Census {
         self.population.get(&Uuid::parse_str(&my_vote).unwrap())
     }
 
+    /// Returns true if it is our turn to update to the given package during a rolling update.
+    /// Members update one at a time, in the order of their census entry ids; it is our turn once
+    /// every live member before us is running the given release, or a newer one.
+    pub fn rolling_update_turn(&self, ident: &PackageIdent) -> bool {
+        let mut members: Vec<&CensusEntry> = self.population
+            .values()
+            .filter(|ce| ce.alive || ce.id == self.me)
+            .collect();
+        members.sort_by_key(|ce| ce.id.to_string());
+        for ce in members {
+            if ce.id == self.me {
+                return true;
+            }
+            let current = ce.package_ident
+                .as_ref()
+                .and_then(|i| PackageIdent::from_str(i).ok());
+            match current {
+                Some(ref current) if current >= ident => continue,
+                _ => return false,
+            }
+        }
+        true
+    }
+
     pub fn total_population(&self) -> usize {
         self.population.len()
     }
@@ -825,6 +860,9 @@ pub fn start_health_adjuster(census_list: Arc<RwLock<CensusList>>,
 #[cfg(test)]
 mod test {
     mod census {
+        use std::str::FromStr;
+
+        use hcore::package::PackageIdent;
         use gossip::member::MemberId;
         use census::{Census, CensusEntry};
 
@@ -865,6 +903,22 @@ mod test {
             leader.set_confirmed();
         }
 
+        #[test]
+        fn rolling_update_turn() {
+            let mut census = generate_census();
+            add_entries(&mut census, 2);
+            let next = PackageIdent::from_str("core/soup/1.0.0/20160101000000").unwrap();
+            let mut ids: Vec<String> = census.population.keys().map(|id| id.to_string()).collect();
+            ids.sort();
+            let me = census.me.to_string();
+            let my_position = ids.iter().position(|id| *id == me).unwrap();
+            assert_eq!(census.rolling_update_turn(&next), my_position == 0);
+            for (_id, ce) in census.iter_mut() {
+                ce.package_ident(Some(next.to_string()));
+            }
+            assert_eq!(census.rolling_update_turn(&next), true);
+        }
+
         #[test]
         fn has_quorum() {
             let mut census = generate_census();
@@ -20,16 +20,21 @@
 //!
 //! See the [Config](struct.Config.html) struct for the specific options available.
 
+use std::cmp;
 use std::str::FromStr;
 
 use hcore::package::PackageIdent;
 
 use error::{Error, SupError};
 use gossip::server::GOSSIP_DEFAULT_PORT;
+use limits::Limits;
 use topology::Topology;
 
 static LOGKEY: &'static str = "CFG";
 
+/// Seconds between health check hook runs unless another interval is given.
+pub const DEFAULT_HEALTH_CHECK_INTERVAL: u64 = 30;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// An enum with the various CLI commands. Used to keep track of what command was called.
 pub enum Command {
@@ -43,6 +48,7 @@ pub enum Command {
 pub enum UpdateStrategy {
     None,
     AtOnce,
+    Rolling,
 }
 
 impl UpdateStrategy {
@@ -50,6 +56,7 @@ impl UpdateStrategy {
         match strategy {
             "none" => UpdateStrategy::None,
             "at-once" => UpdateStrategy::AtOnce,
+            "rolling" => UpdateStrategy::Rolling,
             s => panic!("Invalid update strategy {}", s),
         }
     }
@@ -60,6 +67,35 @@ impl Default for UpdateStrategy {
     }
 }
 
+/// What the Supervisor does when the supervised process exits on its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RestartPolicy {
+    /// Always restart the process
+    Always,
+    /// Restart the process only if it exited with a non-zero code or was killed by a signal
+    OnFailure,
+    /// Never restart the process
+    Never,
+}
+
+impl FromStr for RestartPolicy {
+    type Err = SupError;
+    fn from_str(policy: &str) -> Result<RestartPolicy, SupError> {
+        match policy {
+            "always" => Ok(RestartPolicy::Always),
+            "on-failure" => Ok(RestartPolicy::OnFailure),
+            "never" => Ok(RestartPolicy::Never),
+            s => Err(sup_error!(Error::InvalidRestartPolicy(s.to_string()))),
+        }
+    }
+}
+
+impl Default for RestartPolicy {
+    fn default() -> RestartPolicy {
+        RestartPolicy::Always
+    }
+}
+
 impl FromStr for Command {
     type Err = SupError;
     fn from_str(s: &str) -> Result<Command, SupError> {
@@ -110,12 +146,16 @@ pub struct Config {
     version_number: u64,
     organization: Option<String>,
     ring: Option<String>,
+    health_check_interval: u64,
+    restart_policy: RestartPolicy,
+    restart_max_retries: u32,
+    limits: Limits,
 }
 
 impl Config {
     /// Create a default `Config`
     pub fn new() -> Config {
-        Config::default()
+        Config { health_check_interval: DEFAULT_HEALTH_CHECK_INTERVAL, ..Config::default() }
     }
 
     /// Set the archive
@@ -401,11 +441,55 @@ impl Config {
     pub fn ring(&self) -> &Option<String> {
         &self.ring
     }
+
+    /// Set the number of seconds between health check hook runs; an interval of 0 is taken as 1,
+    /// so the hook is never run back to back
+    pub fn set_health_check_interval(&mut self, interval: u64) -> &mut Config {
+        self.health_check_interval = cmp::max(interval, 1);
+        self
+    }
+
+    /// Return the number of seconds between health check hook runs
+    pub fn health_check_interval(&self) -> u64 {
+        self.health_check_interval
+    }
+
+    pub fn set_restart_policy(&mut self, policy: RestartPolicy) -> &mut Config {
+        self.restart_policy = policy;
+        self
+    }
+
+    /// Return what to do when the supervised process exits
+    pub fn restart_policy(&self) -> RestartPolicy {
+        self.restart_policy.clone()
+    }
+
+    /// Set the number of consecutive restarts allowed under the `on-failure` policy; 0 is
+    /// unlimited
+    pub fn set_restart_max_retries(&mut self, retries: u32) -> &mut Config {
+        self.restart_max_retries = retries;
+        self
+    }
+
+    /// Return the number of consecutive restarts allowed under the `on-failure` policy
+    pub fn restart_max_retries(&self) -> u32 {
+        self.restart_max_retries
+    }
+
+    pub fn set_limits(&mut self, limits: Limits) -> &mut Config {
+        self.limits = limits;
+        self
+    }
+
+    /// Return the resource limits given on the command line
+    pub fn limits(&self) -> &Limits {
+        &self.limits
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Config, Command};
+    use super::{Config, Command, RestartPolicy, DEFAULT_HEALTH_CHECK_INTERVAL};
     use topology::Topology;
 
     #[test]
@@ -442,6 +526,35 @@ mod tests {
         assert_eq!(c.url().as_ref().unwrap(), "http://foolio.com");
     }
 
+    #[test]
+    fn health_check_interval() {
+        let mut c = Config::new();
+        assert_eq!(c.health_check_interval(), DEFAULT_HEALTH_CHECK_INTERVAL);
+        c.set_health_check_interval(10);
+        assert_eq!(c.health_check_interval(), 10);
+        c.set_health_check_interval(0);
+        assert_eq!(c.health_check_interval(), 1);
+    }
+
+    #[test]
+    fn restart_policy() {
+        let mut c = Config::new();
+        assert_eq!(c.restart_policy(), RestartPolicy::Always);
+        c.set_restart_policy("on-failure".parse().unwrap());
+        c.set_restart_max_retries(3);
+        assert_eq!(c.restart_policy(), RestartPolicy::OnFailure);
+        assert_eq!(c.restart_max_retries(), 3);
+    }
+
+    #[test]
+    fn restart_policy_from_str() {
+        assert_eq!("always".parse::<RestartPolicy>().unwrap(), RestartPolicy::Always);
+        assert_eq!("on-failure".parse::<RestartPolicy>().unwrap(),
+                   RestartPolicy::OnFailure);
+        assert_eq!("never".parse::<RestartPolicy>().unwrap(), RestartPolicy::Never);
+        assert!("sometimes".parse::<RestartPolicy>().is_err());
+    }
+
     #[test]
     fn topology() {
         let mut c = Config::new();
@@ -114,7 +114,9 @@ pub enum Error {
     HyperError(hyper::error::Error),
     InvalidBinding(String),
     InvalidKeyParameter(String),
+    InvalidLimit(String),
     InvalidPidFile,
+    InvalidRestartPolicy(String),
     InvalidServiceGroupString(String),
     Io(io::Error),
     IPFailed,
@@ -122,6 +124,7 @@ pub enum Error {
     JsonEncode(json::EncoderError),
     KeyNotFound(String),
     MetaFileIO(io::Error),
+    MissingRequiredBind(Vec<String>),
     NetParseError(net::AddrParseError),
     NoRunFile,
     NulError(ffi::NulError),
@@ -172,7 +175,11 @@ impl fmt::Display for SupError {
             Error::InvalidKeyParameter(ref e) => {
                 format!("Invalid parameter for key generation: {:?}", e)
             }
+            Error::InvalidLimit(ref e) => format!("Invalid resource limit: {}", e),
             Error::InvalidPidFile => format!("Invalid child process PID file"),
+            Error::InvalidRestartPolicy(ref p) => {
+                format!("Invalid restart policy {}, expected always, on-failure or never", p)
+            }
             Error::InvalidServiceGroupString(ref e) => {
                 format!("Invalid service group string: {}", e)
             }
@@ -182,6 +189,11 @@ impl fmt::Display for SupError {
             Error::JsonEncode(ref e) => format!("JSON encoding error: {}", e),
             Error::KeyNotFound(ref e) => format!("Key not found in key cache: {}", e),
             Error::MetaFileIO(ref e) => format!("IO error while accessing MetaFile: {:?}", e),
+            Error::MissingRequiredBind(ref e) => {
+                format!("Missing required bind(s), provide with --bind <name>:<service_group>: \
+                         {}",
+                        e.join(", "))
+            }
             Error::NetParseError(ref e) => format!("Can't parse ip:port: {}", e),
             Error::NoRunFile => {
                 format!("No run file is present for this package; specify a run hook or \
@@ -253,7 +265,9 @@ impl error::Error for SupError {
             Error::HyperError(ref err) => err.description(),
             Error::InvalidBinding(_) => "Invalid binding parameter",
             Error::InvalidKeyParameter(_) => "Key parameter error",
+            Error::InvalidLimit(_) => "Invalid resource limit",
             Error::InvalidPidFile => "Invalid child process PID file",
+            Error::InvalidRestartPolicy(_) => "Invalid restart policy",
             Error::InvalidServiceGroupString(_) => {
                 "Service group strings must be in service.group format (example: redis.default)"
             }
@@ -263,6 +277,7 @@ impl error::Error for SupError {
             Error::JsonEncode(_) => "JSON encoding error",
             Error::KeyNotFound(_) => "Key not found in key cache",
             Error::MetaFileIO(_) => "MetaFile could not be read or written to",
+            Error::MissingRequiredBind(_) => "A required bind was not provided",
             Error::NetParseError(_) => "Can't parse IP:port",
             Error::NoRunFile => {
                 "No run file is present for this package; specify a run hook or $pkg_svc_run \
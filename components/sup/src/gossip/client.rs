@@ -22,7 +22,7 @@ use std::net::ToSocketAddrs;
 use std::str;
 
 use common::wire_message::WireMessage;
-use hcore::crypto::SymKey;
+use hcore::crypto::{default_cache_key_path, SymKey};
 use rustc_serialize::json;
 use utp::UtpSocket;
 
@@ -121,7 +121,7 @@ impl<'a> Client<'a> {
                json_str);
 
         let wire_msg: WireMessage = try!(json::decode(&json_str));
-        Ok(try!(wire_msg.msg(self.ring_key)))
+        Ok(try!(wire_msg.msg(self.ring_key, &default_cache_key_path(None))))
     }
 
     /// Send a message.
@@ -52,6 +52,10 @@ static OUTBOUND_MAX_THREADS: usize = 5;
 static INBOUND_MAX_THREADS: usize = 5;
 /// The default port for the Gossip protocol
 pub static GOSSIP_DEFAULT_PORT: usize = 9634;
+/// How many times do we try to reach our initial peers before giving up?
+static INITIAL_PEER_ATTEMPTS: usize = 10;
+/// How long do we wait between attempts to reach our initial peers, in milliseconds
+static INITIAL_PEER_RETRY_INTERVAL: u64 = 1000;
 
 /// A gossip server
 pub struct Server {
@@ -198,21 +202,24 @@ impl Server {
     ///
     /// * If we cannot contact any of the given peers after 10 attempts through the list.
     pub fn initial_peers(&self, peer_listeners: &[String]) -> Result<()> {
-
-        let fail_after = 10;
         let mut count = 0;
 
         if peer_listeners.len() > 0 {
-            while count < fail_after {
+            while count < INITIAL_PEER_ATTEMPTS {
                 if self.try_peers(peer_listeners) {
                     return Ok(());
                 } else {
                     count = count + 1;
                     outputln!("Could not connect to any initial peers; attempt {} of {}.",
                               count,
-                              fail_after);
+                              INITIAL_PEER_ATTEMPTS);
+                    if count < INITIAL_PEER_ATTEMPTS {
+                        thread::sleep(Duration::from_millis(INITIAL_PEER_RETRY_INTERVAL));
+                    }
                 }
             }
+            outputln!("Starting without joining a peer; we will be joined if another member \
+                       of the ring knows about us");
         }
         Ok(())
     }
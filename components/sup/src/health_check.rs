@@ -53,14 +53,20 @@ impl CheckResult {
     }
 }
 
-impl Display for CheckResult {
+impl Display for Status {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        let status_code = match self.status {
+        let status_code = match *self {
             Status::Ok => "OK",
             Status::Warning => "WARNING",
             Status::Critical => "CRITICAL",
             Status::Unknown => "UNKNOWN",
         };
-        write!(f, "{} - {}", status_code, self.output)
+        write!(f, "{}", status_code)
+    }
+}
+
+impl Display for CheckResult {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} - {}", self.status, self.output)
     }
 }
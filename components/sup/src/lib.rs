@@ -233,6 +233,18 @@ macro_rules! output_format {
             format!("{}", so)
         }
     };
+    (preamble $preamble:expr, logkey $logkey:expr, $content: expr) => {
+        {
+            use $crate::output::StructuredOutput;
+            let so = StructuredOutput::new($preamble,
+                                           $logkey,
+                                           line!(),
+                                           file!(),
+                                           column!(),
+                                           $content);
+            format!("{}", so)
+        }
+    };
 
     ($content: expr, $($arg:tt)*) => {
         {
@@ -272,6 +284,7 @@ pub mod topology;
 pub mod state_machine;
 pub mod sidecar;
 pub mod health_check;
+pub mod limits;
 pub mod config;
 pub mod service_config;
 pub mod census;
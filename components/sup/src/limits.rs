@@ -0,0 +1,370 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Resource limits for the supervised process.
+//!
+//! Limits are read from the `[limits]` table of the service's configuration, so a package can
+//! ship defaults in its `default.toml`, and from `--limit key=value` flags to `start`, which take
+//! precedence:
+//!
+//! * `nofile`, `nproc`, `core`, `address_space`: ulimits, applied to the process as soft limits
+//! * `memory`: a memory cap in bytes, enforced by a cgroup (Linux only)
+//! * `cpu`: a CPU cap in cores, such as `1.5`, enforced by a cgroup (Linux only)
+//!
+//! When a cgroup is in use, the Supervisor watches it and reports each time the service hits its
+//! memory cap or is throttled for exceeding its CPU cap.
+
+use std::cmp;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::os::unix::process::CommandExt;
+use std::process::{Child, Command};
+
+use libc;
+use toml;
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "LM";
+const CGROUP_ROOT: &'static str = "/sys/fs/cgroup";
+const CPU_PERIOD_US: u64 = 100000;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Limits {
+    pub nofile: Option<u64>,
+    pub nproc: Option<u64>,
+    pub core: Option<u64>,
+    pub address_space: Option<u64>,
+    pub memory: Option<u64>,
+    /// Thousandths of a core
+    pub cpu_millis: Option<u64>,
+}
+
+impl Limits {
+    /// Read limits from the `limits` table of a service's merged configuration.
+    pub fn from_cfg(cfg: &toml::Value) -> Result<Limits> {
+        let mut limits = Limits::default();
+        if let Some(&toml::Value::Table(ref table)) = cfg.lookup("limits") {
+            for (key, value) in table.iter() {
+                let value = match *value {
+                    toml::Value::String(ref s) => s.clone(),
+                    toml::Value::Integer(i) => i.to_string(),
+                    toml::Value::Float(f) => f.to_string(),
+                    _ => return Err(sup_error!(Error::InvalidLimit(key.clone()))),
+                };
+                try!(limits.set(key, &value));
+            }
+        }
+        Ok(limits)
+    }
+
+    /// Set a limit from a `key=value` pair, as given on the command line.
+    pub fn set_pair(&mut self, pair: &str) -> Result<()> {
+        let mut parts = pair.splitn(2, '=');
+        match (parts.next(), parts.next()) {
+            (Some(key), Some(value)) => self.set(key.trim(), value.trim()),
+            _ => Err(sup_error!(Error::InvalidLimit(pair.to_string()))),
+        }
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        let invalid = || sup_error!(Error::InvalidLimit(format!("{}={}", key, value)));
+        if key == "cpu" {
+            let cores = try!(value.parse::<f64>().map_err(|_| invalid()));
+            if !(cores > 0.0) {
+                return Err(invalid());
+            }
+            self.cpu_millis = Some((cores * 1000.0).round() as u64);
+            return Ok(());
+        }
+        let n = Some(try!(value.parse::<u64>().map_err(|_| invalid())));
+        match key {
+            "nofile" => self.nofile = n,
+            "nproc" => self.nproc = n,
+            "core" => self.core = n,
+            "address_space" => self.address_space = n,
+            "memory" => self.memory = n,
+            _ => return Err(invalid()),
+        }
+        Ok(())
+    }
+
+    /// Override these limits with any set in `other`.
+    pub fn merge(&mut self, other: &Limits) {
+        self.nofile = other.nofile.or(self.nofile);
+        self.nproc = other.nproc.or(self.nproc);
+        self.core = other.core.or(self.core);
+        self.address_space = other.address_space.or(self.address_space);
+        self.memory = other.memory.or(self.memory);
+        self.cpu_millis = other.cpu_millis.or(self.cpu_millis);
+    }
+
+    pub fn needs_cgroup(&self) -> bool {
+        self.memory.is_some() || self.cpu_millis.is_some()
+    }
+
+    /// Spawn the command with the ulimits applied.
+    ///
+    /// The limits are read and clamped to the hard limits here, and only set in the child between
+    /// fork and exec, so the Supervisor's own limits are never touched. Hard limits are left
+    /// alone.
+    pub fn spawn(&self, command: &mut Command) -> io::Result<Child> {
+        let wanted = [("nofile", libc::RLIMIT_NOFILE, self.nofile),
+                      ("nproc", libc::RLIMIT_NPROC, self.nproc),
+                      ("core", libc::RLIMIT_CORE, self.core),
+                      ("address_space", libc::RLIMIT_AS, self.address_space)];
+        let mut rlimits = vec![];
+        for &(name, resource, limit) in wanted.iter() {
+            let limit = match limit {
+                Some(limit) => limit,
+                None => continue,
+            };
+            let mut old = libc::rlimit {
+                rlim_cur: 0,
+                rlim_max: 0,
+            };
+            if unsafe { libc::getrlimit(resource, &mut old) } != 0 {
+                outputln!("Unable to read the {} limit: {}",
+                          name,
+                          io::Error::last_os_error());
+                continue;
+            }
+            let new = libc::rlimit {
+                rlim_cur: cmp::min(limit as libc::rlim_t, old.rlim_max),
+                rlim_max: old.rlim_max,
+            };
+            if new.rlim_cur < limit as libc::rlim_t {
+                outputln!("The {} limit of {} exceeds the hard limit; using {}",
+                          name,
+                          limit,
+                          new.rlim_cur);
+            }
+            rlimits.push((resource, new));
+        }
+        if !rlimits.is_empty() {
+            // Runs in the forked child, where only async-signal-safe calls may be made.
+            command.before_exec(move || {
+                for &(resource, ref rlimit) in rlimits.iter() {
+                    if unsafe { libc::setrlimit(resource, rlimit) } != 0 {
+                        return Err(io::Error::last_os_error());
+                    }
+                }
+                Ok(())
+            });
+        }
+        command.spawn()
+    }
+}
+
+/// The cgroups enforcing a service's memory and CPU caps.
+#[derive(Debug)]
+pub struct Cgroup {
+    memory: Option<PathBuf>,
+    cpu: Option<PathBuf>,
+    /// Times the service has hit its memory cap
+    pub memory_failures: u64,
+    /// Scheduling periods in which the service was throttled for exceeding its CPU cap
+    pub cpu_throttled: u64,
+}
+
+impl Cgroup {
+    /// Create the cgroups for a service and apply its caps. Returns `None` if there are no caps
+    /// to apply.
+    pub fn create(name: &str, limits: &Limits) -> Result<Option<Cgroup>> {
+        if !limits.needs_cgroup() {
+            return Ok(None);
+        }
+        let mut cgroup = Cgroup {
+            memory: None,
+            cpu: None,
+            memory_failures: 0,
+            cpu_throttled: 0,
+        };
+        if let Some(memory) = limits.memory {
+            let path = try!(Self::hierarchy("memory", name));
+            try!(write_value(path.join("memory.limit_in_bytes"), memory));
+            cgroup.memory = Some(path);
+        }
+        if let Some(cpu_millis) = limits.cpu_millis {
+            let path = try!(Self::hierarchy("cpu", name));
+            try!(write_value(path.join("cpu.cfs_period_us"), CPU_PERIOD_US));
+            try!(write_value(path.join("cpu.cfs_quota_us"), CPU_PERIOD_US * cpu_millis / 1000));
+            cgroup.cpu = Some(path);
+        }
+        Ok(Some(cgroup))
+    }
+
+    fn hierarchy(controller: &str, name: &str) -> Result<PathBuf> {
+        let root = PathBuf::from(CGROUP_ROOT).join(controller);
+        if !root.is_dir() {
+            return Err(sup_error!(Error::InvalidLimit(format!("the {} cgroup controller is \
+                                                               not mounted at {}",
+                                                              controller,
+                                                              root.display()))));
+        }
+        let path = root.join("hab").join(name);
+        try!(fs::create_dir_all(&path));
+        Ok(path)
+    }
+
+    /// Move a process into the cgroups.
+    pub fn add(&self, pid: u32) -> Result<()> {
+        for path in self.memory.iter().chain(self.cpu.iter()) {
+            try!(write_value(path.join("cgroup.procs"), pid));
+        }
+        Ok(())
+    }
+
+    /// Read the cgroups' counters and return a message for each cap the service has hit since
+    /// the last check.
+    pub fn check(&mut self) -> Vec<String> {
+        let mut violations = vec![];
+        if let Some(ref path) = self.memory {
+            if let Some(failures) = read_value(path.join("memory.failcnt")) {
+                if failures > self.memory_failures {
+                    violations.push(format!("hit its memory limit {} times",
+                                            failures - self.memory_failures));
+                    self.memory_failures = failures;
+                }
+            }
+        }
+        if let Some(ref path) = self.cpu {
+            let throttled = read_file(path.join("cpu.stat")).and_then(|stat| {
+                stat.lines()
+                    .filter_map(|l| {
+                        let mut parts = l.split_whitespace();
+                        match (parts.next(), parts.next()) {
+                            (Some("nr_throttled"), Some(n)) => n.parse::<u64>().ok(),
+                            _ => None,
+                        }
+                    })
+                    .next()
+            });
+            if let Some(throttled) = throttled {
+                if throttled > self.cpu_throttled {
+                    violations.push(format!("was throttled by its CPU limit {} times",
+                                            throttled - self.cpu_throttled));
+                    self.cpu_throttled = throttled;
+                }
+            }
+        }
+        violations
+    }
+}
+
+fn write_value<T: ToString>(path: PathBuf, value: T) -> Result<()> {
+    let mut file = try!(File::create(&path));
+    try!(file.write_all(value.to_string().as_bytes()));
+    Ok(())
+}
+
+fn read_file(path: PathBuf) -> Option<String> {
+    let mut contents = String::new();
+    match File::open(path).and_then(|mut f| f.read_to_string(&mut contents)) {
+        Ok(_) => Some(contents),
+        Err(_) => None,
+    }
+}
+
+fn read_value(path: PathBuf) -> Option<u64> {
+    read_file(path).and_then(|s| s.trim().parse().ok())
+}
+
+#[cfg(test)]
+mod test {
+    use std::process::{Command, Stdio};
+
+    use libc;
+    use toml;
+
+    use super::Limits;
+
+    #[test]
+    fn limits_from_cfg() {
+        let cfg = toml::Value::Table(toml::Parser::new("[limits]\nnofile = 1024\ncpu = 1.5\n\
+                                                        memory = \"536870912\"\n")
+            .parse()
+            .unwrap());
+        let limits = Limits::from_cfg(&cfg).unwrap();
+        assert_eq!(limits.nofile, Some(1024));
+        assert_eq!(limits.cpu_millis, Some(1500));
+        assert_eq!(limits.memory, Some(536870912));
+        assert!(limits.needs_cgroup());
+    }
+
+    #[test]
+    fn flags_override_cfg() {
+        let mut limits = Limits::default();
+        limits.set_pair("nofile=1024").unwrap();
+        let mut flags = Limits::default();
+        flags.set_pair("nofile = 4096").unwrap();
+        limits.merge(&flags);
+        assert_eq!(limits.nofile, Some(4096));
+    }
+
+    #[test]
+    fn merge_keeps_limits_not_overridden() {
+        let cfg = toml::Value::Table(toml::Parser::new("[limits]\nnofile = 1024\ncore = 0\n\
+                                                        cpu = 1.5\n")
+            .parse()
+            .unwrap());
+        let mut limits = Limits::from_cfg(&cfg).unwrap();
+        let mut flags = Limits::default();
+        flags.set_pair("nofile=4096").unwrap();
+        flags.set_pair("memory=536870912").unwrap();
+        limits.merge(&flags);
+        assert_eq!(limits.nofile, Some(4096));
+        assert_eq!(limits.core, Some(0));
+        assert_eq!(limits.cpu_millis, Some(1500));
+        assert_eq!(limits.memory, Some(536870912));
+        assert_eq!(limits.nproc, None);
+
+        let merged = limits.clone();
+        limits.merge(&Limits::default());
+        assert_eq!(limits, merged);
+    }
+
+    #[test]
+    fn spawn_limits_only_the_child() {
+        let mut before = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        assert_eq!(unsafe { libc::getrlimit(libc::RLIMIT_NOFILE, &mut before) }, 0);
+        let mut limits = Limits::default();
+        limits.nofile = Some(64);
+        let child = limits.spawn(Command::new("sh")
+                .arg("-c")
+                .arg("ulimit -n")
+                .stdout(Stdio::piped()))
+            .unwrap();
+        let output = child.wait_with_output().unwrap();
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "64");
+        let mut after = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        assert_eq!(unsafe { libc::getrlimit(libc::RLIMIT_NOFILE, &mut after) }, 0);
+        assert_eq!(after.rlim_cur, before.rlim_cur);
+    }
+
+    #[test]
+    fn invalid_limits() {
+        let mut limits = Limits::default();
+        assert!(limits.set_pair("nofile").is_err());
+        assert!(limits.set("bogus", "1").is_err());
+        assert!(limits.set("cpu", "0").is_err());
+    }
+}
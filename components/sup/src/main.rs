@@ -37,8 +37,9 @@ use hcore::crypto::init as crypto_init;
 use hcore::package::PackageIdent;
 use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 
-use sup::config::{Command, Config, UpdateStrategy};
+use sup::config::{Command, Config, UpdateStrategy, DEFAULT_HEALTH_CHECK_INTERVAL};
 use sup::error::{Error, Result, SupError};
+use sup::limits::Limits;
 use sup::command::*;
 use sup::topology::Topology;
 use sup::util::parse_ip_port_with_defaults;
@@ -59,6 +60,9 @@ static DEFAULT_HTTP_LISTEN_PORT: u16 = 9631;
 
 const DEFAULT_GOSSIP_LISTEN_PORT: u16 = 9634;
 
+const DEFAULT_RESTART_MAX_RETRIES: u32 = 5;
+
+static PEER_ENVVAR: &'static str = "HAB_PEER";
 static RING_ENVVAR: &'static str = "HAB_RING";
 static RING_KEY_ENVVAR: &'static str = "HAB_RING_KEY";
 
@@ -161,10 +165,21 @@ fn config_from_args(args: &ArgMatches, subcommand: &str, sub_args: &ArgMatches)
 
     let gossip_peers = match sub_args.values_of("peer") {
         Some(gp) => gp.map(|s| s.to_string()).collect(),
-        None => vec![],
+        None => {
+            match henv::var(PEER_ENVVAR) {
+                Ok(val) => {
+                    val.split(',')
+                        .map(|s| s.trim())
+                        .filter(|s| !s.is_empty())
+                        .map(|s| s.to_string())
+                        .collect()
+                }
+                Err(_) => vec![],
+            }
+        }
     };
     config.set_gossip_peer(gossip_peers);
-    if sub_args.value_of("permanent-peer").is_some() {
+    if sub_args.is_present("permanent-peer") {
         config.set_gossip_permanent(true);
     }
     if let Some(sg) = sub_args.value_of("service-group") {
@@ -174,6 +189,20 @@ fn config_from_args(args: &ArgMatches, subcommand: &str, sub_args: &ArgMatches)
         config.set_file_path(fp.to_string());
     }
     config.set_version_number(value_t!(sub_args, "version-number", u64).unwrap_or(0));
+    config.set_health_check_interval(value_t!(sub_args, "health-check-interval", u64)
+        .unwrap_or(DEFAULT_HEALTH_CHECK_INTERVAL));
+    if let Some(ref policy) = sub_args.value_of("restart-policy") {
+        config.set_restart_policy(try!(policy.parse()));
+    }
+    config.set_restart_max_retries(value_t!(sub_args, "restart-max-retries", u32)
+        .unwrap_or(DEFAULT_RESTART_MAX_RETRIES));
+    if let Some(pairs) = sub_args.values_of("limit") {
+        let mut limits = Limits::default();
+        for pair in pairs {
+            try!(limits.set_pair(pair));
+        }
+        config.set_limits(limits);
+    }
     let ring = match sub_args.value_of("ring") {
         Some(val) => Some(try!(SymKey::get_latest_pair_for(&val, &default_cache_key_path(None)))),
         None => {
@@ -204,6 +233,9 @@ fn config_from_args(args: &ArgMatches, subcommand: &str, sub_args: &ArgMatches)
     if args.value_of("no-color").is_some() {
         sup::output::set_no_color(true);
     }
+    if args.is_present("json-logs") {
+        sup::output::set_json(true);
+    }
 
     if let Some(org) = sub_args.value_of("organization") {
         config.set_organization(org.to_string());
@@ -251,8 +283,9 @@ fn main() {
             .long("strategy")
             .short("s")
             .takes_value(true)
-            .possible_values(&["none", "at-once"])
-            .help("The update strategy; [default: none].")
+            .possible_values(&["none", "at-once", "rolling"])
+            .help("The update strategy; [default: none]. at-once updates as soon as a newer \
+                   release is found, rolling updates members of the group one at a time.")
     };
 
     let sub_start = SubCommand::with_name("start")
@@ -285,7 +318,8 @@ fn main() {
             .long("peer")
             .value_name("ip:port")
             .multiple(true)
-            .help("The listen address of an initial peer"))
+            .help("The listen address of an initial peer; may be given more than once \
+                   [default: $HAB_PEER, comma separated]"))
         .arg(Arg::with_name("listen-peer")
             .long("listen-peer")
             .value_name("ip:port")
@@ -297,7 +331,29 @@ fn main() {
         .arg(Arg::with_name("permanent-peer")
             .short("I")
             .long("permanent-peer")
-            .help("If this service is a permanent peer"));
+            .help("If this service is a permanent peer"))
+        .arg(Arg::with_name("health-check-interval")
+            .long("health-check-interval")
+            .value_name("seconds")
+            .help("Seconds between runs of the health_check hook [default: 30]"))
+        .arg(Arg::with_name("restart-policy")
+            .long("restart-policy")
+            .takes_value(true)
+            .possible_values(&["always", "on-failure", "never"])
+            .help("What to do when the service exits; [default: always]. on-failure restarts \
+                   only after a non-zero exit or a signal. Restarts back off exponentially."))
+        .arg(Arg::with_name("restart-max-retries")
+            .long("restart-max-retries")
+            .value_name("count")
+            .help("Consecutive restarts allowed by the on-failure policy before the service \
+                   is marked failed; 0 is unlimited [default: 5]"))
+        .arg(Arg::with_name("limit")
+            .long("limit")
+            .value_name("key=value")
+            .multiple(true)
+            .number_of_values(1)
+            .help("A resource limit for the service (nofile, nproc, core, address_space, \
+                   memory in bytes, or cpu in cores); overrides [limits] in its config"));
     let sub_bash = SubCommand::with_name("bash")
         .about("Start an interactive shell (bash)")
         .aliases(&["b", "ba", "bas"]);
@@ -321,6 +377,10 @@ fn main() {
             .long("no-color")
             .global(true)
             .help("Turn ANSI color off :("))
+        .arg(Arg::with_name("json-logs")
+            .long("json-logs")
+            .global(true)
+            .help("Print output as JSON lines, for log shippers"))
         .subcommand(sub_start)
         .subcommand(sub_bash)
         .subcommand(sub_sh)
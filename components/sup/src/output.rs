@@ -24,11 +24,17 @@
 //! is turned on, then every line printed is annotated with its preamble, logkey, and precise
 //! location. Without verbose, it prints simply the preamble and logkey. Coloring does what it says
 //! on the tin :)
+//!
+//! A third global option switches output to JSON lines, one object per line with a timestamp, the
+//! preamble, the stream the output came from (`stdout` or `stderr` of the service, or `sup` for
+//! the Supervisor itself), the logkey, and the message. This is meant for log shippers.
 
 use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
 use std::fmt;
 
 use ansi_term::Colour::{White, Cyan, Green};
+use rustc_serialize::json;
+use time;
 
 use PROGRAM_NAME;
 
@@ -36,6 +42,12 @@ static mut VERBOSE: AtomicBool = ATOMIC_BOOL_INIT;
 // I am sorry this isn't named the other way; I can't get an atomic initializer that defaults to
 // true. Them's the breaks.
 static mut NO_COLOR: AtomicBool = ATOMIC_BOOL_INIT;
+static mut JSON: AtomicBool = ATOMIC_BOOL_INIT;
+
+/// The logkey used for lines read from a service's standard output.
+pub const STDOUT_LOGKEY: &'static str = "O";
+/// The logkey used for lines read from a service's standard error.
+pub const STDERR_LOGKEY: &'static str = "E";
 
 /// True if verbose output is on.
 pub fn is_verbose() -> bool {
@@ -67,6 +79,28 @@ pub fn set_no_color(booly: bool) {
     }
 }
 
+/// True if output is formatted as JSON lines.
+pub fn is_json() -> bool {
+    unsafe { JSON.load(Ordering::Relaxed) }
+}
+
+/// Turn JSON lines output on or off.
+pub fn set_json(booly: bool) {
+    unsafe {
+        JSON.store(booly, Ordering::Relaxed);
+    }
+}
+
+/// A single line of output when formatting as JSON.
+#[derive(RustcEncodable)]
+struct JsonLine<'a> {
+    timestamp: String,
+    preamble: &'a str,
+    stream: &'static str,
+    logkey: &'static str,
+    message: &'a str,
+}
+
 /// Adds structure to printed output. Stores a preamble, a logkey, line, file, column, and content
 /// to print.
 pub struct StructuredOutput<'a> {
@@ -78,6 +112,7 @@ pub struct StructuredOutput<'a> {
     content: &'a str,
     pub verbose: Option<bool>,
     pub color: Option<bool>,
+    pub json: Option<bool>,
 }
 
 impl<'a> StructuredOutput<'a> {
@@ -98,6 +133,30 @@ impl<'a> StructuredOutput<'a> {
             content: content,
             verbose: None,
             color: None,
+            json: None,
+        }
+    }
+
+    /// The stream this output came from: the service's `stdout` or `stderr`, or `sup`.
+    pub fn stream(&self) -> &'static str {
+        match self.logkey {
+            STDOUT_LOGKEY => "stdout",
+            STDERR_LOGKEY => "stderr",
+            _ => "sup",
+        }
+    }
+
+    fn fmt_json(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let line = JsonLine {
+            timestamp: time::now_utc().rfc3339().to_string(),
+            preamble: self.preamble,
+            stream: self.stream(),
+            logkey: self.logkey,
+            message: self.content.trim_right_matches('\n'),
+        };
+        match json::encode(&line) {
+            Ok(encoded) => write!(f, "{}", encoded),
+            Err(_) => Err(fmt::Error),
         }
     }
 }
@@ -107,6 +166,9 @@ impl<'a> StructuredOutput<'a> {
 // function. Viola!
 impl<'a> fmt::Display for StructuredOutput<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.json.unwrap_or(is_json()) {
+            return self.fmt_json(f);
+        }
         let verbose = self.verbose.unwrap_or(is_verbose());
         let color = self.color.unwrap_or(is_color());
         let preamble_color = if self.preamble == PROGRAM_NAME.as_str() {
@@ -149,7 +211,7 @@ impl<'a> fmt::Display for StructuredOutput<'a> {
 
 #[cfg(test)]
 mod tests {
-    use super::StructuredOutput;
+    use super::{StructuredOutput, STDERR_LOGKEY};
     use ansi_term::Colour::{White, Cyan};
 
     use PROGRAM_NAME;
@@ -187,4 +249,20 @@ mod tests {
                            Cyan.paint(progname),
                            White.bold().paint("SOT")));
     }
+
+    #[test]
+    fn format_json() {
+        let mut so = so("soup", "opeth is \"amazing\"\n");
+        so.json = Some(true);
+        let out = format!("{}", so);
+        assert!(out.starts_with("{\"timestamp\":\""));
+        assert!(out.ends_with("\"preamble\":\"soup\",\"stream\":\"sup\",\"logkey\":\"SOT\",\
+                               \"message\":\"opeth is \\\"amazing\\\"\"}"));
+    }
+
+    #[test]
+    fn stream() {
+        let so = StructuredOutput::new("soup", STDERR_LOGKEY, 1, file!(), 2, "oops");
+        assert_eq!(so.stream(), "stderr");
+    }
 }
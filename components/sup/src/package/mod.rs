@@ -86,6 +86,11 @@ impl Package {
         })
     }
 
+    /// The bind names this package requires to be satisfied with `--bind` when started.
+    pub fn binds(&self) -> Result<Vec<String>> {
+        Ok(try!(self.pkg_install.binds()))
+    }
+
     /// A vector of ports we expose
     pub fn exposes(&self) -> Vec<String> {
         // This function really should be returning a `Result` as it could fail for a gaggle of
@@ -283,26 +288,8 @@ impl Package {
                         config: &ServiceConfig,
                         supervisor: &Supervisor)
                         -> Result<CheckResult> {
-        if let Some(hook) = self.hooks().health_check_hook {
-            match hook.run(Some(config)) {
-                Ok(output) => Ok(health_check::CheckResult::ok(output)),
-                Err(SupError { err: Error::HookFailed(_, 1, output), .. }) => {
-                    Ok(health_check::CheckResult::warning(output))
-                }
-                Err(SupError { err: Error::HookFailed(_, 2, output), .. }) => {
-                    Ok(health_check::CheckResult::critical(output))
-                }
-                Err(SupError { err: Error::HookFailed(_, 3, output), .. }) => {
-                    Ok(health_check::CheckResult::unknown(output))
-                }
-                Err(SupError { err: Error::HookFailed(_, code, output), .. }) => {
-                    Err(sup_error!(Error::HealthCheck(format!("hook exited code={}, \
-                                                                    output={}",
-                                                              code,
-                                                              output))))
-                }
-                Err(e) => Err(SupError::from(e)),
-            }
+        if let Some(result) = self.health_check_hook(config) {
+            result
         } else {
             let (health, status) = supervisor.status();
             let last_config = try!(self.last_config());
@@ -314,6 +301,35 @@ impl Package {
         }
     }
 
+    /// Runs the package's health check hook, if it has one. Its exit code of 0 to 3 is the
+    /// result's status; any other exit, or failing to run it at all, is an error.
+    pub fn health_check_hook(&self, config: &ServiceConfig) -> Option<Result<CheckResult>> {
+        let hook = match self.hooks().health_check_hook {
+            Some(hook) => hook,
+            None => return None,
+        };
+        let result = match hook.run(Some(config)) {
+            Ok(output) => Ok(health_check::CheckResult::ok(output)),
+            Err(SupError { err: Error::HookFailed(_, 1, output), .. }) => {
+                Ok(health_check::CheckResult::warning(output))
+            }
+            Err(SupError { err: Error::HookFailed(_, 2, output), .. }) => {
+                Ok(health_check::CheckResult::critical(output))
+            }
+            Err(SupError { err: Error::HookFailed(_, 3, output), .. }) => {
+                Ok(health_check::CheckResult::unknown(output))
+            }
+            Err(SupError { err: Error::HookFailed(_, code, output), .. }) => {
+                Err(sup_error!(Error::HealthCheck(format!("hook exited code={}, \
+                                                                output={}",
+                                                          code,
+                                                          output))))
+            }
+            Err(e) => Err(SupError::from(e)),
+        };
+        Some(result)
+    }
+
     pub fn hooks(&self) -> HookTable {
         let mut hooks = HookTable::new(&self);
         hooks.load_hooks();
@@ -12,8 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::sync::{Arc, RwLock};
+use std::sync::{mpsc, Arc, RwLock};
 use std::path::Path;
+use std::thread;
 
 use common::command::ProgressBar;
 use depot_client::Client;
@@ -23,18 +24,31 @@ use hcore::package::PackageIdent;
 use wonder;
 use wonder::actor::{GenServer, InitResult, HandleResult, ActorSender, ActorResult};
 
+use census::CensusList;
+use config::UpdateStrategy;
 use error::SupError;
 use package::Package;
 
+static LOGKEY: &'static str = "PU";
 const TIMEOUT_MS: u64 = 60_000;
+/// How soon to check again when a newer release was found, but it is not our turn to update
+const ROLLING_TIMEOUT_MS: u64 = 5_000;
+/// Longest a single request waits on the depot for package events
+const WATCH_TIMEOUT_MS: u64 = 30_000;
+/// How often to look for news from the watcher thread while the depot is being watched
+const WATCH_POLL_MS: u64 = 1_000;
 
 pub type PackageUpdaterActor = wonder::actor::Actor<UpdaterMessage>;
 
 pub struct PackageUpdater;
 
 impl PackageUpdater {
-    pub fn start(url: &str, package: Arc<RwLock<Package>>) -> PackageUpdaterActor {
-        let state = UpdaterState::new(url.to_string(), package);
+    pub fn start(url: &str,
+                 package: Arc<RwLock<Package>>,
+                 strategy: UpdateStrategy,
+                 census_list: Arc<RwLock<CensusList>>)
+                 -> PackageUpdaterActor {
+        let state = UpdaterState::new(url.to_string(), package, strategy, census_list);
         wonder::actor::Builder::new(PackageUpdater)
             .name("package-updater".to_string())
             .start(state)
@@ -53,14 +67,90 @@ pub struct UpdaterState {
     pub depot: String,
     pub package: Arc<RwLock<Package>>,
     pub status: UpdaterStatus,
+    pub strategy: UpdateStrategy,
+    pub census_list: Arc<RwLock<CensusList>>,
+    /// Receives whether each batch of package events from the depot may include a newer
+    /// release, while a watcher thread is running. The thread exits if the depot can't be
+    /// watched, after which releases are checked for on an interval.
+    watcher: Option<mpsc::Receiver<bool>>,
+    /// True once a newer release has been found, until we have updated to it
+    pending: bool,
 }
 
 impl UpdaterState {
-    pub fn new(depot: String, package: Arc<RwLock<Package>>) -> Self {
+    pub fn new(depot: String,
+               package: Arc<RwLock<Package>>,
+               strategy: UpdateStrategy,
+               census_list: Arc<RwLock<CensusList>>)
+               -> Self {
         UpdaterState {
             depot: depot,
             package: package,
             status: UpdaterStatus::Stopped,
+            strategy: strategy,
+            census_list: census_list,
+            watcher: None,
+            pending: false,
+        }
+    }
+
+    /// How long to wait before checking for a release again. While the depot is watched we only
+    /// wait long enough to stay responsive to messages between looks at the watcher.
+    fn idle_ms(&self) -> u64 {
+        if self.watcher.is_some() {
+            WATCH_POLL_MS
+        } else {
+            TIMEOUT_MS
+        }
+    }
+
+    /// Returns true if a newer release may be available and the depot should be asked for it.
+    /// Never blocks; the depot is watched from a thread of its own which is started the first
+    /// time through, and again whenever it exits.
+    fn should_check(&mut self, origin: &str, name: &str) -> bool {
+        if self.pending {
+            return true;
+        }
+        if self.watcher.is_none() {
+            let (tx, rx) = mpsc::channel();
+            let (depot, origin, name) = (self.depot.clone(), origin.to_string(), name.to_string());
+            match thread::Builder::new()
+                .name("package-watcher".to_string())
+                .spawn(move || watch(depot, origin, name, tx)) {
+                Ok(_) => self.watcher = Some(rx),
+                Err(e) => debug!("Failed to start package watcher: {:?}", e),
+            }
+            return true;
+        }
+        let mut changed = false;
+        let mut watching = true;
+        if let Some(ref rx) = self.watcher {
+            loop {
+                match rx.try_recv() {
+                    Ok(batch) => changed |= batch,
+                    Err(mpsc::TryRecvError::Empty) => break,
+                    Err(mpsc::TryRecvError::Disconnected) => {
+                        watching = false;
+                        changed = true;
+                        break;
+                    }
+                }
+            }
+        }
+        if !watching {
+            self.watcher = None;
+        }
+        changed
+    }
+
+    /// Returns true if we may update to the given package now, according to our strategy.
+    fn may_update(&self, latest: &PackageIdent) -> bool {
+        match self.strategy {
+            UpdateStrategy::Rolling => {
+                let cl = self.census_list.read().unwrap();
+                cl.local_census().rolling_update_turn(latest)
+            }
+            _ => true,
         }
     }
 }
@@ -93,11 +183,13 @@ impl GenServer for PackageUpdater {
                       _me: &ActorSender<Self::T>,
                       state: &mut Self::S)
                       -> HandleResult<Self::T> {
-        let package = state.package.read().unwrap();
-        // JW TODO: Store and use the version if the package was started with a specific version.
-        //          This will allow an operator to lock to a version and receive security updates
-        //          in the form of release updates for a package.
-        let ident = PackageIdent::new(package.origin.clone(), package.name.clone(), None, None);
+        let (origin, name) = {
+            let package = state.package.read().unwrap();
+            (package.origin.clone(), package.name.clone())
+        };
+        if !state.should_check(&origin, &name) {
+            return HandleResult::NoReply(Some(state.idle_ms()));
+        }
         let depot_client = match Client::new(&state.depot, None) {
             Ok(client) => client,
             Err(e) => {
@@ -105,10 +197,20 @@ impl GenServer for PackageUpdater {
                 return HandleResult::NoReply(Some(TIMEOUT_MS));
             }
         };
+        let package = state.package.read().unwrap();
+        // JW TODO: Store and use the version if the package was started with a specific version.
+        //          This will allow an operator to lock to a version and receive security updates
+        //          in the form of release updates for a package.
+        let ident = PackageIdent::new(origin, name, None, None);
         match depot_client.show_package(ident) {
             Ok(remote) => {
                 let latest_ident: PackageIdent = remote.get_ident().clone().into();
                 if &latest_ident > package.ident() {
+                    state.pending = true;
+                    if !state.may_update(&latest_ident) {
+                        debug!("Waiting for our turn to update to {}", &latest_ident);
+                        return HandleResult::NoReply(Some(ROLLING_TIMEOUT_MS));
+                    }
                     let mut progress = ProgressBar::default();
                     match depot_client.fetch_package(latest_ident.clone(),
                                                      &Path::new(FS_ROOT_PATH)
@@ -116,11 +218,29 @@ impl GenServer for PackageUpdater {
                                                      Some(&mut progress)) {
                         Ok(archive) => {
                             debug!("Updater downloaded new package to {:?}", archive);
-                            // JW TODO: actually handle verify and unpack results
-                            archive.verify(&default_cache_key_path(None)).unwrap();
-                            archive.unpack(None).unwrap();
-                            let latest_package = Package::load(&latest_ident, None).unwrap();
+                            if let Err(e) = archive.verify(&default_cache_key_path(None)) {
+                                outputln!("Failed to verify {}, not updating: {}",
+                                          &latest_ident,
+                                          e);
+                                return HandleResult::NoReply(Some(TIMEOUT_MS));
+                            }
+                            if let Err(e) = archive.unpack(None) {
+                                outputln!("Failed to unpack {}, not updating: {}",
+                                          &latest_ident,
+                                          e);
+                                return HandleResult::NoReply(Some(TIMEOUT_MS));
+                            }
+                            let latest_package = match Package::load(&latest_ident, None) {
+                                Ok(package) => package,
+                                Err(e) => {
+                                    outputln!("Failed to load {}, not updating: {}",
+                                              &latest_ident,
+                                              e);
+                                    return HandleResult::NoReply(Some(TIMEOUT_MS));
+                                }
+                            };
                             state.status = UpdaterStatus::Stopped;
+                            state.pending = false;
                             let msg = wonder::actor::Message::Cast(UpdaterMessage::Update(latest_package));
                             tx.send(msg).unwrap();
                             HandleResult::NoReply(None)
@@ -132,7 +252,7 @@ impl GenServer for PackageUpdater {
                     }
                 } else {
                     debug!("Package found is not newer than ours");
-                    HandleResult::NoReply(Some(TIMEOUT_MS))
+                    HandleResult::NoReply(Some(state.idle_ms()))
                 }
             }
             Err(e) => {
@@ -159,3 +279,39 @@ impl GenServer for PackageUpdater {
         }
     }
 }
+
+/// Long-poll the depot for events of the package's origin, telling the updater after every batch
+/// whether it may include a newer release of the package. Returns once the depot can't be watched
+/// or the updater has gone away.
+fn watch(depot: String, origin: String, name: String, tx: mpsc::Sender<bool>) {
+    let depot_client = match Client::new(&depot, None) {
+        Ok(client) => client,
+        Err(e) => {
+            debug!("Failed to create HTTP client: {:?}", e);
+            return;
+        }
+    };
+    let mut after = match depot_client.watch_origin(&origin, None, None, 0) {
+        Ok(events) => events.next,
+        Err(e) => {
+            debug!("Depot can't be watched for package events: {:?}", e);
+            return;
+        }
+    };
+    loop {
+        let events = depot_client.watch_origin(&origin, None, Some(after), WATCH_TIMEOUT_MS);
+        let changed = match events {
+            Ok(events) => {
+                after = events.next;
+                events.missed || events.events.iter().any(|e| e.name == name)
+            }
+            Err(e) => {
+                debug!("Failed to watch depot for package events: {:?}", e);
+                return;
+            }
+        };
+        if tx.send(changed).is_err() {
+            return;
+        }
+    }
+}
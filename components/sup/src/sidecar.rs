@@ -17,17 +17,26 @@
 //!
 //! Supports:
 //!
-//! * /config: Returns the current configuration of the service
+//! * /config: Returns the current configuration of the service, as TOML
+//! * /config.json: Returns the current configuration of the service, as JSON
 //! * /health: Returns the current health of the service
 //! * /status: Returns the current status of the service, from the supervisors point of view
+//! * /census: Returns the census of every service group we know about, and the versions of the
+//!   configuration gossiped to them, as JSON
+//! * /election: Returns the state of leader elections, as JSON
+//! * /gossip: Returns the state of the gossip ring, as JSON
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::net::SocketAddrV4;
 use std::sync::{Arc, RwLock};
 
+use rustc_serialize::Encodable;
 use rustc_serialize::json;
+use iron::headers::ContentType;
+use iron::modifiers::Header;
 use iron::prelude::*;
 use iron::status;
+use toml;
 use router::Router;
 use wonder;
 use wonder::actor::{GenServer, InitResult, HandleResult, StopReason, ActorSender};
@@ -43,10 +52,12 @@ use gossip::detector::Detector;
 use census::{CensusList, CensusEntry, CensusEntryId, Census};
 use election::{Election, ElectionList};
 use supervisor::Supervisor;
+use util::convert;
 
 static LOGKEY: &'static str = "SI";
 const GET_HEALTH: &'static str = "/health";
 const GET_CONFIG: &'static str = "/config";
+const GET_CONFIG_JSON: &'static str = "/config.json";
 const GET_STATUS: &'static str = "/status";
 const GET_GOSSIP: &'static str = "/gossip";
 const GET_CENSUS: &'static str = "/census";
@@ -151,7 +162,11 @@ impl GenServer for Sidecar {
         let package_2 = state.package.clone();
         let config_1 = state.config.clone();
 
+        let package_3 = state.package.clone();
+
         router.get(GET_CONFIG, move |r: &mut Request| config(&package_1, r));
+        router.get(GET_CONFIG_JSON,
+                   move |r: &mut Request| config_json(&package_3, r));
 
         let supervisor_1 = state.supervisor.clone();
         router.get(GET_STATUS, move |r: &mut Request| status(&supervisor_1, r));
@@ -167,12 +182,13 @@ impl GenServer for Sidecar {
             Arc::new(ml.read().unwrap().my_id.clone())
         };
         let gfl = state.gossip_file_list.clone();
+        let gfl2 = state.gossip_file_list.clone();
 
         router.get(GET_GOSSIP,
                    move |r: &mut Request| gossip(&ml, &rl, &gfl, &detector, &id, r));
 
         let cl1 = state.census_list.clone();
-        router.get(GET_CENSUS, move |r: &mut Request| census(&cl1, r));
+        router.get(GET_CENSUS, move |r: &mut Request| census(&cl1, &gfl2, r));
 
         let el = state.election_list.clone();
         router.get(GET_ELECTION, move |r: &mut Request| election(&el, r));
@@ -199,12 +215,7 @@ fn election(election_list: &Arc<RwLock<ElectionList>>, _req: &mut Request) -> Ir
         mine: el.election(),
     };
 
-    let json_response = match json::encode(&er) {
-        Ok(json_response) => json_response,
-        Err(e) => return Err(IronError::from(sup_error!(Error::JsonEncode(e)))),
-    };
-
-    Ok(Response::with((status::Ok, json_response)))
+    json_response(&er)
 }
 
 #[derive(Debug, RustcEncodable)]
@@ -239,12 +250,7 @@ fn gossip(member_list: &Arc<RwLock<MemberList>>,
         file_write_retries: &gfl.file_write_retries,
     };
 
-    let json_response = match json::encode(&gossip_response) {
-        Ok(json_response) => json_response,
-        Err(e) => return Err(IronError::from(sup_error!(Error::JsonEncode(e)))),
-    };
-
-    Ok(Response::with((status::Ok, json_response)))
+    json_response(&gossip_response)
 }
 
 #[derive(Debug, RustcEncodable)]
@@ -256,13 +262,18 @@ struct CensusResponse<'a> {
     minimum_quorum: bool,
     quorum: bool,
     leader: Option<&'a CensusEntry>,
+    config_versions: BTreeMap<String, BTreeMap<String, u64>>,
 }
 
 /// The /census callback.
 ///
 /// Returns information about the census.
-fn census(census_list: &Arc<RwLock<CensusList>>, _req: &mut Request) -> IronResult<Response> {
+fn census(census_list: &Arc<RwLock<CensusList>>,
+          gossip_file_list: &Arc<RwLock<GossipFileList>>,
+          _req: &mut Request)
+          -> IronResult<Response> {
     let cl = census_list.read().unwrap();
+    let gfl = gossip_file_list.read().unwrap();
     let response = CensusResponse {
         id: &cl.me().id.clone(),
         census_list: &cl,
@@ -271,14 +282,10 @@ fn census(census_list: &Arc<RwLock<CensusList>>, _req: &mut Request) -> IronResu
         minimum_quorum: cl.local_census().minimum_quorum(),
         quorum: cl.local_census().has_quorum(),
         leader: cl.local_census().get_leader(),
+        config_versions: gfl.versions(),
     };
 
-    let json_response = match json::encode(&response) {
-        Ok(json_response) => json_response,
-        Err(e) => return Err(IronError::from(sup_error!(Error::JsonEncode(e)))),
-    };
-
-    Ok(Response::with((status::Ok, json_response)))
+    json_response(&response)
 }
 
 /// The /config callback.
@@ -294,9 +301,40 @@ fn config(lock: &Arc<RwLock<Package>>, _req: &mut Request) -> IronResult<Respons
     Ok(Response::with((status::Ok, last_config)))
 }
 
+/// The /config.json callback.
+///
+/// Returns the current running configuration, converted to JSON.
+///
+/// # Failures
+///
+/// * Fails if the configuration cannot be found or parsed.
+fn config_json(lock: &Arc<RwLock<Package>>, _req: &mut Request) -> IronResult<Response> {
+    let package = lock.read().unwrap();
+    let last_config = try!(package.last_config());
+    let mut parser = toml::Parser::new(&last_config);
+    match parser.parse() {
+        Some(table) => {
+            let body = convert::toml_table_to_json(table).to_string();
+            Ok(Response::with((status::Ok, Header(ContentType::json()), body)))
+        }
+        None => Err(IronError::from(sup_error!(Error::TomlParser(parser.errors)))),
+    }
+}
+
+/// Encodes a response struct as JSON, with the matching content type.
+fn json_response<T: Encodable>(response: &T) -> IronResult<Response> {
+    let json_response = match json::encode(response) {
+        Ok(json_response) => json_response,
+        Err(e) => return Err(IronError::from(sup_error!(Error::JsonEncode(e)))),
+    };
+
+    Ok(Response::with((status::Ok, Header(ContentType::json()), json_response)))
+}
+
 /// The /status callback.
 ///
-/// Returns the current status from the supervisors perspective.
+/// Returns the current status from the supervisors perspective. A service which has exhausted
+/// its restarts is reported as unavailable.
 ///
 /// # Failures
 ///
@@ -304,13 +342,18 @@ fn config(lock: &Arc<RwLock<Package>>, _req: &mut Request) -> IronResult<Respons
 fn status(lock: &Arc<RwLock<Supervisor>>, _req: &mut Request) -> IronResult<Response> {
     let supervisor = lock.read().unwrap();
     let (_health, output) = supervisor.status();
-    Ok(Response::with((status::Ok, output)))
+    if supervisor.is_failed() {
+        Ok(Response::with((status::ServiceUnavailable, output)))
+    } else {
+        Ok(Response::with((status::Ok, output)))
+    }
 }
 
 /// The /health callback.
 ///
-/// Runs the packages health_check, if one is defined. Returns the status, and outputs both the
-/// status and config.
+/// Returns the result of the last periodic health_check run by the supervisor. If no check has
+/// run yet, the health_check is run on demand. Returns the status, and outputs both the status
+/// and config.
 ///
 /// # Failures
 ///
@@ -324,7 +367,10 @@ fn health(package_lock: &Arc<RwLock<Package>>,
         let package = package_lock.read().unwrap();
         let config = config_lock.read().unwrap();
         let supervisor = supervisor_lock.read().unwrap();
-        try!(package.health_check(&config, &supervisor))
+        match supervisor.health_check {
+            Some(ref result) => result.clone(),
+            None => try!(package.health_check(&config, &supervisor)),
+        }
     };
 
     match result.status {
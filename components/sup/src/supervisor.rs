@@ -16,8 +16,11 @@
 ///
 /// The supervisor is responsible for running any services we are asked to start. It handles
 /// spawning the new process, watching for failure, and ensuring the service is either up or down.
-/// If the process dies, the supervisor will restart it.
+/// If the process dies, the supervisor will restart it according to its restart policy, backing
+/// off exponentially between consecutive restarts. A service which exhausts its restarts is left
+/// in the `failed` state.
 
+use std::cmp;
 use std::fmt;
 use std::fs::{self, File};
 use std::io::BufReader;
@@ -31,12 +34,23 @@ use hcore::package::PackageIdent;
 use libc::{pid_t, c_int};
 use time::{Duration, SteadyTime};
 
+use config::RestartPolicy;
 use error::{Result, Error};
+use health_check::CheckResult;
+use limits::{Cgroup, Limits};
+use output;
 use util::signals;
 
 const PIDFILE_NAME: &'static str = "PID";
 static LOGKEY: &'static str = "SV";
 
+/// Delay before the first restart; each consecutive restart doubles it
+const RESTART_BACKOFF_BASE_SECS: i64 = 1;
+/// The longest the Supervisor will wait between restarts
+const RESTART_BACKOFF_MAX_SECS: i64 = 60;
+/// A process which stays up this long resets the count of consecutive restarts
+const RESTART_RESET_SECS: i64 = 300;
+
 // Functions from POSIX libc.
 extern "C" {
     fn waitpid(pid: pid_t, status: *mut c_int, options: c_int) -> pid_t;
@@ -98,12 +112,13 @@ pub fn WTERMSIG(status: c_int) -> c_int {
 
 pub type Pid = u32;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum ProcessState {
     Down,
     Up,
     Start,
     Restart,
+    Failed,
 }
 
 impl fmt::Display for ProcessState {
@@ -113,6 +128,7 @@ impl fmt::Display for ProcessState {
             &ProcessState::Up => "up",
             &ProcessState::Start => "start",
             &ProcessState::Restart => "restart",
+            &ProcessState::Failed => "failed",
         };
         write!(f, "{}", state)
     }
@@ -125,16 +141,37 @@ pub struct Supervisor {
     pub state: ProcessState,
     pub state_entered: SteadyTime,
     pub has_started: bool,
+    pub health_check: Option<CheckResult>,
+    pub restart_policy: RestartPolicy,
+    pub restart_max_retries: u32,
+    /// Consecutive restarts since the process last stayed up for `RESTART_RESET_SECS`
+    pub restarts: u32,
+    next_restart: Option<SteadyTime>,
+    started_at: SteadyTime,
+    pub limits: Limits,
+    cgroup: Option<Cgroup>,
 }
 
 impl Supervisor {
-    pub fn new(package_ident: PackageIdent) -> Supervisor {
+    pub fn new(package_ident: PackageIdent,
+               restart_policy: RestartPolicy,
+               restart_max_retries: u32,
+               limits: Limits)
+               -> Supervisor {
         Supervisor {
             pid: None,
             package_ident: package_ident,
             state: ProcessState::Down,
             state_entered: SteadyTime::now(),
             has_started: false,
+            health_check: None,
+            restart_policy: restart_policy,
+            restart_max_retries: restart_max_retries,
+            restarts: 0,
+            next_restart: None,
+            started_at: SteadyTime::now(),
+            limits: limits,
+            cgroup: None,
         }
     }
 
@@ -144,13 +181,28 @@ impl Supervisor {
     }
 
     pub fn status(&self) -> (bool, String) {
-        let status = format!("{}: {} for {}",
-                             self.package_ident,
-                             self.state,
-                             SteadyTime::now() - self.state_entered);
+        let mut status = format!("{}: {} for {}",
+                                 self.package_ident,
+                                 self.state,
+                                 SteadyTime::now() - self.state_entered);
+        if let Some(ref check) = self.health_check {
+            status.push_str(&format!(", health {}", check.status));
+        }
+        if self.restarts > 0 {
+            status.push_str(&format!(", restarted {} times", self.restarts));
+        }
+        if let Some(ref cgroup) = self.cgroup {
+            if cgroup.memory_failures > 0 {
+                status.push_str(&format!(", hit memory limit {} times", cgroup.memory_failures));
+            }
+            if cgroup.cpu_throttled > 0 {
+                status.push_str(&format!(", throttled by cpu limit {} times",
+                                         cgroup.cpu_throttled));
+            }
+        }
         let healthy = match self.state {
             ProcessState::Up | ProcessState::Start | ProcessState::Restart => true,
-            ProcessState::Down => false,
+            ProcessState::Down | ProcessState::Failed => false,
         };
         (healthy, status)
     }
@@ -159,14 +211,24 @@ impl Supervisor {
         if self.pid.is_none() {
             outputln!(preamble & self.package_ident.name, "Starting");
             self.enter_state(ProcessState::Start);
-            let mut child = try!(Command::new(self.run_cmd())
+            let mut child = try!(self.limits.spawn(Command::new(self.run_cmd())
                 .stdin(Stdio::null())
                 .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .spawn());
+                .stderr(Stdio::piped())));
             self.pid = Some(child.id());
+            self.apply_cgroup(child.id());
+            self.next_restart = None;
+            self.started_at = SteadyTime::now();
             try!(self.create_pidfile());
             let package_name = self.package_ident.name.clone();
+            if let Some(stderr) = child.stderr.take() {
+                let package_name = package_name.clone();
+                try!(thread::Builder::new()
+                    .name(String::from("sup-service-read-err"))
+                    .spawn(move || -> Result<()> {
+                        stream_reader(stderr, package_name, output::STDERR_LOGKEY)
+                    }));
+            }
             try!(thread::Builder::new()
                 .name(String::from("sup-service-read"))
                 .spawn(move || -> Result<()> { child_reader(&mut child, package_name) }));
@@ -178,8 +240,10 @@ impl Supervisor {
         Ok(())
     }
 
-    /// Send a SIGTERM to a process, wait 8 seconds, then send SIGKILL
+    /// Send a SIGTERM to a process, wait 8 seconds, then send SIGKILL. The last health check
+    /// result was of the stopped process, so it's cleared.
     pub fn stop(&mut self) -> Result<()> {
+        self.health_check = None;
         let wait = match self.pid {
             Some(ref pid) => {
                 outputln!(preamble & self.package_ident.name, "Stopping");
@@ -221,6 +285,54 @@ impl Supervisor {
         }
     }
 
+    pub fn is_failed(&self) -> bool {
+        if let ProcessState::Failed = self.state {
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Place the process in the cgroups enforcing its memory and CPU limits, creating them the
+    /// first time. A failure is reported but doesn't stop the service from running.
+    fn apply_cgroup(&mut self, pid: Pid) {
+        if self.cgroup.is_none() && self.limits.needs_cgroup() {
+            match Cgroup::create(&self.package_ident.name, &self.limits) {
+                Ok(cgroup) => self.cgroup = cgroup,
+                Err(e) => {
+                    outputln!(preamble & self.package_ident.name,
+                              "Unable to apply memory and CPU limits: {}",
+                              e);
+                    return;
+                }
+            }
+        }
+        if let Some(ref cgroup) = self.cgroup {
+            if let Err(e) = cgroup.add(pid) {
+                outputln!(preamble & self.package_ident.name,
+                          "Unable to apply memory and CPU limits: {}",
+                          e);
+            }
+        }
+    }
+
+    /// Report any resource limits the service has hit since the last check.
+    pub fn check_limits(&mut self) {
+        if let Some(ref mut cgroup) = self.cgroup {
+            for violation in cgroup.check() {
+                outputln!(preamble & self.package_ident.name, "Service {}", violation);
+            }
+        }
+    }
+
+    /// Whether any backoff before restarting an exited process has elapsed.
+    pub fn restart_due(&self) -> bool {
+        match self.next_restart {
+            Some(at) => SteadyTime::now() >= at,
+            None => true,
+        }
+    }
+
     pub fn is_down(&self) -> bool {
         if let ProcessState::Down = self.state {
             true
@@ -265,8 +377,10 @@ impl Supervisor {
             match waitpid(cpid, &mut status, 1 as c_int) {
                 0 => {} // Nothing returned,
                 pid if pid == cpid => {
+                    let mut failed = true;
                     if WIFEXITED(status) {
                         let exit_code = WEXITSTATUS(status);
+                        failed = exit_code != 0;
                         outputln!("{} - process {} died with exit code {}",
                                   self.package_ident.name,
                                   pid,
@@ -283,11 +397,16 @@ impl Supervisor {
                                   pid);
                     }
                     match self.state {
-                        ProcessState::Up | ProcessState::Start | ProcessState::Restart => {
+                        ProcessState::Up | ProcessState::Start => {
                             outputln!("{} - Service exited", self.package_ident.name);
                             self.pid = None;
+                            self.schedule_restart(failed);
                         }
-                        ProcessState::Down => {
+                        ProcessState::Restart => {
+                            outputln!("{} - Service exited", self.package_ident.name);
+                            self.pid = None;
+                        }
+                        ProcessState::Down | ProcessState::Failed => {
                             self.enter_state(ProcessState::Down);
                             self.pid = None;
                         }
@@ -310,6 +429,42 @@ impl Supervisor {
         Ok(())
     }
 
+    /// Decide what to do after the process exited on its own, per the restart policy. Either
+    /// schedules the next start after an exponential backoff, or leaves the service down or
+    /// failed.
+    fn schedule_restart(&mut self, failed: bool) {
+        if SteadyTime::now() - self.started_at >= Duration::seconds(RESTART_RESET_SECS) {
+            self.restarts = 0;
+        }
+        let restart = match self.restart_policy {
+            RestartPolicy::Always => true,
+            RestartPolicy::OnFailure => {
+                failed &&
+                (self.restart_max_retries == 0 || self.restarts < self.restart_max_retries)
+            }
+            RestartPolicy::Never => false,
+        };
+        if restart {
+            let backoff = restart_backoff_secs(self.restarts);
+            self.restarts += 1;
+            self.next_restart = Some(SteadyTime::now() + Duration::seconds(backoff));
+            outputln!(preamble & self.package_ident.name,
+                      "Restarting in {}s (restart {})",
+                      backoff,
+                      self.restarts);
+        } else if failed {
+            outputln!(preamble & self.package_ident.name,
+                      "Not restarting; the service has failed");
+            self.enter_state(ProcessState::Failed);
+            self.cleanup_pidfile();
+        } else {
+            outputln!(preamble & self.package_ident.name,
+                      "Not restarting; the service has finished");
+            self.enter_state(ProcessState::Down);
+            self.cleanup_pidfile();
+        }
+    }
+
     pub fn run_cmd(&self) -> PathBuf {
         self.service_dir().join("run")
     }
@@ -383,6 +538,14 @@ impl Drop for Supervisor {
     }
 }
 
+/// Seconds to wait before a restart, given how many consecutive restarts came before it.
+fn restart_backoff_secs(restarts: u32) -> i64 {
+    if restarts >= 32 {
+        return RESTART_BACKOFF_MAX_SECS;
+    }
+    cmp::min(RESTART_BACKOFF_BASE_SECS << restarts, RESTART_BACKOFF_MAX_SECS)
+}
+
 /// Consume output from a child process until EOF, then finish
 fn child_reader(child: &mut Child, package_name: String) -> Result<()> {
     let c_stdout = match child.stdout {
@@ -390,15 +553,103 @@ fn child_reader(child: &mut Child, package_name: String) -> Result<()> {
         None => return Err(sup_error!(Error::UnpackFailed)),
     };
 
-    let mut reader = BufReader::new(c_stdout);
+    stream_reader(c_stdout, package_name, output::STDOUT_LOGKEY)
+}
+
+/// Reads lines from one of the child's output streams and prints them with our structured output
+/// formatter, tagged with the logkey of the stream.
+fn stream_reader<R: Read>(stream: R, package_name: String, logkey: &'static str) -> Result<()> {
+    let mut reader = BufReader::new(stream);
     let mut buffer = String::new();
 
     while reader.read_line(&mut buffer).unwrap() > 0 {
-        let mut line = output_format!(preamble &package_name, logkey "O");
-        line.push_str(&buffer);
-        print!("{}", line);
+        println!("{}",
+                 output_format!(preamble &package_name,
+                                logkey logkey,
+                                buffer.trim_right_matches('\n')));
         buffer.clear();
     }
-    debug!("child_reader exiting");
+    debug!("stream_reader exiting for {}", logkey);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use hcore::package::PackageIdent;
+    use time::{Duration, SteadyTime};
+
+    use config::RestartPolicy;
+    use limits::Limits;
+    use super::{restart_backoff_secs, ProcessState, Supervisor, RESTART_BACKOFF_MAX_SECS,
+                RESTART_RESET_SECS};
+
+    fn new_supervisor(policy: RestartPolicy, max_retries: u32) -> Supervisor {
+        let ident = PackageIdent::new("acme", "restart-test", None, None);
+        let mut supervisor = Supervisor::new(ident, policy, max_retries, Limits::default());
+        supervisor.enter_state(ProcessState::Up);
+        supervisor
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_max() {
+        assert_eq!(restart_backoff_secs(0), 1);
+        assert_eq!(restart_backoff_secs(1), 2);
+        assert_eq!(restart_backoff_secs(5), 32);
+        assert_eq!(restart_backoff_secs(6), RESTART_BACKOFF_MAX_SECS);
+        assert_eq!(restart_backoff_secs(63), RESTART_BACKOFF_MAX_SECS);
+        assert_eq!(restart_backoff_secs(u32::max_value()), RESTART_BACKOFF_MAX_SECS);
+    }
+
+    #[test]
+    fn always_restarts_after_any_exit() {
+        let mut supervisor = new_supervisor(RestartPolicy::Always, 1);
+        supervisor.schedule_restart(false);
+        supervisor.schedule_restart(true);
+        supervisor.schedule_restart(true);
+        assert_eq!(supervisor.restarts, 3);
+        assert!(supervisor.next_restart.unwrap() > SteadyTime::now() + Duration::seconds(2));
+        assert_eq!(supervisor.state, ProcessState::Up);
+    }
+
+    #[test]
+    fn on_failure_restarts_failures_until_retries_run_out() {
+        let mut supervisor = new_supervisor(RestartPolicy::OnFailure, 2);
+        supervisor.schedule_restart(true);
+        supervisor.schedule_restart(true);
+        assert_eq!(supervisor.restarts, 2);
+        assert_eq!(supervisor.state, ProcessState::Up);
+        supervisor.schedule_restart(true);
+        assert_eq!(supervisor.restarts, 2);
+        assert_eq!(supervisor.state, ProcessState::Failed);
+    }
+
+    #[test]
+    fn on_failure_leaves_a_finished_service_down() {
+        let mut supervisor = new_supervisor(RestartPolicy::OnFailure, 0);
+        supervisor.schedule_restart(false);
+        assert_eq!(supervisor.restarts, 0);
+        assert!(supervisor.next_restart.is_none());
+        assert_eq!(supervisor.state, ProcessState::Down);
+    }
+
+    #[test]
+    fn never_restarts() {
+        let mut supervisor = new_supervisor(RestartPolicy::Never, 0);
+        supervisor.schedule_restart(true);
+        assert_eq!(supervisor.state, ProcessState::Failed);
+        let mut supervisor = new_supervisor(RestartPolicy::Never, 0);
+        supervisor.schedule_restart(false);
+        assert_eq!(supervisor.state, ProcessState::Down);
+        assert_eq!(supervisor.restarts, 0);
+    }
+
+    #[test]
+    fn staying_up_resets_the_restart_count() {
+        let mut supervisor = new_supervisor(RestartPolicy::OnFailure, 2);
+        supervisor.restarts = 2;
+        supervisor.started_at = SteadyTime::now() - Duration::seconds(RESTART_RESET_SECS);
+        supervisor.schedule_restart(true);
+        assert_eq!(supervisor.restarts, 1);
+        assert_eq!(supervisor.state, ProcessState::Up);
+    }
+}
@@ -42,7 +42,7 @@ use census::{self, CensusList};
 use common::gossip_file::GossipFileList;
 use package::{self, Package, PackageUpdaterActor};
 use util::signals::SignalNotifier;
-use error::{Result, SupError};
+use error::{Error, Result, SupError};
 use config::Config;
 use service_config::ServiceConfig;
 use sidecar;
@@ -51,7 +51,9 @@ use gossip;
 use gossip::rumor::{Rumor, RumorList};
 use gossip::member::MemberList;
 use election::ElectionList;
-use time::SteadyTime;
+use health_check;
+use limits::Limits;
+use time::{self, SteadyTime};
 use util::signals;
 use config::UpdateStrategy;
 
@@ -132,20 +134,23 @@ impl<'a> Worker<'a> {
         let package_exposes = package.exposes().clone();
         let package_port = package_exposes.first().map(|e| e.clone());
         let package_ident = package.ident().clone();
+        {
+            let bindings = config.bind();
+            let bound: Vec<&str> = bindings.iter()
+                .filter_map(|b| b.splitn(2, ':').next())
+                .collect();
+            let missing: Vec<String> = try!(package.binds())
+                .into_iter()
+                .filter(|b| !bound.contains(&b.as_str()))
+                .collect();
+            if !missing.is_empty() {
+                return Err(sup_error!(Error::MissingRequiredBind(missing)));
+            }
+        }
         let pkg_lock = Arc::new(RwLock::new(package));
         let pkg_lock_1 = pkg_lock.clone();
 
 
-        match config.update_strategy() {
-            UpdateStrategy::None => {},
-            _ => {
-                let pkg_lock_2 = pkg_lock.clone();
-                if let &Some(ref url) = config.url() {
-                    pkg_updater = Some(package::PackageUpdater::start(url, pkg_lock_2));
-                }
-            }
-        }
-
         let gossip_server = gossip::server::Server::new(String::from(config.gossip_listen_ip()),
                                                         config.gossip_listen_port(),
                                                         config.gossip_permanent(),
@@ -156,6 +161,25 @@ impl<'a> Worker<'a> {
                                                         Some(package_exposes),
                                                         package_port);
 
+        {
+            let mut cl = gossip_server.census_list.write().unwrap();
+            cl.me_mut().package_ident(Some(package_ident.to_string()));
+        }
+
+        match config.update_strategy() {
+            UpdateStrategy::None => {}
+            strategy => {
+                let pkg_lock_2 = pkg_lock.clone();
+                if let &Some(ref url) = config.url() {
+                    pkg_updater = Some(package::PackageUpdater::start(url,
+                                                                      pkg_lock_2,
+                                                                      strategy,
+                                                                      gossip_server.census_list
+                                                                          .clone()));
+                }
+            }
+        }
+
         try!(gossip_server.start_inbound());
         try!(gossip_server.initial_peers(config.gossip_peer()));
         gossip_server.start_outbound();
@@ -173,7 +197,19 @@ impl<'a> Worker<'a> {
         let service_config_lock = Arc::new(RwLock::new(service_config));
         let service_config_lock_1 = service_config_lock.clone();
 
-        let supervisor = Arc::new(RwLock::new(Supervisor::new(package_ident)));
+        let limits = {
+            let sc = service_config_lock.read().unwrap();
+            let mut limits = match try!(sc.to_toml()).lookup("cfg") {
+                Some(cfg) => try!(Limits::from_cfg(cfg)),
+                None => Limits::default(),
+            };
+            limits.merge(config.limits());
+            limits
+        };
+        let supervisor = Arc::new(RwLock::new(Supervisor::new(package_ident,
+                                                              config.restart_policy(),
+                                                              config.restart_max_retries(),
+                                                              limits)));
 
         let sidecar_ml = gossip_server.member_list.clone();
         let sidecar_rl = gossip_server.rumor_list.clone();
@@ -222,10 +258,32 @@ impl<'a> Worker<'a> {
         }
         let package = self.package.read().unwrap();
         try!(package.copy_run(&service_config));
+        let mut cl = self.census_list.write().unwrap();
+        cl.me_mut().package_ident(Some(package.ident().to_string()));
         Ok(())
     }
 }
 
+/// Runs the package's reconfigure hook so the running service can apply a configuration change
+/// live. Returns false if the service must be restarted instead, either because the package has
+/// no reconfigure hook or because the hook failed.
+fn reconfigure(package: &Package, service_config: &ServiceConfig) -> bool {
+    match package.reconfigure(service_config) {
+        Ok(true) => {
+            outputln!(preamble & package.name,
+                      "Applied configuration change with the reconfigure hook");
+            true
+        }
+        Ok(false) => false,
+        Err(e) => {
+            outputln!(preamble & package.name,
+                      "Reconfigure hook failed, falling back to a restart: {}",
+                      e);
+            false
+        }
+    }
+}
+
 /// The main loop of a topology.
 ///
 /// 1. Loops forever
@@ -256,6 +314,9 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
         .name("signal-handler".to_string())
         .start(())
         .unwrap();
+    let health_check_interval =
+        time::Duration::seconds(worker.config.health_check_interval() as i64);
+    let mut next_health_check = SteadyTime::now() + health_check_interval;
     loop {
         let start_time = SteadyTime::now();
         match handler.receiver.try_recv() {
@@ -284,6 +345,7 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
         {
             let mut supervisor = worker.supervisor.write().unwrap();
             try!(supervisor.check_process());
+            supervisor.check_limits();
         }
 
         let mut restart_process = false;
@@ -331,10 +393,11 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
                         // Write the configuration, and restart if needed
                         if try!(service_config.write(&package)) {
                             try!(package.copy_run(&service_config));
-                            try!(package.reconfigure(&service_config));
-                            outputln!("Restarting because the service config was updated via the \
-                                       census");
-                            restart_process = true;
+                            if !reconfigure(&package, &service_config) {
+                                outputln!("Restarting because the service config was updated \
+                                           via the census");
+                                restart_process = true;
+                            }
                         }
                     }
                 }
@@ -374,8 +437,9 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
                 service_config.cfg(&package);
                 if try!(service_config.write(&package)) {
                     try!(package.copy_run(&service_config));
-                    let existed = try!(package.reconfigure(&service_config));
-                    if !existed {
+                    if !reconfigure(&package, &service_config) {
+                        outputln!("Restarting because the service config was updated via \
+                                   config apply");
                         restart_process = true;
                     }
                 }
@@ -401,14 +465,51 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
             }
         }
 
+        if start_time >= next_health_check {
+            next_health_check = start_time + health_check_interval;
+            let package = worker.package.read().unwrap();
+            let running = worker.supervisor.read().unwrap().pid.is_some();
+            // The hook runs without the supervisor locked, as it may take a while
+            let checked = if running {
+                let service_config = worker.service_config.read().unwrap();
+                package.health_check_hook(&service_config)
+            } else {
+                None
+            };
+            if let Some(checked) = checked {
+                let result = match checked {
+                    Ok(result) => result,
+                    Err(e) => {
+                        outputln!(preamble & package.name, "Health check could not run: {}", e);
+                        health_check::CheckResult::unknown(e.to_string())
+                    }
+                };
+                let mut supervisor = worker.supervisor.write().unwrap();
+                let changed = match supervisor.health_check {
+                    Some(ref last) => last.status != result.status,
+                    None => true,
+                };
+                if changed {
+                    outputln!(preamble & package.name, "Health check {}", result);
+                }
+                if result.status == health_check::Status::Critical {
+                    outputln!("Restarting because the health check reported critical");
+                    restart_process = true;
+                }
+                supervisor.health_check = Some(result);
+            }
+        }
+
         {
             let mut supervisor = worker.supervisor.write().unwrap();
             // If our target is that the process is up
             if supervisor.is_up() {
                 // And no process is running
                 if supervisor.pid.is_none() {
-                    // Start a new one
-                    try!(supervisor.start());
+                    // Start a new one, once any restart backoff has elapsed
+                    if supervisor.restart_due() {
+                        try!(supervisor.start());
+                    }
                 } else {
                     // If we were supposed to restart
                     if restart_process {
@@ -39,7 +39,7 @@ fi
 # Extract hart into destination, ignoring the signed header info
 tail -n +6 $hart_file | xzcat | sudo tar xf - -C /
 # Add symlink for convenience under `/bin`
-sudo /$(tail -n +6 $hart_file | xzcat | sudo tar t | head -n 1)bin/hab pkg binlink core/hab hab
+sudo /$(tail -n +6 $hart_file | xzcat | sudo tar t | head -n 1)bin/hab pkg binlink --force --dest /bin core/hab hab
 
 # Clear the file download and extraction clean trap
 trap - INT TERM EXIT
@@ -48,6 +48,6 @@ rm -f $hart_file
 sudo hab install core/hab > /dev/null 2>&1
 sudo hab install core/hab-sup > /dev/null 2>&1
 sudo hab install core/hab-director > /dev/null 2>&1
-sudo hab pkg binlink core/hab hab
+sudo hab pkg binlink --force --dest /bin core/hab hab
 # JW TODO: give director the same treatment as sup in hab
-sudo hab pkg binlink core/hab-director hab-director
+sudo hab pkg binlink --force --dest /bin core/hab-director hab-director